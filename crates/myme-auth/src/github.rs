@@ -1,4 +1,4 @@
-use crate::oauth::{OAuth2Config, OAuth2Provider};
+use crate::oauth::{token_http_client, OAuth2Config, OAuth2Provider};
 
 const GITHUB_AUTH_URL: &str = "https://github.com/login/oauth/authorize";
 const GITHUB_TOKEN_URL: &str = "https://github.com/login/oauth/access_token";
@@ -7,17 +7,20 @@ const DEFAULT_REDIRECT_URI: &str = "http://localhost:8080/callback";
 /// GitHub OAuth2 authentication provider
 pub struct GitHubAuth {
     config: OAuth2Config,
+    http: reqwest::Client,
 }
 
 impl GitHubAuth {
     /// Create a new GitHub authentication provider with default scopes
     ///
-    /// Default scopes: repo, read:user, user:email
+    /// Default scopes: repo, read:user, user:email, gist, read:org
     pub fn new(client_id: String, client_secret: String) -> Self {
         let default_scopes = vec![
             "repo".to_string(),       // Full control of private repositories
             "read:user".to_string(),  // Read user profile data
             "user:email".to_string(), // Access user email addresses
+            "gist".to_string(),       // Create gists (note sharing)
+            "read:org".to_string(),   // List organizations, teams and their repos
         ];
         Self::with_scopes(client_id, client_secret, default_scopes)
     }
@@ -33,8 +36,17 @@ impl GitHubAuth {
                 redirect_uri: DEFAULT_REDIRECT_URI.to_string(),
                 scopes,
             },
+            http: token_http_client(Vec::new()),
         }
     }
+
+    /// Send token requests through `proxies`.
+    pub fn with_proxies(mut self, proxies: Vec<reqwest::Proxy>) -> Self {
+        if !proxies.is_empty() {
+            self.http = token_http_client(proxies);
+        }
+        self
+    }
 }
 
 impl OAuth2Provider for GitHubAuth {
@@ -45,6 +57,10 @@ impl OAuth2Provider for GitHubAuth {
     fn config(&self) -> &OAuth2Config {
         &self.config
     }
+
+    fn http_client(&self) -> reqwest::Client {
+        self.http.clone()
+    }
 }
 
 #[cfg(test)]
@@ -57,7 +73,7 @@ mod tests {
         let auth = GitHubAuth::new("test_client_id".to_string(), "test_client_secret".to_string());
 
         assert_eq!(auth.service_id(), "github");
-        assert_eq!(auth.config().scopes.len(), 3);
+        assert_eq!(auth.config().scopes.len(), 5);
         assert!(auth.config().scopes.contains(&"repo".to_string()));
     }
 
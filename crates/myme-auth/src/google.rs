@@ -3,6 +3,8 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::inspector::{missing_scopes, ScopeRequirement};
+
 const GOOGLE_AUTH_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
 const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
 const GOOGLE_USERINFO_URL: &str = "https://www.googleapis.com/oauth2/v2/userinfo";
@@ -12,6 +14,52 @@ const GMAIL_SCOPE: &str = "https://www.googleapis.com/auth/gmail.modify";
 const CALENDAR_SCOPE: &str = "https://www.googleapis.com/auth/calendar";
 const USERINFO_SCOPE: &str = "https://www.googleapis.com/auth/userinfo.email";
 
+/// A Google feature the user grants access to separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoogleFeature {
+    Gmail,
+    Calendar,
+}
+
+impl GoogleFeature {
+    pub const ALL: [GoogleFeature; 2] = [GoogleFeature::Gmail, GoogleFeature::Calendar];
+
+    pub fn scope(self) -> &'static str {
+        match self {
+            GoogleFeature::Gmail => GMAIL_SCOPE,
+            GoogleFeature::Calendar => CALENDAR_SCOPE,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GoogleFeature::Gmail => "Gmail",
+            GoogleFeature::Calendar => "Calendar",
+        }
+    }
+
+    pub fn requirement(self) -> ScopeRequirement {
+        ScopeRequirement { feature: self.label(), scope: self.scope() }
+    }
+
+    /// Whether `granted` scopes cover this feature.
+    pub fn is_granted(self, granted: &[String]) -> bool {
+        missing_scopes(granted, &[self.requirement()]).is_empty()
+    }
+}
+
+/// Scopes to request for `features`; the account email scope is always included.
+pub fn scopes_for(features: &[GoogleFeature]) -> Vec<&'static str> {
+    features.iter().map(|f| f.scope()).chain([USERINFO_SCOPE]).collect()
+}
+
+/// Features in `requested` the user unticked on the consent screen.
+/// `granted` is the space-separated `scope` of the token response.
+pub fn declined_features(requested: &[GoogleFeature], granted: &str) -> Vec<GoogleFeature> {
+    let granted: Vec<String> = granted.split_whitespace().map(str::to_string).collect();
+    requested.iter().copied().filter(|f| !f.is_granted(&granted)).collect()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GoogleTokenResponse {
     pub access_token: String,
@@ -31,21 +79,52 @@ pub struct GoogleUserInfo {
 pub struct GoogleOAuth2Provider {
     pub client_id: String,
     pub client_secret: String,
+    http: reqwest::Client,
 }
 
 impl GoogleOAuth2Provider {
     pub fn new(client_id: String, client_secret: String) -> Self {
-        Self { client_id, client_secret }
+        Self { client_id, client_secret, http: reqwest::Client::new() }
+    }
+
+    /// Send token and user-info requests through `proxies`.
+    pub fn with_proxies(mut self, proxies: Vec<reqwest::Proxy>) -> Self {
+        if proxies.is_empty() {
+            return self;
+        }
+        match proxies
+            .into_iter()
+            .fold(reqwest::Client::builder(), reqwest::ClientBuilder::proxy)
+            .build()
+        {
+            Ok(client) => self.http = client,
+            Err(e) => tracing::warn!("Failed to apply proxy settings: {}", e),
+        }
+        self
     }
 
-    /// Generate authorization URL for OAuth flow.
+    /// Generate authorization URL for OAuth flow, requesting every feature.
     /// Returns (url, state) where state should be verified on callback.
     pub fn authorization_url(&self, port: u16) -> (String, String) {
+        self.authorization_url_for(port, &GoogleFeature::ALL, false)
+    }
+
+    /// Authorization URL requesting only `features`.
+    ///
+    /// With `incremental`, Google adds the new scopes to the ones already
+    /// granted and the returned token covers both, so enabling a feature later
+    /// doesn't ask the user to approve the others again.
+    pub fn authorization_url_for(
+        &self,
+        port: u16,
+        features: &[GoogleFeature],
+        incremental: bool,
+    ) -> (String, String) {
         let state = uuid::Uuid::new_v4().to_string();
         let redirect_uri = format!("http://localhost:{}/callback", port);
-        let scopes = format!("{} {} {}", GMAIL_SCOPE, CALENDAR_SCOPE, USERINFO_SCOPE);
+        let scopes = scopes_for(features).join(" ");
 
-        let url = format!(
+        let mut url = format!(
             "{}?client_id={}&redirect_uri={}&response_type=code&scope={}&state={}&access_type=offline&prompt=consent",
             GOOGLE_AUTH_URL,
             urlencoding::encode(&self.client_id),
@@ -53,6 +132,9 @@ impl GoogleOAuth2Provider {
             urlencoding::encode(&scopes),
             urlencoding::encode(&state),
         );
+        if incremental {
+            url.push_str("&include_granted_scopes=true");
+        }
 
         (url, state)
     }
@@ -61,9 +143,8 @@ impl GoogleOAuth2Provider {
     #[tracing::instrument(skip(self, code), level = "info")]
     pub async fn exchange_code(&self, code: &str, port: u16) -> Result<GoogleTokenResponse> {
         let redirect_uri = format!("http://localhost:{}/callback", port);
-        let client = reqwest::Client::new();
-
-        let response = client
+        let response = self
+            .http
             .post(GOOGLE_TOKEN_URL)
             .form(&[
                 ("client_id", self.client_id.as_str()),
@@ -87,9 +168,8 @@ impl GoogleOAuth2Provider {
     /// Refresh an expired access token.
     #[tracing::instrument(skip(self, refresh_token), level = "info")]
     pub async fn refresh_token(&self, refresh_token: &str) -> Result<GoogleTokenResponse> {
-        let client = reqwest::Client::new();
-
-        let response = client
+        let response = self
+            .http
             .post(GOOGLE_TOKEN_URL)
             .form(&[
                 ("client_id", self.client_id.as_str()),
@@ -112,9 +192,8 @@ impl GoogleOAuth2Provider {
     /// Get user info (email) from access token.
     #[tracing::instrument(skip(self, access_token), level = "info")]
     pub async fn get_user_info(&self, access_token: &str) -> Result<GoogleUserInfo> {
-        let client = reqwest::Client::new();
-
-        let response = client
+        let response = self
+            .http
             .get(GOOGLE_USERINFO_URL)
             .bearer_auth(access_token)
             .send()
@@ -167,6 +246,29 @@ mod tests {
         assert!(url.contains("prompt=consent"));
     }
 
+    #[test]
+    fn test_google_auth_url_for_single_feature() {
+        let provider = GoogleOAuth2Provider::new(
+            "test_client_id".to_string(),
+            "test_client_secret".to_string(),
+        );
+        let (url, _state) = provider.authorization_url_for(8080, &[GoogleFeature::Calendar], true);
+        assert!(url.contains("calendar"));
+        assert!(url.contains("userinfo.email"));
+        assert!(!url.contains("gmail"));
+        assert!(url.contains("include_granted_scopes=true"));
+
+        let (url, _state) = provider.authorization_url(8080);
+        assert!(!url.contains("include_granted_scopes"));
+    }
+
+    #[test]
+    fn test_declined_features() {
+        let granted = format!("{} {}", GMAIL_SCOPE, USERINFO_SCOPE);
+        assert_eq!(declined_features(&GoogleFeature::ALL, &granted), [GoogleFeature::Calendar]);
+        assert!(declined_features(&[GoogleFeature::Gmail], "https://mail.google.com/").is_empty());
+    }
+
     #[test]
     fn test_google_state_is_unique() {
         let provider = GoogleOAuth2Provider::new(
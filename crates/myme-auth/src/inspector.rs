@@ -0,0 +1,354 @@
+//! Health checks for stored OAuth tokens.
+//!
+//! `TokenInspector` looks at each stored `TokenSet` and reports whether it has
+//! expired, whether the provider still accepts it (one cheap API call), and
+//! which scopes needed by the app's features were never granted. The report is
+//! meant for the settings page, so "why is my calendar empty" has an answer.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::storage::{SecureStorage, TokenSet};
+
+const GITHUB_API_URL: &str = "https://api.github.com";
+const GOOGLE_TOKENINFO_URL: &str = "https://oauth2.googleapis.com/tokeninfo";
+
+/// A scope a feature needs to work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ScopeRequirement {
+    /// Feature name shown to the user
+    pub feature: &'static str,
+    pub scope: &'static str,
+}
+
+/// Scopes used by the GitHub features.
+pub const GITHUB_REQUIREMENTS: &[ScopeRequirement] = &[
+    ScopeRequirement { feature: "Repositories and projects", scope: "repo" },
+    ScopeRequirement { feature: "Notifications", scope: "notifications" },
+    ScopeRequirement { feature: "Gists", scope: "gist" },
+    ScopeRequirement { feature: "Profile", scope: "read:user" },
+    ScopeRequirement { feature: "Organizations and teams", scope: "read:org" },
+];
+
+/// Scopes used by the Google features.
+pub const GOOGLE_REQUIREMENTS: &[ScopeRequirement] = &[
+    ScopeRequirement { feature: "Gmail", scope: "https://www.googleapis.com/auth/gmail.modify" },
+    ScopeRequirement { feature: "Calendar", scope: "https://www.googleapis.com/auth/calendar" },
+    ScopeRequirement {
+        feature: "Account email",
+        scope: "https://www.googleapis.com/auth/userinfo.email",
+    },
+];
+
+/// Broader scopes that include `scope`, per provider documentation.
+fn implied_by(scope: &str) -> &'static [&'static str] {
+    match scope {
+        "notifications" => &["repo"],
+        "read:user" | "user:email" => &["user"],
+        "read:org" => &["write:org", "admin:org"],
+        "https://www.googleapis.com/auth/gmail.modify" => &["https://mail.google.com/"],
+        _ => &[],
+    }
+}
+
+/// Requirements in `required` that `granted` doesn't cover.
+pub fn missing_scopes(granted: &[String], required: &[ScopeRequirement]) -> Vec<ScopeRequirement> {
+    required
+        .iter()
+        .filter(|req| {
+            !granted.iter().any(|g| g == req.scope || implied_by(req.scope).contains(&g.as_str()))
+        })
+        .copied()
+        .collect()
+}
+
+/// Overall state of one stored token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", content = "detail", rename_all = "snake_case")]
+pub enum TokenStatus {
+    /// Nothing stored; the account isn't connected
+    Missing,
+    /// Accepted by the provider
+    Valid,
+    /// Past its expiry; `refreshable` when a refresh token is stored
+    Expired { refreshable: bool },
+    /// Rejected by the provider (revoked, or the app's access was removed)
+    Revoked,
+    /// The provider couldn't be reached; the local checks still apply
+    Unchecked(String),
+}
+
+/// Health of one stored token.
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenReport {
+    /// Keyring service id ("github", "google")
+    pub service: String,
+    pub status: TokenStatus,
+    /// Unix timestamp the access token expires at
+    pub expires_at: Option<i64>,
+    /// Scopes the provider reports for the token, or the stored ones if it
+    /// couldn't be asked
+    pub granted_scopes: Vec<String>,
+    /// Features that won't work because their scope wasn't granted
+    pub missing_scopes: Vec<ScopeRequirement>,
+}
+
+impl TokenReport {
+    /// Whether every feature using this token should work.
+    pub fn is_healthy(&self) -> bool {
+        self.status == TokenStatus::Valid && self.missing_scopes.is_empty()
+    }
+}
+
+/// What the provider said about a token.
+enum Probe {
+    Accepted { scopes: Option<Vec<String>> },
+    Rejected,
+}
+
+/// Checks stored tokens against their providers.
+pub struct TokenInspector {
+    http: reqwest::Client,
+    github_api_url: String,
+    google_tokeninfo_url: String,
+}
+
+impl Default for TokenInspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TokenInspector {
+    pub fn new() -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            github_api_url: GITHUB_API_URL.to_string(),
+            google_tokeninfo_url: GOOGLE_TOKENINFO_URL.to_string(),
+        }
+    }
+
+    /// Inspector probing the given endpoints (for testing).
+    pub fn with_urls(github_api_url: &str, google_tokeninfo_url: &str) -> Self {
+        Self {
+            github_api_url: github_api_url.trim_end_matches('/').to_string(),
+            google_tokeninfo_url: google_tokeninfo_url.to_string(),
+            ..Self::new()
+        }
+    }
+
+    /// Send probes through `proxies`.
+    pub fn with_proxies(mut self, proxies: Vec<reqwest::Proxy>) -> Self {
+        if proxies.is_empty() {
+            return self;
+        }
+        match proxies
+            .into_iter()
+            .fold(reqwest::Client::builder(), reqwest::ClientBuilder::proxy)
+            .build()
+        {
+            Ok(client) => self.http = client,
+            Err(e) => tracing::warn!("Failed to apply proxy settings: {}", e),
+        }
+        self
+    }
+
+    /// Inspect the token stored in the keyring for `service`.
+    pub async fn inspect_stored(
+        &self,
+        service: &str,
+        required: &[ScopeRequirement],
+    ) -> TokenReport {
+        let token = SecureStorage::retrieve_token(service).ok();
+        self.inspect(service, token.as_ref(), required).await
+    }
+
+    /// Inspect `token` for `service` ("github" or "google").
+    pub async fn inspect(
+        &self,
+        service: &str,
+        token: Option<&TokenSet>,
+        required: &[ScopeRequirement],
+    ) -> TokenReport {
+        let Some(token) = token else {
+            return TokenReport {
+                service: service.to_string(),
+                status: TokenStatus::Missing,
+                expires_at: None,
+                granted_scopes: Vec::new(),
+                missing_scopes: required.to_vec(),
+            };
+        };
+
+        let mut granted = token.scopes.clone();
+        let status = if token.is_expired() {
+            // An expired access token can't be probed; refreshing is the caller's job
+            TokenStatus::Expired { refreshable: token.refresh_token.is_some() }
+        } else {
+            match self.probe(service, &token.access_token).await {
+                Ok(Probe::Accepted { scopes }) => {
+                    if let Some(scopes) = scopes {
+                        granted = scopes;
+                    }
+                    TokenStatus::Valid
+                }
+                Ok(Probe::Rejected) => TokenStatus::Revoked,
+                Err(e) => {
+                    tracing::debug!("Token probe for {} failed: {:#}", service, e);
+                    TokenStatus::Unchecked(e.to_string())
+                }
+            }
+        };
+
+        TokenReport {
+            service: service.to_string(),
+            missing_scopes: missing_scopes(&granted, required),
+            status,
+            expires_at: Some(token.expires_at),
+            granted_scopes: granted,
+        }
+    }
+
+    async fn probe(&self, service: &str, access_token: &str) -> Result<Probe> {
+        match service {
+            "github" => self.probe_github(access_token).await,
+            "google" => self.probe_google(access_token).await,
+            other => anyhow::bail!("No probe for service {}", other),
+        }
+    }
+
+    /// `GET /user`; GitHub lists the token's scopes in `X-OAuth-Scopes`.
+    async fn probe_github(&self, access_token: &str) -> Result<Probe> {
+        let response = self
+            .http
+            .get(format!("{}/user", self.github_api_url))
+            .bearer_auth(access_token)
+            .header("User-Agent", "myme")
+            .send()
+            .await
+            .context("Failed to reach GitHub")?;
+
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            return Ok(Probe::Rejected);
+        }
+        if !response.status().is_success() {
+            anyhow::bail!("GitHub returned {}", response.status());
+        }
+        // Fine-grained tokens don't send the header; keep the stored scopes then
+        let scopes = response
+            .headers()
+            .get("x-oauth-scopes")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()))
+            .map(Iterator::collect);
+        Ok(Probe::Accepted { scopes })
+    }
+
+    /// Google's tokeninfo endpoint; answers 400 for revoked or unknown tokens.
+    async fn probe_google(&self, access_token: &str) -> Result<Probe> {
+        #[derive(serde::Deserialize)]
+        struct TokenInfo {
+            #[serde(default)]
+            scope: String,
+        }
+
+        let response = self
+            .http
+            .get(&self.google_tokeninfo_url)
+            .query(&[("access_token", access_token)])
+            .send()
+            .await
+            .context("Failed to reach Google")?;
+
+        if response.status() == reqwest::StatusCode::BAD_REQUEST
+            || response.status() == reqwest::StatusCode::UNAUTHORIZED
+        {
+            return Ok(Probe::Rejected);
+        }
+        if !response.status().is_success() {
+            anyhow::bail!("Google returned {}", response.status());
+        }
+        let info: TokenInfo = response.json().await.context("Failed to parse Google token info")?;
+        let scopes = info.scope.split_whitespace().map(str::to_string).collect();
+        Ok(Probe::Accepted { scopes: Some(scopes) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn token(scopes: &[&str], expires_in: i64, refresh: bool) -> TokenSet {
+        TokenSet {
+            access_token: "tok".to_string(),
+            refresh_token: refresh.then(|| "refresh".to_string()),
+            expires_at: chrono::Utc::now().timestamp() + expires_in,
+            scopes: scopes.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_missing_scopes_honours_broader_grants() {
+        let granted = vec!["repo".to_string(), "user".to_string()];
+        let missing = missing_scopes(&granted, GITHUB_REQUIREMENTS);
+        let features: Vec<&str> = missing.iter().map(|r| r.feature).collect();
+        assert_eq!(features, ["Gists", "Organizations and teams"]);
+    }
+
+    #[tokio::test]
+    async fn test_missing_and_expired_tokens_skip_probe() {
+        let inspector = TokenInspector::with_urls("http://127.0.0.1:9", "http://127.0.0.1:9");
+
+        let report = inspector.inspect("github", None, GITHUB_REQUIREMENTS).await;
+        assert_eq!(report.status, TokenStatus::Missing);
+        assert_eq!(report.missing_scopes.len(), GITHUB_REQUIREMENTS.len());
+
+        let expired = token(&["repo"], -60, true);
+        let report = inspector.inspect("google", Some(&expired), GOOGLE_REQUIREMENTS).await;
+        assert_eq!(report.status, TokenStatus::Expired { refreshable: true });
+        assert!(!report.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_github_probe_reads_granted_scopes() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-OAuth-Scopes", "repo, read:user, gist, read:org")
+                    .set_body_json(serde_json::json!({ "login": "me" })),
+            )
+            .mount(&server)
+            .await;
+
+        let inspector = TokenInspector::with_urls(&server.uri(), "unused");
+        let report =
+            inspector.inspect("github", Some(&token(&[], 3600, false)), GITHUB_REQUIREMENTS).await;
+        assert_eq!(report.status, TokenStatus::Valid);
+        assert_eq!(report.granted_scopes, ["repo", "read:user", "gist", "read:org"]);
+        assert!(report.is_healthy());
+    }
+
+    #[tokio::test]
+    async fn test_google_probe_detects_revocation() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/tokeninfo"))
+            .and(query_param("access_token", "tok"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_json(serde_json::json!({ "error": "invalid_token" })),
+            )
+            .mount(&server)
+            .await;
+
+        let inspector = TokenInspector::with_urls("unused", &format!("{}/tokeninfo", server.uri()));
+        let report =
+            inspector.inspect("google", Some(&token(&[], 3600, true)), GOOGLE_REQUIREMENTS).await;
+        assert_eq!(report.status, TokenStatus::Revoked);
+    }
+}
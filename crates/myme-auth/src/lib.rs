@@ -1,10 +1,18 @@
 pub mod github;
 pub mod google;
+pub mod inspector;
 pub mod oauth;
 pub mod storage;
 
 pub use github::GitHubAuth;
-pub use google::{GoogleOAuth2Provider, GoogleTokenResponse, GoogleUserInfo};
+pub use google::{
+    declined_features, scopes_for, GoogleFeature, GoogleOAuth2Provider, GoogleTokenResponse,
+    GoogleUserInfo,
+};
+pub use inspector::{
+    ScopeRequirement, TokenInspector, TokenReport, TokenStatus, GITHUB_REQUIREMENTS,
+    GOOGLE_REQUIREMENTS,
+};
 pub use oauth::{OAuth2Config, OAuth2Provider};
 pub use storage::{SecureStorage, TokenSet};
 
@@ -3,10 +3,9 @@
 
 use anyhow::{Context, Result};
 use oauth2::basic::BasicClient;
-use oauth2::reqwest::async_http_client;
 use oauth2::{
-    AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, PkceCodeChallenge,
-    PkceCodeVerifier, RedirectUrl, Scope, TokenResponse, TokenUrl,
+    AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, HttpRequest, HttpResponse,
+    PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, Scope, TokenResponse, TokenUrl,
 };
 use std::net::TcpListener;
 use std::sync::Arc;
@@ -34,6 +33,51 @@ fn find_available_port() -> Option<u16> {
     None
 }
 
+/// HTTP client for token requests. Redirects are not followed, as a token
+/// endpoint redirecting elsewhere would leak the code or client secret.
+pub(crate) fn token_http_client(proxies: Vec<reqwest::Proxy>) -> reqwest::Client {
+    let builder = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+    match proxies.into_iter().fold(builder, reqwest::ClientBuilder::proxy).build() {
+        Ok(client) => client,
+        Err(e) => {
+            tracing::warn!("Failed to apply proxy settings: {}", e);
+            reqwest::Client::builder()
+                .redirect(reqwest::redirect::Policy::none())
+                .build()
+                .unwrap_or_default()
+        }
+    }
+}
+
+/// Send an oauth2 token request with `client`. oauth2's own reqwest client
+/// can't be configured, so token exchanges would bypass the proxy settings.
+async fn send_token_request(
+    client: reqwest::Client,
+    request: HttpRequest,
+) -> std::result::Result<HttpResponse, reqwest::Error> {
+    let method = reqwest::Method::from_bytes(request.method.as_str().as_bytes())
+        .unwrap_or(reqwest::Method::POST);
+    let mut builder = client.request(method, request.url.as_str()).body(request.body);
+    for (name, value) in &request.headers {
+        builder = builder.header(name.as_str(), value.as_bytes());
+    }
+    let response = builder.send().await?;
+
+    let status_code = oauth2::http::StatusCode::from_u16(response.status().as_u16())
+        .unwrap_or(oauth2::http::StatusCode::BAD_GATEWAY);
+    let mut headers = oauth2::http::HeaderMap::new();
+    for (name, value) in response.headers() {
+        if let (Ok(name), Ok(value)) = (
+            oauth2::http::header::HeaderName::from_bytes(name.as_str().as_bytes()),
+            oauth2::http::HeaderValue::from_bytes(value.as_bytes()),
+        ) {
+            headers.append(name, value);
+        }
+    }
+    let body = response.bytes().await?.to_vec();
+    Ok(HttpResponse { status_code, headers, body })
+}
+
 /// OAuth2 configuration
 #[derive(Debug, Clone)]
 pub struct OAuth2Config {
@@ -64,6 +108,11 @@ pub trait OAuth2Provider: Send + Sync {
     /// Get the OAuth2 configuration
     fn config(&self) -> &OAuth2Config;
 
+    /// Client for token requests; override to apply proxy settings
+    fn http_client(&self) -> reqwest::Client {
+        token_http_client(Vec::new())
+    }
+
     /// Start the OAuth2 authorization flow
     ///
     /// Returns the authorization URL, CSRF token, and PKCE verifier
@@ -121,7 +170,7 @@ pub trait OAuth2Provider: Send + Sync {
         let token_result = client
             .exchange_code(AuthorizationCode::new(code))
             .set_pkce_verifier(pkce_verifier)
-            .request_async(async_http_client)
+            .request_async(|request| send_token_request(self.http_client(), request))
             .await
             .context("Failed to exchange authorization code")?;
 
@@ -343,7 +392,7 @@ pub trait OAuth2Provider: Send + Sync {
         let token_result = exchange_client
             .exchange_code(AuthorizationCode::new(code))
             .set_pkce_verifier(pkce_verifier)
-            .request_async(async_http_client)
+            .request_async(|request| send_token_request(self.http_client(), request))
             .await
             .context("Failed to exchange authorization code")?;
 
@@ -387,3 +436,63 @@ pub trait OAuth2Provider: Send + Sync {
         SecureStorage::delete_token(self.service_id())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use wiremock::matchers::{body_string, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn token_requests_go_through_the_given_client() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .and(header("accept", "application/json"))
+            .and(body_string("grant_type=authorization_code&code=abc"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(
+                    r#"{"access_token":"t","token_type":"bearer"}"#,
+                    "application/json",
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let mut headers = oauth2::http::HeaderMap::new();
+        headers.insert(
+            oauth2::http::header::ACCEPT,
+            oauth2::http::HeaderValue::from_static("application/json"),
+        );
+        let request = HttpRequest {
+            url: url::Url::parse(&format!("{}/token", server.uri())).unwrap(),
+            method: oauth2::http::Method::POST,
+            headers,
+            body: b"grant_type=authorization_code&code=abc".to_vec(),
+        };
+        let response = send_token_request(token_http_client(Vec::new()), request).await.unwrap();
+        assert_eq!(response.status_code, oauth2::http::StatusCode::OK);
+        assert_eq!(response.headers["content-type"], "application/json");
+        assert_eq!(response.body, br#"{"access_token":"t","token_type":"bearer"}"#);
+    }
+
+    #[tokio::test]
+    async fn token_requests_do_not_follow_redirects() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/token"))
+            .respond_with(ResponseTemplate::new(302).insert_header("location", "/elsewhere"))
+            .mount(&server)
+            .await;
+
+        let request = HttpRequest {
+            url: url::Url::parse(&format!("{}/token", server.uri())).unwrap(),
+            method: oauth2::http::Method::POST,
+            headers: oauth2::http::HeaderMap::new(),
+            body: Vec::new(),
+        };
+        let response = send_token_request(token_http_client(Vec::new()), request).await.unwrap();
+        assert_eq!(response.status_code, oauth2::http::StatusCode::FOUND);
+    }
+}
@@ -235,6 +235,42 @@ impl SecureStorage {
 
         false
     }
+
+    /// Store a plain secret (e.g. the notes passphrase) in the keyring under `name`.
+    pub fn store_secret(name: &str, secret: &str) -> Result<()> {
+        let entry = Entry::new(KEYRING_SERVICE, name).context("Failed to create keyring entry")?;
+        entry.set_password(secret).context("Failed to store secret in keyring")?;
+        tracing::info!("Stored secret {} in system keyring", name);
+        Ok(())
+    }
+
+    /// Retrieve a secret stored with [`SecureStorage::store_secret`].
+    pub fn retrieve_secret(name: &str) -> Result<String> {
+        let entry = Entry::new(KEYRING_SERVICE, name).context("Failed to create keyring entry")?;
+        entry.get_password().context("Failed to retrieve secret from keyring")
+    }
+
+    /// Like [`SecureStorage::retrieve_secret`], but `Ok(None)` when no secret
+    /// is stored under `name`; any other keyring failure is still an error.
+    pub fn find_secret(name: &str) -> Result<Option<String>> {
+        let entry = Entry::new(KEYRING_SERVICE, name).context("Failed to create keyring entry")?;
+        match entry.get_password() {
+            Ok(secret) => Ok(Some(secret)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(e).context("Failed to retrieve secret from keyring"),
+        }
+    }
+}
+
+/// Config secrets (`keyring:` references in `config.toml`) live next to the tokens.
+impl myme_core::config_secrets::SecretStore for SecureStorage {
+    fn get(&self, name: &str) -> Result<String> {
+        Self::retrieve_secret(name)
+    }
+
+    fn set(&self, name: &str, value: &str) -> Result<()> {
+        Self::store_secret(name, value)
+    }
 }
 
 #[cfg(test)]
@@ -2,10 +2,15 @@
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
+use myme_services::db;
 use rusqlite::{params, Connection};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
-use crate::types::{AccessRole, Calendar, Event, EventStatus, EventTime};
+use crate::timezone::DisplayZone;
+use crate::types::{
+    AccessRole, Calendar, DueReminder, Event, EventStatus, EventSync, EventTime, Geocode, Reminder,
+};
 
 /// SQLite cache for Calendar data.
 pub struct CalendarCache {
@@ -15,21 +20,33 @@ pub struct CalendarCache {
 impl CalendarCache {
     /// Create a new cache at the given path.
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let conn = Connection::open(path)?;
+        let conn = db::open_maintained(path.as_ref())?;
         let cache = Self { conn };
-        cache.init_schema()?;
+        cache.migrate()?;
         Ok(cache)
     }
 
     /// Create an in-memory cache (for testing).
     #[cfg(test)]
     pub fn in_memory() -> Result<Self> {
-        let conn = Connection::open_in_memory()?;
+        let conn = db::open_in_memory()?;
         let cache = Self { conn };
-        cache.init_schema()?;
+        cache.migrate()?;
         Ok(cache)
     }
 
+    fn migrate(&self) -> Result<()> {
+        db::migrate(&self.conn, 6, |version| match version {
+            1 => self.init_schema(),
+            2 => self.add_search_index(),
+            3 => self.add_time_zone_columns(),
+            4 => self.add_calendar_preferences(),
+            5 => self.add_reminders(),
+            6 => self.add_travel_tables(),
+            _ => Ok(()),
+        })
+    }
+
     /// Initialize the database schema.
     fn init_schema(&self) -> Result<()> {
         self.conn.execute_batch(
@@ -61,6 +78,7 @@ impl CalendarCache {
                 html_link TEXT,
                 etag TEXT,
                 cached_at INTEGER NOT NULL,
+                conference_url TEXT,
                 PRIMARY KEY (id, calendar_id)
             );
 
@@ -73,10 +91,135 @@ impl CalendarCache {
             CREATE INDEX IF NOT EXISTS idx_events_start ON events(start_ms);
             "#,
         )?;
+        self.migrate_conference_url()?;
+        Ok(())
+    }
+
+    /// Add the conference_url column to caches created before it existed.
+    fn migrate_conference_url(&self) -> Result<()> {
+        let columns: Vec<String> = self
+            .conn
+            .prepare("PRAGMA table_info(events)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+        if !columns.iter().any(|c| c == "conference_url") {
+            self.conn.execute("ALTER TABLE events ADD COLUMN conference_url TEXT", [])?;
+        }
+        Ok(())
+    }
+
+    /// Add a trigram full-text index over event text (summary, description,
+    /// location, attendee names and emails), kept in sync by triggers.
+    fn add_search_index(&self) -> Result<()> {
+        let new_text = search_text_sql("NEW");
+        self.conn.execute_batch(&format!(
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS events_search USING fts5(
+                event_id UNINDEXED,
+                calendar_id UNINDEXED,
+                content,
+                tokenize = 'trigram'
+            );
+
+            CREATE TRIGGER IF NOT EXISTS events_search_insert AFTER INSERT ON events BEGIN
+                DELETE FROM events_search
+                WHERE event_id = NEW.id AND calendar_id = NEW.calendar_id;
+                INSERT INTO events_search (event_id, calendar_id, content)
+                VALUES (NEW.id, NEW.calendar_id, {new_text});
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS events_search_update AFTER UPDATE ON events BEGIN
+                DELETE FROM events_search
+                WHERE event_id = OLD.id AND calendar_id = OLD.calendar_id;
+                INSERT INTO events_search (event_id, calendar_id, content)
+                VALUES (NEW.id, NEW.calendar_id, {new_text});
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS events_search_delete AFTER DELETE ON events BEGIN
+                DELETE FROM events_search
+                WHERE event_id = OLD.id AND calendar_id = OLD.calendar_id;
+            END;
+
+            INSERT INTO events_search (event_id, calendar_id, content)
+            SELECT id, calendar_id, {existing_text} FROM events;
+            "#,
+            existing_text = search_text_sql("events"),
+        ))?;
         Ok(())
     }
 
-    /// Store a calendar in the cache.
+    /// Record the zone events were scheduled in, and which rows hold
+    /// wall-clock times (all-day or floating) rather than instants. For those
+    /// rows `start_ms`/`end_ms` are the wall-clock time read as UTC.
+    fn add_time_zone_columns(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            ALTER TABLE events ADD COLUMN time_zone TEXT;
+            ALTER TABLE events ADD COLUMN floating INTEGER NOT NULL DEFAULT 0;
+            UPDATE events SET floating = all_day;
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Per-calendar choices made in MyMe: a color override, whether the
+    /// calendar is shown, and a default reminder.
+    fn add_calendar_preferences(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            ALTER TABLE calendars ADD COLUMN color TEXT;
+            ALTER TABLE calendars ADD COLUMN visible INTEGER NOT NULL DEFAULT 1;
+            ALTER TABLE calendars ADD COLUMN default_reminder_minutes INTEGER;
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Reminders: per-event overrides (NULL follows the calendar) and
+    /// Google's default reminders per calendar, both as JSON.
+    fn add_reminders(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            ALTER TABLE events ADD COLUMN reminders_json TEXT;
+            ALTER TABLE calendars ADD COLUMN default_reminders_json TEXT NOT NULL DEFAULT '[]';
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Leave-by warnings: geocoded event locations (NULL coordinates when
+    /// nothing matched), events opted out of warnings, and warnings already
+    /// shown per event start.
+    fn add_travel_tables(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS geocoded_locations (
+                query TEXT PRIMARY KEY,
+                latitude REAL,
+                longitude REAL,
+                geocoded_at INTEGER NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS travel_opt_outs (
+                calendar_id TEXT NOT NULL,
+                event_id TEXT NOT NULL,
+                PRIMARY KEY (calendar_id, event_id)
+            );
+
+            CREATE TABLE IF NOT EXISTS travel_alerts (
+                calendar_id TEXT NOT NULL,
+                event_id TEXT NOT NULL,
+                start_ms INTEGER NOT NULL,
+                notified_at INTEGER NOT NULL,
+                PRIMARY KEY (calendar_id, event_id, start_ms)
+            );
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Store a calendar in the cache. A calendar already cached keeps its
+    /// color, visibility and default reminder; only Google's fields update.
     pub fn store_calendar(&self, calendar: &Calendar) -> Result<()> {
         let now = Utc::now().timestamp_millis();
         let access_role = match calendar.access_role {
@@ -88,9 +231,15 @@ impl CalendarCache {
 
         self.conn.execute(
             r#"
-            INSERT OR REPLACE INTO calendars
-            (id, summary, description, time_zone, background_color, foreground_color, is_primary, access_role, cached_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            INSERT INTO calendars
+            (id, summary, description, time_zone, background_color, foreground_color, is_primary, access_role, cached_at, color, visible, default_reminder_minutes, default_reminders_json)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+            ON CONFLICT(id) DO UPDATE SET
+                summary = excluded.summary, description = excluded.description,
+                time_zone = excluded.time_zone, background_color = excluded.background_color,
+                foreground_color = excluded.foreground_color, is_primary = excluded.is_primary,
+                access_role = excluded.access_role, cached_at = excluded.cached_at,
+                default_reminders_json = excluded.default_reminders_json
             "#,
             params![
                 calendar.id,
@@ -102,15 +251,21 @@ impl CalendarCache {
                 calendar.is_primary as i32,
                 access_role,
                 now,
+                calendar.color,
+                calendar.visible as i32,
+                calendar.default_reminder_minutes,
+                serde_json::to_string(&calendar.default_reminders)?,
             ],
         )?;
         Ok(())
     }
 
-    /// List all calendars from cache.
+    /// List all calendars from cache, the primary calendar first.
     pub fn list_calendars(&self) -> Result<Vec<Calendar>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, summary, description, time_zone, background_color, foreground_color, is_primary, access_role FROM calendars"
+            "SELECT id, summary, description, time_zone, background_color, foreground_color, is_primary, access_role,
+                    color, visible, default_reminder_minutes, default_reminders_json
+             FROM calendars ORDER BY is_primary DESC, summary COLLATE NOCASE"
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -129,6 +284,11 @@ impl CalendarCache {
                     "freeBusyReader" => AccessRole::FreeBusyReader,
                     _ => AccessRole::Reader,
                 },
+                color: row.get(8)?,
+                visible: row.get::<_, i32>(9)? != 0,
+                default_reminder_minutes: row.get(10)?,
+                default_reminders: serde_json::from_str(&row.get::<_, String>(11)?)
+                    .unwrap_or_default(),
             })
         })?;
 
@@ -136,10 +296,71 @@ impl CalendarCache {
             .map_err(|e| anyhow::anyhow!("Failed to read calendars: {}", e))
     }
 
+    /// Show or hide a calendar's events. Returns false if the calendar isn't cached.
+    pub fn set_calendar_visible(&self, calendar_id: &str, visible: bool) -> Result<bool> {
+        let changed = self.conn.execute(
+            "UPDATE calendars SET visible = ?2 WHERE id = ?1",
+            params![calendar_id, visible as i32],
+        )?;
+        Ok(changed > 0)
+    }
+
+    /// Override a calendar's color; None goes back to Google's color.
+    pub fn set_calendar_color(&self, calendar_id: &str, color: Option<&str>) -> Result<bool> {
+        let changed = self.conn.execute(
+            "UPDATE calendars SET color = ?2 WHERE id = ?1",
+            params![calendar_id, color],
+        )?;
+        Ok(changed > 0)
+    }
+
+    /// Set a calendar's default reminder in minutes before events, or None to
+    /// follow Google's defaults.
+    pub fn set_default_reminder(&self, calendar_id: &str, minutes: Option<u32>) -> Result<bool> {
+        let changed = self.conn.execute(
+            "UPDATE calendars SET default_reminder_minutes = ?2 WHERE id = ?1",
+            params![calendar_id, minutes],
+        )?;
+        Ok(changed > 0)
+    }
+
+    /// Event calendar IDs (see [`Calendar::event_calendar_id`]) of calendars
+    /// the user has hidden.
+    pub fn hidden_calendar_ids(&self) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT CASE WHEN is_primary != 0 THEN 'primary' ELSE id END
+             FROM calendars WHERE visible = 0",
+        )?;
+        let rows = stmt.query_map([], |row| row.get(0))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to read calendars: {}", e))
+    }
+
+    /// Events from every calendar not hidden, as [`Self::list_events`] reads them.
+    pub fn list_visible_events(
+        &self,
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+        zone: &DisplayZone,
+    ) -> Result<Vec<Event>> {
+        let hidden = self.hidden_calendar_ids()?;
+        let mut stmt = self.conn.prepare("SELECT DISTINCT calendar_id FROM events")?;
+        let calendar_ids =
+            stmt.query_map([], |row| row.get::<_, String>(0))?.collect::<Result<Vec<_>, _>>()?;
+
+        let mut events = Vec::new();
+        for calendar_id in calendar_ids.iter().filter(|id| !hidden.contains(id)) {
+            events.extend(self.list_events(calendar_id, time_min, time_max, zone)?);
+        }
+        events.sort_by_key(|e| e.start.instant_in(zone));
+        Ok(events)
+    }
+
     /// Store an event in the cache.
     pub fn store_event(&self, event: &Event) -> Result<()> {
         let now = Utc::now().timestamp_millis();
         let attendees_json = serde_json::to_string(&event.attendees)?;
+        let reminders_json = event.reminders.as_ref().map(serde_json::to_string).transpose()?;
         let status = match event.status {
             EventStatus::Confirmed => "confirmed",
             EventStatus::Tentative => "tentative",
@@ -149,8 +370,8 @@ impl CalendarCache {
         self.conn.execute(
             r#"
             INSERT OR REPLACE INTO events
-            (id, calendar_id, summary, description, location, start_ms, end_ms, all_day, attendees_json, organizer, status, html_link, etag, cached_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+            (id, calendar_id, summary, description, location, start_ms, end_ms, all_day, attendees_json, organizer, status, html_link, etag, cached_at, conference_url, time_zone, floating, reminders_json)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)
             "#,
             params![
                 event.id,
@@ -167,6 +388,10 @@ impl CalendarCache {
                 event.html_link,
                 event.etag,
                 now,
+                event.conference_url,
+                event.time_zone,
+                event.start.is_floating() as i32,
+                reminders_json,
             ],
         )?;
         Ok(())
@@ -175,7 +400,7 @@ impl CalendarCache {
     /// Get an event from the cache.
     pub fn get_event(&self, calendar_id: &str, event_id: &str) -> Result<Option<Event>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, calendar_id, summary, description, location, start_ms, end_ms, all_day, attendees_json, organizer, status, html_link, etag FROM events WHERE id = ?1 AND calendar_id = ?2"
+            "SELECT id, calendar_id, summary, description, location, start_ms, end_ms, all_day, attendees_json, organizer, status, html_link, etag, conference_url, time_zone, floating, reminders_json FROM events WHERE id = ?1 AND calendar_id = ?2"
         )?;
 
         let mut rows = stmt.query(params![event_id, calendar_id])?;
@@ -186,43 +411,86 @@ impl CalendarCache {
         }
     }
 
-    /// List events in a time range.
+    /// List events starting in a time range, as seen from `zone`: all-day
+    /// and floating events match by their wall-clock time there. Ordered by
+    /// start in `zone`.
     pub fn list_events(
         &self,
         calendar_id: &str,
         time_min: DateTime<Utc>,
         time_max: DateTime<Utc>,
+        zone: &DisplayZone,
     ) -> Result<Vec<Event>> {
         let mut stmt = self.conn.prepare(
             r#"
-            SELECT id, calendar_id, summary, description, location, start_ms, end_ms, all_day, attendees_json, organizer, status, html_link, etag
+            SELECT id, calendar_id, summary, description, location, start_ms, end_ms, all_day, attendees_json, organizer, status, html_link, etag, conference_url, time_zone, floating, reminders_json
             FROM events
-            WHERE calendar_id = ?1 AND start_ms >= ?2 AND start_ms < ?3
-            ORDER BY start_ms ASC
+            WHERE calendar_id = ?1
+              AND ((floating = 0 AND start_ms >= ?2 AND start_ms < ?3)
+                OR (floating != 0 AND start_ms >= ?4 AND start_ms < ?5))
             "#
         )?;
 
+        let wall_ms = |t: DateTime<Utc>| zone.local_datetime(t).and_utc().timestamp_millis();
         let rows = stmt.query_map(
-            params![calendar_id, time_min.timestamp_millis(), time_max.timestamp_millis()],
+            params![
+                calendar_id,
+                time_min.timestamp_millis(),
+                time_max.timestamp_millis(),
+                wall_ms(time_min),
+                wall_ms(time_max),
+            ],
             Self::row_to_event,
         )?;
 
+        let mut events = rows
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to read events: {}", e))?;
+        events.sort_by_key(|e| e.start.instant_in(zone));
+        Ok(events)
+    }
+
+    /// Events in a time range, on any calendar, whose summary, description,
+    /// location or attendees contain every word of `query` (case-insensitive).
+    pub fn search_events(
+        &self,
+        query: &str,
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+    ) -> Result<Vec<Event>> {
+        let words: Vec<String> =
+            query.split_whitespace().map(|w| format!("%{}%", w.to_lowercase())).collect();
+        if words.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // One LIKE per word; the trigram index serves words of 3+ characters
+        let conditions: Vec<String> =
+            (0..words.len()).map(|i| format!("s.content LIKE ?{}", i + 1)).collect();
+        let sql = format!(
+            r#"
+            SELECT e.id, e.calendar_id, e.summary, e.description, e.location, e.start_ms, e.end_ms, e.all_day, e.attendees_json, e.organizer, e.status, e.html_link, e.etag, e.conference_url, e.time_zone, e.floating, e.reminders_json
+            FROM events_search s
+            JOIN events e ON e.id = s.event_id AND e.calendar_id = s.calendar_id
+            WHERE e.start_ms >= {} AND e.start_ms < {} AND {}
+            ORDER BY e.start_ms ASC
+            "#,
+            time_min.timestamp_millis(),
+            time_max.timestamp_millis(),
+            conditions.join(" AND ")
+        );
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(rusqlite::params_from_iter(&words), Self::row_to_event)?;
+
         rows.collect::<Result<Vec<_>, _>>()
-            .map_err(|e| anyhow::anyhow!("Failed to read events: {}", e))
+            .map_err(|e| anyhow::anyhow!("Failed to search events: {}", e))
     }
 
-    /// Get events for today.
-    pub fn get_today_events(&self, calendar_id: &str) -> Result<Vec<Event>> {
-        let today = Utc::now().date_naive();
-        let start = match today.and_hms_opt(0, 0, 0) {
-            Some(t) => t.and_utc(),
-            None => return Ok(vec![]),
-        };
-        let end = match today.and_hms_opt(23, 59, 59) {
-            Some(t) => t.and_utc(),
-            None => return Ok(vec![]),
-        };
-        self.list_events(calendar_id, start, end)
+    /// Get events for today in `zone`.
+    pub fn get_today_events(&self, calendar_id: &str, zone: &DisplayZone) -> Result<Vec<Event>> {
+        let (start, end) = zone.day_range(zone.today(Utc::now()));
+        self.list_events(calendar_id, start, end, zone)
     }
 
     /// Delete an event from the cache.
@@ -234,23 +502,207 @@ impl CalendarCache {
         Ok(())
     }
 
-    /// Get upcoming events count.
-    pub fn upcoming_event_count(&self, calendar_id: &str, hours: i64) -> Result<u32> {
-        let now = Utc::now().timestamp_millis();
-        let future = (Utc::now() + chrono::Duration::hours(hours)).timestamp_millis();
+    /// Token for the next incremental sync of a calendar, if one has been stored.
+    pub fn sync_token(&self, calendar_id: &str) -> Result<Option<String>> {
+        let mut stmt = self.conn.prepare("SELECT value FROM sync_state WHERE key = ?1")?;
+        let mut rows = stmt.query(params![sync_token_key(calendar_id)])?;
+        Ok(rows.next()?.map(|row| row.get::<_, String>(0)).transpose()?)
+    }
+
+    /// Apply a sync to a calendar's cached events in one transaction and keep
+    /// its next sync token. A full sync replaces every cached event of the
+    /// calendar; an incremental one updates changed events and drops deleted ones.
+    pub fn apply_sync(&self, calendar_id: &str, sync: &EventSync) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        if sync.full {
+            tx.execute("DELETE FROM events WHERE calendar_id = ?1", params![calendar_id])?;
+        }
+        for event in &sync.events {
+            self.store_event(event)?;
+        }
+        for event_id in &sync.cancelled {
+            self.delete_event(calendar_id, event_id)?;
+        }
+        match &sync.next_sync_token {
+            Some(token) => tx.execute(
+                "INSERT OR REPLACE INTO sync_state (key, value) VALUES (?1, ?2)",
+                params![sync_token_key(calendar_id), token],
+            )?,
+            None => tx.execute(
+                "DELETE FROM sync_state WHERE key = ?1",
+                params![sync_token_key(calendar_id)],
+            )?,
+        };
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Popup alerts that fall in `(after, until]`, for events on calendars
+    /// not hidden, using each calendar's default reminders where the event
+    /// has none of its own. Ordered by fire time.
+    pub fn due_reminders(
+        &self,
+        after: DateTime<Utc>,
+        until: DateTime<Utc>,
+        zone: &DisplayZone,
+    ) -> Result<Vec<DueReminder>> {
+        let defaults: HashMap<String, Vec<Reminder>> = self
+            .list_calendars()?
+            .into_iter()
+            .map(|c| (c.event_calendar_id().to_string(), c.reminders()))
+            .collect();
+        let max_lead = chrono::Duration::minutes(i64::from(Reminder::MAX_MINUTES));
+
+        let mut due = Vec::new();
+        for event in self.list_visible_events(after, until + max_lead, zone)? {
+            let calendar_defaults =
+                defaults.get(&event.calendar_id).map(Vec::as_slice).unwrap_or_default();
+            for (minutes_before, fire_at) in event.popup_alerts(calendar_defaults, zone) {
+                if fire_at > after && fire_at <= until {
+                    due.push(DueReminder { event: event.clone(), minutes_before, fire_at });
+                }
+            }
+        }
+        due.sort_by_key(|d| d.fire_at);
+        Ok(due)
+    }
+
+    /// When reminders were last checked, so a check picks up where it left off.
+    pub fn reminders_checked_at(&self) -> Result<Option<DateTime<Utc>>> {
+        let mut stmt = self.conn.prepare("SELECT value FROM sync_state WHERE key = ?1")?;
+        let mut rows = stmt.query(params![REMINDERS_CHECKED_KEY])?;
+        let Some(row) = rows.next()? else {
+            return Ok(None);
+        };
+        let value: String = row.get(0)?;
+        Ok(DateTime::parse_from_rfc3339(&value).ok().map(|t| t.with_timezone(&Utc)))
+    }
+
+    /// Record that reminders up to `at` have been handled.
+    pub fn set_reminders_checked_at(&self, at: DateTime<Utc>) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO sync_state (key, value) VALUES (?1, ?2)",
+            params![REMINDERS_CHECKED_KEY, at.to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Cached coordinates for an event location, if it has been looked up.
+    /// Misses are forgotten after [`GEOCODE_RETRY_DAYS`] so a corrected
+    /// address gets another try.
+    pub fn geocoded(&self, location: &str) -> Result<Option<Geocode>> {
+        let retry_after =
+            (Utc::now() - chrono::Duration::days(GEOCODE_RETRY_DAYS)).timestamp_millis();
+        let mut stmt = self.conn.prepare(
+            "SELECT latitude, longitude FROM geocoded_locations
+             WHERE query = ?1 AND (latitude IS NOT NULL OR geocoded_at >= ?2)",
+        )?;
+        let mut rows = stmt.query(params![geocode_key(location), retry_after])?;
+        let Some(row) = rows.next()? else {
+            return Ok(None);
+        };
+        let coordinates: (Option<f64>, Option<f64>) = (row.get(0)?, row.get(1)?);
+        Ok(Some(match coordinates {
+            (Some(latitude), Some(longitude)) => Geocode::Found { latitude, longitude },
+            _ => Geocode::NotFound,
+        }))
+    }
+
+    /// Remember the result of looking up an event location.
+    pub fn store_geocoded(&self, location: &str, geocode: Geocode) -> Result<()> {
+        let (latitude, longitude) = match geocode {
+            Geocode::Found { latitude, longitude } => (Some(latitude), Some(longitude)),
+            Geocode::NotFound => (None, None),
+        };
+        self.conn.execute(
+            "INSERT OR REPLACE INTO geocoded_locations (query, latitude, longitude, geocoded_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![geocode_key(location), latitude, longitude, Utc::now().timestamp_millis()],
+        )?;
+        Ok(())
+    }
+
+    /// Turn leave-by warnings for one event on or off.
+    pub fn set_travel_alerts(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        enabled: bool,
+    ) -> Result<()> {
+        if enabled {
+            self.conn.execute(
+                "DELETE FROM travel_opt_outs WHERE calendar_id = ?1 AND event_id = ?2",
+                params![calendar_id, event_id],
+            )?;
+        } else {
+            self.conn.execute(
+                "INSERT OR IGNORE INTO travel_opt_outs (calendar_id, event_id) VALUES (?1, ?2)",
+                params![calendar_id, event_id],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Events opted out of leave-by warnings, as (calendar ID, event ID).
+    pub fn travel_opt_outs(&self) -> Result<HashSet<(String, String)>> {
+        let mut stmt = self.conn.prepare("SELECT calendar_id, event_id FROM travel_opt_outs")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<Result<HashSet<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to read travel opt-outs: {}", e))
+    }
 
-        let count: u32 = self.conn.query_row(
-            "SELECT COUNT(*) FROM events WHERE calendar_id = ?1 AND start_ms >= ?2 AND start_ms < ?3",
-            params![calendar_id, now, future],
-            |row| row.get(0),
+    /// Whether a leave-by warning was already shown for this start of the event.
+    pub fn travel_alert_sent(&self, event: &Event) -> Result<bool> {
+        let mut stmt = self.conn.prepare(
+            "SELECT 1 FROM travel_alerts WHERE calendar_id = ?1 AND event_id = ?2 AND start_ms = ?3",
         )?;
-        Ok(count)
+        Ok(stmt.exists(params![
+            event.calendar_id,
+            event.id,
+            event.start.as_datetime().timestamp_millis()
+        ])?)
+    }
+
+    /// Record a leave-by warning for the event's current start; a moved event
+    /// warns again. Records for events long past are dropped.
+    pub fn record_travel_alert(&self, event: &Event) -> Result<()> {
+        let now = Utc::now();
+        self.conn.execute(
+            "INSERT OR REPLACE INTO travel_alerts (calendar_id, event_id, start_ms, notified_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                event.calendar_id,
+                event.id,
+                event.start.as_datetime().timestamp_millis(),
+                now.timestamp_millis()
+            ],
+        )?;
+        self.conn.execute(
+            "DELETE FROM travel_alerts WHERE start_ms < ?1",
+            params![(now - chrono::Duration::days(1)).timestamp_millis()],
+        )?;
+        Ok(())
+    }
+
+    /// Get upcoming events count, placing floating events in `zone`.
+    pub fn upcoming_event_count(
+        &self,
+        calendar_id: &str,
+        hours: i64,
+        zone: &DisplayZone,
+    ) -> Result<u32> {
+        let now = Utc::now();
+        let events =
+            self.list_events(calendar_id, now, now + chrono::Duration::hours(hours), zone)?;
+        Ok(events.len() as u32)
     }
 
     /// Clear all cached data.
     pub fn clear(&self) -> Result<()> {
-        self.conn
-            .execute_batch("DELETE FROM events; DELETE FROM calendars; DELETE FROM sync_state;")?;
+        self.conn.execute_batch(
+            "DELETE FROM events; DELETE FROM calendars; DELETE FROM sync_state;
+             DELETE FROM travel_opt_outs; DELETE FROM travel_alerts;",
+        )?;
         Ok(())
     }
 
@@ -260,22 +712,21 @@ impl CalendarCache {
         let all_day: i32 = row.get(7)?;
         let attendees_json: String = row.get(8)?;
         let status_str: String = row.get(10)?;
+        let floating: i32 = row.get(15)?;
+        let reminders_json: Option<String> = row.get(16)?;
 
-        let start = if all_day != 0 {
-            EventTime::Date(
-                DateTime::from_timestamp_millis(start_ms).unwrap_or_default().date_naive(),
-            )
-        } else {
-            EventTime::DateTime(DateTime::from_timestamp_millis(start_ms).unwrap_or_default())
-        };
-
-        let end = if all_day != 0 {
-            EventTime::Date(
-                DateTime::from_timestamp_millis(end_ms).unwrap_or_default().date_naive(),
-            )
-        } else {
-            EventTime::DateTime(DateTime::from_timestamp_millis(end_ms).unwrap_or_default())
+        let event_time = |ms: i64| {
+            let t = DateTime::from_timestamp_millis(ms).unwrap_or_default();
+            if all_day != 0 {
+                EventTime::Date(t.date_naive())
+            } else if floating != 0 {
+                EventTime::Floating(t.naive_utc())
+            } else {
+                EventTime::DateTime(t)
+            }
         };
+        let start = event_time(start_ms);
+        let end = event_time(end_ms);
 
         let status = match status_str.as_str() {
             "tentative" => EventStatus::Tentative,
@@ -297,10 +748,42 @@ impl CalendarCache {
             status,
             html_link: row.get(11)?,
             etag: row.get(12)?,
+            conference_url: row.get(13)?,
+            time_zone: row.get(14)?,
+            reminders: reminders_json.and_then(|json| serde_json::from_str(&json).ok()),
         })
     }
 }
 
+/// Days before a location that could not be geocoded is looked up again
+pub const GEOCODE_RETRY_DAYS: i64 = 7;
+
+/// Cache key for a location: trimmed, lowercased, single-spaced.
+fn geocode_key(location: &str) -> String {
+    location.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// `sync_state` key holding when reminders were last checked.
+const REMINDERS_CHECKED_KEY: &str = "reminders_checked_at";
+
+/// `sync_state` key holding a calendar's sync token.
+fn sync_token_key(calendar_id: &str) -> String {
+    format!("sync_token:{}", calendar_id)
+}
+
+/// SQL expression for the searchable text of an event row referenced as
+/// `row` (`NEW` in triggers, the table name when backfilling).
+fn search_text_sql(row: &str) -> String {
+    format!(
+        "{row}.summary || char(10) || coalesce({row}.description, '') || char(10) \
+         || coalesce({row}.location, '') || char(10) || coalesce((\
+            SELECT group_concat(coalesce(json_extract(value, '$.display_name'), '') \
+                || ' ' || coalesce(json_extract(value, '$.email'), ''), char(10)) \
+            FROM json_each(CASE WHEN json_valid({row}.attendees_json) \
+                THEN {row}.attendees_json ELSE '[]' END)), '')"
+    )
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -324,9 +807,27 @@ mod tests {
             status: EventStatus::Confirmed,
             html_link: None,
             etag: None,
+            conference_url: None,
+            time_zone: None,
+            reminders: None,
         }
     }
 
+    #[test]
+    fn test_conference_url_roundtrip() {
+        let cache = CalendarCache::in_memory().unwrap();
+        let mut event = create_test_event("event1", "Standup", 1);
+        event.conference_url = Some("https://meet.google.com/abc-defg-hij".to_string());
+
+        cache.store_event(&event).unwrap();
+        let retrieved = cache.get_event("primary", "event1").unwrap().unwrap();
+
+        assert_eq!(
+            retrieved.conference_url.as_deref(),
+            Some("https://meet.google.com/abc-defg-hij")
+        );
+    }
+
     #[test]
     fn test_store_and_get_event() {
         let cache = CalendarCache::in_memory().unwrap();
@@ -356,7 +857,7 @@ mod tests {
 
         let now = Utc::now();
         let tomorrow = now + chrono::Duration::hours(24);
-        let events = cache.list_events("primary", now, tomorrow).unwrap();
+        let events = cache.list_events("primary", now, tomorrow, &DisplayZone::Local).unwrap();
 
         assert_eq!(events.len(), 2);
     }
@@ -381,10 +882,57 @@ mod tests {
         cache.store_event(&create_test_event("e2", "Event 2", 2)).unwrap();
         cache.store_event(&create_test_event("e3", "Event 3", 48)).unwrap();
 
-        let count = cache.upcoming_event_count("primary", 24).unwrap();
+        let count = cache.upcoming_event_count("primary", 24, &DisplayZone::Local).unwrap();
         assert_eq!(count, 2);
     }
 
+    #[test]
+    fn test_floating_and_all_day_events_follow_display_zone() {
+        let cache = CalendarCache::in_memory().unwrap();
+        let toronto = DisplayZone::parse("America/Toronto").unwrap();
+        let tokyo = DisplayZone::parse("Asia/Tokyo").unwrap();
+        let day = chrono::NaiveDate::from_ymd_opt(2026, 3, 8).unwrap();
+
+        // 09:00 wherever you are, on the Toronto spring-forward day
+        let mut floating = create_test_event("float", "Run", 0);
+        floating.start = EventTime::Floating(day.and_hms_opt(9, 0, 0).unwrap());
+        floating.end = EventTime::Floating(day.and_hms_opt(10, 0, 0).unwrap());
+        cache.store_event(&floating).unwrap();
+
+        let mut holiday = create_test_event("holiday", "Holiday", 0);
+        holiday.start = EventTime::Date(day);
+        holiday.end = EventTime::Date(day.succ_opt().unwrap());
+        holiday.all_day = true;
+        cache.store_event(&holiday).unwrap();
+
+        // 23:30 Toronto time on 2026-03-07, already 13:30 on the 8th in Tokyo
+        let mut call = create_test_event("call", "Call", 0);
+        call.start = EventTime::DateTime(
+            DateTime::parse_from_rfc3339("2026-03-08T04:30:00Z").unwrap().with_timezone(&Utc),
+        );
+        call.time_zone = Some("America/Toronto".to_string());
+        cache.store_event(&call).unwrap();
+
+        let ids = |zone: &DisplayZone| -> Vec<String> {
+            let (start, end) = zone.day_range(day);
+            cache
+                .list_events("primary", start, end, zone)
+                .unwrap()
+                .into_iter()
+                .map(|e| e.id)
+                .collect()
+        };
+        assert_eq!(ids(&toronto), ["holiday", "float"]);
+        assert_eq!(ids(&tokyo), ["holiday", "float", "call"]);
+
+        let retrieved = cache.get_event("primary", "float").unwrap().unwrap();
+        assert_eq!(retrieved.start, floating.start);
+        assert_eq!(retrieved.end, floating.end);
+        let retrieved = cache.get_event("primary", "call").unwrap().unwrap();
+        assert_eq!(retrieved.time_zone.as_deref(), Some("America/Toronto"));
+        assert_eq!(cache.get_event("primary", "holiday").unwrap().unwrap().start, holiday.start);
+    }
+
     #[test]
     fn test_store_and_list_calendars() {
         let cache = CalendarCache::in_memory().unwrap();
@@ -398,23 +946,226 @@ mod tests {
             foreground_color: None,
             is_primary: true,
             access_role: AccessRole::Owner,
+            color: None,
+            visible: true,
+            default_reminder_minutes: Some(10),
+            default_reminders: Vec::new(),
         };
         let cal2 = Calendar {
             id: "work".to_string(),
             summary: "Work".to_string(),
             description: None,
             time_zone: None,
-            background_color: None,
+            background_color: Some("#9fe1e7".to_string()),
             foreground_color: None,
             is_primary: false,
             access_role: AccessRole::Writer,
+            color: None,
+            visible: true,
+            default_reminder_minutes: None,
+            default_reminders: Vec::new(),
         };
 
-        cache.store_calendar(&cal1).unwrap();
         cache.store_calendar(&cal2).unwrap();
+        cache.store_calendar(&cal1).unwrap();
 
         let calendars = cache.list_calendars().unwrap();
         assert_eq!(calendars.len(), 2);
+        assert_eq!(calendars[0].id, "primary");
+        assert_eq!(calendars[0].default_reminder_minutes, Some(10));
+    }
+
+    #[test]
+    fn test_calendar_preferences_survive_refresh_and_hide_events() {
+        let cache = CalendarCache::in_memory().unwrap();
+        let zone = DisplayZone::Local;
+        let team = Calendar {
+            id: "team".to_string(),
+            summary: "Team".to_string(),
+            description: None,
+            time_zone: None,
+            background_color: Some("#9fe1e7".to_string()),
+            foreground_color: None,
+            is_primary: false,
+            access_role: AccessRole::Reader,
+            color: None,
+            visible: true,
+            default_reminder_minutes: None,
+            default_reminders: Vec::new(),
+        };
+        cache.store_calendar(&team).unwrap();
+        cache.store_event(&create_test_event("mine", "Mine", 1)).unwrap();
+        let mut shared = create_test_event("shared", "Shared", 2);
+        shared.calendar_id = "team".to_string();
+        cache.store_event(&shared).unwrap();
+
+        assert!(cache.set_calendar_visible("team", false).unwrap());
+        assert!(cache.set_calendar_color("team", Some("#ff0000")).unwrap());
+        assert!(cache.set_default_reminder("team", Some(15)).unwrap());
+        assert!(!cache.set_calendar_visible("missing", false).unwrap());
+
+        // A refresh from Google keeps what was chosen locally
+        cache.store_calendar(&Calendar { summary: "Team (renamed)".to_string(), ..team }).unwrap();
+        let stored = cache.list_calendars().unwrap().remove(0);
+        assert_eq!(stored.summary, "Team (renamed)");
+        assert!(!stored.visible);
+        assert_eq!(stored.display_color(), Some("#ff0000"));
+        assert_eq!(stored.default_reminder_minutes, Some(15));
+        assert_eq!(cache.hidden_calendar_ids().unwrap(), ["team"]);
+
+        let (from, to) = (Utc::now(), Utc::now() + chrono::Duration::days(1));
+        let ids = |cache: &CalendarCache| -> Vec<String> {
+            cache.list_visible_events(from, to, &zone).unwrap().into_iter().map(|e| e.id).collect()
+        };
+        assert_eq!(ids(&cache), ["mine"]);
+        cache.set_calendar_visible("team", true).unwrap();
+        assert_eq!(ids(&cache), ["mine", "shared"]);
+    }
+
+    #[test]
+    fn test_due_reminders_use_overrides_and_calendar_defaults() {
+        let cache = CalendarCache::in_memory().unwrap();
+        let zone = DisplayZone::Local;
+        // Whole seconds, as event times are stored in milliseconds
+        let now = DateTime::from_timestamp(Utc::now().timestamp(), 0).unwrap();
+        let minutes = |m: i64| now + chrono::Duration::minutes(m);
+        let primary = Calendar {
+            id: "me@example.com".to_string(),
+            summary: "Me".to_string(),
+            description: None,
+            time_zone: None,
+            background_color: None,
+            foreground_color: None,
+            is_primary: true,
+            access_role: AccessRole::Owner,
+            color: None,
+            visible: true,
+            default_reminder_minutes: None,
+            default_reminders: vec![Reminder::popup(10), Reminder::popup(60)],
+        };
+        cache.store_calendar(&primary).unwrap();
+        cache
+            .store_calendar(&Calendar {
+                id: "team".to_string(),
+                is_primary: false,
+                visible: false,
+                ..primary.clone()
+            })
+            .unwrap();
+
+        let at = |id: &str, calendar_id: &str, start: i64, reminders: Option<Vec<Reminder>>| {
+            let mut event = create_test_event(id, id, 0);
+            event.calendar_id = calendar_id.to_string();
+            event.start = EventTime::DateTime(minutes(start));
+            event.end = EventTime::DateTime(minutes(start + 30));
+            event.reminders = reminders;
+            cache.store_event(&event).unwrap();
+        };
+        at("standup", "primary", 30, None);
+        at("focus", "primary", 30, Some(Vec::new()));
+        at("review", "primary", 28, Some(vec![Reminder::popup(5)]));
+        at("shared", "team", 30, None);
+
+        let due = cache.due_reminders(now, minutes(25), &zone).unwrap();
+        let summary: Vec<(&str, u32)> =
+            due.iter().map(|d| (d.event.id.as_str(), d.minutes_before)).collect();
+        assert_eq!(summary, [("standup", 10), ("review", 5)]);
+        assert_eq!(due[0].fire_at, minutes(20));
+        assert_eq!(
+            cache.get_event("primary", "focus").unwrap().unwrap().reminders,
+            Some(Vec::new())
+        );
+
+        assert_eq!(cache.reminders_checked_at().unwrap(), None);
+        cache.set_reminders_checked_at(now).unwrap();
+        assert_eq!(cache.reminders_checked_at().unwrap(), Some(now));
+    }
+
+    #[test]
+    fn test_geocode_cache_and_travel_alert_bookkeeping() {
+        let cache = CalendarCache::in_memory().unwrap();
+        let found = Geocode::Found { latitude: 43.6534, longitude: -79.3841 };
+
+        assert_eq!(cache.geocoded("100 Queen St W").unwrap(), None);
+        cache.store_geocoded("100 Queen St W", found).unwrap();
+        cache.store_geocoded("Room 4", Geocode::NotFound).unwrap();
+        assert_eq!(cache.geocoded("  100  queen st w ").unwrap(), Some(found));
+        assert_eq!(cache.geocoded("Room 4").unwrap(), Some(Geocode::NotFound));
+
+        // Misses expire; a stale one is looked up again
+        let stale =
+            (Utc::now() - chrono::Duration::days(GEOCODE_RETRY_DAYS + 1)).timestamp_millis();
+        cache
+            .conn
+            .execute("UPDATE geocoded_locations SET geocoded_at = ?1", params![stale])
+            .unwrap();
+        assert_eq!(cache.geocoded("Room 4").unwrap(), None);
+        assert_eq!(cache.geocoded("100 Queen St W").unwrap(), Some(found));
+
+        cache.set_travel_alerts("primary", "offsite", false).unwrap();
+        cache.set_travel_alerts("primary", "offsite", false).unwrap();
+        assert!(cache
+            .travel_opt_outs()
+            .unwrap()
+            .contains(&("primary".to_string(), "offsite".to_string())));
+        cache.set_travel_alerts("primary", "offsite", true).unwrap();
+        assert!(cache.travel_opt_outs().unwrap().is_empty());
+
+        let mut event = create_test_event("offsite", "Offsite", 2);
+        assert!(!cache.travel_alert_sent(&event).unwrap());
+        cache.record_travel_alert(&event).unwrap();
+        assert!(cache.travel_alert_sent(&event).unwrap());
+        // Moved to another time: warn again
+        event.start = EventTime::DateTime(Utc::now() + chrono::Duration::hours(3));
+        assert!(!cache.travel_alert_sent(&event).unwrap());
+    }
+
+    #[test]
+    fn test_apply_incremental_and_full_sync() {
+        let cache = CalendarCache::in_memory().unwrap();
+        let zone = DisplayZone::Local;
+        let (from, to) = (Utc::now(), Utc::now() + chrono::Duration::days(1));
+        let ids = |cache: &CalendarCache| -> Vec<String> {
+            let mut ids: Vec<String> = cache
+                .list_events("primary", from, to, &zone)
+                .unwrap()
+                .into_iter()
+                .map(|e| e.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        let full = EventSync {
+            events: vec![create_test_event("a", "A", 1), create_test_event("b", "B", 2)],
+            next_sync_token: Some("t1".to_string()),
+            full: true,
+            ..EventSync::default()
+        };
+        cache.apply_sync("primary", &full).unwrap();
+        assert_eq!(ids(&cache), ["a", "b"]);
+        assert_eq!(cache.sync_token("primary").unwrap().as_deref(), Some("t1"));
+        assert!(cache.sync_token("other").unwrap().is_none());
+
+        let changes = EventSync {
+            events: vec![create_test_event("c", "C", 3)],
+            cancelled: vec!["a".to_string()],
+            next_sync_token: Some("t2".to_string()),
+            full: false,
+        };
+        cache.apply_sync("primary", &changes).unwrap();
+        assert_eq!(ids(&cache), ["b", "c"]);
+        assert_eq!(cache.sync_token("primary").unwrap().as_deref(), Some("t2"));
+
+        // A full resync drops events that no longer exist
+        let resync = EventSync {
+            events: vec![create_test_event("c", "C", 3)],
+            next_sync_token: Some("t3".to_string()),
+            full: true,
+            ..EventSync::default()
+        };
+        cache.apply_sync("primary", &resync).unwrap();
+        assert_eq!(ids(&cache), ["c"]);
     }
 
     #[test]
@@ -426,4 +1177,66 @@ mod tests {
 
         assert!(cache.get_event("primary", "e1").unwrap().is_none());
     }
+
+    #[test]
+    fn test_search_events() {
+        let cache = CalendarCache::in_memory().unwrap();
+
+        let mut dentist = create_test_event("e1", "Dentist appointment", 72);
+        dentist.description = Some("Cleaning with Dr. Molar".to_string());
+        dentist.location = Some("Smile Dental, 12 Main St".to_string());
+        cache.store_event(&dentist).unwrap();
+
+        let mut review = create_test_event("e2", "Quarterly review", 2);
+        review.calendar_id = "work".to_string();
+        review.attendees = vec![crate::types::Attendee {
+            email: "dana@example.com".to_string(),
+            display_name: Some("Dana Scully".to_string()),
+            response_status: crate::types::ResponseStatus::Accepted,
+            is_organizer: false,
+            is_self: false,
+        }];
+        cache.store_event(&review).unwrap();
+
+        let now = Utc::now();
+        let month = now + chrono::Duration::days(30);
+        let ids = |query: &str| -> Vec<String> {
+            cache.search_events(query, now, month).unwrap().into_iter().map(|e| e.id).collect()
+        };
+
+        assert_eq!(ids("DENTIST"), ["e1"]);
+        assert_eq!(ids("molar"), ["e1"]);
+        assert_eq!(ids("main st"), ["e1"]);
+        assert_eq!(ids("scully"), ["e2"]);
+        assert_eq!(ids("dana@example"), ["e2"]);
+        assert_eq!(ids("dentist review"), Vec::<String>::new());
+        assert_eq!(ids("   "), Vec::<String>::new());
+        // Short words fall back to a scan but still match
+        assert_eq!(ids("dr"), ["e1"]);
+        // Results are ordered by start time
+        assert_eq!(ids("e"), ["e2", "e1"]);
+
+        // Range is respected
+        let tomorrow = now + chrono::Duration::days(1);
+        assert!(cache.search_events("dentist", now, tomorrow).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_index_follows_updates_and_deletes() {
+        let cache = CalendarCache::in_memory().unwrap();
+        let now = Utc::now();
+        let week = now + chrono::Duration::days(7);
+
+        let mut event = create_test_event("e1", "Dentist", 1);
+        cache.store_event(&event).unwrap();
+        event.summary = "Orthodontist".to_string();
+        cache.store_event(&event).unwrap();
+
+        assert_eq!(cache.search_events("orthodontist", now, week).unwrap().len(), 1);
+        // The replaced row's text is no longer indexed
+        assert!(cache.search_events("dentist", now, week).unwrap().is_empty());
+
+        cache.delete_event("primary", "e1").unwrap();
+        assert!(cache.search_events("orthodontist", now, week).unwrap().is_empty());
+    }
 }
@@ -1,6 +1,10 @@
 //! Google Calendar API client.
 
+use std::time::Duration;
+
 use chrono::{DateTime, Utc};
+use myme_core::http_log::HttpObserver;
+use myme_services::retry::{send_observed, RetryConfig, DEFAULT_TIMEOUT_SECS};
 use tracing::instrument;
 
 use crate::error::CalendarError;
@@ -12,6 +16,8 @@ pub struct CalendarClient {
     client: reqwest::Client,
     access_token: String,
     base_url: String,
+    observer: Option<HttpObserver>,
+    retry_config: RetryConfig,
 }
 
 impl CalendarClient {
@@ -20,6 +26,37 @@ impl CalendarClient {
             client: reqwest::Client::new(),
             access_token: access_token.to_string(),
             base_url: CALENDAR_API_BASE.to_string(),
+            observer: None,
+            retry_config: RetryConfig::none(),
+        }
+    }
+
+    /// Create a client that sends requests through `proxies`.
+    pub fn new_with_proxies(access_token: &str, proxies: Vec<reqwest::Proxy>) -> Self {
+        Self::new_with_timeout(access_token, proxies, Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+    }
+
+    /// Like [`Self::new_with_proxies`], abandoning requests after `timeout`.
+    pub fn new_with_timeout(
+        access_token: &str,
+        proxies: Vec<reqwest::Proxy>,
+        timeout: Duration,
+    ) -> Self {
+        let client = proxies
+            .into_iter()
+            .fold(reqwest::Client::builder(), reqwest::ClientBuilder::proxy)
+            .timeout(timeout)
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to apply proxy settings: {}", e);
+                reqwest::Client::new()
+            });
+        Self {
+            client,
+            access_token: access_token.to_string(),
+            base_url: CALENDAR_API_BASE.to_string(),
+            observer: None,
+            retry_config: RetryConfig::none(),
         }
     }
 
@@ -29,9 +66,31 @@ impl CalendarClient {
             client: reqwest::Client::new(),
             access_token: access_token.to_string(),
             base_url: base_url.to_string(),
+            observer: None,
+            retry_config: RetryConfig::none(),
         }
     }
 
+    /// Retry idempotent requests that time out or get a 5xx/429 response.
+    /// Off by default.
+    pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
+        self.retry_config = config;
+        self
+    }
+
+    /// Report every request to `observer` (e.g. the HTTP debug capture).
+    pub fn with_observer(mut self, observer: Option<HttpObserver>) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    /// Send a request, retrying transient failures of idempotent ones and
+    /// reporting each attempt to the observer if one is set.
+    async fn send(&self, request: reqwest::RequestBuilder) -> reqwest::Result<reqwest::Response> {
+        send_observed(&self.client, request.build()?, &self.retry_config, self.observer.as_ref())
+            .await
+    }
+
     fn auth_header(&self) -> String {
         format!("Bearer {}", self.access_token)
     }
@@ -42,7 +101,7 @@ impl CalendarClient {
         let url = format!("{}/users/me/calendarList", self.base_url);
 
         let response =
-            self.client.get(&url).header("Authorization", self.auth_header()).send().await?;
+            self.send(self.client.get(&url).header("Authorization", self.auth_header())).await?;
 
         let resp: CalendarListResponse = self.handle_response(response).await?;
         Ok(resp.items.into_iter().map(Calendar::from).collect())
@@ -70,11 +129,80 @@ impl CalendarClient {
         }
 
         let response =
-            self.client.get(&url).header("Authorization", self.auth_header()).send().await?;
+            self.send(self.client.get(&url).header("Authorization", self.auth_header())).await?;
 
         self.handle_response(response).await
     }
 
+    /// Fetch what changed in a calendar since `sync_token`, following every
+    /// page. Without a token, or when Google has expired it (410 Gone), this
+    /// falls back to a full sync of events ending after `full_sync_from` and
+    /// the result is marked `full`.
+    #[instrument(skip(self, sync_token), level = "info")]
+    pub async fn sync_events(
+        &self,
+        calendar_id: &str,
+        sync_token: Option<&str>,
+        full_sync_from: DateTime<Utc>,
+    ) -> Result<EventSync, CalendarError> {
+        if let Some(token) = sync_token {
+            match self.sync_pages(calendar_id, Some(token), full_sync_from).await {
+                Err(CalendarError::SyncTokenExpired) => {
+                    tracing::info!("Sync token for {} expired; doing a full sync", calendar_id);
+                }
+                result => return result,
+            }
+        }
+        self.sync_pages(calendar_id, None, full_sync_from).await
+    }
+
+    async fn sync_pages(
+        &self,
+        calendar_id: &str,
+        sync_token: Option<&str>,
+        full_sync_from: DateTime<Utc>,
+    ) -> Result<EventSync, CalendarError> {
+        let mut sync = EventSync { full: sync_token.is_none(), ..EventSync::default() };
+        let mut page_token: Option<String> = None;
+        loop {
+            // Google rejects time bounds and ordering alongside a sync token
+            let mut url = format!(
+                "{}/calendars/{}/events?singleEvents=true&maxResults=250",
+                self.base_url,
+                urlencoding::encode(calendar_id),
+            );
+            match sync_token {
+                Some(token) => url.push_str(&format!("&syncToken={}", urlencoding::encode(token))),
+                None => url.push_str(&format!(
+                    "&timeMin={}",
+                    urlencoding::encode(&full_sync_from.to_rfc3339())
+                )),
+            }
+            if let Some(pt) = &page_token {
+                url.push_str(&format!("&pageToken={}", urlencoding::encode(pt)));
+            }
+
+            let response = self
+                .send(self.client.get(&url).header("Authorization", self.auth_header()))
+                .await?;
+            let page: EventListResponse = self.handle_response(response).await?;
+            for api_event in page.items {
+                if api_event.status.as_deref() == Some("cancelled") {
+                    sync.cancelled.push(api_event.id);
+                } else {
+                    sync.events.push(Event::from_api(api_event, calendar_id));
+                }
+            }
+            match page.next_page_token {
+                Some(next) => page_token = Some(next),
+                None => {
+                    sync.next_sync_token = page.next_sync_token;
+                    return Ok(sync);
+                }
+            }
+        }
+    }
+
     /// Get a single event.
     #[instrument(skip(self), level = "info")]
     pub async fn get_event(
@@ -90,13 +218,14 @@ impl CalendarClient {
         );
 
         let response =
-            self.client.get(&url).header("Authorization", self.auth_header()).send().await?;
+            self.send(self.client.get(&url).header("Authorization", self.auth_header())).await?;
 
         let api_event: ApiEvent = self.handle_response(response).await?;
         Ok(Event::from_api(api_event, calendar_id))
     }
 
-    /// Create a new event.
+    /// Create a new event, inviting `attendees` (email addresses) if any.
+    #[allow(clippy::too_many_arguments)]
     #[instrument(skip(self), level = "info")]
     pub async fn create_event(
         &self,
@@ -106,6 +235,7 @@ impl CalendarClient {
         end: DateTime<Utc>,
         description: Option<&str>,
         location: Option<&str>,
+        attendees: &[String],
     ) -> Result<Event, CalendarError> {
         let url =
             format!("{}/calendars/{}/events", self.base_url, urlencoding::encode(calendar_id),);
@@ -122,20 +252,22 @@ impl CalendarClient {
         if let Some(loc) = location {
             body["location"] = serde_json::Value::String(loc.to_string());
         }
+        if !attendees.is_empty() {
+            body["attendees"] =
+                attendees.iter().map(|email| serde_json::json!({ "email": email })).collect();
+        }
 
         let response = self
-            .client
-            .post(&url)
-            .header("Authorization", self.auth_header())
-            .json(&body)
-            .send()
+            .send(self.client.post(&url).header("Authorization", self.auth_header()).json(&body))
             .await?;
 
         let api_event: ApiEvent = self.handle_response(response).await?;
         Ok(Event::from_api(api_event, calendar_id))
     }
 
-    /// Update an existing event.
+    /// Update an existing event. `reminders` of `Some(None)` goes back to the
+    /// calendar's default reminders; `Some(Some(list))` replaces them (an
+    /// empty list turns reminders off).
     #[allow(clippy::too_many_arguments)]
     #[instrument(skip(self), level = "info")]
     pub async fn update_event(
@@ -147,6 +279,7 @@ impl CalendarClient {
         end: Option<DateTime<Utc>>,
         description: Option<&str>,
         location: Option<&str>,
+        reminders: Option<Option<&[Reminder]>>,
     ) -> Result<Event, CalendarError> {
         let url = format!(
             "{}/calendars/{}/events/{}",
@@ -172,13 +305,19 @@ impl CalendarClient {
         if let Some(l) = location {
             body.insert("location".to_string(), serde_json::Value::String(l.to_string()));
         }
+        if let Some(r) = reminders {
+            let value = match r {
+                None => serde_json::json!({ "useDefault": true }),
+                Some(list) => serde_json::json!({
+                    "useDefault": false,
+                    "overrides": list.iter().map(Reminder::to_api_json).collect::<Vec<_>>(),
+                }),
+            };
+            body.insert("reminders".to_string(), value);
+        }
 
         let response = self
-            .client
-            .patch(&url)
-            .header("Authorization", self.auth_header())
-            .json(&body)
-            .send()
+            .send(self.client.patch(&url).header("Authorization", self.auth_header()).json(&body))
             .await?;
 
         let api_event: ApiEvent = self.handle_response(response).await?;
@@ -200,7 +339,7 @@ impl CalendarClient {
         );
 
         let response =
-            self.client.delete(&url).header("Authorization", self.auth_header()).send().await?;
+            self.send(self.client.delete(&url).header("Authorization", self.auth_header())).await?;
 
         // Delete returns 204 No Content on success
         if response.status().is_success() {
@@ -223,7 +362,7 @@ impl CalendarClient {
         );
 
         let response =
-            self.client.post(&url).header("Authorization", self.auth_header()).send().await?;
+            self.send(self.client.post(&url).header("Authorization", self.auth_header())).await?;
 
         let api_event: ApiEvent = self.handle_response(response).await?;
         Ok(Event::from_api(api_event, calendar_id))
@@ -250,6 +389,8 @@ impl CalendarClient {
             Err(CalendarError::EventNotFound(text))
         } else if status.as_u16() == 409 {
             Err(CalendarError::Conflict)
+        } else if status.as_u16() == 410 {
+            Err(CalendarError::SyncTokenExpired)
         } else if status.as_u16() == 429 {
             let retry_after = response
                 .headers()
@@ -269,7 +410,7 @@ impl CalendarClient {
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
     use super::*;
-    use wiremock::matchers::{header, method, path};
+    use wiremock::matchers::{header, method, path, query_param, query_param_is_missing};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[tokio::test]
@@ -326,6 +467,80 @@ mod tests {
         assert_eq!(response.items[0].summary, Some("Meeting".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_sync_events_pages_and_falls_back_on_gone() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/calendars/primary/events"))
+            .and(query_param("syncToken", "stale"))
+            .respond_with(ResponseTemplate::new(410))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/calendars/primary/events"))
+            .and(query_param("pageToken", "p2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [{
+                    "id": "event2",
+                    "summary": "Standup",
+                    "start": {"dateTime": "2024-02-02T09:00:00Z"},
+                    "end": {"dateTime": "2024-02-02T09:15:00Z"}
+                }],
+                "nextSyncToken": "fresh"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/calendars/primary/events"))
+            .and(query_param_is_missing("syncToken"))
+            .and(query_param_is_missing("pageToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [{
+                    "id": "event1",
+                    "summary": "Meeting",
+                    "start": {"dateTime": "2024-02-01T10:00:00Z"},
+                    "end": {"dateTime": "2024-02-01T11:00:00Z"}
+                }],
+                "nextPageToken": "p2"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/calendars/primary/events"))
+            .and(query_param("syncToken", "fresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [
+                    {"id": "event1", "status": "cancelled"},
+                    {
+                        "id": "event2",
+                        "summary": "Standup (moved)",
+                        "start": {"dateTime": "2024-02-02T10:00:00Z"},
+                        "end": {"dateTime": "2024-02-02T10:15:00Z"}
+                    }
+                ],
+                "nextSyncToken": "fresher"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = CalendarClient::new_with_base_url("test_token", &mock_server.uri());
+        let from =
+            DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+
+        let sync = client.sync_events("primary", Some("stale"), from).await.unwrap();
+        assert!(sync.full);
+        let ids: Vec<&str> = sync.events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["event1", "event2"]);
+        assert_eq!(sync.next_sync_token.as_deref(), Some("fresh"));
+
+        let sync = client.sync_events("primary", Some("fresh"), from).await.unwrap();
+        assert!(!sync.full);
+        assert_eq!(sync.cancelled, ["event1"]);
+        assert_eq!(sync.events[0].summary, "Standup (moved)");
+        assert_eq!(sync.next_sync_token.as_deref(), Some("fresher"));
+    }
+
     #[tokio::test]
     async fn test_get_event() {
         let mock_server = MockServer::start().await;
@@ -349,6 +564,92 @@ mod tests {
         assert_eq!(event.summary, "Team Sync");
     }
 
+    #[tokio::test]
+    async fn test_create_event_with_attendees() {
+        use wiremock::matchers::body_partial_json;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/calendars/primary/events"))
+            .and(body_partial_json(serde_json::json!({
+                "attendees": [{"email": "ada@example.com"}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "new1",
+                "summary": "Review",
+                "start": {"dateTime": "2024-02-01T14:00:00Z"},
+                "end": {"dateTime": "2024-02-01T15:00:00Z"},
+                "attendees": [{"email": "ada@example.com", "responseStatus": "needsAction"}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = CalendarClient::new_with_base_url("test_token", &mock_server.uri());
+        let start =
+            DateTime::parse_from_rfc3339("2024-02-01T14:00:00Z").unwrap().with_timezone(&Utc);
+        let event = client
+            .create_event(
+                "primary",
+                "Review",
+                start,
+                start + chrono::Duration::hours(1),
+                None,
+                None,
+                &["ada@example.com".to_string()],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(event.attendees.len(), 1);
+        assert_eq!(event.attendees[0].email, "ada@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_update_event_reminders() {
+        use wiremock::matchers::body_json;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/calendars/primary/events/event1"))
+            .and(body_json(serde_json::json!({
+                "reminders": {
+                    "useDefault": false,
+                    "overrides": [{"method": "popup", "minutes": 15}]
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "event1",
+                "start": {"dateTime": "2024-02-01T14:00:00Z"},
+                "reminders": {
+                    "useDefault": false,
+                    "overrides": [{"method": "popup", "minutes": 15}]
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = CalendarClient::new_with_base_url("test_token", &mock_server.uri());
+        let event = client
+            .update_event(
+                "primary",
+                "event1",
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(Some(&[Reminder::popup(15)])),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(event.reminders, Some(vec![Reminder::popup(15)]));
+    }
+
     #[tokio::test]
     async fn test_token_expired() {
         let mock_server = MockServer::start().await;
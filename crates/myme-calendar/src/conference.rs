@@ -0,0 +1,156 @@
+//! Conferencing link detection for calendar events.
+//!
+//! Finds the URL to join a meeting from the event's conference data, location,
+//! or description so the UI can offer a one-click "Join" action.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::Event;
+
+/// Conferencing service behind a join link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConferenceProvider {
+    GoogleMeet,
+    Zoom,
+    Teams,
+    Jitsi,
+}
+
+impl ConferenceProvider {
+    /// Display name for the UI.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::GoogleMeet => "Google Meet",
+            Self::Zoom => "Zoom",
+            Self::Teams => "Microsoft Teams",
+            Self::Jitsi => "Jitsi Meet",
+        }
+    }
+
+    /// Identify the provider of a URL, if it is a known conferencing link.
+    pub fn from_url(url: &str) -> Option<Self> {
+        let host = url
+            .split_once("://")
+            .map(|(_, rest)| rest)
+            .unwrap_or(url)
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+
+        if host == "meet.google.com" {
+            Some(Self::GoogleMeet)
+        } else if host == "zoom.us" || host.ends_with(".zoom.us") {
+            Some(Self::Zoom)
+        } else if host == "teams.microsoft.com" || host == "teams.live.com" {
+            Some(Self::Teams)
+        } else if host == "meet.jit.si" || host.starts_with("jitsi.") {
+            Some(Self::Jitsi)
+        } else {
+            None
+        }
+    }
+}
+
+/// A typed join link for an event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConferenceLink {
+    pub provider: ConferenceProvider,
+    pub url: String,
+}
+
+impl ConferenceLink {
+    /// Extract the join link for an event.
+    ///
+    /// Conference data from the API wins; otherwise location, then description
+    /// are scanned for the first recognised conferencing URL.
+    pub fn from_event(event: &Event) -> Option<Self> {
+        [event.conference_url.as_deref(), event.location.as_deref(), event.description.as_deref()]
+            .into_iter()
+            .flatten()
+            .find_map(find_in_text)
+    }
+}
+
+/// Find the first conferencing URL in free text (plain or HTML).
+pub fn find_in_text(text: &str) -> Option<ConferenceLink> {
+    text.match_indices("http").find_map(|(start, _)| {
+        let candidate = &text[start..];
+        if !(candidate.starts_with("https://") || candidate.starts_with("http://")) {
+            return None;
+        }
+        let end = candidate
+            .find(|c: char| c.is_whitespace() || matches!(c, '"' | '\'' | '<' | '>' | ')' | ']'))
+            .unwrap_or(candidate.len());
+        let url = candidate[..end].trim_end_matches(['.', ',', ';']);
+        ConferenceProvider::from_url(url)
+            .map(|provider| ConferenceLink { provider, url: url.to_string() })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use crate::types::{EventStatus, EventTime};
+    use chrono::Utc;
+
+    fn event(location: Option<&str>, description: Option<&str>) -> Event {
+        Event {
+            id: "e1".to_string(),
+            calendar_id: "primary".to_string(),
+            summary: "Standup".to_string(),
+            description: description.map(String::from),
+            location: location.map(String::from),
+            start: EventTime::DateTime(Utc::now()),
+            end: EventTime::DateTime(Utc::now()),
+            all_day: false,
+            attendees: vec![],
+            organizer: None,
+            status: EventStatus::Confirmed,
+            html_link: None,
+            etag: None,
+            conference_url: None,
+            time_zone: None,
+            reminders: None,
+        }
+    }
+
+    #[test]
+    fn test_detects_providers() {
+        let cases = [
+            ("https://meet.google.com/abc-defg-hij", ConferenceProvider::GoogleMeet),
+            ("https://us02web.zoom.us/j/123456?pwd=x", ConferenceProvider::Zoom),
+            ("https://teams.microsoft.com/l/meetup-join/19%3a", ConferenceProvider::Teams),
+            ("https://meet.jit.si/MyRoom", ConferenceProvider::Jitsi),
+        ];
+        for (url, provider) in cases {
+            let link = find_in_text(&format!("Join: {} now", url)).unwrap();
+            assert_eq!(link.provider, provider);
+            assert_eq!(link.url, url);
+        }
+        assert!(find_in_text("https://example.com/meeting").is_none());
+    }
+
+    #[test]
+    fn test_html_description_and_trailing_punctuation() {
+        let desc = r#"<a href="https://zoom.us/j/42">Zoom</a>. Or dial in."#;
+        let link = find_in_text(desc).unwrap();
+        assert_eq!(link.url, "https://zoom.us/j/42");
+
+        let link = find_in_text("Call at https://meet.jit.si/Room.").unwrap();
+        assert_eq!(link.url, "https://meet.jit.si/Room");
+    }
+
+    #[test]
+    fn test_conference_data_takes_priority() {
+        let mut ev = event(Some("https://zoom.us/j/1"), None);
+        assert_eq!(ConferenceLink::from_event(&ev).unwrap().provider, ConferenceProvider::Zoom);
+
+        ev.conference_url = Some("https://meet.google.com/xyz".to_string());
+        let link = ConferenceLink::from_event(&ev).unwrap();
+        assert_eq!(link.provider, ConferenceProvider::GoogleMeet);
+
+        assert!(ConferenceLink::from_event(&event(Some("Room A"), Some("Agenda"))).is_none());
+    }
+}
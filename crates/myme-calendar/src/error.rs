@@ -22,9 +22,16 @@ pub enum CalendarError {
     #[error("Invalid event data: {0}")]
     InvalidEventData(String),
 
+    #[error("Unknown time zone: {0}")]
+    InvalidTimeZone(String),
+
     #[error("Conflict: event was modified")]
     Conflict,
 
+    /// The sync token is no longer valid (410 Gone); a full sync is needed
+    #[error("Sync token expired")]
+    SyncTokenExpired,
+
     #[error("API error: {0}")]
     ApiError(String),
 
@@ -45,7 +52,9 @@ impl CalendarError {
             Self::EventNotFound(_) => "Event not found".to_string(),
             Self::CalendarNotFound(_) => "Calendar not found".to_string(),
             Self::InvalidEventData(msg) => format!("Invalid event: {}", msg),
+            Self::InvalidTimeZone(name) => format!("Unknown time zone: {}", name),
             Self::Conflict => "The event was modified elsewhere. Please refresh.".to_string(),
+            Self::SyncTokenExpired => "Calendar needs a full refresh".to_string(),
             Self::ApiError(msg) => format!("Calendar error: {}", msg),
             Self::CacheError(_) => "Local cache error".to_string(),
             Self::NetworkError(_) => "Network error. Check your connection.".to_string(),
@@ -0,0 +1,307 @@
+//! Month and week grid layout for calendar views.
+//!
+//! Layouts refer to events by index into the slice passed in, so callers can
+//! serialize events however they like. Days are wall-clock days in the
+//! display zone; events spanning midnight appear on every day they cover.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use serde::Serialize;
+
+use crate::timezone::DisplayZone;
+use crate::types::Event;
+
+/// Weeks in a month matrix; fixed so the grid height never changes.
+pub const MONTH_WEEKS: usize = 6;
+
+/// Shortest slot used for overlap, so zero-length events still get a column.
+const MIN_SLOT_MINUTES: u32 = 15;
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// One day of a month matrix.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MonthCell {
+    pub date: NaiveDate,
+    /// False for leading and trailing days of the neighbouring months
+    pub in_month: bool,
+    /// Events on this day: all-day first, then by start time
+    pub events: Vec<usize>,
+}
+
+/// A timed event's place in one day column of a week view.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimedSlot {
+    pub index: usize,
+    /// Minutes from midnight, clipped to the day
+    pub start_minute: u32,
+    pub end_minute: u32,
+    /// Column within its overlap group, from 0
+    pub column: u32,
+    /// Columns in the overlap group; the slot is `1 / columns` wide
+    pub columns: u32,
+}
+
+/// One day of a week view.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayLayout {
+    pub date: NaiveDate,
+    /// All-day events, shown in a band above the hours
+    pub all_day: Vec<usize>,
+    pub slots: Vec<TimedSlot>,
+}
+
+/// Wall-clock start and end of an event in `zone`. All-day end dates are
+/// exclusive, so a one-day event ends at the next midnight.
+fn local_span(event: &Event, zone: &DisplayZone) -> (NaiveDateTime, NaiveDateTime) {
+    let start = event.start.local_in(zone);
+    (start, event.end.local_in(zone).max(start))
+}
+
+/// Whether an event covers any of `date` (zero-length events count on their start day).
+fn covers(span: (NaiveDateTime, NaiveDateTime), date: NaiveDate) -> bool {
+    let day_start = date.and_time(NaiveTime::MIN);
+    let day_end = day_start + Duration::days(1);
+    span.0 < day_end && (span.1 > day_start || span.0 >= day_start)
+}
+
+/// Events covering `date`, all-day first, then by start.
+fn events_on(
+    spans: &[(NaiveDateTime, NaiveDateTime)],
+    events: &[Event],
+    date: NaiveDate,
+) -> Vec<usize> {
+    let mut on_day: Vec<usize> = (0..events.len()).filter(|&i| covers(spans[i], date)).collect();
+    on_day.sort_by_key(|&i| (!events[i].all_day, spans[i].0));
+    on_day
+}
+
+/// [`MONTH_WEEKS`] weeks of 7 days starting on `first_day` on or before the
+/// 1st of the month. `None` if `year`/`month` is not a valid month.
+pub fn month_matrix(
+    events: &[Event],
+    year: i32,
+    month: u32,
+    first_day: Weekday,
+    zone: &DisplayZone,
+) -> Option<Vec<Vec<MonthCell>>> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let lead = first.weekday().days_since(first_day);
+    let grid_start = first - Duration::days(i64::from(lead));
+    let spans: Vec<_> = events.iter().map(|e| local_span(e, zone)).collect();
+
+    let weeks = (0..MONTH_WEEKS)
+        .map(|week| {
+            (0..7)
+                .map(|day| {
+                    let date = grid_start + Duration::days((week * 7 + day) as i64);
+                    MonthCell {
+                        date,
+                        in_month: date.month() == month,
+                        events: events_on(&spans, events, date),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    Some(weeks)
+}
+
+/// Seven days from `start`, with timed events placed in columns so that
+/// overlapping events sit side by side.
+pub fn week_layout(events: &[Event], start: NaiveDate, zone: &DisplayZone) -> Vec<DayLayout> {
+    let spans: Vec<_> = events.iter().map(|e| local_span(e, zone)).collect();
+
+    start
+        .iter_days()
+        .take(7)
+        .map(|date| {
+            let day_start = date.and_time(NaiveTime::MIN);
+            let minute = |t: NaiveDateTime| {
+                (t - day_start).num_minutes().clamp(0, i64::from(MINUTES_PER_DAY)) as u32
+            };
+
+            let (all_day, timed): (Vec<usize>, Vec<usize>) =
+                events_on(&spans, events, date).into_iter().partition(|&i| events[i].all_day);
+            let mut timed: Vec<(usize, u32, u32)> = timed
+                .into_iter()
+                .map(|i| {
+                    let start = minute(spans[i].0);
+                    let end = minute(spans[i].1).max(start + MIN_SLOT_MINUTES);
+                    (i, start, end.min(MINUTES_PER_DAY))
+                })
+                .collect();
+            timed.sort_by_key(|&(i, start, end)| (start, std::cmp::Reverse(end), i));
+
+            DayLayout { date, all_day, slots: assign_columns(&timed) }
+        })
+        .collect()
+}
+
+/// Greedy column assignment over (index, start, end) sorted by start. Each
+/// run of transitively overlapping slots shares one column count.
+fn assign_columns(timed: &[(usize, u32, u32)]) -> Vec<TimedSlot> {
+    let mut slots: Vec<TimedSlot> = Vec::with_capacity(timed.len());
+    let mut group_start = 0;
+    let mut group_end = 0;
+    // End minute of the last slot in each column of the current group
+    let mut column_ends: Vec<u32> = Vec::new();
+
+    let close_group = |slots: &mut Vec<TimedSlot>, from: usize, columns: usize| {
+        for slot in &mut slots[from..] {
+            slot.columns = columns as u32;
+        }
+    };
+
+    for &(index, start, end) in timed {
+        if start >= group_end && !column_ends.is_empty() {
+            close_group(&mut slots, group_start, column_ends.len());
+            column_ends.clear();
+            group_start = slots.len();
+        }
+        let column = match column_ends.iter().position(|&e| e <= start) {
+            Some(column) => {
+                column_ends[column] = end;
+                column
+            }
+            None => {
+                column_ends.push(end);
+                column_ends.len() - 1
+            }
+        };
+        group_end = group_end.max(end);
+        slots.push(TimedSlot {
+            index,
+            start_minute: start,
+            end_minute: end,
+            column: column as u32,
+            columns: 0,
+        });
+    }
+    close_group(&mut slots, group_start, column_ends.len());
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use crate::types::{EventStatus, EventTime};
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn event(start: EventTime, end: EventTime) -> Event {
+        Event {
+            id: String::new(),
+            calendar_id: "primary".to_string(),
+            summary: String::new(),
+            description: None,
+            location: None,
+            all_day: matches!(start, EventTime::Date(_)),
+            start,
+            end,
+            attendees: vec![],
+            organizer: None,
+            status: EventStatus::Confirmed,
+            html_link: None,
+            etag: None,
+            conference_url: None,
+            time_zone: None,
+            reminders: None,
+        }
+    }
+
+    fn timed(start: &str, end: &str) -> Event {
+        event(EventTime::Floating(at(start)), EventTime::Floating(at(end)))
+    }
+
+    fn columns(day: &DayLayout) -> Vec<(usize, u32, u32)> {
+        day.slots.iter().map(|s| (s.index, s.column, s.columns)).collect()
+    }
+
+    #[test]
+    fn month_matrix_pads_to_whole_weeks() {
+        let events = [
+            event(EventTime::Date(date("2026-03-10")), EventTime::Date(date("2026-03-12"))),
+            timed("2026-03-10 09:00", "2026-03-10 10:00"),
+            timed("2026-03-31 23:00", "2026-04-01 01:00"),
+        ];
+        let weeks = month_matrix(&events, 2026, 3, Weekday::Mon, &DisplayZone::Local).unwrap();
+
+        assert_eq!(weeks.len(), MONTH_WEEKS);
+        // March 2026 starts on a Sunday
+        assert_eq!(weeks[0][0].date, date("2026-02-23"));
+        assert!(!weeks[0][0].in_month);
+        assert_eq!(weeks[0][6].date, date("2026-03-01"));
+        assert!(weeks[0][6].in_month);
+        assert_eq!(weeks[5][6].date, date("2026-04-05"));
+
+        let cell = |d: &str| weeks.iter().flatten().find(|c| c.date == date(d)).unwrap();
+        assert_eq!(cell("2026-03-10").events, vec![0, 1]);
+        // All-day end dates are exclusive
+        assert_eq!(cell("2026-03-11").events, vec![0]);
+        assert!(cell("2026-03-12").events.is_empty());
+        // Overnight events show on both days
+        assert_eq!(cell("2026-03-31").events, vec![2]);
+        assert_eq!(cell("2026-04-01").events, vec![2]);
+
+        assert!(month_matrix(&events, 2026, 13, Weekday::Mon, &DisplayZone::Local).is_none());
+        let sunday = month_matrix(&events, 2026, 3, Weekday::Sun, &DisplayZone::Local).unwrap();
+        assert_eq!(sunday[0][0].date, date("2026-03-01"));
+    }
+
+    #[test]
+    fn week_layout_assigns_overlap_columns() {
+        let events = [
+            timed("2026-03-09 09:00", "2026-03-09 10:00"),
+            timed("2026-03-09 09:30", "2026-03-09 11:00"),
+            timed("2026-03-09 10:00", "2026-03-09 10:30"),
+            timed("2026-03-09 13:00", "2026-03-09 14:00"),
+            event(EventTime::Date(date("2026-03-09")), EventTime::Date(date("2026-03-10"))),
+        ];
+        let week = week_layout(&events, date("2026-03-09"), &DisplayZone::Local);
+
+        assert_eq!(week.len(), 7);
+        let monday = &week[0];
+        assert_eq!(monday.all_day, vec![4]);
+        // 09:00 and 09:30 overlap; 10:00 reuses the first column once 09:00 ends
+        assert_eq!(columns(monday), vec![(0, 0, 2), (1, 1, 2), (2, 0, 2), (3, 0, 1)]);
+        assert_eq!((monday.slots[0].start_minute, monday.slots[0].end_minute), (540, 600));
+        assert!(week[1].slots.is_empty() && week[1].all_day.is_empty());
+    }
+
+    #[test]
+    fn week_layout_clips_overnight_and_short_events() {
+        let events = [
+            timed("2026-03-09 22:00", "2026-03-10 02:00"),
+            timed("2026-03-10 08:00", "2026-03-10 08:00"),
+            timed("2026-03-10 08:05", "2026-03-10 08:10"),
+        ];
+        let week = week_layout(&events, date("2026-03-09"), &DisplayZone::Local);
+
+        let slot = &week[0].slots[0];
+        assert_eq!((slot.start_minute, slot.end_minute), (22 * 60, MINUTES_PER_DAY));
+        let tuesday = &week[1].slots;
+        assert_eq!((tuesday[0].start_minute, tuesday[0].end_minute), (0, 120));
+        // A zero-length event still occupies a minimum slot, so the next one sits beside it
+        assert_eq!((tuesday[1].start_minute, tuesday[1].end_minute), (480, 495));
+        assert_eq!((tuesday[2].column, tuesday[2].columns), (1, 2));
+    }
+
+    #[test]
+    fn week_layout_uses_display_zone() {
+        let toronto = DisplayZone::parse("America/Toronto").unwrap();
+        let instant = |s: &str| EventTime::DateTime(at(s).and_utc());
+        // 03:30-04:30 UTC on the 10th is 23:30-00:30 in Toronto (EDT)
+        let events = [event(instant("2026-03-10 03:30"), instant("2026-03-10 04:30"))];
+        let week = week_layout(&events, date("2026-03-09"), &toronto);
+
+        assert_eq!(week[0].slots[0].start_minute, 23 * 60 + 30);
+        assert_eq!(week[1].slots[0].end_minute, 30);
+    }
+}
@@ -4,10 +4,19 @@
 
 pub mod cache;
 pub mod client;
+pub mod conference;
 pub mod error;
+pub mod layout;
+pub mod timezone;
 pub mod types;
 
 pub use cache::CalendarCache;
 pub use client::CalendarClient;
+pub use conference::{ConferenceLink, ConferenceProvider};
 pub use error::CalendarError;
-pub use types::{AccessRole, Attendee, Calendar, Event, EventStatus, EventTime, ResponseStatus};
+pub use layout::{month_matrix, week_layout, DayLayout, MonthCell, TimedSlot};
+pub use timezone::DisplayZone;
+pub use types::{
+    AccessRole, Attendee, Calendar, DueReminder, Event, EventStatus, EventSync, EventTime, Geocode,
+    Reminder, ReminderMethod, ResponseStatus,
+};
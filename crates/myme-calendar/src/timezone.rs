@@ -0,0 +1,171 @@
+//! Time zone handling for calendar display.
+//!
+//! Timed events are instants and are shown in a display zone: the system zone
+//! by default, or a fixed IANA zone from `[calendar] display_timezone` (travel
+//! mode: keep seeing home time while abroad). All-day dates and floating
+//! times have no zone and keep their wall-clock value wherever you are.
+
+use chrono::{
+    DateTime, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone,
+    Utc,
+};
+use chrono_tz::Tz;
+
+use crate::error::CalendarError;
+
+/// Zone used to show and bucket events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayZone {
+    /// The system zone, which follows the device when traveling
+    #[default]
+    Local,
+    /// A fixed IANA zone such as `America/Toronto`
+    Named(Tz),
+}
+
+impl DisplayZone {
+    /// Parse an IANA zone name; empty or `local` means the system zone.
+    pub fn parse(name: &str) -> Result<Self, CalendarError> {
+        let name = name.trim();
+        if name.is_empty() || name.eq_ignore_ascii_case("local") {
+            return Ok(Self::Local);
+        }
+        name.parse::<Tz>()
+            .map(Self::Named)
+            .map_err(|_| CalendarError::InvalidTimeZone(name.to_string()))
+    }
+
+    /// Zone from the config setting, falling back to the system zone (with a
+    /// warning) when the name is not a known IANA zone.
+    pub fn from_config(name: Option<&str>) -> Self {
+        let name = name.unwrap_or_default();
+        Self::parse(name).unwrap_or_else(|e| {
+            tracing::warn!("Calendar: {}; using system time zone", e);
+            Self::Local
+        })
+    }
+
+    /// IANA name, or `local` for the system zone.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Local => "local",
+            Self::Named(tz) => tz.name(),
+        }
+    }
+
+    /// Wall-clock time of `instant` in this zone.
+    pub fn local_datetime(&self, instant: DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            Self::Local => instant.with_timezone(&Local).naive_local(),
+            Self::Named(tz) => instant.with_timezone(tz).naive_local(),
+        }
+    }
+
+    /// Instant of a wall-clock time in this zone (see [`resolve_local`]).
+    pub fn to_utc(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        match self {
+            Self::Local => resolve_local(&Local, local),
+            Self::Named(tz) => resolve_local(tz, local),
+        }
+    }
+
+    /// Today's date in this zone.
+    pub fn today(&self, now: DateTime<Utc>) -> NaiveDate {
+        self.local_datetime(now).date()
+    }
+
+    /// Midnight to the following midnight of `date`: 23 or 25 hours on days
+    /// the clocks change.
+    pub fn day_range(&self, date: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+        let next = date.succ_opt().unwrap_or(date);
+        (self.to_utc(date.and_time(NaiveTime::MIN)), self.to_utc(next.and_time(NaiveTime::MIN)))
+    }
+
+    /// Format `instant` in this zone with a chrono format string.
+    pub fn format(&self, instant: DateTime<Utc>, fmt: &str) -> String {
+        self.local_datetime(instant).format(fmt).to_string()
+    }
+}
+
+/// Instant of a wall-clock time in `tz`. Times that occur twice when clocks go
+/// back resolve to the first occurrence; times skipped when clocks go forward
+/// are moved past the gap (02:30 on a spring-forward night is 03:30).
+pub fn resolve_local<Z: TimeZone>(tz: &Z, local: NaiveDateTime) -> DateTime<Utc> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(t) | LocalResult::Ambiguous(t, _) => t.with_timezone(&Utc),
+        LocalResult::None => {
+            // Apply the offset in force before the gap
+            let offset = tz
+                .offset_from_local_datetime(&(local - Duration::days(1)))
+                .earliest()
+                .map(|o| o.fix().local_minus_utc())
+                .unwrap_or_default();
+            (local - Duration::seconds(i64::from(offset))).and_utc()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn toronto() -> DisplayZone {
+        DisplayZone::parse("America/Toronto").unwrap()
+    }
+
+    fn naive(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn parse_zone_names() {
+        assert_eq!(DisplayZone::parse("").unwrap(), DisplayZone::Local);
+        assert_eq!(DisplayZone::parse(" Local ").unwrap(), DisplayZone::Local);
+        assert_eq!(toronto().name(), "America/Toronto");
+        assert!(matches!(
+            DisplayZone::parse("Mars/Olympus"),
+            Err(CalendarError::InvalidTimeZone(name)) if name == "Mars/Olympus"
+        ));
+        assert_eq!(DisplayZone::from_config(Some("Mars/Olympus")), DisplayZone::Local);
+    }
+
+    #[test]
+    fn day_range_spans_dst_changes() {
+        let zone = toronto();
+
+        // 2026-03-08: clocks go forward at 02:00, the day has 23 hours
+        let (start, end) = zone.day_range(NaiveDate::from_ymd_opt(2026, 3, 8).unwrap());
+        assert_eq!(start, utc("2026-03-08T05:00:00Z"));
+        assert_eq!(end - start, Duration::hours(23));
+
+        // 2026-11-01: clocks go back at 02:00, the day has 25 hours
+        let (start, end) = zone.day_range(NaiveDate::from_ymd_opt(2026, 11, 1).unwrap());
+        assert_eq!(start, utc("2026-11-01T04:00:00Z"));
+        assert_eq!(end - start, Duration::hours(25));
+    }
+
+    #[test]
+    fn to_utc_resolves_gaps_and_repeats() {
+        let zone = toronto();
+
+        // Skipped hour moves forward: 02:30 does not exist, becomes 03:30 EDT
+        let t = zone.to_utc(naive("2026-03-08 02:30"));
+        assert_eq!(t, utc("2026-03-08T07:30:00Z"));
+        assert_eq!(zone.format(t, "%H:%M"), "03:30");
+
+        // Repeated hour takes the first (EDT) occurrence
+        assert_eq!(zone.to_utc(naive("2026-11-01 01:30")), utc("2026-11-01T05:30:00Z"));
+
+        // Round trip either side of the change
+        let before = utc("2026-11-01T05:59:00Z");
+        let after = utc("2026-11-01T06:01:00Z");
+        assert_eq!(zone.format(before, "%H:%M"), "01:59");
+        assert_eq!(zone.format(after, "%H:%M"), "01:01");
+        assert_eq!(zone.today(after), NaiveDate::from_ymd_opt(2026, 11, 1).unwrap());
+    }
+}
@@ -1,8 +1,11 @@
 //! Calendar API types and data structures.
 
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
+use crate::timezone::{resolve_local, DisplayZone};
+
 /// Calendar event as stored locally.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
@@ -19,24 +22,160 @@ pub struct Event {
     pub status: EventStatus,
     pub html_link: Option<String>,
     pub etag: Option<String>,
+    /// Video entry point from the API's conference data (e.g. Meet link).
+    #[serde(default)]
+    pub conference_url: Option<String>,
+    /// IANA zone the event was scheduled in (from the API's `start.timeZone`).
+    #[serde(default)]
+    pub time_zone: Option<String>,
+    /// Reminders set on the event itself; None follows the calendar's defaults.
+    #[serde(default)]
+    pub reminders: Option<Vec<Reminder>>,
 }
 
-/// Event time - can be a specific datetime or an all-day date.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl Event {
+    /// Reminders that apply, given the calendar's defaults. Google only uses
+    /// calendar defaults for timed events.
+    pub fn effective_reminders<'a>(&'a self, defaults: &'a [Reminder]) -> &'a [Reminder] {
+        match &self.reminders {
+            Some(reminders) => reminders,
+            None if self.all_day => &[],
+            None => defaults,
+        }
+    }
+
+    /// Whether the signed-in user declined the event.
+    pub fn declined_by_self(&self) -> bool {
+        self.attendees.iter().any(|a| a.is_self && a.response_status == ResponseStatus::Declined)
+    }
+
+    /// Popup alerts due for the event, as (minutes before, fire time), as
+    /// seen from `zone`. Cancelled and declined events have none.
+    pub fn popup_alerts(
+        &self,
+        defaults: &[Reminder],
+        zone: &DisplayZone,
+    ) -> Vec<(u32, DateTime<Utc>)> {
+        if self.status == EventStatus::Cancelled || self.declined_by_self() {
+            return Vec::new();
+        }
+        let start = self.start.instant_in(zone);
+        let mut minutes: Vec<u32> = self
+            .effective_reminders(defaults)
+            .iter()
+            .filter(|r| r.method == ReminderMethod::Popup)
+            .map(|r| r.minutes)
+            .collect();
+        minutes.sort_unstable();
+        minutes.dedup();
+        minutes.into_iter().map(|m| (m, start - chrono::Duration::minutes(i64::from(m)))).collect()
+    }
+}
+
+/// How Google delivers a reminder.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum ReminderMethod {
+    #[default]
+    Popup,
+    Email,
+}
+
+/// A reminder some minutes before an event starts.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Reminder {
+    pub method: ReminderMethod,
+    pub minutes: u32,
+}
+
+impl Reminder {
+    /// Longest lead time Google accepts (four weeks).
+    pub const MAX_MINUTES: u32 = 40320;
+
+    pub fn popup(minutes: u32) -> Self {
+        Self { method: ReminderMethod::Popup, minutes }
+    }
+
+    /// Convert an API reminder; methods other than popup and email (old SMS
+    /// reminders) are dropped.
+    fn from_api(api: &ApiReminder) -> Option<Self> {
+        let method = match api.method.as_str() {
+            "popup" => ReminderMethod::Popup,
+            "email" => ReminderMethod::Email,
+            _ => return None,
+        };
+        Some(Self { method, minutes: api.minutes })
+    }
+
+    /// Request body form, as sent to the API.
+    pub fn to_api_json(&self) -> serde_json::Value {
+        let method = match self.method {
+            ReminderMethod::Popup => "popup",
+            ReminderMethod::Email => "email",
+        };
+        serde_json::json!({ "method": method, "minutes": self.minutes })
+    }
+}
+
+/// Cached result of looking up an event location's coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Geocode {
+    Found {
+        latitude: f64,
+        longitude: f64,
+    },
+    /// Nothing matched; looked up again after a while
+    NotFound,
+}
+
+/// A popup alert due for an event.
+#[derive(Debug, Clone)]
+pub struct DueReminder {
+    pub event: Event,
+    pub minutes_before: u32,
+    pub fire_at: DateTime<Utc>,
+}
+
+/// Event time - a specific instant, an all-day date, or a floating
+/// wall-clock time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EventTime {
     DateTime(DateTime<Utc>),
     Date(NaiveDate),
+    /// Wall-clock time without a zone; happens at that clock time wherever you are.
+    Floating(NaiveDateTime),
 }
 
 impl EventTime {
+    /// Instant for storage and ordering, reading dates and floating times as
+    /// UTC. Use [`EventTime::instant_in`] for anything shown to the user.
     pub fn as_datetime(&self) -> DateTime<Utc> {
         match self {
             EventTime::DateTime(dt) => *dt,
-            EventTime::Date(d) => {
-                // Midnight is valid for any NaiveDate.
-                #[allow(clippy::expect_used)]
-                d.and_hms_opt(0, 0, 0).expect("midnight is valid for any NaiveDate").and_utc()
-            }
+            EventTime::Date(d) => d.and_time(NaiveTime::MIN).and_utc(),
+            EventTime::Floating(t) => t.and_utc(),
+        }
+    }
+
+    /// Whether this time keeps its wall-clock value in every zone.
+    pub fn is_floating(&self) -> bool {
+        !matches!(self, EventTime::DateTime(_))
+    }
+
+    /// When this happens for someone in `zone`.
+    pub fn instant_in(&self, zone: &DisplayZone) -> DateTime<Utc> {
+        match self {
+            EventTime::DateTime(dt) => *dt,
+            EventTime::Date(d) => zone.to_utc(d.and_time(NaiveTime::MIN)),
+            EventTime::Floating(t) => zone.to_utc(*t),
+        }
+    }
+
+    /// Wall-clock time in `zone`.
+    pub fn local_in(&self, zone: &DisplayZone) -> NaiveDateTime {
+        match self {
+            EventTime::DateTime(dt) => zone.local_datetime(*dt),
+            EventTime::Date(d) => d.and_time(NaiveTime::MIN),
+            EventTime::Floating(t) => *t,
         }
     }
 }
@@ -57,6 +196,9 @@ pub struct Attendee {
     pub display_name: Option<String>,
     pub response_status: ResponseStatus,
     pub is_organizer: bool,
+    /// This attendee is the signed-in user
+    #[serde(default)]
+    pub is_self: bool,
 }
 
 /// Attendee response status.
@@ -80,6 +222,48 @@ pub struct Calendar {
     pub foreground_color: Option<String>,
     pub is_primary: bool,
     pub access_role: AccessRole,
+    /// Color chosen in MyMe, overriding Google's `background_color`
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Whether the calendar's events are synced and shown
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+    /// Reminder set in MyMe in minutes before events, replacing Google's defaults
+    #[serde(default)]
+    pub default_reminder_minutes: Option<u32>,
+    /// Google's default reminders for the calendar's timed events
+    #[serde(default)]
+    pub default_reminders: Vec<Reminder>,
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+impl Calendar {
+    /// ID the calendar's events are synced and cached under: Google's
+    /// `primary` alias for the primary calendar, else its own ID.
+    pub fn event_calendar_id(&self) -> &str {
+        if self.is_primary {
+            "primary"
+        } else {
+            &self.id
+        }
+    }
+
+    /// Color to draw the calendar's events in.
+    pub fn display_color(&self) -> Option<&str> {
+        self.color.as_deref().or(self.background_color.as_deref())
+    }
+
+    /// Default reminders for events that don't set their own: the one set in
+    /// MyMe, else Google's.
+    pub fn reminders(&self) -> Vec<Reminder> {
+        match self.default_reminder_minutes {
+            Some(minutes) => vec![Reminder::popup(minutes)],
+            None => self.default_reminders.clone(),
+        }
+    }
 }
 
 /// Calendar access role.
@@ -110,6 +294,32 @@ pub struct ApiEvent {
     pub status: Option<String>,
     pub html_link: Option<String>,
     pub etag: Option<String>,
+    pub hangout_link: Option<String>,
+    pub conference_data: Option<ApiConferenceData>,
+    pub reminders: Option<ApiEventReminders>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiEventReminders {
+    #[serde(default)]
+    pub use_default: bool,
+    #[serde(default)]
+    pub overrides: Vec<ApiReminder>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiConferenceData {
+    #[serde(default)]
+    pub entry_points: Vec<ApiEntryPoint>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiEntryPoint {
+    pub entry_point_type: String,
+    pub uri: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -128,6 +338,8 @@ pub struct ApiAttendee {
     pub response_status: Option<String>,
     #[serde(default)]
     pub organizer: bool,
+    #[serde(default, rename = "self")]
+    pub is_self: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -147,6 +359,19 @@ pub struct EventListResponse {
     pub next_sync_token: Option<String>,
 }
 
+/// Changes from one event sync, all pages included.
+#[derive(Debug, Clone, Default)]
+pub struct EventSync {
+    /// Events added or changed since the last sync
+    pub events: Vec<Event>,
+    /// IDs of events deleted since the last sync
+    pub cancelled: Vec<String>,
+    /// Token for the next incremental sync
+    pub next_sync_token: Option<String>,
+    /// A full listing rather than changes: cached events not in it are gone
+    pub full: bool,
+}
+
 /// API response for calendar list.
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -168,11 +393,24 @@ pub struct ApiCalendar {
     #[serde(default)]
     pub primary: bool,
     pub access_role: Option<String>,
+    /// Shown in Google Calendar's own list; absent means not selected
+    #[serde(default)]
+    pub selected: bool,
+    #[serde(default)]
+    pub default_reminders: Vec<ApiReminder>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiReminder {
+    pub method: String,
+    pub minutes: u32,
 }
 
 impl Event {
     /// Convert API response to local Event.
     pub fn from_api(api: ApiEvent, calendar_id: &str) -> Self {
+        let time_zone = api.start.as_ref().and_then(|t| t.time_zone.clone());
+
         let (start, all_day) = api
             .start
             .map(|t| parse_event_time(&t))
@@ -202,10 +440,22 @@ impl Event {
                     display_name: a.display_name,
                     response_status,
                     is_organizer: a.organizer,
+                    is_self: a.is_self,
                 }
             })
             .collect();
 
+        let conference_url = api
+            .conference_data
+            .and_then(|c| c.entry_points.into_iter().find(|e| e.entry_point_type == "video"))
+            .map(|e| e.uri)
+            .or(api.hangout_link);
+
+        let reminders = api
+            .reminders
+            .filter(|r| !r.use_default)
+            .map(|r| r.overrides.iter().filter_map(Reminder::from_api).collect());
+
         Self {
             id: api.id,
             calendar_id: calendar_id.to_string(),
@@ -220,6 +470,9 @@ impl Event {
             status,
             html_link: api.html_link,
             etag: api.etag,
+            conference_url,
+            time_zone,
+            reminders,
         }
     }
 }
@@ -242,6 +495,15 @@ impl From<ApiCalendar> for Calendar {
             background_color: api.background_color,
             foreground_color: api.foreground_color,
             is_primary: api.primary,
+            // Follow Google's own selection until changed in MyMe
+            visible: api.selected || api.primary,
+            default_reminder_minutes: None,
+            default_reminders: api
+                .default_reminders
+                .iter()
+                .filter_map(Reminder::from_api)
+                .collect(),
+            color: None,
             access_role,
         }
     }
@@ -253,6 +515,14 @@ fn parse_event_time(api: &ApiEventTime) -> (EventTime, bool) {
         if let Ok(dt) = DateTime::parse_from_rfc3339(dt_str) {
             return (EventTime::DateTime(dt.with_timezone(&Utc)), false);
         }
+        // No offset: local to `timeZone` if given, otherwise floating
+        if let Ok(local) = NaiveDateTime::parse_from_str(dt_str, "%Y-%m-%dT%H:%M:%S") {
+            let tz = api.time_zone.as_deref().and_then(|name| name.parse::<Tz>().ok());
+            return match tz {
+                Some(tz) => (EventTime::DateTime(resolve_local(&tz, local)), false),
+                None => (EventTime::Floating(local), false),
+            };
+        }
     }
     if let Some(date_str) = &api.date {
         if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
@@ -306,6 +576,27 @@ mod tests {
         assert!(matches!(event.start, EventTime::Date(_)));
     }
 
+    #[test]
+    fn test_event_conference_data() {
+        let json = r#"{
+            "id": "event789",
+            "summary": "Standup",
+            "start": {"dateTime": "2024-02-01T10:00:00Z"},
+            "hangoutLink": "https://meet.google.com/old-link",
+            "conferenceData": {
+                "entryPoints": [
+                    {"entryPointType": "phone", "uri": "tel:+1-555-0100"},
+                    {"entryPointType": "video", "uri": "https://meet.google.com/abc-defg-hij"}
+                ]
+            }
+        }"#;
+
+        let api_event: ApiEvent = serde_json::from_str(json).unwrap();
+        let event = Event::from_api(api_event, "primary");
+
+        assert_eq!(event.conference_url.as_deref(), Some("https://meet.google.com/abc-defg-hij"));
+    }
+
     #[test]
     fn test_calendar_from_api() {
         let json = r#"{
@@ -322,6 +613,93 @@ mod tests {
         assert_eq!(calendar.id, "primary");
         assert!(calendar.is_primary);
         assert_eq!(calendar.access_role, AccessRole::Owner);
+        assert!(calendar.visible);
+    }
+
+    #[test]
+    fn test_calendar_from_api_selection_and_reminders() {
+        let json = r##"{
+            "id": "team@group.calendar.google.com",
+            "summary": "Team",
+            "backgroundColor": "#9fe1e7",
+            "accessRole": "reader",
+            "defaultReminders": [
+                {"method": "email", "minutes": 5},
+                {"method": "popup", "minutes": 30},
+                {"method": "popup", "minutes": 10}
+            ]
+        }"##;
+
+        let mut calendar = Calendar::from(serde_json::from_str::<ApiCalendar>(json).unwrap());
+        assert!(!calendar.visible);
+        assert_eq!(calendar.default_reminder_minutes, None);
+        assert_eq!(
+            calendar.reminders(),
+            vec![
+                Reminder { method: ReminderMethod::Email, minutes: 5 },
+                Reminder::popup(30),
+                Reminder::popup(10),
+            ]
+        );
+        calendar.default_reminder_minutes = Some(15);
+        assert_eq!(calendar.reminders(), vec![Reminder::popup(15)]);
+        assert_eq!(calendar.display_color(), Some("#9fe1e7"));
+        calendar.color = Some("#ff0000".to_string());
+        assert_eq!(calendar.display_color(), Some("#ff0000"));
+    }
+
+    #[test]
+    fn test_event_reminders_and_popup_alerts() {
+        let event = |reminders: &str| {
+            let json = format!(
+                r#"{{
+                    "id": "standup",
+                    "start": {{"dateTime": "2024-02-01T10:00:00Z"}},
+                    "end": {{"dateTime": "2024-02-01T10:15:00Z"}},
+                    "reminders": {reminders}
+                }}"#
+            );
+            Event::from_api(serde_json::from_str(&json).unwrap(), "primary")
+        };
+        let defaults =
+            [Reminder::popup(10), Reminder { method: ReminderMethod::Email, minutes: 60 }];
+        let zone = DisplayZone::Local;
+        let start =
+            DateTime::parse_from_rfc3339("2024-02-01T10:00:00Z").unwrap().with_timezone(&Utc);
+
+        let default = event(r#"{"useDefault": true}"#);
+        assert_eq!(default.reminders, None);
+        assert_eq!(
+            default.popup_alerts(&defaults, &zone),
+            vec![(10, start - chrono::Duration::minutes(10))]
+        );
+
+        let overridden = event(
+            r#"{"useDefault": false, "overrides": [
+                {"method": "popup", "minutes": 30},
+                {"method": "popup", "minutes": 0},
+                {"method": "sms", "minutes": 5}
+            ]}"#,
+        );
+        assert_eq!(overridden.reminders.as_ref().map(Vec::len), Some(2));
+        assert_eq!(
+            overridden.popup_alerts(&defaults, &zone),
+            vec![(0, start), (30, start - chrono::Duration::minutes(30))]
+        );
+
+        let silenced = event(r#"{"useDefault": false}"#);
+        assert_eq!(silenced.reminders, Some(Vec::new()));
+        assert!(silenced.popup_alerts(&defaults, &zone).is_empty());
+
+        let mut declined = event(r#"{"useDefault": true}"#);
+        declined.attendees.push(Attendee {
+            email: "me@example.com".to_string(),
+            display_name: None,
+            response_status: ResponseStatus::Declined,
+            is_organizer: false,
+            is_self: true,
+        });
+        assert!(declined.popup_alerts(&defaults, &zone).is_empty());
     }
 
     #[test]
@@ -333,7 +711,7 @@ mod tests {
             "end": {"dateTime": "2024-02-01T15:00:00Z"},
             "attendees": [
                 {"email": "alice@example.com", "responseStatus": "accepted", "organizer": true},
-                {"email": "bob@example.com", "responseStatus": "tentative"}
+                {"email": "bob@example.com", "responseStatus": "tentative", "self": true}
             ],
             "organizer": {"email": "alice@example.com"}
         }"#;
@@ -344,6 +722,7 @@ mod tests {
         assert_eq!(event.attendees.len(), 2);
         assert_eq!(event.attendees[0].response_status, ResponseStatus::Accepted);
         assert!(event.attendees[0].is_organizer);
+        assert!(event.attendees[1].is_self);
         assert_eq!(event.organizer, Some("alice@example.com".to_string()));
     }
 
@@ -356,4 +735,49 @@ mod tests {
         let as_dt = date.as_datetime();
         assert_eq!(as_dt.date_naive(), NaiveDate::from_ymd_opt(2024, 2, 1).unwrap());
     }
+
+    #[test]
+    fn test_event_time_zones_from_api() {
+        let json = r#"{
+            "id": "event1",
+            "start": {"dateTime": "2026-03-08T09:00:00", "timeZone": "America/Toronto"},
+            "end": {"dateTime": "2026-03-08T10:00:00-04:00", "timeZone": "America/Toronto"}
+        }"#;
+        let event = Event::from_api(serde_json::from_str(json).unwrap(), "primary");
+        assert_eq!(event.time_zone.as_deref(), Some("America/Toronto"));
+        // 09:00 on the spring-forward day is already EDT
+        assert_eq!(event.start.as_datetime().to_rfc3339(), "2026-03-08T13:00:00+00:00");
+        assert_eq!(event.end.as_datetime().to_rfc3339(), "2026-03-08T14:00:00+00:00");
+
+        let json = r#"{
+            "id": "event2",
+            "start": {"dateTime": "2026-03-08T09:00:00"},
+            "end": {"dateTime": "2026-03-08T10:00:00"}
+        }"#;
+        let event = Event::from_api(serde_json::from_str(json).unwrap(), "primary");
+        assert!(event.start.is_floating());
+        assert!(!event.all_day);
+    }
+
+    #[test]
+    fn test_floating_times_keep_wall_clock() {
+        let toronto = DisplayZone::parse("America/Toronto").unwrap();
+        let london = DisplayZone::parse("Europe/London").unwrap();
+        let nine = NaiveDate::from_ymd_opt(2026, 3, 8).unwrap().and_hms_opt(9, 0, 0).unwrap();
+
+        let floating = EventTime::Floating(nine);
+        assert_eq!(floating.local_in(&toronto), nine);
+        assert_eq!(floating.local_in(&london), nine);
+        assert_eq!(floating.instant_in(&london).to_rfc3339(), "2026-03-08T09:00:00+00:00");
+        assert_eq!(floating.instant_in(&toronto).to_rfc3339(), "2026-03-08T13:00:00+00:00");
+
+        // All-day dates start at local midnight, even on a 23-hour day
+        let day = EventTime::Date(nine.date());
+        assert_eq!(day.local_in(&toronto), nine.date().and_time(NaiveTime::MIN));
+        assert_eq!(day.instant_in(&toronto).to_rfc3339(), "2026-03-08T05:00:00+00:00");
+
+        // Instants move with the viewer
+        let instant = EventTime::DateTime(floating.instant_in(&toronto));
+        assert_eq!(instant.local_in(&london).format("%H:%M").to_string(), "13:00");
+    }
 }
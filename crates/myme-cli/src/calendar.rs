@@ -0,0 +1,85 @@
+//! `myme calendar`: events from the calendar cache the app keeps up to date.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use myme_calendar::{CalendarCache, CalendarClient, DisplayZone, Event};
+use myme_services::RetryConfig;
+
+#[derive(Debug, Subcommand)]
+pub enum CalendarCommand {
+    /// Today's events on the primary calendar
+    Today {
+        /// Fetch from Google before reading the cache (needs a sign-in from the app)
+        #[arg(long)]
+        refresh: bool,
+    },
+}
+
+pub async fn run(cmd: CalendarCommand, json: bool) -> Result<()> {
+    match cmd {
+        CalendarCommand::Today { refresh } => {
+            let config = myme_core::Config::load_cached();
+            let zone = DisplayZone::from_config(config.calendar.display_timezone.as_deref());
+            let (start, end) = zone.day_range(zone.today(Utc::now()));
+            let cache_path = config.config_dir.join("calendar_cache.db");
+            let cache = CalendarCache::new(&cache_path).with_context(|| {
+                format!("Failed to open calendar cache at {}", cache_path.display())
+            })?;
+
+            if refresh {
+                for event in fetch_events(start, end).await? {
+                    cache.store_event(&event)?;
+                }
+            }
+
+            let events = cache.list_events("primary", start, end, &zone)?;
+            if json {
+                crate::print_json(&serde_json::to_value(&events)?);
+            } else if events.is_empty() {
+                println!("No events today");
+            } else {
+                for event in &events {
+                    println!("{}", format_event(event, &zone));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn fetch_events(start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<Event>> {
+    let token = myme_auth::SecureStorage::retrieve_token("google")
+        .context("Not signed in to Google; sign in from the app first")?;
+    if token.is_expired() {
+        bail!("Google token expired; open the app to refresh it");
+    }
+    let config = myme_core::Config::load_cached();
+    let network = config.network.for_service("calendar");
+    let proxies = config.proxy.proxies_for("calendar");
+    let client = CalendarClient::new_with_timeout(&token.access_token, proxies, network.timeout)
+        .with_retry_config(RetryConfig {
+            max_retries: network.max_retries,
+            initial_delay: network.backoff_base,
+            max_delay: network.max_backoff,
+        });
+    let response = client.list_events("primary", start, end, None).await?;
+    Ok(response.items.into_iter().map(|api| Event::from_api(api, "primary")).collect())
+}
+
+/// `09:00-09:30  Standup  @ Room 1`, times in `zone`
+fn format_event(event: &Event, zone: &DisplayZone) -> String {
+    let when = if event.all_day {
+        "all day    ".to_string()
+    } else {
+        format!(
+            "{}-{}",
+            event.start.local_in(zone).format("%H:%M"),
+            event.end.local_in(zone).format("%H:%M")
+        )
+    };
+    match event.location.as_deref().filter(|l| !l.is_empty()) {
+        Some(location) => format!("{}  {}  @ {}", when, event.summary, location),
+        None => format!("{}  {}", when, event.summary),
+    }
+}
@@ -0,0 +1,165 @@
+//! `myme kanban`: pull GitHub issues into project boards.
+
+use std::collections::HashMap;
+
+use anyhow::{bail, Context, Result};
+use clap::Subcommand;
+use myme_services::{GitHubClient, Project, ProjectStore, RetryConfig, Task};
+
+#[derive(Debug, Subcommand)]
+pub enum KanbanCommand {
+    /// Import issues from each project's linked repositories as tasks
+    Sync {
+        /// Project id or name (default: every project)
+        project: Option<String>,
+    },
+}
+
+/// Outcome of syncing one project
+#[derive(Debug, Default, PartialEq, Eq)]
+struct SyncCounts {
+    created: usize,
+    updated: usize,
+    failed_repos: Vec<String>,
+}
+
+pub async fn run(cmd: KanbanCommand, json: bool) -> Result<()> {
+    match cmd {
+        KanbanCommand::Sync { project } => {
+            let store = open_store()?;
+            let projects = select_projects(store.list_projects()?, project.as_deref())?;
+            let client = github_client()?;
+
+            let mut report = Vec::new();
+            for project in &projects {
+                let counts = sync_project(&store, &client, project).await?;
+                if json {
+                    report.push(serde_json::json!({
+                        "project": project.name,
+                        "created": counts.created,
+                        "updated": counts.updated,
+                        "failed_repos": counts.failed_repos,
+                    }));
+                } else {
+                    println!(
+                        "{}: {} new, {} updated",
+                        project.name, counts.created, counts.updated
+                    );
+                    for repo in &counts.failed_repos {
+                        eprintln!("  failed to fetch issues for {}", repo);
+                    }
+                }
+            }
+            if json {
+                crate::print_json(&serde_json::Value::Array(report));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn open_store() -> Result<ProjectStore> {
+    let config_dir = myme_core::Config::load_cached().config_dir.clone();
+    std::fs::create_dir_all(&config_dir)?;
+    let db_path = config_dir.join("projects.db");
+    ProjectStore::open(&db_path)
+        .with_context(|| format!("Failed to open project store at {}", db_path.display()))
+}
+
+fn github_client() -> Result<GitHubClient> {
+    let token = myme_auth::SecureStorage::retrieve_token("github")
+        .context("Not signed in to GitHub; sign in from the app first")?;
+    if token.is_expired() {
+        bail!("GitHub token expired; sign in again from the app");
+    }
+    let config = myme_core::Config::load_cached();
+    let proxies = config.proxy.proxies_for("github");
+    let network = config.network.for_service("github");
+    Ok(GitHubClient::new_with_timeout(token.access_token, proxies, network.timeout)?
+        .with_retry_config(RetryConfig {
+            max_retries: network.max_retries,
+            initial_delay: network.backoff_base,
+            max_delay: network.max_backoff,
+        })
+        .with_graphql(config.github.graphql))
+}
+
+/// All projects, or the one matching `selector` by id or (case-insensitive) name
+fn select_projects(projects: Vec<Project>, selector: Option<&str>) -> Result<Vec<Project>> {
+    let Some(selector) = selector else {
+        return Ok(projects);
+    };
+    let matched: Vec<Project> = projects
+        .into_iter()
+        .filter(|p| p.id == selector || p.name.eq_ignore_ascii_case(selector))
+        .collect();
+    if matched.is_empty() {
+        bail!("No project named '{}'", selector);
+    }
+    Ok(matched)
+}
+
+async fn sync_project(
+    store: &ProjectStore,
+    client: &GitHubClient,
+    project: &Project,
+) -> Result<SyncCounts> {
+    let existing: HashMap<String, Task> =
+        store.list_tasks_for_project(&project.id)?.into_iter().map(|t| (t.id.clone(), t)).collect();
+
+    let mut counts = SyncCounts::default();
+    let repo_ids: Vec<String> = store
+        .list_repos_for_project(&project.id)?
+        .into_iter()
+        .filter(|id| id.contains('/'))
+        .collect();
+    for (repo_id, result) in client.list_issues_for_repos(&repo_ids).await {
+        let issues = match result {
+            Ok(issues) => issues,
+            Err(e) => {
+                tracing::warn!("Failed to fetch issues for {}: {}", repo_id, e);
+                counts.failed_repos.push(repo_id.clone());
+                continue;
+            }
+        };
+        for issue in &issues {
+            let id = Task::issue_id(&repo_id, issue.number);
+            let previous = existing.get(&id);
+            store.upsert_task(&Task::from_issue(&project.id, &repo_id, issue, previous))?;
+            if previous.is_some() {
+                counts.updated += 1;
+            } else {
+                counts.created += 1;
+            }
+        }
+    }
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_select_projects() {
+        let projects = vec![
+            Project {
+                id: "a1".into(),
+                name: "Website".into(),
+                description: None,
+                created_at: String::new(),
+            },
+            Project {
+                id: "b2".into(),
+                name: "CLI".into(),
+                description: None,
+                created_at: String::new(),
+            },
+        ];
+        assert_eq!(select_projects(projects.clone(), None).unwrap().len(), 2);
+        assert_eq!(select_projects(projects.clone(), Some("website")).unwrap()[0].id, "a1");
+        assert_eq!(select_projects(projects.clone(), Some("b2")).unwrap()[0].name, "CLI");
+        assert!(select_projects(projects, Some("nope")).is_err());
+    }
+}
@@ -0,0 +1,105 @@
+//! `myme` command-line interface.
+//!
+//! Headless access to the same data the desktop app shows: notes, local
+//! repositories, project kanban boards and the calendar. Uses the service
+//! crates and local stores directly, so it works without Qt or a running app.
+
+mod calendar;
+mod kanban;
+mod notes;
+mod repos;
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[command(name = "myme", version, about = "MyMe from the terminal")]
+struct Cli {
+    /// Print JSON instead of human-readable text
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Add and list notes
+    #[command(subcommand)]
+    Notes(notes::NotesCommand),
+    /// Inspect local git repositories
+    #[command(subcommand)]
+    Repos(repos::ReposCommand),
+    /// Project kanban boards
+    #[command(subcommand)]
+    Kanban(kanban::KanbanCommand),
+    /// Google Calendar events
+    #[command(subcommand)]
+    Calendar(calendar::CalendarCommand),
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    // Logs go to stderr so stdout stays clean for scripts
+    let _ = tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn")),
+        )
+        .try_init();
+
+    myme_core::config_secrets::install(Box::new(myme_auth::SecureStorage));
+
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Notes(cmd) => notes::run(cmd, cli.json).await,
+        Command::Repos(cmd) => repos::run(cmd, cli.json).await,
+        Command::Kanban(cmd) => kanban::run(cmd, cli.json).await,
+        Command::Calendar(cmd) => calendar::run(cmd, cli.json).await,
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Print a JSON value on one line (one record per invocation, easy to pipe into jq)
+fn print_json(value: &serde_json::Value) {
+    println!("{}", value);
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_subcommands() {
+        let cli = Cli::try_parse_from(["myme", "notes", "add", "buy", "milk", "--json"]).unwrap();
+        assert!(cli.json);
+        match cli.command {
+            Command::Notes(notes::NotesCommand::Add { text, checklist }) => {
+                assert_eq!(text, vec!["buy", "milk"]);
+                assert!(!checklist);
+            }
+            other => panic!("unexpected command: {:?}", other),
+        }
+
+        assert!(Cli::try_parse_from(["myme", "kanban", "sync"]).is_ok());
+        assert!(Cli::try_parse_from(["myme", "calendar", "today", "--refresh"]).is_ok());
+        assert!(Cli::try_parse_from(["myme", "notes", "add"]).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+//! `myme notes`: the local notes database shared with the app.
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use myme_services::{NoteClient, SqliteNoteStore, Todo, TodoCreateRequest};
+
+#[derive(Debug, Subcommand)]
+pub enum NotesCommand {
+    /// Add a note
+    Add {
+        /// Note text (multiple words are joined with spaces)
+        #[arg(required = true)]
+        text: Vec<String>,
+        /// Create a checklist note
+        #[arg(long)]
+        checklist: bool,
+    },
+    /// List notes, pinned first then most recently updated
+    List {
+        /// Show archived notes instead
+        #[arg(long)]
+        archived: bool,
+        /// Only notes with this label
+        #[arg(long)]
+        label: Option<String>,
+        /// Show at most this many notes
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+}
+
+pub async fn run(cmd: NotesCommand, json: bool) -> Result<()> {
+    let client = open_client()?;
+    match cmd {
+        NotesCommand::Add { text, checklist } => {
+            let content = text.join(" ");
+            let note =
+                client.create_todo(TodoCreateRequest { content, is_checklist: checklist }).await?;
+            if json {
+                crate::print_json(&serde_json::to_value(&note)?);
+            } else {
+                println!("Added note {}", note.id);
+            }
+        }
+        NotesCommand::List { archived, label, limit } => {
+            let mut notes = match (archived, label) {
+                (true, _) => client.list_archived().await?,
+                (false, Some(label)) => client.list_by_label(&label).await?,
+                (false, None) => client.list_todos().await?,
+            };
+            if let Some(limit) = limit {
+                notes.truncate(limit);
+            }
+            if json {
+                crate::print_json(&serde_json::to_value(&notes)?);
+            } else {
+                for note in &notes {
+                    println!("{}", format_note(note));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn open_client() -> Result<NoteClient> {
+    let db_path = myme_core::Config::load_cached().notes.sqlite_path();
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let store = SqliteNoteStore::new(&db_path)
+        .with_context(|| format!("Failed to open notes database at {}", db_path.display()))?;
+    Ok(NoteClient::sqlite(store))
+}
+
+/// `  12 [x] * First line  #label`
+fn format_note(note: &Todo) -> String {
+    let done = if note.done { "[x]" } else { "[ ]" };
+    let pin = if note.pinned { "*" } else { " " };
+    let title = note.content.lines().next().unwrap_or_default();
+    let labels: String = note.labels.iter().map(|l| format!("  #{}", l)).collect();
+    format!("{:>4} {} {} {}{}", note.id, done, pin, title, labels)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_format_note() {
+        let note = Todo {
+            id: 7,
+            content: "Groceries\nmilk\neggs".into(),
+            done: false,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            color: None,
+            pinned: true,
+            archived: false,
+            labels: vec!["home".into()],
+            is_checklist: true,
+            reminder: None,
+            deleted_at: None,
+            order_index: 0,
+        };
+        assert_eq!(format_note(&note), "   7 [ ] * Groceries  #home");
+    }
+}
@@ -0,0 +1,78 @@
+//! `myme repos`: local clones under the configured search path.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Subcommand;
+use myme_integrations::{GitOperations, LocalRepo};
+
+/// Same depth the Repos page scans
+const DISCOVERY_DEPTH: usize = 5;
+
+#[derive(Debug, Subcommand)]
+pub enum ReposCommand {
+    /// Branch and working-tree state of each local repository
+    Status {
+        /// Directory to scan (default: `repos.local_search_path` from config)
+        #[arg(long)]
+        path: Option<PathBuf>,
+        /// Only show repositories with uncommitted changes
+        #[arg(long)]
+        dirty: bool,
+    },
+}
+
+pub async fn run(cmd: ReposCommand, json: bool) -> Result<()> {
+    match cmd {
+        ReposCommand::Status { path, dirty } => {
+            let path = path.unwrap_or_else(|| {
+                myme_core::Config::load_cached().repos.effective_local_search_path().0
+            });
+            let mut repos = tokio::task::spawn_blocking(move || {
+                GitOperations::discover_repositories(&path, Some(DISCOVERY_DEPTH))
+            })
+            .await??;
+            repos.sort_by_key(|r| r.name.to_lowercase());
+            if dirty {
+                repos.retain(|r| !r.is_clean);
+            }
+
+            if json {
+                crate::print_json(&serde_json::Value::Array(repos.iter().map(repo_json).collect()));
+            } else {
+                for repo in &repos {
+                    println!("{}", format_repo(repo));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn repo_json(repo: &LocalRepo) -> serde_json::Value {
+    serde_json::json!({
+        "name": repo.name,
+        "path": repo.path,
+        "branch": repo.current_branch,
+        "clean": repo.is_clean,
+        "uncommitted_changes": repo.uncommitted_changes,
+        "remote_url": repo.remote_url,
+        "last_commit": repo.last_commit,
+    })
+}
+
+/// `myme          main      clean      ~/dev/myme`
+fn format_repo(repo: &LocalRepo) -> String {
+    let state = if repo.is_clean {
+        "clean".to_string()
+    } else {
+        format!("{} changed", repo.uncommitted_changes)
+    };
+    format!(
+        "{:<24} {:<16} {:<10} {}",
+        repo.name,
+        repo.current_branch.as_deref().unwrap_or("(detached)"),
+        state,
+        repo.path.display()
+    )
+}
@@ -0,0 +1,271 @@
+//! Merge people from Gmail, Calendar and GitHub into one contact list.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use myme_calendar::{Event, EventStatus};
+use myme_gmail::Message;
+use myme_services::GitHubCollaborator;
+
+use crate::contact::{gravatar_url, parse_address, Contact, Source};
+
+/// Local parts of automated senders that are not people.
+const AUTOMATED_SENDERS: &[&str] = &["noreply", "no-reply", "donotreply", "do-not-reply"];
+
+/// Calendar attendees with this domain are rooms and equipment.
+const RESOURCE_DOMAIN: &str = "resource.calendar.google.com";
+
+fn is_automated(email: &str) -> bool {
+    let local = email.split('@').next().unwrap_or_default();
+    AUTOMATED_SENDERS.iter().any(|a| local.contains(a)) || email.ends_with(RESOURCE_DOMAIN)
+}
+
+/// Accumulates contacts, keyed by email or (for GitHub) login.
+#[derive(Debug, Default)]
+pub struct ContactBook {
+    contacts: HashMap<String, Contact>,
+    /// The user's own addresses, which are never listed
+    ignored: Vec<String>,
+}
+
+impl ContactBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Never list `email` (e.g. the signed-in account).
+    pub fn ignore(&mut self, email: &str) {
+        self.ignored.push(email.trim().to_lowercase());
+    }
+
+    fn upsert(&mut self, key: String, source: Source, at: Option<DateTime<Utc>>) -> &mut Contact {
+        let contact = self.contacts.entry(key).or_insert_with(|| Contact {
+            email: None,
+            name: None,
+            github_login: None,
+            avatar_url: String::new(),
+            sources: Vec::new(),
+            last_interaction: None,
+        });
+        if !contact.sources.contains(&source) {
+            contact.sources.push(source);
+            contact.sources.sort();
+        }
+        contact.last_interaction = contact.last_interaction.max(at);
+        contact
+    }
+
+    fn add_email(
+        &mut self,
+        email: String,
+        name: Option<String>,
+        source: Source,
+        at: Option<DateTime<Utc>>,
+    ) {
+        if is_automated(&email) || self.ignored.contains(&email) {
+            return;
+        }
+        let contact = self.upsert(email.clone(), source, at);
+        if contact.name.is_none() {
+            contact.name = name;
+        }
+        if contact.email.is_none() {
+            contact.avatar_url = gravatar_url(&email);
+            contact.email = Some(email);
+        }
+    }
+
+    /// Add message senders; the message date counts as an interaction.
+    pub fn add_gmail(&mut self, messages: &[Message]) {
+        for message in messages.iter().filter(|m| m.list_id.is_none()) {
+            if let Some((name, email)) = parse_address(&message.from) {
+                self.add_email(email, name, Source::Gmail, Some(message.date));
+            }
+        }
+    }
+
+    /// Add attendees and organizers of non-cancelled events. Only meetings
+    /// that have started by `now` count as interactions. Attendees marked as
+    /// the signed-in user are ignored from then on.
+    pub fn add_calendar(&mut self, events: &[Event], now: DateTime<Utc>) {
+        let own = events.iter().flat_map(|e| &e.attendees).filter(|a| a.is_self);
+        for attendee in own {
+            if !self.ignored.contains(&attendee.email.to_lowercase()) {
+                self.ignore(&attendee.email);
+            }
+        }
+
+        for event in events.iter().filter(|e| e.status != EventStatus::Cancelled) {
+            let start = event.start.as_datetime();
+            let at = (start <= now).then_some(start);
+            for attendee in &event.attendees {
+                if let Some((_, email)) = parse_address(&attendee.email) {
+                    let name = attendee.display_name.clone().filter(|n| !n.trim().is_empty());
+                    self.add_email(email, name, Source::Calendar, at);
+                }
+            }
+            if let Some((_, email)) = event.organizer.as_deref().and_then(parse_address) {
+                self.add_email(email, None, Source::Calendar, at);
+            }
+        }
+    }
+
+    /// Add repository collaborators (GitHub has no interaction time here).
+    pub fn add_github(&mut self, collaborators: &[GitHubCollaborator]) {
+        for collaborator in collaborators {
+            let key = format!("github:{}", collaborator.login.to_lowercase());
+            let contact = self.upsert(key, Source::GitHub, None);
+            contact.github_login = Some(collaborator.login.clone());
+            contact.avatar_url = collaborator.avatar_url.clone();
+        }
+    }
+
+    /// Contacts, most recent interaction first, then by name.
+    pub fn into_contacts(self) -> Vec<Contact> {
+        let mut contacts: Vec<Contact> = self.contacts.into_values().collect();
+        contacts.sort_by(|a, b| {
+            b.last_interaction
+                .cmp(&a.last_interaction)
+                .then_with(|| a.display_name().to_lowercase().cmp(&b.display_name().to_lowercase()))
+        });
+        contacts
+    }
+}
+
+/// Up to `limit` contacts matching `query`: prefix matches first, then
+/// substring matches, each keeping the input (recency) order.
+pub fn search<'a>(contacts: &'a [Contact], query: &str, limit: usize) -> Vec<&'a Contact> {
+    let (prefix, rest): (Vec<&Contact>, Vec<&Contact>) =
+        contacts.iter().filter(|c| c.matches(query)).partition(|c| c.starts_with(query));
+    prefix.into_iter().chain(rest).take(limit).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use myme_calendar::{Attendee, EventTime, ResponseStatus};
+
+    fn message(from: &str, date: DateTime<Utc>) -> Message {
+        Message {
+            id: from.to_string(),
+            thread_id: from.to_string(),
+            from: from.to_string(),
+            to: vec![],
+            subject: String::new(),
+            snippet: String::new(),
+            date,
+            labels: vec![],
+            is_unread: false,
+            is_starred: false,
+            body: None,
+            attachments: vec![],
+            list_id: None,
+            unsubscribe: None,
+        }
+    }
+
+    fn event(start: DateTime<Utc>, attendees: &[(&str, Option<&str>)]) -> Event {
+        Event {
+            id: "e1".to_string(),
+            calendar_id: "primary".to_string(),
+            summary: "Sync".to_string(),
+            description: None,
+            location: None,
+            start: EventTime::DateTime(start),
+            end: EventTime::DateTime(start + Duration::hours(1)),
+            all_day: false,
+            attendees: attendees
+                .iter()
+                .map(|(email, name)| Attendee {
+                    email: email.to_string(),
+                    display_name: name.map(str::to_string),
+                    response_status: ResponseStatus::Accepted,
+                    is_organizer: false,
+                    is_self: false,
+                })
+                .collect(),
+            organizer: None,
+            status: EventStatus::Confirmed,
+            html_link: None,
+            etag: None,
+            conference_url: None,
+            time_zone: None,
+            reminders: None,
+        }
+    }
+
+    #[test]
+    fn parse_address_forms() {
+        assert_eq!(
+            parse_address("\"Ada Lovelace\" <Ada@Example.com>"),
+            Some((Some("Ada Lovelace".to_string()), "ada@example.com".to_string()))
+        );
+        assert_eq!(parse_address("bob@example.com"), Some((None, "bob@example.com".to_string())));
+        assert_eq!(parse_address("Undisclosed recipients"), None);
+        assert!(gravatar_url("ADA@example.com ").ends_with("?d=identicon&s=80"));
+        assert_eq!(gravatar_url("ADA@example.com"), gravatar_url("ada@example.com"));
+    }
+
+    #[test]
+    fn merges_sources_and_keeps_latest_interaction() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        let mut standup =
+            event(now - Duration::days(1), &[("ada@example.com", None), ("Me@example.com", None)]);
+        standup.attendees[1].is_self = true;
+
+        let mut book = ContactBook::new();
+        book.add_calendar(
+            &[standup, event(now + Duration::days(2), &[("carol@example.com", Some("Carol"))])],
+            now,
+        );
+        book.add_gmail(&[
+            message("Ada <ada@example.com>", now - Duration::days(5)),
+            message("GitHub <noreply@github.com>", now),
+            message("me@example.com", now),
+        ]);
+        book.add_github(&[GitHubCollaborator {
+            id: 1,
+            login: "octocat".to_string(),
+            avatar_url: "https://avatars.githubusercontent.com/u/1".to_string(),
+            html_url: "https://github.com/octocat".to_string(),
+        }]);
+
+        let contacts = book.into_contacts();
+        assert_eq!(contacts.len(), 3);
+
+        let ada = &contacts[0];
+        assert_eq!(ada.display_name(), "Ada");
+        assert_eq!(ada.sources, vec![Source::Gmail, Source::Calendar]);
+        assert_eq!(ada.last_interaction, Some(now - Duration::days(1)));
+        assert!(ada.avatar_url.starts_with("https://www.gravatar.com/avatar/"));
+
+        // Upcoming meetings add the person without counting as an interaction
+        let carol = contacts.iter().find(|c| c.display_name() == "Carol").unwrap();
+        assert_eq!(carol.last_interaction, None);
+
+        let octocat = contacts.iter().find(|c| c.github_login.is_some()).unwrap();
+        assert_eq!(octocat.email, None);
+        assert_eq!(octocat.avatar_url, "https://avatars.githubusercontent.com/u/1");
+    }
+
+    #[test]
+    fn search_ranks_prefix_matches_first() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        let mut book = ContactBook::new();
+        book.add_gmail(&[
+            message("Rosalind <ros@example.com>", now),
+            message("Ada Lovelace <ada@example.com>", now - Duration::days(1)),
+        ]);
+        let contacts = book.into_contacts();
+
+        let names: Vec<&str> =
+            search(&contacts, "ro", 10).iter().map(|c| c.display_name()).collect();
+        assert_eq!(names, vec!["Rosalind"]);
+        let names: Vec<&str> =
+            search(&contacts, "lo", 10).iter().map(|c| c.display_name()).collect();
+        assert_eq!(names, vec!["Ada Lovelace"]);
+        assert_eq!(search(&contacts, "", 1).len(), 1);
+    }
+}
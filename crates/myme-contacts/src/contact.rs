@@ -0,0 +1,87 @@
+//! Contact type and address helpers.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Where a contact was seen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Gmail,
+    Calendar,
+    GitHub,
+}
+
+/// A person aggregated from one or more sources.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    /// Lowercased address; `None` for GitHub collaborators (the API does not expose emails)
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub github_login: Option<String>,
+    /// GitHub avatar, or a Gravatar URL (identicon fallback) for email contacts
+    pub avatar_url: String,
+    /// Sorted, without duplicates
+    pub sources: Vec<Source>,
+    /// Most recent email received from, or past meeting with, this person
+    pub last_interaction: Option<DateTime<Utc>>,
+}
+
+impl Contact {
+    /// Name if known, else email, else GitHub login.
+    pub fn display_name(&self) -> &str {
+        self.name
+            .as_deref()
+            .or(self.email.as_deref())
+            .or(self.github_login.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Whether name, email or login contains `query` (case-insensitive).
+    /// An empty query matches everything.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        [&self.name, &self.email, &self.github_login]
+            .into_iter()
+            .flatten()
+            .any(|field| field.to_lowercase().contains(&query))
+    }
+
+    /// Whether name, email or login (or a word of the name) starts with `query`.
+    pub(crate) fn starts_with(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        let name_words = self.name.iter().flat_map(|n| n.split_whitespace());
+        [self.email.as_deref(), self.github_login.as_deref()]
+            .into_iter()
+            .flatten()
+            .chain(name_words)
+            .any(|field| field.to_lowercase().starts_with(&query))
+    }
+}
+
+/// Split an address header like `"Ada Lovelace" <ada@example.com>` into
+/// display name and lowercased email. Returns `None` if there is no address.
+pub fn parse_address(header: &str) -> Option<(Option<String>, String)> {
+    let header = header.trim();
+    let (name, email) = match (header.rfind('<'), header.rfind('>')) {
+        (Some(open), Some(close)) if open < close => {
+            let name = header[..open].trim().trim_matches('"').trim();
+            (Some(name).filter(|n| !n.is_empty()), &header[open + 1..close])
+        }
+        _ => (None, header),
+    };
+    let email = email.trim().to_lowercase();
+    let (local, domain) = email.split_once('@')?;
+    if local.is_empty() || domain.is_empty() || email.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((name.map(str::to_string), email))
+}
+
+/// Gravatar URL for an email, falling back to a generated identicon.
+pub fn gravatar_url(email: &str) -> String {
+    let hash = Sha256::digest(email.trim().to_lowercase().as_bytes());
+    let hex: String = hash.iter().map(|b| format!("{:02x}", b)).collect();
+    format!("https://www.gravatar.com/avatar/{}?d=identicon&s=80", hex)
+}
@@ -0,0 +1,10 @@
+//! Unified contacts for MyMe.
+//!
+//! Aggregates people seen as Gmail senders, calendar attendees and GitHub
+//! collaborators into one list with avatars and last-interaction times.
+
+pub mod aggregate;
+pub mod contact;
+
+pub use aggregate::{search, ContactBook};
+pub use contact::{gravatar_url, parse_address, Contact, Source};
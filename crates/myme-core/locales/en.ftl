@@ -0,0 +1,136 @@
+# English messages, the fallback for every other catalog. Message ids are
+# referenced from Rust through `myme_core::i18n::tr` and `tr_args`.
+
+## Errors (AppError::user_message)
+
+error-io = A file operation failed. Please try again.
+error-service = Something went wrong. Please try again.
+error-other = An unexpected error occurred. Please try again.
+
+error-network-connection-failed = Unable to connect. Check your internet connection.
+error-network-timeout = The request timed out. Please try again.
+error-network-server-down = The server is experiencing issues. Please try again later.
+error-network-request-failed = The request failed. Please try again.
+error-network-invalid-response = Received an unexpected response. Please try again.
+error-network-tls = Secure connection failed. Check your network settings.
+
+error-database-connection-failed = Unable to access local data. Try restarting the app.
+error-database-query-failed = A data operation failed. Please try again.
+error-database-corruption = Local data may be corrupted. Consider resetting app data.
+error-database-migration-failed = Failed to update local data. Try restarting the app.
+
+error-config-not-found = Configuration not found. Using defaults.
+error-config-invalid = Invalid configuration. Check your settings.
+error-config-parse = Configuration file is malformed. Check your settings.
+error-config-missing-setting = A required setting is missing. Check your settings.
+
+error-auth-token-expired = Your session has expired. Please sign in again.
+error-auth-token-not-found = Not signed in. Please authenticate.
+error-auth-invalid-token = Authentication invalid. Please sign in again.
+error-auth-oauth-failed = Sign-in failed. Please try again.
+error-auth-oauth-cancelled = Sign-in was cancelled.
+error-auth-invalid-credentials = Invalid credentials. Please check and try again.
+error-auth-storage = Failed to save credentials. Please try again.
+error-auth-port-in-use = Sign-in port is busy. Close other apps and try again.
+
+error-github-rate-limited = GitHub rate limit exceeded. Please wait and try again.
+error-github-repo-not-found = Repository not found. Check the URL and try again.
+error-github-unauthorized = GitHub authentication failed. Please sign in again.
+error-github-forbidden = You don't have permission to access this resource.
+error-github-server-down = GitHub is experiencing issues. Please try again later.
+error-github-request-failed = GitHub request failed. Please try again.
+error-github-not-authenticated = Not signed into GitHub. Please authenticate first.
+error-github-invalid-repo-url = Invalid repository URL format.
+
+error-weather-location-not-found = Location not found. Check and try again.
+error-weather-api = Weather service error. Please try again.
+error-weather-invalid-api-key = Weather API key is invalid. Check settings.
+error-weather-unavailable = Weather service unavailable. Please try again later.
+error-weather-cache = Weather data may be outdated.
+
+## Status messages
+
+github-not-authenticated = GitHub not authenticated
+
+## Weather conditions
+
+weather-clear = Clear
+weather-partly-cloudy = Partly Cloudy
+weather-cloudy = Cloudy
+weather-fog = Fog
+weather-drizzle = Drizzle
+weather-rain = Rain
+weather-heavy-rain = Heavy Rain
+weather-snow = Snow
+weather-sleet = Sleet
+weather-thunderstorm = Thunderstorm
+
+## Relative time
+
+time-seconds = { $count ->
+    [one] { $count } second
+   *[other] { $count } seconds
+}
+time-minutes = { $count ->
+    [one] { $count } minute
+   *[other] { $count } minutes
+}
+time-hours = { $count ->
+    [one] { $count } hour
+   *[other] { $count } hours
+}
+time-days = { $count ->
+    [one] { $count } day
+   *[other] { $count } days
+}
+time-months = { $count ->
+    [one] { $count } month
+   *[other] { $count } months
+}
+time-years = { $count ->
+    [one] { $count } year
+   *[other] { $count } years
+}
+time-ago = { $duration } ago
+time-from-now = { $duration } from now
+
+## Dates and times
+
+time-12h = { $time } { $period }
+time-am = AM
+time-pm = PM
+date-just-now = just now
+date-minutes-ago = { $count } min ago
+date-hours-ago = { $count } h ago
+date-in-minutes = in { $count } min
+date-today-at = today { $time }
+date-tomorrow-at = tomorrow { $time }
+date-yesterday-at = yesterday { $time }
+date-weekday-at = { $weekday } { $time }
+date-at = { $date } { $time }
+date-weekday-date = { $weekday } { $date }
+date-day-month = { $month } { $day }
+date-day-month-year = { $month } { $day }, { $year }
+date-weekday-short = { $weekday ->
+    [1] Mon
+    [2] Tue
+    [3] Wed
+    [4] Thu
+    [5] Fri
+    [6] Sat
+   *[7] Sun
+}
+date-month-short = { $month ->
+    [1] Jan
+    [2] Feb
+    [3] Mar
+    [4] Apr
+    [5] May
+    [6] Jun
+    [7] Jul
+    [8] Aug
+    [9] Sep
+    [10] Oct
+    [11] Nov
+   *[12] Dec
+}
@@ -0,0 +1,132 @@
+# Messages en français. Les messages absents retombent sur l'anglais (en.ftl).
+
+## Erreurs
+
+error-io = Une opération sur un fichier a échoué. Veuillez réessayer.
+error-service = Un problème est survenu. Veuillez réessayer.
+error-other = Une erreur inattendue s'est produite. Veuillez réessayer.
+
+error-network-connection-failed = Connexion impossible. Vérifiez votre connexion Internet.
+error-network-timeout = La requête a expiré. Veuillez réessayer.
+error-network-server-down = Le serveur rencontre des problèmes. Veuillez réessayer plus tard.
+error-network-request-failed = La requête a échoué. Veuillez réessayer.
+error-network-invalid-response = Réponse inattendue. Veuillez réessayer.
+error-network-tls = La connexion sécurisée a échoué. Vérifiez vos paramètres réseau.
+
+error-database-connection-failed = Impossible d'accéder aux données locales. Essayez de redémarrer l'application.
+error-database-query-failed = Une opération sur les données a échoué. Veuillez réessayer.
+error-database-corruption = Les données locales sont peut-être corrompues. Envisagez de les réinitialiser.
+error-database-migration-failed = La mise à jour des données locales a échoué. Essayez de redémarrer l'application.
+
+error-config-not-found = Configuration introuvable. Valeurs par défaut utilisées.
+error-config-invalid = Configuration invalide. Vérifiez vos paramètres.
+error-config-parse = Le fichier de configuration est mal formé. Vérifiez vos paramètres.
+error-config-missing-setting = Un paramètre requis est manquant. Vérifiez vos paramètres.
+
+error-auth-token-expired = Votre session a expiré. Veuillez vous reconnecter.
+error-auth-token-not-found = Non connecté. Veuillez vous authentifier.
+error-auth-invalid-token = Authentification invalide. Veuillez vous reconnecter.
+error-auth-oauth-failed = La connexion a échoué. Veuillez réessayer.
+error-auth-oauth-cancelled = La connexion a été annulée.
+error-auth-invalid-credentials = Identifiants invalides. Vérifiez-les et réessayez.
+error-auth-storage = L'enregistrement des identifiants a échoué. Veuillez réessayer.
+error-auth-port-in-use = Le port de connexion est occupé. Fermez les autres applications et réessayez.
+
+error-github-rate-limited = Limite de requêtes GitHub atteinte. Patientez puis réessayez.
+error-github-repo-not-found = Dépôt introuvable. Vérifiez l'URL et réessayez.
+error-github-unauthorized = L'authentification GitHub a échoué. Veuillez vous reconnecter.
+error-github-forbidden = Vous n'avez pas accès à cette ressource.
+error-github-server-down = GitHub rencontre des problèmes. Veuillez réessayer plus tard.
+error-github-request-failed = La requête GitHub a échoué. Veuillez réessayer.
+error-github-not-authenticated = Non connecté à GitHub. Veuillez d'abord vous authentifier.
+error-github-invalid-repo-url = Format d'URL de dépôt invalide.
+
+error-weather-location-not-found = Lieu introuvable. Vérifiez et réessayez.
+error-weather-api = Erreur du service météo. Veuillez réessayer.
+error-weather-invalid-api-key = La clé d'API météo est invalide. Vérifiez les paramètres.
+error-weather-unavailable = Service météo indisponible. Veuillez réessayer plus tard.
+error-weather-cache = Les données météo ne sont peut-être pas à jour.
+
+## Messages d'état
+
+github-not-authenticated = GitHub non authentifié
+
+## Conditions météo
+
+weather-clear = Dégagé
+weather-partly-cloudy = Partiellement nuageux
+weather-cloudy = Nuageux
+weather-fog = Brouillard
+weather-drizzle = Bruine
+weather-rain = Pluie
+weather-heavy-rain = Forte pluie
+weather-snow = Neige
+weather-sleet = Grésil
+weather-thunderstorm = Orage
+
+## Temps relatif
+
+time-seconds = { $count ->
+    [one] { $count } seconde
+   *[other] { $count } secondes
+}
+time-minutes = { $count ->
+    [one] { $count } minute
+   *[other] { $count } minutes
+}
+time-hours = { $count ->
+    [one] { $count } heure
+   *[other] { $count } heures
+}
+time-days = { $count ->
+    [one] { $count } jour
+   *[other] { $count } jours
+}
+time-months = { $count ->
+    [one] { $count } mois
+   *[other] { $count } mois
+}
+time-years = { $count ->
+    [one] { $count } an
+   *[other] { $count } ans
+}
+time-ago = il y a { $duration }
+time-from-now = dans { $duration }
+
+## Dates et heures
+
+date-just-now = à l'instant
+date-minutes-ago = il y a { $count } min
+date-hours-ago = il y a { $count } h
+date-in-minutes = dans { $count } min
+date-today-at = aujourd'hui { $time }
+date-tomorrow-at = demain { $time }
+date-yesterday-at = hier { $time }
+date-weekday-at = { $weekday } { $time }
+date-at = { $date } { $time }
+date-weekday-date = { $weekday } { $date }
+date-day-month = { $day } { $month }
+date-day-month-year = { $day } { $month } { $year }
+date-weekday-short = { $weekday ->
+    [1] lun.
+    [2] mar.
+    [3] mer.
+    [4] jeu.
+    [5] ven.
+    [6] sam.
+   *[7] dim.
+}
+date-month-short = { $month ->
+    [1] janv.
+    [2] févr.
+    [3] mars
+    [4] avr.
+    [5] mai
+    [6] juin
+    [7] juil.
+    [8] août
+    [9] sept.
+    [10] oct.
+    [11] nov.
+   *[12] déc.
+}
@@ -1,24 +1,330 @@
-use anyhow::Result;
-use std::sync::Arc;
+//! Application lifecycle and crash recovery.
+//!
+//! `App::initialize` installs a crash guard: a panic hook that flushes the
+//! state journal (open views, pending sync queues) and writes a crash report
+//! with a backtrace under `<config_dir>/crash/`. The journal is also written
+//! whenever it changes and marked clean on shutdown, so the next start can
+//! tell the user what was interrupted even if the process was killed outright.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
 
 use crate::Config;
 
+/// Crash reports kept on disk; older ones are removed
+const MAX_CRASH_REPORTS: usize = 10;
+
+/// What the app was doing, as recorded in the journal
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalState {
+    /// Unix timestamp the run started
+    #[serde(default)]
+    pub started_at: i64,
+    /// Views open in the UI, most recent last
+    #[serde(default)]
+    pub open_views: Vec<String>,
+    /// Operations queued per service (e.g. "gmail" -> 3), waiting to sync
+    #[serde(default)]
+    pub pending: BTreeMap<String, u32>,
+    /// Set by a normal shutdown
+    #[serde(default)]
+    pub clean_exit: bool,
+}
+
+/// Process-wide state journal, persisted on every change once attached to a file
+pub struct StateJournal {
+    state: Mutex<JournalState>,
+    path: Mutex<Option<PathBuf>>,
+}
+
+impl Default for StateJournal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StateJournal {
+    pub fn new() -> Self {
+        Self { state: Mutex::new(JournalState::default()), path: Mutex::new(None) }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, JournalState> {
+        // Used from the panic hook; a poisoned lock still holds usable state
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Write to `path` from now on, starting a fresh (unclean) run.
+    fn attach(&self, path: &Path) {
+        *self.path.lock().unwrap_or_else(|e| e.into_inner()) = Some(path.to_path_buf());
+        {
+            let mut state = self.lock();
+            state.started_at = chrono::Utc::now().timestamp();
+            state.clean_exit = false;
+        }
+        self.flush_quietly();
+    }
+
+    /// Record the view the user switched to.
+    pub fn set_open_view(&self, view: &str) {
+        {
+            let mut state = self.lock();
+            state.open_views.retain(|v| v != view);
+            state.open_views.push(view.to_string());
+            let excess = state.open_views.len().saturating_sub(5);
+            state.open_views.drain(..excess);
+        }
+        self.flush_quietly();
+    }
+
+    /// Record how many operations `queue` has waiting; 0 removes it.
+    pub fn set_pending(&self, queue: &str, count: u32) {
+        {
+            let mut state = self.lock();
+            let previous = if count == 0 {
+                state.pending.remove(queue)
+            } else {
+                state.pending.insert(queue.to_string(), count)
+            };
+            if previous.unwrap_or(0) == count {
+                return;
+            }
+        }
+        self.flush_quietly();
+    }
+
+    fn mark_clean_exit(&self) {
+        self.lock().clean_exit = true;
+        self.flush_quietly();
+    }
+
+    pub fn snapshot(&self) -> JournalState {
+        self.lock().clone()
+    }
+
+    /// Write the journal to its file (no-op until attached).
+    pub fn flush(&self) -> Result<()> {
+        let Some(path) = self.path.lock().unwrap_or_else(|e| e.into_inner()).clone() else {
+            return Ok(());
+        };
+        let contents = serde_json::to_string_pretty(&self.snapshot())
+            .context("Failed to serialize journal")?;
+        std::fs::write(&path, contents).context("Failed to write state journal")
+    }
+
+    fn flush_quietly(&self) {
+        if let Err(e) = self.flush() {
+            tracing::warn!("{:#}", e);
+        }
+    }
+}
+
+static JOURNAL: OnceLock<StateJournal> = OnceLock::new();
+
+/// Process-wide state journal.
+pub fn journal() -> &'static StateJournal {
+    JOURNAL.get_or_init(StateJournal::new)
+}
+
+/// Written by the panic hook
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrashReport {
+    /// Unix timestamp of the panic
+    pub timestamp: i64,
+    pub version: String,
+    pub thread: Option<String>,
+    pub message: String,
+    /// `file:line:column` of the panic
+    pub location: Option<String>,
+    pub backtrace: String,
+    pub journal: JournalState,
+}
+
+/// What the previous run left behind, offered to the user on start
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RecoveryInfo {
+    /// Most recent crash report not yet dismissed
+    pub crash: Option<CrashReport>,
+    pub report_path: Option<PathBuf>,
+    /// The previous run's journal
+    pub journal: JournalState,
+    /// Previous run ended without a normal shutdown
+    pub unclean_exit: bool,
+}
+
+impl RecoveryInfo {
+    /// One paragraph for the recovery prompt.
+    pub fn summary(&self) -> String {
+        let mut out = match &self.crash {
+            Some(crash) => format!("MyMe stopped unexpectedly: {}.", crash.message),
+            None => "MyMe didn't shut down normally last time.".to_string(),
+        };
+        if let Some(view) = self.journal.open_views.last() {
+            out.push_str(&format!(" You were in {}.", view));
+        }
+        let pending: Vec<String> =
+            self.journal.pending.iter().map(|(queue, n)| format!("{} {}", n, queue)).collect();
+        if !pending.is_empty() {
+            out.push_str(&format!(
+                " Queued operations ({}) were kept and will sync when online.",
+                pending.join(", ")
+            ));
+        }
+        out
+    }
+}
+
+/// Panic hook and crash report storage under `<config_dir>/crash/`
+#[derive(Debug, Clone)]
+pub struct CrashGuard {
+    dir: PathBuf,
+}
+
+impl CrashGuard {
+    pub fn new(config_dir: &Path) -> Self {
+        Self { dir: config_dir.join("crash") }
+    }
+
+    fn journal_path(&self) -> PathBuf {
+        self.dir.join("journal.json")
+    }
+
+    /// Undismissed crash reports, oldest first.
+    fn reports(&self) -> Vec<PathBuf> {
+        let mut reports: Vec<PathBuf> = std::fs::read_dir(&self.dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok().map(|e| e.path()))
+                    .filter(|p| {
+                        p.file_name()
+                            .and_then(|n| n.to_str())
+                            .is_some_and(|n| n.starts_with("crash-") && n.ends_with(".json"))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        // Names embed a zero-padded timestamp, so they sort chronologically
+        reports.sort();
+        reports
+    }
+
+    /// Inspect the previous run. Call before `install`, which starts a new journal.
+    /// Returns `None` after a clean exit with no crash reports.
+    pub fn previous_run(&self) -> Option<RecoveryInfo> {
+        let journal: Option<JournalState> = std::fs::read_to_string(self.journal_path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok());
+        let report_path = self.reports().pop();
+        let crash: Option<CrashReport> = report_path
+            .as_ref()
+            .and_then(|p| std::fs::read_to_string(p).ok())
+            .and_then(|s| serde_json::from_str(&s).ok());
+        let unclean_exit = journal.as_ref().is_some_and(|j| !j.clean_exit);
+
+        if crash.is_none() && !unclean_exit {
+            return None;
+        }
+        Some(RecoveryInfo {
+            // The report's journal is the state at the moment of the panic
+            journal: crash.as_ref().map(|c| c.journal.clone()).or(journal).unwrap_or_default(),
+            crash,
+            report_path,
+            unclean_exit,
+        })
+    }
+
+    /// Start the journal for this run and install the panic hook (chained
+    /// before the existing one, so the default message still prints).
+    pub fn install(&self) -> Result<()> {
+        std::fs::create_dir_all(&self.dir).context("Failed to create crash directory")?;
+        journal().attach(&self.journal_path());
+
+        let guard = self.clone();
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = journal().flush();
+            let message = info
+                .payload()
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| info.payload().downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            let report = CrashReport {
+                timestamp: chrono::Utc::now().timestamp(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                thread: std::thread::current().name().map(str::to_string),
+                message,
+                location: info.location().map(|l| l.to_string()),
+                backtrace: std::backtrace::Backtrace::force_capture().to_string(),
+                journal: journal().snapshot(),
+            };
+            let _ = guard.write_report(&report);
+            previous(info);
+        }));
+        Ok(())
+    }
+
+    /// Write `report` and drop the oldest reports past the limit.
+    pub fn write_report(&self, report: &CrashReport) -> Result<PathBuf> {
+        std::fs::create_dir_all(&self.dir).context("Failed to create crash directory")?;
+        let nanos = chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default();
+        let path = self.dir.join(format!("crash-{:020}.json", nanos));
+        let contents =
+            serde_json::to_string_pretty(report).context("Failed to serialize crash report")?;
+        std::fs::write(&path, contents).context("Failed to write crash report")?;
+
+        let reports = self.reports();
+        for old in reports.iter().take(reports.len().saturating_sub(MAX_CRASH_REPORTS)) {
+            let _ = std::fs::remove_file(old);
+        }
+        Ok(path)
+    }
+
+    /// Mark crash reports as seen so they aren't offered again. They are kept
+    /// (renamed to `.seen`) for bug reports.
+    pub fn dismiss(&self) -> Result<()> {
+        for report in self.reports() {
+            let mut seen = report.clone().into_os_string();
+            seen.push(".seen");
+            std::fs::rename(&report, PathBuf::from(seen))
+                .context("Failed to dismiss crash report")?;
+        }
+        Ok(())
+    }
+
+    /// Record a normal shutdown in the journal.
+    pub fn mark_clean_exit(&self) {
+        journal().mark_clean_exit();
+    }
+}
+
 /// Main application state and lifecycle manager
 pub struct App {
     config: Arc<Config>,
+    crash_guard: CrashGuard,
+    recovery: Option<RecoveryInfo>,
 }
 
 impl App {
     /// Create a new application instance
     pub fn new() -> Result<Self> {
         let config = Config::load()?;
+        let crash_guard = CrashGuard::new(&config.config_dir);
         let config = Arc::new(config);
 
-        Ok(Self { config })
+        Ok(Self { config, crash_guard, recovery: None })
     }
 
-    /// Initialize the application
+    /// Initialize the application: pick up what the previous run left behind,
+    /// then install the crash guard for this one.
     pub fn initialize(&mut self) -> Result<()> {
+        self.recovery = self.crash_guard.previous_run();
+        if let Some(recovery) = &self.recovery {
+            tracing::warn!("Previous run did not exit cleanly: {}", recovery.summary());
+        }
+        self.crash_guard.install()?;
         tracing::info!("Application initialized successfully");
         Ok(())
     }
@@ -26,6 +332,7 @@ impl App {
     /// Shutdown the application
     pub fn shutdown(&mut self) -> Result<()> {
         tracing::info!("Shutting down application");
+        self.crash_guard.mark_clean_exit();
         Ok(())
     }
 
@@ -33,6 +340,17 @@ impl App {
     pub fn config(&self) -> &Config {
         &self.config
     }
+
+    /// Crash or unclean-exit details from the previous run, if any
+    pub fn recovery(&self) -> Option<&RecoveryInfo> {
+        self.recovery.as_ref()
+    }
+
+    /// The user has seen the recovery info; don't offer it again.
+    pub fn dismiss_recovery(&mut self) -> Result<()> {
+        self.recovery = None;
+        self.crash_guard.dismiss()
+    }
 }
 
 impl Default for App {
@@ -42,3 +360,85 @@ impl Default for App {
         Self::new().expect("Failed to create default App")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn report(message: &str, journal: JournalState) -> CrashReport {
+        CrashReport {
+            timestamp: 1,
+            version: "test".into(),
+            thread: Some("main".into()),
+            message: message.into(),
+            location: Some("src/main.rs:1:1".into()),
+            backtrace: String::new(),
+            journal,
+        }
+    }
+
+    #[test]
+    fn test_journal_tracks_views_and_pending() {
+        let journal = StateJournal::new();
+        for view in ["NotePage", "GmailPage", "NotePage"] {
+            journal.set_open_view(view);
+        }
+        journal.set_pending("gmail", 2);
+        journal.set_pending("calendar", 0);
+        let state = journal.snapshot();
+        assert_eq!(state.open_views, ["GmailPage", "NotePage"]);
+        assert_eq!(state.pending.get("gmail"), Some(&2));
+        assert!(!state.pending.contains_key("calendar"));
+    }
+
+    #[test]
+    fn test_previous_run_reports_crash_until_dismissed() {
+        let dir = tempfile::tempdir().unwrap();
+        let guard = CrashGuard::new(dir.path());
+        assert!(guard.previous_run().is_none());
+
+        let mut journal =
+            JournalState { open_views: vec!["GmailPage".into()], ..Default::default() };
+        journal.pending.insert("gmail".into(), 3);
+        guard.write_report(&report("index out of bounds", journal)).unwrap();
+
+        let recovery = guard.previous_run().unwrap();
+        assert_eq!(recovery.crash.as_ref().unwrap().message, "index out of bounds");
+        let summary = recovery.summary();
+        assert!(summary.contains("GmailPage"));
+        assert!(summary.contains("3 gmail"));
+
+        guard.dismiss().unwrap();
+        assert!(guard.previous_run().is_none());
+    }
+
+    #[test]
+    fn test_unclean_exit_without_crash_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let guard = CrashGuard::new(dir.path());
+        std::fs::create_dir_all(guard.dir.clone()).unwrap();
+        let journal = JournalState { started_at: 5, ..Default::default() };
+        std::fs::write(guard.journal_path(), serde_json::to_string(&journal).unwrap()).unwrap();
+
+        let recovery = guard.previous_run().unwrap();
+        assert!(recovery.unclean_exit);
+        assert!(recovery.crash.is_none());
+
+        let clean = JournalState { clean_exit: true, ..journal };
+        std::fs::write(guard.journal_path(), serde_json::to_string(&clean).unwrap()).unwrap();
+        assert!(guard.previous_run().is_none());
+    }
+
+    #[test]
+    fn test_old_reports_are_pruned() {
+        let dir = tempfile::tempdir().unwrap();
+        let guard = CrashGuard::new(dir.path());
+        for i in 0..MAX_CRASH_REPORTS + 2 {
+            guard.write_report(&report(&format!("panic {}", i), JournalState::default())).unwrap();
+        }
+        assert_eq!(guard.reports().len(), MAX_CRASH_REPORTS);
+        let latest = guard.previous_run().unwrap().crash.unwrap();
+        assert_eq!(latest.message, format!("panic {}", MAX_CRASH_REPORTS + 1));
+    }
+}
@@ -0,0 +1,357 @@
+//! Layered configuration.
+//!
+//! `config.toml` is the base. An optional `config.<hostname>.toml` next to it
+//! and `MYME__SECTION__KEY=value` environment variables are merged on top, in
+//! that order, so one dotfiles-managed config can carry per-machine values
+//! (repo paths, window size). [`Provenance`] remembers which layer set each
+//! value: validation messages name it, and saving writes overridden values
+//! back as they were in the base file instead of baking the override in.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use toml::{Table, Value};
+
+/// Prefix for override variables; `__` separates the key path
+pub const ENV_PREFIX: &str = "MYME__";
+
+/// Where a config value came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigSource {
+    /// `config.toml`
+    Base,
+    /// Per-machine file, by file name
+    Host(String),
+    /// Environment variable, by name
+    Env(String),
+}
+
+impl std::fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigSource::Base => write!(f, "config.toml"),
+            ConfigSource::Host(file) => write!(f, "{}", file),
+            ConfigSource::Env(var) => write!(f, "${}", var),
+        }
+    }
+}
+
+/// One layer of settings and where it came from
+#[derive(Debug, Clone)]
+pub struct Layer {
+    pub source: ConfigSource,
+    pub table: Table,
+}
+
+/// A value an override layer replaced, kept so `save` can restore it
+#[derive(Debug, Clone)]
+struct Shadowed {
+    path: Vec<String>,
+    /// Value in `config.toml`; None when only the override sets it
+    base: Option<Value>,
+    /// Value the override applied
+    applied: Value,
+}
+
+/// Which layer set each value of a loaded config
+#[derive(Debug, Clone, Default)]
+pub struct Provenance {
+    /// Dotted key path -> layer that set it last
+    sources: BTreeMap<String, ConfigSource>,
+    shadowed: Vec<Shadowed>,
+}
+
+impl Provenance {
+    /// Layer that set `field` (a dotted path like `repos.local_search_path`)
+    pub fn source(&self, field: &str) -> Option<&ConfigSource> {
+        self.sources.get(field)
+    }
+
+    /// Override layer behind `field`: the field itself, a parent table, or
+    /// failing that any key under it. None when it all comes from `config.toml`.
+    pub fn override_for(&self, field: &str) -> Option<&ConfigSource> {
+        let is_override = |s: &&ConfigSource| **s != ConfigSource::Base;
+        let mut path = field;
+        loop {
+            if let Some(source) = self.sources.get(path).filter(is_override) {
+                return Some(source);
+            }
+            match path.rsplit_once('.') {
+                Some((parent, _)) => path = parent,
+                None => break,
+            }
+        }
+        let prefix = format!("{}.", field);
+        self.sources
+            .range(prefix.clone()..)
+            .take_while(|(key, _)| key.starts_with(&prefix))
+            .map(|(_, source)| source)
+            .find(is_override)
+    }
+
+    /// Values set by the host file or environment, as (dotted path, source)
+    pub fn overrides(&self) -> impl Iterator<Item = (&str, &ConfigSource)> {
+        self.sources
+            .iter()
+            .filter(|(_, source)| **source != ConfigSource::Base)
+            .map(|(key, source)| (key.as_str(), source))
+    }
+
+    pub fn has_overrides(&self) -> bool {
+        !self.shadowed.is_empty()
+    }
+
+    /// Undo overrides in a serialized config before it is written to
+    /// `config.toml`. Values changed since loading are kept as changed; keys
+    /// only an override set go back to their value in `defaults` (or are
+    /// dropped if it has none).
+    pub fn restore_base(&self, table: &mut Table, defaults: &Table) {
+        for shadowed in &self.shadowed {
+            if lookup(table, &shadowed.path) != Some(&shadowed.applied) {
+                continue;
+            }
+            match shadowed.base.as_ref().or_else(|| lookup(defaults, &shadowed.path)) {
+                Some(base) => set(table, &shadowed.path, base.clone()),
+                None => remove(table, &shadowed.path),
+            }
+        }
+    }
+}
+
+/// Short host name (before the first dot, lowercased) for `config.<host>.toml`
+pub fn hostname() -> Option<String> {
+    let raw = std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .ok()
+        .filter(|h| !h.trim().is_empty())
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok())?;
+    let host = raw.trim().split('.').next().unwrap_or_default().to_lowercase();
+    (!host.is_empty()).then_some(host)
+}
+
+/// File name of the per-machine layer for `host`
+pub fn host_file_name(host: &str) -> String {
+    format!("config.{}.toml", host)
+}
+
+/// Read a TOML layer; None when the file doesn't exist
+pub fn read_layer(path: &Path, source: ConfigSource) -> Result<Option<Layer>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let table: Table =
+        toml::from_str(&contents).with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(Some(Layer { source, table }))
+}
+
+/// Layer from `MYME__SECTION__KEY=value` variables. Values are read as TOML
+/// (`true`, `15`, `["a"]`) when they parse, otherwise as strings.
+pub fn env_layer(vars: impl IntoIterator<Item = (String, String)>) -> Vec<Layer> {
+    let mut vars: Vec<(String, String)> =
+        vars.into_iter().filter(|(name, _)| name.starts_with(ENV_PREFIX)).collect();
+    vars.sort();
+    vars.into_iter()
+        .filter_map(|(name, raw)| {
+            let path: Vec<String> =
+                name[ENV_PREFIX.len()..].split("__").map(str::to_lowercase).collect();
+            if path.iter().any(String::is_empty) {
+                return None;
+            }
+            let mut table = Table::new();
+            set(&mut table, &path, parse_env_value(&raw));
+            Some(Layer { source: ConfigSource::Env(name), table })
+        })
+        .collect()
+}
+
+fn parse_env_value(raw: &str) -> Value {
+    toml::from_str::<Table>(&format!("v = {}", raw))
+        .ok()
+        .and_then(|mut t| t.remove("v"))
+        .unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+/// Merge override layers onto the base, later layers winning. Tables merge
+/// key by key; any other value replaces what was there.
+pub fn merge(base: Layer, overrides: Vec<Layer>) -> (Table, Provenance) {
+    let mut provenance = Provenance::default();
+    record_leaves(&base.table, &mut Vec::new(), &base.source, &mut provenance.sources);
+    let original = base.table.clone();
+    let mut merged = base.table;
+    let mut touched: Vec<Vec<String>> = Vec::new();
+
+    for layer in overrides {
+        let mut leaves = BTreeMap::new();
+        record_leaves(&layer.table, &mut Vec::new(), &layer.source, &mut leaves);
+        collect_paths(&layer.table, &mut Vec::new(), &mut touched);
+        provenance.sources.extend(leaves);
+        merge_tables(&mut merged, layer.table);
+    }
+
+    touched.sort();
+    touched.dedup();
+    provenance.shadowed = touched
+        .into_iter()
+        .filter_map(|path| {
+            let applied = lookup(&merged, &path)?.clone();
+            Some(Shadowed { base: lookup(&original, &path).cloned(), path, applied })
+        })
+        .collect();
+    (merged, provenance)
+}
+
+fn merge_tables(into: &mut Table, from: Table) {
+    for (key, value) in from {
+        match (into.get_mut(&key), value) {
+            (Some(Value::Table(existing)), Value::Table(incoming)) => {
+                merge_tables(existing, incoming)
+            }
+            (_, value) => {
+                into.insert(key, value);
+            }
+        }
+    }
+}
+
+fn record_leaves(
+    table: &Table,
+    path: &mut Vec<String>,
+    source: &ConfigSource,
+    out: &mut BTreeMap<String, ConfigSource>,
+) {
+    for (key, value) in table {
+        path.push(key.clone());
+        match value {
+            Value::Table(child) => record_leaves(child, path, source, out),
+            _ => {
+                out.insert(path.join("."), source.clone());
+            }
+        }
+        path.pop();
+    }
+}
+
+fn collect_paths(table: &Table, path: &mut Vec<String>, out: &mut Vec<Vec<String>>) {
+    for (key, value) in table {
+        path.push(key.clone());
+        match value {
+            Value::Table(child) => collect_paths(child, path, out),
+            _ => out.push(path.clone()),
+        }
+        path.pop();
+    }
+}
+
+fn lookup<'a>(table: &'a Table, path: &[String]) -> Option<&'a Value> {
+    let (last, parents) = path.split_last()?;
+    let mut current = table;
+    for key in parents {
+        current = current.get(key)?.as_table()?;
+    }
+    current.get(last)
+}
+
+fn set(table: &mut Table, path: &[String], value: Value) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut current = table;
+    for key in parents {
+        let entry = current.entry(key.clone()).or_insert_with(|| Value::Table(Table::new()));
+        if !entry.is_table() {
+            *entry = Value::Table(Table::new());
+        }
+        let Value::Table(child) = entry else {
+            return;
+        };
+        current = child;
+    }
+    current.insert(last.clone(), value);
+}
+
+fn remove(table: &mut Table, path: &[String]) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut current = table;
+    for key in parents {
+        match current.get_mut(key) {
+            Some(Value::Table(child)) => current = child,
+            _ => return,
+        }
+    }
+    current.remove(last);
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn layer(source: ConfigSource, toml: &str) -> Layer {
+        Layer { source, table: toml::from_str(toml).unwrap() }
+    }
+
+    #[test]
+    fn later_layers_win_and_are_tracked() {
+        let base = layer(
+            ConfigSource::Base,
+            "[repos]\nlocal_search_path = \"/home/me/dev\"\n[ui]\nwindow_width = 1200\n",
+        );
+        let host = layer(
+            ConfigSource::Host("config.laptop.toml".into()),
+            "[repos]\nlocal_search_path = \"/Users/me/src\"\n",
+        );
+        let env = env_layer([
+            ("MYME__UI__WINDOW_WIDTH".to_string(), "1600".to_string()),
+            ("MYME__NOTES__SYNC_DIR".to_string(), "/srv/sync".to_string()),
+            ("MYME__".to_string(), "ignored".to_string()),
+            ("PATH".to_string(), "/bin".to_string()),
+        ]);
+        assert_eq!(env.len(), 2);
+
+        let mut layers = vec![host];
+        layers.extend(env);
+        let (merged, provenance) = merge(base, layers);
+        assert_eq!(merged["repos"]["local_search_path"].as_str(), Some("/Users/me/src"));
+        assert_eq!(merged["ui"]["window_width"].as_integer(), Some(1600));
+        assert_eq!(merged["notes"]["sync_dir"].as_str(), Some("/srv/sync"));
+
+        assert_eq!(
+            provenance.source("repos.local_search_path"),
+            Some(&ConfigSource::Host("config.laptop.toml".into()))
+        );
+        assert_eq!(
+            provenance.override_for("ui").map(|s| s.to_string()).as_deref(),
+            Some("$MYME__UI__WINDOW_WIDTH")
+        );
+        assert_eq!(provenance.override_for("github"), None);
+        assert_eq!(provenance.overrides().count(), 3);
+    }
+
+    #[test]
+    fn restore_base_keeps_user_edits() {
+        let base = layer(ConfigSource::Base, "[ui]\nwindow_width = 1200\nwindow_height = 800\n");
+        let host = layer(
+            ConfigSource::Host("config.desk.toml".into()),
+            "[ui]\nwindow_width = 2400\nwindow_height = 1400\n[debug]\nhttp_capture = true\n",
+        );
+        let (mut merged, provenance) = merge(base, vec![host]);
+        assert!(provenance.has_overrides());
+
+        // The user changed the height in the app; the width is untouched
+        merged["ui"].as_table_mut().unwrap().insert("window_height".into(), Value::Integer(900));
+        let defaults: Table = toml::from_str("[debug]\nhttp_capture = false\n").unwrap();
+        provenance.restore_base(&mut merged, &defaults);
+        assert_eq!(merged["ui"]["window_width"].as_integer(), Some(1200));
+        assert_eq!(merged["ui"]["window_height"].as_integer(), Some(900));
+        assert_eq!(merged["debug"]["http_capture"].as_bool(), Some(false));
+
+        merged.remove("debug");
+        provenance.restore_base(&mut merged, &Table::new());
+        assert!(!merged.contains_key("debug"));
+    }
+}
@@ -0,0 +1,286 @@
+//! Secrets kept out of `config.toml`.
+//!
+//! Fields in [`SECRET_KEYS`] are stored in the config file as a reference
+//! (`"keyring:config.github.client_secret"`) and the value lives in a
+//! [`SecretStore`] (the system keyring; `myme_auth::SecureStorage` implements
+//! it). Loading resolves references; saving moves any plaintext value into the
+//! store and writes the reference instead. Plaintext secrets found in an
+//! existing `config.toml` are migrated once, on load, with a warning.
+//!
+//! Without an installed store (tests, or a keyring-less session) references
+//! resolve to empty values and plaintext is left where it is.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use anyhow::Result;
+use toml::{Table, Value};
+
+/// Prefix marking a value as a reference into the secret store
+pub const SECRET_PREFIX: &str = "keyring:";
+
+/// Config keys that hold secrets; `*` matches any key of that table
+pub const SECRET_KEYS: &[&str] = &[
+    "github.client_secret",
+    "google.client_secret",
+    "weather.openweathermap_api_key",
+    "proxy.password",
+    "proxy.services.*.password",
+];
+
+/// Where secret values are kept
+pub trait SecretStore: Send + Sync {
+    fn get(&self, name: &str) -> Result<String>;
+    fn set(&self, name: &str, value: &str) -> Result<()>;
+}
+
+static STORE: OnceLock<Box<dyn SecretStore>> = OnceLock::new();
+
+/// Install the store used to resolve and save secrets. Call before the config
+/// is first loaded. Returns false if one was already installed.
+pub fn install(store: Box<dyn SecretStore>) -> bool {
+    STORE.set(store).is_ok()
+}
+
+/// The installed store, if any
+pub fn store() -> Option<&'static dyn SecretStore> {
+    STORE.get().map(|s| s.as_ref())
+}
+
+/// Store entry name for a config key, e.g. `config.github.client_secret`
+pub fn entry_name(key: &str) -> String {
+    format!("config.{}", key)
+}
+
+/// Entry name referenced by `value`, if it is a reference
+pub fn reference_target(value: &str) -> Option<&str> {
+    value.strip_prefix(SECRET_PREFIX).map(str::trim).filter(|name| !name.is_empty())
+}
+
+/// True for values not worth protecting: empty or the default placeholders
+fn is_placeholder(value: &str) -> bool {
+    let value = value.trim();
+    value.is_empty() || value.starts_with("YOUR_")
+}
+
+/// A secret resolved at load time
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Resolved {
+    /// Reference as written in the config file
+    reference: String,
+    /// Value read from the store; None when it couldn't be read
+    value: Option<String>,
+}
+
+/// References found when loading, so saving can write them back unchanged
+#[derive(Debug, Clone, Default)]
+pub struct SecretRefs {
+    /// Dotted key -> reference and resolved value
+    resolved: BTreeMap<String, Resolved>,
+}
+
+impl SecretRefs {
+    /// Keys whose reference couldn't be read from the store
+    pub fn unresolved(&self) -> impl Iterator<Item = &str> {
+        self.resolved.iter().filter(|(_, r)| r.value.is_none()).map(|(k, _)| k.as_str())
+    }
+}
+
+/// Dotted keys in `table` matching [`SECRET_KEYS`] (wildcards expanded)
+pub fn secret_paths(table: &Table) -> Vec<String> {
+    let mut paths = Vec::new();
+    for pattern in SECRET_KEYS {
+        expand(table, &pattern.split('.').collect::<Vec<_>>(), String::new(), &mut paths);
+    }
+    paths
+}
+
+fn expand(table: &Table, parts: &[&str], prefix: String, out: &mut Vec<String>) {
+    let Some((first, rest)) = parts.split_first() else {
+        return;
+    };
+    let join =
+        |key: &str| if prefix.is_empty() { key.to_string() } else { format!("{prefix}.{key}") };
+    if rest.is_empty() {
+        if table.get(*first).is_some_and(Value::is_str) {
+            out.push(join(first));
+        }
+        return;
+    }
+    let keys: Vec<&String> = match *first {
+        "*" => table.keys().collect(),
+        key => table.keys().filter(|k| k.as_str() == key).collect(),
+    };
+    for key in keys {
+        if let Some(inner) = table.get(key).and_then(Value::as_table) {
+            expand(inner, rest, join(key), out);
+        }
+    }
+}
+
+fn get_str<'a>(table: &'a Table, key: &str) -> Option<&'a str> {
+    let mut parts = key.split('.').peekable();
+    let mut table = table;
+    while let Some(part) = parts.next() {
+        let value = table.get(part)?;
+        if parts.peek().is_none() {
+            return value.as_str();
+        }
+        table = value.as_table()?;
+    }
+    None
+}
+
+fn set_str(table: &mut Table, key: &str, value: String) {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    let Some(name) = parts.pop() else {
+        return;
+    };
+    let mut table = table;
+    for part in parts {
+        let Some(inner) = table.get_mut(part).and_then(Value::as_table_mut) else {
+            return;
+        };
+        table = inner;
+    }
+    table.insert(name.to_string(), Value::String(value));
+}
+
+/// Replace references in `table` with their values from `store`. A reference
+/// that can't be read becomes an empty string.
+pub fn resolve(table: &mut Table, store: Option<&dyn SecretStore>) -> SecretRefs {
+    let mut refs = SecretRefs::default();
+    for key in secret_paths(table) {
+        let Some(reference) = get_str(table, &key).map(str::to_string) else {
+            continue;
+        };
+        let Some(name) = reference_target(&reference) else {
+            continue;
+        };
+        let value = match store.map(|s| s.get(name)) {
+            Some(Ok(value)) => Some(value),
+            Some(Err(e)) => {
+                tracing::warn!("Couldn't read secret for {} from the keyring: {:#}", key, e);
+                None
+            }
+            None => {
+                tracing::warn!("No keyring available for {}; leaving it empty", key);
+                None
+            }
+        };
+        set_str(table, &key, value.clone().unwrap_or_default());
+        refs.resolved.insert(key, Resolved { reference, value });
+    }
+    refs
+}
+
+/// Prepare `table` for writing: secrets loaded from references get their
+/// reference back, and new plaintext secrets are moved into `store`.
+/// Returns the keys moved. Without a store plaintext stays as it is.
+pub fn protect(
+    table: &mut Table,
+    refs: &SecretRefs,
+    store: Option<&dyn SecretStore>,
+) -> Result<Vec<String>> {
+    let mut moved = Vec::new();
+    for key in secret_paths(table) {
+        let Some(value) = get_str(table, &key).map(str::to_string) else {
+            continue;
+        };
+        if reference_target(&value).is_some() {
+            continue;
+        }
+        if let Some(loaded) = refs.resolved.get(&key) {
+            let unchanged = match &loaded.value {
+                Some(resolved) => *resolved == value,
+                None => value.is_empty(),
+            };
+            if unchanged {
+                set_str(table, &key, loaded.reference.clone());
+                continue;
+            }
+        }
+        if is_placeholder(&value) {
+            continue;
+        }
+        let Some(store) = store else {
+            tracing::warn!("No keyring available; {} stays in config.toml", key);
+            continue;
+        };
+        let name = entry_name(&key);
+        store.set(&name, &value)?;
+        set_str(table, &key, format!("{}{}", SECRET_PREFIX, name));
+        moved.push(key);
+    }
+    Ok(moved)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryStore(Mutex<BTreeMap<String, String>>);
+
+    impl SecretStore for MemoryStore {
+        fn get(&self, name: &str) -> Result<String> {
+            let secrets = self.0.lock().unwrap();
+            secrets.get(name).cloned().ok_or_else(|| anyhow::anyhow!("no entry {}", name))
+        }
+
+        fn set(&self, name: &str, value: &str) -> Result<()> {
+            self.0.lock().unwrap().insert(name.to_string(), value.to_string());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn migrate_resolve_and_save_round_trip() {
+        let store = MemoryStore::default();
+        let mut table: Table = toml::from_str(
+            r#"
+            [github]
+            client_id = "abc"
+            client_secret = "gh-secret"
+            [google]
+            client_secret = "YOUR_GOOGLE_SECRET"
+            [proxy.services.github]
+            password = "hunter2"
+            "#,
+        )
+        .unwrap();
+
+        // First load: plaintext moves to the store, placeholders stay
+        let moved = protect(&mut table, &SecretRefs::default(), Some(&store)).unwrap();
+        assert_eq!(moved, vec!["github.client_secret", "proxy.services.github.password"]);
+        assert_eq!(
+            get_str(&table, "github.client_secret"),
+            Some("keyring:config.github.client_secret")
+        );
+        assert_eq!(get_str(&table, "google.client_secret"), Some("YOUR_GOOGLE_SECRET"));
+        assert_eq!(store.get("config.proxy.services.github.password").unwrap(), "hunter2");
+        let written = table.clone();
+
+        // Loading resolves; saving unchanged values writes the references back
+        let refs = resolve(&mut table, Some(&store));
+        assert_eq!(get_str(&table, "github.client_secret"), Some("gh-secret"));
+        let mut saved = table.clone();
+        assert!(protect(&mut saved, &refs, Some(&store)).unwrap().is_empty());
+        assert_eq!(saved, written);
+
+        // A changed secret is stored again
+        set_str(&mut table, "github.client_secret", "rotated".to_string());
+        protect(&mut table, &refs, Some(&store)).unwrap();
+        assert_eq!(store.get("config.github.client_secret").unwrap(), "rotated");
+
+        // Without a store references resolve empty and survive a save
+        let mut table = written.clone();
+        let refs = resolve(&mut table, None);
+        assert_eq!(get_str(&table, "github.client_secret"), Some(""));
+        assert_eq!(refs.unresolved().count(), 2);
+        protect(&mut table, &refs, None).unwrap();
+        assert_eq!(table, written);
+    }
+}
@@ -0,0 +1,211 @@
+//! Network connectivity monitor.
+//!
+//! Tracks whether the machine is online with a periodic lightweight HTTP probe,
+//! probing early when the OS reports a network change (interfaces going up or
+//! down) or when a service hits a connection failure. Transitions are broadcast
+//! so services can skip doomed requests while offline and flush queued work
+//! when the connection comes back.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use tokio::sync::{broadcast, Notify};
+
+/// Endpoint that answers 204 with an empty body; any HTTP response counts as online
+pub const DEFAULT_PROBE_URL: &str = "https://www.gstatic.com/generate_204";
+/// Time between probes while nothing changes
+pub const DEFAULT_PROBE_INTERVAL: Duration = Duration::from_secs(30);
+/// Time between checks of the OS network state
+pub const DEFAULT_HINT_INTERVAL: Duration = Duration::from_secs(5);
+/// Probe request timeout
+pub const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Error detail for requests skipped because we are offline
+pub const OFFLINE_ERROR: &str = "No network connection";
+
+/// Online/offline state shared by every service
+pub struct Connectivity {
+    online: AtomicBool,
+    tx: broadcast::Sender<bool>,
+    probe_now: Notify,
+}
+
+impl Default for Connectivity {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Connectivity {
+    /// Starts out online so nothing is skipped before the first probe.
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(16);
+        Self { online: AtomicBool::new(true), tx, probe_now: Notify::new() }
+    }
+
+    pub fn is_online(&self) -> bool {
+        self.online.load(Ordering::SeqCst)
+    }
+
+    /// Receive `true` when going online and `false` when going offline.
+    pub fn subscribe(&self) -> broadcast::Receiver<bool> {
+        self.tx.subscribe()
+    }
+
+    /// Update the state; returns true (and broadcasts) only on a transition.
+    pub fn set_online(&self, online: bool) -> bool {
+        if self.online.swap(online, Ordering::SeqCst) == online {
+            return false;
+        }
+        tracing::info!("Connectivity changed: {}", if online { "online" } else { "offline" });
+        let _ = self.tx.send(online);
+        true
+    }
+
+    /// Ask the monitor to probe as soon as possible, e.g. after a connection error.
+    pub fn request_probe(&self) {
+        self.probe_now.notify_one();
+    }
+}
+
+/// Probe settings for [`spawn_monitor`]
+#[derive(Debug, Clone)]
+pub struct MonitorConfig {
+    pub probe_url: String,
+    pub probe_interval: Duration,
+    pub hint_interval: Duration,
+    /// Proxies for the probe request, so corporate networks aren't reported offline
+    pub proxies: Vec<reqwest::Proxy>,
+}
+
+impl Default for MonitorConfig {
+    fn default() -> Self {
+        Self {
+            probe_url: DEFAULT_PROBE_URL.to_string(),
+            probe_interval: DEFAULT_PROBE_INTERVAL,
+            hint_interval: DEFAULT_HINT_INTERVAL,
+            proxies: Vec::new(),
+        }
+    }
+}
+
+/// Whether `url` answers at all. HTTP error statuses still mean we reached the network.
+pub async fn probe(client: &reqwest::Client, url: &str) -> bool {
+    match client.head(url).timeout(PROBE_TIMEOUT).send().await {
+        Ok(_) => true,
+        Err(e) => {
+            tracing::debug!("Connectivity probe failed: {}", e);
+            false
+        }
+    }
+}
+
+/// Cheap fingerprint of the OS network state; changes when interfaces go up or down.
+///
+/// Only available on Linux (from `/sys/class/net`); elsewhere the periodic probe
+/// and failure hints are relied on instead.
+pub fn network_signature() -> Option<String> {
+    #[cfg(target_os = "linux")]
+    {
+        let mut states: Vec<String> = std::fs::read_dir("/sys/class/net")
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name() != "lo")
+            .map(|entry| {
+                let state =
+                    std::fs::read_to_string(entry.path().join("operstate")).unwrap_or_default();
+                format!("{}={}", entry.file_name().to_string_lossy(), state.trim())
+            })
+            .collect();
+        states.sort();
+        Some(states.join(","))
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Run the probe loop on `handle` until `shutdown` fires.
+pub fn spawn_monitor(
+    handle: &tokio::runtime::Handle,
+    monitor: &'static Connectivity,
+    config: MonitorConfig,
+    mut shutdown: broadcast::Receiver<()>,
+) -> tokio::task::JoinHandle<()> {
+    handle.spawn(async move {
+        let builder = config
+            .proxies
+            .into_iter()
+            .fold(reqwest::Client::builder().timeout(PROBE_TIMEOUT), reqwest::ClientBuilder::proxy);
+        let client = match builder.build() {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::warn!("Connectivity monitor disabled: {}", e);
+                return;
+            }
+        };
+
+        let mut signature = network_signature();
+        let mut probe_timer = tokio::time::interval(config.probe_interval);
+        let mut hint_timer = tokio::time::interval(config.hint_interval);
+        loop {
+            tokio::select! {
+                _ = shutdown.recv() => break,
+                _ = probe_timer.tick() => {}
+                _ = monitor.probe_now.notified() => {}
+                _ = hint_timer.tick() => {
+                    let current = network_signature();
+                    if current == signature {
+                        continue;
+                    }
+                    tracing::debug!("Network interfaces changed, probing");
+                    signature = current;
+                }
+            }
+            monitor.set_online(probe(&client, &config.probe_url).await);
+        }
+        tracing::debug!("Connectivity monitor stopped");
+    })
+}
+
+static GLOBAL: OnceLock<Connectivity> = OnceLock::new();
+
+/// Process-wide connectivity state.
+pub fn global() -> &'static Connectivity {
+    GLOBAL.get_or_init(Connectivity::new)
+}
+
+/// Whether the process-wide monitor currently considers us online.
+pub fn is_online() -> bool {
+    global().is_online()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_transitions_broadcast_once() {
+        let monitor = Connectivity::new();
+        let mut rx = monitor.subscribe();
+        assert!(monitor.is_online());
+
+        assert!(!monitor.set_online(true));
+        assert!(monitor.set_online(false));
+        assert!(!monitor.set_online(false));
+        assert!(!monitor.is_online());
+        assert!(monitor.set_online(true));
+
+        assert!(!rx.try_recv().unwrap());
+        assert!(rx.try_recv().unwrap());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_probe_unreachable_is_offline() {
+        let client = reqwest::Client::new();
+        assert!(!probe(&client, "http://127.0.0.1:9/").await);
+    }
+}
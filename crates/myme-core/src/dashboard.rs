@@ -0,0 +1,278 @@
+//! Snapshot of what the dashboard last showed.
+//!
+//! Weather, upcoming events, top notes and recent repo activity are kept as
+//! the models receive them and written as compact JSON next to `config.toml`
+//! on shutdown. The next start reads the file before any service is up, so
+//! the dashboard paints at once with slightly stale data until live data
+//! replaces each part.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Events kept, soonest first
+pub const MAX_EVENTS: usize = 10;
+
+/// Notes kept, in list order (pinned first)
+pub const MAX_NOTES: usize = 5;
+
+/// Commits kept, newest first
+pub const MAX_COMMITS: usize = 8;
+
+/// Current weather as the dashboard widget shows it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeatherSnapshot {
+    pub temperature: f64,
+    pub feels_like: f64,
+    pub humidity: i32,
+    pub wind_speed: f64,
+    /// Condition in the display language ("Light Rain")
+    pub condition: String,
+    pub icon: String,
+    pub location: String,
+    pub high: f64,
+    pub low: f64,
+    pub precipitation_chance: i32,
+    /// "06:12"
+    pub sunrise: String,
+    pub sunset: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventSnapshot {
+    pub summary: String,
+    pub start: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteSnapshot {
+    pub id: i64,
+    pub title: String,
+    #[serde(default)]
+    pub pinned: bool,
+}
+
+/// A recent commit in one of the local repos
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitSnapshot {
+    pub repo: String,
+    pub message: String,
+    pub author: String,
+    pub time: DateTime<Utc>,
+}
+
+/// Dashboard data persisted between runs
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DashboardSnapshot {
+    /// When any part last changed
+    #[serde(default)]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub weather: Option<WeatherSnapshot>,
+
+    /// Events from the start of the day they were recorded, soonest first
+    #[serde(default)]
+    pub events: Vec<EventSnapshot>,
+
+    #[serde(default)]
+    pub notes: Vec<NoteSnapshot>,
+
+    #[serde(default)]
+    pub commits: Vec<CommitSnapshot>,
+}
+
+impl DashboardSnapshot {
+    /// Whether there's anything to show
+    pub fn is_empty(&self) -> bool {
+        self.weather.is_none()
+            && self.events.is_empty()
+            && self.notes.is_empty()
+            && self.commits.is_empty()
+    }
+
+    /// Events starting on `date` on the local clock
+    pub fn events_on(&self, date: NaiveDate) -> impl Iterator<Item = &EventSnapshot> {
+        self.events.iter().filter(move |e| e.start.with_timezone(&Local).date_naive() == date)
+    }
+
+    /// The first event starting after `now`
+    pub fn next_event(&self, now: DateTime<Utc>) -> Option<&EventSnapshot> {
+        self.events.iter().filter(|e| e.start > now).min_by_key(|e| e.start)
+    }
+}
+
+/// JSON-file backed store for [`DashboardSnapshot`]
+#[derive(Debug, Clone)]
+pub struct DashboardStore {
+    path: PathBuf,
+    snapshot: DashboardSnapshot,
+    dirty: bool,
+}
+
+impl DashboardStore {
+    /// Create a store rooted in the given config directory (not loaded yet)
+    pub fn new(config_dir: &Path) -> Self {
+        Self {
+            path: config_dir.join("dashboard.json"),
+            snapshot: DashboardSnapshot::default(),
+            dirty: false,
+        }
+    }
+
+    /// Load the snapshot from disk. A missing file leaves it empty.
+    pub fn load(&mut self) -> Result<()> {
+        if !self.path.exists() {
+            return Ok(());
+        }
+        let contents =
+            std::fs::read_to_string(&self.path).context("Failed to read dashboard snapshot")?;
+        self.snapshot =
+            serde_json::from_str(&contents).context("Failed to parse dashboard snapshot")?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Write the snapshot to disk if anything changed since the last load/save
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create snapshot directory")?;
+        }
+        let contents = serde_json::to_string(&self.snapshot)
+            .context("Failed to serialize dashboard snapshot")?;
+        std::fs::write(&self.path, contents).context("Failed to write dashboard snapshot")?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Current snapshot
+    pub fn snapshot(&self) -> &DashboardSnapshot {
+        &self.snapshot
+    }
+
+    pub fn set_weather(&mut self, weather: WeatherSnapshot) {
+        let weather = Some(weather);
+        if self.snapshot.weather != weather {
+            self.snapshot.weather = weather;
+            self.touch();
+        }
+    }
+
+    /// Replace the events; keeps the [`MAX_EVENTS`] soonest
+    pub fn set_events(&mut self, mut events: Vec<EventSnapshot>) {
+        events.sort_by_key(|e| e.start);
+        events.truncate(MAX_EVENTS);
+        if self.snapshot.events != events {
+            self.snapshot.events = events;
+            self.touch();
+        }
+    }
+
+    /// Replace the notes; keeps the first [`MAX_NOTES`]
+    pub fn set_notes(&mut self, mut notes: Vec<NoteSnapshot>) {
+        notes.truncate(MAX_NOTES);
+        if self.snapshot.notes != notes {
+            self.snapshot.notes = notes;
+            self.touch();
+        }
+    }
+
+    /// Replace the commits; keeps the [`MAX_COMMITS`] newest
+    pub fn set_commits(&mut self, mut commits: Vec<CommitSnapshot>) {
+        commits.sort_by_key(|c| std::cmp::Reverse(c.time));
+        commits.truncate(MAX_COMMITS);
+        if self.snapshot.commits != commits {
+            self.snapshot.commits = commits;
+            self.touch();
+        }
+    }
+
+    fn touch(&mut self) {
+        self.snapshot.updated_at = Some(Utc::now());
+        self.dirty = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::Duration;
+    use tempfile::tempdir;
+
+    fn event(summary: &str, start: DateTime<Utc>) -> EventSnapshot {
+        EventSnapshot { summary: summary.to_string(), start }
+    }
+
+    #[test]
+    fn test_missing_file_is_empty_snapshot() {
+        let dir = tempdir().unwrap();
+        let mut store = DashboardStore::new(dir.path());
+        store.load().unwrap();
+        assert!(store.snapshot().is_empty());
+        assert_eq!(store.snapshot().updated_at, None);
+    }
+
+    #[test]
+    fn test_save_and_restore_roundtrip() {
+        let dir = tempdir().unwrap();
+        let now = Utc::now();
+        let mut store = DashboardStore::new(dir.path());
+        store.set_weather(WeatherSnapshot {
+            temperature: 12.5,
+            condition: "Light Rain".into(),
+            location: "Sudbury".into(),
+            high: 14.0,
+            precipitation_chance: 80,
+            ..WeatherSnapshot::default()
+        });
+        store.set_events(vec![event("Standup", now + Duration::hours(1))]);
+        store.set_notes(
+            (1..=8)
+                .map(|id| NoteSnapshot { id, title: format!("Note {}", id), pinned: id == 1 })
+                .collect(),
+        );
+        store.set_commits(vec![CommitSnapshot {
+            repo: "myme".into(),
+            message: "Fix sync".into(),
+            author: "Ann".into(),
+            time: now,
+        }]);
+        store.save().unwrap();
+
+        let mut restored = DashboardStore::new(dir.path());
+        restored.load().unwrap();
+        assert_eq!(restored.snapshot(), store.snapshot());
+        assert_eq!(restored.snapshot().notes.len(), MAX_NOTES);
+        assert!(restored.snapshot().updated_at.is_some());
+    }
+
+    #[test]
+    fn test_events_and_change_tracking() {
+        let dir = tempdir().unwrap();
+        let now = Utc::now();
+        let mut store = DashboardStore::new(dir.path());
+        let events = vec![
+            event("Later", now + Duration::hours(3)),
+            event("Earlier", now - Duration::hours(1)),
+            event("Next", now + Duration::minutes(30)),
+        ];
+        store.set_events(events.clone());
+        assert_eq!(store.snapshot().events[0].summary, "Earlier");
+        assert_eq!(store.snapshot().next_event(now).unwrap().summary, "Next");
+        let today = now.with_timezone(&Local).date_naive();
+        assert!(store.snapshot().events_on(today).count() >= 1);
+        assert_eq!(store.snapshot().events_on(today + Duration::days(7)).count(), 0);
+
+        store.save().unwrap();
+        let saved_at = store.snapshot().updated_at;
+        // Same data in another order isn't a change
+        store.set_events(events);
+        assert_eq!(store.snapshot().updated_at, saved_at);
+        assert!(!store.dirty);
+    }
+}
@@ -0,0 +1,206 @@
+//! Dates and times for display.
+//!
+//! Relative times ("3 h ago", "tomorrow 14:00") and short dates in the
+//! current language (see [`crate::i18n`]), on a 12- or 24-hour clock per
+//! `[ui] clock`. `auto` uses 12 hours for English in regions that do and
+//! 24 hours everywhere else, including when no locale is set.
+
+use chrono::{
+    DateTime, Datelike, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc,
+};
+
+use crate::config::{ClockFormat, Config};
+use crate::i18n::{self, tr, tr_args};
+
+/// Regions where English speakers read a 12-hour clock
+const TWELVE_HOUR_REGIONS: &[&str] = &["US", "CA", "AU", "NZ", "PH", "IN"];
+
+/// Formats dates and times relative to a given "now"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateFormatter {
+    twelve_hour: bool,
+}
+
+impl DateFormatter {
+    /// Formatter for `clock`, resolving `auto` from the current locale
+    pub fn new(clock: ClockFormat) -> Self {
+        let twelve_hour = match clock {
+            ClockFormat::H12 => true,
+            ClockFormat::H24 => false,
+            ClockFormat::Auto => i18n::locale().is_some_and(|locale| {
+                locale.language.as_str() == "en"
+                    && locale
+                        .region
+                        .is_some_and(|region| TWELVE_HOUR_REGIONS.contains(&region.as_str()))
+            }),
+        };
+        Self { twelve_hour }
+    }
+
+    /// Formatter for the configured `[ui] clock`
+    pub fn current() -> Self {
+        Self::new(Config::load_cached().ui.clock)
+    }
+
+    /// "14:00", or "2:00 PM"
+    pub fn time(&self, time: NaiveTime) -> String {
+        if !self.twelve_hour {
+            return time.format("%H:%M").to_string();
+        }
+        let period = if time.hour() < 12 { tr("time-am") } else { tr("time-pm") };
+        tr_args(
+            "time-12h",
+            &[("time", time.format("%-I:%M").to_string().into()), ("period", period.into())],
+        )
+    }
+
+    /// "Mar 4", with the year when it isn't `today`'s
+    pub fn date(&self, date: NaiveDate, today: NaiveDate) -> String {
+        let month = tr_args("date-month-short", &[("month", date.month().into())]);
+        let day = date.day().into();
+        if date.year() == today.year() {
+            tr_args("date-day-month", &[("month", month.into()), ("day", day)])
+        } else {
+            // As text so it isn't digit-grouped
+            let year = date.year().to_string().into();
+            tr_args("date-day-month-year", &[("month", month.into()), ("day", day), ("year", year)])
+        }
+    }
+
+    /// Short weekday name ("Fri")
+    pub fn weekday(&self, date: NaiveDate) -> String {
+        tr_args("date-weekday-short", &[("weekday", date.weekday().number_from_monday().into())])
+    }
+
+    /// Weekday and date ("Fri Mar 7")
+    pub fn day(&self, date: NaiveDate, today: NaiveDate) -> String {
+        let weekday = self.weekday(date).into();
+        tr_args(
+            "date-weekday-date",
+            &[("weekday", weekday), ("date", self.date(date, today).into())],
+        )
+    }
+
+    /// When `at` happens as seen from `now`: "in 20 min" within the hour,
+    /// "today 14:00", "tomorrow 9:30", "yesterday 18:00", "Fri 14:00" within
+    /// a week either way, else "Mar 4 14:00".
+    pub fn moment(&self, at: NaiveDateTime, now: NaiveDateTime) -> String {
+        let ahead = at - now;
+        if ahead > Duration::zero() && ahead < Duration::hours(1) {
+            let minutes = (ahead.num_seconds() + 59) / 60;
+            return tr_args("date-in-minutes", &[("count", minutes.into())]);
+        }
+        let time = self.time(at.time()).into();
+        match (at.date() - now.date()).num_days() {
+            0 => tr_args("date-today-at", &[("time", time)]),
+            1 => tr_args("date-tomorrow-at", &[("time", time)]),
+            -1 => tr_args("date-yesterday-at", &[("time", time)]),
+            -6..=6 => tr_args(
+                "date-weekday-at",
+                &[("weekday", self.weekday(at.date()).into()), ("time", time)],
+            ),
+            _ => tr_args(
+                "date-at",
+                &[("date", self.date(at.date(), now.date()).into()), ("time", time)],
+            ),
+        }
+    }
+
+    /// How long ago `at` was: "just now", "5 min ago", "3 h ago" within a
+    /// day, then "yesterday 14:00", "Mon 14:00" within a week, else the date.
+    /// Times ahead of `now` read as [`moment`](Self::moment).
+    pub fn relative(&self, at: NaiveDateTime, now: NaiveDateTime) -> String {
+        let elapsed = now - at;
+        // A little clock skew between machines still reads as now
+        if elapsed < -Duration::minutes(1) {
+            return self.moment(at, now);
+        }
+        if elapsed < Duration::minutes(1) {
+            tr("date-just-now").to_string()
+        } else if elapsed < Duration::hours(1) {
+            tr_args("date-minutes-ago", &[("count", elapsed.num_minutes().into())])
+        } else if elapsed < Duration::days(1) {
+            tr_args("date-hours-ago", &[("count", elapsed.num_hours().into())])
+        } else if (now.date() - at.date()).num_days() < 7 {
+            self.moment(at, now)
+        } else {
+            self.date(at.date(), now.date())
+        }
+    }
+}
+
+/// `at` on the local clock
+fn local(at: DateTime<Utc>) -> NaiveDateTime {
+    at.with_timezone(&Local).naive_local()
+}
+
+/// [`DateFormatter::relative`] for `at` against the local clock
+pub fn relative(at: DateTime<Utc>) -> String {
+    DateFormatter::current().relative(local(at), Local::now().naive_local())
+}
+
+/// [`DateFormatter::moment`] for `at` against the local clock
+pub fn moment(at: DateTime<Utc>) -> String {
+    DateFormatter::current().moment(local(at), Local::now().naive_local())
+}
+
+/// [`DateFormatter::time`] for `at` on the local clock
+pub fn time(at: DateTime<Utc>) -> String {
+    DateFormatter::current().time(local(at).time())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn at(date: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn formatters() -> (DateFormatter, DateFormatter) {
+        i18n::init(Some("en"));
+        (DateFormatter::new(ClockFormat::H24), DateFormatter::new(ClockFormat::H12))
+    }
+
+    #[test]
+    fn test_clock_formats() {
+        let (h24, h12) = formatters();
+        let time = NaiveTime::from_hms_opt(14, 5, 0).unwrap();
+        assert_eq!(h24.time(time), "14:05");
+        assert_eq!(h12.time(time), "2:05 PM");
+        assert_eq!(h12.time(NaiveTime::from_hms_opt(0, 30, 0).unwrap()), "12:30 AM");
+        // Auto goes by the locale; the test process is English without a region
+        assert_eq!(DateFormatter::new(ClockFormat::Auto), h24);
+    }
+
+    #[test]
+    fn test_relative() {
+        let (fmt, _) = formatters();
+        let now = at("2025-03-06 15:00"); // Thursday
+        assert_eq!(fmt.relative(now, now), "just now");
+        assert_eq!(fmt.relative(at("2025-03-06 15:01"), now), "just now");
+        assert_eq!(fmt.relative(at("2025-03-06 14:55"), now), "5 min ago");
+        assert_eq!(fmt.relative(at("2025-03-06 12:00"), now), "3 h ago");
+        assert_eq!(fmt.relative(at("2025-03-05 20:00"), now), "19 h ago");
+        assert_eq!(fmt.relative(at("2025-03-05 09:30"), now), "yesterday 09:30");
+        assert_eq!(fmt.relative(at("2025-03-03 14:00"), now), "Mon 14:00");
+        assert_eq!(fmt.relative(at("2025-02-20 14:00"), now), "Feb 20");
+        assert_eq!(fmt.relative(at("2024-12-24 14:00"), now), "Dec 24, 2024");
+        assert_eq!(fmt.day(now.date(), now.date()), "Thu Mar 6");
+    }
+
+    #[test]
+    fn test_moment() {
+        let (fmt, h12) = formatters();
+        let now = at("2025-03-06 15:00"); // Thursday
+        assert_eq!(fmt.moment(at("2025-03-06 15:20"), now), "in 20 min");
+        assert_eq!(fmt.moment(at("2025-03-06 18:00"), now), "today 18:00");
+        assert_eq!(fmt.moment(at("2025-03-07 14:00"), now), "tomorrow 14:00");
+        assert_eq!(h12.moment(at("2025-03-07 14:00"), now), "tomorrow 2:00 PM");
+        assert_eq!(fmt.moment(at("2025-03-10 09:00"), now), "Mon 09:00");
+        assert_eq!(fmt.moment(at("2025-03-20 09:00"), now), "Mar 20 09:00");
+        // Future times through relative()
+        assert_eq!(fmt.relative(at("2025-03-07 14:00"), now), "tomorrow 14:00");
+    }
+}
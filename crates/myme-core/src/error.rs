@@ -7,6 +7,8 @@
 
 use thiserror::Error;
 
+use crate::i18n::tr;
+
 /// Top-level application error type.
 ///
 /// All errors in the MyMe application should be convertible to this type.
@@ -43,7 +45,8 @@ pub enum AppError {
 }
 
 impl AppError {
-    /// Returns a user-friendly message suitable for display in the UI.
+    /// Returns a user-friendly message suitable for display in the UI, in the
+    /// current language (see [`crate::i18n`]).
     ///
     /// These messages are designed to be actionable and non-technical.
     pub fn user_message(&self) -> &'static str {
@@ -54,9 +57,9 @@ impl AppError {
             AppError::Auth(e) => e.user_message(),
             AppError::GitHub(e) => e.user_message(),
             AppError::Weather(e) => e.user_message(),
-            AppError::Io(_) => "A file operation failed. Please try again.",
-            AppError::Service(_) => "Something went wrong. Please try again.",
-            AppError::Other(_) => "An unexpected error occurred. Please try again.",
+            AppError::Io(_) => tr("error-io"),
+            AppError::Service(_) => tr("error-service"),
+            AppError::Other(_) => tr("error-other"),
         }
     }
 }
@@ -83,18 +86,14 @@ pub enum NetworkError {
 impl NetworkError {
     pub fn user_message(&self) -> &'static str {
         match self {
-            NetworkError::ConnectionFailed(_) => {
-                "Unable to connect. Check your internet connection."
-            }
-            NetworkError::Timeout => "The request timed out. Please try again.",
+            NetworkError::ConnectionFailed(_) => tr("error-network-connection-failed"),
+            NetworkError::Timeout => tr("error-network-timeout"),
             NetworkError::ServerError { status, .. } if *status >= 500 => {
-                "The server is experiencing issues. Please try again later."
-            }
-            NetworkError::ServerError { .. } => "The request failed. Please try again.",
-            NetworkError::InvalidResponse(_) => {
-                "Received an unexpected response. Please try again."
+                tr("error-network-server-down")
             }
-            NetworkError::TlsError(_) => "Secure connection failed. Check your network settings.",
+            NetworkError::ServerError { .. } => tr("error-network-request-failed"),
+            NetworkError::InvalidResponse(_) => tr("error-network-invalid-response"),
+            NetworkError::TlsError(_) => tr("error-network-tls"),
         }
     }
 }
@@ -118,16 +117,10 @@ pub enum DatabaseError {
 impl DatabaseError {
     pub fn user_message(&self) -> &'static str {
         match self {
-            DatabaseError::ConnectionFailed(_) => {
-                "Unable to access local data. Try restarting the app."
-            }
-            DatabaseError::QueryFailed(_) => "A data operation failed. Please try again.",
-            DatabaseError::Corruption(_) => {
-                "Local data may be corrupted. Consider resetting app data."
-            }
-            DatabaseError::MigrationFailed(_) => {
-                "Failed to update local data. Try restarting the app."
-            }
+            DatabaseError::ConnectionFailed(_) => tr("error-database-connection-failed"),
+            DatabaseError::QueryFailed(_) => tr("error-database-query-failed"),
+            DatabaseError::Corruption(_) => tr("error-database-corruption"),
+            DatabaseError::MigrationFailed(_) => tr("error-database-migration-failed"),
         }
     }
 }
@@ -151,10 +144,10 @@ pub enum ConfigError {
 impl ConfigError {
     pub fn user_message(&self) -> &'static str {
         match self {
-            ConfigError::NotFound(_) => "Configuration not found. Using defaults.",
-            ConfigError::Invalid(_) => "Invalid configuration. Check your settings.",
-            ConfigError::ParseError(_) => "Configuration file is malformed. Check your settings.",
-            ConfigError::MissingSetting(_) => "A required setting is missing. Check your settings.",
+            ConfigError::NotFound(_) => tr("error-config-not-found"),
+            ConfigError::Invalid(_) => tr("error-config-invalid"),
+            ConfigError::ParseError(_) => tr("error-config-parse"),
+            ConfigError::MissingSetting(_) => tr("error-config-missing-setting"),
         }
     }
 }
@@ -190,14 +183,14 @@ pub enum AuthError {
 impl AuthError {
     pub fn user_message(&self) -> &'static str {
         match self {
-            AuthError::TokenExpired => "Your session has expired. Please sign in again.",
-            AuthError::TokenNotFound(_) => "Not signed in. Please authenticate.",
-            AuthError::InvalidToken => "Authentication invalid. Please sign in again.",
-            AuthError::OAuthFailed(_) => "Sign-in failed. Please try again.",
-            AuthError::OAuthCancelled => "Sign-in was cancelled.",
-            AuthError::InvalidCredentials => "Invalid credentials. Please check and try again.",
-            AuthError::StorageError(_) => "Failed to save credentials. Please try again.",
-            AuthError::PortInUse(_) => "Sign-in port is busy. Close other apps and try again.",
+            AuthError::TokenExpired => tr("error-auth-token-expired"),
+            AuthError::TokenNotFound(_) => tr("error-auth-token-not-found"),
+            AuthError::InvalidToken => tr("error-auth-invalid-token"),
+            AuthError::OAuthFailed(_) => tr("error-auth-oauth-failed"),
+            AuthError::OAuthCancelled => tr("error-auth-oauth-cancelled"),
+            AuthError::InvalidCredentials => tr("error-auth-invalid-credentials"),
+            AuthError::StorageError(_) => tr("error-auth-storage"),
+            AuthError::PortInUse(_) => tr("error-auth-port-in-use"),
         }
     }
 }
@@ -236,20 +229,16 @@ impl GitHubError {
 
     pub fn user_message(&self) -> &'static str {
         match self {
-            GitHubError::RateLimited { .. } => {
-                "GitHub rate limit exceeded. Please wait and try again."
-            }
-            GitHubError::RepoNotFound { .. } => {
-                "Repository not found. Check the URL and try again."
-            }
-            GitHubError::Unauthorized => "GitHub authentication failed. Please sign in again.",
-            GitHubError::Forbidden => "You don't have permission to access this resource.",
+            GitHubError::RateLimited { .. } => tr("error-github-rate-limited"),
+            GitHubError::RepoNotFound { .. } => tr("error-github-repo-not-found"),
+            GitHubError::Unauthorized => tr("error-github-unauthorized"),
+            GitHubError::Forbidden => tr("error-github-forbidden"),
             GitHubError::ApiError { status, .. } if *status >= 500 => {
-                "GitHub is experiencing issues. Please try again later."
+                tr("error-github-server-down")
             }
-            GitHubError::ApiError { .. } => "GitHub request failed. Please try again.",
-            GitHubError::NotAuthenticated => "Not signed into GitHub. Please authenticate first.",
-            GitHubError::InvalidRepoUrl(_) => "Invalid repository URL format.",
+            GitHubError::ApiError { .. } => tr("error-github-request-failed"),
+            GitHubError::NotAuthenticated => tr("error-github-not-authenticated"),
+            GitHubError::InvalidRepoUrl(_) => tr("error-github-invalid-repo-url"),
         }
     }
 }
@@ -276,13 +265,11 @@ pub enum WeatherError {
 impl WeatherError {
     pub fn user_message(&self) -> &'static str {
         match self {
-            WeatherError::LocationNotFound(_) => "Location not found. Check and try again.",
-            WeatherError::ApiError(_) => "Weather service error. Please try again.",
-            WeatherError::InvalidApiKey => "Weather API key is invalid. Check settings.",
-            WeatherError::ServiceUnavailable => {
-                "Weather service unavailable. Please try again later."
-            }
-            WeatherError::CacheError(_) => "Weather data may be outdated.",
+            WeatherError::LocationNotFound(_) => tr("error-weather-location-not-found"),
+            WeatherError::ApiError(_) => tr("error-weather-api"),
+            WeatherError::InvalidApiKey => tr("error-weather-invalid-api-key"),
+            WeatherError::ServiceUnavailable => tr("error-weather-unavailable"),
+            WeatherError::CacheError(_) => tr("error-weather-cache"),
         }
     }
 }
@@ -354,6 +341,7 @@ mod tests {
 
     #[test]
     fn test_user_message_propagation() {
+        crate::i18n::init(Some("en"));
         let app_err = AppError::Auth(AuthError::TokenExpired);
         assert_eq!(app_err.user_message(), "Your session has expired. Please sign in again.");
     }
@@ -0,0 +1,220 @@
+//! Recent-error log for the "Recent problems" panel.
+//!
+//! Keeps the last few hundred user-facing errors in memory and, once
+//! `init_persistence` has been called, appends them as JSON lines to a file
+//! that is rotated when it grows too large. The previous file is kept as
+//! `<name>.1`.
+
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// Entries kept in memory
+pub const DEFAULT_CAPACITY: usize = 200;
+/// Rotate the log file once it exceeds this size
+pub const DEFAULT_MAX_FILE_BYTES: u64 = 512 * 1024;
+
+/// One recorded error
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorRecord {
+    /// Unix timestamp in seconds
+    pub timestamp: u64,
+    /// Service that failed (e.g. "gmail", "calendar")
+    pub service: String,
+    /// Message shown to the user
+    pub user_message: String,
+    /// Full technical detail for diagnostics
+    pub detail: String,
+}
+
+struct Inner {
+    entries: VecDeque<ErrorRecord>,
+    file: Option<PathBuf>,
+}
+
+/// Bounded, optionally persisted error log
+pub struct ErrorLog {
+    inner: Mutex<Inner>,
+    capacity: usize,
+    max_file_bytes: u64,
+}
+
+impl ErrorLog {
+    pub fn new(capacity: usize, max_file_bytes: u64) -> Self {
+        Self {
+            inner: Mutex::new(Inner { entries: VecDeque::new(), file: None }),
+            capacity: capacity.max(1),
+            max_file_bytes,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        // A panic while holding the lock cannot leave the buffer inconsistent
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Persist to `path` from now on, loading the most recent entries already in it.
+    pub fn init_persistence(&self, path: &Path) {
+        if let Some(parent) = path.parent() {
+            if let Err(e) = std::fs::create_dir_all(parent) {
+                tracing::warn!("Failed to create error log directory: {}", e);
+                return;
+            }
+        }
+
+        let existing: Vec<ErrorRecord> = std::fs::read_to_string(path)
+            .map(|s| s.lines().filter_map(|l| serde_json::from_str(l).ok()).collect())
+            .unwrap_or_default();
+
+        let mut inner = self.lock();
+        let skip = existing.len().saturating_sub(self.capacity);
+        let mut entries: VecDeque<ErrorRecord> = existing.into_iter().skip(skip).collect();
+        // Keep anything recorded before persistence was set up
+        entries.extend(inner.entries.drain(..));
+        while entries.len() > self.capacity {
+            entries.pop_front();
+        }
+        inner.entries = entries;
+        inner.file = Some(path.to_path_buf());
+    }
+
+    /// Record an error.
+    pub fn record(&self, service: &str, user_message: &str, detail: &str) {
+        let timestamp =
+            SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        self.push(ErrorRecord {
+            timestamp,
+            service: service.to_string(),
+            user_message: user_message.to_string(),
+            detail: detail.to_string(),
+        });
+    }
+
+    fn push(&self, record: ErrorRecord) {
+        let mut inner = self.lock();
+        if let Some(path) = &inner.file {
+            if let Err(e) = append_line(path, &record, self.max_file_bytes) {
+                tracing::warn!("Failed to write error log: {}", e);
+            }
+        }
+        inner.entries.push_back(record);
+        if inner.entries.len() > self.capacity {
+            inner.entries.pop_front();
+        }
+    }
+
+    /// Up to `limit` most recent entries, newest first.
+    pub fn recent(&self, limit: usize) -> Vec<ErrorRecord> {
+        self.lock().entries.iter().rev().take(limit).cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forget all entries (the file is truncated too).
+    pub fn clear(&self) {
+        let mut inner = self.lock();
+        inner.entries.clear();
+        if let Some(path) = &inner.file {
+            if let Err(e) = std::fs::write(path, "") {
+                tracing::warn!("Failed to clear error log: {}", e);
+            }
+        }
+    }
+
+    /// Plain-text report of recent errors for pasting into a bug report.
+    pub fn diagnostics(&self, limit: usize) -> String {
+        let mut out = format!(
+            "MyMe {} ({} {})\n",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        );
+        for record in self.recent(limit) {
+            out.push_str(&format!(
+                "[{}] {}: {}\n    {}\n",
+                record.timestamp, record.service, record.user_message, record.detail
+            ));
+        }
+        out
+    }
+}
+
+/// Append one JSON line, rotating to `<path>.1` first if the file is too large.
+fn append_line(path: &Path, record: &ErrorRecord, max_bytes: u64) -> std::io::Result<()> {
+    if std::fs::metadata(path).map(|m| m.len() >= max_bytes).unwrap_or(false) {
+        let mut rotated = path.as_os_str().to_owned();
+        rotated.push(".1");
+        std::fs::rename(path, PathBuf::from(rotated))?;
+    }
+    let line = serde_json::to_string(record).map_err(std::io::Error::other)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)
+}
+
+static GLOBAL: OnceLock<ErrorLog> = OnceLock::new();
+
+/// Process-wide error log.
+pub fn global() -> &'static ErrorLog {
+    GLOBAL.get_or_init(|| ErrorLog::new(DEFAULT_CAPACITY, DEFAULT_MAX_FILE_BYTES))
+}
+
+/// Record an error in the process-wide log.
+pub fn record(service: &str, user_message: &str, detail: &str) {
+    global().record(service, user_message, detail);
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_keeps_newest() {
+        let log = ErrorLog::new(3, DEFAULT_MAX_FILE_BYTES);
+        for i in 0..5 {
+            log.record("gmail", "Failed", &format!("error {}", i));
+        }
+        let recent = log.recent(10);
+        assert_eq!(recent.len(), 3);
+        assert_eq!(recent[0].detail, "error 4");
+        assert_eq!(recent[2].detail, "error 2");
+        assert!(log.diagnostics(1).contains("gmail: Failed"));
+    }
+
+    #[test]
+    fn test_persistence_reload_and_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("errors.jsonl");
+
+        let log = ErrorLog::new(10, 200);
+        log.record("early", "Before persistence", "kept in memory");
+        log.init_persistence(&path);
+        for i in 0..4 {
+            log.record("calendar", "Calendar error", &format!("detail {}", i));
+        }
+        assert!(dir.path().join("logs").join("errors.jsonl.1").exists());
+
+        let reloaded = ErrorLog::new(10, 200);
+        reloaded.init_persistence(&path);
+        let recent = reloaded.recent(10);
+        assert!(!recent.is_empty());
+        assert_eq!(recent[0].detail, "detail 3");
+
+        reloaded.clear();
+        assert!(reloaded.is_empty());
+        let after_clear = ErrorLog::new(10, 200);
+        after_clear.init_persistence(&path);
+        assert!(after_clear.is_empty());
+    }
+}
@@ -0,0 +1,351 @@
+//! Opt-in HTTP capture for debugging API problems.
+//!
+//! When `[debug] http_capture = true`, API clients report each exchange here and
+//! the most recent ones are written to a HAR 1.2 file (`logs/http.har` under the
+//! config directory) that can be opened in browser dev tools or attached to a
+//! bug report. Only metadata is recorded (method, URL, headers, status, timing);
+//! bodies are never captured, and credentials in headers and query strings are
+//! redacted.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+/// Exchanges kept in the capture file
+pub const DEFAULT_MAX_ENTRIES: usize = 500;
+
+/// Placeholder written instead of secret values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Headers whose values are never written
+const SECRET_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+    "x-goog-api-key",
+];
+
+/// Query parameter names (or name fragments) whose values are never written
+/// `appid` is OpenWeatherMap's API key parameter
+const SECRET_PARAMS: &[&str] = &[
+    "token",
+    "key",
+    "secret",
+    "password",
+    "code",
+    "signature",
+    "auth",
+    "client_secret",
+    "access_token",
+    "api_key",
+    "apikey",
+    "appid",
+];
+
+/// Callback API clients invoke after each request completes (or fails).
+pub type HttpObserver = Arc<
+    dyn Fn(&reqwest::Request, Result<&reqwest::Response, &reqwest::Error>, Duration) + Send + Sync,
+>;
+
+/// Execute `request` on `client`, reporting the exchange to `observer` if one is set.
+pub async fn execute_observed(
+    client: &reqwest::Client,
+    request: reqwest::Request,
+    observer: Option<&HttpObserver>,
+) -> reqwest::Result<reqwest::Response> {
+    let Some(observer) = observer else {
+        return client.execute(request).await;
+    };
+    let snapshot = request.try_clone();
+    let started = Instant::now();
+    let result = client.execute(request).await;
+    if let Some(snapshot) = snapshot {
+        observer(&snapshot, result.as_ref(), started.elapsed());
+    }
+    result
+}
+
+fn is_secret_header(name: &str) -> bool {
+    SECRET_HEADERS.iter().any(|h| name.eq_ignore_ascii_case(h))
+}
+
+fn is_secret_param(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    SECRET_PARAMS.iter().any(|p| name.contains(p))
+}
+
+/// Copy of `url` with secret query values and any userinfo replaced.
+pub fn redact_url(url: &url::Url) -> String {
+    let mut url = url.clone();
+    if !url.username().is_empty() || url.password().is_some() {
+        let _ = url.set_username("");
+        let _ = url.set_password(None);
+    }
+    if url.query().is_some() {
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(k, v)| {
+                let v = if is_secret_param(&k) { REDACTED.to_string() } else { v.into_owned() };
+                (k.into_owned(), v)
+            })
+            .collect();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    url.to_string()
+}
+
+fn header_list(headers: &reqwest::header::HeaderMap) -> Vec<Value> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_secret_header(name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            json!({ "name": name.as_str(), "value": value })
+        })
+        .collect()
+}
+
+fn query_list(url: &url::Url) -> Vec<Value> {
+    url.query_pairs()
+        .map(|(k, v)| {
+            let v = if is_secret_param(&k) { REDACTED.to_string() } else { v.into_owned() };
+            json!({ "name": k, "value": v })
+        })
+        .collect()
+}
+
+/// Build a HAR entry for one exchange.
+pub fn har_entry(
+    service: &str,
+    request: &reqwest::Request,
+    response: Result<&reqwest::Response, &reqwest::Error>,
+    elapsed: Duration,
+) -> Value {
+    let started = chrono::Utc::now()
+        - chrono::Duration::from_std(elapsed).unwrap_or_else(|_| chrono::Duration::zero());
+    let time_ms = elapsed.as_secs_f64() * 1000.0;
+    let (response_json, error) = match response {
+        Ok(resp) => {
+            let size = resp.content_length().map(|l| l as i64).unwrap_or(-1);
+            let response_json = json!({
+                "status": resp.status().as_u16(),
+                "statusText": resp.status().canonical_reason().unwrap_or(""),
+                "httpVersion": format!("{:?}", resp.version()),
+                "headers": header_list(resp.headers()),
+                "cookies": [],
+                "content": { "size": size, "mimeType": "" },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": -1,
+            });
+            (response_json, None)
+        }
+        Err(e) => (
+            json!({
+                "status": 0,
+                "statusText": "",
+                "httpVersion": "",
+                "headers": [],
+                "cookies": [],
+                "content": { "size": -1, "mimeType": "" },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": -1,
+            }),
+            // The error message embeds the URL, query string included
+            Some(match e.url() {
+                Some(url) => e.to_string().replace(url.as_str(), &redact_url(url)),
+                None => e.to_string(),
+            }),
+        ),
+    };
+
+    let body_size = request.body().and_then(|b| b.as_bytes()).map(|b| b.len() as i64).unwrap_or(0);
+    let mut entry = json!({
+        "startedDateTime": started.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        "time": time_ms,
+        "request": {
+            "method": request.method().as_str(),
+            "url": redact_url(request.url()),
+            "httpVersion": format!("{:?}", request.version()),
+            "headers": header_list(request.headers()),
+            "queryString": query_list(request.url()),
+            "cookies": [],
+            "headersSize": -1,
+            "bodySize": body_size,
+        },
+        "response": response_json,
+        "cache": {},
+        "timings": { "send": 0, "wait": time_ms, "receive": 0 },
+        "_service": service,
+    });
+    if let Some(error) = error {
+        entry["_error"] = Value::String(error);
+    }
+    entry
+}
+
+/// Bounded HAR capture written to a file after every exchange
+pub struct HttpCapture {
+    path: PathBuf,
+    entries: Mutex<VecDeque<Value>>,
+    max_entries: usize,
+}
+
+impl HttpCapture {
+    pub fn new(path: &Path, max_entries: usize) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            entries: Mutex::new(VecDeque::new()),
+            max_entries: max_entries.max(1),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<Value>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record one exchange and rewrite the capture file.
+    pub fn record(
+        &self,
+        service: &str,
+        request: &reqwest::Request,
+        response: Result<&reqwest::Response, &reqwest::Error>,
+        elapsed: Duration,
+    ) {
+        let entry = har_entry(service, request, response, elapsed);
+        let mut entries = self.lock();
+        entries.push_back(entry);
+        while entries.len() > self.max_entries {
+            entries.pop_front();
+        }
+        if let Err(e) = self.write(&entries) {
+            tracing::warn!("Failed to write HTTP capture: {}", e);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn write(&self, entries: &VecDeque<Value>) -> std::io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let har = json!({
+            "log": {
+                "version": "1.2",
+                "creator": { "name": "MyMe", "version": env!("CARGO_PKG_VERSION") },
+                "entries": entries,
+            }
+        });
+        let text = serde_json::to_string_pretty(&har).map_err(std::io::Error::other)?;
+        std::fs::write(&self.path, text)
+    }
+
+    /// Observer that tags exchanges with `service`.
+    pub fn observer(self: &Arc<Self>, service: &'static str) -> HttpObserver {
+        let capture = Arc::clone(self);
+        Arc::new(move |request, response, elapsed| {
+            capture.record(service, request, response, elapsed)
+        })
+    }
+}
+
+static GLOBAL: OnceLock<Option<Arc<HttpCapture>>> = OnceLock::new();
+
+/// Process-wide capture, present only when `[debug] http_capture` is enabled.
+pub fn global() -> Option<Arc<HttpCapture>> {
+    GLOBAL
+        .get_or_init(|| {
+            let config = crate::Config::load_cached();
+            if !config.debug.http_capture {
+                return None;
+            }
+            let path = config.config_dir.join("logs").join("http.har");
+            tracing::info!("HTTP capture enabled, writing to {}", path.display());
+            Some(Arc::new(HttpCapture::new(&path, DEFAULT_MAX_ENTRIES)))
+        })
+        .clone()
+}
+
+/// Observer for `service` if HTTP capture is enabled.
+pub fn observer(service: &'static str) -> Option<HttpObserver> {
+    global().map(|capture| capture.observer(service))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_redact_url() {
+        let url = url::Url::parse(
+            "https://user:pw@api.example.com/v1/items?q=rust&access_token=abc&apiKey=xyz",
+        )
+        .unwrap();
+        let redacted = redact_url(&url);
+        assert!(!redacted.contains("abc"));
+        assert!(!redacted.contains("xyz"));
+        assert!(!redacted.contains("pw@"));
+        assert!(redacted.contains("q=rust"));
+
+        let owm = url::Url::parse(
+            "https://api.openweathermap.org/data/2.5/weather?units=metric&appid=owmkey123",
+        )
+        .unwrap();
+        let redacted = redact_url(&owm);
+        assert!(!redacted.contains("owmkey123"));
+        assert!(redacted.contains("units=metric"));
+    }
+
+    #[tokio::test]
+    async fn test_capture_writes_redacted_har() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("http.har");
+        let capture = Arc::new(HttpCapture::new(&path, 2));
+        let observer = capture.observer("gmail");
+
+        let client = reqwest::Client::new();
+        for _ in 0..3 {
+            let request = client
+                .get("http://127.0.0.1:9/gmail/v1/users/me/messages?key=secret1&q=inbox")
+                .header("Authorization", "Bearer secret2")
+                .header("Accept", "application/json")
+                .build()
+                .unwrap();
+            let snapshot = request.try_clone().unwrap();
+            let result = client.execute(request).await;
+            observer(&snapshot, result.as_ref(), Duration::from_millis(120));
+        }
+        assert_eq!(capture.len(), 2);
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(!text.contains("secret1"));
+        assert!(!text.contains("secret2"));
+        let har: Value = serde_json::from_str(&text).unwrap();
+        let entry = &har["log"]["entries"][0];
+        assert_eq!(har["log"]["version"], "1.2");
+        assert_eq!(entry["request"]["method"], "GET");
+        assert_eq!(entry["response"]["status"], 0);
+        assert_eq!(entry["_service"], "gmail");
+        assert!(entry["_error"].is_string());
+    }
+}
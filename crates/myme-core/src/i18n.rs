@@ -0,0 +1,259 @@
+//! Translated user-facing strings.
+//!
+//! Messages are [Fluent](https://projectfluent.org) catalogs under `locales/`,
+//! compiled in. The language is `[ui] language` when set, else the
+//! environment's (`LC_ALL`, `LC_MESSAGES`, `LANG`), and is fixed for the
+//! process by [`init`]. A message missing from the language's catalog falls
+//! back to English, and an unknown id to the id itself.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use fluent_syntax::ast;
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentValue;
+
+/// Compiled-in catalogs by language subtag
+const CATALOGS: &[(&str, &str)] =
+    &[("en", include_str!("../locales/en.ftl")), ("fr", include_str!("../locales/fr.ftl"))];
+
+/// Language every other catalog falls back to
+const FALLBACK: &str = "en";
+
+/// The messages of one language, backed by English
+pub struct Catalog {
+    language: String,
+    /// The locale asked for, even when it has no catalog (for date formats)
+    locale: Option<LanguageIdentifier>,
+    /// The language's bundle, then English
+    bundles: Vec<FluentBundle<FluentResource>>,
+    /// Messages without arguments, formatted once so lookups can borrow them
+    plain: HashMap<String, String>,
+}
+
+impl Catalog {
+    /// Catalog for `language` ("fr", "fr-CA", "fr_CA.UTF-8"); a language
+    /// without a catalog gets English.
+    pub fn new(language: &str) -> Self {
+        let locale = parse_language(language);
+        let requested = locale.clone().filter(has_catalog);
+        let language = requested.as_ref().map_or(FALLBACK, |id| id.language.as_str()).to_string();
+
+        let mut chain = vec![language.as_str()];
+        if language != FALLBACK {
+            chain.push(FALLBACK);
+        }
+        let mut bundles = Vec::new();
+        let mut plain = HashMap::new();
+        for (lang, source) in
+            chain.into_iter().filter_map(|lang| CATALOGS.iter().find(|(l, _)| *l == lang))
+        {
+            let locale = match &requested {
+                Some(id) if *lang == language => id.clone(),
+                _ => lang.parse().unwrap_or_default(),
+            };
+            let (bundle, ids) = load(lang, locale, source);
+            for id in ids {
+                if plain.contains_key(&id) {
+                    continue;
+                }
+                if let Some(text) = format(&bundle, &id, None) {
+                    plain.insert(id, text);
+                }
+            }
+            bundles.push(bundle);
+        }
+        Self { language, locale, bundles, plain }
+    }
+
+    /// Language subtag of the catalog in use
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// The locale asked for, `None` for `C`/`POSIX` or nothing set
+    pub fn locale(&self) -> Option<&LanguageIdentifier> {
+        self.locale.as_ref()
+    }
+
+    /// A message that takes no arguments
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.plain.get(id).map(String::as_str)
+    }
+
+    /// A message formatted with `args`
+    pub fn format(&self, id: &str, args: &FluentArgs) -> Option<String> {
+        self.bundles.iter().find_map(|bundle| format(bundle, id, Some(args)))
+    }
+}
+
+/// Bundle for one catalog, and the ids of its messages
+fn load(
+    lang: &str,
+    locale: LanguageIdentifier,
+    source: &str,
+) -> (FluentBundle<FluentResource>, Vec<String>) {
+    let resource = FluentResource::try_new(source.to_string()).unwrap_or_else(|(resource, e)| {
+        tracing::warn!("Errors in the {} message catalog: {:?}", lang, e);
+        resource
+    });
+    let ids = resource
+        .entries()
+        .filter_map(|entry| match entry {
+            ast::Entry::Message(message) => Some(message.id.name.to_string()),
+            _ => None,
+        })
+        .collect();
+    let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+    // Bidi isolation marks show up as stray glyphs in Qt labels
+    bundle.set_use_isolating(false);
+    if let Err(e) = bundle.add_resource(resource) {
+        tracing::warn!("Duplicate messages in the {} catalog: {:?}", lang, e);
+    }
+    (bundle, ids)
+}
+
+/// `id` from `bundle`, or `None` if it's missing or needs arguments not given
+fn format(
+    bundle: &FluentBundle<FluentResource>,
+    id: &str,
+    args: Option<&FluentArgs>,
+) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    errors.is_empty().then(|| text.into_owned())
+}
+
+/// A language tag from config or a POSIX locale: `fr_CA.UTF-8@euro` reads as
+/// `fr-CA`. `None` for `C`, `POSIX` and malformed tags.
+pub fn parse_language(value: &str) -> Option<LanguageIdentifier> {
+    let tag = value.split(['.', '@']).next().unwrap_or_default().trim().replace('_', "-");
+    if tag.is_empty() || tag == "C" || tag == "POSIX" {
+        return None;
+    }
+    tag.parse().ok()
+}
+
+/// Whether messages are translated into `language`
+pub fn has_catalog(language: &LanguageIdentifier) -> bool {
+    CATALOGS.iter().any(|(lang, _)| *lang == language.language.as_str())
+}
+
+/// The environment's message language: the first of `LC_ALL`, `LC_MESSAGES`
+/// and `LANG` that is set
+pub fn env_language() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())
+}
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+/// Fix the message language for the process: `language` when given, else the
+/// environment's. Only the first call (or lookup) chooses; returns the
+/// language in use.
+pub fn init(language: Option<&str>) -> &'static str {
+    catalog_for(language).language()
+}
+
+fn catalog_for(language: Option<&str>) -> &'static Catalog {
+    CATALOG.get_or_init(|| {
+        let language = language.map(str::to_string).or_else(env_language);
+        Catalog::new(language.as_deref().unwrap_or(FALLBACK))
+    })
+}
+
+fn catalog() -> &'static Catalog {
+    catalog_for(None)
+}
+
+/// The current locale, as asked for (see [`Catalog::locale`])
+pub fn locale() -> Option<&'static LanguageIdentifier> {
+    catalog().locale()
+}
+
+/// The message `id` in the current language
+pub fn tr(id: &'static str) -> &'static str {
+    catalog().get(id).unwrap_or(id)
+}
+
+/// The message `id` in the current language, with arguments:
+/// `tr_args("time-ago", &[("duration", "5 minutes".into())])`
+pub fn tr_args(id: &str, args: &[(&str, FluentValue<'_>)]) -> String {
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(*name, value.clone());
+    }
+    catalog().format(id, &fluent_args).unwrap_or_else(|| id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn args(count: i64) -> FluentArgs<'static> {
+        let mut args = FluentArgs::new();
+        args.set("count", count);
+        args
+    }
+
+    #[test]
+    fn test_parse_language() {
+        assert_eq!(parse_language("fr_CA.UTF-8").unwrap().to_string(), "fr-CA");
+        assert_eq!(parse_language("de_DE@euro").unwrap().to_string(), "de-DE");
+        assert_eq!(parse_language("en").unwrap().to_string(), "en");
+        assert!(parse_language("C.UTF-8").is_none());
+        assert!(parse_language("POSIX").is_none());
+        assert!(parse_language("").is_none());
+        assert!(parse_language("not a tag").is_none());
+    }
+
+    #[test]
+    fn test_catalog_falls_back_to_english() {
+        let en = Catalog::new("en_US.UTF-8");
+        assert_eq!(en.language(), "en");
+        assert_eq!(en.get("weather-heavy-rain"), Some("Heavy Rain"));
+        assert_eq!(en.format("time-minutes", &args(1)).unwrap(), "1 minute");
+        assert_eq!(en.format("time-minutes", &args(5)).unwrap(), "5 minutes");
+        // Needs arguments
+        assert_eq!(en.get("time-minutes"), None);
+
+        let fr = Catalog::new("fr_CA.UTF-8");
+        assert_eq!(fr.language(), "fr");
+        assert_eq!(fr.locale().unwrap().to_string(), "fr-CA");
+        assert_eq!(fr.get("weather-heavy-rain"), Some("Forte pluie"));
+        assert_eq!(fr.format("time-hours", &args(2)).unwrap(), "2 heures");
+
+        // No German catalog, but the locale is kept
+        assert_eq!(Catalog::new("de").language(), "en");
+        assert_eq!(Catalog::new("de").locale().unwrap().to_string(), "de");
+        assert_eq!(Catalog::new("de").get("weather-rain"), Some("Rain"));
+        assert_eq!(en.get("no-such-message"), None);
+    }
+
+    #[test]
+    fn test_translations_have_english_messages() {
+        let en = Catalog::new("en");
+        for (lang, source) in CATALOGS {
+            let resource = FluentResource::try_new(source.to_string())
+                .unwrap_or_else(|(_, e)| panic!("{} catalog doesn't parse: {:?}", lang, e));
+            for entry in resource.entries() {
+                if let ast::Entry::Message(message) = entry {
+                    let id = message.id.name;
+                    assert!(
+                        en.bundles.iter().any(|bundle| bundle.has_message(id)),
+                        "{} has {} but English doesn't",
+                        lang,
+                        id
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Local remote-control interface.
+//!
+//! The running app listens on a Unix socket (`$XDG_RUNTIME_DIR/myme.sock` by
+//! default) for newline-delimited JSON commands, so scripts, Waybar/Polybar
+//! widgets and keyboard daemons can drive it:
+//!
+//! ```text
+//! $ echo '{"cmd":"next_event"}' | socat - UNIX-CONNECT:$XDG_RUNTIME_DIR/myme.sock
+//! {"ok":true,"data":{"summary":"Standup","start":"2026-01-21T09:00:00+00:00",...}}
+//! ```
+//!
+//! Commands: `create_note` (with `text`), `show_window`, `next_event`,
+//! `sync_now`. Every request line gets exactly one response line. The socket
+//! is only accessible to the owning user.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, oneshot};
+
+/// Socket file name inside the runtime directory
+pub const SOCKET_NAME: &str = "myme.sock";
+
+/// A command sent by an external client
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+pub enum IpcCommand {
+    /// Create a note with the given text
+    CreateNote { text: String },
+    /// Show and raise the main window
+    ShowWindow,
+    /// Next upcoming calendar event (from the local cache)
+    NextEvent,
+    /// Replay offline queues and refresh what the UI shows
+    SyncNow,
+}
+
+/// Reply to one command
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IpcResponse {
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl IpcResponse {
+    pub fn ok() -> Self {
+        Self { ok: true, data: None, error: None }
+    }
+
+    pub fn with_data(data: serde_json::Value) -> Self {
+        Self { ok: true, data: Some(data), error: None }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self { ok: false, data: None, error: Some(message.into()) }
+    }
+}
+
+/// A command waiting for the app to handle it
+#[derive(Debug)]
+pub struct IpcRequest {
+    pub command: IpcCommand,
+    pub reply: oneshot::Sender<IpcResponse>,
+}
+
+/// `$XDG_RUNTIME_DIR/myme.sock`, or the config directory where there is no
+/// runtime directory (macOS).
+pub fn default_socket_path() -> PathBuf {
+    dirs::runtime_dir()
+        .unwrap_or_else(|| crate::Config::load_cached().config_dir.clone())
+        .join(SOCKET_NAME)
+}
+
+/// Listen on `path` and forward each command as an [`IpcRequest`].
+///
+/// A socket left behind by a crashed instance is replaced; one that still
+/// accepts connections belongs to a running instance and is an error. The
+/// socket file is removed when `shutdown` fires.
+#[cfg(unix)]
+pub fn spawn_server(
+    handle: &tokio::runtime::Handle,
+    path: &Path,
+    mut shutdown: tokio::sync::broadcast::Receiver<()>,
+) -> anyhow::Result<mpsc::Receiver<IpcRequest>> {
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+    if path.exists() {
+        if std::os::unix::net::UnixStream::connect(path).is_ok() {
+            anyhow::bail!("Another instance is listening on {}", path.display());
+        }
+        std::fs::remove_file(path)?;
+    }
+    let parent = path.parent().unwrap_or(Path::new("."));
+    std::fs::create_dir_all(parent)?;
+
+    // `bind` creates the socket with the umask's mode. Bind inside a private
+    // directory and tighten it there, so the socket is never reachable by
+    // other users before it is moved into place (the macOS fallback lives in
+    // the world-readable config directory).
+    let staging = parent.join(format!(".{}.{}", SOCKET_NAME, std::process::id()));
+    let _ = std::fs::remove_dir_all(&staging);
+    std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+    let staged = staging.join(SOCKET_NAME);
+    let bound = (|| {
+        let listener = {
+            let _guard = handle.enter();
+            tokio::net::UnixListener::bind(&staged)?
+        };
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::rename(&staged, path)?;
+        anyhow::Ok(listener)
+    })();
+    let _ = std::fs::remove_dir_all(&staging);
+    let listener = bound?;
+    tracing::info!("Remote control listening on {}", path.display());
+
+    let (tx, rx) = mpsc::channel(16);
+    let path = path.to_path_buf();
+    handle.spawn(async move {
+        loop {
+            tokio::select! {
+                _ = shutdown.recv() => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, _)) => {
+                        tokio::spawn(serve_connection(stream, tx.clone()));
+                    }
+                    Err(e) => tracing::warn!("Remote control accept failed: {}", e),
+                },
+            }
+        }
+        let _ = std::fs::remove_file(&path);
+    });
+    Ok(rx)
+}
+
+/// Answer each request line on one connection until the client hangs up
+#[cfg(unix)]
+async fn serve_connection(stream: tokio::net::UnixStream, tx: mpsc::Sender<IpcRequest>) {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let (read, mut write) = stream.into_split();
+    let mut lines = BufReader::new(read).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<IpcCommand>(&line) {
+            Ok(command) => dispatch(&tx, command).await,
+            Err(e) => IpcResponse::error(format!("Invalid command: {}", e)),
+        };
+        let mut out = serde_json::to_string(&response)
+            .unwrap_or_else(|_| r#"{"ok":false,"error":"Unserializable response"}"#.to_string());
+        out.push('\n');
+        if write.write_all(out.as_bytes()).await.is_err() {
+            break;
+        }
+    }
+}
+
+async fn dispatch(tx: &mpsc::Sender<IpcRequest>, command: IpcCommand) -> IpcResponse {
+    let (reply, response) = oneshot::channel();
+    if tx.send(IpcRequest { command, reply }).await.is_err() {
+        return IpcResponse::error("MyMe is shutting down");
+    }
+    response.await.unwrap_or_else(|_| IpcResponse::error("Command was dropped"))
+}
+
+/// Send one command to a running instance and wait for its reply
+#[cfg(unix)]
+pub async fn send_command(path: &Path, command: &IpcCommand) -> anyhow::Result<IpcResponse> {
+    use anyhow::Context;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let stream = tokio::net::UnixStream::connect(path)
+        .await
+        .with_context(|| format!("MyMe is not running (no socket at {})", path.display()))?;
+    let (read, mut write) = stream.into_split();
+    let mut line = serde_json::to_string(command)?;
+    line.push('\n');
+    write.write_all(line.as_bytes()).await?;
+
+    let reply = BufReader::new(read)
+        .lines()
+        .next_line()
+        .await?
+        .context("Connection closed without a response")?;
+    Ok(serde_json::from_str(&reply)?)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    #[test]
+    fn test_command_wire_format() {
+        let cmd: IpcCommand =
+            serde_json::from_str(r#"{"cmd":"create_note","text":"buy milk"}"#).unwrap();
+        assert_eq!(cmd, IpcCommand::CreateNote { text: "buy milk".into() });
+        assert_eq!(serde_json::to_string(&IpcCommand::SyncNow).unwrap(), r#"{"cmd":"sync_now"}"#);
+        assert_eq!(serde_json::to_string(&IpcResponse::ok()).unwrap(), r#"{"ok":true}"#);
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_and_stale_socket() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SOCKET_NAME);
+        // Left over from a crash: nothing accepts on it
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+
+        let (shutdown_tx, shutdown_rx) = tokio::sync::broadcast::channel(1);
+        let mut requests =
+            spawn_server(&tokio::runtime::Handle::current(), &path, shutdown_rx).unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        // Nothing left of the private directory the socket was bound in
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        tokio::spawn(async move {
+            while let Some(request) = requests.recv().await {
+                let response = match request.command {
+                    IpcCommand::CreateNote { text } => {
+                        IpcResponse::with_data(serde_json::json!({ "text": text }))
+                    }
+                    _ => IpcResponse::ok(),
+                };
+                let _ = request.reply.send(response);
+            }
+        });
+
+        let reply =
+            send_command(&path, &IpcCommand::CreateNote { text: "hi".into() }).await.unwrap();
+        assert!(reply.ok);
+        assert_eq!(reply.data.unwrap()["text"], "hi");
+
+        // A second server on a live socket is refused
+        let (_tx, rx) = tokio::sync::broadcast::channel(1);
+        assert!(spawn_server(&tokio::runtime::Handle::current(), &path, rx).is_err());
+
+        // Malformed lines get an error and the connection stays usable
+        let stream = tokio::net::UnixStream::connect(&path).await.unwrap();
+        let (read, mut write) = stream.into_split();
+        write.write_all(b"not json\n{\"cmd\":\"show_window\"}\n").await.unwrap();
+        let mut lines = BufReader::new(read).lines();
+        let first: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert!(!first.ok);
+        let second: IpcResponse =
+            serde_json::from_str(&lines.next_line().await.unwrap().unwrap()).unwrap();
+        assert!(second.ok);
+
+        shutdown_tx.send(()).unwrap();
+        for _ in 0..50 {
+            if !path.exists() {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert!(!path.exists());
+    }
+}
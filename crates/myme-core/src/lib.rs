@@ -1,12 +1,38 @@
 pub mod app;
 pub mod config;
+pub mod config_layers;
+pub mod config_secrets;
+pub mod connectivity;
+pub mod dashboard;
+pub mod datefmt;
 pub mod error;
+pub mod error_log;
+pub mod http_log;
+pub mod i18n;
+pub mod ipc;
+pub mod metrics;
+pub mod onboarding;
+pub mod session;
+pub mod settings;
+pub mod theme;
 
-pub use app::App;
-pub use config::{Config, GitHubConfig, NotesConfig, TemperatureUnit, WeatherConfig};
+pub use app::{App, CrashGuard, CrashReport, JournalState, RecoveryInfo};
+pub use config::{
+    BriefingConfig, CalendarConfig, ClockFormat, ClipboardConfig, Config, DebugConfig, FeedsConfig,
+    GitHubConfig, GmailConfig, MetricsConfig, NetworkConfig, NetworkPolicy, NetworkSettings,
+    NoteColor, NotesConfig, ProxyConfig, ProxySettings, SuggestionCondition, SuggestionRule,
+    TemperatureUnit, WeatherConfig, WeatherProviderKind, WipEnforcement,
+};
+pub use connectivity::Connectivity;
+pub use dashboard::{DashboardSnapshot, DashboardStore};
 pub use error::{
     AppError, AuthError, ConfigError, DatabaseError, GitHubError, NetworkError, WeatherError,
 };
+pub use error_log::{ErrorLog, ErrorRecord};
+pub use onboarding::{OnboardingState, OnboardingStep, OnboardingStore};
+pub use session::{SessionState, SessionStore};
+pub use settings::{SettingsDraft, FIELDS as SETTING_FIELDS};
+pub use theme::ThemeMode;
 
 use anyhow::Result;
 
@@ -0,0 +1,385 @@
+//! Opt-in self-monitoring metrics.
+//!
+//! When `[metrics] enabled = true`, API clients, caches and sync jobs report
+//! counters here and a Prometheus text snapshot is written to
+//! `logs/metrics.prom` under the config directory every minute. Setting
+//! `[metrics] listen = "127.0.0.1:9464"` also serves the same text at
+//! `GET /metrics` for a local Prometheus or `curl`. Only loopback addresses
+//! are accepted. When disabled, every recording call is a no-op.
+
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::http_log::{self, HttpObserver};
+
+/// How often the on-disk snapshot is rewritten
+pub const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Content type of the Prometheus text exposition format
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Counter,
+    Gauge,
+    /// Running sum and count of observed values
+    Summary,
+}
+
+impl Kind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Kind::Counter => "counter",
+            Kind::Gauge => "gauge",
+            Kind::Summary => "summary",
+        }
+    }
+}
+
+/// A metric family: name, help text and type.
+#[derive(Debug, Clone, Copy)]
+pub struct Metric {
+    pub name: &'static str,
+    pub help: &'static str,
+    pub kind: Kind,
+}
+
+pub const API_REQUESTS: Metric = Metric {
+    name: "myme_api_requests_total",
+    help: "API requests by service and HTTP status (\"error\" when no response)",
+    kind: Kind::Counter,
+};
+
+pub const API_DURATION: Metric = Metric {
+    name: "myme_api_request_duration_seconds",
+    help: "Time from sending an API request to receiving its response headers",
+    kind: Kind::Summary,
+};
+
+pub const CACHE_LOOKUPS: Metric = Metric {
+    name: "myme_cache_lookups_total",
+    help: "Local cache lookups by cache and result (hit, stale or miss)",
+    kind: Kind::Counter,
+};
+
+pub const SYNC_DURATION: Metric = Metric {
+    name: "myme_sync_duration_seconds",
+    help: "Duration of sync and fetch jobs",
+    kind: Kind::Summary,
+};
+
+pub const SYNC_FAILURES: Metric = Metric {
+    name: "myme_sync_failures_total",
+    help: "Sync and fetch jobs that ended in an error",
+    kind: Kind::Counter,
+};
+
+pub const QUEUE_DEPTH: Metric = Metric {
+    name: "myme_queue_depth",
+    help: "Actions waiting in an offline queue",
+    kind: Kind::Gauge,
+};
+
+type Labels = Vec<(&'static str, String)>;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Value {
+    /// Counter/gauge value, or the sum for a summary
+    value: f64,
+    /// Observations, for summaries
+    count: u64,
+}
+
+struct Family {
+    metric: Metric,
+    series: BTreeMap<Labels, Value>,
+}
+
+/// Metrics registry
+pub struct Metrics {
+    families: Mutex<BTreeMap<&'static str, Family>>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Self { families: Mutex::new(BTreeMap::new()) }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<&'static str, Family>> {
+        self.families.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn update(&self, metric: Metric, labels: &[(&'static str, &str)], f: impl FnOnce(&mut Value)) {
+        let labels: Labels = labels.iter().map(|(k, v)| (*k, v.to_string())).collect();
+        let mut families = self.lock();
+        let family = families
+            .entry(metric.name)
+            .or_insert_with(|| Family { metric, series: BTreeMap::new() });
+        f(family.series.entry(labels).or_default());
+    }
+
+    /// Add `by` to a counter.
+    pub fn inc(&self, metric: Metric, labels: &[(&'static str, &str)], by: u64) {
+        self.update(metric, labels, |v| v.value += by as f64);
+    }
+
+    /// Set a gauge.
+    pub fn set(&self, metric: Metric, labels: &[(&'static str, &str)], value: f64) {
+        self.update(metric, labels, |v| v.value = value);
+    }
+
+    /// Record one observation in a summary.
+    pub fn observe(&self, metric: Metric, labels: &[(&'static str, &str)], value: f64) {
+        self.update(metric, labels, |v| {
+            v.value += value;
+            v.count += 1;
+        });
+    }
+
+    /// Current value of a counter or gauge (the sum, for a summary).
+    pub fn value(&self, metric: Metric, labels: &[(&'static str, &str)]) -> Option<f64> {
+        let labels: Labels = labels.iter().map(|(k, v)| (*k, v.to_string())).collect();
+        self.lock().get(metric.name).and_then(|f| f.series.get(&labels)).map(|v| v.value)
+    }
+
+    /// Render every metric in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for family in self.lock().values() {
+            let Metric { name, help, kind } = family.metric;
+            out.push_str(&format!("# HELP {} {}\n", name, help));
+            out.push_str(&format!("# TYPE {} {}\n", name, kind.as_str()));
+            for (labels, value) in &family.series {
+                let labels = format_labels(labels);
+                match kind {
+                    Kind::Counter | Kind::Gauge => {
+                        out.push_str(&format!("{}{} {}\n", name, labels, value.value));
+                    }
+                    Kind::Summary => {
+                        out.push_str(&format!("{}_sum{} {}\n", name, labels, value.value));
+                        out.push_str(&format!("{}_count{} {}\n", name, labels, value.count));
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Write the rendered metrics to `path`, replacing it atomically.
+    pub fn write_snapshot(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("prom.tmp");
+        std::fs::write(&tmp, self.render())?;
+        std::fs::rename(&tmp, path)
+    }
+
+    /// Observer that counts and times API exchanges for `service`.
+    pub fn observer(self: &Arc<Self>, service: &'static str) -> HttpObserver {
+        let metrics = Arc::clone(self);
+        Arc::new(move |_request, response, elapsed| {
+            let status = match response {
+                Ok(resp) => resp.status().as_u16().to_string(),
+                Err(_) => "error".to_string(),
+            };
+            metrics.inc(API_REQUESTS, &[("service", service), ("status", &status)], 1);
+            metrics.observe(API_DURATION, &[("service", service)], elapsed.as_secs_f64());
+        })
+    }
+}
+
+fn format_labels(labels: &Labels) -> String {
+    if labels.is_empty() {
+        return String::new();
+    }
+    let pairs: Vec<String> = labels
+        .iter()
+        .map(|(k, v)| {
+            let v = v.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n");
+            format!("{}=\"{}\"", k, v)
+        })
+        .collect();
+    format!("{{{}}}", pairs.join(","))
+}
+
+static GLOBAL: OnceLock<Option<Arc<Metrics>>> = OnceLock::new();
+
+/// Process-wide registry, present only when `[metrics] enabled` is set.
+pub fn global() -> Option<Arc<Metrics>> {
+    GLOBAL
+        .get_or_init(|| {
+            let config = crate::Config::load_cached();
+            config.metrics.enabled.then(|| Arc::new(Metrics::new()))
+        })
+        .clone()
+}
+
+/// Observer for `service` combining HTTP capture and metrics, whichever are enabled.
+pub fn http_observer(service: &'static str) -> Option<HttpObserver> {
+    let capture = http_log::observer(service);
+    let metrics = global().map(|m| m.observer(service));
+    match (capture, metrics) {
+        (Some(capture), Some(metrics)) => Some(Arc::new(move |request, response, elapsed| {
+            capture(request, response, elapsed);
+            metrics(request, response, elapsed);
+        })),
+        (capture, metrics) => capture.or(metrics),
+    }
+}
+
+/// Count a cache lookup; `result` is "hit", "stale" or "miss".
+pub fn record_cache_lookup(cache: &str, result: &str) {
+    if let Some(m) = global() {
+        m.inc(CACHE_LOOKUPS, &[("cache", cache), ("result", result)], 1);
+    }
+}
+
+/// Record how long a sync or fetch job took and whether it failed.
+pub fn record_sync(job: &str, elapsed: Duration, ok: bool) {
+    if let Some(m) = global() {
+        m.observe(SYNC_DURATION, &[("job", job)], elapsed.as_secs_f64());
+        if !ok {
+            m.inc(SYNC_FAILURES, &[("job", job)], 1);
+        }
+    }
+}
+
+/// Set the number of actions waiting in an offline queue.
+pub fn set_queue_depth(queue: &str, depth: usize) {
+    if let Some(m) = global() {
+        m.set(QUEUE_DEPTH, &[("queue", queue)], depth as f64);
+    }
+}
+
+/// Path of the periodic snapshot file.
+pub fn snapshot_path(config_dir: &Path) -> PathBuf {
+    config_dir.join("logs").join("metrics.prom")
+}
+
+/// Parse the configured listen address, accepting only loopback addresses.
+pub fn parse_listen(listen: &str) -> Result<SocketAddr, String> {
+    let addr: SocketAddr =
+        listen.parse().map_err(|_| format!("Invalid metrics listen address: {}", listen))?;
+    if !addr.ip().is_loopback() {
+        return Err(format!("Metrics endpoint must listen on a loopback address, not {}", addr));
+    }
+    Ok(addr)
+}
+
+/// Rewrite the snapshot file every `interval`, forever.
+pub async fn run_snapshots(metrics: Arc<Metrics>, path: PathBuf, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(e) = metrics.write_snapshot(&path) {
+            tracing::warn!("Failed to write metrics snapshot: {}", e);
+        }
+    }
+}
+
+/// Serve `GET /metrics` on `listener`, forever.
+pub async fn serve(metrics: Arc<Metrics>, listener: tokio::net::TcpListener) {
+    loop {
+        let (mut stream, _) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                tracing::warn!("Metrics endpoint accept failed: {}", e);
+                continue;
+            }
+        };
+        let metrics = Arc::clone(&metrics);
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            let n = match stream.read(&mut buf).await {
+                Ok(n) => n,
+                Err(_) => return,
+            };
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let path = request.lines().next().and_then(|line| {
+                let mut parts = line.split_whitespace();
+                (parts.next() == Some("GET")).then(|| parts.next()).flatten()
+            });
+            let response = match path {
+                Some("/metrics") => {
+                    let body = metrics.render();
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        CONTENT_TYPE,
+                        body.len(),
+                        body
+                    )
+                }
+                _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string(),
+            };
+            let _ = stream.write_all(response.as_bytes()).await;
+            let _ = stream.shutdown().await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_render_prometheus_text() {
+        let metrics = Metrics::new();
+        metrics.inc(API_REQUESTS, &[("service", "gmail"), ("status", "200")], 1);
+        metrics.inc(API_REQUESTS, &[("service", "gmail"), ("status", "200")], 2);
+        metrics.observe(SYNC_DURATION, &[("job", "gmail_inbox")], 1.5);
+        metrics.observe(SYNC_DURATION, &[("job", "gmail_inbox")], 0.5);
+        metrics.set(QUEUE_DEPTH, &[("queue", "say \"hi\"")], 4.0);
+        metrics.set(QUEUE_DEPTH, &[("queue", "say \"hi\"")], 2.0);
+
+        let text = metrics.render();
+        assert!(text.contains("# TYPE myme_api_requests_total counter\n"));
+        assert!(text.contains("myme_api_requests_total{service=\"gmail\",status=\"200\"} 3\n"));
+        assert!(text.contains("myme_sync_duration_seconds_sum{job=\"gmail_inbox\"} 2\n"));
+        assert!(text.contains("myme_sync_duration_seconds_count{job=\"gmail_inbox\"} 2\n"));
+        assert!(text.contains("myme_queue_depth{queue=\"say \\\"hi\\\"\"} 2\n"));
+        assert_eq!(metrics.value(QUEUE_DEPTH, &[("queue", "say \"hi\"")]), Some(2.0));
+    }
+
+    #[test]
+    fn test_parse_listen_requires_loopback() {
+        assert!(parse_listen("127.0.0.1:9464").is_ok());
+        assert!(parse_listen("[::1]:9464").is_ok());
+        assert!(parse_listen("0.0.0.0:9464").is_err());
+        assert!(parse_listen("localhost").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_serves_metrics() {
+        let metrics = Arc::new(Metrics::new());
+        metrics.inc(CACHE_LOOKUPS, &[("cache", "weather"), ("result", "hit")], 1);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(Arc::clone(&metrics), listener));
+
+        let body =
+            reqwest::get(format!("http://{}/metrics", addr)).await.unwrap().text().await.unwrap();
+        assert!(body.contains("myme_cache_lookups_total{cache=\"weather\",result=\"hit\"} 1"));
+
+        let missing = reqwest::get(format!("http://{}/other", addr)).await.unwrap();
+        assert_eq!(missing.status(), 404);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = snapshot_path(dir.path());
+        metrics.write_snapshot(&path).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), metrics.render());
+    }
+}
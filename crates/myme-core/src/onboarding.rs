@@ -0,0 +1,270 @@
+//! First-run onboarding.
+//!
+//! Tracks which setup steps are done so the UI can point new users at the
+//! next one instead of showing empty panes. Steps that can be detected (a
+//! config file, a repos directory) are marked done by [`OnboardingStore::sync`];
+//! the rest are marked by the UI. Stored as JSON next to `config.toml`.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use crate::Config;
+
+/// A setup step, in the order the guide presents them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStep {
+    ConfigCreated,
+    GithubConnected,
+    GoogleConnected,
+    ReposPathChosen,
+    NotesBackendSelected,
+}
+
+impl OnboardingStep {
+    pub const ALL: [OnboardingStep; 5] = [
+        OnboardingStep::ConfigCreated,
+        OnboardingStep::GithubConnected,
+        OnboardingStep::GoogleConnected,
+        OnboardingStep::ReposPathChosen,
+        OnboardingStep::NotesBackendSelected,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OnboardingStep::ConfigCreated => "config_created",
+            OnboardingStep::GithubConnected => "github_connected",
+            OnboardingStep::GoogleConnected => "google_connected",
+            OnboardingStep::ReposPathChosen => "repos_path_chosen",
+            OnboardingStep::NotesBackendSelected => "notes_backend_selected",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|step| step.as_str() == s)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OnboardingStep::ConfigCreated => "Create your config",
+            OnboardingStep::GithubConnected => "Connect GitHub",
+            OnboardingStep::GoogleConnected => "Connect Google",
+            OnboardingStep::ReposPathChosen => "Choose where your repos live",
+            OnboardingStep::NotesBackendSelected => "Choose where notes are kept",
+        }
+    }
+
+    /// Steps the guide lets you skip; skipping still counts toward progress
+    pub fn is_optional(&self) -> bool {
+        matches!(self, OnboardingStep::GoogleConnected | OnboardingStep::NotesBackendSelected)
+    }
+}
+
+/// Onboarding progress persisted between runs
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnboardingState {
+    #[serde(default)]
+    pub completed: BTreeSet<OnboardingStep>,
+
+    #[serde(default)]
+    pub skipped: BTreeSet<OnboardingStep>,
+
+    /// The user closed the guide; it stays hidden even with steps left
+    #[serde(default)]
+    pub dismissed: bool,
+}
+
+impl OnboardingState {
+    pub fn is_done(&self, step: OnboardingStep) -> bool {
+        self.completed.contains(&step) || self.skipped.contains(&step)
+    }
+
+    /// First step that is neither completed nor skipped
+    pub fn current_step(&self) -> Option<OnboardingStep> {
+        OnboardingStep::ALL.into_iter().find(|step| !self.is_done(*step))
+    }
+
+    /// (done, total) where skipped steps count as done
+    pub fn progress(&self) -> (usize, usize) {
+        let done = OnboardingStep::ALL.iter().filter(|step| self.is_done(**step)).count();
+        (done, OnboardingStep::ALL.len())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.current_step().is_none()
+    }
+
+    /// Whether the guide should be shown
+    pub fn is_active(&self) -> bool {
+        !self.dismissed && !self.is_complete()
+    }
+}
+
+/// Steps already satisfied by the config: the file exists, the repos directory
+/// exists, or a notes sync folder is set. Connections are checked by the caller.
+pub fn detect_steps(config: &Config) -> Vec<OnboardingStep> {
+    let mut steps = Vec::new();
+    if config.config_dir.join("config.toml").is_file() {
+        steps.push(OnboardingStep::ConfigCreated);
+    }
+    let repos = config.repos.local_search_path.trim();
+    if !repos.is_empty() && Path::new(repos).is_dir() {
+        steps.push(OnboardingStep::ReposPathChosen);
+    }
+    if config.notes.sync_dir.as_deref().is_some_and(|dir| !dir.trim().is_empty()) {
+        steps.push(OnboardingStep::NotesBackendSelected);
+    }
+    steps
+}
+
+/// JSON-file backed store for [`OnboardingState`]
+#[derive(Debug, Clone)]
+pub struct OnboardingStore {
+    path: PathBuf,
+    state: OnboardingState,
+    dirty: bool,
+}
+
+impl OnboardingStore {
+    /// Create a store rooted in the given config directory (not loaded yet)
+    pub fn new(config_dir: &Path) -> Self {
+        Self {
+            path: config_dir.join("onboarding.json"),
+            state: OnboardingState::default(),
+            dirty: false,
+        }
+    }
+
+    /// Load progress from disk. A missing file means nothing is done yet.
+    pub fn load(&mut self) -> Result<()> {
+        if !self.path.exists() {
+            return Ok(());
+        }
+        let contents =
+            std::fs::read_to_string(&self.path).context("Failed to read onboarding file")?;
+        self.state = serde_json::from_str(&contents).context("Failed to parse onboarding file")?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Write progress to disk if anything changed since the last load/save
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create onboarding directory")?;
+        }
+        let contents =
+            serde_json::to_string_pretty(&self.state).context("Failed to serialize onboarding")?;
+        std::fs::write(&self.path, contents).context("Failed to write onboarding file")?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    pub fn state(&self) -> &OnboardingState {
+        &self.state
+    }
+
+    pub fn complete(&mut self, step: OnboardingStep) {
+        self.dirty |= self.state.completed.insert(step);
+        self.dirty |= self.state.skipped.remove(&step);
+    }
+
+    /// Skip an optional step; required steps can't be skipped
+    pub fn skip(&mut self, step: OnboardingStep) -> bool {
+        if !step.is_optional() {
+            return false;
+        }
+        if !self.state.completed.contains(&step) {
+            self.dirty |= self.state.skipped.insert(step);
+        }
+        true
+    }
+
+    /// Mark detected steps done. Only adds: a step completed earlier stays
+    /// done even if, say, the GitHub token is later removed.
+    pub fn sync(&mut self, detected: &[OnboardingStep]) {
+        for step in detected {
+            self.complete(*step);
+        }
+    }
+
+    pub fn dismiss(&mut self) {
+        if !self.state.dismissed {
+            self.state.dismissed = true;
+            self.dirty = true;
+        }
+    }
+
+    /// Start over, showing the guide again
+    pub fn reset(&mut self) {
+        if self.state != OnboardingState::default() {
+            self.state = OnboardingState::default();
+            self.dirty = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn steps_advance_and_persist() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = OnboardingStore::new(dir.path());
+        store.load().unwrap();
+        assert_eq!(store.state().current_step(), Some(OnboardingStep::ConfigCreated));
+        assert!(store.state().is_active());
+
+        store.sync(&[OnboardingStep::ConfigCreated, OnboardingStep::ReposPathChosen]);
+        store.complete(OnboardingStep::GithubConnected);
+        assert!(!store.skip(OnboardingStep::GithubConnected));
+        assert!(store.skip(OnboardingStep::GoogleConnected));
+        assert_eq!(store.state().current_step(), Some(OnboardingStep::NotesBackendSelected));
+        assert_eq!(store.state().progress(), (4, 5));
+        store.save().unwrap();
+
+        let mut reloaded = OnboardingStore::new(dir.path());
+        reloaded.load().unwrap();
+        assert_eq!(reloaded.state(), store.state());
+
+        reloaded.complete(OnboardingStep::NotesBackendSelected);
+        assert!(reloaded.state().is_complete());
+        assert!(!reloaded.state().is_active());
+
+        reloaded.reset();
+        assert_eq!(reloaded.state().progress(), (0, 5));
+        reloaded.dismiss();
+        assert!(!reloaded.state().is_active());
+    }
+
+    #[test]
+    fn detects_steps_from_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = Config { config_dir: dir.path().to_path_buf(), ..Config::default() };
+        config.repos.local_search_path = dir.path().join("missing").to_string_lossy().into();
+        assert!(detect_steps(&config).is_empty());
+
+        std::fs::write(dir.path().join("config.toml"), "").unwrap();
+        config.repos.local_search_path = dir.path().to_string_lossy().into();
+        config.notes.sync_dir = Some("/srv/sync".into());
+        assert_eq!(
+            detect_steps(&config),
+            [
+                OnboardingStep::ConfigCreated,
+                OnboardingStep::ReposPathChosen,
+                OnboardingStep::NotesBackendSelected
+            ]
+        );
+        assert_eq!(
+            OnboardingStep::parse("google_connected"),
+            Some(OnboardingStep::GoogleConnected)
+        );
+        assert_eq!(OnboardingStep::parse("nope"), None);
+    }
+}
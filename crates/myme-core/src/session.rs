@@ -0,0 +1,173 @@
+//! Workspace session persistence.
+//!
+//! Remembers where the user left off (last opened project, kanban and note
+//! filters, scroll positions) so the app can reopen in the same context.
+//! Stored as JSON next to `config.toml`; saved on shutdown, loaded on start.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Snapshot of UI context persisted between runs
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionState {
+    /// ID of the project opened most recently (kanban board)
+    #[serde(default)]
+    pub last_project_id: Option<String>,
+
+    /// Kanban filter per project ID
+    #[serde(default)]
+    pub kanban_filters: HashMap<String, String>,
+
+    /// Active note filter ("all", "archived", "reminders", "label:<name>")
+    #[serde(default)]
+    pub note_filter: Option<String>,
+
+    /// Scroll offsets keyed by view name (e.g. "notes", "repos")
+    #[serde(default)]
+    pub scroll_positions: HashMap<String, f64>,
+}
+
+/// JSON-file backed store for [`SessionState`]
+#[derive(Debug, Clone)]
+pub struct SessionStore {
+    path: PathBuf,
+    state: SessionState,
+    dirty: bool,
+}
+
+impl SessionStore {
+    /// Create a store rooted in the given config directory (not loaded yet)
+    pub fn new(config_dir: &Path) -> Self {
+        Self { path: config_dir.join("session.json"), state: SessionState::default(), dirty: false }
+    }
+
+    /// Load session from disk. A missing file leaves the default (empty) session.
+    pub fn load(&mut self) -> Result<()> {
+        if !self.path.exists() {
+            return Ok(());
+        }
+        let contents =
+            std::fs::read_to_string(&self.path).context("Failed to read session file")?;
+        self.state = serde_json::from_str(&contents).context("Failed to parse session file")?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Write session to disk if anything changed since the last load/save
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create session directory")?;
+        }
+        let contents =
+            serde_json::to_string_pretty(&self.state).context("Failed to serialize session")?;
+        std::fs::write(&self.path, contents).context("Failed to write session file")?;
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Current session state
+    pub fn state(&self) -> &SessionState {
+        &self.state
+    }
+
+    /// Last opened project ID
+    pub fn last_project_id(&self) -> Option<&str> {
+        self.state.last_project_id.as_deref()
+    }
+
+    /// Remember the last opened project
+    pub fn set_last_project_id(&mut self, project_id: Option<String>) {
+        if self.state.last_project_id != project_id {
+            self.state.last_project_id = project_id;
+            self.dirty = true;
+        }
+    }
+
+    /// Kanban filter saved for a project
+    pub fn kanban_filter(&self, project_id: &str) -> Option<&str> {
+        self.state.kanban_filters.get(project_id).map(String::as_str)
+    }
+
+    /// Save the kanban filter for a project; an empty filter clears it
+    pub fn set_kanban_filter(&mut self, project_id: &str, filter: &str) {
+        let changed = if filter.is_empty() {
+            self.state.kanban_filters.remove(project_id).is_some()
+        } else {
+            self.state.kanban_filters.insert(project_id.to_string(), filter.to_string())
+                != Some(filter.to_string())
+        };
+        self.dirty |= changed;
+    }
+
+    /// Active note filter
+    pub fn note_filter(&self) -> Option<&str> {
+        self.state.note_filter.as_deref()
+    }
+
+    /// Remember the active note filter
+    pub fn set_note_filter(&mut self, filter: Option<String>) {
+        if self.state.note_filter != filter {
+            self.state.note_filter = filter;
+            self.dirty = true;
+        }
+    }
+
+    /// Scroll position for a view (0.0 if never saved)
+    pub fn scroll_position(&self, view: &str) -> f64 {
+        self.state.scroll_positions.get(view).copied().unwrap_or(0.0)
+    }
+
+    /// Remember the scroll position for a view
+    pub fn set_scroll_position(&mut self, view: &str, position: f64) {
+        let previous = self.state.scroll_positions.insert(view.to_string(), position);
+        self.dirty |= previous != Some(position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_missing_file_is_empty_session() {
+        let dir = tempdir().unwrap();
+        let mut store = SessionStore::new(dir.path());
+        store.load().unwrap();
+        assert_eq!(store.state(), &SessionState::default());
+    }
+
+    #[test]
+    fn test_save_and_restore_roundtrip() {
+        let dir = tempdir().unwrap();
+        let mut store = SessionStore::new(dir.path());
+        store.set_last_project_id(Some("proj-1".into()));
+        store.set_kanban_filter("proj-1", "label:bug");
+        store.set_note_filter(Some("archived".into()));
+        store.set_scroll_position("notes", 120.5);
+        store.save().unwrap();
+
+        let mut restored = SessionStore::new(dir.path());
+        restored.load().unwrap();
+        assert_eq!(restored.last_project_id(), Some("proj-1"));
+        assert_eq!(restored.kanban_filter("proj-1"), Some("label:bug"));
+        assert_eq!(restored.note_filter(), Some("archived"));
+        assert_eq!(restored.scroll_position("notes"), 120.5);
+        assert_eq!(restored.scroll_position("repos"), 0.0);
+    }
+
+    #[test]
+    fn test_empty_kanban_filter_clears_entry() {
+        let dir = tempdir().unwrap();
+        let mut store = SessionStore::new(dir.path());
+        store.set_kanban_filter("proj-1", "todo");
+        store.set_kanban_filter("proj-1", "");
+        assert_eq!(store.kanban_filter("proj-1"), None);
+    }
+}
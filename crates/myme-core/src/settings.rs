@@ -0,0 +1,402 @@
+//! Typed access to config fields for the settings screen.
+//!
+//! [`FIELDS`] lists every user-editable setting with its type. A
+//! [`SettingsDraft`] holds edits as a TOML table: each setter checks the
+//! field's type, then confirms the whole draft still deserializes into a
+//! [`Config`], so a bad value is refused at the field instead of at save time.
+//! Keyed maps (`repos.tasks`, `proxy.services`, `network.services`) are edited
+//! in the file, as are `briefing.rules`, and `notes.palette` from the note
+//! color picker.
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use toml::{Table, Value};
+
+use crate::config::{Config, ValidationResult};
+use SettingKind::{Bool, Choice, Int, List, Text};
+
+/// Value type of a setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "options", rename_all = "snake_case")]
+pub enum SettingKind {
+    Bool,
+    /// Non-negative whole number
+    Int,
+    Text,
+    /// One of the listed values
+    Choice(&'static [&'static str]),
+    /// List of strings
+    List,
+}
+
+/// One editable config field
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct SettingField {
+    /// Dotted path, e.g. `ui.window_width`
+    pub key: &'static str,
+    pub label: &'static str,
+    #[serde(flatten)]
+    pub kind: SettingKind,
+    /// May be left unset (an empty value removes it)
+    pub optional: bool,
+    /// Hide the value while typing (API keys, passwords)
+    pub secret: bool,
+}
+
+impl SettingField {
+    const fn new(key: &'static str, label: &'static str, kind: SettingKind) -> Self {
+        Self { key, label, kind, optional: false, secret: false }
+    }
+
+    const fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    const fn secret(mut self) -> Self {
+        self.optional = true;
+        self.secret = true;
+        self
+    }
+}
+
+/// Every setting the settings screen can edit, grouped by section
+pub const FIELDS: &[SettingField] = &[
+    SettingField::new("ui.window_width", "Window width", Int),
+    SettingField::new("ui.window_height", "Window height", Int),
+    SettingField::new(
+        "ui.theme_mode",
+        "Appearance",
+        Choice(&["auto", "light", "dark", "scheduled"]),
+    )
+    .optional(),
+    SettingField::new("ui.theme", "Theme", Text),
+    SettingField::new("ui.accent_color", "Accent color", Text).optional(),
+    SettingField::new("ui.dark_start", "Dark from", Text),
+    SettingField::new("ui.dark_end", "Dark until", Text),
+    SettingField::new("ui.language", "Language", Text).optional(),
+    SettingField::new("ui.clock", "Clock", Choice(&["auto", "12h", "24h"])),
+    SettingField::new(
+        "weather.temperature_unit",
+        "Temperature unit",
+        Choice(&["auto", "celsius", "fahrenheit"]),
+    ),
+    SettingField::new("weather.refresh_minutes", "Weather refresh (minutes)", Int),
+    SettingField::new(
+        "weather.provider",
+        "Forecast source",
+        Choice(&["open_meteo", "openweathermap"]),
+    ),
+    SettingField::new("weather.openweathermap_api_key", "OpenWeatherMap API key", Text).secret(),
+    SettingField::new("weather.fallback", "Fall back to the other source", Bool),
+    SettingField::new("projects.sync_interval_minutes", "Project sync (minutes)", Int),
+    SettingField::new("projects.auto_create_labels", "Create status labels on repos", Bool),
+    SettingField::new(
+        "projects.wip_enforcement",
+        "Over WIP limit moves",
+        Choice(&["warn", "block"]),
+    ),
+    SettingField::new("repos.local_search_path", "Local repositories folder", Text),
+    SettingField::new("repos.task_timeout_minutes", "Check timeout (minutes)", Int),
+    SettingField::new("repos.orgs", "GitHub organizations and teams", List),
+    SettingField::new("repos.stale_branch_days", "Stale branch age (days)", Int),
+    SettingField::new("repos.watch", "Update repo status as files change", Bool),
+    SettingField::new("github.client_id", "GitHub client ID", Text),
+    SettingField::new("github.client_secret", "GitHub client secret", Text).secret(),
+    SettingField::new("github.graphql", "Sync issues over GraphQL", Bool),
+    SettingField::new("github.webhook_relay_url", "Webhook relay URL (smee.io)", Text),
+    SettingField::new("google.client_id", "Google client ID", Text).optional(),
+    SettingField::new("google.client_secret", "Google client secret", Text).secret(),
+    SettingField::new("google.gmail", "Request Gmail access", Bool),
+    SettingField::new("google.calendar", "Request Calendar access", Bool),
+    SettingField::new("notes.sqlite_path", "Notes database", Text),
+    SettingField::new("notes.sync_dir", "Notes sync folder", Text).optional(),
+    SettingField::new("notes.trash_retention_days", "Keep deleted notes (days)", Int),
+    SettingField::new("notes.encrypt", "Encrypt notes", Bool),
+    SettingField::new("calendar.display_timezone", "Calendar time zone", Text).optional(),
+    SettingField::new("calendar.travel_alerts", "Leave-by alerts for events", Bool),
+    SettingField::new("calendar.travel_buffer_minutes", "Extra travel time (minutes)", Int),
+    SettingField::new("feeds.refresh_minutes", "Feed refresh (minutes)", Int),
+    SettingField::new("feeds.keep_read_days", "Keep read items (days)", Int),
+    SettingField::new("clipboard.enabled", "Clipboard history", Bool),
+    SettingField::new("clipboard.max_entries", "Clipboard entries", Int),
+    SettingField::new("clipboard.excluded_apps", "Never record from", List),
+    SettingField::new("clipboard.skip_secrets", "Skip passwords and tokens", Bool),
+    SettingField::new("gmail.prefetch_days", "Keep mail bodies offline (days)", Int),
+    SettingField::new("gmail.cache_quota_mb", "Mail cache limit (MB, 0 = none)", Int),
+    SettingField::new("briefing.enabled", "Daily suggestions", Bool),
+    SettingField::new("briefing.rain_threshold", "Rainy from (% chance)", Int),
+    SettingField::new("briefing.evening_start", "Evening starts (HH:MM)", Text),
+    SettingField::new("briefing.evening_end", "Evening ends (HH:MM)", Text),
+    SettingField::new("briefing.outdoor_labels", "Outdoor task labels", List),
+    SettingField::new("proxy.http_proxy", "HTTP proxy", Text).optional(),
+    SettingField::new("proxy.https_proxy", "HTTPS proxy", Text).optional(),
+    SettingField::new("proxy.no_proxy", "Bypass proxy for", Text).optional(),
+    SettingField::new("proxy.username", "Proxy user", Text).optional(),
+    SettingField::new("proxy.password", "Proxy password", Text).secret(),
+    SettingField::new("network.timeout_secs", "Request timeout (seconds)", Int),
+    SettingField::new("network.max_retries", "Request retries", Int),
+    SettingField::new("network.backoff_base_ms", "Retry backoff (ms)", Int),
+    SettingField::new("network.max_backoff_ms", "Longest retry backoff (ms)", Int),
+    SettingField::new("debug.http_capture", "Capture HTTP traffic", Bool),
+    SettingField::new("metrics.enabled", "Collect metrics", Bool),
+    SettingField::new("metrics.listen", "Metrics address", Text).optional(),
+];
+
+/// Field definition for `key`
+pub fn field(key: &str) -> Option<&'static SettingField> {
+    FIELDS.iter().find(|f| f.key == key)
+}
+
+/// A setting with its current draft value, as shown on the settings screen
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingRow {
+    #[serde(flatten)]
+    pub field: SettingField,
+    /// None when an optional field is unset
+    pub value: Option<Value>,
+    /// Host file or environment variable that overrides the field; such
+    /// fields are read-only here
+    pub overridden_by: Option<String>,
+}
+
+/// Pending edits to the config, checked as they are made
+#[derive(Debug, Clone)]
+pub struct SettingsDraft {
+    base: Config,
+    saved: Table,
+    table: Table,
+}
+
+impl SettingsDraft {
+    pub fn new(config: &Config) -> Result<Self> {
+        let table = Table::try_from(config).context("Failed to serialize config")?;
+        Ok(Self { base: config.clone(), saved: table.clone(), table })
+    }
+
+    /// Current draft value of `key`; None when unset
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        let (path, name) = split(key);
+        let mut table = &self.table;
+        for part in path {
+            table = table.get(part)?.as_table()?;
+        }
+        table.get(name)
+    }
+
+    pub fn rows(&self) -> Vec<SettingRow> {
+        FIELDS
+            .iter()
+            .map(|field| SettingRow {
+                field: *field,
+                value: self.get(field.key).cloned(),
+                overridden_by: self.base.provenance.override_for(field.key).map(|s| s.to_string()),
+            })
+            .collect()
+    }
+
+    pub fn set_bool(&mut self, key: &str, value: bool) -> Result<()> {
+        self.check(key, |kind| kind == Bool)?;
+        self.apply(key, Some(Value::Boolean(value)))
+    }
+
+    pub fn set_int(&mut self, key: &str, value: i64) -> Result<()> {
+        self.check(key, |kind| kind == Int)?;
+        if value < 0 {
+            bail!("{} can't be negative", key);
+        }
+        self.apply(key, Some(Value::Integer(value)))
+    }
+
+    /// Set a text or choice field. Empty text unsets an optional field.
+    pub fn set_text(&mut self, key: &str, value: &str) -> Result<()> {
+        let field = self.check(key, |kind| matches!(kind, Text | Choice(_)))?;
+        let value = value.trim();
+        if value.is_empty() && field.optional {
+            return self.apply(key, None);
+        }
+        if let Choice(options) = field.kind {
+            if !options.contains(&value) {
+                bail!("{} must be one of: {}", key, options.join(", "));
+            }
+        }
+        self.apply(key, Some(Value::String(value.to_string())))
+    }
+
+    /// Set a list field; blank entries are dropped
+    pub fn set_list(&mut self, key: &str, values: &[String]) -> Result<()> {
+        self.check(key, |kind| kind == List)?;
+        let values = values
+            .iter()
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+            .map(|v| Value::String(v.to_string()))
+            .collect();
+        self.apply(key, Some(Value::Array(values)))
+    }
+
+    /// The draft as a config, keeping the loaded layers' provenance and
+    /// secret references
+    pub fn config(&self) -> Result<Config> {
+        let mut config: Config =
+            Value::Table(self.table.clone()).try_into().context("Invalid settings")?;
+        config.provenance = self.base.provenance.clone();
+        config.secrets = self.base.secrets.clone();
+        Ok(config)
+    }
+
+    /// `Config::validate` on the draft
+    pub fn validate(&self) -> ValidationResult {
+        self.config().map(|c| c.validate()).unwrap_or_else(|e| {
+            let mut result = ValidationResult::default();
+            result.add_error("config", format!("{:#}", e));
+            result
+        })
+    }
+
+    /// True when the draft differs from what was loaded or last saved
+    pub fn is_dirty(&self) -> bool {
+        self.table != self.saved
+    }
+
+    /// Drop unsaved edits
+    pub fn revert(&mut self) {
+        self.table = self.saved.clone();
+    }
+
+    /// Validate, write the config file and publish the new config. Refuses to
+    /// save while validation reports errors.
+    pub fn save(&mut self) -> Result<Config> {
+        let config = self.config()?;
+        let validation = config.validate();
+        if !validation.is_valid() {
+            bail!("{}", validation.error_summary());
+        }
+        config.save()?;
+        self.saved = self.table.clone();
+        self.base = config.clone();
+        Ok(config)
+    }
+
+    /// Look up `key` and check it is editable as `kind`
+    fn check(
+        &self,
+        key: &str,
+        kind: impl Fn(SettingKind) -> bool,
+    ) -> Result<&'static SettingField> {
+        let Some(field) = field(key) else {
+            bail!("Unknown setting: {}", key);
+        };
+        if !kind(field.kind) {
+            bail!("Wrong value type for {}", key);
+        }
+        if let Some(source) = self.base.provenance.override_for(key) {
+            bail!("{} is set in {}; change it there", key, source);
+        }
+        Ok(field)
+    }
+
+    /// Set or (with None) remove `key`, keeping the old draft if the result
+    /// no longer deserializes
+    fn apply(&mut self, key: &str, value: Option<Value>) -> Result<()> {
+        let previous = self.table.clone();
+        let (path, name) = split(key);
+        let mut table = &mut self.table;
+        for part in path {
+            let entry = table.entry(part).or_insert_with(|| Value::Table(Table::new()));
+            let Value::Table(inner) = entry else {
+                bail!("{} is not a section", part);
+            };
+            table = inner;
+        }
+        match value {
+            Some(value) => {
+                table.insert(name.to_string(), value);
+            }
+            None => {
+                table.remove(name);
+            }
+        }
+
+        if let Err(e) = self.config() {
+            self.table = previous;
+            return Err(e.context(format!("Invalid value for {}", key)));
+        }
+        Ok(())
+    }
+}
+
+/// `ui.window_width` -> (["ui"], "window_width")
+fn split(key: &str) -> (Vec<&str>, &str) {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    let name = parts.pop().unwrap_or(key);
+    (parts, name)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn fields_exist_in_default_config() {
+        let draft = SettingsDraft::new(&Config::default()).unwrap();
+        for field in FIELDS {
+            assert!(
+                field.optional || draft.get(field.key).is_some(),
+                "{} missing from the default config",
+                field.key
+            );
+            let sensitive = ["secret", "password", "api_key"];
+            if sensitive.iter().any(|s| field.key.ends_with(s)) {
+                assert!(field.secret, "{} should be masked", field.key);
+            }
+        }
+
+        let rows = serde_json::to_value(draft.rows()).unwrap();
+        let provider = rows.as_array().unwrap().iter().find(|r| r["key"] == "weather.provider");
+        let provider = provider.unwrap();
+        assert_eq!(provider["type"], "choice");
+        assert_eq!(provider["options"][1], "openweathermap");
+        assert_eq!(provider["value"], "open_meteo");
+    }
+
+    #[test]
+    fn setters_check_types_and_values() {
+        let mut draft = SettingsDraft::new(&Config::default()).unwrap();
+        assert!(!draft.is_dirty());
+
+        draft.set_int("ui.window_width", 1600).unwrap();
+        draft.set_bool("clipboard.enabled", true).unwrap();
+        draft.set_text("weather.provider", "openweathermap").unwrap();
+        draft.set_list("clipboard.excluded_apps", &["keepassxc".into(), " ".into()]).unwrap();
+        draft.set_text("notes.sync_dir", "/tmp/sync").unwrap();
+        assert!(draft.is_dirty());
+
+        let config = draft.config().unwrap();
+        assert_eq!(config.ui.window_width, 1600);
+        assert!(config.clipboard.enabled);
+        assert_eq!(config.weather.provider, crate::WeatherProviderKind::OpenWeatherMap);
+        assert_eq!(config.clipboard.excluded_apps, vec!["keepassxc".to_string()]);
+        assert_eq!(config.notes.sync_dir.as_deref(), Some("/tmp/sync"));
+
+        // Empty text unsets optional fields
+        draft.set_text("notes.sync_dir", "").unwrap();
+        assert!(draft.config().unwrap().notes.sync_dir.is_none());
+
+        // Wrong type, unknown option, out of range, unknown key
+        assert!(draft.set_bool("ui.window_width", true).is_err());
+        assert!(draft.set_text("weather.provider", "metoffice").is_err());
+        assert!(draft.set_int("feeds.refresh_minutes", i64::from(u32::MAX) + 1).is_err());
+        assert!(draft.set_int("feeds.refresh_minutes", -1).is_err());
+        assert!(draft.set_text("ui.nope", "x").is_err());
+        assert_eq!(draft.config().unwrap().feeds.refresh_minutes, 30);
+
+        // Live validation
+        draft.set_int("ui.window_width", 0).unwrap();
+        assert!(draft.validate().errors.iter().any(|e| e.field == "ui.window_width"));
+
+        draft.revert();
+        assert!(!draft.is_dirty());
+    }
+}
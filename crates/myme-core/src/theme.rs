@@ -0,0 +1,201 @@
+//! Theme selection: named accent palettes, custom accent colors, and deciding
+//! whether the UI is dark from the configured mode, the desktop's preference
+//! and the time of day.
+
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+
+/// How dark/light is chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeMode {
+    /// Follow the desktop (portal color-scheme, else the platform style hints)
+    #[default]
+    Auto,
+    Light,
+    Dark,
+    /// Dark between `ui.dark_start` and `ui.dark_end`
+    Scheduled,
+}
+
+impl ThemeMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemeMode::Auto => "auto",
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::Scheduled => "scheduled",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "auto" => Some(ThemeMode::Auto),
+            "light" => Some(ThemeMode::Light),
+            "dark" => Some(ThemeMode::Dark),
+            "scheduled" => Some(ThemeMode::Scheduled),
+            _ => None,
+        }
+    }
+}
+
+/// A built-in accent palette
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NamedTheme {
+    pub id: &'static str,
+    pub label: &'static str,
+    /// Accent on dark backgrounds
+    pub accent_dark: &'static str,
+    /// Accent on light backgrounds (darker, for contrast)
+    pub accent_light: &'static str,
+}
+
+/// Theme used when the configured one is unknown
+pub const DEFAULT_THEME: &str = "forge";
+
+/// Built-in themes; the first is the default
+pub const THEMES: &[NamedTheme] = &[
+    NamedTheme {
+        id: "forge",
+        label: "Warm Forge",
+        accent_dark: "#e5a54b",
+        accent_light: "#c08832",
+    },
+    NamedTheme { id: "ocean", label: "Ocean", accent_dark: "#5fa8e8", accent_light: "#2f74b5" },
+    NamedTheme { id: "forest", label: "Forest", accent_dark: "#6cc08b", accent_light: "#3a8a58" },
+    NamedTheme { id: "rose", label: "Rose", accent_dark: "#e57a9a", accent_light: "#b84a6c" },
+    NamedTheme { id: "slate", label: "Slate", accent_dark: "#a0a8b8", accent_light: "#5a6478" },
+];
+
+pub fn find_theme(id: &str) -> Option<&'static NamedTheme> {
+    THEMES.iter().find(|t| t.id == id)
+}
+
+/// Accent colors for the current theme and brightness, as `#rrggbb` (glow is
+/// `#rrggbbaa`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accent {
+    pub primary: String,
+    pub hover: String,
+    pub glow: String,
+}
+
+/// Parse `#rgb` or `#rrggbb`
+pub fn parse_hex_color(s: &str) -> Option<[u8; 3]> {
+    let hex = s.trim().strip_prefix('#')?;
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize, len: usize| {
+        let v = u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).ok()?;
+        Some(if len == 1 { v * 17 } else { v })
+    };
+    let len = match hex.len() {
+        3 => 1,
+        6 => 2,
+        _ => return None,
+    };
+    Some([channel(0, len)?, channel(1, len)?, channel(2, len)?])
+}
+
+fn hex(rgb: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+}
+
+/// Accent for `theme_id`, or for `custom` when it's a valid color. Hover is
+/// the accent darkened by 8%, glow the accent at 25% opacity.
+pub fn accent(theme_id: &str, custom: Option<&str>, dark: bool) -> Accent {
+    let named = find_theme(theme_id).unwrap_or(&THEMES[0]);
+    let base = custom.and_then(parse_hex_color).unwrap_or_else(|| {
+        let fallback = if dark { named.accent_dark } else { named.accent_light };
+        parse_hex_color(fallback).unwrap_or([0xe5, 0xa5, 0x4b])
+    });
+    let hover = base.map(|c| (f32::from(c) * 0.92).round() as u8);
+    let primary = hex(base);
+    Accent { glow: format!("{}40", primary), hover: hex(hover), primary }
+}
+
+/// Parse `HH:MM` (24-hour)
+pub fn parse_time_of_day(s: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(s.trim(), "%H:%M").ok()
+}
+
+/// Whether `now` falls in the dark window from `start` to `end`; the window
+/// wraps past midnight when `end` is earlier than `start`.
+pub fn in_dark_window(now: NaiveTime, start: NaiveTime, end: NaiveTime) -> bool {
+    if start <= end {
+        now >= start && now < end
+    } else {
+        now >= start || now < end
+    }
+}
+
+/// Whether the UI should be dark. `desktop_dark` is the desktop's preference
+/// when known; Scheduled falls back to Auto if the times don't parse.
+pub fn resolve_dark(
+    mode: ThemeMode,
+    desktop_dark: bool,
+    now: NaiveTime,
+    dark_start: &str,
+    dark_end: &str,
+) -> bool {
+    match mode {
+        ThemeMode::Light => false,
+        ThemeMode::Dark => true,
+        ThemeMode::Auto => desktop_dark,
+        ThemeMode::Scheduled => {
+            match (parse_time_of_day(dark_start), parse_time_of_day(dark_end)) {
+                (Some(start), Some(end)) => in_dark_window(now, start, end),
+                _ => desktop_dark,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn at(s: &str) -> NaiveTime {
+        parse_time_of_day(s).unwrap()
+    }
+
+    #[test]
+    fn parses_colors() {
+        assert_eq!(parse_hex_color("#e5a54b"), Some([0xe5, 0xa5, 0x4b]));
+        assert_eq!(parse_hex_color(" #fA0 "), Some([0xff, 0xaa, 0x00]));
+        assert_eq!(parse_hex_color("e5a54b"), None);
+        assert_eq!(parse_hex_color("#e5a54"), None);
+        assert_eq!(parse_hex_color("#gggggg"), None);
+    }
+
+    #[test]
+    fn accent_prefers_valid_custom_color() {
+        let forge = accent("forge", None, true);
+        assert_eq!(forge.primary, "#e5a54b");
+        assert_eq!(forge.glow, "#e5a54b40");
+        assert_eq!(accent("ocean", None, false).primary, "#2f74b5");
+        assert_eq!(accent("nope", None, false).primary, "#c08832");
+
+        let custom = accent("ocean", Some("#808080"), true);
+        assert_eq!(custom.primary, "#808080");
+        assert_eq!(custom.hover, "#767676");
+        assert_eq!(accent("ocean", Some("blue"), true).primary, "#5fa8e8");
+    }
+
+    #[test]
+    fn schedule_wraps_midnight() {
+        assert!(in_dark_window(at("23:30"), at("19:00"), at("07:00")));
+        assert!(in_dark_window(at("06:59"), at("19:00"), at("07:00")));
+        assert!(!in_dark_window(at("07:00"), at("19:00"), at("07:00")));
+        assert!(in_dark_window(at("13:00"), at("12:00"), at("14:00")));
+        assert!(!in_dark_window(at("14:00"), at("12:00"), at("14:00")));
+
+        let noon = at("12:00");
+        assert!(!resolve_dark(ThemeMode::Scheduled, true, noon, "19:00", "07:00"));
+        assert!(resolve_dark(ThemeMode::Scheduled, true, noon, "late", "07:00"));
+        assert!(resolve_dark(ThemeMode::Auto, true, noon, "19:00", "07:00"));
+        assert!(!resolve_dark(ThemeMode::Light, true, noon, "19:00", "07:00"));
+    }
+}
@@ -0,0 +1,391 @@
+//! SQLite cache for subscriptions, items and read state.
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use myme_services::db;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::path::Path;
+
+use crate::types::{CacheValidators, Feed, FeedItem, ParsedFeed};
+
+/// SQLite cache for feeds.
+pub struct FeedCache {
+    conn: Connection,
+}
+
+impl FeedCache {
+    /// Create a new cache at the given path.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let conn = db::open_maintained(path.as_ref())?;
+        let cache = Self { conn };
+        cache.migrate()?;
+        Ok(cache)
+    }
+
+    /// Create an in-memory cache (for testing).
+    #[cfg(test)]
+    pub fn in_memory() -> Result<Self> {
+        let conn = db::open_in_memory()?;
+        let cache = Self { conn };
+        cache.migrate()?;
+        Ok(cache)
+    }
+
+    fn migrate(&self) -> Result<()> {
+        db::migrate(&self.conn, 1, |version| match version {
+            1 => self.init_schema(),
+            _ => Ok(()),
+        })
+    }
+
+    /// Initialize the database schema.
+    fn init_schema(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS feeds (
+                url TEXT PRIMARY KEY,
+                title TEXT,
+                site_url TEXT,
+                etag TEXT,
+                last_modified TEXT,
+                last_fetched_ms INTEGER,
+                last_error TEXT,
+                added_ms INTEGER NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS items (
+                feed_url TEXT NOT NULL REFERENCES feeds(url) ON DELETE CASCADE,
+                id TEXT NOT NULL,
+                title TEXT NOT NULL,
+                link TEXT,
+                summary TEXT NOT NULL,
+                author TEXT,
+                published_ms INTEGER NOT NULL,
+                is_read INTEGER NOT NULL DEFAULT 0,
+                fetched_ms INTEGER NOT NULL,
+                PRIMARY KEY (feed_url, id)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_items_unread ON items(is_read, published_ms DESC);
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Subscribe to `url`. Returns false if already subscribed.
+    pub fn add_feed(&self, url: &str) -> Result<bool> {
+        let added = self.conn.execute(
+            "INSERT OR IGNORE INTO feeds (url, added_ms) VALUES (?1, ?2)",
+            params![url, Utc::now().timestamp_millis()],
+        )?;
+        Ok(added > 0)
+    }
+
+    /// Unsubscribe from `url`, dropping its items.
+    pub fn remove_feed(&self, url: &str) -> Result<()> {
+        self.conn.execute("DELETE FROM items WHERE feed_url = ?1", params![url])?;
+        self.conn.execute("DELETE FROM feeds WHERE url = ?1", params![url])?;
+        Ok(())
+    }
+
+    /// Subscriptions with their unread counts, by title.
+    pub fn list_feeds(&self) -> Result<Vec<Feed>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT f.url, f.title, f.site_url, f.last_fetched_ms, f.last_error,
+                   (SELECT COUNT(*) FROM items i WHERE i.feed_url = f.url AND i.is_read = 0)
+            FROM feeds f
+            ORDER BY COALESCE(f.title, f.url) COLLATE NOCASE
+            "#,
+        )?;
+        let rows = stmt.query_map([], |row| {
+            let url: String = row.get(0)?;
+            let title: Option<String> = row.get(1)?;
+            let last_fetched: Option<i64> = row.get(3)?;
+            Ok(Feed {
+                title: title.unwrap_or_else(|| url.clone()),
+                url,
+                site_url: row.get(2)?,
+                last_fetched: last_fetched.and_then(DateTime::from_timestamp_millis),
+                last_error: row.get(4)?,
+                unread_count: row.get(5)?,
+            })
+        })?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Validators from the last successful fetch of `url`.
+    pub fn validators(&self, url: &str) -> Result<CacheValidators> {
+        let validators = self
+            .conn
+            .query_row(
+                "SELECT etag, last_modified FROM feeds WHERE url = ?1",
+                params![url],
+                |row| Ok(CacheValidators { etag: row.get(0)?, last_modified: row.get(1)? }),
+            )
+            .optional()?;
+        Ok(validators.unwrap_or_default())
+    }
+
+    /// Store a fetched feed. New items arrive unread; items already cached
+    /// keep their read state. Returns how many items were new.
+    pub fn store_fetch(
+        &mut self,
+        url: &str,
+        feed: &ParsedFeed,
+        validators: &CacheValidators,
+        now: DateTime<Utc>,
+    ) -> Result<usize> {
+        let now_ms = now.timestamp_millis();
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            r#"
+            UPDATE feeds SET title = COALESCE(?2, title), site_url = COALESCE(?3, site_url),
+                etag = ?4, last_modified = ?5, last_fetched_ms = ?6, last_error = NULL
+            WHERE url = ?1
+            "#,
+            params![
+                url,
+                feed.title,
+                feed.site_url,
+                validators.etag,
+                validators.last_modified,
+                now_ms
+            ],
+        )?;
+
+        let mut new_items = 0;
+        {
+            let mut insert = tx.prepare(
+                r#"
+                INSERT OR IGNORE INTO items
+                (feed_url, id, title, link, summary, author, published_ms, is_read, fetched_ms)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 0, ?8)
+                "#,
+            )?;
+            let mut update = tx.prepare(
+                r#"
+                UPDATE items SET title = ?3, link = ?4, summary = ?5, author = ?6, fetched_ms = ?7
+                WHERE feed_url = ?1 AND id = ?2
+                "#,
+            )?;
+            for item in &feed.items {
+                let inserted = insert.execute(params![
+                    url,
+                    item.id,
+                    item.title,
+                    item.link,
+                    item.summary,
+                    item.author,
+                    item.published.timestamp_millis(),
+                    now_ms
+                ])?;
+                if inserted > 0 {
+                    new_items += 1;
+                } else {
+                    update.execute(params![
+                        url,
+                        item.id,
+                        item.title,
+                        item.link,
+                        item.summary,
+                        item.author,
+                        now_ms
+                    ])?;
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(new_items)
+    }
+
+    /// Record a fetch that found nothing new (304 Not Modified).
+    pub fn mark_fetched(&self, url: &str, now: DateTime<Utc>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE feeds SET last_fetched_ms = ?2, last_error = NULL WHERE url = ?1",
+            params![url, now.timestamp_millis()],
+        )?;
+        Ok(())
+    }
+
+    /// Record a failed fetch; cached items are kept.
+    pub fn record_error(&self, url: &str, error: &str, now: DateTime<Utc>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE feeds SET last_fetched_ms = ?2, last_error = ?3 WHERE url = ?1",
+            params![url, now.timestamp_millis(), error],
+        )?;
+        Ok(())
+    }
+
+    /// Items newest first, from one feed or all of them.
+    pub fn list_items(
+        &self,
+        feed_url: Option<&str>,
+        unread_only: bool,
+        limit: u32,
+    ) -> Result<Vec<FeedItem>> {
+        let mut stmt = self.conn.prepare(
+            r#"
+            SELECT feed_url, id, title, link, summary, author, published_ms, is_read
+            FROM items
+            WHERE (?1 IS NULL OR feed_url = ?1) AND (?2 = 0 OR is_read = 0)
+            ORDER BY published_ms DESC
+            LIMIT ?3
+            "#,
+        )?;
+        let rows = stmt.query_map(params![feed_url, unread_only, limit], |row| {
+            Ok(FeedItem {
+                feed_url: row.get(0)?,
+                id: row.get(1)?,
+                title: row.get(2)?,
+                link: row.get(3)?,
+                summary: row.get(4)?,
+                author: row.get(5)?,
+                published: DateTime::from_timestamp_millis(row.get(6)?).unwrap_or_default(),
+                is_read: row.get(7)?,
+            })
+        })?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Mark one item read or unread.
+    pub fn set_read(&self, feed_url: &str, id: &str, read: bool) -> Result<()> {
+        self.conn.execute(
+            "UPDATE items SET is_read = ?3 WHERE feed_url = ?1 AND id = ?2",
+            params![feed_url, id, read],
+        )?;
+        Ok(())
+    }
+
+    /// Mark every item read, in one feed or all of them.
+    pub fn mark_all_read(&self, feed_url: Option<&str>) -> Result<usize> {
+        let changed = self.conn.execute(
+            "UPDATE items SET is_read = 1 WHERE is_read = 0 AND (?1 IS NULL OR feed_url = ?1)",
+            params![feed_url],
+        )?;
+        Ok(changed)
+    }
+
+    /// Unread items across all feeds.
+    pub fn unread_count(&self) -> Result<u32> {
+        Ok(self.conn.query_row("SELECT COUNT(*) FROM items WHERE is_read = 0", [], |row| {
+            row.get(0)
+        })?)
+    }
+
+    /// Drop read items last seen in a feed before `before`. Unread items are
+    /// kept however old they are.
+    pub fn prune(&self, before: DateTime<Utc>) -> Result<usize> {
+        let removed = self.conn.execute(
+            "DELETE FROM items WHERE is_read = 1 AND fetched_ms < ?1",
+            params![before.timestamp_millis()],
+        )?;
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    const URL: &str = "https://example.com/feed.xml";
+
+    fn item(id: &str, title: &str, hour: u32) -> FeedItem {
+        FeedItem {
+            id: id.to_string(),
+            feed_url: URL.to_string(),
+            title: title.to_string(),
+            link: Some(format!("https://example.com/{}", id)),
+            summary: String::new(),
+            author: None,
+            published: Utc.with_ymd_and_hms(2026, 3, 9, hour, 0, 0).unwrap(),
+            is_read: false,
+        }
+    }
+
+    fn parsed(items: Vec<FeedItem>) -> ParsedFeed {
+        ParsedFeed {
+            title: Some("Example".to_string()),
+            site_url: Some("https://example.com/".to_string()),
+            items,
+        }
+    }
+
+    #[test]
+    fn subscriptions_use_url_until_fetched() {
+        let cache = FeedCache::in_memory().unwrap();
+        assert!(cache.add_feed(URL).unwrap());
+        assert!(!cache.add_feed(URL).unwrap());
+
+        let feeds = cache.list_feeds().unwrap();
+        assert_eq!(feeds.len(), 1);
+        assert_eq!(feeds[0].title, URL);
+        assert_eq!(feeds[0].last_fetched, None);
+
+        cache.remove_feed(URL).unwrap();
+        assert!(cache.list_feeds().unwrap().is_empty());
+    }
+
+    #[test]
+    fn refetch_keeps_read_state() {
+        let mut cache = FeedCache::in_memory().unwrap();
+        cache.add_feed(URL).unwrap();
+        let now = Utc::now();
+        let validators =
+            CacheValidators { etag: Some("\"v1\"".to_string()), last_modified: None };
+
+        let first = parsed(vec![item("a", "A", 9), item("b", "B", 10)]);
+        assert_eq!(cache.store_fetch(URL, &first, &validators, now).unwrap(), 2);
+        assert_eq!(cache.validators(URL).unwrap(), validators);
+        assert_eq!(cache.unread_count().unwrap(), 2);
+
+        cache.set_read(URL, "a", true).unwrap();
+        let second = parsed(vec![item("a", "A (edited)", 9), item("c", "C", 11)]);
+        assert_eq!(cache.store_fetch(URL, &second, &validators, now).unwrap(), 1);
+
+        let items = cache.list_items(None, false, 10).unwrap();
+        let ids: Vec<_> = items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["c", "b", "a"]);
+        assert_eq!(items[2].title, "A (edited)");
+        assert!(items[2].is_read);
+
+        let unread = cache.list_items(Some(URL), true, 10).unwrap();
+        assert_eq!(unread.len(), 2);
+        let feeds = cache.list_feeds().unwrap();
+        assert_eq!(feeds[0].title, "Example");
+        assert_eq!(feeds[0].unread_count, 2);
+
+        assert_eq!(cache.mark_all_read(Some(URL)).unwrap(), 2);
+        assert_eq!(cache.unread_count().unwrap(), 0);
+    }
+
+    #[test]
+    fn errors_are_recorded_and_cleared() {
+        let mut cache = FeedCache::in_memory().unwrap();
+        cache.add_feed(URL).unwrap();
+        let now = Utc::now();
+
+        cache.record_error(URL, "HTTP 500", now).unwrap();
+        assert_eq!(cache.list_feeds().unwrap()[0].last_error.as_deref(), Some("HTTP 500"));
+
+        cache.store_fetch(URL, &parsed(vec![]), &CacheValidators::default(), now).unwrap();
+        assert_eq!(cache.list_feeds().unwrap()[0].last_error, None);
+    }
+
+    #[test]
+    fn prune_keeps_unread_items() {
+        let mut cache = FeedCache::in_memory().unwrap();
+        cache.add_feed(URL).unwrap();
+        let old = Utc::now() - Duration::days(60);
+        let items = parsed(vec![item("a", "A", 9), item("b", "B", 10)]);
+        cache.store_fetch(URL, &items, &CacheValidators::default(), old).unwrap();
+        cache.set_read(URL, "a", true).unwrap();
+
+        assert_eq!(cache.prune(Utc::now() - Duration::days(30)).unwrap(), 1);
+        let left = cache.list_items(None, false, 10).unwrap();
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].id, "b");
+    }
+}
@@ -0,0 +1,159 @@
+//! HTTP client for fetching feeds with conditional requests.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use reqwest::header::{HeaderName, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::StatusCode;
+use tracing::instrument;
+
+use crate::error::FeedError;
+use crate::parse::parse_feed;
+use crate::types::{CacheValidators, ParsedFeed};
+
+/// Give up on slow feeds so one server can't stall a refresh
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+const USER_AGENT: &str = concat!("myme/", env!("CARGO_PKG_VERSION"), " (feed reader)");
+
+/// Result of fetching a feed.
+#[derive(Debug)]
+pub enum FetchOutcome {
+    /// The server says nothing changed since the validators were issued
+    NotModified,
+    Updated { feed: ParsedFeed, validators: CacheValidators },
+}
+
+pub struct FeedClient {
+    client: reqwest::Client,
+}
+
+impl Default for FeedClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeedClient {
+    pub fn new() -> Self {
+        Self::new_with_proxies(Vec::new())
+    }
+
+    /// Create a client that sends requests through `proxies`.
+    pub fn new_with_proxies(proxies: Vec<reqwest::Proxy>) -> Self {
+        let builder =
+            reqwest::Client::builder().timeout(REQUEST_TIMEOUT).user_agent(USER_AGENT);
+        let client = proxies
+            .into_iter()
+            .fold(builder, reqwest::ClientBuilder::proxy)
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to build feed client: {}", e);
+                reqwest::Client::new()
+            });
+        Self { client }
+    }
+
+    /// Fetch and parse `url`, sending `validators` from the previous fetch.
+    #[instrument(skip(self, validators), level = "debug")]
+    pub async fn fetch(
+        &self,
+        url: &str,
+        validators: &CacheValidators,
+    ) -> Result<FetchOutcome, FeedError> {
+        let parsed = reqwest::Url::parse(url).map_err(|_| FeedError::InvalidUrl(url.to_string()))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(FeedError::InvalidUrl(url.to_string()));
+        }
+
+        let mut request = self.client.get(parsed);
+        if let Some(etag) = &validators.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        if let Some(modified) = &validators.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, modified);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED {
+            return Ok(FetchOutcome::NotModified);
+        }
+        if !status.is_success() {
+            return Err(FeedError::Http(status.as_u16()));
+        }
+
+        let header = |name: HeaderName| {
+            response.headers().get(name).and_then(|v| v.to_str().ok()).map(str::to_string)
+        };
+        let validators =
+            CacheValidators { etag: header(ETAG), last_modified: header(LAST_MODIFIED) };
+        let body = response.bytes().await?;
+        let feed = parse_feed(url, &body, Utc::now())?;
+        tracing::debug!("Fetched {} items from {}", feed.items.len(), url);
+        Ok(FetchOutcome::Updated { feed, validators })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const RSS: &str = r#"<?xml version="1.0"?>
+<rss version="2.0"><channel><title>Mock</title><link>https://example.com/</link>
+<item><title>Hello</title><guid>1</guid></item></channel></rss>"#;
+
+    #[tokio::test]
+    async fn fetch_sends_validators_and_handles_not_modified() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/feed"))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/feed"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_string(RSS),
+            )
+            .mount(&server)
+            .await;
+
+        let client = FeedClient::new();
+        let url = format!("{}/feed", server.uri());
+
+        let FetchOutcome::Updated { feed, validators } =
+            client.fetch(&url, &CacheValidators::default()).await.unwrap()
+        else {
+            panic!("expected a fresh feed");
+        };
+        assert_eq!(feed.title.as_deref(), Some("Mock"));
+        assert_eq!(feed.items.len(), 1);
+        assert_eq!(validators.etag.as_deref(), Some("\"v1\""));
+
+        let outcome = client.fetch(&url, &validators).await.unwrap();
+        assert!(matches!(outcome, FetchOutcome::NotModified));
+    }
+
+    #[tokio::test]
+    async fn fetch_reports_http_errors_and_bad_urls() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = FeedClient::new();
+        let err = client.fetch(&server.uri(), &CacheValidators::default()).await.unwrap_err();
+        assert!(matches!(err, FeedError::Http(404)));
+
+        let err = client.fetch("ftp://example.com/feed", &CacheValidators::default()).await;
+        assert!(matches!(err, Err(FeedError::InvalidUrl(_))));
+    }
+}
@@ -0,0 +1,61 @@
+//! Feed-specific error types.
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FeedError {
+    #[error("Invalid feed URL: {0}")]
+    InvalidUrl(String),
+
+    #[error("HTTP {0}")]
+    Http(u16),
+
+    #[error("Not a valid RSS or Atom feed: {0}")]
+    Parse(String),
+
+    #[error("Cache error: {0}")]
+    CacheError(String),
+
+    #[error("Network error: {0}")]
+    NetworkError(#[from] reqwest::Error),
+}
+
+impl FeedError {
+    /// User-friendly error message for UI display.
+    pub fn user_message(&self) -> String {
+        match self {
+            Self::InvalidUrl(_) => "That doesn't look like a web address".to_string(),
+            Self::Http(404) => "Feed not found".to_string(),
+            Self::Http(status) => format!("The site returned an error ({})", status),
+            Self::Parse(_) => "That address isn't an RSS or Atom feed".to_string(),
+            Self::CacheError(_) => "Local cache error".to_string(),
+            Self::NetworkError(_) => "Network error. Check your connection.".to_string(),
+        }
+    }
+
+    /// Whether this error is retryable.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Http(429 | 500..=599) | Self::NetworkError(_))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_error_user_messages() {
+        assert_eq!(FeedError::Http(404).user_message(), "Feed not found");
+        assert!(FeedError::Http(503).user_message().contains("503"));
+        assert!(FeedError::Parse("eof".into()).user_message().contains("RSS"));
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(FeedError::Http(503).is_retryable());
+        assert!(FeedError::Http(429).is_retryable());
+        assert!(!FeedError::Http(404).is_retryable());
+        assert!(!FeedError::Parse("x".into()).is_retryable());
+    }
+}
@@ -0,0 +1,16 @@
+//! RSS and Atom feeds for MyMe.
+//!
+//! Provides a feed client with conditional fetching, parsing into plain-text
+//! items, and an offline cache with unread tracking.
+
+pub mod cache;
+pub mod client;
+pub mod error;
+pub mod parse;
+pub mod types;
+
+pub use cache::FeedCache;
+pub use client::{FeedClient, FetchOutcome};
+pub use error::FeedError;
+pub use parse::{parse_feed, strip_html};
+pub use types::{CacheValidators, Feed, FeedItem, ParsedFeed};
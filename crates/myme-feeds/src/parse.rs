@@ -0,0 +1,240 @@
+//! Parse RSS and Atom documents into cacheable items.
+
+use chrono::{DateTime, Utc};
+use feed_rs::model::Link;
+
+use crate::error::FeedError;
+use crate::types::{FeedItem, ParsedFeed};
+
+/// Summaries are cut to this many characters
+const SUMMARY_CHARS: usize = 300;
+
+/// The page a set of links points at: the `alternate` (or unlabeled) link,
+/// never the feed's own `self` link.
+fn page_link(links: &[Link]) -> Option<String> {
+    links
+        .iter()
+        .find(|l| l.rel.as_deref().is_none_or(|rel| rel == "alternate"))
+        .map(|l| l.href.trim().to_string())
+        .filter(|href| !href.is_empty())
+}
+
+/// Parse an RSS (0.9x, 1.0, 2.0) or Atom document fetched from `feed_url`.
+/// Items without a date are stamped with `now`.
+pub fn parse_feed(feed_url: &str, body: &[u8], now: DateTime<Utc>) -> Result<ParsedFeed, FeedError> {
+    let feed = feed_rs::parser::parse(body).map_err(|e| FeedError::Parse(e.to_string()))?;
+
+    let items = feed
+        .entries
+        .into_iter()
+        .map(|entry| {
+            let text = entry
+                .summary
+                .map(|s| s.content)
+                .or_else(|| entry.content.and_then(|c| c.body))
+                .unwrap_or_default();
+            let link = page_link(&entry.links);
+            let title = entry
+                .title
+                .map(|t| strip_html(&t.content))
+                .filter(|t| !t.is_empty())
+                .or_else(|| link.clone())
+                .unwrap_or_else(|| "(untitled)".to_string());
+            FeedItem {
+                id: entry.id,
+                feed_url: feed_url.to_string(),
+                title,
+                link,
+                summary: excerpt(&strip_html(&text), SUMMARY_CHARS),
+                author: entry.authors.into_iter().map(|a| a.name).find(|n| !n.trim().is_empty()),
+                published: entry.published.or(entry.updated).unwrap_or(now),
+                is_read: false,
+            }
+        })
+        .collect();
+
+    Ok(ParsedFeed {
+        title: feed.title.map(|t| strip_html(&t.content)).filter(|t| !t.is_empty()),
+        site_url: page_link(&feed.links),
+        items,
+    })
+}
+
+/// At most `max` characters of `text`, cut at a word boundary with an ellipsis.
+fn excerpt(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max).collect();
+    let cut = cut.rsplit_once(' ').map_or(cut.as_str(), |(head, _)| head);
+    format!("{}…", cut.trim_end())
+}
+
+/// Plain text from an HTML fragment: tags dropped (with `script`/`style`
+/// contents), common entities decoded and whitespace collapsed.
+pub fn strip_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let tag = &rest[start..];
+        let Some(end) = tag.find('>') else {
+            rest = "";
+            break;
+        };
+        let name = tag[1..end].trim_start_matches('/').split_whitespace().next().unwrap_or("");
+        rest = &tag[end + 1..];
+        let name = name.to_ascii_lowercase();
+        if name == "script" || name == "style" {
+            let close = format!("</{}", name);
+            rest = rest
+                .to_ascii_lowercase()
+                .find(&close)
+                .and_then(|i| rest[i..].find('>').map(|j| &rest[i + j + 1..]))
+                .unwrap_or("");
+        }
+        // Block-level tags separate words
+        text.push(' ');
+    }
+    text.push_str(rest);
+    decode_entities(&text).split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let decoded = after.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &after[..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::TimeZone;
+
+    const RSS: &str = r#"<?xml version="1.0"?>
+<rss version="2.0">
+  <channel>
+    <title>Example &amp; Co</title>
+    <link>https://example.com/</link>
+    <item>
+      <title>First post</title>
+      <link>https://example.com/first</link>
+      <guid>https://example.com/first</guid>
+      <pubDate>Mon, 09 Mar 2026 10:00:00 GMT</pubDate>
+      <description>&lt;p&gt;Hello &lt;b&gt;world&lt;/b&gt;&lt;/p&gt;</description>
+    </item>
+    <item>
+      <title>Undated</title>
+      <guid>undated-1</guid>
+    </item>
+  </channel>
+</rss>"#;
+
+    const ATOM: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Atom Blog</title>
+  <link rel="self" href="https://blog.example.org/feed.xml"/>
+  <link href="https://blog.example.org/"/>
+  <id>urn:uuid:60a76c80-d399-11d9-b93C-0003939e0af6</id>
+  <updated>2026-03-09T18:30:02Z</updated>
+  <entry>
+    <title type="html">Release &lt;em&gt;1.0&lt;/em&gt;</title>
+    <link rel="alternate" href="https://blog.example.org/release"/>
+    <id>urn:uuid:1225c695-cfb8-4ebb-aaaa-80da344efa6a</id>
+    <updated>2026-03-09T18:30:02Z</updated>
+    <author><name>Ada</name></author>
+    <content type="html">&lt;p&gt;It shipped.&lt;/p&gt;</content>
+  </entry>
+</feed>"#;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn parses_rss() {
+        let feed = parse_feed("https://example.com/rss", RSS.as_bytes(), now()).unwrap();
+        assert_eq!(feed.title.as_deref(), Some("Example & Co"));
+        assert_eq!(feed.site_url.as_deref(), Some("https://example.com/"));
+        assert_eq!(feed.items.len(), 2);
+
+        let first = &feed.items[0];
+        assert_eq!(first.title, "First post");
+        assert_eq!(first.link.as_deref(), Some("https://example.com/first"));
+        assert_eq!(first.summary, "Hello world");
+        assert_eq!(first.published, Utc.with_ymd_and_hms(2026, 3, 9, 10, 0, 0).unwrap());
+        assert_eq!(first.feed_url, "https://example.com/rss");
+
+        assert_eq!(feed.items[1].published, now());
+    }
+
+    #[test]
+    fn parses_atom() {
+        let feed = parse_feed("https://blog.example.org/feed.xml", ATOM.as_bytes(), now()).unwrap();
+        assert_eq!(feed.title.as_deref(), Some("Atom Blog"));
+        // The self link is the feed, not the site
+        assert_eq!(feed.site_url.as_deref(), Some("https://blog.example.org/"));
+
+        let entry = &feed.items[0];
+        assert_eq!(entry.title, "Release 1.0");
+        assert_eq!(entry.link.as_deref(), Some("https://blog.example.org/release"));
+        assert_eq!(entry.author.as_deref(), Some("Ada"));
+        assert_eq!(entry.summary, "It shipped.");
+    }
+
+    #[test]
+    fn rejects_html_pages() {
+        let err = parse_feed("https://example.com", b"<html><body>Hi</body></html>", now());
+        assert!(matches!(err, Err(FeedError::Parse(_))));
+    }
+
+    #[test]
+    fn strip_html_drops_tags_scripts_and_entities() {
+        assert_eq!(
+            strip_html("<p>Caf&eacute; &amp; <i>bar</i>&#33;</p><script>alert(1)</script><br/>End"),
+            "Caf&eacute; & bar ! End"
+        );
+        assert_eq!(strip_html("a&#x2014;b &lt;tag&gt; 5 &lt 6"), "a\u{2014}b <tag> 5 &lt 6");
+        assert_eq!(strip_html("unterminated <b"), "unterminated");
+    }
+
+    #[test]
+    fn excerpt_cuts_at_word_boundary() {
+        assert_eq!(excerpt("short", 10), "short");
+        assert_eq!(excerpt("one two three four", 10), "one two…");
+    }
+}
@@ -0,0 +1,50 @@
+//! Feed and item types.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A subscribed feed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Feed {
+    pub url: String,
+    /// Title from the feed, or the URL until the first successful fetch
+    pub title: String,
+    /// The website the feed belongs to
+    pub site_url: Option<String>,
+    pub last_fetched: Option<DateTime<Utc>>,
+    /// Why the last refresh failed; cleared on success
+    pub last_error: Option<String>,
+    pub unread_count: u32,
+}
+
+/// One article or post.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedItem {
+    /// The feed's id for the item (guid or Atom id), unique within the feed
+    pub id: String,
+    pub feed_url: String,
+    pub title: String,
+    pub link: Option<String>,
+    /// Plain-text excerpt
+    pub summary: String,
+    pub author: Option<String>,
+    /// Publication time; items without one get the time they were first seen
+    pub published: DateTime<Utc>,
+    pub is_read: bool,
+}
+
+/// Result of parsing a feed document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedFeed {
+    pub title: Option<String>,
+    pub site_url: Option<String>,
+    pub items: Vec<FeedItem>,
+}
+
+/// HTTP validators from the last fetch, sent back so unchanged feeds
+/// answer 304 Not Modified.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
@@ -1,10 +1,74 @@
 //! SQLite-based offline cache for Gmail messages and labels.
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use myme_services::db;
 use rusqlite::{params, Connection};
 use std::path::Path;
 
-use crate::types::{Label, LabelType, Message};
+use crate::types::{Attachment, Label, LabelType, Message, Thread};
+use crate::unsubscribe::{sender_address, sender_name, SenderSummary, UnsubscribeTarget};
+
+/// Gmail's system label for the inbox
+const INBOX: &str = "INBOX";
+
+/// A message snoozed out of the inbox until `until`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snooze {
+    pub message_id: String,
+    pub until: DateTime<Utc>,
+}
+
+/// Which messages keep their full body offline: those received in the last
+/// `full_body_days` days, plus any opened within that window. Older mail is
+/// kept as headers and snippet only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefetchPolicy {
+    pub full_body_days: u32,
+}
+
+impl PrefetchPolicy {
+    pub fn new(full_body_days: u32) -> Self {
+        Self { full_body_days }
+    }
+
+    /// Oldest date whose body is kept.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - chrono::Duration::days(i64::from(self.full_body_days))
+    }
+
+    /// Whether a message received at `date` keeps its body.
+    pub fn keeps_body(&self, date: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        date >= self.cutoff(now)
+    }
+}
+
+/// Bytes the cache holds in message bodies and downloaded attachments.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheUsage {
+    pub bodies: u64,
+    pub attachments: u64,
+}
+
+impl CacheUsage {
+    pub fn total(&self) -> u64 {
+        self.bodies + self.attachments
+    }
+}
+
+/// What `enforce_quota` evicted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Eviction {
+    pub bodies: usize,
+    pub attachments: usize,
+    pub freed: u64,
+}
+
+/// A body or downloaded attachment that can be evicted, by last use.
+enum Evictable {
+    Body { id: String },
+    Attachment { message_id: String, attachment_id: String, local_path: String },
+}
 
 /// SQLite cache for Gmail data.
 pub struct GmailCache {
@@ -14,21 +78,31 @@ pub struct GmailCache {
 impl GmailCache {
     /// Create a new cache at the given path.
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let conn = Connection::open(path)?;
+        let conn = db::open_maintained(path.as_ref())?;
         let cache = Self { conn };
-        cache.init_schema()?;
+        cache.migrate()?;
         Ok(cache)
     }
 
     /// Create an in-memory cache (for testing).
     #[cfg(test)]
     pub fn in_memory() -> Result<Self> {
-        let conn = Connection::open_in_memory()?;
+        let conn = db::open_in_memory()?;
         let cache = Self { conn };
-        cache.init_schema()?;
+        cache.migrate()?;
         Ok(cache)
     }
 
+    fn migrate(&self) -> Result<()> {
+        db::migrate(&self.conn, 4, |version| match version {
+            1 => self.init_schema(),
+            2 => self.add_snoozes(),
+            3 => self.add_unsubscribe_targets(),
+            4 => self.add_cache_accounting(),
+            _ => Ok(()),
+        })
+    }
+
     /// Initialize the database schema.
     fn init_schema(&self) -> Result<()> {
         self.conn.execute_batch(
@@ -57,6 +131,25 @@ impl GmailCache {
                 cached_at INTEGER NOT NULL
             );
 
+            CREATE TABLE IF NOT EXISTS threads (
+                id TEXT PRIMARY KEY,
+                snippet TEXT NOT NULL,
+                history_id TEXT,
+                last_message_ms INTEGER NOT NULL,
+                message_count INTEGER NOT NULL,
+                cached_at INTEGER NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS attachments (
+                message_id TEXT NOT NULL,
+                attachment_id TEXT NOT NULL,
+                filename TEXT NOT NULL,
+                mime_type TEXT NOT NULL,
+                size INTEGER NOT NULL,
+                local_path TEXT,
+                PRIMARY KEY (message_id, attachment_id)
+            );
+
             CREATE TABLE IF NOT EXISTS sync_state (
                 key TEXT PRIMARY KEY,
                 value TEXT NOT NULL
@@ -65,22 +158,91 @@ impl GmailCache {
             CREATE INDEX IF NOT EXISTS idx_messages_date ON messages(date_ms DESC);
             CREATE INDEX IF NOT EXISTS idx_messages_thread ON messages(thread_id);
             CREATE INDEX IF NOT EXISTS idx_messages_unread ON messages(is_unread);
+            CREATE INDEX IF NOT EXISTS idx_threads_last ON threads(last_message_ms DESC);
             "#,
         )?;
         Ok(())
     }
 
-    /// Store a message in the cache.
+    /// Messages snoozed out of the inbox until a given time (local only).
+    fn add_snoozes(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS snoozed_until (
+                message_id TEXT PRIMARY KEY,
+                until_ms INTEGER NOT NULL,
+                snoozed_at INTEGER NOT NULL
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_snoozed_until ON snoozed_until(until_ms);
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Unsubscribe targets per sender address, from `List-Unsubscribe` headers.
+    fn add_unsubscribe_targets(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS unsubscribe_targets (
+                sender TEXT PRIMARY KEY,
+                http_url TEXT,
+                mailto TEXT,
+                one_click INTEGER NOT NULL,
+                seen_ms INTEGER NOT NULL,
+                unsubscribed_ms INTEGER
+            );
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// When bodies and downloaded attachments were last opened, for LRU eviction.
+    fn add_cache_accounting(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            ALTER TABLE messages ADD COLUMN accessed_at INTEGER;
+            ALTER TABLE attachments ADD COLUMN accessed_at INTEGER;
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Store a message in the cache. A message without a body keeps the one
+    /// already cached.
     pub fn store_message(&self, msg: &Message) -> Result<()> {
+        self.store_message_body(msg, msg.body.as_deref())
+    }
+
+    /// Store a message, keeping its body only if `policy` says so.
+    pub fn store_message_with_policy(
+        &self,
+        msg: &Message,
+        policy: &PrefetchPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let body = msg.body.as_deref().filter(|_| policy.keeps_body(msg.date, now));
+        self.store_message_body(msg, body)
+    }
+
+    fn store_message_body(&self, msg: &Message, body: Option<&str>) -> Result<()> {
         let to_json = serde_json::to_string(&msg.to)?;
         let labels_json = serde_json::to_string(&msg.labels)?;
         let now = chrono::Utc::now().timestamp_millis();
 
         self.conn.execute(
             r#"
-            INSERT OR REPLACE INTO messages
+            INSERT INTO messages
             (id, thread_id, from_addr, to_addrs, subject, snippet, date_ms, labels, is_unread, is_starred, body, cached_at)
             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+            ON CONFLICT(id) DO UPDATE SET
+                thread_id = excluded.thread_id, from_addr = excluded.from_addr,
+                to_addrs = excluded.to_addrs, subject = excluded.subject,
+                snippet = excluded.snippet, date_ms = excluded.date_ms,
+                labels = excluded.labels, is_unread = excluded.is_unread,
+                is_starred = excluded.is_starred,
+                body = COALESCE(excluded.body, messages.body),
+                cached_at = excluded.cached_at
             "#,
             params![
                 msg.id,
@@ -93,10 +255,197 @@ impl GmailCache {
                 labels_json,
                 msg.is_unread as i32,
                 msg.is_starred as i32,
-                msg.body,
+                body,
                 now,
             ],
         )?;
+        self.store_attachments(&msg.attachments)?;
+        if let Some(target) = &msg.unsubscribe {
+            self.store_unsubscribe_target(&sender_address(&msg.from), target)?;
+        }
+        Ok(())
+    }
+
+    /// Remember how to unsubscribe from `sender`, keeping when the user last did.
+    pub fn store_unsubscribe_target(&self, sender: &str, target: &UnsubscribeTarget) -> Result<()> {
+        self.conn.execute(
+            r#"
+            INSERT INTO unsubscribe_targets (sender, http_url, mailto, one_click, seen_ms)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(sender) DO UPDATE SET
+                http_url = excluded.http_url, mailto = excluded.mailto,
+                one_click = excluded.one_click, seen_ms = excluded.seen_ms
+            "#,
+            params![
+                sender,
+                target.http,
+                target.mailto,
+                target.one_click as i32,
+                Utc::now().timestamp_millis()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// How to unsubscribe from `sender` (a lowercased address), if known.
+    pub fn unsubscribe_target(&self, sender: &str) -> Result<Option<UnsubscribeTarget>> {
+        Ok(self.unsubscribe_row(sender)?.map(|(target, _)| target))
+    }
+
+    fn unsubscribe_row(
+        &self,
+        sender: &str,
+    ) -> Result<Option<(UnsubscribeTarget, Option<DateTime<Utc>>)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT http_url, mailto, one_click, unsubscribed_ms
+             FROM unsubscribe_targets WHERE sender = ?1",
+        )?;
+        let mut rows = stmt.query(params![sender])?;
+        let Some(row) = rows.next()? else {
+            return Ok(None);
+        };
+        let target = UnsubscribeTarget {
+            http: row.get(0)?,
+            mailto: row.get(1)?,
+            one_click: row.get::<_, i32>(2)? != 0,
+        };
+        let unsubscribed = row.get::<_, Option<i64>>(3)?.and_then(DateTime::from_timestamp_millis);
+        Ok(Some((target, unsubscribed)))
+    }
+
+    /// Record that the user unsubscribed from `sender`.
+    pub fn mark_unsubscribed(&self, sender: &str, at: DateTime<Utc>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE unsubscribe_targets SET unsubscribed_ms = ?2 WHERE sender = ?1",
+            params![sender, at.timestamp_millis()],
+        )?;
+        Ok(())
+    }
+
+    /// Cached messages from `sender` received since `since`, with its
+    /// unsubscribe target. None if there are none.
+    pub fn sender_summary(
+        &self,
+        sender: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Option<SenderSummary>> {
+        Ok(self.sender_summaries(since)?.into_iter().find(|s| s.sender == sender))
+    }
+
+    /// Messages per sender received since `since`, most prolific first.
+    pub fn sender_summaries(&self, since: DateTime<Utc>) -> Result<Vec<SenderSummary>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT from_addr FROM messages WHERE date_ms >= ?1 ORDER BY date_ms DESC")?;
+        let froms = stmt
+            .query_map(params![since.timestamp_millis()], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Newest first, so the first header seen gives the current display name
+        let mut summaries: Vec<SenderSummary> = Vec::new();
+        for from in froms {
+            let sender = sender_address(&from);
+            match summaries.iter_mut().find(|s| s.sender == sender) {
+                Some(summary) => summary.count += 1,
+                None => summaries.push(SenderSummary {
+                    name: sender_name(&from),
+                    sender,
+                    count: 1,
+                    target: None,
+                    unsubscribed_at: None,
+                }),
+            }
+        }
+        for summary in &mut summaries {
+            if let Some((target, unsubscribed_at)) = self.unsubscribe_row(&summary.sender)? {
+                summary.target = Some(target);
+                summary.unsubscribed_at = unsubscribed_at;
+            }
+        }
+        summaries.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.sender.cmp(&b.sender)));
+        Ok(summaries)
+    }
+
+    /// Store attachment metadata, keeping any previously downloaded local path.
+    pub fn store_attachments(&self, attachments: &[Attachment]) -> Result<()> {
+        for att in attachments {
+            self.conn.execute(
+                r#"
+                INSERT INTO attachments (message_id, attachment_id, filename, mime_type, size)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT(message_id, attachment_id) DO UPDATE SET
+                    filename = excluded.filename,
+                    mime_type = excluded.mime_type,
+                    size = excluded.size
+                "#,
+                params![
+                    att.message_id,
+                    att.attachment_id,
+                    att.filename,
+                    att.mime_type,
+                    att.size as i64
+                ],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// List attachment metadata for a message.
+    pub fn list_attachments(&self, message_id: &str) -> Result<Vec<Attachment>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT message_id, attachment_id, filename, mime_type, size, local_path
+             FROM attachments WHERE message_id = ?1 ORDER BY filename",
+        )?;
+
+        let rows = stmt.query_map(params![message_id], |row| {
+            Ok(Attachment {
+                message_id: row.get(0)?,
+                attachment_id: row.get(1)?,
+                filename: row.get(2)?,
+                mime_type: row.get(3)?,
+                size: row.get::<_, i64>(4)?.max(0) as u64,
+                local_path: row.get(5)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to read attachments: {}", e))
+    }
+
+    /// Record where an attachment was downloaded to.
+    pub fn set_attachment_path(
+        &self,
+        message_id: &str,
+        attachment_id: &str,
+        local_path: &str,
+    ) -> Result<()> {
+        self.conn.execute(
+            "UPDATE attachments SET local_path = ?3, accessed_at = ?4
+             WHERE message_id = ?1 AND attachment_id = ?2",
+            params![message_id, attachment_id, local_path, Utc::now().timestamp_millis()],
+        )?;
+        Ok(())
+    }
+
+    /// Record that a message was opened, so its body is evicted last.
+    pub fn touch_message(&self, id: &str, at: DateTime<Utc>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE messages SET accessed_at = ?2 WHERE id = ?1",
+            params![id, at.timestamp_millis()],
+        )?;
+        Ok(())
+    }
+
+    /// Record that a downloaded attachment was opened.
+    pub fn touch_attachment(
+        &self,
+        message_id: &str,
+        attachment_id: &str,
+        at: DateTime<Utc>,
+    ) -> Result<()> {
+        self.conn.execute(
+            "UPDATE attachments SET accessed_at = ?3 WHERE message_id = ?1 AND attachment_id = ?2",
+            params![message_id, attachment_id, at.timestamp_millis()],
+        )?;
         Ok(())
     }
 
@@ -142,12 +491,115 @@ impl GmailCache {
             .map_err(|e| anyhow::anyhow!("Failed to read messages: {}", e))
     }
 
+    /// Up to `limit` cached messages whose subject, sender, snippet or body
+    /// contain every word of `query` (case-insensitive), newest first.
+    pub fn search_messages(&self, query: &str, limit: u32) -> Result<Vec<Message>> {
+        let words: Vec<String> =
+            query.split_whitespace().map(|w| format!("%{}%", w.to_lowercase())).collect();
+        if words.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conditions: Vec<String> = (0..words.len())
+            .map(|i| {
+                format!(
+                    "(subject || char(10) || from_addr || char(10) || snippet || char(10) \
+                     || coalesce(body, '')) LIKE ?{}",
+                    i + 1
+                )
+            })
+            .collect();
+        let sql = format!(
+            "SELECT id, thread_id, from_addr, to_addrs, subject, snippet, date_ms, labels, is_unread, is_starred, body
+             FROM messages
+             WHERE {}
+             ORDER BY date_ms DESC
+             LIMIT {}",
+            conditions.join(" AND "),
+            limit
+        );
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(rusqlite::params_from_iter(&words), Self::row_to_message)?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to search messages: {}", e))
+    }
+
     /// Delete a message from the cache.
     pub fn delete_message(&self, id: &str) -> Result<()> {
         self.conn.execute("DELETE FROM messages WHERE id = ?1", params![id])?;
         Ok(())
     }
 
+    /// Store a thread and all of its messages in the cache.
+    pub fn store_thread(&self, thread: &Thread) -> Result<()> {
+        for msg in &thread.messages {
+            self.store_message(msg)?;
+        }
+        let now = chrono::Utc::now().timestamp_millis();
+
+        self.conn.execute(
+            r#"
+            INSERT OR REPLACE INTO threads
+            (id, snippet, history_id, last_message_ms, message_count, cached_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+            params![
+                thread.id,
+                thread.snippet,
+                thread.history_id,
+                thread.latest_date().timestamp_millis(),
+                thread.messages.len() as i64,
+                now,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Get a thread from the cache with its messages (oldest first).
+    pub fn get_thread(&self, thread_id: &str) -> Result<Option<Thread>> {
+        let header: Option<(String, Option<String>)> = {
+            let mut stmt =
+                self.conn.prepare("SELECT snippet, history_id FROM threads WHERE id = ?1")?;
+            let mut rows = stmt.query(params![thread_id])?;
+            match rows.next()? {
+                Some(row) => Some((row.get(0)?, row.get(1)?)),
+                None => None,
+            }
+        };
+
+        let messages = self.list_thread_messages(thread_id)?;
+        if header.is_none() && messages.is_empty() {
+            return Ok(None);
+        }
+
+        let (snippet, history_id) = header.unwrap_or_else(|| {
+            (messages.last().map(|m| m.snippet.clone()).unwrap_or_default(), None)
+        });
+        Ok(Some(Thread { id: thread_id.to_string(), snippet, history_id, messages }))
+    }
+
+    /// List messages belonging to a thread, oldest first.
+    pub fn list_thread_messages(&self, thread_id: &str) -> Result<Vec<Message>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, thread_id, from_addr, to_addrs, subject, snippet, date_ms, labels, is_unread, is_starred, body
+             FROM messages
+             WHERE thread_id = ?1
+             ORDER BY date_ms ASC",
+        )?;
+
+        let rows = stmt.query_map(params![thread_id], Self::row_to_message)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to read thread messages: {}", e))
+    }
+
+    /// List cached messages grouped into threads, newest thread first.
+    pub fn list_threads(&self, label: Option<&str>, limit: u32) -> Result<Vec<Thread>> {
+        let messages = self.list_messages(label, limit)?;
+        Ok(Thread::group(messages))
+    }
+
     /// Store a label in the cache.
     pub fn store_label(&self, label: &Label) -> Result<()> {
         let now = chrono::Utc::now().timestamp_millis();
@@ -199,6 +651,80 @@ impl GmailCache {
             .map_err(|e| anyhow::anyhow!("Failed to read labels: {}", e))
     }
 
+    /// Snooze a message until `until`, taking it out of the cached inbox.
+    /// Snoozing an already snoozed message moves its wake-up time.
+    pub fn snooze(&self, message_id: &str, until: DateTime<Utc>) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO snoozed_until (message_id, until_ms, snoozed_at)
+             VALUES (?1, ?2, ?3)",
+            params![message_id, until.timestamp_millis(), Utc::now().timestamp_millis()],
+        )?;
+        self.set_in_inbox(message_id, false)
+    }
+
+    /// Cancel a snooze without returning the message to the inbox.
+    /// Returns true if the message was snoozed.
+    pub fn unsnooze(&self, message_id: &str) -> Result<bool> {
+        let removed = self
+            .conn
+            .execute("DELETE FROM snoozed_until WHERE message_id = ?1", params![message_id])?;
+        Ok(removed > 0)
+    }
+
+    /// When a snoozed message wakes up, if it is snoozed.
+    pub fn snoozed_until(&self, message_id: &str) -> Result<Option<DateTime<Utc>>> {
+        let mut stmt =
+            self.conn.prepare("SELECT until_ms FROM snoozed_until WHERE message_id = ?1")?;
+        let mut rows = stmt.query(params![message_id])?;
+        match rows.next()? {
+            Some(row) => Ok(DateTime::from_timestamp_millis(row.get(0)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// All snoozes, soonest wake-up first.
+    pub fn list_snoozes(&self) -> Result<Vec<Snooze>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT message_id, until_ms FROM snoozed_until ORDER BY until_ms ASC")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(Snooze {
+                message_id: row.get(0)?,
+                until: DateTime::from_timestamp_millis(row.get(1)?).unwrap_or_default(),
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to read snoozes: {}", e))
+    }
+
+    /// Remove snoozes that are due at `now` and put their messages back in the
+    /// cached inbox. Returns the due snoozes, soonest first.
+    pub fn take_due_snoozes(&self, now: DateTime<Utc>) -> Result<Vec<Snooze>> {
+        let due: Vec<Snooze> =
+            self.list_snoozes()?.into_iter().filter(|s| s.until <= now).collect();
+        for snooze in &due {
+            self.unsnooze(&snooze.message_id)?;
+            self.set_in_inbox(&snooze.message_id, true)?;
+        }
+        Ok(due)
+    }
+
+    /// Add or remove the INBOX label on a cached message.
+    fn set_in_inbox(&self, message_id: &str, in_inbox: bool) -> Result<()> {
+        let Some(mut msg) = self.get_message(message_id)? else {
+            return Ok(());
+        };
+        msg.labels.retain(|l| l != INBOX);
+        if in_inbox {
+            msg.labels.push(INBOX.to_string());
+        }
+        self.conn.execute(
+            "UPDATE messages SET labels = ?2 WHERE id = ?1",
+            params![message_id, serde_json::to_string(&msg.labels)?],
+        )?;
+        Ok(())
+    }
+
     /// Get unread message count.
     pub fn unread_count(&self) -> Result<u32> {
         let count: u32 = self.conn.query_row(
@@ -232,10 +758,142 @@ impl GmailCache {
         Ok(())
     }
 
+    /// Drop bodies of messages older than the policy window, unless opened
+    /// within it. Returns how many bodies were dropped.
+    pub fn apply_prefetch_policy(
+        &self,
+        policy: &PrefetchPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<usize> {
+        let cutoff = policy.cutoff(now).timestamp_millis();
+        let dropped = self.conn.execute(
+            "UPDATE messages SET body = NULL
+             WHERE body IS NOT NULL AND date_ms < ?1 AND COALESCE(accessed_at, 0) < ?1",
+            params![cutoff],
+        )?;
+        Ok(dropped)
+    }
+
+    /// IDs of up to `limit` messages inside the policy window whose body isn't
+    /// cached, newest first.
+    pub fn messages_missing_body(
+        &self,
+        policy: &PrefetchPolicy,
+        now: DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id FROM messages WHERE body IS NULL AND date_ms >= ?1
+             ORDER BY date_ms DESC LIMIT ?2",
+        )?;
+        let rows = stmt
+            .query_map(params![policy.cutoff(now).timestamp_millis(), limit], |row| row.get(0))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to read messages: {}", e))
+    }
+
+    /// Bytes held in cached bodies and downloaded attachments.
+    pub fn cache_size(&self) -> Result<CacheUsage> {
+        let bodies: i64 = self.conn.query_row(
+            "SELECT COALESCE(SUM(length(CAST(body AS BLOB))), 0) FROM messages",
+            [],
+            |row| row.get(0),
+        )?;
+        let attachments: i64 = self.conn.query_row(
+            "SELECT COALESCE(SUM(size), 0) FROM attachments WHERE local_path IS NOT NULL",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(CacheUsage { bodies: bodies.max(0) as u64, attachments: attachments.max(0) as u64 })
+    }
+
+    /// Evict least recently used bodies and downloaded attachments until the
+    /// cache holds at most `max_bytes`. Evicted attachment files are deleted;
+    /// headers stay, so evicted mail still lists and can be fetched again.
+    pub fn enforce_quota(&self, max_bytes: u64) -> Result<Eviction> {
+        let mut eviction = Eviction::default();
+        let mut used = self.cache_size()?.total();
+        if used <= max_bytes {
+            return Ok(eviction);
+        }
+
+        let mut candidates: Vec<(i64, u64, Evictable)> = Vec::new();
+        {
+            let mut stmt = self.conn.prepare(
+                "SELECT id, length(CAST(body AS BLOB)), COALESCE(accessed_at, cached_at)
+                 FROM messages WHERE body IS NOT NULL",
+            )?;
+            let rows = stmt.query_map([], |row| {
+                Ok((row.get::<_, i64>(2)?, row.get::<_, i64>(1)?, row.get::<_, String>(0)?))
+            })?;
+            for row in rows {
+                let (used_at, size, id) = row?;
+                candidates.push((used_at, size.max(0) as u64, Evictable::Body { id }));
+            }
+
+            let mut stmt = self.conn.prepare(
+                "SELECT message_id, attachment_id, local_path, size, COALESCE(accessed_at, 0)
+                 FROM attachments WHERE local_path IS NOT NULL",
+            )?;
+            let rows = stmt.query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(4)?,
+                    row.get::<_, i64>(3)?,
+                    Evictable::Attachment {
+                        message_id: row.get(0)?,
+                        attachment_id: row.get(1)?,
+                        local_path: row.get(2)?,
+                    },
+                ))
+            })?;
+            for row in rows {
+                let (used_at, size, item) = row?;
+                candidates.push((used_at, size.max(0) as u64, item));
+            }
+        }
+        candidates.sort_by_key(|(used_at, _, _)| *used_at);
+
+        for (_, size, item) in candidates {
+            if used <= max_bytes {
+                break;
+            }
+            match item {
+                Evictable::Body { id } => {
+                    self.conn
+                        .execute("UPDATE messages SET body = NULL WHERE id = ?1", params![id])?;
+                    eviction.bodies += 1;
+                }
+                Evictable::Attachment { message_id, attachment_id, local_path } => {
+                    if let Err(e) = std::fs::remove_file(&local_path) {
+                        if e.kind() != std::io::ErrorKind::NotFound {
+                            tracing::warn!(
+                                "Failed to remove cached attachment {}: {}",
+                                local_path,
+                                e
+                            );
+                        }
+                    }
+                    self.conn.execute(
+                        "UPDATE attachments SET local_path = NULL
+                         WHERE message_id = ?1 AND attachment_id = ?2",
+                        params![message_id, attachment_id],
+                    )?;
+                    eviction.attachments += 1;
+                }
+            }
+            used = used.saturating_sub(size);
+            eviction.freed += size;
+        }
+        Ok(eviction)
+    }
+
     /// Clear all cached data.
     pub fn clear(&self) -> Result<()> {
-        self.conn
-            .execute_batch("DELETE FROM messages; DELETE FROM labels; DELETE FROM sync_state;")?;
+        self.conn.execute_batch(
+            "DELETE FROM messages; DELETE FROM threads; DELETE FROM attachments;
+             DELETE FROM labels; DELETE FROM sync_state; DELETE FROM snoozed_until;
+             DELETE FROM unsubscribe_targets;",
+        )?;
         Ok(())
     }
 
@@ -256,6 +914,10 @@ impl GmailCache {
             is_unread: row.get::<_, i32>(8)? != 0,
             is_starred: row.get::<_, i32>(9)? != 0,
             body: row.get(10)?,
+            attachments: Vec::new(),
+            // Only needed when rules run on freshly fetched messages
+            list_id: None,
+            unsubscribe: None,
         })
     }
 }
@@ -279,6 +941,9 @@ mod tests {
             is_unread,
             is_starred: false,
             body: Some("Test body".to_string()),
+            attachments: vec![],
+            list_id: None,
+            unsubscribe: None,
         }
     }
 
@@ -295,6 +960,23 @@ mod tests {
         assert!(retrieved.is_unread);
     }
 
+    #[test]
+    fn test_search_messages() {
+        let cache = GmailCache::in_memory().unwrap();
+        let mut invoice = create_test_message("msg1", false);
+        invoice.subject = "Your Invoice for March".to_string();
+        invoice.from = "billing@acme.example".to_string();
+        cache.store_message(&invoice).unwrap();
+        cache.store_message(&create_test_message("msg2", false)).unwrap();
+
+        assert_eq!(cache.search_messages("invoice", 10).unwrap().len(), 1);
+        assert_eq!(cache.search_messages("ACME march", 10).unwrap()[0].id, "msg1");
+        assert_eq!(cache.search_messages("test body", 10).unwrap().len(), 2);
+        assert_eq!(cache.search_messages("test body", 1).unwrap().len(), 1);
+        assert!(cache.search_messages("invoice april", 10).unwrap().is_empty());
+        assert!(cache.search_messages("", 10).unwrap().is_empty());
+    }
+
     #[test]
     fn test_message_not_found() {
         let cache = GmailCache::in_memory().unwrap();
@@ -388,6 +1070,85 @@ mod tests {
         assert!(cache.get_last_sync().unwrap().is_none());
     }
 
+    #[test]
+    fn test_attachment_metadata_and_local_path() {
+        let cache = GmailCache::in_memory().unwrap();
+        let mut msg = create_test_message("msg1", false);
+        msg.attachments.push(Attachment {
+            message_id: "msg1".to_string(),
+            attachment_id: "att-1".to_string(),
+            filename: "report.pdf".to_string(),
+            mime_type: "application/pdf".to_string(),
+            size: 2048,
+            local_path: None,
+        });
+        cache.store_message(&msg).unwrap();
+        cache.set_attachment_path("msg1", "att-1", "/tmp/report.pdf").unwrap();
+
+        // Re-storing the message must not forget the downloaded file
+        cache.store_message(&msg).unwrap();
+
+        let atts = cache.list_attachments("msg1").unwrap();
+        assert_eq!(atts.len(), 1);
+        assert_eq!(atts[0].filename, "report.pdf");
+        assert_eq!(atts[0].size, 2048);
+        assert_eq!(atts[0].local_path.as_deref(), Some("/tmp/report.pdf"));
+        assert!(cache.list_attachments("msg2").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_store_and_get_thread() {
+        let cache = GmailCache::in_memory().unwrap();
+
+        let mut first = create_test_message("msg1", false);
+        first.thread_id = "t1".to_string();
+        first.date = Utc::now() - chrono::Duration::hours(1);
+        let mut reply = create_test_message("msg2", true);
+        reply.thread_id = "t1".to_string();
+
+        let thread = Thread {
+            id: "t1".to_string(),
+            snippet: "latest".to_string(),
+            history_id: Some("7".to_string()),
+            messages: vec![first, reply],
+        };
+        cache.store_thread(&thread).unwrap();
+
+        let retrieved = cache.get_thread("t1").unwrap().unwrap();
+        assert_eq!(retrieved.messages.len(), 2);
+        assert_eq!(retrieved.messages[0].id, "msg1");
+        assert_eq!(retrieved.history_id.as_deref(), Some("7"));
+        assert!(cache.get_thread("missing").unwrap().is_none());
+
+        let threads = cache.list_threads(None, 10).unwrap();
+        assert_eq!(threads.len(), 1);
+    }
+
+    #[test]
+    fn test_snooze_and_resurface() {
+        let cache = GmailCache::in_memory().unwrap();
+        cache.store_message(&create_test_message("msg1", true)).unwrap();
+        cache.store_message(&create_test_message("msg2", true)).unwrap();
+
+        let now = Utc::now();
+        cache.snooze("msg1", now + chrono::Duration::hours(1)).unwrap();
+        cache.snooze("msg2", now + chrono::Duration::hours(3)).unwrap();
+        assert!(!cache.get_message("msg1").unwrap().unwrap().labels.contains(&"INBOX".into()));
+        assert_eq!(cache.list_snoozes().unwrap()[0].message_id, "msg1");
+
+        assert!(cache.take_due_snoozes(now).unwrap().is_empty());
+        let due = cache.take_due_snoozes(now + chrono::Duration::hours(2)).unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].message_id, "msg1");
+        assert!(cache.get_message("msg1").unwrap().unwrap().labels.contains(&"INBOX".into()));
+        assert!(cache.snoozed_until("msg1").unwrap().is_none());
+        assert!(cache.snoozed_until("msg2").unwrap().is_some());
+
+        assert!(cache.unsnooze("msg2").unwrap());
+        assert!(!cache.unsnooze("msg2").unwrap());
+        assert!(cache.list_snoozes().unwrap().is_empty());
+    }
+
     #[test]
     fn test_update_existing_message() {
         let cache = GmailCache::in_memory().unwrap();
@@ -404,4 +1165,126 @@ mod tests {
         assert!(!retrieved.is_unread);
         assert!(retrieved.is_starred);
     }
+
+    #[test]
+    fn test_prefetch_policy_keeps_recent_bodies() {
+        let cache = GmailCache::in_memory().unwrap();
+        let now = Utc::now();
+        let policy = PrefetchPolicy::new(30);
+
+        let recent = create_test_message("recent", false);
+        let mut old = create_test_message("old", false);
+        old.date = now - chrono::Duration::days(90);
+        cache.store_message_with_policy(&recent, &policy, now).unwrap();
+        cache.store_message_with_policy(&old, &policy, now).unwrap();
+        assert!(cache.get_message("recent").unwrap().unwrap().body.is_some());
+        assert!(cache.get_message("old").unwrap().unwrap().body.is_none());
+
+        // A header-only refresh keeps the cached body
+        let mut headers = recent.clone();
+        headers.body = None;
+        cache.store_message(&headers).unwrap();
+        assert!(cache.get_message("recent").unwrap().unwrap().body.is_some());
+
+        // Old mail opened recently keeps its body; unopened old mail loses it
+        cache.store_message(&old).unwrap();
+        let mut older = create_test_message("older", false);
+        older.date = now - chrono::Duration::days(60);
+        cache.store_message(&older).unwrap();
+        cache.touch_message("old", now).unwrap();
+        assert_eq!(cache.apply_prefetch_policy(&policy, now).unwrap(), 1);
+        assert!(cache.get_message("old").unwrap().unwrap().body.is_some());
+        assert!(cache.get_message("older").unwrap().unwrap().body.is_none());
+
+        let mut missing = create_test_message("missing", false);
+        missing.body = None;
+        cache.store_message(&missing).unwrap();
+        assert_eq!(cache.messages_missing_body(&policy, now, 10).unwrap(), ["missing"]);
+    }
+
+    #[test]
+    fn test_enforce_quota_evicts_least_recently_used() {
+        let cache = GmailCache::in_memory().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let now = Utc::now();
+
+        for (i, id) in ["a", "b", "c"].into_iter().enumerate() {
+            let mut msg = create_test_message(id, false);
+            msg.body = Some("x".repeat(100));
+            cache.store_message(&msg).unwrap();
+            cache.touch_message(id, now + chrono::Duration::seconds(i as i64 * 10)).unwrap();
+        }
+        let path = dir.path().join("report.pdf");
+        std::fs::write(&path, [0u8; 50]).unwrap();
+        let mut msg = create_test_message("d", false);
+        msg.body = None;
+        msg.attachments.push(Attachment {
+            message_id: "d".to_string(),
+            attachment_id: "att-1".to_string(),
+            filename: "report.pdf".to_string(),
+            mime_type: "application/pdf".to_string(),
+            size: 50,
+            local_path: None,
+        });
+        cache.store_message(&msg).unwrap();
+        let path_str = path.to_string_lossy().to_string();
+        cache.set_attachment_path("d", "att-1", &path_str).unwrap();
+        cache.touch_attachment("d", "att-1", now + chrono::Duration::seconds(15)).unwrap();
+
+        let usage = cache.cache_size().unwrap();
+        assert_eq!(usage, CacheUsage { bodies: 300, attachments: 50 });
+        assert_eq!(cache.enforce_quota(1000).unwrap(), Eviction::default());
+
+        // Oldest first: body "a", then body "b", then the attachment
+        let eviction = cache.enforce_quota(150).unwrap();
+        assert_eq!(eviction, Eviction { bodies: 2, attachments: 0, freed: 200 });
+        assert!(cache.get_message("a").unwrap().unwrap().body.is_none());
+        assert!(cache.get_message("c").unwrap().unwrap().body.is_some());
+
+        let eviction = cache.enforce_quota(100).unwrap();
+        assert_eq!(eviction, Eviction { bodies: 0, attachments: 1, freed: 50 });
+        assert!(!path.exists());
+        assert!(cache.list_attachments("d").unwrap()[0].local_path.is_none());
+        assert_eq!(cache.cache_size().unwrap().total(), 100);
+    }
+
+    #[test]
+    fn test_unsubscribe_targets_and_sender_counts() {
+        let cache = GmailCache::in_memory().unwrap();
+        let now = Utc::now();
+        for (id, from, days_ago) in [
+            ("a", "\"Shop\" <News@Shop.example>", 1),
+            ("b", "Shop Deals <news@shop.example>", 3),
+            ("c", "friend@example.com", 2),
+            ("d", "news@shop.example", 45),
+        ] {
+            let mut msg = create_test_message(id, false);
+            msg.from = from.to_string();
+            msg.date = now - chrono::Duration::days(days_ago);
+            if id == "a" {
+                msg.unsubscribe = UnsubscribeTarget::parse(
+                    "<https://shop.example/u>",
+                    Some("List-Unsubscribe=One-Click"),
+                );
+            }
+            cache.store_message(&msg).unwrap();
+        }
+
+        let month = now - chrono::Duration::days(30);
+        let summaries = cache.sender_summaries(month).unwrap();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].sender, "news@shop.example");
+        assert_eq!(summaries[0].count, 2);
+        assert_eq!(summaries[0].name, "Shop");
+        assert!(summaries[0].target.as_ref().unwrap().one_click);
+        assert!(summaries[1].target.is_none());
+
+        cache.mark_unsubscribed("news@shop.example", now).unwrap();
+        // Seeing the header again keeps the unsubscribe time
+        let target = cache.unsubscribe_target("news@shop.example").unwrap().unwrap();
+        cache.store_unsubscribe_target("news@shop.example", &target).unwrap();
+        let summary = cache.sender_summary("news@shop.example", month).unwrap().unwrap();
+        assert_eq!(summary.unsubscribed_at.map(|t| t.timestamp()), Some(now.timestamp()));
+        assert!(cache.sender_summary("nobody@example.com", month).unwrap().is_none());
+    }
 }
@@ -1,6 +1,12 @@
 //! Gmail API client with retry logic.
 
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
 use base64::Engine;
+use myme_core::http_log::HttpObserver;
+use myme_services::retry::{send_observed, RetryConfig, DEFAULT_TIMEOUT_SECS};
+use tokio::io::AsyncWriteExt;
 use tracing::instrument;
 
 use crate::error::GmailError;
@@ -8,18 +14,71 @@ use crate::types::*;
 
 const GMAIL_API_BASE: &str = "https://gmail.googleapis.com";
 
+/// Maximum message IDs accepted by a single batchModify call.
+const BATCH_MODIFY_MAX_IDS: usize = 1000;
+
+/// `builder` with redirects turned off, for requests that must reach only the
+/// URL they were given.
+fn no_redirects(builder: reqwest::ClientBuilder) -> Option<reqwest::Client> {
+    builder
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| tracing::warn!("Failed to build unsubscribe client: {}", e))
+        .ok()
+}
+
 pub struct GmailClient {
     client: reqwest::Client,
+    /// Client for one-click unsubscribe POSTs, which never follows redirects;
+    /// None if it couldn't be built
+    unsubscribe_client: Option<reqwest::Client>,
     access_token: String,
     base_url: String,
+    observer: Option<HttpObserver>,
+    retry_config: RetryConfig,
 }
 
 impl GmailClient {
     pub fn new(access_token: &str) -> Self {
         Self {
             client: reqwest::Client::new(),
+            unsubscribe_client: no_redirects(reqwest::Client::builder()),
+            access_token: access_token.to_string(),
+            base_url: GMAIL_API_BASE.to_string(),
+            observer: None,
+            retry_config: RetryConfig::none(),
+        }
+    }
+
+    /// Create a client that sends requests through `proxies`.
+    pub fn new_with_proxies(access_token: &str, proxies: Vec<reqwest::Proxy>) -> Self {
+        Self::new_with_timeout(access_token, proxies, Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+    }
+
+    /// Like [`Self::new_with_proxies`], abandoning requests after `timeout`.
+    pub fn new_with_timeout(
+        access_token: &str,
+        proxies: Vec<reqwest::Proxy>,
+        timeout: Duration,
+    ) -> Self {
+        let builder = || {
+            proxies
+                .iter()
+                .cloned()
+                .fold(reqwest::Client::builder(), reqwest::ClientBuilder::proxy)
+                .timeout(timeout)
+        };
+        let client = builder().build().unwrap_or_else(|e| {
+            tracing::warn!("Failed to apply proxy settings: {}", e);
+            reqwest::Client::new()
+        });
+        Self {
+            client,
+            unsubscribe_client: no_redirects(builder()),
             access_token: access_token.to_string(),
             base_url: GMAIL_API_BASE.to_string(),
+            observer: None,
+            retry_config: RetryConfig::none(),
         }
     }
 
@@ -27,11 +86,34 @@ impl GmailClient {
     pub fn new_with_base_url(access_token: &str, base_url: &str) -> Self {
         Self {
             client: reqwest::Client::new(),
+            unsubscribe_client: no_redirects(reqwest::Client::builder()),
             access_token: access_token.to_string(),
             base_url: base_url.to_string(),
+            observer: None,
+            retry_config: RetryConfig::none(),
         }
     }
 
+    /// Retry idempotent requests that time out or get a 5xx/429 response.
+    /// Off by default.
+    pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
+        self.retry_config = config;
+        self
+    }
+
+    /// Report every request to `observer` (e.g. the HTTP debug capture).
+    pub fn with_observer(mut self, observer: Option<HttpObserver>) -> Self {
+        self.observer = observer;
+        self
+    }
+
+    /// Send a request, retrying transient failures of idempotent ones and
+    /// reporting each attempt to the observer if one is set.
+    async fn send(&self, request: reqwest::RequestBuilder) -> reqwest::Result<reqwest::Response> {
+        send_observed(&self.client, request.build()?, &self.retry_config, self.observer.as_ref())
+            .await
+    }
+
     fn auth_header(&self) -> String {
         format!("Bearer {}", self.access_token)
     }
@@ -59,7 +141,7 @@ impl GmailClient {
         }
 
         let response =
-            self.client.get(&url).header("Authorization", self.auth_header()).send().await?;
+            self.send(self.client.get(&url).header("Authorization", self.auth_header())).await?;
 
         self.handle_response(response).await
     }
@@ -71,19 +153,89 @@ impl GmailClient {
             format!("{}/gmail/v1/users/me/messages/{}?format=full", self.base_url, message_id);
 
         let response =
-            self.client.get(&url).header("Authorization", self.auth_header()).send().await?;
+            self.send(self.client.get(&url).header("Authorization", self.auth_header())).await?;
 
         let api_msg: ApiMessage = self.handle_response(response).await?;
         Ok(Message::from_api(api_msg))
     }
 
+    /// Get a full conversation (all messages in the thread).
+    #[instrument(skip(self), level = "info")]
+    pub async fn get_thread(&self, thread_id: &str) -> Result<Thread, GmailError> {
+        let url = format!("{}/gmail/v1/users/me/threads/{}?format=full", self.base_url, thread_id);
+
+        let response =
+            self.send(self.client.get(&url).header("Authorization", self.auth_header())).await?;
+
+        let api_thread: ApiThread = self.handle_response(response).await?;
+        Ok(Thread::from_api(api_thread))
+    }
+
+    /// Download an attachment into `dest_dir/<message_id>/<filename>`; a
+    /// second attachment with the same name gets `name (2).ext` and so on.
+    ///
+    /// The API returns the content base64url-encoded inside a JSON body. The
+    /// `data` field is decoded in 4-character groups as chunks arrive and
+    /// written to a `.part` file, which is renamed once complete, so large
+    /// attachments are never held in memory whole.
+    #[instrument(skip(self, dest_dir), level = "info")]
+    pub async fn get_attachment(
+        &self,
+        message_id: &str,
+        attachment_id: &str,
+        filename: &str,
+        dest_dir: &Path,
+    ) -> Result<PathBuf, GmailError> {
+        let url = format!(
+            "{}/gmail/v1/users/me/messages/{}/attachments/{}",
+            self.base_url, message_id, attachment_id
+        );
+
+        let mut response =
+            self.send(self.client.get(&url).header("Authorization", self.auth_header())).await?;
+        if !response.status().is_success() {
+            return self.handle_response::<ApiAttachment>(response).await.map(|_| PathBuf::new());
+        }
+
+        let dir = dest_dir.join(sanitize_filename(message_id));
+        tokio::fs::create_dir_all(&dir).await.map_err(|e| GmailError::CacheError(e.to_string()))?;
+        let (target, partial, mut file) =
+            create_partial(&dir, &sanitize_filename(filename)).await?;
+
+        let download = async {
+            let mut decoder = AttachmentDecoder::default();
+            let mut decoded = Vec::new();
+            while let Some(chunk) = response.chunk().await? {
+                decoder.feed(&chunk, &mut decoded)?;
+                file.write_all(&decoded)
+                    .await
+                    .map_err(|e| GmailError::CacheError(e.to_string()))?;
+                decoded.clear();
+            }
+            decoder.finish(&mut decoded)?;
+            file.write_all(&decoded).await.map_err(|e| GmailError::CacheError(e.to_string()))?;
+            file.flush().await.map_err(|e| GmailError::CacheError(e.to_string()))
+        };
+        if let Err(e) = download.await {
+            drop(file);
+            let _ = tokio::fs::remove_file(&partial).await;
+            return Err(e);
+        }
+        drop(file);
+        tokio::fs::rename(&partial, &target)
+            .await
+            .map_err(|e| GmailError::CacheError(e.to_string()))?;
+
+        Ok(target)
+    }
+
     /// List all labels.
     #[instrument(skip(self), level = "info")]
     pub async fn list_labels(&self) -> Result<Vec<Label>, GmailError> {
         let url = format!("{}/gmail/v1/users/me/labels", self.base_url);
 
         let response =
-            self.client.get(&url).header("Authorization", self.auth_header()).send().await?;
+            self.send(self.client.get(&url).header("Authorization", self.auth_header())).await?;
 
         let resp: LabelListResponse = self.handle_response(response).await?;
         Ok(resp.labels.into_iter().map(Label::from).collect())
@@ -105,11 +257,7 @@ impl GmailClient {
         });
 
         let response = self
-            .client
-            .post(&url)
-            .header("Authorization", self.auth_header())
-            .json(&body)
-            .send()
+            .send(self.client.post(&url).header("Authorization", self.auth_header()).json(&body))
             .await?;
 
         if response.status().is_success() {
@@ -121,13 +269,48 @@ impl GmailClient {
         }
     }
 
+    /// Modify labels on many messages in one request (`users.messages.batchModify`).
+    ///
+    /// Gmail accepts up to 1000 IDs per call; larger selections are split into chunks.
+    #[instrument(skip(self, message_ids), fields(count = message_ids.len()), level = "info")]
+    pub async fn batch_modify(
+        &self,
+        message_ids: &[String],
+        add_labels: &[&str],
+        remove_labels: &[&str],
+    ) -> Result<(), GmailError> {
+        let url = format!("{}/gmail/v1/users/me/messages/batchModify", self.base_url);
+
+        for chunk in message_ids.chunks(BATCH_MODIFY_MAX_IDS) {
+            let body = serde_json::json!({
+                "ids": chunk,
+                "addLabelIds": add_labels,
+                "removeLabelIds": remove_labels,
+            });
+
+            let response = self
+                .send(
+                    self.client.post(&url).header("Authorization", self.auth_header()).json(&body),
+                )
+                .await?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let text = response.text().await.unwrap_or_default();
+                return Err(GmailError::ApiError(format!("{}: {}", status, text)));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Move message to trash.
     #[instrument(skip(self), level = "info")]
     pub async fn trash_message(&self, message_id: &str) -> Result<(), GmailError> {
         let url = format!("{}/gmail/v1/users/me/messages/{}/trash", self.base_url, message_id);
 
         let response =
-            self.client.post(&url).header("Authorization", self.auth_header()).send().await?;
+            self.send(self.client.post(&url).header("Authorization", self.auth_header())).await?;
 
         if response.status().is_success() {
             Ok(())
@@ -149,34 +332,71 @@ impl GmailClient {
     ) -> Result<Message, GmailError> {
         let url = format!("{}/gmail/v1/users/me/messages/send", self.base_url);
 
-        // Build RFC 2822 message
-        let mut headers = format!(
-            "To: {}\r\nSubject: {}\r\nContent-Type: text/plain; charset=utf-8\r\n",
-            to, subject
-        );
+        let request_body = serde_json::json!({
+            "raw": raw_message(to, subject, body, reply_to_id),
+        });
 
-        if let Some(reply_id) = reply_to_id {
-            headers.push_str(&format!("In-Reply-To: {}\r\nReferences: {}\r\n", reply_id, reply_id));
-        }
+        let response = self
+            .send(
+                self.client
+                    .post(&url)
+                    .header("Authorization", self.auth_header())
+                    .json(&request_body),
+            )
+            .await?;
 
-        let raw_message = format!("{}\r\n{}", headers, body);
-        let encoded =
-            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(raw_message.as_bytes());
+        let api_msg: ApiMessage = self.handle_response(response).await?;
+        Ok(Message::from_api(api_msg))
+    }
+
+    /// Save a plain-text email as a draft. Returns the draft ID.
+    #[instrument(skip(self, body), level = "info")]
+    pub async fn create_draft(
+        &self,
+        to: &str,
+        subject: &str,
+        body: &str,
+    ) -> Result<String, GmailError> {
+        let url = format!("{}/gmail/v1/users/me/drafts", self.base_url);
 
         let request_body = serde_json::json!({
-            "raw": encoded,
+            "message": { "raw": raw_message(to, subject, body, None) },
         });
 
         let response = self
-            .client
-            .post(&url)
-            .header("Authorization", self.auth_header())
-            .json(&request_body)
-            .send()
+            .send(
+                self.client
+                    .post(&url)
+                    .header("Authorization", self.auth_header())
+                    .json(&request_body),
+            )
             .await?;
 
-        let api_msg: ApiMessage = self.handle_response(response).await?;
-        Ok(Message::from_api(api_msg))
+        let draft: ApiDraft = self.handle_response(response).await?;
+        Ok(draft.id)
+    }
+
+    /// RFC 8058 one-click unsubscribe: POST `List-Unsubscribe=One-Click` to the
+    /// sender's URL. The request goes to the sender, so no credentials are sent,
+    /// and a redirect is a failure rather than a POST to wherever it points.
+    #[instrument(skip(self), level = "info")]
+    pub async fn one_click_unsubscribe(&self, url: &str) -> Result<(), GmailError> {
+        let Some(client) = &self.unsubscribe_client else {
+            return Err(GmailError::ApiError("One-click unsubscribe is unavailable".into()));
+        };
+        let request = client
+            .post(url)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(crate::unsubscribe::ONE_CLICK_BODY)
+            .build()?;
+        let response =
+            send_observed(client, request, &self.retry_config, self.observer.as_ref()).await?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(GmailError::ApiError(format!("Unsubscribe failed: {}", response.status())))
+        }
     }
 
     /// Mark a message as read.
@@ -204,6 +424,31 @@ impl GmailClient {
         self.modify_labels(message_id, &[], &["INBOX"]).await
     }
 
+    /// Mark several messages as read in one round trip.
+    pub async fn batch_mark_as_read(&self, message_ids: &[String]) -> Result<(), GmailError> {
+        self.batch_modify(message_ids, &[], &["UNREAD"]).await
+    }
+
+    /// Archive several messages in one round trip.
+    pub async fn batch_archive(&self, message_ids: &[String]) -> Result<(), GmailError> {
+        self.batch_modify(message_ids, &[], &["INBOX"]).await
+    }
+
+    /// Move several messages to trash in one round trip.
+    pub async fn batch_trash(&self, message_ids: &[String]) -> Result<(), GmailError> {
+        self.batch_modify(message_ids, &["TRASH"], &["INBOX"]).await
+    }
+
+    /// Put several archived messages back in the inbox in one round trip.
+    pub async fn batch_unarchive(&self, message_ids: &[String]) -> Result<(), GmailError> {
+        self.batch_modify(message_ids, &["INBOX"], &[]).await
+    }
+
+    /// Take several messages out of the trash and back to the inbox in one round trip.
+    pub async fn batch_untrash(&self, message_ids: &[String]) -> Result<(), GmailError> {
+        self.batch_modify(message_ids, &["INBOX"], &["TRASH"]).await
+    }
+
     /// Helper to handle API responses and errors.
     async fn handle_response<T: serde::de::DeserializeOwned>(
         &self,
@@ -239,6 +484,154 @@ impl GmailClient {
     }
 }
 
+/// Reserve a download slot in `dir` for `name`: the first of `name`,
+/// `name (2)`, ... with neither a finished file nor a `.part` in progress.
+/// Returns the final path, the `.part` path and the open `.part` file.
+async fn create_partial(
+    dir: &Path,
+    name: &str,
+) -> Result<(PathBuf, PathBuf, tokio::fs::File), GmailError> {
+    for n in 1u32.. {
+        let name = numbered_filename(name, n);
+        let target = dir.join(&name);
+        let partial = dir.join(format!("{}.part", name));
+        if tokio::fs::try_exists(&target).await.unwrap_or(false) {
+            continue;
+        }
+        match tokio::fs::OpenOptions::new().write(true).create_new(true).open(&partial).await {
+            Ok(file) => return Ok((target, partial, file)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(GmailError::CacheError(e.to_string())),
+        }
+    }
+    Err(GmailError::CacheError(format!("No free file name for {}", name)))
+}
+
+/// `name` for `n == 1`, otherwise `stem (n).ext`.
+fn numbered_filename(name: &str, n: u32) -> String {
+    if n == 1 {
+        return name.to_string();
+    }
+    match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => format!("{} ({}).{}", stem, n, ext),
+        _ => format!("{} ({})", name, n),
+    }
+}
+
+/// Streams the base64url `data` field out of an `attachments.get` response.
+///
+/// Gmail's response is a flat JSON object, so a small scanner finds the
+/// `"data":` key and passes the string that follows through the decoder
+/// without buffering the body. Base64url never needs JSON escapes.
+#[derive(Debug, Default)]
+struct AttachmentDecoder {
+    scan: Scan,
+    /// Last string seen outside `data` (a key or a value), capped in length
+    string: Vec<u8>,
+    escaped: bool,
+    /// The next string is the value of `data`
+    data_next: bool,
+    /// Base64 characters not yet decoded (fewer than 4 after each feed)
+    pending: Vec<u8>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+enum Scan {
+    #[default]
+    Outside,
+    InString,
+    InData,
+    Done,
+}
+
+impl AttachmentDecoder {
+    fn feed(&mut self, bytes: &[u8], out: &mut Vec<u8>) -> Result<(), GmailError> {
+        for &b in bytes {
+            match self.scan {
+                Scan::Outside => match b {
+                    b'"' if self.data_next => self.scan = Scan::InData,
+                    b'"' => {
+                        self.string.clear();
+                        self.scan = Scan::InString;
+                    }
+                    b':' => self.data_next = self.string == b"data",
+                    _ if b.is_ascii_whitespace() => {}
+                    _ => self.data_next = false,
+                },
+                Scan::InString => {
+                    if self.escaped {
+                        self.escaped = false;
+                    } else if b == b'\\' {
+                        self.escaped = true;
+                    } else if b == b'"' {
+                        self.scan = Scan::Outside;
+                    } else if self.string.len() < 8 {
+                        self.string.push(b);
+                    }
+                }
+                Scan::InData => match b {
+                    b'"' => self.scan = Scan::Done,
+                    b'=' => {}
+                    _ if b.is_ascii_whitespace() => {}
+                    _ => self.pending.push(b),
+                },
+                Scan::Done => {}
+            }
+        }
+        let whole = self.pending.len() / 4 * 4;
+        self.decode(whole, out)
+    }
+
+    /// Decode what is left; fails if the body had no complete `data` string.
+    fn finish(mut self, out: &mut Vec<u8>) -> Result<(), GmailError> {
+        if self.scan != Scan::Done {
+            return Err(GmailError::InvalidMessageFormat);
+        }
+        let len = self.pending.len();
+        self.decode(len, out)
+    }
+
+    fn decode(&mut self, len: usize, out: &mut Vec<u8>) -> Result<(), GmailError> {
+        if len == 0 {
+            return Ok(());
+        }
+        base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode_vec(&self.pending[..len], out)
+            .map_err(|_| GmailError::InvalidMessageFormat)?;
+        self.pending.drain(..len);
+        Ok(())
+    }
+}
+
+/// Strip path separators and other characters unsafe in a file name.
+fn sanitize_filename(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || matches!(c, '.' | '-' | '_' | ' ') { c } else { '_' })
+        .collect();
+    let cleaned = cleaned.trim().trim_start_matches('.');
+    if cleaned.is_empty() {
+        "attachment".to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+/// Base64url-encoded RFC 2822 plain-text message for the `raw` field.
+fn raw_message(to: &str, subject: &str, body: &str, reply_to_id: Option<&str>) -> String {
+    let mut headers = format!(
+        "To: {}\r\nSubject: {}\r\nContent-Type: text/plain; charset=utf-8\r\n",
+        to, subject
+    );
+
+    if let Some(reply_id) = reply_to_id {
+        headers.push_str(&format!("In-Reply-To: {}\r\nReferences: {}\r\n", reply_id, reply_id));
+    }
+
+    let raw_message = format!("{}\r\n{}", headers, body);
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(raw_message.as_bytes())
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -298,6 +691,86 @@ mod tests {
         assert_eq!(msg.subject, "Test Subject");
     }
 
+    #[tokio::test]
+    async fn test_get_thread() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/gmail/v1/users/me/threads/thread1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "thread1",
+                "messages": [
+                    {"id": "m1", "threadId": "thread1", "internalDate": "1000"},
+                    {"id": "m2", "threadId": "thread1", "internalDate": "2000"}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = GmailClient::new_with_base_url("test_token", &mock_server.uri());
+        let thread = client.get_thread("thread1").await.unwrap();
+
+        assert_eq!(thread.id, "thread1");
+        assert_eq!(thread.messages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_attachment_writes_file() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/gmail/v1/users/me/messages/msg1/attachments/att1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "size": 5,
+                "data": "aGVsbG8"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let client = GmailClient::new_with_base_url("test_token", &mock_server.uri());
+        let file = client.get_attachment("msg1", "att1", "../notes.txt", dir.path()).await.unwrap();
+
+        assert_eq!(file, dir.path().join("msg1").join("_notes.txt"));
+        assert_eq!(std::fs::read(&file).unwrap(), b"hello");
+
+        // A second attachment with the same name doesn't replace the first
+        let again =
+            client.get_attachment("msg1", "att1", "../notes.txt", dir.path()).await.unwrap();
+        assert_eq!(again, dir.path().join("msg1").join("_notes (2).txt"));
+        assert!(file.exists());
+        assert_eq!(std::fs::read_dir(dir.path().join("msg1")).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn test_attachment_decoder_handles_any_chunking() {
+        let content: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let encoded = base64::engine::general_purpose::URL_SAFE.encode(&content);
+        let body =
+            format!(r#"{{"attachmentId": "ANGj\"data\"", "size": 1000, "data": "{}"}}"#, encoded);
+        for chunk_size in [1, 3, 4, 7, 64, body.len()] {
+            let mut decoder = AttachmentDecoder::default();
+            let mut out = Vec::new();
+            for chunk in body.as_bytes().chunks(chunk_size) {
+                decoder.feed(chunk, &mut out).unwrap();
+            }
+            decoder.finish(&mut out).unwrap();
+            assert_eq!(out, content, "chunk size {}", chunk_size);
+        }
+
+        let mut decoder = AttachmentDecoder::default();
+        decoder.feed(br#"{"size": 0}"#, &mut Vec::new()).unwrap();
+        assert!(decoder.finish(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_numbered_filename() {
+        assert_eq!(numbered_filename("report.pdf", 1), "report.pdf");
+        assert_eq!(numbered_filename("report.pdf", 2), "report (2).pdf");
+        assert_eq!(numbered_filename("archive.tar.gz", 3), "archive.tar (3).gz");
+        assert_eq!(numbered_filename("README", 2), "README (2)");
+    }
+
     #[tokio::test]
     async fn test_list_labels() {
         let mock_server = MockServer::start().await;
@@ -354,6 +827,40 @@ mod tests {
         assert!(matches!(result, Err(GmailError::RateLimited(30))));
     }
 
+    #[tokio::test]
+    async fn test_retries_reads_but_not_writes() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/gmail/v1/users/me/messages"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/gmail/v1/users/me/messages"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "messages": [{"id": "msg1", "threadId": "thread1"}],
+                "resultSizeEstimate": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/gmail/v1/users/me/messages/msg1/trash"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GmailClient::new_with_base_url("token", &mock_server.uri())
+            .with_retry_config(RetryConfig::new(2, 1, 1));
+        let result = client.list_message_ids(None, None).await.unwrap();
+        assert_eq!(result.messages.len(), 1);
+        assert!(client.trash_message("msg1").await.is_err());
+    }
+
     #[tokio::test]
     async fn test_modify_labels() {
         let mock_server = MockServer::start().await;
@@ -374,6 +881,51 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_batch_modify() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/gmail/v1/users/me/messages/batchModify"))
+            .and(wiremock::matchers::body_json(serde_json::json!({
+                "ids": ["msg1", "msg2"],
+                "addLabelIds": [],
+                "removeLabelIds": ["INBOX"]
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GmailClient::new_with_base_url("test_token", &mock_server.uri());
+        let ids = vec!["msg1".to_string(), "msg2".to_string()];
+        let result = client.batch_archive(&ids).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_batch_untrash() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/gmail/v1/users/me/messages/batchModify"))
+            .and(wiremock::matchers::body_json(serde_json::json!({
+                "ids": ["msg1"],
+                "addLabelIds": ["INBOX"],
+                "removeLabelIds": ["TRASH"]
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GmailClient::new_with_base_url("test_token", &mock_server.uri());
+        let result = client.batch_untrash(&["msg1".to_string()]).await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_trash_message() {
         let mock_server = MockServer::start().await;
@@ -412,4 +964,48 @@ mod tests {
 
         assert_eq!(result.messages.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_one_click_unsubscribe_and_draft() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/u/42"))
+            .and(wiremock::matchers::body_string("List-Unsubscribe=One-Click"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/gmail/v1/users/me/drafts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "draft1",
+                "message": {"id": "m1", "threadId": "t1"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = GmailClient::new_with_base_url("test_token", &mock_server.uri());
+        let url = format!("{}/u/42", mock_server.uri());
+        client.one_click_unsubscribe(&url).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests[0].headers.get("authorization").is_none());
+
+        // A redirect fails the unsubscribe instead of being followed
+        Mock::given(method("POST"))
+            .and(path("/u/moved"))
+            .respond_with(
+                ResponseTemplate::new(307).insert_header("Location", "https://elsewhere.example/"),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let moved = format!("{}/u/moved", mock_server.uri());
+        assert!(client.one_click_unsubscribe(&moved).await.is_err());
+
+        let id = client.create_draft("leave@example.com", "unsubscribe", "unsubscribe").await;
+        assert_eq!(id.unwrap(), "draft1");
+    }
 }
@@ -1,15 +1,19 @@
 //! Gmail integration for MyMe.
 //!
-//! Provides Gmail API client, offline caching, and sync queue.
+//! Provides Gmail API client, offline caching, local rules, and sync queue.
 
 pub mod cache;
 pub mod client;
 pub mod error;
+pub mod rules;
 pub mod sync;
 pub mod types;
+pub mod unsubscribe;
 
-pub use cache::GmailCache;
+pub use cache::{CacheUsage, Eviction, GmailCache, PrefetchPolicy, Snooze};
 pub use client::GmailClient;
 pub use error::GmailError;
+pub use rules::{apply_rules, Condition, Rule, RuleAction, RuleStore};
 pub use sync::{QueuedAction, SyncAction, SyncQueue};
-pub use types::{Label, LabelType, Message, MessageListResponse, MessageRef};
+pub use types::{Attachment, Label, LabelType, Message, MessageListResponse, MessageRef, Thread};
+pub use unsubscribe::{MailtoDraft, SenderSummary, UnsubscribeTarget};
@@ -0,0 +1,350 @@
+//! Local mail rules.
+//!
+//! A rule maps conditions on the sender, subject or mailing list to actions
+//! (add a label, archive, mark read). Rules run against newly fetched messages
+//! during sync; the resulting changes are applied to the local copy right away
+//! and pushed to Gmail through the [`SyncQueue`](crate::SyncQueue).
+
+use anyhow::{bail, Result};
+use myme_services::db;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::sync::SyncAction;
+use crate::types::Message;
+
+/// Something a message must match. Matching is a case-insensitive substring test.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "field", content = "value", rename_all = "snake_case")]
+pub enum Condition {
+    /// The `From` header (name or address)
+    From(String),
+    Subject(String),
+    /// The `List-Id` header
+    ListId(String),
+}
+
+impl Condition {
+    fn pattern(&self) -> &str {
+        match self {
+            Condition::From(p) | Condition::Subject(p) | Condition::ListId(p) => p,
+        }
+    }
+
+    pub fn matches(&self, msg: &Message) -> bool {
+        let haystack = match self {
+            Condition::From(_) => msg.from.as_str(),
+            Condition::Subject(_) => msg.subject.as_str(),
+            Condition::ListId(_) => msg.list_id.as_deref().unwrap_or_default(),
+        };
+        haystack.to_lowercase().contains(&self.pattern().to_lowercase())
+    }
+}
+
+/// What to do with a matching message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "label", rename_all = "snake_case")]
+pub enum RuleAction {
+    /// Add a label, by name or ID
+    Label(String),
+    Archive,
+    MarkRead,
+}
+
+/// A named set of conditions (all must match) and the actions to take.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rule {
+    /// Assigned by [`RuleStore::save`]; 0 for a rule not saved yet
+    #[serde(default)]
+    pub id: i64,
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    pub conditions: Vec<Condition>,
+    pub actions: Vec<RuleAction>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Rule {
+    pub fn matches(&self, msg: &Message) -> bool {
+        self.enabled
+            && !self.conditions.is_empty()
+            && self.conditions.iter().all(|c| c.matches(msg))
+    }
+
+    /// Reject rules that could never match or would do nothing.
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            bail!("Rule needs a name");
+        }
+        if self.conditions.is_empty() {
+            bail!("Rule needs at least one condition");
+        }
+        if self.conditions.iter().any(|c| c.pattern().trim().is_empty()) {
+            bail!("Rule conditions can't be empty");
+        }
+        if self.actions.is_empty() {
+            bail!("Rule needs at least one action");
+        }
+        if self.actions.iter().any(|a| matches!(a, RuleAction::Label(l) if l.trim().is_empty())) {
+            bail!("Rule label can't be empty");
+        }
+        Ok(())
+    }
+}
+
+/// Run every enabled rule against `msg`, in order.
+///
+/// Matching actions are applied to `msg` and returned as the sync actions that
+/// make the same change in Gmail. Changes the message already has (e.g. it is
+/// already read) are skipped. `label_id` maps a label name to its Gmail ID.
+pub fn apply_rules(
+    rules: &[Rule],
+    msg: &mut Message,
+    label_id: impl Fn(&str) -> String,
+) -> Vec<SyncAction> {
+    let mut add_labels: Vec<String> = Vec::new();
+    let mut archive = false;
+    let mut mark_read = false;
+    for rule in rules.iter().filter(|r| r.matches(msg)) {
+        for action in &rule.actions {
+            match action {
+                RuleAction::Label(label) => {
+                    let id = label_id(label);
+                    if !msg.labels.contains(&id) && !add_labels.contains(&id) {
+                        add_labels.push(id);
+                    }
+                }
+                RuleAction::Archive => archive |= msg.labels.iter().any(|l| l == "INBOX"),
+                RuleAction::MarkRead => mark_read |= msg.is_unread,
+            }
+        }
+    }
+
+    let message_id = msg.id.clone();
+    let mut actions = Vec::new();
+    if !add_labels.is_empty() {
+        msg.labels.extend(add_labels.iter().cloned());
+        actions.push(SyncAction::AddLabels { message_id: message_id.clone(), labels: add_labels });
+    }
+    if mark_read {
+        msg.is_unread = false;
+        msg.labels.retain(|l| l != "UNREAD");
+        actions.push(SyncAction::MarkRead { message_id: message_id.clone() });
+    }
+    if archive {
+        msg.labels.retain(|l| l != "INBOX");
+        actions.push(SyncAction::Archive { message_id });
+    }
+    actions
+}
+
+/// Rules stored in SQLite.
+pub struct RuleStore {
+    conn: Connection,
+}
+
+impl RuleStore {
+    /// Open the rule store at the given path.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let conn = db::open_maintained(path.as_ref())?;
+        let store = Self { conn };
+        db::migrate(&store.conn, 1, |_| store.init_schema())?;
+        Ok(store)
+    }
+
+    /// Create an in-memory store (for testing).
+    #[cfg(test)]
+    pub fn in_memory() -> Result<Self> {
+        let conn = db::open_in_memory()?;
+        let store = Self { conn };
+        db::migrate(&store.conn, 1, |_| store.init_schema())?;
+        Ok(store)
+    }
+
+    fn init_schema(&self) -> Result<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS rules (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                conditions_json TEXT NOT NULL,
+                actions_json TEXT NOT NULL
+            );
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// All rules in the order they run (oldest first).
+    pub fn list(&self) -> Result<Vec<Rule>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, name, enabled, conditions_json, actions_json FROM rules ORDER BY id",
+        )?;
+        let rows = stmt.query_map([], Self::row_to_rule)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to read rules: {}", e))
+    }
+
+    pub fn get(&self, id: i64) -> Result<Option<Rule>> {
+        self.conn
+            .query_row(
+                "SELECT id, name, enabled, conditions_json, actions_json FROM rules WHERE id = ?1",
+                params![id],
+                Self::row_to_rule,
+            )
+            .optional()
+            .map_err(Into::into)
+    }
+
+    /// Insert a new rule (`id` 0) or update an existing one. Returns the rule's ID.
+    pub fn save(&self, rule: &Rule) -> Result<i64> {
+        rule.validate()?;
+        let conditions = serde_json::to_string(&rule.conditions)?;
+        let actions = serde_json::to_string(&rule.actions)?;
+        if rule.id == 0 {
+            self.conn.execute(
+                "INSERT INTO rules (name, enabled, conditions_json, actions_json)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![rule.name, rule.enabled as i32, conditions, actions],
+            )?;
+            return Ok(self.conn.last_insert_rowid());
+        }
+        let updated = self.conn.execute(
+            "UPDATE rules SET name = ?2, enabled = ?3, conditions_json = ?4, actions_json = ?5
+             WHERE id = ?1",
+            params![rule.id, rule.name, rule.enabled as i32, conditions, actions],
+        )?;
+        if updated == 0 {
+            bail!("Rule {} not found", rule.id);
+        }
+        Ok(rule.id)
+    }
+
+    /// Delete a rule. Returns true if it existed.
+    pub fn delete(&self, id: i64) -> Result<bool> {
+        Ok(self.conn.execute("DELETE FROM rules WHERE id = ?1", params![id])? > 0)
+    }
+
+    fn row_to_rule(row: &rusqlite::Row) -> rusqlite::Result<Rule> {
+        let conditions_json: String = row.get(3)?;
+        let actions_json: String = row.get(4)?;
+        Ok(Rule {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            enabled: row.get::<_, i32>(2)? != 0,
+            conditions: serde_json::from_str(&conditions_json).unwrap_or_default(),
+            actions: serde_json::from_str(&actions_json).unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::Utc;
+
+    fn message(from: &str, subject: &str, list_id: Option<&str>) -> Message {
+        Message {
+            id: "m1".to_string(),
+            thread_id: "t1".to_string(),
+            from: from.to_string(),
+            to: vec![],
+            subject: subject.to_string(),
+            snippet: String::new(),
+            date: Utc::now(),
+            labels: vec!["INBOX".to_string(), "UNREAD".to_string()],
+            is_unread: true,
+            is_starred: false,
+            body: None,
+            attachments: vec![],
+            list_id: list_id.map(String::from),
+            unsubscribe: None,
+        }
+    }
+
+    fn rule(name: &str, conditions: Vec<Condition>, actions: Vec<RuleAction>) -> Rule {
+        Rule { id: 0, name: name.to_string(), enabled: true, conditions, actions }
+    }
+
+    #[test]
+    fn test_apply_rules() {
+        let rules = vec![
+            rule(
+                "Dev list",
+                vec![Condition::ListId("dev.lists.example.org".into())],
+                vec![RuleAction::Label("Lists".into()), RuleAction::Archive],
+            ),
+            rule(
+                "Receipts",
+                vec![Condition::From("shop.example".into()), Condition::Subject("receipt".into())],
+                vec![RuleAction::Label("Lists".into()), RuleAction::MarkRead],
+            ),
+        ];
+        let label_id = |name: &str| if name == "Lists" { "Label_7".into() } else { name.into() };
+
+        let mut msg = message("Dev <dev@example.org>", "Weekly", Some("<dev.lists.example.org>"));
+        let actions = apply_rules(&rules, &mut msg, label_id);
+        assert_eq!(
+            actions,
+            vec![
+                SyncAction::AddLabels { message_id: "m1".into(), labels: vec!["Label_7".into()] },
+                SyncAction::Archive { message_id: "m1".into() },
+            ]
+        );
+        assert!(!msg.labels.contains(&"INBOX".to_string()));
+        assert!(msg.is_unread);
+
+        // Every condition must match
+        let mut msg = message("orders@shop.example", "Your order shipped", None);
+        assert!(apply_rules(&rules, &mut msg, label_id).is_empty());
+
+        let mut msg = message("orders@shop.example", "Your RECEIPT", None);
+        msg.labels.push("Label_7".into());
+        let actions = apply_rules(&rules, &mut msg, label_id);
+        assert_eq!(actions, vec![SyncAction::MarkRead { message_id: "m1".into() }]);
+        assert!(!msg.is_unread);
+
+        // Disabled rules don't run
+        let mut disabled = rules.clone();
+        disabled.iter_mut().for_each(|r| r.enabled = false);
+        let mut msg = message("orders@shop.example", "receipt", None);
+        assert!(apply_rules(&disabled, &mut msg, label_id).is_empty());
+    }
+
+    #[test]
+    fn test_rule_store_crud() {
+        let store = RuleStore::in_memory().unwrap();
+        let mut r = rule("Newsletters", vec![Condition::From("news@".into())], vec![]);
+        assert!(store.save(&r).is_err());
+
+        r.actions = vec![RuleAction::Archive, RuleAction::Label("News".into())];
+        r.id = store.save(&r).unwrap();
+        assert_eq!(store.list().unwrap(), vec![r.clone()]);
+
+        r.enabled = false;
+        store.save(&r).unwrap();
+        assert!(!store.get(r.id).unwrap().unwrap().enabled);
+
+        assert!(store.delete(r.id).unwrap());
+        assert!(!store.delete(r.id).unwrap());
+        assert!(store.save(&r).is_err());
+        assert!(store.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_rule_json_shape() {
+        let json = r#"{"name":"Lists","conditions":[{"field":"list_id","value":"dev"}],
+            "actions":[{"type":"label","label":"Dev"},{"type":"archive"}]}"#;
+        let r: Rule = serde_json::from_str(json).unwrap();
+        assert!(r.enabled);
+        assert_eq!(r.conditions, vec![Condition::ListId("dev".into())]);
+        assert_eq!(r.actions, vec![RuleAction::Label("Dev".into()), RuleAction::Archive]);
+    }
+}
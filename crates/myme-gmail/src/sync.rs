@@ -3,6 +3,7 @@
 //! Stores pending actions when offline and syncs them when connectivity is restored.
 
 use anyhow::Result;
+use myme_services::db;
 use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -26,6 +27,33 @@ pub enum SyncAction {
     AddLabels { message_id: String, labels: Vec<String> },
     /// Remove labels from a message
     RemoveLabels { message_id: String, labels: Vec<String> },
+    /// Mark several messages as read (one batchModify call)
+    BatchMarkRead { message_ids: Vec<String> },
+    /// Archive several messages (one batchModify call)
+    BatchArchive { message_ids: Vec<String> },
+    /// Move several messages to trash (one batchModify call)
+    BatchTrash { message_ids: Vec<String> },
+}
+
+impl SyncAction {
+    /// Message IDs affected by this action.
+    pub fn message_ids(&self) -> Vec<&str> {
+        match self {
+            SyncAction::MarkRead { message_id }
+            | SyncAction::MarkUnread { message_id }
+            | SyncAction::Star { message_id }
+            | SyncAction::Unstar { message_id }
+            | SyncAction::Archive { message_id }
+            | SyncAction::Trash { message_id }
+            | SyncAction::AddLabels { message_id, .. }
+            | SyncAction::RemoveLabels { message_id, .. } => vec![message_id.as_str()],
+            SyncAction::BatchMarkRead { message_ids }
+            | SyncAction::BatchArchive { message_ids }
+            | SyncAction::BatchTrash { message_ids } => {
+                message_ids.iter().map(String::as_str).collect()
+            }
+        }
+    }
 }
 
 /// A queued action with metadata.
@@ -46,18 +74,18 @@ pub struct SyncQueue {
 impl SyncQueue {
     /// Create a new sync queue at the given path.
     pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let conn = Connection::open(path)?;
+        let conn = db::open_maintained(path.as_ref())?;
         let queue = Self { conn };
-        queue.init_schema()?;
+        db::migrate(&queue.conn, 1, |_| queue.init_schema())?;
         Ok(queue)
     }
 
     /// Create an in-memory queue (for testing).
     #[cfg(test)]
     pub fn in_memory() -> Result<Self> {
-        let conn = Connection::open_in_memory()?;
+        let conn = db::open_in_memory()?;
         let queue = Self { conn };
-        queue.init_schema()?;
+        db::migrate(&queue.conn, 1, |_| queue.init_schema())?;
         Ok(queue)
     }
 
@@ -188,7 +216,22 @@ impl SyncQueue {
             params![pattern],
             |row| row.get(0),
         )?;
-        Ok(count > 0)
+        if count > 0 {
+            return Ok(true);
+        }
+
+        // Batch actions store an ID array; check them precisely.
+        let batch_pattern = format!("%\"{}\"%", message_id);
+        let mut stmt = self.conn.prepare(
+            "SELECT action_json FROM sync_queue WHERE action_json LIKE '%\"message_ids\"%' AND action_json LIKE ?1",
+        )?;
+        let jsons = stmt
+            .query_map(params![batch_pattern], |row| row.get::<_, String>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(jsons
+            .iter()
+            .filter_map(|j| serde_json::from_str::<SyncAction>(j).ok())
+            .any(|a| a.message_ids().contains(&message_id)))
     }
 }
 
@@ -290,6 +333,20 @@ mod tests {
         assert!(!queue.has_pending_for_message("msg3").unwrap());
     }
 
+    #[test]
+    fn test_batch_action_pending_for_message() {
+        let queue = SyncQueue::in_memory().unwrap();
+
+        let action =
+            SyncAction::BatchArchive { message_ids: vec!["msg1".to_string(), "msg2".to_string()] };
+        queue.enqueue(action.clone()).unwrap();
+
+        let queued = queue.peek().unwrap().unwrap();
+        assert_eq!(queued.action, action);
+        assert!(queue.has_pending_for_message("msg2").unwrap());
+        assert!(!queue.has_pending_for_message("msg").unwrap());
+    }
+
     #[test]
     fn test_clear() {
         let queue = SyncQueue::in_memory().unwrap();
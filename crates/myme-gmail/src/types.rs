@@ -1,8 +1,11 @@
 //! Gmail API types and data structures.
 
+use base64::Engine;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::unsubscribe::UnsubscribeTarget;
+
 /// Gmail message as stored locally.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Message {
@@ -17,6 +20,98 @@ pub struct Message {
     pub is_unread: bool,
     pub is_starred: bool,
     pub body: Option<String>,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    /// Mailing list identifier from the `List-Id` header, e.g. `<dev.lists.example.org>`
+    #[serde(default)]
+    pub list_id: Option<String>,
+    /// Where to unsubscribe, from the `List-Unsubscribe` headers
+    #[serde(default)]
+    pub unsubscribe: Option<UnsubscribeTarget>,
+}
+
+/// Attachment metadata for a message part; content is downloaded on demand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attachment {
+    pub message_id: String,
+    pub attachment_id: String,
+    pub filename: String,
+    pub mime_type: String,
+    pub size: u64,
+    /// Path of the downloaded file, once fetched into the attachment cache.
+    pub local_path: Option<String>,
+}
+
+/// Extensions the OS runs rather than displays: programs, scripts, installers,
+/// shortcuts and disk images that auto-mount with programs inside.
+const EXECUTABLE_EXTENSIONS: &[&str] = &[
+    "app",
+    "appimage",
+    "application",
+    "bat",
+    "cmd",
+    "com",
+    "command",
+    "cpl",
+    "deb",
+    "desktop",
+    "dmg",
+    "exe",
+    "gadget",
+    "hta",
+    "img",
+    "inf",
+    "iso",
+    "jar",
+    "js",
+    "jse",
+    "lnk",
+    "msc",
+    "msi",
+    "msp",
+    "pif",
+    "pkg",
+    "ps1",
+    "psm1",
+    "reg",
+    "rpm",
+    "run",
+    "scf",
+    "scr",
+    "sh",
+    "url",
+    "vbe",
+    "vbs",
+    "vhd",
+    "vhdx",
+    "ws",
+    "wsf",
+    "wsh",
+];
+
+impl Attachment {
+    /// Whether opening the file would run something instead of showing it.
+    pub fn is_executable(&self) -> bool {
+        std::path::Path::new(self.filename.trim_end_matches(['.', ' ']))
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| EXECUTABLE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+    }
+}
+
+/// Gmail API attachment body (`messages.attachments.get`).
+#[derive(Debug, Deserialize)]
+pub struct ApiAttachment {
+    #[serde(default)]
+    pub size: u64,
+    /// Base64url-encoded content.
+    pub data: String,
+}
+
+/// Gmail API draft (`drafts.create` response); only the ID is used.
+#[derive(Debug, Deserialize)]
+pub struct ApiDraft {
+    pub id: String,
 }
 
 /// Gmail API message response structure.
@@ -33,8 +128,14 @@ pub struct ApiMessage {
     pub payload: Option<MessagePayload>,
 }
 
+/// Root MIME part of a message.
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MessagePayload {
+    #[serde(default)]
+    pub mime_type: String,
+    #[serde(default)]
+    pub filename: String,
     #[serde(default)]
     pub headers: Vec<Header>,
     pub body: Option<MessageBody>,
@@ -49,15 +150,22 @@ pub struct Header {
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MessageBody {
     pub data: Option<String>,
     pub size: Option<u64>,
+    pub attachment_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MessagePart {
     pub mime_type: String,
+    #[serde(default)]
+    pub filename: String,
+    /// Part headers: `Content-Type` (with charset), `Content-ID`, `Content-Disposition`
+    #[serde(default)]
+    pub headers: Vec<Header>,
     pub body: Option<MessageBody>,
     #[serde(default)]
     pub parts: Vec<MessagePart>,
@@ -83,6 +191,18 @@ impl Message {
             .map(|h| h.value.clone())
             .unwrap_or_default();
 
+        let list_id = headers
+            .and_then(|h| h.iter().find(|h| h.name.eq_ignore_ascii_case("list-id")))
+            .map(|h| h.value.clone());
+
+        let header = |name: &str| {
+            headers
+                .and_then(|h| h.iter().find(|h| h.name.eq_ignore_ascii_case(name)))
+                .map(|h| h.value.as_str())
+        };
+        let unsubscribe = header("list-unsubscribe")
+            .and_then(|value| UnsubscribeTarget::parse(value, header("list-unsubscribe-post")));
+
         let date = api
             .internal_date
             .as_ref()
@@ -93,6 +213,14 @@ impl Message {
         let is_unread = api.label_ids.iter().any(|l| l == "UNREAD");
         let is_starred = api.label_ids.iter().any(|l| l == "STARRED");
 
+        let mut attachments = Vec::new();
+        if let Some(payload) = &api.payload {
+            collect_attachments(&api.id, &payload.parts, &mut attachments);
+        }
+        // Empty unless the message was fetched with format=full
+        let body =
+            api.payload.as_ref().and_then(|p| MessageContent::from_payload(p).display_body());
+
         Self {
             id: api.id,
             thread_id: api.thread_id,
@@ -104,8 +232,376 @@ impl Message {
             labels: api.label_ids,
             is_unread,
             is_starred,
-            body: None, // Loaded separately with full message
+            body,
+            attachments,
+            list_id,
+            unsubscribe,
+        }
+    }
+}
+
+/// Walk MIME parts collecting those that carry a filename and attachment ID.
+fn collect_attachments(message_id: &str, parts: &[MessagePart], out: &mut Vec<Attachment>) {
+    for part in parts {
+        let attachment_id = part.body.as_ref().and_then(|b| b.attachment_id.clone());
+        if let (false, Some(attachment_id)) = (part.filename.is_empty(), attachment_id) {
+            out.push(Attachment {
+                message_id: message_id.to_string(),
+                attachment_id,
+                filename: part.filename.clone(),
+                mime_type: part.mime_type.clone(),
+                size: part.body.as_ref().and_then(|b| b.size).unwrap_or(0),
+                local_path: None,
+            });
+        }
+        collect_attachments(message_id, &part.parts, out);
+    }
+}
+
+/// What a message shows: its plain text and HTML bodies and the images the
+/// HTML embeds, read from the MIME tree.
+///
+/// `multipart/alternative` keeps the last (richest) version of each kind,
+/// `multipart/related` takes its first part as the body and the rest as
+/// resources, and other multiparts join their inline parts in order. Text is
+/// decoded from the part's declared charset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MessageContent {
+    pub text: Option<String>,
+    pub html: Option<String>,
+    /// Images the HTML references as `cid:<content_id>`
+    pub inline_images: Vec<InlineImage>,
+}
+
+/// An image embedded in the HTML body through its `Content-ID`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineImage {
+    /// Without the angle brackets, e.g. `logo@example.com`
+    pub content_id: String,
+    pub mime_type: String,
+    pub filename: String,
+    /// Content, when Gmail sent it with the message
+    pub data: Option<Vec<u8>>,
+    /// Set when the content has to be downloaded like an attachment
+    pub attachment_id: Option<String>,
+}
+
+impl InlineImage {
+    /// The image as a `data:` URL, if its content came with the message.
+    pub fn data_uri(&self) -> Option<String> {
+        let data = self.data.as_ref()?;
+        Some(format!(
+            "data:{};base64,{}",
+            self.mime_type,
+            base64::engine::general_purpose::STANDARD.encode(data)
+        ))
+    }
+}
+
+/// Text bodies found under one MIME subtree.
+#[derive(Debug, Default)]
+struct PartBody {
+    text: Option<String>,
+    html: Option<String>,
+}
+
+/// A payload or part, so the root and nested parts are walked alike.
+struct PartView<'a> {
+    mime_type: &'a str,
+    filename: &'a str,
+    headers: &'a [Header],
+    body: Option<&'a MessageBody>,
+    parts: &'a [MessagePart],
+}
+
+impl<'a> From<&'a MessagePart> for PartView<'a> {
+    fn from(part: &'a MessagePart) -> Self {
+        Self {
+            mime_type: &part.mime_type,
+            filename: &part.filename,
+            headers: &part.headers,
+            body: part.body.as_ref(),
+            parts: &part.parts,
+        }
+    }
+}
+
+impl<'a> PartView<'a> {
+    fn header(&self, name: &str) -> Option<&'a str> {
+        self.headers.iter().find(|h| h.name.eq_ignore_ascii_case(name)).map(|h| h.value.as_str())
+    }
+
+    /// `charset` from `Content-Type`, if declared.
+    fn charset(&self) -> Option<String> {
+        header_param(self.header("content-type")?, "charset")
+    }
+
+    fn content_id(&self) -> Option<String> {
+        let id = self.header("content-id")?.trim();
+        let id = id.strip_prefix('<').and_then(|i| i.strip_suffix('>')).unwrap_or(id);
+        (!id.is_empty()).then(|| id.to_string())
+    }
+
+    /// An attachment rather than part of the body: marked as one, or named
+    /// without being marked inline.
+    fn is_attachment(&self) -> bool {
+        match self.header("content-disposition") {
+            Some(d) => disposition_type(d) == "attachment",
+            None => !self.filename.is_empty(),
+        }
+    }
+
+    fn data(&self) -> Option<Vec<u8>> {
+        let data = self.body?.data.as_deref()?;
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(data.trim_end_matches('=')).ok()
+    }
+}
+
+impl MessageContent {
+    /// Read a message's content from its payload (format=full).
+    pub fn from_payload(payload: &MessagePayload) -> Self {
+        let root = PartView {
+            mime_type: &payload.mime_type,
+            filename: &payload.filename,
+            headers: &payload.headers,
+            body: payload.body.as_ref(),
+            parts: &payload.parts,
+        };
+        let mut inline_images = Vec::new();
+        let body = walk_part(&root, &mut inline_images);
+        Self { text: body.text, html: body.html, inline_images }
+    }
+
+    /// The inline image with this content ID.
+    pub fn image(&self, content_id: &str) -> Option<&InlineImage> {
+        self.inline_images.iter().find(|i| i.content_id == content_id)
+    }
+
+    /// The HTML body with each `cid:` reference replaced by `resolve`'s URL.
+    /// References `resolve` returns None for, or to unknown images, are kept.
+    pub fn html_with_images(
+        &self,
+        resolve: impl Fn(&InlineImage) -> Option<String>,
+    ) -> Option<String> {
+        let html = self.html.as_deref()?;
+        let mut out = String::with_capacity(html.len());
+        let mut rest = html;
+        while let Some(start) = find_ignore_case(rest, "cid:") {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 4..];
+            let end = after
+                .find(|c: char| matches!(c, '"' | '\'' | ')' | '>') || c.is_whitespace())
+                .unwrap_or(after.len());
+            let url = self.image(&after[..end]).and_then(&resolve);
+            match url {
+                Some(url) => out.push_str(&url),
+                None => out.push_str(&rest[start..start + 4 + end]),
+            }
+            rest = &after[end..];
+        }
+        out.push_str(rest);
+        Some(out)
+    }
+
+    /// What to store and show: the HTML with inline images as `data:` URLs,
+    /// or the plain text when there is no HTML.
+    pub fn display_body(&self) -> Option<String> {
+        self.html_with_images(InlineImage::data_uri).or_else(|| self.text.clone())
+    }
+}
+
+fn walk_part(part: &PartView, images: &mut Vec<InlineImage>) -> PartBody {
+    let mime_type = part.mime_type.to_ascii_lowercase();
+    if let Some(subtype) = mime_type.strip_prefix("multipart/") {
+        let mut children = part.parts.iter().map(PartView::from);
+        return match subtype {
+            "alternative" => {
+                // Versions are ordered plainest first
+                let mut best = PartBody::default();
+                for child in children {
+                    let body = walk_part(&child, images);
+                    best.text = body.text.or(best.text);
+                    best.html = body.html.or(best.html);
+                }
+                best
+            }
+            "related" => {
+                let body = children.next().map(|root| walk_part(&root, images)).unwrap_or_default();
+                // The other parts are resources for the root, mostly images
+                for resource in children {
+                    walk_part(&resource, images);
+                }
+                body
+            }
+            _ => join_bodies(children.map(|child| walk_part(&child, images)).collect()),
+        };
+    }
+
+    if mime_type.starts_with("image/") {
+        if let Some(content_id) = part.content_id() {
+            images.push(InlineImage {
+                content_id,
+                mime_type,
+                filename: part.filename.to_string(),
+                data: part.data(),
+                attachment_id: part.body.and_then(|b| b.attachment_id.clone()),
+            });
+        }
+        return PartBody::default();
+    }
+    if part.is_attachment() {
+        return PartBody::default();
+    }
+    let text = || part.data().map(|bytes| decode_charset(&bytes, part.charset().as_deref()));
+    match mime_type.as_str() {
+        "text/plain" => PartBody { text: text(), html: None },
+        "text/html" => PartBody { text: None, html: text() },
+        _ => PartBody::default(),
+    }
+}
+
+/// Join the bodies of a `multipart/mixed` in order. When any part has HTML,
+/// text-only parts are carried into the HTML as preformatted blocks.
+fn join_bodies(bodies: Vec<PartBody>) -> PartBody {
+    let texts: Vec<&str> = bodies.iter().filter_map(|b| b.text.as_deref()).collect();
+    let text = (!texts.is_empty()).then(|| texts.join("\n\n"));
+
+    let html = if bodies.iter().any(|b| b.html.is_some()) {
+        let parts: Vec<String> = bodies
+            .iter()
+            .filter_map(|b| match (&b.html, &b.text) {
+                (Some(html), _) => Some(html.clone()),
+                (None, Some(text)) => Some(format!(
+                    "<pre style=\"white-space: pre-wrap\">{}</pre>",
+                    escape_html(text)
+                )),
+                (None, None) => None,
+            })
+            .collect();
+        Some(parts.join("\n"))
+    } else {
+        None
+    };
+    PartBody { text, html }
+}
+
+/// Decode text in `charset` (UTF-8 when missing or unknown), replacing
+/// malformed sequences.
+fn decode_charset(bytes: &[u8], charset: Option<&str>) -> String {
+    let encoding = charset
+        .and_then(|c| encoding_rs::Encoding::for_label(c.trim().as_bytes()))
+        .unwrap_or(encoding_rs::UTF_8);
+    let (text, _, had_errors) = encoding.decode(bytes);
+    if had_errors {
+        tracing::debug!("Malformed {} text in message part", encoding.name());
+    }
+    text.into_owned()
+}
+
+/// A parameter of a structured header such as `text/plain; charset="utf-8"`.
+fn header_param(value: &str, name: &str) -> Option<String> {
+    value.split(';').skip(1).find_map(|param| {
+        let (key, val) = param.split_once('=')?;
+        key.trim().eq_ignore_ascii_case(name).then(|| val.trim().trim_matches('"').to_string())
+    })
+}
+
+/// `inline` or `attachment`, lowercased.
+fn disposition_type(value: &str) -> String {
+    value.split(';').next().unwrap_or_default().trim().to_ascii_lowercase()
+}
+
+fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|w| w.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Gmail conversation: a thread of related messages, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Thread {
+    pub id: String,
+    pub snippet: String,
+    pub history_id: Option<String>,
+    pub messages: Vec<Message>,
+}
+
+/// Gmail API thread response structure.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiThread {
+    pub id: String,
+    #[serde(default)]
+    pub snippet: String,
+    pub history_id: Option<String>,
+    #[serde(default)]
+    pub messages: Vec<ApiMessage>,
+}
+
+impl Thread {
+    /// Convert API response to local Thread.
+    pub fn from_api(api: ApiThread) -> Self {
+        let mut messages: Vec<Message> = api.messages.into_iter().map(Message::from_api).collect();
+        messages.sort_by_key(|m| m.date);
+        let snippet = if api.snippet.is_empty() {
+            messages.last().map(|m| m.snippet.clone()).unwrap_or_default()
+        } else {
+            api.snippet
+        };
+        Self { id: api.id, snippet, history_id: api.history_id, messages }
+    }
+
+    /// Group a flat message list into threads, newest thread first.
+    pub fn group(messages: Vec<Message>) -> Vec<Thread> {
+        let mut threads: Vec<Thread> = Vec::new();
+        for msg in messages {
+            match threads.iter_mut().find(|t| t.id == msg.thread_id) {
+                Some(thread) => thread.messages.push(msg),
+                None => threads.push(Thread {
+                    id: msg.thread_id.clone(),
+                    snippet: String::new(),
+                    history_id: None,
+                    messages: vec![msg],
+                }),
+            }
+        }
+        for thread in &mut threads {
+            thread.messages.sort_by_key(|m| m.date);
+            thread.snippet = thread.messages.last().map(|m| m.snippet.clone()).unwrap_or_default();
         }
+        threads.sort_by_key(|t| std::cmp::Reverse(t.latest_date()));
+        threads
+    }
+
+    /// Subject of the first message in the thread.
+    pub fn subject(&self) -> &str {
+        self.messages.first().map(|m| m.subject.as_str()).unwrap_or("")
+    }
+
+    /// Date of the most recent message.
+    pub fn latest_date(&self) -> DateTime<Utc> {
+        self.messages.iter().map(|m| m.date).max().unwrap_or_default()
+    }
+
+    /// True if any message in the thread is unread.
+    pub fn is_unread(&self) -> bool {
+        self.messages.iter().any(|m| m.is_unread)
+    }
+
+    /// Distinct senders in order of first appearance.
+    pub fn participants(&self) -> Vec<String> {
+        let mut out: Vec<String> = Vec::new();
+        for m in &self.messages {
+            if !out.contains(&m.from) {
+                out.push(m.from.clone());
+            }
+        }
+        out
     }
 }
 
@@ -215,7 +711,10 @@ mod tests {
                 "headers": [
                     {"name": "From", "value": "sender@example.com"},
                     {"name": "To", "value": "me@example.com"},
-                    {"name": "Subject", "value": "Test Subject"}
+                    {"name": "Subject", "value": "Test Subject"},
+                    {"name": "List-Id", "value": "Dev list <dev.lists.example.org>"},
+                    {"name": "List-Unsubscribe", "value": "<https://lists.example.org/u?id=1>"},
+                    {"name": "List-Unsubscribe-Post", "value": "List-Unsubscribe=One-Click"}
                 ]
             }
         }"#;
@@ -227,6 +726,9 @@ mod tests {
         assert_eq!(msg.thread_id, "thread456");
         assert_eq!(msg.from, "sender@example.com");
         assert_eq!(msg.subject, "Test Subject");
+        assert_eq!(msg.list_id.as_deref(), Some("Dev list <dev.lists.example.org>"));
+        let unsubscribe = msg.unsubscribe.unwrap();
+        assert_eq!(unsubscribe.one_click_url(), Some("https://lists.example.org/u?id=1"));
         assert!(msg.is_unread);
     }
 
@@ -280,6 +782,171 @@ mod tests {
         assert_eq!(label.label_type, LabelType::User);
     }
 
+    #[test]
+    fn test_message_attachments_from_parts() {
+        let json = r#"{
+            "id": "msg1",
+            "threadId": "thread1",
+            "payload": {
+                "headers": [],
+                "parts": [
+                    {"mimeType": "text/plain", "filename": "", "body": {"size": 10, "data": "aGk"}},
+                    {
+                        "mimeType": "multipart/mixed",
+                        "parts": [{
+                            "mimeType": "application/pdf",
+                            "filename": "report.pdf",
+                            "body": {"size": 2048, "attachmentId": "att-1"}
+                        }]
+                    }
+                ]
+            }
+        }"#;
+
+        let api_msg: ApiMessage = serde_json::from_str(json).unwrap();
+        let msg = Message::from_api(api_msg);
+
+        assert_eq!(msg.attachments.len(), 1);
+        let att = &msg.attachments[0];
+        assert_eq!(att.message_id, "msg1");
+        assert_eq!(att.attachment_id, "att-1");
+        assert_eq!(att.filename, "report.pdf");
+        assert_eq!(att.mime_type, "application/pdf");
+        assert_eq!(att.size, 2048);
+    }
+
+    /// Readable dump of parsed content, compared against `testdata/mime/*.golden`.
+    fn render_content(content: &MessageContent) -> String {
+        let images: Vec<String> = content
+            .inline_images
+            .iter()
+            .map(|i| match (&i.data, &i.attachment_id) {
+                (Some(data), _) => format!(
+                    "{} {} {:?} {} bytes",
+                    i.content_id,
+                    i.mime_type,
+                    i.filename,
+                    data.len()
+                ),
+                (None, Some(id)) => {
+                    format!("{} {} {:?} attachment {}", i.content_id, i.mime_type, i.filename, id)
+                }
+                (None, None) => format!("{} {} {:?} empty", i.content_id, i.mime_type, i.filename),
+            })
+            .collect();
+        format!(
+            "== text ==\n{}\n== html ==\n{}\n== images ==\n{}\n== display ==\n{}\n",
+            content.text.as_deref().unwrap_or("(none)"),
+            content.html.as_deref().unwrap_or("(none)"),
+            images.join("\n"),
+            content.display_body().as_deref().unwrap_or("(none)"),
+        )
+    }
+
+    /// Parse each fixture and compare with its golden file. Run with
+    /// `UPDATE_GOLDEN=1` to rewrite the golden files after an intended change.
+    #[test]
+    fn test_mime_golden_files() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/mime");
+        let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+        for name in ["newsletter", "charsets", "corporate"] {
+            let json = std::fs::read_to_string(dir.join(format!("{}.json", name))).unwrap();
+            let api_msg: ApiMessage = serde_json::from_str(&json).unwrap();
+            let content = MessageContent::from_payload(api_msg.payload.as_ref().unwrap());
+            let rendered = render_content(&content);
+
+            let golden = dir.join(format!("{}.golden", name));
+            if update {
+                std::fs::write(&golden, &rendered).unwrap();
+                continue;
+            }
+            let expected = std::fs::read_to_string(&golden).unwrap_or_else(|_| {
+                panic!("missing {}; run with UPDATE_GOLDEN=1", golden.display())
+            });
+            assert_eq!(rendered, expected, "{} does not match {}", name, golden.display());
+
+            let msg = Message::from_api(api_msg);
+            assert_eq!(msg.body, content.display_body(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_inline_image_references_resolve() {
+        let content = MessageContent {
+            text: None,
+            html: Some(r#"<img src="cid:a"><img src='cid:ab'><a href="cid:zz">x</a>"#.into()),
+            inline_images: ["a", "ab"]
+                .into_iter()
+                .map(|id| InlineImage {
+                    content_id: id.to_string(),
+                    mime_type: "image/png".to_string(),
+                    filename: String::new(),
+                    data: None,
+                    attachment_id: Some(format!("att-{}", id)),
+                })
+                .collect(),
+        };
+        let html = content
+            .html_with_images(|img| img.attachment_id.as_ref().map(|id| format!("file:///{}", id)))
+            .unwrap();
+        assert_eq!(
+            html,
+            r#"<img src="file:///att-a"><img src='file:///att-ab'><a href="cid:zz">x</a>"#
+        );
+        // Nothing inline to embed, no plain text: the references stay as they are
+        assert_eq!(content.display_body().as_deref(), content.html.as_deref());
+    }
+
+    #[test]
+    fn test_thread_from_api_orders_messages() {
+        let json = r#"{
+            "id": "thread1",
+            "historyId": "42",
+            "messages": [
+                {"id": "m2", "threadId": "thread1", "snippet": "re: hi", "internalDate": "2000"},
+                {"id": "m1", "threadId": "thread1", "snippet": "hi", "internalDate": "1000"}
+            ]
+        }"#;
+
+        let api_thread: ApiThread = serde_json::from_str(json).unwrap();
+        let thread = Thread::from_api(api_thread);
+
+        assert_eq!(thread.messages.len(), 2);
+        assert_eq!(thread.messages[0].id, "m1");
+        assert_eq!(thread.snippet, "re: hi");
+        assert_eq!(thread.history_id.as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn test_group_messages_into_threads() {
+        let msg = |id: &str, thread: &str, ms: i64| Message {
+            id: id.into(),
+            thread_id: thread.into(),
+            from: format!("{}@example.com", id),
+            to: vec![],
+            subject: "s".into(),
+            snippet: id.into(),
+            date: DateTime::from_timestamp_millis(ms).unwrap(),
+            labels: vec![],
+            is_unread: id == "c",
+            is_starred: false,
+            body: None,
+            attachments: vec![],
+            list_id: None,
+            unsubscribe: None,
+        };
+
+        let threads =
+            Thread::group(vec![msg("a", "t1", 1000), msg("b", "t2", 2000), msg("c", "t1", 3000)]);
+
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].id, "t1");
+        assert_eq!(threads[0].messages.len(), 2);
+        assert!(threads[0].is_unread());
+        assert_eq!(threads[0].participants().len(), 2);
+        assert_eq!(threads[1].id, "t2");
+    }
+
     #[test]
     fn test_message_list_response_parsing() {
         let json = r#"{
@@ -295,4 +962,23 @@ mod tests {
         assert_eq!(response.messages.len(), 2);
         assert_eq!(response.next_page_token, Some("token123".into()));
     }
+
+    #[test]
+    fn test_attachment_is_executable() {
+        let named = |filename: &str| Attachment {
+            message_id: "m".into(),
+            attachment_id: "a".into(),
+            filename: filename.into(),
+            mime_type: "application/octet-stream".into(),
+            size: 1,
+            local_path: None,
+        };
+        assert!(named("setup.exe").is_executable());
+        assert!(named("Invoice.PDF.lnk").is_executable());
+        assert!(named("run.bat. ").is_executable());
+        assert!(named("app.desktop").is_executable());
+        assert!(!named("invoice.pdf").is_executable());
+        assert!(!named("photo.JPG").is_executable());
+        assert!(!named("README").is_executable());
+    }
 }
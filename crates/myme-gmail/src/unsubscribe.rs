@@ -0,0 +1,217 @@
+//! Unsubscribe targets from `List-Unsubscribe` headers, and per-sender counts.
+//!
+//! Senders advertise how to leave their list as `<mailto:...>` and/or
+//! `<https://...>` URIs. With `List-Unsubscribe-Post: List-Unsubscribe=One-Click`
+//! (RFC 8058) an https URI can be POSTed to directly; otherwise the mailto is
+//! turned into a draft for the user to send, or the link is opened.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Body of an RFC 8058 one-click unsubscribe POST
+pub const ONE_CLICK_BODY: &str = "List-Unsubscribe=One-Click";
+
+/// Where and how to unsubscribe from a sender's mail.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnsubscribeTarget {
+    /// First `https:`/`http:` URI in the header
+    pub http: Option<String>,
+    /// First `mailto:` URI in the header
+    pub mailto: Option<String>,
+    /// The sender accepts a one-click POST to `http` (only ever set for https)
+    pub one_click: bool,
+}
+
+impl UnsubscribeTarget {
+    /// Parse a `List-Unsubscribe` header, with the `List-Unsubscribe-Post`
+    /// header if present. None when neither kind of URI is found.
+    pub fn parse(header: &str, post: Option<&str>) -> Option<Self> {
+        let mut http = None;
+        let mut mailto = None;
+        for uri in header.split(',').filter_map(|part| {
+            let part = part.trim();
+            part.strip_prefix('<').and_then(|p| p.strip_suffix('>')).map(str::trim)
+        }) {
+            let lower = uri.to_ascii_lowercase();
+            if lower.starts_with("mailto:") {
+                mailto.get_or_insert_with(|| uri.to_string());
+            } else if lower.starts_with("https://") || lower.starts_with("http://") {
+                http.get_or_insert_with(|| uri.to_string());
+            }
+        }
+        if http.is_none() && mailto.is_none() {
+            return None;
+        }
+        // RFC 8058 requires https; a plain http link can only be opened
+        let one_click = http.as_deref().is_some_and(is_https)
+            && post.is_some_and(|p| p.trim().eq_ignore_ascii_case(ONE_CLICK_BODY));
+        Some(Self { http, mailto, one_click })
+    }
+
+    /// The one-click URL, if the sender supports it
+    pub fn one_click_url(&self) -> Option<&str> {
+        self.http.as_deref().filter(|url| self.one_click && is_https(url))
+    }
+}
+
+fn is_https(url: &str) -> bool {
+    url.get(..8).is_some_and(|scheme| scheme.eq_ignore_ascii_case("https://"))
+}
+
+/// Email to send to unsubscribe, from a `mailto:` URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MailtoDraft {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+impl MailtoDraft {
+    /// Parse `mailto:list@example.com?subject=unsubscribe&body=...`. Missing
+    /// subjects default to "unsubscribe", which list servers understand.
+    pub fn parse(uri: &str) -> Option<Self> {
+        let rest = uri.get(..7).filter(|s| s.eq_ignore_ascii_case("mailto:")).map(|_| &uri[7..])?;
+        let (to, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let to = decode(to);
+        // The address goes into a `To:` header; a line break would add headers
+        if !to.contains('@') || to.contains(['\r', '\n']) {
+            return None;
+        }
+        let mut draft =
+            Self { to, subject: "unsubscribe".to_string(), body: "unsubscribe".to_string() };
+        for (key, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            match key.to_ascii_lowercase().as_str() {
+                "subject" => draft.subject = header_value(&decode(value)),
+                "body" => draft.body = decode(value),
+                _ => {}
+            }
+        }
+        Some(draft)
+    }
+}
+
+fn decode(value: &str) -> String {
+    urlencoding::decode(value).map(|v| v.into_owned()).unwrap_or_else(|_| value.to_string())
+}
+
+/// `value` safe for a single header line: control characters (CR/LF above
+/// all, which would start new headers) become spaces.
+fn header_value(value: &str) -> String {
+    let line: String = value.chars().map(|c| if c.is_control() { ' ' } else { c }).collect();
+    line.trim().to_string()
+}
+
+/// Lowercased address from a `From` header like `"Shop" <news@shop.example>`;
+/// used as the key for per-sender data.
+pub fn sender_address(from: &str) -> String {
+    let from = from.trim();
+    let address = match (from.rfind('<'), from.rfind('>')) {
+        (Some(open), Some(close)) if open < close => &from[open + 1..close],
+        _ => from,
+    };
+    address.trim().to_lowercase()
+}
+
+/// Display name from a `From` header, falling back to the address.
+pub fn sender_name(from: &str) -> String {
+    let name = from.rfind('<').map(|open| from[..open].trim().trim_matches('"').trim());
+    match name {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => sender_address(from),
+    }
+}
+
+/// How much mail one sender sent over a period, and how to stop it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SenderSummary {
+    /// Lowercased address
+    pub sender: String,
+    /// Display name from the most recent message
+    pub name: String,
+    /// Messages received in the period
+    pub count: u32,
+    pub target: Option<UnsubscribeTarget>,
+    /// When the user last unsubscribed, if they have
+    pub unsubscribed_at: Option<DateTime<Utc>>,
+}
+
+impl SenderSummary {
+    /// e.g. "You got 14 emails from Shop this month"
+    pub fn describe(&self, period: &str) -> String {
+        let emails = if self.count == 1 { "email" } else { "emails" };
+        format!("You got {} {} from {} {}", self.count, emails, self.name, period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn parse_list_unsubscribe_headers() {
+        let target = UnsubscribeTarget::parse(
+            "<mailto:leave@lists.example.com?subject=stop%20please>, <https://example.com/u/42>",
+            Some("List-Unsubscribe=One-Click"),
+        )
+        .unwrap();
+        assert_eq!(target.http.as_deref(), Some("https://example.com/u/42"));
+        assert_eq!(target.one_click_url(), Some("https://example.com/u/42"));
+
+        let draft = MailtoDraft::parse(target.mailto.as_deref().unwrap()).unwrap();
+        assert_eq!(draft.to, "leave@lists.example.com");
+        assert_eq!(draft.subject, "stop please");
+        assert_eq!(draft.body, "unsubscribe");
+
+        // Without the Post header the link must be opened, not POSTed
+        let target = UnsubscribeTarget::parse("<https://example.com/u/42>", None).unwrap();
+        assert!(target.one_click_url().is_none());
+        assert!(target.mailto.is_none());
+
+        // One-click is https only (RFC 8058 section 3.1)
+        let target = UnsubscribeTarget::parse(
+            "<http://example.com/u/42>",
+            Some("List-Unsubscribe=One-Click"),
+        )
+        .unwrap();
+        assert!(!target.one_click);
+        assert!(target.one_click_url().is_none());
+        let stale = UnsubscribeTarget { one_click: true, ..target };
+        assert!(stale.one_click_url().is_none());
+
+        assert!(UnsubscribeTarget::parse("not a uri", None).is_none());
+        assert!(MailtoDraft::parse("https://example.com").is_none());
+    }
+
+    #[test]
+    fn mailto_cannot_inject_headers() {
+        let draft =
+            MailtoDraft::parse("mailto:leave@example.com?subject=x%0D%0ABcc:%20someone@evil")
+                .unwrap();
+        assert!(!draft.subject.contains(['\r', '\n']));
+        assert_eq!(draft.subject, "x  Bcc: someone@evil");
+
+        assert!(MailtoDraft::parse("mailto:leave@example.com%0ABcc:%20someone@evil").is_none());
+
+        // The body is not a header; line breaks stay
+        let draft = MailtoDraft::parse("mailto:leave@example.com?body=a%0D%0Ab").unwrap();
+        assert_eq!(draft.body, "a\r\nb");
+    }
+
+    #[test]
+    fn sender_key_and_summary() {
+        let from = "\"Shop News\" <News@Shop.Example>";
+        assert_eq!(sender_address(from), "news@shop.example");
+        assert_eq!(sender_name(from), "Shop News");
+        assert_eq!(sender_name("news@shop.example"), "news@shop.example");
+
+        let summary = SenderSummary {
+            sender: sender_address(from),
+            name: sender_name(from),
+            count: 14,
+            target: None,
+            unsubscribed_at: None,
+        };
+        assert_eq!(summary.describe("this month"), "You got 14 emails from Shop News this month");
+    }
+}
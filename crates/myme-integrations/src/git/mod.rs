@@ -1,9 +1,12 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use git2::{Repository as Git2Repository, Status, StatusOptions};
+use serde::Serialize;
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
 
 /// Local git repository information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct LocalRepo {
     /// Repository path
     pub path: PathBuf,
@@ -28,11 +31,306 @@ pub struct LocalRepo {
 
     /// Last commit time
     pub last_commit_time: Option<String>,
+
+    /// GitHub repository id recorded in the local config (`myme.repo-id`), which
+    /// keeps the link to its GitHub repo across renames and transfers
+    pub github_id: Option<i64>,
+}
+
+/// Local git config key holding the GitHub repository id.
+pub const REPO_ID_CONFIG_KEY: &str = "myme.repo-id";
+
+/// One commit with its change size against its first parent
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CommitInfo {
+    /// Abbreviated commit hash
+    pub id: String,
+    pub author: String,
+    /// First line of the commit message
+    pub message: String,
+    pub time: DateTime<Utc>,
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// Commit activity totals over a time window (merge commits excluded)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CommitStats {
+    pub commits: usize,
+    /// Distinct author names, sorted
+    pub authors: Vec<String>,
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// A configured remote
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RemoteInfo {
+    pub name: String,
+    pub url: Option<String>,
+    /// Only set when pushes go somewhere other than `url`
+    pub push_url: Option<String>,
+}
+
+/// Name of the remote a fork is synced from
+pub const UPSTREAM_REMOTE: &str = "upstream";
+
+/// Outcome of [`GitOperations::sync_fork`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ForkSync {
+    /// The upstream default branch that was brought up to date
+    pub branch: String,
+    /// Commits the local branch moved forward by (0 when already current)
+    pub fast_forwarded: usize,
+    /// Whether the branch was pushed to `origin`
+    pub pushed: bool,
+}
+
+/// How much of a repository [`GitOperations::clone_repository_with_progress`]
+/// fetches and checks out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CloneOptions {
+    /// Commits of history to fetch; 0 fetches all of it
+    pub depth: u32,
+    /// Fetch only `branch`, or the remote's default branch when unset
+    pub single_branch: bool,
+    /// Branch to check out instead of the remote's default
+    pub branch: Option<String>,
+    /// Directories or files (relative to the root) to check out; empty checks
+    /// out everything. Recorded in `.git/info/sparse-checkout` so the git CLI
+    /// agrees.
+    pub sparse_paths: Vec<String>,
+}
+
+/// Files larger than this (either side) are not diffed line by line
+pub const MAX_DIFF_FILE_BYTES: u64 = 512 * 1024;
+
+/// Lines kept per file diff; the rest are dropped and the diff marked truncated
+pub const MAX_DIFF_LINES: usize = 2000;
+
+/// Kind of a line in a diff hunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+/// One line of a hunk. Added lines have no old line number, removed lines no new one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub old_lineno: Option<u32>,
+    pub new_lineno: Option<u32>,
+    /// Line text without the trailing newline
+    pub text: String,
+}
+
+/// A contiguous block of changes with its surrounding context
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffHunk {
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Changes to one file between HEAD and the working tree (staged or not)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileDiff {
+    pub path: String,
+    /// Previous path when the file was renamed
+    pub old_path: Option<String>,
+    /// "added", "deleted", "renamed" or "modified"
+    pub status: String,
+    pub binary: bool,
+    /// Larger than [`MAX_DIFF_FILE_BYTES`]; no hunks are included
+    pub too_large: bool,
+    /// Hunks stop after [`MAX_DIFF_LINES`] lines
+    pub truncated: bool,
+    pub insertions: usize,
+    pub deletions: usize,
+    pub hunks: Vec<DiffHunk>,
 }
 
 /// Git operations handler
 pub struct GitOperations;
 
+/// Network transfer progress reported while cloning or fetching
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferProgress {
+    pub received_objects: usize,
+    pub total_objects: usize,
+    pub indexed_deltas: usize,
+    pub total_deltas: usize,
+    pub received_bytes: usize,
+}
+
+impl TransferProgress {
+    fn from_stats(stats: &git2::Progress<'_>) -> Self {
+        Self {
+            received_objects: stats.received_objects(),
+            total_objects: stats.total_objects(),
+            indexed_deltas: stats.indexed_deltas(),
+            total_deltas: stats.total_deltas(),
+            received_bytes: stats.received_bytes(),
+        }
+    }
+
+    /// Overall completion (0-100): objects received, then deltas resolved
+    pub fn percent(&self) -> u8 {
+        let total = self.total_objects + self.total_deltas;
+        if total == 0 {
+            return 0;
+        }
+        let done = self.received_objects + self.indexed_deltas;
+        ((done.min(total) * 100) / total) as u8
+    }
+}
+
+/// Error returned when a progress callback asks to stop a transfer
+#[derive(Debug, thiserror::Error)]
+#[error("Transfer cancelled")]
+pub struct TransferCancelled;
+
+/// Fetch options that report progress and abort when `on_progress` returns false
+fn fetch_options<'a, F>(on_progress: &'a mut F) -> git2::FetchOptions<'a>
+where
+    F: FnMut(TransferProgress) -> bool,
+{
+    let mut callbacks = git2::RemoteCallbacks::new();
+    callbacks.transfer_progress(move |stats| on_progress(TransferProgress::from_stats(&stats)));
+    let mut options = git2::FetchOptions::new();
+    options.remote_callbacks(callbacks);
+    options
+}
+
+/// Map a git2 failure caused by our callback returning false to [`TransferCancelled`]
+fn transfer_error(e: git2::Error, cancelled: bool, what: &'static str) -> anyhow::Error {
+    if cancelled || e.code() == git2::ErrorCode::User {
+        anyhow::Error::new(TransferCancelled)
+    } else {
+        anyhow::Error::new(e).context(what)
+    }
+}
+
+/// Sparse-checkout patterns file, relative to the `.git` directory
+const SPARSE_CHECKOUT_FILE: &str = "info/sparse-checkout";
+
+/// Paths a sparse clone checks out, or empty when the whole tree is checked out
+fn sparse_paths(repo: &Git2Repository) -> Vec<String> {
+    let enabled =
+        repo.config().ok().and_then(|c| c.get_bool("core.sparseCheckout").ok()).unwrap_or(false);
+    if !enabled {
+        return Vec::new();
+    }
+    let Ok(patterns) = std::fs::read_to_string(repo.path().join(SPARSE_CHECKOUT_FILE)) else {
+        return Vec::new();
+    };
+    // Only the plain `/path` lines written by clone are understood; negations
+    // and globs from hand-edited files are ignored
+    patterns
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with(['#', '!']))
+        .map(|line| line.trim_matches('/'))
+        .filter(|line| !line.is_empty() && !line.contains('*'))
+        .map(str::to_string)
+        .collect()
+}
+
+fn in_sparse_paths(path: &str, sparse: &[String]) -> bool {
+    sparse.iter().any(|p| {
+        path.strip_prefix(p.as_str()).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    })
+}
+
+/// Checkout limited to `sparse` (everything when empty)
+fn sparse_checkout<'cb>(sparse: &[String]) -> git2::build::CheckoutBuilder<'cb> {
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    for path in sparse {
+        checkout.path(path.as_str());
+    }
+    checkout
+}
+
+/// Record `sparse` so later pulls (and the git CLI) keep to it
+fn write_sparse_config(repo: &Git2Repository, sparse: &[String]) -> Result<()> {
+    let file = repo.path().join(SPARSE_CHECKOUT_FILE);
+    if let Some(dir) = file.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let patterns: String = sparse.iter().map(|p| format!("/{}\n", p)).collect();
+    std::fs::write(&file, patterns).context("Failed to write sparse-checkout file")?;
+    repo.config()?.set_bool("core.sparseCheckout", true)?;
+    Ok(())
+}
+
+/// Make the index match HEAD outside `sparse`, with those entries flagged
+/// skip-worktree. Without them a commit would delete every file that isn't
+/// checked out.
+fn mark_sparse_index(repo: &Git2Repository, sparse: &[String]) -> Result<()> {
+    let tree = repo.head()?.peel_to_tree().context("Failed to read HEAD tree")?;
+    let mut index = repo.index().context("Failed to get index")?;
+    let outside: Vec<String> = index
+        .iter()
+        .map(|entry| String::from_utf8_lossy(&entry.path).into_owned())
+        .filter(|path| !in_sparse_paths(path, sparse))
+        .collect();
+    for path in outside {
+        index.remove(Path::new(&path), 0)?;
+    }
+
+    let mut entries = Vec::new();
+    tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+        let path = format!("{}{}", dir, entry.name().unwrap_or_default());
+        if entry.kind() == Some(git2::ObjectType::Tree) {
+            return if in_sparse_paths(&path, sparse) {
+                git2::TreeWalkResult::Skip
+            } else {
+                git2::TreeWalkResult::Ok
+            };
+        }
+        if !in_sparse_paths(&path, sparse) {
+            entries.push(git2::IndexEntry {
+                ctime: git2::IndexTime::new(0, 0),
+                mtime: git2::IndexTime::new(0, 0),
+                dev: 0,
+                ino: 0,
+                mode: entry.filemode() as u32,
+                uid: 0,
+                gid: 0,
+                file_size: 0,
+                id: entry.id(),
+                flags: path.len().min(0xfff) as u16,
+                flags_extended: git2::IndexEntryExtendedFlag::SKIP_WORKTREE.bits(),
+                path: path.into_bytes(),
+            });
+        }
+        git2::TreeWalkResult::Ok
+    })?;
+    for entry in &entries {
+        index.add(entry)?;
+    }
+    index.write().context("Failed to write index")?;
+    Ok(())
+}
+
+/// Default branch a remote advertises, asked before anything is cloned
+fn remote_default_branch(url: &str) -> Result<String> {
+    let mut remote = git2::Remote::create_detached(url)?;
+    remote.connect(git2::Direction::Fetch).context("Failed to connect to remote")?;
+    let head = remote.default_branch().context("Remote has no default branch")?;
+    head.as_str()
+        .and_then(|h| h.strip_prefix("refs/heads/"))
+        .map(str::to_string)
+        .context("Remote has no default branch")
+}
+
 impl GitOperations {
     /// Discover git repositories in a directory
     ///
@@ -114,10 +412,17 @@ impl GitOperations {
         let remote_url =
             repo.find_remote("origin").ok().and_then(|remote| remote.url().map(|s| s.to_string()));
 
+        let github_id =
+            repo.config().ok().and_then(|config| config.get_i64(REPO_ID_CONFIG_KEY).ok());
+
         // Check working directory status
         let mut status_opts = StatusOptions::new();
         status_opts.include_untracked(true);
         status_opts.recurse_untracked_dirs(true);
+        // Files left out of a sparse clone would otherwise show as deleted
+        for path in sparse_paths(&repo) {
+            status_opts.pathspec(path);
+        }
 
         let statuses =
             repo.statuses(Some(&mut status_opts)).context("Failed to get repository status")?;
@@ -154,20 +459,439 @@ impl GitOperations {
             uncommitted_changes,
             last_commit,
             last_commit_time,
+            github_id,
         })
     }
 
+    /// The `n` most recent commits reachable from HEAD, newest first.
+    /// An empty repository yields no commits.
+    pub fn recent_commits(path: &Path, n: usize) -> Result<Vec<CommitInfo>> {
+        let repo = Git2Repository::open(path).context("Failed to open git repository")?;
+        let Some(walk) = Self::history(&repo)? else {
+            return Ok(Vec::new());
+        };
+        walk.take(n)
+            .map(|oid| {
+                let commit = repo.find_commit(oid?)?;
+                Self::commit_info(&repo, &commit)
+            })
+            .collect()
+    }
+
+    /// Totals for the non-merge commits reachable from HEAD made at or after `since`.
+    pub fn commit_stats(path: &Path, since: DateTime<Utc>) -> Result<CommitStats> {
+        let repo = Git2Repository::open(path).context("Failed to open git repository")?;
+        let mut stats = CommitStats::default();
+        let Some(walk) = Self::history(&repo)? else {
+            return Ok(stats);
+        };
+
+        let mut authors = BTreeSet::new();
+        for oid in walk {
+            let commit = repo.find_commit(oid?)?;
+            // History is walked newest first, so the window ends at the first older commit
+            if commit.time().seconds() < since.timestamp() {
+                break;
+            }
+            if commit.parent_count() > 1 {
+                continue;
+            }
+            let info = Self::commit_info(&repo, &commit)?;
+            stats.commits += 1;
+            stats.files_changed += info.files_changed;
+            stats.insertions += info.insertions;
+            stats.deletions += info.deletions;
+            authors.insert(info.author);
+        }
+        stats.authors = authors.into_iter().collect();
+        Ok(stats)
+    }
+
+    /// Revision walk over HEAD's history by commit time, or `None` for an unborn HEAD
+    fn history(repo: &Git2Repository) -> Result<Option<git2::Revwalk<'_>>> {
+        let mut walk = repo.revwalk().context("Failed to walk history")?;
+        if walk.push_head().is_err() {
+            return Ok(None);
+        }
+        walk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+        Ok(Some(walk))
+    }
+
+    fn commit_info(repo: &Git2Repository, commit: &git2::Commit<'_>) -> Result<CommitInfo> {
+        let tree = commit.tree()?;
+        let parent_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
+        let diff_stats =
+            repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?.stats()?;
+        let id = commit.id().to_string();
+
+        Ok(CommitInfo {
+            id: id.chars().take(7).collect(),
+            author: commit.author().name().unwrap_or("unknown").to_string(),
+            message: commit.summary().unwrap_or("").to_string(),
+            time: DateTime::from_timestamp(commit.time().seconds(), 0).unwrap_or_default(),
+            files_changed: diff_stats.files_changed(),
+            insertions: diff_stats.insertions(),
+            deletions: diff_stats.deletions(),
+        })
+    }
+
+    /// Uncommitted changes (staged, unstaged and untracked) in every file, as
+    /// structured hunks.
+    pub fn diff_workdir(path: &Path) -> Result<Vec<FileDiff>> {
+        let repo = Git2Repository::open(path).context("Failed to open git repository")?;
+        let diff = Self::workdir_diff(&repo, None)?;
+        (0..diff.deltas().len()).map(|i| Self::file_diff(&diff, i)).collect()
+    }
+
+    /// Uncommitted changes to `file` (relative to the repository root), or `None`
+    /// when it is unchanged.
+    pub fn diff_file(path: &Path, file: &str) -> Result<Option<FileDiff>> {
+        let repo = Git2Repository::open(path).context("Failed to open git repository")?;
+        let diff = Self::workdir_diff(&repo, Some(file))?;
+        if diff.deltas().len() == 0 {
+            return Ok(None);
+        }
+        Self::file_diff(&diff, 0).map(Some)
+    }
+
+    fn workdir_diff<'r>(repo: &'r Git2Repository, file: Option<&str>) -> Result<git2::Diff<'r>> {
+        // An unborn HEAD diffs against the empty tree
+        let head_tree = repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+        let mut opts = git2::DiffOptions::new();
+        opts.include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .show_untracked_content(true)
+            .max_size(MAX_DIFF_FILE_BYTES as i64);
+        if let Some(file) = file {
+            opts.pathspec(file).disable_pathspec_match(true);
+        } else {
+            for path in sparse_paths(repo) {
+                opts.pathspec(path);
+            }
+        }
+        let mut diff = repo
+            .diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut opts))
+            .context("Failed to diff working tree")?;
+        diff.find_similar(None).context("Failed to detect renames")?;
+        Ok(diff)
+    }
+
+    fn file_diff(diff: &git2::Diff<'_>, index: usize) -> Result<FileDiff> {
+        let delta = diff.get_delta(index).context("Missing diff entry")?;
+        let new_path = delta.new_file().path().map(|p| p.to_string_lossy().into_owned());
+        let old_path = delta.old_file().path().map(|p| p.to_string_lossy().into_owned());
+        let status = match delta.status() {
+            git2::Delta::Added | git2::Delta::Untracked => "added",
+            git2::Delta::Deleted => "deleted",
+            git2::Delta::Renamed => "renamed",
+            _ => "modified",
+        };
+        let too_large = delta.new_file().size().max(delta.old_file().size()) > MAX_DIFF_FILE_BYTES;
+
+        let mut out = FileDiff {
+            path: new_path.clone().or_else(|| old_path.clone()).unwrap_or_default(),
+            old_path: old_path.filter(|old| status == "renamed" && Some(old) != new_path.as_ref()),
+            status: status.to_string(),
+            binary: false,
+            too_large,
+            truncated: false,
+            insertions: 0,
+            deletions: 0,
+            hunks: Vec::new(),
+        };
+        if too_large {
+            return Ok(out);
+        }
+
+        // `max_size` makes libgit2 treat oversized files as binary too, so check size first
+        let patch = match git2::Patch::from_diff(diff, index)? {
+            Some(patch) if !patch.delta().flags().is_binary() => patch,
+            _ => {
+                out.binary = true;
+                return Ok(out);
+            }
+        };
+        let (_, insertions, deletions) = patch.line_stats()?;
+        out.insertions = insertions;
+        out.deletions = deletions;
+
+        let mut kept = 0;
+        'hunks: for h in 0..patch.num_hunks() {
+            let (hunk, line_count) = patch.hunk(h)?;
+            let mut lines = Vec::new();
+            for l in 0..line_count {
+                if kept == MAX_DIFF_LINES {
+                    out.truncated = true;
+                    if !lines.is_empty() {
+                        out.hunks.push(Self::hunk(&hunk, lines));
+                    }
+                    break 'hunks;
+                }
+                let line = patch.line_in_hunk(h, l)?;
+                let kind = match line.origin() {
+                    '+' => DiffLineKind::Added,
+                    '-' => DiffLineKind::Removed,
+                    ' ' => DiffLineKind::Context,
+                    // "\ No newline at end of file" markers
+                    _ => continue,
+                };
+                let text = String::from_utf8_lossy(line.content());
+                lines.push(DiffLine {
+                    kind,
+                    old_lineno: line.old_lineno(),
+                    new_lineno: line.new_lineno(),
+                    text: text.trim_end_matches(['\n', '\r']).to_string(),
+                });
+                kept += 1;
+            }
+            out.hunks.push(Self::hunk(&hunk, lines));
+        }
+        Ok(out)
+    }
+
+    fn hunk(hunk: &git2::DiffHunk<'_>, lines: Vec<DiffLine>) -> DiffHunk {
+        DiffHunk {
+            old_start: hunk.old_start(),
+            old_lines: hunk.old_lines(),
+            new_start: hunk.new_start(),
+            new_lines: hunk.new_lines(),
+            lines,
+        }
+    }
+
+    /// Record the GitHub repository id in the repo's local config so later
+    /// matching survives the remote being renamed.
+    pub fn set_github_id(path: &Path, id: i64) -> Result<()> {
+        let repo = Git2Repository::open(path).context("Failed to open git repository")?;
+        let config = repo.config().context("Failed to open repository config")?;
+        let mut local = config
+            .open_level(git2::ConfigLevel::Local)
+            .context("Failed to open local repository config")?;
+        local.set_i64(REPO_ID_CONFIG_KEY, id).context("Failed to write repository id")?;
+        Ok(())
+    }
+
+    /// Remotes configured for the repository, in name order
+    pub fn list_remotes(path: &Path) -> Result<Vec<RemoteInfo>> {
+        let repo = Git2Repository::open(path).context("Failed to open git repository")?;
+        let names = repo.remotes().context("Failed to list remotes")?;
+        let mut remotes = Vec::new();
+        for name in names.iter().flatten() {
+            let remote = repo.find_remote(name).context("Failed to read remote")?;
+            let url = remote.url().map(str::to_string);
+            let push_url = remote.pushurl().map(str::to_string).filter(|p| Some(p) != url.as_ref());
+            remotes.push(RemoteInfo { name: name.to_string(), url, push_url });
+        }
+        remotes.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(remotes)
+    }
+
+    /// Add remote `name` pointing at `url`, or repoint it if it already exists
+    pub fn set_remote(path: &Path, name: &str, url: &str) -> Result<()> {
+        let repo = Git2Repository::open(path).context("Failed to open git repository")?;
+        if repo.find_remote(name).is_ok() {
+            repo.remote_set_url(name, url).context("Failed to update remote")?;
+        } else {
+            repo.remote(name, url).context("Failed to add remote")?;
+        }
+        tracing::info!("Remote {} of {:?} set to {}", name, path, url);
+        Ok(())
+    }
+
+    /// Bring a fork's default branch up to date with its `upstream` remote.
+    pub fn sync_fork(path: &Path, push: bool) -> Result<ForkSync> {
+        Self::sync_fork_with_progress(path, push, |_| true)
+    }
+
+    /// Fetch `upstream`, fast-forward the local copy of its default branch and
+    /// optionally push that branch to `origin`. Fails without touching
+    /// anything when the local branch has commits upstream doesn't; those need
+    /// a manual merge or rebase. Returning false from `on_progress` aborts the
+    /// fetch with [`TransferCancelled`].
+    #[tracing::instrument(skip(path, on_progress), fields(repo = %path.display()), level = "info")]
+    pub fn sync_fork_with_progress<F>(
+        path: &Path,
+        push: bool,
+        mut on_progress: F,
+    ) -> Result<ForkSync>
+    where
+        F: FnMut(TransferProgress) -> bool,
+    {
+        let repo = Git2Repository::open(path).context("Failed to open git repository")?;
+        let mut upstream = repo
+            .find_remote(UPSTREAM_REMOTE)
+            .context("No 'upstream' remote configured; add one to sync this fork")?;
+
+        let mut cancelled = false;
+        let mut track = |progress| {
+            cancelled = !on_progress(progress);
+            !cancelled
+        };
+        let result = upstream.fetch(&[] as &[&str], Some(&mut fetch_options(&mut track)), None);
+        result.map_err(|e| transfer_error(e, cancelled, "Failed to fetch from upstream"))?;
+
+        let branch = Self::upstream_default_branch(&repo, &upstream)?;
+        let upstream_ref = format!("refs/remotes/{}/{}", UPSTREAM_REMOTE, branch);
+        let target = repo
+            .refname_to_id(&upstream_ref)
+            .with_context(|| format!("Upstream has no branch '{}'", branch))?;
+
+        let local_ref = format!("refs/heads/{}", branch);
+        let fast_forwarded = match repo.refname_to_id(&local_ref) {
+            Ok(local) if local == target => 0,
+            Ok(local) => {
+                let (ahead, behind) = repo.graph_ahead_behind(local, target)?;
+                if ahead > 0 {
+                    anyhow::bail!(
+                        "'{}' has {} commit(s) upstream doesn't; merge or rebase manually",
+                        branch,
+                        ahead
+                    );
+                }
+                Self::move_branch(&repo, &local_ref, target)?;
+                behind
+            }
+            Err(_) => {
+                repo.reference(&local_ref, target, false, "sync fork: create from upstream")
+                    .context("Failed to create branch")?;
+                0
+            }
+        };
+
+        if push {
+            let mut origin =
+                repo.find_remote("origin").context("Failed to find remote 'origin'")?;
+            origin
+                .push(&[format!("{0}:{0}", local_ref)], None)
+                .context("Failed to push to origin")?;
+        }
+
+        tracing::info!("Synced {} of {:?} ({} new commits)", branch, path, fast_forwarded);
+        Ok(ForkSync { branch, fast_forwarded, pushed: push })
+    }
+
+    /// Default branch of a just-fetched remote: its advertised HEAD, else a
+    /// fetched `main` or `master`
+    fn upstream_default_branch(repo: &Git2Repository, remote: &git2::Remote<'_>) -> Result<String> {
+        if let Ok(head) = remote.default_branch() {
+            if let Some(name) = head.as_str().and_then(|h| h.strip_prefix("refs/heads/")) {
+                return Ok(name.to_string());
+            }
+        }
+        ["main", "master"]
+            .into_iter()
+            .find(|b| {
+                repo.refname_to_id(&format!("refs/remotes/{}/{}", UPSTREAM_REMOTE, b)).is_ok()
+            })
+            .map(str::to_string)
+            .context("Couldn't tell upstream's default branch")
+    }
+
+    /// Point `refname` at `target`, updating the working tree when it is checked out
+    fn move_branch(repo: &Git2Repository, refname: &str, target: git2::Oid) -> Result<()> {
+        let checked_out = repo.head().ok().and_then(|h| h.name().map(str::to_string))
+            == Some(refname.to_string());
+        if checked_out {
+            // Safe checkout: refuses to overwrite local edits rather than losing them
+            let commit = repo.find_commit(target)?;
+            let sparse = sparse_paths(repo);
+            repo.checkout_tree(commit.as_object(), Some(sparse_checkout(&sparse).safe()))
+                .context("Local changes conflict with upstream; commit or stash them first")?;
+            repo.find_reference(refname)?
+                .set_target(target, "sync fork: fast-forward")
+                .context("Failed to update branch")?;
+            if !sparse.is_empty() {
+                mark_sparse_index(repo, &sparse)?;
+            }
+            return Ok(());
+        }
+        repo.find_reference(refname)?
+            .set_target(target, "sync fork: fast-forward")
+            .context("Failed to update branch")?;
+        Ok(())
+    }
+
     /// Clone a repository
     ///
     /// # Arguments
     /// * `url` - Repository URL to clone
     /// * `target_path` - Target directory for cloning
-    #[tracing::instrument(skip(target_path), fields(target = %target_path.display()), level = "info")]
     pub fn clone_repository(url: &str, target_path: &Path) -> Result<LocalRepo> {
+        Self::clone_repository_with_progress(url, target_path, &CloneOptions::default(), |_| true)
+    }
+
+    /// Clone a repository, reporting transfer progress.
+    ///
+    /// `options` can shorten history, skip other branches and check out only
+    /// some paths. `on_progress` is called as objects arrive; returning false
+    /// aborts the clone with [`TransferCancelled`] and removes the partial
+    /// checkout.
+    #[tracing::instrument(skip(target_path, options, on_progress), fields(target = %target_path.display()), level = "info")]
+    pub fn clone_repository_with_progress<F>(
+        url: &str,
+        target_path: &Path,
+        options: &CloneOptions,
+        mut on_progress: F,
+    ) -> Result<LocalRepo>
+    where
+        F: FnMut(TransferProgress) -> bool,
+    {
         tracing::info!("Cloning repository from {} to {:?}", url, target_path);
 
-        let _repo =
-            Git2Repository::clone(url, target_path).context("Failed to clone repository")?;
+        let branch = match (&options.branch, options.single_branch) {
+            (Some(branch), _) => Some(branch.clone()),
+            (None, true) => Some(remote_default_branch(url)?),
+            (None, false) => None,
+        };
+        let sparse: Vec<String> = options
+            .sparse_paths
+            .iter()
+            .map(|p| p.trim().trim_matches('/').to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+
+        let existed = target_path.exists();
+        let mut cancelled = false;
+        let mut track = |progress| {
+            cancelled = !on_progress(progress);
+            !cancelled
+        };
+        let mut fetch = fetch_options(&mut track);
+        if options.depth > 0 {
+            fetch.depth(options.depth.min(i32::MAX as u32) as i32);
+        }
+        let mut builder = git2::build::RepoBuilder::new();
+        builder.fetch_options(fetch).with_checkout(sparse_checkout(&sparse));
+        if let Some(branch) = &branch {
+            builder.branch(branch);
+            if options.single_branch {
+                let refspec = format!("+refs/heads/{0}:refs/remotes/origin/{0}", branch);
+                builder.remote_create(move |repo, name, url| {
+                    repo.remote_with_fetch(name, url, &refspec)
+                });
+            }
+        }
+        let cloned = builder.clone(url, target_path);
+        // The builder holds the progress callback, which borrows `cancelled`
+        drop(builder);
+        let result = cloned
+            .map_err(|e| transfer_error(e, cancelled, "Failed to clone repository"))
+            .and_then(|repo| {
+                if sparse.is_empty() {
+                    return Ok(());
+                }
+                write_sparse_config(&repo, &sparse)?;
+                mark_sparse_index(&repo, &sparse)
+            });
+        if let Err(e) = result {
+            if !existed && target_path.exists() {
+                let _ = std::fs::remove_dir_all(target_path);
+            }
+            return Err(e);
+        }
 
         tracing::info!("Successfully cloned repository");
 
@@ -178,8 +902,17 @@ impl GitOperations {
     ///
     /// # Arguments
     /// * `path` - Repository path
-    #[tracing::instrument(skip(path), fields(repo = %path.display()), level = "info")]
     pub fn fetch(path: &Path) -> Result<()> {
+        Self::fetch_with_progress(path, |_| true)
+    }
+
+    /// Fetch from remote, reporting transfer progress; returning false from
+    /// `on_progress` aborts with [`TransferCancelled`].
+    #[tracing::instrument(skip(path, on_progress), fields(repo = %path.display()), level = "info")]
+    pub fn fetch_with_progress<F>(path: &Path, mut on_progress: F) -> Result<()>
+    where
+        F: FnMut(TransferProgress) -> bool,
+    {
         let repo = Git2Repository::open(path).context("Failed to open git repository")?;
 
         let head = repo.head().context("Failed to get HEAD reference")?;
@@ -187,7 +920,13 @@ impl GitOperations {
 
         let mut remote = repo.find_remote("origin").context("Failed to find remote 'origin'")?;
 
-        remote.fetch(&[branch_name], None, None).context("Failed to fetch from remote")?;
+        let mut cancelled = false;
+        let mut track = |progress| {
+            cancelled = !on_progress(progress);
+            !cancelled
+        };
+        let result = remote.fetch(&[branch_name], Some(&mut fetch_options(&mut track)), None);
+        result.map_err(|e| transfer_error(e, cancelled, "Failed to fetch from remote"))?;
 
         tracing::info!("Fetched latest for {:?}", path);
         Ok(())
@@ -197,9 +936,18 @@ impl GitOperations {
     ///
     /// # Arguments
     /// * `path` - Repository path
-    #[tracing::instrument(skip(path), fields(repo = %path.display()), level = "info")]
     pub fn pull(path: &Path) -> Result<()> {
-        Self::fetch(path)?;
+        Self::pull_with_progress(path, |_| true)
+    }
+
+    /// Pull latest changes, reporting fetch progress; returning false from
+    /// `on_progress` aborts before anything is merged.
+    #[tracing::instrument(skip(path, on_progress), fields(repo = %path.display()), level = "info")]
+    pub fn pull_with_progress<F>(path: &Path, on_progress: F) -> Result<()>
+    where
+        F: FnMut(TransferProgress) -> bool,
+    {
+        Self::fetch_with_progress(path, on_progress)?;
 
         let repo = Git2Repository::open(path).context("Failed to open git repository")?;
 
@@ -227,14 +975,20 @@ impl GitOperations {
                 .set_target(fetch_commit.id(), "fast-forward")
                 .context("Failed to update ref")?;
             repo.set_head(&refname).context("Failed to set HEAD")?;
-            repo.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))
+            let sparse = sparse_paths(&repo);
+            repo.checkout_head(Some(sparse_checkout(&sparse).force()))
                 .context("Failed to checkout")?;
+            if !sparse.is_empty() {
+                mark_sparse_index(&repo, &sparse)?;
+            }
             tracing::info!("Fast-forwarded {:?}", path);
             return Ok(());
         }
 
         if analysis.is_normal() {
-            repo.merge(&[&fetch_commit], None, None).context("Failed to merge")?;
+            let sparse = sparse_paths(&repo);
+            repo.merge(&[&fetch_commit], None, Some(&mut sparse_checkout(&sparse)))
+                .context("Failed to merge")?;
             let mut index = repo.index().context("Failed to get index")?;
             if index.has_conflicts() {
                 anyhow::bail!("Merge conflicts; resolve manually");
@@ -251,6 +1005,9 @@ impl GitOperations {
             let sig = repo.signature().context("Failed to get signature")?;
             repo.commit(Some("HEAD"), &sig, &sig, "Merge", &tree, &[&head_commit, &their_commit])
                 .context("Failed to create merge commit")?;
+            if !sparse.is_empty() {
+                mark_sparse_index(&repo, &sparse)?;
+            }
             tracing::info!("Merged for {:?}", path);
             return Ok(());
         }
@@ -292,6 +1049,10 @@ impl GitOperations {
         let mut status_opts = StatusOptions::new();
         status_opts.include_untracked(true);
         status_opts.recurse_untracked_dirs(true);
+        // Files left out of a sparse clone would otherwise show as deleted
+        for path in sparse_paths(&repo) {
+            status_opts.pathspec(path);
+        }
 
         let statuses =
             repo.statuses(Some(&mut status_opts)).context("Failed to get repository status")?;
@@ -366,6 +1127,119 @@ mod tests {
         assert!(names.contains(&"repo3"));
     }
 
+    #[test]
+    fn test_github_id_round_trip() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        git2::Repository::init(dir.path()).unwrap();
+
+        assert_eq!(GitOperations::get_repository_info(dir.path()).unwrap().github_id, None);
+        GitOperations::set_github_id(dir.path(), 4242).unwrap();
+        assert_eq!(GitOperations::get_repository_info(dir.path()).unwrap().github_id, Some(4242));
+    }
+
+    #[test]
+    fn test_recent_commits_and_stats() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        assert!(GitOperations::recent_commits(dir.path(), 5).unwrap().is_empty());
+
+        let sig = git2::Signature::now("Ada", "ada@example.com").unwrap();
+        let mut parent: Option<git2::Oid> = None;
+        for (i, body) in ["one\n", "one\ntwo\n", "two\n"].iter().enumerate() {
+            fs::write(dir.path().join("file.txt"), body).unwrap();
+            let mut index = repo.index().unwrap();
+            index.add_path(Path::new("file.txt")).unwrap();
+            index.write().unwrap();
+            let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+            let parents: Vec<git2::Commit> =
+                parent.iter().map(|id| repo.find_commit(*id).unwrap()).collect();
+            let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+            let message = format!("commit {}\n\nbody", i);
+            parent =
+                Some(repo.commit(Some("HEAD"), &sig, &sig, &message, &tree, &parent_refs).unwrap());
+        }
+
+        let commits = GitOperations::recent_commits(dir.path(), 2).unwrap();
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].message, "commit 2");
+        assert_eq!(commits[0].author, "Ada");
+        assert_eq!(commits[0].id.len(), 7);
+        assert_eq!((commits[0].insertions, commits[0].deletions), (0, 1));
+        assert_eq!((commits[1].insertions, commits[1].deletions), (1, 0));
+
+        let week = GitOperations::commit_stats(dir.path(), Utc::now() - chrono::Duration::days(7))
+            .unwrap();
+        assert_eq!(week.commits, 3);
+        assert_eq!(week.authors, vec!["Ada".to_string()]);
+        assert_eq!((week.insertions, week.deletions), (2, 1));
+
+        let future =
+            GitOperations::commit_stats(dir.path(), Utc::now() + chrono::Duration::hours(1))
+                .unwrap();
+        assert_eq!(future, CommitStats::default());
+    }
+
+    #[test]
+    fn test_diff_workdir_and_file() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        fs::write(dir.path().join("a.txt"), "one\ntwo\nthree\n").unwrap();
+        fs::write(dir.path().join("gone.txt"), "bye\n").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("a.txt")).unwrap();
+        index.add_path(Path::new("gone.txt")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "init", &tree, &[]).unwrap();
+
+        fs::write(dir.path().join("a.txt"), "one\nTWO\nthree\nfour\n").unwrap();
+        fs::remove_file(dir.path().join("gone.txt")).unwrap();
+        fs::write(dir.path().join("new.txt"), "hello\n").unwrap();
+        fs::write(dir.path().join("blob.bin"), [0u8, 159, 146, 150, 0, 1]).unwrap();
+        let big = "x".repeat(80) + "\n";
+        fs::write(dir.path().join("big.txt"), big.repeat(8000)).unwrap();
+
+        let diffs = GitOperations::diff_workdir(dir.path()).unwrap();
+        let by_path = |p: &str| diffs.iter().find(|d| d.path == p).unwrap();
+
+        let a = by_path("a.txt");
+        assert_eq!(a.status, "modified");
+        assert_eq!((a.insertions, a.deletions), (2, 1));
+        assert_eq!(a.hunks.len(), 1);
+        let lines = &a.hunks[0].lines;
+        assert_eq!(lines[0].kind, DiffLineKind::Context);
+        assert_eq!((lines[0].old_lineno, lines[0].new_lineno), (Some(1), Some(1)));
+        let removed = lines.iter().find(|l| l.kind == DiffLineKind::Removed).unwrap();
+        assert_eq!(
+            (removed.text.as_str(), removed.old_lineno, removed.new_lineno),
+            ("two", Some(2), None)
+        );
+        let added: Vec<_> = lines
+            .iter()
+            .filter(|l| l.kind == DiffLineKind::Added)
+            .map(|l| (l.text.as_str(), l.new_lineno))
+            .collect();
+        assert_eq!(added, vec![("TWO", Some(2)), ("four", Some(4))]);
+
+        assert_eq!(by_path("gone.txt").status, "deleted");
+        assert_eq!(by_path("new.txt").status, "added");
+        assert!(by_path("blob.bin").binary);
+        let big = by_path("big.txt");
+        assert!(big.too_large && big.hunks.is_empty());
+
+        fs::write(dir.path().join("long.txt"), "y\n".repeat(MAX_DIFF_LINES + 10)).unwrap();
+        let long = GitOperations::diff_file(dir.path(), "long.txt").unwrap().unwrap();
+        assert!(long.truncated);
+        assert_eq!(long.insertions, MAX_DIFF_LINES + 10);
+        assert_eq!(long.hunks.iter().map(|h| h.lines.len()).sum::<usize>(), MAX_DIFF_LINES);
+
+        let single = GitOperations::diff_file(dir.path(), "new.txt").unwrap().unwrap();
+        assert_eq!(single.hunks[0].lines[0].text, "hello");
+        fs::write(dir.path().join("a.txt"), "one\ntwo\nthree\n").unwrap();
+        assert!(GitOperations::diff_file(dir.path(), "a.txt").unwrap().is_none());
+    }
+
     #[test]
     fn test_clone_from_local() {
         // Create a "remote" repo
@@ -395,6 +1269,43 @@ mod tests {
         assert!(target_path.join("README").exists());
     }
 
+    #[test]
+    fn test_clone_progress_and_cancel() {
+        let remote_dir = tempfile::tempdir().expect("remote");
+        let remote_path = remote_dir.path();
+        let repo = git2::Repository::init(remote_path).unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        fs::File::create(remote_path.join("README")).unwrap().write_all(b"hello").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(std::path::Path::new("README")).unwrap();
+        index.write().unwrap();
+        let tree_id = index.write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[]).unwrap();
+        let url = format!("file://{}", remote_path.display());
+
+        let target_dir = tempfile::tempdir().expect("target");
+        let target_path = target_dir.path().join("cloned");
+        let mut last = TransferProgress::default();
+        let options = CloneOptions::default();
+        GitOperations::clone_repository_with_progress(&url, &target_path, &options, |p| {
+            last = p;
+            true
+        })
+        .unwrap();
+        assert!(last.total_objects > 0);
+        assert_eq!(last.percent(), 100);
+
+        let cancelled_path = target_dir.path().join("cancelled");
+        let err =
+            GitOperations::clone_repository_with_progress(&url, &cancelled_path, &options, |_| {
+                false
+            })
+            .unwrap_err();
+        assert!(err.is::<TransferCancelled>());
+        assert!(!cancelled_path.exists());
+    }
+
     #[test]
     fn test_fetch_and_pull() {
         // Create remote repo with a commit
@@ -434,4 +1345,115 @@ mod tests {
         let content = fs::read_to_string(target_path.join("file.txt")).unwrap();
         assert_eq!(content, "v2");
     }
+
+    fn commit_file(repo: &git2::Repository, body: &str) -> git2::Oid {
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        fs::write(repo.workdir().unwrap().join("file.txt"), body).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("file.txt")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, body, &tree, &parents).unwrap()
+    }
+
+    #[test]
+    fn test_sync_fork_fast_forwards_and_pushes() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let upstream_path = dir.path().join("upstream");
+        let upstream = git2::Repository::init(&upstream_path).unwrap();
+        commit_file(&upstream, "v1");
+        let branch = upstream.head().unwrap().shorthand().unwrap().to_string();
+
+        // The fork on "GitHub" (bare) and its local clone
+        let origin_path = dir.path().join("origin.git");
+        git2::build::RepoBuilder::new()
+            .bare(true)
+            .clone(upstream_path.to_str().unwrap(), &origin_path)
+            .unwrap();
+        let fork_path = dir.path().join("fork");
+        GitOperations::clone_repository(origin_path.to_str().unwrap(), &fork_path).unwrap();
+
+        assert!(GitOperations::sync_fork(&fork_path, false).is_err(), "no upstream remote yet");
+        GitOperations::set_remote(&fork_path, UPSTREAM_REMOTE, upstream_path.to_str().unwrap())
+            .unwrap();
+        let names: Vec<String> =
+            GitOperations::list_remotes(&fork_path).unwrap().into_iter().map(|r| r.name).collect();
+        assert_eq!(names, vec!["origin", "upstream"]);
+
+        let v2 = commit_file(&upstream, "v2");
+        let synced = GitOperations::sync_fork(&fork_path, true).unwrap();
+        assert_eq!(synced, ForkSync { branch: branch.clone(), fast_forwarded: 1, pushed: true });
+        assert_eq!(fs::read_to_string(fork_path.join("file.txt")).unwrap(), "v2");
+        let origin = git2::Repository::open_bare(&origin_path).unwrap();
+        assert_eq!(origin.refname_to_id(&format!("refs/heads/{}", branch)).unwrap(), v2);
+
+        // Local work on the default branch blocks the fast-forward
+        let fork = git2::Repository::open(&fork_path).unwrap();
+        commit_file(&fork, "mine");
+        commit_file(&upstream, "v3");
+        let err = GitOperations::sync_fork(&fork_path, false).unwrap_err();
+        assert!(err.to_string().contains("manually"), "{}", err);
+        assert_eq!(fs::read_to_string(fork_path.join("file.txt")).unwrap(), "mine");
+    }
+
+    #[test]
+    fn test_single_branch_sparse_clone() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let remote_path = dir.path().join("monorepo");
+        let remote = git2::Repository::init(&remote_path).unwrap();
+        for file in ["apps/web/index.js", "apps/api/main.rs", "docs/guide.md"] {
+            let full = remote_path.join(file);
+            fs::create_dir_all(full.parent().unwrap()).unwrap();
+            fs::write(full, file).unwrap();
+        }
+        commit_file(&remote, "v1");
+        let mut index = remote.index().unwrap();
+        index.add_all(["*"], git2::IndexAddOption::DEFAULT, None).unwrap();
+        index.write().unwrap();
+        let sig = git2::Signature::now("Test", "test@test.com").unwrap();
+        let tree = remote.find_tree(index.write_tree().unwrap()).unwrap();
+        let head = remote.head().unwrap().peel_to_commit().unwrap();
+        remote.commit(Some("HEAD"), &sig, &sig, "tree", &tree, &[&head]).unwrap();
+        let default = remote.head().unwrap().shorthand().unwrap().to_string();
+        remote.branch("experiment", &head, false).unwrap();
+
+        let clone_path = dir.path().join("clone");
+        let options = CloneOptions {
+            single_branch: true,
+            sparse_paths: vec!["apps/web/".into(), " docs ".into()],
+            ..CloneOptions::default()
+        };
+        let url = remote_path.to_str().unwrap();
+        let info =
+            GitOperations::clone_repository_with_progress(url, &clone_path, &options, |_| true)
+                .unwrap();
+        assert_eq!(info.current_branch.as_deref(), Some(default.as_str()));
+        assert!(info.is_clean, "files outside the sparse paths aren't deletions");
+        assert!(clone_path.join("apps/web/index.js").exists());
+        assert!(clone_path.join("docs/guide.md").exists());
+        assert!(!clone_path.join("apps/api").exists() && !clone_path.join("file.txt").exists());
+
+        let clone = git2::Repository::open(&clone_path).unwrap();
+        assert!(clone.find_reference("refs/remotes/origin/experiment").is_err());
+        let skipped = clone.index().unwrap().get_path(Path::new("apps/api/main.rs"), 0).unwrap();
+        assert!(skipped.flags_extended & git2::IndexEntryExtendedFlag::SKIP_WORKTREE.bits() != 0);
+
+        // Pull keeps to the sparse paths and the index in step with HEAD
+        commit_file(&remote, "v2");
+        GitOperations::pull(&clone_path).unwrap();
+        assert!(!clone_path.join("file.txt").exists());
+        let clone = git2::Repository::open(&clone_path).unwrap();
+        let head_tree = clone.head().unwrap().peel_to_tree().unwrap();
+        let index_tree = clone.index().unwrap().write_tree().unwrap();
+        assert_eq!(index_tree, head_tree.id());
+        assert!(GitOperations::get_uncommitted_files(&clone_path).unwrap().is_empty());
+        fs::write(clone_path.join("docs/guide.md"), "edited").unwrap();
+        assert_eq!(
+            GitOperations::get_uncommitted_files(&clone_path).unwrap(),
+            vec![("docs/guide.md".to_string(), "modified".to_string())]
+        );
+        assert_eq!(GitOperations::diff_workdir(&clone_path).unwrap().len(), 1);
+    }
 }
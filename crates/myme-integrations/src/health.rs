@@ -0,0 +1,255 @@
+//! Hygiene report for a local clone: stale branches, missing LICENSE or CI,
+//! commits not yet pushed and large files left untracked.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use git2::{BranchType, Repository as Git2Repository, Status, StatusOptions};
+use serde::Serialize;
+use std::path::Path;
+
+/// Untracked files at least this large are reported (5 MiB)
+pub const LARGE_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Thresholds for a health check
+#[derive(Debug, Clone, Copy)]
+pub struct HealthOptions {
+    /// Branches whose tip is at least this many days old are stale
+    pub stale_days: u32,
+    pub large_file_bytes: u64,
+}
+
+impl Default for HealthOptions {
+    fn default() -> Self {
+        Self { stale_days: 90, large_file_bytes: LARGE_FILE_BYTES }
+    }
+}
+
+/// Local branch with no recent commits
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StaleBranch {
+    pub name: String,
+    pub last_commit: DateTime<Utc>,
+    pub days: i64,
+}
+
+/// Local branch ahead of its upstream
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UnpushedBranch {
+    pub name: String,
+    pub ahead: usize,
+}
+
+/// Untracked file over the size threshold
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LargeFile {
+    /// Path relative to the repository root
+    pub path: String,
+    pub bytes: u64,
+}
+
+/// Result of [`check_repo_health`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RepoHealth {
+    /// 0-100, lower for each problem found
+    pub score: u8,
+    pub has_license: bool,
+    pub has_ci: bool,
+    /// Oldest first; the checked-out branch is never listed
+    pub stale_branches: Vec<StaleBranch>,
+    /// Only branches with an upstream are compared
+    pub unpushed: Vec<UnpushedBranch>,
+    /// Largest first
+    pub large_untracked: Vec<LargeFile>,
+}
+
+impl RepoHealth {
+    /// Number of findings, for a compact badge
+    pub fn issue_count(&self) -> usize {
+        usize::from(!self.has_license)
+            + usize::from(!self.has_ci)
+            + self.stale_branches.len()
+            + self.unpushed.len()
+            + self.large_untracked.len()
+    }
+
+    fn compute_score(&self) -> u8 {
+        let capped = |count: usize, each: u32| (count as u32 * each).min(20);
+        let penalty = 15 * u32::from(!self.has_license)
+            + 15 * u32::from(!self.has_ci)
+            + capped(self.stale_branches.len(), 5)
+            + capped(self.unpushed.len(), 10)
+            + capped(self.large_untracked.len(), 10);
+        100u32.saturating_sub(penalty) as u8
+    }
+}
+
+/// Files at the repository root that count as a license
+const LICENSE_PREFIXES: &[&str] = &["LICENSE", "LICENCE", "COPYING", "UNLICENSE"];
+
+/// CI configuration files other than GitHub Actions workflows
+const CI_FILES: &[&str] = &[
+    ".gitlab-ci.yml",
+    ".travis.yml",
+    ".circleci/config.yml",
+    "azure-pipelines.yml",
+    "bitbucket-pipelines.yml",
+    ".woodpecker.yml",
+    "Jenkinsfile",
+];
+
+/// Check the clone at `path`. `now` dates the stale branches.
+pub fn check_repo_health(
+    path: &Path,
+    options: &HealthOptions,
+    now: DateTime<Utc>,
+) -> Result<RepoHealth> {
+    let repo = Git2Repository::open(path).context("Failed to open git repository")?;
+    let workdir = repo.workdir().context("Repository has no working directory")?;
+
+    let mut health = RepoHealth {
+        has_license: has_license(workdir),
+        has_ci: has_ci(workdir),
+        ..RepoHealth::default()
+    };
+
+    for branch in repo.branches(Some(BranchType::Local))? {
+        let (branch, _) = branch?;
+        let Some(name) = branch.name()?.map(str::to_string) else {
+            continue;
+        };
+        let Ok(tip) = branch.get().peel_to_commit() else {
+            continue;
+        };
+
+        if !branch.is_head() {
+            let last_commit = DateTime::from_timestamp(tip.time().seconds(), 0).unwrap_or_default();
+            let days = (now - last_commit).num_days();
+            if days >= i64::from(options.stale_days) {
+                health.stale_branches.push(StaleBranch { name: name.clone(), last_commit, days });
+            }
+        }
+
+        let upstream = branch.upstream().ok().and_then(|u| u.get().target());
+        if let Some(upstream) = upstream {
+            let (ahead, _) = repo.graph_ahead_behind(tip.id(), upstream)?;
+            if ahead > 0 {
+                health.unpushed.push(UnpushedBranch { name, ahead });
+            }
+        }
+    }
+    health.stale_branches.sort_by_key(|b| b.last_commit);
+    health.unpushed.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut status_opts = StatusOptions::new();
+    status_opts.include_untracked(true);
+    status_opts.recurse_untracked_dirs(true);
+    let statuses =
+        repo.statuses(Some(&mut status_opts)).context("Failed to get repository status")?;
+    for entry in statuses.iter() {
+        if !entry.status().contains(Status::WT_NEW) {
+            continue;
+        }
+        let Some(file) = entry.path() else {
+            continue;
+        };
+        let bytes = std::fs::metadata(workdir.join(file)).map(|m| m.len()).unwrap_or(0);
+        if bytes >= options.large_file_bytes {
+            health.large_untracked.push(LargeFile { path: file.to_string(), bytes });
+        }
+    }
+    health.large_untracked.sort_by_key(|f| std::cmp::Reverse(f.bytes));
+
+    health.score = health.compute_score();
+    Ok(health)
+}
+
+fn has_license(workdir: &Path) -> bool {
+    let Ok(entries) = std::fs::read_dir(workdir) else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        let name = entry.file_name().to_string_lossy().to_uppercase();
+        entry.path().is_file() && LICENSE_PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+    })
+}
+
+fn has_ci(workdir: &Path) -> bool {
+    let workflows = std::fs::read_dir(workdir.join(".github").join("workflows"))
+        .map(|entries| {
+            entries.flatten().any(|entry| {
+                let name = entry.file_name().to_string_lossy().to_lowercase();
+                name.ends_with(".yml") || name.ends_with(".yaml")
+            })
+        })
+        .unwrap_or(false);
+    workflows || CI_FILES.iter().any(|file| workdir.join(file).is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::Duration;
+    use std::fs;
+
+    fn commit(repo: &git2::Repository, file: &str, when: DateTime<Utc>) -> git2::Oid {
+        let sig =
+            git2::Signature::new("Ada", "ada@example.com", &git2::Time::new(when.timestamp(), 0))
+                .unwrap();
+        fs::write(repo.workdir().unwrap().join(file), file).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(file)).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let parents: Vec<git2::Commit<'_>> =
+            repo.head().ok().and_then(|h| h.peel_to_commit().ok()).into_iter().collect();
+        let parents: Vec<&git2::Commit<'_>> = parents.iter().collect();
+        repo.commit(Some("HEAD"), &sig, &sig, file, &tree, &parents).unwrap()
+    }
+
+    #[test]
+    fn neglected_repo_scores_low_until_fixed() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        let now = Utc::now();
+        let old = commit(&repo, "README.md", now - Duration::days(200));
+
+        // A stale branch, and HEAD's branch two commits ahead of its upstream
+        repo.branch("old-idea", &repo.find_commit(old).unwrap(), false).unwrap();
+        repo.remote("origin", "https://github.com/me/app.git").unwrap();
+        let head_branch = repo.head().unwrap().shorthand().unwrap().to_string();
+        repo.reference(&format!("refs/remotes/origin/{}", head_branch), old, true, "test").unwrap();
+        commit(&repo, "a.txt", now);
+        commit(&repo, "b.txt", now);
+        let mut branch = repo.find_branch(&head_branch, BranchType::Local).unwrap();
+        branch.set_upstream(Some(&format!("origin/{}", head_branch))).unwrap();
+
+        fs::write(dir.path().join("dump.bin"), vec![0u8; 2048]).unwrap();
+        fs::write(dir.path().join("notes.txt"), "small").unwrap();
+
+        let options = HealthOptions { stale_days: 90, large_file_bytes: 1024 };
+        let health = check_repo_health(dir.path(), &options, now).unwrap();
+        assert!(!health.has_license && !health.has_ci);
+        assert_eq!(health.stale_branches.len(), 1);
+        assert_eq!(health.stale_branches[0].name, "old-idea");
+        assert!(health.stale_branches[0].days >= 199);
+        assert_eq!(health.unpushed, vec![UnpushedBranch { name: head_branch, ahead: 2 }]);
+        assert_eq!(
+            health.large_untracked,
+            vec![LargeFile { path: "dump.bin".into(), bytes: 2048 }]
+        );
+        assert_eq!(health.issue_count(), 5);
+        assert_eq!(health.score, 100 - 15 - 15 - 5 - 10 - 10);
+
+        fs::write(dir.path().join("LICENSE-MIT"), "MIT").unwrap();
+        fs::create_dir_all(dir.path().join(".github/workflows")).unwrap();
+        fs::write(dir.path().join(".github/workflows/ci.yml"), "on: push").unwrap();
+        fs::remove_file(dir.path().join("dump.bin")).unwrap();
+        repo.find_branch("old-idea", BranchType::Local).unwrap().delete().unwrap();
+
+        let health = check_repo_health(dir.path(), &options, now).unwrap();
+        assert!(health.has_license && health.has_ci);
+        assert!(health.stale_branches.is_empty() && health.large_untracked.is_empty());
+        assert_eq!(health.score, 90);
+    }
+}
@@ -1,9 +1,22 @@
 pub mod git;
 pub mod github;
+pub mod health;
 pub mod repo;
+pub mod repo_state;
+pub mod repo_watch;
 pub mod repo_url;
+pub mod task_runner;
 
-pub use git::{GitOperations, LocalRepo};
+pub use git::{
+    CloneOptions, CommitInfo, CommitStats, DiffHunk, DiffLine, DiffLineKind, FileDiff, ForkSync,
+    GitOperations, LocalRepo, RemoteInfo, TransferCancelled, TransferProgress, UPSTREAM_REMOTE,
+};
 pub use github::{GitHubClient, Issue, Repository};
+pub use health::{
+    check_repo_health, HealthOptions, LargeFile, RepoHealth, StaleBranch, UnpushedBranch,
+};
 pub use repo::{match_repos, RepoEntry, RepoId, RepoState};
+pub use repo_state::RepoStateStore;
+pub use repo_watch::{RepoChanges, RepoWatcher};
 pub use repo_url::normalize_github_url;
+pub use task_runner::{RepoTask, TaskOutputLine, TaskRunner};
@@ -17,6 +17,24 @@ pub enum RepoState {
     LocalOnly = 0,
     GitHubOnly = 1,
     Both = 2,
+    /// A fork on GitHub that isn't cloned locally
+    ForkGitHubOnly = 3,
+    /// A fork on GitHub with a local clone
+    ForkBoth = 4,
+}
+
+impl RepoState {
+    pub fn has_local(self) -> bool {
+        matches!(self, RepoState::LocalOnly | RepoState::Both | RepoState::ForkBoth)
+    }
+
+    pub fn has_github(self) -> bool {
+        !matches!(self, RepoState::LocalOnly)
+    }
+
+    pub fn is_fork(self) -> bool {
+        matches!(self, RepoState::ForkGitHubOnly | RepoState::ForkBoth)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +45,9 @@ pub struct RepoEntry {
     pub github: Option<GitHubRepo>,
     pub state: RepoState,
     pub busy: bool,
+    /// `owner/repo` the local clone's remote still points at when the GitHub repo
+    /// has since been renamed or transferred (matched through `myme.repo-id`)
+    pub renamed_from: Option<String>,
 }
 
 /// Build owner/repo from LocalRepo's remote_url if it's a GitHub URL.
@@ -43,42 +64,78 @@ fn github_owner_repo(gh: &GitHubRepo) -> String {
 }
 
 /// Pure function: match local and remote repos into a unified `Vec<RepoEntry>`.
+///
+/// A local clone is paired with its GitHub repo by the id recorded in its git
+/// config when there is one (so renames and transfers still match), otherwise by
+/// the origin URL. GitHub names are case-insensitive, so URLs are compared
+/// case-insensitively. Local clones come first, in input order, followed by the
+/// GitHub repos nothing matched.
 pub fn match_repos(local: &[LocalRepo], remote: &[GitHubRepo]) -> Vec<RepoEntry> {
-    let mut by_owner_repo: HashMap<String, (Option<LocalRepo>, Option<GitHubRepo>)> =
-        HashMap::new();
+    let mut by_id: HashMap<i64, usize> = HashMap::new();
+    let mut by_owner_repo: HashMap<String, usize> = HashMap::new();
+    for (i, gh) in remote.iter().enumerate() {
+        by_id.entry(gh.id).or_insert(i);
+        by_owner_repo.entry(github_owner_repo(gh).to_lowercase()).or_insert(i);
+    }
+
+    let mut claimed = vec![false; remote.len()];
+    let mut out = Vec::new();
 
     for loc in local {
-        let key = local_owner_repo(loc).unwrap_or_else(|| {
-            // Local-only: use path as unique id
-            loc.path.to_string_lossy().into_owned()
-        });
-        let entry = by_owner_repo.entry(key.clone()).or_default();
-        if entry.0.is_none() {
-            entry.0 = Some(loc.clone());
-        }
-    }
+        let url_key = local_owner_repo(loc);
+        let matched = loc
+            .github_id
+            .and_then(|id| by_id.get(&id))
+            .or_else(|| url_key.as_ref().and_then(|k| by_owner_repo.get(&k.to_lowercase())))
+            .copied()
+            // A second clone of the same repo is listed on its own
+            .filter(|&i| !claimed[i]);
 
-    for gh in remote {
-        let key = github_owner_repo(gh);
-        let entry = by_owner_repo.entry(key.clone()).or_default();
-        if entry.1.is_none() {
-            entry.1 = Some(gh.clone());
+        match matched {
+            Some(i) => {
+                claimed[i] = true;
+                let gh = &remote[i];
+                let key = github_owner_repo(gh);
+                let renamed_from = url_key
+                    .filter(|old| !old.eq_ignore_ascii_case(&key) && loc.github_id.is_some());
+                let state = if gh.fork { RepoState::ForkBoth } else { RepoState::Both };
+                out.push(RepoEntry {
+                    id: RepoId(key),
+                    full_name: gh.full_name.clone(),
+                    local: Some(loc.clone()),
+                    github: Some(gh.clone()),
+                    state,
+                    busy: false,
+                    renamed_from,
+                });
+            }
+            None => out.push(RepoEntry {
+                // Local-only: use path as unique id
+                id: RepoId(loc.path.to_string_lossy().into_owned()),
+                full_name: loc.name.clone(),
+                local: Some(loc.clone()),
+                github: None,
+                state: RepoState::LocalOnly,
+                busy: false,
+                renamed_from: None,
+            }),
         }
     }
 
-    let mut out = Vec::new();
-    for (id_str, (loc, gh)) in by_owner_repo {
-        let (state, full_name) = match (&loc, &gh) {
-            (Some(_l), Some(g)) => (RepoState::Both, g.full_name.clone()),
-            (Some(l), None) => (
-                RepoState::LocalOnly,
-                l.name.clone(), // local-only: use dir name
-            ),
-            (None, Some(g)) => (RepoState::GitHubOnly, g.full_name.clone()),
-            (None, None) => continue,
-        };
-        let id = RepoId(id_str);
-        out.push(RepoEntry { id, full_name, local: loc, github: gh, state, busy: false });
+    for (gh, claimed) in remote.iter().zip(claimed) {
+        if claimed {
+            continue;
+        }
+        let state = if gh.fork { RepoState::ForkGitHubOnly } else { RepoState::GitHubOnly };
+        out.push(RepoEntry {
+            id: RepoId(github_owner_repo(gh)),
+            full_name: gh.full_name.clone(),
+            local: None,
+            github: Some(gh.clone()),
+            state,
+            busy: false,
+            renamed_from: None,
+        });
     }
     out
 }
@@ -99,6 +156,7 @@ mod tests {
             uncommitted_changes: 0,
             last_commit: None,
             last_commit_time: None,
+            github_id: None,
         }
     }
 
@@ -115,6 +173,11 @@ mod tests {
             default_branch: "main".to_string(),
             open_issues_count: 0,
             updated_at: String::new(),
+            pushed_at: None,
+            fork: false,
+            stargazers_count: 0,
+            forks_count: 0,
+            parent: None,
         }
     }
 
@@ -160,4 +223,59 @@ mod tests {
         let out = match_repos(&[], &[]);
         assert!(out.is_empty());
     }
+
+    #[test]
+    fn test_url_match_is_case_insensitive() {
+        let local_repos =
+            vec![local(Some("git@github.com:Owner/Repo.git"), "repo", "/home/user/dev/repo")];
+        let remote = vec![github("owner/repo", None)];
+        let out = match_repos(&local_repos, &remote);
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].state, RepoState::Both);
+        assert_eq!(out[0].renamed_from, None);
+    }
+
+    #[test]
+    fn test_rename_detected_through_recorded_id() {
+        let mut clone =
+            local(Some("https://github.com/owner/old-name.git"), "old-name", "/dev/old-name");
+        clone.github_id = Some(1);
+        let remote = vec![github("owner/new-name", Some("https://github.com/owner/new-name.git"))];
+        let out = match_repos(&[clone], &remote);
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].state, RepoState::Both);
+        assert_eq!(out[0].id, RepoId("owner/new-name".into()));
+        assert_eq!(out[0].renamed_from.as_deref(), Some("owner/old-name"));
+    }
+
+    #[test]
+    fn test_forks_marked() {
+        let mut cloned_fork = github("me/tool", Some("https://github.com/me/tool.git"));
+        cloned_fork.fork = true;
+        let mut remote_fork = github("me/lib", None);
+        remote_fork.id = 2;
+        remote_fork.fork = true;
+        let local_repos = vec![local(Some("https://github.com/me/tool"), "tool", "/dev/tool")];
+
+        let out = match_repos(&local_repos, &[cloned_fork, remote_fork]);
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[0].state, RepoState::ForkBoth);
+        assert_eq!(out[1].state, RepoState::ForkGitHubOnly);
+        assert!(out.iter().all(|e| e.state.is_fork() && e.state.has_github()));
+        assert!(out[0].state.has_local() && !out[1].state.has_local());
+    }
+
+    #[test]
+    fn test_second_clone_listed_separately() {
+        let local_repos = vec![
+            local(Some("https://github.com/owner/repo"), "repo", "/dev/repo"),
+            local(Some("https://github.com/owner/repo"), "repo-copy", "/dev/repo-copy"),
+        ];
+        let remote = vec![github("owner/repo", None)];
+        let out = match_repos(&local_repos, &remote);
+        assert_eq!(out.len(), 2);
+        assert_eq!(out[0].state, RepoState::Both);
+        assert_eq!(out[1].state, RepoState::LocalOnly);
+        assert_eq!(out[1].full_name, "repo-copy");
+    }
 }
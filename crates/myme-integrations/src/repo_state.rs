@@ -0,0 +1,408 @@
+//! Last-known state of local clones, so the repos pane can paint before
+//! discovery has walked the filesystem.
+//!
+//! Rows are keyed by clone path and hold the `LocalRepo` details plus what
+//! the clone was matched to on GitHub. A path that discovery no longer finds
+//! (or that is gone from disk) is tombstoned rather than deleted, so a clone
+//! on an unmounted drive comes back with its history when it reappears.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use myme_services::{db, GitHubRepo};
+use parking_lot::Mutex;
+use rusqlite::{params, Connection};
+
+use crate::git::LocalRepo;
+use crate::repo::{RepoEntry, RepoId, RepoState};
+
+/// How long tombstoned clones are remembered
+pub const TOMBSTONE_DAYS: i64 = 30;
+
+/// SQLite-backed cache of discovered clones.
+pub struct RepoStateStore {
+    conn: Mutex<Connection>,
+}
+
+impl RepoStateStore {
+    /// Open (creating if needed) the store at `path`.
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::init(db::open_maintained(path)?)
+    }
+
+    /// In-memory store (for testing).
+    #[cfg(test)]
+    pub fn in_memory() -> Result<Self> {
+        Self::init(db::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        db::migrate(&conn, 1, |_| {
+            conn.execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS repo_state (
+                    path TEXT PRIMARY KEY,
+                    name TEXT NOT NULL,
+                    current_branch TEXT,
+                    is_clean INTEGER NOT NULL,
+                    remote_url TEXT,
+                    uncommitted_changes INTEGER NOT NULL DEFAULT 0,
+                    last_commit TEXT,
+                    last_commit_time TEXT,
+                    github_id INTEGER,
+                    repo_id TEXT NOT NULL,
+                    full_name TEXT NOT NULL,
+                    state INTEGER NOT NULL,
+                    github_json TEXT,
+                    renamed_from TEXT,
+                    seen_ms INTEGER NOT NULL,
+                    deleted_ms INTEGER
+                );
+                "#,
+            )?;
+            Ok(())
+        })?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    /// Entries for every live clone, by path. Clones whose directory is gone
+    /// are tombstoned on the way.
+    pub fn load(&self, now: DateTime<Utc>) -> Result<Vec<RepoEntry>> {
+        let entries = {
+            let conn = self.conn.lock();
+            let mut stmt = conn.prepare(
+                "SELECT path, name, current_branch, is_clean, remote_url, uncommitted_changes,
+                        last_commit, last_commit_time, github_id, repo_id, full_name, state,
+                        github_json, renamed_from
+                 FROM repo_state WHERE deleted_ms IS NULL ORDER BY path",
+            )?;
+            let rows = stmt.query_map([], row_to_entry)?;
+            rows.collect::<Result<Vec<_>, _>>()?
+        };
+        let (live, missing): (Vec<_>, Vec<_>) =
+            entries.into_iter().partition(|e| e.local.as_ref().is_some_and(|l| l.path.exists()));
+        let missing: Vec<PathBuf> =
+            missing.into_iter().filter_map(|e| e.local.map(|l| l.path)).collect();
+        self.tombstone(&missing, now)?;
+        Ok(live)
+    }
+
+    /// Record the result of a discovery: every entry with a local clone is
+    /// stored (reviving it if it was tombstoned), and live rows discovery
+    /// didn't report are tombstoned. Returns the tombstoned paths.
+    pub fn save(&self, entries: &[RepoEntry], now: DateTime<Utc>) -> Result<Vec<PathBuf>> {
+        let seen: Vec<(&LocalRepo, &RepoEntry)> =
+            entries.iter().filter_map(|e| e.local.as_ref().map(|l| (l, e))).collect();
+        let stale: Vec<PathBuf> = self
+            .live_paths()?
+            .into_iter()
+            .filter(|p| !seen.iter().any(|(l, _)| &l.path == p))
+            .collect();
+
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        for (local, entry) in seen {
+            let github_json = entry.github.as_ref().map(serde_json::to_string).transpose()?;
+            tx.execute(
+                "INSERT OR REPLACE INTO repo_state (path, name, current_branch, is_clean,
+                     remote_url, uncommitted_changes, last_commit, last_commit_time, github_id,
+                     repo_id, full_name, state, github_json, renamed_from, seen_ms, deleted_ms)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, NULL)",
+                params![
+                    path_key(&local.path),
+                    local.name,
+                    local.current_branch,
+                    local.is_clean,
+                    local.remote_url,
+                    local.uncommitted_changes as i64,
+                    local.last_commit,
+                    local.last_commit_time,
+                    local.github_id,
+                    entry.id.0,
+                    entry.full_name,
+                    entry.state as i32,
+                    github_json,
+                    entry.renamed_from,
+                    now.timestamp_millis(),
+                ],
+            )?;
+        }
+        for path in &stale {
+            tx.execute(
+                "UPDATE repo_state SET deleted_ms = ?2 WHERE path = ?1",
+                params![path_key(path), now.timestamp_millis()],
+            )?;
+        }
+        tx.commit()?;
+        Ok(stale)
+    }
+
+    /// Refresh the local half of a cached clone after it changed on disk.
+    /// Returns false when the clone isn't cached (or is tombstoned).
+    pub fn update_local(&self, local: &LocalRepo, now: DateTime<Utc>) -> Result<bool> {
+        let conn = self.conn.lock();
+        let updated = conn.execute(
+            "UPDATE repo_state SET name = ?2, current_branch = ?3, is_clean = ?4,
+                 remote_url = ?5, uncommitted_changes = ?6, last_commit = ?7,
+                 last_commit_time = ?8, github_id = ?9, seen_ms = ?10
+             WHERE path = ?1 AND deleted_ms IS NULL",
+            params![
+                path_key(&local.path),
+                local.name,
+                local.current_branch,
+                local.is_clean,
+                local.remote_url,
+                local.uncommitted_changes as i64,
+                local.last_commit,
+                local.last_commit_time,
+                local.github_id,
+                now.timestamp_millis(),
+            ],
+        )?;
+        Ok(updated > 0)
+    }
+
+    /// Mark clones as gone without forgetting what they were.
+    pub fn tombstone(&self, paths: &[PathBuf], now: DateTime<Utc>) -> Result<()> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        for path in paths {
+            tx.execute(
+                "UPDATE repo_state SET deleted_ms = ?2 WHERE path = ?1 AND deleted_ms IS NULL",
+                params![path_key(path), now.timestamp_millis()],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Forget clones tombstoned before `cutoff`. Returns how many were removed.
+    pub fn prune_tombstones(&self, cutoff: DateTime<Utc>) -> Result<usize> {
+        let conn = self.conn.lock();
+        Ok(conn.execute(
+            "DELETE FROM repo_state WHERE deleted_ms IS NOT NULL AND deleted_ms < ?1",
+            params![cutoff.timestamp_millis()],
+        )?)
+    }
+
+    fn live_paths(&self) -> Result<Vec<PathBuf>> {
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare("SELECT path FROM repo_state WHERE deleted_ms IS NULL")?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        Ok(rows.map(|p| p.map(PathBuf::from)).collect::<Result<Vec<_>, _>>()?)
+    }
+}
+
+fn path_key(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+fn state_from_i32(value: i32) -> RepoState {
+    match value {
+        1 => RepoState::GitHubOnly,
+        2 => RepoState::Both,
+        3 => RepoState::ForkGitHubOnly,
+        4 => RepoState::ForkBoth,
+        _ => RepoState::LocalOnly,
+    }
+}
+
+fn row_to_entry(row: &rusqlite::Row) -> rusqlite::Result<RepoEntry> {
+    let path: String = row.get(0)?;
+    let uncommitted: i64 = row.get(5)?;
+    let github_json: Option<String> = row.get(12)?;
+    // A GitHub repo that no longer parses is re-matched by the next discovery
+    let github: Option<GitHubRepo> = github_json.and_then(|j| serde_json::from_str(&j).ok());
+    let state = state_from_i32(row.get(11)?);
+    let state = if github.is_none() && state.has_github() { RepoState::LocalOnly } else { state };
+    Ok(RepoEntry {
+        local: Some(LocalRepo {
+            path: PathBuf::from(path),
+            name: row.get(1)?,
+            current_branch: row.get(2)?,
+            is_clean: row.get(3)?,
+            remote_url: row.get(4)?,
+            uncommitted_changes: usize::try_from(uncommitted).unwrap_or_default(),
+            last_commit: row.get(6)?,
+            last_commit_time: row.get(7)?,
+            github_id: row.get(8)?,
+        }),
+        id: RepoId(row.get(9)?),
+        full_name: row.get(10)?,
+        github,
+        state,
+        busy: false,
+        renamed_from: row.get(13)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use tempfile::TempDir;
+
+    fn local(path: &Path) -> LocalRepo {
+        LocalRepo {
+            path: path.to_path_buf(),
+            name: path.file_name().unwrap().to_string_lossy().into_owned(),
+            current_branch: Some("main".into()),
+            is_clean: false,
+            remote_url: Some("https://github.com/me/app.git".into()),
+            uncommitted_changes: 3,
+            last_commit: Some("Fix it".into()),
+            last_commit_time: Some("2026-10-01T12:00:00Z".into()),
+            github_id: Some(42),
+        }
+    }
+
+    fn github() -> GitHubRepo {
+        serde_json::from_value(serde_json::json!({
+            "id": 42,
+            "name": "app",
+            "full_name": "me/app",
+            "description": null,
+            "html_url": "https://github.com/me/app",
+            "private": false,
+            "default_branch": "main",
+            "updated_at": "2026-10-01T12:00:00Z",
+        }))
+        .unwrap()
+    }
+
+    fn matched(path: &Path) -> RepoEntry {
+        RepoEntry {
+            id: RepoId("me/app".into()),
+            full_name: "me/app".into(),
+            local: Some(local(path)),
+            github: Some(github()),
+            state: RepoState::Both,
+            busy: false,
+            renamed_from: None,
+        }
+    }
+
+    fn local_only(path: &Path) -> RepoEntry {
+        RepoEntry {
+            id: RepoId(path.to_string_lossy().into_owned()),
+            full_name: "scratch".into(),
+            local: Some(local(path)),
+            github: None,
+            state: RepoState::LocalOnly,
+            busy: false,
+            renamed_from: None,
+        }
+    }
+
+    fn dirs(names: &[&str]) -> (TempDir, Vec<PathBuf>) {
+        let root = TempDir::new().unwrap();
+        let paths = names
+            .iter()
+            .map(|n| {
+                let p = root.path().join(n);
+                std::fs::create_dir(&p).unwrap();
+                p
+            })
+            .collect();
+        (root, paths)
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let store = RepoStateStore::in_memory().unwrap();
+        let (_root, paths) = dirs(&["app", "scratch"]);
+        let now = Utc::now();
+        let stale = store.save(&[matched(&paths[0]), local_only(&paths[1])], now).unwrap();
+        assert!(stale.is_empty());
+
+        let loaded = store.load(now).unwrap();
+        assert_eq!(loaded.len(), 2);
+        let app = loaded.iter().find(|e| e.full_name == "me/app").unwrap();
+        assert_eq!(app.state, RepoState::Both);
+        assert_eq!(app.github.as_ref().map(|g| g.id), Some(42));
+        let l = app.local.as_ref().unwrap();
+        assert_eq!(l.current_branch.as_deref(), Some("main"));
+        assert_eq!(l.uncommitted_changes, 3);
+        assert!(!l.is_clean);
+        assert_eq!(l.github_id, Some(42));
+        let scratch = loaded.iter().find(|e| e.full_name == "scratch").unwrap();
+        assert_eq!(scratch.state, RepoState::LocalOnly);
+        assert!(scratch.github.is_none());
+    }
+
+    #[test]
+    fn github_only_entries_are_not_cached() {
+        let store = RepoStateStore::in_memory().unwrap();
+        let entry =
+            RepoEntry { local: None, state: RepoState::GitHubOnly, ..matched(Path::new("/x")) };
+        store.save(&[entry], Utc::now()).unwrap();
+        assert!(store.load(Utc::now()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn undiscovered_paths_are_tombstoned_and_revived() {
+        let store = RepoStateStore::in_memory().unwrap();
+        let (_root, paths) = dirs(&["app", "scratch"]);
+        let now = Utc::now();
+        store.save(&[matched(&paths[0]), local_only(&paths[1])], now).unwrap();
+
+        let stale = store.save(&[matched(&paths[0])], now).unwrap();
+        assert_eq!(stale, vec![paths[1].clone()]);
+        assert_eq!(store.load(now).unwrap().len(), 1);
+
+        // Found again (say, the drive was remounted)
+        store.save(&[matched(&paths[0]), local_only(&paths[1])], now).unwrap();
+        assert_eq!(store.load(now).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn load_tombstones_deleted_directories() {
+        let store = RepoStateStore::in_memory().unwrap();
+        let (_root, paths) = dirs(&["app", "scratch"]);
+        let now = Utc::now();
+        store.save(&[matched(&paths[0]), local_only(&paths[1])], now).unwrap();
+
+        std::fs::remove_dir(&paths[1]).unwrap();
+        assert_eq!(store.load(now).unwrap().len(), 1);
+        // Tombstoned, so it stays hidden even if a directory shows up there again
+        std::fs::create_dir(&paths[1]).unwrap();
+        assert_eq!(store.load(now).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn update_local_keeps_the_github_match() {
+        let store = RepoStateStore::in_memory().unwrap();
+        let (_root, paths) = dirs(&["app"]);
+        let now = Utc::now();
+        store.save(&[matched(&paths[0])], now).unwrap();
+
+        let switched = LocalRepo {
+            current_branch: Some("feature".into()),
+            is_clean: true,
+            uncommitted_changes: 0,
+            ..local(&paths[0])
+        };
+        assert!(store.update_local(&switched, now).unwrap());
+        let loaded = store.load(now).unwrap();
+        assert_eq!(loaded[0].state, RepoState::Both);
+        assert_eq!(loaded[0].local.as_ref(), Some(&switched));
+
+        store.tombstone(&paths, now).unwrap();
+        assert!(!store.update_local(&switched, now).unwrap());
+    }
+
+    #[test]
+    fn prune_forgets_old_tombstones() {
+        let store = RepoStateStore::in_memory().unwrap();
+        let (_root, paths) = dirs(&["app"]);
+        let then = Utc::now() - chrono::Duration::days(TOMBSTONE_DAYS + 1);
+        store.save(&[matched(&paths[0])], then).unwrap();
+        store.tombstone(&paths, then).unwrap();
+        assert_eq!(store.prune_tombstones(Utc::now()).unwrap(), 1);
+        assert_eq!(store.prune_tombstones(Utc::now()).unwrap(), 0);
+    }
+}
@@ -0,0 +1,263 @@
+//! Filesystem watching for local clones.
+//!
+//! One `notify` watcher covers every clone. Events are sorted into commits,
+//! branch switches and working-tree edits, then held until the clone has been
+//! quiet for the debounce window, so a rebase or a build writing hundreds of
+//! files produces one update instead of hundreds.
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+/// A burst of activity is reported at the latest after this many debounce windows
+const MAX_DELAY_WINDOWS: u32 = 10;
+
+/// What kind of change a filesystem event stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepoChange {
+    /// A local branch moved (commit, reset, merge)
+    Commit,
+    /// HEAD points somewhere else (checkout, switch)
+    BranchSwitch,
+    /// Files in the working tree or the index changed
+    WorkingTree,
+}
+
+/// Changes seen in one clone since it was last reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RepoChanges {
+    pub commit: bool,
+    pub branch_switch: bool,
+    pub working_tree: bool,
+}
+
+impl RepoChanges {
+    fn add(&mut self, change: RepoChange) {
+        match change {
+            RepoChange::Commit => self.commit = true,
+            RepoChange::BranchSwitch => self.branch_switch = true,
+            RepoChange::WorkingTree => self.working_tree = true,
+        }
+    }
+}
+
+/// What a change to `path` inside the clone at `root` means, or `None` for
+/// git's own bookkeeping (objects, lock files, remote refs, logs).
+pub fn classify(root: &Path, path: &Path) -> Option<RepoChange> {
+    let relative = path.strip_prefix(root).ok()?;
+    let mut parts = relative.components().filter_map(|c| match c {
+        Component::Normal(part) => part.to_str(),
+        _ => None,
+    });
+    let first = parts.next()?;
+    if first != ".git" {
+        return Some(RepoChange::WorkingTree);
+    }
+    // Lock files are renamed over the real file, which reports it again
+    if relative.extension().is_some_and(|ext| ext == "lock") {
+        return None;
+    }
+    match (parts.next(), parts.next()) {
+        (Some("HEAD"), None) => Some(RepoChange::BranchSwitch),
+        (Some("index"), None) => Some(RepoChange::WorkingTree),
+        (Some("packed-refs"), None) | (Some("refs"), Some("heads")) => Some(RepoChange::Commit),
+        _ => None,
+    }
+}
+
+/// Holds changes per clone until the clone goes quiet.
+#[derive(Debug)]
+pub struct Debouncer {
+    window: Duration,
+    pending: HashMap<PathBuf, Pending>,
+}
+
+#[derive(Debug)]
+struct Pending {
+    changes: RepoChanges,
+    first: Instant,
+    last: Instant,
+}
+
+impl Debouncer {
+    pub fn new(window: Duration) -> Self {
+        Self { window, pending: HashMap::new() }
+    }
+
+    pub fn record(&mut self, repo: &Path, change: RepoChange, now: Instant) {
+        let pending = self.pending.entry(repo.to_path_buf()).or_insert(Pending {
+            changes: RepoChanges::default(),
+            first: now,
+            last: now,
+        });
+        pending.changes.add(change);
+        pending.last = now;
+    }
+
+    /// Clones quiet for a full window, or busy for too long, with their changes
+    pub fn take_due(&mut self, now: Instant) -> Vec<(PathBuf, RepoChanges)> {
+        let due: Vec<PathBuf> = self
+            .pending
+            .iter()
+            .filter(|(_, p)| now >= self.deadline(p))
+            .map(|(repo, _)| repo.clone())
+            .collect();
+        due.into_iter()
+            .filter_map(|repo| self.pending.remove(&repo).map(|p| (repo, p.changes)))
+            .collect()
+    }
+
+    /// When the next clone comes due, if any are pending
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|p| self.deadline(p)).min()
+    }
+
+    fn deadline(&self, pending: &Pending) -> Instant {
+        (pending.last + self.window).min(pending.first + self.window * MAX_DELAY_WINDOWS)
+    }
+}
+
+/// Watches a set of clones and reports debounced changes. Dropping it stops
+/// the watch.
+pub struct RepoWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl RepoWatcher {
+    /// Watch every clone in `repos`, calling `on_change` from a background
+    /// thread once a clone has been quiet for `debounce`. Clones that can't
+    /// be watched (gone, or out of inotify watches) are logged and skipped.
+    pub fn start<F>(repos: Vec<PathBuf>, debounce: Duration, on_change: F) -> Result<Self>
+    where
+        F: Fn(&Path, RepoChanges) + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel::<notify::Result<Event>>();
+        let mut watcher = notify::recommended_watcher(tx)?;
+        for repo in &repos {
+            if let Err(e) = watcher.watch(repo, RecursiveMode::Recursive) {
+                tracing::warn!("Not watching {}: {}", repo.display(), e);
+            }
+        }
+
+        std::thread::Builder::new().name("repo-watch".into()).spawn(move || {
+            let mut debouncer = Debouncer::new(debounce);
+            loop {
+                let received = match debouncer.next_deadline() {
+                    Some(deadline) => {
+                        rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    }
+                    None => rx.recv().map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+                };
+                match received {
+                    Ok(Ok(event)) if !matches!(event.kind, EventKind::Access(_)) => {
+                        let now = Instant::now();
+                        for path in &event.paths {
+                            // Nested clones belong to the innermost one
+                            let repo = repos
+                                .iter()
+                                .filter(|r| path.starts_with(r))
+                                .max_by_key(|r| r.components().count());
+                            if let Some(repo) = repo {
+                                if let Some(change) = classify(repo, path) {
+                                    debouncer.record(repo, change, now);
+                                }
+                            }
+                        }
+                    }
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => tracing::debug!("Repo watch error: {}", e),
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    // The watcher was dropped
+                    Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                }
+                for (repo, changes) in debouncer.take_due(Instant::now()) {
+                    on_change(&repo, changes);
+                }
+            }
+        })?;
+
+        Ok(Self { _watcher: watcher })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn classify_sorts_git_paths() {
+        let root = Path::new("/dev/app");
+        let at = |p: &str| classify(root, &root.join(p));
+        assert_eq!(at("src/main.rs"), Some(RepoChange::WorkingTree));
+        assert_eq!(at(".gitignore"), Some(RepoChange::WorkingTree));
+        assert_eq!(at(".git/index"), Some(RepoChange::WorkingTree));
+        assert_eq!(at(".git/HEAD"), Some(RepoChange::BranchSwitch));
+        assert_eq!(at(".git/refs/heads/main"), Some(RepoChange::Commit));
+        assert_eq!(at(".git/refs/heads/feature/x"), Some(RepoChange::Commit));
+        assert_eq!(at(".git/packed-refs"), Some(RepoChange::Commit));
+        assert_eq!(at(".git/refs/heads/main.lock"), None);
+        assert_eq!(at(".git/index.lock"), None);
+        assert_eq!(at(".git/objects/ab/cdef"), None);
+        assert_eq!(at(".git/refs/remotes/origin/main"), None);
+        assert_eq!(at(".git/logs/HEAD"), None);
+        assert_eq!(classify(root, Path::new("/dev/other/file")), None);
+    }
+
+    #[test]
+    fn debouncer_waits_for_quiet() {
+        let window = Duration::from_millis(500);
+        let mut debouncer = Debouncer::new(window);
+        let repo = Path::new("/dev/app");
+        let start = Instant::now();
+        debouncer.record(repo, RepoChange::WorkingTree, start);
+        debouncer.record(repo, RepoChange::Commit, start + Duration::from_millis(300));
+
+        assert!(debouncer.take_due(start + Duration::from_millis(600)).is_empty());
+        assert_eq!(debouncer.next_deadline(), Some(start + Duration::from_millis(800)));
+
+        let due = debouncer.take_due(start + Duration::from_millis(800));
+        let changes = RepoChanges { commit: true, working_tree: true, branch_switch: false };
+        assert_eq!(due, vec![(repo.to_path_buf(), changes)]);
+        assert_eq!(debouncer.next_deadline(), None);
+    }
+
+    #[test]
+    fn debouncer_reports_long_bursts() {
+        let window = Duration::from_millis(100);
+        let mut debouncer = Debouncer::new(window);
+        let repo = Path::new("/dev/app");
+        let start = Instant::now();
+        // A build touching files every 50ms never goes quiet
+        let mut reported = 0;
+        for tick in 0..40u32 {
+            let now = start + Duration::from_millis(50) * tick;
+            debouncer.record(repo, RepoChange::WorkingTree, now);
+            reported += debouncer.take_due(now).len();
+        }
+        assert_eq!(reported, 1);
+    }
+
+    #[test]
+    fn debouncer_keeps_clones_apart() {
+        let window = Duration::from_millis(100);
+        let mut debouncer = Debouncer::new(window);
+        let start = Instant::now();
+        debouncer.record(Path::new("/dev/a"), RepoChange::BranchSwitch, start);
+        debouncer.record(
+            Path::new("/dev/b"),
+            RepoChange::WorkingTree,
+            start + Duration::from_millis(80),
+        );
+
+        let due = debouncer.take_due(start + Duration::from_millis(120));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].0, PathBuf::from("/dev/a"));
+        assert!(due[0].1.branch_switch);
+        assert_eq!(debouncer.take_due(start + Duration::from_millis(180)).len(), 1);
+    }
+}
@@ -0,0 +1,468 @@
+//! Local checks for a repository: detect the usual test commands (`cargo test`,
+//! `npm test`, Taskfile targets) and run them as a subprocess with a cleared
+//! environment (only `ENV_ALLOWLIST` is passed through), streaming output line
+//! by line. Tasks are not sandboxed otherwise: they run as the user, with full
+//! access to the filesystem and network.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use myme_services::{RepoTaskRun, RepoTaskStatus};
+use std::collections::VecDeque;
+use std::path::Path;
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::Command;
+use tokio_util::sync::CancellationToken;
+
+/// Output lines kept with a finished run
+const OUTPUT_TAIL_LINES: usize = 200;
+
+/// Environment variables passed through to tasks; everything else is cleared
+/// so tokens in the app's environment don't leak into build scripts.
+const ENV_ALLOWLIST: &[&str] = &[
+    "PATH",
+    "HOME",
+    "USER",
+    "LANG",
+    "LC_ALL",
+    "TERM",
+    "TMPDIR",
+    "CARGO_HOME",
+    "RUSTUP_HOME",
+    "RUSTUP_TOOLCHAIN",
+    "NVM_DIR",
+    "NODE_PATH",
+    "GOPATH",
+    "GOROOT",
+    "SystemRoot",
+    "SystemDrive",
+    "windir",
+    "ComSpec",
+    "PATHEXT",
+    "TEMP",
+    "TMP",
+    "USERPROFILE",
+    "APPDATA",
+    "LOCALAPPDATA",
+    "ProgramData",
+];
+
+/// Windows launchers tried when a program is named without an extension.
+/// `npm`, `yarn` and friends are `.cmd` shims, which `CreateProcess` won't
+/// find by bare name.
+const WINDOWS_EXTENSIONS: &[&str] = &[".exe", ".cmd", ".bat", ".com"];
+
+/// A command that can be run in a repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoTask {
+    /// Display name and history key, e.g. "cargo test"
+    pub name: String,
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl RepoTask {
+    /// Parse a configured command line. Split on whitespace, no shell: quoting,
+    /// pipes and `&&` aren't supported.
+    pub fn parse(command: &str) -> Option<Self> {
+        let mut parts = command.split_whitespace().map(String::from);
+        let program = parts.next()?;
+        let args: Vec<String> = parts.collect();
+        Some(Self { name: command.split_whitespace().collect::<Vec<_>>().join(" "), program, args })
+    }
+
+    fn new(program: &str, args: &[&str]) -> Self {
+        let mut name = program.to_string();
+        for arg in args {
+            name.push(' ');
+            name.push_str(arg);
+        }
+        Self { name, program: program.into(), args: args.iter().map(|a| a.to_string()).collect() }
+    }
+}
+
+/// One line of task output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskOutputLine {
+    pub text: String,
+    pub stderr: bool,
+}
+
+/// Runs repo tasks with a timeout and a cleared environment
+#[derive(Debug, Clone)]
+pub struct TaskRunner {
+    timeout: Duration,
+}
+
+impl TaskRunner {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout }
+    }
+
+    /// Tasks found in the repo's manifests: `cargo test` and `cargo clippy` for
+    /// Cargo projects, `npm test` and `npm run lint` for package.json scripts,
+    /// and one `task <name>` per Taskfile target.
+    pub fn detect(repo: &Path) -> Vec<RepoTask> {
+        let mut tasks = Vec::new();
+        if repo.join("Cargo.toml").is_file() {
+            tasks.push(RepoTask::new("cargo", &["test"]));
+            tasks.push(RepoTask::new("cargo", &["clippy", "--all-targets"]));
+        }
+        if let Ok(text) = std::fs::read_to_string(repo.join("package.json")) {
+            let scripts = serde_json::from_str::<serde_json::Value>(&text)
+                .ok()
+                .and_then(|v| v.get("scripts").cloned());
+            let has = |name: &str| scripts.as_ref().is_some_and(|s| s.get(name).is_some());
+            if has("test") {
+                tasks.push(RepoTask::new("npm", &["test"]));
+            }
+            if has("lint") {
+                tasks.push(RepoTask::new("npm", &["run", "lint"]));
+            }
+        }
+        for file in ["Taskfile.yml", "Taskfile.yaml"] {
+            if let Ok(text) = std::fs::read_to_string(repo.join(file)) {
+                for target in taskfile_targets(&text) {
+                    tasks.push(RepoTask::new("task", &[target.as_str()]));
+                }
+                break;
+            }
+        }
+        tasks
+    }
+
+    /// Run `task` in `repo`, calling `on_line` for each line of output.
+    ///
+    /// The process gets no stdin and only `ENV_ALLOWLIST` from the environment.
+    /// It is killed, along with everything it started, when the timeout passes
+    /// or `cancel` fires. Errors only when the process can't be started.
+    pub async fn run<F>(
+        &self,
+        repo_id: &str,
+        repo: &Path,
+        task: &RepoTask,
+        cancel: CancellationToken,
+        mut on_line: F,
+    ) -> Result<RepoTaskRun>
+    where
+        F: FnMut(TaskOutputLine),
+    {
+        let started_at = Utc::now();
+        let started = Instant::now();
+
+        let mut command = Command::new(resolve_program(&task.program));
+        command
+            .args(&task.args)
+            .current_dir(repo)
+            .env_clear()
+            .envs(ENV_ALLOWLIST.iter().filter_map(|k| std::env::var_os(k).map(|v| (k, v))))
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        // Lead a new process group so a timeout can kill the compilers and
+        // test binaries the task starts, not just the task itself
+        #[cfg(unix)]
+        command.process_group(0);
+        let mut child =
+            command.spawn().with_context(|| format!("Failed to start {}", task.program))?;
+
+        let mut stdout = child.stdout.take().map(|s| BufReader::new(s).lines());
+        let mut stderr = child.stderr.take().map(|s| BufReader::new(s).lines());
+        let mut tail: VecDeque<String> = VecDeque::with_capacity(OUTPUT_TAIL_LINES);
+        let mut push = |text: String, stderr: bool, tail: &mut VecDeque<String>| {
+            if tail.len() == OUTPUT_TAIL_LINES {
+                tail.pop_front();
+            }
+            tail.push_back(text.clone());
+            on_line(TaskOutputLine { text, stderr });
+        };
+
+        let deadline = tokio::time::sleep(self.timeout);
+        tokio::pin!(deadline);
+        let mut stopped = None;
+        // Read until both pipes close, then wait for the exit status
+        while stdout.is_some() || stderr.is_some() {
+            tokio::select! {
+                line = next_line(&mut stdout) => match line {
+                    Some(text) => push(text, false, &mut tail),
+                    None => stdout = None,
+                },
+                line = next_line(&mut stderr) => match line {
+                    Some(text) => push(text, true, &mut tail),
+                    None => stderr = None,
+                },
+                _ = &mut deadline => {
+                    stopped = Some(RepoTaskStatus::TimedOut);
+                    break;
+                }
+                _ = cancel.cancelled() => {
+                    stopped = Some(RepoTaskStatus::Cancelled);
+                    break;
+                }
+            }
+        }
+
+        let (status, exit_code) = match stopped {
+            Some(status) => {
+                kill_tree(&mut child).await;
+                (status, None)
+            }
+            None => {
+                let exit = child.wait().await.context("Failed to wait for task")?;
+                let status =
+                    if exit.success() { RepoTaskStatus::Passed } else { RepoTaskStatus::Failed };
+                (status, exit.code())
+            }
+        };
+
+        Ok(RepoTaskRun {
+            id: uuid::Uuid::new_v4().to_string(),
+            repo_id: repo_id.to_string(),
+            task: task.name.clone(),
+            started_at: started_at.to_rfc3339(),
+            duration_ms: started.elapsed().as_millis() as i64,
+            exit_code,
+            status,
+            output_tail: Vec::from(tail).join("\n"),
+        })
+    }
+}
+
+/// Kill a task and every process it started.
+async fn kill_tree(child: &mut tokio::process::Child) {
+    if let Some(pid) = child.id() {
+        // The task leads its own process group (see `run`)
+        #[cfg(unix)]
+        if let Err(e) = nix::sys::signal::killpg(
+            nix::unistd::Pid::from_raw(pid as i32),
+            nix::sys::signal::Signal::SIGKILL,
+        ) {
+            tracing::debug!("Failed to kill task process group {}: {}", pid, e);
+        }
+        // A job object would need unsafe Win32 calls; taskkill walks the tree
+        #[cfg(windows)]
+        if let Err(e) = Command::new("taskkill")
+            .args(["/T", "/F", "/PID", &pid.to_string()])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+        {
+            tracing::debug!("Failed to run taskkill for {}: {}", pid, e);
+        }
+    }
+    let _ = child.kill().await;
+}
+
+/// The program to start for `program`. On Windows a bare name is looked up
+/// on `PATH` with each of `WINDOWS_EXTENSIONS`, so `.cmd` shims start (std
+/// runs a `.cmd`/`.bat` path through `cmd.exe` with proper escaping).
+fn resolve_program(program: &str) -> std::path::PathBuf {
+    if cfg!(windows) {
+        if let Some(path) = std::env::var_os("PATH") {
+            if let Some(found) = find_on_path(program, &path, WINDOWS_EXTENSIONS) {
+                return found;
+            }
+        }
+    }
+    program.into()
+}
+
+/// First `dir/program<ext>` that is a file, for each directory in `path`.
+/// Names with a directory or an extension are left alone.
+fn find_on_path(
+    program: &str,
+    path: &std::ffi::OsStr,
+    extensions: &[&str],
+) -> Option<std::path::PathBuf> {
+    let name = Path::new(program);
+    if name.components().count() != 1 || name.extension().is_some() {
+        return None;
+    }
+    std::env::split_paths(path).find_map(|dir| {
+        extensions.iter().map(|ext| dir.join(format!("{}{}", program, ext))).find(|p| p.is_file())
+    })
+}
+
+/// Next line from an open pipe; pends forever once the pipe is gone so the
+/// other branch of the select keeps going.
+async fn next_line<R>(lines: &mut Option<tokio::io::Lines<R>>) -> Option<String>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    match lines {
+        Some(lines) => lines.next_line().await.ok().flatten(),
+        None => std::future::pending().await,
+    }
+}
+
+/// Target names under `tasks:` in a Taskfile, skipping internal ones (the
+/// `internal: true` key isn't checked; names starting with `_` are skipped).
+fn taskfile_targets(text: &str) -> Vec<String> {
+    let mut targets = Vec::new();
+    let mut in_tasks = false;
+    let mut indent = None;
+    for line in text.lines() {
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() || trimmed.trim_start().starts_with('#') {
+            continue;
+        }
+        let depth = trimmed.len() - trimmed.trim_start().len();
+        if depth == 0 {
+            in_tasks = trimmed == "tasks:";
+            continue;
+        }
+        if !in_tasks {
+            continue;
+        }
+        // Targets are the first indentation level under `tasks:`
+        let level = *indent.get_or_insert(depth);
+        if depth != level {
+            continue;
+        }
+        if let Some(name) = trimmed.trim_start().strip_suffix(':') {
+            let name = name.trim_matches(|c| c == '"' || c == '\'');
+            if !name.is_empty() && !name.starts_with('_') {
+                targets.push(name.to_string());
+            }
+        }
+    }
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn detects_tasks_from_manifests() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), "[package]\nname = \"x\"\n").unwrap();
+        std::fs::write(
+            dir.path().join("package.json"),
+            r#"{"scripts": {"test": "vitest", "build": "vite build"}}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("Taskfile.yml"),
+            "version: '3'\n\ntasks:\n  lint:\n    cmds:\n      - golangci-lint run\n  \
+             _setup:\n    cmds: [echo]\n  test:\n    deps: [lint]\n",
+        )
+        .unwrap();
+
+        let names: Vec<String> =
+            TaskRunner::detect(dir.path()).into_iter().map(|t| t.name).collect();
+        assert_eq!(
+            names,
+            ["cargo test", "cargo clippy --all-targets", "npm test", "task lint", "task test"]
+        );
+        assert_eq!(
+            RepoTask::parse("  make   lint ").unwrap(),
+            RepoTask {
+                name: "make lint".into(),
+                program: "make".into(),
+                args: vec!["lint".into()]
+            }
+        );
+        assert!(RepoTask::parse("   ").is_none());
+    }
+
+    #[test]
+    fn finds_windows_shims_on_path() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        std::fs::write(second.path().join("npm.cmd"), "").unwrap();
+        std::fs::write(second.path().join("cargo.exe"), "").unwrap();
+        let path = std::env::join_paths([first.path(), second.path()]).unwrap();
+
+        let find = |program: &str| find_on_path(program, &path, WINDOWS_EXTENSIONS);
+        assert_eq!(find("npm"), Some(second.path().join("npm.cmd")));
+        assert_eq!(find("cargo"), Some(second.path().join("cargo.exe")));
+        assert_eq!(find("task"), None);
+        // Explicit extensions and paths are used as given
+        assert_eq!(find("npm.cmd"), None);
+        assert_eq!(find("bin/npm"), None);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn runs_streams_and_times_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let runner = TaskRunner::new(Duration::from_secs(10));
+
+        let mut lines = Vec::new();
+        let task = RepoTask::parse("sh -c echo").unwrap();
+        let run = runner
+            .run("me/app", dir.path(), &task, CancellationToken::new(), |l| lines.push(l))
+            .await
+            .unwrap();
+        assert_eq!(run.status, RepoTaskStatus::Passed);
+        assert_eq!(run.exit_code, Some(0));
+        assert_eq!(lines.len(), 1);
+
+        let task = RepoTask::new("sh", &["-c", "echo oops >&2; exit 3"]);
+        let run = runner
+            .run("me/app", dir.path(), &task, CancellationToken::new(), |_| {})
+            .await
+            .unwrap();
+        assert_eq!((run.status, run.exit_code), (RepoTaskStatus::Failed, Some(3)));
+        assert_eq!(run.output_tail, "oops");
+
+        let quick = TaskRunner::new(Duration::from_millis(100));
+        let run = quick
+            .run(
+                "me/app",
+                dir.path(),
+                &RepoTask::new("sleep", &["5"]),
+                CancellationToken::new(),
+                |_| {},
+            )
+            .await
+            .unwrap();
+        assert_eq!((run.status, run.exit_code), (RepoTaskStatus::TimedOut, None));
+
+        assert!(runner
+            .run(
+                "me/app",
+                dir.path(),
+                &RepoTask::new("no-such-program-xyz", &[]),
+                CancellationToken::new(),
+                |_| {}
+            )
+            .await
+            .is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn timeout_kills_processes_the_task_started() {
+        let dir = tempfile::tempdir().unwrap();
+        let runner = TaskRunner::new(Duration::from_millis(300));
+        let task = RepoTask::new("sh", &["-c", "sleep 30 & echo $!; wait"]);
+        let mut lines = Vec::new();
+        let run = runner
+            .run("me/app", dir.path(), &task, CancellationToken::new(), |l| lines.push(l))
+            .await
+            .unwrap();
+        assert_eq!(run.status, RepoTaskStatus::TimedOut);
+
+        // Gone, or a zombie nobody has reaped yet
+        let stat = format!("/proc/{}/stat", lines[0].text);
+        let dead = || {
+            std::fs::read_to_string(&stat).map_or(true, |s| {
+                s.rsplit(')').next().is_some_and(|r| r.trim_start().starts_with('Z'))
+            })
+        };
+        for _ in 0..50 {
+            if dead() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(dead(), "background sleep survived the timeout");
+    }
+}
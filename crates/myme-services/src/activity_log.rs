@@ -0,0 +1,202 @@
+//! Activity log of user-initiated changes.
+//!
+//! Every change a user makes through the app (note created, issue moved, email
+//! archived, repo cloned) is appended here with a timestamp, a one-line
+//! summary and, where the change can be reversed, an undo hint. The log backs
+//! the "what did I do today" pane.
+
+use std::path::Path;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::db;
+
+/// Entries older than this are pruned by `prune_before` callers.
+pub const RETENTION_DAYS: i64 = 90;
+
+/// One recorded change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    pub id: i64,
+    pub at: DateTime<Utc>,
+    /// Service the change was made in: "notes", "gmail", "kanban", "repos"
+    pub service: String,
+    /// What was done: "created", "archived", "moved", "cloned", ...
+    pub action: String,
+    /// Id of the changed item within its service
+    pub target: String,
+    /// One line for display
+    pub summary: String,
+    /// How to reverse the change, as JSON understood by the app's undo support
+    pub undo_hint: Option<String>,
+}
+
+/// A change to record; the id and timestamp are assigned by `record`.
+#[derive(Debug, Clone, Default)]
+pub struct NewActivity {
+    pub service: String,
+    pub action: String,
+    pub target: String,
+    pub summary: String,
+    pub undo_hint: Option<String>,
+}
+
+/// SQLite-backed activity log.
+pub struct ActivityLog {
+    conn: Mutex<Connection>,
+}
+
+impl ActivityLog {
+    /// Open (creating if needed) the log at `path`.
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::init(db::open_maintained(path)?)
+    }
+
+    /// In-memory log (for testing).
+    #[cfg(test)]
+    pub fn in_memory() -> Result<Self> {
+        Self::init(db::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        db::migrate(&conn, 1, |_| {
+            conn.execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS activity (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    at_ms INTEGER NOT NULL,
+                    service TEXT NOT NULL,
+                    action TEXT NOT NULL,
+                    target TEXT NOT NULL,
+                    summary TEXT NOT NULL,
+                    undo_hint TEXT
+                );
+                CREATE INDEX IF NOT EXISTS idx_activity_at ON activity(at_ms);
+                "#,
+            )?;
+            Ok(())
+        })?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    /// Append a change timestamped now; returns its id.
+    pub fn record(&self, entry: &NewActivity) -> Result<i64> {
+        self.record_at(entry, Utc::now())
+    }
+
+    fn record_at(&self, entry: &NewActivity, at: DateTime<Utc>) -> Result<i64> {
+        let conn = self.conn.lock();
+        conn.execute(
+            "INSERT INTO activity (at_ms, service, action, target, summary, undo_hint)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                at.timestamp_millis(),
+                entry.service,
+                entry.action,
+                entry.target,
+                entry.summary,
+                entry.undo_hint,
+            ],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Changes made in `[from, to)`, oldest first.
+    pub fn list_between(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ActivityEntry>> {
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(
+            "SELECT id, at_ms, service, action, target, summary, undo_hint
+             FROM activity WHERE at_ms >= ?1 AND at_ms < ?2 ORDER BY at_ms, id",
+        )?;
+        let rows =
+            stmt.query_map(params![from.timestamp_millis(), to.timestamp_millis()], row_to_entry)?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// The `limit` most recent changes, newest first.
+    pub fn list_recent(&self, limit: usize) -> Result<Vec<ActivityEntry>> {
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(
+            "SELECT id, at_ms, service, action, target, summary, undo_hint
+             FROM activity ORDER BY at_ms DESC, id DESC LIMIT ?1",
+        )?;
+        let rows = stmt.query_map(params![i64::try_from(limit).unwrap_or(-1)], row_to_entry)?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Delete changes recorded before `cutoff`; returns how many were removed.
+    pub fn prune_before(&self, cutoff: DateTime<Utc>) -> Result<usize> {
+        let conn = self.conn.lock();
+        Ok(conn
+            .execute("DELETE FROM activity WHERE at_ms < ?1", params![cutoff.timestamp_millis()])?)
+    }
+}
+
+fn row_to_entry(row: &rusqlite::Row) -> rusqlite::Result<ActivityEntry> {
+    let at_ms: i64 = row.get(1)?;
+    Ok(ActivityEntry {
+        id: row.get(0)?,
+        at: DateTime::from_timestamp_millis(at_ms).unwrap_or_default(),
+        service: row.get(2)?,
+        action: row.get(3)?,
+        target: row.get(4)?,
+        summary: row.get(5)?,
+        undo_hint: row.get(6)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::Duration;
+
+    fn activity(action: &str, undo_hint: Option<&str>) -> NewActivity {
+        NewActivity {
+            service: "notes".to_string(),
+            action: action.to_string(),
+            target: "42".to_string(),
+            summary: format!("Note {}", action),
+            undo_hint: undo_hint.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_record_and_list() {
+        let log = ActivityLog::in_memory().unwrap();
+        let now = Utc::now();
+        log.record_at(&activity("created", None), now - Duration::days(2)).unwrap();
+        let id = log.record_at(&activity("archived", Some(r#"{"op":"x"}"#)), now).unwrap();
+        log.record_at(&activity("restored", None), now + Duration::seconds(1)).unwrap();
+
+        let today = log.list_between(now - Duration::hours(1), now + Duration::hours(1)).unwrap();
+        let actions: Vec<&str> = today.iter().map(|e| e.action.as_str()).collect();
+        assert_eq!(actions, ["archived", "restored"]);
+        assert_eq!(today[0].id, id);
+        assert_eq!(today[0].undo_hint.as_deref(), Some(r#"{"op":"x"}"#));
+
+        let recent = log.list_recent(2).unwrap();
+        assert_eq!(recent[0].action, "restored");
+        assert_eq!(recent.len(), 2);
+    }
+
+    #[test]
+    fn test_prune_before() {
+        let log = ActivityLog::in_memory().unwrap();
+        let now = Utc::now();
+        log.record_at(&activity("created", None), now - Duration::days(RETENTION_DAYS + 1))
+            .unwrap();
+        log.record(&activity("archived", None)).unwrap();
+
+        assert_eq!(log.prune_before(now - Duration::days(RETENTION_DAYS)).unwrap(), 1);
+        assert_eq!(log.list_recent(10).unwrap().len(), 1);
+    }
+}
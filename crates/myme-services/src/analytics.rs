@@ -0,0 +1,244 @@
+//! Board analytics from task status history (`ProjectStore::list_status_history`):
+//! tasks per status each day (cumulative flow and burndown), throughput and
+//! cycle time.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+
+use crate::project::{StatusChange, TaskStatus};
+
+/// Tasks in each status at the end of one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayCounts {
+    pub date: NaiveDate,
+    /// Indexed like [`TaskStatus::all`]
+    pub counts: [u32; 6],
+}
+
+impl DayCounts {
+    pub fn count(&self, status: TaskStatus) -> u32 {
+        self.counts[status_index(status)]
+    }
+
+    pub fn total(&self) -> u32 {
+        self.counts.iter().sum()
+    }
+
+    /// Tasks not yet done (the burndown line)
+    pub fn remaining(&self) -> u32 {
+        self.total() - self.count(TaskStatus::Done)
+    }
+}
+
+/// Time from starting work on a task to finishing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleTime {
+    pub task_id: String,
+    pub started: DateTime<Utc>,
+    pub finished: DateTime<Utc>,
+}
+
+impl CycleTime {
+    pub fn duration(&self) -> Duration {
+        self.finished - self.started
+    }
+}
+
+fn status_index(status: TaskStatus) -> usize {
+    TaskStatus::all().iter().position(|s| *s == status).unwrap_or(0)
+}
+
+/// Work has started once a task reaches one of these
+fn is_active(status: TaskStatus) -> bool {
+    matches!(status, TaskStatus::InProgress | TaskStatus::Blocked | TaskStatus::Review)
+}
+
+/// Changes with parseable times, oldest first
+fn timed(history: &[StatusChange]) -> Vec<(DateTime<Utc>, &StatusChange)> {
+    let mut changes: Vec<_> = history
+        .iter()
+        .filter_map(|c| {
+            DateTime::parse_from_rfc3339(&c.changed_at).ok().map(|t| (t.with_timezone(&Utc), c))
+        })
+        .collect();
+    changes.sort_by_key(|(at, _)| *at);
+    changes
+}
+
+/// First instant after `date` in `tz`
+fn day_end<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> DateTime<Utc> {
+    let next = date.succ_opt().unwrap_or(date).and_hms_opt(0, 0, 0).unwrap_or_default();
+    tz.from_local_datetime(&next)
+        .earliest()
+        // Midnight skipped by a DST change: an hour later exists
+        .or_else(|| tz.from_local_datetime(&(next + Duration::hours(1))).earliest())
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&next))
+}
+
+/// Tasks per status at the end of each day from `first` to `last` in `tz`.
+/// Tasks count from their first recorded status.
+pub fn cumulative_flow<Tz: TimeZone>(
+    history: &[StatusChange],
+    first: NaiveDate,
+    last: NaiveDate,
+    tz: &Tz,
+) -> Vec<DayCounts> {
+    let changes = timed(history);
+    let mut current: HashMap<&str, TaskStatus> = HashMap::new();
+    let mut next = 0;
+    first
+        .iter_days()
+        .take_while(|date| *date <= last)
+        .map(|date| {
+            let end = day_end(date, tz);
+            while let Some((_, change)) = changes.get(next).filter(|(at, _)| *at < end) {
+                current.insert(&change.task_id, change.to);
+                next += 1;
+            }
+            let mut counts = [0; 6];
+            for status in current.values() {
+                counts[status_index(*status)] += 1;
+            }
+            DayCounts { date, counts }
+        })
+        .collect()
+}
+
+/// Tasks moved into Done on each day from `first` to `last` in `tz`. A task
+/// reopened and finished again counts again.
+pub fn throughput<Tz: TimeZone>(
+    history: &[StatusChange],
+    first: NaiveDate,
+    last: NaiveDate,
+    tz: &Tz,
+) -> Vec<(NaiveDate, u32)> {
+    let mut per_day: HashMap<NaiveDate, u32> = HashMap::new();
+    for (at, change) in timed(history) {
+        if change.to == TaskStatus::Done && change.from.is_some_and(|f| f != TaskStatus::Done) {
+            *per_day.entry(at.with_timezone(tz).date_naive()).or_default() += 1;
+        }
+    }
+    first
+        .iter_days()
+        .take_while(|date| *date <= last)
+        .map(|date| (date, per_day.get(&date).copied().unwrap_or(0)))
+        .collect()
+}
+
+/// When work on a task started and when it was (last) finished
+type WorkSpan = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
+/// Cycle times of tasks that are done: from first reaching In Progress,
+/// Blocked or Review to the last move into Done. Tasks finished without
+/// passing through those are left out. Ordered by finish time.
+pub fn cycle_times(history: &[StatusChange]) -> Vec<CycleTime> {
+    let mut tasks: HashMap<&str, WorkSpan> = HashMap::new();
+    for (at, change) in timed(history) {
+        let (started, finished) = tasks.entry(&change.task_id).or_default();
+        if is_active(change.to) && started.is_none() {
+            *started = Some(at);
+        }
+        // Reopening clears the finish
+        *finished = (change.to == TaskStatus::Done).then_some(at);
+    }
+    let mut times: Vec<CycleTime> = tasks
+        .into_iter()
+        .filter_map(|(task_id, (started, finished))| {
+            Some(CycleTime { task_id: task_id.to_string(), started: started?, finished: finished? })
+        })
+        .collect();
+    times.sort_by(|a, b| a.finished.cmp(&b.finished).then_with(|| a.task_id.cmp(&b.task_id)));
+    times
+}
+
+/// Median of `durations` (the mean of the middle two for an even count)
+pub fn median(durations: &[Duration]) -> Option<Duration> {
+    let mut sorted = durations.to_vec();
+    sorted.sort();
+    let mid = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[mid]),
+        _ => Some(sorted[mid - 1] + (sorted[mid] - sorted[mid - 1]) / 2),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn change(task: &str, from: Option<TaskStatus>, to: TaskStatus, at: &str) -> StatusChange {
+        StatusChange { task_id: task.to_string(), from, to, changed_at: at.to_string() }
+    }
+
+    fn history() -> Vec<StatusChange> {
+        use TaskStatus::*;
+        vec![
+            change("a", None, Todo, "2026-03-01T09:00:00Z"),
+            change("b", None, Backlog, "2026-03-01T10:00:00Z"),
+            change("a", Some(Todo), InProgress, "2026-03-02T09:00:00Z"),
+            change("a", Some(InProgress), Done, "2026-03-03T21:00:00Z"),
+            change("c", None, Todo, "2026-03-03T12:00:00Z"),
+            change("b", Some(Backlog), Done, "2026-03-04T08:00:00Z"),
+            change("c", Some(Todo), Review, "2026-03-04T09:00:00Z"),
+        ]
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_cumulative_flow_and_burndown() {
+        let days = cumulative_flow(&history(), date("2026-02-28"), date("2026-03-04"), &Utc);
+        assert_eq!(days.len(), 5);
+        assert_eq!(days[0].total(), 0);
+        assert_eq!(days[1].count(TaskStatus::Todo), 1);
+        assert_eq!(days[1].count(TaskStatus::Backlog), 1);
+        assert_eq!(days[2].count(TaskStatus::InProgress), 1);
+        let mar3 = &days[3];
+        assert_eq!((mar3.count(TaskStatus::Done), mar3.remaining(), mar3.total()), (1, 2, 3));
+        let mar4 = &days[4];
+        assert_eq!((mar4.count(TaskStatus::Done), mar4.count(TaskStatus::Review)), (2, 1));
+        assert_eq!(mar4.remaining(), 1);
+
+        // Five hours ahead, a's finish falls on March 4
+        let tz = FixedOffset::east_opt(5 * 3600).unwrap();
+        let days = cumulative_flow(&history(), date("2026-03-03"), date("2026-03-04"), &tz);
+        assert_eq!(days[0].count(TaskStatus::Done), 0);
+        assert_eq!(days[1].count(TaskStatus::Done), 2);
+    }
+
+    #[test]
+    fn test_throughput() {
+        let per_day = throughput(&history(), date("2026-03-02"), date("2026-03-04"), &Utc);
+        assert_eq!(
+            per_day,
+            [(date("2026-03-02"), 0), (date("2026-03-03"), 1), (date("2026-03-04"), 1)]
+        );
+    }
+
+    #[test]
+    fn test_cycle_times() {
+        use TaskStatus::*;
+        let mut history = history();
+        // Reopened and finished again: the last finish counts
+        history.push(change("a", Some(Done), InProgress, "2026-03-05T09:00:00Z"));
+        history.push(change("a", Some(InProgress), Done, "2026-03-06T09:00:00Z"));
+
+        let times = cycle_times(&history);
+        // b went straight to Done and c isn't finished
+        assert_eq!(times.len(), 1);
+        assert_eq!(times[0].task_id, "a");
+        assert_eq!(times[0].duration(), Duration::days(4));
+
+        let durations = [Duration::hours(3), Duration::hours(1), Duration::hours(2)];
+        assert_eq!(median(&durations), Some(Duration::hours(2)));
+        assert_eq!(median(&durations[..2]), Some(Duration::hours(2)));
+        assert_eq!(median(&[]), None);
+    }
+}
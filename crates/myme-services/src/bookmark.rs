@@ -0,0 +1,587 @@
+//! Read-it-later bookmarks.
+//!
+//! A bookmark is saved with just its URL; the page title, description and
+//! OpenGraph details are filled in by `MetadataFetcher` afterwards, so saving
+//! works offline. Tags are lowercase words without the leading `#`.
+
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::db;
+
+/// Only the start of a page is read; metadata lives in `<head>`
+const MAX_PAGE_BYTES: usize = 512 * 1024;
+
+/// Longest excerpt kept from a page description
+const EXCERPT_CHARS: usize = 500;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// A saved page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub id: i64,
+    pub url: String,
+    /// Page title, or the URL until metadata is fetched
+    pub title: String,
+    pub excerpt: String,
+    pub site_name: Option<String>,
+    pub image_url: Option<String>,
+    pub tags: Vec<String>,
+    pub archived: bool,
+    pub created_at: DateTime<Utc>,
+    /// When metadata was last fetched; `None` until the first fetch
+    pub fetched_at: Option<DateTime<Utc>>,
+}
+
+/// Details read from a page's `<title>` and `<meta>` tags.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageMetadata {
+    pub title: Option<String>,
+    pub excerpt: Option<String>,
+    pub site_name: Option<String>,
+    pub image_url: Option<String>,
+}
+
+/// Which bookmarks `BookmarkStore::list` returns.
+#[derive(Debug, Clone, Default)]
+pub struct BookmarkFilter {
+    pub tag: Option<String>,
+    /// Archived bookmarks instead of the reading list
+    pub archived: bool,
+    /// Case-insensitive match on title, URL or excerpt
+    pub query: Option<String>,
+}
+
+/// A web address typed by the user, with `https://` added when no scheme is
+/// given. `None` unless it is an http(s) URL with a host.
+pub fn normalize_url(input: &str) -> Option<String> {
+    let input = input.trim();
+    let candidate =
+        if input.contains("://") { input.to_string() } else { format!("https://{}", input) };
+    let url = Url::parse(&candidate).ok()?;
+    // `mailto:me@example.com` would otherwise read as user `mailto` at example.com
+    let web = matches!(url.scheme(), "http" | "https")
+        && url.host_str().is_some_and(|h| !h.is_empty())
+        && url.username().is_empty();
+    web.then(|| url.to_string())
+}
+
+/// `#Rust` -> `rust`; `None` for an empty tag
+pub fn normalize_tag(tag: &str) -> Option<String> {
+    let tag = tag.trim().trim_start_matches('#').trim().to_lowercase();
+    (!tag.is_empty()).then_some(tag)
+}
+
+/// SQLite-backed bookmark store.
+pub struct BookmarkStore {
+    conn: Mutex<Connection>,
+}
+
+impl BookmarkStore {
+    /// Open (creating if needed) the store at `path`.
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::init(db::open_maintained(path)?)
+    }
+
+    /// In-memory store (for testing).
+    #[cfg(test)]
+    pub fn in_memory() -> Result<Self> {
+        Self::init(db::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        db::migrate(&conn, 1, |_| {
+            conn.execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS bookmarks (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    url TEXT NOT NULL UNIQUE,
+                    title TEXT,
+                    excerpt TEXT NOT NULL DEFAULT '',
+                    site_name TEXT,
+                    image_url TEXT,
+                    archived INTEGER NOT NULL DEFAULT 0,
+                    created_ms INTEGER NOT NULL,
+                    fetched_ms INTEGER
+                );
+                CREATE TABLE IF NOT EXISTS bookmark_tags (
+                    bookmark_id INTEGER NOT NULL REFERENCES bookmarks(id) ON DELETE CASCADE,
+                    tag TEXT NOT NULL,
+                    PRIMARY KEY (bookmark_id, tag)
+                );
+                CREATE INDEX IF NOT EXISTS idx_bookmark_tags_tag ON bookmark_tags(tag);
+                "#,
+            )?;
+            Ok(())
+        })?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    /// Save `url` with `tags`. Saving a URL again adds the tags to the
+    /// existing bookmark and moves it back to the reading list.
+    /// Returns the bookmark and whether it is new.
+    pub fn add(&self, url: &str, tags: &[String]) -> Result<(Bookmark, bool)> {
+        let Some(url) = normalize_url(url) else {
+            bail!("Not a web address: {}", url);
+        };
+        let (id, created) = {
+            let mut conn = self.conn.lock();
+            let tx = conn.transaction()?;
+            let inserted = tx.execute(
+                "INSERT OR IGNORE INTO bookmarks (url, created_ms) VALUES (?1, ?2)",
+                params![url, Utc::now().timestamp_millis()],
+            )?;
+            let id: i64 =
+                tx.query_row("SELECT id FROM bookmarks WHERE url = ?1", params![url], |row| {
+                    row.get(0)
+                })?;
+            if inserted == 0 {
+                tx.execute("UPDATE bookmarks SET archived = 0 WHERE id = ?1", params![id])?;
+            }
+            for tag in tags.iter().filter_map(|t| normalize_tag(t)) {
+                tx.execute(
+                    "INSERT OR IGNORE INTO bookmark_tags (bookmark_id, tag) VALUES (?1, ?2)",
+                    params![id, tag],
+                )?;
+            }
+            tx.commit()?;
+            (id, inserted > 0)
+        };
+        let bookmark = self.get(id)?.ok_or_else(|| anyhow::anyhow!("Bookmark vanished"))?;
+        Ok((bookmark, created))
+    }
+
+    pub fn get(&self, id: i64) -> Result<Option<Bookmark>> {
+        let conn = self.conn.lock();
+        let bookmark = conn
+            .query_row(
+                "SELECT id, url, title, excerpt, site_name, image_url, archived, created_ms, fetched_ms
+                 FROM bookmarks WHERE id = ?1",
+                params![id],
+                row_to_bookmark,
+            )
+            .optional()?;
+        bookmark.map(|b| with_tags(&conn, b)).transpose()
+    }
+
+    /// Store fetched page details. Fields the page did not provide keep
+    /// their previous values.
+    pub fn set_metadata(
+        &self,
+        id: i64,
+        meta: &PageMetadata,
+        fetched_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let conn = self.conn.lock();
+        conn.execute(
+            "UPDATE bookmarks SET title = COALESCE(?2, title), excerpt = COALESCE(?3, excerpt),
+                 site_name = COALESCE(?4, site_name), image_url = COALESCE(?5, image_url),
+                 fetched_ms = ?6
+             WHERE id = ?1",
+            params![
+                id,
+                meta.title,
+                meta.excerpt,
+                meta.site_name,
+                meta.image_url,
+                fetched_at.timestamp_millis()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Replace the tags of a bookmark.
+    pub fn set_tags(&self, id: i64, tags: &[String]) -> Result<()> {
+        let mut conn = self.conn.lock();
+        let tx = conn.transaction()?;
+        tx.execute("DELETE FROM bookmark_tags WHERE bookmark_id = ?1", params![id])?;
+        for tag in tags.iter().filter_map(|t| normalize_tag(t)) {
+            tx.execute(
+                "INSERT OR IGNORE INTO bookmark_tags (bookmark_id, tag) VALUES (?1, ?2)",
+                params![id, tag],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    pub fn set_archived(&self, id: i64, archived: bool) -> Result<()> {
+        let conn = self.conn.lock();
+        conn.execute("UPDATE bookmarks SET archived = ?2 WHERE id = ?1", params![id, archived])?;
+        Ok(())
+    }
+
+    pub fn delete(&self, id: i64) -> Result<()> {
+        let conn = self.conn.lock();
+        conn.execute("DELETE FROM bookmark_tags WHERE bookmark_id = ?1", params![id])?;
+        conn.execute("DELETE FROM bookmarks WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    /// Bookmarks matching `filter`, newest first.
+    pub fn list(&self, filter: &BookmarkFilter, limit: usize) -> Result<Vec<Bookmark>> {
+        let tag = filter.tag.as_deref().and_then(normalize_tag);
+        let query = filter.query.as_deref().map(str::trim).filter(|q| !q.is_empty()).map(|q| {
+            format!("%{}%", q.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"))
+        });
+
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT id, url, title, excerpt, site_name, image_url, archived, created_ms, fetched_ms
+            FROM bookmarks b
+            WHERE archived = ?1
+              AND (?2 IS NULL OR EXISTS
+                   (SELECT 1 FROM bookmark_tags t WHERE t.bookmark_id = b.id AND t.tag = ?2))
+              AND (?3 IS NULL OR title LIKE ?3 ESCAPE '\' OR url LIKE ?3 ESCAPE '\'
+                   OR excerpt LIKE ?3 ESCAPE '\')
+            ORDER BY created_ms DESC, id DESC
+            LIMIT ?4
+            "#,
+        )?;
+        let rows = stmt.query_map(
+            params![filter.archived, tag, query, i64::try_from(limit).unwrap_or(-1)],
+            row_to_bookmark,
+        )?;
+        let bookmarks = rows.collect::<Result<Vec<_>, _>>()?;
+        bookmarks.into_iter().map(|b| with_tags(&conn, b)).collect()
+    }
+
+    /// Number of bookmarks in the reading list, or in the archive.
+    pub fn count(&self, archived: bool) -> Result<usize> {
+        let conn = self.conn.lock();
+        let n: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM bookmarks WHERE archived = ?1",
+            params![archived],
+            |row| row.get(0),
+        )?;
+        Ok(n as usize)
+    }
+
+    /// Tags in use with how many bookmarks carry each, most used first.
+    pub fn tags(&self) -> Result<Vec<(String, u32)>> {
+        let conn = self.conn.lock();
+        let mut stmt = conn.prepare(
+            "SELECT tag, COUNT(*) AS n FROM bookmark_tags GROUP BY tag ORDER BY n DESC, tag",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+}
+
+fn row_to_bookmark(row: &rusqlite::Row) -> rusqlite::Result<Bookmark> {
+    let url: String = row.get(1)?;
+    let title: Option<String> = row.get(2)?;
+    let fetched_ms: Option<i64> = row.get(8)?;
+    Ok(Bookmark {
+        id: row.get(0)?,
+        title: title.unwrap_or_else(|| url.clone()),
+        url,
+        excerpt: row.get(3)?,
+        site_name: row.get(4)?,
+        image_url: row.get(5)?,
+        tags: Vec::new(),
+        archived: row.get(6)?,
+        created_at: DateTime::from_timestamp_millis(row.get(7)?).unwrap_or_default(),
+        fetched_at: fetched_ms.and_then(DateTime::from_timestamp_millis),
+    })
+}
+
+fn with_tags(conn: &Connection, mut bookmark: Bookmark) -> Result<Bookmark> {
+    let mut stmt =
+        conn.prepare_cached("SELECT tag FROM bookmark_tags WHERE bookmark_id = ?1 ORDER BY tag")?;
+    let tags = stmt.query_map(params![bookmark.id], |row| row.get(0))?;
+    bookmark.tags = tags.collect::<Result<Vec<_>, _>>()?;
+    Ok(bookmark)
+}
+
+/// Fetches page metadata for bookmarks.
+pub struct MetadataFetcher {
+    client: reqwest::Client,
+}
+
+impl Default for MetadataFetcher {
+    fn default() -> Self {
+        Self::new_with_proxies(Vec::new())
+    }
+}
+
+impl MetadataFetcher {
+    /// Create a fetcher that sends requests through `proxies`.
+    pub fn new_with_proxies(proxies: Vec<reqwest::Proxy>) -> Self {
+        let builder = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .user_agent(concat!("myme/", env!("CARGO_PKG_VERSION")));
+        let client = proxies
+            .into_iter()
+            .fold(builder, reqwest::ClientBuilder::proxy)
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to build metadata client: {}", e);
+                reqwest::Client::new()
+            });
+        Self { client }
+    }
+
+    /// Fetch `url` and read its metadata. Non-HTML pages (PDFs, images)
+    /// give empty metadata rather than an error.
+    pub async fn fetch(&self, url: &str) -> Result<PageMetadata> {
+        let mut response = self.client.get(url).send().await?.error_for_status()?;
+        let page_url = response.url().clone();
+        let is_html = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .is_none_or(|ct| ct.contains("html"));
+        if !is_html {
+            return Ok(PageMetadata::default());
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            body.extend_from_slice(&chunk);
+            if body.len() >= MAX_PAGE_BYTES {
+                break;
+            }
+        }
+        Ok(parse_metadata(&String::from_utf8_lossy(&body), &page_url))
+    }
+}
+
+/// Read the title, description, site name and image from an HTML page,
+/// preferring OpenGraph and Twitter card tags over `<title>` and
+/// `<meta name="description">`. Relative image URLs are resolved against `page_url`.
+pub fn parse_metadata(html: &str, page_url: &Url) -> PageMetadata {
+    // ASCII lowercasing keeps byte offsets, so indexes carry over to `html`
+    let lower = html.to_ascii_lowercase();
+    let head = lower.find("</head>").map_or(html.len(), |i| i);
+
+    let mut meta: Vec<(String, String)> = Vec::new();
+    let mut pos = 0;
+    while let Some(start) = lower[pos..head].find("<meta").map(|i| i + pos) {
+        let Some(end) = lower[start..].find('>').map(|i| i + start) else {
+            break;
+        };
+        let attrs = parse_attributes(&html[start + 5..end]);
+        let key = attrs.iter().find(|(k, _)| k == "property" || k == "name").map(|(_, v)| v);
+        let content = attrs.iter().find(|(k, _)| k == "content").map(|(_, v)| v);
+        if let (Some(key), Some(content)) = (key, content) {
+            meta.push((key.to_ascii_lowercase(), clean_text(content)));
+        }
+        pos = end;
+    }
+    let find = |keys: &[&str]| {
+        keys.iter().find_map(|key| {
+            meta.iter().find(|(k, v)| k == key && !v.is_empty()).map(|(_, v)| v.clone())
+        })
+    };
+
+    let title_tag = lower[..head].find("<title").and_then(|start| {
+        let open_end = lower[start..].find('>')? + start + 1;
+        let close = lower[open_end..].find("</title")? + open_end;
+        Some(clean_text(&html[open_end..close])).filter(|t| !t.is_empty())
+    });
+
+    PageMetadata {
+        title: find(&["og:title", "twitter:title"]).or(title_tag),
+        excerpt: find(&["og:description", "description", "twitter:description"])
+            .map(|d| truncate(&d, EXCERPT_CHARS)),
+        site_name: find(&["og:site_name"]),
+        image_url: find(&["og:image", "og:image:url", "twitter:image"])
+            .and_then(|src| page_url.join(&src).ok())
+            .map(|u| u.to_string()),
+    }
+}
+
+/// `key="value"` pairs of a tag; keys lowercased, quotes optional.
+fn parse_attributes(tag: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = tag.trim_end_matches('/');
+    loop {
+        rest = rest.trim_start();
+        let Some(eq) = rest.find('=') else {
+            break;
+        };
+        let key = rest[..eq].split_whitespace().last().unwrap_or("").to_ascii_lowercase();
+        let after = rest[eq + 1..].trim_start();
+        let (value, tail) = match after.chars().next() {
+            Some(quote @ ('"' | '\'')) => match after[1..].find(quote) {
+                Some(close) => (&after[1..close + 1], &after[close + 2..]),
+                None => (&after[1..], ""),
+            },
+            _ => {
+                let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                (&after[..end], &after[end..])
+            }
+        };
+        attrs.push((key, value.to_string()));
+        rest = tail;
+    }
+    attrs
+}
+
+/// Decode common entities and collapse whitespace
+fn clean_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let decoded = after.find(';').filter(|&end| end <= 8).and_then(|end| {
+            let c = match &after[..end] {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                entity => entity
+                    .strip_prefix("#x")
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(max).collect();
+    format!("{}…", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const PAGE: &str = r#"<!doctype html>
+<html><head>
+  <title>Fallback &amp; title</title>
+  <meta charset="utf-8">
+  <meta name="description" content="Plain description">
+  <meta property="og:title" content="Shipping   Rust &quot;fast&quot;">
+  <meta property=og:site_name content=Example>
+  <meta content='/img/cover.png' property='og:image' />
+</head><body><meta property="og:title" content="Not in head"></body></html>"#;
+
+    fn tags(list: &[&str]) -> Vec<String> {
+        list.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_prefers_opengraph() {
+        let url = Url::parse("https://example.com/posts/1").unwrap();
+        let meta = parse_metadata(PAGE, &url);
+        assert_eq!(meta.title.as_deref(), Some("Shipping Rust \"fast\""));
+        assert_eq!(meta.excerpt.as_deref(), Some("Plain description"));
+        assert_eq!(meta.site_name.as_deref(), Some("Example"));
+        assert_eq!(meta.image_url.as_deref(), Some("https://example.com/img/cover.png"));
+
+        let plain = parse_metadata("<title>\n Just a title\n</title>", &url);
+        assert_eq!(plain.title.as_deref(), Some("Just a title"));
+        assert_eq!(plain.excerpt, None);
+    }
+
+    #[test]
+    fn normalizes_urls_and_tags() {
+        assert_eq!(normalize_url(" example.com/a ").as_deref(), Some("https://example.com/a"));
+        assert_eq!(normalize_url("http://example.com").as_deref(), Some("http://example.com/"));
+        assert_eq!(normalize_url("ftp://example.com"), None);
+        assert_eq!(normalize_url("not a url"), None);
+        assert_eq!(normalize_tag(" #Rust "), Some("rust".to_string()));
+        assert_eq!(normalize_tag("#"), None);
+    }
+
+    #[test]
+    fn add_merges_duplicates_and_filters_by_tag() {
+        let store = BookmarkStore::in_memory().unwrap();
+        let (first, created) = store.add("example.com/a", &tags(&["#Rust"])).unwrap();
+        assert!(created);
+        assert_eq!(first.title, "https://example.com/a");
+        assert_eq!(first.tags, vec!["rust"]);
+
+        store.set_archived(first.id, true).unwrap();
+        let (again, created) = store.add("https://example.com/a", &tags(&["async"])).unwrap();
+        assert!(!created);
+        assert_eq!(again.id, first.id);
+        assert_eq!(again.tags, vec!["async", "rust"]);
+        assert!(!again.archived);
+
+        let (other, _) = store.add("https://example.org/b", &[]).unwrap();
+        let meta = PageMetadata { title: Some("Other 100%".into()), ..Default::default() };
+        store.set_metadata(other.id, &meta, Utc::now()).unwrap();
+
+        let rust = BookmarkFilter { tag: Some("RUST".into()), ..Default::default() };
+        assert_eq!(store.list(&rust, 10).unwrap().len(), 1);
+        let search = BookmarkFilter { query: Some("100%".into()), ..Default::default() };
+        let found = store.list(&search, 10).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].title, "Other 100%");
+        assert!(found[0].fetched_at.is_some());
+
+        store.set_tags(first.id, &tags(&["reading"])).unwrap();
+        assert_eq!(store.tags().unwrap(), vec![("reading".to_string(), 1)]);
+
+        store.set_archived(other.id, true).unwrap();
+        assert_eq!(store.count(true).unwrap(), 1);
+        let archived = BookmarkFilter { archived: true, ..Default::default() };
+        assert_eq!(store.list(&archived, 10).unwrap()[0].id, other.id);
+
+        store.delete(first.id).unwrap();
+        assert!(store.get(first.id).unwrap().is_none());
+        assert!(store.add("mailto:me@example.com", &[]).is_err());
+    }
+
+    #[tokio::test]
+    async fn fetcher_reads_html_and_skips_other_types() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/post"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(PAGE, "text/html; charset=utf-8"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(b"%PDF-1.7".to_vec(), "application/pdf"),
+            )
+            .mount(&server)
+            .await;
+
+        let fetcher = MetadataFetcher::default();
+        let meta = fetcher.fetch(&format!("{}/post", server.uri())).await.unwrap();
+        assert_eq!(meta.site_name.as_deref(), Some("Example"));
+        assert_eq!(meta.image_url, Some(format!("{}/img/cover.png", server.uri())));
+
+        let pdf = fetcher.fetch(&format!("{}/paper.pdf", server.uri())).await.unwrap();
+        assert_eq!(pdf, PageMetadata::default());
+        assert!(fetcher.fetch(&format!("{}/missing", server.uri())).await.is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! Release changelog drafting.
+//!
+//! Builds Markdown release notes from the issues API: merged pull requests and
+//! issues closed after the previous release, plus a suggested next tag.
+
+use chrono::{DateTime, Utc};
+
+use crate::github::GitHubIssue;
+
+/// Labels that make a merged pull request a breaking change or a feature
+const BREAKING_LABELS: &[&str] = &["breaking", "breaking-change", "breaking change"];
+const FEATURE_LABELS: &[&str] = &["enhancement", "feature"];
+
+/// Which part of a semantic version the next release bumps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VersionBump {
+    Patch,
+    Minor,
+    Major,
+}
+
+/// Release notes drafted from closed work
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogDraft {
+    /// Markdown body for the release
+    pub body: String,
+    pub pull_requests: usize,
+    pub issues: usize,
+    pub bump: VersionBump,
+}
+
+fn parse_time(value: Option<&str>) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value?).ok().map(|t| t.with_timezone(&Utc))
+}
+
+fn has_label(issue: &GitHubIssue, names: &[&str]) -> bool {
+    issue.labels.iter().any(|l| names.iter().any(|n| l.name.eq_ignore_ascii_case(n)))
+}
+
+/// Draft release notes from `issues` (as returned by `list_issues_since`),
+/// keeping pull requests merged and issues closed after `since`. Pull
+/// requests closed without merging are left out.
+pub fn draft_changelog(issues: &[GitHubIssue], since: Option<DateTime<Utc>>) -> ChangelogDraft {
+    let after_since = |at: &DateTime<Utc>| since.is_none_or(|since| *at > since);
+
+    let mut merged: Vec<(DateTime<Utc>, &GitHubIssue)> = Vec::new();
+    let mut closed: Vec<(DateTime<Utc>, &GitHubIssue)> = Vec::new();
+    for issue in issues {
+        match &issue.pull_request {
+            Some(pr) => {
+                if let Some(at) = parse_time(pr.merged_at.as_deref()).filter(after_since) {
+                    merged.push((at, issue));
+                }
+            }
+            None if issue.state == "closed" => {
+                if let Some(at) = parse_time(issue.closed_at.as_deref()).filter(after_since) {
+                    closed.push((at, issue));
+                }
+            }
+            None => {}
+        }
+    }
+    merged.sort_by_key(|(at, _)| *at);
+    closed.sort_by_key(|(at, _)| *at);
+
+    let bump = merged
+        .iter()
+        .map(|(_, pr)| {
+            if has_label(pr, BREAKING_LABELS) {
+                VersionBump::Major
+            } else if has_label(pr, FEATURE_LABELS) {
+                VersionBump::Minor
+            } else {
+                VersionBump::Patch
+            }
+        })
+        .max()
+        .unwrap_or(VersionBump::Patch);
+
+    let mut sections = Vec::new();
+    for (heading, items) in [("Pull requests", &merged), ("Closed issues", &closed)] {
+        if items.is_empty() {
+            continue;
+        }
+        let lines: Vec<String> = items
+            .iter()
+            .map(|(_, issue)| format!("- {} (#{})", issue.title.trim(), issue.number))
+            .collect();
+        sections.push(format!("## {}\n\n{}", heading, lines.join("\n")));
+    }
+
+    ChangelogDraft {
+        body: sections.join("\n\n"),
+        pull_requests: merged.len(),
+        issues: closed.len(),
+        bump,
+    }
+}
+
+/// The tag after `last` for a `bump`, keeping a `v` prefix: `v1.2.3` + minor
+/// is `v1.3.0`. `None` when `last` isn't `major.minor.patch`.
+pub fn next_tag(last: &str, bump: VersionBump) -> Option<String> {
+    let (prefix, version) = match last.strip_prefix('v') {
+        Some(rest) => ("v", rest),
+        None => ("", last),
+    };
+    let mut parts = version.splitn(3, '.');
+    let major: u64 = parts.next()?.parse().ok()?;
+    let minor: u64 = parts.next()?.parse().ok()?;
+    // "3+build" carries build metadata, "3-rc.1" is a pre-release
+    let patch_part = parts.next()?;
+    let patch_part = patch_part.split_once('+').map_or(patch_part, |(p, _)| p);
+    let (patch_part, prerelease) =
+        patch_part.split_once('-').map_or((patch_part, false), |(p, _)| (p, true));
+    let patch: u64 = patch_part.parse().ok()?;
+
+    let (major, minor, patch) = match bump {
+        // Releasing a pre-release's version as final needs no bump
+        _ if prerelease => (major, minor, patch),
+        VersionBump::Major => (major + 1, 0, 0),
+        VersionBump::Minor => (major, minor + 1, 0),
+        VersionBump::Patch => (major, minor, patch + 1),
+    };
+    Some(format!("{}{}.{}.{}", prefix, major, minor, patch))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn issue(number: i32, title: &str, closed_at: &str, merged: Option<&str>) -> GitHubIssue {
+        let mut value = serde_json::json!({
+            "id": number,
+            "number": number,
+            "title": title,
+            "body": null,
+            "state": "closed",
+            "html_url": "",
+            "labels": [],
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": closed_at,
+            "closed_at": closed_at,
+        });
+        if let Some(merged_at) = merged {
+            value["pull_request"] = serde_json::json!({ "merged_at": merged_at });
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn drafts_merged_prs_and_closed_issues_since_release() {
+        let mut feature =
+            issue(12, "Add export", "2026-03-02T00:00:00Z", Some("2026-03-02T00:00:00Z"));
+        feature.labels = serde_json::from_value(
+            serde_json::json!([{"id": 1, "name": "Enhancement", "color": "a2eeef"}]),
+        )
+        .unwrap();
+        let issues = vec![
+            feature,
+            issue(11, "Fix crash", "2026-03-01T00:00:00Z", Some("2026-03-01T00:00:00Z")),
+            issue(10, "Crash on start", "2026-03-01T00:00:00Z", None),
+            issue(9, "Abandoned", "2026-03-01T00:00:00Z", Some("")),
+            issue(3, "Old fix", "2026-01-15T00:00:00Z", Some("2026-01-15T00:00:00Z")),
+        ];
+        let since = parse_time(Some("2026-02-01T00:00:00Z"));
+
+        let draft = draft_changelog(&issues, since);
+        assert_eq!(
+            draft.body,
+            "## Pull requests\n\n- Fix crash (#11)\n- Add export (#12)\n\n\
+             ## Closed issues\n\n- Crash on start (#10)"
+        );
+        assert_eq!((draft.pull_requests, draft.issues), (2, 1));
+        assert_eq!(draft.bump, VersionBump::Minor);
+
+        assert!(draft_changelog(&[], since).body.is_empty());
+    }
+
+    #[test]
+    fn next_tags() {
+        assert_eq!(next_tag("v1.2.3", VersionBump::Patch).as_deref(), Some("v1.2.4"));
+        assert_eq!(next_tag("1.2.3", VersionBump::Minor).as_deref(), Some("1.3.0"));
+        assert_eq!(next_tag("v1.2.3", VersionBump::Major).as_deref(), Some("v2.0.0"));
+        assert_eq!(next_tag("v2.0.0-rc.1", VersionBump::Minor).as_deref(), Some("v2.0.0"));
+        assert_eq!(next_tag("0.4.1+linux", VersionBump::Patch).as_deref(), Some("0.4.2"));
+        assert_eq!(next_tag("release-7", VersionBump::Patch), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! GitHub task lists (`- [ ] item`, `- [x] item`) in issue and task bodies:
+//! reading the items and ticking one off without touching the rest of the
+//! markdown.
+
+use serde::{Deserialize, Serialize};
+
+/// One task-list item of a body, in document order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub text: String,
+    pub checked: bool,
+}
+
+/// Items and the byte offset of each item's check mark (the character
+/// between the brackets). Nested items count; fenced code blocks and quotes
+/// don't.
+fn scan(body: &str) -> Vec<(usize, ChecklistItem)> {
+    let mut items = Vec::new();
+    let mut fence: Option<&str> = None;
+    let mut offset = 0;
+    for line in body.split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let content = line.trim_end_matches(['\n', '\r']);
+        let trimmed = content.trim_start();
+        let indent = content.len() - trimmed.len();
+
+        if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            match fence {
+                None => fence = Some(marker),
+                Some(open) if open == marker => fence = None,
+                Some(_) => {}
+            }
+            continue;
+        }
+        if fence.is_some() {
+            continue;
+        }
+
+        let Some(rest) = strip_bullet(trimmed) else {
+            continue;
+        };
+        let checked = match rest.get(..3) {
+            Some("[ ]") => false,
+            Some("[x]" | "[X]") => true,
+            _ => continue,
+        };
+        let after = &rest[3..];
+        if !after.starts_with([' ', '\t']) || after.trim().is_empty() {
+            continue;
+        }
+        let mark = start + indent + (trimmed.len() - rest.len()) + 1;
+        items.push((mark, ChecklistItem { text: after.trim().to_string(), checked }));
+    }
+    items
+}
+
+/// The text after a list bullet (`-`, `*`, `+`, `1.` or `1)`) and its space
+fn strip_bullet(line: &str) -> Option<&str> {
+    let rest = match line.strip_prefix(['-', '*', '+']) {
+        Some(rest) => rest,
+        None => {
+            let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if digits == 0 || digits > 9 {
+                return None;
+            }
+            line[digits..].strip_prefix(['.', ')'])?
+        }
+    };
+    rest.strip_prefix([' ', '\t']).map(str::trim_start)
+}
+
+/// Task-list items of a markdown body
+pub fn parse(body: &str) -> Vec<ChecklistItem> {
+    scan(body).into_iter().map(|(_, item)| item).collect()
+}
+
+/// Done and total items
+pub fn progress(items: &[ChecklistItem]) -> (usize, usize) {
+    (items.iter().filter(|i| i.checked).count(), items.len())
+}
+
+/// Index of the item that was at `index` with `text` when the body was last
+/// read: that index if it still holds the same text, else the first item with
+/// that text. `None` if the item is gone.
+pub fn locate(body: &str, index: usize, text: &str) -> Option<usize> {
+    let items = parse(body);
+    if items.get(index).is_some_and(|i| i.text == text) {
+        return Some(index);
+    }
+    items.iter().position(|i| i.text == text)
+}
+
+/// `body` with item `index` ticked or unticked; everything else is kept
+/// byte for byte. `None` if there is no such item.
+pub fn set_checked(body: &str, index: usize, checked: bool) -> Option<String> {
+    let (mark, _) = scan(body).into_iter().nth(index)?;
+    let mut updated = String::with_capacity(body.len());
+    updated.push_str(&body[..mark]);
+    updated.push(if checked { 'x' } else { ' ' });
+    updated.push_str(&body[mark + 1..]);
+    Some(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    const BODY: &str = "Release checklist:\r\n\
+        \r\n\
+        - [x] Bump version\r\n\
+        - [ ] Update changelog\r\n  \
+          * [X] nested item\r\n\
+        1. [ ] Tag release\r\n\
+        - [ ]\r\n\
+        - [] not an item\r\n\
+        > - [ ] quoted\r\n\
+        ```\r\n\
+        - [ ] in code\r\n\
+        ```\r\n\
+        + [ ] Announce";
+
+    #[test]
+    fn test_parse_task_list() {
+        let items = parse(BODY);
+        let texts: Vec<&str> = items.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            ["Bump version", "Update changelog", "nested item", "Tag release", "Announce"]
+        );
+        let checked: Vec<bool> = items.iter().map(|i| i.checked).collect();
+        assert_eq!(checked, [true, false, true, false, false]);
+        assert_eq!(progress(&items), (2, 5));
+        assert!(parse("No list here").is_empty());
+    }
+
+    #[test]
+    fn test_set_checked_keeps_the_rest() {
+        let updated = set_checked(BODY, 1, true).unwrap();
+        assert_eq!(updated, BODY.replace("- [ ] Update changelog", "- [x] Update changelog"));
+        let updated = set_checked(&updated, 2, false).unwrap();
+        assert!(updated.contains("  * [ ] nested item\r\n"));
+        assert_eq!(progress(&parse(&updated)), (2, 5));
+        assert_eq!(set_checked(BODY, 5, true), None);
+    }
+
+    #[test]
+    fn test_locate_follows_moved_items() {
+        assert_eq!(locate(BODY, 3, "Tag release"), Some(3));
+        // An item was added above it
+        let edited = format!("- [ ] New first\n{}", BODY);
+        assert_eq!(locate(&edited, 3, "Tag release"), Some(4));
+        assert_eq!(locate(BODY, 0, "Removed"), None);
+    }
+}
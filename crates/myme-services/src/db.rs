@@ -0,0 +1,232 @@
+//! Shared SQLite connection setup and maintenance.
+//!
+//! Every local database (projects, notes, Gmail/Calendar caches) opens through
+//! [`open`], which enables WAL so the UI can read while a background sync
+//! writes, turns on foreign keys, and sets a busy timeout instead of failing
+//! immediately on a locked database. Schema changes go through [`migrate`],
+//! tracked with `PRAGMA user_version`, and [`maintain_if_due`] runs
+//! `ANALYZE`/`VACUUM` at most once per [`MAINTENANCE_INTERVAL`].
+//! [`with_migration_progress`] lets a caller watch migrations as they run.
+
+use std::cell::RefCell;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use rusqlite::{Connection, OptionalExtension};
+
+/// How long a statement waits for a lock held by another connection
+pub const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Minimum time between VACUUM/ANALYZE runs on a database
+pub const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Open (creating if needed) a database file with the standard pragmas.
+pub fn open(path: &Path) -> Result<Connection> {
+    let conn = Connection::open(path)
+        .with_context(|| format!("Failed to open database {}", path.display()))?;
+    configure(&conn)?;
+    Ok(conn)
+}
+
+/// In-memory database with the standard pragmas (WAL does not apply).
+pub fn open_in_memory() -> Result<Connection> {
+    let conn = Connection::open_in_memory()?;
+    configure(&conn)?;
+    Ok(conn)
+}
+
+/// Apply WAL, foreign keys, busy timeout and relaxed fsync to a connection.
+pub fn configure(conn: &Connection) -> Result<()> {
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+    // In-memory databases report "memory" and stay that way; that's fine
+    let _mode: String = conn.query_row("PRAGMA journal_mode = WAL", [], |row| row.get(0))?;
+    conn.execute_batch("PRAGMA foreign_keys = ON; PRAGMA synchronous = NORMAL;")?;
+    Ok(())
+}
+
+/// Current `PRAGMA user_version`.
+pub fn user_version(conn: &Connection) -> Result<i32> {
+    Ok(conn.query_row("PRAGMA user_version", [], |row| row.get(0))?)
+}
+
+/// A schema migration step about to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationProgress {
+    /// Version being migrated to
+    pub version: i32,
+    /// Position of this step in the run, from 1
+    pub step: i32,
+    /// Steps in the run
+    pub steps: i32,
+}
+
+type ProgressHook = Box<dyn Fn(MigrationProgress)>;
+
+thread_local! {
+    static PROGRESS: RefCell<Option<ProgressHook>> = const { RefCell::new(None) };
+}
+
+/// Restores the previous hook when `with_migration_progress` returns or unwinds
+struct ProgressGuard(Option<ProgressHook>);
+
+impl Drop for ProgressGuard {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        PROGRESS.with(|hook| *hook.borrow_mut() = previous);
+    }
+}
+
+/// Run `f`, calling `report` before each step [`migrate`] runs on this
+/// thread meanwhile (startup shows it while a store opens).
+pub fn with_migration_progress<T>(
+    report: impl Fn(MigrationProgress) + 'static,
+    f: impl FnOnce() -> T,
+) -> T {
+    let _guard = ProgressGuard(PROGRESS.with(|hook| hook.replace(Some(Box::new(report)))));
+    f()
+}
+
+/// Bring the schema up to `latest` by calling `step(v)` for each version above
+/// the stored `user_version`, recording each version as soon as its step succeeds.
+///
+/// Steps may manage their own transactions. Foreign keys are off while steps
+/// run so table rebuilds (create/copy/drop/rename) don't trip constraints.
+pub fn migrate<F>(conn: &Connection, latest: i32, mut step: F) -> Result<()>
+where
+    F: FnMut(i32) -> Result<()>,
+{
+    let current = user_version(conn)?;
+    if current >= latest {
+        return Ok(());
+    }
+
+    tracing::info!("Migrating database schema from version {} to {}", current, latest);
+    conn.execute_batch("PRAGMA foreign_keys = OFF;")?;
+    let result = (current + 1..=latest).try_for_each(|version| {
+        let progress =
+            MigrationProgress { version, step: version - current, steps: latest - current };
+        PROGRESS.with(|hook| {
+            if let Some(report) = hook.borrow().as_ref() {
+                report(progress);
+            }
+        });
+        step(version).with_context(|| format!("Schema migration to version {} failed", version))?;
+        conn.execute_batch(&format!("PRAGMA user_version = {};", version))?;
+        Ok(())
+    });
+    conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+    result
+}
+
+/// Run `ANALYZE` and `VACUUM` if they haven't run within `interval`.
+/// Returns true if maintenance ran. The last run time is kept in the database.
+pub fn maintain_if_due(conn: &Connection, interval: Duration) -> Result<bool> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS db_maintenance (key TEXT PRIMARY KEY, value INTEGER NOT NULL)",
+    )?;
+    let now = chrono::Utc::now().timestamp();
+    let last: Option<i64> = conn
+        .query_row("SELECT value FROM db_maintenance WHERE key = 'last_vacuum'", [], |row| {
+            row.get(0)
+        })
+        .optional()?;
+    if let Some(last) = last {
+        if now - last < interval.as_secs() as i64 {
+            return Ok(false);
+        }
+    }
+    // First open just records the time; a fresh database has nothing to reclaim
+    if last.is_some() {
+        conn.execute_batch("ANALYZE; VACUUM; PRAGMA wal_checkpoint(TRUNCATE);")?;
+        tracing::info!("Database maintenance complete (ANALYZE, VACUUM)");
+    }
+    conn.execute(
+        "INSERT OR REPLACE INTO db_maintenance (key, value) VALUES ('last_vacuum', ?1)",
+        [now],
+    )?;
+    Ok(last.is_some())
+}
+
+/// [`open`] followed by [`maintain_if_due`] with the default interval.
+/// Maintenance failures are logged, not returned; the connection is still usable.
+pub fn open_maintained(path: &Path) -> Result<Connection> {
+    let conn = open(path)?;
+    if let Err(e) = maintain_if_due(&conn, MAINTENANCE_INTERVAL) {
+        tracing::warn!("Database maintenance failed for {}: {:#}", path.display(), e);
+    }
+    Ok(conn)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_open_enables_wal_and_foreign_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let conn = open(&dir.path().join("test.db")).unwrap();
+        let mode: String = conn.query_row("PRAGMA journal_mode", [], |r| r.get(0)).unwrap();
+        assert_eq!(mode, "wal");
+        let fk: i32 = conn.query_row("PRAGMA foreign_keys", [], |r| r.get(0)).unwrap();
+        assert_eq!(fk, 1);
+    }
+
+    #[test]
+    fn test_migrate_runs_pending_steps_once() {
+        let conn = open_in_memory().unwrap();
+        let mut ran = Vec::new();
+        migrate(&conn, 2, |v| {
+            ran.push(v);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(ran, vec![1, 2]);
+        assert_eq!(user_version(&conn).unwrap(), 2);
+
+        ran.clear();
+        migrate(&conn, 3, |v| {
+            ran.push(v);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(ran, vec![3]);
+
+        // A failing step leaves the version at the last success
+        let err = migrate(&conn, 5, |v| if v == 5 { anyhow::bail!("boom") } else { Ok(()) });
+        assert!(err.is_err());
+        assert_eq!(user_version(&conn).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_migration_progress() {
+        use std::rc::Rc;
+
+        let conn = open_in_memory().unwrap();
+        migrate(&conn, 1, |_| Ok(())).unwrap();
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let record = seen.clone();
+        with_migration_progress(
+            move |p| record.borrow_mut().push((p.version, p.step, p.steps)),
+            || migrate(&conn, 3, |_| Ok(())),
+        )
+        .unwrap();
+        assert_eq!(*seen.borrow(), vec![(2, 1, 2), (3, 2, 2)]);
+
+        // Only reported inside the call
+        migrate(&conn, 4, |_| Ok(())).unwrap();
+        assert_eq!(seen.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_maintenance_schedule() {
+        let conn = open_in_memory().unwrap();
+        // First call only records the time
+        assert!(!maintain_if_due(&conn, MAINTENANCE_INTERVAL).unwrap());
+        assert!(!maintain_if_due(&conn, MAINTENANCE_INTERVAL).unwrap());
+        conn.execute("UPDATE db_maintenance SET value = 0 WHERE key = 'last_vacuum'", []).unwrap();
+        assert!(maintain_if_due(&conn, MAINTENANCE_INTERVAL).unwrap());
+    }
+}
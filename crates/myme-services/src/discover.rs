@@ -0,0 +1,192 @@
+//! Discovery feed from starred repositories.
+//!
+//! Built only from the starred listing and each repo's releases: releases
+//! published lately, then starred repos with recent pushes ranked by stars.
+//! GitHub has no trending API, so "trending" means busy among what you follow.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use crate::github::{GitHubRelease, GitHubRepo};
+
+/// Releases published within this many days appear in the feed
+pub const RELEASE_WINDOW_DAYS: i64 = 30;
+/// Starred repos pushed to within this many days count as active
+pub const ACTIVE_WINDOW_DAYS: i64 = 7;
+/// Active repos listed after the releases
+pub const ACTIVE_LIMIT: usize = 10;
+/// Starred repos checked for releases, bounding the API calls per refresh
+pub const RELEASE_CHECK_LIMIT: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiscoverKind {
+    /// A starred repo published a release
+    Release,
+    /// A starred repo is being pushed to
+    Active,
+}
+
+/// One entry of the discovery feed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiscoverItem {
+    pub kind: DiscoverKind,
+    /// `owner/repo`
+    pub repo: String,
+    /// Release name (or tag) for releases, the repo description otherwise
+    pub title: String,
+    pub url: String,
+    /// When the release was published or the repo last pushed to
+    pub at: DateTime<Utc>,
+    pub stars: i64,
+}
+
+fn parse_time(value: Option<&str>) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value?).ok().map(|t| t.with_timezone(&Utc))
+}
+
+/// Starred repos worth checking for releases: the most recently pushed ones,
+/// up to `RELEASE_CHECK_LIMIT`
+pub fn release_candidates(starred: &[GitHubRepo]) -> Vec<&GitHubRepo> {
+    let mut repos: Vec<&GitHubRepo> = starred.iter().collect();
+    repos.sort_by_key(|r| std::cmp::Reverse(parse_time(r.pushed_at.as_deref())));
+    repos.truncate(RELEASE_CHECK_LIMIT);
+    repos
+}
+
+/// Build the feed from `starred` and the releases fetched per repo (keyed by
+/// full name): recent stable releases newest first, then active repos
+/// without a release by stars.
+pub fn build_feed(
+    starred: &[GitHubRepo],
+    releases: &[(String, Vec<GitHubRelease>)],
+    now: DateTime<Utc>,
+) -> Vec<DiscoverItem> {
+    let stars = |full_name: &str| {
+        starred.iter().find(|r| r.full_name == full_name).map_or(0, |r| r.stargazers_count)
+    };
+
+    let release_since = now - Duration::days(RELEASE_WINDOW_DAYS);
+    let mut feed: Vec<DiscoverItem> = releases
+        .iter()
+        .filter_map(|(full_name, releases)| {
+            releases
+                .iter()
+                .filter(|r| !r.draft && !r.prerelease)
+                .filter_map(|r| Some((r, parse_time(r.published_at.as_deref())?)))
+                .filter(|(_, at)| *at >= release_since)
+                .max_by_key(|(_, at)| *at)
+                .map(|(release, at)| DiscoverItem {
+                    kind: DiscoverKind::Release,
+                    repo: full_name.clone(),
+                    title: release
+                        .name
+                        .clone()
+                        .filter(|n| !n.trim().is_empty())
+                        .unwrap_or_else(|| release.tag_name.clone()),
+                    url: release.html_url.clone(),
+                    at,
+                    stars: stars(full_name),
+                })
+        })
+        .collect();
+    feed.sort_by_key(|item| std::cmp::Reverse(item.at));
+
+    let active_since = now - Duration::days(ACTIVE_WINDOW_DAYS);
+    let mut active: Vec<DiscoverItem> = starred
+        .iter()
+        .filter(|r| !feed.iter().any(|item| item.repo == r.full_name))
+        .filter_map(|r| {
+            let at = parse_time(r.pushed_at.as_deref()).filter(|at| *at >= active_since)?;
+            Some(DiscoverItem {
+                kind: DiscoverKind::Active,
+                repo: r.full_name.clone(),
+                title: r.description.clone().unwrap_or_default(),
+                url: r.html_url.clone(),
+                at,
+                stars: r.stargazers_count,
+            })
+        })
+        .collect();
+    active.sort_by_key(|item| std::cmp::Reverse(item.stars));
+    active.truncate(ACTIVE_LIMIT);
+
+    feed.extend(active);
+    feed
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn repo(full_name: &str, stars: i64, pushed_days_ago: i64, now: DateTime<Utc>) -> GitHubRepo {
+        serde_json::from_value(serde_json::json!({
+            "id": stars,
+            "name": full_name.split('/').nth(1).unwrap(),
+            "full_name": full_name,
+            "description": format!("About {}", full_name),
+            "html_url": format!("https://github.com/{}", full_name),
+            "private": false,
+            "default_branch": "main",
+            "updated_at": now.to_rfc3339(),
+            "pushed_at": (now - Duration::days(pushed_days_ago)).to_rfc3339(),
+            "stargazers_count": stars,
+        }))
+        .unwrap()
+    }
+
+    fn release(tag: &str, days_ago: i64, prerelease: bool, now: DateTime<Utc>) -> GitHubRelease {
+        let published = (now - Duration::days(days_ago)).to_rfc3339();
+        GitHubRelease {
+            id: days_ago,
+            tag_name: tag.to_string(),
+            name: None,
+            body: None,
+            draft: false,
+            prerelease,
+            html_url: format!("https://github.com/x/releases/{}", tag),
+            created_at: published.clone(),
+            published_at: Some(published),
+        }
+    }
+
+    #[test]
+    fn feed_lists_recent_releases_then_active_repos() {
+        let now = Utc::now();
+        let starred = vec![
+            repo("a/lib", 50, 1, now),
+            repo("b/app", 900, 2, now),
+            repo("c/old", 5000, 60, now),
+            repo("d/busy", 10, 0, now),
+        ];
+        let releases = vec![
+            (
+                "a/lib".to_string(),
+                vec![release("v2.0.0-rc1", 1, true, now), release("v1.9.0", 3, false, now)],
+            ),
+            ("b/app".to_string(), vec![release("v0.1.0", 90, false, now)]),
+            ("c/old".to_string(), vec![release("v10.0.0", 10, false, now)]),
+        ];
+
+        let feed = build_feed(&starred, &releases, now);
+        let summary: Vec<(DiscoverKind, &str, &str)> =
+            feed.iter().map(|i| (i.kind, i.repo.as_str(), i.title.as_str())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                // Prereleases and releases older than the window are skipped
+                (DiscoverKind::Release, "a/lib", "v1.9.0"),
+                (DiscoverKind::Release, "c/old", "v10.0.0"),
+                // Active repos by stars; c/old has gone quiet
+                (DiscoverKind::Active, "b/app", "About b/app"),
+                (DiscoverKind::Active, "d/busy", "About d/busy"),
+            ]
+        );
+        assert_eq!(feed[1].stars, 5000);
+
+        let candidates: Vec<&str> =
+            release_candidates(&starred).iter().map(|r| r.full_name.as_str()).collect();
+        assert_eq!(candidates, vec!["d/busy", "a/lib", "b/app", "c/old"]);
+    }
+}
@@ -1,12 +1,20 @@
 // crates/myme-services/src/github.rs
 
 use anyhow::{Context, Result};
+use myme_core::http_log::{execute_observed, HttpObserver};
 use reqwest::{header, Client, Response};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
+use std::time::Duration;
 use url::Url;
 
-use crate::retry::{is_retryable_status, with_retry, RetryConfig, RetryDecision};
+use crate::github_graphql;
+use crate::project::TaskStatus;
+use crate::retry::{
+    is_retryable_status, with_retry, RetryConfig, RetryDecision, DEFAULT_TIMEOUT_SECS,
+};
+use crate::workflow_dispatch::{parse_dispatch_inputs, WorkflowInput};
 
 const GITHUB_API_URL: &str = "https://api.github.com";
 
@@ -29,6 +37,27 @@ pub struct GitHubRepo {
     #[serde(default)]
     pub open_issues_count: i32,
     pub updated_at: String,
+    /// Last push to any branch; unlike `updated_at` this ignores stars and metadata edits
+    #[serde(default)]
+    pub pushed_at: Option<String>,
+    /// Whether this repo is a fork of another
+    #[serde(default)]
+    pub fork: bool,
+    #[serde(default)]
+    pub stargazers_count: i64,
+    #[serde(default)]
+    pub forks_count: i64,
+    /// Repo this one was forked from; only filled in by [`GitHubClient::get_repo`]
+    #[serde(default)]
+    pub parent: Option<ParentRepo>,
+}
+
+/// The repository a fork was made from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParentRepo {
+    pub full_name: String,
+    #[serde(default)]
+    pub clone_url: Option<String>,
 }
 
 /// GitHub issue representation
@@ -43,6 +72,30 @@ pub struct GitHubIssue {
     pub labels: Vec<GitHubLabel>,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub closed_at: Option<String>,
+    /// Set when this "issue" is a pull request
+    #[serde(default)]
+    pub pull_request: Option<IssuePullRequest>,
+    #[serde(default)]
+    pub assignees: Vec<IssueAssignee>,
+    /// Number of comments
+    #[serde(default)]
+    pub comments: i32,
+}
+
+/// User an issue is assigned to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueAssignee {
+    pub login: String,
+}
+
+/// Pull request details carried on an issue from the issues API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuePullRequest {
+    /// None for pull requests closed without merging
+    #[serde(default)]
+    pub merged_at: Option<String>,
 }
 
 /// GitHub label
@@ -54,7 +107,7 @@ pub struct GitHubLabel {
 }
 
 /// Request to create a new repo
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct CreateRepoRequest {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -62,6 +115,32 @@ pub struct CreateRepoRequest {
     pub private: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_init: Option<bool>,
+    /// `.gitignore` template name, e.g. "Rust" (ignored when generating from a template)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gitignore_template: Option<String>,
+    /// License keyword, e.g. "mit" (ignored when generating from a template)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_template: Option<String>,
+    /// Organization to create the repo in; `None` creates it for the authenticated user
+    #[serde(skip)]
+    pub org: Option<String>,
+    /// Template repository ("owner/repo") to generate the new repo from
+    #[serde(skip)]
+    pub template: Option<String>,
+    /// Topics to set once the repo exists
+    #[serde(skip)]
+    pub topics: Vec<String>,
+}
+
+/// Body for `POST /repos/{template}/generate`
+#[derive(Debug, Serialize)]
+struct GenerateRepoRequest<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    private: bool,
 }
 
 /// Request to create a new issue
@@ -116,20 +195,577 @@ pub struct ListWorkflowsResponse {
     pub workflows: Vec<GitHubWorkflow>,
 }
 
+/// Markdown issue template from `.github/ISSUE_TEMPLATE`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueTemplate {
+    pub name: String,
+    pub about: String,
+    /// Default issue title (often a prefix like "[Bug] ")
+    pub title: String,
+    pub labels: Vec<String>,
+    pub body: String,
+}
+
+impl IssueTemplate {
+    /// Parse a template file's YAML front matter and body. Only the flat
+    /// `key: value` fields GitHub documents for markdown templates are read;
+    /// files without front matter use the file stem as the name.
+    pub fn parse(file_name: &str, text: &str) -> Self {
+        let stem = file_name.rsplit_once('.').map_or(file_name, |(stem, _)| stem);
+        let mut template = IssueTemplate {
+            name: stem.to_string(),
+            about: String::new(),
+            title: String::new(),
+            labels: Vec::new(),
+            body: text.trim().to_string(),
+        };
+
+        let text = text.trim_start_matches('\u{feff}');
+        let Some(rest) = text.strip_prefix("---") else {
+            return template;
+        };
+        let Some((front, body)) = rest.split_once("\n---") else {
+            return template;
+        };
+        template.body = body.split_once('\n').map_or("", |(_, b)| b).trim().to_string();
+
+        let mut in_labels = false;
+        for line in front.lines() {
+            // Block list continuation under `labels:`
+            if in_labels {
+                if let Some(item) = line.trim().strip_prefix("- ") {
+                    template.labels.push(unquote(item).to_string());
+                    continue;
+                }
+                in_labels = false;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "name" => template.name = unquote(value).to_string(),
+                "about" => template.about = unquote(value).to_string(),
+                "title" => template.title = unquote(value).to_string(),
+                "labels" if value.is_empty() => in_labels = true,
+                "labels" => {
+                    template.labels = value
+                        .trim_start_matches('[')
+                        .trim_end_matches(']')
+                        .split(',')
+                        .map(|l| unquote(l.trim()).to_string())
+                        .filter(|l| !l.is_empty())
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+        template
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim_matches(|c| c == '"' || c == '\'')
+}
+
+/// Entry from GET /repos/{owner}/{repo}/contents/{dir}
+#[derive(Debug, Deserialize)]
+struct ContentEntry {
+    name: String,
+    path: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+/// Notification thread from GET /notifications
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubNotification {
+    /// Thread id, used to mark read or unsubscribe
+    pub id: String,
+    pub unread: bool,
+    /// Why it was delivered: review_requested, mention, ci_activity, assign, ...
+    pub reason: String,
+    pub updated_at: String,
+    #[serde(default)]
+    pub last_read_at: Option<String>,
+    pub subject: NotificationSubject,
+    pub repository: NotificationRepository,
+}
+
+/// What a notification is about
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationSubject {
+    pub title: String,
+    /// API URL of the issue/PR/release; absent for CI notifications
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub latest_comment_url: Option<String>,
+    /// Issue, PullRequest, CheckSuite, Release, Discussion, ...
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+/// Repository a notification belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationRepository {
+    pub full_name: String,
+    #[serde(default)]
+    pub html_url: Option<String>,
+}
+
+impl GitHubNotification {
+    /// Browser URL for the subject, falling back to the repository (or its
+    /// Actions tab for CI notifications) when there is no subject URL.
+    pub fn html_url(&self) -> String {
+        let repo_url = self
+            .repository
+            .html_url
+            .clone()
+            .unwrap_or_else(|| format!("https://github.com/{}", self.repository.full_name));
+        match &self.subject.url {
+            Some(api_url) => api_url
+                .replacen("https://api.github.com/repos/", "https://github.com/", 1)
+                .replacen("/pulls/", "/pull/", 1),
+            None if self.subject.kind == "CheckSuite" => format!("{}/actions", repo_url),
+            None => repo_url,
+        }
+    }
+}
+
+/// Organization the user belongs to, from GET /user/orgs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubOrg {
+    pub id: i64,
+    pub login: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Team the user is on, from GET /user/teams
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubTeam {
+    pub id: i64,
+    pub slug: String,
+    pub name: String,
+    pub organization: GitHubOrg,
+}
+
+/// Where repositories are listed from besides the user's own: a whole
+/// organization (`org`) or one of its teams (`org/team-slug`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RepoScope {
+    Org(String),
+    Team { org: String, team: String },
+}
+
+impl RepoScope {
+    /// Parse `org` or `org/team-slug`; None for blank or malformed entries
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        match value.split_once('/') {
+            None if !value.is_empty() => Some(Self::Org(value.to_string())),
+            Some((org, team)) if !org.is_empty() && !team.is_empty() && !team.contains('/') => {
+                Some(Self::Team { org: org.to_string(), team: team.to_string() })
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse config entries, skipping invalid ones and duplicates
+    pub fn parse_all<S: AsRef<str>>(values: &[S]) -> Vec<Self> {
+        let mut scopes: Vec<Self> = Vec::new();
+        for scope in values.iter().filter_map(|v| Self::parse(v.as_ref())) {
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+        scopes
+    }
+
+    pub fn org(&self) -> &str {
+        match self {
+            Self::Org(org) | Self::Team { org, .. } => org,
+        }
+    }
+}
+
+impl std::fmt::Display for RepoScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Org(org) => write!(f, "{}", org),
+            Self::Team { org, team } => write!(f, "{}/{}", org, team),
+        }
+    }
+}
+
+/// Concatenate repo listings, keeping the first copy of a repo that appears
+/// in more than one, newest update first
+pub fn merge_repo_listings(listings: Vec<Vec<GitHubRepo>>) -> Vec<GitHubRepo> {
+    let mut seen = std::collections::HashSet::new();
+    let mut merged: Vec<GitHubRepo> =
+        listings.into_iter().flatten().filter(|repo| seen.insert(repo.id)).collect();
+    // RFC 3339 timestamps from GitHub sort as strings
+    merged.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    merged
+}
+
+/// Repository collaborator from GET /repos/{owner}/{repo}/collaborators
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubCollaborator {
+    pub id: i64,
+    pub login: String,
+    pub avatar_url: String,
+    pub html_url: String,
+}
+
+/// One day of repository traffic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrafficPoint {
+    pub timestamp: String,
+    pub count: u64,
+    pub uniques: u64,
+}
+
+/// Views or clones over the last 14 days, from /repos/{owner}/{repo}/traffic/*
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrafficSummary {
+    pub count: u64,
+    pub uniques: u64,
+    /// Daily breakdown, oldest first
+    #[serde(default, alias = "views", alias = "clones")]
+    pub points: Vec<TrafficPoint>,
+}
+
+/// Repository traffic (requires push access to the repo)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepoTraffic {
+    pub views: TrafficSummary,
+    pub clones: TrafficSummary,
+}
+
+/// Contributor from GET /repos/{owner}/{repo}/contributors
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubContributor {
+    pub id: i64,
+    pub login: String,
+    pub avatar_url: String,
+    pub html_url: String,
+    pub contributions: u64,
+}
+
+/// A file the community profile checks for; `None` when missing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityFile {
+    #[serde(default)]
+    pub html_url: Option<String>,
+}
+
+/// Recommended community files and whether the repo has them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommunityFiles {
+    #[serde(default)]
+    pub readme: Option<CommunityFile>,
+    #[serde(default)]
+    pub license: Option<CommunityFile>,
+    #[serde(default)]
+    pub contributing: Option<CommunityFile>,
+    #[serde(default)]
+    pub code_of_conduct: Option<CommunityFile>,
+    #[serde(default)]
+    pub issue_template: Option<CommunityFile>,
+    #[serde(default)]
+    pub pull_request_template: Option<CommunityFile>,
+}
+
+/// Community profile from GET /repos/{owner}/{repo}/community/profile
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityProfile {
+    /// 0-100, how many of the recommended files the repo has
+    pub health_percentage: u32,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub documentation: Option<String>,
+    #[serde(default)]
+    pub files: CommunityFiles,
+}
+
+impl CommunityProfile {
+    /// Names of the recommended files the repo lacks
+    pub fn missing_files(&self) -> Vec<&'static str> {
+        let files = &self.files;
+        [
+            ("README", &files.readme),
+            ("License", &files.license),
+            ("Contributing guide", &files.contributing),
+            ("Code of conduct", &files.code_of_conduct),
+            ("Issue template", &files.issue_template),
+            ("Pull request template", &files.pull_request_template),
+        ]
+        .into_iter()
+        .filter(|(_, file)| file.is_none())
+        .map(|(name, _)| name)
+        .collect()
+    }
+}
+
+/// Share of a repository's code in one language
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageShare {
+    pub name: String,
+    /// 0-100
+    pub percent: f64,
+}
+
+/// README and metadata shown when previewing a repository
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepoPreview {
+    /// Markdown source; None when the repo has no README
+    pub readme: Option<String>,
+    pub topics: Vec<String>,
+    /// Largest first
+    pub languages: Vec<LanguageShare>,
+}
+
+impl RepoPreview {
+    /// Turn GET /repos/{owner}/{repo}/languages byte counts into shares, largest first
+    pub fn language_shares(bytes: &BTreeMap<String, u64>) -> Vec<LanguageShare> {
+        let total: u64 = bytes.values().sum();
+        if total == 0 {
+            return Vec::new();
+        }
+        let mut shares: Vec<LanguageShare> = bytes
+            .iter()
+            .map(|(name, &count)| LanguageShare {
+                name: name.clone(),
+                percent: count as f64 * 100.0 / total as f64,
+            })
+            .collect();
+        shares.sort_by(|a, b| b.percent.total_cmp(&a.percent));
+        shares
+    }
+}
+
+/// Release from the /releases endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubRelease {
+    pub id: i64,
+    pub tag_name: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub html_url: String,
+    pub created_at: String,
+    /// None for drafts
+    #[serde(default)]
+    pub published_at: Option<String>,
+}
+
+/// Request to create a release. The tag is created from `target_commitish`
+/// (default branch when unset) if it doesn't exist yet.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateReleaseRequest {
+    pub tag_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_commitish: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+}
+
+/// Tag from GET /repos/{owner}/{repo}/tags
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubTag {
+    pub name: String,
+    pub commit: TagCommit,
+}
+
+/// Commit a tag points at
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagCommit {
+    pub sha: String,
+}
+
+/// Open Dependabot alert from GET /repos/{owner}/{repo}/dependabot/alerts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependabotAlert {
+    pub number: i64,
+    pub state: String,
+    pub html_url: String,
+    pub security_advisory: SecurityAdvisory,
+    #[serde(default)]
+    pub dependency: Option<AlertDependency>,
+}
+
+impl DependabotAlert {
+    /// `critical`, `high`, `medium` or `low`
+    pub fn severity(&self) -> &str {
+        &self.security_advisory.severity
+    }
+}
+
+/// Advisory behind a Dependabot alert
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityAdvisory {
+    pub summary: String,
+    pub severity: String,
+}
+
+/// Vulnerable dependency of a Dependabot alert
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertDependency {
+    #[serde(default)]
+    pub manifest_path: Option<String>,
+    pub package: AlertPackage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertPackage {
+    pub ecosystem: String,
+    pub name: String,
+}
+
+/// Open code scanning alert from GET /repos/{owner}/{repo}/code-scanning/alerts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningAlert {
+    pub number: i64,
+    pub state: String,
+    pub html_url: String,
+    pub rule: CodeScanningRule,
+}
+
+impl CodeScanningAlert {
+    /// `critical`, `high`, `medium` or `low`. Rules without a security
+    /// severity (plain lint findings) map `error` to high, `warning` to
+    /// medium and anything else to low.
+    pub fn severity(&self) -> &str {
+        if let Some(level) = self.rule.security_severity_level.as_deref() {
+            return level;
+        }
+        match self.rule.severity.as_deref() {
+            Some("error") => "high",
+            Some("warning") => "medium",
+            _ => "low",
+        }
+    }
+}
+
+/// Rule that raised a code scanning alert
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeScanningRule {
+    pub id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// `none`, `note`, `warning` or `error`
+    #[serde(default)]
+    pub severity: Option<String>,
+    /// Set for security rules: `low`, `medium`, `high` or `critical`
+    #[serde(default)]
+    pub security_severity_level: Option<String>,
+}
+
+/// Gist from the /gists endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitHubGist {
+    pub id: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Secret gists are unlisted but readable by anyone with the URL
+    pub public: bool,
+    pub html_url: String,
+    /// Files by name. Listings omit `content`; fetch `raw_url` for it.
+    #[serde(default)]
+    pub files: BTreeMap<String, GistFile>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// One file of a gist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GistFile {
+    pub filename: String,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub raw_url: Option<String>,
+    #[serde(default)]
+    pub content: Option<String>,
+}
+
+/// File body for creating or updating a gist
+#[derive(Debug, Clone, Serialize)]
+pub struct GistFileContent {
+    pub content: String,
+}
+
+/// Request to create a gist
+#[derive(Debug, Serialize)]
+pub struct CreateGistRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub public: bool,
+    pub files: BTreeMap<String, GistFileContent>,
+}
+
+/// Request to update a gist. Files not listed are left alone; a `None`
+/// entry deletes that file.
+#[derive(Debug, Default, Serialize)]
+pub struct UpdateGistRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub files: BTreeMap<String, Option<GistFileContent>>,
+}
+
 /// GitHub API client
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct GitHubClient {
     base_url: Url,
     client: Arc<Client>,
     token: String,
     retry_config: RetryConfig,
+    observer: Option<HttpObserver>,
+    /// Batch issue listings into GraphQL queries
+    graphql: bool,
+}
+
+impl std::fmt::Debug for GitHubClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GitHubClient")
+            .field("base_url", &self.base_url)
+            .field("retry_config", &self.retry_config)
+            .field("graphql", &self.graphql)
+            .finish_non_exhaustive()
+    }
 }
 
 impl GitHubClient {
     /// Create a new GitHub client with OAuth token
     pub fn new(token: String) -> Result<Self> {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
+        Self::new_with_proxies(token, Vec::new())
+    }
+
+    /// Create a new GitHub client that sends requests through `proxies`
+    pub fn new_with_proxies(token: String, proxies: Vec<reqwest::Proxy>) -> Result<Self> {
+        Self::new_with_timeout(token, proxies, Duration::from_secs(DEFAULT_TIMEOUT_SECS))
+    }
+
+    /// Like [`Self::new_with_proxies`], abandoning requests after `timeout`
+    pub fn new_with_timeout(
+        token: String,
+        proxies: Vec<reqwest::Proxy>,
+        timeout: Duration,
+    ) -> Result<Self> {
+        let client = proxies
+            .into_iter()
+            .fold(Client::builder(), reqwest::ClientBuilder::proxy)
+            .timeout(timeout)
             .build()
             .context("Failed to create HTTP client")?;
 
@@ -138,23 +774,97 @@ impl GitHubClient {
             client: Arc::new(client),
             token,
             retry_config: RetryConfig::default(),
+            observer: None,
+            graphql: false,
         })
     }
 
+    /// Point the client at a different API root (GitHub Enterprise, tests)
+    pub fn with_base_url(mut self, base_url: &str) -> Result<Self> {
+        let base =
+            if base_url.ends_with('/') { base_url.to_string() } else { format!("{}/", base_url) };
+        self.base_url = Url::parse(&base).context("Invalid GitHub API base URL")?;
+        Ok(self)
+    }
+
     /// Set custom retry configuration
     pub fn with_retry_config(mut self, config: RetryConfig) -> Self {
         self.retry_config = config;
         self
     }
 
+    /// Fetch issues for several repos per request over GraphQL in
+    /// [`Self::list_issues_for_repos`], falling back to REST when it fails
+    pub fn with_graphql(mut self, enabled: bool) -> Self {
+        self.graphql = enabled;
+        self
+    }
+
+    /// Report every request to `observer` (e.g. the HTTP debug capture).
+    pub fn with_observer(mut self, observer: Option<HttpObserver>) -> Self {
+        self.observer = observer;
+        self
+    }
+
     /// Build request with auth headers
     fn build_request(&self, req: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        self.build_request_accepting(req, "application/vnd.github+json")
+    }
+
+    /// Build request with auth headers and a specific media type
+    fn build_request_accepting(
+        &self,
+        req: reqwest::RequestBuilder,
+        accept: &str,
+    ) -> reqwest::RequestBuilder {
         req.header(header::AUTHORIZATION, format!("Bearer {}", self.token))
-            .header(header::ACCEPT, "application/vnd.github+json")
+            .header(header::ACCEPT, accept)
             .header(header::USER_AGENT, "myme-app")
             .header("X-GitHub-Api-Version", "2022-11-28")
     }
 
+    /// Send a request, reporting it to the observer if one is set.
+    async fn send(&self, request: reqwest::RequestBuilder) -> reqwest::Result<Response> {
+        execute_observed(&self.client, request.build()?, self.observer.as_ref()).await
+    }
+
+    /// Like `send_with_retry`, but a 404 yields `Ok(None)` instead of an error.
+    async fn send_optional<F>(&self, build_request: F) -> Result<Option<Response>>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let response =
+            with_retry(self.retry_config.clone(), || async { self.send(build_request()).await })
+                .await
+                .context("Failed to send request after retries")?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        Self::check_status(response).await.map(Some)
+    }
+
+    /// Like `send_optional`, but a 403 also yields `Ok(None)`: GitHub answers
+    /// 403 when a security feature is disabled for the repo or the token
+    /// lacks access to it.
+    async fn send_unless_disabled<F>(&self, build_request: F) -> Result<Option<Response>>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let response =
+            with_retry(self.retry_config.clone(), || async { self.send(build_request()).await })
+                .await
+                .context("Failed to send request after retries")?;
+
+        if matches!(
+            response.status(),
+            reqwest::StatusCode::FORBIDDEN | reqwest::StatusCode::NOT_FOUND
+        ) {
+            return Ok(None);
+        }
+        Self::check_status(response).await.map(Some)
+    }
+
     /// Send a request with retry logic for transient failures.
     ///
     /// This wraps the request with exponential backoff retry for:
@@ -169,10 +879,14 @@ impl GitHubClient {
         F: Fn() -> reqwest::RequestBuilder,
     {
         let response =
-            with_retry(self.retry_config.clone(), || async { build_request().send().await })
+            with_retry(self.retry_config.clone(), || async { self.send(build_request()).await })
                 .await
                 .context("Failed to send request after retries")?;
 
+        Self::check_status(response).await
+    }
+
+    async fn check_status(response: Response) -> Result<Response> {
         let status = response.status();
 
         // Check for non-retryable error status codes (4xx except rate limit)
@@ -204,6 +918,123 @@ impl GitHubClient {
         Ok(repos)
     }
 
+    /// Organizations the authenticated user is a member of
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn list_user_orgs(&self) -> Result<Vec<GitHubOrg>> {
+        let url = self.base_url.join("user/orgs")?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.get(url.clone()).query(&[("per_page", "100")]))
+            })
+            .await?;
+
+        let orgs: Vec<GitHubOrg> = response.json().await?;
+        tracing::info!("Fetched {} organizations", orgs.len());
+        Ok(orgs)
+    }
+
+    /// Teams the authenticated user is on, across all organizations
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn list_user_teams(&self) -> Result<Vec<GitHubTeam>> {
+        let url = self.base_url.join("user/teams")?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.get(url.clone()).query(&[("per_page", "100")]))
+            })
+            .await?;
+
+        let teams: Vec<GitHubTeam> = response.json().await?;
+        tracing::info!("Fetched {} teams", teams.len());
+        Ok(teams)
+    }
+
+    /// Repositories of an organization the user can see
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn list_org_repos(&self, org: &str) -> Result<Vec<GitHubRepo>> {
+        let url = self.base_url.join(&format!("orgs/{}/repos", org))?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.get(url.clone()).query(&[
+                    ("type", "all"),
+                    ("sort", "updated"),
+                    ("per_page", "100"),
+                ]))
+            })
+            .await?;
+
+        let repos: Vec<GitHubRepo> = response.json().await?;
+        tracing::info!("Fetched {} repositories of {}", repos.len(), org);
+        Ok(repos)
+    }
+
+    /// Repositories a team has access to
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn list_team_repos(&self, org: &str, team_slug: &str) -> Result<Vec<GitHubRepo>> {
+        let url = self.base_url.join(&format!("orgs/{}/teams/{}/repos", org, team_slug))?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.get(url.clone()).query(&[("per_page", "100")]))
+            })
+            .await?;
+
+        let repos: Vec<GitHubRepo> = response.json().await?;
+        tracing::info!("Fetched {} repositories of {}/{}", repos.len(), org, team_slug);
+        Ok(repos)
+    }
+
+    /// The user's repositories plus those of each scope, without duplicates.
+    /// With no scopes this is just [`Self::list_repos`].
+    pub async fn list_scoped_repos(&self, scopes: &[RepoScope]) -> Result<Vec<GitHubRepo>> {
+        let mut listings = vec![self.list_repos().await?];
+        for scope in scopes {
+            let repos = match scope {
+                RepoScope::Org(org) => self.list_org_repos(org).await,
+                RepoScope::Team { org, team } => self.list_team_repos(org, team).await,
+            }
+            .with_context(|| format!("Failed to list repositories of {}", scope))?;
+            listings.push(repos);
+        }
+        Ok(merge_repo_listings(listings))
+    }
+
+    /// Repositories the authenticated user has starred, most recently starred first
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn list_starred(&self) -> Result<Vec<GitHubRepo>> {
+        let url = self.base_url.join("user/starred")?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(
+                    self.client.get(url.clone()).query(&[("sort", "created"), ("per_page", "100")]),
+                )
+            })
+            .await?;
+
+        let repos: Vec<GitHubRepo> = response.json().await?;
+        tracing::info!("Fetched {} starred repositories", repos.len());
+        Ok(repos)
+    }
+
+    /// Star a repository
+    pub async fn star_repo(&self, owner: &str, repo: &str) -> Result<()> {
+        tracing::debug!("Starring {}/{}", owner, repo);
+
+        let url = self.base_url.join(&format!("user/starred/{}/{}", owner, repo))?;
+        self.send_with_retry(|| {
+            self.build_request(self.client.put(url.clone()).header(header::CONTENT_LENGTH, 0))
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Remove the star from a repository
+    pub async fn unstar_repo(&self, owner: &str, repo: &str) -> Result<()> {
+        tracing::debug!("Unstarring {}/{}", owner, repo);
+
+        let url = self.base_url.join(&format!("user/starred/{}/{}", owner, repo))?;
+        self.send_with_retry(|| self.build_request(self.client.delete(url.clone()))).await?;
+        Ok(())
+    }
+
     /// Get a specific repository
     #[tracing::instrument(skip(self), level = "debug")]
     pub async fn get_repo(&self, owner: &str, repo: &str) -> Result<GitHubRepo> {
@@ -217,13 +1048,37 @@ impl GitHubClient {
         Ok(repo)
     }
 
-    /// Create a new repository
+    /// Create a new repository.
+    ///
+    /// Generates from `req.template` when set, otherwise creates an empty repo
+    /// (optionally seeded with a README, `.gitignore` and license) under
+    /// `req.org` or the authenticated user. Topics are applied afterwards.
     #[tracing::instrument(skip(self, req), fields(repo_name = %req.name), level = "info")]
     pub async fn create_repo(&self, req: CreateRepoRequest) -> Result<GitHubRepo> {
         tracing::debug!("Creating repository: {}", req.name);
 
-        let url = self.base_url.join("user/repos")?;
-        let request_json = serde_json::to_value(&req).context("Failed to serialize request")?;
+        let (url, request_json) = match (&req.template, &req.org) {
+            (Some(template), _) => {
+                let body = GenerateRepoRequest {
+                    name: &req.name,
+                    owner: req.org.as_deref(),
+                    description: req.description.as_deref(),
+                    private: req.private,
+                };
+                (
+                    self.base_url.join(&format!("repos/{}/generate", template))?,
+                    serde_json::to_value(&body).context("Failed to serialize request")?,
+                )
+            }
+            (None, Some(org)) => (
+                self.base_url.join(&format!("orgs/{}/repos", org))?,
+                serde_json::to_value(&req).context("Failed to serialize request")?,
+            ),
+            (None, None) => (
+                self.base_url.join("user/repos")?,
+                serde_json::to_value(&req).context("Failed to serialize request")?,
+            ),
+        };
 
         let response = self
             .send_with_retry(|| {
@@ -234,9 +1089,27 @@ impl GitHubClient {
         let repo: GitHubRepo = response.json().await?;
 
         tracing::info!("Created repository: {}", repo.full_name);
+
+        if !req.topics.is_empty() {
+            let (owner, name) = repo
+                .full_name
+                .split_once('/')
+                .ok_or_else(|| anyhow::anyhow!("Unexpected repo name: {}", repo.full_name))?;
+            self.set_topics(owner, name, &req.topics).await?;
+        }
         Ok(repo)
     }
 
+    /// Replace a repository's topics
+    #[tracing::instrument(skip(self, topics), level = "debug")]
+    pub async fn set_topics(&self, owner: &str, repo: &str, topics: &[String]) -> Result<()> {
+        let url = self.base_url.join(&format!("repos/{}/{}/topics", owner, repo))?;
+        let body = serde_json::json!({ "names": topics });
+        self.send_with_retry(|| self.build_request(self.client.put(url.clone()).json(&body)))
+            .await?;
+        Ok(())
+    }
+
     /// List issues for a repository
     #[tracing::instrument(skip(self), level = "info")]
     pub async fn list_issues(&self, owner: &str, repo: &str) -> Result<Vec<GitHubIssue>> {
@@ -257,6 +1130,55 @@ impl GitHubClient {
         Ok(issues)
     }
 
+    /// Issues (and pull requests) of each repo in `repo_ids` (`owner/repo`),
+    /// like [`Self::list_issues`] per repo. With GraphQL enabled, repos are
+    /// fetched several per request; a batch or repo GraphQL can't serve is
+    /// fetched over REST instead. Results are in the order of `repo_ids`.
+    pub async fn list_issues_for_repos(
+        &self,
+        repo_ids: &[String],
+    ) -> Vec<(String, Result<Vec<GitHubIssue>>)> {
+        let mut results = Vec::with_capacity(repo_ids.len());
+        for batch in repo_ids.chunks(if self.graphql { github_graphql::REPOS_PER_QUERY } else { 1 })
+        {
+            let mut fetched = if self.graphql {
+                self.graphql_issues(batch).await.unwrap_or_else(|e| {
+                    tracing::warn!("GraphQL issue fetch failed, using REST: {:#}", e);
+                    vec![None; batch.len()]
+                })
+            } else {
+                vec![None]
+            };
+            for (repo_id, issues) in batch.iter().zip(fetched.iter_mut()) {
+                let result = match issues.take() {
+                    Some(issues) => Ok(issues),
+                    None => match repo_id.split_once('/') {
+                        Some((owner, repo)) => self.list_issues(owner, repo).await,
+                        None => Err(anyhow::anyhow!("Not a repository name: {}", repo_id)),
+                    },
+                };
+                results.push((repo_id.clone(), result));
+            }
+        }
+        results
+    }
+
+    /// One GraphQL query for the issues of `repo_ids`; None for repos it
+    /// couldn't resolve
+    async fn graphql_issues(&self, repo_ids: &[String]) -> Result<Vec<Option<Vec<GitHubIssue>>>> {
+        let repos: Vec<(&str, &str)> =
+            repo_ids.iter().map(|id| id.split_once('/').unwrap_or((id.as_str(), ""))).collect();
+        let body = github_graphql::issues_query(&repos);
+        let url = self.base_url.join("graphql")?;
+        let response = self
+            .send_with_retry(|| self.build_request(self.client.post(url.clone()).json(&body)))
+            .await?;
+
+        let issues = github_graphql::parse_issues(response.json().await?, repo_ids.len())?;
+        tracing::info!("Fetched issues for {} repos in one GraphQL query", repo_ids.len());
+        Ok(issues)
+    }
+
     /// List issues updated since a timestamp
     pub async fn list_issues_since(
         &self,
@@ -321,16 +1243,31 @@ impl GitHubClient {
 
         let url =
             self.base_url.join(&format!("repos/{}/{}/issues/{}", owner, repo, issue_number))?;
-        let request_json = serde_json::to_value(&req).context("Failed to serialize request")?;
-
-        let response = self
-            .send_with_retry(|| {
-                self.build_request(self.client.patch(url.clone()).json(&request_json))
-            })
-            .await?;
-
+        let request_json = serde_json::to_value(&req).context("Failed to serialize request")?;
+
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.patch(url.clone()).json(&request_json))
+            })
+            .await?;
+
+        let issue: GitHubIssue = response.json().await?;
+
+        Ok(issue)
+    }
+
+    /// Fetch one issue
+    pub async fn get_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i32,
+    ) -> Result<GitHubIssue> {
+        let url =
+            self.base_url.join(&format!("repos/{}/{}/issues/{}", owner, repo, issue_number))?;
+        let response =
+            self.send_with_retry(|| self.build_request(self.client.get(url.clone()))).await?;
         let issue: GitHubIssue = response.json().await?;
-
         Ok(issue)
     }
 
@@ -389,6 +1326,129 @@ impl GitHubClient {
         Ok(labels)
     }
 
+    /// List collaborators for a repository (requires push access to the repo)
+    pub async fn list_collaborators(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<GitHubCollaborator>> {
+        tracing::debug!("Fetching collaborators for {}/{}", owner, repo);
+
+        let url = self.base_url.join(&format!("repos/{}/{}/collaborators", owner, repo))?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.get(url.clone()).query(&[("per_page", "100")]))
+            })
+            .await?;
+
+        let collaborators: Vec<GitHubCollaborator> = response.json().await?;
+        Ok(collaborators)
+    }
+
+    /// Views and clones for the last 14 days (requires push access to the repo)
+    #[tracing::instrument(skip(self), level = "debug")]
+    pub async fn get_repo_traffic(&self, owner: &str, repo: &str) -> Result<RepoTraffic> {
+        let mut traffic = RepoTraffic::default();
+        for (kind, summary) in [("views", &mut traffic.views), ("clones", &mut traffic.clones)] {
+            let url = self.base_url.join(&format!("repos/{}/{}/traffic/{}", owner, repo, kind))?;
+            let response =
+                self.send_with_retry(|| self.build_request(self.client.get(url.clone()))).await?;
+            *summary = response.json().await?;
+        }
+        Ok(traffic)
+    }
+
+    /// Contributors by commit count, highest first
+    pub async fn list_contributors(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<GitHubContributor>> {
+        tracing::debug!("Fetching contributors for {}/{}", owner, repo);
+
+        let url = self.base_url.join(&format!("repos/{}/{}/contributors", owner, repo))?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.get(url.clone()).query(&[("per_page", "100")]))
+            })
+            .await?;
+
+        // Empty repositories answer 204 with no body
+        if response.status() == reqwest::StatusCode::NO_CONTENT {
+            return Ok(Vec::new());
+        }
+        let contributors: Vec<GitHubContributor> = response.json().await?;
+        Ok(contributors)
+    }
+
+    /// Community health for a public repository; `None` where GitHub has no
+    /// profile (e.g. private repos)
+    pub async fn get_community_profile(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<CommunityProfile>> {
+        tracing::debug!("Fetching community profile for {}/{}", owner, repo);
+
+        let url = self.base_url.join(&format!("repos/{}/{}/community/profile", owner, repo))?;
+        let Some(response) =
+            self.send_optional(|| self.build_request(self.client.get(url.clone()))).await?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(response.json().await?))
+    }
+
+    /// README as markdown source; `None` when the repo has none
+    #[tracing::instrument(skip(self), level = "debug")]
+    pub async fn get_readme(&self, owner: &str, repo: &str) -> Result<Option<String>> {
+        let url = self.base_url.join(&format!("repos/{}/{}/readme", owner, repo))?;
+        let Some(response) = self
+            .send_optional(|| {
+                self.build_request_accepting(
+                    self.client.get(url.clone()),
+                    "application/vnd.github.raw+json",
+                )
+            })
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(Some(response.text().await?))
+    }
+
+    /// Topics set on a repository
+    pub async fn list_topics(&self, owner: &str, repo: &str) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct Topics {
+            names: Vec<String>,
+        }
+
+        let url = self.base_url.join(&format!("repos/{}/{}/topics", owner, repo))?;
+        let response =
+            self.send_with_retry(|| self.build_request(self.client.get(url.clone()))).await?;
+        let topics: Topics = response.json().await?;
+        Ok(topics.names)
+    }
+
+    /// Bytes of code per language
+    pub async fn list_languages(&self, owner: &str, repo: &str) -> Result<BTreeMap<String, u64>> {
+        let url = self.base_url.join(&format!("repos/{}/{}/languages", owner, repo))?;
+        let response =
+            self.send_with_retry(|| self.build_request(self.client.get(url.clone()))).await?;
+        Ok(response.json().await?)
+    }
+
+    /// README, topics and languages for previewing a repository
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn get_repo_preview(&self, owner: &str, repo: &str) -> Result<RepoPreview> {
+        Ok(RepoPreview {
+            readme: self.get_readme(owner, repo).await?,
+            topics: self.list_topics(owner, repo).await?,
+            languages: RepoPreview::language_shares(&self.list_languages(owner, repo).await?),
+        })
+    }
+
     /// Create a label
     pub async fn create_label(
         &self,
@@ -412,6 +1472,79 @@ impl GitHubClient {
         Ok(label)
     }
 
+    /// Create any missing status labels (`backlog`, `todo`, `in-progress`, ...)
+    /// on a repository. Returns the names that were created.
+    pub async fn ensure_status_labels(&self, owner: &str, repo: &str) -> Result<Vec<String>> {
+        let existing: Vec<String> = self
+            .list_labels(owner, repo)
+            .await?
+            .into_iter()
+            .map(|l| l.name.to_lowercase())
+            .collect();
+
+        let mut created = Vec::new();
+        for status in TaskStatus::all() {
+            let Some(name) = status.to_label() else {
+                continue;
+            };
+            if existing.iter().any(|l| l == name) {
+                continue;
+            }
+            let req = CreateLabelRequest {
+                name: name.to_string(),
+                color: status.label_color().to_string(),
+                description: Some("Kanban status (managed by MyMe)".to_string()),
+            };
+            self.create_label(owner, repo, req).await?;
+            created.push(name.to_string());
+        }
+
+        if !created.is_empty() {
+            tracing::info!("Created status labels {:?} in {}/{}", created, owner, repo);
+        }
+        Ok(created)
+    }
+
+    /// Read the markdown issue templates in `.github/ISSUE_TEMPLATE`.
+    /// Repos without templates return an empty list. YAML issue forms are
+    /// skipped since they have no single body to pre-fill.
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn get_issue_templates(&self, owner: &str, repo: &str) -> Result<Vec<IssueTemplate>> {
+        tracing::debug!("Fetching issue templates for {}/{}", owner, repo);
+
+        let url = self
+            .base_url
+            .join(&format!("repos/{}/{}/contents/.github/ISSUE_TEMPLATE", owner, repo))?;
+        let Some(response) =
+            self.send_optional(|| self.build_request(self.client.get(url.clone()))).await?
+        else {
+            return Ok(Vec::new());
+        };
+        let entries: Vec<ContentEntry> = response.json().await?;
+
+        let mut templates = Vec::new();
+        for entry in entries {
+            if entry.kind != "file" || !entry.name.to_lowercase().ends_with(".md") {
+                continue;
+            }
+            let file_url =
+                self.base_url.join(&format!("repos/{}/{}/contents/{}", owner, repo, entry.path))?;
+            let response = self
+                .send_with_retry(|| {
+                    self.build_request_accepting(
+                        self.client.get(file_url.clone()),
+                        "application/vnd.github.raw+json",
+                    )
+                })
+                .await?;
+            let text = response.text().await?;
+            templates.push(IssueTemplate::parse(&entry.name, &text));
+        }
+
+        tracing::info!("Fetched {} issue templates for {}/{}", templates.len(), owner, repo);
+        Ok(templates)
+    }
+
     /// Set labels on an issue (replaces existing)
     pub async fn set_issue_labels(
         &self,
@@ -461,6 +1594,262 @@ impl GitHubClient {
         tracing::info!("Fetched {} workflows for {}/{}", body.workflows.len(), owner, repo);
         Ok(body.workflows)
     }
+
+    /// Inputs a workflow takes when run by hand, read from its file (`path`
+    /// as listed by [`Self::list_workflows`]) on the default branch. `None`
+    /// when the file is gone or has no `workflow_dispatch` trigger.
+    #[tracing::instrument(skip(self), level = "debug")]
+    pub async fn get_workflow_inputs(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+    ) -> Result<Option<Vec<WorkflowInput>>> {
+        let url = self.base_url.join(&format!("repos/{}/{}/contents/{}", owner, repo, path))?;
+        let Some(response) = self
+            .send_optional(|| {
+                self.build_request_accepting(
+                    self.client.get(url.clone()),
+                    "application/vnd.github.raw+json",
+                )
+            })
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(parse_dispatch_inputs(&response.text().await?))
+    }
+
+    /// Run a `workflow_dispatch` workflow on `git_ref` (branch or tag).
+    /// `workflow_id` is the numeric id or the file name; `inputs` should have
+    /// been checked with [`crate::dispatch_values`].
+    pub async fn dispatch_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        git_ref: &str,
+        inputs: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        tracing::info!("Dispatching workflow {} on {} in {}/{}", workflow_id, git_ref, owner, repo);
+
+        let url = self.base_url.join(&format!(
+            "repos/{}/{}/actions/workflows/{}/dispatches",
+            owner, repo, workflow_id
+        ))?;
+        let body = serde_json::json!({ "ref": git_ref, "inputs": inputs });
+        self.send_with_retry(|| self.build_request(self.client.post(url.clone()).json(&body)))
+            .await?;
+        Ok(())
+    }
+
+    /// List notification threads for the authenticated user, newest first.
+    /// Only unread threads unless `all` is set.
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn list_notifications(&self, all: bool) -> Result<Vec<GitHubNotification>> {
+        tracing::debug!("Fetching notifications (all: {})", all);
+
+        let url = self.base_url.join("notifications")?;
+        let all = if all { "true" } else { "false" };
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(
+                    self.client.get(url.clone()).query(&[("all", all), ("per_page", "50")]),
+                )
+            })
+            .await?;
+
+        let notifications: Vec<GitHubNotification> = response.json().await?;
+
+        tracing::info!("Fetched {} notifications", notifications.len());
+        Ok(notifications)
+    }
+
+    /// Mark a notification thread as read
+    pub async fn mark_notification_read(&self, thread_id: &str) -> Result<()> {
+        tracing::debug!("Marking notification thread {} as read", thread_id);
+
+        let url = self.base_url.join(&format!("notifications/threads/{}", thread_id))?;
+        self.send_with_retry(|| self.build_request(self.client.patch(url.clone()))).await?;
+        Ok(())
+    }
+
+    /// Stop notifications for a thread until you comment or are mentioned again
+    pub async fn unsubscribe_thread(&self, thread_id: &str) -> Result<()> {
+        tracing::debug!("Unsubscribing from notification thread {}", thread_id);
+
+        let url =
+            self.base_url.join(&format!("notifications/threads/{}/subscription", thread_id))?;
+        self.send_with_retry(|| self.build_request(self.client.delete(url.clone()))).await?;
+        Ok(())
+    }
+
+    /// List releases, newest first
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn list_releases(&self, owner: &str, repo: &str) -> Result<Vec<GitHubRelease>> {
+        tracing::debug!("Fetching releases for {}/{}", owner, repo);
+
+        let url = self.base_url.join(&format!("repos/{}/{}/releases", owner, repo))?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.get(url.clone()).query(&[("per_page", "100")]))
+            })
+            .await?;
+
+        let releases: Vec<GitHubRelease> = response.json().await?;
+        tracing::info!("Fetched {} releases for {}/{}", releases.len(), owner, repo);
+        Ok(releases)
+    }
+
+    /// Create a release (and its tag, if new)
+    #[tracing::instrument(skip(self, req), fields(tag = %req.tag_name), level = "info")]
+    pub async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        req: CreateReleaseRequest,
+    ) -> Result<GitHubRelease> {
+        let url = self.base_url.join(&format!("repos/{}/{}/releases", owner, repo))?;
+        let response = self
+            .send_with_retry(|| self.build_request(self.client.post(url.clone()).json(&req)))
+            .await?;
+
+        let release: GitHubRelease = response.json().await?;
+        tracing::info!("Created release {} for {}/{}", release.tag_name, owner, repo);
+        Ok(release)
+    }
+
+    /// List tags, most recent first
+    pub async fn list_tags(&self, owner: &str, repo: &str) -> Result<Vec<GitHubTag>> {
+        tracing::debug!("Fetching tags for {}/{}", owner, repo);
+
+        let url = self.base_url.join(&format!("repos/{}/{}/tags", owner, repo))?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.get(url.clone()).query(&[("per_page", "100")]))
+            })
+            .await?;
+
+        let tags: Vec<GitHubTag> = response.json().await?;
+        Ok(tags)
+    }
+
+    /// Committer date (RFC 3339) of the commit `reference` (a SHA, branch or tag) points at
+    pub async fn get_commit_date(
+        &self,
+        owner: &str,
+        repo: &str,
+        reference: &str,
+    ) -> Result<String> {
+        #[derive(Deserialize)]
+        struct CommitResponse {
+            commit: CommitDetail,
+        }
+        #[derive(Deserialize)]
+        struct CommitDetail {
+            committer: CommitSignature,
+        }
+        #[derive(Deserialize)]
+        struct CommitSignature {
+            date: String,
+        }
+
+        let url = self.base_url.join(&format!("repos/{}/{}/commits/{}", owner, repo, reference))?;
+        let response =
+            self.send_with_retry(|| self.build_request(self.client.get(url.clone()))).await?;
+
+        let body: CommitResponse = response.json().await?;
+        Ok(body.commit.committer.date)
+    }
+
+    /// Open Dependabot alerts; `None` when Dependabot alerts are disabled for
+    /// the repo or the token can't read them
+    #[tracing::instrument(skip(self), level = "debug")]
+    pub async fn list_dependabot_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<Vec<DependabotAlert>>> {
+        let url = self.base_url.join(&format!("repos/{}/{}/dependabot/alerts", owner, repo))?;
+        let Some(response) = self
+            .send_unless_disabled(|| {
+                self.build_request(
+                    self.client.get(url.clone()).query(&[("state", "open"), ("per_page", "100")]),
+                )
+            })
+            .await?
+        else {
+            tracing::debug!("Dependabot alerts unavailable for {}/{}", owner, repo);
+            return Ok(None);
+        };
+        Ok(Some(response.json().await?))
+    }
+
+    /// Open code scanning alerts; `None` when code scanning isn't set up for
+    /// the repo or the token can't read it
+    #[tracing::instrument(skip(self), level = "debug")]
+    pub async fn list_code_scanning_alerts(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<Vec<CodeScanningAlert>>> {
+        let url = self.base_url.join(&format!("repos/{}/{}/code-scanning/alerts", owner, repo))?;
+        let Some(response) = self
+            .send_unless_disabled(|| {
+                self.build_request(
+                    self.client.get(url.clone()).query(&[("state", "open"), ("per_page", "100")]),
+                )
+            })
+            .await?
+        else {
+            tracing::debug!("Code scanning alerts unavailable for {}/{}", owner, repo);
+            return Ok(None);
+        };
+        Ok(Some(response.json().await?))
+    }
+
+    /// List the authenticated user's gists, most recently updated first
+    #[tracing::instrument(skip(self), level = "info")]
+    pub async fn list_gists(&self) -> Result<Vec<GitHubGist>> {
+        tracing::debug!("Fetching gists");
+
+        let url = self.base_url.join("gists")?;
+        let response = self
+            .send_with_retry(|| {
+                self.build_request(self.client.get(url.clone()).query(&[("per_page", "100")]))
+            })
+            .await?;
+
+        let gists: Vec<GitHubGist> = response.json().await?;
+        tracing::info!("Fetched {} gists", gists.len());
+        Ok(gists)
+    }
+
+    /// Create a gist
+    #[tracing::instrument(skip(self, req), fields(files = req.files.len()), level = "info")]
+    pub async fn create_gist(&self, req: CreateGistRequest) -> Result<GitHubGist> {
+        let url = self.base_url.join("gists")?;
+        let response = self
+            .send_with_retry(|| self.build_request(self.client.post(url.clone()).json(&req)))
+            .await?;
+
+        let gist: GitHubGist = response.json().await?;
+        tracing::info!("Created gist {}", gist.id);
+        Ok(gist)
+    }
+
+    /// Update a gist's description and/or files
+    #[tracing::instrument(skip(self, req), level = "info")]
+    pub async fn update_gist(&self, gist_id: &str, req: UpdateGistRequest) -> Result<GitHubGist> {
+        let url = self.base_url.join(&format!("gists/{}", gist_id))?;
+        let response = self
+            .send_with_retry(|| self.build_request(self.client.patch(url.clone()).json(&req)))
+            .await?;
+
+        let gist: GitHubGist = response.json().await?;
+        tracing::info!("Updated gist {}", gist.id);
+        Ok(gist)
+    }
 }
 
 #[cfg(test)]
@@ -564,4 +1953,748 @@ mod tests {
         assert_eq!(response.workflows[1].name, "Linter");
         assert!(response.workflows[1].html_url.is_none());
     }
+
+    #[test]
+    fn test_issue_template_parse() {
+        let text = "---\nname: Bug report\nabout: Something broke\ntitle: \"[Bug] \"\nlabels: bug, 'needs triage'\n---\n\n## Steps\n1.\n";
+        let t = IssueTemplate::parse("bug_report.md", text);
+        assert_eq!(t.name, "Bug report");
+        assert_eq!(t.about, "Something broke");
+        assert_eq!(t.title, "[Bug] ");
+        assert_eq!(t.labels, vec!["bug", "needs triage"]);
+        assert_eq!(t.body, "## Steps\n1.");
+
+        let list = "---\nname: Feature\nlabels:\n  - enhancement\n  - \"ui\"\n---\nDescribe it";
+        let t = IssueTemplate::parse("feature.md", list);
+        assert_eq!(t.labels, vec!["enhancement", "ui"]);
+        assert_eq!(t.body, "Describe it");
+
+        let bare = IssueTemplate::parse("chore.md", "Just a body\n");
+        assert_eq!(bare.name, "chore");
+        assert_eq!(bare.body, "Just a body");
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_templates() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/repo/contents/.github/ISSUE_TEMPLATE"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"name": "bug.md", "path": ".github/ISSUE_TEMPLATE/bug.md", "type": "file"},
+                {"name": "config.yml", "path": ".github/ISSUE_TEMPLATE/config.yml", "type": "file"}
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/repo/contents/.github/ISSUE_TEMPLATE/bug.md"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_string("---\nname: Bug\n---\nWhat happened?"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let templates = client.get_issue_templates("octo", "repo").await.unwrap();
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].name, "Bug");
+        assert_eq!(templates[0].body, "What happened?");
+
+        // No template directory
+        assert!(client.get_issue_templates("octo", "empty").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_workflow_inputs_and_dispatch() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/repo/contents/.github/workflows/deploy.yml"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                "on:\n  workflow_dispatch:\n    inputs:\n      env:\n        type: choice\n        options: [staging, prod]\n",
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/repo/actions/workflows/42/dispatches"))
+            .and(body_json(serde_json::json!({"ref": "main", "inputs": {"env": "prod"}})))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let inputs = client
+            .get_workflow_inputs("octo", "repo", ".github/workflows/deploy.yml")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(inputs[0].options, vec!["staging", "prod"]);
+        assert!(client
+            .get_workflow_inputs("octo", "repo", ".github/workflows/gone.yml")
+            .await
+            .unwrap()
+            .is_none());
+
+        let inputs = BTreeMap::from([("env".to_string(), "prod".to_string())]);
+        client.dispatch_workflow("octo", "repo", "42", "main", &inputs).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ensure_status_labels_creates_missing() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/repo/labels"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"id": 1, "name": "Todo", "color": "0366d6"},
+                {"id": 2, "name": "blocked", "color": "d93f0b"}
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octo/repo/labels"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!(
+                {"id": 3, "name": "x", "color": "ffffff"}
+            )))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let created = client.ensure_status_labels("octo", "repo").await.unwrap();
+        assert_eq!(created, vec!["backlog", "in-progress", "review"]);
+    }
+
+    fn notification_json() -> serde_json::Value {
+        serde_json::json!([{
+            "id": "1",
+            "unread": true,
+            "reason": "review_requested",
+            "updated_at": "2026-01-21T00:00:00Z",
+            "last_read_at": null,
+            "subject": {
+                "title": "Add feature",
+                "url": "https://api.github.com/repos/octo/repo/pulls/7",
+                "latest_comment_url": null,
+                "type": "PullRequest"
+            },
+            "repository": {
+                "full_name": "octo/repo",
+                "html_url": "https://github.com/octo/repo"
+            }
+        }])
+    }
+
+    #[test]
+    fn test_notification_html_url() {
+        let mut n: Vec<GitHubNotification> = serde_json::from_value(notification_json()).unwrap();
+        let mut n = n.remove(0);
+        assert_eq!(n.subject.kind, "PullRequest");
+        assert_eq!(n.html_url(), "https://github.com/octo/repo/pull/7");
+
+        n.subject.url = None;
+        n.subject.kind = "CheckSuite".to_string();
+        assert_eq!(n.html_url(), "https://github.com/octo/repo/actions");
+    }
+
+    #[tokio::test]
+    async fn test_notification_endpoints() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/notifications"))
+            .and(query_param("all", "false"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(notification_json()))
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/notifications/threads/1"))
+            .respond_with(ResponseTemplate::new(205))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/notifications/threads/1/subscription"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let notifications = client.list_notifications(false).await.unwrap();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].reason, "review_requested");
+        client.mark_notification_read("1").await.unwrap();
+        client.unsubscribe_thread("1").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_collaborators() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/repo/collaborators"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "id": 1,
+                "login": "octocat",
+                "avatar_url": "https://avatars.githubusercontent.com/u/1",
+                "html_url": "https://github.com/octocat",
+                "type": "User"
+            }])))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let collaborators = client.list_collaborators("octo", "repo").await.unwrap();
+        assert_eq!(collaborators.len(), 1);
+        assert_eq!(collaborators[0].login, "octocat");
+    }
+
+    fn created_repo_json(full_name: &str) -> serde_json::Value {
+        let name = full_name.split('/').nth(1).unwrap();
+        serde_json::json!({
+            "id": 7,
+            "name": name,
+            "full_name": full_name,
+            "description": null,
+            "html_url": format!("https://github.com/{}", full_name),
+            "private": true,
+            "default_branch": "main",
+            "open_issues_count": 0,
+            "updated_at": "2026-01-21T00:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_repo_in_org_with_topics() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/orgs/acme/repos"))
+            .and(body_json(serde_json::json!({
+                "name": "widget",
+                "private": true,
+                "auto_init": true,
+                "gitignore_template": "Rust",
+                "license_template": "mit"
+            })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(created_repo_json("acme/widget")),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/acme/widget/topics"))
+            .and(body_json(serde_json::json!({ "names": ["rust", "cli"] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let repo = client
+            .create_repo(CreateRepoRequest {
+                name: "widget".into(),
+                private: true,
+                auto_init: Some(true),
+                gitignore_template: Some("Rust".into()),
+                license_template: Some("mit".into()),
+                org: Some("acme".into()),
+                topics: vec!["rust".into(), "cli".into()],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(repo.full_name, "acme/widget");
+    }
+
+    #[tokio::test]
+    async fn test_create_repo_from_template() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/me/starter/generate"))
+            .and(body_json(serde_json::json!({
+                "name": "app",
+                "description": "New app",
+                "private": false
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(created_repo_json("me/app")))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let repo = client
+            .create_repo(CreateRepoRequest {
+                name: "app".into(),
+                description: Some("New app".into()),
+                template: Some("me/starter".into()),
+                gitignore_template: Some("Rust".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(repo.full_name, "me/app");
+    }
+
+    fn gist_json(description: &str, content: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "id": "abc123",
+            "description": description,
+            "public": false,
+            "html_url": "https://gist.github.com/abc123",
+            "files": {
+                "note.md": {
+                    "filename": "note.md",
+                    "language": "Markdown",
+                    "raw_url": "https://gist.githubusercontent.com/raw/note.md",
+                    "content": content
+                }
+            },
+            "created_at": "2026-01-21T00:00:00Z",
+            "updated_at": "2026-01-21T00:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_gist_endpoints() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/gists"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(vec![gist_json("Shopping", None)]),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/gists"))
+            .and(body_json(serde_json::json!({
+                "description": "Shopping",
+                "public": false,
+                "files": { "note.md": { "content": "milk" } }
+            })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(gist_json("Shopping", Some("milk"))),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/gists/abc123"))
+            .and(body_json(serde_json::json!({
+                "files": { "note.md": { "content": "milk, eggs" }, "old.md": null }
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(gist_json("Shopping", Some("milk, eggs"))),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+
+        let gists = client.list_gists().await.unwrap();
+        assert_eq!(gists.len(), 1);
+        assert!(!gists[0].public);
+        assert_eq!(gists[0].files["note.md"].content, None);
+
+        let created = client
+            .create_gist(CreateGistRequest {
+                description: Some("Shopping".into()),
+                public: false,
+                files: BTreeMap::from([(
+                    "note.md".to_string(),
+                    GistFileContent { content: "milk".into() },
+                )]),
+            })
+            .await
+            .unwrap();
+        assert_eq!(created.id, "abc123");
+
+        let updated = client
+            .update_gist(
+                "abc123",
+                UpdateGistRequest {
+                    description: None,
+                    files: BTreeMap::from([
+                        (
+                            "note.md".to_string(),
+                            Some(GistFileContent { content: "milk, eggs".into() }),
+                        ),
+                        ("old.md".to_string(), None),
+                    ]),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.files["note.md"].content.as_deref(), Some("milk, eggs"));
+    }
+
+    #[tokio::test]
+    async fn test_repo_insights_endpoints() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let point =
+            serde_json::json!({"timestamp": "2026-10-01T00:00:00Z", "count": 7, "uniques": 3});
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/repo/traffic/views"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({"count": 7, "uniques": 3, "views": [point.clone()]}),
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/repo/traffic/clones"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(
+                    serde_json::json!({"count": 7, "uniques": 3, "clones": [point]}),
+                ),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/repo/community/profile"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "health_percentage": 42,
+                "files": {"readme": {"html_url": "https://github.com/octo/repo/README.md"},
+                          "license": null}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/empty/contributors"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let traffic = client.get_repo_traffic("octo", "repo").await.unwrap();
+        assert_eq!(traffic.views.points.len(), 1);
+        assert_eq!(traffic.clones.points[0].count, 7);
+
+        let profile = client.get_community_profile("octo", "repo").await.unwrap().unwrap();
+        assert_eq!(profile.health_percentage, 42);
+        assert!(!profile.missing_files().contains(&"README"));
+        assert!(profile.missing_files().contains(&"License"));
+        assert!(client.get_community_profile("octo", "private").await.unwrap().is_none());
+
+        assert!(client.list_contributors("octo", "empty").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_security_alerts_disabled_is_none() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/app/dependabot/alerts"))
+            .and(query_param("state", "open"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "number": 7,
+                "state": "open",
+                "html_url": "https://github.com/octo/app/security/dependabot/7",
+                "security_advisory": { "summary": "Prototype pollution", "severity": "high" },
+                "dependency": { "package": { "ecosystem": "npm", "name": "lodash" } }
+            }])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/app/code-scanning/alerts"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "Code scanning is not enabled for this repository"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/private/dependabot/alerts"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "Dependabot alerts are disabled for this repository."
+            })))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+
+        let alerts = client.list_dependabot_alerts("octo", "app").await.unwrap().unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].severity(), "high");
+        assert!(client.list_code_scanning_alerts("octo", "app").await.unwrap().is_none());
+        assert!(client.list_dependabot_alerts("octo", "private").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_repo_preview() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/app/readme"))
+            .and(header("accept", "application/vnd.github.raw+json"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("# App\n\nDoes things."))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/app/topics"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"names": ["rust", "qt"]})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octo/app/languages"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"QML": 250, "Rust": 750})),
+            )
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let preview = client.get_repo_preview("octo", "app").await.unwrap();
+        assert_eq!(preview.readme.as_deref(), Some("# App\n\nDoes things."));
+        assert_eq!(preview.topics, vec!["rust", "qt"]);
+        assert_eq!(
+            preview.languages,
+            vec![
+                LanguageShare { name: "Rust".into(), percent: 75.0 },
+                LanguageShare { name: "QML".into(), percent: 25.0 },
+            ]
+        );
+
+        // No README is not an error
+        assert!(client.get_readme("octo", "bare").await.unwrap().is_none());
+        assert!(RepoPreview::language_shares(&BTreeMap::new()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_starred_endpoints() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user/starred"))
+            .and(query_param("per_page", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "id": 9,
+                "name": "tool",
+                "full_name": "octo/tool",
+                "html_url": "https://github.com/octo/tool",
+                "private": false,
+                "default_branch": "main",
+                "updated_at": "2026-10-01T00:00:00Z",
+                "pushed_at": "2026-09-30T12:00:00Z",
+                "stargazers_count": 1200
+            }])))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/user/starred/octo/tool"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/user/starred/octo/tool"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        let starred = client.list_starred().await.unwrap();
+        assert_eq!(starred.len(), 1);
+        assert_eq!(starred[0].pushed_at.as_deref(), Some("2026-09-30T12:00:00Z"));
+        client.star_repo("octo", "tool").await.unwrap();
+        client.unstar_repo("octo", "tool").await.unwrap();
+    }
+
+    #[test]
+    fn test_repo_scope_parse() {
+        assert_eq!(RepoScope::parse(" acme "), Some(RepoScope::Org("acme".into())));
+        assert_eq!(
+            RepoScope::parse("acme/platform"),
+            Some(RepoScope::Team { org: "acme".into(), team: "platform".into() })
+        );
+        assert_eq!(RepoScope::parse(""), None);
+        assert_eq!(RepoScope::parse("acme/"), None);
+        assert_eq!(RepoScope::parse("a/b/c"), None);
+
+        let scopes = RepoScope::parse_all(&["acme", "", "acme", "acme/platform"]);
+        let names: Vec<String> = scopes.iter().map(ToString::to_string).collect();
+        assert_eq!(names, vec!["acme", "acme/platform"]);
+        assert_eq!(scopes[1].org(), "acme");
+    }
+
+    #[tokio::test]
+    async fn test_scoped_repo_listing() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let repo = |id: i64, full_name: &str, updated_at: &str| {
+            serde_json::json!({
+                "id": id,
+                "name": full_name.split('/').nth(1).unwrap(),
+                "full_name": full_name,
+                "html_url": format!("https://github.com/{}", full_name),
+                "private": false,
+                "default_branch": "main",
+                "updated_at": updated_at
+            })
+        };
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user/repos"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                repo(1, "me/dotfiles", "2026-10-01T00:00:00Z"),
+                repo(2, "acme/api", "2026-09-01T00:00:00Z")
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/orgs/acme/repos"))
+            .and(query_param("type", "all"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                repo(2, "acme/api", "2026-09-01T00:00:00Z"),
+                repo(3, "acme/web", "2026-10-02T00:00:00Z")
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/orgs/corp/teams/infra/repos"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([repo(
+                4,
+                "corp/deploy",
+                "2026-08-01T00:00:00Z"
+            )])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user/orgs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"id": 10, "login": "acme", "description": "Acme Inc"}
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user/teams"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                "id": 20,
+                "slug": "infra",
+                "name": "Infra",
+                "organization": {"id": 11, "login": "corp"}
+            }])))
+            .mount(&server)
+            .await;
+
+        let client =
+            GitHubClient::new("token".to_string()).unwrap().with_base_url(&server.uri()).unwrap();
+        assert_eq!(client.list_user_orgs().await.unwrap()[0].login, "acme");
+        let teams = client.list_user_teams().await.unwrap();
+        assert_eq!(
+            (teams[0].organization.login.as_str(), teams[0].slug.as_str()),
+            ("corp", "infra")
+        );
+
+        let scopes = RepoScope::parse_all(&["acme", "corp/infra"]);
+        let repos = client.list_scoped_repos(&scopes).await.unwrap();
+        let names: Vec<&str> = repos.iter().map(|r| r.full_name.as_str()).collect();
+        assert_eq!(names, vec!["acme/web", "me/dotfiles", "acme/api", "corp/deploy"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_for_repos_over_graphql() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("\"o1\":\"acme\""))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "r0": {
+                        "issues": { "nodes": [{
+                            "databaseId": 7,
+                            "number": 1,
+                            "title": "Crash on start",
+                            "body": "Steps",
+                            "state": "OPEN",
+                            "url": "https://github.com/me/app/issues/1",
+                            "createdAt": "2026-10-01T00:00:00Z",
+                            "updatedAt": "2026-10-01T00:00:00Z",
+                            "labels": { "nodes": [] },
+                            "assignees": { "nodes": [{ "login": "me" }] },
+                            "comments": { "totalCount": 2 }
+                        }] },
+                        "pullRequests": { "nodes": [] }
+                    },
+                    "r1": null
+                }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // The repo GraphQL couldn't resolve is asked for over REST
+        Mock::given(method("GET"))
+            .and(path("/repos/acme/gone/issues"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("Not Found"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = GitHubClient::new("token".to_string())
+            .unwrap()
+            .with_base_url(&server.uri())
+            .unwrap()
+            .with_graphql(true);
+        let repos = vec!["me/app".to_string(), "acme/gone".to_string()];
+        let results = client.list_issues_for_repos(&repos).await;
+
+        assert_eq!(results[0].0, "me/app");
+        let issues = results[0].1.as_ref().unwrap();
+        assert_eq!((issues[0].number, issues[0].comments), (1, 2));
+        assert_eq!(issues[0].assignees[0].login, "me");
+        assert_eq!(results[1].0, "acme/gone");
+        assert!(results[1].1.is_err());
+    }
 }
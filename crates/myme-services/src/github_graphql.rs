@@ -0,0 +1,243 @@
+//! GraphQL path for fetching the issues of several repositories at once.
+//!
+//! One query asks for the issues and pull requests of up to
+//! [`REPOS_PER_QUERY`] repositories, each under its own alias (`r0`, `r1`, ...).
+//! The answer is turned back into the REST shape ([`GitHubIssue`]), newest
+//! first and capped like a REST page, so callers can't tell which path served
+//! them.
+
+use anyhow::{bail, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::github::{GitHubIssue, GitHubLabel, IssueAssignee, IssuePullRequest};
+
+/// Repositories asked for in one query
+pub(crate) const REPOS_PER_QUERY: usize = 10;
+
+/// Issues kept per repository, the REST page size
+const ISSUES_PER_REPO: usize = 100;
+
+/// Fields shared by issues and pull requests
+const ITEM_FIELDS: &str = "databaseId number title body state url createdAt updatedAt closedAt \
+     labels(first: 20) { nodes { name color } } \
+     assignees(first: 10) { nodes { login } } \
+     comments { totalCount }";
+
+/// Request body (query and variables) for the issues of `repos`, given as
+/// `(owner, name)` pairs
+pub(crate) fn issues_query(repos: &[(&str, &str)]) -> serde_json::Value {
+    let mut params = Vec::new();
+    let mut fields = Vec::new();
+    let mut variables = serde_json::Map::new();
+    for (i, (owner, name)) in repos.iter().enumerate() {
+        params.push(format!("$o{i}: String!, $n{i}: String!"));
+        fields.push(format!(
+            "r{i}: repository(owner: $o{i}, name: $n{i}) {{ \
+             issues(first: {n}, orderBy: {{field: CREATED_AT, direction: DESC}}) {{ nodes {{ {f} }} }} \
+             pullRequests(first: {n}, orderBy: {{field: CREATED_AT, direction: DESC}}) \
+             {{ nodes {{ {f} mergedAt }} }} }}",
+            n = ISSUES_PER_REPO,
+            f = ITEM_FIELDS,
+        ));
+        variables.insert(format!("o{i}"), (*owner).into());
+        variables.insert(format!("n{i}"), (*name).into());
+    }
+    serde_json::json!({
+        "query": format!("query({}) {{ {} }}", params.join(", "), fields.join(" ")),
+        "variables": variables,
+    })
+}
+
+#[derive(Deserialize)]
+struct QueryResponse {
+    data: Option<HashMap<String, Option<RepoNode>>>,
+    #[serde(default)]
+    errors: Vec<QueryError>,
+}
+
+#[derive(Deserialize)]
+struct QueryError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RepoNode {
+    issues: Nodes<ItemNode>,
+    pull_requests: Nodes<ItemNode>,
+}
+
+#[derive(Deserialize)]
+struct Nodes<T> {
+    nodes: Vec<Option<T>>,
+}
+
+impl<T> Nodes<T> {
+    fn into_items(self) -> impl Iterator<Item = T> {
+        self.nodes.into_iter().flatten()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ItemNode {
+    database_id: Option<i64>,
+    number: i32,
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    state: String,
+    url: String,
+    created_at: String,
+    updated_at: String,
+    #[serde(default)]
+    closed_at: Option<String>,
+    labels: Nodes<LabelNode>,
+    assignees: Nodes<IssueAssignee>,
+    comments: Count,
+    #[serde(default)]
+    merged_at: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LabelNode {
+    name: String,
+    color: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Count {
+    total_count: i32,
+}
+
+impl ItemNode {
+    fn into_issue(self, pull_request: bool) -> GitHubIssue {
+        GitHubIssue {
+            id: self.database_id.unwrap_or_default(),
+            number: self.number,
+            title: self.title,
+            // REST reports an empty body as null
+            body: self.body.filter(|b| !b.is_empty()),
+            // Pull requests can also be MERGED, which REST calls closed
+            state: if self.state == "OPEN" { "open" } else { "closed" }.to_string(),
+            html_url: self.url,
+            // Label database ids aren't exposed over GraphQL; nothing keys on them
+            labels: self
+                .labels
+                .into_items()
+                .map(|l| GitHubLabel { id: 0, name: l.name, color: l.color })
+                .collect(),
+            assignees: self.assignees.into_items().collect(),
+            comments: self.comments.total_count,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            closed_at: self.closed_at,
+            pull_request: pull_request.then_some(IssuePullRequest { merged_at: self.merged_at }),
+        }
+    }
+}
+
+/// Issues per repository from the answer to [`issues_query`] for `count`
+/// repos, in the order they were asked for. None for a repo the query
+/// couldn't resolve (missing, renamed or not visible to the token).
+pub(crate) fn parse_issues(
+    body: serde_json::Value,
+    count: usize,
+) -> Result<Vec<Option<Vec<GitHubIssue>>>> {
+    let response: QueryResponse = serde_json::from_value(body)?;
+    let Some(mut data) = response.data else {
+        let messages: Vec<String> = response.errors.into_iter().map(|e| e.message).collect();
+        bail!("GitHub GraphQL error: {}", messages.join("; "));
+    };
+    Ok((0..count)
+        .map(|i| {
+            let repo = data.remove(&format!("r{i}")).flatten()?;
+            let mut issues: Vec<GitHubIssue> = repo
+                .issues
+                .into_items()
+                .map(|item| item.into_issue(false))
+                .chain(repo.pull_requests.into_items().map(|item| item.into_issue(true)))
+                .collect();
+            // RFC 3339 timestamps from GitHub sort as strings
+            issues.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+            issues.truncate(ISSUES_PER_REPO);
+            Some(issues)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn item(number: i32, created_at: &str, state: &str) -> serde_json::Value {
+        serde_json::json!({
+            "databaseId": number * 100,
+            "number": number,
+            "title": format!("Item {}", number),
+            "body": "",
+            "state": state,
+            "url": format!("https://github.com/o/r/issues/{}", number),
+            "createdAt": created_at,
+            "updatedAt": created_at,
+            "closedAt": null,
+            "labels": { "nodes": [{ "name": "in-progress", "color": "fbca04" }] },
+            "assignees": { "nodes": [{ "login": "me" }] },
+            "comments": { "totalCount": 3 },
+        })
+    }
+
+    #[test]
+    fn query_aliases_each_repo_with_variables() {
+        let body = issues_query(&[("acme", "api"), ("me", "dotfiles")]);
+        let query = body["query"].as_str().unwrap();
+        assert!(query.starts_with("query($o0: String!, $n0: String!, $o1: String!, $n1: String!)"));
+        assert!(query.contains("r1: repository(owner: $o1, name: $n1)"));
+        assert_eq!(body["variables"]["o0"], "acme");
+        assert_eq!(body["variables"]["n1"], "dotfiles");
+    }
+
+    #[test]
+    fn parse_issues_matches_rest_shape() {
+        let mut pr = item(2, "2026-10-02T00:00:00Z", "MERGED");
+        pr["mergedAt"] = "2026-10-03T00:00:00Z".into();
+        let body = serde_json::json!({
+            "data": {
+                "r0": {
+                    "issues": { "nodes": [item(1, "2026-10-01T00:00:00Z", "OPEN")] },
+                    "pullRequests": { "nodes": [pr] },
+                },
+                "r1": null,
+            },
+            "errors": [{ "message": "Could not resolve to a Repository" }],
+        });
+
+        let repos = parse_issues(body, 2).unwrap();
+        assert!(repos[1].is_none());
+        let issues = repos[0].as_ref().unwrap();
+        assert_eq!(issues.iter().map(|i| i.number).collect::<Vec<_>>(), vec![2, 1]);
+
+        let (pr, issue) = (&issues[0], &issues[1]);
+        assert_eq!(pr.state, "closed");
+        assert_eq!(
+            pr.pull_request.as_ref().and_then(|p| p.merged_at.as_deref()),
+            Some("2026-10-03T00:00:00Z")
+        );
+        assert_eq!(issue.state, "open");
+        assert_eq!(issue.id, 100);
+        assert!(issue.body.is_none() && issue.pull_request.is_none());
+        assert_eq!(issue.labels[0].name, "in-progress");
+        assert_eq!(issue.assignees[0].login, "me");
+        assert_eq!(issue.comments, 3);
+    }
+
+    #[test]
+    fn parse_issues_fails_without_data() {
+        let body = serde_json::json!({ "errors": [{ "message": "Bad credentials" }] });
+        let err = parse_issues(body, 1).unwrap_err();
+        assert!(err.to_string().contains("Bad credentials"));
+    }
+}
@@ -0,0 +1,189 @@
+//! Disk cache for remote images (avatars, page thumbnails).
+//!
+//! Each image is stored under a hash of its URL, so the UI can show a local
+//! file instead of downloading it again on every view. Files older than the
+//! TTL are still usable while a fresh copy is fetched.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::{bail, Result};
+
+/// Larger responses are dropped rather than cached
+const MAX_IMAGE_BYTES: usize = 5 * 1024 * 1024;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// What the cache holds for a URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CachedImage {
+    /// Stored within the TTL
+    Fresh(PathBuf),
+    /// Stored, but older than the TTL
+    Stale(PathBuf),
+    Missing,
+}
+
+/// Downloads images into a directory and serves them from there.
+pub struct ImageCache {
+    dir: PathBuf,
+    ttl: Duration,
+    client: reqwest::Client,
+}
+
+impl ImageCache {
+    /// Cache in `dir` whose entries go stale after `ttl`, fetching through
+    /// `proxies`. The directory is created on the first fetch.
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration, proxies: Vec<reqwest::Proxy>) -> Self {
+        let builder = reqwest::Client::builder()
+            .timeout(FETCH_TIMEOUT)
+            .user_agent(concat!("myme/", env!("CARGO_PKG_VERSION")));
+        let client = proxies
+            .into_iter()
+            .fold(builder, reqwest::ClientBuilder::proxy)
+            .build()
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to build image client: {}", e);
+                reqwest::Client::new()
+            });
+        Self { dir: dir.into(), ttl, client }
+    }
+
+    /// File an image from `url` is stored in
+    pub fn path_for(&self, url: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}", fnv1a(url.as_bytes())))
+    }
+
+    /// What's stored for `url` as of `now`
+    pub fn lookup(&self, url: &str, now: SystemTime) -> CachedImage {
+        let path = self.path_for(url);
+        let Ok(modified) = std::fs::metadata(&path).and_then(|m| m.modified()) else {
+            return CachedImage::Missing;
+        };
+        let age = now.duration_since(modified).unwrap_or_default();
+        if age <= self.ttl {
+            CachedImage::Fresh(path)
+        } else {
+            CachedImage::Stale(path)
+        }
+    }
+
+    /// Download `url` and store it, replacing any older copy. Responses that
+    /// aren't images or are too large are errors.
+    pub async fn fetch(&self, url: &str) -> Result<PathBuf> {
+        let mut response = self.client.get(url).send().await?.error_for_status()?;
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        if !content_type.starts_with("image/") {
+            bail!("Not an image ({}): {}", content_type, url);
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            body.extend_from_slice(&chunk);
+            if body.len() > MAX_IMAGE_BYTES {
+                bail!("Image larger than {} bytes: {}", MAX_IMAGE_BYTES, url);
+            }
+        }
+
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.path_for(url);
+        // Write aside and rename so a reader never sees half a file
+        let partial = path.with_extension("part");
+        std::fs::write(&partial, &body)?;
+        std::fs::rename(&partial, &path)?;
+        Ok(path)
+    }
+
+    /// Delete images not refreshed within `max_age` of `now`. Returns how
+    /// many were removed.
+    pub fn prune(&self, max_age: Duration, now: SystemTime) -> usize {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return 0;
+        };
+        entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| is_expired(&entry.path(), max_age, now))
+            .filter(|entry| std::fs::remove_file(entry.path()).is_ok())
+            .count()
+    }
+}
+
+fn is_expired(path: &Path, max_age: Duration, now: SystemTime) -> bool {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .is_ok_and(|modified| now.duration_since(modified).unwrap_or_default() > max_age)
+}
+
+/// FNV-1a: a file name hash that stays the same across builds
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    #[tokio::test]
+    async fn fetch_stores_images_and_rejects_other_types() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/avatar.png"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(b"\x89PNG".to_vec(), "image/png"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/page"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("<html>", "text/html"))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ImageCache::new(dir.path().join("images"), DAY, Vec::new());
+        let url = format!("{}/avatar.png", server.uri());
+        assert_eq!(cache.lookup(&url, SystemTime::now()), CachedImage::Missing);
+
+        let stored = cache.fetch(&url).await.unwrap();
+        assert_eq!(std::fs::read(&stored).unwrap(), b"\x89PNG");
+        assert_eq!(cache.lookup(&url, SystemTime::now()), CachedImage::Fresh(stored.clone()));
+        assert_eq!(cache.lookup(&url, SystemTime::now() + 2 * DAY), CachedImage::Stale(stored));
+
+        let page = format!("{}/page", server.uri());
+        assert!(cache.fetch(&page).await.is_err());
+        assert_eq!(cache.lookup(&page, SystemTime::now()), CachedImage::Missing);
+        assert!(cache.fetch(&format!("{}/missing.png", server.uri())).await.is_err());
+    }
+
+    #[test]
+    fn prune_removes_old_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ImageCache::new(dir.path(), DAY, Vec::new());
+        let url = "https://example.com/a.png";
+        std::fs::write(cache.path_for(url), b"a").unwrap();
+
+        assert_eq!(cache.prune(30 * DAY, SystemTime::now()), 0);
+        assert_eq!(cache.prune(30 * DAY, SystemTime::now() + 31 * DAY), 1);
+        assert_eq!(cache.lookup(url, SystemTime::now()), CachedImage::Missing);
+    }
+
+    #[test]
+    fn paths_are_stable_per_url() {
+        let cache = ImageCache::new("/cache", DAY, Vec::new());
+        assert_eq!(cache.path_for("https://a/x.png"), cache.path_for("https://a/x.png"));
+        assert_ne!(cache.path_for("https://a/x.png"), cache.path_for("https://a/y.png"));
+        // Known FNV-1a value, so names don't change between releases
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
@@ -0,0 +1,762 @@
+//! Import notes and tasks from other tools.
+//!
+//! Supported exports:
+//! - Todoist CSV (project export) and JSON (REST `tasks` array or a sync
+//!   backup with `items`/`sections`) become kanban tasks
+//! - Google Keep notes from Takeout (`Takeout/Keep/*.json`) become notes
+//! - A Trello board JSON export becomes kanban tasks
+//!
+//! Parsing produces an [`ImportBatch`]; [`plan_import`] drops items that
+//! already exist (same normalized text), so the plan doubles as a dry-run
+//! preview. [`apply_import`] then creates what's left.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::note_backend::{NoteBackend, MAX_CONTENT_LENGTH};
+use crate::project::{Task, TaskStatus};
+use crate::project_store::ProjectStore;
+use crate::todo::{Todo, TodoUpdateRequest};
+
+/// Export format being imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    TodoistCsv,
+    TodoistJson,
+    GoogleKeep,
+    Trello,
+}
+
+impl ImportSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportSource::TodoistCsv => "todoist_csv",
+            ImportSource::TodoistJson => "todoist_json",
+            ImportSource::GoogleKeep => "google_keep",
+            ImportSource::Trello => "trello",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "todoist_csv" => Some(ImportSource::TodoistCsv),
+            "todoist_json" => Some(ImportSource::TodoistJson),
+            "google_keep" => Some(ImportSource::GoogleKeep),
+            "trello" => Some(ImportSource::Trello),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImportSource::TodoistCsv => "Todoist (CSV)",
+            ImportSource::TodoistJson => "Todoist (JSON)",
+            ImportSource::GoogleKeep => "Google Keep (Takeout)",
+            ImportSource::Trello => "Trello (board JSON)",
+        }
+    }
+
+    /// Guess the source from a path: a directory is a Keep Takeout folder,
+    /// `.csv` is Todoist, and JSON is told apart by its shape.
+    pub fn detect(path: &Path) -> Option<Self> {
+        if path.is_dir() {
+            return Some(ImportSource::GoogleKeep);
+        }
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "csv" => Some(ImportSource::TodoistCsv),
+            "json" => {
+                let value: Value =
+                    serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()?;
+                if value.get("cards").is_some() && value.get("lists").is_some() {
+                    Some(ImportSource::Trello)
+                } else if value.get("textContent").is_some() || value.get("listContent").is_some() {
+                    Some(ImportSource::GoogleKeep)
+                } else {
+                    Some(ImportSource::TodoistJson)
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A note to create
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedNote {
+    pub content: String,
+    pub is_checklist: bool,
+    pub color: Option<String>,
+    pub pinned: bool,
+    pub archived: bool,
+    pub labels: Vec<String>,
+}
+
+/// A kanban task to create
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedTask {
+    pub title: String,
+    pub body: Option<String>,
+    pub status: TaskStatus,
+}
+
+/// Everything parsed from one export
+#[derive(Debug, Clone)]
+pub struct ImportBatch {
+    pub source: ImportSource,
+    pub notes: Vec<ImportedNote>,
+    pub tasks: Vec<ImportedTask>,
+    /// Items left out or changed (trashed, archived, truncated)
+    pub warnings: Vec<String>,
+}
+
+impl ImportBatch {
+    fn new(source: ImportSource) -> Self {
+        Self { source, notes: Vec::new(), tasks: Vec::new(), warnings: Vec::new() }
+    }
+}
+
+/// What an import will create once duplicates are dropped
+#[derive(Debug, Clone)]
+pub struct ImportPlan {
+    pub source: ImportSource,
+    pub notes: Vec<ImportedNote>,
+    pub tasks: Vec<ImportedTask>,
+    /// Notes and tasks skipped because they already exist (or repeat in the file)
+    pub duplicates: usize,
+    pub warnings: Vec<String>,
+}
+
+/// Counts shown in the preview and after an import
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportReport {
+    pub source: String,
+    pub notes: usize,
+    pub tasks: usize,
+    pub duplicates: usize,
+    pub warnings: Vec<String>,
+    /// True for a preview: nothing was created
+    pub dry_run: bool,
+}
+
+impl ImportPlan {
+    /// Preview of the plan without creating anything
+    pub fn preview(&self) -> ImportReport {
+        ImportReport {
+            source: self.source.as_str().to_string(),
+            notes: self.notes.len(),
+            tasks: self.tasks.len(),
+            duplicates: self.duplicates,
+            warnings: self.warnings.clone(),
+            dry_run: true,
+        }
+    }
+}
+
+/// Read and parse an export. Google Keep takes a single note file or the
+/// Takeout `Keep` folder.
+pub fn parse_path(path: &Path, source: ImportSource) -> Result<ImportBatch> {
+    if source == ImportSource::GoogleKeep && path.is_dir() {
+        return parse_keep_dir(path);
+    }
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    match source {
+        ImportSource::TodoistCsv => parse_todoist_csv(&text),
+        ImportSource::TodoistJson => parse_todoist_json(&text),
+        ImportSource::GoogleKeep => {
+            let mut batch = ImportBatch::new(source);
+            match parse_keep_note(&text, &mut batch.warnings)? {
+                Some(note) => batch.notes.push(note),
+                None => batch.warnings.push("The note is in the Keep trash".to_string()),
+            }
+            Ok(batch)
+        }
+        ImportSource::Trello => parse_trello(&text),
+    }
+}
+
+/// Drop notes and tasks whose normalized text matches an existing one (or an
+/// earlier one in the same batch)
+pub fn plan_import(
+    batch: ImportBatch,
+    existing_notes: &[Todo],
+    existing_tasks: &[Task],
+) -> ImportPlan {
+    let mut seen_notes: HashSet<String> =
+        existing_notes.iter().map(|n| normalize(&n.content)).collect();
+    let mut seen_tasks: HashSet<String> =
+        existing_tasks.iter().map(|t| normalize(&t.title)).collect();
+
+    let before = batch.notes.len() + batch.tasks.len();
+    let notes: Vec<ImportedNote> =
+        batch.notes.into_iter().filter(|n| seen_notes.insert(normalize(&n.content))).collect();
+    let tasks: Vec<ImportedTask> =
+        batch.tasks.into_iter().filter(|t| seen_tasks.insert(normalize(&t.title))).collect();
+
+    ImportPlan {
+        source: batch.source,
+        duplicates: before - notes.len() - tasks.len(),
+        notes,
+        tasks,
+        warnings: batch.warnings,
+    }
+}
+
+/// Create the planned notes and tasks. Tasks go to the end of `project_id`'s
+/// board, which is required when the plan has tasks.
+pub fn apply_import(
+    plan: &ImportPlan,
+    notes: &dyn NoteBackend,
+    store: &ProjectStore,
+    project_id: Option<&str>,
+) -> Result<ImportReport> {
+    let project_id = match (plan.tasks.is_empty(), project_id.filter(|p| !p.is_empty())) {
+        (true, project_id) => project_id,
+        (false, Some(project_id)) => Some(project_id),
+        (false, None) => bail!("Choose a project to import tasks into"),
+    };
+
+    for note in &plan.notes {
+        let created = notes.create(&note.content, note.is_checklist)?;
+        let extras = TodoUpdateRequest {
+            color: note.color.clone().map(Some),
+            pinned: note.pinned.then_some(true),
+            archived: note.archived.then_some(true),
+            labels: (!note.labels.is_empty()).then(|| note.labels.clone()),
+            ..Default::default()
+        };
+        if extras.color.is_some()
+            || extras.pinned.is_some()
+            || extras.archived.is_some()
+            || extras.labels.is_some()
+        {
+            notes.update(created.id, extras)?;
+        }
+    }
+
+    if let Some(project_id) = project_id {
+        let existing = store.list_tasks_for_project(project_id)?;
+        let first_index = existing.iter().map(|t| t.order_index + 1).max().unwrap_or(0);
+        for (order_index, task) in (first_index..).zip(&plan.tasks) {
+            let now = chrono::Utc::now().to_rfc3339();
+            store.upsert_task(&Task {
+                id: uuid::Uuid::new_v4().to_string(),
+                project_id: project_id.to_string(),
+                title: task.title.clone(),
+                body: task.body.clone(),
+                status: task.status,
+                column_id: None,
+                created_at: now.clone(),
+                updated_at: now,
+                order_index,
+            })?;
+        }
+    }
+
+    Ok(ImportReport { dry_run: false, ..plan.preview() })
+}
+
+/// Lowercased with whitespace collapsed, for duplicate checks
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Status for a Trello list or Todoist section name
+fn status_for_column(name: &str) -> TaskStatus {
+    let name = name.to_lowercase();
+    if name.contains("done") || name.contains("complete") || name.contains("finished") {
+        TaskStatus::Done
+    } else if name.contains("progress") || name.contains("doing") || name.contains("active") {
+        TaskStatus::InProgress
+    } else if name.contains("review") || name.contains("testing") {
+        TaskStatus::Review
+    } else if name.contains("blocked") || name.contains("waiting") {
+        TaskStatus::Blocked
+    } else if name.contains("backlog") || name.contains("later") || name.contains("someday") {
+        TaskStatus::Backlog
+    } else {
+        TaskStatus::Todo
+    }
+}
+
+/// Join non-empty body parts with blank lines; None when all are empty
+fn join_body(parts: &[String]) -> Option<String> {
+    let parts: Vec<&str> = parts.iter().map(|p| p.trim()).filter(|p| !p.is_empty()).collect();
+    (!parts.is_empty()).then(|| parts.join("\n\n"))
+}
+
+/// Cut `content` to the note length limit on a character boundary
+fn fit_note(mut content: String, what: &str, warnings: &mut Vec<String>) -> String {
+    if content.len() > MAX_CONTENT_LENGTH {
+        let mut end = MAX_CONTENT_LENGTH;
+        while !content.is_char_boundary(end) {
+            end -= 1;
+        }
+        content.truncate(end);
+        warnings.push(format!("\"{}\" was shortened to {} characters", what, end));
+    }
+    content
+}
+
+// --- Todoist ---
+
+/// Todoist project CSV: `TYPE,CONTENT,DESCRIPTION,...,DATE,...`. Section
+/// rows set the status of the tasks below them; note rows (comments) are
+/// added to the previous task's body.
+pub fn parse_todoist_csv(text: &str) -> Result<ImportBatch> {
+    let mut rows = parse_csv(text.trim_start_matches('\u{feff}')).into_iter();
+    let header = rows.next().context("The CSV file is empty")?;
+    let column = |name: &str| header.iter().position(|h| h.trim().eq_ignore_ascii_case(name));
+    let (Some(kind_col), Some(content_col)) = (column("TYPE"), column("CONTENT")) else {
+        bail!("Not a Todoist CSV export (missing TYPE or CONTENT column)");
+    };
+    let description_col = column("DESCRIPTION");
+    let date_col = column("DATE");
+    let field = |row: &[String], col: Option<usize>| {
+        col.and_then(|c| row.get(c)).map(|s| s.trim().to_string()).unwrap_or_default()
+    };
+
+    let mut batch = ImportBatch::new(ImportSource::TodoistCsv);
+    let mut status = TaskStatus::Todo;
+    for row in rows {
+        let content = field(&row, Some(content_col));
+        match field(&row, Some(kind_col)).as_str() {
+            "section" => status = status_for_column(&content),
+            "task" if !content.is_empty() => {
+                let due = field(&row, date_col);
+                let due = if due.is_empty() { String::new() } else { format!("Due: {}", due) };
+                batch.tasks.push(ImportedTask {
+                    title: content,
+                    body: join_body(&[field(&row, description_col), due]),
+                    status,
+                });
+            }
+            "note" if !content.is_empty() => {
+                if let Some(task) = batch.tasks.last_mut() {
+                    task.body = join_body(&[task.body.take().unwrap_or_default(), content]);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(batch)
+}
+
+/// Todoist JSON: a REST `tasks` array, or an object with `items` (sync
+/// backup) or `tasks`, plus optional `sections` used for status
+pub fn parse_todoist_json(text: &str) -> Result<ImportBatch> {
+    let value: Value = serde_json::from_str(text).context("Not valid JSON")?;
+    let (items, sections) = match &value {
+        Value::Array(items) => (items.as_slice(), None),
+        Value::Object(map) => {
+            let items = map
+                .get("items")
+                .or_else(|| map.get("tasks"))
+                .and_then(Value::as_array)
+                .context("No Todoist tasks found (expected `items` or `tasks`)")?;
+            (items.as_slice(), map.get("sections").and_then(Value::as_array))
+        }
+        _ => bail!("No Todoist tasks found"),
+    };
+    let section_names: HashMap<String, &str> = sections
+        .into_iter()
+        .flatten()
+        .filter_map(|s| Some((id_string(s.get("id")?)?, s.get("name")?.as_str()?)))
+        .collect();
+
+    let mut batch = ImportBatch::new(ImportSource::TodoistJson);
+    for item in items {
+        let title = str_field(item, "content");
+        if title.is_empty() || item.get("is_deleted").and_then(Value::as_bool) == Some(true) {
+            continue;
+        }
+        let completed = ["checked", "is_completed", "completed"]
+            .iter()
+            .any(|k| item.get(*k).and_then(Value::as_bool) == Some(true));
+        let status = if completed {
+            TaskStatus::Done
+        } else {
+            item.get("section_id")
+                .and_then(id_string)
+                .and_then(|id| section_names.get(&id))
+                .map(|name| status_for_column(name))
+                .unwrap_or(TaskStatus::Todo)
+        };
+        let labels: Vec<&str> = item
+            .get("labels")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let due = item.get("due").and_then(|d| d.get("date")).and_then(Value::as_str);
+        batch.tasks.push(ImportedTask {
+            title: title.to_string(),
+            body: join_body(&[
+                str_field(item, "description").to_string(),
+                due.map(|d| format!("Due: {}", d)).unwrap_or_default(),
+                if labels.is_empty() {
+                    String::new()
+                } else {
+                    format!("Labels: {}", labels.join(", "))
+                },
+            ]),
+            status,
+        });
+    }
+    Ok(batch)
+}
+
+/// Todoist ids are strings in the REST API and numbers in older backups
+fn id_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(Value::as_str).map(str::trim).unwrap_or_default()
+}
+
+/// Minimal RFC 4180 reader: quoted fields may hold commas, doubled quotes
+/// and newlines. Blank lines are skipped.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|f| !f.is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                }
+                row.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+    row.push(field);
+    if row.iter().any(|f| !f.is_empty()) {
+        rows.push(row);
+    }
+    rows
+}
+
+// --- Google Keep ---
+
+/// Every `*.json` note in a Takeout `Keep` folder
+fn parse_keep_dir(dir: &Path) -> Result<ImportBatch> {
+    let mut batch = ImportBatch::new(ImportSource::GoogleKeep);
+    let mut paths: Vec<_> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|e| e.eq_ignore_ascii_case("json")))
+        .collect();
+    paths.sort();
+
+    let mut trashed = 0;
+    for path in paths {
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let parsed = std::fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|text| parse_keep_note(&text, &mut batch.warnings));
+        match parsed {
+            Ok(Some(note)) => batch.notes.push(note),
+            Ok(None) => trashed += 1,
+            Err(e) => batch.warnings.push(format!("Skipped {}: {}", name, e)),
+        }
+    }
+    if trashed > 0 {
+        batch.warnings.push(format!("Skipped {} notes in the Keep trash", trashed));
+    }
+    Ok(batch)
+}
+
+/// One Keep note. Checklists become `- [ ]`/`- [x]` lines, with the title
+/// (if any) as the first line. None for trashed notes.
+pub fn parse_keep_note(text: &str, warnings: &mut Vec<String>) -> Result<Option<ImportedNote>> {
+    let value: Value = serde_json::from_str(text).context("Not valid JSON")?;
+    if value.get("isTrashed").and_then(Value::as_bool) == Some(true) {
+        return Ok(None);
+    }
+    let title = str_field(&value, "title");
+    let list = value.get("listContent").and_then(Value::as_array);
+
+    let body = match list {
+        Some(items) => items
+            .iter()
+            .map(|item| {
+                let mark = if item.get("isChecked").and_then(Value::as_bool) == Some(true) {
+                    "x"
+                } else {
+                    " "
+                };
+                format!("- [{}] {}", mark, str_field(item, "text"))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        None => {
+            value.get("textContent").and_then(Value::as_str).unwrap_or_default().trim().to_string()
+        }
+    };
+    let content = match (title.is_empty(), body.is_empty()) {
+        (_, true) if title.is_empty() => bail!("Empty note"),
+        (true, _) => body,
+        (false, true) => title.to_string(),
+        (false, false) if list.is_some() => format!("{}\n{}", title, body),
+        (false, false) => format!("{}\n\n{}", title, body),
+    };
+    let labels = value
+        .get("labels")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|l| l.get("name").and_then(Value::as_str))
+        .map(str::to_string)
+        .collect();
+
+    Ok(Some(ImportedNote {
+        content: fit_note(
+            content,
+            if title.is_empty() { "Untitled note" } else { title },
+            warnings,
+        ),
+        is_checklist: list.is_some(),
+        color: value.get("color").and_then(Value::as_str).and_then(keep_color),
+        pinned: value.get("isPinned").and_then(Value::as_bool).unwrap_or(false),
+        archived: value.get("isArchived").and_then(Value::as_bool).unwrap_or(false),
+        labels,
+    }))
+}
+
+/// Closest note color from the color picker for a Keep color name
+fn keep_color(name: &str) -> Option<String> {
+    let hex = match name {
+        "YELLOW" | "ORANGE" => "#fef9c3",
+        "RED" => "#fecaca",
+        "PINK" => "#fecdd3",
+        "PURPLE" => "#e9d5ff",
+        "BLUE" | "DARK_BLUE" => "#bfdbfe",
+        "CERULEAN" => "#a5f3fc",
+        "TEAL" | "GREEN" => "#ccfbf1",
+        "GRAY" | "BROWN" => "#d1d5db",
+        _ => return None,
+    };
+    Some(hex.to_string())
+}
+
+// --- Trello ---
+
+/// Trello board export. Each open card becomes a task; its list sets the
+/// status and its checklists are appended to the body.
+pub fn parse_trello(text: &str) -> Result<ImportBatch> {
+    let value: Value = serde_json::from_str(text).context("Not valid JSON")?;
+    let (Some(lists), Some(cards)) = (
+        value.get("lists").and_then(Value::as_array),
+        value.get("cards").and_then(Value::as_array),
+    ) else {
+        bail!("Not a Trello board export (missing lists or cards)");
+    };
+    let is_closed = |v: &Value| v.get("closed").and_then(Value::as_bool) == Some(true);
+    let list_status: HashMap<&str, Option<TaskStatus>> = lists
+        .iter()
+        .map(|l| {
+            let status = (!is_closed(l)).then(|| status_for_column(str_field(l, "name")));
+            (str_field(l, "id"), status)
+        })
+        .collect();
+
+    let mut checklists: HashMap<&str, Vec<String>> = HashMap::new();
+    for checklist in value.get("checklists").and_then(Value::as_array).into_iter().flatten() {
+        let items: Vec<String> = checklist
+            .get("checkItems")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|item| {
+                let mark = if str_field(item, "state") == "complete" { "x" } else { " " };
+                format!("- [{}] {}", mark, str_field(item, "name"))
+            })
+            .collect();
+        let name = str_field(checklist, "name");
+        let block = if name.is_empty() {
+            items.join("\n")
+        } else {
+            format!("{}:\n{}", name, items.join("\n"))
+        };
+        checklists.entry(str_field(checklist, "idCard")).or_default().push(block);
+    }
+
+    let mut batch = ImportBatch::new(ImportSource::Trello);
+    let mut archived = 0;
+    for card in cards {
+        let title = str_field(card, "name");
+        if title.is_empty() {
+            continue;
+        }
+        let Some(Some(status)) =
+            list_status.get(str_field(card, "idList")).copied().filter(|_| !is_closed(card))
+        else {
+            archived += 1;
+            continue;
+        };
+        let mut body = vec![str_field(card, "desc").to_string()];
+        if let Some(due) = card.get("due").and_then(Value::as_str) {
+            body.push(format!("Due: {}", due));
+        }
+        let labels: Vec<&str> = card
+            .get("labels")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|l| str_field(l, "name"))
+            .filter(|n| !n.is_empty())
+            .collect();
+        if !labels.is_empty() {
+            body.push(format!("Labels: {}", labels.join(", ")));
+        }
+        body.extend(checklists.remove(str_field(card, "id")).unwrap_or_default());
+        batch.tasks.push(ImportedTask { title: title.to_string(), body: join_body(&body), status });
+    }
+    if archived > 0 {
+        batch.warnings.push(format!("Skipped {} archived cards", archived));
+    }
+    Ok(batch)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use crate::note_store::SqliteNoteStore;
+
+    #[test]
+    fn parses_todoist_csv_with_sections_and_comments() {
+        let csv = "\u{feff}TYPE,CONTENT,DESCRIPTION,PRIORITY,INDENT,AUTHOR,RESPONSIBLE,DATE,DATE_LANG,TIMEZONE\r\n\
+            section,Doing,,,,,,,,\r\n\
+            task,\"Write docs, part 1\",\"Cover \"\"setup\"\"\nand usage\",4,1,me,,tomorrow,en,UTC\r\n\
+            note,Started the outline,,,,,,,,\r\n\
+            \r\n\
+            section,Done,,,,,,,,\r\n\
+            task,Ship it,,1,1,me,,,en,UTC\r\n";
+        let batch = parse_todoist_csv(csv).unwrap();
+        assert_eq!(batch.tasks.len(), 2);
+        assert_eq!(batch.tasks[0].title, "Write docs, part 1");
+        assert_eq!(batch.tasks[0].status, TaskStatus::InProgress);
+        assert_eq!(
+            batch.tasks[0].body.as_deref(),
+            Some("Cover \"setup\"\nand usage\n\nDue: tomorrow\n\nStarted the outline")
+        );
+        assert_eq!(batch.tasks[1].status, TaskStatus::Done);
+        assert!(parse_todoist_csv("a,b\n1,2").is_err());
+    }
+
+    #[test]
+    fn parses_todoist_json_keep_and_trello() {
+        let todoist = r#"{"sections":[{"id":7,"name":"Backlog"}],"items":[
+            {"content":"Plan","section_id":7,"labels":["work"],"due":{"date":"2026-01-05"}},
+            {"content":"Old","checked":true}]}"#;
+        let batch = parse_todoist_json(todoist).unwrap();
+        assert_eq!(batch.tasks[0].status, TaskStatus::Backlog);
+        assert_eq!(batch.tasks[0].body.as_deref(), Some("Due: 2026-01-05\n\nLabels: work"));
+        assert_eq!(batch.tasks[1].status, TaskStatus::Done);
+
+        let mut warnings = Vec::new();
+        let keep = r#"{"title":"Groceries","color":"YELLOW","isPinned":true,"isTrashed":false,
+            "labels":[{"name":"home"}],
+            "listContent":[{"text":"Milk","isChecked":true},{"text":"Eggs","isChecked":false}]}"#;
+        let note = parse_keep_note(keep, &mut warnings).unwrap().unwrap();
+        assert_eq!(note.content, "Groceries\n- [x] Milk\n- [ ] Eggs");
+        assert!(note.is_checklist && note.pinned);
+        assert_eq!(note.color.as_deref(), Some("#fef9c3"));
+        assert_eq!(note.labels, ["home"]);
+        let long = format!(r#"{{"title":"Long","textContent":"{}"}}"#, "é".repeat(600));
+        let note = parse_keep_note(&long, &mut warnings).unwrap().unwrap();
+        assert!(note.content.len() <= MAX_CONTENT_LENGTH);
+        assert_eq!(warnings.len(), 1);
+        assert!(parse_keep_note(r#"{"isTrashed":true}"#, &mut warnings).unwrap().is_none());
+
+        let trello = r#"{"lists":[{"id":"l1","name":"In Progress"},{"id":"l2","name":"Old","closed":true}],
+            "cards":[{"id":"c1","name":"Card","desc":"Details","idList":"l1","labels":[{"name":"bug"}]},
+                     {"id":"c2","name":"Gone","idList":"l2"},
+                     {"id":"c3","name":"Closed","idList":"l1","closed":true}],
+            "checklists":[{"idCard":"c1","name":"Steps","checkItems":[{"name":"One","state":"complete"}]}]}"#;
+        let batch = parse_trello(trello).unwrap();
+        assert_eq!(batch.tasks.len(), 1);
+        assert_eq!(batch.tasks[0].status, TaskStatus::InProgress);
+        assert_eq!(
+            batch.tasks[0].body.as_deref(),
+            Some("Details\n\nLabels: bug\n\nSteps:\n- [x] One")
+        );
+        assert_eq!(batch.warnings, ["Skipped 2 archived cards"]);
+    }
+
+    #[test]
+    fn plans_without_duplicates_and_applies() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("projects.db")).unwrap();
+        store
+            .upsert_project(&crate::project::Project {
+                id: "p1".into(),
+                name: "Home".into(),
+                description: None,
+                created_at: "2026-01-01T00:00:00Z".into(),
+            })
+            .unwrap();
+        let notes = SqliteNoteStore::in_memory().unwrap();
+        notes.create("Existing  NOTE", false).unwrap();
+
+        let note = |content: &str| ImportedNote {
+            content: content.into(),
+            is_checklist: false,
+            color: Some("#fecaca".into()),
+            pinned: true,
+            archived: false,
+            labels: vec!["imported".into()],
+        };
+        let task = |title: &str| ImportedTask {
+            title: title.into(),
+            body: None,
+            status: TaskStatus::Todo,
+        };
+        let batch = ImportBatch {
+            source: ImportSource::GoogleKeep,
+            notes: vec![note("existing note"), note("New"), note("new")],
+            tasks: vec![task("Fix"), task("Fix")],
+            warnings: vec![],
+        };
+        let existing = notes.list().unwrap();
+        let plan = plan_import(batch, &existing, &[]);
+        let preview = plan.preview();
+        assert_eq!((preview.notes, preview.tasks, preview.duplicates), (1, 1, 3));
+        assert!(preview.dry_run);
+        assert_eq!(notes.list().unwrap().len(), 1);
+
+        assert!(apply_import(&plan, &notes, &store, None).is_err());
+        let report = apply_import(&plan, &notes, &store, Some("p1")).unwrap();
+        assert!(!report.dry_run);
+        let created = notes.list().unwrap().into_iter().find(|n| n.content == "New").unwrap();
+        assert!(created.pinned);
+        assert_eq!(created.labels, ["imported"]);
+        assert_eq!(store.list_tasks_for_project("p1").unwrap()[0].title, "Fix");
+    }
+}
@@ -1,17 +1,49 @@
+pub mod activity_log;
+pub mod analytics;
+pub mod bookmark;
+pub mod changelog;
+pub mod checklist;
+pub mod clipboard;
+pub mod db;
+pub mod discover;
 pub mod github;
+mod github_graphql;
+pub mod image_cache;
+pub mod importers;
 pub mod note_backend;
 pub mod note_client;
+pub mod note_crypto;
+pub mod note_export;
 pub mod note_store;
+pub mod note_sync;
 pub mod project;
 pub mod project_store;
 pub mod retry;
+pub mod template;
 pub mod todo;
+pub mod webhook_relay;
+pub mod workflow_dispatch;
 
+pub use activity_log::{ActivityEntry, ActivityLog, NewActivity};
+pub use bookmark::{Bookmark, BookmarkFilter, BookmarkStore, MetadataFetcher, PageMetadata};
+pub use changelog::{draft_changelog, next_tag, ChangelogDraft, VersionBump};
+pub use checklist::ChecklistItem;
+pub use clipboard::{looks_like_secret, ClipEntry, ClipboardHistory};
+pub use discover::{DiscoverItem, DiscoverKind};
 pub use github::*;
+pub use image_cache::{CachedImage, ImageCache};
+pub use importers::{ImportPlan, ImportReport, ImportSource};
 pub use note_backend::{NoteBackend, NoteBackendError, NoteBackendResult};
 pub use note_client::NoteClient;
-pub use note_store::SqliteNoteStore;
+pub use note_export::ExportFormat;
+pub use note_store::{LabelUsage, SqliteNoteStore};
+pub use note_sync::{sync_notes, FolderTransport, SyncReport, SyncTransport, SyncWatcher};
 pub use project::*;
 pub use project_store::ProjectStore;
 pub use retry::{with_retry, RetryConfig, RetryDecision};
+pub use template::{Template, TemplateContext, TemplateKind, TemplateSchedule};
 pub use todo::{Todo, TodoCreateRequest, TodoUpdateRequest};
+pub use webhook_relay::{GitHubEvent, RelayClient, RelayEvent};
+pub use workflow_dispatch::{
+    dispatch_values, parse_dispatch_inputs, WorkflowInput, WorkflowInputKind,
+};
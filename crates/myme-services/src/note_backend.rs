@@ -4,6 +4,7 @@
 //! storage implementations (SQLite, HTTP API).
 
 use crate::todo::{Todo, TodoUpdateRequest};
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
 /// Errors that can occur during note backend operations.
@@ -21,6 +22,10 @@ pub enum NoteBackendError {
     #[error("Storage error: {0}")]
     Storage(String),
 
+    /// Notes are encrypted and the store hasn't been unlocked.
+    #[error("Notes are locked; unlock them with the passphrase")]
+    Locked,
+
     /// Generic error wrapper.
     #[error(transparent)]
     Other(#[from] anyhow::Error),
@@ -56,12 +61,43 @@ pub type NoteBackendResult<T> = Result<T, NoteBackendError>;
 pub trait NoteBackend: Send {
     /// List all non-archived notes.
     ///
-    /// Returns notes ordered by pinned DESC, updated_at DESC.
+    /// Returns notes ordered by pinned DESC, then manual order (see `reorder`).
     fn list(&self) -> NoteBackendResult<Vec<Todo>>;
 
+    /// One page of `list()`: up to `limit` notes starting at `offset`, in the
+    /// same order.
+    ///
+    /// Default implementation slices `list()`; backends should override it
+    /// with a bounded query.
+    fn list_page(&self, offset: usize, limit: usize) -> NoteBackendResult<Vec<Todo>> {
+        Ok(self.list()?.into_iter().skip(offset).take(limit).collect())
+    }
+
+    /// Notes (archived or not) created or modified at or after `since`,
+    /// including changes applied by sync. Deletions are not reported.
+    ///
+    /// Default implementation filters `list()` and `list_archived()` by
+    /// `updated_at`.
+    fn list_changed_since(&self, since: DateTime<Utc>) -> NoteBackendResult<Vec<Todo>> {
+        let mut notes = self.list()?;
+        notes.extend(self.list_archived()?);
+        notes.retain(|n| n.updated_at >= since);
+        Ok(notes)
+    }
+
     /// List archived notes.
     fn list_archived(&self) -> NoteBackendResult<Vec<Todo>>;
 
+    /// Up to `limit` notes, archived or not, whose content contains every word
+    /// of `query` (case-insensitive), most recently updated first.
+    ///
+    /// Default implementation filters `list()` and `list_archived()`.
+    fn search(&self, query: &str, limit: usize) -> NoteBackendResult<Vec<Todo>> {
+        let mut notes = self.list()?;
+        notes.extend(self.list_archived()?);
+        Ok(filter_matching(notes, query, limit))
+    }
+
     /// List notes filtered by label (non-archived only).
     fn list_by_label(&self, label: &str) -> NoteBackendResult<Vec<Todo>>;
 
@@ -100,6 +136,32 @@ pub trait NoteBackend: Send {
     /// Returns `NoteBackendError::NotFound` if the note doesn't exist.
     fn delete(&self, id: i64) -> NoteBackendResult<()>;
 
+    /// List notes in the trash, most recently deleted first.
+    fn list_trashed(&self) -> NoteBackendResult<Vec<Todo>>;
+
+    /// Move a note to the trash. Trashed notes are hidden from every other
+    /// listing but stay recoverable with `restore` until purged.
+    ///
+    /// # Errors
+    /// Returns `NoteBackendError::NotFound` if the note doesn't exist.
+    fn trash(&self, id: i64) -> NoteBackendResult<()>;
+
+    /// Take a note back out of the trash.
+    ///
+    /// # Errors
+    /// Returns `NoteBackendError::NotFound` if the note doesn't exist.
+    fn restore(&self, id: i64) -> NoteBackendResult<Todo>;
+
+    /// Arrange `ids` in the given order (first on top) within the positions
+    /// they already hold, leaving every other note where it is.
+    ///
+    /// Returns `NoteBackendError::NotFound` if any of the notes doesn't exist.
+    fn reorder(&self, ids: &[i64]) -> NoteBackendResult<()>;
+
+    /// Permanently delete notes that have been in the trash for at least `days`.
+    /// Returns the number of notes purged.
+    fn purge_older_than(&self, days: u32) -> NoteBackendResult<usize>;
+
     /// Toggle the done status of a note.
     ///
     /// Default implementation fetches the note and updates with inverted done status.
@@ -122,6 +184,22 @@ pub trait NoteBackend: Send {
     }
 }
 
+/// Keep the notes whose content contains every word of `query`
+/// (case-insensitive), most recently updated first, at most `limit`.
+pub fn filter_matching(mut notes: Vec<Todo>, query: &str, limit: usize) -> Vec<Todo> {
+    let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if words.is_empty() {
+        return Vec::new();
+    }
+    notes.retain(|n| {
+        let content = n.content.to_lowercase();
+        words.iter().all(|w| content.contains(w.as_str()))
+    });
+    notes.sort_by_key(|n| std::cmp::Reverse(n.updated_at));
+    notes.truncate(limit);
+    notes
+}
+
 /// Maximum content length for notes (matches Godo validation).
 pub const MAX_CONTENT_LENGTH: usize = 1000;
 
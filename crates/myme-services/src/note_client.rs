@@ -5,10 +5,11 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use parking_lot::Mutex;
 
 use crate::note_backend::NoteBackend;
-use crate::note_store::SqliteNoteStore;
+use crate::note_store::{LabelUsage, SqliteNoteStore};
 use crate::todo::{Todo, TodoCreateRequest, TodoUpdateRequest};
 
 /// Note client wrapping SQLite storage.
@@ -21,7 +22,7 @@ impl NoteClient {
         Self(Arc::new(Mutex::new(store)))
     }
 
-    /// List all non-archived notes (pinned first, then by updated_at DESC).
+    /// List all non-archived notes (pinned first, then in manual order).
     pub async fn list_todos(&self) -> Result<Vec<Todo>> {
         let store = self.0.clone();
         tokio::task::spawn_blocking(move || {
@@ -30,6 +31,24 @@ impl NoteClient {
         .await?
     }
 
+    /// One page of non-archived notes, in `list_todos` order.
+    pub async fn list_page(&self, offset: usize, limit: usize) -> Result<Vec<Todo>> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().list_page(offset, limit).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Notes created or modified (locally or by sync) at or after `since`.
+    pub async fn list_changed_since(&self, since: DateTime<Utc>) -> Result<Vec<Todo>> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().list_changed_since(since).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
     /// List archived notes.
     pub async fn list_archived(&self) -> Result<Vec<Todo>> {
         let store = self.0.clone();
@@ -39,6 +58,16 @@ impl NoteClient {
         .await?
     }
 
+    /// Notes (archived included) containing every word of `query`, newest first.
+    pub async fn search(&self, query: &str, limit: usize) -> Result<Vec<Todo>> {
+        let store = self.0.clone();
+        let query = query.to_string();
+        tokio::task::spawn_blocking(move || {
+            store.lock().search(&query, limit).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
     /// List notes filtered by label.
     pub async fn list_by_label(&self, label: &str) -> Result<Vec<Todo>> {
         let store = self.0.clone();
@@ -101,6 +130,96 @@ impl NoteClient {
         .await?
     }
 
+    /// List notes in the trash (most recently deleted first).
+    pub async fn list_trashed(&self) -> Result<Vec<Todo>> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().list_trashed().map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Move a note to the trash.
+    pub async fn trash_todo(&self, id: i64) -> Result<()> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().trash(id).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Restore a note from the trash.
+    pub async fn restore_todo(&self, id: i64) -> Result<Todo> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().restore(id).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Arrange notes in the given order, first on top (see `NoteBackend::reorder`).
+    pub async fn reorder(&self, ids: Vec<i64>) -> Result<()> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().reorder(&ids).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Every label with how many notes show under it (see `SqliteNoteStore::list_labels`).
+    pub async fn list_labels(&self) -> Result<Vec<LabelUsage>> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().list_labels().map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Register a label before any note uses it.
+    pub async fn create_label(&self, name: String) -> Result<()> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().create_label(&name).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Rename a label on every note; returns how many notes changed.
+    pub async fn rename_label(&self, from: String, to: String) -> Result<usize> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().rename_label(&from, &to).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Fold label `from` into `into`; returns how many notes changed.
+    pub async fn merge_labels(&self, from: String, into: String) -> Result<usize> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().merge_labels(&from, &into).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Remove a label from every note; returns how many notes changed.
+    pub async fn delete_label(&self, name: String) -> Result<usize> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().delete_label(&name).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
+    /// Permanently delete notes trashed at least `days` ago; returns how many.
+    pub async fn purge_trash(&self, days: u32) -> Result<usize> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            store.lock().purge_older_than(days).map_err(|e| anyhow::anyhow!("{}", e))
+        })
+        .await?
+    }
+
     /// Mark a note as done.
     pub async fn mark_done(&self, id: i64) -> Result<Todo> {
         let req = TodoUpdateRequest { done: Some(true), ..Default::default() };
@@ -122,6 +241,31 @@ impl NoteClient {
         .await?
     }
 
+    /// Whether note content is stored encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.0.lock().is_encrypted()
+    }
+
+    /// Encrypted and not yet unlocked.
+    pub fn is_locked(&self) -> bool {
+        self.0.lock().is_locked()
+    }
+
+    /// Unlock an encrypted store, or turn encryption on (encrypting existing
+    /// notes) if the store is still plaintext.
+    pub async fn unlock_or_encrypt(&self, passphrase: String) -> Result<()> {
+        let store = self.0.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut store = store.lock();
+            if store.is_encrypted() {
+                store.unlock(&passphrase)
+            } else {
+                store.enable_encryption(&passphrase)
+            }
+        })
+        .await?
+    }
+
     /// Health check (always true for local store).
     pub async fn health_check(&self) -> Result<bool> {
         Ok(true)
@@ -208,6 +352,30 @@ mod tests {
         assert!(notes.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_trash_and_restore() {
+        let client = create_client();
+
+        let note = client
+            .create_todo(TodoCreateRequest { content: "Oops".to_string(), is_checklist: false })
+            .await
+            .unwrap();
+
+        client.trash_todo(note.id).await.unwrap();
+        assert!(client.list_todos().await.unwrap().is_empty());
+        let trashed = client.list_trashed().await.unwrap();
+        assert_eq!(trashed.len(), 1);
+        assert!(trashed[0].deleted_at.is_some());
+
+        // Too recent to purge
+        assert_eq!(client.purge_trash(30).await.unwrap(), 0);
+
+        let restored = client.restore_todo(note.id).await.unwrap();
+        assert!(restored.deleted_at.is_none());
+        assert_eq!(client.list_todos().await.unwrap().len(), 1);
+        assert!(client.list_trashed().await.unwrap().is_empty());
+    }
+
     #[tokio::test]
     async fn test_toggle_done() {
         let client = create_client();
@@ -226,6 +394,58 @@ mod tests {
         assert!(!toggled_back.done);
     }
 
+    #[tokio::test]
+    async fn test_list_page_and_changes() {
+        let client = create_client();
+        for i in 0..5 {
+            client
+                .create_todo(TodoCreateRequest {
+                    content: format!("Note {}", i),
+                    is_checklist: false,
+                })
+                .await
+                .unwrap();
+        }
+
+        let first = client.list_page(0, 2).await.unwrap();
+        let rest = client.list_page(2, 10).await.unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(rest.len(), 3);
+        let all: Vec<i64> = first.iter().chain(&rest).map(|n| n.id).collect();
+        let listed: Vec<i64> = client.list_todos().await.unwrap().iter().map(|n| n.id).collect();
+        assert_eq!(all, listed);
+
+        // changed_at has millisecond resolution
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        let since = Utc::now();
+        let req = TodoUpdateRequest { archived: Some(true), ..Default::default() };
+        let archived = client.update_todo(rest[0].id, req).await.unwrap();
+        let changed = client.list_changed_since(since).await.unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].id, archived.id);
+        assert!(changed[0].archived);
+    }
+
+    #[tokio::test]
+    async fn test_unlock_or_encrypt() {
+        let client = create_client();
+        client
+            .create_todo(TodoCreateRequest { content: "Secret".to_string(), is_checklist: false })
+            .await
+            .unwrap();
+
+        client.unlock_or_encrypt("pw".to_string()).await.unwrap();
+        assert!(client.is_encrypted());
+        assert!(!client.is_locked());
+
+        client.sqlite_store().lock().lock();
+        assert!(client.is_locked());
+        assert!(client.list_todos().await.is_err());
+        assert!(client.unlock_or_encrypt("nope".to_string()).await.is_err());
+        client.unlock_or_encrypt("pw".to_string()).await.unwrap();
+        assert_eq!(client.list_todos().await.unwrap()[0].content, "Secret");
+    }
+
     #[tokio::test]
     async fn test_health_check() {
         let client = create_client();
@@ -0,0 +1,194 @@
+//! Encryption of note content at rest.
+//!
+//! Content is sealed with AES-256-GCM under a key derived from the user's
+//! passphrase with Argon2id. Each value gets a fresh random nonce and is stored
+//! as `enc:v1:<base64(nonce || ciphertext)>`, so encrypted and plaintext rows can
+//! be told apart while an existing database is being migrated.
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{anyhow, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+/// Prefix marking an encrypted value.
+const PREFIX: &str = "enc:v1:";
+
+/// AES-GCM nonce length in bytes.
+const NONCE_LEN: usize = 12;
+
+/// Salt length for key derivation.
+pub const SALT_LEN: usize = 16;
+
+/// Argon2id cost, stored with the salt so it can be raised later without
+/// breaking databases encrypted under the old setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+}
+
+impl Default for KdfParams {
+    /// OWASP's Argon2id baseline. Tests use a cheap setting so they stay fast in debug builds.
+    fn default() -> Self {
+        if cfg!(test) {
+            Self { memory_kib: 64, iterations: 1 }
+        } else {
+            Self { memory_kib: 19 * 1024, iterations: 2 }
+        }
+    }
+}
+
+impl KdfParams {
+    /// `"<memory_kib>,<iterations>"`, as stored in the database.
+    pub fn encode(&self) -> String {
+        format!("{},{}", self.memory_kib, self.iterations)
+    }
+
+    /// Parse the stored form.
+    pub fn parse(stored: &str) -> Result<Self> {
+        let (memory, iterations) =
+            stored.split_once(',').ok_or_else(|| anyhow!("Invalid key derivation settings"))?;
+        Ok(Self {
+            memory_kib: memory.trim().parse().context("Invalid key derivation memory cost")?,
+            iterations: iterations.trim().parse().context("Invalid key derivation iterations")?,
+        })
+    }
+}
+
+/// Random salt for a new encrypted database.
+pub fn generate_salt() -> [u8; SALT_LEN] {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    salt
+}
+
+/// Random key for `NoteCipher::from_key`.
+pub fn generate_key() -> [u8; 32] {
+    let mut key = [0u8; 32];
+    OsRng.fill_bytes(&mut key);
+    key
+}
+
+/// Salt as stored in the database.
+pub fn encode_salt(salt: &[u8]) -> String {
+    BASE64.encode(salt)
+}
+
+/// Salt read back from the database.
+pub fn decode_salt(stored: &str) -> Result<Vec<u8>> {
+    BASE64.decode(stored).context("Stored encryption salt is not valid base64")
+}
+
+/// Whether a stored value is encrypted.
+pub fn is_encrypted(value: &str) -> bool {
+    value.starts_with(PREFIX)
+}
+
+/// Content cipher keyed from a passphrase.
+pub struct NoteCipher {
+    cipher: Aes256Gcm,
+}
+
+impl NoteCipher {
+    /// Derive the key for `passphrase` with the database's `salt` and cost.
+    pub fn from_passphrase(passphrase: &str, salt: &[u8], kdf: KdfParams) -> Result<Self> {
+        let params = argon2::Params::new(kdf.memory_kib, kdf.iterations, 1, Some(32))
+            .map_err(|e| anyhow!("Invalid key derivation parameters: {}", e))?;
+        let argon =
+            argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+        let mut key = [0u8; 32];
+        argon
+            .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+            .map_err(|e| anyhow!("Key derivation failed: {}", e))?;
+        Ok(Self { cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)) })
+    }
+
+    /// Use a random 256-bit key directly, for stores with no passphrase
+    /// (the key itself lives in the system keyring).
+    pub fn from_key(key: &[u8; 32]) -> Self {
+        Self { cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)) }
+    }
+
+    /// Encrypt `plaintext` into the stored `enc:v1:` form.
+    pub fn encrypt(&self, plaintext: &str) -> Result<String> {
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let ciphertext = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+            .map_err(|_| anyhow!("Encryption failed"))?;
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend_from_slice(&ciphertext);
+        Ok(format!("{}{}", PREFIX, BASE64.encode(sealed)))
+    }
+
+    /// Decrypt a stored value. Values without the prefix are returned as-is,
+    /// so rows written before encryption was enabled still read back.
+    pub fn decrypt(&self, stored: &str) -> Result<String> {
+        let Some(encoded) = stored.strip_prefix(PREFIX) else {
+            return Ok(stored.to_string());
+        };
+        let sealed = BASE64.decode(encoded).context("Encrypted value is not valid base64")?;
+        if sealed.len() < NONCE_LEN {
+            return Err(anyhow!("Encrypted value is truncated"));
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!("Decryption failed (wrong passphrase or corrupted data)"))?;
+        String::from_utf8(plaintext).context("Decrypted value is not UTF-8")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_round_trip_uses_fresh_nonces() {
+        let salt = generate_salt();
+        let cipher =
+            NoteCipher::from_passphrase("correct horse", &salt, KdfParams::default()).unwrap();
+
+        let a = cipher.encrypt("Buy milk").unwrap();
+        let b = cipher.encrypt("Buy milk").unwrap();
+        assert!(is_encrypted(&a));
+        assert!(!a.contains("milk"));
+        assert_ne!(a, b);
+        assert_eq!(cipher.decrypt(&a).unwrap(), "Buy milk");
+        assert_eq!(cipher.decrypt(&b).unwrap(), "Buy milk");
+    }
+
+    #[test]
+    fn test_wrong_passphrase_fails() {
+        let salt = generate_salt();
+        let sealed = NoteCipher::from_passphrase("right", &salt, KdfParams::default())
+            .unwrap()
+            .encrypt("secret")
+            .unwrap();
+
+        let wrong = NoteCipher::from_passphrase("wrong", &salt, KdfParams::default()).unwrap();
+        assert!(wrong.decrypt(&sealed).is_err());
+        assert!(wrong.decrypt("enc:v1:AAAA").is_err());
+    }
+
+    #[test]
+    fn test_kdf_params_round_trip() {
+        let kdf = KdfParams { memory_kib: 19456, iterations: 2 };
+        assert_eq!(KdfParams::parse(&kdf.encode()).unwrap(), kdf);
+        assert!(KdfParams::parse("fast").is_err());
+    }
+
+    #[test]
+    fn test_plaintext_passes_through() {
+        let cipher =
+            NoteCipher::from_passphrase("pw", &generate_salt(), KdfParams::default()).unwrap();
+        assert!(!is_encrypted("plain note"));
+        assert_eq!(cipher.decrypt("plain note").unwrap(), "plain note");
+    }
+}
@@ -0,0 +1,303 @@
+//! Note export to Markdown and standalone HTML.
+//!
+//! HTML output is a single self-contained file with print styles, so it can be
+//! opened in a browser and printed to PDF without extra assets.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::todo::Todo;
+
+/// Output format for exported notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+}
+
+impl ExportFormat {
+    /// Parse a format name ("markdown"/"md", "html"/"htm").
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "markdown" | "md" => Some(ExportFormat::Markdown),
+            "html" | "htm" => Some(ExportFormat::Html),
+            _ => None,
+        }
+    }
+
+    /// File extension without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+        }
+    }
+}
+
+/// One checklist line parsed from note content.
+struct ChecklistItem<'a> {
+    text: &'a str,
+    checked: bool,
+}
+
+/// Parse a checklist line ("- [ ] text" / "- [x] text"); plain lines count as unchecked.
+fn parse_checklist_line(line: &str) -> ChecklistItem<'_> {
+    let trimmed = line.trim();
+    if let Some(rest) = trimmed.strip_prefix('-').map(str::trim_start) {
+        if let Some(text) = rest.strip_prefix("[ ]").or_else(|| rest.strip_prefix("[]")) {
+            return ChecklistItem { text: text.trim(), checked: false };
+        }
+        if let Some(text) = rest.strip_prefix("[x]").or_else(|| rest.strip_prefix("[X]")) {
+            return ChecklistItem { text: text.trim(), checked: true };
+        }
+    }
+    ChecklistItem { text: trimmed, checked: false }
+}
+
+fn checklist_items(content: &str) -> impl Iterator<Item = ChecklistItem<'_>> {
+    content.lines().filter(|l| !l.trim().is_empty()).map(parse_checklist_line)
+}
+
+/// First non-empty line without checklist or heading markup (may be empty).
+fn first_line(note: &Todo) -> &str {
+    let first = note.content.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+    parse_checklist_line(first).text.trim_start_matches('#').trim()
+}
+
+/// Short title for a note: its first line, or "Note <id>" if empty.
+pub fn note_title(note: &Todo) -> String {
+    let title = first_line(note);
+    if title.is_empty() {
+        format!("Note {}", note.id)
+    } else {
+        title.chars().take(80).collect()
+    }
+}
+
+/// Render a note as Markdown.
+pub fn render_markdown(note: &Todo) -> String {
+    let mut out = String::new();
+
+    if note.is_checklist {
+        for item in checklist_items(&note.content) {
+            let mark = if item.checked { "x" } else { " " };
+            out.push_str(&format!("- [{}] {}\n", mark, item.text));
+        }
+    } else {
+        out.push_str(note.content.trim_end());
+        out.push('\n');
+    }
+
+    out.push_str("\n---\n\n");
+    if !note.labels.is_empty() {
+        out.push_str(&format!("Labels: {}  \n", note.labels.join(", ")));
+    }
+    if let Some(reminder) = note.reminder {
+        out.push_str(&format!("Reminder: {}  \n", reminder.to_rfc3339()));
+    }
+    out.push_str(&format!("Created: {}  \n", note.created_at.to_rfc3339()));
+    out.push_str(&format!("Updated: {}\n", note.updated_at.to_rfc3339()));
+    out
+}
+
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Only allow "#rgb"/"#rrggbb" colors into the stylesheet.
+fn safe_color(color: Option<&str>) -> Option<&str> {
+    color.filter(|c| {
+        c.starts_with('#')
+            && matches!(c.len(), 4 | 7)
+            && c[1..].chars().all(|ch| ch.is_ascii_hexdigit())
+    })
+}
+
+/// Render a note as a standalone, print-ready HTML document.
+pub fn render_html(note: &Todo) -> String {
+    let title = escape_html(&note_title(note));
+    let background = safe_color(note.color.as_deref()).unwrap_or("#ffffff");
+
+    let body = if note.is_checklist {
+        let items: String = checklist_items(&note.content)
+            .map(|item| {
+                format!(
+                    "    <li class=\"{}\"><input type=\"checkbox\" disabled{}> {}</li>\n",
+                    if item.checked { "done" } else { "todo" },
+                    if item.checked { " checked" } else { "" },
+                    escape_html(item.text)
+                )
+            })
+            .collect();
+        format!("  <ul class=\"checklist\">\n{}  </ul>\n", items)
+    } else {
+        note.content
+            .split("\n\n")
+            .filter(|p| !p.trim().is_empty())
+            .map(|p| format!("  <p>{}</p>\n", escape_html(p.trim()).replace('\n', "<br>\n")))
+            .collect()
+    };
+
+    let labels: String = note
+        .labels
+        .iter()
+        .map(|l| format!("<span class=\"label\">{}</span>", escape_html(l)))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut meta = String::new();
+    if !labels.is_empty() {
+        meta.push_str(&format!("    <div>{}</div>\n", labels));
+    }
+    if let Some(reminder) = note.reminder {
+        meta.push_str(&format!("    <div>Reminder: {}</div>\n", reminder.to_rfc3339()));
+    }
+    meta.push_str(&format!("    <div>Updated: {}</div>\n", note.updated_at.to_rfc3339()));
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>{title}</title>
+  <style>
+    body {{ font-family: system-ui, sans-serif; max-width: 40rem; margin: 2rem auto; padding: 1.5rem; background: {background}; color: #202124; line-height: 1.5; }}
+    .checklist {{ list-style: none; padding-left: 0; }}
+    .checklist li.done {{ text-decoration: line-through; color: #5f6368; }}
+    .label {{ display: inline-block; border: 1px solid #dadce0; border-radius: 1rem; padding: 0 0.6rem; font-size: 0.85em; }}
+    footer {{ margin-top: 2rem; font-size: 0.85em; color: #5f6368; }}
+    @media print {{ body {{ margin: 0; max-width: none; background: #ffffff; }} }}
+  </style>
+</head>
+<body>
+{body}  <footer>
+{meta}  </footer>
+</body>
+</html>
+"#
+    )
+}
+
+/// Render a note in the given format.
+pub fn render(note: &Todo, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Markdown => render_markdown(note),
+        ExportFormat::Html => render_html(note),
+    }
+}
+
+/// File name for an exported note, e.g. `note-42-shopping-list.md`.
+pub fn file_name_for(note: &Todo, format: ExportFormat) -> String {
+    let slug: String = first_line(note)
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|s| !s.is_empty())
+        .take(8)
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        format!("note-{}.{}", note.id, format.extension())
+    } else {
+        format!("note-{}-{}.{}", note.id, slug, format.extension())
+    }
+}
+
+/// Export a note to `path`. If `path` is an existing directory, a file name is generated.
+pub fn export_note(note: &Todo, format: ExportFormat, path: &Path) -> Result<PathBuf> {
+    let target = if path.is_dir() { path.join(file_name_for(note, format)) } else { path.into() };
+    if let Some(parent) = target.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).context("Failed to create export directory")?;
+    }
+    std::fs::write(&target, render(note, format))
+        .with_context(|| format!("Failed to write {}", target.display()))?;
+    Ok(target)
+}
+
+/// Export several notes into `dir`, one file per note.
+pub fn export_notes(notes: &[Todo], format: ExportFormat, dir: &Path) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir).context("Failed to create export directory")?;
+    notes.iter().map(|note| export_note(note, format, dir)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::Utc;
+
+    fn note(content: &str, is_checklist: bool) -> Todo {
+        Todo {
+            id: 7,
+            content: content.to_string(),
+            done: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            color: Some("#fff475".to_string()),
+            pinned: false,
+            archived: false,
+            labels: vec!["home".to_string(), "errands".to_string()],
+            is_checklist,
+            reminder: None,
+            deleted_at: None,
+            order_index: 0,
+        }
+    }
+
+    #[test]
+    fn test_markdown_checklist_and_labels() {
+        let md = render_markdown(&note("- [ ] Milk\n-[x] Eggs\nBread", true));
+        assert!(md.starts_with("- [ ] Milk\n- [x] Eggs\n- [ ] Bread\n"));
+        assert!(md.contains("Labels: home, errands"));
+    }
+
+    #[test]
+    fn test_html_escapes_and_checks() {
+        let html = render_html(&note("- [x] <b>Tools</b> & nails", true));
+        assert!(html.contains("<title>&lt;b&gt;Tools&lt;/b&gt; &amp; nails</title>"));
+        assert!(html.contains("disabled checked"));
+        assert!(html.contains("background: #fff475"));
+        assert!(html.contains("<span class=\"label\">home</span>"));
+    }
+
+    #[test]
+    fn test_html_rejects_unsafe_color() {
+        let mut n = note("Plain text", false);
+        n.color = Some("red;} body{display:none".to_string());
+        let html = render_html(&n);
+        assert!(html.contains("background: #ffffff"));
+        assert!(html.contains("<p>Plain text</p>"));
+    }
+
+    #[test]
+    fn test_export_to_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let notes = vec![note("Shopping list!", false), note("", false)];
+        let paths = export_notes(&notes, ExportFormat::Markdown, dir.path()).unwrap();
+
+        assert_eq!(paths[0].file_name().unwrap(), "note-7-shopping-list.md");
+        assert_eq!(paths[1].file_name().unwrap(), "note-7.md");
+        assert!(std::fs::read_to_string(&paths[0]).unwrap().starts_with("Shopping list!"));
+    }
+
+    #[test]
+    fn test_format_parse() {
+        assert_eq!(ExportFormat::parse("MD"), Some(ExportFormat::Markdown));
+        assert_eq!(ExportFormat::parse("html"), Some(ExportFormat::Html));
+        assert_eq!(ExportFormat::parse("pdf"), None);
+    }
+}
@@ -1,18 +1,36 @@
 //! SQLite-based note storage implementation.
 //!
 //! This module provides `SqliteNoteStore`, a local SQLite implementation of
-//! the `NoteBackend` trait. Schema supports Keep-style notes with color, pin, archive, labels, checklists, reminders.
+//! the `NoteBackend` trait. Schema supports Keep-style notes with color, pin, archive, labels, checklists, reminders
+//! and a manual order.
 
 use chrono::{DateTime, Utc};
-use rusqlite::{params, Connection};
+use rusqlite::{params, Connection, OptionalExtension};
 use std::path::Path;
 
-use crate::note_backend::{validate_content, NoteBackend, NoteBackendError, NoteBackendResult};
+use crate::db;
+use crate::note_backend::{
+    filter_matching, validate_content, NoteBackend, NoteBackendError, NoteBackendResult,
+};
+use crate::note_crypto::{self, KdfParams, NoteCipher};
+use crate::note_sync::{SyncRecord, VersionVector, CONFLICT_LABEL};
 use crate::todo::{Todo, TodoUpdateRequest};
 
+/// Columns read by `row_to_todo`, in order.
+const NOTE_COLUMNS: &str = "id, content, done, created_at, updated_at, color, pinned, archived, labels, is_checklist, reminder, deleted_at, order_index";
+
+/// Known plaintext sealed with the key so `unlock` can check the passphrase.
+const KEY_CHECK: &str = "myme-notes";
+
 /// SQLite-based note storage.
+///
+/// With encryption enabled, note content is stored sealed (see `note_crypto`)
+/// and the store starts locked: reads and writes fail with
+/// `NoteBackendError::Locked` until `unlock` is given the passphrase.
 pub struct SqliteNoteStore {
     conn: Connection,
+    cipher: Option<NoteCipher>,
+    encrypted: bool,
 }
 
 impl SqliteNoteStore {
@@ -20,21 +38,36 @@ impl SqliteNoteStore {
     ///
     /// Creates the database file and schema if they don't exist.
     pub fn new<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
-        let conn = Connection::open(path)?;
-        let store = Self { conn };
-        store.init_schema()?;
-        Ok(store)
+        let conn = db::open_maintained(path.as_ref())?;
+        Self::open(conn)
     }
 
     /// Create an in-memory note store (for testing).
     #[cfg(test)]
     pub fn in_memory() -> anyhow::Result<Self> {
-        let conn = Connection::open_in_memory()?;
-        let store = Self { conn };
-        store.init_schema()?;
+        Self::open(db::open_in_memory()?)
+    }
+
+    fn open(conn: Connection) -> anyhow::Result<Self> {
+        let mut store = Self { conn, cipher: None, encrypted: false };
+        store.migrate()?;
+        store.encrypted = store.encryption_meta("salt")?.is_some();
         Ok(store)
     }
 
+    fn migrate(&self) -> anyhow::Result<()> {
+        db::migrate(&self.conn, 7, |version| match version {
+            1 => self.init_schema(),
+            2 => self.add_changed_at(),
+            3 => self.add_trash(),
+            4 => self.add_encryption_meta(),
+            5 => self.add_search_index(),
+            6 => self.add_order_index(),
+            7 => self.add_labels_table(),
+            _ => Ok(()),
+        })
+    }
+
     /// Initialize the database schema.
     ///
     /// Detects old schema (TEXT id or missing pinned column) and migrates by
@@ -66,6 +99,183 @@ impl SqliteNoteStore {
 
             CREATE INDEX IF NOT EXISTS idx_notes_archived ON notes(archived);
             CREATE INDEX IF NOT EXISTS idx_notes_pinned_updated ON notes(pinned DESC, updated_at DESC);
+
+            CREATE TABLE IF NOT EXISTS note_tombstones (
+                sync_uuid TEXT PRIMARY KEY,
+                deleted_at TEXT NOT NULL,
+                version TEXT NOT NULL DEFAULT '{}',
+                dirty INTEGER NOT NULL DEFAULT 1
+            );
+
+            CREATE TABLE IF NOT EXISTS sync_meta (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );
+            "#,
+        )?;
+        self.migrate_sync_columns()?;
+        Ok(())
+    }
+
+    /// Add sync tracking columns to note tables created before sync existed.
+    /// Existing notes start dirty so they are published on the first sync.
+    fn migrate_sync_columns(&self) -> anyhow::Result<()> {
+        let columns: Vec<String> = self
+            .conn
+            .prepare("PRAGMA table_info(notes)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !columns.iter().any(|c| c == "sync_uuid") {
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE notes ADD COLUMN sync_uuid TEXT;
+                ALTER TABLE notes ADD COLUMN version TEXT NOT NULL DEFAULT '{}';
+                ALTER TABLE notes ADD COLUMN dirty INTEGER NOT NULL DEFAULT 1;
+                "#,
+            )?;
+        }
+        self.conn.execute_batch(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_notes_sync_uuid ON notes(sync_uuid);",
+        )?;
+        Ok(())
+    }
+
+    /// Track when each note last changed locally (unix ms), for delta fetches.
+    /// Triggers keep it current for every write, including notes applied by
+    /// sync whose `updated_at` comes from another device.
+    fn add_changed_at(&self) -> anyhow::Result<()> {
+        self.conn.execute_batch(
+            r#"
+            ALTER TABLE notes ADD COLUMN changed_at INTEGER NOT NULL DEFAULT 0;
+            UPDATE notes
+            SET changed_at = coalesce(CAST(round((julianday(updated_at) - 2440587.5) * 86400000) AS INTEGER), 0);
+            CREATE INDEX IF NOT EXISTS idx_notes_changed_at ON notes(changed_at);
+
+            CREATE TRIGGER IF NOT EXISTS notes_changed_insert AFTER INSERT ON notes BEGIN
+                UPDATE notes SET changed_at = CAST(round((julianday('now') - 2440587.5) * 86400000) AS INTEGER)
+                WHERE id = NEW.id;
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS notes_changed_update
+            AFTER UPDATE OF content, done, updated_at, color, pinned, archived, labels, is_checklist, reminder
+            ON notes BEGIN
+                UPDATE notes SET changed_at = CAST(round((julianday('now') - 2440587.5) * 86400000) AS INTEGER)
+                WHERE id = NEW.id;
+            END;
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Soft delete: trashed notes keep their row with `deleted_at` set until purged.
+    /// The change trigger is recreated so trashing and restoring bump `changed_at`.
+    fn add_trash(&self) -> anyhow::Result<()> {
+        self.conn.execute_batch(
+            r#"
+            ALTER TABLE notes ADD COLUMN deleted_at TEXT NULL;
+            CREATE INDEX IF NOT EXISTS idx_notes_deleted_at ON notes(deleted_at);
+
+            DROP TRIGGER IF EXISTS notes_changed_update;
+            CREATE TRIGGER notes_changed_update
+            AFTER UPDATE OF content, done, updated_at, color, pinned, archived, labels, is_checklist, reminder, deleted_at
+            ON notes BEGIN
+                UPDATE notes SET changed_at = CAST(round((julianday('now') - 2440587.5) * 86400000) AS INTEGER)
+                WHERE id = NEW.id;
+            END;
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Salt and key check for content encryption; empty while encryption is off.
+    fn add_encryption_meta(&self) -> anyhow::Result<()> {
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS note_encryption (key TEXT PRIMARY KEY, value TEXT NOT NULL);",
+        )?;
+        Ok(())
+    }
+
+    /// Trigram full-text index over plaintext note content, keyed by note id and
+    /// kept in sync by triggers. Sealed content is never indexed, so an
+    /// encrypted store searches by decrypting instead.
+    fn add_search_index(&self) -> anyhow::Result<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS notes_search USING fts5(
+                content,
+                tokenize = 'trigram'
+            );
+
+            CREATE TRIGGER IF NOT EXISTS notes_search_insert AFTER INSERT ON notes
+            WHEN NEW.content NOT LIKE 'enc:v1:%' BEGIN
+                INSERT INTO notes_search (rowid, content) VALUES (NEW.id, NEW.content);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS notes_search_update AFTER UPDATE OF content ON notes BEGIN
+                DELETE FROM notes_search WHERE rowid = OLD.id;
+                INSERT INTO notes_search (rowid, content)
+                SELECT NEW.id, NEW.content WHERE NEW.content NOT LIKE 'enc:v1:%';
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS notes_search_delete AFTER DELETE ON notes BEGIN
+                DELETE FROM notes_search WHERE rowid = OLD.id;
+            END;
+
+            INSERT INTO notes_search (rowid, content)
+            SELECT id, content FROM notes WHERE content NOT LIKE 'enc:v1:%';
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Manual note order: higher `order_index` sorts first. Existing notes keep
+    /// their updated_at order, and a trigger puts each new note (created here,
+    /// by sync or as a conflict copy) on top. The order is local to this device.
+    fn add_order_index(&self) -> anyhow::Result<()> {
+        self.conn.execute_batch(
+            r#"
+            ALTER TABLE notes ADD COLUMN order_index INTEGER NOT NULL DEFAULT 0;
+            UPDATE notes SET order_index = 1 + (
+                SELECT count(*) FROM notes AS older
+                WHERE (older.updated_at, older.id) < (notes.updated_at, notes.id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_notes_pinned_order ON notes(pinned DESC, order_index DESC);
+
+            CREATE TRIGGER IF NOT EXISTS notes_order_insert AFTER INSERT ON notes
+            WHEN NEW.order_index = 0 BEGIN
+                UPDATE notes SET order_index = (SELECT max(order_index) FROM notes) + 1
+                WHERE id = NEW.id;
+            END;
+            "#,
+        )?;
+        Ok(())
+    }
+
+    /// Registered labels, so a label exists before (and after) any note uses
+    /// it. Filled from the notes' `labels` arrays, and triggers register every
+    /// label a note is given, whether edited here or applied by sync.
+    fn add_labels_table(&self) -> anyhow::Result<()> {
+        self.conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS labels (
+                name TEXT PRIMARY KEY
+            );
+            INSERT OR IGNORE INTO labels (name)
+                SELECT DISTINCT label.value FROM notes, json_each(notes.labels) AS label
+                WHERE json_valid(notes.labels) AND label.type = 'text';
+
+            CREATE TRIGGER IF NOT EXISTS notes_labels_insert AFTER INSERT ON notes
+            WHEN json_valid(NEW.labels) BEGIN
+                INSERT OR IGNORE INTO labels (name)
+                    SELECT value FROM json_each(NEW.labels) WHERE type = 'text';
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS notes_labels_update AFTER UPDATE OF labels ON notes
+            WHEN json_valid(NEW.labels) BEGIN
+                INSERT OR IGNORE INTO labels (name)
+                    SELECT value FROM json_each(NEW.labels) WHERE type = 'text';
+            END;
             "#,
         )?;
         Ok(())
@@ -96,9 +306,9 @@ impl SqliteNoteStore {
     }
 
     /// Convert a database row to a Todo.
-    fn row_to_todo(row: &rusqlite::Row) -> rusqlite::Result<Todo> {
+    fn row_to_todo(&self, row: &rusqlite::Row) -> rusqlite::Result<Todo> {
         let id: i64 = row.get(0)?;
-        let content: String = row.get(1)?;
+        let content = self.open_content(row.get(1)?)?;
         let done: i32 = row.get(2)?;
         let created_at_str: String = row.get(3)?;
         let updated_at_str: String = row.get(4)?;
@@ -108,6 +318,8 @@ impl SqliteNoteStore {
         let labels_str: String = row.get(8)?;
         let is_checklist: i32 = row.get(9)?;
         let reminder_str: Option<String> = row.get(10)?;
+        let deleted_at_str: Option<String> = row.get(11)?;
+        let order_index: i64 = row.get(12)?;
 
         let created_at = DateTime::parse_from_rfc3339(&created_at_str)
             .map(|dt| dt.with_timezone(&Utc))
@@ -120,6 +332,8 @@ impl SqliteNoteStore {
 
         let reminder = reminder_str
             .and_then(|s| DateTime::parse_from_rfc3339(&s).ok().map(|dt| dt.with_timezone(&Utc)));
+        let deleted_at = deleted_at_str
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok().map(|dt| dt.with_timezone(&Utc)));
 
         Ok(Todo {
             id,
@@ -133,6 +347,8 @@ impl SqliteNoteStore {
             labels,
             is_checklist: is_checklist != 0,
             reminder,
+            deleted_at,
+            order_index,
         })
     }
 
@@ -153,38 +369,612 @@ impl SqliteNoteStore {
     }
 }
 
+/// Content encryption (see `note_crypto`).
+impl SqliteNoteStore {
+    /// Whether note content is stored encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.encrypted
+    }
+
+    /// Encrypted and not yet unlocked with the passphrase.
+    pub fn is_locked(&self) -> bool {
+        self.encrypted && self.cipher.is_none()
+    }
+
+    /// Unlock an encrypted store with its passphrase.
+    pub fn unlock(&mut self, passphrase: &str) -> anyhow::Result<()> {
+        let (Some(salt), Some(kdf), Some(check)) = (
+            self.encryption_meta("salt")?,
+            self.encryption_meta("kdf")?,
+            self.encryption_meta("check")?,
+        ) else {
+            anyhow::bail!("Notes database is not encrypted");
+        };
+        let salt = note_crypto::decode_salt(&salt)?;
+        let cipher = NoteCipher::from_passphrase(passphrase, &salt, KdfParams::parse(&kdf)?)?;
+        if cipher.decrypt(&check).ok().as_deref() != Some(KEY_CHECK) {
+            anyhow::bail!("Wrong passphrase");
+        }
+        self.cipher = Some(cipher);
+        tracing::info!("Notes database unlocked");
+        Ok(())
+    }
+
+    /// Forget the key; the store is locked again until `unlock`.
+    pub fn lock(&mut self) {
+        self.cipher = None;
+    }
+
+    /// Turn on encryption with `passphrase`, encrypting existing plaintext
+    /// notes in one transaction. The store is left unlocked.
+    pub fn enable_encryption(&mut self, passphrase: &str) -> anyhow::Result<()> {
+        if self.encrypted {
+            anyhow::bail!("Notes database is already encrypted");
+        }
+        let salt = note_crypto::generate_salt();
+        let kdf = KdfParams::default();
+        let cipher = NoteCipher::from_passphrase(passphrase, &salt, kdf)?;
+        // Zero the pages the plaintext rows are freed from
+        self.conn.execute_batch("PRAGMA secure_delete = ON;")?;
+
+        let plaintext: Vec<(i64, String)> = self
+            .conn
+            .prepare("SELECT id, content FROM notes")?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let tx = self.conn.transaction()?;
+        for (id, content) in &plaintext {
+            if !note_crypto::is_encrypted(content) {
+                tx.execute(
+                    "UPDATE notes SET content = ?1 WHERE id = ?2",
+                    params![cipher.encrypt(content)?, id],
+                )?;
+            }
+        }
+        tx.execute(
+            "INSERT OR REPLACE INTO note_encryption (key, value)
+             VALUES ('salt', ?1), ('kdf', ?2), ('check', ?3)",
+            params![note_crypto::encode_salt(&salt), kdf.encode(), cipher.encrypt(KEY_CHECK)?],
+        )?;
+        tx.commit()?;
+        // The update trigger dropped the plaintext rows from the search index;
+        // merge its segments, rebuild the file and empty the WAL so the old
+        // pages don't survive in free space or the log
+        self.conn.execute("INSERT INTO notes_search (notes_search) VALUES ('optimize')", [])?;
+        self.conn.execute_batch("VACUUM;")?;
+        let _checkpoint: (i64, i64, i64) =
+            self.conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })?;
+
+        self.encrypted = true;
+        self.cipher = Some(cipher);
+        tracing::info!("Encrypted {} existing note(s)", plaintext.len());
+        Ok(())
+    }
+
+    fn encryption_meta(&self, key: &str) -> anyhow::Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row("SELECT value FROM note_encryption WHERE key = ?1", params![key], |row| {
+                row.get(0)
+            })
+            .optional()?)
+    }
+
+    fn check_unlocked(&self) -> NoteBackendResult<()> {
+        if self.is_locked() {
+            return Err(NoteBackendError::Locked);
+        }
+        Ok(())
+    }
+
+    /// Content as stored: sealed when encryption is on.
+    fn seal_content(&self, content: &str) -> NoteBackendResult<String> {
+        match &self.cipher {
+            Some(cipher) => Ok(cipher.encrypt(content)?),
+            None if self.encrypted => Err(NoteBackendError::Locked),
+            None => Ok(content.to_string()),
+        }
+    }
+
+    /// Stored content back to plaintext (rows written before encryption pass through).
+    fn open_content(&self, stored: String) -> rusqlite::Result<String> {
+        match &self.cipher {
+            Some(cipher) if note_crypto::is_encrypted(&stored) => {
+                cipher.decrypt(&stored).map_err(|e| {
+                    rusqlite::Error::FromSqlConversionFailure(
+                        1,
+                        rusqlite::types::Type::Text,
+                        e.into(),
+                    )
+                })
+            }
+            _ => Ok(stored),
+        }
+    }
+}
+
+/// Sync support (see `note_sync`).
+impl SqliteNoteStore {
+    /// This device's sync ID, generated on first use.
+    pub fn sync_device_id(&self) -> anyhow::Result<String> {
+        let existing: Option<String> = self
+            .conn
+            .query_row("SELECT value FROM sync_meta WHERE key = 'device_id'", [], |row| row.get(0))
+            .optional()?;
+        if let Some(id) = existing {
+            return Ok(id);
+        }
+
+        let nanos = Utc::now().timestamp_nanos_opt().unwrap_or_default() as u64;
+        let id = format!("dev-{:x}{:x}", nanos, std::process::id());
+        self.set_sync_device_id(&id)?;
+        Ok(id)
+    }
+
+    /// Override the device ID (e.g. from config).
+    pub fn set_sync_device_id(&self, device_id: &str) -> anyhow::Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO sync_meta (key, value) VALUES ('device_id', ?1)",
+            params![device_id],
+        )?;
+        Ok(())
+    }
+
+    /// Give new notes a sync UUID and fold unpublished local edits into their versions.
+    pub(crate) fn prepare_sync(&self, device_id: &str) -> anyhow::Result<()> {
+        self.conn.execute(
+            "UPDATE notes SET sync_uuid = ?1 || '-' || id WHERE sync_uuid IS NULL",
+            params![device_id],
+        )?;
+
+        for table in ["notes", "note_tombstones"] {
+            let dirty: Vec<(String, String)> = self
+                .conn
+                .prepare(&format!("SELECT sync_uuid, version FROM {} WHERE dirty = 1", table))?
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for (uuid, version_json) in dirty {
+                let mut version: VersionVector =
+                    serde_json::from_str(&version_json).unwrap_or_default();
+                version.bump(device_id);
+                self.conn.execute(
+                    &format!("UPDATE {} SET version = ?1, dirty = 0 WHERE sync_uuid = ?2", table),
+                    params![serde_json::to_string(&version)?, uuid],
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn row_to_sync_record(&self, row: &rusqlite::Row) -> rusqlite::Result<SyncRecord> {
+        let todo = self.row_to_todo(row)?;
+        let uuid: String = row.get(13)?;
+        let version_json: String = row.get(14)?;
+        Ok(SyncRecord {
+            uuid,
+            content: todo.content,
+            done: todo.done,
+            created_at: todo.created_at,
+            updated_at: todo.updated_at,
+            color: todo.color,
+            pinned: todo.pinned,
+            archived: todo.archived,
+            labels: todo.labels,
+            is_checklist: todo.is_checklist,
+            reminder: todo.reminder,
+            deleted: false,
+            version: serde_json::from_str(&version_json).unwrap_or_default(),
+        })
+    }
+
+    fn tombstone_record(uuid: String, deleted_at: String, version_json: String) -> SyncRecord {
+        let deleted_at = DateTime::parse_from_rfc3339(&deleted_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+        SyncRecord {
+            uuid,
+            content: String::new(),
+            done: false,
+            created_at: deleted_at,
+            updated_at: deleted_at,
+            color: None,
+            pinned: false,
+            archived: false,
+            labels: vec![],
+            is_checklist: false,
+            reminder: None,
+            deleted: true,
+            version: serde_json::from_str(&version_json).unwrap_or_default(),
+        }
+    }
+
+    /// Current local state of a synced note (or its tombstone).
+    pub(crate) fn sync_record(&self, uuid: &str) -> anyhow::Result<Option<SyncRecord>> {
+        self.check_unlocked()?;
+        let note = self
+            .conn
+            .query_row(
+                &format!(
+                    "SELECT {}, sync_uuid, version FROM notes WHERE sync_uuid = ?1",
+                    NOTE_COLUMNS
+                ),
+                params![uuid],
+                |row| self.row_to_sync_record(row),
+            )
+            .optional()?;
+        if note.is_some() {
+            return Ok(note);
+        }
+
+        let tombstone = self
+            .conn
+            .query_row(
+                "SELECT sync_uuid, deleted_at, version FROM note_tombstones WHERE sync_uuid = ?1",
+                params![uuid],
+                |row| Ok(Self::tombstone_record(row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?;
+        Ok(tombstone)
+    }
+
+    /// Store a record received from (or merged with) another device; not marked dirty.
+    pub(crate) fn apply_sync_record(&self, record: &SyncRecord) -> anyhow::Result<()> {
+        let version = serde_json::to_string(&record.version)?;
+
+        if record.deleted {
+            self.conn.execute("DELETE FROM notes WHERE sync_uuid = ?1", params![record.uuid])?;
+            self.conn.execute(
+                "INSERT OR REPLACE INTO note_tombstones (sync_uuid, deleted_at, version, dirty)
+                 VALUES (?1, ?2, ?3, 0)",
+                params![record.uuid, record.updated_at.to_rfc3339(), version],
+            )?;
+            return Ok(());
+        }
+
+        self.conn
+            .execute("DELETE FROM note_tombstones WHERE sync_uuid = ?1", params![record.uuid])?;
+        let labels = serde_json::to_string(&record.labels)?;
+        let content = self.seal_content(&record.content)?;
+        let params = params![
+            content,
+            record.done as i32,
+            record.created_at.to_rfc3339(),
+            record.updated_at.to_rfc3339(),
+            record.color,
+            record.pinned as i32,
+            record.archived as i32,
+            labels,
+            record.is_checklist as i32,
+            record.reminder.map(|dt| dt.to_rfc3339()),
+            record.uuid,
+            version,
+        ];
+        let updated = self.conn.execute(
+            r#"
+            UPDATE notes
+            SET content = ?1, done = ?2, created_at = ?3, updated_at = ?4, color = ?5, pinned = ?6,
+                archived = ?7, labels = ?8, is_checklist = ?9, reminder = ?10, version = ?12, dirty = 0
+            WHERE sync_uuid = ?11
+            "#,
+            params,
+        )?;
+        if updated == 0 {
+            self.conn.execute(
+                r#"
+                INSERT INTO notes (content, done, created_at, updated_at, color, pinned, archived, labels, is_checklist, reminder, sync_uuid, version, dirty)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, 0)
+                "#,
+                params,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Keep the losing side of a conflict as a new local note labelled "conflict".
+    pub(crate) fn insert_conflict_copy(&self, record: &SyncRecord) -> anyhow::Result<()> {
+        let mut labels = record.labels.clone();
+        if !labels.iter().any(|l| l == CONFLICT_LABEL) {
+            labels.push(CONFLICT_LABEL.to_string());
+        }
+        let now = Utc::now().to_rfc3339();
+        self.conn.execute(
+            r#"
+            INSERT INTO notes (content, done, created_at, updated_at, color, pinned, archived, labels, is_checklist, reminder, dirty)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, 1)
+            "#,
+            params![
+                self.seal_content(&record.content)?,
+                record.done as i32,
+                record.created_at.to_rfc3339(),
+                now,
+                record.color,
+                record.pinned as i32,
+                record.archived as i32,
+                serde_json::to_string(&labels)?,
+                record.is_checklist as i32,
+                record.reminder.map(|dt| dt.to_rfc3339()),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// All synced notes and tombstones, as published to other devices.
+    ///
+    /// Snapshots carry note content in the clear, so an encrypted store refuses.
+    pub(crate) fn sync_snapshot(&self) -> anyhow::Result<Vec<SyncRecord>> {
+        if self.encrypted {
+            anyhow::bail!("Notes sync is unavailable while notes are encrypted");
+        }
+        let mut records: Vec<SyncRecord> = self
+            .conn
+            .prepare(&format!(
+                "SELECT {}, sync_uuid, version FROM notes WHERE sync_uuid IS NOT NULL",
+                NOTE_COLUMNS
+            ))?
+            .query_map([], |row| self.row_to_sync_record(row))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let tombstones = self
+            .conn
+            .prepare("SELECT sync_uuid, deleted_at, version FROM note_tombstones")?
+            .query_map([], |row| Ok(Self::tombstone_record(row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        records.extend(tombstones);
+        Ok(records)
+    }
+}
+
+/// A registered label and how many notes show under it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct LabelUsage {
+    pub name: String,
+    /// Notes carrying the label that are neither archived nor trashed
+    pub count: usize,
+}
+
+/// Label management. Labels are stored on each note, so renaming, merging or
+/// deleting one rewrites every note that carries it (archived and trashed
+/// notes included) in a single transaction, and marks them for sync.
+impl SqliteNoteStore {
+    /// Every label with its usage count, by name.
+    pub fn list_labels(&self) -> NoteBackendResult<Vec<LabelUsage>> {
+        let storage = |e: rusqlite::Error| NoteBackendError::storage(e.to_string());
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT l.name, (
+                     SELECT count(*) FROM notes, json_each(notes.labels) AS label
+                     WHERE label.value = l.name AND notes.archived = 0
+                       AND notes.deleted_at IS NULL
+                 )
+                 FROM labels AS l
+                 ORDER BY l.name COLLATE NOCASE",
+            )
+            .map_err(storage)?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(LabelUsage { name: row.get(0)?, count: row.get::<_, i64>(1)? as usize })
+            })
+            .map_err(storage)?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(storage)
+    }
+
+    /// Register a label no note uses yet. Existing labels are left alone.
+    pub fn create_label(&self, name: &str) -> NoteBackendResult<()> {
+        let name = validate_label(name)?;
+        self.conn
+            .execute("INSERT OR IGNORE INTO labels (name) VALUES (?1)", params![name])
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Rename `from` to `to` on every note. Returns the number of notes changed.
+    ///
+    /// # Errors
+    /// Returns `NoteBackendError::NotFound` if `from` isn't a label and
+    /// `NoteBackendError::Validation` if `to` is empty or already exists (use
+    /// `merge_labels` to combine two labels).
+    pub fn rename_label(&self, from: &str, to: &str) -> NoteBackendResult<usize> {
+        let to = validate_label(to)?;
+        if from != to && self.label_exists(to)? {
+            return Err(NoteBackendError::validation(format!("Label '{}' already exists", to)));
+        }
+        self.relabel(from, Some(to))
+    }
+
+    /// Fold `from` into `into`: notes carrying `from` get `into` instead (once),
+    /// and `from` goes away. Returns the number of notes changed.
+    pub fn merge_labels(&self, from: &str, into: &str) -> NoteBackendResult<usize> {
+        let into = validate_label(into)?;
+        self.relabel(from, Some(into))
+    }
+
+    /// Remove a label from every note and forget it. Returns the number of notes changed.
+    pub fn delete_label(&self, name: &str) -> NoteBackendResult<usize> {
+        self.relabel(name, None)
+    }
+
+    fn label_exists(&self, name: &str) -> NoteBackendResult<bool> {
+        self.conn
+            .query_row("SELECT 1 FROM labels WHERE name = ?1", params![name], |_| Ok(()))
+            .optional()
+            .map(|found| found.is_some())
+            .map_err(|e| NoteBackendError::storage(e.to_string()))
+    }
+
+    /// Replace (or with `None`, drop) label `from` on every note that has it.
+    fn relabel(&self, from: &str, to: Option<&str>) -> NoteBackendResult<usize> {
+        if !self.label_exists(from)? {
+            return Err(NoteBackendError::not_found(format!("label {}", from)));
+        }
+        if to == Some(from) {
+            return Ok(0);
+        }
+
+        let storage = |e: rusqlite::Error| NoteBackendError::storage(e.to_string());
+        let tx = self.conn.unchecked_transaction().map_err(storage)?;
+        let affected: Vec<(i64, String)> = tx
+            .prepare(
+                "SELECT id, labels FROM notes
+                 WHERE EXISTS (SELECT 1 FROM json_each(notes.labels) WHERE value = ?1)",
+            )
+            .map_err(storage)?
+            .query_map(params![from], |row| Ok((row.get(0)?, row.get(1)?)))
+            .map_err(storage)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(storage)?;
+
+        for (id, labels_json) in &affected {
+            let labels: Vec<String> = serde_json::from_str(labels_json).unwrap_or_default();
+            let mut relabelled: Vec<String> = Vec::with_capacity(labels.len());
+            for label in labels {
+                let label = if label == from {
+                    match to {
+                        Some(to) => to.to_string(),
+                        None => continue,
+                    }
+                } else {
+                    label
+                };
+                if !relabelled.contains(&label) {
+                    relabelled.push(label);
+                }
+            }
+            let labels_json = serde_json::to_string(&relabelled)
+                .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+            tx.execute(
+                "UPDATE notes SET labels = ?1, dirty = 1 WHERE id = ?2",
+                params![labels_json, id],
+            )
+            .map_err(storage)?;
+        }
+
+        tx.execute("DELETE FROM labels WHERE name = ?1", params![from]).map_err(storage)?;
+        if let Some(to) = to {
+            tx.execute("INSERT OR IGNORE INTO labels (name) VALUES (?1)", params![to])
+                .map_err(storage)?;
+        }
+        tx.commit().map_err(storage)?;
+        tracing::info!("Relabelled {} note(s) from '{}'", affected.len(), from);
+        Ok(affected.len())
+    }
+}
+
+/// Trimmed label name, or a validation error if it's empty.
+fn validate_label(name: &str) -> NoteBackendResult<&str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(NoteBackendError::validation("Label cannot be empty"));
+    }
+    Ok(name)
+}
+
 impl NoteBackend for SqliteNoteStore {
     fn list(&self) -> NoteBackendResult<Vec<Todo>> {
+        self.list_page(0, usize::MAX)
+    }
+
+    fn list_page(&self, offset: usize, limit: usize) -> NoteBackendResult<Vec<Todo>> {
+        self.check_unlocked()?;
         let mut stmt = self
             .conn
-            .prepare(
-                "SELECT id, content, done, created_at, updated_at, color, pinned, archived, labels, is_checklist, reminder
+            .prepare(&format!(
+                "SELECT {}
                  FROM notes
-                 WHERE archived = 0
-                 ORDER BY pinned DESC, updated_at DESC",
-            )
+                 WHERE archived = 0 AND deleted_at IS NULL
+                 ORDER BY pinned DESC, order_index DESC, id DESC
+                 LIMIT ?1 OFFSET ?2",
+                NOTE_COLUMNS
+            ))
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+
+        // SQLite treats a negative LIMIT as "no limit"
+        let limit = i64::try_from(limit).unwrap_or(-1);
+        let offset = i64::try_from(offset).unwrap_or(i64::MAX);
+        let rows = stmt
+            .query_map(params![limit, offset], |row| self.row_to_todo(row))
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(|e| NoteBackendError::storage(e.to_string()))
+    }
+
+    fn list_changed_since(&self, since: DateTime<Utc>) -> NoteBackendResult<Vec<Todo>> {
+        self.check_unlocked()?;
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                "SELECT {}
+                 FROM notes
+                 WHERE changed_at >= ?1
+                 ORDER BY changed_at DESC",
+                NOTE_COLUMNS
+            ))
             .map_err(|e| NoteBackendError::storage(e.to_string()))?;
 
         let rows = stmt
-            .query_map([], Self::row_to_todo)
+            .query_map(params![since.timestamp_millis()], |row| self.row_to_todo(row))
             .map_err(|e| NoteBackendError::storage(e.to_string()))?;
 
         rows.collect::<Result<Vec<_>, _>>().map_err(|e| NoteBackendError::storage(e.to_string()))
     }
 
     fn list_archived(&self) -> NoteBackendResult<Vec<Todo>> {
+        self.check_unlocked()?;
         let mut stmt = self
             .conn
-            .prepare(
-                "SELECT id, content, done, created_at, updated_at, color, pinned, archived, labels, is_checklist, reminder
+            .prepare(&format!(
+                "SELECT {}
                  FROM notes
-                 WHERE archived = 1
+                 WHERE archived = 1 AND deleted_at IS NULL
                  ORDER BY updated_at DESC",
-            )
+                NOTE_COLUMNS
+            ))
             .map_err(|e| NoteBackendError::storage(e.to_string()))?;
 
         let rows = stmt
-            .query_map([], Self::row_to_todo)
+            .query_map([], |row| self.row_to_todo(row))
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(|e| NoteBackendError::storage(e.to_string()))
+    }
+
+    fn search(&self, query: &str, limit: usize) -> NoteBackendResult<Vec<Todo>> {
+        self.check_unlocked()?;
+        if self.encrypted {
+            let mut notes = self.list()?;
+            notes.extend(self.list_archived()?);
+            return Ok(filter_matching(notes, query, limit));
+        }
+
+        let words: Vec<String> =
+            query.split_whitespace().map(|w| format!("%{}%", w.to_lowercase())).collect();
+        if words.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // One LIKE per word; the trigram index serves words of 3+ characters
+        let conditions: Vec<String> =
+            (0..words.len()).map(|i| format!("content LIKE ?{}", i + 1)).collect();
+        let sql = format!(
+            "SELECT {}
+             FROM notes
+             WHERE deleted_at IS NULL
+               AND id IN (SELECT rowid FROM notes_search WHERE {})
+             ORDER BY updated_at DESC
+             LIMIT {}",
+            NOTE_COLUMNS,
+            conditions.join(" AND "),
+            i64::try_from(limit).unwrap_or(-1)
+        );
+
+        let mut stmt =
+            self.conn.prepare(&sql).map_err(|e| NoteBackendError::storage(e.to_string()))?;
+        let rows = stmt
+            .query_map(rusqlite::params_from_iter(&words), |row| self.row_to_todo(row))
             .map_err(|e| NoteBackendError::storage(e.to_string()))?;
 
         rows.collect::<Result<Vec<_>, _>>().map_err(|e| NoteBackendError::storage(e.to_string()))
@@ -201,12 +991,10 @@ impl NoteBackend for SqliteNoteStore {
     }
 
     fn get(&self, id: i64) -> NoteBackendResult<Option<Todo>> {
+        self.check_unlocked()?;
         let mut stmt = self
             .conn
-            .prepare(
-                "SELECT id, content, done, created_at, updated_at, color, pinned, archived, labels, is_checklist, reminder
-                 FROM notes WHERE id = ?1",
-            )
+            .prepare(&format!("SELECT {} FROM notes WHERE id = ?1", NOTE_COLUMNS))
             .map_err(|e| NoteBackendError::storage(e.to_string()))?;
 
         let mut rows =
@@ -214,7 +1002,7 @@ impl NoteBackend for SqliteNoteStore {
 
         match rows.next().map_err(|e| NoteBackendError::storage(e.to_string()))? {
             Some(row) => Ok(Some(
-                Self::row_to_todo(row).map_err(|e| NoteBackendError::storage(e.to_string()))?,
+                self.row_to_todo(row).map_err(|e| NoteBackendError::storage(e.to_string()))?,
             )),
             None => Ok(None),
         }
@@ -233,12 +1021,21 @@ impl NoteBackend for SqliteNoteStore {
                 INSERT INTO notes (content, done, created_at, updated_at, color, pinned, archived, labels, is_checklist, reminder)
                 VALUES (?1, 0, ?2, ?3, NULL, 0, 0, '[]', ?4, NULL)
                 "#,
-                params![content, created_at_str, updated_at_str, is_checklist as i32],
+                params![
+                    self.seal_content(content)?,
+                    created_at_str,
+                    updated_at_str,
+                    is_checklist as i32
+                ],
             )
             .map_err(|e| NoteBackendError::storage(e.to_string()))?;
 
         let id = self.conn.last_insert_rowid();
         tracing::debug!("Created note with ID: {}", id);
+        let order_index = self
+            .conn
+            .query_row("SELECT order_index FROM notes WHERE id = ?1", params![id], |row| row.get(0))
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
 
         Ok(Todo {
             id,
@@ -252,6 +1049,8 @@ impl NoteBackend for SqliteNoteStore {
             labels: vec![],
             is_checklist,
             reminder: None,
+            deleted_at: None,
+            order_index,
         })
     }
 
@@ -293,11 +1092,11 @@ impl NoteBackend for SqliteNoteStore {
             .execute(
                 r#"
                 UPDATE notes
-                SET content = ?1, done = ?2, updated_at = ?3, color = ?4, pinned = ?5, archived = ?6, labels = ?7, is_checklist = ?8, reminder = ?9
+                SET content = ?1, done = ?2, updated_at = ?3, color = ?4, pinned = ?5, archived = ?6, labels = ?7, is_checklist = ?8, reminder = ?9, dirty = 1
                 WHERE id = ?10
                 "#,
                 params![
-                    note.content,
+                    self.seal_content(&note.content)?,
                     note.done as i32,
                     updated_at_str,
                     note.color,
@@ -320,6 +1119,17 @@ impl NoteBackend for SqliteNoteStore {
             return Err(NoteBackendError::not_found(id.to_string()));
         }
 
+        // Published notes leave a tombstone so other devices delete them too
+        self.conn
+            .execute(
+                r#"
+                INSERT OR REPLACE INTO note_tombstones (sync_uuid, deleted_at, version, dirty)
+                SELECT sync_uuid, ?2, version, 1 FROM notes WHERE id = ?1 AND sync_uuid IS NOT NULL
+                "#,
+                params![id, Utc::now().to_rfc3339()],
+            )
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+
         self.conn
             .execute("DELETE FROM notes WHERE id = ?1", params![id])
             .map_err(|e| NoteBackendError::storage(e.to_string()))?;
@@ -327,6 +1137,110 @@ impl NoteBackend for SqliteNoteStore {
         tracing::debug!("Deleted note: {}", id);
         Ok(())
     }
+
+    fn list_trashed(&self) -> NoteBackendResult<Vec<Todo>> {
+        self.check_unlocked()?;
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                "SELECT {}
+                 FROM notes
+                 WHERE deleted_at IS NOT NULL
+                 ORDER BY deleted_at DESC",
+                NOTE_COLUMNS
+            ))
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+
+        let rows = stmt
+            .query_map([], |row| self.row_to_todo(row))
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+
+        rows.collect::<Result<Vec<_>, _>>().map_err(|e| NoteBackendError::storage(e.to_string()))
+    }
+
+    fn trash(&self, id: i64) -> NoteBackendResult<()> {
+        if !self.exists(id).map_err(|e| NoteBackendError::storage(e.to_string()))? {
+            return Err(NoteBackendError::not_found(id.to_string()));
+        }
+
+        self.conn
+            .execute(
+                "UPDATE notes SET deleted_at = ?2 WHERE id = ?1 AND deleted_at IS NULL",
+                params![id, Utc::now().to_rfc3339()],
+            )
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+
+        tracing::debug!("Moved note to trash: {}", id);
+        Ok(())
+    }
+
+    fn restore(&self, id: i64) -> NoteBackendResult<Todo> {
+        let updated = self
+            .conn
+            .execute("UPDATE notes SET deleted_at = NULL WHERE id = ?1", params![id])
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+        if updated == 0 {
+            return Err(NoteBackendError::not_found(id.to_string()));
+        }
+
+        tracing::debug!("Restored note from trash: {}", id);
+        self.get(id)?.ok_or_else(|| NoteBackendError::not_found(id.to_string()))
+    }
+
+    fn reorder(&self, ids: &[i64]) -> NoteBackendResult<()> {
+        let storage = |e: rusqlite::Error| NoteBackendError::storage(e.to_string());
+        let tx = self.conn.unchecked_transaction().map_err(storage)?;
+        let mut slots = Vec::with_capacity(ids.len());
+        for id in ids {
+            let slot: Option<i64> = tx
+                .query_row("SELECT order_index FROM notes WHERE id = ?1", params![id], |row| {
+                    row.get(0)
+                })
+                .optional()
+                .map_err(storage)?;
+            slots.push(slot.ok_or_else(|| NoteBackendError::not_found(id.to_string()))?);
+        }
+        // The notes trade places among the positions they already hold
+        slots.sort_unstable_by(|a, b| b.cmp(a));
+        for (id, slot) in ids.iter().zip(slots) {
+            tx.execute("UPDATE notes SET order_index = ?1 WHERE id = ?2", params![slot, id])
+                .map_err(storage)?;
+        }
+        tx.commit().map_err(storage)?;
+        tracing::debug!("Reordered {} note(s)", ids.len());
+        Ok(())
+    }
+
+    fn purge_older_than(&self, days: u32) -> NoteBackendResult<usize> {
+        let cutoff = (Utc::now() - chrono::Duration::days(i64::from(days))).to_rfc3339();
+
+        // Same as delete: published notes leave a tombstone for other devices
+        self.conn
+            .execute(
+                r#"
+                INSERT OR REPLACE INTO note_tombstones (sync_uuid, deleted_at, version, dirty)
+                SELECT sync_uuid, deleted_at, version, 1 FROM notes
+                WHERE deleted_at IS NOT NULL AND julianday(deleted_at) <= julianday(?1)
+                    AND sync_uuid IS NOT NULL
+                "#,
+                params![cutoff],
+            )
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+
+        let purged = self
+            .conn
+            .execute(
+                "DELETE FROM notes
+                 WHERE deleted_at IS NOT NULL AND julianday(deleted_at) <= julianday(?1)",
+                params![cutoff],
+            )
+            .map_err(|e| NoteBackendError::storage(e.to_string()))?;
+
+        if purged > 0 {
+            tracing::info!("Purged {} note(s) from trash", purged);
+        }
+        Ok(purged)
+    }
 }
 
 #[cfg(test)]
@@ -505,4 +1419,343 @@ mod tests {
         let note = store.create("- [ ] item", true).unwrap();
         assert!(note.is_checklist);
     }
+
+    #[test]
+    fn test_list_page_matches_list_order() {
+        let store = create_test_store();
+        for i in 0..5 {
+            store.create(&format!("Note {}", i), false).unwrap();
+        }
+        let pinned = store.create("Pinned", false).unwrap();
+        store
+            .update(pinned.id, TodoUpdateRequest { pinned: Some(true), ..Default::default() })
+            .unwrap();
+
+        let paged: Vec<i64> =
+            (0..3).flat_map(|page| store.list_page(page * 2, 2).unwrap()).map(|n| n.id).collect();
+        let listed: Vec<i64> = store.list().unwrap().iter().map(|n| n.id).collect();
+        assert_eq!(paged, listed);
+        assert_eq!(paged[0], pinned.id);
+        assert!(store.list_page(6, 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_new_notes_go_on_top_and_edits_keep_order() {
+        let store = create_test_store();
+        let a = store.create("A", false).unwrap();
+        let b = store.create("B", false).unwrap();
+        assert!(b.order_index > a.order_index);
+        store
+            .update(a.id, TodoUpdateRequest { content: Some("A2".into()), ..Default::default() })
+            .unwrap();
+
+        let ids: Vec<i64> = store.list().unwrap().iter().map(|n| n.id).collect();
+        assert_eq!(ids, [b.id, a.id]);
+    }
+
+    #[test]
+    fn test_reorder_moves_within_held_positions() {
+        let store = create_test_store();
+        let ids: Vec<i64> =
+            (0..4).map(|i| store.create(&format!("Note {}", i), false).unwrap().id).collect();
+        // Listed newest first: 3, 2, 1, 0. Drag note 1 above note 3.
+        store.reorder(&[ids[1], ids[3], ids[2]]).unwrap();
+
+        let listed: Vec<i64> = store.list().unwrap().iter().map(|n| n.id).collect();
+        assert_eq!(listed, [ids[1], ids[3], ids[2], ids[0]]);
+
+        let err = store.reorder(&[ids[0], 999]).unwrap_err();
+        assert!(matches!(err, NoteBackendError::NotFound(_)));
+        // A failed reorder changes nothing
+        let after: Vec<i64> = store.list().unwrap().iter().map(|n| n.id).collect();
+        assert_eq!(after, listed);
+    }
+
+    #[test]
+    fn test_order_index_migration_keeps_updated_order() {
+        let store = create_test_store();
+        let old = store.create("Old", false).unwrap();
+        let new = store.create("New", false).unwrap();
+        // Edited last, so it led the list before manual ordering existed
+        store.update(old.id, TodoUpdateRequest { done: Some(true), ..Default::default() }).unwrap();
+        store
+            .conn
+            .execute_batch(
+                "DROP TRIGGER notes_order_insert;
+                 DROP INDEX idx_notes_pinned_order;
+                 ALTER TABLE notes DROP COLUMN order_index;",
+            )
+            .unwrap();
+
+        store.add_order_index().unwrap();
+
+        let ids: Vec<i64> = store.list().unwrap().iter().map(|n| n.id).collect();
+        assert_eq!(ids, [old.id, new.id]);
+        let next = store.create("Next", false).unwrap();
+        assert_eq!(store.list().unwrap()[0].id, next.id);
+    }
+
+    fn set_labels(store: &SqliteNoteStore, id: i64, labels: &[&str]) {
+        let labels = labels.iter().map(|l| l.to_string()).collect();
+        store.update(id, TodoUpdateRequest { labels: Some(labels), ..Default::default() }).unwrap();
+    }
+
+    fn usage(store: &SqliteNoteStore) -> Vec<(String, usize)> {
+        store.list_labels().unwrap().into_iter().map(|l| (l.name, l.count)).collect()
+    }
+
+    #[test]
+    fn test_labels_are_registered_and_counted() {
+        let store = create_test_store();
+        let a = store.create("A", false).unwrap();
+        let b = store.create("B", false).unwrap();
+        set_labels(&store, a.id, &["work", "Ideas"]);
+        set_labels(&store, b.id, &["work"]);
+        store
+            .update(b.id, TodoUpdateRequest { archived: Some(true), ..Default::default() })
+            .unwrap();
+        store.create_label("later").unwrap();
+        assert!(store.create_label("  ").is_err());
+
+        assert_eq!(
+            usage(&store),
+            [("Ideas".to_string(), 1), ("later".to_string(), 0), ("work".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_rename_merge_and_delete_labels_rewrite_notes() {
+        let store = create_test_store();
+        let a = store.create("A", false).unwrap();
+        let b = store.create("B", false).unwrap();
+        set_labels(&store, a.id, &["todo", "work"]);
+        set_labels(&store, b.id, &["work"]);
+        store.trash(b.id).unwrap();
+
+        assert_eq!(store.rename_label("work", "job").unwrap(), 2);
+        assert_eq!(store.get(a.id).unwrap().unwrap().labels, ["todo", "job"]);
+        assert!(matches!(store.rename_label("job", "todo"), Err(NoteBackendError::Validation(_))));
+        assert!(matches!(store.rename_label("work", "x"), Err(NoteBackendError::NotFound(_))));
+
+        // Merging doesn't leave the note with the label twice
+        assert_eq!(store.merge_labels("todo", "job").unwrap(), 1);
+        assert_eq!(store.get(a.id).unwrap().unwrap().labels, ["job"]);
+
+        assert_eq!(store.delete_label("job").unwrap(), 2);
+        assert!(store.get(a.id).unwrap().unwrap().labels.is_empty());
+        assert!(store.list_trashed().unwrap()[0].labels.is_empty());
+        assert!(usage(&store).is_empty());
+    }
+
+    #[test]
+    fn test_labels_migration_registers_existing_labels() {
+        let store = create_test_store();
+        let note = store.create("Tagged", false).unwrap();
+        set_labels(&store, note.id, &["home"]);
+        store
+            .conn
+            .execute_batch(
+                "DROP TRIGGER notes_labels_insert;
+                 DROP TRIGGER notes_labels_update;
+                 DROP TABLE labels;",
+            )
+            .unwrap();
+
+        store.add_labels_table().unwrap();
+
+        assert_eq!(usage(&store), [("home".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_list_changed_since_includes_sync_applied_notes() {
+        let store = create_test_store();
+        store.create("Old", false).unwrap();
+        // changed_at has millisecond resolution
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let since = Utc::now();
+        let edited = store.create("New", false).unwrap();
+
+        // A note from another device keeps its (older) remote updated_at
+        let long_ago = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z").unwrap().to_utc();
+        store
+            .apply_sync_record(&SyncRecord {
+                uuid: "remote-1".to_string(),
+                content: "From laptop".to_string(),
+                done: false,
+                created_at: long_ago,
+                updated_at: long_ago,
+                color: None,
+                pinned: false,
+                archived: false,
+                labels: vec![],
+                is_checklist: false,
+                reminder: None,
+                deleted: false,
+                version: VersionVector::default(),
+            })
+            .unwrap();
+
+        let mut changed: Vec<String> =
+            store.list_changed_since(since).unwrap().into_iter().map(|n| n.content).collect();
+        changed.sort();
+        assert_eq!(changed, ["From laptop", "New"]);
+        assert_eq!(store.get(edited.id).unwrap().unwrap().content, "New");
+    }
+
+    #[test]
+    fn test_trash_hides_note_until_restored() {
+        let store = create_test_store();
+        let note = store.create("Trash me", false).unwrap();
+        let archived = store.create("Archived", false).unwrap();
+        store
+            .update(archived.id, TodoUpdateRequest { archived: Some(true), ..Default::default() })
+            .unwrap();
+        store.trash(archived.id).unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let since = Utc::now();
+        store.trash(note.id).unwrap();
+        assert!(store.list().unwrap().is_empty());
+        assert!(store.list_archived().unwrap().is_empty());
+        assert_eq!(store.list_trashed().unwrap().len(), 2);
+        // Trashing counts as a change so open lists can drop the note
+        let changed = store.list_changed_since(since).unwrap();
+        assert_eq!(changed.len(), 1);
+        assert!(changed[0].deleted_at.is_some());
+
+        let restored = store.restore(note.id).unwrap();
+        assert!(restored.deleted_at.is_none());
+        assert_eq!(store.list().unwrap().len(), 1);
+        assert!(matches!(store.trash(99999), Err(NoteBackendError::NotFound(_))));
+        assert!(matches!(store.restore(99999), Err(NoteBackendError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_purge_older_than_leaves_tombstones() {
+        let store = create_test_store();
+        let old = store.create("Old", false).unwrap();
+        let recent = store.create("Recent", false).unwrap();
+        store.prepare_sync("dev").unwrap();
+        store.trash(old.id).unwrap();
+        store.trash(recent.id).unwrap();
+        let long_ago = (Utc::now() - chrono::Duration::days(31)).to_rfc3339();
+        store
+            .conn
+            .execute("UPDATE notes SET deleted_at = ?1 WHERE id = ?2", params![long_ago, old.id])
+            .unwrap();
+
+        assert_eq!(store.purge_older_than(30).unwrap(), 1);
+        assert!(!store.exists(old.id).unwrap());
+        let trashed = store.list_trashed().unwrap();
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].id, recent.id);
+
+        let tombstone = store.sync_record(&format!("dev-{}", old.id)).unwrap().unwrap();
+        assert!(tombstone.deleted);
+        assert_eq!(store.purge_older_than(30).unwrap(), 0);
+    }
+
+    fn raw_contents(store: &SqliteNoteStore) -> Vec<String> {
+        store
+            .conn
+            .prepare("SELECT content FROM notes ORDER BY id")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<String>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_enable_encryption_migrates_plaintext() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.db");
+        {
+            let mut store = SqliteNoteStore::new(&path).unwrap();
+            store.create("Existing secret", false).unwrap();
+            assert!(!store.is_encrypted());
+
+            store.enable_encryption("hunter2").unwrap();
+            // Nothing of the plaintext row is left in the file or the log
+            for file in ["notes.db", "notes.db-wal"] {
+                let bytes = std::fs::read(dir.path().join(file)).unwrap_or_default();
+                assert!(!bytes.windows(15).any(|w| w == b"Existing secret"), "{}", file);
+            }
+
+            let note = store.create("New secret", false).unwrap();
+            assert!(raw_contents(&store).iter().all(|c| note_crypto::is_encrypted(c)));
+            assert_eq!(store.get(note.id).unwrap().unwrap().content, "New secret");
+            assert!(store.enable_encryption("again").is_err());
+        }
+
+        let mut store = SqliteNoteStore::new(&path).unwrap();
+        assert!(store.is_locked());
+        assert!(matches!(store.list(), Err(NoteBackendError::Locked)));
+        assert!(matches!(store.create("x", false), Err(NoteBackendError::Locked)));
+        assert!(store.unlock("wrong").is_err());
+        assert!(store.is_locked());
+
+        store.unlock("hunter2").unwrap();
+        let mut contents: Vec<String> =
+            store.list().unwrap().into_iter().map(|n| n.content).collect();
+        contents.sort();
+        assert_eq!(contents, ["Existing secret", "New secret"]);
+
+        store.lock();
+        assert!(matches!(store.get(1), Err(NoteBackendError::Locked)));
+    }
+
+    #[test]
+    fn test_unlock_plaintext_store_fails() {
+        let mut store = create_test_store();
+        assert!(!store.is_locked());
+        assert!(store.unlock("anything").is_err());
+    }
+
+    #[test]
+    fn test_search_uses_index_and_skips_trash() {
+        let store = create_test_store();
+        let groceries = store.create("Groceries: milk, eggs, bread", false).unwrap();
+        let archived = store.create("Old bread recipe", false).unwrap();
+        store
+            .update(archived.id, TodoUpdateRequest { archived: Some(true), ..Default::default() })
+            .unwrap();
+        let trashed = store.create("Bread crumbs", false).unwrap();
+        store.trash(trashed.id).unwrap();
+        store.create("Call the bank", false).unwrap();
+
+        let hits: Vec<i64> = store.search("BREAD", 10).unwrap().into_iter().map(|n| n.id).collect();
+        assert_eq!(hits.len(), 2);
+        assert!(hits.contains(&groceries.id) && hits.contains(&archived.id));
+        assert_eq!(store.search("milk bread", 10).unwrap().len(), 1);
+        assert_eq!(store.search("bread", 1).unwrap().len(), 1);
+        assert!(store.search("   ", 10).unwrap().is_empty());
+
+        store
+            .update(
+                groceries.id,
+                TodoUpdateRequest { content: Some("Groceries: rice".into()), ..Default::default() },
+            )
+            .unwrap();
+        assert!(store.search("milk", 10).unwrap().is_empty());
+        assert_eq!(store.search("rice", 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_search_encrypted_store_decrypts() {
+        let mut store = create_test_store();
+        store.create("Secret plans for the garden", false).unwrap();
+        store.enable_encryption("hunter2").unwrap();
+        store.create("More garden notes", false).unwrap();
+
+        let indexed: i64 = store
+            .conn
+            .query_row("SELECT COUNT(*) FROM notes_search", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(indexed, 0);
+        assert_eq!(store.search("garden", 10).unwrap().len(), 2);
+
+        store.lock();
+        assert!(matches!(store.search("garden", 10), Err(NoteBackendError::Locked)));
+    }
 }
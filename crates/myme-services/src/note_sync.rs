@@ -0,0 +1,455 @@
+//! Notes sync between devices.
+//!
+//! Each device publishes a full snapshot of its notes (plus deletion
+//! tombstones) through a [`SyncTransport`] and merges the snapshots of every
+//! other device. Concurrent edits are detected with per-note version vectors
+//! and resolved last-writer-wins by `updated_at`; the losing side is kept as a
+//! "conflict" copy so no edit is silently dropped.
+//!
+//! [`SyncWatcher`] keeps a device up to date between manual syncs by watching
+//! the shared folder for snapshots written by other devices.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+
+use crate::note_store::SqliteNoteStore;
+
+/// Label added to notes created from the losing side of a conflict.
+pub const CONFLICT_LABEL: &str = "conflict";
+
+/// Delay before retrying a failed live sync; doubles with each failure
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Longest delay between retries of a failing live sync
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// Per-device edit counters for a note.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionVector(pub BTreeMap<String, u64>);
+
+impl VersionVector {
+    /// Record one local edit by `device`.
+    pub fn bump(&mut self, device: &str) {
+        *self.0.entry(device.to_string()).or_insert(0) += 1;
+    }
+
+    /// Component-wise maximum of both vectors.
+    pub fn merge(&mut self, other: &VersionVector) {
+        for (device, &count) in &other.0 {
+            let entry = self.0.entry(device.clone()).or_insert(0);
+            *entry = (*entry).max(count);
+        }
+    }
+
+    /// True if every counter in `other` is <= ours (we have seen all its edits).
+    pub fn dominates(&self, other: &VersionVector) -> bool {
+        other.0.iter().all(|(device, &count)| self.0.get(device).copied().unwrap_or(0) >= count)
+    }
+}
+
+/// A note (or tombstone) as exchanged between devices.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncRecord {
+    pub uuid: String,
+    pub content: String,
+    pub done: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub color: Option<String>,
+    pub pinned: bool,
+    pub archived: bool,
+    pub labels: Vec<String>,
+    pub is_checklist: bool,
+    pub reminder: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub deleted: bool,
+    pub version: VersionVector,
+}
+
+/// Where device snapshots are exchanged.
+pub trait SyncTransport: Send + Sync {
+    /// Snapshots published by all devices except `device_id`.
+    fn pull(&self, device_id: &str) -> Result<Vec<SyncRecord>>;
+
+    /// Publish this device's full snapshot.
+    fn push(&self, device_id: &str, records: &[SyncRecord]) -> Result<()>;
+}
+
+/// Transport over a shared folder (Syncthing, Dropbox, a mounted WebDAV share, ...).
+/// Each device writes `<device_id>.json`; no file is ever written by two devices.
+pub struct FolderTransport {
+    dir: PathBuf,
+}
+
+impl FolderTransport {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl SyncTransport for FolderTransport {
+    fn pull(&self, device_id: &str) -> Result<Vec<SyncRecord>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut records = Vec::new();
+        for entry in std::fs::read_dir(&self.dir).context("Failed to read sync folder")? {
+            let path = entry?.path();
+            if !is_remote_snapshot(&path, device_id) {
+                continue;
+            }
+            let contents = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            match serde_json::from_str::<Vec<SyncRecord>>(&contents) {
+                Ok(mut snapshot) => records.append(&mut snapshot),
+                // A half-synced file from another device; pick it up next time
+                Err(e) => tracing::warn!("Skipping unreadable snapshot {}: {}", path.display(), e),
+            }
+        }
+        Ok(records)
+    }
+
+    fn push(&self, device_id: &str, records: &[SyncRecord]) -> Result<()> {
+        std::fs::create_dir_all(&self.dir).context("Failed to create sync folder")?;
+        let target = self.dir.join(format!("{}.json", device_id));
+        let partial = self.dir.join(format!(".{}.json.tmp", device_id));
+        let contents = serde_json::to_string_pretty(records)?;
+        std::fs::write(&partial, contents).context("Failed to write snapshot")?;
+        std::fs::rename(&partial, &target).context("Failed to publish snapshot")?;
+        Ok(())
+    }
+}
+
+/// True if `path` is a snapshot published by a device other than `device_id`
+/// (not our own file, and not a snapshot still being written).
+pub fn is_remote_snapshot(path: &Path, device_id: &str) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    name.strip_suffix(".json").is_some_and(|device| !device.starts_with('.') && device != device_id)
+}
+
+/// How long to wait before retrying after `failures` failed live syncs in a row
+fn retry_delay(failures: u32) -> Duration {
+    INITIAL_RETRY_DELAY.saturating_mul(1 << failures.min(16)).min(MAX_RETRY_DELAY)
+}
+
+/// Watches a sync folder and calls `on_change` once snapshots from other
+/// devices have been quiet for the debounce window. A failed run is retried
+/// with exponential backoff until it succeeds. Dropping it stops the watch.
+pub struct SyncWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl SyncWatcher {
+    /// Watch `dir` for snapshots not written by `device_id`, calling
+    /// `on_change` from a background thread.
+    pub fn start<F>(
+        dir: PathBuf,
+        device_id: String,
+        debounce: Duration,
+        on_change: F,
+    ) -> Result<Self>
+    where
+        F: FnMut() -> Result<()> + Send + 'static,
+    {
+        std::fs::create_dir_all(&dir).context("Failed to create sync folder")?;
+        let (tx, rx) = mpsc::channel::<notify::Result<Event>>();
+        let mut watcher = notify::recommended_watcher(tx)?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+        let mut on_change = on_change;
+        std::thread::Builder::new().name("note-sync-watch".into()).spawn(move || {
+            let mut due: Option<Instant> = None;
+            let mut failures = 0;
+            loop {
+                let received = match due {
+                    Some(deadline) => {
+                        rx.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                    }
+                    None => rx.recv().map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+                };
+                match received {
+                    Ok(Ok(event)) if !matches!(event.kind, EventKind::Access(_)) => {
+                        if event.paths.iter().any(|p| is_remote_snapshot(p, &device_id)) {
+                            // New activity never pulls a pending backoff retry forward
+                            let quiet = Instant::now() + debounce;
+                            due = Some(due.map_or(quiet, |d| d.max(quiet)));
+                        }
+                    }
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => tracing::debug!("Note sync watch error: {}", e),
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    // The watcher was dropped
+                    Err(mpsc::RecvTimeoutError::Disconnected) => return,
+                }
+                if due.is_some_and(|d| d <= Instant::now()) {
+                    match on_change() {
+                        Ok(()) => {
+                            failures = 0;
+                            due = None;
+                        }
+                        Err(e) => {
+                            let delay = retry_delay(failures);
+                            failures += 1;
+                            tracing::warn!(
+                                "Live notes sync failed, retrying in {:?}: {}",
+                                delay,
+                                e
+                            );
+                            due = Some(Instant::now() + delay);
+                        }
+                    }
+                }
+            }
+        })?;
+
+        Ok(Self { _watcher: watcher })
+    }
+}
+
+/// Outcome of one sync run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Notes created or updated from other devices
+    pub applied: usize,
+    /// Notes deleted because another device deleted them
+    pub deleted: usize,
+    /// Concurrent edits resolved with a conflict copy
+    pub conflicts: usize,
+    /// Records published in this device's snapshot
+    pub pushed: usize,
+}
+
+/// Reduce records from several devices to one per note, preferring the newest.
+fn latest_per_note(records: Vec<SyncRecord>) -> HashMap<String, SyncRecord> {
+    let mut latest: HashMap<String, SyncRecord> = HashMap::new();
+    for record in records {
+        match latest.get_mut(&record.uuid) {
+            None => {
+                latest.insert(record.uuid.clone(), record);
+            }
+            Some(current) => {
+                let newer = (record.version.dominates(&current.version)
+                    && record.version != current.version)
+                    || (!current.version.dominates(&record.version)
+                        && record.updated_at > current.updated_at);
+                if newer {
+                    *current = record;
+                }
+            }
+        }
+    }
+    latest
+}
+
+/// Run one sync: merge other devices' snapshots into `store`, then publish ours.
+///
+/// Snapshots are plaintext, so an encrypted store is never synced.
+pub fn sync_notes(store: &SqliteNoteStore, transport: &dyn SyncTransport) -> Result<SyncReport> {
+    if store.is_encrypted() {
+        anyhow::bail!("Notes sync is unavailable while notes are encrypted");
+    }
+    let device = store.sync_device_id()?;
+    store.prepare_sync(&device)?;
+
+    let mut report = SyncReport::default();
+    let remote = latest_per_note(transport.pull(&device)?);
+
+    for (uuid, theirs) in remote {
+        let local = store.sync_record(&uuid)?;
+        let Some(ours) = local else {
+            if !theirs.deleted {
+                store.apply_sync_record(&theirs)?;
+                report.applied += 1;
+            }
+            continue;
+        };
+
+        if ours.version.dominates(&theirs.version) {
+            continue;
+        }
+        if theirs.version.dominates(&ours.version) {
+            if theirs.deleted && !ours.deleted {
+                report.deleted += 1;
+            } else if !theirs.deleted {
+                report.applied += 1;
+            }
+            store.apply_sync_record(&theirs)?;
+            continue;
+        }
+
+        // Concurrent edits: newest wins, an edit beats a delete, loser becomes a copy
+        let mut merged = ours.version.clone();
+        merged.merge(&theirs.version);
+        merged.bump(&device);
+
+        let theirs_wins = match (ours.deleted, theirs.deleted) {
+            (true, false) => true,
+            (false, true) => false,
+            _ => theirs.updated_at > ours.updated_at,
+        };
+        let (mut winner, loser) = if theirs_wins { (theirs, ours) } else { (ours, theirs) };
+        winner.version = merged;
+        store.apply_sync_record(&winner)?;
+        if !loser.deleted && !winner.deleted {
+            store.insert_conflict_copy(&loser)?;
+            report.conflicts += 1;
+        } else if theirs_wins {
+            report.applied += 1;
+        }
+    }
+
+    // Conflict copies are new local notes; give them IDs and versions before publishing
+    store.prepare_sync(&device)?;
+    let snapshot = store.sync_snapshot()?;
+    report.pushed = snapshot.len();
+    transport.push(&device, &snapshot)?;
+
+    tracing::info!(
+        "Notes sync: {} applied, {} deleted, {} conflicts, {} pushed",
+        report.applied,
+        report.deleted,
+        report.conflicts,
+        report.pushed
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use crate::note_backend::NoteBackend;
+    use crate::todo::TodoUpdateRequest;
+
+    fn vv(entries: &[(&str, u64)]) -> VersionVector {
+        VersionVector(entries.iter().map(|(d, c)| (d.to_string(), *c)).collect())
+    }
+
+    fn store(device: &str) -> SqliteNoteStore {
+        let store = SqliteNoteStore::in_memory().unwrap();
+        store.set_sync_device_id(device).unwrap();
+        store
+    }
+
+    fn edit(store: &SqliteNoteStore, id: i64, content: &str) {
+        let req = TodoUpdateRequest { content: Some(content.to_string()), ..Default::default() };
+        store.update(id, req).unwrap();
+    }
+
+    #[test]
+    fn test_version_vector_ordering() {
+        let a = vv(&[("a", 2), ("b", 1)]);
+        let b = vv(&[("a", 1), ("b", 1)]);
+        let c = vv(&[("a", 1), ("b", 2)]);
+        assert!(a.dominates(&b));
+        assert!(!b.dominates(&a));
+        assert!(!a.dominates(&c) && !c.dominates(&a));
+
+        let mut m = a.clone();
+        m.merge(&c);
+        assert_eq!(m, vv(&[("a", 2), ("b", 2)]));
+    }
+
+    #[test]
+    fn test_remote_snapshot_paths() {
+        let dir = Path::new("/sync");
+        assert!(is_remote_snapshot(&dir.join("desktop.json"), "laptop"));
+        assert!(!is_remote_snapshot(&dir.join("laptop.json"), "laptop"));
+        assert!(!is_remote_snapshot(&dir.join(".desktop.json.tmp"), "laptop"));
+        assert!(!is_remote_snapshot(&dir.join(".desktop.json"), "laptop"));
+        assert!(!is_remote_snapshot(&dir.join("notes.txt"), "laptop"));
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off_to_cap() {
+        assert_eq!(retry_delay(0), INITIAL_RETRY_DELAY);
+        assert_eq!(retry_delay(1), INITIAL_RETRY_DELAY * 2);
+        assert_eq!(retry_delay(3), INITIAL_RETRY_DELAY * 8);
+        assert_eq!(retry_delay(40), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn test_two_devices_exchange_notes() {
+        let dir = tempfile::tempdir().unwrap();
+        let transport = FolderTransport::new(dir.path());
+        let laptop = store("laptop");
+        let desktop = store("desktop");
+
+        let note = laptop.create("Buy milk", false).unwrap();
+        sync_notes(&laptop, &transport).unwrap();
+        let report = sync_notes(&desktop, &transport).unwrap();
+        assert_eq!(report.applied, 1);
+        assert_eq!(desktop.list().unwrap()[0].content, "Buy milk");
+
+        // Edit on desktop flows back to the laptop
+        let remote_id = desktop.list().unwrap()[0].id;
+        edit(&desktop, remote_id, "Buy oat milk");
+        sync_notes(&desktop, &transport).unwrap();
+        sync_notes(&laptop, &transport).unwrap();
+        assert_eq!(laptop.get(note.id).unwrap().unwrap().content, "Buy oat milk");
+
+        // Deletion propagates too
+        laptop.delete(note.id).unwrap();
+        sync_notes(&laptop, &transport).unwrap();
+        let report = sync_notes(&desktop, &transport).unwrap();
+        assert_eq!(report.deleted, 1);
+        assert!(desktop.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_encrypted_store_is_not_synced() {
+        let dir = tempfile::tempdir().unwrap();
+        let transport = FolderTransport::new(dir.path());
+        let mut laptop = store("laptop");
+        laptop.create("Private", false).unwrap();
+        laptop.enable_encryption("hunter2").unwrap();
+
+        assert!(sync_notes(&laptop, &transport).is_err());
+        assert!(laptop.sync_snapshot().is_err());
+        assert!(!dir.path().join("laptop.json").exists());
+    }
+
+    #[test]
+    fn test_concurrent_edits_keep_conflict_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let transport = FolderTransport::new(dir.path());
+        let laptop = store("laptop");
+        let desktop = store("desktop");
+
+        let note = laptop.create("Draft", false).unwrap();
+        sync_notes(&laptop, &transport).unwrap();
+        sync_notes(&desktop, &transport).unwrap();
+        let remote_id = desktop.list().unwrap()[0].id;
+
+        edit(&laptop, note.id, "Laptop edit");
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        edit(&desktop, remote_id, "Desktop edit");
+
+        sync_notes(&desktop, &transport).unwrap();
+        let report = sync_notes(&laptop, &transport).unwrap();
+        assert_eq!(report.conflicts, 1);
+
+        let notes = laptop.list().unwrap();
+        assert_eq!(notes.len(), 2);
+        assert_eq!(laptop.get(note.id).unwrap().unwrap().content, "Desktop edit");
+        let copy = notes.iter().find(|n| n.id != note.id).unwrap();
+        assert_eq!(copy.content, "Laptop edit");
+        assert!(copy.labels.iter().any(|l| l == CONFLICT_LABEL));
+
+        // Desktop converges to the same state
+        sync_notes(&desktop, &transport).unwrap();
+        let desktop_notes = desktop.list().unwrap();
+        assert_eq!(desktop_notes.len(), 2);
+        assert!(desktop_notes.iter().any(|n| n.content == "Laptop edit"));
+        assert_eq!(desktop.get(remote_id).unwrap().unwrap().content, "Desktop edit");
+    }
+}
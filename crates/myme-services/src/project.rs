@@ -69,6 +69,35 @@ impl TaskStatus {
         TaskStatus::Todo
     }
 
+    /// Stable key, also the id of the matching default board column
+    pub fn key(&self) -> &'static str {
+        match self {
+            TaskStatus::Backlog => "backlog",
+            TaskStatus::Todo => "todo",
+            TaskStatus::InProgress => "inprogress",
+            TaskStatus::Blocked => "blocked",
+            TaskStatus::Review => "review",
+            TaskStatus::Done => "done",
+        }
+    }
+
+    /// Status with the given `key()`
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::all().iter().copied().find(|s| s.key() == key)
+    }
+
+    /// Column heading used for the default board
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TaskStatus::Backlog => "Backlog",
+            TaskStatus::Todo => "Todo",
+            TaskStatus::InProgress => "In Progress",
+            TaskStatus::Blocked => "Blocked",
+            TaskStatus::Review => "Review",
+            TaskStatus::Done => "Done",
+        }
+    }
+
     /// Get all status variants
     pub fn all() -> &'static [TaskStatus] {
         &[
@@ -98,16 +127,446 @@ pub struct ProjectRepo {
     pub repo_id: String,
 }
 
+/// User-defined organization metadata for a repo (tags, favorite, grouping, order)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoMeta {
+    pub repo_id: String,
+    pub favorite: bool,
+    pub group_name: Option<String>,
+    pub sort_order: i32,
+    pub tags: Vec<String>,
+}
+
+/// Open security alerts for a repo by severity, from Dependabot and code
+/// scanning. Kept per repo so the dashboard can flag it between refreshes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoSecurity {
+    pub repo_id: String,
+    pub critical: u32,
+    pub high: u32,
+    pub medium: u32,
+    pub low: u32,
+    /// False when Dependabot alerts are disabled or not readable
+    pub dependabot_enabled: bool,
+    /// False when code scanning isn't set up or not readable
+    pub code_scanning_enabled: bool,
+    /// RFC 3339 time of the last check
+    pub checked_at: String,
+}
+
+impl RepoSecurity {
+    /// Count open alerts; `None` for a feature that is disabled
+    pub fn from_alerts(
+        repo_id: &str,
+        dependabot: Option<&[crate::github::DependabotAlert]>,
+        code_scanning: Option<&[crate::github::CodeScanningAlert]>,
+    ) -> Self {
+        let mut security = RepoSecurity {
+            repo_id: repo_id.to_string(),
+            dependabot_enabled: dependabot.is_some(),
+            code_scanning_enabled: code_scanning.is_some(),
+            checked_at: chrono::Utc::now().to_rfc3339(),
+            ..Default::default()
+        };
+        let severities = dependabot
+            .unwrap_or_default()
+            .iter()
+            .map(|a| a.severity())
+            .chain(code_scanning.unwrap_or_default().iter().map(|a| a.severity()));
+        for severity in severities {
+            match severity.to_ascii_lowercase().as_str() {
+                "critical" => security.critical += 1,
+                "high" => security.high += 1,
+                "medium" | "moderate" => security.medium += 1,
+                _ => security.low += 1,
+            }
+        }
+        security
+    }
+
+    pub fn total(&self) -> u32 {
+        self.critical + self.high + self.medium + self.low
+    }
+
+    /// Most severe level with an open alert, for the badge
+    pub fn highest(&self) -> Option<&'static str> {
+        [
+            (self.critical, "critical"),
+            (self.high, "high"),
+            (self.medium, "medium"),
+            (self.low, "low"),
+        ]
+        .into_iter()
+        .find(|(count, _)| *count > 0)
+        .map(|(_, level)| level)
+    }
+}
+
 /// Local task representation (first-class, belongs to project)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Task {
     pub id: String,
     pub project_id: String,
     pub title: String,
     pub body: Option<String>,
     pub status: TaskStatus,
+    /// Board column the task sits in; `None` places it by `status`
+    #[serde(default)]
+    pub column_id: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    /// Manual position within the project (lower first)
+    #[serde(default)]
+    pub order_index: i32,
+}
+
+impl Task {
+    /// Stable task id for an issue, so repeated syncs update instead of duplicating
+    pub fn issue_id(repo_id: &str, number: i32) -> String {
+        format!("{}#{}", repo_id, number)
+    }
+
+    /// Board task for an issue. A task that stays in the same status keeps the
+    /// column the user put it in; a status change moves it to that status's column.
+    pub fn from_issue(
+        project_id: &str,
+        repo_id: &str,
+        issue: &crate::github::GitHubIssue,
+        previous: Option<&Task>,
+    ) -> Task {
+        let labels: Vec<String> = issue.labels.iter().map(|l| l.name.clone()).collect();
+        let status = TaskStatus::from_github(&issue.state, &labels);
+        let column_id = previous.filter(|t| t.status == status).and_then(|t| t.column_id.clone());
+        Task {
+            id: Task::issue_id(repo_id, issue.number),
+            project_id: project_id.to_string(),
+            title: issue.title.clone(),
+            body: issue.body.clone(),
+            status,
+            column_id,
+            created_at: issue.created_at.clone(),
+            updated_at: issue.updated_at.clone(),
+            order_index: previous.map(|t| t.order_index).unwrap_or_default(),
+        }
+    }
+}
+
+/// A kanban column on a project's board.
+///
+/// Projects start with one column per `TaskStatus` (ids equal to
+/// `TaskStatus::key`); users can add, rename and reorder columns. Each column
+/// still maps to a status so counts and Done/closed semantics keep working.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectColumn {
+    /// Unique within the project
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub position: i32,
+    /// GitHub label applied to issues in this column
+    pub label: Option<String>,
+    pub status: TaskStatus,
+    /// Most tasks the column should hold at once (`None` for no limit)
+    #[serde(default)]
+    pub wip_limit: Option<u32>,
+}
+
+impl ProjectColumn {
+    /// The board every project starts with
+    pub fn defaults(project_id: &str) -> Vec<ProjectColumn> {
+        TaskStatus::all()
+            .iter()
+            .enumerate()
+            .map(|(i, status)| ProjectColumn {
+                id: status.key().to_string(),
+                project_id: project_id.to_string(),
+                name: status.display_name().to_string(),
+                position: i as i32,
+                label: status.to_label().map(str::to_string),
+                status: *status,
+                wip_limit: None,
+            })
+            .collect()
+    }
+
+    /// Whether `count` tasks would be more than the column's WIP limit
+    pub fn is_over_wip(&self, count: usize) -> bool {
+        self.wip_limit.is_some_and(|limit| count > limit as usize)
+    }
+
+    /// Column a task is shown in: its own column if it still exists, else the
+    /// first column with the task's status, else the first column.
+    pub fn for_task<'a>(columns: &'a [ProjectColumn], task: &Task) -> Option<&'a ProjectColumn> {
+        task.column_id
+            .as_deref()
+            .and_then(|id| columns.iter().find(|c| c.id == id))
+            .or_else(|| columns.iter().find(|c| c.status == task.status))
+            .or_else(|| columns.first())
+    }
+}
+
+/// Phase of a pomodoro cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FocusKind {
+    Work,
+    ShortBreak,
+    LongBreak,
+}
+
+impl FocusKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FocusKind::Work => "work",
+            FocusKind::ShortBreak => "short_break",
+            FocusKind::LongBreak => "long_break",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "work" => Some(FocusKind::Work),
+            "short_break" => Some(FocusKind::ShortBreak),
+            "long_break" => Some(FocusKind::LongBreak),
+            _ => None,
+        }
+    }
+}
+
+/// A task entering a status, as recorded for board analytics
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub task_id: String,
+    /// `None` when the task was created in `to`
+    pub from: Option<TaskStatus>,
+    pub to: TaskStatus,
+    pub changed_at: String,
+}
+
+/// A finished (or stopped) focus session, optionally linked to a kanban task
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FocusSession {
+    pub id: String,
+    pub task_id: Option<String>,
+    pub kind: FocusKind,
+    pub started_at: String,
+    pub ended_at: String,
+    /// Planned length of the interval in seconds
+    pub planned_secs: i64,
+    /// Time actually spent, excluding pauses
+    pub focused_secs: i64,
+    /// False if the session was stopped before the interval ended
+    pub completed: bool,
+}
+
+/// A calendar event blocking out time for a kanban task. The task's
+/// completion is mirrored on the event's title (see [`TaskSchedule::event_summary`]),
+/// and ticking the event off marks the task done.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskSchedule {
+    /// Google Calendar event id
+    pub event_id: String,
+    pub calendar_id: String,
+    pub task_id: String,
+    pub start: String,
+    pub end: String,
+    pub created_at: String,
+    /// When the task was marked done through either side
+    pub completed_at: Option<String>,
+}
+
+impl TaskSchedule {
+    /// Title prefix marking a time block's task as done
+    pub const DONE_MARK: &'static str = "✓ ";
+
+    /// Event title for a task, marked when the task is done
+    pub fn event_summary(title: &str, done: bool) -> String {
+        if done {
+            format!("{}{}", Self::DONE_MARK, title)
+        } else {
+            title.to_string()
+        }
+    }
+
+    /// Whether an event title carries the done mark (also when ticked off by
+    /// hand in another calendar app)
+    pub fn is_marked_done(summary: &str) -> bool {
+        summary.trim_start().starts_with(Self::DONE_MARK.trim_end())
+    }
+
+    /// Event title without the done mark
+    pub fn unmarked(summary: &str) -> &str {
+        let summary = summary.trim_start();
+        summary.strip_prefix(Self::DONE_MARK.trim_end()).map(str::trim_start).unwrap_or(summary)
+    }
+
+    /// What to change so the event and its task agree on completion. The
+    /// recorded `completed_at` tells which side changed since the last sync.
+    pub fn sync_action(&self, task_done: bool, event_summary: &str) -> ScheduleSync {
+        let event_done = Self::is_marked_done(event_summary);
+        let recorded = self.completed_at.is_some();
+        match (task_done, event_done, recorded) {
+            (true, true, true) | (false, false, false) => ScheduleSync::InSync,
+            (true, true, false) | (false, false, true) => ScheduleSync::Record(task_done),
+            // The task moved since the last sync
+            (true, false, false) => ScheduleSync::MarkEvent,
+            (false, true, true) => ScheduleSync::UnmarkEvent,
+            // The event was ticked or unticked by hand
+            (false, true, false) => ScheduleSync::CompleteTask,
+            (true, false, true) => ScheduleSync::ReopenTask,
+        }
+    }
+
+    /// Event description: the task body and where to find the task
+    pub fn event_description(task: &Task, issue_url: Option<&str>) -> String {
+        let mut description = String::new();
+        if let Some(body) = task.body.as_deref().map(str::trim).filter(|b| !b.is_empty()) {
+            description.push_str(body);
+            description.push_str("\n\n");
+        }
+        match issue_url {
+            Some(url) => description.push_str(&format!("Task: {}", url)),
+            None => description.push_str(&format!("MyMe task {}", task.id)),
+        }
+        description
+    }
+}
+
+/// Change needed to keep a scheduled event and its task in step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleSync {
+    InSync,
+    /// Both sides agree; only the recorded completion is stale
+    Record(bool),
+    /// Put the done mark on the event's title
+    MarkEvent,
+    /// Remove the done mark from the event's title
+    UnmarkEvent,
+    /// Move the task to Done
+    CompleteTask,
+    /// Move the task out of Done
+    ReopenTask,
+}
+
+/// How a local repo task (`cargo test`, `npm test`, ...) ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepoTaskStatus {
+    Passed,
+    Failed,
+    TimedOut,
+    Cancelled,
+}
+
+impl RepoTaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepoTaskStatus::Passed => "passed",
+            RepoTaskStatus::Failed => "failed",
+            RepoTaskStatus::TimedOut => "timed_out",
+            RepoTaskStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "passed" => Some(RepoTaskStatus::Passed),
+            "failed" => Some(RepoTaskStatus::Failed),
+            "timed_out" => Some(RepoTaskStatus::TimedOut),
+            "cancelled" => Some(RepoTaskStatus::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+/// One run of a local repo task, kept as pass/fail history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepoTaskRun {
+    pub id: String,
+    /// owner/repo, or the clone's path for local-only repos
+    pub repo_id: String,
+    /// Task name, e.g. "cargo test" or "task lint"
+    pub task: String,
+    pub started_at: String,
+    pub duration_ms: i64,
+    /// None when the process was killed or never started
+    pub exit_code: Option<i32>,
+    pub status: RepoTaskStatus,
+    /// Last lines of combined output
+    pub output_tail: String,
+}
+
+/// Kind of item that can be linked to another (see `ProjectStore::link`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityKind {
+    Note,
+    Task,
+    Event,
+    Issue,
+}
+
+impl EntityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntityKind::Note => "note",
+            EntityKind::Task => "task",
+            EntityKind::Event => "event",
+            EntityKind::Issue => "issue",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "note" => Some(EntityKind::Note),
+            "task" => Some(EntityKind::Task),
+            "event" => Some(EntityKind::Event),
+            "issue" => Some(EntityKind::Issue),
+            _ => None,
+        }
+    }
+}
+
+/// A note, kanban task, calendar event or GitHub issue, by id.
+///
+/// Ids are stored as text: note ids are the numeric note id, event ids are
+/// Google Calendar ids in the primary calendar, and issue ids are
+/// `owner/repo#number`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct EntityRef {
+    pub kind: EntityKind,
+    pub id: String,
+}
+
+impl EntityRef {
+    pub fn new(kind: EntityKind, id: impl Into<String>) -> Self {
+        Self { kind, id: id.into() }
+    }
+
+    pub fn note(id: i64) -> Self {
+        Self::new(EntityKind::Note, id.to_string())
+    }
+
+    pub fn task(id: &str) -> Self {
+        Self::new(EntityKind::Task, id)
+    }
+
+    pub fn event(id: &str) -> Self {
+        Self::new(EntityKind::Event, id)
+    }
+
+    pub fn issue(repo_id: &str, number: i32) -> Self {
+        Self::new(EntityKind::Issue, format!("{}#{}", repo_id, number))
+    }
+
+    /// GitHub page of an issue reference
+    pub fn issue_url(&self) -> Option<String> {
+        if self.kind != EntityKind::Issue {
+            return None;
+        }
+        let (repo, number) = self.id.rsplit_once('#')?;
+        Some(format!("https://github.com/{}/issues/{}", repo, number))
+    }
 }
 
 #[cfg(test)]
@@ -115,6 +574,67 @@ mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
     use super::*;
 
+    fn issue(state: &str, labels: &[&str]) -> crate::github::GitHubIssue {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "number": 42,
+            "title": "Fix login",
+            "body": null,
+            "state": state,
+            "html_url": "https://github.com/o/r/issues/42",
+            "labels": labels.iter().map(|l| serde_json::json!({ "id": 1, "name": l, "color": "ededed" })).collect::<Vec<_>>(),
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-02T00:00:00Z",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_task_from_issue_keeps_column_while_status_unchanged() {
+        let first = Task::from_issue("p1", "o/r", &issue("open", &["in-progress"]), None);
+        assert_eq!(first.id, "o/r#42");
+        assert_eq!(first.status, TaskStatus::InProgress);
+        assert_eq!(first.column_id, None);
+
+        let placed = Task { column_id: Some("qa".into()), ..first };
+        let resynced =
+            Task::from_issue("p1", "o/r", &issue("open", &["in-progress"]), Some(&placed));
+        assert_eq!(resynced.column_id.as_deref(), Some("qa"));
+
+        let closed = Task::from_issue("p1", "o/r", &issue("closed", &[]), Some(&placed));
+        assert_eq!(closed.status, TaskStatus::Done);
+        assert_eq!(closed.column_id, None);
+    }
+
+    #[test]
+    fn test_repo_security_counts_alerts() {
+        let dependabot: Vec<crate::github::DependabotAlert> =
+            serde_json::from_value(serde_json::json!([
+                {"number": 1, "state": "open", "html_url": "",
+                 "security_advisory": {"summary": "RCE", "severity": "critical"}},
+                {"number": 2, "state": "open", "html_url": "",
+                 "security_advisory": {"summary": "ReDoS", "severity": "medium"}}
+            ]))
+            .unwrap();
+        let code_scanning: Vec<crate::github::CodeScanningAlert> =
+            serde_json::from_value(serde_json::json!([
+                {"number": 3, "state": "open", "html_url": "",
+                 "rule": {"id": "sql-injection", "security_severity_level": "high"}},
+                {"number": 4, "state": "open", "html_url": "",
+                 "rule": {"id": "unused-var", "severity": "warning"}}
+            ]))
+            .unwrap();
+
+        let security = RepoSecurity::from_alerts("o/r", Some(&dependabot), Some(&code_scanning));
+        assert_eq!((security.critical, security.high, security.medium, security.low), (1, 1, 2, 0));
+        assert_eq!(security.total(), 4);
+        assert_eq!(security.highest(), Some("critical"));
+
+        let disabled = RepoSecurity::from_alerts("o/r", None, Some(&[]));
+        assert!(!disabled.dependabot_enabled && disabled.code_scanning_enabled);
+        assert_eq!(disabled.highest(), None);
+    }
+
     #[test]
     fn test_status_from_closed_issue() {
         let status = TaskStatus::from_github("closed", &[]);
@@ -140,9 +660,107 @@ mod tests {
         assert_eq!(status, TaskStatus::Todo);
     }
 
+    #[test]
+    fn test_column_for_task() {
+        let mut columns = ProjectColumn::defaults("p");
+        assert_eq!(columns.len(), 6);
+        assert_eq!(columns[2].id, "inprogress");
+        columns.push(ProjectColumn {
+            id: "qa".to_string(),
+            project_id: "p".to_string(),
+            name: "QA".to_string(),
+            position: 6,
+            label: Some("qa".to_string()),
+            status: TaskStatus::Review,
+            wip_limit: None,
+        });
+
+        let mut task = Task {
+            id: "t".to_string(),
+            project_id: "p".to_string(),
+            title: "Task".to_string(),
+            body: None,
+            status: TaskStatus::Review,
+            column_id: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+            order_index: 0,
+        };
+        assert_eq!(ProjectColumn::for_task(&columns, &task).unwrap().id, "review");
+
+        task.column_id = Some("qa".to_string());
+        assert_eq!(ProjectColumn::for_task(&columns, &task).unwrap().id, "qa");
+
+        // Column was removed: fall back to status
+        task.column_id = Some("gone".to_string());
+        assert_eq!(ProjectColumn::for_task(&columns, &task).unwrap().id, "review");
+    }
+
     #[test]
     fn test_status_to_label() {
         assert_eq!(TaskStatus::InProgress.to_label(), Some("in-progress"));
         assert_eq!(TaskStatus::Done.to_label(), None);
     }
+
+    #[test]
+    fn test_status_from_key() {
+        for status in TaskStatus::all() {
+            assert_eq!(TaskStatus::from_key(status.key()), Some(*status));
+        }
+        assert_eq!(TaskStatus::from_key("in-progress"), None);
+    }
+
+    #[test]
+    fn test_task_schedule_summary_and_description() {
+        let summary = TaskSchedule::event_summary("Write report", true);
+        assert_eq!(summary, "✓ Write report");
+        assert!(TaskSchedule::is_marked_done(&summary));
+        assert!(TaskSchedule::is_marked_done("✓Write report"));
+        assert!(!TaskSchedule::is_marked_done("Write report"));
+        assert_eq!(TaskSchedule::unmarked(&summary), "Write report");
+        assert_eq!(TaskSchedule::unmarked("Write report"), "Write report");
+
+        let task = Task {
+            id: "t1".into(),
+            project_id: "p1".into(),
+            title: "Write report".into(),
+            body: Some("Quarterly numbers".into()),
+            status: TaskStatus::Todo,
+            column_id: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+            order_index: 0,
+        };
+        let issue = EntityRef::issue("acme/app", 7);
+        let url = issue.issue_url();
+        assert_eq!(url.as_deref(), Some("https://github.com/acme/app/issues/7"));
+        assert_eq!(
+            TaskSchedule::event_description(&task, url.as_deref()),
+            "Quarterly numbers\n\nTask: https://github.com/acme/app/issues/7"
+        );
+        assert_eq!(EntityRef::task("t1").issue_url(), None);
+    }
+
+    #[test]
+    fn test_task_schedule_sync_action() {
+        let mut schedule = TaskSchedule {
+            event_id: "e1".into(),
+            calendar_id: "primary".into(),
+            task_id: "t1".into(),
+            start: String::new(),
+            end: String::new(),
+            created_at: String::new(),
+            completed_at: None,
+        };
+        assert_eq!(schedule.sync_action(false, "Write"), ScheduleSync::InSync);
+        assert_eq!(schedule.sync_action(true, "Write"), ScheduleSync::MarkEvent);
+        assert_eq!(schedule.sync_action(false, "✓ Write"), ScheduleSync::CompleteTask);
+        assert_eq!(schedule.sync_action(true, "✓ Write"), ScheduleSync::Record(true));
+
+        schedule.completed_at = Some("2026-03-02T10:00:00Z".into());
+        assert_eq!(schedule.sync_action(true, "✓ Write"), ScheduleSync::InSync);
+        assert_eq!(schedule.sync_action(false, "✓ Write"), ScheduleSync::UnmarkEvent);
+        assert_eq!(schedule.sync_action(true, "Write"), ScheduleSync::ReopenTask);
+        assert_eq!(schedule.sync_action(false, "Write"), ScheduleSync::Record(false));
+    }
 }
@@ -1,52 +1,108 @@
 // crates/myme-services/src/project_store.rs
 
 use anyhow::{Context, Result};
-use rusqlite::{params, Connection, OptionalExtension};
+use parking_lot::{Mutex, MutexGuard};
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension};
+use std::collections::HashMap;
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-use crate::project::{Project, Task, TaskStatus};
+use crate::checklist::{self, ChecklistItem};
+use crate::db;
+use crate::project::{
+    EntityKind, EntityRef, FocusKind, FocusSession, Project, ProjectColumn, RepoMeta, RepoSecurity,
+    RepoTaskRun, RepoTaskStatus, StatusChange, Task, TaskSchedule, TaskStatus,
+};
+use crate::template::{Template, TemplateKind, TemplateSchedule};
 
 const SCHEMA_VERSION: i32 = 3;
 
-/// Local SQLite storage for projects and tasks
+/// Read-only connections available for concurrent reads
+const READER_POOL_SIZE: usize = 4;
+
+/// Runs kept per repo and task in the task history
+const REPO_TASK_RUNS_KEPT: i64 = 50;
+
+/// Local SQLite storage for projects and tasks.
+///
+/// Writes go through a single connection behind a mutex; reads use a small pool
+/// of read-only connections. With WAL enabled, a long write (e.g. a GitHub sync
+/// upserting tasks) doesn't block the UI's reads, so the store is shared as
+/// `Arc<ProjectStore>` without an outer lock.
 pub struct ProjectStore {
-    conn: Connection,
+    writer: Mutex<Connection>,
+    readers: Vec<Mutex<Connection>>,
+    next_reader: AtomicUsize,
 }
 
 impl ProjectStore {
     /// Open or create the database
     pub fn open(path: &Path) -> Result<Self> {
-        let conn = Connection::open(path).context("Failed to open projects database")?;
+        let conn = db::open_maintained(path).context("Failed to open projects database")?;
+        db::migrate(&conn, 12, |version| match version {
+            1 => Self::init_schema(&conn),
+            2 => Self::add_project_columns(&conn),
+            3 => Self::add_task_order(&conn),
+            4 => Self::add_links(&conn),
+            5 => Self::add_templates(&conn),
+            6 => Self::add_repo_security(&conn),
+            7 => Self::add_repo_task_runs(&conn),
+            8 => Self::add_task_schedules(&conn),
+            9 => Self::add_project_archive(&conn),
+            10 => Self::add_task_status_history(&conn),
+            11 => Self::add_column_wip_limits(&conn),
+            _ => Self::add_task_subtasks(&conn),
+        })?;
+
+        let readers = (0..READER_POOL_SIZE)
+            .map(|_| {
+                let reader = Connection::open_with_flags(
+                    path,
+                    OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+                )
+                .context("Failed to open projects database for reading")?;
+                db::configure(&reader)?;
+                Ok(Mutex::new(reader))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { writer: Mutex::new(conn), readers, next_reader: AtomicUsize::new(0) })
+    }
 
-        let store = Self { conn };
-        store.init_schema()?;
+    /// Exclusive access to the write connection
+    fn writer(&self) -> MutexGuard<'_, Connection> {
+        self.writer.lock()
+    }
 
-        Ok(store)
+    /// A free read-only connection, or the next one in turn if all are busy
+    fn reader(&self) -> MutexGuard<'_, Connection> {
+        if let Some(conn) = self.readers.iter().find_map(|r| r.try_lock()) {
+            return conn;
+        }
+        let next = self.next_reader.fetch_add(1, Ordering::Relaxed) % self.readers.len();
+        self.readers[next].lock()
     }
 
     /// Initialize database schema and run migrations if needed
-    fn init_schema(&self) -> Result<()> {
+    fn init_schema(conn: &Connection) -> Result<()> {
         // Create schema version table
-        self.conn
-            .execute("CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL)", [])?;
+        conn.execute("CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL)", [])?;
 
-        let version: i32 = self
-            .conn
+        let version: i32 = conn
             .query_row("SELECT version FROM schema_version LIMIT 1", [], |row| row.get(0))
             .optional()?
             .unwrap_or(0);
 
         if version < 2 {
-            self.migrate_to_v2(version)?;
+            Self::migrate_to_v2(conn, version)?;
         }
         if version < SCHEMA_VERSION {
-            self.migrate_to_v3()?;
+            Self::migrate_to_v3(conn)?;
         }
 
         // Ensure schema exists
-        self.conn
-            .execute_batch(
-                "CREATE TABLE IF NOT EXISTS projects (
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS projects (
                 id TEXT PRIMARY KEY,
                 name TEXT NOT NULL,
                 description TEXT,
@@ -71,28 +127,266 @@ impl ProjectStore {
                 FOREIGN KEY (project_id) REFERENCES projects(id)
             );
 
+            CREATE TABLE IF NOT EXISTS repo_meta (
+                repo_id TEXT PRIMARY KEY,
+                favorite INTEGER NOT NULL DEFAULT 0,
+                group_name TEXT,
+                sort_order INTEGER NOT NULL DEFAULT 0
+            );
+
+            CREATE TABLE IF NOT EXISTS repo_tags (
+                repo_id TEXT NOT NULL,
+                tag TEXT NOT NULL,
+                PRIMARY KEY (repo_id, tag)
+            );
+
+            CREATE TABLE IF NOT EXISTS focus_sessions (
+                id TEXT PRIMARY KEY,
+                task_id TEXT,
+                kind TEXT NOT NULL,
+                started_at TEXT NOT NULL,
+                ended_at TEXT NOT NULL,
+                planned_secs INTEGER NOT NULL,
+                focused_secs INTEGER NOT NULL,
+                completed INTEGER NOT NULL DEFAULT 0
+            );
+
             CREATE INDEX IF NOT EXISTS idx_tasks_project ON tasks(project_id);
             CREATE INDEX IF NOT EXISTS idx_tasks_status ON tasks(status);
             CREATE INDEX IF NOT EXISTS idx_project_repos_project ON project_repos(project_id);
-            CREATE INDEX IF NOT EXISTS idx_project_repos_repo ON project_repos(repo_id);",
-            )
-            .context("Failed to initialize schema")?;
+            CREATE INDEX IF NOT EXISTS idx_project_repos_repo ON project_repos(repo_id);
+            CREATE INDEX IF NOT EXISTS idx_repo_tags_tag ON repo_tags(tag);
+            CREATE INDEX IF NOT EXISTS idx_focus_sessions_task ON focus_sessions(task_id);
+            CREATE INDEX IF NOT EXISTS idx_focus_sessions_started ON focus_sessions(started_at);",
+        )
+        .context("Failed to initialize schema")?;
 
         Ok(())
     }
 
+    /// Custom board columns per project; tasks remember their column
+    fn add_project_columns(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            ALTER TABLE tasks ADD COLUMN column_id TEXT;
+            CREATE TABLE IF NOT EXISTS project_columns (
+                project_id TEXT NOT NULL,
+                id TEXT NOT NULL,
+                name TEXT NOT NULL,
+                position INTEGER NOT NULL,
+                label TEXT,
+                status TEXT NOT NULL,
+                PRIMARY KEY (project_id, id),
+                FOREIGN KEY (project_id) REFERENCES projects(id)
+            );
+            COMMIT;",
+        )
+        .context("Failed to add project columns")?;
+        Ok(())
+    }
+
+    /// Manual card order; existing tasks keep their creation order
+    fn add_task_order(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            ALTER TABLE tasks ADD COLUMN order_index INTEGER NOT NULL DEFAULT 0;
+            UPDATE tasks SET order_index = (
+                SELECT COUNT(*) FROM tasks t2
+                WHERE t2.project_id = tasks.project_id
+                  AND (t2.created_at < tasks.created_at
+                       OR (t2.created_at = tasks.created_at AND t2.id < tasks.id))
+            );
+            CREATE INDEX IF NOT EXISTS idx_tasks_order ON tasks(project_id, order_index);
+            COMMIT;",
+        )
+        .context("Failed to add task order")?;
+        Ok(())
+    }
+
+    /// Links between notes, tasks, events and issues. Each pair is stored once
+    /// with the smaller endpoint as source, so links read the same both ways.
+    fn add_links(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            CREATE TABLE IF NOT EXISTS links (
+                source_type TEXT NOT NULL,
+                source_id TEXT NOT NULL,
+                target_type TEXT NOT NULL,
+                target_id TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                PRIMARY KEY (source_type, source_id, target_type, target_id)
+            );
+            CREATE INDEX IF NOT EXISTS idx_links_target ON links(target_type, target_id);
+            COMMIT;",
+        )
+        .context("Failed to add links")?;
+        Ok(())
+    }
+
+    /// Note and issue templates
+    fn add_templates(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            CREATE TABLE IF NOT EXISTS templates (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                title TEXT NOT NULL DEFAULT '',
+                body TEXT NOT NULL DEFAULT '',
+                project_id TEXT,
+                repo_id TEXT,
+                schedule TEXT,
+                last_run TEXT,
+                created_at TEXT NOT NULL
+            );
+            COMMIT;",
+        )
+        .context("Failed to add templates")?;
+        Ok(())
+    }
+
+    /// Open security alert counts per repo
+    fn add_repo_security(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            CREATE TABLE IF NOT EXISTS repo_security (
+                repo_id TEXT PRIMARY KEY,
+                critical INTEGER NOT NULL DEFAULT 0,
+                high INTEGER NOT NULL DEFAULT 0,
+                medium INTEGER NOT NULL DEFAULT 0,
+                low INTEGER NOT NULL DEFAULT 0,
+                dependabot_enabled INTEGER NOT NULL DEFAULT 0,
+                code_scanning_enabled INTEGER NOT NULL DEFAULT 0,
+                checked_at TEXT NOT NULL
+            );
+            COMMIT;",
+        )
+        .context("Failed to add repo security")?;
+        Ok(())
+    }
+
+    /// Pass/fail history of local repo tasks
+    fn add_repo_task_runs(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            CREATE TABLE IF NOT EXISTS repo_task_runs (
+                id TEXT PRIMARY KEY,
+                repo_id TEXT NOT NULL,
+                task TEXT NOT NULL,
+                started_at TEXT NOT NULL,
+                duration_ms INTEGER NOT NULL,
+                exit_code INTEGER,
+                status TEXT NOT NULL,
+                output_tail TEXT NOT NULL DEFAULT ''
+            );
+            CREATE INDEX IF NOT EXISTS idx_repo_task_runs_repo
+                ON repo_task_runs(repo_id, started_at);
+            COMMIT;",
+        )
+        .context("Failed to add repo task runs")?;
+        Ok(())
+    }
+
+    /// Calendar events scheduled for kanban tasks
+    fn add_task_schedules(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            CREATE TABLE IF NOT EXISTS task_schedules (
+                event_id TEXT PRIMARY KEY,
+                calendar_id TEXT NOT NULL,
+                task_id TEXT NOT NULL,
+                start_at TEXT NOT NULL,
+                end_at TEXT NOT NULL,
+                created_at TEXT NOT NULL,
+                completed_at TEXT
+            );
+            CREATE INDEX IF NOT EXISTS idx_task_schedules_task ON task_schedules(task_id);
+            COMMIT;",
+        )
+        .context("Failed to add task schedules")?;
+        Ok(())
+    }
+
+    /// Archived projects keep their tasks but leave the board
+    fn add_project_archive(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            ALTER TABLE projects ADD COLUMN archived_at TEXT;
+            COMMIT;",
+        )
+        .context("Failed to add project archive")?;
+        Ok(())
+    }
+
+    /// Status transitions for board analytics. Existing tasks start with
+    /// their current status as of their last update.
+    fn add_task_status_history(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            CREATE TABLE IF NOT EXISTS task_status_history (
+                task_id TEXT NOT NULL,
+                project_id TEXT NOT NULL,
+                from_status TEXT,
+                to_status TEXT NOT NULL,
+                changed_at TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS idx_task_status_history_project
+                ON task_status_history(project_id, changed_at);
+            INSERT INTO task_status_history (task_id, project_id, from_status, to_status, changed_at)
+                SELECT id, project_id, NULL, status, updated_at FROM tasks;
+            COMMIT;",
+        )
+        .context("Failed to add task status history")?;
+        Ok(())
+    }
+
+    /// Per-column work-in-progress limits
+    fn add_column_wip_limits(conn: &Connection) -> Result<()> {
+        conn.execute_batch(
+            "BEGIN;
+            ALTER TABLE project_columns ADD COLUMN wip_limit INTEGER;
+            COMMIT;",
+        )
+        .context("Failed to add column WIP limits")?;
+        Ok(())
+    }
+
+    /// Task-list items of task bodies, parsed from the bodies already stored
+    fn add_task_subtasks(conn: &Connection) -> Result<()> {
+        let tx = conn.unchecked_transaction()?;
+        tx.execute_batch(
+            "CREATE TABLE IF NOT EXISTS task_subtasks (
+                task_id TEXT NOT NULL,
+                position INTEGER NOT NULL,
+                text TEXT NOT NULL,
+                checked INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (task_id, position)
+            );",
+        )
+        .context("Failed to add task subtasks")?;
+        let bodies: Vec<(String, String)> = tx
+            .prepare("SELECT id, body FROM tasks WHERE body LIKE '%[%]%'")?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_, _>>()?;
+        for (task_id, body) in bodies {
+            Self::replace_subtasks(&tx, &task_id, Some(&body))?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
     /// Migrate from v1 (github_repo per project) to v2 (many-to-many)
-    fn migrate_to_v2(&self, _from_version: i32) -> Result<()> {
+    fn migrate_to_v2(conn: &Connection, _from_version: i32) -> Result<()> {
         // Check if old schema exists
-        let old_exists: bool = self.conn.query_row(
+        let old_exists: bool = conn.query_row(
             "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='projects'",
             [],
             |row| row.get::<_, i32>(0),
         )? > 0;
 
         if !old_exists {
-            self.conn.execute("DELETE FROM schema_version", [])?;
-            self.conn.execute(
+            conn.execute("DELETE FROM schema_version", [])?;
+            conn.execute(
                 "INSERT INTO schema_version (version) VALUES (?1)",
                 params![SCHEMA_VERSION],
             )?;
@@ -100,8 +394,7 @@ impl ProjectStore {
         }
 
         // Check if old schema has github_repo column
-        let table_info: Vec<String> = self
-            .conn
+        let table_info: Vec<String> = conn
             .prepare("PRAGMA table_info(projects)")?
             .query_map([], |row| row.get::<_, String>(1))?
             .collect::<Result<Vec<_>, _>>()?;
@@ -110,8 +403,8 @@ impl ProjectStore {
 
         if !has_github_repo {
             // Already v2
-            self.conn.execute("DELETE FROM schema_version", [])?;
-            self.conn.execute(
+            conn.execute("DELETE FROM schema_version", [])?;
+            conn.execute(
                 "INSERT INTO schema_version (version) VALUES (?1)",
                 params![SCHEMA_VERSION],
             )?;
@@ -120,7 +413,7 @@ impl ProjectStore {
 
         // Migrate: copy old projects -> new projects (name = github_repo), insert project_repos
         // Copy old tasks -> new tasks (repo_id from project.github_repo)
-        self.conn.execute_batch("BEGIN TRANSACTION;
+        conn.execute_batch("BEGIN TRANSACTION;
 
             -- Create new tables if not exist (idempotent)
             CREATE TABLE IF NOT EXISTS projects_new (
@@ -178,17 +471,15 @@ impl ProjectStore {
 
             COMMIT;")?;
 
-        self.conn.execute("DELETE FROM schema_version", [])?;
-        self.conn
-            .execute("INSERT INTO schema_version (version) VALUES (?1)", params![SCHEMA_VERSION])?;
+        conn.execute("DELETE FROM schema_version", [])?;
+        conn.execute("INSERT INTO schema_version (version) VALUES (?1)", params![SCHEMA_VERSION])?;
 
         Ok(())
     }
 
     /// Migrate from v2 (repo-based tasks) to v3 (project-based tasks)
-    fn migrate_to_v3(&self) -> Result<()> {
-        let version: i32 = self
-            .conn
+    fn migrate_to_v3(conn: &Connection) -> Result<()> {
+        let version: i32 = conn
             .query_row("SELECT version FROM schema_version LIMIT 1", [], |row| row.get(0))
             .optional()?
             .unwrap_or(2);
@@ -197,38 +488,37 @@ impl ProjectStore {
             return Ok(());
         }
 
-        let has_tasks: bool = self.conn.query_row(
+        let has_tasks: bool = conn.query_row(
             "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='tasks'",
             [],
             |row| row.get::<_, i32>(0),
         )? > 0;
 
         if !has_tasks {
-            self.conn.execute("DELETE FROM schema_version", [])?;
-            self.conn.execute(
+            conn.execute("DELETE FROM schema_version", [])?;
+            conn.execute(
                 "INSERT INTO schema_version (version) VALUES (?1)",
                 params![SCHEMA_VERSION],
             )?;
             return Ok(());
         }
 
-        let table_info: Vec<String> = self
-            .conn
+        let table_info: Vec<String> = conn
             .prepare("PRAGMA table_info(tasks)")?
             .query_map([], |row| row.get::<_, String>(1))?
             .collect::<Result<Vec<_>, _>>()?;
 
         let has_project_id = table_info.iter().any(|c| c == "project_id");
         if has_project_id {
-            self.conn.execute("DELETE FROM schema_version", [])?;
-            self.conn.execute(
+            conn.execute("DELETE FROM schema_version", [])?;
+            conn.execute(
                 "INSERT INTO schema_version (version) VALUES (?1)",
                 params![SCHEMA_VERSION],
             )?;
             return Ok(());
         }
 
-        self.conn.execute_batch(
+        conn.execute_batch(
             "BEGIN TRANSACTION;
 
             CREATE TABLE IF NOT EXISTS tasks_new (
@@ -257,22 +547,33 @@ impl ProjectStore {
             DROP TABLE tasks;
             ALTER TABLE tasks_new RENAME TO tasks;
 
+            CREATE TABLE IF NOT EXISTS focus_sessions (
+                id TEXT PRIMARY KEY,
+                task_id TEXT,
+                kind TEXT NOT NULL,
+                started_at TEXT NOT NULL,
+                ended_at TEXT NOT NULL,
+                planned_secs INTEGER NOT NULL,
+                focused_secs INTEGER NOT NULL,
+                completed INTEGER NOT NULL DEFAULT 0
+            );
+
             CREATE INDEX IF NOT EXISTS idx_tasks_project ON tasks(project_id);
             CREATE INDEX IF NOT EXISTS idx_tasks_status ON tasks(status);
 
             COMMIT;"
         )?;
 
-        self.conn.execute("DELETE FROM schema_version", [])?;
-        self.conn
-            .execute("INSERT INTO schema_version (version) VALUES (?1)", params![SCHEMA_VERSION])?;
+        conn.execute("DELETE FROM schema_version", [])?;
+        conn.execute("INSERT INTO schema_version (version) VALUES (?1)", params![SCHEMA_VERSION])?;
 
         Ok(())
     }
 
     /// Insert or update a project
     pub fn upsert_project(&self, project: &Project) -> Result<()> {
-        self.conn.execute(
+        let conn = self.writer();
+        conn.execute(
             "INSERT INTO projects (id, name, description, created_at)
              VALUES (?1, ?2, ?3, ?4)
              ON CONFLICT(id) DO UPDATE SET
@@ -283,59 +584,94 @@ impl ProjectStore {
         Ok(())
     }
 
-    /// Get all projects
+    /// Get all projects that aren't archived
     pub fn list_projects(&self) -> Result<Vec<Project>> {
-        let mut stmt = self.conn.prepare(
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
             "SELECT id, name, description, created_at
-             FROM projects ORDER BY created_at DESC",
+             FROM projects WHERE archived_at IS NULL ORDER BY created_at DESC",
         )?;
 
-        let projects = stmt
-            .query_map([], |row| {
-                Ok(Project {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    description: row.get(2)?,
-                    created_at: row.get(3)?,
-                })
-            })?
-            .collect::<Result<Vec<_>, _>>()?;
+        let projects = stmt.query_map([], Self::row_to_project)?.collect::<Result<Vec<_>, _>>()?;
+        Ok(projects)
+    }
 
+    /// Archived projects, most recently archived first
+    pub fn list_archived(&self) -> Result<Vec<Project>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT id, name, description, created_at
+             FROM projects WHERE archived_at IS NOT NULL ORDER BY archived_at DESC",
+        )?;
+
+        let projects = stmt.query_map([], Self::row_to_project)?.collect::<Result<Vec<_>, _>>()?;
         Ok(projects)
     }
 
+    /// Archive a project: it leaves `list_projects` and background syncs but
+    /// keeps its tasks, columns and repos. Returns false if it doesn't exist
+    /// or is already archived.
+    pub fn archive_project(&self, id: &str) -> Result<bool> {
+        let conn = self.writer();
+        let changed = conn.execute(
+            "UPDATE projects SET archived_at = ?2 WHERE id = ?1 AND archived_at IS NULL",
+            params![id, chrono::Utc::now().to_rfc3339()],
+        )?;
+        Ok(changed > 0)
+    }
+
+    /// Bring an archived project back. Returns false if it isn't archived.
+    pub fn restore_project(&self, id: &str) -> Result<bool> {
+        let conn = self.writer();
+        let changed = conn.execute(
+            "UPDATE projects SET archived_at = NULL WHERE id = ?1 AND archived_at IS NOT NULL",
+            [id],
+        )?;
+        Ok(changed > 0)
+    }
+
+    fn row_to_project(row: &rusqlite::Row) -> rusqlite::Result<Project> {
+        Ok(Project {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            description: row.get(2)?,
+            created_at: row.get(3)?,
+        })
+    }
+
     /// Get a project by ID
     pub fn get_project(&self, id: &str) -> Result<Option<Project>> {
-        let mut stmt = self.conn.prepare(
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
             "SELECT id, name, description, created_at
              FROM projects WHERE id = ?1",
         )?;
 
-        let project = stmt
-            .query_row([id], |row| {
-                Ok(Project {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    description: row.get(2)?,
-                    created_at: row.get(3)?,
-                })
-            })
-            .optional()?;
+        let project = stmt.query_row([id], Self::row_to_project).optional()?;
 
         Ok(project)
     }
 
-    /// Delete a project, its project_repos links, and its tasks
+    /// Delete a project, its project_repos links, columns, tasks and their
+    /// history and subtasks
     pub fn delete_project(&self, id: &str) -> Result<()> {
-        self.conn.execute("DELETE FROM tasks WHERE project_id = ?1", [id])?;
-        self.conn.execute("DELETE FROM project_repos WHERE project_id = ?1", [id])?;
-        self.conn.execute("DELETE FROM projects WHERE id = ?1", [id])?;
+        let conn = self.writer();
+        conn.execute(
+            "DELETE FROM task_subtasks WHERE task_id IN (SELECT id FROM tasks WHERE project_id = ?1)",
+            [id],
+        )?;
+        conn.execute("DELETE FROM tasks WHERE project_id = ?1", [id])?;
+        conn.execute("DELETE FROM task_status_history WHERE project_id = ?1", [id])?;
+        conn.execute("DELETE FROM project_columns WHERE project_id = ?1", [id])?;
+        conn.execute("DELETE FROM project_repos WHERE project_id = ?1", [id])?;
+        conn.execute("DELETE FROM projects WHERE id = ?1", [id])?;
         Ok(())
     }
 
     /// Add a repo to a project
     pub fn add_repo_to_project(&self, project_id: &str, repo_id: &str) -> Result<()> {
-        self.conn.execute(
+        let conn = self.writer();
+        conn.execute(
             "INSERT OR IGNORE INTO project_repos (project_id, repo_id) VALUES (?1, ?2)",
             params![project_id, repo_id],
         )?;
@@ -344,7 +680,8 @@ impl ProjectStore {
 
     /// Remove a repo from a project
     pub fn remove_repo_from_project(&self, project_id: &str, repo_id: &str) -> Result<()> {
-        self.conn.execute(
+        let conn = self.writer();
+        conn.execute(
             "DELETE FROM project_repos WHERE project_id = ?1 AND repo_id = ?2",
             params![project_id, repo_id],
         )?;
@@ -353,8 +690,8 @@ impl ProjectStore {
 
     /// List repos associated with a project
     pub fn list_repos_for_project(&self, project_id: &str) -> Result<Vec<String>> {
-        let mut stmt = self
-            .conn
+        let conn = self.reader();
+        let mut stmt = conn
             .prepare("SELECT repo_id FROM project_repos WHERE project_id = ?1 ORDER BY repo_id")?;
 
         let repos =
@@ -362,10 +699,16 @@ impl ProjectStore {
         Ok(repos)
     }
 
-    /// List all distinct repo_ids linked to any project (owner/repo format)
+    /// List all distinct repo_ids linked to any project that isn't archived
+    /// (owner/repo format)
     pub fn list_all_linked_repo_ids(&self) -> Result<Vec<String>> {
-        let mut stmt =
-            self.conn.prepare("SELECT DISTINCT repo_id FROM project_repos ORDER BY repo_id")?;
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT DISTINCT pr.repo_id FROM project_repos pr
+             JOIN projects p ON p.id = pr.project_id
+             WHERE p.archived_at IS NULL
+             ORDER BY pr.repo_id",
+        )?;
 
         let repos = stmt.query_map([], |row| row.get(0))?.collect::<Result<Vec<_>, _>>()?;
         Ok(repos)
@@ -373,7 +716,8 @@ impl ProjectStore {
 
     /// List projects that contain a repo
     pub fn list_projects_for_repo(&self, repo_id: &str) -> Result<Vec<Project>> {
-        let mut stmt = self.conn.prepare(
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
             "SELECT p.id, p.name, p.description, p.created_at
              FROM projects p
              JOIN project_repos pr ON p.id = pr.project_id
@@ -381,31 +725,37 @@ impl ProjectStore {
              ORDER BY p.created_at DESC",
         )?;
 
-        let projects = stmt
-            .query_map([repo_id], |row| {
-                Ok(Project {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    description: row.get(2)?,
-                    created_at: row.get(3)?,
-                })
-            })?
-            .collect::<Result<Vec<_>, _>>()?;
+        let projects =
+            stmt.query_map([repo_id], Self::row_to_project)?.collect::<Result<Vec<_>, _>>()?;
 
         Ok(projects)
     }
 
-    /// Insert or update a task
+    /// Insert or update a task.
+    ///
+    /// New tasks go to the end of the project's order. `order_index` is never
+    /// overwritten here, so syncs keep manual ordering; use `set_task_order`.
+    ///
+    /// New tasks and status changes are recorded in the status history, as of
+    /// `created_at` and `updated_at` respectively. The body's task list is
+    /// stored as the task's subtasks.
     pub fn upsert_task(&self, task: &Task) -> Result<()> {
+        let mut conn = self.writer();
+        let tx = conn.transaction()?;
         let status_str = serde_json::to_string(&task.status)?;
+        let previous: Option<String> = tx
+            .query_row("SELECT status FROM tasks WHERE id = ?1", [&task.id], |row| row.get(0))
+            .optional()?;
 
-        self.conn.execute(
-            "INSERT INTO tasks (id, project_id, title, body, status, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        tx.execute(
+            "INSERT INTO tasks (id, project_id, title, body, status, column_id, created_at, updated_at, order_index)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8,
+                (SELECT COALESCE(MAX(order_index) + 1, 0) FROM tasks WHERE project_id = ?2))
              ON CONFLICT(id) DO UPDATE SET
                 title = excluded.title,
                 body = excluded.body,
                 status = excluded.status,
+                column_id = excluded.column_id,
                 updated_at = excluded.updated_at",
             params![
                 task.id,
@@ -413,18 +763,102 @@ impl ProjectStore {
                 task.title,
                 task.body,
                 status_str,
+                task.column_id,
                 task.created_at,
                 task.updated_at,
             ],
         )?;
+        Self::replace_subtasks(&tx, &task.id, task.body.as_deref())?;
+        if previous.as_deref() != Some(status_str.as_str()) {
+            let changed_at = if previous.is_some() { &task.updated_at } else { &task.created_at };
+            tx.execute(
+                "INSERT INTO task_status_history
+                 (task_id, project_id, from_status, to_status, changed_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![task.id, task.project_id, previous, status_str, changed_at],
+            )?;
+        }
+        tx.commit()?;
         Ok(())
     }
 
+    /// Store the task-list items of `body` as a task's subtasks
+    fn replace_subtasks(conn: &Connection, task_id: &str, body: Option<&str>) -> Result<()> {
+        conn.execute("DELETE FROM task_subtasks WHERE task_id = ?1", [task_id])?;
+        let items = body.map(checklist::parse).unwrap_or_default();
+        for (position, item) in items.iter().enumerate() {
+            conn.execute(
+                "INSERT INTO task_subtasks (task_id, position, text, checked)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![task_id, position as i32, item.text, item.checked],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// A task's subtasks (its body's task list), in order
+    pub fn list_subtasks(&self, task_id: &str) -> Result<Vec<ChecklistItem>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT text, checked FROM task_subtasks WHERE task_id = ?1 ORDER BY position",
+        )?;
+        let items = stmt
+            .query_map([task_id], |row| {
+                Ok(ChecklistItem { text: row.get(0)?, checked: row.get(1)? })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(items)
+    }
+
+    /// Done and total subtasks of each task in a project that has any
+    pub fn subtask_progress(&self, project_id: &str) -> Result<HashMap<String, (usize, usize)>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT s.task_id, SUM(s.checked), COUNT(*)
+             FROM task_subtasks s JOIN tasks t ON t.id = s.task_id
+             WHERE t.project_id = ?1 GROUP BY s.task_id",
+        )?;
+        let progress = stmt
+            .query_map([project_id], |row| {
+                let done: i64 = row.get(1)?;
+                let total: i64 = row.get(2)?;
+                Ok((row.get(0)?, (done as usize, total as usize)))
+            })?
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        Ok(progress)
+    }
+
+    /// Status transitions of a project's tasks, oldest first
+    pub fn list_status_history(&self, project_id: &str) -> Result<Vec<StatusChange>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT task_id, from_status, to_status, changed_at
+             FROM task_status_history WHERE project_id = ?1
+             ORDER BY changed_at, rowid",
+        )?;
+
+        let history = stmt
+            .query_map([project_id], |row| {
+                let from: Option<String> = row.get(1)?;
+                let to: String = row.get(2)?;
+                Ok(StatusChange {
+                    task_id: row.get(0)?,
+                    from: from.and_then(|s| serde_json::from_str(&s).ok()),
+                    to: serde_json::from_str(&to).unwrap_or(TaskStatus::Todo),
+                    changed_at: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(history)
+    }
+
     /// Get tasks for a project
     pub fn list_tasks_for_project(&self, project_id: &str) -> Result<Vec<Task>> {
-        let mut stmt = self.conn.prepare(
-            "SELECT id, project_id, title, body, status, created_at, updated_at
-             FROM tasks WHERE project_id = ?1 ORDER BY created_at",
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT id, project_id, title, body, status, column_id, created_at, updated_at,
+                    order_index
+             FROM tasks WHERE project_id = ?1 ORDER BY order_index, created_at",
         )?;
 
         let tasks = stmt
@@ -436,8 +870,10 @@ impl ProjectStore {
                     title: row.get(2)?,
                     body: row.get(3)?,
                     status: serde_json::from_str(&status_str).unwrap_or(TaskStatus::Todo),
-                    created_at: row.get(5)?,
-                    updated_at: row.get(6)?,
+                    column_id: row.get(5)?,
+                    created_at: row.get(6)?,
+                    updated_at: row.get(7)?,
+                    order_index: row.get(8)?,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -445,16 +881,119 @@ impl ProjectStore {
         Ok(tasks)
     }
 
+    /// Get a task by id
+    pub fn get_task(&self, task_id: &str) -> Result<Option<Task>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT id, project_id, title, body, status, column_id, created_at, updated_at,
+                    order_index
+             FROM tasks WHERE id = ?1",
+        )?;
+
+        let task = stmt
+            .query_row([task_id], |row| {
+                let status_str: String = row.get(4)?;
+                Ok(Task {
+                    id: row.get(0)?,
+                    project_id: row.get(1)?,
+                    title: row.get(2)?,
+                    body: row.get(3)?,
+                    status: serde_json::from_str(&status_str).unwrap_or(TaskStatus::Todo),
+                    column_id: row.get(5)?,
+                    created_at: row.get(6)?,
+                    updated_at: row.get(7)?,
+                    order_index: row.get(8)?,
+                })
+            })
+            .optional()?;
+
+        Ok(task)
+    }
+
+    /// Up to `limit` tasks in any project whose title or body contains every
+    /// word of `query` (case-insensitive), most recently updated first.
+    pub fn search_tasks(&self, query: &str, limit: usize) -> Result<Vec<Task>> {
+        let words: Vec<String> =
+            query.split_whitespace().map(|w| format!("%{}%", w.to_lowercase())).collect();
+        if words.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conditions: Vec<String> = (0..words.len())
+            .map(|i| format!("(title || char(10) || coalesce(body, '')) LIKE ?{}", i + 1))
+            .collect();
+        let sql = format!(
+            "SELECT id, project_id, title, body, status, column_id, created_at, updated_at,
+                    order_index
+             FROM tasks WHERE {} ORDER BY updated_at DESC LIMIT {}",
+            conditions.join(" AND "),
+            i64::try_from(limit).unwrap_or(-1)
+        );
+
+        let conn = self.reader();
+        let mut stmt = conn.prepare(&sql)?;
+        let tasks = stmt
+            .query_map(rusqlite::params_from_iter(&words), |row| {
+                let status_str: String = row.get(4)?;
+                Ok(Task {
+                    id: row.get(0)?,
+                    project_id: row.get(1)?,
+                    title: row.get(2)?,
+                    body: row.get(3)?,
+                    status: serde_json::from_str(&status_str).unwrap_or(TaskStatus::Todo),
+                    column_id: row.get(5)?,
+                    created_at: row.get(6)?,
+                    updated_at: row.get(7)?,
+                    order_index: row.get(8)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(tasks)
+    }
+
+    /// Reindex a project's tasks in the given order (in one transaction).
+    /// Tasks not listed keep their relative order after the listed ones.
+    pub fn set_task_order(&self, project_id: &str, task_ids: &[String]) -> Result<()> {
+        let mut conn = self.writer();
+        let tx = conn.transaction()?;
+        let existing: Vec<String> = {
+            let mut stmt = tx.prepare(
+                "SELECT id FROM tasks WHERE project_id = ?1 ORDER BY order_index, created_at",
+            )?;
+            let ids =
+                stmt.query_map([project_id], |row| row.get(0))?.collect::<Result<Vec<_>, _>>()?;
+            ids
+        };
+        let ordered = task_ids
+            .iter()
+            .filter(|id| existing.contains(id))
+            .chain(existing.iter().filter(|id| !task_ids.contains(id)));
+        for (order_index, id) in ordered.enumerate() {
+            tx.execute(
+                "UPDATE tasks SET order_index = ?2 WHERE id = ?1",
+                params![id, order_index as i32],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
     /// Delete a task by id
     pub fn delete_task(&self, task_id: &str) -> Result<()> {
-        self.conn.execute("DELETE FROM tasks WHERE id = ?1", [task_id])?;
+        let conn = self.writer();
+        conn.execute("DELETE FROM tasks WHERE id = ?1", [task_id])?;
+        conn.execute("DELETE FROM task_schedules WHERE task_id = ?1", [task_id])?;
+        conn.execute("DELETE FROM task_status_history WHERE task_id = ?1", [task_id])?;
+        conn.execute("DELETE FROM task_subtasks WHERE task_id = ?1", [task_id])?;
+        Self::delete_links(&conn, &EntityRef::task(task_id))?;
         Ok(())
     }
 
     /// Count tasks by status for a project
     pub fn count_tasks_by_status(&self, project_id: &str) -> Result<Vec<(TaskStatus, i32)>> {
-        let mut stmt = self
-            .conn
+        let conn = self.reader();
+        let mut stmt = conn
             .prepare("SELECT status, COUNT(*) FROM tasks WHERE project_id = ?1 GROUP BY status")?;
 
         let counts = stmt
@@ -468,6 +1007,725 @@ impl ProjectStore {
 
         Ok(counts)
     }
+
+    /// Board columns for a project in display order (the defaults until the
+    /// board is customized)
+    pub fn list_columns(&self, project_id: &str) -> Result<Vec<ProjectColumn>> {
+        let conn = self.reader();
+        let columns = Self::query_columns(&conn, project_id)?;
+        if columns.is_empty() {
+            return Ok(ProjectColumn::defaults(project_id));
+        }
+        Ok(columns)
+    }
+
+    fn query_columns(conn: &Connection, project_id: &str) -> Result<Vec<ProjectColumn>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, project_id, name, position, label, status, wip_limit
+             FROM project_columns WHERE project_id = ?1 ORDER BY position, id",
+        )?;
+        let columns = stmt
+            .query_map([project_id], |row| {
+                let status_str: String = row.get(5)?;
+                Ok(ProjectColumn {
+                    id: row.get(0)?,
+                    project_id: row.get(1)?,
+                    name: row.get(2)?,
+                    position: row.get(3)?,
+                    label: row.get(4)?,
+                    status: serde_json::from_str(&status_str).unwrap_or(TaskStatus::Todo),
+                    wip_limit: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(columns)
+    }
+
+    /// Store the default columns so they can be edited, unless the project
+    /// already has its own. Returns the project's columns.
+    fn materialize_columns(conn: &Connection, project_id: &str) -> Result<Vec<ProjectColumn>> {
+        let existing = Self::query_columns(conn, project_id)?;
+        if !existing.is_empty() {
+            return Ok(existing);
+        }
+        let defaults = ProjectColumn::defaults(project_id);
+        for column in &defaults {
+            Self::insert_column(conn, column)?;
+        }
+        Ok(defaults)
+    }
+
+    fn insert_column(conn: &Connection, column: &ProjectColumn) -> Result<()> {
+        conn.execute(
+            "INSERT INTO project_columns (project_id, id, name, position, label, status, wip_limit)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                column.project_id,
+                column.id,
+                column.name,
+                column.position,
+                column.label,
+                serde_json::to_string(&column.status)?,
+                column.wip_limit,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Append a column to a project's board. Its id is derived from the name.
+    pub fn add_column(
+        &self,
+        project_id: &str,
+        name: &str,
+        label: Option<&str>,
+        status: TaskStatus,
+    ) -> Result<ProjectColumn> {
+        let name = name.trim();
+        if name.is_empty() {
+            anyhow::bail!("Column name cannot be empty");
+        }
+        let conn = self.writer();
+        let columns = Self::materialize_columns(&conn, project_id)?;
+
+        let slug = name
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        let base = if slug.is_empty() { "column".to_string() } else { slug };
+        let mut id = base.clone();
+        let mut n = 2;
+        while columns.iter().any(|c| c.id == id) {
+            id = format!("{}-{}", base, n);
+            n += 1;
+        }
+
+        let column = ProjectColumn {
+            id,
+            project_id: project_id.to_string(),
+            name: name.to_string(),
+            position: columns.iter().map(|c| c.position + 1).max().unwrap_or(0),
+            label: label.map(str::trim).filter(|l| !l.is_empty()).map(str::to_string),
+            status,
+            wip_limit: None,
+        };
+        Self::insert_column(&conn, &column)?;
+        Ok(column)
+    }
+
+    /// Rename a board column
+    pub fn rename_column(&self, project_id: &str, column_id: &str, name: &str) -> Result<()> {
+        let name = name.trim();
+        if name.is_empty() {
+            anyhow::bail!("Column name cannot be empty");
+        }
+        let conn = self.writer();
+        Self::materialize_columns(&conn, project_id)?;
+        let updated = conn.execute(
+            "UPDATE project_columns SET name = ?3 WHERE project_id = ?1 AND id = ?2",
+            params![project_id, column_id, name],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Column not found: {}", column_id);
+        }
+        Ok(())
+    }
+
+    /// Change the GitHub label a column maps to (`None` to clear it)
+    pub fn set_column_label(
+        &self,
+        project_id: &str,
+        column_id: &str,
+        label: Option<&str>,
+    ) -> Result<()> {
+        let conn = self.writer();
+        Self::materialize_columns(&conn, project_id)?;
+        let label = label.map(str::trim).filter(|l| !l.is_empty());
+        let updated = conn.execute(
+            "UPDATE project_columns SET label = ?3 WHERE project_id = ?1 AND id = ?2",
+            params![project_id, column_id, label],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Column not found: {}", column_id);
+        }
+        Ok(())
+    }
+
+    /// Set how many tasks a column should hold at once (`None` or 0 for no
+    /// limit)
+    pub fn set_column_wip_limit(
+        &self,
+        project_id: &str,
+        column_id: &str,
+        limit: Option<u32>,
+    ) -> Result<()> {
+        let conn = self.writer();
+        Self::materialize_columns(&conn, project_id)?;
+        let limit = limit.filter(|l| *l > 0);
+        let updated = conn.execute(
+            "UPDATE project_columns SET wip_limit = ?3 WHERE project_id = ?1 AND id = ?2",
+            params![project_id, column_id, limit],
+        )?;
+        if updated == 0 {
+            anyhow::bail!("Column not found: {}", column_id);
+        }
+        Ok(())
+    }
+
+    /// Reorder a board's columns. Columns not in `column_ids` keep their
+    /// relative order after the listed ones.
+    pub fn reorder_columns(&self, project_id: &str, column_ids: &[String]) -> Result<()> {
+        let mut conn = self.writer();
+        let columns = Self::materialize_columns(&conn, project_id)?;
+        let ordered = column_ids
+            .iter()
+            .filter(|id| columns.iter().any(|c| &c.id == *id))
+            .chain(columns.iter().map(|c| &c.id).filter(|id| !column_ids.contains(id)));
+
+        let tx = conn.transaction()?;
+        for (position, id) in ordered.enumerate() {
+            tx.execute(
+                "UPDATE project_columns SET position = ?3 WHERE project_id = ?1 AND id = ?2",
+                params![project_id, id, position as i32],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Ensure a repo_meta row exists for a repo
+    fn ensure_repo_meta(conn: &Connection, repo_id: &str) -> Result<()> {
+        conn.execute("INSERT OR IGNORE INTO repo_meta (repo_id) VALUES (?1)", [repo_id])?;
+        Ok(())
+    }
+
+    /// Mark or unmark a repo as favorite
+    pub fn set_repo_favorite(&self, repo_id: &str, favorite: bool) -> Result<()> {
+        let conn = self.writer();
+        Self::ensure_repo_meta(&conn, repo_id)?;
+        conn.execute(
+            "UPDATE repo_meta SET favorite = ?2 WHERE repo_id = ?1",
+            params![repo_id, favorite],
+        )?;
+        Ok(())
+    }
+
+    /// Assign a repo to a named group (None clears the group)
+    pub fn set_repo_group(&self, repo_id: &str, group_name: Option<&str>) -> Result<()> {
+        let conn = self.writer();
+        Self::ensure_repo_meta(&conn, repo_id)?;
+        conn.execute(
+            "UPDATE repo_meta SET group_name = ?2 WHERE repo_id = ?1",
+            params![repo_id, group_name],
+        )?;
+        Ok(())
+    }
+
+    /// Set the custom sort position of a repo (lower sorts first)
+    pub fn set_repo_sort_order(&self, repo_id: &str, sort_order: i32) -> Result<()> {
+        let conn = self.writer();
+        Self::ensure_repo_meta(&conn, repo_id)?;
+        conn.execute(
+            "UPDATE repo_meta SET sort_order = ?2 WHERE repo_id = ?1",
+            params![repo_id, sort_order],
+        )?;
+        Ok(())
+    }
+
+    /// Add a tag to a repo (no-op if already tagged)
+    pub fn add_repo_tag(&self, repo_id: &str, tag: &str) -> Result<()> {
+        let conn = self.writer();
+        Self::ensure_repo_meta(&conn, repo_id)?;
+        conn.execute(
+            "INSERT OR IGNORE INTO repo_tags (repo_id, tag) VALUES (?1, ?2)",
+            params![repo_id, tag],
+        )?;
+        Ok(())
+    }
+
+    /// Remove a tag from a repo
+    pub fn remove_repo_tag(&self, repo_id: &str, tag: &str) -> Result<()> {
+        let conn = self.writer();
+        conn.execute(
+            "DELETE FROM repo_tags WHERE repo_id = ?1 AND tag = ?2",
+            params![repo_id, tag],
+        )?;
+        Ok(())
+    }
+
+    /// List all distinct repo tags
+    pub fn list_repo_tags(&self) -> Result<Vec<String>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare("SELECT DISTINCT tag FROM repo_tags ORDER BY tag")?;
+        let tags = stmt.query_map([], |row| row.get(0))?.collect::<Result<Vec<_>, _>>()?;
+        Ok(tags)
+    }
+
+    /// Get metadata for all repos that have any, including tags
+    pub fn list_repo_meta(&self) -> Result<Vec<RepoMeta>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT repo_id, favorite, group_name, sort_order
+             FROM repo_meta ORDER BY sort_order, repo_id",
+        )?;
+        let mut metas = stmt
+            .query_map([], |row| {
+                Ok(RepoMeta {
+                    repo_id: row.get(0)?,
+                    favorite: row.get(1)?,
+                    group_name: row.get(2)?,
+                    sort_order: row.get(3)?,
+                    tags: Vec::new(),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut tag_stmt =
+            conn.prepare("SELECT tag FROM repo_tags WHERE repo_id = ?1 ORDER BY tag")?;
+        for meta in &mut metas {
+            meta.tags = tag_stmt
+                .query_map([&meta.repo_id], |row| row.get(0))?
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        Ok(metas)
+    }
+
+    /// Replace the stored alert counts for a repo
+    pub fn set_repo_security(&self, security: &RepoSecurity) -> Result<()> {
+        let conn = self.writer();
+        conn.execute(
+            "INSERT OR REPLACE INTO repo_security
+             (repo_id, critical, high, medium, low, dependabot_enabled, code_scanning_enabled,
+              checked_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                security.repo_id,
+                security.critical,
+                security.high,
+                security.medium,
+                security.low,
+                security.dependabot_enabled,
+                security.code_scanning_enabled,
+                security.checked_at,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Alert counts for every repo checked so far
+    pub fn list_repo_security(&self) -> Result<Vec<RepoSecurity>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT repo_id, critical, high, medium, low, dependabot_enabled,
+                    code_scanning_enabled, checked_at
+             FROM repo_security ORDER BY repo_id",
+        )?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(RepoSecurity {
+                    repo_id: row.get(0)?,
+                    critical: row.get(1)?,
+                    high: row.get(2)?,
+                    medium: row.get(3)?,
+                    low: row.get(4)?,
+                    dependabot_enabled: row.get(5)?,
+                    code_scanning_enabled: row.get(6)?,
+                    checked_at: row.get(7)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(rows)
+    }
+
+    /// Record a finished repo task run, keeping the newest `REPO_TASK_RUNS_KEPT`
+    /// runs per repo and task
+    pub fn record_repo_task_run(&self, run: &RepoTaskRun) -> Result<()> {
+        let mut conn = self.writer();
+        let tx = conn.transaction()?;
+        tx.execute(
+            "INSERT OR REPLACE INTO repo_task_runs
+             (id, repo_id, task, started_at, duration_ms, exit_code, status, output_tail)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                run.id,
+                run.repo_id,
+                run.task,
+                run.started_at,
+                run.duration_ms,
+                run.exit_code,
+                run.status.as_str(),
+                run.output_tail,
+            ],
+        )?;
+        tx.execute(
+            "DELETE FROM repo_task_runs
+             WHERE repo_id = ?1 AND task = ?2 AND id NOT IN (
+                 SELECT id FROM repo_task_runs WHERE repo_id = ?1 AND task = ?2
+                 ORDER BY started_at DESC LIMIT ?3
+             )",
+            params![run.repo_id, run.task, REPO_TASK_RUNS_KEPT],
+        )?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Most recent task runs for a repo, newest first
+    pub fn list_repo_task_runs(&self, repo_id: &str, limit: usize) -> Result<Vec<RepoTaskRun>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT id, repo_id, task, started_at, duration_ms, exit_code, status, output_tail
+             FROM repo_task_runs WHERE repo_id = ?1
+             ORDER BY started_at DESC LIMIT ?2",
+        )?;
+        let rows = stmt.query_map(params![repo_id, limit as i64], |row| {
+            let status: String = row.get(6)?;
+            let Some(status) = RepoTaskStatus::parse(&status) else {
+                return Ok(None);
+            };
+            Ok(Some(RepoTaskRun {
+                id: row.get(0)?,
+                repo_id: row.get(1)?,
+                task: row.get(2)?,
+                started_at: row.get(3)?,
+                duration_ms: row.get(4)?,
+                exit_code: row.get(5)?,
+                status,
+                output_tail: row.get(7)?,
+            }))
+        })?;
+        // Skip statuses written by a newer version
+        Ok(rows.filter_map(|row| row.transpose()).collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Record a finished focus session
+    pub fn insert_focus_session(&self, session: &FocusSession) -> Result<()> {
+        let conn = self.writer();
+        conn.execute(
+            "INSERT OR REPLACE INTO focus_sessions
+             (id, task_id, kind, started_at, ended_at, planned_secs, focused_secs, completed)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                session.id,
+                session.task_id,
+                session.kind.as_str(),
+                session.started_at,
+                session.ended_at,
+                session.planned_secs,
+                session.focused_secs,
+                session.completed as i32,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Most recent focus sessions, newest first; limited to one task if `task_id` is set
+    pub fn list_focus_sessions(
+        &self,
+        task_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<FocusSession>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT id, task_id, kind, started_at, ended_at, planned_secs, focused_secs, completed
+             FROM focus_sessions
+             WHERE ?1 IS NULL OR task_id = ?1
+             ORDER BY started_at DESC LIMIT ?2",
+        )?;
+
+        let sessions = stmt
+            .query_map(params![task_id, limit as i64], |row| {
+                let kind: String = row.get(2)?;
+                Ok(FocusSession {
+                    id: row.get(0)?,
+                    task_id: row.get(1)?,
+                    kind: FocusKind::parse(&kind).unwrap_or(FocusKind::Work),
+                    started_at: row.get(3)?,
+                    ended_at: row.get(4)?,
+                    planned_secs: row.get(5)?,
+                    focused_secs: row.get(6)?,
+                    completed: row.get::<_, i32>(7)? != 0,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(sessions)
+    }
+
+    /// Total focused (work) seconds recorded for a task
+    pub fn focus_seconds_for_task(&self, task_id: &str) -> Result<i64> {
+        let conn = self.reader();
+        let total = conn.query_row(
+            "SELECT COALESCE(SUM(focused_secs), 0) FROM focus_sessions
+             WHERE task_id = ?1 AND kind = 'work'",
+            [task_id],
+            |row| row.get(0),
+        )?;
+        Ok(total)
+    }
+
+    /// Record a calendar event scheduled for a task, linking the two
+    pub fn add_task_schedule(&self, schedule: &TaskSchedule) -> Result<()> {
+        self.writer().execute(
+            "INSERT OR REPLACE INTO task_schedules
+             (event_id, calendar_id, task_id, start_at, end_at, created_at, completed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                schedule.event_id,
+                schedule.calendar_id,
+                schedule.task_id,
+                schedule.start,
+                schedule.end,
+                schedule.created_at,
+                schedule.completed_at,
+            ],
+        )?;
+        self.link(&EntityRef::task(&schedule.task_id), &EntityRef::event(&schedule.event_id))?;
+        Ok(())
+    }
+
+    /// Events scheduled for a task, earliest first
+    pub fn list_task_schedules(&self, task_id: &str) -> Result<Vec<TaskSchedule>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT event_id, calendar_id, task_id, start_at, end_at, created_at, completed_at
+             FROM task_schedules WHERE task_id = ?1 ORDER BY start_at",
+        )?;
+        let schedules =
+            stmt.query_map([task_id], Self::row_to_schedule)?.collect::<Result<Vec<_>, _>>()?;
+        Ok(schedules)
+    }
+
+    /// The task schedule behind a calendar event, if it is one
+    pub fn task_schedule_for_event(&self, event_id: &str) -> Result<Option<TaskSchedule>> {
+        let conn = self.reader();
+        let schedule = conn
+            .query_row(
+                "SELECT event_id, calendar_id, task_id, start_at, end_at, created_at, completed_at
+                 FROM task_schedules WHERE event_id = ?1",
+                [event_id],
+                Self::row_to_schedule,
+            )
+            .optional()?;
+        Ok(schedule)
+    }
+
+    /// Set or clear when a scheduled event's task was completed
+    pub fn set_task_schedule_completed(
+        &self,
+        event_id: &str,
+        completed_at: Option<&str>,
+    ) -> Result<()> {
+        self.writer().execute(
+            "UPDATE task_schedules SET completed_at = ?2 WHERE event_id = ?1",
+            params![event_id, completed_at],
+        )?;
+        Ok(())
+    }
+
+    /// Forget a scheduled event (e.g. after it was deleted from the calendar)
+    pub fn remove_task_schedule(&self, event_id: &str) -> Result<()> {
+        let Some(schedule) = self.task_schedule_for_event(event_id)? else {
+            return Ok(());
+        };
+        self.writer().execute("DELETE FROM task_schedules WHERE event_id = ?1", [event_id])?;
+        self.unlink(&EntityRef::task(&schedule.task_id), &EntityRef::event(event_id))?;
+        Ok(())
+    }
+
+    fn row_to_schedule(row: &rusqlite::Row) -> rusqlite::Result<TaskSchedule> {
+        Ok(TaskSchedule {
+            event_id: row.get(0)?,
+            calendar_id: row.get(1)?,
+            task_id: row.get(2)?,
+            start: row.get(3)?,
+            end: row.get(4)?,
+            created_at: row.get(5)?,
+            completed_at: row.get(6)?,
+        })
+    }
+
+    /// Link two items; returns false if they were already linked. Links have
+    /// no direction: each end shows up in the other's `list_links`.
+    pub fn link(&self, a: &EntityRef, b: &EntityRef) -> Result<bool> {
+        if a == b {
+            anyhow::bail!("Cannot link an item to itself");
+        }
+        let (source, target) = if a < b { (a, b) } else { (b, a) };
+        let conn = self.writer();
+        let inserted = conn.execute(
+            "INSERT OR IGNORE INTO links (source_type, source_id, target_type, target_id, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                source.kind.as_str(),
+                source.id,
+                target.kind.as_str(),
+                target.id,
+                chrono::Utc::now().to_rfc3339(),
+            ],
+        )?;
+        Ok(inserted > 0)
+    }
+
+    /// Remove the link between two items; returns false if there was none
+    pub fn unlink(&self, a: &EntityRef, b: &EntityRef) -> Result<bool> {
+        let (source, target) = if a < b { (a, b) } else { (b, a) };
+        let conn = self.writer();
+        let removed = conn.execute(
+            "DELETE FROM links
+             WHERE source_type = ?1 AND source_id = ?2 AND target_type = ?3 AND target_id = ?4",
+            params![source.kind.as_str(), source.id, target.kind.as_str(), target.id],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// Items linked to `entity` in either direction (so a task lists the
+    /// notes attached to it), most recently linked first
+    pub fn list_links(&self, entity: &EntityRef) -> Result<Vec<EntityRef>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT target_type, target_id, created_at FROM links
+             WHERE source_type = ?1 AND source_id = ?2
+             UNION ALL
+             SELECT source_type, source_id, created_at FROM links
+             WHERE target_type = ?1 AND target_id = ?2
+             ORDER BY created_at DESC",
+        )?;
+
+        let rows = stmt
+            .query_map(params![entity.kind.as_str(), entity.id], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Skip kinds written by a newer version
+        Ok(rows
+            .into_iter()
+            .filter_map(|(kind, id)| EntityKind::parse(&kind).map(|kind| EntityRef::new(kind, id)))
+            .collect())
+    }
+
+    /// Remove every link to or from `entity`, e.g. after deleting it
+    pub fn remove_links(&self, entity: &EntityRef) -> Result<()> {
+        Self::delete_links(&self.writer(), entity)
+    }
+
+    fn delete_links(conn: &Connection, entity: &EntityRef) -> Result<()> {
+        conn.execute(
+            "DELETE FROM links
+             WHERE (source_type = ?1 AND source_id = ?2) OR (target_type = ?1 AND target_id = ?2)",
+            params![entity.kind.as_str(), entity.id],
+        )?;
+        Ok(())
+    }
+
+    /// Insert or update a template. `last_run` is kept as stored; use
+    /// `mark_template_run` to change it.
+    pub fn upsert_template(&self, template: &Template) -> Result<()> {
+        let conn = self.writer();
+        conn.execute(
+            "INSERT INTO templates
+             (id, name, kind, title, body, project_id, repo_id, schedule, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+             ON CONFLICT(id) DO UPDATE SET
+               name = excluded.name, kind = excluded.kind, title = excluded.title,
+               body = excluded.body, project_id = excluded.project_id,
+               repo_id = excluded.repo_id, schedule = excluded.schedule",
+            params![
+                template.id,
+                template.name,
+                template.kind.as_str(),
+                template.title,
+                template.body,
+                template.project_id,
+                template.repo_id,
+                template.schedule.map(|s| s.as_string()),
+                template.created_at,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Templates by name; limited to one kind if `kind` is set
+    pub fn list_templates(&self, kind: Option<TemplateKind>) -> Result<Vec<Template>> {
+        let conn = self.reader();
+        let mut stmt = conn.prepare(
+            "SELECT id, name, kind, title, body, project_id, repo_id, schedule, last_run, created_at
+             FROM templates
+             WHERE ?1 IS NULL OR kind = ?1
+             ORDER BY name COLLATE NOCASE",
+        )?;
+
+        let rows = stmt.query_map([kind.map(|k| k.as_str())], Self::row_to_template)?;
+        // Skip kinds written by a newer version
+        Ok(rows.filter_map(|row| row.transpose()).collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Get a template by id
+    pub fn get_template(&self, id: &str) -> Result<Option<Template>> {
+        let conn = self.reader();
+        let template = conn
+            .query_row(
+                "SELECT id, name, kind, title, body, project_id, repo_id, schedule, last_run,
+                        created_at
+                 FROM templates WHERE id = ?1",
+                [id],
+                Self::row_to_template,
+            )
+            .optional()?;
+        Ok(template.flatten())
+    }
+
+    fn row_to_template(row: &rusqlite::Row) -> rusqlite::Result<Option<Template>> {
+        let kind: String = row.get(2)?;
+        let Some(kind) = TemplateKind::parse(&kind) else {
+            return Ok(None);
+        };
+        let schedule: Option<String> = row.get(7)?;
+        let last_run: Option<String> = row.get(8)?;
+        Ok(Some(Template {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            kind,
+            title: row.get(3)?,
+            body: row.get(4)?,
+            project_id: row.get(5)?,
+            repo_id: row.get(6)?,
+            schedule: schedule.as_deref().and_then(TemplateSchedule::parse),
+            last_run: last_run.and_then(|d| d.parse().ok()),
+            created_at: row.get(9)?,
+        }))
+    }
+
+    /// Delete a template by id
+    pub fn delete_template(&self, id: &str) -> Result<()> {
+        let conn = self.writer();
+        conn.execute("DELETE FROM templates WHERE id = ?1", [id])?;
+        Ok(())
+    }
+
+    /// Scheduled templates with a run due on `today`, except those creating
+    /// tasks in archived projects
+    pub fn due_templates(&self, today: chrono::NaiveDate) -> Result<Vec<Template>> {
+        let archived: Vec<String> = self.list_archived()?.into_iter().map(|p| p.id).collect();
+        Ok(self
+            .list_templates(None)?
+            .into_iter()
+            .filter(|t| t.schedule.is_some_and(|s| s.is_due(t.last_run, today)))
+            .filter(|t| t.project_id.as_ref().is_none_or(|id| !archived.contains(id)))
+            .collect())
+    }
+
+    /// Record that the schedule created an instance on `date`
+    pub fn mark_template_run(&self, id: &str, date: chrono::NaiveDate) -> Result<()> {
+        let conn = self.writer();
+        conn.execute(
+            "UPDATE templates SET last_run = ?2 WHERE id = ?1",
+            params![id, date.format("%Y-%m-%d").to_string()],
+        )?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -496,6 +1754,35 @@ mod tests {
         assert_eq!(projects[0].name, "My Project");
     }
 
+    #[test]
+    fn test_reads_do_not_wait_for_writer() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        store
+            .upsert_project(&Project {
+                id: "p1".to_string(),
+                name: "Committed".to_string(),
+                description: None,
+                created_at: "2026-01-21T00:00:00Z".to_string(),
+            })
+            .unwrap();
+
+        // Simulate a long sync: writer holds an open transaction
+        let writer = store.writer();
+        writer.execute_batch("BEGIN IMMEDIATE;").unwrap();
+        writer
+            .execute("INSERT INTO projects (id, name, created_at) VALUES ('p2', 'Pending', '')", [])
+            .unwrap();
+
+        let projects = store.list_projects().unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "Committed");
+
+        writer.execute_batch("COMMIT;").unwrap();
+        drop(writer);
+        assert_eq!(store.list_projects().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_project_repos_many_to_many() {
         let dir = tempdir().unwrap();
@@ -544,6 +1831,69 @@ mod tests {
         assert!(all.is_empty());
     }
 
+    #[test]
+    fn test_archive_and_restore_project() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        for id in ["proj-1", "proj-2"] {
+            store
+                .upsert_project(&Project {
+                    id: id.to_string(),
+                    name: id.to_string(),
+                    description: None,
+                    created_at: "2026-01-21T00:00:00Z".to_string(),
+                })
+                .unwrap();
+        }
+        store.add_repo_to_project("proj-1", "owner/repo-a").unwrap();
+        store.add_repo_to_project("proj-2", "owner/repo-b").unwrap();
+        store
+            .upsert_template(&Template {
+                id: "weekly".to_string(),
+                name: "Weekly".to_string(),
+                kind: TemplateKind::Issue,
+                title: "Review".to_string(),
+                body: String::new(),
+                project_id: Some("proj-2".to_string()),
+                repo_id: None,
+                schedule: TemplateSchedule::parse("daily"),
+                last_run: None,
+                created_at: "2026-03-01T00:00:00Z".to_string(),
+            })
+            .unwrap();
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 3, 9).unwrap();
+        assert_eq!(store.due_templates(today).unwrap().len(), 1);
+
+        assert!(store.archive_project("proj-2").unwrap());
+        assert!(!store.archive_project("proj-2").unwrap());
+        let active = store.list_projects().unwrap();
+        assert_eq!(active.iter().map(|p| p.id.as_str()).collect::<Vec<_>>(), ["proj-1"]);
+        let archived = store.list_archived().unwrap();
+        assert_eq!(archived.iter().map(|p| p.id.as_str()).collect::<Vec<_>>(), ["proj-2"]);
+        // History stays, but background work skips it
+        assert!(store.get_project("proj-2").unwrap().is_some());
+        assert_eq!(store.list_repos_for_project("proj-2").unwrap(), ["owner/repo-b"]);
+        assert_eq!(store.list_all_linked_repo_ids().unwrap(), ["owner/repo-a"]);
+        assert!(store.due_templates(today).unwrap().is_empty());
+
+        // Editing an archived project doesn't restore it
+        store
+            .upsert_project(&Project {
+                id: "proj-2".to_string(),
+                name: "Renamed".to_string(),
+                description: None,
+                created_at: "2026-01-21T00:00:00Z".to_string(),
+            })
+            .unwrap();
+        assert_eq!(store.list_archived().unwrap().len(), 1);
+
+        assert!(store.restore_project("proj-2").unwrap());
+        assert!(!store.restore_project("proj-2").unwrap());
+        assert_eq!(store.list_projects().unwrap().len(), 2);
+        assert!(store.list_archived().unwrap().is_empty());
+        assert_eq!(store.list_all_linked_repo_ids().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_create_and_list_tasks() {
         let dir = tempdir().unwrap();
@@ -564,6 +1914,8 @@ mod tests {
             title: "Test task".to_string(),
             body: Some("Description".to_string()),
             status: TaskStatus::InProgress,
+            column_id: None,
+            order_index: 0,
             created_at: "2026-01-21T00:00:00Z".to_string(),
             updated_at: "2026-01-21T00:00:00Z".to_string(),
         };
@@ -573,5 +1925,548 @@ mod tests {
         assert_eq!(tasks.len(), 1);
         assert_eq!(tasks[0].status, TaskStatus::InProgress);
         assert_eq!(tasks[0].project_id, "proj-1");
+
+        assert_eq!(store.get_task("task-1").unwrap().unwrap().title, "Test task");
+        assert!(store.get_task("missing").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_status_history() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        store
+            .upsert_project(&Project {
+                id: "proj-1".to_string(),
+                name: "Test Project".to_string(),
+                description: None,
+                created_at: "2026-01-21T00:00:00Z".to_string(),
+            })
+            .unwrap();
+        let mut task = Task {
+            id: "task-1".to_string(),
+            project_id: "proj-1".to_string(),
+            title: "Test task".to_string(),
+            body: None,
+            status: TaskStatus::Todo,
+            column_id: None,
+            order_index: 0,
+            created_at: "2026-03-01T09:00:00Z".to_string(),
+            updated_at: "2026-03-01T09:00:00Z".to_string(),
+        };
+        store.upsert_task(&task).unwrap();
+        // Edits that keep the status aren't transitions
+        task.title = "Renamed".to_string();
+        task.updated_at = "2026-03-01T10:00:00Z".to_string();
+        store.upsert_task(&task).unwrap();
+        task.status = TaskStatus::Done;
+        task.updated_at = "2026-03-02T09:00:00Z".to_string();
+        store.upsert_task(&task).unwrap();
+
+        let history = store.list_status_history("proj-1").unwrap();
+        assert_eq!(
+            history,
+            [
+                StatusChange {
+                    task_id: "task-1".to_string(),
+                    from: None,
+                    to: TaskStatus::Todo,
+                    changed_at: "2026-03-01T09:00:00Z".to_string(),
+                },
+                StatusChange {
+                    task_id: "task-1".to_string(),
+                    from: Some(TaskStatus::Todo),
+                    to: TaskStatus::Done,
+                    changed_at: "2026-03-02T09:00:00Z".to_string(),
+                },
+            ]
+        );
+
+        store.delete_task("task-1").unwrap();
+        assert!(store.list_status_history("proj-1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_subtasks_follow_the_body() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        store
+            .upsert_project(&Project {
+                id: "proj-1".to_string(),
+                name: "Test Project".to_string(),
+                description: None,
+                created_at: "2026-01-21T00:00:00Z".to_string(),
+            })
+            .unwrap();
+        let mut task = Task {
+            id: "task-1".to_string(),
+            project_id: "proj-1".to_string(),
+            title: "Ship it".to_string(),
+            body: Some("Steps:\n- [x] Build\n- [ ] Test\n- [ ] Deploy".to_string()),
+            status: TaskStatus::Todo,
+            column_id: None,
+            order_index: 0,
+            created_at: "2026-03-01T09:00:00Z".to_string(),
+            updated_at: "2026-03-01T09:00:00Z".to_string(),
+        };
+        store.upsert_task(&task).unwrap();
+        let subtasks = store.list_subtasks("task-1").unwrap();
+        assert_eq!(subtasks.len(), 3);
+        assert_eq!(subtasks[1], ChecklistItem { text: "Test".to_string(), checked: false });
+        assert_eq!(store.subtask_progress("proj-1").unwrap()["task-1"], (1, 3));
+
+        task.body = checklist::set_checked(task.body.as_deref().unwrap(), 1, true);
+        store.upsert_task(&task).unwrap();
+        assert_eq!(store.subtask_progress("proj-1").unwrap()["task-1"], (2, 3));
+
+        task.body = None;
+        store.upsert_task(&task).unwrap();
+        assert!(store.list_subtasks("task-1").unwrap().is_empty());
+        assert!(store.subtask_progress("proj-1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_tasks() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        store
+            .upsert_project(&Project {
+                id: "p1".to_string(),
+                name: "Search".to_string(),
+                description: None,
+                created_at: "2026-01-21T00:00:00Z".to_string(),
+            })
+            .unwrap();
+        for (id, title, body, updated) in [
+            ("t1", "Fix login bug", Some("OAuth redirect fails"), "2026-01-21T00:00:00Z"),
+            ("t2", "Write docs", Some("Explain the login flow"), "2026-01-22T00:00:00Z"),
+            ("t3", "Release", None, "2026-01-23T00:00:00Z"),
+        ] {
+            store
+                .upsert_task(&Task {
+                    id: id.to_string(),
+                    project_id: "p1".to_string(),
+                    title: title.to_string(),
+                    body: body.map(str::to_string),
+                    status: TaskStatus::Todo,
+                    column_id: None,
+                    order_index: 0,
+                    created_at: updated.to_string(),
+                    updated_at: updated.to_string(),
+                })
+                .unwrap();
+        }
+
+        let ids: Vec<String> =
+            store.search_tasks("LOGIN", 10).unwrap().into_iter().map(|t| t.id).collect();
+        assert_eq!(ids, ["t2", "t1"]);
+        assert_eq!(store.search_tasks("login oauth", 10).unwrap().len(), 1);
+        assert_eq!(store.search_tasks("login", 1).unwrap().len(), 1);
+        assert!(store.search_tasks(" ", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_custom_columns() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        store
+            .upsert_project(&Project {
+                id: "p1".to_string(),
+                name: "Board".to_string(),
+                description: None,
+                created_at: "2026-01-21T00:00:00Z".to_string(),
+            })
+            .unwrap();
+
+        // Untouched boards show the defaults without storing them
+        let columns = store.list_columns("p1").unwrap();
+        assert_eq!(columns.len(), 6);
+        assert_eq!(columns[0].id, "backlog");
+
+        let qa = store.add_column("p1", "QA / Verify", Some("qa"), TaskStatus::Review).unwrap();
+        assert_eq!(qa.id, "qa-verify");
+        assert_eq!(qa.position, 6);
+        let dup = store.add_column("p1", "QA / Verify", None, TaskStatus::Review).unwrap();
+        assert_eq!(dup.id, format!("{}-2", qa.id));
+        assert!(store.add_column("p1", "  ", None, TaskStatus::Todo).is_err());
+
+        store.rename_column("p1", "todo", "Next up").unwrap();
+        store.set_column_label("p1", "todo", Some("next")).unwrap();
+        assert!(store.rename_column("p1", "missing", "X").is_err());
+
+        store.reorder_columns("p1", &[qa.id.clone(), "done".to_string()]).unwrap();
+        let columns = store.list_columns("p1").unwrap();
+        let ids: Vec<&str> = columns.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids[0], qa.id);
+        assert_eq!(ids[1], "done");
+        assert_eq!(ids[2], "backlog");
+        assert_eq!(columns.len(), 8);
+        let todo = columns.iter().find(|c| c.id == "todo").unwrap();
+        assert_eq!(todo.name, "Next up");
+        assert_eq!(todo.label.as_deref(), Some("next"));
+
+        // Tasks keep their column
+        store
+            .upsert_task(&Task {
+                id: "t1".to_string(),
+                project_id: "p1".to_string(),
+                title: "Check".to_string(),
+                body: None,
+                status: TaskStatus::Review,
+                column_id: Some(qa.id.clone()),
+                order_index: 0,
+                created_at: "2026-01-21T00:00:00Z".to_string(),
+                updated_at: "2026-01-21T00:00:00Z".to_string(),
+            })
+            .unwrap();
+        let tasks = store.list_tasks_for_project("p1").unwrap();
+        assert_eq!(tasks[0].column_id.as_deref(), Some(qa.id.as_str()));
+
+        store.delete_project("p1").unwrap();
+        assert_eq!(store.list_columns("p1").unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_column_wip_limits() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        store
+            .upsert_project(&Project {
+                id: "p1".to_string(),
+                name: "Board".to_string(),
+                description: None,
+                created_at: "2026-01-21T00:00:00Z".to_string(),
+            })
+            .unwrap();
+        assert!(store.list_columns("p1").unwrap().iter().all(|c| c.wip_limit.is_none()));
+
+        store.set_column_wip_limit("p1", "inprogress", Some(2)).unwrap();
+        let columns = store.list_columns("p1").unwrap();
+        assert_eq!(columns.len(), 6);
+        let doing = columns.iter().find(|c| c.id == "inprogress").unwrap();
+        assert_eq!(doing.wip_limit, Some(2));
+        assert!(!doing.is_over_wip(2));
+        assert!(doing.is_over_wip(3));
+        assert!(columns.iter().filter(|c| c.id != "inprogress").all(|c| !c.is_over_wip(99)));
+
+        // Zero clears the limit
+        store.set_column_wip_limit("p1", "inprogress", Some(0)).unwrap();
+        let columns = store.list_columns("p1").unwrap();
+        assert_eq!(columns.iter().find(|c| c.id == "inprogress").unwrap().wip_limit, None);
+        assert!(store.set_column_wip_limit("p1", "missing", Some(1)).is_err());
+    }
+
+    #[test]
+    fn test_task_order_survives_upserts() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        store
+            .upsert_project(&Project {
+                id: "p1".to_string(),
+                name: "Board".to_string(),
+                description: None,
+                created_at: "2026-01-21T00:00:00Z".to_string(),
+            })
+            .unwrap();
+        let task = |id: &str| Task {
+            id: id.to_string(),
+            project_id: "p1".to_string(),
+            title: id.to_string(),
+            body: None,
+            status: TaskStatus::Todo,
+            column_id: None,
+            order_index: 0,
+            created_at: "2026-01-21T00:00:00Z".to_string(),
+            updated_at: "2026-01-21T00:00:00Z".to_string(),
+        };
+        for id in ["a", "b", "c"] {
+            store.upsert_task(&task(id)).unwrap();
+        }
+        let ids = |store: &ProjectStore| -> Vec<String> {
+            store.list_tasks_for_project("p1").unwrap().into_iter().map(|t| t.id).collect()
+        };
+        assert_eq!(ids(&store), vec!["a", "b", "c"]);
+
+        store.set_task_order("p1", &["c".to_string(), "a".to_string()]).unwrap();
+        assert_eq!(ids(&store), vec!["c", "a", "b"]);
+
+        // A sync re-upserting every task doesn't reset the order; new tasks append
+        for id in ["a", "b", "c", "d"] {
+            store.upsert_task(&task(id)).unwrap();
+        }
+        assert_eq!(ids(&store), vec!["c", "a", "b", "d"]);
+        let tasks = store.list_tasks_for_project("p1").unwrap();
+        assert_eq!(tasks.iter().map(|t| t.order_index).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_repo_meta_tags_and_favorites() {
+        let dir = tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        let store = ProjectStore::open(&db_path).unwrap();
+
+        store.set_repo_favorite("owner/repo-a", true).unwrap();
+        store.set_repo_group("owner/repo-a", Some("work")).unwrap();
+        store.add_repo_tag("owner/repo-a", "rust").unwrap();
+        store.add_repo_tag("owner/repo-a", "rust").unwrap(); // duplicate ignored
+        store.add_repo_tag("owner/repo-b", "php").unwrap();
+        store.set_repo_sort_order("owner/repo-b", -1).unwrap();
+
+        let metas = store.list_repo_meta().unwrap();
+        assert_eq!(metas.len(), 2);
+        assert_eq!(metas[0].repo_id, "owner/repo-b");
+        assert_eq!(metas[1].tags, vec!["rust".to_string()]);
+        assert!(metas[1].favorite);
+        assert_eq!(metas[1].group_name.as_deref(), Some("work"));
+
+        store.remove_repo_tag("owner/repo-b", "php").unwrap();
+        assert_eq!(store.list_repo_tags().unwrap(), vec!["rust".to_string()]);
+    }
+
+    #[test]
+    fn test_repo_task_runs_newest_first() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+
+        let run = |id: &str, task: &str, started_at: &str, status| RepoTaskRun {
+            id: id.into(),
+            repo_id: "owner/repo".into(),
+            task: task.into(),
+            started_at: started_at.into(),
+            duration_ms: 1200,
+            exit_code: (status == RepoTaskStatus::Passed).then_some(0),
+            status,
+            output_tail: "test result: ok".into(),
+        };
+        store
+            .record_repo_task_run(&run(
+                "a",
+                "cargo test",
+                "2026-03-01T10:00:00Z",
+                RepoTaskStatus::Failed,
+            ))
+            .unwrap();
+        store
+            .record_repo_task_run(&run(
+                "b",
+                "cargo test",
+                "2026-03-01T11:00:00Z",
+                RepoTaskStatus::Passed,
+            ))
+            .unwrap();
+        store
+            .record_repo_task_run(&run(
+                "c",
+                "npm test",
+                "2026-03-01T09:00:00Z",
+                RepoTaskStatus::TimedOut,
+            ))
+            .unwrap();
+
+        let runs = store.list_repo_task_runs("owner/repo", 10).unwrap();
+        let ids: Vec<&str> = runs.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["b", "a", "c"]);
+        assert_eq!(runs[0].exit_code, Some(0));
+        assert_eq!(runs[2].status, RepoTaskStatus::TimedOut);
+        assert!(store.list_repo_task_runs("other/repo", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_repo_security_replaces_counts() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+
+        let mut security = RepoSecurity {
+            repo_id: "owner/repo".into(),
+            high: 2,
+            dependabot_enabled: true,
+            checked_at: "2026-03-01T00:00:00Z".into(),
+            ..Default::default()
+        };
+        store.set_repo_security(&security).unwrap();
+        security.high = 0;
+        security.low = 1;
+        store.set_repo_security(&security).unwrap();
+
+        assert_eq!(store.list_repo_security().unwrap(), vec![security]);
+    }
+
+    #[test]
+    fn test_focus_sessions_history() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+
+        let session = |id: &str, task: Option<&str>, kind: FocusKind, started: &str, secs: i64| {
+            FocusSession {
+                id: id.to_string(),
+                task_id: task.map(String::from),
+                kind,
+                started_at: started.to_string(),
+                ended_at: started.to_string(),
+                planned_secs: 1500,
+                focused_secs: secs,
+                completed: secs == 1500,
+            }
+        };
+        store
+            .insert_focus_session(&session(
+                "a",
+                Some("t1"),
+                FocusKind::Work,
+                "2026-01-21T09:00:00Z",
+                1500,
+            ))
+            .unwrap();
+        store
+            .insert_focus_session(&session(
+                "b",
+                Some("t1"),
+                FocusKind::ShortBreak,
+                "2026-01-21T09:25:00Z",
+                300,
+            ))
+            .unwrap();
+        store
+            .insert_focus_session(&session(
+                "c",
+                Some("t1"),
+                FocusKind::Work,
+                "2026-01-21T09:30:00Z",
+                600,
+            ))
+            .unwrap();
+        store
+            .insert_focus_session(&session(
+                "d",
+                None,
+                FocusKind::Work,
+                "2026-01-21T10:00:00Z",
+                1500,
+            ))
+            .unwrap();
+
+        let all = store.list_focus_sessions(None, 10).unwrap();
+        assert_eq!(all.len(), 4);
+        assert_eq!(all[0].id, "d");
+
+        let for_task = store.list_focus_sessions(Some("t1"), 2).unwrap();
+        assert_eq!(for_task.iter().map(|s| s.id.as_str()).collect::<Vec<_>>(), ["c", "b"]);
+        assert!(!for_task[0].completed);
+
+        assert_eq!(store.focus_seconds_for_task("t1").unwrap(), 2100);
+    }
+
+    #[test]
+    fn test_links_read_both_ways() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        let note = EntityRef::note(7);
+        let task = EntityRef::task("t1");
+        let event = EntityRef::event("evt123");
+        let issue = EntityRef::issue("octo/repo", 42);
+        assert_eq!(issue.id, "octo/repo#42");
+
+        assert!(store.link(&note, &task).unwrap());
+        assert!(store.link(&event, &note).unwrap());
+        assert!(store.link(&note, &issue).unwrap());
+        // Either order is the same link
+        assert!(!store.link(&task, &note).unwrap());
+        assert!(store.link(&note, &note).is_err());
+
+        let mut linked = store.list_links(&note).unwrap();
+        linked.sort();
+        assert_eq!(linked, vec![task.clone(), event.clone(), issue.clone()]);
+        // Backlinks
+        assert_eq!(store.list_links(&task).unwrap(), vec![note.clone()]);
+        assert_eq!(store.list_links(&event).unwrap(), vec![note.clone()]);
+
+        assert!(store.unlink(&issue, &note).unwrap());
+        assert!(!store.unlink(&issue, &note).unwrap());
+        assert!(store.list_links(&issue).unwrap().is_empty());
+
+        // Deleting a task drops its links
+        store.delete_task("t1").unwrap();
+        assert_eq!(store.list_links(&note).unwrap(), vec![event.clone()]);
+        store.remove_links(&event).unwrap();
+        assert!(store.list_links(&note).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_task_schedules() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        let schedule = |event_id: &str, start: &str| TaskSchedule {
+            event_id: event_id.to_string(),
+            calendar_id: "primary".to_string(),
+            task_id: "t1".to_string(),
+            start: start.to_string(),
+            end: start.replace("T09", "T10"),
+            created_at: "2026-03-01T08:00:00Z".to_string(),
+            completed_at: None,
+        };
+        store.add_task_schedule(&schedule("evt2", "2026-03-03T09:00:00Z")).unwrap();
+        store.add_task_schedule(&schedule("evt1", "2026-03-02T09:00:00Z")).unwrap();
+
+        let schedules = store.list_task_schedules("t1").unwrap();
+        assert_eq!(
+            schedules.iter().map(|s| s.event_id.as_str()).collect::<Vec<_>>(),
+            ["evt1", "evt2"]
+        );
+        assert_eq!(
+            store.list_links(&EntityRef::event("evt1")).unwrap(),
+            vec![EntityRef::task("t1")]
+        );
+
+        store.set_task_schedule_completed("evt1", Some("2026-03-02T10:00:00Z")).unwrap();
+        let done = store.task_schedule_for_event("evt1").unwrap().unwrap();
+        assert_eq!(done.completed_at.as_deref(), Some("2026-03-02T10:00:00Z"));
+        assert!(store.task_schedule_for_event("other").unwrap().is_none());
+
+        store.remove_task_schedule("evt1").unwrap();
+        assert!(store.list_links(&EntityRef::event("evt1")).unwrap().is_empty());
+        store.delete_task("t1").unwrap();
+        assert!(store.list_task_schedules("t1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_templates_and_schedule() {
+        let dir = tempdir().unwrap();
+        let store = ProjectStore::open(&dir.path().join("test.db")).unwrap();
+        let date = |s: &str| chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let template = |id: &str, kind: TemplateKind, schedule: Option<&str>| Template {
+            id: id.to_string(),
+            name: format!("Template {}", id),
+            kind,
+            title: "Review {{date}}".to_string(),
+            body: String::new(),
+            project_id: None,
+            repo_id: None,
+            schedule: schedule.and_then(TemplateSchedule::parse),
+            last_run: None,
+            created_at: "2026-03-01T00:00:00Z".to_string(),
+        };
+
+        store.upsert_template(&template("a", TemplateKind::Note, Some("weekly:mon"))).unwrap();
+        store.upsert_template(&template("b", TemplateKind::Issue, None)).unwrap();
+        assert_eq!(store.list_templates(None).unwrap().len(), 2);
+        let issues = store.list_templates(Some(TemplateKind::Issue)).unwrap();
+        assert_eq!(issues.iter().map(|t| t.id.as_str()).collect::<Vec<_>>(), ["b"]);
+
+        // 2026-03-09 is a Monday
+        let due = store.due_templates(date("2026-03-09")).unwrap();
+        assert_eq!(due.iter().map(|t| t.id.as_str()).collect::<Vec<_>>(), ["a"]);
+        store.mark_template_run("a", date("2026-03-09")).unwrap();
+        assert!(store.due_templates(date("2026-03-09")).unwrap().is_empty());
+
+        // Editing keeps the last run
+        let mut edited = template("a", TemplateKind::Note, Some("weekly:mon"));
+        edited.body = "- Wins".to_string();
+        store.upsert_template(&edited).unwrap();
+        let stored = store.get_template("a").unwrap().unwrap();
+        assert_eq!(stored.body, "- Wins");
+        assert_eq!(stored.last_run, Some(date("2026-03-09")));
+        assert_eq!(stored.schedule, TemplateSchedule::parse("weekly:mon"));
+
+        store.delete_template("a").unwrap();
+        assert!(store.get_template("a").unwrap().is_none());
     }
 }
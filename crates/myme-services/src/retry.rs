@@ -12,6 +12,7 @@
 use std::future::Future;
 use std::time::Duration;
 
+use myme_core::http_log::{execute_observed, HttpObserver};
 use reqwest::{Response, StatusCode};
 
 /// Default retry configuration
@@ -19,6 +20,9 @@ pub const DEFAULT_MAX_RETRIES: u32 = 3;
 pub const DEFAULT_INITIAL_DELAY_MS: u64 = 100;
 pub const DEFAULT_MAX_DELAY_MS: u64 = 5000;
 
+/// Default per-request timeout for clients built without one
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
 /// Retry configuration
 #[derive(Debug, Clone)]
 pub struct RetryConfig {
@@ -50,6 +54,11 @@ impl RetryConfig {
         }
     }
 
+    /// Never retry
+    pub fn none() -> Self {
+        Self { max_retries: 0, ..Self::default() }
+    }
+
     /// Calculate the delay for a given attempt number
     pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
         // Exponential backoff: initial_delay * 2^attempt
@@ -221,6 +230,51 @@ where
     Err(last_error.expect("at least one error should have occurred"))
 }
 
+/// Send `request` on `client`, reporting every attempt to `observer` if one is
+/// set. Only idempotent requests are retried, so a dropped connection can't
+/// make a POST happen twice; a request whose body can't be cloned (a stream)
+/// is sent once.
+pub async fn send_observed(
+    client: &reqwest::Client,
+    request: reqwest::Request,
+    config: &RetryConfig,
+    observer: Option<&HttpObserver>,
+) -> Result<Response, reqwest::Error> {
+    let idempotent = request.method().is_idempotent();
+    let mut attempt = 0;
+    loop {
+        // Earlier attempts send a copy; the last one sends the request itself
+        let copy =
+            if idempotent && attempt < config.max_retries { request.try_clone() } else { None };
+        let Some(copy) = copy else {
+            return execute_observed(client, request, observer).await;
+        };
+
+        let result = execute_observed(client, copy, observer).await;
+        match &result {
+            Ok(response) if is_retryable_status(response.status()) == RetryDecision::Retry => {
+                tracing::warn!(
+                    "Request returned retryable status {}, attempt {} of {}",
+                    response.status(),
+                    attempt + 1,
+                    config.max_retries + 1
+                );
+            }
+            Err(e) if is_retryable_error(e) == RetryDecision::Retry => {
+                tracing::warn!(
+                    "Retryable error on attempt {} of {}: {}",
+                    attempt + 1,
+                    config.max_retries + 1,
+                    e
+                );
+            }
+            _ => return result,
+        }
+        tokio::time::sleep(config.delay_for_attempt(attempt)).await;
+        attempt += 1;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -278,4 +332,43 @@ mod tests {
         assert_eq!(is_retryable_status(StatusCode::OK), RetryDecision::NoRetry);
         assert_eq!(is_retryable_status(StatusCode::CREATED), RetryDecision::NoRetry);
     }
+
+    #[tokio::test]
+    async fn test_send_observed_retries_only_idempotent_requests() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET")).respond_with(ResponseTemplate::new(200)).mount(&server).await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let seen = Arc::new(AtomicUsize::new(0));
+        let counter = seen.clone();
+        let observer: HttpObserver = Arc::new(move |_, _, _| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let client = reqwest::Client::new();
+        let config = RetryConfig::new(2, 1, 1);
+
+        let get = client.get(server.uri()).build().unwrap();
+        let response = send_observed(&client, get, &config, Some(&observer)).await.unwrap();
+        assert_eq!(response.status(), 200);
+        assert_eq!(seen.load(Ordering::SeqCst), 3);
+
+        let post = client.post(server.uri()).body("x").build().unwrap();
+        let response = send_observed(&client, post, &config, Some(&observer)).await.unwrap();
+        assert_eq!(response.status(), 503);
+        assert_eq!(seen.load(Ordering::SeqCst), 4);
+    }
 }
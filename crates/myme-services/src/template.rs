@@ -0,0 +1,264 @@
+//! Templates for recurring notes and GitHub issues.
+//!
+//! Templates are kept in the `ProjectStore`. Text may contain placeholders
+//! that are filled in when the template is instantiated:
+//! `{{date}}` (YYYY-MM-DD), `{{weekday}}`, `{{week}}` (ISO week number) and
+//! `{{project}}`. Unknown placeholders are left as written.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Days looked back for missed runs, e.g. when the app was closed on the
+/// scheduled day. At most one instance is created for all missed runs.
+const CATCH_UP_DAYS: i64 = 31;
+
+/// What a template instantiates as
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemplateKind {
+    Note,
+    Issue,
+}
+
+impl TemplateKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemplateKind::Note => "note",
+            TemplateKind::Issue => "issue",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "note" => Some(TemplateKind::Note),
+            "issue" => Some(TemplateKind::Issue),
+            _ => None,
+        }
+    }
+}
+
+/// When a template is instantiated automatically
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateSchedule {
+    Daily,
+    Weekly(Weekday),
+    /// Day of the month; days past the end of a short month run on its last day
+    Monthly(u32),
+}
+
+impl TemplateSchedule {
+    /// Parse `daily`, `weekly:mon` or `monthly:15`
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim().to_lowercase();
+        match s.split_once(':') {
+            None if s == "daily" => Some(TemplateSchedule::Daily),
+            Some(("weekly", day)) => day.parse::<Weekday>().ok().map(TemplateSchedule::Weekly),
+            Some(("monthly", day)) => {
+                day.parse().ok().filter(|d| (1..=31).contains(d)).map(TemplateSchedule::Monthly)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            TemplateSchedule::Daily => "daily".to_string(),
+            TemplateSchedule::Weekly(day) => format!("weekly:{}", day.to_string().to_lowercase()),
+            TemplateSchedule::Monthly(day) => format!("monthly:{}", day),
+        }
+    }
+
+    /// Whether the schedule has a run on `date`
+    pub fn occurs_on(&self, date: NaiveDate) -> bool {
+        match *self {
+            TemplateSchedule::Daily => true,
+            TemplateSchedule::Weekly(day) => date.weekday() == day,
+            TemplateSchedule::Monthly(day) => {
+                let last_day = (date + Duration::days(1)).day() == 1;
+                date.day() == day || (last_day && date.day() < day)
+            }
+        }
+    }
+
+    /// Whether a run is due on `today`: one occurred after `last_run` (within
+    /// the catch-up window) and no later than today. Templates that never ran
+    /// start at their first scheduled day.
+    pub fn is_due(&self, last_run: Option<NaiveDate>, today: NaiveDate) -> bool {
+        let from = match last_run {
+            Some(last) if last >= today => return false,
+            Some(last) => (last + Duration::days(1)).max(today - Duration::days(CATCH_UP_DAYS)),
+            None => today,
+        };
+        from.iter_days().take_while(|d| *d <= today).any(|d| self.occurs_on(d))
+    }
+}
+
+impl Serialize for TemplateSchedule {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TemplateSchedule {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        TemplateSchedule::parse(&s)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid schedule: {}", s)))
+    }
+}
+
+/// A reusable note or issue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Template {
+    pub id: String,
+    pub name: String,
+    pub kind: TemplateKind,
+    /// Issue title, or the first line of a note; may be empty for notes
+    pub title: String,
+    pub body: String,
+    /// Project whose name fills `{{project}}`
+    pub project_id: Option<String>,
+    /// Repo (`owner/repo`) that scheduled issues are opened in
+    pub repo_id: Option<String>,
+    pub schedule: Option<TemplateSchedule>,
+    /// Day the schedule last created an instance
+    pub last_run: Option<NaiveDate>,
+    pub created_at: String,
+}
+
+/// Values for placeholders
+#[derive(Debug, Clone)]
+pub struct TemplateContext {
+    pub date: NaiveDate,
+    pub project: Option<String>,
+}
+
+impl TemplateContext {
+    pub fn new(date: NaiveDate) -> Self {
+        Self { date, project: None }
+    }
+
+    pub fn with_project(mut self, project: Option<String>) -> Self {
+        self.project = project;
+        self
+    }
+
+    fn value(&self, key: &str) -> Option<String> {
+        match key {
+            "date" => Some(self.date.format("%Y-%m-%d").to_string()),
+            "weekday" => Some(self.date.format("%A").to_string()),
+            "week" => Some(self.date.iso_week().week().to_string()),
+            "project" => Some(self.project.clone().unwrap_or_default()),
+            _ => None,
+        }
+    }
+}
+
+/// Replace `{{name}}` placeholders (whitespace inside the braces is allowed)
+pub fn render(text: &str, context: &TemplateContext) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+        match context.value(after[..end].trim()) {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[start..start + end + 4]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+impl Template {
+    /// Rendered title and body
+    pub fn render(&self, context: &TemplateContext) -> (String, String) {
+        (render(&self.title, context), render(&self.body, context))
+    }
+
+    /// Rendered note content: the title as first line, then the body
+    pub fn render_note(&self, context: &TemplateContext) -> String {
+        let (title, body) = self.render(context);
+        match (title.trim().is_empty(), body.trim().is_empty()) {
+            (true, _) => body,
+            (false, true) => title,
+            (false, false) => format!("{}\n\n{}", title, body),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn render_fills_known_placeholders() {
+        let context = TemplateContext::new(date("2026-03-09")).with_project(Some("myme".into()));
+        assert_eq!(
+            render("Weekly review {{date}} ({{ weekday }}, week {{week}}) - {{project}}", &context),
+            "Weekly review 2026-03-09 (Monday, week 11) - myme"
+        );
+        // Unknown and unterminated placeholders are kept
+        assert_eq!(render("{{owner}} {{date", &context), "{{owner}} {{date");
+        assert_eq!(render("{{project}}", &TemplateContext::new(date("2026-03-09"))), "");
+    }
+
+    #[test]
+    fn parse_schedules() {
+        assert_eq!(TemplateSchedule::parse("daily"), Some(TemplateSchedule::Daily));
+        assert_eq!(
+            TemplateSchedule::parse("Weekly:Monday"),
+            Some(TemplateSchedule::Weekly(Weekday::Mon))
+        );
+        assert_eq!(TemplateSchedule::parse("monthly:31"), Some(TemplateSchedule::Monthly(31)));
+        assert_eq!(TemplateSchedule::parse("monthly:0"), None);
+        assert_eq!(TemplateSchedule::parse("hourly"), None);
+        assert_eq!(TemplateSchedule::Weekly(Weekday::Mon).as_string(), "weekly:mon");
+    }
+
+    #[test]
+    fn schedule_due_catches_up_once() {
+        let monday = TemplateSchedule::Weekly(Weekday::Mon);
+        // 2026-03-09 is a Monday
+        assert!(monday.is_due(None, date("2026-03-09")));
+        assert!(!monday.is_due(None, date("2026-03-10")));
+        assert!(!monday.is_due(Some(date("2026-03-09")), date("2026-03-09")));
+        // Missed Monday, app opened on Wednesday
+        assert!(monday.is_due(Some(date("2026-03-02")), date("2026-03-11")));
+        assert!(!monday.is_due(Some(date("2026-03-09")), date("2026-03-15")));
+
+        // The 31st runs on the last day of shorter months
+        let month_end = TemplateSchedule::Monthly(31);
+        assert!(month_end.occurs_on(date("2026-02-28")));
+        assert!(!month_end.occurs_on(date("2026-02-27")));
+        assert!(month_end.occurs_on(date("2026-03-31")));
+    }
+
+    #[test]
+    fn note_content_joins_title_and_body() {
+        let template = Template {
+            id: "t".into(),
+            name: "Review".into(),
+            kind: TemplateKind::Note,
+            title: "Review {{date}}".into(),
+            body: "- Wins\n- Misses".into(),
+            project_id: None,
+            repo_id: None,
+            schedule: None,
+            last_run: None,
+            created_at: String::new(),
+        };
+        let context = TemplateContext::new(date("2026-03-09"));
+        assert_eq!(template.render_note(&context), "Review 2026-03-09\n\n- Wins\n- Misses");
+    }
+}
@@ -19,6 +19,12 @@ pub struct Todo {
     pub labels: Vec<String>,
     pub is_checklist: bool,
     pub reminder: Option<chrono::DateTime<chrono::Utc>>,
+    /// When the note was moved to the trash; `None` for live notes.
+    #[serde(default)]
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Position in the manual order; higher sorts first. Local to this device.
+    #[serde(default)]
+    pub order_index: i64,
 }
 
 /// Request to create a new note.
@@ -69,6 +75,8 @@ mod tests {
             labels: vec![],
             is_checklist: false,
             reminder: None,
+            deleted_at: None,
+            order_index: 0,
         };
 
         let json = serde_json::to_string(&todo).unwrap();
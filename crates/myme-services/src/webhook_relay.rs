@@ -0,0 +1,327 @@
+//! GitHub webhook deliveries through a relay channel.
+//!
+//! A desktop app can't receive webhooks directly, so GitHub (a repo webhook or
+//! a GitHub App) delivers to a relay such as smee.io, and `RelayClient` reads
+//! the channel as a server-sent event stream. Each delivery is reduced to a
+//! [`GitHubEvent`]: which repo changed and how. Events are only hints to
+//! re-fetch that repo over the authenticated API; nothing from the payload is
+//! stored, so an unsigned or forged delivery can at worst cause an extra sync.
+
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use url::Url;
+
+/// A relay that sends nothing (not even keep-alives) for this long is dead
+const IDLE_TIMEOUT: Duration = Duration::from_secs(120);
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// A repository change reported by a webhook.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitHubEvent {
+    /// Commits pushed to `git_ref` (e.g. `refs/heads/main`)
+    Push { repo: String, git_ref: String },
+    /// A workflow run was requested, started or finished
+    WorkflowRun { repo: String, workflow: String, status: String, conclusion: Option<String> },
+    /// An issue was opened, edited, closed, labeled, ...
+    Issues { repo: String, action: String, number: i32 },
+}
+
+impl GitHubEvent {
+    /// Repository the event is about (`owner/repo`)
+    pub fn repo(&self) -> &str {
+        match self {
+            GitHubEvent::Push { repo, .. }
+            | GitHubEvent::WorkflowRun { repo, .. }
+            | GitHubEvent::Issues { repo, .. } => repo,
+        }
+    }
+
+    /// Read a delivery from its `X-GitHub-Event` name and JSON body. Events the
+    /// sync doesn't use (stars, pings, ...) give `None`.
+    pub fn from_delivery(event: &str, body: &Value) -> Option<Self> {
+        let repo = body.pointer("/repository/full_name")?.as_str()?.to_string();
+        let text = |pointer: &str| body.pointer(pointer).and_then(Value::as_str);
+        match event {
+            "push" => Some(GitHubEvent::Push { repo, git_ref: text("/ref")?.to_string() }),
+            "workflow_run" => Some(GitHubEvent::WorkflowRun {
+                repo,
+                workflow: text("/workflow_run/name").unwrap_or_default().to_string(),
+                status: text("/workflow_run/status").unwrap_or_default().to_string(),
+                conclusion: text("/workflow_run/conclusion").map(str::to_string),
+            }),
+            "issues" => Some(GitHubEvent::Issues {
+                repo,
+                action: text("/action").unwrap_or_default().to_string(),
+                number: i32::try_from(body.pointer("/issue/number")?.as_i64()?).ok()?,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Read a relayed delivery: the webhook's headers (lowercased) and its
+    /// `body`, as smee.io forwards them.
+    pub fn from_relay_message(data: &str) -> Option<Self> {
+        let message: Value = serde_json::from_str(data).ok()?;
+        let event = message.get("x-github-event")?.as_str()?;
+        Self::from_delivery(event, message.get("body")?)
+    }
+}
+
+/// What a relay connection reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelayEvent {
+    /// The channel is open; deliveries from now on will arrive
+    Connected,
+    Delivery(GitHubEvent),
+}
+
+/// One server-sent event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SseMessage {
+    /// `event:` field; empty for the default `message` type
+    pub event: String,
+    pub data: String,
+}
+
+/// Incremental `text/event-stream` parser. Chunks may split lines anywhere.
+#[derive(Debug, Default)]
+pub struct SseParser {
+    line: String,
+    event: String,
+    data: Vec<String>,
+}
+
+impl SseParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed the next chunk of the stream; returns the events it completed.
+    pub fn feed(&mut self, chunk: &str) -> Vec<SseMessage> {
+        let mut done = Vec::new();
+        for c in chunk.chars() {
+            if c != '\n' {
+                self.line.push(c);
+                continue;
+            }
+            let line = std::mem::take(&mut self.line);
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+            if line.is_empty() {
+                if !self.data.is_empty() {
+                    done.push(SseMessage {
+                        event: std::mem::take(&mut self.event),
+                        data: std::mem::take(&mut self.data).join("\n"),
+                    });
+                }
+                self.event.clear();
+                continue;
+            }
+            // Comments are keep-alives
+            if line.starts_with(':') {
+                continue;
+            }
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => self.event = value.to_string(),
+                "data" => self.data.push(value.to_string()),
+                _ => {}
+            }
+        }
+        done
+    }
+}
+
+/// Client for a smee-style webhook relay channel.
+pub struct RelayClient {
+    url: Url,
+    client: reqwest::Client,
+}
+
+impl RelayClient {
+    /// Client for the channel at `url`, connecting through `proxies`.
+    pub fn new(url: &str, proxies: Vec<reqwest::Proxy>) -> Result<Self> {
+        let url = Url::parse(url.trim()).context("Invalid webhook relay URL")?;
+        if !matches!(url.scheme(), "http" | "https") {
+            bail!("Webhook relay URL must be http(s): {}", url);
+        }
+        // No overall timeout: the stream stays open; `IDLE_TIMEOUT` catches a dead one
+        let builder = reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .user_agent(concat!("myme/", env!("CARGO_PKG_VERSION")));
+        let client = proxies
+            .into_iter()
+            .fold(builder, reqwest::ClientBuilder::proxy)
+            .build()
+            .context("Failed to create HTTP client")?;
+        Ok(Self { url, client })
+    }
+
+    /// Connect and report deliveries until the relay closes the stream
+    /// (`Ok`) or the connection fails or goes quiet (`Err`).
+    pub async fn listen(&self, mut on_event: impl FnMut(RelayEvent)) -> Result<()> {
+        let mut response = self
+            .client
+            .get(self.url.clone())
+            .header(reqwest::header::ACCEPT, "text/event-stream")
+            .send()
+            .await?
+            .error_for_status()?;
+        on_event(RelayEvent::Connected);
+
+        let mut parser = SseParser::new();
+        let mut pending = Vec::new();
+        loop {
+            let chunk = tokio::time::timeout(IDLE_TIMEOUT, response.chunk())
+                .await
+                .context("Webhook relay went quiet")??;
+            let Some(chunk) = chunk else {
+                return Ok(());
+            };
+            // Keep a multi-byte character split across chunks for the next one
+            pending.extend_from_slice(&chunk);
+            let complete = match std::str::from_utf8(&pending) {
+                Ok(text) => text.len(),
+                // Invalid bytes are replaced rather than held back forever
+                Err(e) => e.valid_up_to() + e.error_len().unwrap_or(0),
+            };
+            let text = String::from_utf8_lossy(&pending[..complete]).into_owned();
+            pending.drain(..complete);
+            for message in parser.feed(&text) {
+                // `ready` and `ping` events carry no delivery
+                if !message.event.is_empty() && message.event != "message" {
+                    continue;
+                }
+                match GitHubEvent::from_relay_message(&message.data) {
+                    Some(event) => on_event(RelayEvent::Delivery(event)),
+                    None => tracing::trace!("Ignoring relayed delivery"),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn relayed(event: &str, body: Value) -> String {
+        serde_json::json!({ "x-github-event": event, "body": body, "timestamp": 1 }).to_string()
+    }
+
+    #[test]
+    fn deliveries_become_events() {
+        let repo = serde_json::json!({ "full_name": "me/app" });
+        let push = serde_json::json!({ "ref": "refs/heads/main", "repository": repo });
+        assert_eq!(
+            GitHubEvent::from_delivery("push", &push),
+            Some(GitHubEvent::Push { repo: "me/app".into(), git_ref: "refs/heads/main".into() })
+        );
+
+        let run = serde_json::json!({
+            "action": "completed",
+            "workflow_run": { "name": "CI", "status": "completed", "conclusion": "failure" },
+            "repository": repo,
+        });
+        assert_eq!(
+            GitHubEvent::from_delivery("workflow_run", &run),
+            Some(GitHubEvent::WorkflowRun {
+                repo: "me/app".into(),
+                workflow: "CI".into(),
+                status: "completed".into(),
+                conclusion: Some("failure".into()),
+            })
+        );
+
+        let issue = serde_json::json!({
+            "action": "labeled",
+            "issue": { "number": 7 },
+            "repository": repo,
+        });
+        let event = GitHubEvent::from_delivery("issues", &issue).unwrap();
+        assert_eq!(event.repo(), "me/app");
+        assert!(matches!(event, GitHubEvent::Issues { number: 7, .. }));
+
+        assert_eq!(GitHubEvent::from_delivery("star", &push), None);
+        assert_eq!(GitHubEvent::from_delivery("push", &serde_json::json!({})), None);
+    }
+
+    #[test]
+    fn relay_messages_carry_the_event_header() {
+        let body =
+            serde_json::json!({ "ref": "refs/tags/v1", "repository": { "full_name": "o/r" } });
+        let event = GitHubEvent::from_relay_message(&relayed("push", body)).unwrap();
+        assert_eq!(event.repo(), "o/r");
+        assert_eq!(GitHubEvent::from_relay_message("{}"), None);
+        assert_eq!(GitHubEvent::from_relay_message("not json"), None);
+    }
+
+    #[test]
+    fn sse_parser_handles_split_chunks() {
+        let mut parser = SseParser::new();
+        assert!(parser.feed("event: ready\ndata: {}\n").is_empty());
+        let ready = parser.feed("\n: keep-alive\n\nda");
+        assert_eq!(ready, vec![SseMessage { event: "ready".into(), data: "{}".into() }]);
+
+        assert!(parser.feed("ta: line one\r\ndata:line two\r").is_empty());
+        let message = parser.feed("\n\r\n");
+        assert_eq!(
+            message,
+            vec![SseMessage { event: String::new(), data: "line one\nline two".into() }]
+        );
+
+        // An event name doesn't leak into the next message
+        assert!(parser.feed("event: ping\n\n").is_empty());
+        assert_eq!(parser.feed("data: x\n\n")[0].event, "");
+    }
+
+    #[test]
+    fn relay_url_must_be_http() {
+        assert!(RelayClient::new("https://smee.io/abc", Vec::new()).is_ok());
+        assert!(RelayClient::new("ftp://smee.io/abc", Vec::new()).is_err());
+        assert!(RelayClient::new("smee.io/abc", Vec::new()).is_err());
+    }
+
+    #[tokio::test]
+    async fn listen_reports_deliveries_until_the_stream_ends() {
+        let push = relayed(
+            "push",
+            serde_json::json!({ "ref": "refs/heads/main", "repository": { "full_name": "me/app" } }),
+        );
+        let star = relayed("star", serde_json::json!({ "repository": { "full_name": "me/app" } }));
+        let stream = format!(
+            "event: ready\ndata: {{}}\n\nevent: ping\ndata: {{}}\n\ndata: {}\n\ndata: {}\n\n",
+            star, push
+        );
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/channel"))
+            .and(header("accept", "text/event-stream"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(stream, "text/event-stream"))
+            .mount(&server)
+            .await;
+
+        let client = RelayClient::new(&format!("{}/channel", server.uri()), Vec::new()).unwrap();
+        let mut events = Vec::new();
+        client.listen(|e| events.push(e)).await.unwrap();
+        assert_eq!(
+            events,
+            vec![
+                RelayEvent::Connected,
+                RelayEvent::Delivery(GitHubEvent::Push {
+                    repo: "me/app".into(),
+                    git_ref: "refs/heads/main".into(),
+                }),
+            ]
+        );
+
+        let missing = RelayClient::new(&format!("{}/gone", server.uri()), Vec::new()).unwrap();
+        assert!(missing.listen(|_| {}).await.is_err());
+    }
+}
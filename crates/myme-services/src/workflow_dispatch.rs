@@ -0,0 +1,328 @@
+//! Inputs of manually triggered (`workflow_dispatch`) workflows.
+//!
+//! Workflow files are read with a small indentation-based reader rather than a
+//! YAML parser: only the `on.workflow_dispatch.inputs` mapping is needed, and
+//! its shape is fixed by GitHub (plain keys, scalars, an `options` list).
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Value type of a dispatch input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkflowInputKind {
+    String,
+    Boolean,
+    Number,
+    Choice,
+    Environment,
+}
+
+/// One input a workflow asks for when run by hand
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorkflowInput {
+    pub name: String,
+    pub description: String,
+    pub required: bool,
+    pub default: Option<String>,
+    pub kind: WorkflowInputKind,
+    /// Allowed values for `choice` inputs
+    pub options: Vec<String>,
+}
+
+impl WorkflowInput {
+    fn from_block(name: &str, props: &[Line<'_>]) -> Self {
+        let mut input = WorkflowInput {
+            name: unquote(name).to_string(),
+            description: String::new(),
+            required: false,
+            default: None,
+            kind: WorkflowInputKind::String,
+            options: Vec::new(),
+        };
+        for (key, value, children) in entries(props) {
+            match key {
+                "description" if is_block_scalar(value) => {
+                    input.description =
+                        children.iter().map(|l| l.text.trim()).collect::<Vec<_>>().join(" ");
+                }
+                "description" => input.description = unquote(value).to_string(),
+                "required" => input.required = value == "true",
+                "default" => input.default = Some(unquote(value).to_string()),
+                "type" => {
+                    input.kind = match value {
+                        "boolean" => WorkflowInputKind::Boolean,
+                        "number" => WorkflowInputKind::Number,
+                        "choice" => WorkflowInputKind::Choice,
+                        "environment" => WorkflowInputKind::Environment,
+                        _ => WorkflowInputKind::String,
+                    }
+                }
+                "options" => input.options = list_values(value, children),
+                _ => {}
+            }
+        }
+        input
+    }
+
+    /// Check `value` against the input's type; returns the value to send
+    fn check(&self, value: &str) -> Result<String, String> {
+        match self.kind {
+            WorkflowInputKind::Boolean if value != "true" && value != "false" => {
+                Err(format!("{} must be true or false", self.name))
+            }
+            WorkflowInputKind::Number if value.parse::<f64>().is_err() => {
+                Err(format!("{} must be a number", self.name))
+            }
+            WorkflowInputKind::Choice if !self.options.iter().any(|o| o == value) => {
+                Err(format!("{} must be one of: {}", self.name, self.options.join(", ")))
+            }
+            _ => Ok(value.to_string()),
+        }
+    }
+}
+
+/// Inputs declared under `on.workflow_dispatch` in a workflow file, in file
+/// order. `None` when the workflow can't be run by hand; an empty list when it
+/// can but takes no inputs.
+pub fn parse_dispatch_inputs(yaml: &str) -> Option<Vec<WorkflowInput>> {
+    let lines = lines(yaml);
+    // Workflows sometimes quote the key (`"on":`)
+    let (_, on_value, on_block) =
+        entries(&lines).into_iter().find(|(key, _, _)| unquote(key) == "on")?;
+    if !on_value.is_empty() {
+        return list_values(on_value, &[])
+            .iter()
+            .any(|event| event == "workflow_dispatch")
+            .then(Vec::new);
+    }
+    if list_values("", on_block).iter().any(|event| event == "workflow_dispatch") {
+        return Some(Vec::new());
+    }
+    let (_, _, dispatch) =
+        entries(on_block).into_iter().find(|(key, _, _)| *key == "workflow_dispatch")?;
+    let Some((_, _, inputs)) = entries(dispatch).into_iter().find(|(key, _, _)| *key == "inputs")
+    else {
+        return Some(Vec::new());
+    };
+    Some(
+        entries(inputs)
+            .into_iter()
+            .map(|(name, _, props)| WorkflowInput::from_block(name, props))
+            .collect(),
+    )
+}
+
+/// Values to dispatch with: `values` checked against `inputs`, blanks filled
+/// from defaults and unknown keys dropped. Errors name the first bad input.
+pub fn dispatch_values(
+    inputs: &[WorkflowInput],
+    values: &BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>, String> {
+    let mut out = BTreeMap::new();
+    for input in inputs {
+        let given = values.get(&input.name).map(|v| v.trim()).filter(|v| !v.is_empty());
+        let Some(value) = given.or(input.default.as_deref().filter(|d| !d.is_empty())) else {
+            if input.required {
+                return Err(format!("{} is required", input.name));
+            }
+            continue;
+        };
+        out.insert(input.name.clone(), input.check(value)?);
+    }
+    Ok(out)
+}
+
+/// Non-blank line with its indentation, comments removed
+#[derive(Debug, Clone, Copy)]
+struct Line<'a> {
+    indent: usize,
+    text: &'a str,
+}
+
+fn lines(yaml: &str) -> Vec<Line<'_>> {
+    yaml.lines()
+        .filter(|line| !line.starts_with("---"))
+        .map(|line| {
+            let line = strip_comment(line).trim_end();
+            let text = line.trim_start();
+            Line { indent: line.len() - text.len(), text }
+        })
+        .filter(|line| !line.text.is_empty())
+        .collect()
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut prev = ' ';
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '#') if prev.is_whitespace() => return &line[..i],
+            _ => {}
+        }
+        prev = c;
+    }
+    line
+}
+
+/// `key: value` entries at the block's outermost indentation, each with the
+/// lines nested under it
+fn entries<'a, 'b>(block: &'b [Line<'a>]) -> Vec<(&'a str, &'a str, &'b [Line<'a>])> {
+    let Some(indent) = block.iter().map(|l| l.indent).min() else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    for (i, line) in block.iter().enumerate() {
+        if line.indent != indent {
+            continue;
+        }
+        let Some((key, value)) = split_key(line.text) else {
+            continue;
+        };
+        let end = block[i + 1..]
+            .iter()
+            .position(|l| l.indent <= indent)
+            .map_or(block.len(), |p| i + 1 + p);
+        out.push((key, value, &block[i + 1..end]));
+    }
+    out
+}
+
+fn split_key(text: &str) -> Option<(&str, &str)> {
+    if text.starts_with("- ") {
+        return None;
+    }
+    if let Some(key) = text.strip_suffix(':') {
+        return Some((key.trim(), ""));
+    }
+    text.split_once(": ").map(|(key, value)| (key.trim(), value.trim()))
+}
+
+/// Items of a flow list (`[a, b]`), a lone scalar, or a block list nested in
+/// `children`
+fn list_values(value: &str, children: &[Line<'_>]) -> Vec<String> {
+    if !value.is_empty() {
+        return value
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split(',')
+            .map(|v| unquote(v.trim()).to_string())
+            .filter(|v| !v.is_empty())
+            .collect();
+    }
+    let Some(indent) = children.iter().map(|l| l.indent).min() else {
+        return Vec::new();
+    };
+    children
+        .iter()
+        .filter(|l| l.indent == indent)
+        .filter_map(|l| l.text.strip_prefix("- ").or_else(|| (l.text == "-").then_some("")))
+        .map(|v| unquote(v.trim()).to_string())
+        .collect()
+}
+
+fn is_block_scalar(value: &str) -> bool {
+    value.starts_with('|') || value.starts_with('>')
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim_matches(|c| c == '"' || c == '\'')
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    const DEPLOY: &str = r#"name: Deploy
+on:
+  push:
+    branches: [main]
+  workflow_dispatch:
+    inputs:
+      environment:
+        description: 'Where to deploy # not a comment'
+        required: true
+        type: choice
+        options:
+          - staging
+          - production
+        default: staging
+      dry_run:
+        description: >
+          Plan only,
+          change nothing
+        type: boolean
+        default: false
+      replicas:
+        type: number  # how many pods
+      note:
+        description: Release note
+jobs:
+  deploy:
+    runs-on: ubuntu-latest
+"#;
+
+    #[test]
+    fn parse_dispatch_inputs_reads_typed_inputs() {
+        let inputs = parse_dispatch_inputs(DEPLOY).unwrap();
+        let names: Vec<&str> = inputs.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["environment", "dry_run", "replicas", "note"]);
+
+        let env = &inputs[0];
+        assert_eq!(env.description, "Where to deploy # not a comment");
+        assert!(env.required);
+        assert_eq!(env.kind, WorkflowInputKind::Choice);
+        assert_eq!(env.options, vec!["staging", "production"]);
+        assert_eq!(env.default.as_deref(), Some("staging"));
+
+        assert_eq!(inputs[1].kind, WorkflowInputKind::Boolean);
+        assert_eq!(inputs[1].description, "Plan only, change nothing");
+        assert_eq!(inputs[2].kind, WorkflowInputKind::Number);
+        assert_eq!(inputs[3].kind, WorkflowInputKind::String);
+        assert!(!inputs[3].required && inputs[3].default.is_none());
+    }
+
+    #[test]
+    fn parse_dispatch_inputs_handles_trigger_forms() {
+        assert_eq!(parse_dispatch_inputs("on: workflow_dispatch\n"), Some(vec![]));
+        assert_eq!(parse_dispatch_inputs("on: [push, workflow_dispatch]\n"), Some(vec![]));
+        assert_eq!(
+            parse_dispatch_inputs("\"on\":\n  - push\n  - workflow_dispatch\n"),
+            Some(vec![])
+        );
+        assert_eq!(parse_dispatch_inputs("on:\n  workflow_dispatch:\n  push:\n"), Some(vec![]));
+        assert_eq!(parse_dispatch_inputs("on:\n  push:\n    branches: [main]\n"), None);
+        assert_eq!(parse_dispatch_inputs("on: push\n"), None);
+        assert_eq!(parse_dispatch_inputs("name: no trigger\n"), None);
+    }
+
+    #[test]
+    fn dispatch_values_checks_types_and_fills_defaults() {
+        let inputs = parse_dispatch_inputs(DEPLOY).unwrap();
+        let mut values = BTreeMap::new();
+        values.insert("replicas".to_string(), " 3 ".to_string());
+        values.insert("unknown".to_string(), "x".to_string());
+
+        let out = dispatch_values(&inputs, &values).unwrap();
+        assert_eq!(out.get("environment").map(String::as_str), Some("staging"));
+        assert_eq!(out.get("dry_run").map(String::as_str), Some("false"));
+        assert_eq!(out.get("replicas").map(String::as_str), Some("3"));
+        assert!(!out.contains_key("note") && !out.contains_key("unknown"));
+
+        values.insert("environment".to_string(), "qa".to_string());
+        assert!(dispatch_values(&inputs, &values).unwrap_err().contains("staging, production"));
+        values.insert("environment".to_string(), "production".to_string());
+        values.insert("replicas".to_string(), "many".to_string());
+        assert_eq!(dispatch_values(&inputs, &values).unwrap_err(), "replicas must be a number");
+
+        let mut required = inputs[0].clone();
+        required.default = None;
+        assert_eq!(
+            dispatch_values(&[required], &BTreeMap::new()).unwrap_err(),
+            "environment is required"
+        );
+    }
+}
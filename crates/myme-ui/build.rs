@@ -2,20 +2,51 @@ use cxx_qt_build::{CxxQtBuilder, QmlModule};
 
 fn main() {
     CxxQtBuilder::new_qml_module(QmlModule::new("myme_ui"))
+        .file("src/models/activity_model.rs")
+        .file("src/models/analytics_model.rs")
         .file("src/models/auth_model.rs")
+        .file("src/models/bookmark_model.rs")
+        .file("src/models/briefing_model.rs")
+        .file("src/models/calendar_list_model.rs")
         .file("src/models/calendar_model.rs")
+        .file("src/models/capture_model.rs")
+        .file("src/models/clipboard_model.rs")
+        .file("src/models/contacts_model.rs")
+        .file("src/models/dashboard_model.rs")
+        .file("src/models/discover_model.rs")
+        .file("src/models/connectivity_model.rs")
         .file("src/models/encoding_model.rs")
+        .file("src/models/error_log_model.rs")
+        .file("src/models/feed_model.rs")
+        .file("src/models/focus_model.rs")
+        .file("src/models/gist_model.rs")
+        .file("src/models/insights_model.rs")
+        .file("src/models/release_model.rs")
+        .file("src/models/global_search_model.rs")
         .file("src/models/gmail_model.rs")
         .file("src/models/google_auth_model.rs")
         .file("src/models/hash_model.rs")
+        .file("src/models/image_cache_model.rs")
+        .file("src/models/import_model.rs")
         .file("src/models/json_model.rs")
         .file("src/models/jwt_model.rs")
         .file("src/models/kanban_model.rs")
+        .file("src/models/label_model.rs")
         .file("src/models/note_model.rs")
+        .file("src/models/notifications_model.rs")
+        .file("src/models/onboarding_model.rs")
         .file("src/models/project_model.rs")
+        .file("src/models/remote_control_model.rs")
         .file("src/models/repo_model.rs")
+        .file("src/models/runner_model.rs")
+        .file("src/models/session_model.rs")
+        .file("src/models/settings_model.rs")
+        .file("src/models/startup_model.rs")
+        .file("src/models/theme_model.rs")
         .file("src/models/workflow_model.rs")
         .file("src/models/time_model.rs")
+        .file("src/models/toast_model.rs")
+        .file("src/models/undo_model.rs")
         .file("src/models/uuid_model.rs")
         .file("src/models/weather_model.rs")
         .build();
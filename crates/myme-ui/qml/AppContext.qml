@@ -8,9 +8,21 @@ QtObject {
     property var weatherModel: null
     property var gmailModel: null
     property var calendarModel: null
+    property var feedModel: null
+    property var clipboardModel: null
+    property var undoManager: null
+    property var themeModel: null
+    property var imageCache: null
+    // Workspace session: last project and scroll positions
+    property var sessionModel: null
+    // Project board to reopen once the projects list has loaded
+    property string pendingProjectId: ""
 
     property string currentPage: "WelcomePage"
     property bool sidebarExpanded: true
+    property bool offline: false
+    // Set once startup has run every service step
+    property bool servicesReady: false
 
     function goToTopLevelPage(url) {
         if (pageStack) {
@@ -19,6 +31,32 @@ QtObject {
         }
     }
 
+    // Persist a theme mode ("light", "dark", "auto", "scheduled")
+    function setThemeMode(mode) {
+        if (themeModel) {
+            themeModel.save_mode(mode)
+        } else {
+            Theme.mode = mode
+        }
+    }
+
+    // Cached copy of a remote image; bindings re-evaluate as downloads land
+    function imageSource(url) {
+        if (!url || !imageCache) return url || ""
+        return imageCache.revision >= 0 ? imageCache.source(url) : ""
+    }
+
+    // Scroll a view back to where it was last session
+    function restoreScroll(view, flickable) {
+        if (!sessionModel || !flickable) return
+        const maxY = Math.max(0, flickable.contentHeight - flickable.height)
+        flickable.contentY = Math.min(sessionModel.scroll_position(view), maxY)
+    }
+
+    function saveScroll(view, flickable) {
+        if (sessionModel && flickable) sessionModel.save_scroll_position(view, flickable.contentY)
+    }
+
     function pageUrl(name) {
         return Qt.resolvedUrl("pages/" + name + ".qml")
     }
@@ -16,6 +16,85 @@ ApplicationWindow {
     color: Theme.background
 
     property string currentPage: "WelcomePage"
+    property bool firstFrameShown: false
+
+    // Databases and schedulers start once the first frame is up
+    onFrameSwapped: {
+        if (!firstFrameShown) {
+            firstFrameShown = true
+            startupModel.first_frame_shown()
+        }
+    }
+
+    // Global online/offline state; services skip network requests while offline
+    ConnectivityModel {
+        id: connectivityModel
+        onOfflineChanged: {
+            if (!offline) {
+                // Back online: refresh what the dashboard shows
+                weatherModel.refresh()
+                if (gmailModel.authenticated) gmailModel.fetch_messages()
+                if (calendarModel.authenticated) calendarModel.fetch_today_events()
+            }
+        }
+    }
+
+    Timer {
+        id: connectivityPollTimer
+        interval: 2000
+        running: true
+        repeat: true
+        onTriggered: connectivityModel.refresh()
+    }
+
+    Binding {
+        target: AppContext
+        property: "offline"
+        value: connectivityModel.offline
+    }
+
+    // Avatars and thumbnails downloaded once and loaded from disk
+    ImageCacheModel {
+        id: imageCacheModel
+    }
+
+    Timer {
+        interval: 1000
+        running: true
+        repeat: true
+        onTriggered: imageCacheModel.refresh()
+    }
+
+    // Records the current page in the crash journal and restores the last session
+    SessionModel {
+        id: sessionModel
+    }
+
+    // Services start in the background; show what is loading and what failed
+    StartupModel {
+        id: startupModel
+        Component.onCompleted: refresh()
+        onStatus_changed: {
+            startupPopup.services = JSON.parse(get_services())
+            startupPopup.timings = JSON.parse(get_timings())
+        }
+        onFinished: {
+            AppContext.servicesReady = true
+            // Models created before their service was ready pick it up now
+            weatherModel.refresh()
+            gmailModel.check_auth()
+            calendarModel.check_auth()
+        }
+    }
+
+    Timer {
+        id: startupPollTimer
+        interval: 200
+        // Also poll until the first status arrives
+        running: startupModel.loading || startupPopup.services.length === 0
+        repeat: true
+        onTriggered: startupModel.refresh()
+    }
 
     // Global weather model for dashboard
     WeatherModel {
@@ -69,10 +148,123 @@ ApplicationWindow {
         onTriggered: calendarModel.poll_channel()
     }
 
+    // Global feed model for the dashboard reading queue
+    FeedModel {
+        id: feedModel
+        Component.onCompleted: reload()
+    }
+
+    Timer {
+        id: feedPollTimer
+        interval: 250
+        // Background refreshes report here too, so keep polling
+        running: true
+        repeat: true
+        onTriggered: feedModel.poll_channel()
+    }
+
+    // Opt-in clipboard history. Qt has no clipboard-changed signal in QML, so
+    // poll by pasting into a hidden field and record text that changed.
+    ClipboardModel {
+        id: clipboardModel
+        Component.onCompleted: reload()
+    }
+
+    TextArea {
+        id: clipboardWatcher
+        visible: false
+        property string lastText: ""
+    }
+
+    Timer {
+        id: clipboardPollTimer
+        interval: 1000
+        running: clipboardModel.enabled
+        repeat: true
+        onTriggered: {
+            clipboardModel.poll_channel();
+            clipboardWatcher.clear();
+            clipboardWatcher.paste();
+            const text = clipboardWatcher.text;
+            if (text.length > 0 && text !== clipboardWatcher.lastText) {
+                clipboardWatcher.lastText = text;
+                clipboardModel.record(text);
+            }
+            clipboardWatcher.clear();
+        }
+    }
+
+    // Theme from [ui] in the config: mode, accent and dark schedule. The Theme
+    // singleton keeps its own defaults until the model has loaded.
+    ThemeModel {
+        id: themeModel
+        Component.onCompleted: {
+            load();
+            tick(Theme.systemDark);
+        }
+    }
+
+    Binding { target: Theme; property: "mode"; value: themeModel.mode; when: themeModel.mode.length > 0 }
+    Binding { target: Theme; property: "isDark"; value: themeModel.dark; when: themeModel.mode.length > 0 }
+    Binding { target: Theme; property: "primary"; value: themeModel.primary; when: themeModel.primary.length > 0 }
+    Binding { target: Theme; property: "primaryHover"; value: themeModel.primary_hover; when: themeModel.primary.length > 0 }
+    Binding { target: Theme; property: "primaryGlow"; value: themeModel.primary_glow; when: themeModel.primary.length > 0 }
+
+    Connections {
+        target: Theme
+        function onSystemDarkChanged() {
+            themeModel.tick(Theme.systemDark);
+        }
+    }
+
+    Timer {
+        id: themeTickTimer
+        // Scheduled switches and desktop changes the style hints miss
+        interval: 60000
+        running: true
+        repeat: true
+        onTriggered: themeModel.tick(Theme.systemDark)
+    }
+
+    Timer {
+        id: themePollTimer
+        interval: 500
+        running: true
+        repeat: true
+        onTriggered: themeModel.poll_channel()
+    }
+
+    // Commands from the remote-control socket (scripts, status bars, hotkeys)
+    RemoteControlModel {
+        id: remoteControlModel
+        onShow_window_requested: {
+            root.show()
+            root.raise()
+            root.requestActivate()
+        }
+        onSync_requested: {
+            weatherModel.refresh()
+            if (gmailModel.authenticated) gmailModel.fetch_messages()
+            if (calendarModel.authenticated) calendarModel.fetch_today_events()
+            feedModel.refresh()
+        }
+    }
+
+    Timer {
+        id: remoteControlPollTimer
+        interval: 250
+        running: true
+        repeat: true
+        onTriggered: remoteControlModel.poll_channel()
+    }
+
     // Navigate to a page by name
     function navigateToPage(pageName) {
         root.currentPage = pageName;
         AppContext.currentPage = pageName;
+        sessionModel.set_current_view(pageName);
+        // Switching pages closes any open project board
+        sessionModel.forget_last_project();
         AppContext.goToTopLevelPage(AppContext.pageUrl(pageName));
     }
 
@@ -164,7 +356,424 @@ ApplicationWindow {
         }
     }
 
+    // Global search (Ctrl+K): jump to the page that owns the chosen result
+    GlobalSearchPopup {
+        id: globalSearch
+        parent: Overlay.overlay
+        onResultActivated: (source, itemId, context) => {
+            switch (source) {
+            case "note":
+                root.navigateToPage("NotePage");
+                break;
+            case "email":
+                root.navigateToPage("GmailPage");
+                break;
+            case "event":
+                root.navigateToPage("CalendarPage");
+                break;
+            case "repo":
+                root.navigateToPage("RepoPage");
+                break;
+            case "task":
+                root.navigateToPage("ProjectsPage");
+                AppContext.pageStack.push(AppContext.pageUrl("ProjectDetailPage"), {
+                    projectId: context,
+                    projectName: ""
+                });
+                break;
+            }
+        }
+    }
+
+    // Undo/redo of note, email and kanban changes, with a transient "Undo" toast
+    UndoManager {
+        id: undoManager
+        onUndo_offered: summary => {
+            undoToast.text = summary;
+            undoToast.redo = false;
+            undoToast.show();
+        }
+        onUndo_applied: (service, summary, redo) => {
+            if (service === "gmail") gmailModel.poll_channel();
+            undoToast.text = (redo ? "Redone: " : "Undone: ") + summary;
+            undoToast.redo = !redo;
+            undoToast.show();
+        }
+        onError_messageChanged: {
+            if (error_message !== "") {
+                undoToast.text = "Couldn't undo: " + error_message;
+                undoToast.redo = false;
+                undoToast.show();
+            }
+        }
+    }
+
+    Timer {
+        interval: 200
+        running: true
+        repeat: true
+        onTriggered: undoManager.poll_channel()
+    }
+
+    Rectangle {
+        id: undoToast
+        property string text: ""
+        // After an undo the toast offers redo instead
+        property bool redo: false
+
+        function show() {
+            visible = true;
+            undoToastTimer.restart();
+        }
+
+        parent: Overlay.overlay
+        visible: false
+        z: 100
+        anchors.horizontalCenter: parent.horizontalCenter
+        anchors.bottom: parent.bottom
+        anchors.bottomMargin: Theme.spacingXl
+        width: Math.min(toastRow.implicitWidth + Theme.spacingMd * 2, parent.width - Theme.spacingXl * 2)
+        height: toastRow.implicitHeight + Theme.spacingSm * 2
+        radius: Theme.cardRadius
+        color: Theme.surface
+        border.color: Theme.border
+        border.width: 1
+
+        Timer {
+            id: undoToastTimer
+            interval: 6000
+            onTriggered: undoToast.visible = false
+        }
+
+        RowLayout {
+            id: toastRow
+            anchors.fill: parent
+            anchors.leftMargin: Theme.spacingMd
+            anchors.rightMargin: Theme.spacingSm
+            spacing: Theme.spacingSm
+
+            Label {
+                text: undoToast.text
+                font.pixelSize: Theme.fontSizeSmall
+                font.family: Theme.fontFamily
+                color: Theme.text
+                elide: Text.ElideRight
+                Layout.fillWidth: true
+                Layout.maximumWidth: 420
+            }
+
+            Button {
+                text: undoToast.redo ? "Redo" : "Undo"
+                flat: true
+                enabled: (undoToast.redo ? undoManager.can_redo : undoManager.can_undo) && !undoManager.busy
+                onClicked: {
+                    undoToast.visible = false;
+                    if (undoToast.redo) undoManager.redo(); else undoManager.undo();
+                }
+            }
+        }
+    }
+
+    // Progress, success and error toasts published by services, shown on every page
+    ToastModel {
+        id: toastModel
+        onAction_requested: action => {
+            if (action.startsWith("navigate:")) {
+                root.navigateToPage(action.substring(9));
+            } else if (action.startsWith("open:")) {
+                Qt.openUrlExternally(action.substring(5));
+            }
+        }
+    }
+
+    Timer {
+        interval: 200
+        running: true
+        repeat: true
+        onTriggered: toastModel.poll_channel()
+    }
+
+    Column {
+        id: toastStack
+        parent: Overlay.overlay
+        z: 95
+        anchors.right: parent.right
+        anchors.bottom: parent.bottom
+        anchors.margins: Theme.spacingMd
+        // Clear of the centered undo toast
+        anchors.bottomMargin: Theme.spacingXl * 3
+        spacing: Theme.spacingSm
+        width: Math.min(360, parent.width - Theme.spacingXl * 2)
+
+        Repeater {
+            model: toastModel.count > 0 ? JSON.parse(toastModel.toasts) : []
+
+            Rectangle {
+                id: toast
+                required property var modelData
+                readonly property color accent: modelData.severity === "error" ? Theme.error
+                    : modelData.severity === "warning" ? Theme.warning
+                    : modelData.severity === "success" ? Theme.success : Theme.info
+
+                width: toastStack.width
+                height: toastColumn.implicitHeight + Theme.spacingSm * 2
+                radius: Theme.cardRadius
+                color: Theme.surface
+                border.color: toast.accent
+                border.width: 1
+
+                ColumnLayout {
+                    id: toastColumn
+                    anchors.fill: parent
+                    anchors.leftMargin: Theme.spacingMd
+                    anchors.rightMargin: Theme.spacingSm
+                    anchors.topMargin: Theme.spacingSm
+                    anchors.bottomMargin: Theme.spacingSm
+                    spacing: Theme.spacingXs
+
+                    RowLayout {
+                        Layout.fillWidth: true
+                        spacing: Theme.spacingSm
+
+                        Label {
+                            text: toast.modelData.title
+                            font.pixelSize: Theme.fontSizeSmall
+                            font.family: Theme.fontFamily
+                            font.bold: true
+                            color: Theme.text
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+
+                        Button {
+                            visible: toast.modelData.action_label !== ""
+                            text: toast.modelData.action_label
+                            flat: true
+                            onClicked: toastModel.activate(toast.modelData.id)
+                        }
+
+                        ToolButton {
+                            text: "✕"
+                            visible: toast.modelData.severity !== "progress"
+                            onClicked: toastModel.dismiss(toast.modelData.id)
+                        }
+                    }
+
+                    Label {
+                        visible: text !== ""
+                        text: toast.modelData.detail
+                        font.pixelSize: Theme.fontSizeSmall
+                        font.family: Theme.fontFamily
+                        color: Theme.textSecondary
+                        wrapMode: Text.Wrap
+                        Layout.fillWidth: true
+                    }
+
+                    ProgressBar {
+                        visible: toast.modelData.severity === "progress"
+                        from: 0
+                        to: 100
+                        indeterminate: toast.modelData.percent < 0
+                        value: Math.max(toast.modelData.percent, 0)
+                        Layout.fillWidth: true
+                    }
+                }
+            }
+        }
+    }
+
+    // Startup status: visible while services load, and afterwards if any failed
+    Rectangle {
+        id: startupBanner
+        parent: Overlay.overlay
+        visible: startupModel.summary !== ""
+        z: 90
+        anchors.right: parent.right
+        anchors.top: parent.top
+        anchors.margins: Theme.spacingMd
+        width: Math.min(startupRow.implicitWidth + Theme.spacingMd * 2, parent.width - Theme.spacingXl * 2)
+        height: startupRow.implicitHeight + Theme.spacingSm * 2
+        radius: Theme.cardRadius
+        color: startupModel.failed_count > 0 && !startupModel.loading ? Theme.errorBg : Theme.surface
+        border.color: startupModel.failed_count > 0 && !startupModel.loading ? Theme.error : Theme.border
+        border.width: 1
+
+        RowLayout {
+            id: startupRow
+            anchors.fill: parent
+            anchors.leftMargin: Theme.spacingMd
+            anchors.rightMargin: Theme.spacingSm
+            spacing: Theme.spacingSm
+
+            BusyIndicator {
+                visible: startupModel.loading
+                running: visible
+                Layout.preferredWidth: 20
+                Layout.preferredHeight: 20
+            }
+
+            Label {
+                text: startupModel.summary
+                font.pixelSize: Theme.fontSizeSmall
+                font.family: Theme.fontFamily
+                color: Theme.text
+                elide: Text.ElideRight
+                Layout.maximumWidth: 360
+            }
+
+            Button {
+                text: "Details"
+                flat: true
+                onClicked: startupPopup.open()
+            }
+        }
+    }
+
+    // Offered once after a crash or unclean exit
+    Dialog {
+        id: recoveryDialog
+        parent: Overlay.overlay
+        anchors.centerIn: parent
+        width: Math.min(480, parent.width - Theme.spacingXl * 2)
+        modal: true
+        title: "Recovered from a problem"
+        visible: startupModel.recovery_summary !== ""
+        closePolicy: Popup.NoAutoClose
+
+        contentItem: ColumnLayout {
+            spacing: Theme.spacingSm
+
+            Label {
+                text: startupModel.recovery_summary
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeNormal
+                color: Theme.text
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            Label {
+                property string reportPath: startupModel.crash_report_path()
+                visible: reportPath !== ""
+                text: "Crash report: " + reportPath
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+                wrapMode: Text.WrapAnywhere
+                Layout.fillWidth: true
+            }
+        }
+
+        footer: DialogButtonBox {
+            Button {
+                text: "OK"
+                DialogButtonBox.buttonRole: DialogButtonBox.AcceptRole
+            }
+        }
+
+        onAccepted: startupModel.dismiss_recovery()
+    }
+
+    Popup {
+        id: startupPopup
+        property var services: []
+        property var timings: []
+
+        parent: Overlay.overlay
+        x: (parent.width - width) / 2
+        y: (parent.height - height) / 3
+        width: Math.min(460, parent.width - Theme.spacingXl * 2)
+        modal: true
+        padding: Theme.spacingMd
+
+        background: Rectangle {
+            radius: Theme.cardRadius
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+        }
+
+        contentItem: ColumnLayout {
+            spacing: Theme.spacingSm
+
+            Label {
+                text: "Services"
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+            }
+
+            Repeater {
+                model: startupPopup.services
+
+                delegate: RowLayout {
+                    required property var modelData
+                    Layout.fillWidth: true
+                    spacing: Theme.spacingSm
+
+                    Label {
+                        text: modelData.label
+                        font.family: Theme.fontFamily
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.text
+                        Layout.preferredWidth: 120
+                    }
+
+                    Label {
+                        text: {
+                            let text = modelData.progress || modelData.state
+                            if (modelData.reason !== "") text += " — " + modelData.reason
+                            if (modelData.elapsedMs !== null) text += " · " + modelData.elapsedMs + " ms"
+                            return text
+                        }
+                        font.family: Theme.fontFamily
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: modelData.state === "failed" ? Theme.error
+                            : modelData.state === "degraded" ? Theme.warning
+                            : modelData.state === "ok" ? Theme.success : Theme.textSecondary
+                        wrapMode: Text.WordWrap
+                        Layout.fillWidth: true
+                    }
+                }
+            }
+
+            // Phase end times since launch
+            Label {
+                visible: startupPopup.timings.length > 0
+                text: startupPopup.timings.map(t => t.phase.replace("_", " ") + " " + t.elapsed_ms + " ms").join(" · ")
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            RowLayout {
+                Layout.fillWidth: true
+                Layout.topMargin: Theme.spacingSm
+
+                Item { Layout.fillWidth: true }
+
+                Button {
+                    text: "Retry failed"
+                    visible: startupModel.failed_count > 0
+                    enabled: !startupModel.loading
+                    onClicked: startupModel.retry_failed()
+                }
+
+                Button {
+                    text: "Close"
+                    onClicked: startupPopup.close()
+                }
+            }
+        }
+    }
+
     // Keyboard shortcuts for navigation
+    Shortcut { sequence: "Ctrl+K"; onActivated: globalSearch.open() }
+    Shortcut { sequence: StandardKey.Undo; enabled: undoManager.can_undo; onActivated: undoManager.undo() }
+    Shortcut { sequence: StandardKey.Redo; enabled: undoManager.can_redo; onActivated: undoManager.redo() }
     Shortcut { sequence: "Ctrl+1"; onActivated: root.navigateToPage("WelcomePage") }
     Shortcut { sequence: "Ctrl+2"; onActivated: root.navigateToPage("NotePage") }
     Shortcut { sequence: "Ctrl+3"; onActivated: root.navigateToPage("GmailPage") }
@@ -181,6 +790,19 @@ ApplicationWindow {
         AppContext.weatherModel = weatherModel
         AppContext.gmailModel = gmailModel
         AppContext.calendarModel = calendarModel
+        AppContext.feedModel = feedModel
+        AppContext.clipboardModel = clipboardModel
+        AppContext.undoManager = undoManager
+        AppContext.themeModel = themeModel
+        AppContext.imageCache = imageCacheModel
+        AppContext.sessionModel = sessionModel
         stackView.push(Qt.resolvedUrl("pages/WelcomePage.qml"))
+
+        // Reopen the project board the user had open when the app last closed
+        const lastProject = sessionModel.last_project_id()
+        if (lastProject !== "") {
+            root.navigateToPage("ProjectsPage")
+            AppContext.pendingProjectId = lastProject
+        }
     }
 }
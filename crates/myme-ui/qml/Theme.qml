@@ -4,7 +4,8 @@ import QtQuick
 QtObject {
     id: theme
 
-    // Theme mode: "light", "dark", "auto"
+    // Theme mode: "light", "dark", "auto", "scheduled". Main.qml binds mode,
+    // isDark and the primary colors to ThemeModel once the config is loaded.
     property string mode: "auto"
 
     // Detect system dark mode
@@ -63,7 +64,7 @@ QtObject {
     property color sidebarHover: isDark ? "#1a1a1a" : "#e5e0d8"
     property color sidebarActive: isDark ? "#252525" : "#e0dbd3"
     property color sidebarBorder: isDark ? "#1a1a1a" : "#e5e0d8"
-    property color sidebarActiveIndicator: isDark ? "#e5a54b" : "#c08832"
+    property color sidebarActiveIndicator: primary
 
     // Card styling
     property color cardBg: surface
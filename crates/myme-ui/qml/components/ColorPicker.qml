@@ -6,16 +6,15 @@ import ".."
 Popup {
     id: colorPicker
     width: 200
-    height: colorGrid.implicitHeight + Theme.spacingMd * 2
+    height: pickerLayout.implicitHeight + Theme.spacingMd * 2
     padding: Theme.spacingMd
 
     property string selectedColor: ""
+    // Named colors, [{ name, color }] (NoteModel.get_palette)
+    property var colors: []
     signal colorPicked(string hex)
-
-    readonly property var keepColors: [
-        "#ffffff", "#fef9c3", "#fecaca", "#fecdd3", "#e9d5ff",
-        "#ddd6fe", "#bfdbfe", "#a5f3fc", "#ccfbf1", "#d1d5db"
-    ]
+    signal colorAdded(string name, string hex)
+    signal colorRemoved(string name)
 
     background: Rectangle {
         color: Theme.surface
@@ -24,57 +23,109 @@ Popup {
         radius: Theme.cardRadius
     }
 
-    GridLayout {
-        id: colorGrid
-        columns: 5
-        rowSpacing: Theme.spacingSm
-        columnSpacing: Theme.spacingSm
+    ColumnLayout {
+        id: pickerLayout
+        width: parent.width
+        spacing: Theme.spacingSm
+
+        GridLayout {
+            id: colorGrid
+            columns: 5
+            rowSpacing: Theme.spacingSm
+            columnSpacing: Theme.spacingSm
+
+            Repeater {
+                model: colorPicker.colors
+
+                delegate: Rectangle {
+                    required property var modelData
+                    Layout.preferredWidth: 32
+                    Layout.preferredHeight: 32
+                    radius: 4
+                    color: modelData.color
+                    border.width: selectedColor === modelData.color ? 2 : 0
+                    border.color: Theme.primary
 
-        Repeater {
-            model: keepColors
+                    ToolTip.visible: swatchArea.containsMouse
+                    ToolTip.text: modelData.name + " (right-click to remove)"
+                    ToolTip.delay: 500
+
+                    MouseArea {
+                        id: swatchArea
+                        anchors.fill: parent
+                        hoverEnabled: true
+                        acceptedButtons: Qt.LeftButton | Qt.RightButton
+                        onClicked: (mouse) => {
+                            if (mouse.button === Qt.RightButton) {
+                                colorRemoved(modelData.name);
+                                return;
+                            }
+                            selectedColor = modelData.color;
+                            colorPicked(modelData.color);
+                            colorPicker.close();
+                        }
+                    }
+                }
+            }
 
-            delegate: Rectangle {
-                required property string modelData
+            Rectangle {
                 Layout.preferredWidth: 32
                 Layout.preferredHeight: 32
                 radius: 4
-                color: modelData
-                border.width: selectedColor === modelData ? 2 : 0
-                border.color: Theme.primary
+                color: "transparent"
+                border.width: 1
+                border.color: Theme.border
+
+                Label {
+                    anchors.centerIn: parent
+                    text: Icons.x
+                    font.family: Icons.family
+                    font.pixelSize: 14
+                    color: Theme.textMuted
+                }
 
                 MouseArea {
                     anchors.fill: parent
                     onClicked: {
-                        selectedColor = modelData;
-                        colorPicked(modelData);
+                        selectedColor = "";
+                        colorPicked("");
                         colorPicker.close();
                     }
                 }
             }
         }
 
-        Rectangle {
-            Layout.preferredWidth: 32
-            Layout.preferredHeight: 32
-            radius: 4
-            color: "transparent"
-            border.width: 1
-            border.color: Theme.border
-
-            Label {
-                anchors.centerIn: parent
-                text: Icons.x
-                font.family: Icons.family
-                font.pixelSize: 14
-                color: Theme.textMuted
+        // Add a named color to the palette
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: Theme.spacingSm
+
+            TextField {
+                id: nameField
+                Layout.fillWidth: true
+                placeholderText: "Name"
+                font.pixelSize: Theme.fontSizeSmall
             }
 
-            MouseArea {
-                anchors.fill: parent
+            TextField {
+                id: hexField
+                Layout.preferredWidth: 64
+                placeholderText: "#rrggbb"
+                font.pixelSize: Theme.fontSizeSmall
+                validator: RegularExpressionValidator { regularExpression: /#([0-9a-fA-F]{3}|[0-9a-fA-F]{6})/ }
+            }
+
+            Button {
+                text: Icons.plus
+                font.family: Icons.family
+                flat: true
+                enabled: nameField.text.trim().length > 0 && hexField.acceptableInput
+                ToolTip.visible: hovered
+                ToolTip.text: "Add to palette"
                 onClicked: {
-                    selectedColor = "";
-                    colorPicked("");
-                    colorPicker.close();
+                    colorAdded(nameField.text.trim(), hexField.text);
+                    nameField.text = "";
+                    hexField.text = "";
                 }
             }
         }
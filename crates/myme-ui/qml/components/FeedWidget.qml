@@ -0,0 +1,200 @@
+import QtQuick
+import QtQuick.Layouts
+import QtQuick.Controls
+import ".."
+
+// Feed widget for dashboard
+// Shows the unread count and the newest unread headlines
+Rectangle {
+    id: root
+
+    property bool loading: false
+    property int feedCount: 0
+    property int unreadCount: 0
+    // Unread items as parsed from FeedModel.get_items()
+    property var items: []
+
+    signal clicked()
+    signal refreshRequested()
+    signal itemClicked(var item)
+
+    color: Theme.cardBg
+    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+    border.width: 1
+    radius: Theme.cardRadius
+
+    implicitWidth: 200
+    implicitHeight: contentColumn.implicitHeight + Theme.cardPadding * 2
+
+    MouseArea {
+        anchors.fill: parent
+        cursorShape: Qt.PointingHandCursor
+        hoverEnabled: true
+        onClicked: root.clicked()
+
+        Rectangle {
+            anchors.fill: parent
+            color: parent.containsMouse ? Theme.surfaceHover : "transparent"
+            radius: root.radius
+            opacity: 0.5
+
+            Behavior on color {
+                ColorAnimation { duration: 150 }
+            }
+        }
+    }
+
+    ColumnLayout {
+        id: contentColumn
+        anchors.fill: parent
+        anchors.margins: Theme.cardPadding
+        spacing: Theme.spacingMd
+
+        // Header
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: Theme.spacingSm
+
+            Text {
+                font.pixelSize: Theme.fontSizeSmall
+                font.weight: Font.Medium
+                font.letterSpacing: 1
+                text: "READING"
+                color: Theme.textMuted
+            }
+
+            Item { Layout.fillWidth: true }
+
+            // Refresh button
+            Rectangle {
+                width: 24
+                height: 24
+                radius: Theme.buttonRadius
+                color: refreshArea.containsMouse ? Theme.surfaceHover : "transparent"
+                visible: root.feedCount > 0
+
+                Text {
+                    anchors.centerIn: parent
+                    font.family: Icons.family
+                    font.pixelSize: Theme.fontSizeSmall
+                    text: Icons.arrowsClockwise
+                    color: Theme.textSecondary
+
+                    RotationAnimation on rotation {
+                        running: root.loading
+                        from: 0
+                        to: 360
+                        duration: 1000
+                        loops: Animation.Infinite
+                    }
+                }
+
+                MouseArea {
+                    id: refreshArea
+                    anchors.fill: parent
+                    hoverEnabled: true
+                    cursorShape: Qt.PointingHandCursor
+                    onClicked: mouse => {
+                        mouse.accepted = true;
+                        root.refreshRequested();
+                    }
+                }
+            }
+        }
+
+        Loader {
+            Layout.fillWidth: true
+            sourceComponent: root.feedCount > 0 ? queueContent : emptyContent
+        }
+    }
+
+    // Unread count and headlines
+    Component {
+        id: queueContent
+
+        ColumnLayout {
+            spacing: Theme.spacingSm
+
+            RowLayout {
+                spacing: Theme.spacingMd
+
+                Text {
+                    font.family: Icons.family
+                    font.pixelSize: 36
+                    text: Icons.article
+                    color: root.unreadCount > 0 ? Theme.primary : Theme.textMuted
+                }
+
+                ColumnLayout {
+                    Layout.fillWidth: true
+                    spacing: 2
+
+                    Text {
+                        font.pixelSize: Theme.fontSizeTitle
+                        font.weight: Font.Bold
+                        text: root.unreadCount.toString()
+                        color: Theme.text
+                    }
+
+                    Text {
+                        font.pixelSize: Theme.fontSizeSmall
+                        text: root.unreadCount === 1 ? "unread article" : "unread articles"
+                        color: Theme.textSecondary
+                    }
+                }
+            }
+
+            Repeater {
+                model: root.items
+
+                delegate: Text {
+                    required property var modelData
+                    Layout.fillWidth: true
+                    font.pixelSize: Theme.fontSizeSmall
+                    text: modelData.title
+                    color: headlineArea.containsMouse ? Theme.primary : Theme.text
+                    elide: Text.ElideRight
+
+                    MouseArea {
+                        id: headlineArea
+                        anchors.fill: parent
+                        hoverEnabled: true
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: root.itemClicked(modelData)
+                    }
+                }
+            }
+        }
+    }
+
+    // No subscriptions yet
+    Component {
+        id: emptyContent
+
+        ColumnLayout {
+            spacing: Theme.spacingSm
+
+            Text {
+                font.family: Icons.family
+                font.pixelSize: 32
+                text: Icons.article
+                color: Theme.textMuted
+                Layout.alignment: Qt.AlignHCenter
+            }
+
+            Text {
+                font.pixelSize: Theme.fontSizeSmall
+                text: "No feeds yet"
+                color: Theme.textSecondary
+                Layout.alignment: Qt.AlignHCenter
+            }
+
+            Text {
+                font.pixelSize: Theme.fontSizeSmall
+                text: "Add a feed →"
+                color: Theme.primary
+                Layout.alignment: Qt.AlignHCenter
+            }
+        }
+    }
+}
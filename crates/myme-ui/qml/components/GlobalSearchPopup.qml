@@ -0,0 +1,183 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+// Search across notes, email, events, repos and tasks (Ctrl+K)
+Popup {
+    id: searchPopup
+    modal: true
+    focus: true
+    width: Math.min(640, parent ? parent.width - Theme.spacingLg * 2 : 640)
+    height: Math.min(520, parent ? parent.height - Theme.spacingLg * 4 : 520)
+    x: parent ? (parent.width - width) / 2 : 0
+    y: parent ? parent.height * 0.12 : 0
+    padding: Theme.spacingMd
+
+    property int resultCount: 0
+
+    // Emitted when a result is chosen; the window decides where to go
+    signal resultActivated(string source, string itemId, string context)
+
+    readonly property var sourceIcons: ({
+        "note": Icons.notePencil,
+        "email": Icons.envelopeSimple,
+        "event": Icons.calendarBlank,
+        "repo": Icons.gitBranch,
+        "task": Icons.squaresFour
+    })
+
+    background: Rectangle {
+        color: Theme.surface
+        border.color: Theme.border
+        border.width: 1
+        radius: Theme.cardRadius
+    }
+
+    GlobalSearchModel {
+        id: searchModel
+        onResults_changed: searchPopup.resultCount = searchModel.row_count()
+    }
+
+    Timer {
+        id: searchPollTimer
+        interval: 50
+        running: searchModel.loading
+        repeat: true
+        onTriggered: searchModel.poll_channel()
+    }
+
+    // Debounce typing so each keystroke doesn't fan out a search
+    Timer {
+        id: debounceTimer
+        interval: 250
+        onTriggered: searchModel.search(queryField.text)
+    }
+
+    onOpened: {
+        queryField.forceActiveFocus();
+        queryField.selectAll();
+    }
+
+    function activate(index) {
+        if (index < 0 || index >= resultCount)
+            return;
+        resultActivated(searchModel.get_source(index), searchModel.get_item_id(index),
+                        searchModel.get_context(index));
+        close();
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        spacing: Theme.spacingSm
+
+        TextField {
+            id: queryField
+            Layout.fillWidth: true
+            placeholderText: "Search notes, email, events, repos and tasks"
+            color: Theme.text
+            onTextChanged: debounceTimer.restart()
+            Keys.onDownPressed: resultsList.incrementCurrentIndex()
+            Keys.onUpPressed: resultsList.decrementCurrentIndex()
+            onAccepted: searchPopup.activate(resultsList.currentIndex)
+
+            background: Rectangle {
+                color: Theme.inputBg
+                border.color: queryField.activeFocus ? Theme.inputFocus : Theme.inputBorder
+                border.width: 1
+                radius: Theme.inputRadius
+            }
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            visible: searchModel.loading || searchModel.error_message.length > 0
+
+            BusyIndicator {
+                running: searchModel.loading
+                visible: running
+                Layout.preferredWidth: 20
+                Layout.preferredHeight: 20
+            }
+
+            Label {
+                text: searchModel.error_message
+                color: Theme.textMuted
+                font.pixelSize: Theme.fontSizeSmall
+                Layout.fillWidth: true
+                elide: Text.ElideRight
+            }
+        }
+
+        ListView {
+            id: resultsList
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+            model: searchPopup.resultCount
+            currentIndex: 0
+            highlightMoveDuration: 0
+
+            delegate: ItemDelegate {
+                id: resultDelegate
+                required property int index
+                width: resultsList.width
+                highlighted: ListView.isCurrentItem
+                onClicked: searchPopup.activate(index)
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: resultDelegate.highlighted || resultDelegate.hovered
+                        ? Theme.surfaceHover : "transparent"
+                }
+
+                contentItem: RowLayout {
+                    spacing: Theme.spacingMd
+
+                    Label {
+                        text: searchPopup.sourceIcons[searchModel.get_source(resultDelegate.index)] || ""
+                        font.family: Icons.family
+                        font.pixelSize: 18
+                        color: Theme.textSecondary
+                    }
+
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 2
+
+                        Label {
+                            text: searchModel.get_title(resultDelegate.index)
+                            color: Theme.text
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+
+                        Label {
+                            text: searchModel.get_snippet(resultDelegate.index)
+                            visible: text.length > 0
+                            color: Theme.textMuted
+                            font.pixelSize: Theme.fontSizeSmall
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+                    }
+
+                    Label {
+                        text: searchModel.get_source(resultDelegate.index)
+                        color: Theme.textSecondary
+                        font.pixelSize: Theme.fontSizeSmall
+                    }
+                }
+            }
+        }
+
+        Label {
+            visible: !searchModel.loading && queryField.text.trim().length > 0
+                     && searchPopup.resultCount === 0
+            text: "No results"
+            color: Theme.textMuted
+            Layout.alignment: Qt.AlignHCenter
+        }
+    }
+}
@@ -7,18 +7,31 @@ Rectangle {
     id: noteCard
     required property int noteIndex
     required property var noteModel
+    // Note data, from NoteModel's roles
+    required property string content
+    required property bool done
+    required property string createdAt
+    required property string noteColor
+    required property bool pinned
+    required property bool archived
+    required property var labels
+    required property bool isChecklist
+    required property string reminder
+    required property string deletedAt
 
     property bool editing: false
     property bool dirty: false
     property real cardWidth: 220
+    // Offer "Share as gist" (set when GitHub is signed in)
+    property bool canShareGist: false
+    // Optional LabelModel for label autocomplete
+    property var labelModel: null
+    readonly property bool trashed: deletedAt.length > 0
 
     implicitWidth: cardWidth
     implicitHeight: editing ? editContent.implicitHeight + Theme.spacingMd * 2 : readContent.implicitHeight + Theme.spacingMd * 2
     radius: Theme.cardRadius
-    color: {
-        const c = noteModel ? noteModel.get_color(noteIndex) : "";
-        return (c && c.length > 0) ? c : Theme.surface;
-    }
+    color: noteColor.length > 0 ? noteColor : Theme.surface
     border.color: Theme.isDark ? "#ffffff08" : "#00000008"
     border.width: 1
 
@@ -73,7 +86,7 @@ Rectangle {
             Layout.fillHeight: true
             onClicked: {
                 editing = true;
-                editField.text = noteModel.get_content(noteIndex);
+                editField.text = noteCard.content;
                 editField.forceActiveFocus();
             }
             cursorShape: Qt.IBeamCursor
@@ -83,21 +96,19 @@ Rectangle {
                 spacing: Theme.spacingXs
 
                 Repeater {
-                    model: noteModel
-                        ? (noteModel.get_is_checklist(noteIndex)
-                            ? parseChecklistContent(noteModel.get_content(noteIndex))
-                            : [{ text: noteModel.get_content(noteIndex), checked: false }])
-                        : []
+                    model: noteCard.isChecklist
+                        ? parseChecklistContent(noteCard.content)
+                        : [{ text: noteCard.content, checked: false }]
 
                     delegate: RowLayout {
                         Layout.fillWidth: true
                         spacing: Theme.spacingSm
 
                         CheckBox {
-                            visible: noteModel && noteModel.get_is_checklist(noteIndex)
+                            visible: noteCard.isChecklist
                             checked: modelData.checked
                             onClicked: {
-                                const content = noteModel.get_content(noteIndex);
+                                const content = noteCard.content;
                                 const lines = content.split("\n");
                                 const idx = index;
                                 if (idx >= 0 && idx < lines.length) {
@@ -125,10 +136,10 @@ Rectangle {
                 Flow {
                     Layout.fillWidth: true
                     spacing: Theme.spacingXs
-                    visible: noteModel && noteModel.get_labels(noteIndex).length > 0
+                    visible: noteCard.labels.length > 0
 
                     Repeater {
-                        model: noteModel ? noteModel.get_labels(noteIndex) : []
+                        model: noteCard.labels
 
                         delegate: Rectangle {
                             width: labelText.implicitWidth + Theme.spacingSm * 2
@@ -153,14 +164,14 @@ Rectangle {
                 }
 
                 Label {
-                    text: noteModel ? noteModel.get_created_at(noteIndex) : ""
+                    text: noteCard.createdAt
                     font.pixelSize: Theme.fontSizeSmall
                     color: Theme.textMuted
                     visible: text.length > 0
                 }
 
                 RowLayout {
-                    visible: noteModel && noteModel.get_reminder(noteIndex).length > 0
+                    visible: noteCard.reminder.length > 0
                     spacing: Theme.spacingXs
                     Label {
                         text: Icons.clock
@@ -169,7 +180,7 @@ Rectangle {
                         color: Theme.primary
                     }
                     Label {
-                        text: noteModel ? noteModel.get_reminder(noteIndex) : ""
+                        text: noteCard.reminder
                         font.pixelSize: Theme.fontSizeSmall
                         color: Theme.primary
                     }
@@ -178,14 +189,70 @@ Rectangle {
                 RowLayout {
                     Layout.fillWidth: true
                     spacing: Theme.spacingXs
-                    visible: !editing
+                    visible: trashed
+
+                    Label {
+                        text: "Deleted " + deletedAt
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.textMuted
+                        Layout.fillWidth: true
+                        elide: Text.ElideRight
+                    }
+
+                    ToolButton {
+                        text: "Restore"
+                        font.pixelSize: Theme.fontSizeSmall
+                        onClicked: noteModel.restore_note(noteIndex)
+
+                        background: Rectangle {
+                            radius: Theme.buttonRadius
+                            color: parent.hovered ? Theme.surfaceHover : "transparent"
+                        }
+
+                        contentItem: Text {
+                            text: parent.text
+                            color: Theme.text
+                            font.pixelSize: Theme.fontSizeSmall
+                            horizontalAlignment: Text.AlignHCenter
+                            verticalAlignment: Text.AlignVCenter
+                        }
+                    }
 
                     ToolButton {
-                        text: noteModel.get_pinned(noteIndex) ? Icons.starFill : Icons.pushPin
+                        text: Icons.trash
                         font.family: Icons.family
                         font.pixelSize: 14
-                        onClicked: noteModel.set_pinned(noteIndex, !noteModel.get_pinned(noteIndex))
-                        ToolTip.text: noteModel.get_pinned(noteIndex) ? "Unpin" : "Pin"
+                        onClicked: noteModel.delete_note(noteIndex)
+                        ToolTip.text: "Delete forever"
+                        ToolTip.visible: hovered
+
+                        background: Rectangle {
+                            radius: Theme.buttonRadius
+                            color: parent.hovered ? Theme.error + "30" : "transparent"
+                        }
+
+                        contentItem: Text {
+                            text: parent.text
+                            font.family: Icons.family
+                            color: Theme.error
+                            font.pixelSize: 14
+                            horizontalAlignment: Text.AlignHCenter
+                            verticalAlignment: Text.AlignVCenter
+                        }
+                    }
+                }
+
+                RowLayout {
+                    Layout.fillWidth: true
+                    spacing: Theme.spacingXs
+                    visible: !editing && !trashed
+
+                    ToolButton {
+                        text: noteCard.pinned ? Icons.starFill : Icons.pushPin
+                        font.family: Icons.family
+                        font.pixelSize: 14
+                        onClicked: noteModel.set_pinned(noteIndex, !noteCard.pinned)
+                        ToolTip.text: noteCard.pinned ? "Unpin" : "Pin"
                         ToolTip.visible: hovered
 
                         background: Rectangle {
@@ -196,7 +263,7 @@ Rectangle {
                         contentItem: Text {
                             text: parent.text
                             font.family: Icons.family
-                            color: noteModel.get_pinned(noteIndex) ? Theme.primary : Theme.textMuted
+                            color: noteCard.pinned ? Theme.primary : Theme.textMuted
                             font.pixelSize: 14
                             horizontalAlignment: Text.AlignHCenter
                             verticalAlignment: Text.AlignVCenter
@@ -207,7 +274,7 @@ Rectangle {
                         text: Icons.squaresFour
                         font.family: Icons.family
                         font.pixelSize: 14
-                        onClicked: noteCard.promoteRequested(noteIndex, noteModel.get_content(noteIndex))
+                        onClicked: noteCard.promoteRequested(noteIndex, noteCard.content)
                         ToolTip.text: "Promote to Project"
                         ToolTip.visible: hovered
 
@@ -227,7 +294,7 @@ Rectangle {
                     }
 
                     ToolButton {
-                        text: noteModel.get_done(noteIndex) ? "Undo" : "Done"
+                        text: noteCard.done ? "Undo" : "Done"
                         font.pixelSize: Theme.fontSizeSmall
                         onClicked: noteModel.toggle_done(noteIndex)
 
@@ -246,13 +313,13 @@ Rectangle {
                     }
 
                     ToolButton {
-                        text: noteModel.get_archived(noteIndex) ? "Restore" : Icons.archiveBox
+                        text: noteCard.archived ? "Restore" : Icons.archiveBox
                         font.family: Icons.family
                         font.pixelSize: 14
-                        onClicked: noteModel.get_archived(noteIndex)
+                        onClicked: noteCard.archived
                             ? noteModel.unarchive_note(noteIndex)
                             : noteModel.archive_note(noteIndex)
-                        ToolTip.text: noteModel.get_archived(noteIndex) ? "Restore" : "Archive"
+                        ToolTip.text: noteCard.archived ? "Restore" : "Archive"
                         ToolTip.visible: hovered
 
                         background: Rectangle {
@@ -300,33 +367,43 @@ Rectangle {
                                 onTriggered: colorPicker.open()
                             }
                             MenuItem {
-                                text: noteModel.get_pinned(noteIndex) ? "Unpin" : "Pin"
-                                onTriggered: noteModel.set_pinned(noteIndex, !noteModel.get_pinned(noteIndex))
+                                text: noteCard.pinned ? "Unpin" : "Pin"
+                                onTriggered: noteModel.set_pinned(noteIndex, !noteCard.pinned)
                             }
                             MenuItem {
-                                text: noteModel.get_archived(noteIndex) ? "Restore" : "Archive"
-                                onTriggered: noteModel.get_archived(noteIndex)
+                                text: noteCard.archived ? "Restore" : "Archive"
+                                onTriggered: noteCard.archived
                                     ? noteModel.unarchive_note(noteIndex)
                                     : noteModel.archive_note(noteIndex)
                             }
                             MenuItem {
                                 text: "Promote to Project"
-                                onTriggered: noteCard.promoteRequested(noteIndex, noteModel.get_content(noteIndex))
+                                onTriggered: noteCard.promoteRequested(noteIndex, noteCard.content)
+                            }
+                            MenuItem {
+                                text: "Share as gist"
+                                visible: noteCard.canShareGist
+                                height: visible ? implicitHeight : 0
+                                onTriggered: noteCard.shareGistRequested(noteIndex, noteCard.content)
                             }
                             MenuItem {
                                 text: "Add label"
                                 onTriggered: addLabelPopup.open()
                             }
                             MenuItem {
-                                text: noteModel.get_reminder(noteIndex).length > 0 ? "Remove reminder" : "Add reminder"
+                                text: noteCard.reminder.length > 0 ? "Remove reminder" : "Add reminder"
                                 onTriggered: {
-                                    if (noteModel.get_reminder(noteIndex).length > 0) {
+                                    if (noteCard.reminder.length > 0) {
                                         noteModel.set_reminder(noteIndex, "");
                                     } else {
                                         reminderPopup.open();
                                     }
                                 }
                             }
+                            MenuItem {
+                                text: "Move to trash"
+                                onTriggered: noteModel.delete_note(noteIndex)
+                            }
                         }
                     }
 
@@ -434,7 +511,19 @@ Rectangle {
 
     ColorPicker {
         id: colorPicker
+        onAboutToShow: {
+            colors = JSON.parse(noteModel.get_palette());
+            selectedColor = noteCard.noteColor;
+        }
         onColorPicked: (hex) => noteModel.set_color(noteIndex, hex)
+        onColorAdded: (name, hex) => {
+            if (noteModel.add_palette_color(name, hex))
+                colors = JSON.parse(noteModel.get_palette());
+        }
+        onColorRemoved: (name) => {
+            if (noteModel.remove_palette_color(name))
+                colors = JSON.parse(noteModel.get_palette());
+        }
     }
 
     Popup {
@@ -463,6 +552,7 @@ Rectangle {
                 id: labelField
                 placeholderText: "Label name"
                 Layout.fillWidth: true
+                property var suggestions: labelModel ? labelModel.suggest(text) : []
                 onAccepted: {
                     if (text.trim().length > 0) {
                         noteModel.add_label(noteIndex, text.trim());
@@ -471,6 +561,43 @@ Rectangle {
                     addLabelPopup.close();
                 }
             }
+            // Existing labels matching what's typed
+            Flow {
+                Layout.fillWidth: true
+                spacing: Theme.spacingXs
+                visible: labelField.suggestions.length > 0
+
+                Repeater {
+                    model: labelField.suggestions
+
+                    delegate: Rectangle {
+                        required property string modelData
+                        width: suggestionText.implicitWidth + Theme.spacingSm * 2
+                        height: suggestionText.implicitHeight + Theme.spacingXs * 2
+                        radius: height / 2
+                        color: suggestionArea.containsMouse ? Theme.surfaceHover : Theme.surfaceAlt
+
+                        Label {
+                            id: suggestionText
+                            anchors.centerIn: parent
+                            text: modelData
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.text
+                        }
+
+                        MouseArea {
+                            id: suggestionArea
+                            anchors.fill: parent
+                            hoverEnabled: true
+                            onClicked: {
+                                noteModel.add_label(noteIndex, modelData);
+                                labelField.text = "";
+                                addLabelPopup.close();
+                            }
+                        }
+                    }
+                }
+            }
             Button {
                 text: "Add"
                 onClicked: {
@@ -485,6 +612,7 @@ Rectangle {
     }
 
     signal promoteRequested(int index, string title)
+    signal shareGistRequested(int index, string content)
 
     // Edit mode
     ColumnLayout {
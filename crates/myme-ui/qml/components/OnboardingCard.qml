@@ -0,0 +1,181 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+// First-run guide on the dashboard: progress, the step list, and the action
+// for the current step. Hidden once every step is done or the guide is dismissed.
+Rectangle {
+    id: onboardingCard
+
+    property var steps: []
+
+    visible: onboardingModel.active
+    implicitHeight: cardLayout.implicitHeight + Theme.spacingMd * 2
+    radius: Theme.cardRadius
+    color: Theme.surface
+    border.color: Theme.primary
+    border.width: 1
+
+    OnboardingModel {
+        id: onboardingModel
+        onSteps_changed: {
+            try {
+                onboardingCard.steps = JSON.parse(get_steps());
+            } catch (e) {
+                onboardingCard.steps = [];
+            }
+        }
+        Component.onCompleted: refresh()
+    }
+
+    // Connections are made on other pages; pick them up when coming back
+    onVisibleChanged: {
+        if (visible) onboardingModel.refresh();
+    }
+
+    function openSettings() {
+        AppContext.goToTopLevelPage(AppContext.pageUrl("SettingsPage"));
+    }
+
+    ColumnLayout {
+        id: cardLayout
+        anchors.fill: parent
+        anchors.margins: Theme.spacingMd
+        spacing: Theme.spacingSm
+
+        RowLayout {
+            Layout.fillWidth: true
+
+            Label {
+                text: "Get set up"
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+            }
+
+            Label {
+                text: onboardingModel.completed_count + " of " + onboardingModel.total_steps
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+            }
+
+            ToolButton {
+                text: Icons.x
+                font.family: Icons.family
+                font.pixelSize: 14
+                onClicked: onboardingModel.dismiss()
+                ToolTip.text: "Hide the guide"
+                ToolTip.visible: hovered
+            }
+        }
+
+        ProgressBar {
+            Layout.fillWidth: true
+            from: 0
+            to: onboardingModel.total_steps
+            value: onboardingModel.completed_count
+        }
+
+        Repeater {
+            model: onboardingCard.steps
+
+            delegate: RowLayout {
+                id: stepRow
+                required property var modelData
+                Layout.fillWidth: true
+                spacing: Theme.spacingSm
+
+                Label {
+                    text: stepRow.modelData.done ? Icons.check
+                        : (stepRow.modelData.skipped ? Icons.minus : Icons.caretRight)
+                    font.family: Icons.family
+                    font.pixelSize: 16
+                    opacity: stepRow.modelData.done || stepRow.modelData.skipped || stepRow.modelData.current ? 1 : 0
+                    color: stepRow.modelData.done ? Theme.success
+                        : (stepRow.modelData.current ? Theme.primary : Theme.textMuted)
+                }
+
+                Label {
+                    text: stepRow.modelData.label + (stepRow.modelData.optional ? " (optional)" : "")
+                    font.family: Theme.fontFamily
+                    font.pixelSize: Theme.fontSizeNormal
+                    font.bold: stepRow.modelData.current
+                    color: stepRow.modelData.current ? Theme.text : Theme.textSecondary
+                    Layout.fillWidth: true
+                }
+            }
+        }
+
+        // Action for the current step
+        RowLayout {
+            Layout.fillWidth: true
+            Layout.topMargin: Theme.spacingSm
+            spacing: Theme.spacingSm
+
+            Button {
+                visible: onboardingModel.current_step === "config_created"
+                text: "Continue"
+                onClicked: onboardingModel.complete_step("config_created")
+            }
+
+            Button {
+                visible: onboardingModel.current_step === "github_connected"
+                    || onboardingModel.current_step === "google_connected"
+                text: onboardingModel.current_step === "github_connected" ? "Connect GitHub in Settings" : "Connect Google in Settings"
+                onClicked: onboardingCard.openSettings()
+            }
+
+            TextField {
+                id: pathField
+                visible: onboardingModel.current_step === "repos_path_chosen"
+                    || onboardingModel.current_step === "notes_backend_selected"
+                placeholderText: onboardingModel.current_step === "repos_path_chosen"
+                    ? "Folder with your git clones, e.g. ~/dev"
+                    : "Shared folder to sync notes (Syncthing, Dropbox...)"
+                Layout.fillWidth: true
+            }
+
+            Button {
+                visible: onboardingModel.current_step === "repos_path_chosen"
+                enabled: pathField.text.trim().length > 0
+                text: "Use this folder"
+                onClicked: onboardingModel.choose_repos_path(pathField.text)
+            }
+
+            Button {
+                visible: onboardingModel.current_step === "notes_backend_selected"
+                enabled: pathField.text.trim().length > 0
+                text: "Sync through this folder"
+                onClicked: onboardingModel.choose_notes_backend(pathField.text)
+            }
+
+            Button {
+                visible: onboardingModel.current_step === "notes_backend_selected"
+                text: "Keep notes on this computer"
+                onClicked: onboardingModel.choose_notes_backend("")
+            }
+
+            Button {
+                visible: onboardingModel.current_step === "google_connected"
+                text: "Skip"
+                flat: true
+                onClicked: onboardingModel.skip_step("google_connected")
+            }
+        }
+
+        Label {
+            visible: onboardingModel.error_message.length > 0
+            text: onboardingModel.error_message
+            font.family: Theme.fontFamily
+            font.pixelSize: Theme.fontSizeSmall
+            color: Theme.error
+            wrapMode: Text.WordWrap
+            Layout.fillWidth: true
+        }
+    }
+}
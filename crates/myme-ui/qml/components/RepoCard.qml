@@ -9,6 +9,75 @@ Rectangle {
     required property var repoModel
     property var projectModel: null
 
+    // This week's commit totals and latest commits, refreshed when activity loads
+    property var commitStats: ({})
+    property var recentCommits: []
+
+    function loadActivity() {
+        if (!repoModel)
+            return;
+        try {
+            commitStats = JSON.parse(repoModel.get_commit_stats(index));
+            recentCommits = JSON.parse(repoModel.get_recent_commits(index));
+        } catch (e) {
+            commitStats = {};
+            recentCommits = [];
+        }
+    }
+
+    // Open Dependabot/code scanning alerts, refreshed when stored counts change
+    property int alertCount: 0
+    property string alertSeverity: ""
+
+    function loadSecurity() {
+        if (!repoModel)
+            return;
+        alertCount = repoModel.get_alert_count(index);
+        alertSeverity = repoModel.get_alert_severity(index);
+    }
+
+    // Result of the last fork sync, shown under the status row
+    property string syncNote: ""
+
+    // Hygiene report of the local clone, or null before it has been checked
+    property var health: null
+
+    function loadHealth() {
+        if (!repoModel)
+            return;
+        try {
+            health = JSON.parse(repoModel.get_health(index));
+        } catch (e) {
+            health = null;
+        }
+    }
+
+    function formatBytes(bytes) {
+        if (bytes >= 1024 * 1024)
+            return (bytes / (1024 * 1024)).toFixed(1) + " MB";
+        return Math.round(bytes / 1024) + " KB";
+    }
+
+    Connections {
+        target: card.repoModel
+        function onActivity_changed() {
+            card.loadActivity();
+        }
+        function onSecurity_changed() {
+            card.loadSecurity();
+        }
+        function onHealth_changed() {
+            card.loadHealth();
+        }
+        function onFork_synced(syncedIndex, branch, commits) {
+            if (syncedIndex === card.index) {
+                card.syncNote = commits > 0
+                    ? branch + " synced with upstream (" + commits + " new commit" + (commits === 1 ? "" : "s") + ")"
+                    : branch + " already matches upstream";
+            }
+        }
+    }
+
     implicitHeight: cardContent.implicitHeight + Theme.spacingMd * 2
     radius: Theme.cardRadius
     color: cardMouseArea.containsMouse ? Theme.surfaceHover : Theme.surface
@@ -20,7 +89,12 @@ Rectangle {
     Behavior on color { ColorAnimation { duration: 100 } }
 
     opacity: 0
-    Component.onCompleted: cardEntryAnim.start()
+    Component.onCompleted: {
+        cardEntryAnim.start();
+        loadActivity();
+        loadSecurity();
+        loadHealth();
+    }
     SequentialAnimation {
         id: cardEntryAnim
         PauseAnimation { duration: card.index * 30 }
@@ -51,12 +125,52 @@ Rectangle {
                 Layout.fillWidth: true
                 spacing: Theme.spacingXs
 
-                Label {
-                    text: repoModel ? repoModel.getFullName(index) : ""
-                    font.pixelSize: Theme.fontSizeMedium
-                    font.bold: true
-                    color: Theme.text
+                RowLayout {
                     Layout.fillWidth: true
+                    spacing: Theme.spacingSm
+
+                    Label {
+                        text: repoModel ? repoModel.getFullName(index) : ""
+                        font.pixelSize: Theme.fontSizeMedium
+                        font.bold: true
+                        color: Theme.text
+                        elide: Text.ElideRight
+                        Layout.fillWidth: true
+                    }
+
+                    SecurityBadge {
+                        count: card.alertCount
+                        severity: card.alertSeverity
+                    }
+
+                    Rectangle {
+                        visible: card.health !== null
+                        implicitWidth: healthLabel.implicitWidth + Theme.spacingSm * 2
+                        implicitHeight: healthLabel.implicitHeight + 4
+                        radius: height / 2
+                        color: !card.health ? "transparent"
+                               : card.health.score >= 80 ? Theme.success
+                               : card.health.score >= 50 ? Theme.warning : Theme.error
+
+                        Label {
+                            id: healthLabel
+                            anchors.centerIn: parent
+                            text: card.health ? card.health.score : ""
+                            font.pixelSize: Theme.fontSizeSmall
+                            font.bold: true
+                            color: "white"
+                        }
+
+                        MouseArea {
+                            anchors.fill: parent
+                            cursorShape: Qt.PointingHandCursor
+                            onClicked: healthPopup.open()
+                        }
+
+                        ToolTip.text: "Repo health"
+                        ToolTip.visible: healthHover.hovered
+                        HoverHandler { id: healthHover }
+                    }
                 }
 
                 Label {
@@ -79,6 +193,8 @@ Rectangle {
                     const s = repoModel ? repoModel.getState(index) : 0;
                     if (s === 0) return "Local only";
                     if (s === 1) return "GitHub";
+                    if (s === 3) return "Fork";
+                    if (s === 4) return "Local + Fork";
                     return "Local + GitHub";
                 }
                 font.pixelSize: Theme.fontSizeSmall
@@ -96,7 +212,45 @@ Rectangle {
                 color: repoModel && repoModel.getIsClean(index) ? Theme.success : Theme.warning
             }
 
+            Label {
+                visible: card.syncNote.length > 0
+                text: card.syncNote
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+                elide: Text.ElideRight
+                Layout.fillWidth: true
+            }
+
+            Label {
+                readonly property string oldName: repoModel ? repoModel.get_renamed_from(index) : ""
+                visible: oldName !== ""
+                text: "Renamed from " + oldName
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.warning
+                ToolTip.text: "The local remote still points at the old name; GitHub redirects it"
+                ToolTip.visible: renamedHover.hovered
+
+                HoverHandler {
+                    id: renamedHover
+                }
+            }
+
             Item { Layout.fillWidth: true }
+
+            Label {
+                visible: (card.commitStats.commits || 0) > 0
+                text: card.commitStats.commits + (card.commitStats.commits === 1 ? " commit" : " commits")
+                      + " this week · +" + card.commitStats.insertions + " −" + card.commitStats.deletions
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+                ToolTip.text: card.recentCommits.slice(0, 5)
+                    .map(c => c.id + "  " + c.message + " (" + c.author + ", " + c.when + ")").join("\n")
+                ToolTip.visible: commitsHover.hovered && ToolTip.text.length > 0
+
+                HoverHandler {
+                    id: commitsHover
+                }
+            }
         }
 
         RowLayout {
@@ -107,7 +261,7 @@ Rectangle {
                 visible: repoModel && repoModel.getHasGithub(index) && !repoModel.getHasLocal(index)
                 enabled: repoModel && !repoModel.getBusy(index)
                 text: "Clone"
-                onClicked: repoModel.cloneRepo(index)
+                onClicked: cloneMenu.open()
                 background: Rectangle {
                     radius: Theme.buttonRadius
                     color: parent.hovered ? Theme.primaryHover : Theme.primary
@@ -118,6 +272,43 @@ Rectangle {
                     horizontalAlignment: Text.AlignHCenter
                     verticalAlignment: Text.AlignVCenter
                 }
+
+                Menu {
+                    id: cloneMenu
+                    y: parent.height
+
+                    MenuItem {
+                        text: "Full clone"
+                        onTriggered: repoModel.cloneRepo(card.index)
+                    }
+                    MenuItem {
+                        text: "Partial clone…"
+                        onTriggered: cloneOptionsPopup.open()
+                    }
+                }
+            }
+
+            Button {
+                visible: repoModel && repoModel.getHasLocal(index) && !repoModel.getIsClean(index)
+                text: "Changes"
+                onClicked: {
+                    try {
+                        diffPopup.files = JSON.parse(repoModel.get_workdir_diff(index));
+                    } catch (e) {
+                        diffPopup.files = [];
+                    }
+                    diffPopup.open();
+                }
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : Theme.surfaceAlt
+                }
+                contentItem: Label {
+                    text: parent.text
+                    color: Theme.text
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
             }
 
             Button {
@@ -137,6 +328,39 @@ Rectangle {
                 }
             }
 
+            Button {
+                visible: repoModel && repoModel.get_is_fork(index) && repoModel.getHasLocal(index)
+                enabled: repoModel && !repoModel.getBusy(index) && !AppContext.offline
+                text: "Sync fork"
+                onClicked: syncMenu.open()
+                ToolTip.text: "Fast-forward the default branch from upstream"
+                ToolTip.visible: hovered
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : Theme.surfaceAlt
+                }
+                contentItem: Label {
+                    text: parent.text
+                    color: Theme.text
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+
+                Menu {
+                    id: syncMenu
+                    y: parent.height
+
+                    MenuItem {
+                        text: "Sync from upstream"
+                        onTriggered: repoModel.sync_fork(card.index, false)
+                    }
+                    MenuItem {
+                        text: "Sync and push to origin"
+                        onTriggered: repoModel.sync_fork(card.index, true)
+                    }
+                }
+            }
+
             Button {
                 visible: repoModel && repoModel.getHasGithub(index)
                 text: "Open"
@@ -156,6 +380,61 @@ Rectangle {
                 }
             }
 
+            Button {
+                visible: repoModel && (repoModel.getHasGithub(index) || repoModel.getHasLocal(index))
+                text: "README"
+                onClicked: {
+                    readmePopup.load();
+                    readmePopup.open();
+                }
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : Theme.surfaceAlt
+                }
+                contentItem: Label {
+                    text: parent.text
+                    color: Theme.text
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
+            Button {
+                visible: repoModel && repoModel.getHasGithub(index)
+                text: "Releases"
+                onClicked: AppContext.pageStack.push(AppContext.pageUrl("ReleasesPage"),
+                                                     { repoName: repoModel.getFullName(index) })
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : Theme.surfaceAlt
+                }
+                contentItem: Label {
+                    text: parent.text
+                    color: Theme.text
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
+            Button {
+                visible: repoModel && repoModel.getHasLocal(index)
+                text: "Checks"
+                onClicked: AppContext.pageStack.push(AppContext.pageUrl("RepoChecksPage"), {
+                    repoName: repoModel.getFullName(index),
+                    localPath: repoModel.getLocalPath(index)
+                })
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : Theme.surfaceAlt
+                }
+                contentItem: Label {
+                    text: parent.text
+                    color: Theme.text
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
             Button {
                 visible: repoModel && repoModel.getHasGithub(index) && projectModel && projectModel.row_count() > 0
                 text: "Add to project"
@@ -239,10 +518,462 @@ Rectangle {
                 Layout.alignment: Qt.AlignRight
             }
         }
+
+        RowLayout {
+            readonly property int progress: repoModel && repoModel.getBusy(index) && repoModel.opIndex === index
+                ? repoModel.opProgress : -1
+            visible: progress >= 0
+            Layout.fillWidth: true
+            spacing: Theme.spacingSm
+
+            ProgressBar {
+                from: 0
+                to: 100
+                value: Math.max(parent.progress, 0)
+                Layout.fillWidth: true
+            }
+
+            Label {
+                text: parent.progress + "%"
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+            }
+        }
     }
 
     TextField {
         id: clipboardHelper
         visible: false
     }
+
+    // Shallow, single-branch and sparse clone options for large repos
+    Popup {
+        id: cloneOptionsPopup
+
+        parent: Overlay.overlay
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.8, 460)
+        modal: true
+        padding: Theme.spacingMd
+
+        onOpened: {
+            depthField.value = 1;
+            singleBranchBox.checked = true;
+            sparseField.text = "";
+        }
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingSm
+
+            Label {
+                text: "Partial clone"
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+            }
+
+            RowLayout {
+                spacing: Theme.spacingSm
+
+                Label {
+                    text: "History depth"
+                    color: Theme.text
+                }
+
+                SpinBox {
+                    id: depthField
+                    from: 0
+                    to: 100000
+                    editable: true
+                }
+
+                Label {
+                    text: depthField.value === 0 ? "(all commits)" : "commits"
+                    font.pixelSize: Theme.fontSizeSmall
+                    color: Theme.textSecondary
+                }
+            }
+
+            CheckBox {
+                id: singleBranchBox
+                text: "Default branch only"
+            }
+
+            Label {
+                text: "Only check out these paths (one per line; empty for all)"
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+            }
+
+            TextArea {
+                id: sparseField
+                Layout.fillWidth: true
+                Layout.preferredHeight: 90
+                placeholderText: "apps/web\ndocs"
+                wrapMode: TextEdit.NoWrap
+            }
+
+            RowLayout {
+                Layout.fillWidth: true
+
+                Item { Layout.fillWidth: true }
+
+                Button {
+                    text: "Cancel"
+                    onClicked: cloneOptionsPopup.close()
+                }
+
+                Button {
+                    text: "Clone"
+                    onClicked: {
+                        repoModel.clone_repo_with_options(card.index, depthField.value,
+                                                          singleBranchBox.checked, sparseField.text);
+                        cloneOptionsPopup.close();
+                    }
+                }
+            }
+        }
+    }
+
+    // README and metadata preview; GitHub repos arrive via preview_changed
+    Popup {
+        id: readmePopup
+        property var preview: ({})
+
+        function load() {
+            preview = JSON.parse(repoModel.get_readme(index));
+        }
+
+        parent: Overlay.overlay
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.9, 900)
+        height: Math.min(parent.height * 0.85, 700)
+        modal: true
+        padding: Theme.spacingMd
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        Connections {
+            target: repoModel
+            enabled: readmePopup.opened
+            function onPreview_changed(fullName) {
+                if (fullName === readmePopup.preview.fullName) readmePopup.load();
+            }
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingSm
+
+            Label {
+                text: readmePopup.preview.fullName || ""
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+                elide: Text.ElideMiddle
+            }
+
+            Flow {
+                Layout.fillWidth: true
+                spacing: Theme.spacingXs
+                visible: (readmePopup.preview.topics || []).length > 0
+
+                Repeater {
+                    model: readmePopup.preview.topics || []
+
+                    delegate: Rectangle {
+                        required property var modelData
+                        width: topicLabel.implicitWidth + Theme.spacingSm * 2
+                        height: topicLabel.implicitHeight + 4
+                        radius: height / 2
+                        color: Theme.primary + "20"
+
+                        Label {
+                            id: topicLabel
+                            anchors.centerIn: parent
+                            text: modelData
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.primary
+                        }
+                    }
+                }
+            }
+
+            Label {
+                visible: (readmePopup.preview.languages || []).length > 0
+                text: (readmePopup.preview.languages || []).map(function(l) {
+                    return l.name + " " + l.percent.toFixed(1) + "%";
+                }).join(" · ")
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+                Layout.fillWidth: true
+                wrapMode: Text.Wrap
+            }
+
+            Label {
+                visible: text.length > 0
+                text: readmePopup.preview.loading ? "Loading README…"
+                    : readmePopup.preview.error ? readmePopup.preview.error
+                    : readmePopup.preview.readme ? "" : "No README"
+                color: readmePopup.preview.error ? Theme.error : Theme.textSecondary
+            }
+
+            ScrollView {
+                id: readmeScroll
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                clip: true
+                contentWidth: readmeScroll.availableWidth
+
+                Text {
+                    width: readmeScroll.availableWidth
+                    text: readmePopup.preview.readme || ""
+                    textFormat: Text.MarkdownText
+                    wrapMode: Text.Wrap
+                    color: Theme.text
+                    font.pixelSize: Theme.fontSizeNormal
+                    onLinkActivated: function(link) { Qt.openUrlExternally(link); }
+                }
+            }
+        }
+    }
+
+    // Health drill-down: each finding behind the score
+    Popup {
+        id: healthPopup
+
+        parent: Overlay.overlay
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.8, 520)
+        height: Math.min(parent.height * 0.8, healthColumn.implicitHeight + Theme.spacingMd * 2)
+        modal: true
+        padding: Theme.spacingMd
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        ScrollView {
+            id: healthScroll
+            anchors.fill: parent
+            clip: true
+            contentWidth: healthScroll.availableWidth
+
+            ColumnLayout {
+                id: healthColumn
+                width: healthScroll.availableWidth
+                spacing: Theme.spacingSm
+
+                Label {
+                    text: "Health " + (card.health ? card.health.score : "") + "/100"
+                    font.pixelSize: Theme.fontSizeMedium
+                    font.bold: true
+                    color: Theme.text
+                }
+
+                Label {
+                    visible: card.health !== null && !card.health.has_license
+                    text: "No LICENSE file"
+                    color: Theme.warning
+                }
+
+                Label {
+                    visible: card.health !== null && !card.health.has_ci
+                    text: "No CI configuration"
+                    color: Theme.warning
+                }
+
+                Label {
+                    visible: card.health !== null && card.health.stale_branches.length > 0
+                    text: "Stale branches"
+                    font.bold: true
+                    color: Theme.text
+                }
+
+                Repeater {
+                    model: card.health ? card.health.stale_branches : []
+                    delegate: Label {
+                        required property var modelData
+                        text: modelData.name + " · " + modelData.days + " days since last commit"
+                        color: Theme.textSecondary
+                        Layout.leftMargin: Theme.spacingMd
+                    }
+                }
+
+                Label {
+                    visible: card.health !== null && card.health.unpushed.length > 0
+                    text: "Unpushed commits"
+                    font.bold: true
+                    color: Theme.text
+                }
+
+                Repeater {
+                    model: card.health ? card.health.unpushed : []
+                    delegate: Label {
+                        required property var modelData
+                        text: modelData.name + " · " + modelData.ahead + " ahead of upstream"
+                        color: Theme.textSecondary
+                        Layout.leftMargin: Theme.spacingMd
+                    }
+                }
+
+                Label {
+                    visible: card.health !== null && card.health.large_untracked.length > 0
+                    text: "Large untracked files"
+                    font.bold: true
+                    color: Theme.text
+                }
+
+                Repeater {
+                    model: card.health ? card.health.large_untracked : []
+                    delegate: Label {
+                        required property var modelData
+                        text: modelData.path + " · " + card.formatBytes(modelData.bytes)
+                        color: Theme.textSecondary
+                        elide: Text.ElideMiddle
+                        Layout.fillWidth: true
+                        Layout.leftMargin: Theme.spacingMd
+                    }
+                }
+
+                Label {
+                    visible: card.health !== null && card.health.score === 100
+                    text: "Nothing to tidy up."
+                    color: Theme.success
+                }
+            }
+        }
+    }
+
+    // Uncommitted changes, rendered from get_workdir_diff's structured hunks
+    Popup {
+        id: diffPopup
+        property var files: []
+
+        parent: Overlay.overlay
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.9, 900)
+        height: Math.min(parent.height * 0.85, 700)
+        modal: true
+        padding: Theme.spacingMd
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        ScrollView {
+            id: diffScroll
+            anchors.fill: parent
+            clip: true
+            contentWidth: diffScroll.availableWidth
+
+            ColumnLayout {
+                width: diffScroll.availableWidth
+                spacing: Theme.spacingMd
+
+                Label {
+                    visible: diffPopup.files.length === 0
+                    text: "No uncommitted changes"
+                    color: Theme.textSecondary
+                }
+
+                Repeater {
+                    model: diffPopup.files
+
+                    delegate: ColumnLayout {
+                        id: fileBlock
+                        required property var modelData
+                        Layout.fillWidth: true
+                        spacing: 0
+
+                        Label {
+                            text: (fileBlock.modelData.old_path ? fileBlock.modelData.old_path + " → " : "")
+                                  + fileBlock.modelData.path + "  (" + fileBlock.modelData.status
+                                  + ", +" + fileBlock.modelData.insertions + " −" + fileBlock.modelData.deletions + ")"
+                            font.bold: true
+                            color: Theme.text
+                            Layout.fillWidth: true
+                            elide: Text.ElideMiddle
+                        }
+
+                        Label {
+                            visible: fileBlock.modelData.binary || fileBlock.modelData.too_large || fileBlock.modelData.truncated
+                            text: fileBlock.modelData.binary ? "Binary file not shown"
+                                : fileBlock.modelData.too_large ? "File too large to show"
+                                : "Diff truncated"
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textMuted
+                        }
+
+                        Repeater {
+                            model: fileBlock.modelData.hunks
+
+                            delegate: ColumnLayout {
+                                id: hunkBlock
+                                required property var modelData
+                                Layout.fillWidth: true
+                                spacing: 0
+
+                                Label {
+                                    text: "@@ -" + hunkBlock.modelData.old_start + "," + hunkBlock.modelData.old_lines
+                                          + " +" + hunkBlock.modelData.new_start + "," + hunkBlock.modelData.new_lines + " @@"
+                                    font.family: "Consolas, Monaco, monospace"
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    color: Theme.textMuted
+                                    topPadding: Theme.spacingXs
+                                }
+
+                                Repeater {
+                                    model: hunkBlock.modelData.lines
+
+                                    delegate: Rectangle {
+                                        required property var modelData
+                                        Layout.fillWidth: true
+                                        implicitHeight: lineText.implicitHeight
+                                        color: modelData.kind === "added" ? Theme.success + "20"
+                                             : modelData.kind === "removed" ? Theme.error + "20" : "transparent"
+
+                                        Text {
+                                            id: lineText
+                                            width: parent.width
+                                            text: (modelData.old_lineno || "").toString().padStart(5) + " "
+                                                  + (modelData.new_lineno || "").toString().padStart(5) + " "
+                                                  + (modelData.kind === "added" ? "+" : modelData.kind === "removed" ? "-" : " ")
+                                                  + modelData.text
+                                            font.family: "Consolas, Monaco, monospace"
+                                            font.pixelSize: Theme.fontSizeSmall
+                                            color: Theme.text
+                                            textFormat: Text.PlainText
+                                            wrapMode: Text.NoWrap
+                                            elide: Text.ElideRight
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,49 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import ".."
+
+// Open security alert count, colored by the most severe alert. Hidden when
+// there are none.
+Rectangle {
+    id: badge
+
+    property int count: 0
+    // "critical", "high", "medium", "low" or ""
+    property string severity: ""
+
+    readonly property color tint: severity === "critical" || severity === "high"
+        ? Theme.error
+        : severity === "medium" ? Theme.warning : Theme.info
+
+    visible: count > 0
+    implicitWidth: badgeRow.implicitWidth + Theme.spacingSm * 2
+    implicitHeight: badgeRow.implicitHeight + 4
+    radius: height / 2
+    color: severity === "critical" || severity === "high"
+        ? Theme.errorBg
+        : severity === "medium" ? Theme.warningBg : Theme.infoBg
+
+    RowLayout {
+        id: badgeRow
+        anchors.centerIn: parent
+        spacing: 4
+
+        Label {
+            text: Icons.warning
+            font.family: Icons.family
+            font.pixelSize: Theme.fontSizeSmall
+            color: badge.tint
+        }
+
+        Label {
+            text: badge.count + (badge.count === 1 ? " alert" : " alerts")
+            font.pixelSize: Theme.fontSizeSmall
+            color: badge.tint
+        }
+    }
+
+    HoverHandler { id: badgeHover }
+    ToolTip.text: "Open Dependabot and code scanning alerts (most severe: " + badge.severity + ")"
+    ToolTip.visible: badgeHover.hovered
+}
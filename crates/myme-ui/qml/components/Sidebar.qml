@@ -25,8 +25,12 @@ Rectangle {
         ListElement { title: "Notes"; page: "NotePage"; icon: "" }
         ListElement { title: "Gmail"; page: "GmailPage"; icon: "" }
         ListElement { title: "Calendar"; page: "CalendarPage"; icon: "" }
+        ListElement { title: "Feeds"; page: "FeedsPage"; icon: "" }
+        ListElement { title: "Bookmarks"; page: "BookmarksPage"; icon: "" }
+        ListElement { title: "Clipboard"; page: "ClipboardPage"; icon: "" }
         ListElement { title: "Projects"; page: "ProjectsPage"; icon: "" }
         ListElement { title: "Repos"; page: "RepoPage"; icon: "" }
+        ListElement { title: "GitHub Inbox"; page: "NotificationsPage"; icon: "" }
         ListElement { title: "Weather"; page: "WeatherPage"; icon: "" }
         ListElement { title: "Dev Tools"; page: "DevToolsPage"; icon: "" }
     }
@@ -38,8 +42,12 @@ Rectangle {
             "NotePage": Icons.notePencil,
             "GmailPage": Icons.envelopeSimple,
             "CalendarPage": Icons.calendarBlank,
+            "FeedsPage": Icons.article,
+            "BookmarksPage": Icons.pushPin,
+            "ClipboardPage": Icons.copy,
             "ProjectsPage": Icons.squaresFour,
             "RepoPage": Icons.gitBranch,
+            "NotificationsPage": Icons.githubLogo,
             "WeatherPage": Icons.cloud_sun,
             "DevToolsPage": Icons.wrench,
             "SettingsPage": Icons.gearSix
@@ -261,7 +269,9 @@ Rectangle {
                     anchors.centerIn: parent
                     font.family: Icons.family
                     font.pixelSize: 16
-                    text: Theme.mode === "dark" ? Icons.moon : (Theme.mode === "light" ? Icons.sun : Icons.circleHalf)
+                    text: Theme.mode === "dark" ? Icons.moon
+                        : (Theme.mode === "light" ? Icons.sun
+                        : (Theme.mode === "scheduled" ? Icons.clock : Icons.circleHalf))
                     color: Theme.textSecondary
                 }
 
@@ -271,9 +281,9 @@ Rectangle {
                     hoverEnabled: true
                     cursorShape: Qt.PointingHandCursor
                     onClicked: {
-                        if (Theme.mode === "dark") Theme.mode = "light";
-                        else if (Theme.mode === "light") Theme.mode = "auto";
-                        else Theme.mode = "dark";
+                        if (Theme.mode === "dark") AppContext.setThemeMode("light");
+                        else if (Theme.mode === "light") AppContext.setThemeMode("auto");
+                        else AppContext.setThemeMode("dark");
                     }
                 }
 
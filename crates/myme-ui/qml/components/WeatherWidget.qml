@@ -27,6 +27,8 @@ Rectangle {
     property int precipChance: 0
     property string sunrise: ""
     property string sunset: ""
+    // "5° warmer than this time yesterday"; empty without a reading from then
+    property string comparison: ""
 
     signal clicked()
     signal refreshRequested()
@@ -200,6 +202,15 @@ Rectangle {
                     color: Theme.textMuted
                     opacity: root.isStale ? 0.6 : 1.0
                 }
+
+                // Compared with yesterday
+                Text {
+                    visible: root.hasData && root.comparison !== ""
+                    font.pixelSize: Theme.fontSizeSmall
+                    text: root.comparison
+                    color: Theme.textMuted
+                    opacity: root.isStale ? 0.6 : 1.0
+                }
             }
         }
 
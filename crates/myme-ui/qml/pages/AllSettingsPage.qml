@@ -0,0 +1,232 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: allSettingsPage
+    title: "All settings"
+
+    property var settings: []
+    property var validation: ({ errors: [], warnings: [] })
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    SettingsModel {
+        id: settingsModel
+        onSettings_changed: allSettingsPage.reload()
+    }
+
+    // Follow edits made to the file while the page is open
+    Timer {
+        interval: 2000
+        running: true
+        repeat: true
+        onTriggered: settingsModel.poll_config()
+    }
+
+    Component.onCompleted: settingsModel.load_settings()
+
+    function reload() {
+        try {
+            settings = JSON.parse(settingsModel.get_settings());
+            validation = JSON.parse(settingsModel.get_validation());
+        } catch (e) {
+            settings = [];
+            validation = { errors: [], warnings: [] };
+        }
+    }
+
+    function issueFor(key) {
+        const all = validation.errors.concat(validation.warnings);
+        for (let i = 0; i < all.length; i++) {
+            if (all[i].field === key) return all[i];
+        }
+        return null;
+    }
+
+    function sectionName(key) {
+        const section = key.split(".")[0];
+        return section.charAt(0).toUpperCase() + section.slice(1);
+    }
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            ToolButton {
+                text: Icons.caretLeft
+                font.family: Icons.family
+                font.pixelSize: 18
+                onClicked: AppContext.pageStack.pop()
+                ToolTip.text: "Back to Settings"
+                ToolTip.visible: hovered
+            }
+
+            Label {
+                text: "All settings"
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+            }
+
+            Button {
+                text: "Revert"
+                enabled: settingsModel.dirty
+                onClicked: settingsModel.revert_settings()
+            }
+
+            Button {
+                text: "Save"
+                enabled: settingsModel.dirty && settingsModel.valid
+                onClicked: settingsModel.save_settings()
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.primaryHover : Theme.primary
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+                contentItem: Label {
+                    text: parent.text
+                    color: Theme.primaryText
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+        }
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: Theme.spacingLg
+        spacing: Theme.spacingMd
+
+        Label {
+            visible: settingsModel.error_message.length > 0
+            text: settingsModel.error_message
+            font.pixelSize: Theme.fontSizeSmall
+            color: Theme.error
+            wrapMode: Text.WordWrap
+            Layout.fillWidth: true
+        }
+
+        Label {
+            visible: allSettingsPage.validation.errors.length > 0
+            text: allSettingsPage.validation.errors.length + " problem(s) to fix before saving"
+            font.pixelSize: Theme.fontSizeSmall
+            color: Theme.error
+            Layout.fillWidth: true
+        }
+
+        ListView {
+            id: settingsList
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+            spacing: Theme.spacingSm
+            model: allSettingsPage.settings
+            ScrollBar.vertical: ScrollBar {}
+
+            delegate: ColumnLayout {
+                id: row
+                required property var modelData
+                required property int index
+                readonly property var issue: allSettingsPage.issueFor(modelData.key)
+                readonly property bool locked: modelData.overridden_by !== null
+                readonly property bool newSection: index === 0
+                    || allSettingsPage.sectionName(allSettingsPage.settings[index - 1].key)
+                        !== allSettingsPage.sectionName(modelData.key)
+
+                width: settingsList.width - Theme.spacingMd
+                spacing: Theme.spacingXs
+
+                Label {
+                    visible: row.newSection
+                    text: allSettingsPage.sectionName(row.modelData.key)
+                    font.pixelSize: Theme.fontSizeMedium
+                    font.bold: true
+                    color: Theme.text
+                    topPadding: row.index === 0 ? 0 : Theme.spacingMd
+                }
+
+                RowLayout {
+                    Layout.fillWidth: true
+                    spacing: Theme.spacingMd
+
+                    Label {
+                        text: row.modelData.label
+                        font.pixelSize: Theme.fontSizeNormal
+                        color: Theme.text
+                        Layout.preferredWidth: 240
+                        elide: Text.ElideRight
+                    }
+
+                    Switch {
+                        visible: row.modelData.type === "bool"
+                        enabled: !row.locked
+                        checked: row.modelData.value === true
+                        onToggled: settingsModel.set_flag(row.modelData.key, checked)
+                    }
+
+                    ComboBox {
+                        visible: row.modelData.type === "choice"
+                        enabled: !row.locked
+                        model: row.modelData.type === "choice"
+                            ? (row.modelData.optional ? [""] : []).concat(row.modelData.options) : []
+                        currentIndex: model.indexOf(row.modelData.value === null ? "" : row.modelData.value)
+                        displayText: currentText.length > 0 ? currentText : "Not set"
+                        onActivated: index => settingsModel.set_text(row.modelData.key, model[index])
+                        Layout.preferredWidth: 220
+                    }
+
+                    TextField {
+                        visible: row.modelData.type === "int" || row.modelData.type === "text"
+                            || row.modelData.type === "list"
+                        enabled: !row.locked
+                        text: row.modelData.value === null ? ""
+                            : row.modelData.type === "list" ? row.modelData.value.join(", ")
+                            : String(row.modelData.value)
+                        placeholderText: row.modelData.optional ? "Not set" : ""
+                        echoMode: row.modelData.secret ? TextInput.PasswordEchoOnEdit : TextInput.Normal
+                        validator: row.modelData.type === "int" ? intValidator : null
+                        Layout.fillWidth: true
+                        onEditingFinished: {
+                            if (row.modelData.type === "int") {
+                                settingsModel.set_number(row.modelData.key, parseInt(text));
+                            } else if (row.modelData.type === "list") {
+                                settingsModel.set_list(row.modelData.key, text);
+                            } else {
+                                settingsModel.set_text(row.modelData.key, text);
+                            }
+                        }
+                    }
+                }
+
+                Label {
+                    visible: row.locked || row.issue !== null
+                    text: row.issue !== null ? row.issue.message
+                        : "Set in " + row.modelData.overridden_by + "; change it there"
+                    font.pixelSize: Theme.fontSizeSmall
+                    color: row.issue === null ? Theme.textSecondary
+                        : allSettingsPage.validation.errors.indexOf(row.issue) >= 0 ? Theme.error : Theme.warning
+                    wrapMode: Text.WordWrap
+                    Layout.fillWidth: true
+                    Layout.leftMargin: 240 + Theme.spacingMd
+                }
+            }
+        }
+    }
+
+    IntValidator {
+        id: intValidator
+        bottom: 0
+    }
+}
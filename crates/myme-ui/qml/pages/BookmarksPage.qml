@@ -0,0 +1,352 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: bookmarksPage
+    title: "Bookmarks"
+
+    // Selected tag; empty shows all bookmarks
+    property string selectedTag: ""
+    property bool showArchived: false
+    property var bookmarks: []
+    property var tags: []
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    BookmarkModel {
+        id: bookmarkModel
+    }
+
+    Timer {
+        id: bookmarkPollTimer
+        interval: 100
+        running: bookmarkModel.loading
+        repeat: true
+        onTriggered: bookmarkModel.poll_channel()
+    }
+
+    function reload() {
+        try {
+            bookmarksPage.tags = JSON.parse(bookmarkModel.get_tags());
+            bookmarksPage.bookmarks = JSON.parse(bookmarkModel.get_bookmarks(
+                bookmarksPage.selectedTag, searchField.text, bookmarksPage.showArchived));
+        } catch (e) {
+            bookmarksPage.tags = [];
+            bookmarksPage.bookmarks = [];
+        }
+    }
+
+    function timeAgo(iso) {
+        const minutes = Math.floor((Date.now() - new Date(iso).getTime()) / 60000);
+        if (minutes < 60) return Math.max(minutes, 0) + "m ago";
+        if (minutes < 60 * 24) return Math.floor(minutes / 60) + "h ago";
+        return Math.floor(minutes / (60 * 24)) + "d ago";
+    }
+
+    onSelectedTagChanged: reload()
+    onShowArchivedChanged: reload()
+
+    Connections {
+        target: bookmarkModel
+        function onBookmarks_changed() { bookmarksPage.reload() }
+    }
+
+    Component.onCompleted: bookmarkModel.reload()
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            Label {
+                text: bookmarkModel.count > 0 ? "Bookmarks (" + bookmarkModel.count + ")" : "Bookmarks"
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+                leftPadding: Theme.spacingMd
+            }
+
+            TextField {
+                id: searchField
+                placeholderText: "Search"
+                Layout.preferredWidth: 220
+                onTextChanged: bookmarksPage.reload()
+            }
+
+            CheckBox {
+                text: "Archive (" + bookmarkModel.archived_count + ")"
+                checked: bookmarksPage.showArchived
+                onToggled: bookmarksPage.showArchived = checked
+            }
+
+            Item { width: Theme.spacingSm }
+        }
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: Theme.spacingLg
+        spacing: Theme.spacingMd
+
+        // Add by URL
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: Theme.spacingSm
+
+            TextField {
+                id: urlField
+                Layout.fillWidth: true
+                placeholderText: "Save a link (https://...)"
+                onAccepted: saveButton.clicked()
+            }
+
+            TextField {
+                id: tagsField
+                Layout.preferredWidth: 200
+                placeholderText: "Tags"
+                onAccepted: saveButton.clicked()
+            }
+
+            Button {
+                id: saveButton
+                text: "Save"
+                enabled: urlField.text.trim().length > 0
+                onClicked: {
+                    if (bookmarkModel.add_url(urlField.text, tagsField.text)) {
+                        urlField.text = "";
+                        tagsField.text = "";
+                    }
+                }
+            }
+
+            BusyIndicator {
+                running: bookmarkModel.loading
+                visible: running
+                Layout.preferredWidth: 24
+                Layout.preferredHeight: 24
+            }
+        }
+
+        Label {
+            visible: bookmarkModel.error_message.length > 0
+            text: bookmarkModel.error_message
+            font.pixelSize: Theme.fontSizeSmall
+            color: Theme.error
+            wrapMode: Text.WordWrap
+            Layout.fillWidth: true
+        }
+
+        // Tag filter
+        Flow {
+            visible: bookmarksPage.tags.length > 0
+            Layout.fillWidth: true
+            spacing: Theme.spacingXs
+
+            Repeater {
+                model: [{ tag: "", count: -1 }].concat(bookmarksPage.tags)
+
+                delegate: Rectangle {
+                    id: tagChip
+                    required property var modelData
+                    readonly property bool selected: bookmarksPage.selectedTag === modelData.tag
+                    width: tagLabel.implicitWidth + Theme.spacingMd * 2
+                    height: 28
+                    radius: 14
+                    color: selected ? Theme.primary : (tagMouse.containsMouse ? Theme.surfaceHover : Theme.surface)
+
+                    Label {
+                        id: tagLabel
+                        anchors.centerIn: parent
+                        text: tagChip.modelData.tag === "" ? "All" : "#" + tagChip.modelData.tag + " " + tagChip.modelData.count
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: tagChip.selected ? Theme.primaryText : Theme.text
+                    }
+
+                    MouseArea {
+                        id: tagMouse
+                        anchors.fill: parent
+                        hoverEnabled: true
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: bookmarksPage.selectedTag = tagChip.modelData.tag
+                    }
+                }
+            }
+        }
+
+        Rectangle {
+            visible: bookmarksPage.bookmarks.length === 0
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            color: Theme.surface
+            border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+            border.width: 1
+            radius: Theme.cardRadius
+
+            ColumnLayout {
+                anchors.centerIn: parent
+                spacing: Theme.spacingMd
+
+                Label {
+                    text: Icons.pushPin
+                    font.family: Icons.family
+                    font.pixelSize: 64
+                    color: Theme.textSecondary
+                    Layout.alignment: Qt.AlignHCenter
+                }
+
+                Label {
+                    text: bookmarksPage.showArchived ? "Nothing archived yet"
+                        : "Save a link here or type \"bookmark <url> #tag\" in quick capture"
+                    font.pixelSize: Theme.fontSizeMedium
+                    color: Theme.textSecondary
+                    Layout.alignment: Qt.AlignHCenter
+                }
+            }
+        }
+
+        ListView {
+            id: bookmarkList
+            visible: bookmarksPage.bookmarks.length > 0
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+            spacing: Theme.spacingSm
+            model: bookmarksPage.bookmarks
+
+            delegate: Rectangle {
+                id: bookmarkRow
+                required property var modelData
+                width: bookmarkList.width
+                height: rowLayout.implicitHeight + Theme.spacingSm * 2
+                color: rowMouse.containsMouse ? Theme.surfaceHover : Theme.surface
+                border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                border.width: 1
+                radius: Theme.cardRadius
+
+                MouseArea {
+                    id: rowMouse
+                    anchors.fill: parent
+                    hoverEnabled: true
+                    cursorShape: Qt.PointingHandCursor
+                    onClicked: Qt.openUrlExternally(bookmarkRow.modelData.url)
+                }
+
+                RowLayout {
+                    id: rowLayout
+                    anchors.fill: parent
+                    anchors.margins: Theme.spacingSm
+                    spacing: Theme.spacingSm
+
+                    Image {
+                        visible: !!bookmarkRow.modelData.imageUrl && status === Image.Ready
+                        source: AppContext.imageSource(bookmarkRow.modelData.imageUrl)
+                        asynchronous: true
+                        fillMode: Image.PreserveAspectCrop
+                        Layout.preferredWidth: 96
+                        Layout.preferredHeight: 54
+                        Layout.alignment: Qt.AlignTop
+                    }
+
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 2
+
+                        Label {
+                            text: bookmarkRow.modelData.title
+                            font.pixelSize: Theme.fontSizeNormal
+                            font.bold: true
+                            color: Theme.text
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+
+                        Label {
+                            visible: text.length > 0
+                            text: bookmarkRow.modelData.excerpt
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textSecondary
+                            wrapMode: Text.WordWrap
+                            maximumLineCount: 2
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+
+                        Label {
+                            text: (bookmarkRow.modelData.siteName || bookmarkRow.modelData.url)
+                                + " · " + bookmarksPage.timeAgo(bookmarkRow.modelData.createdAt)
+                                + bookmarkRow.modelData.tags.map(t => "  #" + t).join("")
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textMuted
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+                    }
+
+                    ToolButton {
+                        text: Icons.tag
+                        font.family: Icons.family
+                        onClicked: {
+                            tagDialog.bookmarkId = bookmarkRow.modelData.id;
+                            tagDialogField.text = bookmarkRow.modelData.tags.join(" ");
+                            tagDialog.open();
+                        }
+                        ToolTip.text: "Edit tags"
+                        ToolTip.visible: hovered
+                    }
+
+                    ToolButton {
+                        visible: !bookmarkRow.modelData.fetched
+                        text: Icons.arrowsClockwise
+                        font.family: Icons.family
+                        enabled: !AppContext.offline
+                        onClicked: bookmarkModel.refetch(bookmarkRow.modelData.id)
+                        ToolTip.text: "Fetch title"
+                        ToolTip.visible: hovered
+                    }
+
+                    ToolButton {
+                        text: Icons.archiveBox
+                        font.family: Icons.family
+                        onClicked: bookmarkModel.set_archived(bookmarkRow.modelData.id, !bookmarkRow.modelData.archived)
+                        ToolTip.text: bookmarkRow.modelData.archived ? "Move to reading list" : "Archive"
+                        ToolTip.visible: hovered
+                    }
+
+                    ToolButton {
+                        text: Icons.trash
+                        font.family: Icons.family
+                        onClicked: bookmarkModel.delete_bookmark(bookmarkRow.modelData.id)
+                        ToolTip.text: "Delete"
+                        ToolTip.visible: hovered
+                    }
+                }
+            }
+        }
+    }
+
+    Dialog {
+        id: tagDialog
+        property var bookmarkId: 0
+        title: "Tags"
+        anchors.centerIn: parent
+        modal: true
+        standardButtons: Dialog.Ok | Dialog.Cancel
+        onAccepted: bookmarkModel.set_tags(tagDialog.bookmarkId, tagDialogField.text)
+
+        TextField {
+            id: tagDialogField
+            width: 280
+            placeholderText: "rust later reading"
+        }
+    }
+}
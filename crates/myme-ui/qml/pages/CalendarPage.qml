@@ -8,6 +8,26 @@ Page {
     id: calendarPage
     title: "Calendar"
 
+    property string searchQuery: ""
+    property var searchIndices: []
+    readonly property bool searching: searchQuery.trim().length > 0
+
+    // "10 min", "1 h", "1 day" for a reminder's minutes before the event
+    function reminderLabel(minutes) {
+        if (minutes === 0) return "at start"
+        if (minutes % 1440 === 0) return (minutes / 1440) + (minutes === 1440 ? " day" : " days")
+        if (minutes % 60 === 0) return (minutes / 60) + " h"
+        return minutes + " min"
+    }
+
+    function runSearch() {
+        try {
+            searchIndices = searching ? JSON.parse(calendarModel.search(searchQuery)) : []
+        } catch (e) {
+            searchIndices = []
+        }
+    }
+
     CalendarModel {
         id: calendarModel
         Component.onCompleted: {
@@ -16,6 +36,23 @@ Page {
                 calendarModel.fetch_events()
             }
         }
+        // A refresh may have cached new matches
+        onEvents_changed: if (calendarPage.searching) calendarPage.runSearch()
+        onCalendars_changed: calendarListModel.refresh()
+    }
+
+    // Which calendars are shown, and in what color
+    CalendarListModel {
+        id: calendarListModel
+        // Bumped on every reload so delegate bindings re-read the model
+        property int revision: 0
+        Component.onCompleted: refresh()
+        onCalendars_changed: revision++
+        onVisibility_changed: (calendarId, visible) => {
+            calendarModel.reload_calendars()
+            // Hidden calendars are not synced, so catch up on ones shown again
+            if (visible && !calendarModel.loading) calendarModel.fetch_events()
+        }
     }
 
     Timer {
@@ -26,6 +63,18 @@ Page {
         onTriggered: calendarModel.poll_channel()
     }
 
+    // People for attendee autocomplete
+    ContactsModel {
+        id: contactsModel
+    }
+
+    Timer {
+        interval: 100
+        running: contactsModel.loading
+        repeat: true
+        onTriggered: contactsModel.poll_channel()
+    }
+
     background: Rectangle {
         color: Theme.background
     }
@@ -65,6 +114,80 @@ Page {
                 }
             }
 
+            ToolButton {
+                text: Icons.list
+                font.family: Icons.family
+                font.pixelSize: 18
+                enabled: calendarModel.authenticated
+                onClicked: calendarsDialog.open()
+                ToolTip.text: "Calendars"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    font.family: Icons.family
+                    font.pixelSize: 18
+                    color: parent.enabled ? Theme.text : Theme.textMuted
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
+            // Travel mode: pinned display zone
+            ToolButton {
+                text: Icons.clock
+                font.family: Icons.family
+                font.pixelSize: 18
+                onClicked: timeZoneDialog.open()
+                ToolTip.text: calendarModel.display_timezone
+                              ? "Showing times in " + calendarModel.display_timezone
+                              : "Showing times in this device's zone"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    font.family: Icons.family
+                    font.pixelSize: 18
+                    color: calendarModel.display_timezone ? Theme.primary : Theme.text
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
+            ToolButton {
+                text: Icons.plus
+                font.family: Icons.family
+                font.pixelSize: 18
+                enabled: calendarModel.authenticated
+                onClicked: newEventDialog.open()
+                ToolTip.text: "New event"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    font.family: Icons.family
+                    font.pixelSize: 18
+                    color: parent.enabled ? Theme.text : Theme.textMuted
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
             Button {
                 text: calendarModel.loading ? "Refreshing..." : "Refresh"
                 enabled: !calendarModel.loading && calendarModel.authenticated
@@ -153,9 +276,61 @@ Page {
             width: calendarScroll.viewport.width
             spacing: Theme.spacingMd
 
-            // Today section header
+            // Search bar
+            Rectangle {
+                Layout.fillWidth: true
+                height: 44
+                color: Theme.inputBg
+                border.color: searchField.activeFocus ? Theme.primary : Theme.inputBorder
+                border.width: searchField.activeFocus ? 2 : 1
+                radius: Theme.inputRadius
+
+                RowLayout {
+                    anchors.fill: parent
+                    anchors.margins: Theme.spacingSm
+                    spacing: Theme.spacingSm
+
+                    TextField {
+                        id: searchField
+                        Layout.fillWidth: true
+                        placeholderText: "Search events, places, people..."
+                        color: Theme.text
+                        placeholderTextColor: Theme.textMuted
+                        onTextChanged: {
+                            calendarPage.searchQuery = text
+                            searchDebounce.restart()
+                        }
+
+                        background: Rectangle {
+                            color: "transparent"
+                        }
+                    }
+
+                    Label {
+                        visible: calendarPage.searching
+                        text: Icons.x
+                        font.family: Icons.family
+                        font.pixelSize: 14
+                        color: Theme.textSecondary
+
+                        MouseArea {
+                            anchors.fill: parent
+                            cursorShape: Qt.PointingHandCursor
+                            onClicked: searchField.text = ""
+                        }
+                    }
+                }
+
+                Timer {
+                    id: searchDebounce
+                    interval: 200
+                    onTriggered: calendarPage.runSearch()
+                }
+            }
+
+            // Section header
             Label {
-                text: "Upcoming Events"
+                text: calendarPage.searching ? "Search Results" : "Upcoming Events"
                 font.pixelSize: Theme.fontSizeMedium
                 font.bold: true
                 color: Theme.text
@@ -163,11 +338,12 @@ Page {
 
             // Events
             Repeater {
-                model: calendarModel.event_count
+                model: calendarPage.searching ? calendarPage.searchIndices : calendarModel.event_count
 
                 Rectangle {
                     id: eventDelegate
                     required property int index
+                    required property var modelData
                     Layout.fillWidth: true
                     height: eventContent.implicitHeight + Theme.spacingMd * 2
                     radius: Theme.cardRadius
@@ -188,7 +364,9 @@ Page {
 
                     property var eventData: {
                         try {
-                            return JSON.parse(calendarModel.get_event(index))
+                            return JSON.parse(calendarPage.searching
+                                              ? calendarModel.get_search_result(modelData)
+                                              : calendarModel.get_event(index))
                         } catch (e) {
                             return {}
                         }
@@ -205,7 +383,7 @@ Page {
                             Layout.preferredWidth: 60
                             Layout.preferredHeight: 50
                             radius: Theme.cardRadius
-                            color: Theme.primary + "20"
+                            color: (eventData.color || Theme.primary) + "20"
 
                             ColumnLayout {
                                 anchors.centerIn: parent
@@ -215,12 +393,11 @@ Page {
                                     text: {
                                         if (!eventData.start) return "--:--"
                                         if (eventData.allDay) return "All"
-                                        const d = new Date(eventData.start)
-                                        return d.toLocaleTimeString([], {hour: '2-digit', minute: '2-digit'})
+                                        return eventData.startTime
                                     }
                                     font.pixelSize: Theme.fontSizeNormal
                                     font.bold: true
-                                    color: Theme.primary
+                                    color: eventData.color || Theme.primary
                                     Layout.alignment: Qt.AlignHCenter
                                 }
 
@@ -228,12 +405,22 @@ Page {
                                     visible: eventData.allDay
                                     text: "Day"
                                     font.pixelSize: Theme.fontSizeSmall
-                                    color: Theme.primary
+                                    color: eventData.color || Theme.primary
                                     Layout.alignment: Qt.AlignHCenter
                                 }
                             }
                         }
 
+                        // Time block for a kanban task: ticking it completes the task
+                        CheckBox {
+                            visible: !!eventData.taskId
+                            checked: !!eventData.taskDone
+                            onToggled: calendarModel.set_event_task_done(eventData.id, checked)
+                            ToolTip.visible: hovered
+                            ToolTip.text: checked ? "Reopen task" : "Complete task"
+                            ToolTip.delay: 500
+                        }
+
                         ColumnLayout {
                             Layout.fillWidth: true
                             spacing: 4
@@ -259,17 +446,16 @@ Page {
                                 }
 
                                 Label {
+                                    // Times come formatted in the display zone
                                     text: {
                                         if (!eventData.start) return ""
-                                        const start = new Date(eventData.start)
-                                        const end = new Date(eventData.end)
                                         if (eventData.allDay) {
-                                            return start.toLocaleDateString()
+                                            return eventData.dateLabel
                                         }
-                                        return start.toLocaleDateString() + " • " +
-                                               start.toLocaleTimeString([], {hour: '2-digit', minute: '2-digit'}) +
-                                               " - " +
-                                               end.toLocaleTimeString([], {hour: '2-digit', minute: '2-digit'})
+                                        let label = eventData.dateLabel + " • " + eventData.startTime + " - " + eventData.endTime
+                                        if (eventData.eventTimeZone)
+                                            label += " (" + eventData.eventStartTime + " " + eventData.eventTimeZone + ")"
+                                        return label
                                     }
                                     font.pixelSize: Theme.fontSizeSmall
                                     color: Theme.textSecondary
@@ -295,10 +481,70 @@ Page {
                                     Layout.fillWidth: true
                                 }
                             }
+
+                            // Popup alerts, as Google would fire them; click to change
+                            Label {
+                                text: {
+                                    const minutes = eventData.reminders || []
+                                    let label = minutes.length === 0
+                                        ? "No reminders"
+                                        : "Remind " + minutes.map(calendarPage.reminderLabel).join(", ")
+                                    if (eventData.remindersDefault) label += " (calendar default)"
+                                    return label
+                                }
+                                font.pixelSize: Theme.fontSizeSmall
+                                color: reminderArea.containsMouse ? Theme.primary : Theme.textMuted
+
+                                MouseArea {
+                                    id: reminderArea
+                                    anchors.fill: parent
+                                    hoverEnabled: true
+                                    cursorShape: Qt.PointingHandCursor
+                                    onClicked: reminderMenu.popup()
+                                }
+
+                                Menu {
+                                    id: reminderMenu
+
+                                    Repeater {
+                                        model: [
+                                            { text: "Calendar default", value: "null" },
+                                            { text: "None", value: "[]" },
+                                            { text: "At start", value: "[0]" },
+                                            { text: "10 minutes before", value: "[10]" },
+                                            { text: "30 minutes before", value: "[30]" },
+                                            { text: "1 hour before", value: "[60]" },
+                                            { text: "1 day before", value: "[1440]" }
+                                        ]
+
+                                        MenuItem {
+                                            text: modelData.text
+                                            onTriggered: calendarModel.set_event_reminders(eventData.id, modelData.value)
+                                        }
+                                    }
+
+                                    MenuSeparator {
+                                        visible: !!eventData.location
+                                        height: visible ? implicitHeight : 0
+                                    }
+
+                                    // Local only: when to leave, from the current location
+                                    MenuItem {
+                                        text: "Leave-by alert"
+                                        checkable: true
+                                        checked: eventData.travelAlerts !== false
+                                        visible: !!eventData.location
+                                        height: visible ? implicitHeight : 0
+                                        onTriggered: calendarModel.set_event_travel_alerts(eventData.id, checked)
+                                    }
+                                }
+                            }
                         }
                     }
 
                     MouseArea {
+                        // Below the card's controls so they get their clicks
+                        z: -1
                         anchors.fill: parent
                         cursorShape: Qt.PointingHandCursor
                         onClicked: {
@@ -311,7 +557,9 @@ Page {
 
             // Empty state
             Item {
-                visible: calendarModel.event_count === 0 && !calendarModel.loading
+                visible: !calendarModel.loading && (calendarPage.searching
+                                                    ? calendarPage.searchIndices.length === 0
+                                                    : calendarModel.event_count === 0)
                 Layout.fillWidth: true
                 Layout.preferredHeight: 200
 
@@ -328,14 +576,16 @@ Page {
                     }
 
                     Label {
-                        text: "No upcoming events"
+                        text: calendarPage.searching ? "No matching events" : "No upcoming events"
                         font.pixelSize: Theme.fontSizeMedium
                         color: Theme.textSecondary
                         Layout.alignment: Qt.AlignHCenter
                     }
 
                     Label {
-                        text: "Your schedule is clear for the next 7 days"
+                        text: calendarPage.searching
+                              ? "Searched cached events from the past and coming year"
+                              : "Your schedule is clear for the next 7 days"
                         font.pixelSize: Theme.fontSizeSmall
                         color: Theme.textMuted
                         Layout.alignment: Qt.AlignHCenter
@@ -385,4 +635,388 @@ Page {
             }
         }
     }
+
+    // New event with attendee autocomplete from contacts
+    Dialog {
+        id: newEventDialog
+        title: "New event"
+        modal: true
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.9, 480)
+
+        property var attendees: []
+        property var suggestions: []
+        property bool submitted: false
+
+        function suggest(text) {
+            try {
+                suggestions = JSON.parse(contactsModel.suggest_attendees(text)).filter(c => attendees.indexOf(c.email) < 0);
+            } catch (e) {
+                suggestions = [];
+            }
+        }
+
+        function addAttendee(email) {
+            const value = email.trim().toLowerCase();
+            if (value.length > 0 && attendees.indexOf(value) < 0)
+                attendees = attendees.concat([value]);
+            attendeeField.text = "";
+            suggestions = [];
+        }
+
+        function submit() {
+            if (attendeeField.text.trim().length > 0)
+                addAttendee(attendeeField.text);
+            submitted = calendarModel.create_event(titleField.text, startField.text, durationSpin.value, JSON.stringify(attendees));
+        }
+
+        onOpened: {
+            titleField.text = "";
+            startField.text = calendarModel.default_event_start();
+            durationSpin.value = 30;
+            attendees = [];
+            suggestions = [];
+            submitted = false;
+            titleField.forceActiveFocus();
+            if (contactsModel.count === 0)
+                contactsModel.refresh();
+        }
+
+        Connections {
+            target: calendarModel
+            function onEvent_created(summary) { newEventDialog.close(); }
+            function onLoadingChanged() {
+                // Creation failed: keep the dialog open so the input isn't lost
+                if (!calendarModel.loading && calendarModel.error_message !== "")
+                    newEventDialog.submitted = false;
+            }
+        }
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingSm
+
+            TextField {
+                id: titleField
+                Layout.fillWidth: true
+                placeholderText: "Title"
+            }
+
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: Theme.spacingSm
+
+                TextField {
+                    id: startField
+                    Layout.fillWidth: true
+                    placeholderText: "YYYY-MM-DD HH:mm"
+                    ToolTip.text: calendarModel.display_timezone ? "Time in " + calendarModel.display_timezone : "Time in this device's zone"
+                    ToolTip.visible: hovered
+                }
+
+                SpinBox {
+                    id: durationSpin
+                    from: 5
+                    to: 480
+                    stepSize: 15
+                    editable: true
+                }
+
+                Label {
+                    text: "min"
+                    color: Theme.textSecondary
+                }
+            }
+
+            TextField {
+                id: attendeeField
+                Layout.fillWidth: true
+                placeholderText: contactsModel.loading ? "Loading contacts..." : "Add attendee by name or email"
+                onTextChanged: newEventDialog.suggest(text)
+                onAccepted: newEventDialog.addAttendee(newEventDialog.suggestions.length > 0 ? newEventDialog.suggestions[0].email : text)
+            }
+
+            ListView {
+                Layout.fillWidth: true
+                Layout.preferredHeight: Math.min(contentHeight, 160)
+                visible: newEventDialog.suggestions.length > 0
+                clip: true
+                model: newEventDialog.suggestions
+
+                delegate: ItemDelegate {
+                    width: ListView.view.width
+                    onClicked: newEventDialog.addAttendee(modelData.email)
+
+                    contentItem: RowLayout {
+                        spacing: Theme.spacingSm
+
+                        Image {
+                            source: AppContext.imageSource(modelData.avatarUrl)
+                            Layout.preferredWidth: 24
+                            Layout.preferredHeight: 24
+                            fillMode: Image.PreserveAspectCrop
+                            asynchronous: true
+                        }
+
+                        Label {
+                            text: modelData.name ? modelData.name + " <" + modelData.email + ">" : modelData.email
+                            color: Theme.text
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+                    }
+                }
+            }
+
+            Flow {
+                Layout.fillWidth: true
+                spacing: Theme.spacingXs
+                visible: newEventDialog.attendees.length > 0
+
+                Repeater {
+                    model: newEventDialog.attendees
+
+                    delegate: Rectangle {
+                        height: 26
+                        width: chipRow.implicitWidth + Theme.spacingSm * 2
+                        radius: 13
+                        color: Theme.surfaceAlt
+
+                        RowLayout {
+                            id: chipRow
+                            anchors.centerIn: parent
+                            spacing: Theme.spacingXs
+
+                            Label {
+                                text: modelData
+                                font.pixelSize: Theme.fontSizeSmall
+                                color: Theme.text
+                            }
+
+                            Text {
+                                text: Icons.x
+                                font.family: Icons.family
+                                font.pixelSize: 12
+                                color: Theme.textMuted
+
+                                MouseArea {
+                                    anchors.fill: parent
+                                    cursorShape: Qt.PointingHandCursor
+                                    onClicked: newEventDialog.attendees = newEventDialog.attendees.filter(a => a !== modelData)
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            Label {
+                text: calendarModel.error_message
+                visible: text !== ""
+                color: Theme.error
+                font.pixelSize: Theme.fontSizeSmall
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            RowLayout {
+                Layout.fillWidth: true
+
+                Item {
+                    Layout.fillWidth: true
+                }
+
+                Button {
+                    text: "Cancel"
+                    flat: true
+                    onClicked: newEventDialog.close()
+                }
+
+                Button {
+                    text: newEventDialog.submitted && calendarModel.loading ? "Creating..." : "Create"
+                    enabled: titleField.text.trim().length > 0 && !calendarModel.loading
+                    highlighted: true
+                    onClicked: newEventDialog.submit()
+                }
+            }
+        }
+    }
+
+    // Travel mode: pin the zone times are shown in, or follow the device
+    Dialog {
+        id: timeZoneDialog
+        title: "Display time zone"
+        modal: true
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.9, 420)
+
+        onOpened: {
+            zoneField.text = calendarModel.display_timezone;
+            zoneField.forceActiveFocus();
+        }
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingSm
+
+            Label {
+                text: "Pin a zone to keep seeing home time while traveling. All-day and floating events keep their date and time in every zone."
+                color: Theme.textSecondary
+                font.pixelSize: Theme.fontSizeSmall
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            TextField {
+                id: zoneField
+                Layout.fillWidth: true
+                placeholderText: "e.g. America/Toronto (empty follows this device)"
+                onAccepted: if (calendarModel.set_display_timezone_name(text)) timeZoneDialog.close()
+            }
+
+            Label {
+                text: calendarModel.error_message
+                visible: text !== ""
+                color: Theme.error
+                font.pixelSize: Theme.fontSizeSmall
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            RowLayout {
+                Layout.fillWidth: true
+
+                Button {
+                    text: "Use device zone"
+                    flat: true
+                    enabled: calendarModel.display_timezone !== ""
+                    onClicked: if (calendarModel.set_display_timezone_name("")) timeZoneDialog.close()
+                }
+
+                Item {
+                    Layout.fillWidth: true
+                }
+
+                Button {
+                    text: "Cancel"
+                    flat: true
+                    onClicked: timeZoneDialog.close()
+                }
+
+                Button {
+                    text: "Save"
+                    highlighted: true
+                    onClicked: if (calendarModel.set_display_timezone_name(zoneField.text)) timeZoneDialog.close()
+                }
+            }
+        }
+    }
+
+    // Show or hide calendars; hidden ones are skipped by sync and views
+    Dialog {
+        id: calendarsDialog
+        title: "Calendars"
+        modal: true
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.9, 420)
+
+        onOpened: calendarListModel.refresh()
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingSm
+
+            Label {
+                text: calendarListModel.count === 0
+                      ? "Refresh to load your calendars."
+                      : "Hidden calendars are not synced and their events are not shown."
+                color: Theme.textSecondary
+                font.pixelSize: Theme.fontSizeSmall
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            Repeater {
+                model: calendarListModel.count
+
+                RowLayout {
+                    required property int index
+                    Layout.fillWidth: true
+                    spacing: Theme.spacingSm
+
+                    CheckBox {
+                        checked: (calendarListModel.revision, calendarListModel.is_visible(index))
+                        onToggled: calendarListModel.set_visible(index, checked)
+                    }
+
+                    Rectangle {
+                        width: 12
+                        height: 12
+                        radius: 6
+                        color: (calendarListModel.revision, calendarListModel.get_color(index)) || Theme.primary
+                    }
+
+                    Label {
+                        text: calendarListModel.get_summary(index)
+                              + (calendarListModel.is_primary(index) ? " (primary)" : "")
+                        color: Theme.text
+                        elide: Text.ElideRight
+                        Layout.fillWidth: true
+                    }
+
+                    TextField {
+                        Layout.preferredWidth: 90
+                        text: (calendarListModel.revision, calendarListModel.get_color(index))
+                        placeholderText: "#rrggbb"
+                        onEditingFinished: if (text !== calendarListModel.get_color(index))
+                                               calendarListModel.set_color(index, text)
+                    }
+                }
+            }
+
+            Label {
+                text: calendarListModel.error_message
+                visible: text !== ""
+                color: Theme.error
+                font.pixelSize: Theme.fontSizeSmall
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            RowLayout {
+                Layout.fillWidth: true
+
+                Item {
+                    Layout.fillWidth: true
+                }
+
+                Button {
+                    text: "Done"
+                    highlighted: true
+                    onClicked: calendarsDialog.close()
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,228 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: clipboardPage
+    title: "Clipboard"
+
+    // Shared with Main.qml, which watches the clipboard
+    readonly property var clipboardModel: AppContext.clipboardModel
+    readonly property bool hasHistory: clipboardModel && (clipboardModel.enabled || clipboardModel.count > 0)
+    property var entries: []
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    function reload() {
+        if (!hasHistory) {
+            clipboardPage.entries = [];
+            return;
+        }
+        try {
+            clipboardPage.entries = JSON.parse(clipboardModel.get_entries(searchField.text));
+        } catch (e) {
+            clipboardPage.entries = [];
+        }
+    }
+
+    function copyEntry(text) {
+        copyHelper.text = text;
+        copyHelper.selectAll();
+        copyHelper.copy();
+        copyHelper.text = "";
+    }
+
+    function timeAgo(iso) {
+        const minutes = Math.floor((Date.now() - new Date(iso).getTime()) / 60000);
+        if (minutes < 60) return Math.max(minutes, 0) + "m ago";
+        if (minutes < 60 * 24) return Math.floor(minutes / 60) + "h ago";
+        return Math.floor(minutes / (60 * 24)) + "d ago";
+    }
+
+    Connections {
+        target: clipboardPage.clipboardModel
+        function onEntries_changed() { clipboardPage.reload() }
+    }
+
+    Component.onCompleted: reload()
+
+    TextArea {
+        id: copyHelper
+        visible: false
+    }
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            Label {
+                text: "Clipboard"
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+                leftPadding: Theme.spacingMd
+            }
+
+            TextField {
+                id: searchField
+                visible: clipboardPage.hasHistory
+                placeholderText: "Search"
+                Layout.preferredWidth: 220
+                onTextChanged: clipboardPage.reload()
+            }
+
+            Button {
+                visible: clipboardPage.hasHistory
+                text: "Clear"
+                enabled: clipboardPage.entries.some(entry => !entry.pinned)
+                onClicked: clipboardPage.clipboardModel.clear_unpinned()
+            }
+
+            Switch {
+                text: "Record history"
+                checked: clipboardPage.clipboardModel ? clipboardPage.clipboardModel.enabled : false
+                onToggled: clipboardPage.clipboardModel.set_history_enabled(checked)
+            }
+
+            Item { width: Theme.spacingSm }
+        }
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: Theme.spacingLg
+        spacing: Theme.spacingMd
+
+        Label {
+            visible: clipboardPage.clipboardModel && clipboardPage.clipboardModel.error_message.length > 0
+            text: clipboardPage.clipboardModel ? clipboardPage.clipboardModel.error_message : ""
+            font.pixelSize: Theme.fontSizeSmall
+            color: Theme.error
+            wrapMode: Text.WordWrap
+            Layout.fillWidth: true
+        }
+
+        Rectangle {
+            visible: clipboardPage.entries.length === 0
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            color: Theme.surface
+            border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+            border.width: 1
+            radius: Theme.cardRadius
+
+            ColumnLayout {
+                anchors.centerIn: parent
+                width: Math.min(parent.width - Theme.spacingLg * 2, 420)
+                spacing: Theme.spacingMd
+
+                Label {
+                    text: Icons.copy
+                    font.family: Icons.family
+                    font.pixelSize: 64
+                    color: Theme.textSecondary
+                    Layout.alignment: Qt.AlignHCenter
+                }
+
+                Label {
+                    text: clipboardPage.hasHistory ? "Nothing copied yet"
+                        : "Clipboard history is off. When on, text you copy is kept here, encrypted, "
+                          + "except passwords, tokens and copies from password managers."
+                    font.pixelSize: Theme.fontSizeMedium
+                    color: Theme.textSecondary
+                    horizontalAlignment: Text.AlignHCenter
+                    wrapMode: Text.WordWrap
+                    Layout.fillWidth: true
+                }
+            }
+        }
+
+        ListView {
+            id: entryList
+            visible: clipboardPage.entries.length > 0
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+            spacing: Theme.spacingSm
+            model: clipboardPage.entries
+
+            delegate: Rectangle {
+                id: entryRow
+                required property var modelData
+                width: entryList.width
+                height: entryLayout.implicitHeight + Theme.spacingSm * 2
+                color: entryMouse.containsMouse ? Theme.surfaceHover : Theme.surface
+                border.color: modelData.pinned ? Theme.primary : (Theme.isDark ? "#ffffff08" : "#00000008")
+                border.width: 1
+                radius: Theme.cardRadius
+
+                MouseArea {
+                    id: entryMouse
+                    anchors.fill: parent
+                    hoverEnabled: true
+                    cursorShape: Qt.PointingHandCursor
+                    onClicked: clipboardPage.copyEntry(entryRow.modelData.text)
+                    ToolTip.text: "Copy"
+                    ToolTip.visible: containsMouse
+                    ToolTip.delay: 800
+                }
+
+                RowLayout {
+                    id: entryLayout
+                    anchors.fill: parent
+                    anchors.margins: Theme.spacingSm
+                    spacing: Theme.spacingSm
+
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 2
+
+                        Label {
+                            text: entryRow.modelData.text
+                            font.family: "monospace"
+                            font.pixelSize: Theme.fontSizeNormal
+                            color: Theme.text
+                            wrapMode: Text.WrapAnywhere
+                            maximumLineCount: 3
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+
+                        Label {
+                            text: clipboardPage.timeAgo(entryRow.modelData.lastUsedAt)
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textMuted
+                        }
+                    }
+
+                    ToolButton {
+                        text: Icons.pushPin
+                        font.family: Icons.family
+                        opacity: entryRow.modelData.pinned ? 1.0 : 0.5
+                        onClicked: clipboardPage.clipboardModel.set_pinned(entryRow.modelData.id, !entryRow.modelData.pinned)
+                        ToolTip.text: entryRow.modelData.pinned ? "Unpin" : "Pin"
+                        ToolTip.visible: hovered
+                    }
+
+                    ToolButton {
+                        text: Icons.trash
+                        font.family: Icons.family
+                        onClicked: clipboardPage.clipboardModel.delete_entry(entryRow.modelData.id)
+                        ToolTip.text: "Delete"
+                        ToolTip.visible: hovered
+                    }
+                }
+            }
+        }
+    }
+}
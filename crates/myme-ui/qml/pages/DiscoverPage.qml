@@ -0,0 +1,287 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: discoverPage
+    title: "Discover"
+
+    property var feed: []
+    property var starred: []
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    DiscoverModel {
+        id: discoverModel
+    }
+
+    Timer {
+        id: discoverPollTimer
+        interval: 100
+        running: discoverModel.loading
+        repeat: true
+        onTriggered: discoverModel.poll_channel()
+    }
+
+    Component.onCompleted: {
+        discoverModel.check_auth();
+        if (discoverModel.authenticated) {
+            discoverModel.load();
+        }
+    }
+
+    Connections {
+        target: discoverModel
+        function onFeed_changed() {
+            try {
+                discoverPage.feed = JSON.parse(discoverModel.get_feed());
+                discoverPage.starred = JSON.parse(discoverModel.get_starred());
+            } catch (e) {
+                discoverPage.feed = [];
+                discoverPage.starred = [];
+            }
+        }
+        function onStar_changed(fullName, starred) {
+            if (starred) {
+                starField.text = "";
+                discoverModel.refresh();
+            }
+        }
+    }
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            ToolButton {
+                text: Icons.caretLeft
+                font.family: Icons.family
+                font.pixelSize: 18
+                onClicked: AppContext.pageStack.pop()
+                ToolTip.text: "Back to Repos"
+                ToolTip.visible: hovered
+            }
+
+            Label {
+                text: "Discover"
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+            }
+
+            TextField {
+                id: starField
+                Layout.preferredWidth: 220
+                placeholderText: "Star owner/repo"
+                enabled: discoverModel.authenticated && !AppContext.offline
+                onAccepted: if (text.trim().length > 0) discoverModel.set_starred(text.trim(), true)
+            }
+
+            ToolButton {
+                text: Icons.arrowsClockwise
+                font.family: Icons.family
+                font.pixelSize: 18
+                enabled: discoverModel.authenticated && !discoverModel.loading && !AppContext.offline
+                onClicked: discoverModel.refresh()
+                ToolTip.text: "Refresh"
+                ToolTip.visible: hovered
+            }
+
+            Item { width: Theme.spacingSm }
+        }
+    }
+
+    ScrollView {
+        anchors.fill: parent
+        contentWidth: availableWidth
+
+        ColumnLayout {
+            width: parent.width
+            spacing: Theme.spacingMd
+
+            Item { Layout.preferredHeight: Theme.spacingSm }
+
+            Label {
+                visible: !discoverModel.authenticated
+                text: "Connect GitHub in Settings to follow your starred repositories."
+                font.pixelSize: Theme.fontSizeMedium
+                color: Theme.textSecondary
+                Layout.leftMargin: Theme.spacingLg
+            }
+
+            Label {
+                visible: discoverModel.error_message.length > 0
+                text: discoverModel.error_message
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.error
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingLg
+                Layout.rightMargin: Theme.spacingLg
+            }
+
+            BusyIndicator {
+                running: discoverModel.loading && discoverPage.feed.length === 0
+                visible: running
+                Layout.alignment: Qt.AlignHCenter
+            }
+
+            Label {
+                text: "From your stars"
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+                Layout.leftMargin: Theme.spacingLg
+            }
+
+            Label {
+                visible: discoverModel.authenticated && !discoverModel.loading && discoverPage.feed.length === 0
+                text: "No new releases or activity in the repositories you starred."
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+                Layout.leftMargin: Theme.spacingLg
+            }
+
+            // Releases first, then busy repos
+            Repeater {
+                model: discoverPage.feed
+
+                delegate: Rectangle {
+                    required property var modelData
+                    Layout.fillWidth: true
+                    Layout.leftMargin: Theme.spacingLg
+                    Layout.rightMargin: Theme.spacingLg
+                    implicitHeight: feedRow.implicitHeight + Theme.spacingMd * 2
+                    color: feedMouse.containsMouse ? Theme.surfaceHover : Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+                    radius: Theme.cardRadius
+
+                    RowLayout {
+                        id: feedRow
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingMd
+                        spacing: Theme.spacingMd
+
+                        Label {
+                            text: modelData.kind === "release" ? Icons.tag : Icons.star
+                            font.family: Icons.family
+                            font.pixelSize: 18
+                            color: Theme.primary
+                        }
+
+                        ColumnLayout {
+                            Layout.fillWidth: true
+                            spacing: 2
+
+                            Label {
+                                text: modelData.kind === "release"
+                                      ? modelData.repo + " released " + modelData.title
+                                      : modelData.repo + " is active"
+                                font.pixelSize: Theme.fontSizeNormal
+                                font.bold: true
+                                color: Theme.text
+                                elide: Text.ElideRight
+                                Layout.fillWidth: true
+                            }
+
+                            Label {
+                                visible: modelData.kind === "active" && modelData.title.length > 0
+                                text: modelData.title
+                                font.pixelSize: Theme.fontSizeSmall
+                                color: Theme.textSecondary
+                                elide: Text.ElideRight
+                                Layout.fillWidth: true
+                            }
+                        }
+
+                        Label {
+                            text: modelData.dateLabel + " · " + modelData.stars + " ★"
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textMuted
+                        }
+                    }
+
+                    MouseArea {
+                        id: feedMouse
+                        anchors.fill: parent
+                        hoverEnabled: true
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: Qt.openUrlExternally(modelData.url)
+                    }
+                }
+            }
+
+            Label {
+                text: "Starred (" + discoverPage.starred.length + ")"
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+                Layout.leftMargin: Theme.spacingLg
+                Layout.topMargin: Theme.spacingMd
+            }
+
+            Repeater {
+                model: discoverPage.starred
+
+                delegate: RowLayout {
+                    required property var modelData
+                    Layout.fillWidth: true
+                    Layout.leftMargin: Theme.spacingLg
+                    Layout.rightMargin: Theme.spacingLg
+                    spacing: Theme.spacingSm
+
+                    Label {
+                        text: modelData.fullName
+                        font.pixelSize: Theme.fontSizeNormal
+                        color: Theme.primary
+                        Layout.preferredWidth: 240
+                        elide: Text.ElideRight
+
+                        MouseArea {
+                            anchors.fill: parent
+                            cursorShape: Qt.PointingHandCursor
+                            onClicked: Qt.openUrlExternally(modelData.htmlUrl)
+                        }
+                    }
+
+                    Label {
+                        text: modelData.description || ""
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.textSecondary
+                        elide: Text.ElideRight
+                        Layout.fillWidth: true
+                    }
+
+                    Label {
+                        text: modelData.stars + " ★"
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.textMuted
+                    }
+
+                    ToolButton {
+                        text: Icons.starFill
+                        font.family: Icons.family
+                        font.pixelSize: 16
+                        enabled: !discoverModel.loading && !AppContext.offline
+                        onClicked: discoverModel.set_starred(modelData.fullName, false)
+                        ToolTip.text: "Unstar"
+                        ToolTip.visible: hovered
+                    }
+                }
+            }
+
+            Item { Layout.preferredHeight: Theme.spacingLg }
+        }
+    }
+}
@@ -0,0 +1,355 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: feedsPage
+    title: "Feeds"
+
+    // Shared with the dashboard so read state stays in sync
+    readonly property var feedModel: AppContext.feedModel
+    // Selected subscription; empty shows all feeds
+    property string selectedFeed: ""
+    property bool unreadOnly: true
+    property var feeds: []
+    property var items: []
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    function reload() {
+        if (!feedModel) return;
+        try {
+            feedsPage.feeds = JSON.parse(feedModel.get_feeds());
+            feedsPage.items = JSON.parse(feedModel.get_items(feedsPage.selectedFeed, feedsPage.unreadOnly, 200));
+        } catch (e) {
+            feedsPage.feeds = [];
+            feedsPage.items = [];
+        }
+    }
+
+    function timeAgo(iso) {
+        const minutes = Math.floor((Date.now() - new Date(iso).getTime()) / 60000);
+        if (minutes < 60) return Math.max(minutes, 0) + "m ago";
+        if (minutes < 60 * 24) return Math.floor(minutes / 60) + "h ago";
+        return Math.floor(minutes / (60 * 24)) + "d ago";
+    }
+
+    onSelectedFeedChanged: reload()
+    onUnreadOnlyChanged: reload()
+
+    Connections {
+        target: feedsPage.feedModel
+        function onFeeds_changed() { feedsPage.reload() }
+    }
+
+    Component.onCompleted: reload()
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            Label {
+                text: feedsPage.feedModel && feedsPage.feedModel.unread_count > 0
+                    ? "Feeds (" + feedsPage.feedModel.unread_count + ")"
+                    : "Feeds"
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+                leftPadding: Theme.spacingMd
+            }
+
+            CheckBox {
+                text: "Unread only"
+                checked: feedsPage.unreadOnly
+                onToggled: feedsPage.unreadOnly = checked
+            }
+
+            Button {
+                text: "Mark all read"
+                enabled: feedsPage.items.some(item => !item.isRead)
+                onClicked: feedsPage.feedModel.mark_all_read(feedsPage.selectedFeed)
+            }
+
+            ToolButton {
+                text: Icons.arrowsClockwise
+                font.family: Icons.family
+                font.pixelSize: 18
+                enabled: feedsPage.feedModel && !feedsPage.feedModel.loading && !AppContext.offline
+                onClicked: feedsPage.feedModel.refresh()
+                ToolTip.text: "Refresh all feeds"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    font.family: Icons.family
+                    color: Theme.text
+                    font.pixelSize: 18
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+            }
+
+            Item { width: Theme.spacingSm }
+        }
+    }
+
+    RowLayout {
+        anchors.fill: parent
+        anchors.margins: Theme.spacingLg
+        spacing: Theme.spacingLg
+
+        // Subscriptions
+        ColumnLayout {
+            Layout.preferredWidth: 260
+            Layout.fillHeight: true
+            spacing: Theme.spacingSm
+
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: Theme.spacingSm
+
+                TextField {
+                    id: urlField
+                    Layout.fillWidth: true
+                    placeholderText: "Feed URL"
+                    onAccepted: addButton.clicked()
+                }
+
+                Button {
+                    id: addButton
+                    text: "Add"
+                    enabled: urlField.text.trim().length > 0 && feedsPage.feedModel && !feedsPage.feedModel.loading
+                    onClicked: {
+                        feedsPage.feedModel.subscribe(urlField.text);
+                        urlField.text = "";
+                    }
+                }
+            }
+
+            Label {
+                visible: feedsPage.feedModel && feedsPage.feedModel.error_message.length > 0
+                text: feedsPage.feedModel ? feedsPage.feedModel.error_message : ""
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.error
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            ListView {
+                id: feedList
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                clip: true
+                spacing: 2
+                model: [{ url: "", title: "All feeds", unreadCount: feedsPage.feedModel ? feedsPage.feedModel.unread_count : 0 }].concat(feedsPage.feeds)
+
+                delegate: Rectangle {
+                    id: feedRow
+                    required property var modelData
+                    readonly property bool selected: feedsPage.selectedFeed === modelData.url
+                    width: feedList.width
+                    height: 36
+                    radius: Theme.buttonRadius
+                    color: selected ? Theme.sidebarActive : (feedMouse.containsMouse ? Theme.surfaceHover : "transparent")
+
+                    MouseArea {
+                        id: feedMouse
+                        anchors.fill: parent
+                        hoverEnabled: true
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: feedsPage.selectedFeed = feedRow.modelData.url
+                    }
+
+                    RowLayout {
+                        anchors.fill: parent
+                        anchors.leftMargin: Theme.spacingSm
+                        anchors.rightMargin: Theme.spacingXs
+                        spacing: Theme.spacingXs
+
+                        Label {
+                            text: feedRow.modelData.title
+                            font.pixelSize: Theme.fontSizeNormal
+                            font.bold: feedRow.modelData.unreadCount > 0
+                            color: Theme.text
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+
+                        Label {
+                            visible: !!feedRow.modelData.lastError
+                            text: Icons.warning
+                            font.family: Icons.family
+                            color: Theme.warning
+                            ToolTip.text: feedRow.modelData.lastError || ""
+                            ToolTip.visible: errorHover.hovered
+                            HoverHandler { id: errorHover }
+                        }
+
+                        Label {
+                            visible: feedRow.modelData.unreadCount > 0
+                            text: feedRow.modelData.unreadCount
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textSecondary
+                        }
+
+                        ToolButton {
+                            visible: feedRow.modelData.url.length > 0 && feedMouse.containsMouse
+                            text: Icons.x
+                            font.family: Icons.family
+                            onClicked: {
+                                if (feedsPage.selectedFeed === feedRow.modelData.url) feedsPage.selectedFeed = "";
+                                feedsPage.feedModel.unsubscribe(feedRow.modelData.url);
+                            }
+                            ToolTip.text: "Unsubscribe"
+                            ToolTip.visible: hovered
+                        }
+                    }
+                }
+            }
+        }
+
+        // Items
+        ColumnLayout {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            spacing: Theme.spacingSm
+
+            Rectangle {
+                visible: feedsPage.items.length === 0
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                color: Theme.surface
+                border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                border.width: 1
+                radius: Theme.cardRadius
+
+                ColumnLayout {
+                    anchors.centerIn: parent
+                    spacing: Theme.spacingMd
+
+                    Label {
+                        text: Icons.article
+                        font.family: Icons.family
+                        font.pixelSize: 64
+                        color: Theme.textSecondary
+                        Layout.alignment: Qt.AlignHCenter
+                    }
+
+                    Label {
+                        text: feedsPage.feeds.length === 0 ? "Add an RSS or Atom feed to start reading" : "All caught up"
+                        font.pixelSize: Theme.fontSizeMedium
+                        color: Theme.textSecondary
+                        Layout.alignment: Qt.AlignHCenter
+                    }
+                }
+            }
+
+            ListView {
+                id: itemList
+                visible: feedsPage.items.length > 0
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                clip: true
+                spacing: Theme.spacingSm
+                model: feedsPage.items
+
+                delegate: Rectangle {
+                    id: itemRow
+                    required property var modelData
+                    width: itemList.width
+                    height: itemLayout.implicitHeight + Theme.spacingSm * 2
+                    color: itemMouse.containsMouse ? Theme.surfaceHover : Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+                    radius: Theme.cardRadius
+                    opacity: modelData.isRead ? 0.6 : 1.0
+
+                    MouseArea {
+                        id: itemMouse
+                        anchors.fill: parent
+                        hoverEnabled: true
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: {
+                            if (itemRow.modelData.link) Qt.openUrlExternally(itemRow.modelData.link);
+                            if (!itemRow.modelData.isRead) {
+                                feedsPage.feedModel.set_read(itemRow.modelData.feedUrl, itemRow.modelData.id, true);
+                            }
+                        }
+                    }
+
+                    RowLayout {
+                        id: itemLayout
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingSm
+                        spacing: Theme.spacingSm
+
+                        Rectangle {
+                            width: 8
+                            height: 8
+                            radius: 4
+                            color: itemRow.modelData.isRead ? "transparent" : Theme.primary
+                            Layout.alignment: Qt.AlignTop
+                            Layout.topMargin: 6
+                        }
+
+                        ColumnLayout {
+                            Layout.fillWidth: true
+                            spacing: 2
+
+                            Label {
+                                text: itemRow.modelData.title
+                                font.pixelSize: Theme.fontSizeNormal
+                                font.bold: !itemRow.modelData.isRead
+                                color: Theme.text
+                                elide: Text.ElideRight
+                                Layout.fillWidth: true
+                            }
+
+                            Label {
+                                visible: text.length > 0
+                                text: itemRow.modelData.summary
+                                font.pixelSize: Theme.fontSizeSmall
+                                color: Theme.textSecondary
+                                wrapMode: Text.WordWrap
+                                maximumLineCount: 2
+                                elide: Text.ElideRight
+                                Layout.fillWidth: true
+                            }
+
+                            Label {
+                                text: (itemRow.modelData.author ? itemRow.modelData.author + " · " : "") + feedsPage.timeAgo(itemRow.modelData.published)
+                                font.pixelSize: Theme.fontSizeSmall
+                                color: Theme.textMuted
+                            }
+                        }
+
+                        ToolButton {
+                            text: itemRow.modelData.isRead ? Icons.envelopeSimple : Icons.check
+                            font.family: Icons.family
+                            onClicked: feedsPage.feedModel.set_read(itemRow.modelData.feedUrl, itemRow.modelData.id, !itemRow.modelData.isRead)
+                            ToolTip.text: itemRow.modelData.isRead ? "Mark as unread" : "Mark as read"
+                            ToolTip.visible: hovered
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
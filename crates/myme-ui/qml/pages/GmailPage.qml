@@ -18,14 +18,62 @@ Page {
         }
     }
 
+    // Fast while a request is in flight; slower otherwise to pick up snoozed
+    // messages coming back to the inbox
     Timer {
         id: pollTimer
-        interval: 100
-        running: gmailModel.loading
+        interval: gmailModel.loading ? 100 : 1000
+        running: true
         repeat: true
         onTriggered: gmailModel.poll_channel()
     }
 
+    // Snooze presets: later today (+3h), tomorrow 8:00, next Monday 8:00
+    function snoozeUntil(preset) {
+        const d = new Date();
+        if (preset === "later") {
+            d.setHours(d.getHours() + 3);
+        } else if (preset === "tomorrow") {
+            d.setDate(d.getDate() + 1);
+            d.setHours(8, 0, 0, 0);
+        } else {
+            d.setDate(d.getDate() + ((8 - d.getDay()) % 7 || 7));
+            d.setHours(8, 0, 0, 0);
+        }
+        return d.toISOString();
+    }
+
+    // Result of an unsubscribe, shown briefly above the list
+    property string unsubscribeStatus: ""
+
+    Connections {
+        target: gmailModel
+        function onUnsubscribed(sender, outcome) {
+            if (outcome === "one_click") {
+                gmailPage.unsubscribeStatus = "Unsubscribed from " + sender;
+            } else if (outcome === "drafted") {
+                gmailPage.unsubscribeStatus = "Unsubscribe email to " + sender + " saved in Drafts; send it to finish";
+            } else {
+                gmailPage.unsubscribeStatus = "Opened the unsubscribe page for " + sender;
+            }
+            unsubscribeStatusTimer.restart();
+        }
+    }
+
+    Timer {
+        id: unsubscribeStatusTimer
+        interval: 5000
+        onTriggered: gmailPage.unsubscribeStatus = ""
+    }
+
+    // Undoing an archive or trash puts messages back; refresh the list
+    Connections {
+        target: AppContext.undoManager
+        function onUndo_applied(service, summary, redo) {
+            if (service === "gmail") gmailModel.fetch_messages();
+        }
+    }
+
     background: Rectangle {
         color: Theme.background
     }
@@ -48,6 +96,22 @@ Page {
                 leftPadding: Theme.spacingMd
             }
 
+            // Offline cache usage against the quota
+            Label {
+                visible: gmailModel.authenticated && gmailModel.cache_usage.length > 0
+                text: gmailModel.cache_usage
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textMuted
+                ToolTip.visible: cacheUsageArea.containsMouse
+                ToolTip.text: "Mail kept offline (Settings: gmail.cache_quota_mb)"
+
+                MouseArea {
+                    id: cacheUsageArea
+                    anchors.fill: parent
+                    hoverEnabled: true
+                }
+            }
+
             // Unread count badge
             Rectangle {
                 visible: gmailModel.unread_count > 0
@@ -65,6 +129,20 @@ Page {
                 }
             }
 
+            Button {
+                text: "Rules"
+                enabled: gmailModel.authenticated
+                flat: true
+                onClicked: rulesDialog.open()
+
+                contentItem: Label {
+                    text: parent.text
+                    font.pixelSize: Theme.fontSizeSmall
+                    color: parent.enabled ? Theme.text : Theme.textMuted
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+
             Button {
                 text: gmailModel.loading ? "Refreshing..." : "Refresh"
                 enabled: !gmailModel.loading && gmailModel.authenticated
@@ -152,12 +230,20 @@ Page {
         ListView {
             id: messageList
             width: gmailScroll.viewport.width
-            model: gmailModel.message_count
+            model: gmailModel
             spacing: Theme.spacingSm
 
             delegate: Rectangle {
                 id: messageDelegate
                 required property int index
+                required property string messageId
+                required property string sender
+                required property string subject
+                required property string snippet
+                required property string dateLabel
+                required property bool isUnread
+                required property bool isStarred
+                required property bool canUnsubscribe
                 width: messageList.width
                 height: 80
                 radius: Theme.cardRadius
@@ -176,25 +262,19 @@ Page {
                     }
                 }
 
-                property var messageData: {
-                    try {
-                        return JSON.parse(gmailModel.get_message(index))
-                    } catch (e) {
-                        return {}
-                    }
-                }
-
                 RowLayout {
                     anchors.fill: parent
                     anchors.margins: Theme.spacingMd
                     spacing: Theme.spacingMd
+                    // Above the row's MouseArea so the snooze button gets clicks
+                    z: 1
 
                     // Unread indicator
                     Rectangle {
                         width: 8
                         height: 8
                         radius: 4
-                        color: messageData.isUnread ? Theme.primary : "transparent"
+                        color: messageDelegate.isUnread ? Theme.primary : "transparent"
                     }
 
                     ColumnLayout {
@@ -205,36 +285,32 @@ Page {
                             Layout.fillWidth: true
 
                             Label {
-                                text: messageData.from || "Unknown"
+                                text: messageDelegate.sender || "Unknown"
                                 font.pixelSize: Theme.fontSizeNormal
-                                font.bold: messageData.isUnread
+                                font.bold: messageDelegate.isUnread
                                 color: Theme.text
                                 elide: Text.ElideRight
                                 Layout.fillWidth: true
                             }
 
                             Label {
-                                text: {
-                                    if (!messageData.date) return ""
-                                    const d = new Date(messageData.date)
-                                    return d.toLocaleDateString()
-                                }
+                                text: messageDelegate.dateLabel || ""
                                 font.pixelSize: Theme.fontSizeSmall
                                 color: Theme.textSecondary
                             }
                         }
 
                         Label {
-                            text: messageData.subject || "(No subject)"
+                            text: messageDelegate.subject || "(No subject)"
                             font.pixelSize: Theme.fontSizeNormal
-                            font.bold: messageData.isUnread
+                            font.bold: messageDelegate.isUnread
                             color: Theme.text
                             elide: Text.ElideRight
                             Layout.fillWidth: true
                         }
 
                         Label {
-                            text: messageData.snippet || ""
+                            text: messageDelegate.snippet || ""
                             font.pixelSize: Theme.fontSizeSmall
                             color: Theme.textSecondary
                             elide: Text.ElideRight
@@ -242,12 +318,96 @@ Page {
                         }
                     }
 
+                    ToolButton {
+                        visible: messageDelegate.canUnsubscribe
+                        text: Icons.signOut
+                        font.family: Icons.family
+                        font.pixelSize: 18
+                        onClicked: {
+                            unsubscribeMenu.summary = JSON.parse(gmailModel.sender_summary(messageDelegate.index));
+                            unsubscribeMenu.open();
+                        }
+                        ToolTip.visible: hovered
+                        ToolTip.text: "Unsubscribe"
+
+                        background: Rectangle {
+                            radius: Theme.buttonRadius
+                            color: parent.hovered ? Theme.surfaceHover : "transparent"
+                        }
+
+                        contentItem: Text {
+                            text: parent.text
+                            font.family: Icons.family
+                            font.pixelSize: 18
+                            color: Theme.textMuted
+                            horizontalAlignment: Text.AlignHCenter
+                            verticalAlignment: Text.AlignVCenter
+                        }
+
+                        Menu {
+                            id: unsubscribeMenu
+                            width: 280
+                            property var summary: ({})
+
+                            MenuItem {
+                                enabled: false
+                                text: unsubscribeMenu.summary.summary || "Mailing list"
+                            }
+                            MenuItem {
+                                text: unsubscribeMenu.summary.unsubscribedAt ? "Unsubscribe again"
+                                    : unsubscribeMenu.summary.oneClick ? "Unsubscribe" : "Unsubscribe…"
+                                onTriggered: gmailModel.unsubscribe(messageDelegate.index)
+                            }
+                        }
+                    }
+
+                    ToolButton {
+                        text: Icons.clock
+                        font.family: Icons.family
+                        font.pixelSize: 18
+                        onClicked: snoozeMenu.open()
+                        ToolTip.visible: hovered
+                        ToolTip.text: "Snooze"
+
+                        background: Rectangle {
+                            radius: Theme.buttonRadius
+                            color: parent.hovered ? Theme.surfaceHover : "transparent"
+                        }
+
+                        contentItem: Text {
+                            text: parent.text
+                            font.family: Icons.family
+                            font.pixelSize: 18
+                            color: Theme.textMuted
+                            horizontalAlignment: Text.AlignHCenter
+                            verticalAlignment: Text.AlignVCenter
+                        }
+
+                        Menu {
+                            id: snoozeMenu
+                            width: 180
+
+                            MenuItem {
+                                text: "Later today"
+                                onTriggered: gmailModel.snooze(messageDelegate.index, gmailPage.snoozeUntil("later"))
+                            }
+                            MenuItem {
+                                text: "Tomorrow morning"
+                                onTriggered: gmailModel.snooze(messageDelegate.index, gmailPage.snoozeUntil("tomorrow"))
+                            }
+                            MenuItem {
+                                text: "Next week"
+                                onTriggered: gmailModel.snooze(messageDelegate.index, gmailPage.snoozeUntil("next_week"))
+                            }
+                        }
+                    }
+
                     // Star indicator
                     Text {
-                        text: messageData.isStarred ? Icons.starFill : Icons.star
+                        text: messageDelegate.isStarred ? Icons.starFill : Icons.star
                         font.family: Icons.family
                         font.pixelSize: 20
-                        color: messageData.isStarred ? "#f59e0b" : Theme.textMuted
+                        color: messageDelegate.isStarred ? "#f59e0b" : Theme.textMuted
                     }
                 }
 
@@ -256,8 +416,8 @@ Page {
                     cursorShape: Qt.PointingHandCursor
                     onClicked: {
                         // Mark as read when clicked
-                        if (messageData.isUnread && messageData.id) {
-                            gmailModel.mark_as_read(messageData.id)
+                        if (messageDelegate.isUnread && messageDelegate.messageId) {
+                            gmailModel.mark_as_read(messageDelegate.messageId)
                         }
                     }
                 }
@@ -281,6 +441,28 @@ Page {
         visible: gmailModel.loading
     }
 
+    // Unsubscribe result
+    Rectangle {
+        anchors.bottom: parent.bottom
+        anchors.left: parent.left
+        anchors.right: parent.right
+        anchors.margins: Theme.spacingMd
+        height: 50
+        radius: Theme.cardRadius
+        color: Theme.surfaceAlt
+        visible: gmailPage.unsubscribeStatus !== "" && gmailModel.error_message === ""
+
+        Label {
+            anchors.fill: parent
+            anchors.margins: Theme.spacingSm
+            text: gmailPage.unsubscribeStatus
+            font.pixelSize: Theme.fontSizeSmall
+            color: Theme.text
+            verticalAlignment: Text.AlignVCenter
+            elide: Text.ElideRight
+        }
+    }
+
     // Error message
     Rectangle {
         anchors.bottom: parent.bottom
@@ -314,4 +496,193 @@ Page {
             }
         }
     }
+
+    // Local mail rules, applied to new messages on refresh
+    Dialog {
+        id: rulesDialog
+        title: "Mail rules"
+        standardButtons: Dialog.Close
+        modal: true
+
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.9, 560)
+        height: Math.min(parent.height * 0.9, 520)
+
+        property var rules: []
+        readonly property var fields: [
+            { key: "from", label: "From contains" },
+            { key: "subject", label: "Subject contains" },
+            { key: "list_id", label: "Mailing list contains" }
+        ]
+        readonly property var actions: [
+            { key: "archive", label: "Archive" },
+            { key: "mark_read", label: "Mark read" },
+            { key: "label", label: "Add label" }
+        ]
+
+        function reload() {
+            try {
+                rules = JSON.parse(gmailModel.list_rules());
+            } catch (e) {
+                rules = [];
+            }
+        }
+
+        function describe(rule) {
+            const conditions = rule.conditions.map(c => {
+                const field = fields.find(f => f.key === c.field);
+                return (field ? field.label : c.field) + " \"" + c.value + "\"";
+            });
+            const actionText = rule.actions.map(a => a.type === "label" ? "label " + a.label : actions.find(x => x.key === a.type).label.toLowerCase());
+            return conditions.join(" and ") + " → " + actionText.join(", ");
+        }
+
+        function addRule() {
+            const action = actions[ruleActionCombo.currentIndex].key;
+            const rule = {
+                name: ruleNameField.text.trim() || ruleValueField.text.trim(),
+                conditions: [{ field: fields[ruleFieldCombo.currentIndex].key, value: ruleValueField.text.trim() }],
+                actions: [action === "label" ? { type: "label", label: ruleLabelField.text.trim() } : { type: action }]
+            };
+            if (gmailModel.save_rule(JSON.stringify(rule)) >= 0) {
+                ruleNameField.text = "";
+                ruleValueField.text = "";
+                ruleLabelField.text = "";
+            }
+        }
+
+        onOpened: reload()
+
+        Connections {
+            target: gmailModel
+            function onRules_changed() { rulesDialog.reload(); }
+        }
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            Label {
+                text: "Rules run on new messages when the inbox refreshes."
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            ListView {
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                clip: true
+                spacing: Theme.spacingSm
+                model: rulesDialog.rules
+
+                delegate: RowLayout {
+                    required property var modelData
+                    width: ListView.view.width
+                    spacing: Theme.spacingSm
+
+                    CheckBox {
+                        checked: modelData.enabled
+                        onToggled: {
+                            const rule = Object.assign({}, modelData, { enabled: checked });
+                            gmailModel.save_rule(JSON.stringify(rule));
+                        }
+                    }
+
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 2
+
+                        Label {
+                            text: modelData.name
+                            font.pixelSize: Theme.fontSizeNormal
+                            color: modelData.enabled ? Theme.text : Theme.textMuted
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+                        Label {
+                            text: rulesDialog.describe(modelData)
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textSecondary
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+                    }
+
+                    ToolButton {
+                        text: Icons.trash
+                        font.family: Icons.family
+                        onClicked: gmailModel.delete_rule(modelData.id)
+                    }
+                }
+
+                Label {
+                    visible: parent.count === 0
+                    anchors.centerIn: parent
+                    text: "No rules yet"
+                    color: Theme.textSecondary
+                }
+            }
+
+            GridLayout {
+                Layout.fillWidth: true
+                columns: 2
+                columnSpacing: Theme.spacingSm
+                rowSpacing: Theme.spacingSm
+
+                TextField {
+                    id: ruleNameField
+                    placeholderText: "Rule name (optional)"
+                    Layout.columnSpan: 2
+                    Layout.fillWidth: true
+                }
+                ComboBox {
+                    id: ruleFieldCombo
+                    model: rulesDialog.fields.map(f => f.label)
+                    Layout.preferredWidth: 200
+                }
+                TextField {
+                    id: ruleValueField
+                    placeholderText: "e.g. newsletter@, receipt, dev.lists.example.org"
+                    Layout.fillWidth: true
+                }
+                ComboBox {
+                    id: ruleActionCombo
+                    model: rulesDialog.actions.map(a => a.label)
+                    Layout.preferredWidth: 200
+                }
+                TextField {
+                    id: ruleLabelField
+                    placeholderText: "Label name"
+                    enabled: rulesDialog.actions[ruleActionCombo.currentIndex].key === "label"
+                    Layout.fillWidth: true
+                }
+            }
+
+            Label {
+                visible: text !== ""
+                text: gmailModel.error_message
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.error
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            Button {
+                text: "Add rule"
+                Layout.alignment: Qt.AlignRight
+                enabled: ruleValueField.text.trim().length > 0
+                    && (!ruleLabelField.enabled || ruleLabelField.text.trim().length > 0)
+                onClicked: rulesDialog.addRule()
+            }
+        }
+    }
 }
@@ -0,0 +1,233 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: importPage
+    title: "Import"
+
+    property var sources: []
+    property var projects: []
+    property var report: ({})
+    property string sourceId: ""
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    ImportModel {
+        id: importModel
+        onReport_changed: {
+            try {
+                importPage.report = JSON.parse(get_report());
+            } catch (e) {
+                importPage.report = {};
+            }
+        }
+    }
+
+    Timer {
+        interval: 100
+        running: importModel.loading
+        repeat: true
+        onTriggered: importModel.poll_channel()
+    }
+
+    Component.onCompleted: {
+        try {
+            sources = JSON.parse(importModel.get_sources());
+            projects = JSON.parse(importModel.get_projects());
+        } catch (e) {
+            sources = [];
+            projects = [];
+        }
+    }
+
+    function selectedProjectId() {
+        return projectCombo.currentIndex >= 0 && projects.length > 0
+            ? projects[projectCombo.currentIndex].id : "";
+    }
+
+    function sourceIndex(id) {
+        for (let i = 0; i < sources.length; i++) {
+            if (sources[i].id === id) return i;
+        }
+        return -1;
+    }
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            ToolButton {
+                text: Icons.caretLeft
+                font.family: Icons.family
+                font.pixelSize: 18
+                onClicked: AppContext.pageStack.pop()
+                ToolTip.text: "Back to Settings"
+                ToolTip.visible: hovered
+            }
+
+            Label {
+                text: "Import from another app"
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+            }
+        }
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: Theme.spacingLg
+        spacing: Theme.spacingMd
+
+        Label {
+            text: "Todoist and Trello exports become tasks on a project board; Google Keep notes become notes. Items that already exist are skipped."
+            font.pixelSize: Theme.fontSizeSmall
+            color: Theme.textSecondary
+            wrapMode: Text.WordWrap
+            Layout.fillWidth: true
+        }
+
+        Label {
+            text: "Export file (or Takeout Keep folder)"
+            font.pixelSize: Theme.fontSizeNormal
+            color: Theme.text
+        }
+
+        TextField {
+            id: pathField
+            placeholderText: "/home/you/Downloads/board.json"
+            Layout.fillWidth: true
+            onEditingFinished: {
+                const detected = importModel.detect_source(text);
+                if (detected.length > 0) importPage.sourceId = detected;
+                importPage.report = {};
+            }
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: Theme.spacingMd
+
+            ComboBox {
+                id: sourceCombo
+                model: importPage.sources
+                textRole: "label"
+                currentIndex: importPage.sourceIndex(importPage.sourceId)
+                displayText: currentIndex >= 0 ? currentText : "Exported from..."
+                onActivated: index => importPage.sourceId = importPage.sources[index].id
+                Layout.preferredWidth: 240
+            }
+
+            ComboBox {
+                id: projectCombo
+                visible: importPage.sourceId !== "google_keep"
+                model: importPage.projects
+                textRole: "name"
+                displayText: importPage.projects.length > 0 ? currentText : "No projects yet"
+                Layout.fillWidth: true
+            }
+        }
+
+        RowLayout {
+            spacing: Theme.spacingSm
+
+            Button {
+                text: "Preview"
+                enabled: !importModel.loading && pathField.text.trim().length > 0
+                onClicked: importModel.preview(pathField.text, importPage.sourceId, importPage.selectedProjectId())
+            }
+
+            Button {
+                text: "Import"
+                enabled: !importModel.loading && importPage.report.dry_run === true
+                    && (importPage.report.notes + importPage.report.tasks) > 0
+                onClicked: importModel.run_import(pathField.text, importPage.sourceId, importPage.selectedProjectId())
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.primaryHover : Theme.primary
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+                contentItem: Label {
+                    text: parent.text
+                    color: Theme.primaryText
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
+            BusyIndicator {
+                running: importModel.loading
+                visible: running
+                Layout.preferredHeight: 28
+                Layout.preferredWidth: 28
+            }
+        }
+
+        Label {
+            visible: importModel.error_message.length > 0
+            text: importModel.error_message
+            font.pixelSize: Theme.fontSizeSmall
+            color: Theme.error
+            wrapMode: Text.WordWrap
+            Layout.fillWidth: true
+        }
+
+        // Preview or result
+        Rectangle {
+            visible: importPage.report.source !== undefined
+            Layout.fillWidth: true
+            implicitHeight: reportLayout.implicitHeight + Theme.spacingMd * 2
+            color: Theme.surface
+            border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+            border.width: 1
+            radius: Theme.cardRadius
+
+            ColumnLayout {
+                id: reportLayout
+                anchors.fill: parent
+                anchors.margins: Theme.spacingMd
+                spacing: Theme.spacingXs
+
+                Label {
+                    text: importPage.report.dry_run ? "Will import" : "Imported"
+                    font.pixelSize: Theme.fontSizeMedium
+                    font.bold: true
+                    color: importPage.report.dry_run ? Theme.text : Theme.success
+                }
+
+                Label {
+                    text: (importPage.report.notes || 0) + " notes · " + (importPage.report.tasks || 0)
+                        + " tasks · " + (importPage.report.duplicates || 0) + " already there"
+                    font.pixelSize: Theme.fontSizeNormal
+                    color: Theme.text
+                }
+
+                Repeater {
+                    model: importPage.report.warnings || []
+
+                    delegate: Label {
+                        required property string modelData
+                        text: modelData
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.warning
+                        wrapMode: Text.WordWrap
+                        Layout.fillWidth: true
+                    }
+                }
+            }
+        }
+
+        Item { Layout.fillHeight: true }
+    }
+}
@@ -0,0 +1,395 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: insightsPage
+    title: "Insights"
+
+    property var repos: []
+    property var insights: ({})
+    readonly property bool hasInsights: insights.fullName !== undefined
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    InsightsModel {
+        id: insightsModel
+    }
+
+    Timer {
+        id: insightsPollTimer
+        interval: 100
+        running: insightsModel.loading
+        repeat: true
+        onTriggered: insightsModel.poll_channel()
+    }
+
+    Component.onCompleted: {
+        insightsModel.check_auth();
+        if (insightsModel.authenticated) {
+            insightsModel.load_repos();
+        }
+    }
+
+    Connections {
+        target: insightsModel
+        function onRepos_changed() {
+            try {
+                insightsPage.repos = JSON.parse(insightsModel.get_repos());
+            } catch (e) {
+                insightsPage.repos = [];
+            }
+            // Open the most starred repo first
+            if (insightsModel.repo.length === 0 && insightsPage.repos.length > 0) {
+                insightsModel.load(insightsPage.repos[0]);
+            }
+        }
+        function onInsights_changed() {
+            try {
+                insightsPage.insights = JSON.parse(insightsModel.get_insights());
+            } catch (e) {
+                insightsPage.insights = {};
+            }
+        }
+    }
+
+    function maxCount(points) {
+        return points.reduce((max, p) => Math.max(max, p.count), 1);
+    }
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            ToolButton {
+                text: Icons.caretLeft
+                font.family: Icons.family
+                font.pixelSize: 18
+                onClicked: AppContext.pageStack.pop()
+                ToolTip.text: "Back to Repos"
+                ToolTip.visible: hovered
+            }
+
+            Label {
+                text: "Insights"
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+            }
+
+            ComboBox {
+                id: repoCombo
+                Layout.preferredWidth: 280
+                model: insightsPage.repos
+                currentIndex: insightsPage.repos.indexOf(insightsModel.repo)
+                displayText: insightsModel.repo.length > 0 ? insightsModel.repo : "Choose a repository"
+                onActivated: index => insightsModel.load(insightsPage.repos[index])
+            }
+
+            ToolButton {
+                text: Icons.arrowsClockwise
+                font.family: Icons.family
+                font.pixelSize: 18
+                enabled: !insightsModel.loading && insightsModel.repo.length > 0 && !AppContext.offline
+                onClicked: insightsModel.refresh()
+                ToolTip.text: "Refresh"
+                ToolTip.visible: hovered
+            }
+
+            Item { width: Theme.spacingSm }
+        }
+    }
+
+    ScrollView {
+        anchors.fill: parent
+        contentWidth: availableWidth
+
+        ColumnLayout {
+            width: parent.width
+            spacing: Theme.spacingMd
+
+            Item { Layout.preferredHeight: Theme.spacingSm }
+
+            Label {
+                visible: !insightsModel.authenticated
+                text: "Connect GitHub in Settings to see repository insights."
+                font.pixelSize: Theme.fontSizeMedium
+                color: Theme.textSecondary
+                Layout.leftMargin: Theme.spacingLg
+            }
+
+            Label {
+                visible: insightsModel.error_message.length > 0
+                text: insightsModel.error_message
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.error
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingLg
+                Layout.rightMargin: Theme.spacingLg
+            }
+
+            BusyIndicator {
+                running: insightsModel.loading && !insightsPage.hasInsights
+                visible: running
+                Layout.alignment: Qt.AlignHCenter
+            }
+
+            // Headline numbers
+            RowLayout {
+                visible: insightsPage.hasInsights
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingLg
+                Layout.rightMargin: Theme.spacingLg
+                spacing: Theme.spacingMd
+
+                Repeater {
+                    model: insightsPage.hasInsights ? [
+                        { label: "Stars", value: insightsPage.insights.stars },
+                        { label: "Forks", value: insightsPage.insights.forks },
+                        { label: "Open issues", value: insightsPage.insights.openIssues },
+                        { label: "Views (14d)", value: insightsPage.insights.hasTraffic ? insightsPage.insights.views.count : "-" },
+                        { label: "Clones (14d)", value: insightsPage.insights.hasTraffic ? insightsPage.insights.clones.count : "-" }
+                    ] : []
+
+                    delegate: Rectangle {
+                        required property var modelData
+                        Layout.fillWidth: true
+                        Layout.preferredHeight: 72
+                        color: Theme.surface
+                        border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                        border.width: 1
+                        radius: Theme.cardRadius
+
+                        ColumnLayout {
+                            anchors.centerIn: parent
+                            spacing: 2
+
+                            Label {
+                                text: String(modelData.value)
+                                font.pixelSize: Theme.fontSizeLarge
+                                font.bold: true
+                                color: Theme.text
+                                Layout.alignment: Qt.AlignHCenter
+                            }
+
+                            Label {
+                                text: modelData.label
+                                font.pixelSize: Theme.fontSizeSmall
+                                color: Theme.textSecondary
+                                Layout.alignment: Qt.AlignHCenter
+                            }
+                        }
+                    }
+                }
+            }
+
+            Label {
+                visible: insightsPage.hasInsights && !insightsPage.insights.hasTraffic
+                text: "Traffic is only visible to people with push access to this repository."
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textMuted
+                Layout.leftMargin: Theme.spacingLg
+            }
+
+            // Daily views and clones
+            Repeater {
+                model: insightsPage.hasInsights && insightsPage.insights.hasTraffic ? [
+                    { title: "Views", data: insightsPage.insights.views },
+                    { title: "Clones", data: insightsPage.insights.clones }
+                ] : []
+
+                delegate: Rectangle {
+                    id: trafficCard
+                    required property var modelData
+                    readonly property real peak: insightsPage.maxCount(modelData.data.points)
+                    Layout.fillWidth: true
+                    Layout.leftMargin: Theme.spacingLg
+                    Layout.rightMargin: Theme.spacingLg
+                    Layout.preferredHeight: 160
+                    color: Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+                    radius: Theme.cardRadius
+
+                    ColumnLayout {
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingMd
+                        spacing: Theme.spacingSm
+
+                        Label {
+                            text: trafficCard.modelData.title + " · " + trafficCard.modelData.data.uniques + " unique"
+                            font.pixelSize: Theme.fontSizeNormal
+                            font.bold: true
+                            color: Theme.text
+                        }
+
+                        RowLayout {
+                            Layout.fillWidth: true
+                            Layout.fillHeight: true
+                            spacing: 2
+
+                            Repeater {
+                                model: trafficCard.modelData.data.points
+
+                                delegate: Item {
+                                    required property var modelData
+                                    Layout.fillWidth: true
+                                    Layout.fillHeight: true
+
+                                    Rectangle {
+                                        anchors.bottom: parent.bottom
+                                        width: parent.width
+                                        height: Math.max(2, parent.height * modelData.count / trafficCard.peak)
+                                        radius: 2
+                                        color: Theme.primary
+                                    }
+
+                                    MouseArea {
+                                        id: barMouse
+                                        anchors.fill: parent
+                                        hoverEnabled: true
+                                        ToolTip.text: modelData.date + ": " + modelData.count + " (" + modelData.uniques + " unique)"
+                                        ToolTip.visible: containsMouse
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Contributors and community health
+            RowLayout {
+                visible: insightsPage.hasInsights
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingLg
+                Layout.rightMargin: Theme.spacingLg
+                spacing: Theme.spacingMd
+
+                Rectangle {
+                    Layout.fillWidth: true
+                    Layout.preferredHeight: contributorColumn.implicitHeight + Theme.spacingMd * 2
+                    Layout.alignment: Qt.AlignTop
+                    color: Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+                    radius: Theme.cardRadius
+
+                    ColumnLayout {
+                        id: contributorColumn
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingMd
+                        spacing: Theme.spacingXs
+
+                        Label {
+                            text: "Contributors (" + (insightsPage.insights.contributorCount || 0) + ")"
+                            font.pixelSize: Theme.fontSizeNormal
+                            font.bold: true
+                            color: Theme.text
+                        }
+
+                        Repeater {
+                            model: insightsPage.insights.contributors || []
+
+                            delegate: RowLayout {
+                                required property var modelData
+                                Layout.fillWidth: true
+
+                                Label {
+                                    text: modelData.login
+                                    font.pixelSize: Theme.fontSizeNormal
+                                    color: Theme.primary
+                                    Layout.fillWidth: true
+
+                                    MouseArea {
+                                        anchors.fill: parent
+                                        cursorShape: Qt.PointingHandCursor
+                                        onClicked: Qt.openUrlExternally(modelData.htmlUrl)
+                                    }
+                                }
+
+                                Label {
+                                    text: modelData.contributions + " commits"
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    color: Theme.textSecondary
+                                }
+                            }
+                        }
+                    }
+                }
+
+                Rectangle {
+                    Layout.fillWidth: true
+                    Layout.preferredHeight: healthColumn.implicitHeight + Theme.spacingMd * 2
+                    Layout.alignment: Qt.AlignTop
+                    color: Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+                    radius: Theme.cardRadius
+
+                    ColumnLayout {
+                        id: healthColumn
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingMd
+                        spacing: Theme.spacingXs
+
+                        Label {
+                            text: insightsPage.insights.health >= 0
+                                ? "Community health " + insightsPage.insights.health + "%"
+                                : "Community health"
+                            font.pixelSize: Theme.fontSizeNormal
+                            font.bold: true
+                            color: Theme.text
+                        }
+
+                        Label {
+                            visible: insightsPage.insights.health < 0
+                            text: "Not available for private repositories."
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textMuted
+                        }
+
+                        Label {
+                            visible: insightsPage.insights.health >= 0
+                                && (insightsPage.insights.missingFiles || []).length === 0
+                            text: "All recommended files present"
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textSecondary
+                        }
+
+                        Repeater {
+                            model: insightsPage.insights.missingFiles || []
+
+                            delegate: Label {
+                                required property var modelData
+                                text: "Missing: " + modelData
+                                font.pixelSize: Theme.fontSizeSmall
+                                color: Theme.textSecondary
+                            }
+                        }
+                    }
+                }
+            }
+
+            Label {
+                visible: insightsPage.hasInsights
+                text: "Updated " + new Date(insightsPage.insights.fetchedAt || Date.now()).toLocaleTimeString(Qt.locale(), Locale.ShortFormat)
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textMuted
+                Layout.leftMargin: Theme.spacingLg
+                Layout.bottomMargin: Theme.spacingLg
+            }
+        }
+    }
+}
@@ -9,7 +9,19 @@ Page {
     id: notePage
     title: "Notes"
 
-    property int noteCount: 0
+    // Index of the note being dragged to a new position, or -1
+    property int dragFrom: -1
+
+    // Drop the dragged note on the card under `scenePos`
+    function finishDrag(scenePos) {
+        const from = dragFrom;
+        dragFrom = -1;
+        const p = notesFlow.mapFromItem(null, scenePos.x, scenePos.y);
+        const target = notesFlow.childAt(p.x, p.y);
+        if (from < 0 || !target || target.noteIndex === undefined || target.noteIndex === from)
+            return;
+        noteModel.move_note(from, target.noteIndex);
+    }
 
     background: Rectangle {
         color: Theme.background
@@ -19,26 +31,70 @@ Page {
         id: noteModel
     }
 
+    GistModel {
+        id: gistModel
+    }
+
+    LabelModel {
+        id: labelModel
+    }
+
     Timer {
         id: pollTimer
         interval: 100
         running: true
         repeat: true
-        onTriggered: noteModel.poll_channel()
+        onTriggered: {
+            noteModel.poll_channel();
+            gistModel.poll_channel();
+            labelModel.poll_channel();
+        }
     }
 
+    // Scroll back to where the notes were last session once the first page is in
+    property bool scrollRestored: false
+
     Connections {
         target: noteModel
-        function onNotes_changed() {
-            notePage.noteCount = noteModel.row_count();
-        }
         function onLoadingChanged() {
-            if (!noteModel.loading) {
-                notePage.noteCount = noteModel.row_count();
+            if (!noteModel.loading && !notePage.scrollRestored) {
+                notePage.scrollRestored = true;
+                Qt.callLater(() => AppContext.restoreScroll("notes", notesScroll.contentItem));
             }
         }
     }
 
+    Connections {
+        target: gistModel
+        function onGist_shared(url) {
+            gistBanner.url = url;
+        }
+    }
+
+    Connections {
+        target: labelModel
+        function onNotes_relabelled(count) {
+            noteModel.fetch_notes();
+        }
+    }
+
+    // Edits may have added labels or changed how many notes use them
+    Connections {
+        target: noteModel
+        function onDataChanged() {
+            labelModel.fetch_labels();
+        }
+        function onRowsInserted() {
+            labelModel.fetch_labels();
+        }
+        function onRowsRemoved() {
+            labelModel.fetch_labels();
+        }
+        function onModelReset() {
+            labelModel.fetch_labels();
+        }
+    }
+
     header: ToolBar {
         background: Rectangle {
             color: "transparent"
@@ -148,6 +204,137 @@ Page {
                     }
                 }
 
+                ToolButton {
+                    text: "Trash"
+                    font.pixelSize: Theme.fontSizeSmall
+                    onClicked: noteModel.set_filter("trash")
+                    ToolTip.text: "Recently deleted notes"
+                    ToolTip.visible: hovered
+
+                    background: Rectangle {
+                        radius: Theme.buttonRadius
+                        color: parent.hovered ? Theme.surfaceHover : "transparent"
+                    }
+
+                    contentItem: Text {
+                        text: parent.text
+                        color: Theme.text
+                        font.pixelSize: Theme.fontSizeSmall
+                        horizontalAlignment: Text.AlignHCenter
+                        verticalAlignment: Text.AlignVCenter
+                    }
+                }
+
+                ToolButton {
+                    text: "Templates"
+                    font.pixelSize: Theme.fontSizeSmall
+                    onClicked: {
+                        templateMenu.templates = JSON.parse(noteModel.get_note_templates());
+                        templateMenu.popup();
+                    }
+                    ToolTip.text: "New note from a template"
+                    ToolTip.visible: hovered
+
+                    background: Rectangle {
+                        radius: Theme.buttonRadius
+                        color: parent.hovered ? Theme.surfaceHover : "transparent"
+                    }
+
+                    contentItem: Text {
+                        text: parent.text
+                        color: Theme.text
+                        font.pixelSize: Theme.fontSizeSmall
+                        horizontalAlignment: Text.AlignHCenter
+                        verticalAlignment: Text.AlignVCenter
+                    }
+
+                    Menu {
+                        id: templateMenu
+                        property var templates: []
+
+                        Instantiator {
+                            model: templateMenu.templates
+                            delegate: MenuItem {
+                                required property var modelData
+                                text: modelData.schedule ? modelData.name + " (" + modelData.schedule + ")" : modelData.name
+                                onTriggered: noteModel.create_note_from_template(modelData.id)
+                            }
+                            onObjectAdded: (index, object) => templateMenu.insertItem(index, object)
+                            onObjectRemoved: (index, object) => templateMenu.removeItem(object)
+                        }
+
+                        MenuItem {
+                            text: "No templates yet"
+                            enabled: false
+                            visible: templateMenu.templates.length === 0
+                            height: visible ? implicitHeight : 0
+                        }
+                    }
+                }
+
+                ToolButton {
+                    text: "Labels"
+                    font.pixelSize: Theme.fontSizeSmall
+                    onClicked: labelMenu.popup()
+                    ToolTip.text: "Show notes with a label"
+                    ToolTip.visible: hovered
+
+                    background: Rectangle {
+                        radius: Theme.buttonRadius
+                        color: parent.hovered ? Theme.surfaceHover : "transparent"
+                    }
+
+                    contentItem: Text {
+                        text: parent.text
+                        color: Theme.text
+                        font.pixelSize: Theme.fontSizeSmall
+                        horizontalAlignment: Text.AlignHCenter
+                        verticalAlignment: Text.AlignVCenter
+                    }
+
+                    Menu {
+                        id: labelMenu
+                        property int labelCount: 0
+
+                        Connections {
+                            target: labelModel
+                            function onLabels_changed() {
+                                // Reset first so entries re-read names that changed in place
+                                labelMenu.labelCount = 0;
+                                labelMenu.labelCount = labelModel.row_count();
+                            }
+                        }
+
+                        Instantiator {
+                            model: labelMenu.labelCount
+                            delegate: MenuItem {
+                                required property int index
+                                text: labelModel.get_name(index) + "  (" + labelModel.get_count(index) + ")"
+                                onTriggered: {
+                                    labelFilterField.text = labelModel.get_name(index);
+                                    noteModel.set_filter("label:" + labelModel.get_name(index));
+                                }
+                            }
+                            onObjectAdded: (index, object) => labelMenu.insertItem(index, object)
+                            onObjectRemoved: (index, object) => labelMenu.removeItem(object)
+                        }
+
+                        MenuItem {
+                            text: "No labels yet"
+                            enabled: false
+                            visible: labelMenu.labelCount === 0
+                            height: visible ? implicitHeight : 0
+                        }
+
+                        MenuSeparator {}
+
+                        MenuItem {
+                            text: "Manage labels…"
+                            onTriggered: manageLabelsPopup.open()
+                        }
+                    }
+                }
+
                 TextField {
                     id: labelFilterField
                     placeholderText: "Filter by label"
@@ -224,9 +411,143 @@ Page {
             }
         }
 
+        // Result of "Share as gist": link to the new secret gist, or why it failed
+        Rectangle {
+            id: gistBanner
+            property string url: ""
+            readonly property bool failed: gistModel.error_message.length > 0
+
+            visible: url.length > 0 || failed || gistModel.loading
+            Layout.fillWidth: true
+            Layout.preferredHeight: 48
+            color: failed ? Theme.errorBg : Theme.successBg
+            radius: Theme.cardRadius
+
+            RowLayout {
+                anchors.fill: parent
+                anchors.margins: Theme.spacingMd
+                spacing: Theme.spacingMd
+
+                Label {
+                    text: Icons.githubLogo
+                    font.family: Icons.family
+                    font.pixelSize: 18
+                    color: gistBanner.failed ? Theme.error : Theme.success
+                }
+
+                Label {
+                    text: gistModel.loading ? "Publishing gist..."
+                        : gistBanner.failed ? gistModel.error_message
+                        : "Shared as secret gist: " + gistBanner.url
+                    color: gistBanner.failed ? Theme.error : Theme.text
+                    elide: Text.ElideMiddle
+                    Layout.fillWidth: true
+                }
+
+                Button {
+                    text: "Open"
+                    visible: !gistBanner.failed && gistBanner.url.length > 0 && !gistModel.loading
+                    flat: true
+                    onClicked: Qt.openUrlExternally(gistBanner.url)
+                }
+
+                ToolButton {
+                    text: Icons.x
+                    font.family: Icons.family
+                    visible: !gistModel.loading
+                    onClicked: {
+                        gistBanner.url = "";
+                        gistModel.error_message = "";
+                    }
+                }
+            }
+        }
+
+        // Shown instead of the notes while encrypted notes are locked
+        ColumnLayout {
+            visible: noteModel.locked
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            spacing: Theme.spacingMd
+
+            Item {
+                Layout.fillHeight: true
+            }
+
+            Label {
+                text: Icons.lock
+                font.family: Icons.family
+                font.pixelSize: 48
+                color: Theme.textSecondary
+                Layout.alignment: Qt.AlignHCenter
+            }
+
+            Label {
+                text: "Notes are encrypted"
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                Layout.alignment: Qt.AlignHCenter
+            }
+
+            Label {
+                text: "Enter your passphrase to unlock them."
+                color: Theme.textSecondary
+                Layout.alignment: Qt.AlignHCenter
+            }
+
+            RowLayout {
+                Layout.alignment: Qt.AlignHCenter
+                spacing: Theme.spacingSm
+
+                TextField {
+                    id: passphraseField
+                    placeholderText: "Passphrase"
+                    echoMode: TextInput.Password
+                    Layout.preferredWidth: 260
+                    enabled: !noteModel.loading
+                    onAccepted: unlockButton.clicked()
+
+                    background: Rectangle {
+                        color: Theme.inputBg
+                        border.color: Theme.inputBorder
+                        border.width: 1
+                        radius: Theme.inputRadius
+                    }
+                }
+
+                Button {
+                    id: unlockButton
+                    text: "Unlock"
+                    enabled: passphraseField.text.length > 0 && !noteModel.loading
+                    onClicked: {
+                        noteModel.unlock(passphraseField.text);
+                        passphraseField.text = "";
+                    }
+
+                    background: Rectangle {
+                        radius: Theme.buttonRadius
+                        color: parent.hovered ? Theme.primaryHover : Theme.primary
+                    }
+
+                    contentItem: Text {
+                        text: parent.text
+                        color: Theme.primaryText
+                        horizontalAlignment: Text.AlignHCenter
+                        verticalAlignment: Text.AlignVCenter
+                    }
+                }
+            }
+
+            Item {
+                Layout.fillHeight: true
+            }
+        }
+
         QuickAddBar {
             id: quickAdd
             Layout.fillWidth: true
+            visible: !noteModel.locked
             onCreateNote: (text, isChecklist) => {
                 if (text.trim().length > 0) {
                     if (isChecklist) {
@@ -241,34 +562,69 @@ Page {
         ScrollView {
             id: notesScroll
             Layout.fillWidth: true
+            visible: !noteModel.locked
             Layout.fillHeight: true
             clip: true
             contentWidth: notesFlow.width
             contentHeight: notesFlow.height
 
+            Connections {
+                target: notesScroll.contentItem
+                function onContentYChanged() {
+                    if (notePage.scrollRestored) AppContext.saveScroll("notes", notesScroll.contentItem);
+                }
+            }
+
+            // Infinite scroll: load the next page near the bottom
+            Connections {
+                target: notesScroll.ScrollBar.vertical
+                function onPositionChanged() {
+                    const bar = notesScroll.ScrollBar.vertical;
+                    if (noteModel.has_more && !noteModel.loading && bar.position + bar.size > 0.9)
+                        noteModel.fetch_more();
+                }
+            }
+
             Flow {
                 id: notesFlow
                 width: notesScroll.viewport ? notesScroll.viewport.width : notesScroll.width
                 spacing: Theme.spacingMd
 
                 Repeater {
-                    model: notePage.noteCount
+                    id: notesRepeater
+                    model: noteModel
 
                     delegate: NoteCard {
                         required property int index
                         noteIndex: index
                         noteModel: noteModel
                         cardWidth: Math.min(280, Math.max(180, (notesFlow.width - Theme.spacingMd * 2) / 3))
+                        canShareGist: gistModel.authenticated
+                        labelModel: labelModel
                         onPromoteRequested: (idx, title) => {
                             promoteDialog.noteIndex = idx;
                             promoteDialog.noteTitle = title;
                             promoteDialog.open();
                         }
+                        onShareGistRequested: (idx, content) => gistModel.share_note(content)
+                        scale: notePage.dragFrom === index ? 0.95 : 1
+
+                        // Drag a card onto another to reorder (pinned and unpinned separately)
+                        DragHandler {
+                            target: null
+                            enabled: !parent.editing && !parent.trashed
+                            onActiveChanged: {
+                                if (active)
+                                    notePage.dragFrom = parent.noteIndex;
+                                else
+                                    notePage.finishDrag(centroid.scenePosition);
+                            }
+                        }
                     }
                 }
 
                 Column {
-                    visible: !noteModel.loading && noteModel.row_count() === 0
+                    visible: !noteModel.loading && notesRepeater.count === 0
                     width: notesFlow.width
                     spacing: Theme.spacingMd
 
@@ -319,12 +675,8 @@ Page {
 
                 Label {
                     text: {
-                        const total = noteModel.row_count();
-                        let done = 0;
-                        for (let i = 0; i < total; i++) {
-                            if (noteModel.get_done(i))
-                                done++;
-                        }
+                        const total = notesRepeater.count;
+                        const done = noteModel.done_count;
                         return `${total} notes (${done} done, ${total - done} pending)`;
                     }
                     font.pixelSize: Theme.fontSizeSmall
@@ -356,6 +708,132 @@ Page {
         }
     }
 
+    // Rename, merge or delete a label on every note at once
+    Popup {
+        id: manageLabelsPopup
+        anchors.centerIn: parent
+        width: 360
+        padding: Theme.spacingMd
+        modal: true
+
+        property string selected: ""
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        onOpened: {
+            selected = "";
+            labelModel.fetch_labels();
+        }
+
+        ColumnLayout {
+            width: parent.width
+            spacing: Theme.spacingSm
+
+            Label {
+                text: "Labels"
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+            }
+
+            ListView {
+                id: manageLabelsList
+                Layout.fillWidth: true
+                Layout.preferredHeight: Math.min(contentHeight, 240)
+                clip: true
+                model: labelMenu.labelCount
+
+                delegate: ItemDelegate {
+                    required property int index
+                    width: manageLabelsList.width
+                    highlighted: manageLabelsPopup.selected === labelModel.get_name(index)
+                    text: labelModel.get_name(index) + "  (" + labelModel.get_count(index) + ")"
+                    onClicked: {
+                        manageLabelsPopup.selected = labelModel.get_name(index);
+                        newLabelNameField.text = labelModel.get_name(index);
+                    }
+                }
+            }
+
+            TextField {
+                id: newLabelNameField
+                Layout.fillWidth: true
+                placeholderText: manageLabelsPopup.selected ? "New name or label to merge into" : "New label"
+            }
+
+            Label {
+                visible: labelModel.error_message.length > 0
+                text: labelModel.error_message
+                color: Theme.error
+                font.pixelSize: Theme.fontSizeSmall
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: Theme.spacingSm
+
+                Button {
+                    text: "Add"
+                    visible: manageLabelsPopup.selected.length === 0
+                    enabled: newLabelNameField.text.trim().length > 0
+                    onClicked: {
+                        labelModel.create_label(newLabelNameField.text);
+                        newLabelNameField.text = "";
+                    }
+                }
+
+                Button {
+                    text: "Rename"
+                    visible: manageLabelsPopup.selected.length > 0
+                    enabled: newLabelNameField.text.trim().length > 0
+                    onClicked: {
+                        labelModel.rename_label(manageLabelsPopup.selected, newLabelNameField.text);
+                        manageLabelsPopup.selected = "";
+                        newLabelNameField.text = "";
+                    }
+                }
+
+                Button {
+                    text: "Merge into"
+                    visible: manageLabelsPopup.selected.length > 0
+                    enabled: newLabelNameField.text.trim().length > 0
+                    ToolTip.visible: hovered
+                    ToolTip.text: "Give its notes the label typed above instead"
+                    onClicked: {
+                        labelModel.merge_labels(manageLabelsPopup.selected, newLabelNameField.text);
+                        manageLabelsPopup.selected = "";
+                        newLabelNameField.text = "";
+                    }
+                }
+
+                Button {
+                    text: "Delete"
+                    visible: manageLabelsPopup.selected.length > 0
+                    onClicked: {
+                        labelModel.delete_label(manageLabelsPopup.selected);
+                        manageLabelsPopup.selected = "";
+                        newLabelNameField.text = "";
+                    }
+                }
+
+                Item { Layout.fillWidth: true }
+
+                Button {
+                    text: "Close"
+                    flat: true
+                    onClicked: manageLabelsPopup.close()
+                }
+            }
+        }
+    }
+
     Dialog {
         id: promoteDialog
         title: "Promote to Project"
@@ -487,5 +965,10 @@ Page {
 
     Component.onCompleted: {
         noteModel.fetch_notes();
+        gistModel.check_auth();
+        labelModel.fetch_labels();
     }
+
+    // Pick up notes changed elsewhere (quick capture, remote control) on return
+    onVisibleChanged: if (visible) noteModel.refresh_changes()
 }
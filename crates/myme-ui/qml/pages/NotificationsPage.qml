@@ -0,0 +1,317 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: notificationsPage
+    title: "GitHub Inbox"
+
+    property int rowCount: 0
+    // Bumped on every model change so per-row getters re-evaluate
+    property int revision: 0
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    NotificationsModel {
+        id: notificationsModel
+    }
+
+    // Deliver service results (fetches, mark-read, unsubscribe)
+    Timer {
+        interval: 100
+        running: true
+        repeat: true
+        onTriggered: notificationsModel.poll_channel()
+    }
+
+    // Poll GitHub for new review requests, mentions and CI failures
+    Timer {
+        interval: 60000
+        running: notificationsModel.authenticated
+        repeat: true
+        onTriggered: notificationsModel.fetch_notifications()
+    }
+
+    Component.onCompleted: {
+        notificationsModel.check_auth();
+        if (notificationsModel.authenticated) {
+            notificationsModel.fetch_notifications();
+        }
+    }
+
+    Connections {
+        target: notificationsModel
+        function onNotifications_changed() {
+            notificationsPage.rowCount = notificationsModel.row_count();
+            notificationsPage.revision++;
+        }
+        function onAuthenticatedChanged() {
+            if (notificationsModel.authenticated) {
+                notificationsModel.fetch_notifications();
+            }
+        }
+    }
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            Label {
+                text: notificationsModel.unread_count > 0
+                    ? "GitHub Inbox (" + notificationsModel.unread_count + ")"
+                    : "GitHub Inbox"
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                Layout.fillWidth: true
+                leftPadding: Theme.spacingMd
+            }
+
+            CheckBox {
+                text: "Show read"
+                checked: notificationsModel.show_all
+                enabled: notificationsModel.authenticated
+                onToggled: {
+                    notificationsModel.show_all = checked;
+                    notificationsModel.fetch_notifications();
+                }
+            }
+
+            ToolButton {
+                text: Icons.arrowsClockwise
+                font.family: Icons.family
+                font.pixelSize: 18
+                enabled: notificationsModel.authenticated && !notificationsModel.loading
+                onClicked: notificationsModel.fetch_notifications()
+                ToolTip.text: "Refresh"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    font.family: Icons.family
+                    color: Theme.text
+                    font.pixelSize: 18
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+            }
+
+            Item { width: Theme.spacingSm }
+        }
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        anchors.margins: Theme.spacingLg
+        spacing: Theme.spacingMd
+
+        // Not authenticated
+        Rectangle {
+            visible: !notificationsModel.authenticated
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            color: Theme.surface
+            border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+            border.width: 1
+            radius: Theme.cardRadius
+
+            ColumnLayout {
+                anchors.centerIn: parent
+                spacing: Theme.spacingMd
+
+                Label {
+                    text: Icons.githubLogo
+                    font.family: Icons.family
+                    font.pixelSize: 64
+                    color: Theme.textSecondary
+                    Layout.alignment: Qt.AlignHCenter
+                }
+
+                Label {
+                    text: "Connect GitHub to see your notifications"
+                    font.pixelSize: Theme.fontSizeMedium
+                    color: Theme.textSecondary
+                    Layout.alignment: Qt.AlignHCenter
+                }
+            }
+        }
+
+        // Error message
+        Rectangle {
+            visible: notificationsModel.authenticated && notificationsModel.error_message.length > 0
+            Layout.fillWidth: true
+            Layout.preferredHeight: 60
+            color: Theme.errorBg
+            radius: Theme.cardRadius
+
+            RowLayout {
+                anchors.fill: parent
+                anchors.margins: Theme.spacingMd
+                spacing: Theme.spacingSm
+
+                Label {
+                    text: notificationsModel.error_message
+                    font.pixelSize: Theme.fontSizeNormal
+                    color: Theme.error
+                    wrapMode: Text.WordWrap
+                    Layout.fillWidth: true
+                }
+
+                Button {
+                    text: "Retry"
+                    onClicked: notificationsModel.fetch_notifications()
+                }
+            }
+        }
+
+        // Loading (no data yet)
+        BusyIndicator {
+            visible: notificationsModel.authenticated && notificationsModel.loading && notificationsPage.rowCount === 0
+            running: visible
+            Layout.alignment: Qt.AlignCenter
+        }
+
+        // Inbox zero
+        Rectangle {
+            visible: notificationsModel.authenticated && !notificationsModel.loading && notificationsPage.rowCount === 0 && notificationsModel.error_message.length === 0
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            color: Theme.surface
+            border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+            border.width: 1
+            radius: Theme.cardRadius
+
+            Label {
+                anchors.centerIn: parent
+                text: "All caught up"
+                font.pixelSize: Theme.fontSizeMedium
+                color: Theme.textSecondary
+            }
+        }
+
+        ScrollView {
+            id: notificationsScroll
+            visible: notificationsModel.authenticated && notificationsPage.rowCount > 0
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+            contentWidth: notificationsScroll.viewport.width
+
+            ColumnLayout {
+                width: notificationsScroll.viewport.width
+                spacing: Theme.spacingSm
+
+                Repeater {
+                    model: notificationsPage.rowCount
+
+                    Rectangle {
+                        id: row
+                        required property int index
+                        readonly property bool unread: notificationsPage.revision >= 0 && notificationsModel.get_unread(index)
+                        Layout.fillWidth: true
+                        Layout.preferredHeight: rowLayout.implicitHeight + Theme.spacingSm * 2
+                        color: rowMouse.containsMouse ? Theme.surfaceHover : Theme.surface
+                        border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                        border.width: 1
+                        radius: Theme.cardRadius
+                        opacity: unread ? 1.0 : 0.6
+
+                        MouseArea {
+                            id: rowMouse
+                            anchors.fill: parent
+                            hoverEnabled: true
+                            cursorShape: Qt.PointingHandCursor
+                            onClicked: {
+                                const url = notificationsModel.get_html_url(row.index);
+                                if (url.length > 0) {
+                                    Qt.openUrlExternally(url);
+                                }
+                                if (row.unread) {
+                                    notificationsModel.mark_read(row.index);
+                                }
+                            }
+                        }
+
+                        RowLayout {
+                            id: rowLayout
+                            anchors.fill: parent
+                            anchors.margins: Theme.spacingSm
+                            spacing: Theme.spacingSm
+
+                            Rectangle {
+                                width: 8
+                                height: 8
+                                radius: 4
+                                color: row.unread ? Theme.primary : "transparent"
+                                Layout.alignment: Qt.AlignVCenter
+                            }
+
+                            ColumnLayout {
+                                Layout.fillWidth: true
+                                spacing: 2
+
+                                Label {
+                                    text: notificationsPage.revision >= 0 ? notificationsModel.get_title(row.index) : ""
+                                    font.pixelSize: Theme.fontSizeNormal
+                                    font.bold: row.unread
+                                    color: Theme.text
+                                    elide: Text.ElideRight
+                                    Layout.fillWidth: true
+                                }
+
+                                Label {
+                                    text: notificationsPage.revision >= 0
+                                        ? notificationsModel.get_repo(row.index) + " · " + notificationsModel.get_kind(row.index)
+                                        : ""
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    color: Theme.textSecondary
+                                    elide: Text.ElideRight
+                                    Layout.fillWidth: true
+                                }
+                            }
+
+                            Label {
+                                text: notificationsPage.revision >= 0 ? notificationsModel.get_reason(row.index) : ""
+                                font.pixelSize: Theme.fontSizeSmall
+                                color: Theme.textMuted
+                            }
+
+                            ToolButton {
+                                text: Icons.check
+                                font.family: Icons.family
+                                visible: row.unread
+                                onClicked: notificationsModel.mark_read(row.index)
+                                ToolTip.text: "Mark as read"
+                                ToolTip.visible: hovered
+                            }
+
+                            ToolButton {
+                                text: Icons.x
+                                font.family: Icons.family
+                                onClicked: notificationsModel.unsubscribe(row.index)
+                                ToolTip.text: "Unsubscribe from thread"
+                                ToolTip.visible: hovered
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
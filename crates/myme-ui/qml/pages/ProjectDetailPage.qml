@@ -18,15 +18,22 @@ Page {
         } catch (e) { return false; }
     }
 
-    // Kanban columns configuration
-    readonly property var columns: [
-        { key: "backlog", label: "Backlog", color: "#8a8580" },
-        { key: "todo", label: "Todo", color: "#64b5f6" },
-        { key: "inprogress", label: "In Progress", color: "#e5a54b" },
-        { key: "blocked", label: "Blocked", color: "#e57373" },
-        { key: "review", label: "Review", color: "#b39ddb" },
-        { key: "done", label: "Done", color: "#5bb98c" }
-    ]
+    // Kanban columns, per project (defaults to one per status)
+    property var columns: []
+
+    function loadColumns() {
+        try {
+            const parsed = JSON.parse(kanbanModel.get_columns()) || [];
+            columns = parsed.map(c => ({ key: c.id, label: c.name, githubLabel: c.label, color: c.color, wipLimit: c.wipLimit }));
+        } catch (e) {
+            columns = [];
+        }
+        columnsRepeater.model = 0;
+        columnsRepeater.model = columns.length;
+    }
+
+    // Bumped when tasks change so column counts re-evaluate
+    property int taskRevision: 0
 
     // Track the currently dragged task
     property int draggedTaskIndex: -1
@@ -54,15 +61,24 @@ Page {
         onTriggered: projectModel.poll_channel()
     }
 
-    // Poll timer for async kanban operations
+    // Poll timer for async kanban operations; slower when idle to pick up
+    // issues imported by the GitHub sync
     Timer {
         id: kanbanPollTimer
-        interval: 100
-        running: kanbanModel.loading
+        interval: kanbanModel.loading || kanbanModel.templates_loading ? 100 : 1000
+        running: true
         repeat: true
         onTriggered: kanbanModel.poll_channel()
     }
 
+    // Undoing a card move updates the store; pick up the changed task
+    Connections {
+        target: AppContext.undoManager
+        function onUndo_applied(service, summary, redo) {
+            if (service === "kanban") kanbanModel.poll_channel();
+        }
+    }
+
     // Force UI update when loading finishes
     Connections {
         target: kanbanModel
@@ -74,6 +90,33 @@ Page {
         }
     }
 
+    Connections {
+        target: kanbanModel
+        function onColumns_changed() {
+            projectDetailPage.loadColumns();
+        }
+    }
+
+    // Column counts and checklist progress aren't roles; recompute on any row change
+    Connections {
+        target: kanbanModel
+        function onDataChanged() {
+            projectDetailPage.taskRevision++;
+        }
+        function onRowsInserted() {
+            projectDetailPage.taskRevision++;
+        }
+        function onRowsRemoved() {
+            projectDetailPage.taskRevision++;
+        }
+        function onRowsMoved() {
+            projectDetailPage.taskRevision++;
+        }
+        function onModelReset() {
+            projectDetailPage.taskRevision++;
+        }
+    }
+
     // Reload kanban when project repos change (e.g. after adding repo)
     Connections {
         target: projectModel
@@ -96,7 +139,11 @@ Page {
                 text: Icons.caretLeft
                 font.family: Icons.family
                 font.pixelSize: 18
-                onClicked: AppContext.pageStack.pop()
+                onClicked: {
+                    // Leaving the board on purpose: don't reopen it next start
+                    if (AppContext.sessionModel) AppContext.sessionModel.forget_last_project();
+                    AppContext.pageStack.pop();
+                }
                 ToolTip.text: "Back to Projects"
                 ToolTip.visible: hovered
 
@@ -125,6 +172,23 @@ Page {
                 elide: Text.ElideMiddle
             }
 
+            // Board filter, restored per project from the last session
+            TextField {
+                id: boardFilterField
+                placeholderText: "Filter tasks"
+                text: kanbanModel.filter
+                Layout.preferredWidth: 180
+                font.pixelSize: Theme.fontSizeSmall
+                onTextEdited: kanbanModel.apply_filter(text)
+
+                background: Rectangle {
+                    color: Theme.inputBg
+                    border.color: Theme.inputBorder
+                    border.width: 1
+                    radius: Theme.inputRadius
+                }
+            }
+
             // Add repo button
             ToolButton {
                 text: Icons.plus
@@ -179,6 +243,58 @@ Page {
                 }
             }
 
+            // Analytics button
+            ToolButton {
+                text: "Analytics"
+                font.pixelSize: Theme.fontSizeNormal
+                onClicked: analyticsDialog.open()
+                ToolTip.text: "Cumulative flow, burndown and cycle time"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: Theme.text
+                    font.pixelSize: Theme.fontSizeNormal
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
+            // Add column button
+            ToolButton {
+                text: Icons.squaresFour
+                font.family: Icons.family
+                font.pixelSize: 18
+                enabled: !kanbanModel.loading
+                onClicked: {
+                    columnDialog.columnKey = "";
+                    columnDialog.open();
+                }
+                ToolTip.text: "Add Column"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    font.family: Icons.family
+                    color: Theme.text
+                    font.pixelSize: 18
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+            }
+
             // New Task button
             ToolButton {
                 text: Icons.plus
@@ -267,7 +383,7 @@ Page {
 
         // Loading indicator (centered when no tasks)
         BusyIndicator {
-            visible: kanbanModel.loading && kanbanModel.row_count() === 0
+            visible: kanbanModel.loading && projectDetailPage.taskRevision >= 0 && kanbanModel.rowCount() === 0
             running: kanbanModel.loading
             Layout.alignment: Qt.AlignHCenter
         }
@@ -297,6 +413,7 @@ Page {
                         property string columnKey: columnData.key
                         property string columnLabel: columnData.label
                         property color columnColor: columnData.color
+                        property int wipLimit: columnData.wipLimit || 0
 
                         Layout.preferredWidth: 280
                         Layout.fillHeight: true
@@ -317,8 +434,21 @@ Page {
                             keys: ["task"]
 
                             onDropped: (drop) => {
-                                if (projectDetailPage.draggedTaskIndex >= 0) {
-                                    kanbanModel.move_task(projectDetailPage.draggedTaskIndex, columnContainer.columnKey);
+                                const taskIndex = projectDetailPage.draggedTaskIndex;
+                                if (taskIndex >= 0) {
+                                    // Card under the drop point, or the end of the column
+                                    const pos = tasksList.mapFromItem(dropArea, drop.x, drop.y);
+                                    const row = tasksList.indexAt(pos.x, pos.y + tasksList.contentY);
+                                    let position = row >= 0 ? kanbanModel.position_in_column(row) : -1;
+                                    if (position < 0) {
+                                        position = kanbanModel.count_in_column(columnContainer.columnKey);
+                                    }
+                                    // A refused move (column at its WIP limit) leaves the card where it was
+                                    const moved = projectDetailPage.draggedFromColumn === columnContainer.columnKey
+                                        || kanbanModel.move_task(taskIndex, columnContainer.columnKey);
+                                    if (moved) {
+                                        kanbanModel.reorder_task(taskIndex, position);
+                                    }
                                     projectDetailPage.draggedTaskIndex = -1;
                                     projectDetailPage.draggedFromColumn = "";
                                 }
@@ -352,22 +482,38 @@ Page {
                                     Layout.fillWidth: true
                                 }
 
-                                // Count badge
+                                // Count badge, against the WIP limit when the column has one
                                 Rectangle {
-                                    visible: kanbanModel.count_by_status(columnContainer.columnKey) > 0
+                                    id: countBadge
+                                    readonly property int count: projectDetailPage.taskRevision >= 0 ? kanbanModel.count_in_column(columnContainer.columnKey) : 0
+                                    readonly property bool overWip: projectDetailPage.taskRevision >= 0 && kanbanModel.is_over_wip(columnContainer.columnKey)
+                                    visible: count > 0 || columnContainer.wipLimit > 0
                                     width: countLabel.implicitWidth + Theme.spacingSm * 2
                                     height: 22
                                     radius: 11
-                                    color: columnContainer.columnColor + "30"
+                                    color: overWip ? Theme.errorBg : columnContainer.columnColor + "30"
+                                    border.color: Theme.error
+                                    border.width: overWip ? 1 : 0
 
                                     Label {
                                         id: countLabel
                                         anchors.centerIn: parent
-                                        text: kanbanModel.count_by_status(columnContainer.columnKey)
+                                        text: columnContainer.wipLimit > 0
+                                            ? countBadge.count + " / " + columnContainer.wipLimit
+                                            : countBadge.count
                                         font.family: Theme.fontFamily
                                         font.pixelSize: Theme.fontSizeSmall
                                         font.bold: true
-                                        color: columnContainer.columnColor
+                                        color: countBadge.overWip ? Theme.error : columnContainer.columnColor
+                                    }
+
+                                    ToolTip.visible: overWip && countMouse.containsMouse
+                                    ToolTip.text: "Over the WIP limit of " + columnContainer.wipLimit
+
+                                    MouseArea {
+                                        id: countMouse
+                                        anchors.fill: parent
+                                        hoverEnabled: true
                                     }
                                 }
 
@@ -401,6 +547,55 @@ Page {
                                     ToolTip.text: "Add task to " + columnContainer.columnLabel
                                     ToolTip.delay: 500
                                 }
+
+                                // Column options
+                                Rectangle {
+                                    width: 24
+                                    height: 24
+                                    radius: Theme.buttonRadius
+                                    color: columnMenuMouseArea.containsMouse ? Theme.surfaceHover : "transparent"
+
+                                    Label {
+                                        anchors.centerIn: parent
+                                        text: Icons.dotsThree
+                                        font.family: Icons.family
+                                        font.pixelSize: 14
+                                        color: Theme.textSecondary
+                                    }
+
+                                    MouseArea {
+                                        id: columnMenuMouseArea
+                                        anchors.fill: parent
+                                        hoverEnabled: true
+                                        cursorShape: Qt.PointingHandCursor
+                                        onClicked: columnMenu.popup()
+                                    }
+
+                                    Menu {
+                                        id: columnMenu
+
+                                        MenuItem {
+                                            text: "Edit column..."
+                                            onTriggered: {
+                                                columnDialog.columnKey = columnContainer.columnKey;
+                                                columnDialog.columnName = columnContainer.columnLabel;
+                                                columnDialog.columnGithubLabel = columnContainer.columnData.githubLabel;
+                                                columnDialog.columnWipLimit = columnContainer.wipLimit;
+                                                columnDialog.open();
+                                            }
+                                        }
+                                        MenuItem {
+                                            text: "Move left"
+                                            enabled: columnContainer.index > 0
+                                            onTriggered: kanbanModel.move_column(columnContainer.columnKey, -1)
+                                        }
+                                        MenuItem {
+                                            text: "Move right"
+                                            enabled: columnContainer.index < projectDetailPage.columns.length - 1
+                                            onTriggered: kanbanModel.move_column(columnContainer.columnKey, 1)
+                                        }
+                                    }
+                                }
                             }
 
                             // Separator
@@ -416,150 +611,182 @@ Page {
                                 Layout.fillWidth: true
                                 Layout.fillHeight: true
                                 clip: true
-                                spacing: Theme.spacingSm
-
-                                model: {
-                                    try {
-                                        const indices = JSON.parse(kanbanModel.tasks_for_status(columnContainer.columnKey));
-                                        return indices;
-                                    } catch (e) {
-                                        return [];
-                                    }
+                                // Every column shows the whole model and hides other columns' cards,
+                                // so the gap between cards lives in the delegate
+                                spacing: 0
+                                model: kanbanModel
+
+                                // Reloads announce only the rows that changed; animate them
+                                add: Transition {
+                                    NumberAnimation { property: "opacity"; from: 0; to: 1; duration: Theme.transitionDuration }
+                                }
+                                remove: Transition {
+                                    NumberAnimation { property: "opacity"; to: 0; duration: Theme.transitionDuration }
+                                }
+                                move: Transition {
+                                    NumberAnimation { property: "y"; duration: Theme.transitionDuration; easing.type: Easing.OutCubic }
+                                }
+                                displaced: Transition {
+                                    NumberAnimation { property: "y"; duration: Theme.transitionDuration; easing.type: Easing.OutCubic }
                                 }
 
-                                delegate: Rectangle {
-                                    id: taskCard
-                                    required property int modelData
-                                    property int taskIndex: modelData
+                                delegate: Item {
+                                    id: taskSlot
+                                    required property int index
+                                    required property string title
+                                    required property string columnKey
+                                    required property string repoId
+                                    required property int issueNumber
+                                    required property string issueUrl
+                                    required property string subtaskProgress
+                                    required property bool matchesFilter
+
+                                    readonly property bool inColumn: columnKey === columnContainer.columnKey && matchesFilter
 
                                     width: tasksList.width
-                                    height: taskContent.implicitHeight + Theme.spacingMd * 2
-                                    color: taskMouseArea.containsMouse ? Theme.surfaceHover : Theme.surfaceAlt
-                                    border.color: taskMouseArea.containsMouse ? Theme.primary : Theme.borderLight
-                                    border.width: 1
-                                    radius: Theme.cardRadius
-                                    opacity: dragHandler.active ? 0.8 : 1.0
-
-                                    Behavior on color {
-                                        ColorAnimation { duration: 100 }
-                                    }
-                                    Behavior on border.color {
-                                        ColorAnimation { duration: 100 }
-                                    }
+                                    height: inColumn ? taskCard.height + Theme.spacingSm : 0
+                                    visible: inColumn
+
+                                    Rectangle {
+                                        id: taskCard
+                                        property int taskIndex: taskSlot.index
+
+                                        width: tasksList.width
+                                        height: taskContent.implicitHeight + Theme.spacingMd * 2
+                                        color: taskMouseArea.containsMouse ? Theme.surfaceHover : Theme.surfaceAlt
+                                        border.color: taskMouseArea.containsMouse ? Theme.primary : Theme.borderLight
+                                        border.width: 1
+                                        radius: Theme.cardRadius
+                                        opacity: dragHandler.active ? 0.8 : 1.0
+
+                                        Behavior on color {
+                                            ColorAnimation { duration: 100 }
+                                        }
+                                        Behavior on border.color {
+                                            ColorAnimation { duration: 100 }
+                                        }
 
-                                    // Drag handling
-                                    Drag.active: dragHandler.active
-                                    Drag.keys: ["task"]
-                                    Drag.hotSpot.x: width / 2
-                                    Drag.hotSpot.y: height / 2
-
-                                    DragHandler {
-                                        id: dragHandler
-                                        onActiveChanged: {
-                                            if (active) {
-                                                projectDetailPage.draggedTaskIndex = taskCard.taskIndex;
-                                                projectDetailPage.draggedFromColumn = columnContainer.columnKey;
-                                                taskCard.z = 100;
-                                            } else {
-                                                taskCard.z = 0;
+                                        // Drag handling
+                                        Drag.active: dragHandler.active
+                                        Drag.keys: ["task"]
+                                        Drag.hotSpot.x: width / 2
+                                        Drag.hotSpot.y: height / 2
+
+                                        DragHandler {
+                                            id: dragHandler
+                                            onActiveChanged: {
+                                                if (active) {
+                                                    projectDetailPage.draggedTaskIndex = taskCard.taskIndex;
+                                                    projectDetailPage.draggedFromColumn = columnContainer.columnKey;
+                                                    taskSlot.z = 100;
+                                                } else {
+                                                    taskSlot.z = 0;
+                                                }
                                             }
                                         }
-                                    }
 
-                                    MouseArea {
-                                        id: taskMouseArea
-                                        anchors.fill: parent
-                                        hoverEnabled: true
-                                        cursorShape: Qt.PointingHandCursor
-                                        // Let DragHandler handle dragging
-                                        onClicked: {
-                                            taskDetailDialog.taskIndex = taskCard.taskIndex;
-                                            taskDetailDialog.taskTitle = kanbanModel.get_title(taskCard.taskIndex);
-                                            taskDetailDialog.taskBody = kanbanModel.get_body(taskCard.taskIndex);
-                                            taskDetailDialog.open();
+                                        MouseArea {
+                                            id: taskMouseArea
+                                            anchors.fill: parent
+                                            hoverEnabled: true
+                                            cursorShape: Qt.PointingHandCursor
+                                            // Let DragHandler handle dragging
+                                            onClicked: {
+                                                taskDetailDialog.showTask(taskCard.taskIndex);
+                                            }
                                         }
-                                    }
 
-                                    ColumnLayout {
-                                        id: taskContent
-                                        anchors.left: parent.left
-                                        anchors.right: parent.right
-                                        anchors.top: parent.top
-                                        anchors.margins: Theme.spacingMd
-                                        spacing: Theme.spacingXs
-
-                                        // Issue number and GitHub link
-                                        RowLayout {
-                                            Layout.fillWidth: true
+                                        ColumnLayout {
+                                            id: taskContent
+                                            anchors.left: parent.left
+                                            anchors.right: parent.right
+                                            anchors.top: parent.top
+                                            anchors.margins: Theme.spacingMd
                                             spacing: Theme.spacingXs
 
-                                            Label {
-                                                text: "#" + kanbanModel.get_issue_number(taskCard.taskIndex)
-                                                font.family: Theme.fontFamily
-                                                font.pixelSize: Theme.fontSizeSmall
-                                                font.bold: true
-                                                color: columnContainer.columnColor
-                                            }
-
-                                            Item { Layout.fillWidth: true }
-
-                                            // Open on GitHub button
-                                            Rectangle {
-                                                width: 20
-                                                height: 20
-                                                radius: 4
-                                                color: githubMouseArea.containsMouse ? Theme.surfaceHover : "transparent"
+                                            // Issue number and GitHub link
+                                            RowLayout {
+                                                visible: taskSlot.issueNumber > 0
+                                                Layout.fillWidth: true
+                                                spacing: Theme.spacingXs
 
                                                 Label {
-                                                    anchors.centerIn: parent
-                                                    text: Icons.githubLogo
-                                                    font.family: Icons.family
-                                                    font.pixelSize: 12
-                                                    color: Theme.textSecondary
+                                                    text: "#" + taskSlot.issueNumber
+                                                    font.family: Theme.fontFamily
+                                                    font.pixelSize: Theme.fontSizeSmall
+                                                    font.bold: true
+                                                    color: columnContainer.columnColor
                                                 }
 
-                                                MouseArea {
-                                                    id: githubMouseArea
-                                                    anchors.fill: parent
-                                                    hoverEnabled: true
-                                                    cursorShape: Qt.PointingHandCursor
-                                                    onClicked: {
-                                                        Qt.openUrlExternally(kanbanModel.get_url(taskCard.taskIndex));
+                                                Item { Layout.fillWidth: true }
+
+                                                // Open on GitHub button
+                                                Rectangle {
+                                                    width: 20
+                                                    height: 20
+                                                    radius: 4
+                                                    color: githubMouseArea.containsMouse ? Theme.surfaceHover : "transparent"
+
+                                                    Label {
+                                                        anchors.centerIn: parent
+                                                        text: Icons.githubLogo
+                                                        font.family: Icons.family
+                                                        font.pixelSize: 12
+                                                        color: Theme.textSecondary
+                                                    }
+
+                                                    MouseArea {
+                                                        id: githubMouseArea
+                                                        anchors.fill: parent
+                                                        hoverEnabled: true
+                                                        cursorShape: Qt.PointingHandCursor
+                                                        onClicked: {
+                                                            Qt.openUrlExternally(taskSlot.issueUrl);
+                                                        }
                                                     }
+
+                                                    ToolTip.visible: githubMouseArea.containsMouse
+                                                    ToolTip.text: "Open on GitHub"
+                                                    ToolTip.delay: 500
                                                 }
+                                            }
 
-                                                ToolTip.visible: githubMouseArea.containsMouse
-                                                ToolTip.text: "Open on GitHub"
-                                                ToolTip.delay: 500
+                                            // Repo badge (when multiple repos)
+                                            Label {
+                                                visible: {
+                                                    try {
+                                                        const repos = JSON.parse(kanbanModel.repo_ids);
+                                                        return repos && repos.length > 1;
+                                                    } catch (e) { return false; }
+                                                }
+                                                text: taskSlot.repoId
+                                                font.family: Theme.fontFamily
+                                                font.pixelSize: Theme.fontSizeSmall - 1
+                                                color: Theme.textMuted
+                                                Layout.fillWidth: true
+                                                elide: Text.ElideMiddle
                                             }
-                                        }
 
-                                        // Repo badge (when multiple repos)
-                                        Label {
-                                            visible: {
-                                                try {
-                                                    const repos = JSON.parse(kanbanModel.repo_ids);
-                                                    return repos && repos.length > 1;
-                                                } catch (e) { return false; }
+                                            // Task title
+                                            Label {
+                                                text: taskSlot.title
+                                                font.family: Theme.fontFamily
+                                                font.pixelSize: Theme.fontSizeNormal
+                                                color: Theme.text
+                                                Layout.fillWidth: true
+                                                wrapMode: Text.WordWrap
+                                                maximumLineCount: 3
+                                                elide: Text.ElideRight
                                             }
-                                            text: kanbanModel.get_repo_id(taskCard.taskIndex)
-                                            font.family: Theme.fontFamily
-                                            font.pixelSize: Theme.fontSizeSmall - 1
-                                            color: Theme.textMuted
-                                            Layout.fillWidth: true
-                                            elide: Text.ElideMiddle
-                                        }
 
-                                        // Task title
-                                        Label {
-                                            text: kanbanModel.get_title(taskCard.taskIndex)
-                                            font.family: Theme.fontFamily
-                                            font.pixelSize: Theme.fontSizeNormal
-                                            color: Theme.text
-                                            Layout.fillWidth: true
-                                            wrapMode: Text.WordWrap
-                                            maximumLineCount: 3
-                                            elide: Text.ElideRight
+                                            // Checklist progress
+                                            Label {
+                                                visible: taskSlot.subtaskProgress.length > 0
+                                                text: taskSlot.subtaskProgress + " done"
+                                                font.family: Theme.fontFamily
+                                                font.pixelSize: Theme.fontSizeSmall
+                                                color: Theme.textSecondary
+                                            }
                                         }
                                     }
                                 }
@@ -567,13 +794,17 @@ Page {
 
                             // Empty column placeholder
                             Item {
-                                visible: kanbanModel.count_by_status(columnContainer.columnKey) === 0
+                                id: emptyColumn
+                                readonly property int total: projectDetailPage.taskRevision >= 0
+                                    ? kanbanModel.count_in_column(columnContainer.columnKey) : 0
+                                visible: projectDetailPage.taskRevision >= 0
+                                    && JSON.parse(kanbanModel.tasks_for_column(columnContainer.columnKey)).length === 0
                                 Layout.fillWidth: true
                                 Layout.fillHeight: true
 
                                 Label {
                                     anchors.centerIn: parent
-                                    text: "No tasks"
+                                    text: emptyColumn.total > 0 ? "No matching tasks" : "No tasks"
                                     font.family: Theme.fontFamily
                                     font.pixelSize: Theme.fontSizeSmall
                                     color: Theme.textMuted
@@ -595,9 +826,33 @@ Page {
 
         anchors.centerIn: parent
         width: Math.min(parent.width * 0.8, 500)
-        height: 350
+        height: templates.length > 0 ? 420 : 350
 
         property string preselectedStatus: "todo"
+        // Issue templates from the project's repos (.github/ISSUE_TEMPLATE)
+        property var templates: []
+
+        function applyTemplate(index) {
+            if (index <= 0) {
+                return;
+            }
+            const t = templates[index - 1];
+            if (newTaskTitleField.text.trim().length === 0) {
+                newTaskTitleField.text = t.title;
+            }
+            newTaskDescField.text = t.body;
+        }
+
+        Connections {
+            target: kanbanModel
+            function onTemplates_changed() {
+                try {
+                    newTaskDialog.templates = JSON.parse(kanbanModel.get_templates()) || [];
+                } catch (e) {
+                    newTaskDialog.templates = [];
+                }
+            }
+        }
 
         background: Rectangle {
             color: Theme.surface
@@ -646,6 +901,8 @@ Page {
             newTaskDescField.text = "";
         }
 
+        onClosed: newTaskTemplateCombo.currentIndex = 0
+
         onOpened: {
             // Find index for preselected status
             for (let i = 0; i < projectDetailPage.columns.length; i++) {
@@ -661,6 +918,39 @@ Page {
             anchors.fill: parent
             spacing: Theme.spacingMd
 
+            Label {
+                text: "Template:"
+                visible: newTaskDialog.templates.length > 0
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeNormal
+                color: Theme.text
+            }
+
+            ComboBox {
+                id: newTaskTemplateCombo
+                visible: newTaskDialog.templates.length > 0
+                Layout.fillWidth: true
+                model: ["Blank"].concat(newTaskDialog.templates.map(t => t.name))
+                onActivated: index => newTaskDialog.applyTemplate(index)
+                ToolTip.text: currentIndex > 0 ? newTaskDialog.templates[currentIndex - 1].about : ""
+                ToolTip.visible: hovered && ToolTip.text.length > 0
+
+                background: Rectangle {
+                    color: Theme.inputBg
+                    border.color: newTaskTemplateCombo.pressed ? Theme.primary : Theme.inputBorder
+                    border.width: 1
+                    radius: Theme.inputRadius
+                }
+
+                contentItem: Text {
+                    leftPadding: Theme.spacingSm
+                    text: newTaskTemplateCombo.displayText
+                    font.pixelSize: Theme.fontSizeNormal
+                    color: Theme.text
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
             Label {
                 text: "Title:"
                 font.family: Theme.fontFamily
@@ -881,11 +1171,58 @@ Page {
 
         anchors.centerIn: parent
         width: Math.min(parent.width * 0.8, 500)
-        height: 320
+        height: subtasks.length > 0 ? 500 : 420
 
         property int taskIndex: -1
+        property string taskId: ""
         property string taskTitle: ""
         property string taskBody: ""
+        property string issueUrl: ""
+        property var schedules: []
+        property var subtasks: []
+
+        function showTask(index) {
+            const task = JSON.parse(kanbanModel.get_task(index));
+            taskIndex = index;
+            taskId = task.id || "";
+            taskTitle = task.title || "";
+            taskBody = task.body || "";
+            issueUrl = task.issueUrl || "";
+            open();
+        }
+
+        function loadSubtasks() {
+            subtasks = JSON.parse(kanbanModel.get_subtasks(taskIndex));
+        }
+
+        function loadSchedules() {
+            schedules = JSON.parse(kanbanModel.get_schedules(taskId));
+        }
+
+        // Next full hour, as schedule_task expects it
+        function defaultStart() {
+            var start = new Date();
+            start.setHours(start.getHours() + 1, 0, 0, 0);
+            return Qt.formatDateTime(start, "yyyy-MM-dd HH:mm");
+        }
+
+        Connections {
+            target: kanbanModel
+            function onTask_scheduled(taskId) {
+                if (taskId === taskDetailDialog.taskId)
+                    taskDetailDialog.loadSchedules();
+            }
+            // Checklist saved to (or refreshed from) GitHub; unsaved description edits win
+            function onDataChanged(topLeft, bottomRight) {
+                const index = taskDetailDialog.taskIndex;
+                if (!taskDetailDialog.opened || index < topLeft.row || index > bottomRight.row
+                        || editBodyField.text !== taskDetailDialog.taskBody)
+                    return;
+                taskDetailDialog.taskBody = JSON.parse(kanbanModel.get_task(index)).body || "";
+                editBodyField.text = taskDetailDialog.taskBody;
+                taskDetailDialog.loadSubtasks();
+            }
+        }
 
         background: Rectangle {
             color: Theme.surface
@@ -942,7 +1279,7 @@ Page {
                         hoverEnabled: true
                         cursorShape: Qt.PointingHandCursor
                         onClicked: {
-                            Qt.openUrlExternally(kanbanModel.get_url(taskDetailDialog.taskIndex));
+                            Qt.openUrlExternally(taskDetailDialog.issueUrl);
                         }
                     }
 
@@ -966,6 +1303,9 @@ Page {
         onOpened: {
             editTitleField.text = taskTitle;
             editBodyField.text = taskBody;
+            scheduleStartField.text = defaultStart();
+            loadSchedules();
+            loadSubtasks();
             editTitleField.forceActiveFocus();
         }
 
@@ -1034,6 +1374,91 @@ Page {
                     }
                 }
             }
+
+            // Task-list items of the description, ticked here and on GitHub
+            Flow {
+                visible: taskDetailDialog.subtasks.length > 0
+                Layout.fillWidth: true
+                spacing: Theme.spacingXs
+
+                Repeater {
+                    model: taskDetailDialog.subtasks
+
+                    CheckBox {
+                        required property var modelData
+                        required property int index
+                        text: modelData.text
+                        checked: modelData.checked
+                        // Edit the description text instead while it has unsaved changes
+                        enabled: editBodyField.text === taskDetailDialog.taskBody
+                        onToggled: kanbanModel.set_subtask_checked(taskDetailDialog.taskIndex, index, checked)
+                    }
+                }
+            }
+
+            // Time-block the task on the calendar
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: Theme.spacingSm
+
+                Label {
+                    text: "Schedule:"
+                    font.family: Theme.fontFamily
+                    font.pixelSize: Theme.fontSizeNormal
+                    color: Theme.text
+                }
+
+                TextField {
+                    id: scheduleStartField
+                    Layout.fillWidth: true
+                    placeholderText: "YYYY-MM-DD HH:MM"
+                    color: Theme.text
+                    placeholderTextColor: Theme.textMuted
+                }
+
+                SpinBox {
+                    id: scheduleDurationBox
+                    from: 15
+                    to: 480
+                    stepSize: 15
+                    value: 60
+                    editable: true
+                    textFromValue: function(value) { return value + " min"; }
+                    valueFromText: function(text) { return parseInt(text); }
+                }
+
+                Button {
+                    text: "Schedule"
+                    enabled: !kanbanModel.loading && scheduleStartField.text.length > 0
+                    onClicked: kanbanModel.schedule_task(
+                        taskDetailDialog.taskId,
+                        scheduleStartField.text.trim(),
+                        scheduleDurationBox.value
+                    )
+                }
+            }
+
+            Label {
+                Layout.fillWidth: true
+                visible: taskDetailDialog.schedules.length > 0
+                text: "On the calendar: " + taskDetailDialog.schedules.map(function(s) {
+                    return (s.done ? "\u2713 " : "") + s.label;
+                }).join(", ")
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+                wrapMode: Text.Wrap
+            }
+
+            Label {
+                Layout.fillWidth: true
+                visible: kanbanModel.error_message.length > 0
+                text: kanbanModel.error_message
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.error
+                wrapMode: Text.Wrap
+            }
         }
 
         Shortcut {
@@ -1042,6 +1467,410 @@ Page {
         }
     }
 
+    // Board analytics from the task status history
+    Dialog {
+        id: analyticsDialog
+        title: "Analytics"
+        standardButtons: Dialog.Close
+        modal: true
+
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.9, 720)
+        height: Math.min(parent.height * 0.9, 640)
+
+        property var flow: []
+        property var burndown: []
+        property var throughput: []
+
+        readonly property var statusKeys: ["backlog", "todo", "inprogress", "blocked", "review", "done"]
+        readonly property var statusColors: ({
+            backlog: "#8a8580",
+            todo: "#64b5f6",
+            inprogress: "#e5a54b",
+            blocked: "#e57373",
+            review: "#b39ddb",
+            done: "#5bb98c"
+        })
+
+        function reload() {
+            analyticsModel.load(projectDetailPage.projectId, windowCombo.currentValue);
+        }
+
+        function peak(points, valueOf) {
+            return points.reduce((max, p) => Math.max(max, valueOf(p)), 1);
+        }
+
+        function cycleLabel(hours) {
+            if (hours < 0) return "-";
+            return hours < 48 ? hours.toFixed(1) + " h" : (hours / 24).toFixed(1) + " d";
+        }
+
+        onOpened: reload()
+
+        AnalyticsModel {
+            id: analyticsModel
+            onAnalytics_changed: {
+                analyticsDialog.flow = JSON.parse(get_cumulative_flow());
+                analyticsDialog.burndown = JSON.parse(get_burndown());
+                analyticsDialog.throughput = JSON.parse(get_throughput());
+            }
+        }
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: Theme.spacingLg
+
+                ComboBox {
+                    id: windowCombo
+                    textRole: "text"
+                    valueRole: "value"
+                    currentIndex: 1
+                    model: [
+                        { text: "Last 14 days", value: 14 },
+                        { text: "Last 30 days", value: 30 },
+                        { text: "Last 90 days", value: 90 }
+                    ]
+                    onActivated: analyticsDialog.reload()
+                }
+
+                Label {
+                    text: "Completed: " + analyticsModel.completed
+                    font.pixelSize: Theme.fontSizeNormal
+                    color: Theme.text
+                }
+
+                Label {
+                    text: "Cycle time: " + analyticsDialog.cycleLabel(analyticsModel.median_cycle_hours)
+                          + " median, " + analyticsDialog.cycleLabel(analyticsModel.average_cycle_hours) + " mean"
+                    font.pixelSize: Theme.fontSizeNormal
+                    color: Theme.text
+                    Layout.fillWidth: true
+                    elide: Text.ElideRight
+                }
+            }
+
+            Label {
+                visible: analyticsModel.error_message.length > 0
+                text: analyticsModel.error_message
+                color: Theme.error
+                font.pixelSize: Theme.fontSizeSmall
+            }
+
+            // Cumulative flow: tasks per status each day, stacked
+            Label {
+                text: "Cumulative flow"
+                font.pixelSize: Theme.fontSizeNormal
+                font.bold: true
+                color: Theme.text
+            }
+
+            RowLayout {
+                id: flowChart
+                readonly property real peak: analyticsDialog.peak(analyticsDialog.flow, d =>
+                    analyticsDialog.statusKeys.reduce((sum, key) => sum + d[key], 0))
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                spacing: 1
+
+                Repeater {
+                    model: analyticsDialog.flow
+
+                    delegate: Item {
+                        id: flowDay
+                        required property var modelData
+                        Layout.fillWidth: true
+                        Layout.fillHeight: true
+
+                        Column {
+                            anchors.bottom: parent.bottom
+                            width: parent.width
+
+                            Repeater {
+                                // Done at the bottom, as in a cumulative flow diagram
+                                model: analyticsDialog.statusKeys.slice().reverse()
+
+                                delegate: Rectangle {
+                                    required property string modelData
+                                    width: flowDay.width
+                                    height: flowDay.height * flowDay.modelData[modelData] / flowChart.peak
+                                    color: analyticsDialog.statusColors[modelData]
+                                }
+                            }
+                        }
+
+                        MouseArea {
+                            anchors.fill: parent
+                            hoverEnabled: true
+                            ToolTip.visible: containsMouse
+                            ToolTip.text: flowDay.modelData.date + "\n" + analyticsDialog.statusKeys
+                                .map(key => key + ": " + flowDay.modelData[key]).join("\n")
+                        }
+                    }
+                }
+            }
+
+            // Burndown: open tasks each day
+            Label {
+                text: "Burndown"
+                font.pixelSize: Theme.fontSizeNormal
+                font.bold: true
+                color: Theme.text
+            }
+
+            RowLayout {
+                id: burndownChart
+                readonly property real peak: analyticsDialog.peak(analyticsDialog.burndown, d => d.remaining)
+                Layout.fillWidth: true
+                Layout.preferredHeight: 90
+                spacing: 1
+
+                Repeater {
+                    model: analyticsDialog.burndown
+
+                    delegate: Item {
+                        required property var modelData
+                        Layout.fillWidth: true
+                        Layout.fillHeight: true
+
+                        Rectangle {
+                            anchors.bottom: parent.bottom
+                            width: parent.width
+                            height: parent.height * modelData.remaining / burndownChart.peak
+                            radius: 2
+                            color: Theme.primary
+                        }
+
+                        MouseArea {
+                            anchors.fill: parent
+                            hoverEnabled: true
+                            ToolTip.visible: containsMouse
+                            ToolTip.text: modelData.date + ": " + modelData.remaining + " open, " + modelData.done + " done"
+                        }
+                    }
+                }
+            }
+
+            // Throughput: tasks finished each day
+            Label {
+                text: "Throughput"
+                font.pixelSize: Theme.fontSizeNormal
+                font.bold: true
+                color: Theme.text
+            }
+
+            RowLayout {
+                id: throughputChart
+                readonly property real peak: analyticsDialog.peak(analyticsDialog.throughput, d => d.count)
+                Layout.fillWidth: true
+                Layout.preferredHeight: 70
+                spacing: 1
+
+                Repeater {
+                    model: analyticsDialog.throughput
+
+                    delegate: Item {
+                        required property var modelData
+                        Layout.fillWidth: true
+                        Layout.fillHeight: true
+
+                        Rectangle {
+                            anchors.bottom: parent.bottom
+                            width: parent.width
+                            height: parent.height * modelData.count / throughputChart.peak
+                            radius: 2
+                            color: analyticsDialog.statusColors.done
+                        }
+
+                        MouseArea {
+                            anchors.fill: parent
+                            hoverEnabled: true
+                            ToolTip.visible: containsMouse
+                            ToolTip.text: modelData.date + ": " + modelData.count + " finished"
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Add / edit column dialog
+    Dialog {
+        id: columnDialog
+        title: columnKey.length > 0 ? "Edit Column" : "Add Column"
+        standardButtons: Dialog.Ok | Dialog.Cancel
+        modal: true
+
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.8, 450)
+        height: columnKey.length > 0 ? 340 : 280
+
+        // Empty when adding a new column
+        property string columnKey: ""
+        property string columnName: ""
+        property string columnGithubLabel: ""
+        property int columnWipLimit: 0
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        header: Rectangle {
+            color: Theme.surfaceAlt
+            height: 50
+            radius: Theme.cardRadius
+
+            Rectangle {
+                anchors.bottom: parent.bottom
+                width: parent.width
+                height: Theme.cardRadius
+                color: Theme.surfaceAlt
+            }
+
+            Label {
+                anchors.centerIn: parent
+                text: columnDialog.title
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+            }
+        }
+
+        onOpened: {
+            columnNameField.text = columnKey.length > 0 ? columnName : "";
+            columnLabelField.text = columnKey.length > 0 ? columnGithubLabel : "";
+            columnWipField.value = columnKey.length > 0 ? columnWipLimit : 0;
+            columnNameField.forceActiveFocus();
+        }
+
+        onAccepted: {
+            const name = columnNameField.text.trim();
+            const label = columnLabelField.text.trim();
+            if (name.length === 0) {
+                return;
+            }
+            if (columnKey.length === 0) {
+                kanbanModel.add_column(name, label);
+                return;
+            }
+            if (name !== columnName) {
+                kanbanModel.rename_column(columnKey, name);
+            }
+            if (label !== columnGithubLabel) {
+                kanbanModel.set_column_label(columnKey, label);
+            }
+            if (columnWipField.value !== columnWipLimit) {
+                kanbanModel.set_column_wip_limit(columnKey, columnWipField.value);
+            }
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            Label {
+                text: "Name:"
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeNormal
+                color: Theme.text
+            }
+
+            Rectangle {
+                Layout.fillWidth: true
+                Layout.preferredHeight: 44
+                color: Theme.inputBg
+                border.color: columnNameField.activeFocus ? Theme.primary : Theme.inputBorder
+                border.width: columnNameField.activeFocus ? 2 : 1
+                radius: Theme.inputRadius
+
+                TextField {
+                    id: columnNameField
+                    anchors.fill: parent
+                    anchors.margins: 2
+                    placeholderText: "Column name"
+                    color: Theme.text
+                    placeholderTextColor: Theme.textMuted
+
+                    background: Rectangle {
+                        color: "transparent"
+                    }
+                }
+            }
+
+            Label {
+                text: "GitHub label (optional):"
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeNormal
+                color: Theme.text
+            }
+
+            Rectangle {
+                Layout.fillWidth: true
+                Layout.preferredHeight: 44
+                color: Theme.inputBg
+                border.color: columnLabelField.activeFocus ? Theme.primary : Theme.inputBorder
+                border.width: columnLabelField.activeFocus ? 2 : 1
+                radius: Theme.inputRadius
+
+                TextField {
+                    id: columnLabelField
+                    anchors.fill: parent
+                    anchors.margins: 2
+                    placeholderText: "e.g. needs-qa"
+                    color: Theme.text
+                    placeholderTextColor: Theme.textMuted
+
+                    background: Rectangle {
+                        color: "transparent"
+                    }
+                }
+            }
+
+            // Set once the column exists
+            RowLayout {
+                visible: columnDialog.columnKey.length > 0
+                Layout.fillWidth: true
+                spacing: Theme.spacingMd
+
+                Label {
+                    text: "WIP limit:"
+                    font.family: Theme.fontFamily
+                    font.pixelSize: Theme.fontSizeNormal
+                    color: Theme.text
+                    Layout.fillWidth: true
+                }
+
+                SpinBox {
+                    id: columnWipField
+                    from: 0
+                    to: 99
+                    editable: true
+                    textFromValue: function(value) { return value === 0 ? "None" : String(value); }
+                    valueFromText: function(text) { return parseInt(text) || 0; }
+                }
+            }
+        }
+
+        Shortcut {
+            sequence: "Ctrl+Return"
+            onActivated: columnDialog.accept()
+        }
+    }
+
     // Add repo dialog
     Dialog {
         id: addRepoDialog
@@ -1049,9 +1878,21 @@ Page {
         standardButtons: Dialog.Ok | Dialog.Cancel
         modal: true
 
+        property var repoChoices: []
+        // Choices matching what has been typed, not already linked
+        readonly property var filteredChoices: {
+            const typed = addRepoField.text.trim().toLowerCase();
+            let linked = [];
+            try {
+                linked = JSON.parse(kanbanModel.repo_ids) || [];
+            } catch (e) {}
+            return repoChoices.filter(name => linked.indexOf(name) < 0
+                                      && name.toLowerCase().indexOf(typed) >= 0).slice(0, 50);
+        }
+
         anchors.centerIn: parent
         width: Math.min(parent.width * 0.8, 450)
-        height: 200
+        height: repoChoices.length > 0 ? 420 : 200
 
         background: Rectangle {
             color: Theme.surface
@@ -1093,6 +1934,19 @@ Page {
             addRepoField.text = "";
         }
 
+        onOpened: projectModel.load_repo_choices()
+
+        Connections {
+            target: projectModel
+            function onRepo_choices_changed() {
+                try {
+                    addRepoDialog.repoChoices = JSON.parse(projectModel.get_repo_choices());
+                } catch (e) {
+                    addRepoDialog.repoChoices = [];
+                }
+            }
+        }
+
         ColumnLayout {
             anchors.fill: parent
             spacing: Theme.spacingMd
@@ -1125,6 +1979,29 @@ Page {
                     }
                 }
             }
+
+            // Own repos plus the orgs and teams picked on the Repos page
+            ListView {
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                visible: addRepoDialog.repoChoices.length > 0
+                clip: true
+                model: addRepoDialog.filteredChoices
+
+                delegate: ItemDelegate {
+                    required property string modelData
+                    width: ListView.view.width
+                    text: modelData
+                    font.family: Theme.fontFamily
+                    font.pixelSize: Theme.fontSizeNormal
+                    highlighted: addRepoField.text.trim() === modelData
+                    onClicked: addRepoField.text = modelData
+                    onDoubleClicked: {
+                        addRepoField.text = modelData;
+                        addRepoDialog.accept();
+                    }
+                }
+            }
         }
 
         Shortcut {
@@ -43,12 +43,29 @@ Page {
         onTriggered: authModel.poll_channel()
     }
 
+    // Open the board restored from the last session, if the project still exists
+    function openPendingProject() {
+        const pending = AppContext.pendingProjectId;
+        if (pending === "") return;
+        AppContext.pendingProjectId = "";
+        for (let i = 0; i < projectModel.row_count(); i++) {
+            if (projectModel.get_id(i) === pending) {
+                AppContext.pageStack.push(AppContext.pageUrl("ProjectDetailPage"), {
+                    projectId: pending,
+                    projectName: projectModel.get_project_name(i)
+                });
+                return;
+            }
+        }
+    }
+
     // Update project count when loading finishes
     Connections {
         target: projectModel
         function onLoadingChanged() {
             if (!projectModel.loading) {
                 projectsPage.projectCount = projectModel.row_count();
+                projectsPage.openPendingProject();
             }
         }
         function onProjects_changed() {
@@ -157,6 +174,29 @@ Page {
                 }
             }
 
+            ToolButton {
+                text: Icons.archiveBox
+                font.family: Icons.family
+                font.pixelSize: 18
+                onClicked: archivedDialog.open()
+                ToolTip.text: "Archived Projects"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    font.family: Icons.family
+                    color: Theme.text
+                    font.pixelSize: 18
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
             ToolButton {
                 text: Icons.plus
                 font.family: Icons.family
@@ -474,6 +514,34 @@ Page {
                                     ToolTip.delay: 500
                                 }
 
+                                // Archive button
+                                Rectangle {
+                                    width: 28
+                                    height: 28
+                                    radius: Theme.buttonRadius
+                                    color: archiveMouseArea.containsMouse ? Theme.surfaceHover : "transparent"
+
+                                    Label {
+                                        anchors.centerIn: parent
+                                        text: Icons.archiveBox
+                                        font.family: Icons.family
+                                        font.pixelSize: 14
+                                        color: Theme.textSecondary
+                                    }
+
+                                    MouseArea {
+                                        id: archiveMouseArea
+                                        anchors.fill: parent
+                                        hoverEnabled: true
+                                        cursorShape: Qt.PointingHandCursor
+                                        onClicked: projectModel.archive_project(projectCard.index)
+                                    }
+
+                                    ToolTip.visible: archiveMouseArea.containsMouse
+                                    ToolTip.text: "Archive project"
+                                    ToolTip.delay: 500
+                                }
+
                                 // Delete button
                                 Rectangle {
                                     width: 28
@@ -934,7 +1002,7 @@ Page {
             }
 
             Label {
-                text: "This will remove the project and all its tasks from the local database."
+                text: "This will remove the project and all its tasks from the local database. Archive it instead to keep its history."
                 font.family: Theme.fontFamily
                 font.pixelSize: Theme.fontSizeSmall
                 color: Theme.textMuted
@@ -944,6 +1012,80 @@ Page {
         }
     }
 
+    // Archived projects, with restore
+    Dialog {
+        id: archivedDialog
+        title: "Archived Projects"
+        standardButtons: Dialog.Close
+        modal: true
+
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.8, 420)
+        height: Math.min(parent.height * 0.8, 420)
+
+        // Bumped on archived_changed so bindings re-read the model
+        property int revision: 0
+
+        onOpened: projectModel.fetch_archived()
+
+        Connections {
+            target: projectModel
+            function onArchived_changed() {
+                archivedDialog.revision++;
+            }
+        }
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingSm
+
+            Label {
+                visible: archivedDialog.revision >= 0 && projectModel.archived_count() === 0
+                text: "No archived projects"
+                font.family: Theme.fontFamily
+                font.pixelSize: Theme.fontSizeNormal
+                color: Theme.textMuted
+                Layout.fillWidth: true
+            }
+
+            ListView {
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                clip: true
+                spacing: Theme.spacingXs
+                model: archivedDialog.revision >= 0 ? projectModel.archived_count() : 0
+
+                delegate: RowLayout {
+                    required property int index
+                    width: ListView.view.width
+                    spacing: Theme.spacingSm
+
+                    Label {
+                        text: projectModel.get_archived_name(index)
+                        font.family: Theme.fontFamily
+                        font.pixelSize: Theme.fontSizeNormal
+                        color: Theme.text
+                        elide: Text.ElideMiddle
+                        Layout.fillWidth: true
+                    }
+
+                    Button {
+                        text: "Restore"
+                        flat: true
+                        onClicked: projectModel.restore_project(index)
+                    }
+                }
+            }
+        }
+    }
+
     Component.onCompleted: {
         projectModel.check_auth();
         // Fetch is triggered by onAuthenticatedChanged when authenticated becomes true
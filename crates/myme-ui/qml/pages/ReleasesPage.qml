@@ -0,0 +1,306 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: releasesPage
+    title: "Releases"
+
+    // owner/repo, set by the page that pushes this one
+    property string repoName: ""
+    property var releases: []
+    property var tags: []
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    ReleaseModel {
+        id: releaseModel
+    }
+
+    Timer {
+        id: releasePollTimer
+        interval: 100
+        running: releaseModel.loading
+        repeat: true
+        onTriggered: releaseModel.poll_channel()
+    }
+
+    Component.onCompleted: {
+        releaseModel.check_auth();
+        if (releaseModel.authenticated) {
+            releaseModel.load(repoName);
+        }
+    }
+
+    Connections {
+        target: releaseModel
+        function onReleases_changed() {
+            try {
+                releasesPage.releases = JSON.parse(releaseModel.get_releases());
+                releasesPage.tags = JSON.parse(releaseModel.get_tags());
+            } catch (e) {
+                releasesPage.releases = [];
+                releasesPage.tags = [];
+            }
+        }
+        function onSuggested_tagChanged() {
+            tagField.text = releaseModel.suggested_tag;
+        }
+        function onDraft_bodyChanged() {
+            notesArea.text = releaseModel.draft_body;
+        }
+        function onRelease_created(url) {
+            nameField.text = "";
+            draftCheck.checked = false;
+            prereleaseCheck.checked = false;
+            Qt.openUrlExternally(url);
+        }
+    }
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            ToolButton {
+                text: Icons.caretLeft
+                font.family: Icons.family
+                font.pixelSize: 18
+                onClicked: AppContext.pageStack.pop()
+                ToolTip.text: "Back to Repos"
+                ToolTip.visible: hovered
+            }
+
+            Label {
+                text: "Releases · " + releasesPage.repoName
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                elide: Text.ElideRight
+                Layout.fillWidth: true
+            }
+
+            ToolButton {
+                text: Icons.arrowsClockwise
+                font.family: Icons.family
+                font.pixelSize: 18
+                enabled: !releaseModel.loading && !AppContext.offline
+                onClicked: releaseModel.load(releasesPage.repoName)
+                ToolTip.text: "Refresh"
+                ToolTip.visible: hovered
+            }
+
+            Item { width: Theme.spacingSm }
+        }
+    }
+
+    RowLayout {
+        anchors.fill: parent
+        anchors.margins: Theme.spacingLg
+        spacing: Theme.spacingLg
+
+        // New release
+        Rectangle {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            Layout.preferredWidth: 3
+            color: Theme.surface
+            border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+            border.width: 1
+            radius: Theme.cardRadius
+
+            ColumnLayout {
+                anchors.fill: parent
+                anchors.margins: Theme.spacingMd
+                spacing: Theme.spacingSm
+
+                RowLayout {
+                    Layout.fillWidth: true
+
+                    Label {
+                        text: "New release"
+                        font.pixelSize: Theme.fontSizeMedium
+                        font.bold: true
+                        color: Theme.text
+                        Layout.fillWidth: true
+                    }
+
+                    Button {
+                        text: "Draft from changes"
+                        enabled: releaseModel.authenticated && !releaseModel.loading && !AppContext.offline
+                        onClicked: releaseModel.draft_changelog()
+                        ToolTip.text: "Pull requests merged and issues closed since the last tag"
+                        ToolTip.visible: hovered
+                    }
+                }
+
+                Label {
+                    visible: releaseModel.error_message.length > 0
+                    text: releaseModel.error_message
+                    font.pixelSize: Theme.fontSizeSmall
+                    color: Theme.error
+                    wrapMode: Text.WordWrap
+                    Layout.fillWidth: true
+                }
+
+                RowLayout {
+                    Layout.fillWidth: true
+                    spacing: Theme.spacingSm
+
+                    TextField {
+                        id: tagField
+                        placeholderText: releasesPage.tags.length > 0 ? "Tag (last: " + releasesPage.tags[0] + ")" : "Tag, e.g. v0.1.0"
+                        Layout.preferredWidth: 200
+                    }
+
+                    TextField {
+                        id: nameField
+                        placeholderText: "Title (defaults to the tag)"
+                        Layout.fillWidth: true
+                    }
+                }
+
+                ScrollView {
+                    Layout.fillWidth: true
+                    Layout.fillHeight: true
+
+                    TextArea {
+                        id: notesArea
+                        wrapMode: TextEdit.Wrap
+                        font.family: "monospace"
+                        placeholderText: releaseModel.suggested_tag.length > 0
+                            ? "Nothing merged or closed since " + (releaseModel.since_tag || "the first commit")
+                            : "Release notes (Markdown)"
+                    }
+                }
+
+                RowLayout {
+                    Layout.fillWidth: true
+                    spacing: Theme.spacingSm
+
+                    CheckBox {
+                        id: draftCheck
+                        text: "Save as draft"
+                    }
+
+                    CheckBox {
+                        id: prereleaseCheck
+                        text: "Pre-release"
+                    }
+
+                    Item { Layout.fillWidth: true }
+
+                    BusyIndicator {
+                        running: releaseModel.loading
+                        visible: running
+                        Layout.preferredWidth: 24
+                        Layout.preferredHeight: 24
+                    }
+
+                    Button {
+                        text: draftCheck.checked ? "Save draft" : "Publish"
+                        enabled: tagField.text.trim().length > 0 && !releaseModel.loading && !AppContext.offline
+                        onClicked: releaseModel.create_release(tagField.text, nameField.text, notesArea.text,
+                                                               draftCheck.checked, prereleaseCheck.checked)
+                        background: Rectangle {
+                            radius: Theme.buttonRadius
+                            color: parent.hovered ? Theme.primaryHover : Theme.primary
+                            opacity: parent.enabled ? 1.0 : 0.5
+                        }
+                        contentItem: Label {
+                            text: parent.text
+                            color: Theme.primaryText
+                            horizontalAlignment: Text.AlignHCenter
+                            verticalAlignment: Text.AlignVCenter
+                        }
+                    }
+                }
+            }
+        }
+
+        // Past releases
+        ColumnLayout {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            Layout.preferredWidth: 2
+            spacing: Theme.spacingSm
+
+            Label {
+                text: "Releases (" + releasesPage.releases.length + ")"
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+            }
+
+            Label {
+                visible: releasesPage.releases.length === 0 && !releaseModel.loading
+                text: releasesPage.tags.length > 0
+                    ? releasesPage.tags.length + " tags, no releases yet"
+                    : "No releases yet"
+                font.pixelSize: Theme.fontSizeNormal
+                color: Theme.textSecondary
+            }
+
+            ListView {
+                id: releaseList
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                clip: true
+                spacing: Theme.spacingSm
+                model: releasesPage.releases
+
+                delegate: Rectangle {
+                    id: releaseRow
+                    required property var modelData
+                    width: releaseList.width
+                    height: releaseColumn.implicitHeight + Theme.spacingSm * 2
+                    color: releaseMouse.containsMouse ? Theme.surfaceHover : Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+                    radius: Theme.cardRadius
+
+                    MouseArea {
+                        id: releaseMouse
+                        anchors.fill: parent
+                        hoverEnabled: true
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: Qt.openUrlExternally(releaseRow.modelData.htmlUrl)
+                    }
+
+                    ColumnLayout {
+                        id: releaseColumn
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingSm
+                        spacing: 2
+
+                        Label {
+                            text: releaseRow.modelData.name
+                                + (releaseRow.modelData.draft ? "  · draft" : "")
+                                + (releaseRow.modelData.prerelease ? "  · pre-release" : "")
+                            font.pixelSize: Theme.fontSizeNormal
+                            font.bold: true
+                            color: Theme.text
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+
+                        Label {
+                            text: releaseRow.modelData.tag + " · "
+                                + new Date(releaseRow.modelData.publishedAt).toLocaleDateString(Qt.locale(), Locale.ShortFormat)
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textMuted
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
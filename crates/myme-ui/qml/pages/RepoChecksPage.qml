@@ -0,0 +1,321 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import myme_ui
+import ".."
+
+Page {
+    id: checksPage
+    title: "Checks"
+
+    // owner/repo (or directory name) and clone path, set by the page that pushes this one
+    property string repoName: ""
+    property string localPath: ""
+    property var tasks: []
+    property var history: []
+    // Output of a past run picked from the history, shown instead of the live output
+    property string historyOutput: ""
+
+    background: Rectangle {
+        color: Theme.background
+    }
+
+    RunnerModel {
+        id: runnerModel
+    }
+
+    Timer {
+        id: runnerPollTimer
+        interval: 100
+        running: runnerModel.running
+        repeat: true
+        onTriggered: runnerModel.poll_channel()
+    }
+
+    Component.onCompleted: runnerModel.load(repoName, localPath)
+
+    // Leaving the page stops the check rather than leaving it running unseen
+    Component.onDestruction: runnerModel.cancel()
+
+    function statusColor(status) {
+        switch (status) {
+        case "passed": return Theme.success;
+        case "failed": return Theme.error;
+        case "timed_out": return Theme.warning;
+        default: return Theme.textMuted;
+        }
+    }
+
+    function statusText(status) {
+        switch (status) {
+        case "passed": return "Passed";
+        case "failed": return "Failed";
+        case "timed_out": return "Timed out";
+        case "cancelled": return "Cancelled";
+        default: return "Not run";
+        }
+    }
+
+    Connections {
+        target: runnerModel
+        function onTasks_changed() {
+            try {
+                checksPage.tasks = JSON.parse(runnerModel.get_tasks());
+                checksPage.history = JSON.parse(runnerModel.get_history());
+            } catch (e) {
+                checksPage.tasks = [];
+                checksPage.history = [];
+            }
+        }
+        function onOutputChanged() {
+            outputArea.cursorPosition = outputArea.length;
+        }
+    }
+
+    header: ToolBar {
+        background: Rectangle {
+            color: "transparent"
+        }
+
+        RowLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingMd
+
+            ToolButton {
+                text: Icons.caretLeft
+                font.family: Icons.family
+                font.pixelSize: 18
+                onClicked: AppContext.pageStack.pop()
+                ToolTip.text: "Back to Repos"
+                ToolTip.visible: hovered
+            }
+
+            Label {
+                text: "Checks · " + checksPage.repoName
+                font.pixelSize: Theme.fontSizeLarge
+                font.bold: true
+                color: Theme.text
+                elide: Text.ElideRight
+                Layout.fillWidth: true
+            }
+
+            Item { width: Theme.spacingSm }
+        }
+    }
+
+    RowLayout {
+        anchors.fill: parent
+        anchors.margins: Theme.spacingLg
+        spacing: Theme.spacingLg
+
+        // Checks and past runs
+        ColumnLayout {
+            Layout.fillHeight: true
+            Layout.preferredWidth: 320
+            Layout.maximumWidth: 360
+            spacing: Theme.spacingSm
+
+            Label {
+                text: "Checks"
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+            }
+
+            Label {
+                visible: checksPage.tasks.length === 0
+                text: "No Cargo.toml, package.json test script or Taskfile found. Add commands under [repos.tasks] in the config."
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textSecondary
+                wrapMode: Text.WordWrap
+                Layout.fillWidth: true
+            }
+
+            Repeater {
+                model: checksPage.tasks
+
+                delegate: Rectangle {
+                    id: taskRow
+                    required property var modelData
+                    required property int index
+                    Layout.fillWidth: true
+                    implicitHeight: taskLayout.implicitHeight + Theme.spacingSm * 2
+                    color: Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+                    radius: Theme.cardRadius
+
+                    RowLayout {
+                        id: taskLayout
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingSm
+                        spacing: Theme.spacingSm
+
+                        ColumnLayout {
+                            Layout.fillWidth: true
+                            spacing: 2
+
+                            Label {
+                                text: taskRow.modelData.name
+                                font.family: "monospace"
+                                font.pixelSize: Theme.fontSizeNormal
+                                color: Theme.text
+                                elide: Text.ElideRight
+                                Layout.fillWidth: true
+                            }
+
+                            Label {
+                                text: checksPage.statusText(taskRow.modelData.lastStatus)
+                                    + (taskRow.modelData.lastRunAt
+                                       ? " · " + new Date(taskRow.modelData.lastRunAt).toLocaleString(Qt.locale(), Locale.ShortFormat)
+                                       : "")
+                                font.pixelSize: Theme.fontSizeSmall
+                                color: checksPage.statusColor(taskRow.modelData.lastStatus)
+                            }
+                        }
+
+                        Button {
+                            text: "Run"
+                            enabled: !runnerModel.running
+                            onClicked: {
+                                checksPage.historyOutput = "";
+                                runnerModel.run_task(taskRow.index);
+                            }
+                            background: Rectangle {
+                                radius: Theme.buttonRadius
+                                color: parent.hovered ? Theme.primaryHover : Theme.primary
+                                opacity: parent.enabled ? 1.0 : 0.5
+                            }
+                            contentItem: Label {
+                                text: parent.text
+                                color: Theme.primaryText
+                                horizontalAlignment: Text.AlignHCenter
+                                verticalAlignment: Text.AlignVCenter
+                            }
+                        }
+                    }
+                }
+            }
+
+            Label {
+                text: "History"
+                font.pixelSize: Theme.fontSizeMedium
+                font.bold: true
+                color: Theme.text
+                Layout.topMargin: Theme.spacingMd
+            }
+
+            ListView {
+                id: historyList
+                Layout.fillWidth: true
+                Layout.fillHeight: true
+                clip: true
+                spacing: 2
+                model: checksPage.history
+
+                delegate: ItemDelegate {
+                    id: historyRow
+                    required property var modelData
+                    width: historyList.width
+                    onClicked: {
+                        if (!runnerModel.running) {
+                            checksPage.historyOutput = historyRow.modelData.outputTail;
+                        }
+                    }
+
+                    contentItem: RowLayout {
+                        spacing: Theme.spacingSm
+
+                        Label {
+                            text: checksPage.statusText(historyRow.modelData.status)
+                            color: checksPage.statusColor(historyRow.modelData.status)
+                            font.pixelSize: Theme.fontSizeSmall
+                            Layout.preferredWidth: 70
+                        }
+
+                        Label {
+                            text: historyRow.modelData.task
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.text
+                            elide: Text.ElideRight
+                            Layout.fillWidth: true
+                        }
+
+                        Label {
+                            text: Math.round(historyRow.modelData.durationMs / 1000) + "s"
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textMuted
+                        }
+                    }
+                }
+            }
+        }
+
+        // Output
+        Rectangle {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            color: Theme.surface
+            border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+            border.width: 1
+            radius: Theme.cardRadius
+
+            ColumnLayout {
+                anchors.fill: parent
+                anchors.margins: Theme.spacingMd
+                spacing: Theme.spacingSm
+
+                RowLayout {
+                    Layout.fillWidth: true
+                    spacing: Theme.spacingSm
+
+                    Label {
+                        text: runnerModel.current_task.length > 0 ? runnerModel.current_task : "Output"
+                        font.pixelSize: Theme.fontSizeMedium
+                        font.bold: true
+                        color: Theme.text
+                        elide: Text.ElideRight
+                        Layout.fillWidth: true
+                    }
+
+                    BusyIndicator {
+                        running: runnerModel.running
+                        visible: running
+                        Layout.preferredWidth: 24
+                        Layout.preferredHeight: 24
+                    }
+
+                    Button {
+                        visible: runnerModel.running
+                        text: "Stop"
+                        onClicked: runnerModel.cancel()
+                    }
+                }
+
+                Label {
+                    visible: runnerModel.error_message.length > 0
+                    text: runnerModel.error_message
+                    font.pixelSize: Theme.fontSizeSmall
+                    color: Theme.error
+                    wrapMode: Text.WordWrap
+                    Layout.fillWidth: true
+                }
+
+                ScrollView {
+                    Layout.fillWidth: true
+                    Layout.fillHeight: true
+
+                    TextArea {
+                        id: outputArea
+                        readOnly: true
+                        text: checksPage.historyOutput.length > 0 ? checksPage.historyOutput : runnerModel.output
+                        wrapMode: TextEdit.NoWrap
+                        font.family: "monospace"
+                        font.pixelSize: Theme.fontSizeSmall
+                        placeholderText: "Run a check to see its output here"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -42,11 +42,18 @@ Page {
         onTriggered: repoModel.poll_channel()
     }
 
+    // Scroll back to where the list was last session once repos are in
+    property bool scrollRestored: false
+
     Connections {
         target: repoModel
         function onLoadingChanged() {
             if (!repoModel.loading) {
                 repoPage.repoCount = repoModel.rowCount();
+                if (!repoPage.scrollRestored && repoPage.repoCount > 0) {
+                    repoPage.scrollRestored = true;
+                    Qt.callLater(() => AppContext.restoreScroll("repos", repoScroll.contentItem));
+                }
             }
         }
         function onReposChanged() {
@@ -55,6 +62,9 @@ Page {
         function onAuthenticatedChanged() {
             repoModel.fetchRepos();
         }
+        function onRepo_created(fullName) {
+            createRepoDialog.close();
+        }
     }
 
     Connections {
@@ -89,6 +99,154 @@ Page {
                 leftPadding: Theme.spacingMd
             }
 
+            ToolButton {
+                text: "Insights"
+                font.pixelSize: Theme.fontSizeNormal
+                enabled: repoModel.authenticated
+                onClicked: AppContext.pageStack.push(AppContext.pageUrl("InsightsPage"))
+                ToolTip.text: "Stars, traffic and contributors"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: Theme.text
+                    font.pixelSize: Theme.fontSizeNormal
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+            }
+
+            ToolButton {
+                text: "Discover"
+                font.pixelSize: Theme.fontSizeNormal
+                enabled: repoModel.authenticated
+                onClicked: AppContext.pageStack.push(AppContext.pageUrl("DiscoverPage"))
+                ToolTip.text: "Starred repos and their new releases"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: Theme.text
+                    font.pixelSize: Theme.fontSizeNormal
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+            }
+
+            ToolButton {
+                id: orgsButton
+                text: "Orgs"
+                font.pixelSize: Theme.fontSizeNormal
+                enabled: repoModel.authenticated
+                onClicked: {
+                    repoModel.fetch_scopes();
+                    orgsPopup.open();
+                }
+                ToolTip.text: "List organization and team repos with your own"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: Theme.text
+                    font.pixelSize: Theme.fontSizeNormal
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+
+                Popup {
+                    id: orgsPopup
+                    y: parent.height
+                    width: 280
+                    padding: Theme.spacingMd
+
+                    property var scopes: []
+
+                    onOpened: orgsPopup.scopes = JSON.parse(repoModel.get_scopes())
+
+                    Connections {
+                        target: repoModel
+                        function onScopes_changed() {
+                            orgsPopup.scopes = JSON.parse(repoModel.get_scopes());
+                        }
+                    }
+
+                    background: Rectangle {
+                        color: Theme.surface
+                        border.color: Theme.border
+                        border.width: 1
+                        radius: Theme.cardRadius
+                    }
+
+                    contentItem: ColumnLayout {
+                        spacing: Theme.spacingXs
+
+                        Label {
+                            text: orgsPopup.scopes.length > 0
+                                  ? "Also list repositories of"
+                                  : "No organizations found"
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.textSecondary
+                        }
+
+                        Repeater {
+                            model: orgsPopup.scopes
+
+                            delegate: CheckBox {
+                                required property var modelData
+                                text: modelData.team ? "    " + modelData.team + " (team)" : modelData.org
+                                checked: modelData.selected
+                                enabled: !repoModel.loading
+                                onToggled: repoModel.set_scope_selected(modelData.scope, checked)
+                            }
+                        }
+                    }
+                }
+            }
+
+            ToolButton {
+                text: Icons.plus
+                font.family: Icons.family
+                font.pixelSize: 18
+                enabled: repoModel.authenticated && !repoModel.loading
+                onClicked: createRepoDialog.open()
+                ToolTip.text: "New GitHub repository"
+                ToolTip.visible: hovered
+
+                background: Rectangle {
+                    radius: Theme.buttonRadius
+                    color: parent.hovered ? Theme.surfaceHover : "transparent"
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    font.family: Icons.family
+                    color: Theme.text
+                    font.pixelSize: 18
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+            }
+
             ToolButton {
                 text: Icons.arrowsClockwise
                 font.family: Icons.family
@@ -239,6 +397,13 @@ Page {
             clip: true
             contentWidth: repoScroll.viewport.width
 
+            Connections {
+                target: repoScroll.contentItem
+                function onContentYChanged() {
+                    if (repoPage.scrollRestored) AppContext.saveScroll("repos", repoScroll.contentItem);
+                }
+            }
+
             ColumnLayout {
                 width: repoScroll.viewport.width
                 spacing: Theme.spacingMd
@@ -297,4 +462,132 @@ Page {
             }
         }
     }
+
+    // New repo wizard: create on GitHub, clone locally, optionally link to a project
+    Dialog {
+        id: createRepoDialog
+        title: "New Repository"
+        standardButtons: Dialog.Ok | Dialog.Cancel
+        modal: true
+
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.8, 480)
+
+        background: Rectangle {
+            color: Theme.surface
+            border.color: Theme.border
+            border.width: 1
+            radius: Theme.cardRadius
+        }
+
+        function reset() {
+            repoNameField.text = "";
+            repoDescField.text = "";
+            repoPrivateCheck.checked = true;
+            repoOrgField.text = "";
+            repoTemplateField.text = "";
+            repoGitignoreField.text = "";
+            repoLicenseField.text = "";
+            repoTopicsField.text = "";
+            repoProjectCombo.currentIndex = 0;
+        }
+
+        onAccepted: {
+            if (repoNameField.text.trim().length === 0)
+                return;
+            const projectIndex = repoProjectCombo.currentIndex - 1;
+            const projectId = projectIndex >= 0 ? projectModel.get_id(projectIndex) : "";
+            repoModel.create_remote_repo(repoNameField.text, repoDescField.text,
+                                         repoPrivateCheck.checked, repoOrgField.text,
+                                         repoTemplateField.text, repoGitignoreField.text,
+                                         repoLicenseField.text, repoTopicsField.text, projectId);
+            reset();
+        }
+
+        onRejected: reset()
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingSm
+
+            TextField {
+                id: repoNameField
+                Layout.fillWidth: true
+                placeholderText: "Repository name"
+            }
+
+            TextField {
+                id: repoDescField
+                Layout.fillWidth: true
+                placeholderText: "Description (optional)"
+            }
+
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: Theme.spacingSm
+
+                TextField {
+                    id: repoOrgField
+                    Layout.fillWidth: true
+                    placeholderText: "Organization (blank for personal)"
+                }
+
+                CheckBox {
+                    id: repoPrivateCheck
+                    text: "Private"
+                    checked: true
+                }
+            }
+
+            TextField {
+                id: repoTemplateField
+                Layout.fillWidth: true
+                placeholderText: "Template repo, owner/name (optional)"
+            }
+
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: Theme.spacingSm
+                // GitHub ignores these when generating from a template
+                enabled: repoTemplateField.text.trim().length === 0
+
+                TextField {
+                    id: repoGitignoreField
+                    Layout.fillWidth: true
+                    placeholderText: ".gitignore, e.g. Rust"
+                }
+
+                TextField {
+                    id: repoLicenseField
+                    Layout.fillWidth: true
+                    placeholderText: "License, e.g. mit"
+                }
+            }
+
+            TextField {
+                id: repoTopicsField
+                Layout.fillWidth: true
+                placeholderText: "Topics, comma-separated"
+            }
+
+            ComboBox {
+                id: repoProjectCombo
+                Layout.fillWidth: true
+                model: {
+                    const names = ["No project"];
+                    for (let i = 0; i < projectModel.row_count(); i++)
+                        names.push(projectModel.get_project_name(i));
+                    return names;
+                }
+            }
+
+            Label {
+                text: "The repo is cloned to " + (repoModel.effective_path || ".")
+                font.pixelSize: Theme.fontSizeSmall
+                color: Theme.textMuted
+                Layout.fillWidth: true
+                elide: Text.ElideMiddle
+            }
+        }
+    }
 }
@@ -8,6 +8,27 @@ Page {
     id: settingsPage
     title: "Settings"
 
+    // Built-in accent themes from ThemeModel, refreshed when dark/light flips
+    property var themes: []
+
+    function reloadThemes() {
+        if (!AppContext.themeModel) return;
+        try {
+            themes = JSON.parse(AppContext.themeModel.get_themes());
+        } catch (e) {
+            themes = [];
+        }
+    }
+
+    Component.onCompleted: reloadThemes()
+
+    Connections {
+        target: AppContext.themeModel
+        function onTheme_changed() {
+            settingsPage.reloadThemes();
+        }
+    }
+
     // Auth model for connected accounts (GitHub)
     AuthModel {
         id: authModel
@@ -18,6 +39,23 @@ Page {
     GoogleAuthModel {
         id: googleAuthModel
         Component.onCompleted: googleAuthModel.check_auth()
+        onAuth_changed: settingsModel.check_tokens()
+    }
+
+    // Token health for connected accounts (expiry, revocation, missing scopes)
+    SettingsModel {
+        id: settingsModel
+        Component.onCompleted: settingsModel.check_tokens()
+        onToken_report_changed: settingsPage.tokenReport = JSON.parse(settingsModel.get_token_report())
+    }
+
+    property var tokenReport: []
+
+    Timer {
+        interval: 100
+        running: settingsModel.checking
+        repeat: true
+        onTriggered: settingsModel.poll_channel()
     }
 
     // Timer to poll for async auth operation results
@@ -104,7 +142,7 @@ Page {
                     GridLayout {
                         Layout.fillWidth: true
                         Layout.topMargin: Theme.spacingSm
-                        columns: Responsive.columnsFor(scroll.viewport.width - Theme.spacingLg * 2 - Theme.spacingMd * 2, 160, 3)
+                        columns: Responsive.columnsFor(scroll.viewport.width - Theme.spacingLg * 2 - Theme.spacingMd * 2, 160, 4)
                         rowSpacing: Theme.spacingMd
                         columnSpacing: Theme.spacingMd
 
@@ -122,7 +160,7 @@ Page {
                             MouseArea {
                                 anchors.fill: parent
                                 cursorShape: Qt.PointingHandCursor
-                                onClicked: Theme.mode = "light"
+                                onClicked: AppContext.setThemeMode("light")
                             }
 
                             ColumnLayout {
@@ -172,7 +210,7 @@ Page {
                             MouseArea {
                                 anchors.fill: parent
                                 cursorShape: Qt.PointingHandCursor
-                                onClicked: Theme.mode = "dark"
+                                onClicked: AppContext.setThemeMode("dark")
                             }
 
                             ColumnLayout {
@@ -222,7 +260,7 @@ Page {
                             MouseArea {
                                 anchors.fill: parent
                                 cursorShape: Qt.PointingHandCursor
-                                onClicked: Theme.mode = "auto"
+                                onClicked: AppContext.setThemeMode("auto")
                             }
 
                             ColumnLayout {
@@ -267,6 +305,198 @@ Page {
                                 }
                             }
                         }
+
+                        // Scheduled theme option
+                        Rectangle {
+                            Layout.preferredWidth: 140
+                            Layout.minimumWidth: 120
+                            Layout.fillWidth: true
+                            Layout.preferredHeight: 100
+                            radius: Theme.cardRadius
+                            color: Theme.mode === "scheduled" ? Theme.primary + "20" : Theme.surfaceAlt
+                            border.color: Theme.mode === "scheduled" ? Theme.primary : Theme.border
+                            border.width: Theme.mode === "scheduled" ? 2 : 1
+
+                            MouseArea {
+                                anchors.fill: parent
+                                cursorShape: Qt.PointingHandCursor
+                                onClicked: AppContext.setThemeMode("scheduled")
+                            }
+
+                            ColumnLayout {
+                                anchors.centerIn: parent
+                                spacing: Theme.spacingSm
+
+                                Rectangle {
+                                    Layout.alignment: Qt.AlignHCenter
+                                    width: 40
+                                    height: 40
+                                    radius: 20
+                                    color: Theme.surface
+                                    border.color: "#6b6560"
+                                    border.width: 1
+
+                                    Text {
+                                        anchors.centerIn: parent
+                                        text: Icons.clock
+                                        font.family: Icons.family
+                                        font.pixelSize: 20
+                                        color: "#6b6560"
+                                    }
+                                }
+
+                                Label {
+                                    text: "Scheduled"
+                                    font.family: Theme.fontFamily
+                                    font.pixelSize: Theme.fontSizeNormal
+                                    font.bold: Theme.mode === "scheduled"
+                                    color: Theme.text
+                                    Layout.alignment: Qt.AlignHCenter
+                                }
+                            }
+                        }
+                    }
+
+                    // Dark period for scheduled mode
+                    RowLayout {
+                        visible: Theme.mode === "scheduled" && AppContext.themeModel !== null
+                        Layout.fillWidth: true
+                        spacing: Theme.spacingSm
+
+                        Label {
+                            text: "Dark from"
+                            font.family: Theme.fontFamily
+                            font.pixelSize: Theme.fontSizeNormal
+                            color: Theme.text
+                        }
+
+                        TextField {
+                            id: darkStartField
+                            text: AppContext.themeModel ? AppContext.themeModel.dark_start : ""
+                            placeholderText: "19:00"
+                            inputMask: "99:99"
+                            Layout.preferredWidth: 80
+                            onEditingFinished: AppContext.themeModel.save_schedule(darkStartField.text, darkEndField.text)
+                        }
+
+                        Label {
+                            text: "until"
+                            font.family: Theme.fontFamily
+                            font.pixelSize: Theme.fontSizeNormal
+                            color: Theme.text
+                        }
+
+                        TextField {
+                            id: darkEndField
+                            text: AppContext.themeModel ? AppContext.themeModel.dark_end : ""
+                            placeholderText: "07:00"
+                            inputMask: "99:99"
+                            Layout.preferredWidth: 80
+                            onEditingFinished: AppContext.themeModel.save_schedule(darkStartField.text, darkEndField.text)
+                        }
+
+                        Item { Layout.fillWidth: true }
+                    }
+
+                    // Accent color
+                    Label {
+                        visible: AppContext.themeModel !== null
+                        text: "Accent"
+                        font.family: Theme.fontFamily
+                        font.pixelSize: Theme.fontSizeNormal
+                        color: Theme.text
+                        Layout.topMargin: Theme.spacingSm
+                    }
+
+                    Flow {
+                        visible: AppContext.themeModel !== null
+                        Layout.fillWidth: true
+                        spacing: Theme.spacingSm
+
+                        Repeater {
+                            model: settingsPage.themes
+
+                            delegate: Rectangle {
+                                id: themeSwatch
+                                required property var modelData
+                                readonly property bool selected: AppContext.themeModel
+                                    && AppContext.themeModel.accent_color.length === 0
+                                    && AppContext.themeModel.theme === modelData.id
+                                width: swatchRow.implicitWidth + Theme.spacingMd * 2
+                                height: 36
+                                radius: Theme.buttonRadius
+                                color: selected ? Theme.primary + "20" : Theme.surfaceAlt
+                                border.color: selected ? Theme.primary : Theme.border
+                                border.width: selected ? 2 : 1
+
+                                RowLayout {
+                                    id: swatchRow
+                                    anchors.centerIn: parent
+                                    spacing: Theme.spacingSm
+
+                                    Rectangle {
+                                        width: 16
+                                        height: 16
+                                        radius: 8
+                                        color: themeSwatch.modelData.accent
+                                    }
+
+                                    Label {
+                                        text: themeSwatch.modelData.label
+                                        font.family: Theme.fontFamily
+                                        font.pixelSize: Theme.fontSizeSmall
+                                        color: Theme.text
+                                    }
+                                }
+
+                                MouseArea {
+                                    anchors.fill: parent
+                                    cursorShape: Qt.PointingHandCursor
+                                    onClicked: {
+                                        AppContext.themeModel.save_accent_color("");
+                                        AppContext.themeModel.save_theme(themeSwatch.modelData.id);
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    RowLayout {
+                        visible: AppContext.themeModel !== null
+                        Layout.fillWidth: true
+                        spacing: Theme.spacingSm
+
+                        Rectangle {
+                            width: 24
+                            height: 24
+                            radius: 12
+                            color: Theme.primary
+                            border.color: Theme.border
+                            border.width: 1
+                        }
+
+                        TextField {
+                            id: accentField
+                            text: AppContext.themeModel ? AppContext.themeModel.accent_color : ""
+                            placeholderText: "Custom accent, e.g. #7c5cff"
+                            Layout.preferredWidth: 220
+                            onAccepted: AppContext.themeModel.save_accent_color(accentField.text)
+                        }
+
+                        Button {
+                            text: "Apply"
+                            onClicked: AppContext.themeModel.save_accent_color(accentField.text)
+                        }
+
+                        Label {
+                            visible: AppContext.themeModel && AppContext.themeModel.error_message.length > 0
+                            text: AppContext.themeModel ? AppContext.themeModel.error_message : ""
+                            font.family: Theme.fontFamily
+                            font.pixelSize: Theme.fontSizeSmall
+                            color: Theme.error
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
                     }
 
                     // Current theme indicator
@@ -282,7 +512,9 @@ Page {
                             anchors.margins: Theme.spacingSm
 
                             Label {
-                                text: Theme.mode === "auto" ? "Currently using " + (Theme.isDark ? "dark" : "light") + " theme (synced with system)" : "Using " + Theme.mode + " theme"
+                                text: Theme.mode === "auto" ? "Currently using " + (Theme.isDark ? "dark" : "light") + " theme (synced with system)"
+                                    : Theme.mode === "scheduled" ? "Currently using " + (Theme.isDark ? "dark" : "light") + " theme (on a schedule)"
+                                    : "Using " + Theme.mode + " theme"
                                 font.family: Theme.fontFamily
                                 font.pixelSize: Theme.fontSizeSmall
                                 color: Theme.textSecondary
@@ -726,6 +958,29 @@ Page {
                         }
                     }
 
+                    // Google features: only the enabled ones are requested when connecting
+                    RowLayout {
+                        Layout.fillWidth: true
+                        spacing: Theme.spacingMd
+
+                        Repeater {
+                            model: [
+                                { key: "gmail", label: "Gmail", enabled: googleAuthModel.gmail_enabled, granted: googleAuthModel.gmail_granted },
+                                { key: "calendar", label: "Calendar", enabled: googleAuthModel.calendar_enabled, granted: googleAuthModel.calendar_granted }
+                            ]
+
+                            delegate: CheckBox {
+                                required property var modelData
+                                text: modelData.label + (googleAuthModel.authenticated && modelData.enabled && !modelData.granted ? " (not granted)" : "")
+                                checked: modelData.enabled
+                                enabled: !googleAuthModel.loading
+                                onToggled: googleAuthModel.set_feature_enabled(modelData.key, checked)
+                            }
+                        }
+
+                        Item { Layout.fillWidth: true }
+                    }
+
                     // Google Error message
                     Label {
                         visible: googleAuthModel.error_message !== ""
@@ -737,6 +992,15 @@ Page {
                         Layout.fillWidth: true
                     }
 
+                    // Re-consent for scopes left unticked on Google's consent screen
+                    Button {
+                        visible: googleAuthModel.declined_features !== ""
+                        text: "Grant " + googleAuthModel.declined_features + " access"
+                        enabled: !googleAuthModel.loading
+                        font.pixelSize: Theme.fontSizeSmall
+                        onClicked: googleAuthModel.reconsent()
+                    }
+
                     // Google setup helper
                     Label {
                         text: "Add Google Cloud credentials (client_id and client_secret) to config.toml before connecting. Create OAuth 2.0 credentials at console.cloud.google.com."
@@ -756,6 +1020,88 @@ Page {
                         onClicked: googleAuthModel.open_config_folder()
                     }
 
+                    // Token health
+                    RowLayout {
+                        Layout.fillWidth: true
+                        Layout.topMargin: Theme.spacingSm
+                        spacing: Theme.spacingSm
+
+                        Label {
+                            text: "Token health"
+                            font.family: Theme.fontFamily
+                            font.pixelSize: Theme.fontSizeNormal
+                            font.bold: true
+                            color: Theme.text
+                        }
+
+                        Item { Layout.fillWidth: true }
+
+                        Button {
+                            text: settingsModel.checking ? "Checking..." : "Check again"
+                            enabled: !settingsModel.checking
+                            onClicked: settingsModel.check_tokens()
+                        }
+                    }
+
+                    Label {
+                        visible: settingsModel.error_message !== ""
+                        text: settingsModel.error_message
+                        font.family: Theme.fontFamily
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.error
+                        wrapMode: Text.WordWrap
+                        Layout.fillWidth: true
+                    }
+
+                    Repeater {
+                        model: settingsPage.tokenReport
+
+                        delegate: Rectangle {
+                            required property var modelData
+                            Layout.fillWidth: true
+                            implicitHeight: tokenRow.implicitHeight + Theme.spacingSm * 2
+                            radius: Theme.inputRadius
+                            color: modelData.healthy ? Theme.successBg
+                                : modelData.state === "missing" ? Theme.surfaceAlt : Theme.warningBg
+
+                            RowLayout {
+                                id: tokenRow
+                                anchors.fill: parent
+                                anchors.margins: Theme.spacingSm
+                                spacing: Theme.spacingSm
+
+                                Text {
+                                    text: modelData.healthy ? Icons.check : Icons.warning
+                                    font.family: Icons.family
+                                    font.pixelSize: Theme.fontSizeNormal
+                                    color: modelData.healthy ? Theme.success : Theme.warning
+                                }
+
+                                ColumnLayout {
+                                    Layout.fillWidth: true
+                                    spacing: 2
+
+                                    Label {
+                                        text: modelData.service + " — " + modelData.summary
+                                        font.family: Theme.fontFamily
+                                        font.pixelSize: Theme.fontSizeSmall
+                                        color: Theme.text
+                                        wrapMode: Text.WordWrap
+                                        Layout.fillWidth: true
+                                    }
+
+                                    Label {
+                                        visible: modelData.expires !== ""
+                                        text: "Expires " + modelData.expires
+                                        font.family: Theme.fontFamily
+                                        font.pixelSize: Theme.fontSizeSmall
+                                        color: Theme.textMuted
+                                    }
+                                }
+                            }
+                        }
+                    }
+
                     // Info text
                     Rectangle {
                         Layout.fillWidth: true
@@ -799,6 +1145,86 @@ Page {
                 }
             }
 
+            // All Settings Section
+            Rectangle {
+                Layout.fillWidth: true
+                Layout.preferredHeight: allSettingsContent.implicitHeight + Theme.spacingMd * 2
+                color: Theme.surface
+                border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                border.width: 1
+                radius: Theme.cardRadius
+
+                ColumnLayout {
+                    id: allSettingsContent
+                    anchors.fill: parent
+                    anchors.margins: Theme.spacingMd
+                    spacing: Theme.spacingSm
+
+                    Label {
+                        text: "All settings"
+                        font.family: Theme.fontFamily
+                        font.pixelSize: Theme.fontSizeMedium
+                        font.bold: true
+                        color: Theme.text
+                    }
+
+                    Label {
+                        text: "Every option from config.toml, checked as you edit"
+                        font.family: Theme.fontFamily
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.textSecondary
+                        wrapMode: Text.WordWrap
+                        Layout.fillWidth: true
+                    }
+
+                    Button {
+                        text: "Edit all settings"
+                        font.pixelSize: Theme.fontSizeSmall
+                        onClicked: AppContext.pageStack.push(AppContext.pageUrl("AllSettingsPage"))
+                    }
+                }
+            }
+
+            // Import Section
+            Rectangle {
+                Layout.fillWidth: true
+                Layout.preferredHeight: importContent.implicitHeight + Theme.spacingMd * 2
+                color: Theme.surface
+                border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                border.width: 1
+                radius: Theme.cardRadius
+
+                ColumnLayout {
+                    id: importContent
+                    anchors.fill: parent
+                    anchors.margins: Theme.spacingMd
+                    spacing: Theme.spacingSm
+
+                    Label {
+                        text: "Import"
+                        font.family: Theme.fontFamily
+                        font.pixelSize: Theme.fontSizeMedium
+                        font.bold: true
+                        color: Theme.text
+                    }
+
+                    Label {
+                        text: "Bring in tasks from Todoist or Trello and notes from Google Keep"
+                        font.family: Theme.fontFamily
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.textSecondary
+                        wrapMode: Text.WordWrap
+                        Layout.fillWidth: true
+                    }
+
+                    Button {
+                        text: "Import from another app"
+                        font.pixelSize: Theme.fontSizeSmall
+                        onClicked: AppContext.pageStack.push(AppContext.pageUrl("ImportPage"))
+                    }
+                }
+            }
+
             // About Section
             Rectangle {
                 Layout.fillWidth: true
@@ -12,16 +12,26 @@ Page {
         color: Theme.background
     }
 
+    // Precipitation for the next 2 hours, refreshed with the weather data
+    property var precipSeries: []
+    // Latest radar frame around the location ({} until it loads)
+    property var radar: ({})
+
     WeatherModel {
         id: weatherModel
         Component.onCompleted: refresh()
+        onWeather_changed: {
+            weatherPage.precipSeries = JSON.parse(get_precip_series(2));
+            refresh_radar();
+        }
+        onRadar_changed: weatherPage.radar = JSON.parse(get_radar())
     }
 
     // Timer to poll for async weather operation results
     Timer {
         id: weatherPollTimer
         interval: 100
-        running: weatherModel.loading
+        running: weatherModel.loading || weatherModel.radar_loading
         repeat: true
         onTriggered: weatherModel.poll_channel()
     }
@@ -254,6 +264,14 @@ Page {
                                 font.pixelSize: Theme.fontSizeNormal
                                 color: Theme.textMuted
                             }
+
+                            // Compared with yesterday
+                            Label {
+                                visible: weatherModel.has_comparison
+                                text: weatherModel.comparison_text
+                                font.pixelSize: Theme.fontSizeNormal
+                                color: weatherModel.temperature_delta >= 0 ? Theme.warning : Theme.primary
+                            }
                         }
 
                         Item { Layout.fillWidth: true }
@@ -356,9 +374,18 @@ Page {
                             color: Theme.text
                         }
 
-                        // Empty spacer
-                        Item { width: 1 }
-                        Item { width: 1 }
+                        // Day length
+                        Text {
+                            font.family: Icons.family
+                            font.pixelSize: Theme.fontSizeMedium
+                            text: Icons.clock
+                            color: Theme.textSecondary
+                        }
+                        Label {
+                            text: weatherModel.day_length
+                            color: Theme.text
+                            Layout.rightMargin: Theme.spacingLg
+                        }
 
                         // Sunset
                         Text {
@@ -372,6 +399,133 @@ Page {
                             color: Theme.text
                         }
                     }
+
+                    // Moon and golden hour
+                    ColumnLayout {
+                        visible: weatherModel.moon_phase !== ""
+                        spacing: Theme.spacingXs
+
+                        Label {
+                            text: `${weatherModel.moon_phase} · ${weatherModel.moon_illumination}% lit`
+                            font.pixelSize: Theme.fontSizeNormal
+                            color: Theme.textSecondary
+                        }
+
+                        Label {
+                            visible: weatherModel.golden_hour_morning !== ""
+                            text: `Golden hour ${weatherModel.golden_hour_morning} and ${weatherModel.golden_hour_evening}`
+                            font.pixelSize: Theme.fontSizeNormal
+                            color: Theme.textSecondary
+                        }
+                    }
+                }
+            }
+
+            // Rain nowcast sparkline
+            Rectangle {
+                Layout.fillWidth: true
+                Layout.preferredHeight: nowcastContent.implicitHeight + Theme.spacingMd * 2
+                color: Theme.surface
+                border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                border.width: 1
+                radius: Theme.cardRadius
+                visible: weatherModel.has_data && weatherPage.precipSeries.length > 0
+
+                ColumnLayout {
+                    id: nowcastContent
+                    anchors.fill: parent
+                    anchors.margins: Theme.spacingMd
+                    spacing: Theme.spacingSm
+
+                    Label {
+                        text: weatherModel.minutes_until_rain < 0 ? "No rain expected in the next 2 hours"
+                            : weatherModel.minutes_until_rain === 0 ? "Raining now"
+                            : `Rain in ${weatherModel.minutes_until_rain} minutes`
+                        font.pixelSize: Theme.fontSizeNormal
+                        color: weatherModel.minutes_until_rain < 0 ? Theme.textSecondary : Theme.text
+                    }
+
+                    RowLayout {
+                        id: sparkline
+                        Layout.fillWidth: true
+                        Layout.preferredHeight: 40
+                        spacing: 2
+
+                        // Scale to the heaviest point, but at least 2 mm/h so drizzle stays small
+                        readonly property real peak: Math.max(2, ...weatherPage.precipSeries.map(p => p.intensity))
+
+                        Repeater {
+                            model: weatherPage.precipSeries
+
+                            Rectangle {
+                                required property var modelData
+                                Layout.fillWidth: true
+                                Layout.alignment: Qt.AlignBottom
+                                Layout.preferredHeight: Math.max(2, sparkline.height * Math.min(1, modelData.intensity / sparkline.peak))
+                                radius: 1
+                                color: modelData.intensity > 0 ? Theme.primary : Theme.textSecondary + "40"
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Precipitation radar around the location
+            Rectangle {
+                Layout.fillWidth: true
+                Layout.preferredHeight: radarContent.implicitHeight + Theme.spacingMd * 2
+                color: Theme.surface
+                border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                border.width: 1
+                radius: Theme.cardRadius
+                visible: weatherModel.has_data && weatherPage.radar.radarUrl !== undefined
+
+                ColumnLayout {
+                    id: radarContent
+                    anchors.fill: parent
+                    anchors.margins: Theme.spacingMd
+                    spacing: Theme.spacingSm
+
+                    Label {
+                        text: `Radar at ${weatherPage.radar.time || ""}`
+                        font.pixelSize: Theme.fontSizeNormal
+                        color: Theme.text
+                    }
+
+                    Item {
+                        Layout.preferredWidth: 256
+                        Layout.preferredHeight: 256
+
+                        Image {
+                            anchors.fill: parent
+                            source: weatherPage.radar.mapUrl || ""
+                            asynchronous: true
+                        }
+
+                        Image {
+                            anchors.fill: parent
+                            source: weatherPage.radar.radarUrl || ""
+                            asynchronous: true
+                        }
+
+                        // The user's location
+                        Rectangle {
+                            width: 10
+                            height: 10
+                            radius: 5
+                            x: (weatherPage.radar.x || 0) * parent.width - width / 2
+                            y: (weatherPage.radar.y || 0) * parent.height - height / 2
+                            color: Theme.primary
+                            border.color: "white"
+                            border.width: 2
+                        }
+                    }
+
+                    Label {
+                        text: "Radar by RainViewer · Map © OpenStreetMap contributors"
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.textSecondary
+                    }
                 }
             }
 
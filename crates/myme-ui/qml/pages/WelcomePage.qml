@@ -26,6 +26,141 @@ Page {
         return Qt.formatDate(now, "dddd, MMMM d");
     }
 
+    // What the dashboard showed last run, shown until live data arrives
+    property var snapshot: ({})
+    readonly property var savedWeather: AppContext.weatherModel && AppContext.weatherModel.has_data
+        ? null : (snapshot.weather || null)
+    // The calendar model reads its cache once services are up
+    readonly property bool savedCalendar: !AppContext.servicesReady
+        && (snapshot.todayEventCount > 0 || !!snapshot.nextEventSummary)
+    readonly property bool showingSnapshot: savedWeather !== null || savedCalendar
+        || (!activityLoaded && activityFeed.length > 0)
+
+    DashboardModel {
+        id: dashboardModel
+        onSnapshot_changed: {
+            try {
+                welcomePage.snapshot = JSON.parse(get_snapshot());
+            } catch (e) {
+                welcomePage.snapshot = {};
+            }
+            if (!welcomePage.activityLoaded)
+                welcomePage.activityFeed = welcomePage.snapshot.activity || [];
+        }
+        Component.onCompleted: refresh()
+    }
+
+    // Commit activity across local repos for the "This week" section
+    property var activityFeed: []
+    property var activitySummary: ({})
+    // Until the first scan finishes, activityFeed holds the snapshot's commits
+    property bool activityLoaded: false
+    // Repos with open security alerts, as last checked from the Repos page
+    property var vulnerableRepos: []
+
+    RepoModel {
+        id: activityRepoModel
+        onActivity_changed: {
+            try {
+                welcomePage.activityFeed = JSON.parse(get_activity_feed(8));
+                welcomePage.activitySummary = JSON.parse(get_activity_summary());
+            } catch (e) {
+                welcomePage.activityFeed = [];
+                welcomePage.activitySummary = {};
+            }
+            welcomePage.activityLoaded = true;
+            activityPollTimer.stop();
+        }
+        onSecurity_changed: {
+            try {
+                welcomePage.vulnerableRepos = JSON.parse(get_vulnerable_repos());
+            } catch (e) {
+                welcomePage.vulnerableRepos = [];
+            }
+        }
+        Component.onCompleted: {
+            fetch_activity();
+            load_security();
+        }
+    }
+
+    Timer {
+        id: activityPollTimer
+        interval: 100
+        running: true
+        repeat: true
+        onTriggered: activityRepoModel.poll_channel()
+    }
+
+    // Changes made through the app today, from the activity log
+    property var todayEntries: []
+
+    ActivityModel {
+        id: activityModel
+        onEntries_changed: {
+            try {
+                welcomePage.todayEntries = JSON.parse(get_entries_today());
+            } catch (e) {
+                welcomePage.todayEntries = [];
+            }
+        }
+        Component.onCompleted: refresh()
+    }
+
+    // Today's suggestions from the [briefing] rules
+    property var suggestions: []
+
+    BriefingModel {
+        id: briefingModel
+        onSuggestions_changed: {
+            try {
+                welcomePage.suggestions = JSON.parse(get_suggestions());
+            } catch (e) {
+                welcomePage.suggestions = [];
+            }
+        }
+        Component.onCompleted: refresh()
+    }
+
+    onVisibleChanged: {
+        if (visible) {
+            activityModel.refresh();
+            briefingModel.refresh();
+            dashboardModel.refresh();
+            activityRepoModel.load_security();
+        }
+    }
+
+    Timer {
+        interval: 60000
+        running: welcomePage.visible
+        repeat: true
+        onTriggered: {
+            activityModel.refresh();
+            briefingModel.refresh();
+            dashboardModel.refresh();
+        }
+    }
+
+    // Newest unread feed items for the reading widget
+    property var readingQueue: []
+
+    function loadReadingQueue() {
+        if (!AppContext.feedModel) return;
+        try {
+            welcomePage.readingQueue = JSON.parse(AppContext.feedModel.get_items("", true, 3));
+        } catch (e) {
+            welcomePage.readingQueue = [];
+        }
+    }
+
+    Connections {
+        target: AppContext.feedModel
+        function onFeeds_changed() { welcomePage.loadReadingQueue() }
+    }
+
+    Component.onCompleted: loadReadingQueue()
+
     ScrollView {
         id: scroll
         anchors.fill: parent
@@ -58,6 +193,74 @@ Page {
                     font.family: Theme.fontFamily
                     color: Theme.textSecondary
                 }
+
+                Label {
+                    visible: welcomePage.showingSnapshot && dashboardModel.updated_label !== ""
+                    text: "Saved data from " + dashboardModel.updated_label + " · refreshing…"
+                    font.pixelSize: Theme.fontSizeSmall
+                    font.family: Theme.fontFamily
+                    color: Theme.textMuted
+                }
+            }
+
+            // First-run setup guide; hides itself when done or dismissed
+            OnboardingCard {
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingXl
+                Layout.rightMargin: Theme.spacingXl
+            }
+
+            // Daily suggestions
+            Rectangle {
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingXl
+                Layout.rightMargin: Theme.spacingXl
+                Layout.preferredHeight: suggestionsColumn.implicitHeight + Theme.spacingMd * 2
+                visible: welcomePage.suggestions.length > 0
+                radius: Theme.cardRadius
+                color: Theme.surface
+                border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                border.width: 1
+
+                ColumnLayout {
+                    id: suggestionsColumn
+                    anchors.fill: parent
+                    anchors.margins: Theme.spacingMd
+                    spacing: Theme.spacingXs
+
+                    Label {
+                        text: "Today"
+                        font.pixelSize: Theme.fontSizeMedium
+                        font.weight: Font.DemiBold
+                        font.family: Theme.fontFamily
+                        color: Theme.text
+                    }
+
+                    Repeater {
+                        model: welcomePage.suggestions
+
+                        RowLayout {
+                            Layout.fillWidth: true
+                            spacing: Theme.spacingSm
+
+                            Text {
+                                font.family: Icons.family
+                                font.pixelSize: 16
+                                text: Icons.cloud_sun
+                                color: Theme.primary
+                            }
+
+                            Label {
+                                Layout.fillWidth: true
+                                text: modelData
+                                wrapMode: Text.WordWrap
+                                font.pixelSize: Theme.fontSizeNormal
+                                font.family: Theme.fontFamily
+                                color: Theme.text
+                            }
+                        }
+                    }
+                }
             }
 
             // Stat cards
@@ -166,7 +369,8 @@ Page {
                             spacing: 2
 
                             Label {
-                                text: AppContext.calendarModel ? (AppContext.calendarModel.authenticated ? AppContext.calendarModel.today_event_count.toString() : "--") : "--"
+                                text: welcomePage.savedCalendar ? welcomePage.snapshot.todayEventCount.toString()
+                                    : AppContext.calendarModel ? (AppContext.calendarModel.authenticated ? AppContext.calendarModel.today_event_count.toString() : "--") : "--"
                                 font.pixelSize: Theme.fontSizeXLarge
                                 font.weight: Font.Bold
                                 font.family: Theme.fontFamily
@@ -264,15 +468,38 @@ Page {
                     }
                 }
 
+                // Feed reading queue
+                FeedWidget {
+                    Layout.fillWidth: true
+                    Layout.minimumWidth: 180
+                    loading: AppContext.feedModel ? AppContext.feedModel.loading : false
+                    feedCount: AppContext.feedModel ? AppContext.feedModel.feed_count : 0
+                    unreadCount: AppContext.feedModel ? AppContext.feedModel.unread_count : 0
+                    items: welcomePage.readingQueue
+
+                    onClicked: AppContext.goToTopLevelPage(AppContext.pageUrl("FeedsPage"))
+                    onRefreshRequested: {
+                        if (AppContext.feedModel) AppContext.feedModel.refresh();
+                    }
+                    onItemClicked: (item) => {
+                        if (item.link) Qt.openUrlExternally(item.link);
+                        AppContext.feedModel.set_read(item.feedUrl, item.id, true);
+                    }
+                }
+
                 // Calendar widget
                 CalendarWidget {
                     Layout.fillWidth: true
                     Layout.minimumWidth: 180
                     loading: AppContext.calendarModel ? AppContext.calendarModel.loading : false
-                    authenticated: AppContext.calendarModel ? AppContext.calendarModel.authenticated : false
-                    todayEventCount: AppContext.calendarModel ? AppContext.calendarModel.today_event_count : 0
-                    nextEventSummary: AppContext.calendarModel ? AppContext.calendarModel.next_event_summary : ""
-                    nextEventTime: AppContext.calendarModel ? AppContext.calendarModel.next_event_time : ""
+                    authenticated: welcomePage.savedCalendar
+                        || (AppContext.calendarModel ? AppContext.calendarModel.authenticated : false)
+                    todayEventCount: welcomePage.savedCalendar ? welcomePage.snapshot.todayEventCount
+                        : AppContext.calendarModel ? AppContext.calendarModel.today_event_count : 0
+                    nextEventSummary: welcomePage.savedCalendar ? welcomePage.snapshot.nextEventSummary
+                        : AppContext.calendarModel ? AppContext.calendarModel.next_event_summary : ""
+                    nextEventTime: welcomePage.savedCalendar ? welcomePage.snapshot.nextEventTime
+                        : AppContext.calendarModel ? AppContext.calendarModel.next_event_time : ""
 
                     onClicked: AppContext.goToTopLevelPage(AppContext.pageUrl("CalendarPage"))
                     onRefreshRequested: {
@@ -282,23 +509,28 @@ Page {
 
                 // Weather widget
                 WeatherWidget {
+                    // Last run's weather, shown as stale until the model has data
+                    readonly property var saved: welcomePage.savedWeather
+
                     Layout.fillWidth: true
                     Layout.minimumWidth: 180
                     loading: AppContext.weatherModel ? AppContext.weatherModel.loading : false
-                    hasData: AppContext.weatherModel ? AppContext.weatherModel.has_data : false
-                    isStale: AppContext.weatherModel ? AppContext.weatherModel.is_stale : false
-                    temperature: AppContext.weatherModel ? AppContext.weatherModel.temperature : 0
-                    feelsLike: AppContext.weatherModel ? AppContext.weatherModel.feels_like : 0
-                    humidity: AppContext.weatherModel ? AppContext.weatherModel.humidity : 0
-                    windSpeed: AppContext.weatherModel ? AppContext.weatherModel.wind_speed : 0
-                    condition: AppContext.weatherModel ? AppContext.weatherModel.condition : ""
-                    conditionIcon: AppContext.weatherModel ? AppContext.weatherModel.condition_icon : ""
-                    locationName: AppContext.weatherModel ? AppContext.weatherModel.location_name : ""
-                    todayHigh: AppContext.weatherModel ? AppContext.weatherModel.today_high : 0
-                    todayLow: AppContext.weatherModel ? AppContext.weatherModel.today_low : 0
-                    precipChance: AppContext.weatherModel ? AppContext.weatherModel.precipitation_chance : 0
-                    sunrise: AppContext.weatherModel ? AppContext.weatherModel.sunrise : ""
-                    sunset: AppContext.weatherModel ? AppContext.weatherModel.sunset : ""
+                    hasData: saved !== null || (AppContext.weatherModel ? AppContext.weatherModel.has_data : false)
+                    isStale: saved !== null || (AppContext.weatherModel ? AppContext.weatherModel.is_stale : false)
+                    temperature: saved ? saved.temperature : AppContext.weatherModel ? AppContext.weatherModel.temperature : 0
+                    feelsLike: saved ? saved.feelsLike : AppContext.weatherModel ? AppContext.weatherModel.feels_like : 0
+                    humidity: saved ? saved.humidity : AppContext.weatherModel ? AppContext.weatherModel.humidity : 0
+                    windSpeed: saved ? saved.windSpeed : AppContext.weatherModel ? AppContext.weatherModel.wind_speed : 0
+                    condition: saved ? saved.condition : AppContext.weatherModel ? AppContext.weatherModel.condition : ""
+                    conditionIcon: saved ? saved.conditionIcon : AppContext.weatherModel ? AppContext.weatherModel.condition_icon : ""
+                    locationName: saved ? saved.locationName : AppContext.weatherModel ? AppContext.weatherModel.location_name : ""
+                    todayHigh: saved ? saved.todayHigh : AppContext.weatherModel ? AppContext.weatherModel.today_high : 0
+                    todayLow: saved ? saved.todayLow : AppContext.weatherModel ? AppContext.weatherModel.today_low : 0
+                    precipChance: saved ? saved.precipChance : AppContext.weatherModel ? AppContext.weatherModel.precipitation_chance : 0
+                    sunrise: saved ? saved.sunrise : AppContext.weatherModel ? AppContext.weatherModel.sunrise : ""
+                    sunset: saved ? saved.sunset : AppContext.weatherModel ? AppContext.weatherModel.sunset : ""
+                    comparison: !saved && AppContext.weatherModel && AppContext.weatherModel.has_comparison
+                        ? AppContext.weatherModel.comparison_text : ""
 
                     onClicked: AppContext.goToTopLevelPage(AppContext.pageUrl("WeatherPage"))
                     onRefreshRequested: {
@@ -307,6 +539,297 @@ Page {
                 }
             }
 
+            // Repos with open Dependabot or code scanning alerts
+            ColumnLayout {
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingXl
+                Layout.rightMargin: Theme.spacingXl
+                spacing: Theme.spacingSm
+                visible: welcomePage.vulnerableRepos.length > 0
+
+                Label {
+                    text: "Security alerts"
+                    font.pixelSize: Theme.fontSizeMedium
+                    font.weight: Font.Medium
+                    font.family: Theme.fontFamily
+                    color: Theme.text
+                }
+
+                Rectangle {
+                    Layout.fillWidth: true
+                    implicitHeight: securityColumn.implicitHeight + Theme.spacingMd * 2
+                    radius: Theme.cardRadius
+                    color: Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+
+                    ColumnLayout {
+                        id: securityColumn
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingMd
+                        spacing: Theme.spacingXs
+
+                        Repeater {
+                            model: welcomePage.vulnerableRepos
+
+                            delegate: RowLayout {
+                                required property var modelData
+                                Layout.fillWidth: true
+                                spacing: Theme.spacingSm
+
+                                Label {
+                                    text: modelData.repo
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    font.weight: Font.Medium
+                                    font.family: Theme.fontFamily
+                                    color: Theme.primary
+                                    elide: Text.ElideRight
+                                    Layout.fillWidth: true
+
+                                    MouseArea {
+                                        anchors.fill: parent
+                                        cursorShape: Qt.PointingHandCursor
+                                        onClicked: Qt.openUrlExternally("https://github.com/" + modelData.repo + "/security")
+                                    }
+                                }
+
+                                SecurityBadge {
+                                    count: modelData.total
+                                    severity: modelData.severity
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // First notes of the notes list, as of the last time it loaded
+            ColumnLayout {
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingXl
+                Layout.rightMargin: Theme.spacingXl
+                spacing: Theme.spacingSm
+                visible: (welcomePage.snapshot.notes || []).length > 0
+
+                Label {
+                    text: "Notes"
+                    font.pixelSize: Theme.fontSizeMedium
+                    font.weight: Font.Medium
+                    font.family: Theme.fontFamily
+                    color: Theme.text
+                }
+
+                Rectangle {
+                    Layout.fillWidth: true
+                    implicitHeight: notesColumn.implicitHeight + Theme.spacingMd * 2
+                    radius: Theme.cardRadius
+                    color: Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+
+                    MouseArea {
+                        anchors.fill: parent
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: AppContext.goToTopLevelPage(AppContext.pageUrl("NotePage"))
+                    }
+
+                    ColumnLayout {
+                        id: notesColumn
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingMd
+                        spacing: Theme.spacingXs
+
+                        Repeater {
+                            model: welcomePage.snapshot.notes || []
+
+                            delegate: RowLayout {
+                                required property var modelData
+                                Layout.fillWidth: true
+                                spacing: Theme.spacingSm
+
+                                Text {
+                                    font.family: Icons.family
+                                    font.pixelSize: 14
+                                    text: modelData.pinned ? Icons.pushPin : Icons.note
+                                    color: modelData.pinned ? Theme.primary : Theme.textMuted
+                                }
+
+                                Label {
+                                    text: modelData.title
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    font.family: Theme.fontFamily
+                                    color: Theme.text
+                                    elide: Text.ElideRight
+                                    Layout.fillWidth: true
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Commit activity this week
+            ColumnLayout {
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingXl
+                Layout.rightMargin: Theme.spacingXl
+                spacing: Theme.spacingSm
+                visible: welcomePage.activityFeed.length > 0
+
+                RowLayout {
+                    Layout.fillWidth: true
+
+                    Label {
+                        text: "Activity this week"
+                        font.pixelSize: Theme.fontSizeMedium
+                        font.weight: Font.Medium
+                        font.family: Theme.fontFamily
+                        color: Theme.text
+                        Layout.fillWidth: true
+                    }
+
+                    Label {
+                        visible: welcomePage.activityLoaded
+                        text: (welcomePage.activitySummary.commits || 0) + " commits in "
+                              + (welcomePage.activitySummary.active_repos || 0) + " repos · +"
+                              + (welcomePage.activitySummary.insertions || 0) + " −"
+                              + (welcomePage.activitySummary.deletions || 0)
+                        font.pixelSize: Theme.fontSizeSmall
+                        font.family: Theme.fontFamily
+                        color: Theme.textSecondary
+                    }
+                }
+
+                Rectangle {
+                    Layout.fillWidth: true
+                    implicitHeight: feedColumn.implicitHeight + Theme.spacingMd * 2
+                    radius: Theme.cardRadius
+                    color: Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+
+                    MouseArea {
+                        anchors.fill: parent
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: AppContext.goToTopLevelPage(AppContext.pageUrl("RepoPage"))
+                    }
+
+                    ColumnLayout {
+                        id: feedColumn
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingMd
+                        spacing: Theme.spacingXs
+
+                        Repeater {
+                            model: welcomePage.activityFeed
+
+                            delegate: RowLayout {
+                                required property var modelData
+                                Layout.fillWidth: true
+                                spacing: Theme.spacingSm
+
+                                Text {
+                                    font.family: Icons.family
+                                    font.pixelSize: 14
+                                    text: Icons.gitBranch
+                                    color: Theme.textMuted
+                                }
+
+                                Label {
+                                    text: modelData.repo
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    font.weight: Font.Medium
+                                    font.family: Theme.fontFamily
+                                    color: Theme.text
+                                }
+
+                                Label {
+                                    text: modelData.message
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    font.family: Theme.fontFamily
+                                    color: Theme.textSecondary
+                                    elide: Text.ElideRight
+                                    Layout.fillWidth: true
+                                }
+
+                                Label {
+                                    text: modelData.author + " · " + modelData.when
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    font.family: Theme.fontFamily
+                                    color: Theme.textMuted
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            // What the user did today
+            ColumnLayout {
+                Layout.fillWidth: true
+                Layout.leftMargin: Theme.spacingXl
+                Layout.rightMargin: Theme.spacingXl
+                spacing: Theme.spacingSm
+                visible: welcomePage.todayEntries.length > 0
+
+                Label {
+                    text: "Today"
+                    font.pixelSize: Theme.fontSizeMedium
+                    font.weight: Font.Medium
+                    font.family: Theme.fontFamily
+                    color: Theme.text
+                }
+
+                Rectangle {
+                    Layout.fillWidth: true
+                    implicitHeight: todayColumn.implicitHeight + Theme.spacingMd * 2
+                    radius: Theme.cardRadius
+                    color: Theme.surface
+                    border.color: Theme.isDark ? "#ffffff08" : "#00000008"
+                    border.width: 1
+
+                    ColumnLayout {
+                        id: todayColumn
+                        anchors.fill: parent
+                        anchors.margins: Theme.spacingMd
+                        spacing: Theme.spacingXs
+
+                        Repeater {
+                            model: welcomePage.todayEntries
+
+                            delegate: RowLayout {
+                                required property var modelData
+                                Layout.fillWidth: true
+                                spacing: Theme.spacingSm
+
+                                Label {
+                                    text: modelData.time
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    font.family: Theme.fontFamily
+                                    color: Theme.textMuted
+                                }
+
+                                Label {
+                                    text: modelData.service
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    font.family: Theme.fontFamily
+                                    color: Theme.primary
+                                }
+
+                                Label {
+                                    text: modelData.summary
+                                    font.pixelSize: Theme.fontSizeSmall
+                                    font.family: Theme.fontFamily
+                                    color: Theme.textSecondary
+                                    elide: Text.ElideRight
+                                    Layout.fillWidth: true
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             // Quick links section
             ColumnLayout {
                 Layout.fillWidth: true
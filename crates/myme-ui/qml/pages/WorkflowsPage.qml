@@ -3,6 +3,7 @@ import QtQuick.Controls
 import QtQuick.Layouts
 import myme_ui
 import ".."
+import "../components"
 
 Page {
     id: workflowsPage
@@ -16,10 +17,12 @@ Page {
         id: workflowModel
     }
 
+    // Fast while a request is in flight; slower otherwise to pick up runs
+    // reported by the GitHub sync
     Timer {
         id: workflowPollTimer
-        interval: 100
-        running: workflowModel.loading
+        interval: workflowModel.loading ? 100 : 1000
+        running: true
         repeat: true
         onTriggered: workflowModel.poll_channel()
     }
@@ -38,6 +41,15 @@ Page {
                 workflowModel.fetch_workflows();
             }
         }
+        function onInputs_changed() {
+            if (runDialog.visible) {
+                runDialog.reloadInputs();
+            }
+        }
+        // The toast bus reports the started run
+        function onWorkflow_dispatched(workflowName, gitRef) {
+            runDialog.close();
+        }
     }
 
     header: ToolBar {
@@ -277,12 +289,22 @@ Page {
                             anchors.margins: Theme.spacingMd
                             spacing: Theme.spacingSm
 
-                            Label {
-                                text: workflowModel.get_repo_id(repoColumn.parent.repoIndex)
-                                font.pixelSize: Theme.fontSizeMedium
-                                font.bold: true
-                                color: Theme.text
+                            RowLayout {
                                 Layout.fillWidth: true
+                                spacing: Theme.spacingSm
+
+                                Label {
+                                    text: workflowModel.get_repo_id(repoColumn.parent.repoIndex)
+                                    font.pixelSize: Theme.fontSizeMedium
+                                    font.bold: true
+                                    color: Theme.text
+                                    Layout.fillWidth: true
+                                }
+
+                                SecurityBadge {
+                                    count: workflowModel.get_alert_count(repoColumn.parent.repoIndex)
+                                    severity: workflowModel.get_alert_severity(repoColumn.parent.repoIndex)
+                                }
                             }
 
                             Repeater {
@@ -325,6 +347,14 @@ Page {
                                             Layout.alignment: Qt.AlignRight
                                         }
 
+                                        Button {
+                                            text: "Run"
+                                            enabled: !AppContext.offline
+                                            onClicked: runDialog.openFor(repoColumn.parent.repoIndex, workflowRow.parent.workflowIndex)
+                                            ToolTip.text: "Run this workflow manually"
+                                            ToolTip.visible: hovered
+                                        }
+
                                         ToolButton {
                                             text: Icons.cornersOut
                                             font.family: Icons.family
@@ -357,4 +387,140 @@ Page {
             }
         }
     }
+
+    Dialog {
+        id: runDialog
+        title: "Run " + workflowName
+        modal: true
+
+        anchors.centerIn: parent
+        width: Math.min(parent.width * 0.8, 500)
+
+        property int repoIndex: -1
+        property int workflowIndex: -1
+        property string workflowName: ""
+        property var info: ({ loaded: false, dispatchable: false, inputs: [] })
+        // Entered values by input name
+        property var values: ({})
+
+        function openFor(repo, workflow) {
+            repoIndex = repo;
+            workflowIndex = workflow;
+            workflowName = workflowModel.get_workflow_name(repo, workflow);
+            refField.text = "";
+            reloadInputs();
+            if (!info.loaded) {
+                workflowModel.load_inputs(repo, workflow);
+            }
+            open();
+        }
+
+        function reloadInputs() {
+            info = JSON.parse(workflowModel.get_inputs(repoIndex, workflowIndex));
+            var initial = {};
+            for (var i = 0; i < info.inputs.length; i++) {
+                var input = info.inputs[i];
+                initial[input.name] = input.default !== null ? input.default : (input.kind === "boolean" ? "false" : "");
+            }
+            values = initial;
+        }
+
+        footer: DialogButtonBox {
+            Button {
+                text: "Run"
+                enabled: runDialog.info.dispatchable && !workflowModel.loading
+                // Not AcceptRole: the dialog stays open until workflow_dispatched
+                DialogButtonBox.buttonRole: DialogButtonBox.ActionRole
+                onClicked: workflowModel.run_workflow(runDialog.repoIndex, runDialog.workflowIndex, refField.text, JSON.stringify(runDialog.values))
+            }
+            Button {
+                text: "Cancel"
+                DialogButtonBox.buttonRole: DialogButtonBox.RejectRole
+            }
+        }
+
+        ColumnLayout {
+            anchors.fill: parent
+            spacing: Theme.spacingSm
+
+            BusyIndicator {
+                visible: !runDialog.info.loaded
+                running: visible
+                Layout.alignment: Qt.AlignHCenter
+            }
+
+            Label {
+                visible: runDialog.info.loaded && !runDialog.info.dispatchable
+                text: "This workflow has no workflow_dispatch trigger, so it can't be run by hand."
+                wrapMode: Text.WordWrap
+                color: Theme.textSecondary
+                Layout.fillWidth: true
+            }
+
+            Label {
+                visible: workflowModel.error_message.length > 0
+                text: workflowModel.error_message
+                wrapMode: Text.WordWrap
+                color: Theme.error
+                Layout.fillWidth: true
+            }
+
+            TextField {
+                id: refField
+                visible: runDialog.info.dispatchable
+                placeholderText: "Branch or tag (default branch if empty)"
+                Layout.fillWidth: true
+            }
+
+            Repeater {
+                model: runDialog.info.inputs
+
+                delegate: ColumnLayout {
+                    required property var modelData
+                    Layout.fillWidth: true
+                    spacing: 2
+
+                    Label {
+                        text: modelData.name + (modelData.required ? " *" : "")
+                        font.bold: true
+                        color: Theme.text
+                    }
+
+                    Label {
+                        visible: modelData.description.length > 0
+                        text: modelData.description
+                        font.pixelSize: Theme.fontSizeSmall
+                        color: Theme.textSecondary
+                        wrapMode: Text.WordWrap
+                        Layout.fillWidth: true
+                    }
+
+                    CheckBox {
+                        visible: modelData.kind === "boolean"
+                        checked: runDialog.values[modelData.name] === "true"
+                        onToggled: runDialog.values[modelData.name] = checked ? "true" : "false"
+                    }
+
+                    ComboBox {
+                        visible: modelData.kind === "choice"
+                        model: modelData.options
+                        currentIndex: Math.max(0, modelData.options.indexOf(runDialog.values[modelData.name]))
+                        onActivated: runDialog.values[modelData.name] = currentText
+                        Component.onCompleted: if (modelData.kind === "choice" && modelData.options.length > 0)
+                            runDialog.values[modelData.name] = modelData.options[currentIndex]
+                        Layout.fillWidth: true
+                    }
+
+                    TextField {
+                        visible: modelData.kind !== "boolean" && modelData.kind !== "choice"
+                        text: runDialog.values[modelData.name] || ""
+                        placeholderText: modelData.kind === "number" ? "Number" : ""
+                        inputMethodHints: modelData.kind === "number" ? Qt.ImhFormattedNumbersOnly : Qt.ImhNone
+                        onTextEdited: runDialog.values[modelData.name] = text
+                        Layout.fillWidth: true
+                    }
+                }
+            }
+        }
+    }
 }
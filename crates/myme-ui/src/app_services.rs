@@ -15,9 +15,35 @@ use tokio::sync::broadcast;
 use tokio_util::sync::CancellationToken;
 
 use myme_auth::GitHubAuth;
-use myme_services::{GitHubClient, NoteClient, ProjectStore, SqliteNoteStore};
+use myme_core::{connectivity, App, DashboardStore, RecoveryInfo, SessionStore};
+use myme_integrations::RepoStateStore;
+use myme_services::{
+    ActivityLog, BookmarkStore, ClipboardHistory, GitHubClient, NoteClient, ProjectStore,
+    RetryConfig, SqliteNoteStore,
+};
 use myme_weather::{WeatherCache, WeatherProvider};
 
+use crate::services::google_common::get_google_access_token;
+use crate::services::{
+    flush_gmail_sync_queue, instantiate_due_templates, notify_due_calendar_reminders,
+    notify_travel_warnings, refresh_all_feeds, resurface_gmail_snoozed, run_github_sync,
+};
+
+/// How often trashed notes past their retention period are purged
+const TRASH_PURGE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+/// How often snoozed emails are checked for their wake-up time
+const SNOOZE_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// How often cached calendar events are checked for due reminders
+const REMINDER_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// How often upcoming events with a location are checked for leave-by warnings
+const TRAVEL_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2 * 60);
+
+/// How often scheduled templates are checked for a due run
+const TEMPLATE_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15 * 60);
+
 /// Message types for the repo service channel
 pub use crate::services::RepoServiceMessage;
 
@@ -45,6 +71,60 @@ pub use crate::services::GmailServiceMessage;
 /// Message types for the Calendar service channel
 pub use crate::services::CalendarServiceMessage;
 
+/// Message types for the quick-capture service channel
+pub use crate::services::CaptureServiceMessage;
+
+/// Message types for the contacts service channel
+pub use crate::services::ContactsServiceMessage;
+
+/// Message types for the feed reader service channel
+pub use crate::services::FeedServiceMessage;
+
+/// Message types for the bookmark service channel
+pub use crate::services::BookmarkServiceMessage;
+
+/// Message types for the GitHub notifications service channel
+pub use crate::services::NotificationServiceMessage;
+
+/// Message types for the GitHub gists service channel
+pub use crate::services::GistServiceMessage;
+
+/// Message types for the import service channel
+pub use crate::services::ImportServiceMessage;
+
+/// Message types for the starred repos / discovery service channel
+pub use crate::services::DiscoverServiceMessage;
+
+/// Message types for the GitHub insights service channel
+pub use crate::services::InsightsServiceMessage;
+
+/// Message types for the GitHub releases service channel
+pub use crate::services::ReleaseServiceMessage;
+
+/// Message types for the local repo checks service channel
+pub use crate::services::RunnerServiceMessage;
+
+/// Message types for the global search service channel
+pub use crate::services::SearchServiceMessage;
+
+/// Message types for the settings (account health) service channel
+pub use crate::services::SettingsServiceMessage;
+
+/// Message types for the theme service channel
+pub use crate::services::ThemeServiceMessage;
+
+/// Message types for the undo/redo service channel
+pub use crate::services::UndoServiceMessage;
+
+/// Message types for the remote-control (IPC) channel
+pub use crate::services::IpcServiceMessage;
+
+/// Message types for the note label service channel
+pub use crate::services::LabelServiceMessage;
+
+/// Message types for the clipboard history service channel
+pub use crate::services::ClipboardServiceMessage;
+
 /// Generate shutdown clear lines for service channels. Pass `self` so the macro can refer to the receiver.
 macro_rules! service_channel_shutdown {
     ($self_expr:expr; $($svc:ident : $msg:ty),* $(,)?) => {
@@ -99,6 +179,9 @@ pub struct AppServices {
     /// Tokio runtime for async operations
     runtime: tokio::runtime::Runtime,
 
+    /// Core app lifecycle (crash guard, recovery info from the previous run)
+    app: RwLock<Option<App>>,
+
     /// Shutdown signal broadcaster
     shutdown_tx: broadcast::Sender<()>,
 
@@ -112,7 +195,19 @@ pub struct AppServices {
     github_auth: RwLock<Option<Arc<GitHubAuth>>>,
 
     /// Project store (SQLite database)
-    project_store: RwLock<Option<Arc<parking_lot::Mutex<ProjectStore>>>>,
+    project_store: RwLock<Option<Arc<ProjectStore>>>,
+
+    /// Log of user-initiated changes (SQLite database)
+    activity_log: RwLock<Option<Arc<ActivityLog>>>,
+
+    /// Read-it-later bookmarks (SQLite database)
+    bookmark_store: RwLock<Option<Arc<BookmarkStore>>>,
+
+    /// Last-known local clones for the repos pane (SQLite database)
+    repo_state_store: RwLock<Option<Arc<RepoStateStore>>>,
+
+    /// Encrypted clipboard history (SQLite database, opt-in)
+    clipboard_history: RwLock<Option<Arc<ClipboardHistory>>>,
 
     /// Weather provider
     weather_provider: RwLock<Option<Arc<WeatherProvider>>>,
@@ -120,6 +215,31 @@ pub struct AppServices {
     /// Weather cache
     weather_cache: RwLock<Option<parking_lot::Mutex<WeatherCache>>>,
 
+    /// Workspace session (last project, filters, scroll positions)
+    session_store: RwLock<Option<Arc<parking_lot::Mutex<SessionStore>>>>,
+
+    /// What the dashboard last showed, painted before services are up
+    dashboard_store: RwLock<Option<Arc<parking_lot::Mutex<DashboardStore>>>>,
+
+    /// Connectivity monitor task (started once)
+    connectivity_task: RwLock<Option<tokio::task::JoinHandle<()>>>,
+    /// Task that resurfaces snoozed emails (started once)
+    snooze_task: RwLock<Option<tokio::task::JoinHandle<()>>>,
+    /// Task that raises calendar event reminders (started once)
+    reminder_task: RwLock<Option<tokio::task::JoinHandle<()>>>,
+    /// Task that raises leave-by warnings for events with a location (started once)
+    travel_task: RwLock<Option<tokio::task::JoinHandle<()>>>,
+    /// Task that creates notes and issues from scheduled templates (started once)
+    template_task: RwLock<Option<tokio::task::JoinHandle<()>>>,
+    /// Task that refreshes feed subscriptions in the background (started once)
+    feed_task: RwLock<Option<tokio::task::JoinHandle<()>>>,
+    /// Task that syncs GitHub changes from the webhook relay or by polling (started once)
+    github_sync_task: RwLock<Option<tokio::task::JoinHandle<()>>>,
+    /// Task that reloads the config when its files change (started once)
+    config_watch_task: RwLock<Option<tokio::task::JoinHandle<()>>>,
+    /// Remote-control socket path, once the IPC server is listening
+    ipc_socket: RwLock<Option<std::path::PathBuf>>,
+
     /// Repo service channel sender
     repo_service_tx: RwLock<Option<std::sync::mpsc::Sender<RepoServiceMessage>>>,
     /// Repo service channel receiver
@@ -165,6 +285,96 @@ pub struct AppServices {
     /// Calendar service channel receiver
     calendar_service_rx:
         RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<CalendarServiceMessage>>>>,
+    /// Capture service channel sender
+    capture_service_tx: RwLock<Option<std::sync::mpsc::Sender<CaptureServiceMessage>>>,
+    /// Capture service channel receiver
+    capture_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<CaptureServiceMessage>>>>,
+    /// Contacts service channel sender
+    contacts_service_tx: RwLock<Option<std::sync::mpsc::Sender<ContactsServiceMessage>>>,
+    /// Contacts service channel receiver
+    contacts_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<ContactsServiceMessage>>>>,
+    /// Feed service channel sender
+    feed_service_tx: RwLock<Option<std::sync::mpsc::Sender<FeedServiceMessage>>>,
+    /// Feed service channel receiver
+    feed_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<FeedServiceMessage>>>>,
+    /// Bookmark service channel sender
+    bookmark_service_tx: RwLock<Option<std::sync::mpsc::Sender<BookmarkServiceMessage>>>,
+    /// Bookmark service channel receiver
+    bookmark_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<BookmarkServiceMessage>>>>,
+    /// Notification service channel sender
+    notification_service_tx: RwLock<Option<std::sync::mpsc::Sender<NotificationServiceMessage>>>,
+    /// Notification service channel receiver
+    notification_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<NotificationServiceMessage>>>>,
+    /// Gist service channel sender
+    gist_service_tx: RwLock<Option<std::sync::mpsc::Sender<GistServiceMessage>>>,
+    /// Gist service channel receiver
+    gist_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<GistServiceMessage>>>>,
+    /// Import service channel sender
+    import_service_tx: RwLock<Option<std::sync::mpsc::Sender<ImportServiceMessage>>>,
+    /// Import service channel receiver
+    import_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<ImportServiceMessage>>>>,
+    /// Discover service channel sender
+    discover_service_tx: RwLock<Option<std::sync::mpsc::Sender<DiscoverServiceMessage>>>,
+    /// Discover service channel receiver
+    discover_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<DiscoverServiceMessage>>>>,
+    /// Insights service channel sender
+    insights_service_tx: RwLock<Option<std::sync::mpsc::Sender<InsightsServiceMessage>>>,
+    /// Insights service channel receiver
+    insights_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<InsightsServiceMessage>>>>,
+    /// Release service channel sender
+    release_service_tx: RwLock<Option<std::sync::mpsc::Sender<ReleaseServiceMessage>>>,
+    /// Release service channel receiver
+    release_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<ReleaseServiceMessage>>>>,
+    /// Runner service channel sender
+    runner_service_tx: RwLock<Option<std::sync::mpsc::Sender<RunnerServiceMessage>>>,
+    /// Runner service channel receiver
+    runner_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<RunnerServiceMessage>>>>,
+    /// Search service channel sender
+    search_service_tx: RwLock<Option<std::sync::mpsc::Sender<SearchServiceMessage>>>,
+    /// Search service channel receiver
+    search_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<SearchServiceMessage>>>>,
+    /// Settings service channel sender
+    settings_service_tx: RwLock<Option<std::sync::mpsc::Sender<SettingsServiceMessage>>>,
+    /// Settings service channel receiver
+    settings_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<SettingsServiceMessage>>>>,
+    /// Theme service channel sender
+    theme_service_tx: RwLock<Option<std::sync::mpsc::Sender<ThemeServiceMessage>>>,
+    /// Theme service channel receiver
+    theme_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<ThemeServiceMessage>>>>,
+    /// Undo service channel sender
+    undo_service_tx: RwLock<Option<std::sync::mpsc::Sender<UndoServiceMessage>>>,
+    /// Undo service channel receiver
+    undo_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<UndoServiceMessage>>>>,
+    /// IPC service channel sender
+    ipc_service_tx: RwLock<Option<std::sync::mpsc::Sender<IpcServiceMessage>>>,
+    /// IPC service channel receiver
+    ipc_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<IpcServiceMessage>>>>,
+    /// Label service channel sender
+    label_service_tx: RwLock<Option<std::sync::mpsc::Sender<LabelServiceMessage>>>,
+    /// Label service channel receiver
+    label_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<LabelServiceMessage>>>>,
+    /// Clipboard service channel sender
+    clipboard_service_tx: RwLock<Option<std::sync::mpsc::Sender<ClipboardServiceMessage>>>,
+    /// Clipboard service channel receiver
+    clipboard_service_rx:
+        RwLock<Option<parking_lot::Mutex<std::sync::mpsc::Receiver<ClipboardServiceMessage>>>>,
 
     /// Cancellation token for repo operations (clone, pull)
     repo_cancel_token: RwLock<Option<Arc<CancellationToken>>>,
@@ -194,12 +404,27 @@ impl AppServices {
                 Arc::new(Self {
                     runtime,
                     shutdown_tx,
+                    app: RwLock::new(None),
                     note_client: RwLock::new(None),
                     github_client: RwLock::new(None),
                     github_auth: RwLock::new(None),
                     project_store: RwLock::new(None),
+                    activity_log: RwLock::new(None),
+                    bookmark_store: RwLock::new(None),
+                    repo_state_store: RwLock::new(None),
+                    clipboard_history: RwLock::new(None),
                     weather_provider: RwLock::new(None),
                     weather_cache: RwLock::new(None),
+                    session_store: RwLock::new(None),
+                    dashboard_store: RwLock::new(None),
+                    connectivity_task: RwLock::new(None),
+                    snooze_task: RwLock::new(None),
+                    reminder_task: RwLock::new(None),
+                    travel_task: RwLock::new(None),
+                    template_task: RwLock::new(None),
+                    feed_task: RwLock::new(None),
+                    github_sync_task: RwLock::new(None),
+                    config_watch_task: RwLock::new(None),
                     repo_service_tx: RwLock::new(None),
                     repo_service_rx: RwLock::new(None),
                     note_service_tx: RwLock::new(None),
@@ -218,6 +443,43 @@ impl AppServices {
                     gmail_service_rx: RwLock::new(None),
                     calendar_service_tx: RwLock::new(None),
                     calendar_service_rx: RwLock::new(None),
+                    capture_service_tx: RwLock::new(None),
+                    capture_service_rx: RwLock::new(None),
+                    contacts_service_tx: RwLock::new(None),
+                    contacts_service_rx: RwLock::new(None),
+                    feed_service_tx: RwLock::new(None),
+                    feed_service_rx: RwLock::new(None),
+                    bookmark_service_tx: RwLock::new(None),
+                    bookmark_service_rx: RwLock::new(None),
+                    notification_service_tx: RwLock::new(None),
+                    notification_service_rx: RwLock::new(None),
+                    gist_service_tx: RwLock::new(None),
+                    gist_service_rx: RwLock::new(None),
+                    import_service_tx: RwLock::new(None),
+                    import_service_rx: RwLock::new(None),
+                    discover_service_tx: RwLock::new(None),
+                    discover_service_rx: RwLock::new(None),
+                    insights_service_tx: RwLock::new(None),
+                    insights_service_rx: RwLock::new(None),
+                    release_service_tx: RwLock::new(None),
+                    release_service_rx: RwLock::new(None),
+                    runner_service_tx: RwLock::new(None),
+                    runner_service_rx: RwLock::new(None),
+                    search_service_tx: RwLock::new(None),
+                    search_service_rx: RwLock::new(None),
+                    settings_service_tx: RwLock::new(None),
+                    settings_service_rx: RwLock::new(None),
+                    theme_service_tx: RwLock::new(None),
+                    theme_service_rx: RwLock::new(None),
+                    undo_service_tx: RwLock::new(None),
+                    undo_service_rx: RwLock::new(None),
+                    ipc_service_tx: RwLock::new(None),
+                    ipc_service_rx: RwLock::new(None),
+                    label_service_tx: RwLock::new(None),
+                    label_service_rx: RwLock::new(None),
+                    clipboard_service_tx: RwLock::new(None),
+                    clipboard_service_rx: RwLock::new(None),
+                    ipc_socket: RwLock::new(None),
                     repo_cancel_token: RwLock::new(None),
                 })
             })
@@ -244,13 +506,45 @@ impl AppServices {
         // Broadcast shutdown signal
         let _ = self.shutdown_tx.send(());
 
+        // Record the clean exit so the next start doesn't offer crash recovery
+        if let Some(mut app) = self.app.write().take() {
+            if let Err(e) = app.shutdown() {
+                tracing::warn!("App shutdown failed: {}", e);
+            }
+        }
+
+        // Persist workspace session before dropping state
+        if let Some(session) = self.session_store.write().take() {
+            if let Err(e) = session.lock().save() {
+                tracing::warn!("Failed to save session: {}", e);
+            }
+        }
+        if let Some(dashboard) = self.dashboard_store.write().take() {
+            if let Err(e) = dashboard.lock().save() {
+                tracing::warn!("Failed to save dashboard snapshot: {}", e);
+            }
+        }
+
         // Clear all mutable state
         *self.note_client.write() = None;
         *self.github_client.write() = None;
         *self.github_auth.write() = None;
         *self.project_store.write() = None;
+        *self.activity_log.write() = None;
+        *self.bookmark_store.write() = None;
+        *self.repo_state_store.write() = None;
+        *self.clipboard_history.write() = None;
         *self.weather_provider.write() = None;
         *self.weather_cache.write() = None;
+        *self.connectivity_task.write() = None;
+        *self.snooze_task.write() = None;
+        *self.reminder_task.write() = None;
+        *self.travel_task.write() = None;
+        *self.template_task.write() = None;
+        *self.feed_task.write() = None;
+        *self.github_sync_task.write() = None;
+        *self.config_watch_task.write() = None;
+        *self.ipc_socket.write() = None;
         service_channel_shutdown!(
             self;
             repo: RepoServiceMessage,
@@ -262,6 +556,24 @@ impl AppServices {
             kanban: KanbanServiceMessage,
             gmail: GmailServiceMessage,
             calendar: CalendarServiceMessage,
+            capture: CaptureServiceMessage,
+            contacts: ContactsServiceMessage,
+            feed: FeedServiceMessage,
+            bookmark: BookmarkServiceMessage,
+            notification: NotificationServiceMessage,
+            gist: GistServiceMessage,
+            import: ImportServiceMessage,
+            discover: DiscoverServiceMessage,
+            insights: InsightsServiceMessage,
+            release: ReleaseServiceMessage,
+            runner: RunnerServiceMessage,
+            search: SearchServiceMessage,
+            settings: SettingsServiceMessage,
+            theme: ThemeServiceMessage,
+            undo: UndoServiceMessage,
+            ipc: IpcServiceMessage,
+            label: LabelServiceMessage,
+            clipboard: ClipboardServiceMessage,
         );
 
         // Cancel any active repo operations
@@ -272,6 +584,44 @@ impl AppServices {
         tracing::info!("AppServices shutdown complete");
     }
 
+    // =========== App Lifecycle ===========
+
+    /// Create the core app and install its crash guard.
+    ///
+    /// Returns `true` if the app was initialized or was already initialized.
+    pub fn init_app(&self) -> bool {
+        let mut app = self.app.write();
+        if app.is_some() {
+            return true;
+        }
+        let mut core = match App::new() {
+            Ok(core) => core,
+            Err(e) => {
+                tracing::error!("Failed to create app: {}", e);
+                return false;
+            }
+        };
+        if let Err(e) = core.initialize() {
+            tracing::error!("Failed to install crash guard: {}", e);
+        }
+        *app = Some(core);
+        true
+    }
+
+    /// What the previous run left behind (crash report, queued work), if anything.
+    pub fn recovery_info(&self) -> Option<RecoveryInfo> {
+        self.app.read().as_ref().and_then(|app| app.recovery().cloned())
+    }
+
+    /// Stop offering the previous run's recovery info.
+    pub fn dismiss_recovery(&self) {
+        if let Some(app) = self.app.write().as_mut() {
+            if let Err(e) = app.dismiss_recovery() {
+                tracing::warn!("Failed to dismiss crash report: {}", e);
+            }
+        }
+    }
+
     // =========== Note Client ===========
 
     /// Get the unified note client if initialized.
@@ -303,7 +653,7 @@ impl AppServices {
             }
         }
 
-        let store = match SqliteNoteStore::new(&db_path) {
+        let mut store = match SqliteNoteStore::new(&db_path) {
             Ok(s) => s,
             Err(e) => {
                 tracing::error!("Failed to create SQLite note store at {:?}: {}", db_path, e);
@@ -312,10 +662,33 @@ impl AppServices {
         };
 
         tracing::info!("SQLite note store opened at {:?}", db_path);
-        self.set_note_client(Some(Arc::new(NoteClient::sqlite(store))));
+        if config.notes.encrypt || store.is_encrypted() {
+            unlock_note_store(&mut store);
+        }
+        let client = Arc::new(NoteClient::sqlite(store));
+        self.set_note_client(Some(client.clone()));
+        self.spawn_trash_purge(client, config.notes.trash_retention_days);
         true
     }
 
+    /// Purge notes past the trash retention period now and once a day after.
+    fn spawn_trash_purge(&self, client: Arc<NoteClient>, retention_days: u32) {
+        let mut shutdown = self.subscribe_shutdown();
+        self.runtime().spawn(async move {
+            let mut interval = tokio::time::interval(TRASH_PURGE_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = shutdown.recv() => break,
+                    _ = interval.tick() => {
+                        if let Err(e) = client.purge_trash(retention_days).await {
+                            tracing::warn!("Failed to purge note trash: {:#}", e);
+                        }
+                    }
+                }
+            }
+        });
+    }
+
     // =========== GitHub Client ===========
 
     /// Get the GitHub client if initialized.
@@ -357,8 +730,19 @@ impl AppServices {
         };
 
         // Create GitHub client
-        match GitHubClient::new(token) {
+        let config = myme_core::Config::load_cached();
+        let proxies = config.proxy.proxies_for("github");
+        let network = config.network.for_service("github");
+        match GitHubClient::new_with_timeout(token, proxies, network.timeout) {
             Ok(client) => {
+                let client = client
+                    .with_retry_config(RetryConfig {
+                        max_retries: network.max_retries,
+                        initial_delay: network.backoff_base,
+                        max_delay: network.max_backoff,
+                    })
+                    .with_graphql(config.github.graphql)
+                    .with_observer(myme_core::metrics::http_observer("github"));
                 self.set_github_client(Some(Arc::new(client)));
                 tracing::info!("GitHub client initialized");
                 true
@@ -397,10 +781,10 @@ impl AppServices {
             return false;
         }
 
-        let provider = Arc::new(GitHubAuth::new(
-            config.github.client_id.clone(),
-            config.github.client_secret.clone(),
-        ));
+        let provider = Arc::new(
+            GitHubAuth::new(config.github.client_id.clone(), config.github.client_secret.clone())
+                .with_proxies(config.proxy.proxies_for("github")),
+        );
 
         self.set_github_auth(Some(provider));
         tracing::info!("GitHub OAuth provider initialized");
@@ -410,12 +794,12 @@ impl AppServices {
     // =========== Project Store ===========
 
     /// Get the project store if initialized.
-    pub fn project_store(&self) -> Option<Arc<parking_lot::Mutex<ProjectStore>>> {
+    pub fn project_store(&self) -> Option<Arc<ProjectStore>> {
         self.project_store.read().clone()
     }
 
     /// Set or update the project store.
-    pub fn set_project_store(&self, store: Option<Arc<parking_lot::Mutex<ProjectStore>>>) {
+    pub fn set_project_store(&self, store: Option<Arc<ProjectStore>>) {
         *self.project_store.write() = store;
     }
 
@@ -438,7 +822,7 @@ impl AppServices {
 
         match ProjectStore::open(&db_path) {
             Ok(store) => {
-                self.set_project_store(Some(Arc::new(parking_lot::Mutex::new(store))));
+                self.set_project_store(Some(Arc::new(store)));
                 tracing::info!("Project store initialized at {:?}", db_path);
                 true
             }
@@ -449,6 +833,149 @@ impl AppServices {
         }
     }
 
+    // =========== Activity Log ===========
+
+    /// Get the activity log if initialized.
+    pub fn activity_log(&self) -> Option<Arc<ActivityLog>> {
+        self.activity_log.read().clone()
+    }
+
+    /// Open the activity log, pruning entries past the retention period.
+    pub fn init_activity_log(&self) -> bool {
+        if self.activity_log.read().is_some() {
+            return true;
+        }
+
+        let config_dir = myme_core::Config::load_cached().config_dir.clone();
+        if let Err(e) = std::fs::create_dir_all(&config_dir) {
+            tracing::error!("Failed to create config directory: {}", e);
+            return false;
+        }
+
+        let db_path = config_dir.join("activity.db");
+        match ActivityLog::open(&db_path) {
+            Ok(log) => {
+                let cutoff = chrono::Utc::now()
+                    - chrono::Duration::days(myme_services::activity_log::RETENTION_DAYS);
+                if let Err(e) = log.prune_before(cutoff) {
+                    tracing::warn!("Failed to prune activity log: {}", e);
+                }
+                *self.activity_log.write() = Some(Arc::new(log));
+                true
+            }
+            Err(e) => {
+                tracing::error!("Failed to open activity log: {}", e);
+                false
+            }
+        }
+    }
+
+    // =========== Bookmarks ===========
+
+    /// Get the bookmark store if initialized.
+    pub fn bookmark_store(&self) -> Option<Arc<BookmarkStore>> {
+        self.bookmark_store.read().clone()
+    }
+
+    /// Open the bookmark store.
+    pub fn init_bookmark_store(&self) -> bool {
+        if self.bookmark_store.read().is_some() {
+            return true;
+        }
+
+        let config_dir = myme_core::Config::load_cached().config_dir.clone();
+        if let Err(e) = std::fs::create_dir_all(&config_dir) {
+            tracing::error!("Failed to create config directory: {}", e);
+            return false;
+        }
+
+        match BookmarkStore::open(&config_dir.join("bookmarks.db")) {
+            Ok(store) => {
+                *self.bookmark_store.write() = Some(Arc::new(store));
+                true
+            }
+            Err(e) => {
+                tracing::error!("Failed to open bookmark store: {}", e);
+                false
+            }
+        }
+    }
+
+    // =========== Repo State Cache ===========
+
+    /// Get the repo state cache if initialized.
+    pub fn repo_state_store(&self) -> Option<Arc<RepoStateStore>> {
+        self.repo_state_store.read().clone()
+    }
+
+    /// Open the repo state cache, forgetting clones gone for a while.
+    pub fn init_repo_state_store(&self) -> bool {
+        if self.repo_state_store.read().is_some() {
+            return true;
+        }
+
+        let config_dir = myme_core::Config::load_cached().config_dir.clone();
+        if let Err(e) = std::fs::create_dir_all(&config_dir) {
+            tracing::error!("Failed to create config directory: {}", e);
+            return false;
+        }
+
+        match RepoStateStore::open(&config_dir.join("repo_state.db")) {
+            Ok(store) => {
+                let cutoff = chrono::Utc::now()
+                    - chrono::Duration::days(myme_integrations::repo_state::TOMBSTONE_DAYS);
+                if let Err(e) = store.prune_tombstones(cutoff) {
+                    tracing::warn!("Failed to prune repo state cache: {}", e);
+                }
+                *self.repo_state_store.write() = Some(Arc::new(store));
+                true
+            }
+            Err(e) => {
+                tracing::error!("Failed to open repo state cache: {}", e);
+                false
+            }
+        }
+    }
+
+    // =========== Clipboard History ===========
+
+    /// Get the clipboard history if initialized.
+    pub fn clipboard_history(&self) -> Option<Arc<ClipboardHistory>> {
+        self.clipboard_history.read().clone()
+    }
+
+    /// Open the clipboard history with its key from the keyring.
+    pub fn init_clipboard_history(&self) -> bool {
+        if self.clipboard_history.read().is_some() {
+            return true;
+        }
+
+        let key = match crate::services::clipboard_service::history_key() {
+            Ok(key) => key,
+            Err(e) => {
+                tracing::error!("Clipboard history disabled: {}", e);
+                return false;
+            }
+        };
+
+        let config_dir = myme_core::Config::load_cached().config_dir.clone();
+        if let Err(e) = std::fs::create_dir_all(&config_dir) {
+            tracing::error!("Failed to create config directory: {}", e);
+            return false;
+        }
+
+        match ClipboardHistory::open(&config_dir.join("clipboard.db"), &key) {
+            Ok(history) => {
+                *self.clipboard_history.write() = Some(Arc::new(history));
+                true
+            }
+            Err(e) => {
+                tracing::error!("Failed to open clipboard history: {}", e);
+                false
+            }
+        }
+    }
+
     // =========== Weather Services ===========
 
     /// Get the weather provider if initialized.
@@ -496,10 +1023,13 @@ impl AppServices {
         *self.weather_cache.write() = Some(parking_lot::Mutex::new(cache));
 
         // Create weather provider
-        match WeatherProvider::new(weather_unit) {
+        match weather_provider_from_config(weather_unit) {
             Ok(provider) => {
+                tracing::info!(
+                    "Weather provider initialized ({})",
+                    provider.source_names().join(" -> ")
+                );
                 self.set_weather_provider(Some(Arc::new(provider)));
-                tracing::info!("Weather provider initialized");
                 true
             }
             Err(e) => {
@@ -509,7 +1039,41 @@ impl AppServices {
         }
     }
 
-    // Service channel methods (repo, note, weather, auth, project, workflow, kanban, gmail, calendar)
+    // =========== Session Store ===========
+
+    /// Get the session store, loading it from disk on first use.
+    pub fn session_store(&self) -> Option<Arc<parking_lot::Mutex<SessionStore>>> {
+        if let Some(store) = self.session_store.read().clone() {
+            return Some(store);
+        }
+        let config_dir = myme_core::Config::load_cached().config_dir.clone();
+        let mut store = SessionStore::new(&config_dir);
+        if let Err(e) = store.load() {
+            tracing::warn!("Failed to restore session, starting fresh: {}", e);
+        }
+        let store = Arc::new(parking_lot::Mutex::new(store));
+        *self.session_store.write() = Some(store.clone());
+        Some(store)
+    }
+
+    // =========== Dashboard Snapshot ===========
+
+    /// Get the dashboard snapshot, loading it from disk on first use.
+    pub fn dashboard_store(&self) -> Option<Arc<parking_lot::Mutex<DashboardStore>>> {
+        if let Some(store) = self.dashboard_store.read().clone() {
+            return Some(store);
+        }
+        let config_dir = myme_core::Config::load_cached().config_dir.clone();
+        let mut store = DashboardStore::new(&config_dir);
+        if let Err(e) = store.load() {
+            tracing::warn!("Failed to read dashboard snapshot, starting empty: {}", e);
+        }
+        let store = Arc::new(parking_lot::Mutex::new(store));
+        *self.dashboard_store.write() = Some(store.clone());
+        Some(store)
+    }
+
+    // Service channel methods (repo, note, weather, auth, project, workflow, kanban, gmail, calendar, capture, contacts, feed, bookmark, notification, gist, import, discover, insights, release, runner, search, settings, theme, undo, ipc, label, clipboard)
     service_channel_methods!(
         repo: RepoServiceMessage,
         note: NoteServiceMessage,
@@ -520,8 +1084,306 @@ impl AppServices {
         kanban: KanbanServiceMessage,
         gmail: GmailServiceMessage,
         calendar: CalendarServiceMessage,
+        capture: CaptureServiceMessage,
+        contacts: ContactsServiceMessage,
+        feed: FeedServiceMessage,
+        bookmark: BookmarkServiceMessage,
+        notification: NotificationServiceMessage,
+        gist: GistServiceMessage,
+        import: ImportServiceMessage,
+        discover: DiscoverServiceMessage,
+        insights: InsightsServiceMessage,
+        release: ReleaseServiceMessage,
+        runner: RunnerServiceMessage,
+        search: SearchServiceMessage,
+        settings: SettingsServiceMessage,
+        theme: ThemeServiceMessage,
+        undo: UndoServiceMessage,
+        ipc: IpcServiceMessage,
+        label: LabelServiceMessage,
+        clipboard: ClipboardServiceMessage,
     );
 
+    // =========== Connectivity ===========
+
+    /// Start the connectivity monitor and replay offline work on reconnect.
+    ///
+    /// Returns `true` if the monitor is running (including if already started).
+    pub fn init_connectivity_monitor(&self) -> bool {
+        let mut task = self.connectivity_task.write();
+        if task.is_some() {
+            return true;
+        }
+
+        let monitor = connectivity::global();
+        let handle = self.runtime();
+        *task = Some(connectivity::spawn_monitor(
+            &handle,
+            monitor,
+            connectivity::MonitorConfig {
+                proxies: myme_core::Config::load_cached().proxy.proxies_for("connectivity"),
+                ..Default::default()
+            },
+            self.subscribe_shutdown(),
+        ));
+
+        let mut changes = monitor.subscribe();
+        let mut shutdown = self.subscribe_shutdown();
+        handle.spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = shutdown.recv() => break,
+                    change = changes.recv() => match change {
+                        Ok(true) => flush_offline_queues().await,
+                        Ok(false) => {}
+                        Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                }
+            }
+        });
+        true
+    }
+
+    // =========== Email Snooze ===========
+
+    /// Check for snoozed emails that are due once a minute, put them back in
+    /// the inbox and tell the Gmail model.
+    ///
+    /// Returns `true` if the scheduler is running (including if already started).
+    pub fn init_snooze_scheduler(&self) -> bool {
+        let mut task = self.snooze_task.write();
+        if task.is_some() {
+            return true;
+        }
+
+        let mut shutdown = self.subscribe_shutdown();
+        *task = Some(self.runtime().spawn(async move {
+            let mut interval = tokio::time::interval(SNOOZE_CHECK_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = shutdown.recv() => break,
+                    _ = interval.tick() => {
+                        let messages = resurface_gmail_snoozed().await;
+                        if messages.is_empty() {
+                            continue;
+                        }
+                        if let Some(tx) = services().gmail_service_tx() {
+                            let _ = tx.send(GmailServiceMessage::Resurfaced(messages));
+                        }
+                    }
+                }
+            }
+        }));
+        true
+    }
+
+    // =========== Calendar Reminders ===========
+
+    /// Raise desktop notifications for calendar reminders as they come due,
+    /// using the reminders Google would (see `notify_due_calendar_reminders`).
+    ///
+    /// Returns `true` if the scheduler is running (including if already started).
+    pub fn init_reminder_scheduler(&self) -> bool {
+        let mut task = self.reminder_task.write();
+        if task.is_some() {
+            return true;
+        }
+
+        let mut shutdown = self.subscribe_shutdown();
+        *task = Some(self.runtime().spawn(async move {
+            let mut interval = tokio::time::interval(REMINDER_CHECK_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = shutdown.recv() => break,
+                    _ = interval.tick() => {
+                        // SQLite reads; keep them off the async workers
+                        let _ = tokio::task::spawn_blocking(notify_due_calendar_reminders).await;
+                    }
+                }
+            }
+        }));
+        true
+    }
+
+    // =========== Travel Warnings ===========
+
+    /// Raise leave-by warnings for upcoming events with a physical location
+    /// (see `notify_travel_warnings`).
+    ///
+    /// Returns `true` if the scheduler is running (including if already started).
+    pub fn init_travel_scheduler(&self) -> bool {
+        let mut task = self.travel_task.write();
+        if task.is_some() {
+            return true;
+        }
+
+        let mut shutdown = self.subscribe_shutdown();
+        *task = Some(self.runtime().spawn(async move {
+            let mut interval = tokio::time::interval(TRAVEL_CHECK_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = shutdown.recv() => break,
+                    _ = interval.tick() => {
+                        notify_travel_warnings().await;
+                    }
+                }
+            }
+        }));
+        true
+    }
+
+    // =========== Scheduled Templates ===========
+
+    /// Create notes and issues from templates whose schedule is due, at start
+    /// and then every 15 minutes (so a day change is picked up while running).
+    ///
+    /// Returns `true` if the scheduler is running (including if already started).
+    pub fn init_template_scheduler(&self) -> bool {
+        let mut task = self.template_task.write();
+        if task.is_some() {
+            return true;
+        }
+
+        let mut shutdown = self.subscribe_shutdown();
+        *task = Some(self.runtime().spawn(async move {
+            let mut interval = tokio::time::interval(TEMPLATE_CHECK_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = shutdown.recv() => break,
+                    _ = interval.tick() => {
+                        let Some(store) = services().project_store() else {
+                            continue;
+                        };
+                        let today = chrono::Local::now().date_naive();
+                        instantiate_due_templates(
+                            store,
+                            services().note_client(),
+                            services().github_client(),
+                            today,
+                        )
+                        .await;
+                    }
+                }
+            }
+        }));
+        true
+    }
+
+    // =========== Feed Refresh ===========
+
+    /// Refresh feed subscriptions at start and then every
+    /// `feeds.refresh_minutes` (0 disables it), telling the feed model when
+    /// new items arrive.
+    ///
+    /// Returns `true` if the scheduler is running (including if already started).
+    pub fn init_feed_refresh(&self) -> bool {
+        let mut task = self.feed_task.write();
+        if task.is_some() {
+            return true;
+        }
+
+        let minutes = myme_core::Config::load_cached().feeds.refresh_minutes;
+        if minutes == 0 {
+            return false;
+        }
+        let period = std::time::Duration::from_secs(u64::from(minutes) * 60);
+
+        let mut shutdown = self.subscribe_shutdown();
+        *task = Some(self.runtime().spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            loop {
+                tokio::select! {
+                    _ = shutdown.recv() => break,
+                    _ = interval.tick() => {
+                        if !connectivity::is_online() {
+                            continue;
+                        }
+                        let result = refresh_all_feeds().await;
+                        if let Some(tx) = services().feed_service_tx() {
+                            let _ = tx.send(FeedServiceMessage::RefreshDone(result));
+                        }
+                    }
+                }
+            }
+        }));
+        true
+    }
+
+    /// Keep project boards, workflows and repos in step with GitHub: webhook
+    /// deliveries from `github.webhook_relay_url` when set, with polling every
+    /// `projects.sync_interval_minutes` whenever the relay is unavailable.
+    ///
+    /// Returns `true` if the sync is running (including if already started).
+    pub fn init_github_sync(&self) -> bool {
+        let mut task = self.github_sync_task.write();
+        if task.is_some() {
+            return true;
+        }
+        *task = Some(self.runtime().spawn(run_github_sync(self.subscribe_shutdown())));
+        true
+    }
+
+    /// Reload the config when `config.toml` or the host file is edited outside
+    /// the app, so `Config::load_cached` and subscribers see the change.
+    ///
+    /// Returns `true` if the watcher is running (including if already started).
+    pub fn init_config_watcher(&self) -> bool {
+        let mut task = self.config_watch_task.write();
+        if task.is_some() {
+            return true;
+        }
+
+        let mut shutdown = self.subscribe_shutdown();
+        *task = Some(self.runtime().spawn(async move {
+            tokio::select! {
+                _ = shutdown.recv() => {}
+                _ = myme_core::Config::watch_file(std::time::Duration::from_secs(2)) => {}
+            }
+        }));
+        true
+    }
+
+    // =========== Remote Control ===========
+
+    /// Listen on the remote-control socket and answer commands from scripts.
+    ///
+    /// Returns `true` if the server is running (including if already started).
+    #[cfg(unix)]
+    pub fn init_ipc_server(&self) -> bool {
+        let mut socket = self.ipc_socket.write();
+        if socket.is_some() {
+            return true;
+        }
+
+        // The dispatcher forwards window commands to QML through this channel
+        self.init_ipc_service_channel();
+        let path = myme_core::ipc::default_socket_path();
+        let handle = self.runtime();
+        let mut requests =
+            match myme_core::ipc::spawn_server(&handle, &path, self.subscribe_shutdown()) {
+                Ok(requests) => requests,
+                Err(e) => {
+                    tracing::warn!("Remote control unavailable: {}", e);
+                    return false;
+                }
+            };
+        handle.spawn(async move {
+            while let Some(request) = requests.recv().await {
+                let response = crate::services::handle_ipc_command(request.command).await;
+                let _ = request.reply.send(response);
+            }
+        });
+        *socket = Some(path);
+        true
+    }
+
+    /// Remote control needs Unix sockets; not available on this platform.
+    #[cfg(not(unix))]
+    pub fn init_ipc_server(&self) -> bool {
+        false
+    }
+
     // =========== Repo Operation Cancellation ===========
 
     /// Create a new cancellation token for a repo operation.
@@ -552,6 +1414,39 @@ impl AppServices {
     }
 }
 
+/// Replay work queued while offline (currently Gmail actions).
+pub(crate) async fn flush_offline_queues() {
+    // Token refresh blocks on its own runtime, so keep it off the async workers
+    let token = tokio::task::spawn_blocking(get_google_access_token).await.ok().flatten();
+    let Some(token) = token else {
+        return;
+    };
+    if let Err(e) = flush_gmail_sync_queue(&token).await {
+        tracing::warn!("Failed to flush Gmail offline queue: {}", e);
+    }
+}
+
+/// Unlock (or first encrypt) the notes store with the passphrase saved in the
+/// keyring. Without a saved passphrase the store is left for the user to unlock.
+fn unlock_note_store(store: &mut SqliteNoteStore) {
+    let passphrase =
+        match myme_auth::SecureStorage::retrieve_secret(crate::services::NOTE_PASSPHRASE_SECRET) {
+            Ok(p) => p,
+            Err(_) => {
+                tracing::info!("Notes passphrase not saved; waiting for the user to unlock");
+                return;
+            }
+        };
+    let result = if store.is_encrypted() {
+        store.unlock(&passphrase)
+    } else {
+        store.enable_encryption(&passphrase)
+    };
+    if let Err(e) = result {
+        tracing::warn!("Failed to unlock notes with the saved passphrase: {:#}", e);
+    }
+}
+
 // =========== Convenience Functions ===========
 // These provide a simpler API for common operations
 
@@ -591,23 +1486,79 @@ pub fn github_auth_and_runtime() -> Option<(Arc<GitHubAuth>, tokio::runtime::Han
 }
 
 /// Get project store.
-pub fn project_store() -> Option<Arc<parking_lot::Mutex<ProjectStore>>> {
+pub fn project_store() -> Option<Arc<ProjectStore>> {
     services().project_store()
 }
 
 /// Get project store, initializing if needed.
-pub fn project_store_or_init() -> Option<Arc<parking_lot::Mutex<ProjectStore>>> {
+pub fn project_store_or_init() -> Option<Arc<ProjectStore>> {
     let svc = services();
     svc.init_project_store();
     svc.project_store()
 }
 
+/// Get the activity log, opening it if needed.
+pub fn activity_log_or_init() -> Option<Arc<ActivityLog>> {
+    let svc = services();
+    svc.init_activity_log();
+    svc.activity_log()
+}
+
+/// Get the bookmark store, opening it if needed.
+pub fn bookmark_store_or_init() -> Option<Arc<BookmarkStore>> {
+    let svc = services();
+    svc.init_bookmark_store();
+    svc.bookmark_store()
+}
+
+/// Get the repo state cache, opening it if needed.
+pub fn repo_state_store_or_init() -> Option<Arc<RepoStateStore>> {
+    let svc = services();
+    svc.init_repo_state_store();
+    svc.repo_state_store()
+}
+
+/// Get the clipboard history, opening it if needed.
+pub fn clipboard_history_or_init() -> Option<Arc<ClipboardHistory>> {
+    let svc = services();
+    svc.init_clipboard_history();
+    svc.clipboard_history()
+}
+
 /// Get weather services.
 pub fn weather_services() -> Option<(Arc<WeatherProvider>, WeatherCache, tokio::runtime::Handle)> {
     let svc = services();
     Some((svc.weather_provider()?, svc.weather_cache()?, svc.runtime()))
 }
 
+/// Build a weather provider for `unit` with the configured sources, proxies,
+/// timeouts, retries, HTTP debug capture and metrics.
+pub fn weather_provider_from_config(
+    unit: myme_weather::TemperatureUnit,
+) -> Result<WeatherProvider, myme_weather::WeatherError> {
+    let config = myme_core::Config::load_cached();
+    let primary = match config.weather.provider {
+        myme_core::WeatherProviderKind::OpenMeteo => myme_weather::SourceKind::OpenMeteo,
+        myme_core::WeatherProviderKind::OpenWeatherMap => myme_weather::SourceKind::OpenWeatherMap,
+    };
+    let network = config.network.for_service("weather");
+    let proxies = config.proxy.proxies_for("weather");
+    Ok(WeatherProvider::new_with_timeout(unit, proxies, network.timeout)?
+        .with_retries(network.max_retries, network.backoff_base, network.max_backoff)
+        .with_observer(myme_core::metrics::http_observer("weather"))
+        .with_source_kind(primary, config.weather.openweathermap_key(), config.weather.fallback))
+}
+
+/// Get session store (loaded on first access).
+pub fn session_store() -> Option<Arc<parking_lot::Mutex<SessionStore>>> {
+    services().session_store()
+}
+
+/// Get dashboard snapshot store (loaded on first access).
+pub fn dashboard_store() -> Option<Arc<parking_lot::Mutex<DashboardStore>>> {
+    services().dashboard_store()
+}
+
 /// Check if GitHub is authenticated.
 pub fn is_github_authenticated() -> bool {
     services().is_github_authenticated()
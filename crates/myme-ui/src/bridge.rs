@@ -6,7 +6,9 @@
 use std::sync::Arc;
 
 use myme_auth::GitHubAuth;
-use myme_services::{GitHubClient, NoteClient, ProjectStore};
+use myme_services::{
+    ActivityLog, BookmarkStore, ClipboardHistory, GitHubClient, NoteClient, ProjectStore,
+};
 use myme_weather::{WeatherCache, WeatherProvider};
 
 use crate::app_services::{self, AppServices};
@@ -35,17 +37,40 @@ macro_rules! service_channel_bridge {
 
 // =========== C FFI Initialization Functions ===========
 
-/// Initialize note client from configuration (SQLite).
-/// Must be called before QML tries to access NoteModel.
-#[no_mangle]
-pub extern "C" fn initialize_note_client() -> bool {
-    // Initialize tracing if not already done
+/// Initialize tracing if not already done.
+fn init_tracing() {
     let _ = tracing_subscriber::fmt()
         .with_env_filter(
             tracing_subscriber::EnvFilter::try_from_default_env()
                 .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
         )
         .try_init();
+}
+
+/// Start every service in dependency order on a background task.
+/// Returns immediately; `StartupModel` reports progress and failures.
+#[no_mangle]
+pub extern "C" fn start_app_services() -> bool {
+    crate::startup::mark_launch();
+    init_tracing();
+    // Config secrets resolve through the keyring, so install it before the first load
+    myme_core::config_secrets::install(Box::new(myme_auth::SecureStorage));
+    // Fix the message language before anything is translated
+    let language = myme_core::Config::load_cached().ui.language.clone();
+    tracing::info!("Messages in {}", myme_core::i18n::init(language.as_deref()));
+    // Install the crash guard before anything else can panic
+    AppServices::init().init_app();
+    // Read the dashboard snapshot now so the first frame has something to show
+    app_services::dashboard_store();
+    crate::startup::mark_phase("app_core");
+    crate::startup::start()
+}
+
+/// Initialize note client from configuration (SQLite).
+/// Must be called before QML tries to access NoteModel.
+#[no_mangle]
+pub extern "C" fn initialize_note_client() -> bool {
+    init_tracing();
 
     tracing::info!("Initializing unified note client from configuration");
 
@@ -59,6 +84,28 @@ pub extern "C" fn initialize_note_client() -> bool {
     success
 }
 
+/// Start persisting the "Recent problems" error log under the config directory.
+#[no_mangle]
+pub extern "C" fn initialize_error_log() -> bool {
+    let config_dir = myme_core::Config::load_cached().config_dir.clone();
+    myme_core::error_log::global().init_persistence(&config_dir.join("logs").join("errors.jsonl"));
+    true
+}
+
+/// Start the connectivity monitor (online/offline probing and reconnect sync).
+#[no_mangle]
+pub extern "C" fn initialize_connectivity_monitor() -> bool {
+    let services = AppServices::init();
+    services.init_connectivity_monitor()
+}
+
+/// Start the remote-control socket (create_note, show_window, next_event, sync_now).
+#[no_mangle]
+pub extern "C" fn initialize_ipc_server() -> bool {
+    let services = AppServices::init();
+    services.init_ipc_server()
+}
+
 /// Get the unified note client and runtime for use by NoteModels.
 pub fn get_note_client_and_runtime() -> Option<(Arc<NoteClient>, tokio::runtime::Handle)> {
     app_services::note_client_and_runtime()
@@ -83,6 +130,13 @@ pub fn get_weather_services() -> Option<(Arc<WeatherProvider>, WeatherCache, tok
     app_services::weather_services()
 }
 
+/// Build a weather provider from config (used when the unit changes)
+pub fn build_weather_provider(
+    unit: myme_weather::TemperatureUnit,
+) -> Result<WeatherProvider, myme_weather::WeatherError> {
+    app_services::weather_provider_from_config(unit)
+}
+
 /// Initialize GitHub client and project store
 /// Must be called before QML tries to access ProjectModel
 #[no_mangle]
@@ -110,10 +164,20 @@ pub fn get_github_client_and_runtime() -> Option<(Arc<GitHubClient>, tokio::runt
 }
 
 /// Get project store if initialized.
-pub fn get_project_store() -> Option<Arc<parking_lot::Mutex<ProjectStore>>> {
+pub fn get_project_store() -> Option<Arc<ProjectStore>> {
     app_services::project_store()
 }
 
+/// Get the workspace session store (last project, filters, scroll positions).
+pub fn get_session_store() -> Option<Arc<parking_lot::Mutex<myme_core::SessionStore>>> {
+    app_services::session_store()
+}
+
+/// Get the dashboard snapshot store (last weather, events, notes, commits).
+pub fn get_dashboard_store() -> Option<Arc<parking_lot::Mutex<myme_core::DashboardStore>>> {
+    app_services::dashboard_store()
+}
+
 /// Check if GitHub is authenticated
 pub fn is_github_authenticated() -> bool {
     app_services::is_github_authenticated()
@@ -125,10 +189,40 @@ pub fn get_runtime() -> Option<tokio::runtime::Handle> {
 }
 
 /// Get project store, initializing if needed.
-pub fn get_project_store_or_init() -> Option<Arc<parking_lot::Mutex<ProjectStore>>> {
+pub fn get_project_store_or_init() -> Option<Arc<ProjectStore>> {
     app_services::project_store_or_init()
 }
 
+/// Crash or unclean-exit details from the previous run.
+pub fn get_recovery_info() -> Option<myme_core::RecoveryInfo> {
+    AppServices::init().recovery_info()
+}
+
+/// Don't offer the previous run's recovery info again.
+pub fn dismiss_recovery() {
+    AppServices::init().dismiss_recovery();
+}
+
+/// Get the activity log, opening it if needed.
+pub fn get_activity_log_or_init() -> Option<Arc<ActivityLog>> {
+    app_services::activity_log_or_init()
+}
+
+/// Get the bookmark store, opening it if needed.
+pub fn get_bookmark_store_or_init() -> Option<Arc<BookmarkStore>> {
+    app_services::bookmark_store_or_init()
+}
+
+/// Get the repo state cache, opening it if needed.
+pub fn get_repo_state_store_or_init() -> Option<Arc<myme_integrations::RepoStateStore>> {
+    app_services::repo_state_store_or_init()
+}
+
+/// Get the clipboard history, opening it if needed.
+pub fn get_clipboard_history_or_init() -> Option<Arc<ClipboardHistory>> {
+    app_services::clipboard_history_or_init()
+}
+
 /// Initialize GitHub OAuth provider
 /// Must be called before QML tries to use AuthModel
 #[no_mangle]
@@ -159,6 +253,24 @@ service_channel_bridge!(
     kanban: crate::services::KanbanServiceMessage,
     gmail: crate::services::GmailServiceMessage,
     calendar: crate::services::CalendarServiceMessage,
+    capture: crate::services::CaptureServiceMessage,
+    contacts: crate::services::ContactsServiceMessage,
+    feed: crate::services::FeedServiceMessage,
+    bookmark: crate::services::BookmarkServiceMessage,
+    notification: crate::services::NotificationServiceMessage,
+    gist: crate::services::GistServiceMessage,
+    import: crate::services::ImportServiceMessage,
+    discover: crate::services::DiscoverServiceMessage,
+    insights: crate::services::InsightsServiceMessage,
+    release: crate::services::ReleaseServiceMessage,
+    runner: crate::services::RunnerServiceMessage,
+    search: crate::services::SearchServiceMessage,
+    settings: crate::services::SettingsServiceMessage,
+    theme: crate::services::ThemeServiceMessage,
+    undo: crate::services::UndoServiceMessage,
+    ipc: crate::services::IpcServiceMessage,
+    label: crate::services::LabelServiceMessage,
+    clipboard: crate::services::ClipboardServiceMessage,
 );
 
 /// Reinitialize GitHub client after successful OAuth
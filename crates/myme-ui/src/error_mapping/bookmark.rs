@@ -0,0 +1,17 @@
+use crate::services::bookmark_service::BookmarkError;
+use myme_core::{AppError, NetworkError};
+
+impl From<BookmarkError> for AppError {
+    fn from(e: BookmarkError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            BookmarkError::Network(s) => AppError::Network(NetworkError::ConnectionFailed(s)),
+            BookmarkError::InvalidUrl(_) => AppError::Service(detail.clone()),
+            BookmarkError::Store(s) => AppError::Service(s),
+            BookmarkError::NotInitialized => {
+                AppError::Service("Bookmark service not initialized".into())
+            }
+        };
+        super::logged("bookmarks", &detail, err)
+    }
+}
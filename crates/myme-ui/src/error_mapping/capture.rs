@@ -0,0 +1,18 @@
+use crate::services::capture_service::CaptureError;
+use myme_core::{AppError, NetworkError};
+
+impl From<CaptureError> for AppError {
+    fn from(e: CaptureError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            CaptureError::Network(s) => AppError::Network(NetworkError::ConnectionFailed(s)),
+            CaptureError::Unavailable(service) => {
+                AppError::Service(format!("{} is not connected", service))
+            }
+            CaptureError::NotInitialized => {
+                AppError::Service("Capture service not initialized".into())
+            }
+        };
+        super::logged("capture", &detail, err)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::services::clipboard_service::ClipboardError;
+use myme_core::AppError;
+
+impl From<ClipboardError> for AppError {
+    fn from(e: ClipboardError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            ClipboardError::Keyring(_) => AppError::Service(
+                "Clipboard history needs the system keyring to store its key".into(),
+            ),
+            ClipboardError::Store(s) => AppError::Service(s),
+            ClipboardError::NotInitialized => {
+                AppError::Service("Clipboard history not initialized".into())
+            }
+        };
+        super::logged("clipboard", &detail, err)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::services::contacts_service::ContactsError;
+use myme_core::AppError;
+
+impl From<ContactsError> for AppError {
+    fn from(e: ContactsError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            ContactsError::NotInitialized => {
+                AppError::Service("Contacts service not initialized".into())
+            }
+        };
+        super::logged("contacts", &detail, err)
+    }
+}
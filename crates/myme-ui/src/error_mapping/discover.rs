@@ -0,0 +1,18 @@
+use crate::services::discover_service::DiscoverError;
+use myme_core::{AppError, GitHubError};
+
+impl From<DiscoverError> for AppError {
+    fn from(e: DiscoverError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            DiscoverError::Network(s) => AppError::GitHub(GitHubError::message(s)),
+            DiscoverError::InvalidRepo(s) => {
+                AppError::Service(format!("\"{}\" is not an owner/repo name", s))
+            }
+            DiscoverError::NotInitialized => {
+                AppError::Service("Discover service not initialized".into())
+            }
+        };
+        super::logged("discover", &detail, err)
+    }
+}
@@ -0,0 +1,14 @@
+use crate::services::feed_service::FeedError;
+use myme_core::{AppError, NetworkError};
+
+impl From<FeedError> for AppError {
+    fn from(e: FeedError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            FeedError::Network(s) => AppError::Network(NetworkError::ConnectionFailed(s)),
+            FeedError::InvalidFeed(s) | FeedError::Cache(s) => AppError::Service(s),
+            FeedError::NotInitialized => AppError::Service("Feed service not initialized".into()),
+        };
+        super::logged("feeds", &detail, err)
+    }
+}
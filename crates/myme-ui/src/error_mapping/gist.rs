@@ -0,0 +1,13 @@
+use crate::services::gist_service::GistError;
+use myme_core::{AppError, GitHubError};
+
+impl From<GistError> for AppError {
+    fn from(e: GistError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            GistError::Network(s) => AppError::GitHub(GitHubError::message(s)),
+            GistError::NotInitialized => AppError::Service("Gist service not initialized".into()),
+        };
+        super::logged("gists", &detail, err)
+    }
+}
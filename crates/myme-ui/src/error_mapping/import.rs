@@ -0,0 +1,16 @@
+use crate::services::import_service::ImportError;
+use myme_core::AppError;
+
+impl From<ImportError> for AppError {
+    fn from(e: ImportError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            ImportError::Parse(s) => AppError::Service(format!("Couldn't read the export: {}", s)),
+            ImportError::Store(s) => AppError::Service(format!("Import failed: {}", s)),
+            ImportError::NotInitialized => {
+                AppError::Service("Import service not initialized".into())
+            }
+        };
+        super::logged("import", &detail, err)
+    }
+}
@@ -0,0 +1,18 @@
+use crate::services::insights_service::InsightsError;
+use myme_core::{AppError, GitHubError};
+
+impl From<InsightsError> for AppError {
+    fn from(e: InsightsError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            InsightsError::Network(s) => AppError::GitHub(GitHubError::message(s)),
+            InsightsError::InvalidRepo(s) => {
+                AppError::Service(format!("\"{}\" is not an owner/repo name", s))
+            }
+            InsightsError::NotInitialized => {
+                AppError::Service("Insights service not initialized".into())
+            }
+        };
+        super::logged("insights", &detail, err)
+    }
+}
@@ -3,11 +3,13 @@ use myme_core::{AppError, GitHubError};
 
 impl From<KanbanError> for AppError {
     fn from(e: KanbanError) -> Self {
-        match e {
+        let detail = e.to_string();
+        let err = match e {
             KanbanError::Network(s) => AppError::GitHub(GitHubError::message(s)),
             KanbanError::NotInitialized => {
                 AppError::Service("Kanban service not initialized".into())
             }
-        }
+        };
+        super::logged("kanban", &detail, err)
     }
 }
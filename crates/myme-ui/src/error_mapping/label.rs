@@ -0,0 +1,14 @@
+use crate::services::label_service::LabelError;
+use myme_core::{AppError, DatabaseError};
+
+impl From<LabelError> for AppError {
+    fn from(e: LabelError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            LabelError::Store(s) => AppError::Database(DatabaseError::QueryFailed(s)),
+            LabelError::Invalid(s) => AppError::Service(s),
+            LabelError::NotInitialized => AppError::Service("Label service not initialized".into()),
+        };
+        super::logged("labels", &detail, err)
+    }
+}
@@ -1,12 +1,38 @@
 //! Maps UI service errors to myme_core::AppError for consistent user-facing messages.
-//! Each service has its own module to keep mappings small and readable.
+//! Each service has its own module to keep mappings small and readable. The
+//! messages come from the `myme_core::i18n` catalogs, so they follow the
+//! configured language; the logged detail stays as the service reported it.
 
 mod auth;
+mod bookmark;
 mod calendar;
+mod capture;
+mod clipboard;
+mod contacts;
+mod discover;
+mod feed;
+mod gist;
 mod gmail;
+mod import;
+mod insights;
 mod kanban;
+mod label;
 mod note;
+mod notification;
 mod project;
+mod release;
 mod repo;
+mod runner;
+mod search;
+mod settings;
+mod undo;
 mod weather;
 mod workflow;
+
+use myme_core::AppError;
+
+/// Record a mapped error in the "Recent problems" log and pass it through.
+fn logged(service: &str, detail: &str, err: AppError) -> AppError {
+    myme_core::error_log::record(service, err.user_message(), detail);
+    err
+}
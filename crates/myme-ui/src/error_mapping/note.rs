@@ -1,12 +1,16 @@
 use crate::services::note_service::NoteError;
-use myme_core::{AppError, DatabaseError};
+use myme_core::{AppError, ConfigError, DatabaseError};
 
 impl From<NoteError> for AppError {
     fn from(e: NoteError) -> Self {
-        match e {
+        let detail = e.to_string();
+        let err = match e {
             NoteError::Network(s) => AppError::Database(DatabaseError::QueryFailed(s)),
             NoteError::NotInitialized => AppError::Service("Note service not initialized".into()),
             NoteError::InvalidIndex => AppError::Service("Invalid note index".into()),
-        }
+            NoteError::Config(s) => AppError::Config(ConfigError::Invalid(s)),
+            NoteError::Export(_) => AppError::Service("Failed to export notes".into()),
+        };
+        super::logged("notes", &detail, err)
     }
 }
@@ -0,0 +1,15 @@
+use crate::services::notification_service::NotificationError;
+use myme_core::{AppError, GitHubError};
+
+impl From<NotificationError> for AppError {
+    fn from(e: NotificationError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            NotificationError::Network(s) => AppError::GitHub(GitHubError::message(s)),
+            NotificationError::NotInitialized => {
+                AppError::Service("Notification service not initialized".into())
+            }
+        };
+        super::logged("notifications", &detail, err)
+    }
+}
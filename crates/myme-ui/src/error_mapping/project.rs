@@ -3,11 +3,13 @@ use myme_core::{AppError, GitHubError};
 
 impl From<ProjectError> for AppError {
     fn from(e: ProjectError) -> Self {
-        match e {
+        let detail = e.to_string();
+        let err = match e {
             ProjectError::Network(s) => AppError::GitHub(GitHubError::message(s)),
             ProjectError::NotInitialized => {
                 AppError::Service("Project service not initialized".into())
             }
-        }
+        };
+        super::logged("project", &detail, err)
     }
 }
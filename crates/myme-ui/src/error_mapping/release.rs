@@ -0,0 +1,18 @@
+use crate::services::release_service::ReleaseError;
+use myme_core::{AppError, GitHubError};
+
+impl From<ReleaseError> for AppError {
+    fn from(e: ReleaseError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            ReleaseError::Network(s) => AppError::GitHub(GitHubError::message(s)),
+            ReleaseError::InvalidRepo(s) => {
+                AppError::Service(format!("\"{}\" is not an owner/repo name", s))
+            }
+            ReleaseError::NotInitialized => {
+                AppError::Service("Release service not initialized".into())
+            }
+        };
+        super::logged("releases", &detail, err)
+    }
+}
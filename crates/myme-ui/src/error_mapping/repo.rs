@@ -4,12 +4,19 @@ use std::io;
 
 impl From<RepoError> for AppError {
     fn from(e: RepoError) -> Self {
-        match e {
+        let detail = e.to_string();
+        let cancelled = matches!(e, RepoError::Cancelled);
+        let err = match e {
             RepoError::Git(s) => AppError::Service(format!("Git operation failed: {}", s)),
             RepoError::GitHub(s) => AppError::GitHub(GitHubError::message(s)),
             RepoError::Io(s) => AppError::Io(io::Error::new(io::ErrorKind::Other, s)),
             RepoError::Config(s) => AppError::Config(ConfigError::Invalid(s)),
             RepoError::Cancelled => AppError::Service("Operation cancelled".into()),
+        };
+        // Cancellation is a user action, not a problem worth listing
+        if cancelled {
+            return err;
         }
+        super::logged("repo", &detail, err)
     }
 }
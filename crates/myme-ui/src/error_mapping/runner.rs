@@ -0,0 +1,16 @@
+use crate::services::runner_service::RunnerError;
+use myme_core::AppError;
+
+impl From<RunnerError> for AppError {
+    fn from(e: RunnerError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            RunnerError::Spawn(s) => AppError::Service(format!("Couldn't start the check: {}", s)),
+            RunnerError::NotLocal(s) => AppError::Service(format!("No local clone at {}", s)),
+            RunnerError::NotInitialized => {
+                AppError::Service("Runner service not initialized".into())
+            }
+        };
+        super::logged("checks", &detail, err)
+    }
+}
@@ -0,0 +1,15 @@
+use crate::services::search_service::SearchError;
+use myme_core::AppError;
+
+impl From<SearchError> for AppError {
+    fn from(e: SearchError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            SearchError::Source(s) => AppError::Service(s),
+            SearchError::NotInitialized => {
+                AppError::Service("Search service not initialized".into())
+            }
+        };
+        super::logged("search", &detail, err)
+    }
+}
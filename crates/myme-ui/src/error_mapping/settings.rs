@@ -0,0 +1,14 @@
+use crate::services::settings_service::SettingsError;
+use myme_core::AppError;
+
+impl From<SettingsError> for AppError {
+    fn from(e: SettingsError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            SettingsError::NotInitialized => {
+                AppError::Service("Settings service not initialized".into())
+            }
+        };
+        super::logged("settings", &detail, err)
+    }
+}
@@ -0,0 +1,17 @@
+use crate::services::undo_service::UndoError;
+use myme_core::{AppError, NetworkError};
+
+impl From<UndoError> for AppError {
+    fn from(e: UndoError) -> Self {
+        let detail = e.to_string();
+        let err = match e {
+            UndoError::Failed(s) => AppError::Service(s),
+            UndoError::Offline => {
+                AppError::Network(NetworkError::ConnectionFailed("offline".into()))
+            }
+            UndoError::Empty => AppError::Service("Nothing to undo".into()),
+            UndoError::NotInitialized => AppError::Service("Undo not initialized".into()),
+        };
+        super::logged("undo", &detail, err)
+    }
+}
@@ -3,10 +3,12 @@ use myme_core::{AppError, WeatherError};
 
 impl From<UiWeatherError> for AppError {
     fn from(e: UiWeatherError) -> Self {
-        match e {
+        let detail = e.to_string();
+        let err = match e {
             UiWeatherError::Network(s) => AppError::Weather(WeatherError::ApiError(s)),
             UiWeatherError::Location(s) => AppError::Weather(WeatherError::LocationNotFound(s)),
             UiWeatherError::NotInitialized => AppError::Weather(WeatherError::ServiceUnavailable),
-        }
+        };
+        super::logged("weather", &detail, err)
     }
 }
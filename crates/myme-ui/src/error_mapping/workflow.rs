@@ -3,11 +3,13 @@ use myme_core::{AppError, GitHubError};
 
 impl From<WorkflowError> for AppError {
     fn from(e: WorkflowError) -> Self {
-        match e {
+        let detail = e.to_string();
+        let err = match e {
             WorkflowError::Network(s) => AppError::GitHub(GitHubError::message(s)),
             WorkflowError::NotInitialized => {
                 AppError::Service("Workflow service not initialized".into())
             }
-        }
+        };
+        super::logged("workflow", &detail, err)
     }
 }
@@ -3,6 +3,7 @@ pub mod bridge;
 pub mod error_mapping;
 pub mod models;
 pub mod services;
+pub mod startup;
 
 // Re-export cxx-qt generated types
 pub use models::auth_model::qobject::AuthModel;
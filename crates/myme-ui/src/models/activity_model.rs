@@ -0,0 +1,98 @@
+// crates/myme-ui/src/models/activity_model.rs
+
+use core::pin::Pin;
+
+use chrono::{DateTime, Duration, Local, Utc};
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::ActivityEntry;
+
+use crate::bridge;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, count)]
+        #[qproperty(QString, error_message)]
+        type ActivityModel = super::ActivityModelRust;
+
+        /// Reload today's entries from the activity log
+        #[qinvokable]
+        fn refresh(self: Pin<&mut ActivityModel>);
+
+        /// JSON array of today's entries, oldest first:
+        /// [{"id","time","service","action","summary","undoable"}]
+        #[qinvokable]
+        fn get_entries_today(self: &ActivityModel) -> QString;
+
+        #[qsignal]
+        fn entries_changed(self: Pin<&mut ActivityModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct ActivityModelRust {
+    count: i32,
+    error_message: QString,
+    entries: Vec<ActivityEntry>,
+}
+
+/// Start and end of the local calendar day containing `now`, in UTC.
+fn local_day_bounds(now: DateTime<Local>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start = now
+        .date_naive()
+        .and_hms_opt(0, 0, 0)
+        .and_then(|midnight| midnight.and_local_timezone(Local).earliest())
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|| now.with_timezone(&Utc) - Duration::hours(24));
+    (start, start + Duration::days(1))
+}
+
+impl qobject::ActivityModel {
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        let Some(log) = bridge::get_activity_log_or_init() else {
+            self.as_mut().set_error_message(QString::from("Activity log not available"));
+            return;
+        };
+        let (from, to) = local_day_bounds(Local::now());
+        match log.list_between(from, to) {
+            Ok(entries) => {
+                let count = entries.len() as i32;
+                self.as_mut().rust_mut().entries = entries;
+                self.as_mut().set_count(count);
+                self.as_mut().set_error_message(QString::default());
+                self.as_mut().entries_changed();
+            }
+            Err(e) => {
+                tracing::warn!("Failed to load activity log: {}", e);
+                self.as_mut().set_error_message(QString::from(&e.to_string()));
+            }
+        }
+    }
+
+    pub fn get_entries_today(&self) -> QString {
+        let entries: Vec<serde_json::Value> = self
+            .rust()
+            .entries
+            .iter()
+            .map(|e| {
+                serde_json::json!({
+                    "id": e.id,
+                    "time": e.at.with_timezone(&Local).format("%H:%M").to_string(),
+                    "service": e.service,
+                    "action": e.action,
+                    "summary": e.summary,
+                    "undoable": e.undo_hint.is_some(),
+                })
+            })
+            .collect();
+        QString::from(&serde_json::to_string(&entries).unwrap_or_else(|_| "[]".into()))
+    }
+}
@@ -0,0 +1,185 @@
+//! Project analytics for QML: cumulative flow, burndown, throughput and cycle
+//! time over the last days, from the task status history.
+
+use core::pin::Pin;
+
+use chrono::{Duration, Local, NaiveDate};
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::analytics::{self, DayCounts};
+use myme_services::TaskStatus;
+
+use crate::bridge;
+
+/// Window used when `load` is given no sensible number of days
+const DEFAULT_DAYS: i32 = 30;
+
+/// Longest window offered
+const MAX_DAYS: i32 = 365;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(QString, project_id)]
+        #[qproperty(i32, days)]
+        #[qproperty(QString, error_message)]
+        #[qproperty(i32, completed)]
+        #[qproperty(f64, median_cycle_hours)]
+        #[qproperty(f64, average_cycle_hours)]
+        type AnalyticsModel = super::AnalyticsModelRust;
+
+        /// Compute analytics for a project over the last `days` days (today
+        /// included); emits `analytics_changed`
+        #[qinvokable]
+        fn load(self: Pin<&mut AnalyticsModel>, project_id: &QString, days: i32);
+
+        /// Tasks per status at the end of each day, as a JSON array:
+        /// [{"date","backlog","todo","inprogress","blocked","review","done"}]
+        #[qinvokable]
+        fn get_cumulative_flow(self: &AnalyticsModel) -> QString;
+
+        /// Open and done tasks at the end of each day: [{"date","remaining","done"}]
+        #[qinvokable]
+        fn get_burndown(self: &AnalyticsModel) -> QString;
+
+        /// Tasks finished each day: [{"date","count"}]
+        #[qinvokable]
+        fn get_throughput(self: &AnalyticsModel) -> QString;
+
+        #[qsignal]
+        fn analytics_changed(self: Pin<&mut AnalyticsModel>);
+    }
+}
+
+pub struct AnalyticsModelRust {
+    project_id: QString,
+    days: i32,
+    error_message: QString,
+    /// Tasks finished in the window
+    completed: i32,
+    /// Cycle times in hours of tasks finished in the window; -1 if none
+    median_cycle_hours: f64,
+    average_cycle_hours: f64,
+    flow: Vec<DayCounts>,
+    throughput: Vec<(NaiveDate, u32)>,
+}
+
+impl Default for AnalyticsModelRust {
+    fn default() -> Self {
+        Self {
+            project_id: QString::default(),
+            days: DEFAULT_DAYS,
+            error_message: QString::default(),
+            completed: 0,
+            median_cycle_hours: -1.0,
+            average_cycle_hours: -1.0,
+            flow: Vec::new(),
+            throughput: Vec::new(),
+        }
+    }
+}
+
+fn hours(duration: Duration) -> f64 {
+    duration.num_minutes() as f64 / 60.0
+}
+
+fn json_string(values: Vec<serde_json::Value>) -> QString {
+    QString::from(&serde_json::to_string(&values).unwrap_or_else(|_| "[]".into()))
+}
+
+impl qobject::AnalyticsModel {
+    pub fn load(mut self: Pin<&mut Self>, project_id: &QString, days: i32) {
+        let days = if days > 0 { days.min(MAX_DAYS) } else { DEFAULT_DAYS };
+        self.as_mut().set_project_id(project_id.clone());
+        self.as_mut().set_days(days);
+
+        let Some(store) = bridge::get_project_store_or_init() else {
+            self.as_mut().set_error_message(QString::from("Project store not initialized"));
+            return;
+        };
+        let history = match store.list_status_history(&project_id.to_string()) {
+            Ok(history) => history,
+            Err(e) => {
+                tracing::warn!("Failed to load task history: {}", e);
+                self.as_mut().set_error_message(QString::from(&e.to_string()));
+                return;
+            }
+        };
+
+        let last = Local::now().date_naive();
+        let first = last - Duration::days(i64::from(days - 1));
+        let flow = analytics::cumulative_flow(&history, first, last, &Local);
+        let throughput = analytics::throughput(&history, first, last, &Local);
+        let cycles: Vec<Duration> = analytics::cycle_times(&history)
+            .iter()
+            .filter(|c| c.finished.with_timezone(&Local).date_naive() >= first)
+            .map(|c| c.duration())
+            .collect();
+        let median = analytics::median(&cycles).map_or(-1.0, hours);
+        let average = if cycles.is_empty() {
+            -1.0
+        } else {
+            cycles.iter().copied().map(hours).sum::<f64>() / cycles.len() as f64
+        };
+        let completed = throughput.iter().map(|(_, count)| count).sum::<u32>();
+
+        self.as_mut().rust_mut().flow = flow;
+        self.as_mut().rust_mut().throughput = throughput;
+        self.as_mut().set_completed(completed as i32);
+        self.as_mut().set_median_cycle_hours(median);
+        self.as_mut().set_average_cycle_hours(average);
+        self.as_mut().set_error_message(QString::default());
+        self.as_mut().analytics_changed();
+    }
+
+    pub fn get_cumulative_flow(&self) -> QString {
+        json_string(
+            self.rust()
+                .flow
+                .iter()
+                .map(|day| {
+                    let mut value = serde_json::json!({ "date": day.date.to_string() });
+                    for status in TaskStatus::all() {
+                        value[status.key()] = day.count(*status).into();
+                    }
+                    value
+                })
+                .collect(),
+        )
+    }
+
+    pub fn get_burndown(&self) -> QString {
+        json_string(
+            self.rust()
+                .flow
+                .iter()
+                .map(|day| {
+                    serde_json::json!({
+                        "date": day.date.to_string(),
+                        "remaining": day.remaining(),
+                        "done": day.count(TaskStatus::Done),
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    pub fn get_throughput(&self) -> QString {
+        json_string(
+            self.rust()
+                .throughput
+                .iter()
+                .map(
+                    |(date, count)| serde_json::json!({ "date": date.to_string(), "count": count }),
+                )
+                .collect(),
+        )
+    }
+}
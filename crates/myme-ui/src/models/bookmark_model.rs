@@ -0,0 +1,261 @@
+//! Read-it-later bookmarks model for QML.
+//!
+//! Bookmarks are saved to the local store right away; page titles and
+//! excerpts are fetched in the background and report back through the channel.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::bookmark::normalize_url;
+use myme_services::{Bookmark, BookmarkFilter, BookmarkStore};
+
+use crate::bridge;
+use crate::services::{request_bookmark_metadata, BookmarkError, BookmarkServiceMessage};
+
+/// Most bookmarks returned by one `get_bookmarks` call
+const LIST_LIMIT: usize = 500;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(i32, count)]
+        #[qproperty(i32, archived_count)]
+        #[qproperty(QString, error_message)]
+        type BookmarkModel = super::BookmarkModelRust;
+
+        /// Reload counts from the store
+        #[qinvokable]
+        fn reload(self: Pin<&mut BookmarkModel>);
+
+        /// Save a URL with space-separated tags and fetch its title in the background
+        #[qinvokable]
+        fn add_url(self: Pin<&mut BookmarkModel>, url: &QString, tags: &QString) -> bool;
+
+        /// Bookmarks newest first as a JSON array. An empty `tag` or `query` matches all.
+        #[qinvokable]
+        fn get_bookmarks(
+            self: &BookmarkModel,
+            tag: &QString,
+            query: &QString,
+            archived: bool,
+        ) -> QString;
+
+        /// Tags in use as a JSON array of {"tag", "count"}
+        #[qinvokable]
+        fn get_tags(self: &BookmarkModel) -> QString;
+
+        /// Replace a bookmark's tags with space-separated `tags`
+        #[qinvokable]
+        fn set_tags(self: Pin<&mut BookmarkModel>, id: i64, tags: &QString);
+
+        #[qinvokable]
+        fn set_archived(self: Pin<&mut BookmarkModel>, id: i64, archived: bool);
+
+        #[qinvokable]
+        fn delete_bookmark(self: Pin<&mut BookmarkModel>, id: i64);
+
+        /// Fetch the page title and excerpt again
+        #[qinvokable]
+        fn refetch(self: Pin<&mut BookmarkModel>, id: i64);
+
+        /// Poll for async operation results. Call this from a QML Timer.
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut BookmarkModel>);
+
+        #[qsignal]
+        fn bookmarks_changed(self: Pin<&mut BookmarkModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct BookmarkModelRust {
+    loading: bool,
+    count: i32,
+    archived_count: i32,
+    error_message: QString,
+    /// Metadata fetches still running
+    pending: usize,
+}
+
+fn bookmark_json(bookmark: &Bookmark) -> serde_json::Value {
+    serde_json::json!({
+        "id": bookmark.id,
+        "url": bookmark.url,
+        "title": bookmark.title,
+        "excerpt": bookmark.excerpt,
+        "siteName": bookmark.site_name,
+        "imageUrl": bookmark.image_url,
+        "tags": bookmark.tags,
+        "archived": bookmark.archived,
+        "createdAt": bookmark.created_at.to_rfc3339(),
+        "fetched": bookmark.fetched_at.is_some(),
+    })
+}
+
+fn split_tags(tags: &QString) -> Vec<String> {
+    tags.to_string().split([' ', ',']).map(str::to_string).collect()
+}
+
+fn store() -> Result<std::sync::Arc<BookmarkStore>, BookmarkError> {
+    bridge::get_bookmark_store_or_init().ok_or(BookmarkError::NotInitialized)
+}
+
+/// `None` for an empty string, meaning "no filter"
+fn non_empty(value: &QString) -> Option<String> {
+    Some(value.to_string()).filter(|v| !v.trim().is_empty())
+}
+
+impl qobject::BookmarkModel {
+    fn show_error(mut self: Pin<&mut Self>, e: BookmarkError) {
+        let msg = myme_core::AppError::from(e).user_message().to_string();
+        self.as_mut().set_error_message(QString::from(&msg));
+    }
+
+    /// Start a background metadata fetch for bookmark `id`.
+    fn fetch(mut self: Pin<&mut Self>, id: i64, url: String) {
+        let Ok(store) = store() else {
+            return;
+        };
+        bridge::init_bookmark_service_channel();
+        let Some(tx) = bridge::get_bookmark_service_tx() else {
+            return;
+        };
+        self.as_mut().rust_mut().pending += 1;
+        self.as_mut().set_loading(true);
+        request_bookmark_metadata(&tx, store, id, url);
+    }
+
+    pub fn reload(mut self: Pin<&mut Self>) {
+        let counts = store().and_then(|store| {
+            let to_store_err = |e: anyhow::Error| BookmarkError::Store(e.to_string());
+            Ok((
+                store.count(false).map_err(to_store_err)?,
+                store.count(true).map_err(to_store_err)?,
+            ))
+        });
+        match counts {
+            Ok((unread, archived)) => {
+                self.as_mut().set_count(unread as i32);
+                self.as_mut().set_archived_count(archived as i32);
+            }
+            Err(e) => self.as_mut().show_error(e),
+        }
+        self.as_mut().bookmarks_changed();
+    }
+
+    pub fn add_url(mut self: Pin<&mut Self>, url: &QString, tags: &QString) -> bool {
+        let url = url.to_string();
+        if url.trim().is_empty() {
+            return false;
+        }
+
+        let added = if normalize_url(&url).is_none() {
+            Err(BookmarkError::InvalidUrl(url.trim().to_string()))
+        } else {
+            store().and_then(|store| {
+                store.add(&url, &split_tags(tags)).map_err(|e| BookmarkError::Store(e.to_string()))
+            })
+        };
+        let (bookmark, created) = match added {
+            Ok(added) => added,
+            Err(e) => {
+                self.as_mut().show_error(e);
+                return false;
+            }
+        };
+
+        self.as_mut().set_error_message(QString::from(""));
+        if created && myme_core::connectivity::is_online() {
+            self.as_mut().fetch(bookmark.id, bookmark.url);
+        }
+        self.reload();
+        true
+    }
+
+    pub fn get_bookmarks(&self, tag: &QString, query: &QString, archived: bool) -> QString {
+        let filter = BookmarkFilter { tag: non_empty(tag), archived, query: non_empty(query) };
+        let rows: Vec<serde_json::Value> = store()
+            .ok()
+            .and_then(|store| store.list(&filter, LIST_LIMIT).ok())
+            .unwrap_or_default()
+            .iter()
+            .map(bookmark_json)
+            .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn get_tags(&self) -> QString {
+        let rows: Vec<serde_json::Value> = store()
+            .ok()
+            .and_then(|store| store.tags().ok())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(tag, count)| serde_json::json!({ "tag": tag, "count": count }))
+            .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn set_tags(self: Pin<&mut Self>, id: i64, tags: &QString) {
+        let result = store().and_then(|store| {
+            store.set_tags(id, &split_tags(tags)).map_err(|e| BookmarkError::Store(e.to_string()))
+        });
+        if let Err(e) = result {
+            tracing::warn!("Failed to update bookmark tags: {}", e);
+        }
+        self.reload();
+    }
+
+    pub fn set_archived(self: Pin<&mut Self>, id: i64, archived: bool) {
+        let result = store().and_then(|store| {
+            store.set_archived(id, archived).map_err(|e| BookmarkError::Store(e.to_string()))
+        });
+        if let Err(e) = result {
+            tracing::warn!("Failed to archive bookmark: {}", e);
+        }
+        self.reload();
+    }
+
+    pub fn delete_bookmark(self: Pin<&mut Self>, id: i64) {
+        let result = store()
+            .and_then(|store| store.delete(id).map_err(|e| BookmarkError::Store(e.to_string())));
+        if let Err(e) = result {
+            tracing::warn!("Failed to delete bookmark: {}", e);
+        }
+        self.reload();
+    }
+
+    pub fn refetch(mut self: Pin<&mut Self>, id: i64) {
+        let bookmark = store().ok().and_then(|store| store.get(id).ok().flatten());
+        if let Some(bookmark) = bookmark {
+            self.as_mut().set_error_message(QString::from(""));
+            self.fetch(bookmark.id, bookmark.url);
+        }
+    }
+
+    /// Poll for async operation results. Call this from a QML Timer (e.g., every 100ms).
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let Some(message) = bridge::try_recv_bookmark_message() else {
+            return;
+        };
+
+        let pending = self.rust().pending.saturating_sub(1);
+        self.as_mut().rust_mut().pending = pending;
+        self.as_mut().set_loading(pending > 0);
+        match message {
+            BookmarkServiceMessage::MetadataDone(Ok(id)) => {
+                tracing::debug!("Fetched metadata for bookmark {}", id);
+            }
+            BookmarkServiceMessage::MetadataDone(Err(e)) => self.as_mut().show_error(e),
+        }
+        self.reload();
+    }
+}
@@ -0,0 +1,60 @@
+//! Daily suggestions model for QML: the `[briefing]` rules that apply today,
+//! shown on the dashboard.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+
+use crate::services::briefing_suggestions;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, count)]
+        type BriefingModel = super::BriefingModelRust;
+
+        /// Check the rules again against the cached forecast, calendar and tasks
+        #[qinvokable]
+        fn refresh(self: Pin<&mut BriefingModel>);
+
+        /// Today's suggestions as a JSON array of strings
+        #[qinvokable]
+        fn get_suggestions(self: &BriefingModel) -> QString;
+
+        #[qsignal]
+        fn suggestions_changed(self: Pin<&mut BriefingModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct BriefingModelRust {
+    count: i32,
+    suggestions: Vec<String>,
+}
+
+impl qobject::BriefingModel {
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        let suggestions = briefing_suggestions();
+        if suggestions == self.rust().suggestions {
+            return;
+        }
+        let count = suggestions.len() as i32;
+        self.as_mut().rust_mut().suggestions = suggestions;
+        self.as_mut().set_count(count);
+        self.as_mut().suggestions_changed();
+    }
+
+    pub fn get_suggestions(&self) -> QString {
+        QString::from(
+            &serde_json::to_string(&self.rust().suggestions).unwrap_or_else(|_| "[]".into()),
+        )
+    }
+}
@@ -0,0 +1,204 @@
+//! Calendar list for QML: which calendars are shown, their colors and
+//! default reminders. Choices are kept in the calendar cache and survive
+//! refreshes from Google.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_calendar::{Calendar, CalendarCache};
+
+use crate::services::google_common::get_google_cache_path;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, count)]
+        #[qproperty(QString, error_message)]
+        type CalendarListModel = super::CalendarListModelRust;
+
+        /// Re-read the calendar list from the cache
+        #[qinvokable]
+        fn refresh(self: Pin<&mut CalendarListModel>);
+
+        #[qinvokable]
+        fn get_id(self: &CalendarListModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_summary(self: &CalendarListModel, index: i32) -> QString;
+
+        /// Color events are drawn in: the chosen one, else Google's
+        #[qinvokable]
+        fn get_color(self: &CalendarListModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn is_primary(self: &CalendarListModel, index: i32) -> bool;
+
+        #[qinvokable]
+        fn is_visible(self: &CalendarListModel, index: i32) -> bool;
+
+        /// Reminder set in MyMe in minutes before events, or -1 when
+        /// following Google's default reminders
+        #[qinvokable]
+        fn get_default_reminder(self: &CalendarListModel, index: i32) -> i32;
+
+        /// Show or hide a calendar's events; emits `visibility_changed`
+        #[qinvokable]
+        fn set_visible(self: Pin<&mut CalendarListModel>, index: i32, visible: bool) -> bool;
+
+        /// Override the calendar's color ("#rrggbb"); empty restores Google's
+        #[qinvokable]
+        fn set_color(self: Pin<&mut CalendarListModel>, index: i32, color: &QString) -> bool;
+
+        /// Default reminder in minutes, replacing Google's; negative goes back
+        /// to Google's
+        #[qinvokable]
+        fn set_default_reminder(
+            self: Pin<&mut CalendarListModel>,
+            index: i32,
+            minutes: i32,
+        ) -> bool;
+
+        #[qsignal]
+        fn calendars_changed(self: Pin<&mut CalendarListModel>);
+
+        /// Event views should reload; a calendar shown again also needs a sync
+        #[qsignal]
+        fn visibility_changed(
+            self: Pin<&mut CalendarListModel>,
+            calendar_id: QString,
+            visible: bool,
+        );
+    }
+}
+
+#[derive(Default)]
+pub struct CalendarListModelRust {
+    count: i32,
+    error_message: QString,
+    calendars: Vec<Calendar>,
+}
+
+impl CalendarListModelRust {
+    fn get_calendar(&self, index: i32) -> Option<&Calendar> {
+        usize::try_from(index).ok().and_then(|i| self.calendars.get(i))
+    }
+
+    fn open_cache() -> anyhow::Result<CalendarCache> {
+        CalendarCache::new(get_google_cache_path("calendar_cache.db"))
+    }
+}
+
+impl qobject::CalendarListModel {
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        match CalendarListModelRust::open_cache().and_then(|cache| cache.list_calendars()) {
+            Ok(calendars) => {
+                let count = calendars.len() as i32;
+                self.as_mut().rust_mut().calendars = calendars;
+                self.as_mut().set_count(count);
+                self.as_mut().set_error_message(QString::from(""));
+                self.as_mut().calendars_changed();
+            }
+            Err(e) => {
+                tracing::warn!("Failed to read calendars: {}", e);
+                self.as_mut().set_error_message(QString::from("Local cache error"));
+            }
+        }
+    }
+
+    pub fn get_id(&self, index: i32) -> QString {
+        self.rust().get_calendar(index).map(|c| QString::from(&c.id)).unwrap_or_default()
+    }
+
+    pub fn get_summary(&self, index: i32) -> QString {
+        self.rust().get_calendar(index).map(|c| QString::from(&c.summary)).unwrap_or_default()
+    }
+
+    pub fn get_color(&self, index: i32) -> QString {
+        self.rust()
+            .get_calendar(index)
+            .and_then(|c| c.display_color())
+            .map(QString::from)
+            .unwrap_or_default()
+    }
+
+    pub fn is_primary(&self, index: i32) -> bool {
+        self.rust().get_calendar(index).is_some_and(|c| c.is_primary)
+    }
+
+    pub fn is_visible(&self, index: i32) -> bool {
+        self.rust().get_calendar(index).is_some_and(|c| c.visible)
+    }
+
+    pub fn get_default_reminder(&self, index: i32) -> i32 {
+        self.rust()
+            .get_calendar(index)
+            .and_then(|c| c.default_reminder_minutes)
+            .map_or(-1, |m| m as i32)
+    }
+
+    /// Apply a change to the cached calendar at `index`, then re-read the list.
+    fn update(
+        mut self: Pin<&mut Self>,
+        index: i32,
+        change: impl FnOnce(&CalendarCache, &str) -> anyhow::Result<bool>,
+    ) -> bool {
+        let Some(id) = self.rust().get_calendar(index).map(|c| c.id.clone()) else {
+            return false;
+        };
+        match CalendarListModelRust::open_cache().and_then(|cache| change(&cache, &id)) {
+            Ok(true) => {
+                self.as_mut().refresh();
+                true
+            }
+            Ok(false) => false,
+            Err(e) => {
+                tracing::warn!("Failed to update calendar {}: {}", id, e);
+                self.as_mut().set_error_message(QString::from("Local cache error"));
+                false
+            }
+        }
+    }
+
+    pub fn set_visible(mut self: Pin<&mut Self>, index: i32, visible: bool) -> bool {
+        let Some(calendar_id) = self.rust().get_calendar(index).map(|c| QString::from(&c.id))
+        else {
+            return false;
+        };
+        let updated =
+            self.as_mut().update(index, |cache, id| cache.set_calendar_visible(id, visible));
+        if updated {
+            self.as_mut().visibility_changed(calendar_id, visible);
+        }
+        updated
+    }
+
+    pub fn set_color(self: Pin<&mut Self>, index: i32, color: &QString) -> bool {
+        let color = color.to_string();
+        let color = color.trim();
+        if !color.is_empty() && !is_hex_color(color) {
+            return false;
+        }
+        let color = (!color.is_empty()).then_some(color);
+        self.update(index, |cache, id| cache.set_calendar_color(id, color))
+    }
+
+    pub fn set_default_reminder(self: Pin<&mut Self>, index: i32, minutes: i32) -> bool {
+        let minutes = u32::try_from(minutes).ok();
+        self.update(index, |cache, id| cache.set_default_reminder(id, minutes))
+    }
+}
+
+/// `#rgb` or `#rrggbb`.
+fn is_hex_color(color: &str) -> bool {
+    color
+        .strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
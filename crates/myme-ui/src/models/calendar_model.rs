@@ -4,17 +4,26 @@
 //! Uses the shared AppServices runtime and channel pattern (no block_on).
 
 use core::pin::Pin;
+use std::collections::HashSet;
 
-use chrono::Utc;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc, Weekday};
 use cxx_qt::CxxQtType;
 use cxx_qt_lib::QString;
 use myme_auth::SecureStorage;
-use myme_calendar::{Calendar, CalendarCache, Event};
+use myme_calendar::{
+    Calendar, CalendarCache, ConferenceLink, DisplayZone, Event, EventStatus, Reminder,
+    ReminderMethod,
+};
+use myme_core::dashboard::EventSnapshot;
+use myme_core::datefmt::DateFormatter;
+use myme_services::TaskStatus;
 
 use crate::bridge;
 use crate::services::google_common::{get_google_access_token, get_google_cache_path};
 use crate::services::{
-    request_calendar_fetch_events, request_calendar_fetch_today_events, CalendarServiceMessage,
+    calendar_display_zone, request_calendar_create_event, request_calendar_fetch_events,
+    request_calendar_fetch_today_events, request_calendar_update_reminders,
+    request_sync_task_schedules, set_scheduled_task_done, task_for_event, CalendarServiceMessage,
 };
 
 #[cxx_qt::bridge]
@@ -34,6 +43,8 @@ pub mod qobject {
         #[qproperty(i32, today_event_count)]
         #[qproperty(QString, next_event_summary)]
         #[qproperty(QString, next_event_time)]
+        #[qproperty(QString, next_join_url)]
+        #[qproperty(QString, display_timezone)]
         type CalendarModel = super::CalendarModelRust;
 
         #[qinvokable]
@@ -48,9 +59,98 @@ pub mod qobject {
         #[qinvokable]
         fn get_event(self: Pin<&mut CalendarModel>, index: i32) -> QString;
 
+        /// Search cached events; returns a JSON array of result indices
+        #[qinvokable]
+        fn search(self: Pin<&mut CalendarModel>, query: &QString) -> QString;
+
+        #[qinvokable]
+        fn get_search_result(self: Pin<&mut CalendarModel>, index: i32) -> QString;
+
+        /// Calendars as JSON `[{id, summary, isPrimary, backgroundColor, color,
+        /// visible, defaultReminderMinutes}]`, primary first
         #[qinvokable]
         fn get_calendars(self: Pin<&mut CalendarModel>) -> QString;
 
+        /// Re-read calendars and this week's events from the cache, e.g. after
+        /// a calendar was shown or hidden. Emits `calendars_changed` and
+        /// `events_changed`.
+        #[qinvokable]
+        fn reload_calendars(self: Pin<&mut CalendarModel>);
+
+        /// Conferencing URL (Meet, Zoom, Teams, Jitsi) for the event at index, or ""
+        #[qinvokable]
+        fn get_join_url(self: Pin<&mut CalendarModel>, index: i32) -> QString;
+
+        /// Join URL of the meeting in progress or the next one today, or ""
+        #[qinvokable]
+        fn join_next_meeting(self: Pin<&mut CalendarModel>) -> QString;
+
+        /// Create an event on the primary calendar. `start` is "YYYY-MM-DD HH:MM"
+        /// in the display zone and `attendees` a JSON array of email addresses.
+        /// Returns false if the input is invalid (see `error_message`); emits
+        /// `event_created` when done.
+        #[qinvokable]
+        fn create_event(
+            self: Pin<&mut CalendarModel>,
+            summary: &QString,
+            start: &QString,
+            duration_minutes: i32,
+            attendees: &QString,
+        ) -> bool;
+
+        /// Month grid from the offline cache for `year` and `month` (1-12): six
+        /// weeks starting Monday, each a list of day cells
+        /// `{date, day, inMonth, isToday, events}`, as JSON
+        #[qinvokable]
+        fn events_matrix(self: &CalendarModel, year: i32, month: i32) -> QString;
+
+        /// Week view from the offline cache for the seven days from `start_date`
+        /// ("YYYY-MM-DD"): per day `{date, weekday, isToday, allDay, slots}`,
+        /// where each slot is `{event, startMinute, endMinute, column, columns}`
+        /// with overlapping events in separate columns, as JSON
+        #[qinvokable]
+        fn week_layout(self: &CalendarModel, start_date: &QString) -> QString;
+
+        /// Next full hour in the display zone as "YYYY-MM-DD HH:MM"
+        #[qinvokable]
+        fn default_event_start(self: &CalendarModel) -> QString;
+
+        /// Show times in an IANA zone (e.g. "America/Toronto") instead of the
+        /// system zone; empty follows the system zone again. Saved to config.
+        /// Returns false for an unknown zone (see `error_message`).
+        #[qinvokable]
+        fn set_display_timezone_name(self: Pin<&mut CalendarModel>, name: &QString) -> bool;
+
+        /// Complete or reopen the kanban task an event time-blocks (see
+        /// `taskId` in event JSON); the event title follows. Returns false if
+        /// the event is not a task's time block.
+        #[qinvokable]
+        fn set_event_task_done(
+            self: Pin<&mut CalendarModel>,
+            event_id: &QString,
+            done: bool,
+        ) -> bool;
+
+        /// Set an event's reminders from a JSON array of minutes before it
+        /// starts (popup alerts; `[]` for none), or `null` to use the
+        /// calendar's defaults. Saved to Google; emits `events_changed` when
+        /// done. Returns false for invalid input (see `error_message`).
+        #[qinvokable]
+        fn set_event_reminders(
+            self: Pin<&mut CalendarModel>,
+            event_id: &QString,
+            minutes: &QString,
+        ) -> bool;
+
+        /// Turn leave-by warnings for an event on or off (see `travelAlerts`
+        /// in event JSON). Kept locally, not synced to Google.
+        #[qinvokable]
+        fn set_event_travel_alerts(
+            self: Pin<&mut CalendarModel>,
+            event_id: &QString,
+            enabled: bool,
+        ) -> bool;
+
         /// Poll for async operation results. Call this from a QML Timer.
         #[qinvokable]
         fn poll_channel(self: Pin<&mut CalendarModel>);
@@ -58,6 +158,9 @@ pub mod qobject {
         #[qsignal]
         fn events_changed(self: Pin<&mut CalendarModel>);
 
+        #[qsignal]
+        fn event_created(self: Pin<&mut CalendarModel>, summary: QString);
+
         #[qsignal]
         fn calendars_changed(self: Pin<&mut CalendarModel>);
     }
@@ -72,10 +175,27 @@ pub struct CalendarModelRust {
     today_event_count: i32,
     next_event_summary: QString,
     next_event_time: QString,
+    next_join_url: QString,
+    /// Pinned IANA zone, or "" when following the system zone
+    display_timezone: QString,
+    zone: DisplayZone,
     events: Vec<Event>,
     calendars: Vec<Calendar>,
+    search_results: Vec<Event>,
+    /// (calendar id, event id) of events without leave-by warnings
+    travel_opt_outs: HashSet<(String, String)>,
 }
 
+/// How far back and ahead `search` looks in the cache
+const SEARCH_WINDOW_DAYS: i64 = 365;
+
+/// Grid views also load events starting this many days before the first
+/// visible day, so multi-day events show from their first visible day
+const GRID_LOOKBACK_DAYS: i64 = 14;
+
+/// Month grids start on Monday
+const WEEK_START: Weekday = Weekday::Mon;
+
 impl CalendarModelRust {
     fn set_error(&mut self, msg: &str) {
         self.error_message = QString::from(msg);
@@ -92,23 +212,215 @@ impl CalendarModelRust {
     fn get_cache_path() -> std::path::PathBuf {
         get_google_cache_path("calendar_cache.db")
     }
+
+    /// Earliest joinable meeting that is in progress or starts later today.
+    fn next_meeting_link(&self) -> Option<ConferenceLink> {
+        let zone = &self.zone;
+        let now = Utc::now();
+        let today = zone.today(now);
+        self.events
+            .iter()
+            .filter(|e| !e.all_day && e.status != EventStatus::Cancelled)
+            .filter(|e| e.end.instant_in(zone) > now && e.start.local_in(zone).date() == today)
+            .filter_map(|e| ConferenceLink::from_event(e).map(|link| (e, link)))
+            .min_by_key(|(e, _)| e.start.instant_in(zone))
+            .map(|(_, link)| link)
+    }
+
+    /// Event as JSON. Times are formatted in the display zone; when the event
+    /// was scheduled in a zone whose clock differs, `eventTimeZone` and
+    /// `eventStartTime` give its time there.
+    ///
+    /// Events that are time blocks for a kanban task carry `taskId` and
+    /// `taskDone`. `reminders` lists popup alert minutes, with
+    /// `remindersDefault` true when they come from the calendar, and
+    /// `travelAlerts` is false when leave-by warnings are turned off.
+    fn event_value(&self, event: &Event) -> serde_json::Value {
+        let zone = &self.zone;
+        let fmt = DateFormatter::current();
+        let (start, end) = (event.start.local_in(zone), event.end.local_in(zone));
+        let scheduled = event
+            .time_zone
+            .as_deref()
+            .filter(|_| !event.start.is_floating())
+            .and_then(|name| DisplayZone::parse(name).ok())
+            .filter(|tz| event.start.local_in(tz) != start)
+            .map(|tz| (tz.name().to_string(), fmt.time(event.start.local_in(&tz).time())));
+        let task =
+            bridge::get_project_store_or_init().and_then(|store| task_for_event(&store, &event.id));
+        let calendar = self.calendar_of(event);
+        let defaults = calendar.map(Calendar::reminders).unwrap_or_default();
+        let reminders: Vec<u32> = event
+            .effective_reminders(&defaults)
+            .iter()
+            .filter(|r| r.method == ReminderMethod::Popup)
+            .map(|r| r.minutes)
+            .collect();
+        serde_json::json!({
+            "id": event.id,
+            "calendarId": event.calendar_id,
+            "calendarName": calendar.map(|c| &c.summary),
+            "color": calendar.and_then(|c| c.display_color()),
+            "summary": event.summary,
+            "description": event.description,
+            "location": event.location,
+            "start": event.start.instant_in(zone).to_rfc3339(),
+            "end": event.end.instant_in(zone).to_rfc3339(),
+            "startTime": fmt.time(start.time()),
+            "endTime": fmt.time(end.time()),
+            "dateLabel": fmt.day(start.date(), zone.today(Utc::now())),
+            "eventTimeZone": scheduled.as_ref().map(|(name, _)| name),
+            "eventStartTime": scheduled.as_ref().map(|(_, time)| time),
+            "allDay": event.all_day,
+            "status": format!("{:?}", event.status),
+            "joinUrl": ConferenceLink::from_event(event).map(|l| l.url),
+            "joinProvider": ConferenceLink::from_event(event).map(|l| l.provider.display_name()),
+            "taskId": task.as_ref().map(|t| &t.id),
+            "taskDone": task.as_ref().is_some_and(|t| t.status == TaskStatus::Done),
+            "reminders": reminders,
+            "remindersDefault": event.reminders.is_none(),
+            "travelAlerts": !self
+                .travel_opt_outs
+                .contains(&(event.calendar_id.clone(), event.id.clone())),
+        })
+    }
+
+    fn event_json(&self, event: &Event) -> QString {
+        QString::from(self.event_value(event).to_string().as_str())
+    }
+
+    /// The cached calendar an event belongs to.
+    fn calendar_of(&self, event: &Event) -> Option<&Calendar> {
+        self.calendars.iter().find(|c| c.event_calendar_id() == event.calendar_id)
+    }
+
+    /// Cached events of visible calendars for the display-zone days `first` to
+    /// `last`, starting early enough to catch multi-day events already under way.
+    fn cached_events_between(&self, first: NaiveDate, last: NaiveDate) -> Vec<Event> {
+        let zone = &self.zone;
+        let (start, _) = zone.day_range(first - Duration::days(GRID_LOOKBACK_DAYS));
+        let (_, end) = zone.day_range(last);
+        match CalendarCache::new(Self::get_cache_path())
+            .and_then(|cache| cache.list_visible_events(start, end, zone))
+        {
+            Ok(events) => {
+                events.into_iter().filter(|e| e.status != EventStatus::Cancelled).collect()
+            }
+            Err(e) => {
+                tracing::warn!("Calendar grid: cache unavailable: {}", e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Parse `set_event_reminders` input: `null` for the calendar's defaults, or a
+/// JSON array of minutes before the event.
+fn parse_reminders(minutes: &str) -> Result<Option<Vec<Reminder>>, &'static str> {
+    let minutes: Option<Vec<u32>> =
+        serde_json::from_str(minutes).map_err(|_| "Reminders must be a list of minutes")?;
+    let Some(mut minutes) = minutes else {
+        return Ok(None);
+    };
+    if minutes.iter().any(|&m| m > Reminder::MAX_MINUTES) {
+        return Err("Reminders can be at most four weeks before");
+    }
+    minutes.sort_unstable();
+    minutes.dedup();
+    Ok(Some(minutes.into_iter().map(Reminder::popup).collect()))
+}
+
+/// Validate `create_event` input into (summary, start, end, attendee emails),
+/// reading `start` as a wall-clock time in `zone`.
+fn new_event(
+    summary: &str,
+    start: &str,
+    duration_minutes: i32,
+    attendees: &str,
+    zone: &DisplayZone,
+) -> Result<(String, DateTime<Utc>, DateTime<Utc>, Vec<String>), &'static str> {
+    let summary = summary.trim();
+    if summary.is_empty() {
+        return Err("Event needs a title");
+    }
+    let start = NaiveDateTime::parse_from_str(start.trim(), "%Y-%m-%d %H:%M")
+        .map(|local| zone.to_utc(local))
+        .map_err(|_| "Start must be YYYY-MM-DD HH:MM")?;
+    if duration_minutes <= 0 {
+        return Err("Duration must be positive");
+    }
+    let attendees: Vec<String> =
+        serde_json::from_str(attendees).map_err(|_| "Invalid attendee list")?;
+    let emails = attendees
+        .iter()
+        .map(|a| myme_contacts::parse_address(a).map(|(_, email)| email))
+        .collect::<Option<Vec<String>>>()
+        .ok_or("Attendees must be email addresses")?;
+    let end = start + Duration::minutes(duration_minutes as i64);
+    Ok((summary.to_string(), start, end, emails))
 }
 
 impl qobject::CalendarModel {
+    fn apply_zone(mut self: Pin<&mut Self>, zone: DisplayZone) {
+        let name = match zone {
+            DisplayZone::Local => "",
+            DisplayZone::Named(tz) => tz.name(),
+        };
+        self.as_mut().set_display_timezone(QString::from(name));
+        self.as_mut().rust_mut().zone = zone;
+    }
+
     /// Check if Google is authenticated
     pub fn check_auth(mut self: Pin<&mut Self>) {
+        self.as_mut().apply_zone(calendar_display_zone());
         let is_authenticated = SecureStorage::has_token("google");
         self.as_mut().set_authenticated(is_authenticated);
 
         if is_authenticated {
             if let Ok(cache) = CalendarCache::new(CalendarModelRust::get_cache_path()) {
-                if let Ok(count) = cache.upcoming_event_count("primary", 24) {
+                if let Ok(count) = cache.upcoming_event_count("primary", 24, &self.rust().zone) {
                     self.as_mut().set_today_event_count(count as i32);
                 }
+                if let Ok(calendars) = cache.list_calendars() {
+                    self.as_mut().rust_mut().calendars = calendars;
+                    self.as_mut().calendars_changed();
+                }
+                if let Ok(opt_outs) = cache.travel_opt_outs() {
+                    self.as_mut().rust_mut().travel_opt_outs = opt_outs;
+                }
             }
         }
     }
 
+    pub fn reload_calendars(mut self: Pin<&mut Self>) {
+        let zone = self.rust().zone;
+        let now = Utc::now();
+        let loaded = CalendarCache::new(CalendarModelRust::get_cache_path()).and_then(|cache| {
+            let calendars = cache.list_calendars()?;
+            // Same window as `fetch_events`, including events under way
+            let mut events = cache.list_visible_events(
+                now - Duration::days(1),
+                now + Duration::days(7),
+                &zone,
+            )?;
+            events.retain(|e| e.end.instant_in(&zone) > now);
+            Ok((calendars, events))
+        });
+        let (calendars, events) = match loaded {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                tracing::warn!("Failed to reload calendars: {}", e);
+                return;
+            }
+        };
+        self.as_mut().set_event_count(events.len() as i32);
+        self.as_mut().rust_mut().calendars = calendars;
+        self.as_mut().rust_mut().events = events;
+        self.as_mut().refresh_summary();
+        self.as_mut().calendars_changed();
+        self.as_mut().events_changed();
+    }
+
     /// Fetch events for the next 7 days (non-blocking, uses shared runtime)
     pub fn fetch_events(mut self: Pin<&mut Self>) {
         let access_token = match CalendarModelRust::get_access_token() {
@@ -169,22 +481,42 @@ impl qobject::CalendarModel {
             return QString::from("{}");
         }
 
-        let event = &rust.events[index as usize];
-        let json = serde_json::json!({
-            "id": event.id,
-            "summary": event.summary,
-            "description": event.description,
-            "location": event.location,
-            "start": event.start.as_datetime().to_rfc3339(),
-            "end": event.end.as_datetime().to_rfc3339(),
-            "allDay": event.all_day,
-            "status": format!("{:?}", event.status),
-        });
+        rust.event_json(&rust.events[index as usize])
+    }
+
+    /// Search the offline cache (all calendars, a year either side of today)
+    /// by summary, description, location and attendees. Results are read
+    /// with `get_search_result`.
+    pub fn search(mut self: Pin<&mut Self>, query: &QString) -> QString {
+        let query = query.to_string();
+        let now = Utc::now();
+        let window = chrono::Duration::days(SEARCH_WINDOW_DAYS);
+        let results = match CalendarCache::new(CalendarModelRust::get_cache_path())
+            .and_then(|cache| cache.search_events(&query, now - window, now + window))
+        {
+            Ok(results) => results,
+            Err(e) => {
+                tracing::warn!("Calendar search failed: {}", e);
+                Vec::new()
+            }
+        };
 
-        let s = json.to_string();
+        let indices: Vec<usize> = (0..results.len()).collect();
+        self.as_mut().rust_mut().search_results = results;
+        let s = serde_json::to_string(&indices).unwrap_or_else(|_| "[]".to_string());
         QString::from(s.as_str())
     }
 
+    /// Get search result at index as JSON (same shape as `get_event`)
+    pub fn get_search_result(self: Pin<&mut Self>, index: i32) -> QString {
+        let rust = self.rust();
+        if index < 0 || index as usize >= rust.search_results.len() {
+            return QString::from("{}");
+        }
+
+        rust.event_json(&rust.search_results[index as usize])
+    }
+
     /// Get calendars as JSON
     pub fn get_calendars(self: Pin<&mut Self>) -> QString {
         let calendars: Vec<_> = self
@@ -198,6 +530,9 @@ impl qobject::CalendarModel {
                     "summary": cal.summary,
                     "isPrimary": cal.is_primary,
                     "backgroundColor": cal.background_color,
+                    "color": cal.display_color(),
+                    "visible": cal.visible,
+                    "defaultReminderMinutes": cal.default_reminder_minutes,
                 })
             })
             .collect();
@@ -206,6 +541,335 @@ impl qobject::CalendarModel {
         QString::from(s.as_str())
     }
 
+    /// Get the join URL for the event at index
+    pub fn get_join_url(self: Pin<&mut Self>, index: i32) -> QString {
+        let rust = self.rust();
+        if index < 0 || index as usize >= rust.events.len() {
+            return QString::from("");
+        }
+
+        match ConferenceLink::from_event(&rust.events[index as usize]) {
+            Some(link) => QString::from(link.url.as_str()),
+            None => QString::from(""),
+        }
+    }
+
+    /// Get the join URL for the current or next meeting (for the tray/today view)
+    pub fn join_next_meeting(self: Pin<&mut Self>) -> QString {
+        match self.rust().next_meeting_link() {
+            Some(link) => QString::from(link.url.as_str()),
+            None => QString::from(""),
+        }
+    }
+
+    pub fn create_event(
+        mut self: Pin<&mut Self>,
+        summary: &QString,
+        start: &QString,
+        duration_minutes: i32,
+        attendees: &QString,
+    ) -> bool {
+        let parsed = new_event(
+            &summary.to_string(),
+            &start.to_string(),
+            duration_minutes,
+            &attendees.to_string(),
+            &self.rust().zone,
+        );
+        let (summary, start, end, emails) = match parsed {
+            Ok(parts) => parts,
+            Err(msg) => {
+                self.as_mut().set_error_message(QString::from(msg));
+                return false;
+            }
+        };
+
+        let Some(access_token) = CalendarModelRust::get_access_token() else {
+            self.as_mut().set_error_message(QString::from("Not authenticated"));
+            self.as_mut().set_authenticated(false);
+            return false;
+        };
+        bridge::init_calendar_service_channel();
+        let Some(tx) = bridge::get_calendar_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            return false;
+        };
+
+        self.as_mut().set_loading(true);
+        self.as_mut().rust_mut().clear_error();
+        request_calendar_create_event(
+            &tx,
+            access_token,
+            CalendarModelRust::get_cache_path(),
+            summary,
+            start,
+            end,
+            emails,
+        );
+        true
+    }
+
+    pub fn set_event_task_done(mut self: Pin<&mut Self>, event_id: &QString, done: bool) -> bool {
+        let Some(store) = bridge::get_project_store_or_init() else {
+            return false;
+        };
+        let Some(task) = task_for_event(&store, &event_id.to_string()) else {
+            return false;
+        };
+        set_scheduled_task_done(&store, task.clone(), done);
+        if let Some(access_token) = CalendarModelRust::get_access_token() {
+            request_sync_task_schedules(store, access_token, task);
+        }
+        self.as_mut().events_changed();
+        true
+    }
+
+    pub fn set_event_reminders(
+        mut self: Pin<&mut Self>,
+        event_id: &QString,
+        minutes: &QString,
+    ) -> bool {
+        let reminders = match parse_reminders(&minutes.to_string()) {
+            Ok(reminders) => reminders,
+            Err(msg) => {
+                self.as_mut().set_error_message(QString::from(msg));
+                return false;
+            }
+        };
+        let event_id = event_id.to_string();
+        let rust = self.rust();
+        let Some(calendar_id) = rust
+            .events
+            .iter()
+            .chain(&rust.search_results)
+            .find(|e| e.id == event_id)
+            .map(|e| e.calendar_id.clone())
+        else {
+            self.as_mut().set_error_message(QString::from("Event not found"));
+            return false;
+        };
+
+        let Some(access_token) = CalendarModelRust::get_access_token() else {
+            self.as_mut().set_error_message(QString::from("Not authenticated"));
+            self.as_mut().set_authenticated(false);
+            return false;
+        };
+        bridge::init_calendar_service_channel();
+        let Some(tx) = bridge::get_calendar_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            return false;
+        };
+
+        self.as_mut().set_loading(true);
+        self.as_mut().rust_mut().clear_error();
+        request_calendar_update_reminders(
+            &tx,
+            access_token,
+            CalendarModelRust::get_cache_path(),
+            calendar_id,
+            event_id,
+            reminders,
+        );
+        true
+    }
+
+    pub fn set_event_travel_alerts(
+        mut self: Pin<&mut Self>,
+        event_id: &QString,
+        enabled: bool,
+    ) -> bool {
+        let event_id = event_id.to_string();
+        let rust = self.rust();
+        let Some(calendar_id) = rust
+            .events
+            .iter()
+            .chain(&rust.search_results)
+            .find(|e| e.id == event_id)
+            .map(|e| e.calendar_id.clone())
+        else {
+            self.as_mut().set_error_message(QString::from("Event not found"));
+            return false;
+        };
+
+        let updated = CalendarCache::new(CalendarModelRust::get_cache_path())
+            .and_then(|cache| cache.set_travel_alerts(&calendar_id, &event_id, enabled));
+        if let Err(e) = updated {
+            tracing::warn!("Failed to update travel alerts for {}: {}", event_id, e);
+            self.as_mut().set_error_message(QString::from("Local cache error"));
+            return false;
+        }
+        let key = (calendar_id, event_id);
+        let mut rust = self.as_mut().rust_mut();
+        if enabled {
+            rust.travel_opt_outs.remove(&key);
+        } else {
+            rust.travel_opt_outs.insert(key);
+        }
+        self.as_mut().events_changed();
+        true
+    }
+
+    pub fn events_matrix(&self, year: i32, month: i32) -> QString {
+        let rust = self.rust();
+        let Some(first) =
+            u32::try_from(month).ok().and_then(|m| NaiveDate::from_ymd_opt(year, m, 1))
+        else {
+            return QString::from("{\"weeks\":[]}");
+        };
+        let events = rust.cached_events_between(
+            first - Duration::days(7),
+            first + Duration::days(7 * myme_calendar::layout::MONTH_WEEKS as i64),
+        );
+        let today = rust.zone.today(Utc::now());
+        let weeks =
+            myme_calendar::month_matrix(&events, year, first.month(), WEEK_START, &rust.zone)
+                .unwrap_or_default();
+
+        let weeks: Vec<Vec<serde_json::Value>> = weeks
+            .iter()
+            .map(|week| {
+                week.iter()
+                    .map(|cell| {
+                        let on_day: Vec<_> =
+                            cell.events.iter().map(|&i| rust.event_value(&events[i])).collect();
+                        serde_json::json!({
+                            "date": cell.date.to_string(),
+                            "day": cell.date.day(),
+                            "inMonth": cell.in_month,
+                            "isToday": cell.date == today,
+                            "events": on_day,
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+        QString::from(
+            serde_json::json!({ "year": year, "month": month, "weeks": weeks }).to_string(),
+        )
+    }
+
+    pub fn week_layout(&self, start_date: &QString) -> QString {
+        let rust = self.rust();
+        let Ok(start) = NaiveDate::parse_from_str(start_date.to_string().trim(), "%Y-%m-%d") else {
+            return QString::from("[]");
+        };
+        let events = rust.cached_events_between(start, start + Duration::days(6));
+        let today = rust.zone.today(Utc::now());
+        let fmt = DateFormatter::current();
+
+        let days: Vec<serde_json::Value> = myme_calendar::week_layout(&events, start, &rust.zone)
+            .iter()
+            .map(|day| {
+                let all_day: Vec<_> =
+                    day.all_day.iter().map(|&i| rust.event_value(&events[i])).collect();
+                let slots: Vec<_> = day
+                    .slots
+                    .iter()
+                    .map(|slot| {
+                        serde_json::json!({
+                            "event": rust.event_value(&events[slot.index]),
+                            "startMinute": slot.start_minute,
+                            "endMinute": slot.end_minute,
+                            "column": slot.column,
+                            "columns": slot.columns,
+                        })
+                    })
+                    .collect();
+                serde_json::json!({
+                    "date": day.date.to_string(),
+                    "weekday": fmt.weekday(day.date),
+                    "isToday": day.date == today,
+                    "allDay": all_day,
+                    "slots": slots,
+                })
+            })
+            .collect();
+        QString::from(serde_json::Value::Array(days).to_string())
+    }
+
+    pub fn default_event_start(&self) -> QString {
+        let zone = &self.rust().zone;
+        let next_hour = zone.local_datetime(Utc::now() + Duration::hours(1));
+        let start = next_hour.with_minute(0).unwrap_or(next_hour);
+        QString::from(start.format("%Y-%m-%d %H:%M").to_string().as_str())
+    }
+
+    pub fn set_display_timezone_name(mut self: Pin<&mut Self>, name: &QString) -> bool {
+        let zone = match DisplayZone::parse(&name.to_string()) {
+            Ok(zone) => zone,
+            Err(e) => {
+                self.as_mut().set_error_message(QString::from(e.user_message().as_str()));
+                return false;
+            }
+        };
+        let mut config = match myme_core::Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::warn!("Failed to load config: {}", e);
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                return false;
+            }
+        };
+        config.calendar.display_timezone = match zone {
+            DisplayZone::Local => None,
+            DisplayZone::Named(tz) => Some(tz.name().to_string()),
+        };
+        if let Err(e) = config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+            self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            return false;
+        }
+
+        tracing::info!("Calendar display time zone: {}", zone.name());
+        self.as_mut().apply_zone(zone);
+        self.as_mut().rust_mut().clear_error();
+        self.as_mut().refresh_summary();
+        self.as_mut().events_changed();
+        true
+    }
+
+    /// Recompute today's count, the next event and the join link for the
+    /// display zone.
+    fn refresh_summary(mut self: Pin<&mut Self>) {
+        let rust = self.rust();
+        let zone = &rust.zone;
+        let fmt = DateFormatter::current();
+        let now = Utc::now();
+        let today = zone.today(now);
+        let today_count =
+            rust.events.iter().filter(|e| e.start.local_in(zone).date() == today).count();
+        let next_event = rust
+            .events
+            .iter()
+            .filter(|e| e.start.instant_in(zone) > now)
+            .min_by_key(|e| e.start.instant_in(zone))
+            .map(|event| {
+                let summary =
+                    if event.summary.is_empty() { "(No title)" } else { event.summary.as_str() };
+                let when = fmt.moment(event.start.local_in(zone), zone.local_datetime(now));
+                (summary.to_string(), when)
+            });
+        let join_url = rust.next_meeting_link().map(|l| l.url).unwrap_or_default();
+        if let Some(store) = bridge::get_dashboard_store() {
+            let upcoming = rust
+                .events
+                .iter()
+                .filter(|e| e.start.local_in(zone).date() >= today)
+                .map(|e| EventSnapshot {
+                    summary: e.summary.clone(),
+                    start: e.start.instant_in(zone),
+                })
+                .collect();
+            store.lock().set_events(upcoming);
+        }
+
+        let (summary, time) = next_event.unwrap_or_default();
+        self.as_mut().set_next_event_summary(QString::from(summary.as_str()));
+        self.as_mut().set_next_event_time(QString::from(time.as_str()));
+        self.as_mut().set_today_event_count(today_count as i32);
+        self.as_mut().set_next_join_url(QString::from(join_url.as_str()));
+    }
+
     /// Poll for async operation results
     pub fn poll_channel(mut self: Pin<&mut Self>) {
         let msg = match bridge::try_recv_calendar_message() {
@@ -218,36 +882,12 @@ impl qobject::CalendarModel {
                 self.as_mut().set_loading(false);
 
                 match result {
-                    Ok(events) => {
-                        let now = Utc::now();
-                        let today = now.date_naive();
-                        let today_count = events
-                            .iter()
-                            .filter(|e| e.start.as_datetime().date_naive() == today)
-                            .count();
-
-                        let next_event = events
-                            .iter()
-                            .filter(|e| e.start.as_datetime() > now)
-                            .min_by_key(|e| e.start.as_datetime());
-
-                        if let Some(event) = next_event {
-                            let summary = if event.summary.is_empty() {
-                                "(No title)"
-                            } else {
-                                &event.summary
-                            };
-                            self.as_mut().set_next_event_summary(QString::from(summary));
-                            let time_str = event.start.as_datetime().format("%H:%M").to_string();
-                            self.as_mut().set_next_event_time(QString::from(time_str.as_str()));
-                        } else {
-                            self.as_mut().set_next_event_summary(QString::from(""));
-                            self.as_mut().set_next_event_time(QString::from(""));
-                        }
-
+                    Ok(mut events) => {
+                        let zone = self.rust().zone;
+                        events.sort_by_key(|e| e.start.instant_in(&zone));
                         self.as_mut().set_event_count(events.len() as i32);
-                        self.as_mut().set_today_event_count(today_count as i32);
                         self.as_mut().rust_mut().events = events;
+                        self.as_mut().refresh_summary();
                         self.as_mut().rust_mut().clear_error();
                         self.as_mut().events_changed();
                     }
@@ -258,14 +898,22 @@ impl qobject::CalendarModel {
                     }
                 }
             }
-            CalendarServiceMessage::FetchCalendarsDone(result) => {
+            CalendarServiceMessage::CreateEventDone(result) => {
                 self.as_mut().set_loading(false);
 
                 match result {
-                    Ok(calendars) => {
-                        self.as_mut().rust_mut().calendars = calendars;
+                    Ok(event) => {
+                        tracing::info!("Created event {}", event.id);
+                        let summary = QString::from(&event.summary);
+                        let mut rust = self.as_mut().rust_mut();
+                        let zone = rust.zone;
+                        rust.events.push(event);
+                        rust.events.sort_by_key(|e| e.start.instant_in(&zone));
+                        let count = rust.events.len() as i32;
+                        self.as_mut().set_event_count(count);
                         self.as_mut().rust_mut().clear_error();
-                        self.as_mut().calendars_changed();
+                        self.as_mut().events_changed();
+                        self.as_mut().event_created(summary);
                     }
                     Err(e) => {
                         self.as_mut()
@@ -274,6 +922,39 @@ impl qobject::CalendarModel {
                     }
                 }
             }
+            CalendarServiceMessage::UpdateRemindersDone(result) => {
+                self.as_mut().set_loading(false);
+
+                match result {
+                    Ok(event) => {
+                        let mut rust = self.as_mut().rust_mut();
+                        let rust = &mut *rust;
+                        for cached in rust.events.iter_mut().chain(&mut rust.search_results) {
+                            if cached.id == event.id && cached.calendar_id == event.calendar_id {
+                                *cached = event.clone();
+                            }
+                        }
+                        self.as_mut().rust_mut().clear_error();
+                        self.as_mut().events_changed();
+                    }
+                    Err(e) => {
+                        self.as_mut()
+                            .rust_mut()
+                            .set_error(myme_core::AppError::from(e).user_message());
+                    }
+                }
+            }
+            // Sent ahead of FetchEventsDone, so loading stays on
+            CalendarServiceMessage::FetchCalendarsDone(result) => match result {
+                Ok(calendars) => {
+                    self.as_mut().rust_mut().calendars = calendars;
+                    self.as_mut().rust_mut().clear_error();
+                    self.as_mut().calendars_changed();
+                }
+                Err(e) => {
+                    self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                }
+            },
         }
     }
 }
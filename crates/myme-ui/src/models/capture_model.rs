@@ -0,0 +1,173 @@
+use core::pin::Pin;
+
+use chrono::{Local, Utc};
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_calendar::DisplayZone;
+
+use crate::bridge;
+use crate::services::google_common::get_google_access_token;
+use crate::services::{
+    calendar_display_zone, parse_capture, request_capture, CaptureClients, CaptureError,
+    CaptureParseError, CaptureServiceMessage, CaptureTarget,
+};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, busy)]
+        #[qproperty(QString, last_kind)]
+        #[qproperty(QString, last_title)]
+        #[qproperty(QString, last_url)]
+        #[qproperty(QString, error_message)]
+        type CaptureModel = super::CaptureModelRust;
+
+        /// Parse and route one line of text. Returns false if it could not be parsed.
+        #[qinvokable]
+        fn quick_capture(self: Pin<&mut CaptureModel>, text: &QString) -> bool;
+
+        /// Describe where text would go, as JSON ({"kind", "title", ...} or {"error"})
+        #[qinvokable]
+        fn preview(self: &CaptureModel, text: &QString) -> QString;
+
+        /// Poll for async operation results. Call this from a QML Timer.
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut CaptureModel>);
+
+        #[qsignal]
+        fn captured(self: Pin<&mut CaptureModel>);
+
+        #[qsignal]
+        fn error_occurred(self: Pin<&mut CaptureModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct CaptureModelRust {
+    busy: bool,
+    /// "note", "issue" or "event" for the last successful capture
+    last_kind: QString,
+    last_title: QString,
+    last_url: QString,
+    error_message: QString,
+}
+
+/// Resolve only the client the target needs, so e.g. notes work without Google sign-in.
+fn clients_for(target: &CaptureTarget) -> CaptureClients {
+    match target {
+        CaptureTarget::Note { .. } => {
+            CaptureClients { notes: bridge::get_note_client_or_init(), ..Default::default() }
+        }
+        CaptureTarget::Issue { .. } => CaptureClients {
+            github: bridge::get_github_client_and_runtime().map(|(client, _)| client),
+            ..Default::default()
+        },
+        CaptureTarget::Event { .. } => {
+            CaptureClients { google_token: get_google_access_token(), ..Default::default() }
+        }
+        CaptureTarget::Bookmark { .. } => {
+            CaptureClients { bookmarks: bridge::get_bookmark_store_or_init(), ..Default::default() }
+        }
+    }
+}
+
+/// Parse relative to now in the calendar display zone, so "tomorrow 9am"
+/// means 9am on the clock the calendar shows.
+fn parse_in_zone(text: &str, zone: &DisplayZone) -> Result<CaptureTarget, CaptureParseError> {
+    match zone {
+        DisplayZone::Local => parse_capture(text, &Local::now()),
+        DisplayZone::Named(tz) => parse_capture(text, &Utc::now().with_timezone(tz)),
+    }
+}
+
+impl qobject::CaptureModel {
+    pub fn quick_capture(mut self: Pin<&mut Self>, text: &QString) -> bool {
+        if self.rust().busy {
+            tracing::warn!("quick_capture: capture already in progress");
+            return false;
+        }
+
+        let target = match parse_in_zone(&text.to_string(), &calendar_display_zone()) {
+            Ok(target) => target,
+            Err(e) => {
+                self.as_mut().set_error_message(QString::from(e.to_string()));
+                self.as_mut().error_occurred();
+                return false;
+            }
+        };
+
+        bridge::init_capture_service_channel();
+        let Some(tx) = bridge::get_capture_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            self.as_mut().error_occurred();
+            return false;
+        };
+
+        let clients = clients_for(&target);
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_busy(true);
+        request_capture(&tx, target, clients);
+        true
+    }
+
+    pub fn preview(&self, text: &QString) -> QString {
+        let zone = calendar_display_zone();
+        let json = match parse_in_zone(&text.to_string(), &zone) {
+            Ok(CaptureTarget::Note { content }) => {
+                serde_json::json!({ "kind": "note", "title": content })
+            }
+            Ok(CaptureTarget::Issue { owner, repo, title, .. }) => {
+                let repo = format!("{}/{}", owner, repo);
+                serde_json::json!({ "kind": "issue", "title": title, "repo": repo })
+            }
+            Ok(CaptureTarget::Event { title, start, end, attendees }) => serde_json::json!({
+                "kind": "event",
+                "title": title,
+                "start": zone.format(start, "%a %b %-d %H:%M"),
+                "end": zone.format(end, "%H:%M"),
+                "attendees": attendees,
+            }),
+            Ok(CaptureTarget::Bookmark { url, tags }) => {
+                serde_json::json!({ "kind": "bookmark", "title": url, "tags": tags })
+            }
+            Err(e) => serde_json::json!({ "error": e.to_string() }),
+        };
+        QString::from(json.to_string())
+    }
+
+    /// Poll for async operation results. Call this from a QML Timer (e.g., every 100ms).
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let Some(CaptureServiceMessage::CaptureDone(result)) = bridge::try_recv_capture_message()
+        else {
+            return;
+        };
+
+        self.as_mut().set_busy(false);
+        match result {
+            Ok(outcome) => {
+                tracing::info!("Captured {}: {}", outcome.kind, outcome.title);
+                self.as_mut().set_last_kind(QString::from(outcome.kind));
+                self.as_mut().set_last_title(QString::from(&outcome.title));
+                self.as_mut().set_last_url(QString::from(outcome.url.as_deref().unwrap_or("")));
+                self.as_mut().captured();
+            }
+            Err(e) => {
+                tracing::error!("Quick capture failed: {}", e);
+                // "GitHub is not connected" is more useful than the generic service message
+                let msg = match &e {
+                    CaptureError::Unavailable(_) => e.to_string(),
+                    _ => myme_core::AppError::from(e).user_message().to_string(),
+                };
+                self.as_mut().set_error_message(QString::from(&msg));
+                self.as_mut().error_occurred();
+            }
+        }
+    }
+}
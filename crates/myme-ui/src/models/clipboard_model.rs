@@ -0,0 +1,228 @@
+//! Clipboard history model for QML.
+//!
+//! QML watches the clipboard while `enabled` is set and passes new text to
+//! `record`; which copies are kept is decided by the clipboard service, off the
+//! UI thread, and the result arrives through `poll_channel`.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::{ClipEntry, ClipboardHistory};
+
+use crate::bridge;
+use crate::services::{
+    request_clipboard_record, ClipboardError, ClipboardRecordOutcome, ClipboardServiceMessage,
+};
+
+/// Most entries returned by one `get_entries` call
+const LIST_LIMIT: usize = 500;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, enabled)]
+        #[qproperty(i32, count)]
+        #[qproperty(QString, error_message)]
+        type ClipboardModel = super::ClipboardModelRust;
+
+        /// Reload the entry count (and, the first time, the enabled setting)
+        #[qinvokable]
+        fn reload(self: Pin<&mut ClipboardModel>);
+
+        /// Turn history on or off and save the setting. Turning it off keeps
+        /// existing entries until they are cleared.
+        #[qinvokable]
+        fn set_history_enabled(self: Pin<&mut ClipboardModel>, enabled: bool);
+
+        /// Record newly copied text in the background; `entries_changed`
+        /// follows if it was kept
+        #[qinvokable]
+        fn record(self: Pin<&mut ClipboardModel>, text: &QString);
+
+        /// Poll for recording results. Call this from a QML Timer.
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut ClipboardModel>);
+
+        /// Entries matching `query` as a JSON array, pinned first
+        #[qinvokable]
+        fn get_entries(self: &ClipboardModel, query: &QString) -> QString;
+
+        #[qinvokable]
+        fn set_pinned(self: Pin<&mut ClipboardModel>, id: i64, pinned: bool);
+
+        #[qinvokable]
+        fn delete_entry(self: Pin<&mut ClipboardModel>, id: i64);
+
+        /// Remove every entry that is not pinned
+        #[qinvokable]
+        fn clear_unpinned(self: Pin<&mut ClipboardModel>);
+
+        #[qsignal]
+        fn entries_changed(self: Pin<&mut ClipboardModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct ClipboardModelRust {
+    enabled: bool,
+    count: i32,
+    error_message: QString,
+    /// The enabled setting has been read from config
+    loaded: bool,
+}
+
+fn entry_json(entry: &ClipEntry) -> serde_json::Value {
+    serde_json::json!({
+        "id": entry.id,
+        "text": entry.text,
+        "pinned": entry.pinned,
+        "createdAt": entry.created_at.to_rfc3339(),
+        "lastUsedAt": entry.last_used_at.to_rfc3339(),
+    })
+}
+
+fn history() -> Result<std::sync::Arc<ClipboardHistory>, ClipboardError> {
+    bridge::get_clipboard_history_or_init().ok_or(ClipboardError::NotInitialized)
+}
+
+impl qobject::ClipboardModel {
+    fn show_error(mut self: Pin<&mut Self>, e: ClipboardError) {
+        let msg = myme_core::AppError::from(e).user_message().to_string();
+        self.as_mut().set_error_message(QString::from(&msg));
+    }
+
+    pub fn reload(mut self: Pin<&mut Self>) {
+        if !self.rust().loaded {
+            self.as_mut().rust_mut().loaded = true;
+            let enabled = myme_core::Config::load_cached().clipboard.enabled;
+            self.as_mut().set_enabled(enabled);
+        }
+        if !*self.enabled() && self.rust().count == 0 {
+            // Don't prompt for the keyring until history is used
+            return;
+        }
+
+        let count = history().and_then(|history| {
+            history.list("", usize::MAX).map_err(|e| ClipboardError::Store(e.to_string()))
+        });
+        match count {
+            Ok(entries) => self.as_mut().set_count(entries.len() as i32),
+            Err(e) => self.as_mut().show_error(e),
+        }
+        self.as_mut().entries_changed();
+    }
+
+    pub fn set_history_enabled(mut self: Pin<&mut Self>, enabled: bool) {
+        if enabled {
+            if let Err(e) = history() {
+                self.as_mut().show_error(e);
+                return;
+            }
+        }
+
+        let mut config = match myme_core::Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::warn!("Failed to load config: {}", e);
+                let msg = myme_core::AppError::from(e).user_message().to_string();
+                self.as_mut().set_error_message(QString::from(&msg));
+                return;
+            }
+        };
+        config.clipboard.enabled = enabled;
+        if let Err(e) = config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+            let msg = myme_core::AppError::from(e).user_message().to_string();
+            self.as_mut().set_error_message(QString::from(&msg));
+            return;
+        }
+
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_enabled(enabled);
+        self.reload();
+    }
+
+    pub fn record(self: Pin<&mut Self>, text: &QString) {
+        if !*self.enabled() {
+            return;
+        }
+        bridge::init_clipboard_service_channel();
+        if let Some(tx) = bridge::get_clipboard_service_tx() {
+            request_clipboard_record(&tx, text.to_string());
+        }
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        while let Some(message) = bridge::try_recv_clipboard_message() {
+            match message {
+                ClipboardServiceMessage::RecordDone(Ok(ClipboardRecordOutcome::Stored {
+                    count,
+                })) => {
+                    self.as_mut().set_error_message(QString::from(""));
+                    self.as_mut().set_count(count as i32);
+                    self.as_mut().entries_changed();
+                }
+                ClipboardServiceMessage::RecordDone(Ok(ClipboardRecordOutcome::Skipped)) => {}
+                ClipboardServiceMessage::RecordDone(Ok(ClipboardRecordOutcome::SourceUnknown)) => {
+                    self.as_mut().set_error_message(QString::from(
+                        "Copies can't be matched to an app here, so none are recorded while \
+                         apps are excluded. Clear Excluded apps in Settings to record them.",
+                    ));
+                }
+                ClipboardServiceMessage::RecordDone(Err(e)) => {
+                    tracing::warn!("Failed to record clipboard entry: {}", e);
+                    self.as_mut().show_error(e);
+                }
+            }
+        }
+    }
+
+    pub fn get_entries(&self, query: &QString) -> QString {
+        let rows: Vec<serde_json::Value> = history()
+            .ok()
+            .and_then(|history| history.list(&query.to_string(), LIST_LIMIT).ok())
+            .unwrap_or_default()
+            .iter()
+            .map(entry_json)
+            .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn set_pinned(self: Pin<&mut Self>, id: i64, pinned: bool) {
+        let result = history().and_then(|history| {
+            history.set_pinned(id, pinned).map_err(|e| ClipboardError::Store(e.to_string()))
+        });
+        if let Err(e) = result {
+            tracing::warn!("Failed to pin clipboard entry: {}", e);
+        }
+        self.reload();
+    }
+
+    pub fn delete_entry(self: Pin<&mut Self>, id: i64) {
+        let result = history().and_then(|history| {
+            history.delete(id).map_err(|e| ClipboardError::Store(e.to_string()))
+        });
+        if let Err(e) = result {
+            tracing::warn!("Failed to delete clipboard entry: {}", e);
+        }
+        self.reload();
+    }
+
+    pub fn clear_unpinned(self: Pin<&mut Self>) {
+        let result = history().and_then(|history| {
+            history.clear_unpinned().map_err(|e| ClipboardError::Store(e.to_string()))
+        });
+        if let Err(e) = result {
+            tracing::warn!("Failed to clear clipboard history: {}", e);
+        }
+        self.reload();
+    }
+}
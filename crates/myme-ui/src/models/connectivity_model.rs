@@ -0,0 +1,50 @@
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use myme_core::connectivity;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, offline)]
+        type ConnectivityModel = super::ConnectivityModelRust;
+
+        /// Pick up the monitor's current state. Call this from a QML Timer.
+        #[qinvokable]
+        fn refresh(self: Pin<&mut ConnectivityModel>);
+
+        /// Probe now instead of waiting for the next interval (e.g. a "Retry" button)
+        #[qinvokable]
+        fn check_now(self: &ConnectivityModel);
+
+        #[qsignal]
+        fn connectivity_changed(self: Pin<&mut ConnectivityModel>);
+    }
+}
+
+pub struct ConnectivityModelRust {
+    offline: bool,
+}
+
+impl Default for ConnectivityModelRust {
+    fn default() -> Self {
+        Self { offline: !connectivity::is_online() }
+    }
+}
+
+impl qobject::ConnectivityModel {
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        let offline = !connectivity::is_online();
+        if offline == self.rust().offline {
+            return;
+        }
+        self.as_mut().set_offline(offline);
+        self.as_mut().connectivity_changed();
+    }
+
+    pub fn check_now(&self) {
+        connectivity::global().request_probe();
+    }
+}
@@ -0,0 +1,145 @@
+//! Contacts model for QML.
+//!
+//! Unified people list from Gmail senders, calendar attendees and GitHub
+//! collaborators, with search and attendee autocomplete.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_contacts::Contact;
+
+use crate::bridge;
+use crate::services::{request_contacts_refresh, ContactsServiceMessage};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(i32, count)]
+        #[qproperty(QString, error_message)]
+        type ContactsModel = super::ContactsModelRust;
+
+        /// Rebuild the list from local caches and GitHub collaborators
+        #[qinvokable]
+        fn refresh(self: Pin<&mut ContactsModel>);
+
+        /// Contacts matching `query` (all when empty), most recent first, as a JSON array
+        #[qinvokable]
+        fn get_contacts(self: &ContactsModel, query: &QString) -> QString;
+
+        /// Up to 8 contacts with an email matching `query`, for attendee autocomplete
+        #[qinvokable]
+        fn suggest_attendees(self: &ContactsModel, query: &QString) -> QString;
+
+        /// Poll for async operation results. Call this from a QML Timer.
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut ContactsModel>);
+
+        #[qsignal]
+        fn contacts_changed(self: Pin<&mut ContactsModel>);
+    }
+}
+
+/// Rows returned by `get_contacts`
+const MAX_RESULTS: usize = 200;
+
+/// Rows returned by `suggest_attendees`
+const MAX_SUGGESTIONS: usize = 8;
+
+#[derive(Default)]
+pub struct ContactsModelRust {
+    loading: bool,
+    count: i32,
+    error_message: QString,
+    contacts: Vec<Contact>,
+}
+
+fn contact_json(contact: &Contact) -> serde_json::Value {
+    serde_json::json!({
+        "email": contact.email,
+        "name": contact.name,
+        "displayName": contact.display_name(),
+        "githubLogin": contact.github_login,
+        "avatarUrl": contact.avatar_url,
+        "sources": contact.sources,
+        "lastInteraction": contact.last_interaction.map(|t| t.to_rfc3339()),
+    })
+}
+
+impl qobject::ContactsModel {
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        if self.rust().loading {
+            return;
+        }
+
+        bridge::init_contacts_service_channel();
+        let Some(tx) = bridge::get_contacts_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            return;
+        };
+
+        let github = bridge::get_github_client_and_runtime().map(|(client, _)| client);
+        let repo_ids = bridge::get_project_store_or_init()
+            .and_then(|store| store.list_all_linked_repo_ids().ok())
+            .unwrap_or_default();
+
+        self.as_mut().set_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+        request_contacts_refresh(&tx, github, repo_ids);
+    }
+
+    pub fn get_contacts(&self, query: &QString) -> QString {
+        let query = query.to_string();
+        let rows: Vec<serde_json::Value> =
+            myme_contacts::search(&self.rust().contacts, &query, MAX_RESULTS)
+                .into_iter()
+                .map(contact_json)
+                .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn suggest_attendees(&self, query: &QString) -> QString {
+        let query = query.to_string();
+        if query.trim().is_empty() {
+            return QString::from("[]");
+        }
+        let rows: Vec<serde_json::Value> =
+            myme_contacts::search(&self.rust().contacts, &query, usize::MAX)
+                .into_iter()
+                .filter(|c| c.email.is_some())
+                .take(MAX_SUGGESTIONS)
+                .map(contact_json)
+                .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    /// Poll for async operation results. Call this from a QML Timer (e.g., every 100ms).
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let Some(ContactsServiceMessage::RefreshDone(result)) = bridge::try_recv_contacts_message()
+        else {
+            return;
+        };
+
+        self.as_mut().set_loading(false);
+        match result {
+            Ok(contacts) => {
+                tracing::info!("Contacts refreshed: {} people", contacts.len());
+                self.as_mut().set_count(contacts.len() as i32);
+                self.as_mut().rust_mut().contacts = contacts;
+                self.as_mut().contacts_changed();
+            }
+            Err(e) => {
+                let msg = myme_core::AppError::from(e).user_message().to_string();
+                self.as_mut().set_error_message(QString::from(&msg));
+            }
+        }
+    }
+}
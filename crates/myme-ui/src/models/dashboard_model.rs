@@ -0,0 +1,124 @@
+//! The dashboard snapshot for QML.
+//!
+//! `get_snapshot()` gives what the dashboard showed last time, read from disk
+//! before services start. WelcomePage shows it in place of each widget's live
+//! data until that arrives.
+
+use core::pin::Pin;
+
+use chrono::{DateTime, Local, Utc};
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_core::datefmt;
+use myme_core::DashboardSnapshot;
+
+use crate::bridge;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, has_snapshot)]
+        #[qproperty(QString, updated_label)]
+        type DashboardModel = super::DashboardModelRust;
+
+        /// JSON: `weather` (or null), `todayEventCount`, `nextEventSummary`,
+        /// `nextEventTime`, `notes` and `activity` shaped like the live feed
+        #[qinvokable]
+        fn get_snapshot(self: &DashboardModel) -> QString;
+
+        /// Re-read the store; emits `snapshot_changed` when models recorded
+        /// something new
+        #[qinvokable]
+        fn refresh(self: Pin<&mut DashboardModel>);
+
+        #[qsignal]
+        fn snapshot_changed(self: Pin<&mut DashboardModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct DashboardModelRust {
+    has_snapshot: bool,
+    updated_label: QString,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl qobject::DashboardModel {
+    pub fn get_snapshot(&self) -> QString {
+        let json = bridge::get_dashboard_store()
+            .map(|store| snapshot_json(store.lock().snapshot(), Utc::now()))
+            .unwrap_or_else(|| serde_json::json!({}));
+        QString::from(&json.to_string())
+    }
+
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        let Some((updated_at, empty)) = bridge::get_dashboard_store().map(|store| {
+            let store = store.lock();
+            (store.snapshot().updated_at, store.snapshot().is_empty())
+        }) else {
+            return;
+        };
+        let label = updated_at.map(datefmt::relative).unwrap_or_default();
+        self.as_mut().set_updated_label(QString::from(&label));
+        self.as_mut().set_has_snapshot(!empty);
+        if updated_at != self.rust().updated_at {
+            self.as_mut().rust_mut().updated_at = updated_at;
+            self.as_mut().snapshot_changed();
+        }
+    }
+}
+
+fn snapshot_json(snapshot: &DashboardSnapshot, now: DateTime<Utc>) -> serde_json::Value {
+    let today = now.with_timezone(&Local).date_naive();
+    let weather = snapshot.weather.as_ref().map(|w| {
+        serde_json::json!({
+            "temperature": w.temperature,
+            "feelsLike": w.feels_like,
+            "humidity": w.humidity,
+            "windSpeed": w.wind_speed,
+            "condition": w.condition,
+            "conditionIcon": w.icon,
+            "locationName": w.location,
+            "todayHigh": w.high,
+            "todayLow": w.low,
+            "precipChance": w.precipitation_chance,
+            "sunrise": w.sunrise,
+            "sunset": w.sunset,
+        })
+    });
+    let next = snapshot.next_event(now);
+    let notes: Vec<serde_json::Value> = snapshot
+        .notes
+        .iter()
+        .map(|n| serde_json::json!({ "id": n.id, "title": n.title, "pinned": n.pinned }))
+        .collect();
+    let activity: Vec<serde_json::Value> = snapshot
+        .commits
+        .iter()
+        .map(|c| {
+            serde_json::json!({
+                "repo": c.repo,
+                "message": c.message,
+                "author": c.author,
+                "when": datefmt::relative(c.time),
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "weather": weather,
+        "todayEventCount": snapshot.events_on(today).count(),
+        "nextEventSummary": next
+            .map(|e| if e.summary.is_empty() { "(No title)" } else { e.summary.as_str() })
+            .unwrap_or_default(),
+        "nextEventTime": next.map(|e| datefmt::moment(e.start)).unwrap_or_default(),
+        "notes": notes,
+        "activity": activity,
+    })
+}
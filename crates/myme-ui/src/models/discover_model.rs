@@ -0,0 +1,201 @@
+//! Discover model for QML: starred repositories and a feed of their new
+//! releases and activity.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+
+use crate::bridge;
+use crate::services::{
+    request_discover_feed, request_discover_star, DiscoverFeed, DiscoverServiceMessage,
+};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(bool, authenticated)]
+        #[qproperty(QString, error_message)]
+        type DiscoverModel = super::DiscoverModelRust;
+
+        #[qinvokable]
+        fn check_auth(self: Pin<&mut DiscoverModel>);
+
+        /// Load starred repos and the feed, from the cache when fresh
+        #[qinvokable]
+        fn load(self: Pin<&mut DiscoverModel>);
+
+        /// Build the feed again, bypassing the cache
+        #[qinvokable]
+        fn refresh(self: Pin<&mut DiscoverModel>);
+
+        /// Star (`starred` true) or unstar `full_name`; emits `star_changed`
+        #[qinvokable]
+        fn set_starred(self: Pin<&mut DiscoverModel>, full_name: &QString, starred: bool);
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut DiscoverModel>);
+
+        /// Feed as a JSON array of `{kind, repo, title, url, at, dateLabel, stars}`,
+        /// where `kind` is "release" or "active"
+        #[qinvokable]
+        fn get_feed(self: &DiscoverModel) -> QString;
+
+        /// Starred repos as a JSON array of
+        /// `{fullName, description, htmlUrl, stars, pushedAt}`
+        #[qinvokable]
+        fn get_starred(self: &DiscoverModel) -> QString;
+
+        #[qsignal]
+        fn feed_changed(self: Pin<&mut DiscoverModel>);
+
+        #[qsignal]
+        fn star_changed(self: Pin<&mut DiscoverModel>, full_name: QString, starred: bool);
+    }
+}
+
+#[derive(Default)]
+pub struct DiscoverModelRust {
+    loading: bool,
+    authenticated: bool,
+    error_message: QString,
+    feed: Option<DiscoverFeed>,
+    /// Requests sent but not yet answered
+    pending: u32,
+}
+
+impl qobject::DiscoverModel {
+    pub fn check_auth(mut self: Pin<&mut Self>) {
+        let auth = bridge::get_github_client_and_runtime().is_some();
+        if self.as_ref().rust().authenticated != auth {
+            self.as_mut().set_authenticated(auth);
+        }
+    }
+
+    /// Client and channel for a request, flagging the model when either is missing
+    fn begin_request(
+        mut self: Pin<&mut Self>,
+    ) -> Option<(
+        std::sync::Arc<myme_services::GitHubClient>,
+        std::sync::mpsc::Sender<DiscoverServiceMessage>,
+    )> {
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            self.as_mut().set_authenticated(false);
+            return None;
+        };
+        bridge::init_discover_service_channel();
+        let Some(tx) = bridge::get_discover_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Discover service channel not ready"));
+            return None;
+        };
+        self.as_mut().rust_mut().pending += 1;
+        self.as_mut().set_loading(true);
+        Some((client, tx))
+    }
+
+    pub fn load(mut self: Pin<&mut Self>) {
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_discover_feed(&tx, client, false);
+        }
+    }
+
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        if self.rust().loading {
+            return;
+        }
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_discover_feed(&tx, client, true);
+        }
+    }
+
+    pub fn set_starred(mut self: Pin<&mut Self>, full_name: &QString, starred: bool) {
+        let full_name = full_name.to_string();
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_discover_star(&tx, client, full_name, starred);
+        }
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        while let Some(msg) = bridge::try_recv_discover_message() {
+            let pending = self.rust().pending.saturating_sub(1);
+            self.as_mut().rust_mut().pending = pending;
+            if pending == 0 {
+                self.as_mut().set_loading(false);
+            }
+
+            match msg {
+                DiscoverServiceMessage::FeedDone(Ok(feed)) => {
+                    self.as_mut().set_error_message(QString::from(""));
+                    self.as_mut().rust_mut().feed = Some(feed);
+                    self.as_mut().feed_changed();
+                }
+                DiscoverServiceMessage::StarDone(Ok((full_name, starred))) => {
+                    self.as_mut().set_error_message(QString::from(""));
+                    // Unstarred repos drop out of the list right away; new stars
+                    // show up with the next feed
+                    if !starred {
+                        if let Some(feed) = self.as_mut().rust_mut().feed.as_mut() {
+                            feed.starred.retain(|r| !r.full_name.eq_ignore_ascii_case(&full_name));
+                            feed.items.retain(|i| !i.repo.eq_ignore_ascii_case(&full_name));
+                        }
+                        self.as_mut().feed_changed();
+                    }
+                    self.as_mut().star_changed(QString::from(&full_name), starred);
+                }
+                DiscoverServiceMessage::FeedDone(Err(e))
+                | DiscoverServiceMessage::StarDone(Err(e)) => {
+                    let text = myme_core::AppError::from(e).user_message();
+                    self.as_mut().set_error_message(QString::from(text));
+                }
+            }
+        }
+    }
+
+    pub fn get_feed(&self) -> QString {
+        let items: Vec<serde_json::Value> = self
+            .rust()
+            .feed
+            .iter()
+            .flat_map(|f| f.items.iter())
+            .map(|item| {
+                serde_json::json!({
+                    "kind": item.kind,
+                    "repo": item.repo,
+                    "title": item.title,
+                    "url": item.url,
+                    "at": item.at.to_rfc3339(),
+                    "dateLabel": item.at.with_timezone(&chrono::Local).format("%b %-d").to_string(),
+                    "stars": item.stars,
+                })
+            })
+            .collect();
+        QString::from(serde_json::to_string(&items).unwrap_or_else(|_| "[]".into()))
+    }
+
+    pub fn get_starred(&self) -> QString {
+        let repos: Vec<serde_json::Value> = self
+            .rust()
+            .feed
+            .iter()
+            .flat_map(|f| f.starred.iter())
+            .map(|repo| {
+                serde_json::json!({
+                    "fullName": repo.full_name,
+                    "description": repo.description,
+                    "htmlUrl": repo.html_url,
+                    "stars": repo.stargazers_count,
+                    "pushedAt": repo.pushed_at,
+                })
+            })
+            .collect();
+        QString::from(serde_json::to_string(&repos).unwrap_or_else(|_| "[]".into()))
+    }
+}
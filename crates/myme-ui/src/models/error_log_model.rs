@@ -0,0 +1,98 @@
+use core::pin::Pin;
+
+use chrono::{Local, TimeZone};
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_core::error_log;
+
+/// Entries included in copied diagnostics
+const DIAGNOSTICS_LIMIT: usize = 50;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, count)]
+        type ErrorLogModel = super::ErrorLogModelRust;
+
+        /// Re-read the error log; emits entries_changed if it changed
+        #[qinvokable]
+        fn refresh(self: Pin<&mut ErrorLogModel>);
+
+        /// Recent problems as a JSON array, newest first
+        #[qinvokable]
+        fn get_entries(self: &ErrorLogModel, limit: i32) -> QString;
+
+        /// Plain-text report for pasting into a bug report
+        #[qinvokable]
+        fn get_diagnostics(self: &ErrorLogModel) -> QString;
+
+        #[qinvokable]
+        fn clear(self: Pin<&mut ErrorLogModel>);
+
+        #[qsignal]
+        fn entries_changed(self: Pin<&mut ErrorLogModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct ErrorLogModelRust {
+    count: i32,
+    /// Timestamp of the newest entry seen, to detect new errors on refresh
+    newest: Option<u64>,
+}
+
+fn format_timestamp(secs: u64) -> String {
+    Local
+        .timestamp_opt(secs as i64, 0)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+impl qobject::ErrorLogModel {
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        let log = error_log::global();
+        let count = log.len() as i32;
+        let newest = log.recent(1).first().map(|r| r.timestamp);
+        if count == self.rust().count && newest == self.rust().newest {
+            return;
+        }
+        self.as_mut().rust_mut().newest = newest;
+        self.as_mut().set_count(count);
+        self.as_mut().entries_changed();
+    }
+
+    pub fn get_entries(&self, limit: i32) -> QString {
+        let entries: Vec<serde_json::Value> = error_log::global()
+            .recent(limit.max(0) as usize)
+            .into_iter()
+            .map(|r| {
+                serde_json::json!({
+                    "time": format_timestamp(r.timestamp),
+                    "service": r.service,
+                    "message": r.user_message,
+                    "detail": r.detail,
+                })
+            })
+            .collect();
+        QString::from(serde_json::to_string(&entries).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    pub fn get_diagnostics(&self) -> QString {
+        QString::from(error_log::global().diagnostics(DIAGNOSTICS_LIMIT))
+    }
+
+    pub fn clear(mut self: Pin<&mut Self>) {
+        error_log::global().clear();
+        self.as_mut().rust_mut().newest = None;
+        self.as_mut().set_count(0);
+        self.as_mut().entries_changed();
+    }
+}
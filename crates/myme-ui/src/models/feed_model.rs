@@ -0,0 +1,247 @@
+//! Feed reader model for QML.
+//!
+//! Subscriptions and items come from the local feed cache; refreshes and new
+//! subscriptions run in the background and report back through the channel.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_feeds::{Feed, FeedItem};
+
+use crate::bridge;
+use crate::services::{
+    open_feed_cache, request_feed_refresh, request_feed_subscribe, FeedError, FeedServiceMessage,
+};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(i32, feed_count)]
+        #[qproperty(i32, unread_count)]
+        #[qproperty(QString, error_message)]
+        type FeedModel = super::FeedModelRust;
+
+        /// Reload counts from the local cache
+        #[qinvokable]
+        fn reload(self: Pin<&mut FeedModel>);
+
+        /// Fetch all subscriptions in the background
+        #[qinvokable]
+        fn refresh(self: Pin<&mut FeedModel>);
+
+        /// Subscribe to a feed URL; it is fetched once to check it is a feed
+        #[qinvokable]
+        fn subscribe(self: Pin<&mut FeedModel>, url: &QString);
+
+        #[qinvokable]
+        fn unsubscribe(self: Pin<&mut FeedModel>, url: &QString);
+
+        /// Subscriptions with unread counts and last errors, as a JSON array
+        #[qinvokable]
+        fn get_feeds(self: &FeedModel) -> QString;
+
+        /// Items newest first as a JSON array; an empty `feed_url` means all feeds
+        #[qinvokable]
+        fn get_items(
+            self: &FeedModel,
+            feed_url: &QString,
+            unread_only: bool,
+            limit: i32,
+        ) -> QString;
+
+        #[qinvokable]
+        fn set_read(self: Pin<&mut FeedModel>, feed_url: &QString, id: &QString, read: bool);
+
+        /// Mark everything read; an empty `feed_url` means all feeds
+        #[qinvokable]
+        fn mark_all_read(self: Pin<&mut FeedModel>, feed_url: &QString);
+
+        /// Poll for async operation results. Call this from a QML Timer.
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut FeedModel>);
+
+        #[qsignal]
+        fn feeds_changed(self: Pin<&mut FeedModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct FeedModelRust {
+    loading: bool,
+    feed_count: i32,
+    unread_count: i32,
+    error_message: QString,
+}
+
+fn feed_json(feed: &Feed) -> serde_json::Value {
+    serde_json::json!({
+        "url": feed.url,
+        "title": feed.title,
+        "siteUrl": feed.site_url,
+        "lastFetched": feed.last_fetched.map(|t| t.to_rfc3339()),
+        "lastError": feed.last_error,
+        "unreadCount": feed.unread_count,
+    })
+}
+
+fn item_json(item: &FeedItem) -> serde_json::Value {
+    serde_json::json!({
+        "id": item.id,
+        "feedUrl": item.feed_url,
+        "title": item.title,
+        "link": item.link,
+        "summary": item.summary,
+        "author": item.author,
+        "published": item.published.to_rfc3339(),
+        "isRead": item.is_read,
+    })
+}
+
+/// `None` for an empty string, meaning "all feeds"
+fn feed_filter(feed_url: &QString) -> Option<String> {
+    Some(feed_url.to_string()).filter(|url| !url.is_empty())
+}
+
+impl qobject::FeedModel {
+    pub fn reload(mut self: Pin<&mut Self>) {
+        let counts = open_feed_cache().and_then(|cache| {
+            let feeds = cache.list_feeds().map_err(|e| FeedError::Cache(e.to_string()))?;
+            let unread = cache.unread_count().map_err(|e| FeedError::Cache(e.to_string()))?;
+            Ok((feeds.len(), unread))
+        });
+        match counts {
+            Ok((feeds, unread)) => {
+                self.as_mut().set_feed_count(feeds as i32);
+                self.as_mut().set_unread_count(unread as i32);
+            }
+            Err(e) => {
+                let msg = myme_core::AppError::from(e).user_message().to_string();
+                self.as_mut().set_error_message(QString::from(&msg));
+            }
+        }
+        self.as_mut().feeds_changed();
+    }
+
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        if self.rust().loading {
+            return;
+        }
+
+        bridge::init_feed_service_channel();
+        let Some(tx) = bridge::get_feed_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            return;
+        };
+
+        self.as_mut().set_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+        request_feed_refresh(&tx);
+    }
+
+    pub fn subscribe(mut self: Pin<&mut Self>, url: &QString) {
+        let url = url.to_string();
+        if url.trim().is_empty() {
+            return;
+        }
+
+        bridge::init_feed_service_channel();
+        let Some(tx) = bridge::get_feed_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            return;
+        };
+
+        self.as_mut().set_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+        request_feed_subscribe(&tx, url);
+    }
+
+    pub fn unsubscribe(mut self: Pin<&mut Self>, url: &QString) {
+        let result = open_feed_cache().and_then(|cache| {
+            cache.remove_feed(&url.to_string()).map_err(|e| FeedError::Cache(e.to_string()))
+        });
+        if let Err(e) = result {
+            let msg = myme_core::AppError::from(e).user_message().to_string();
+            self.as_mut().set_error_message(QString::from(&msg));
+        }
+        self.reload();
+    }
+
+    pub fn get_feeds(&self) -> QString {
+        let rows: Vec<serde_json::Value> = open_feed_cache()
+            .ok()
+            .and_then(|cache| cache.list_feeds().ok())
+            .unwrap_or_default()
+            .iter()
+            .map(feed_json)
+            .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn get_items(&self, feed_url: &QString, unread_only: bool, limit: i32) -> QString {
+        let feed_url = feed_filter(feed_url);
+        let rows: Vec<serde_json::Value> = open_feed_cache()
+            .ok()
+            .and_then(|cache| {
+                cache.list_items(feed_url.as_deref(), unread_only, limit.max(0) as u32).ok()
+            })
+            .unwrap_or_default()
+            .iter()
+            .map(item_json)
+            .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn set_read(self: Pin<&mut Self>, feed_url: &QString, id: &QString, read: bool) {
+        let result = open_feed_cache().and_then(|cache| {
+            cache
+                .set_read(&feed_url.to_string(), &id.to_string(), read)
+                .map_err(|e| FeedError::Cache(e.to_string()))
+        });
+        if let Err(e) = result {
+            tracing::warn!("Failed to update feed item: {}", e);
+        }
+        self.reload();
+    }
+
+    pub fn mark_all_read(self: Pin<&mut Self>, feed_url: &QString) {
+        let feed_url = feed_filter(feed_url);
+        let result = open_feed_cache().and_then(|cache| {
+            cache.mark_all_read(feed_url.as_deref()).map_err(|e| FeedError::Cache(e.to_string()))
+        });
+        if let Err(e) = result {
+            tracing::warn!("Failed to mark feed items read: {}", e);
+        }
+        self.reload();
+    }
+
+    /// Poll for async operation results. Call this from a QML Timer (e.g., every 100ms).
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let Some(message) = bridge::try_recv_feed_message() else {
+            return;
+        };
+
+        self.as_mut().set_loading(false);
+        match message {
+            FeedServiceMessage::RefreshDone(Ok(new_items)) => {
+                tracing::info!("Feeds refreshed: {} new items", new_items);
+            }
+            FeedServiceMessage::SubscribeDone(Ok(url)) => {
+                tracing::info!("Subscribed to feed {}", url);
+            }
+            FeedServiceMessage::RefreshDone(Err(e)) | FeedServiceMessage::SubscribeDone(Err(e)) => {
+                let msg = myme_core::AppError::from(e).user_message().to_string();
+                self.as_mut().set_error_message(QString::from(&msg));
+            }
+        }
+        self.reload();
+    }
+}
@@ -0,0 +1,198 @@
+use core::pin::Pin;
+
+use chrono::Utc;
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::{FocusKind, FocusSession};
+
+use crate::bridge;
+use crate::services::{notify_desktop, FocusService, FocusState};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, running)]
+        #[qproperty(bool, paused)]
+        #[qproperty(QString, phase)]
+        #[qproperty(i32, remaining_seconds)]
+        #[qproperty(QString, task_id)]
+        #[qproperty(i32, completed_pomodoros)]
+        type FocusModel = super::FocusModelRust;
+
+        /// Start a work interval, optionally linked to a kanban task ID (empty for none)
+        #[qinvokable]
+        fn start(self: Pin<&mut FocusModel>, task_id: &QString);
+
+        #[qinvokable]
+        fn pause(self: Pin<&mut FocusModel>);
+
+        #[qinvokable]
+        fn resume(self: Pin<&mut FocusModel>);
+
+        /// Abandon the current interval; the partial session is still recorded
+        #[qinvokable]
+        fn stop(self: Pin<&mut FocusModel>);
+
+        /// End the current interval early and move to the next phase
+        #[qinvokable]
+        fn skip(self: Pin<&mut FocusModel>);
+
+        /// Advance the timer. Call this from a QML Timer (e.g., every second).
+        #[qinvokable]
+        fn tick(self: Pin<&mut FocusModel>);
+
+        /// Recent sessions as a JSON array, for one task or all tasks (empty ID)
+        #[qinvokable]
+        fn get_history(self: &FocusModel, task_id: &QString, limit: i32) -> QString;
+
+        /// Total focused minutes recorded for a task
+        #[qinvokable]
+        fn get_task_focus_minutes(self: &FocusModel, task_id: &QString) -> i32;
+
+        #[qsignal]
+        fn session_finished(self: Pin<&mut FocusModel>);
+    }
+}
+
+pub struct FocusModelRust {
+    running: bool,
+    paused: bool,
+    /// "work", "short_break" or "long_break"
+    phase: QString,
+    remaining_seconds: i32,
+    task_id: QString,
+    completed_pomodoros: i32,
+    timer: FocusService,
+}
+
+impl Default for FocusModelRust {
+    fn default() -> Self {
+        let timer = FocusService::default();
+        Self {
+            running: false,
+            paused: false,
+            phase: QString::from(timer.kind().as_str()),
+            remaining_seconds: timer.planned_secs() as i32,
+            task_id: QString::from(""),
+            completed_pomodoros: 0,
+            timer,
+        }
+    }
+}
+
+impl FocusModelRust {
+    /// Persist a session in the project store; zero-length sessions are dropped
+    fn record(session: &FocusSession) {
+        if session.focused_secs <= 0 {
+            return;
+        }
+        let Some(store) = bridge::get_project_store_or_init() else {
+            tracing::warn!("Project store unavailable; focus session not saved");
+            return;
+        };
+        if let Err(e) = store.insert_focus_session(session) {
+            tracing::warn!("Failed to save focus session: {}", e);
+        }
+    }
+}
+
+impl qobject::FocusModel {
+    /// Push timer state into the Qt properties
+    fn sync_properties(mut self: Pin<&mut Self>) {
+        let now = Utc::now();
+        let timer = &self.rust().timer;
+        let state = timer.state();
+        let phase = QString::from(timer.kind().as_str());
+        let remaining = timer.remaining_secs(now) as i32;
+        let task_id = QString::from(timer.task_id().unwrap_or(""));
+        let completed = timer.completed_work() as i32;
+
+        self.as_mut().set_running(state == FocusState::Running);
+        self.as_mut().set_paused(state == FocusState::Paused);
+        self.as_mut().set_phase(phase);
+        self.as_mut().set_remaining_seconds(remaining);
+        self.as_mut().set_task_id(task_id);
+        self.as_mut().set_completed_pomodoros(completed);
+    }
+
+    fn finish(mut self: Pin<&mut Self>, session: FocusSession) {
+        FocusModelRust::record(&session);
+
+        if session.completed {
+            let (title, body) = match session.kind {
+                FocusKind::Work => ("Focus session complete", "Time for a break."),
+                FocusKind::ShortBreak | FocusKind::LongBreak => {
+                    ("Break over", "Ready for the next focus session?")
+                }
+            };
+            notify_desktop(title, body);
+        }
+        self.as_mut().session_finished();
+    }
+
+    pub fn start(mut self: Pin<&mut Self>, task_id: &QString) {
+        let task_id = task_id.to_string();
+        let task_id = (!task_id.is_empty()).then_some(task_id);
+        if !self.as_mut().rust_mut().timer.start(task_id, Utc::now()) {
+            tracing::warn!("focus start: a session is already active");
+            return;
+        }
+        self.sync_properties();
+    }
+
+    pub fn pause(mut self: Pin<&mut Self>) {
+        if self.as_mut().rust_mut().timer.pause(Utc::now()) {
+            self.sync_properties();
+        }
+    }
+
+    pub fn resume(mut self: Pin<&mut Self>) {
+        if self.as_mut().rust_mut().timer.resume(Utc::now()) {
+            self.sync_properties();
+        }
+    }
+
+    pub fn stop(mut self: Pin<&mut Self>) {
+        if let Some(session) = self.as_mut().rust_mut().timer.stop(Utc::now()) {
+            FocusModelRust::record(&session);
+        }
+        self.sync_properties();
+    }
+
+    pub fn skip(mut self: Pin<&mut Self>) {
+        if let Some(session) = self.as_mut().rust_mut().timer.skip(Utc::now()) {
+            self.as_mut().finish(session);
+        }
+        self.sync_properties();
+    }
+
+    pub fn tick(mut self: Pin<&mut Self>) {
+        if let Some(session) = self.as_mut().rust_mut().timer.tick(Utc::now()) {
+            self.as_mut().finish(session);
+        }
+        self.sync_properties();
+    }
+
+    pub fn get_history(&self, task_id: &QString, limit: i32) -> QString {
+        let task_id = task_id.to_string();
+        let task_filter = (!task_id.is_empty()).then_some(task_id.as_str());
+        let sessions = bridge::get_project_store_or_init()
+            .and_then(|store| store.list_focus_sessions(task_filter, limit.max(0) as usize).ok())
+            .unwrap_or_default();
+        QString::from(serde_json::to_string(&sessions).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    pub fn get_task_focus_minutes(&self, task_id: &QString) -> i32 {
+        bridge::get_project_store_or_init()
+            .and_then(|store| store.focus_seconds_for_task(&task_id.to_string()).ok())
+            .map(|secs| (secs / 60) as i32)
+            .unwrap_or(0)
+    }
+}
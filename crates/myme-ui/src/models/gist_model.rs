@@ -0,0 +1,234 @@
+// crates/myme-ui/src/models/gist_model.rs
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::GitHubGist;
+
+use crate::bridge;
+use crate::services::{
+    request_gist_create, request_gist_fetch, request_gist_update, GistServiceMessage,
+};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(bool, authenticated)]
+        #[qproperty(QString, error_message)]
+        #[qproperty(QString, last_url)]
+        type GistModel = super::GistModelRust;
+
+        #[qinvokable]
+        fn check_auth(self: Pin<&mut GistModel>);
+
+        #[qinvokable]
+        fn fetch_gists(self: Pin<&mut GistModel>);
+
+        /// Publish note text as a secret gist; emits `gist_shared` with its URL
+        #[qinvokable]
+        fn share_note(self: Pin<&mut GistModel>, content: QString);
+
+        /// Replace the first file of the gist at `index` with `content`
+        #[qinvokable]
+        fn update_gist(self: Pin<&mut GistModel>, index: i32, content: QString);
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut GistModel>);
+
+        #[qinvokable]
+        fn row_count(self: &GistModel) -> i32;
+
+        #[qinvokable]
+        fn get_description(self: &GistModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_filename(self: &GistModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_html_url(self: &GistModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_public(self: &GistModel, index: i32) -> bool;
+
+        #[qinvokable]
+        fn get_updated_at(self: &GistModel, index: i32) -> QString;
+
+        #[qsignal]
+        fn gists_changed(self: Pin<&mut GistModel>);
+
+        #[qsignal]
+        fn gist_shared(self: Pin<&mut GistModel>, url: QString);
+    }
+}
+
+#[derive(Default)]
+pub struct GistModelRust {
+    loading: bool,
+    authenticated: bool,
+    error_message: QString,
+    /// URL of the most recently shared or updated gist
+    last_url: QString,
+    gists: Vec<GitHubGist>,
+}
+
+impl GistModelRust {
+    fn get(&self, index: i32) -> Option<&GitHubGist> {
+        if index < 0 {
+            return None;
+        }
+        self.gists.get(index as usize)
+    }
+
+    fn set_error(&mut self, msg: &str) {
+        self.error_message = QString::from(msg);
+    }
+
+    fn clear_error(&mut self) {
+        self.error_message = QString::from("");
+    }
+
+    /// Put a created or updated gist at the top of the list
+    fn upsert(&mut self, gist: GitHubGist) {
+        self.gists.retain(|g| g.id != gist.id);
+        self.gists.insert(0, gist);
+    }
+}
+
+impl qobject::GistModel {
+    pub fn check_auth(mut self: Pin<&mut Self>) {
+        let auth = bridge::get_github_client_and_runtime().is_some();
+        if self.as_ref().rust().authenticated != auth {
+            self.as_mut().set_authenticated(auth);
+        }
+    }
+
+    /// Client and channel for a request, flagging the model when either is missing
+    fn begin_request(
+        mut self: Pin<&mut Self>,
+    ) -> Option<(
+        std::sync::Arc<myme_services::GitHubClient>,
+        std::sync::mpsc::Sender<GistServiceMessage>,
+    )> {
+        if self.as_ref().rust().loading {
+            return None;
+        }
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            self.as_mut().set_authenticated(false);
+            return None;
+        };
+        bridge::init_gist_service_channel();
+        let Some(tx) = bridge::get_gist_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Gist service channel not ready"));
+            return None;
+        };
+        self.as_mut().set_loading(true);
+        Some((client, tx))
+    }
+
+    pub fn fetch_gists(mut self: Pin<&mut Self>) {
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_gist_fetch(&tx, client);
+        }
+    }
+
+    pub fn share_note(mut self: Pin<&mut Self>, content: QString) {
+        let content = content.to_string();
+        if content.trim().is_empty() {
+            return;
+        }
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            let description =
+                content.lines().map(str::trim).find(|l| !l.is_empty()).map(str::to_string);
+            request_gist_create(&tx, client, description, content);
+        }
+    }
+
+    pub fn update_gist(mut self: Pin<&mut Self>, index: i32, content: QString) {
+        let Some(gist) = self.as_ref().rust().get(index) else {
+            return;
+        };
+        let Some(filename) = gist.files.keys().next().cloned() else {
+            return;
+        };
+        let gist_id = gist.id.clone();
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_gist_update(&tx, client, gist_id, filename, content.to_string());
+        }
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let Some(msg) = bridge::try_recv_gist_message() else {
+            return;
+        };
+
+        self.as_mut().set_loading(false);
+        match msg {
+            GistServiceMessage::FetchDone(Ok(gists)) => {
+                self.as_mut().rust_mut().clear_error();
+                self.as_mut().rust_mut().gists = gists;
+                self.as_mut().gists_changed();
+            }
+            GistServiceMessage::CreateDone(Ok(gist)) | GistServiceMessage::UpdateDone(Ok(gist)) => {
+                self.as_mut().rust_mut().clear_error();
+                let url = QString::from(&gist.html_url);
+                self.as_mut().rust_mut().upsert(gist);
+                self.as_mut().set_last_url(url.clone());
+                self.as_mut().gists_changed();
+                self.as_mut().gist_shared(url);
+            }
+            GistServiceMessage::FetchDone(Err(e))
+            | GistServiceMessage::CreateDone(Err(e))
+            | GistServiceMessage::UpdateDone(Err(e)) => {
+                let text = myme_core::AppError::from(e).user_message();
+                self.as_mut().set_error_message(QString::from(text));
+            }
+        }
+    }
+
+    pub fn row_count(&self) -> i32 {
+        self.rust().gists.len() as i32
+    }
+
+    pub fn get_description(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .and_then(|g| g.description.as_deref())
+            .map(QString::from)
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_filename(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .and_then(|g| g.files.keys().next())
+            .map(QString::from)
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_html_url(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .map(|g| QString::from(&g.html_url))
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_public(&self, index: i32) -> bool {
+        self.rust().get(index).map(|g| g.public).unwrap_or(false)
+    }
+
+    pub fn get_updated_at(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .map(|g| QString::from(&g.updated_at))
+            .unwrap_or_else(|| QString::from(""))
+    }
+}
@@ -0,0 +1,203 @@
+// crates/myme-ui/src/models/global_search_model.rs
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+
+use crate::bridge;
+use crate::services::{merge_search_hits, request_search, SearchHit, SearchServiceMessage};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(QString, error_message)]
+        #[qproperty(i32, result_count)]
+        type GlobalSearchModel = super::GlobalSearchModelRust;
+
+        /// Search every source; results arrive incrementally via `results_changed`
+        #[qinvokable]
+        fn search(self: Pin<&mut GlobalSearchModel>, query: QString);
+
+        #[qinvokable]
+        fn clear(self: Pin<&mut GlobalSearchModel>);
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut GlobalSearchModel>);
+
+        #[qinvokable]
+        fn row_count(self: &GlobalSearchModel) -> i32;
+
+        /// Source tag: note, email, event, repo or task
+        #[qinvokable]
+        fn get_source(self: &GlobalSearchModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_item_id(self: &GlobalSearchModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_title(self: &GlobalSearchModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_snippet(self: &GlobalSearchModel, index: i32) -> QString;
+
+        /// Where to open the hit: thread id, event link, repo path or URL, project id
+        #[qinvokable]
+        fn get_context(self: &GlobalSearchModel, index: i32) -> QString;
+
+        /// RFC 3339 timestamp, or empty when the hit has none
+        #[qinvokable]
+        fn get_time(self: &GlobalSearchModel, index: i32) -> QString;
+
+        #[qsignal]
+        fn results_changed(self: Pin<&mut GlobalSearchModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct GlobalSearchModelRust {
+    loading: bool,
+    error_message: QString,
+    result_count: i32,
+    /// Bumped per search so replies to an older query are dropped
+    generation: u64,
+    results: Vec<SearchHit>,
+    /// Sources that failed for the current search
+    failed: Vec<&'static str>,
+}
+
+impl GlobalSearchModelRust {
+    fn get(&self, index: i32) -> Option<&SearchHit> {
+        if index < 0 {
+            return None;
+        }
+        self.results.get(index as usize)
+    }
+
+    fn field(&self, index: i32, f: impl Fn(&SearchHit) -> &str) -> QString {
+        self.get(index).map(|h| QString::from(f(h))).unwrap_or_else(|| QString::from(""))
+    }
+}
+
+impl qobject::GlobalSearchModel {
+    pub fn search(mut self: Pin<&mut Self>, query: QString) {
+        let query = query.to_string().trim().to_string();
+        if query.is_empty() {
+            self.clear();
+            return;
+        }
+
+        bridge::init_search_service_channel();
+        let Some(tx) = bridge::get_search_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Search service channel not ready"));
+            return;
+        };
+
+        let generation = {
+            let mut rust = self.as_mut().rust_mut();
+            rust.generation += 1;
+            rust.results.clear();
+            rust.failed.clear();
+            rust.generation
+        };
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_result_count(0);
+        self.as_mut().set_loading(true);
+        self.as_mut().results_changed();
+        request_search(&tx, query, generation);
+    }
+
+    pub fn clear(mut self: Pin<&mut Self>) {
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.generation += 1;
+            rust.results.clear();
+            rust.failed.clear();
+        }
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_result_count(0);
+        self.as_mut().set_loading(false);
+        self.as_mut().results_changed();
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        // Drain everything queued so a burst of sources lands in one repaint
+        let mut changed = false;
+        while let Some(msg) = bridge::try_recv_search_message() {
+            let current = self.as_ref().rust().generation;
+            match msg {
+                SearchServiceMessage::Results { generation, source, result }
+                    if generation == current =>
+                {
+                    match result {
+                        Ok(hits) => {
+                            merge_search_hits(&mut self.as_mut().rust_mut().results, hits);
+                            changed = true;
+                        }
+                        Err(e) => {
+                            tracing::debug!("Global search: {} unavailable: {}", source.key(), e);
+                            self.as_mut().rust_mut().failed.push(source.key());
+                        }
+                    }
+                }
+                SearchServiceMessage::Done { generation } if generation == current => {
+                    self.as_mut().set_loading(false);
+                    let failed = self.as_ref().rust().failed.join(", ");
+                    if !failed.is_empty() {
+                        self.as_mut().set_error_message(QString::from(&format!(
+                            "Could not search: {}",
+                            failed
+                        )));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if changed {
+            let count = self.as_ref().rust().results.len() as i32;
+            self.as_mut().set_result_count(count);
+            self.as_mut().results_changed();
+        }
+    }
+
+    pub fn row_count(&self) -> i32 {
+        self.rust().results.len() as i32
+    }
+
+    pub fn get_source(&self, index: i32) -> QString {
+        self.rust().field(index, |h| h.source.key())
+    }
+
+    pub fn get_item_id(&self, index: i32) -> QString {
+        self.rust().field(index, |h| &h.id)
+    }
+
+    pub fn get_title(&self, index: i32) -> QString {
+        self.rust().field(index, |h| &h.title)
+    }
+
+    pub fn get_snippet(&self, index: i32) -> QString {
+        self.rust().field(index, |h| &h.snippet)
+    }
+
+    pub fn get_context(&self, index: i32) -> QString {
+        self.rust().field(index, |h| &h.context)
+    }
+
+    pub fn get_time(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .and_then(|h| h.time)
+            .map(|t| QString::from(&t.to_rfc3339()))
+            .unwrap_or_else(|| QString::from(""))
+    }
+}
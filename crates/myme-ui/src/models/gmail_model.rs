@@ -4,34 +4,56 @@
 //! Uses the shared AppServices runtime and channel pattern (no block_on).
 
 use core::pin::Pin;
+use std::collections::HashSet;
+use std::process::Command;
 
 use cxx_qt::CxxQtType;
-use cxx_qt_lib::QString;
+use cxx_qt_lib::{QHash, QHashPair_i32_QByteArray, QModelIndex, QString, QVariant};
 use myme_auth::SecureStorage;
-use myme_gmail::{GmailCache, Message};
+use myme_core::datefmt;
+use myme_gmail::unsubscribe::sender_address;
+use myme_gmail::{Attachment, GmailCache, Message, Thread, UnsubscribeTarget};
 
 use crate::bridge;
+use crate::models::list_model::{self, list_rows, ListRole};
 use crate::services::google_common::{get_google_access_token, get_google_cache_path};
 use crate::services::{
-    request_gmail_archive, request_gmail_fetch, request_gmail_mark_as_read, request_gmail_trash,
-    GmailServiceMessage,
+    delete_gmail_rule, describe_gmail_cache_usage, gmail_cache_usage, list_gmail_rules,
+    publish_ui_event, request_gmail_archive, request_gmail_attachment, request_gmail_batch,
+    request_gmail_fetch, request_gmail_mark_as_read, request_gmail_snooze, request_gmail_thread,
+    request_gmail_trash, request_gmail_unsubscribe, save_gmail_rule, GmailBatchOp,
+    GmailServiceMessage, UiEvent, UiSeverity, Unsubscribed,
 };
 
 #[cxx_qt::bridge]
 pub mod qobject {
     unsafe extern "C++" {
+        include!(<QtCore/QAbstractListModel>);
+        type QAbstractListModel;
+
         include!("cxx-qt-lib/qstring.h");
+        include!("cxx-qt-lib/qvariant.h");
+        include!("cxx-qt-lib/qmodelindex.h");
+        include!("cxx-qt-lib/qhash.h");
+        include!("cxx-qt-lib/qvector.h");
         type QString = cxx_qt_lib::QString;
+        type QVariant = cxx_qt_lib::QVariant;
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        type QHash_i32_QByteArray = cxx_qt_lib::QHash<cxx_qt_lib::QHashPair_i32_QByteArray>;
+        type QVector_i32 = cxx_qt_lib::QVector<i32>;
     }
 
     extern "RustQt" {
         #[qobject]
         #[qml_element]
+        #[base = QAbstractListModel]
         #[qproperty(bool, loading)]
         #[qproperty(bool, authenticated)]
         #[qproperty(QString, error_message)]
         #[qproperty(i32, unread_count)]
         #[qproperty(i32, message_count)]
+        #[qproperty(i32, thread_count)]
+        #[qproperty(QString, cache_usage)]
         type GmailModel = super::GmailModelRust;
 
         #[qinvokable]
@@ -40,8 +62,31 @@ pub mod qobject {
         #[qinvokable]
         fn fetch_messages(self: Pin<&mut GmailModel>);
 
+        /// Thread summary at index as JSON (subject, participants, count, unread)
+        #[qinvokable]
+        fn get_thread(self: Pin<&mut GmailModel>, index: i32) -> QString;
+
+        /// Messages of the thread at index as a JSON array, oldest first
+        #[qinvokable]
+        fn get_thread_messages(self: Pin<&mut GmailModel>, index: i32) -> QString;
+
+        /// Fetch the full conversation (including replies outside the inbox)
         #[qinvokable]
-        fn get_message(self: Pin<&mut GmailModel>, index: i32) -> QString;
+        fn load_thread(self: Pin<&mut GmailModel>, thread_id: QString);
+
+        /// Attachments of a message as a JSON array (filename, mimeType, size, downloaded)
+        #[qinvokable]
+        fn list_attachments(self: Pin<&mut GmailModel>, message_id: QString) -> QString;
+
+        /// Open an attachment with the system viewer, downloading it first if needed.
+        /// Executable attachments are shown in their folder instead of run.
+        /// Returns true if it was opened immediately.
+        #[qinvokable]
+        fn open_attachment(
+            self: Pin<&mut GmailModel>,
+            message_id: QString,
+            attachment_id: QString,
+        ) -> bool;
 
         #[qinvokable]
         fn mark_as_read(self: Pin<&mut GmailModel>, message_id: QString);
@@ -52,15 +97,191 @@ pub mod qobject {
         #[qinvokable]
         fn trash_message(self: Pin<&mut GmailModel>, message_id: QString);
 
+        /// Snooze the message at index until `until_iso` (RFC 3339). It leaves the
+        /// inbox now and comes back, with a desktop notification, at that time.
+        #[qinvokable]
+        fn snooze(self: Pin<&mut GmailModel>, index: i32, until_iso: QString);
+
+        /// Unsubscribe from the sender of the message at index: a one-click
+        /// request when supported, else an unsubscribe email saved to Drafts,
+        /// else the sender's unsubscribe page opened in the browser.
+        #[qinvokable]
+        fn unsubscribe(self: Pin<&mut GmailModel>, index: i32);
+
+        /// Mail from the sender of the message at index this month, as JSON:
+        /// {"sender","name","count","summary","canUnsubscribe","oneClick","unsubscribedAt"}
+        #[qinvokable]
+        fn sender_summary(self: &GmailModel, index: i32) -> QString;
+
+        /// Mark selected messages as read. `indices_json` is a JSON array, e.g. [0,2,5]
+        #[qinvokable]
+        fn mark_read_selected(self: Pin<&mut GmailModel>, indices_json: QString);
+
+        /// Archive selected messages. `indices_json` is a JSON array, e.g. [0,2,5]
+        #[qinvokable]
+        fn archive_selected(self: Pin<&mut GmailModel>, indices_json: QString);
+
+        /// Trash selected messages. `indices_json` is a JSON array, e.g. [0,2,5]
+        #[qinvokable]
+        fn trash_selected(self: Pin<&mut GmailModel>, indices_json: QString);
+
+        /// Mail rules as a JSON array, in the order they run:
+        /// [{"id","name","enabled","conditions":[{"field","value"}],"actions":[{"type","label"}]}]
+        /// where field is from/subject/list_id and type is label/archive/mark_read
+        #[qinvokable]
+        fn list_rules(self: &GmailModel) -> QString;
+
+        /// Create (id 0 or missing) or update a rule from JSON in the `list_rules`
+        /// shape. Returns the rule's ID, or -1 with `error_message` set.
+        #[qinvokable]
+        fn save_rule(self: Pin<&mut GmailModel>, rule_json: QString) -> i32;
+
+        #[qinvokable]
+        fn delete_rule(self: Pin<&mut GmailModel>, id: i32) -> bool;
+
         /// Poll for async operation results. Call this from a QML Timer.
         #[qinvokable]
         fn poll_channel(self: Pin<&mut GmailModel>);
 
-        #[qsignal]
-        fn messages_changed(self: Pin<&mut GmailModel>);
+        #[cxx_override]
+        fn data(self: &GmailModel, index: &QModelIndex, role: i32) -> QVariant;
+
+        #[cxx_override]
+        #[cxx_name = "roleNames"]
+        fn role_names(self: &GmailModel) -> QHash_i32_QByteArray;
+
+        #[cxx_override]
+        #[cxx_name = "rowCount"]
+        fn row_count(self: &GmailModel, parent: &QModelIndex) -> i32;
 
         #[qsignal]
         fn message_updated(self: Pin<&mut GmailModel>, message_id: QString);
+
+        #[qsignal]
+        fn thread_loaded(self: Pin<&mut GmailModel>, thread_id: QString);
+
+        #[qsignal]
+        fn attachment_ready(self: Pin<&mut GmailModel>, attachment_id: QString, path: QString);
+
+        #[qsignal]
+        fn rules_changed(self: Pin<&mut GmailModel>);
+
+        /// Snoozed messages came back to the inbox
+        #[qsignal]
+        fn messages_resurfaced(self: Pin<&mut GmailModel>, count: i32);
+
+        /// Unsubscribe handled; `outcome` is one_click, drafted or opened
+        #[qsignal]
+        fn unsubscribed(self: Pin<&mut GmailModel>, sender: QString, outcome: QString);
+    }
+
+    unsafe extern "RustQt" {
+        #[inherit]
+        #[cxx_name = "beginResetModel"]
+        fn begin_reset_model(self: Pin<&mut GmailModel>);
+
+        #[inherit]
+        #[cxx_name = "endResetModel"]
+        fn end_reset_model(self: Pin<&mut GmailModel>);
+
+        #[inherit]
+        #[cxx_name = "beginInsertRows"]
+        fn begin_insert_rows(
+            self: Pin<&mut GmailModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        #[inherit]
+        #[cxx_name = "endInsertRows"]
+        fn end_insert_rows(self: Pin<&mut GmailModel>);
+
+        #[inherit]
+        #[cxx_name = "beginRemoveRows"]
+        fn begin_remove_rows(
+            self: Pin<&mut GmailModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        #[inherit]
+        #[cxx_name = "endRemoveRows"]
+        fn end_remove_rows(self: Pin<&mut GmailModel>);
+
+        #[inherit]
+        #[cxx_name = "beginMoveRows"]
+        fn begin_move_rows(
+            self: Pin<&mut GmailModel>,
+            source_parent: &QModelIndex,
+            source_first: i32,
+            source_last: i32,
+            destination_parent: &QModelIndex,
+            destination_child: i32,
+        ) -> bool;
+
+        #[inherit]
+        #[cxx_name = "endMoveRows"]
+        fn end_move_rows(self: Pin<&mut GmailModel>);
+
+        #[inherit]
+        fn index(self: &GmailModel, row: i32, column: i32, parent: &QModelIndex) -> QModelIndex;
+
+        #[inherit]
+        #[qsignal]
+        #[cxx_name = "dataChanged"]
+        fn data_changed(
+            self: Pin<&mut GmailModel>,
+            top_left: &QModelIndex,
+            bottom_right: &QModelIndex,
+            roles: &QVector_i32,
+        );
+    }
+}
+
+/// Roles the message list delegates bind to
+#[derive(Clone, Copy)]
+enum MessageRole {
+    MessageId,
+    ThreadId,
+    Sender,
+    Subject,
+    Snippet,
+    Date,
+    DateLabel,
+    IsUnread,
+    IsStarred,
+    CanUnsubscribe,
+}
+
+impl ListRole for MessageRole {
+    const ALL: &'static [Self] = &[
+        MessageRole::MessageId,
+        MessageRole::ThreadId,
+        MessageRole::Sender,
+        MessageRole::Subject,
+        MessageRole::Snippet,
+        MessageRole::Date,
+        MessageRole::DateLabel,
+        MessageRole::IsUnread,
+        MessageRole::IsStarred,
+        MessageRole::CanUnsubscribe,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            MessageRole::MessageId => "messageId",
+            MessageRole::ThreadId => "threadId",
+            MessageRole::Sender => "sender",
+            MessageRole::Subject => "subject",
+            MessageRole::Snippet => "snippet",
+            MessageRole::Date => "date",
+            MessageRole::DateLabel => "dateLabel",
+            MessageRole::IsUnread => "isUnread",
+            MessageRole::IsStarred => "isStarred",
+            MessageRole::CanUnsubscribe => "canUnsubscribe",
+        }
     }
 }
 
@@ -71,7 +292,11 @@ pub struct GmailModelRust {
     error_message: QString,
     unread_count: i32,
     message_count: i32,
+    thread_count: i32,
+    /// Offline cache size against the quota, e.g. "12.3 MB of 500 MB"
+    cache_usage: QString,
     messages: Vec<Message>,
+    threads: Vec<Thread>,
 }
 
 impl GmailModelRust {
@@ -90,6 +315,154 @@ impl GmailModelRust {
     fn get_cache_path() -> std::path::PathBuf {
         get_google_cache_path("gmail_cache.db")
     }
+
+    fn get_attachment_dir() -> std::path::PathBuf {
+        get_google_cache_path("gmail_attachments")
+    }
+
+    /// Attachments for a message: cached metadata (with download paths) if present,
+    /// otherwise what came with the fetched message.
+    fn attachments_for(&self, message_id: &str) -> Vec<Attachment> {
+        let cached = GmailCache::new(Self::get_cache_path())
+            .and_then(|cache| cache.list_attachments(message_id))
+            .unwrap_or_default();
+        if !cached.is_empty() {
+            return cached;
+        }
+        self.messages
+            .iter()
+            .chain(self.threads.iter().flat_map(|t| t.messages.iter()))
+            .find(|m| m.id == message_id)
+            .map(|m| m.attachments.clone())
+            .unwrap_or_default()
+    }
+
+    /// Open a file or URL with the platform's default application.
+    fn open_with_system(path: &str) -> bool {
+        let mut command = if cfg!(target_os = "windows") {
+            let mut c = Command::new("cmd");
+            c.args(["/C", "start", ""]);
+            c
+        } else if cfg!(target_os = "macos") {
+            Command::new("open")
+        } else {
+            Command::new("xdg-open")
+        };
+        command.arg(path);
+        GmailModelRust::launch(command, path)
+    }
+
+    /// Show a file in the system file manager without opening it.
+    fn reveal_in_folder(path: &str) -> bool {
+        let mut command;
+        if cfg!(target_os = "windows") {
+            command = Command::new("explorer");
+            command.arg(format!("/select,{}", path));
+        } else if cfg!(target_os = "macos") {
+            command = Command::new("open");
+            command.args(["-R", path]);
+        } else {
+            // No portable way to select the file; open its folder
+            command = Command::new("xdg-open");
+            command.arg(std::path::Path::new(path).parent().unwrap_or(std::path::Path::new(".")));
+        }
+        GmailModelRust::launch(command, path)
+    }
+
+    /// Open a downloaded attachment. Programs, scripts and shortcuts from a
+    /// sender are never run from here: they are shown in their folder.
+    fn open_attachment_file(attachment: &Attachment, path: &str) -> bool {
+        if !attachment.is_executable() {
+            return GmailModelRust::open_with_system(path);
+        }
+        publish_ui_event(
+            UiEvent::new("gmail", UiSeverity::Warning, "Attachment shown in its folder")
+                .with_detail(format!(
+                    "{} can run programs, so it was not opened",
+                    attachment.filename
+                )),
+        );
+        GmailModelRust::reveal_in_folder(path)
+    }
+
+    fn launch(mut command: Command, target: &str) -> bool {
+        match command.spawn() {
+            Ok(mut child) => {
+                // Reap the launcher once it exits so it doesn't linger as a zombie
+                std::thread::spawn(move || {
+                    let _ = child.wait();
+                });
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to open {}: {}", target, e);
+                false
+            }
+        }
+    }
+
+    /// Resolve a JSON array of row indices to message IDs, skipping invalid entries.
+    fn ids_for_indices(&self, indices_json: &str) -> Vec<String> {
+        let indices: Vec<i64> = serde_json::from_str(indices_json).unwrap_or_default();
+        let mut seen = HashSet::new();
+        indices
+            .into_iter()
+            .filter(|&i| i >= 0)
+            .filter_map(|i| self.messages.get(i as usize))
+            .filter(|m| seen.insert(m.id.as_str()))
+            .map(|m| m.id.clone())
+            .collect()
+    }
+
+    fn message_json(msg: &Message) -> serde_json::Value {
+        serde_json::json!({
+            "id": msg.id,
+            "threadId": msg.thread_id,
+            "from": msg.from,
+            "subject": msg.subject,
+            "snippet": msg.snippet,
+            "date": msg.date.to_rfc3339(),
+            "dateLabel": datefmt::relative(msg.date),
+            "isUnread": msg.is_unread,
+            "isStarred": msg.is_starred,
+            "canUnsubscribe": msg.unsubscribe.is_some(),
+        })
+    }
+
+    /// Sender address and unsubscribe target for the message at index; cached
+    /// messages don't carry the headers, so fall back to the per-sender record.
+    fn unsubscribe_target_at(&self, index: i32) -> Option<(String, Option<UnsubscribeTarget>)> {
+        let msg = self.messages.get(usize::try_from(index).ok()?)?;
+        let sender = sender_address(&msg.from);
+        let target = msg.unsubscribe.clone().or_else(|| {
+            GmailCache::new(Self::get_cache_path())
+                .and_then(|cache| cache.unsubscribe_target(&sender))
+                .ok()
+                .flatten()
+        });
+        Some((sender, target))
+    }
+
+    /// Start of the current month in local time
+    fn month_start() -> chrono::DateTime<chrono::Utc> {
+        use chrono::{Datelike, Local};
+        let now = Local::now();
+        now.date_naive()
+            .with_day(1)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .and_then(|d| d.and_local_timezone(Local).earliest())
+            .map(|d| d.with_timezone(&chrono::Utc))
+            .unwrap_or_else(|| now.with_timezone(&chrono::Utc) - chrono::Duration::days(30))
+    }
+
+    /// Replace a thread with its full version, or insert it if not listed yet.
+    fn merge_thread(&mut self, thread: Thread) {
+        match self.threads.iter_mut().find(|t| t.id == thread.id) {
+            Some(existing) => *existing = thread,
+            None => self.threads.push(thread),
+        }
+        self.threads.sort_by(|a, b| b.latest_date().cmp(&a.latest_date()));
+    }
 }
 
 impl qobject::GmailModel {
@@ -104,9 +477,18 @@ impl qobject::GmailModel {
                     self.as_mut().set_unread_count(count as i32);
                 }
             }
+            self.as_mut().refresh_cache_usage();
         }
     }
 
+    fn refresh_cache_usage(mut self: Pin<&mut Self>) {
+        let Some(usage) = gmail_cache_usage(&GmailModelRust::get_cache_path()) else {
+            return;
+        };
+        let quota = myme_core::Config::load_cached().gmail.cache_quota_bytes();
+        self.as_mut().set_cache_usage(QString::from(&describe_gmail_cache_usage(usage, quota)));
+    }
+
     /// Fetch messages from Gmail (non-blocking, uses shared runtime)
     pub fn fetch_messages(mut self: Pin<&mut Self>) {
         let access_token = match GmailModelRust::get_access_token() {
@@ -134,28 +516,135 @@ impl qobject::GmailModel {
         request_gmail_fetch(&tx, access_token, cache_path);
     }
 
-    /// Get message at index as JSON
-    pub fn get_message(self: Pin<&mut Self>, index: i32) -> QString {
+    /// Get thread summary at index as JSON
+    pub fn get_thread(self: Pin<&mut Self>, index: i32) -> QString {
         let rust = self.rust();
-        if index < 0 || index as usize >= rust.messages.len() {
+        if index < 0 || index as usize >= rust.threads.len() {
             return QString::from("{}");
         }
 
-        let msg = &rust.messages[index as usize];
+        let thread = &rust.threads[index as usize];
         let json = serde_json::json!({
-            "id": msg.id,
-            "from": msg.from,
-            "subject": msg.subject,
-            "snippet": msg.snippet,
-            "date": msg.date.to_rfc3339(),
-            "isUnread": msg.is_unread,
-            "isStarred": msg.is_starred,
+            "id": thread.id,
+            "subject": thread.subject(),
+            "snippet": thread.snippet,
+            "participants": thread.participants(),
+            "messageCount": thread.messages.len(),
+            "date": thread.latest_date().to_rfc3339(),
+            "dateLabel": datefmt::relative(thread.latest_date()),
+            "isUnread": thread.is_unread(),
         });
 
         let s = json.to_string();
         QString::from(s.as_str())
     }
 
+    /// Get messages of the thread at index as a JSON array
+    pub fn get_thread_messages(self: Pin<&mut Self>, index: i32) -> QString {
+        let rust = self.rust();
+        if index < 0 || index as usize >= rust.threads.len() {
+            return QString::from("[]");
+        }
+
+        let messages: Vec<serde_json::Value> = rust.threads[index as usize]
+            .messages
+            .iter()
+            .map(GmailModelRust::message_json)
+            .collect();
+        let s = serde_json::to_string(&messages).unwrap_or_else(|_| "[]".to_string());
+        QString::from(s.as_str())
+    }
+
+    /// Fetch a full conversation from Gmail (non-blocking)
+    pub fn load_thread(mut self: Pin<&mut Self>, thread_id: QString) {
+        let access_token = match GmailModelRust::get_access_token() {
+            Some(t) => t,
+            None => return,
+        };
+
+        bridge::init_gmail_service_channel();
+        let tx = match bridge::get_gmail_service_tx() {
+            Some(t) => t,
+            None => return,
+        };
+
+        self.as_mut().set_loading(true);
+        let cache_path = GmailModelRust::get_cache_path();
+        request_gmail_thread(&tx, access_token, thread_id.to_string(), cache_path);
+    }
+
+    /// List attachments for a message as JSON
+    pub fn list_attachments(self: Pin<&mut Self>, message_id: QString) -> QString {
+        let attachments = self.rust().attachments_for(&message_id.to_string());
+        let json: Vec<serde_json::Value> = attachments
+            .iter()
+            .map(|a| {
+                serde_json::json!({
+                    "attachmentId": a.attachment_id,
+                    "filename": a.filename,
+                    "mimeType": a.mime_type,
+                    "size": a.size,
+                    "downloaded": a.local_path.as_deref().is_some_and(|p| std::path::Path::new(p).exists()),
+                })
+            })
+            .collect();
+        let s = serde_json::to_string(&json).unwrap_or_else(|_| "[]".to_string());
+        QString::from(s.as_str())
+    }
+
+    /// Open an attachment, downloading it in the background if not cached yet
+    pub fn open_attachment(
+        mut self: Pin<&mut Self>,
+        message_id: QString,
+        attachment_id: QString,
+    ) -> bool {
+        let attachment_id = attachment_id.to_string();
+        let attachment = match self
+            .rust()
+            .attachments_for(&message_id.to_string())
+            .into_iter()
+            .find(|a| a.attachment_id == attachment_id)
+        {
+            Some(a) => a,
+            None => return false,
+        };
+
+        if let Some(path) = &attachment.local_path {
+            if std::path::Path::new(path).exists() {
+                // Recently opened files are evicted last
+                if let Ok(cache) = GmailCache::new(GmailModelRust::get_cache_path()) {
+                    let _ = cache.touch_attachment(
+                        &attachment.message_id,
+                        &attachment.attachment_id,
+                        chrono::Utc::now(),
+                    );
+                }
+                return GmailModelRust::open_attachment_file(&attachment, path);
+            }
+        }
+
+        let access_token = match GmailModelRust::get_access_token() {
+            Some(t) => t,
+            None => return false,
+        };
+
+        bridge::init_gmail_service_channel();
+        let tx = match bridge::get_gmail_service_tx() {
+            Some(t) => t,
+            None => return false,
+        };
+
+        self.as_mut().set_loading(true);
+        request_gmail_attachment(
+            &tx,
+            access_token,
+            attachment,
+            GmailModelRust::get_attachment_dir(),
+            GmailModelRust::get_cache_path(),
+        );
+        false
+    }
+
     /// Mark message as read
     pub fn mark_as_read(self: Pin<&mut Self>, message_id: QString) {
         let access_token = match GmailModelRust::get_access_token() {
@@ -207,6 +696,232 @@ impl qobject::GmailModel {
         request_gmail_trash(&tx, access_token, msg_id);
     }
 
+    /// Snooze a message until a later time
+    pub fn snooze(mut self: Pin<&mut Self>, index: i32, until_iso: QString) {
+        let Some(msg_id) = self.rust().messages.get(index.max(0) as usize).map(|m| m.id.clone())
+        else {
+            return;
+        };
+        let until = match chrono::DateTime::parse_from_rfc3339(&until_iso.to_string()) {
+            Ok(t) => t.with_timezone(&chrono::Utc),
+            Err(_) => {
+                self.as_mut().rust_mut().set_error("Invalid snooze time");
+                return;
+            }
+        };
+        if until <= chrono::Utc::now() {
+            self.as_mut().rust_mut().set_error("Pick a snooze time in the future");
+            return;
+        }
+
+        let access_token = match GmailModelRust::get_access_token() {
+            Some(t) => t,
+            None => return,
+        };
+
+        bridge::init_gmail_service_channel();
+        let tx = match bridge::get_gmail_service_tx() {
+            Some(t) => t,
+            None => return,
+        };
+
+        self.as_mut().set_loading(true);
+        request_gmail_snooze(&tx, access_token, msg_id, until);
+    }
+
+    /// Unsubscribe from the sender of the message at index
+    pub fn unsubscribe(mut self: Pin<&mut Self>, index: i32) {
+        let Some((sender, target)) = self.rust().unsubscribe_target_at(index) else {
+            return;
+        };
+        let Some(target) = target else {
+            self.as_mut().rust_mut().set_error("This sender has no unsubscribe link");
+            return;
+        };
+
+        if target.one_click_url().is_none() && target.mailto.is_none() {
+            // Only a web page: the user finishes there
+            if let Some(url) = target.http.as_deref() {
+                if GmailModelRust::open_with_system(url) {
+                    self.as_mut().unsubscribed(QString::from(&sender), QString::from("opened"));
+                }
+            }
+            return;
+        }
+
+        let access_token = match GmailModelRust::get_access_token() {
+            Some(t) => t,
+            None => return,
+        };
+
+        bridge::init_gmail_service_channel();
+        let tx = match bridge::get_gmail_service_tx() {
+            Some(t) => t,
+            None => return,
+        };
+
+        self.as_mut().set_loading(true);
+        request_gmail_unsubscribe(&tx, access_token, sender, target);
+    }
+
+    /// This month's mail from the sender of the message at index
+    pub fn sender_summary(&self, index: i32) -> QString {
+        let Some((sender, target)) = self.rust().unsubscribe_target_at(index) else {
+            return QString::from("{}");
+        };
+        let summary = GmailCache::new(GmailModelRust::get_cache_path())
+            .and_then(|cache| cache.sender_summary(&sender, GmailModelRust::month_start()))
+            .ok()
+            .flatten();
+        let Some(summary) = summary else {
+            return QString::from("{}");
+        };
+        let target = target.or(summary.target.clone());
+        let json = serde_json::json!({
+            "sender": summary.sender,
+            "name": summary.name,
+            "count": summary.count,
+            "summary": summary.describe("this month"),
+            "canUnsubscribe": target.is_some(),
+            "oneClick": target.as_ref().is_some_and(|t| t.one_click_url().is_some()),
+            "unsubscribedAt": summary.unsubscribed_at.map(|t| t.to_rfc3339()),
+        });
+        QString::from(&json.to_string())
+    }
+
+    pub fn list_rules(&self) -> QString {
+        let rules = list_gmail_rules().unwrap_or_default();
+        QString::from(&serde_json::to_string(&rules).unwrap_or_else(|_| "[]".into()))
+    }
+
+    pub fn save_rule(mut self: Pin<&mut Self>, rule_json: QString) -> i32 {
+        let rule: myme_gmail::Rule = match serde_json::from_str(&rule_json.to_string()) {
+            Ok(rule) => rule,
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(&format!("Invalid rule: {}", e));
+                return -1;
+            }
+        };
+        if let Err(e) = rule.validate() {
+            self.as_mut().rust_mut().set_error(&e.to_string());
+            return -1;
+        }
+        match save_gmail_rule(&rule) {
+            Ok(id) => {
+                self.as_mut().rust_mut().clear_error();
+                self.as_mut().rules_changed();
+                id as i32
+            }
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                -1
+            }
+        }
+    }
+
+    pub fn delete_rule(mut self: Pin<&mut Self>, id: i32) -> bool {
+        match delete_gmail_rule(id as i64) {
+            Ok(deleted) => {
+                self.as_mut().rules_changed();
+                deleted
+            }
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                false
+            }
+        }
+    }
+
+    /// Replace the listed messages and their thread grouping.
+    fn show_messages(mut self: Pin<&mut Self>, messages: Vec<Message>) {
+        let loaded = &self.rust().messages;
+        let same_rows = !messages.is_empty()
+            && loaded.len() == messages.len()
+            && loaded.iter().zip(&messages).all(|(a, b)| a.id == b.id);
+        if same_rows {
+            // A refetch of the same inbox (e.g. after marking read) keeps the delegates
+            let last = messages.len() - 1;
+            self.as_mut().rust_mut().messages = messages;
+            self.as_mut().rows_changed(0, last);
+        } else {
+            self.as_mut().reset_rows(|rust| rust.messages = messages);
+        }
+        self.as_mut().refresh_counts();
+    }
+
+    /// Regroup threads and update the counts after the message list changed
+    fn refresh_counts(mut self: Pin<&mut Self>) {
+        let messages = &self.rust().messages;
+        let unread = messages.iter().filter(|m| m.is_unread).count();
+        let total = messages.len();
+        let threads = Thread::group(messages.clone());
+        self.as_mut().set_unread_count(unread as i32);
+        self.as_mut().set_message_count(total as i32);
+        self.as_mut().set_thread_count(threads.len() as i32);
+        self.as_mut().rust_mut().threads = threads;
+    }
+
+    pub fn row_count(&self, _parent: &QModelIndex) -> i32 {
+        self.rust().messages.len() as i32
+    }
+
+    pub fn role_names(&self) -> QHash<QHashPair_i32_QByteArray> {
+        list_model::role_names::<MessageRole>()
+    }
+
+    pub fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        let msg = usize::try_from(index.row()).ok().and_then(|i| self.rust().messages.get(i));
+        let (Some(msg), Some(role)) = (msg, MessageRole::from_role(role)) else {
+            return QVariant::default();
+        };
+        let text = |s: &str| QVariant::from(&QString::from(s));
+        match role {
+            MessageRole::MessageId => text(&msg.id),
+            MessageRole::ThreadId => text(&msg.thread_id),
+            MessageRole::Sender => text(&msg.from),
+            MessageRole::Subject => text(&msg.subject),
+            MessageRole::Snippet => text(&msg.snippet),
+            MessageRole::Date => text(&msg.date.to_rfc3339()),
+            MessageRole::DateLabel => text(&datefmt::relative(msg.date)),
+            MessageRole::IsUnread => QVariant::from(&msg.is_unread),
+            MessageRole::IsStarred => QVariant::from(&msg.is_starred),
+            MessageRole::CanUnsubscribe => QVariant::from(&msg.unsubscribe.is_some()),
+        }
+    }
+
+    pub fn mark_read_selected(self: Pin<&mut Self>, indices_json: QString) {
+        self.batch_selected(indices_json, GmailBatchOp::MarkRead);
+    }
+
+    pub fn archive_selected(self: Pin<&mut Self>, indices_json: QString) {
+        self.batch_selected(indices_json, GmailBatchOp::Archive);
+    }
+
+    pub fn trash_selected(self: Pin<&mut Self>, indices_json: QString) {
+        self.batch_selected(indices_json, GmailBatchOp::Trash);
+    }
+
+    fn batch_selected(mut self: Pin<&mut Self>, indices_json: QString, op: GmailBatchOp) {
+        let ids = self.as_ref().rust().ids_for_indices(&indices_json.to_string());
+        if ids.is_empty() {
+            return;
+        }
+
+        let access_token = match GmailModelRust::get_access_token() {
+            Some(t) => t,
+            None => return,
+        };
+
+        bridge::init_gmail_service_channel();
+        let tx = match bridge::get_gmail_service_tx() {
+            Some(t) => t,
+            None => return,
+        };
+
+        self.as_mut().set_loading(true);
+        request_gmail_batch(&tx, access_token, ids, op);
+    }
+
     /// Poll for async operation results
     pub fn poll_channel(mut self: Pin<&mut Self>) {
         let msg = match bridge::try_recv_gmail_message() {
@@ -220,12 +935,9 @@ impl qobject::GmailModel {
 
                 match result {
                     Ok(messages) => {
-                        let unread = messages.iter().filter(|m| m.is_unread).count();
-                        self.as_mut().set_unread_count(unread as i32);
-                        self.as_mut().set_message_count(messages.len() as i32);
-                        self.as_mut().rust_mut().messages = messages;
                         self.as_mut().rust_mut().clear_error();
-                        self.as_mut().messages_changed();
+                        self.as_mut().show_messages(messages);
+                        self.as_mut().refresh_cache_usage();
                     }
                     Err(e) => {
                         self.as_mut()
@@ -243,6 +955,105 @@ impl qobject::GmailModel {
                     self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
                 }
             },
+            GmailServiceMessage::BatchActionDone(result) => match result {
+                Ok(msg_ids) => {
+                    for msg_id in &msg_ids {
+                        self.as_mut().message_updated(QString::from(msg_id));
+                    }
+                    self.fetch_messages();
+                }
+                Err(e) => {
+                    self.as_mut().set_loading(false);
+                    self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                }
+            },
+            GmailServiceMessage::ThreadDone(result) => {
+                self.as_mut().set_loading(false);
+                match result {
+                    Ok(thread) => {
+                        let thread_id = thread.id.clone();
+                        self.as_mut().rust_mut().merge_thread(thread);
+                        let count = self.rust().threads.len() as i32;
+                        self.as_mut().set_thread_count(count);
+                        self.as_mut().thread_loaded(QString::from(&thread_id));
+                        self.as_mut().refresh_cache_usage();
+                    }
+                    Err(e) => {
+                        self.as_mut()
+                            .rust_mut()
+                            .set_error(myme_core::AppError::from(e).user_message());
+                    }
+                }
+            }
+            GmailServiceMessage::AttachmentDone(result) => {
+                self.as_mut().set_loading(false);
+                match result {
+                    Ok(att) => {
+                        let path = att.local_path.clone().unwrap_or_default();
+                        GmailModelRust::open_attachment_file(&att, &path);
+                        self.as_mut().attachment_ready(
+                            QString::from(&att.attachment_id),
+                            QString::from(&path),
+                        );
+                        self.as_mut().refresh_cache_usage();
+                    }
+                    Err(e) => {
+                        self.as_mut()
+                            .rust_mut()
+                            .set_error(myme_core::AppError::from(e).user_message());
+                    }
+                }
+            }
+            GmailServiceMessage::SnoozeDone(result) => {
+                self.as_mut().set_loading(false);
+                match result {
+                    Ok(msg_id) => {
+                        if let Some(i) = self.rust().messages.iter().position(|m| m.id == msg_id) {
+                            self.as_mut().remove_rows(i, 1, |rust| {
+                                rust.messages.remove(i);
+                            });
+                            self.as_mut().refresh_counts();
+                        }
+                    }
+                    Err(e) => {
+                        self.as_mut()
+                            .rust_mut()
+                            .set_error(myme_core::AppError::from(e).user_message());
+                    }
+                }
+            }
+            GmailServiceMessage::Resurfaced(resurfaced) => {
+                let count = resurfaced.len() as i32;
+                let mut messages = self.rust().messages.clone();
+                for msg in resurfaced {
+                    if !messages.iter().any(|m| m.id == msg.id) {
+                        messages.push(msg);
+                    }
+                }
+                messages.sort_by(|a, b| b.date.cmp(&a.date));
+                self.as_mut().show_messages(messages);
+                self.as_mut().messages_resurfaced(count);
+            }
+            GmailServiceMessage::UnsubscribeDone(result) => {
+                self.as_mut().set_loading(false);
+                match result {
+                    Ok((sender, outcome)) => {
+                        self.as_mut().rust_mut().clear_error();
+                        let outcome = match outcome {
+                            Unsubscribed::OneClick => "one_click",
+                            Unsubscribed::Drafted => "drafted",
+                        };
+                        self.as_mut().unsubscribed(QString::from(&sender), QString::from(outcome));
+                    }
+                    Err(e) => {
+                        self.as_mut()
+                            .rust_mut()
+                            .set_error(myme_core::AppError::from(e).user_message());
+                    }
+                }
+            }
         }
     }
 }
+
+list_rows!(qobject::GmailModel);
@@ -9,7 +9,7 @@ use std::sync::mpsc;
 
 use cxx_qt::CxxQtType;
 use cxx_qt_lib::QString;
-use myme_auth::{GoogleOAuth2Provider, SecureStorage, TokenSet};
+use myme_auth::{declined_features, GoogleFeature, GoogleOAuth2Provider, SecureStorage, TokenSet};
 
 #[cxx_qt::bridge]
 pub mod qobject {
@@ -25,11 +25,26 @@ pub mod qobject {
         #[qproperty(bool, authenticated)]
         #[qproperty(QString, error_message)]
         #[qproperty(QString, user_email)]
+        #[qproperty(bool, gmail_enabled)]
+        #[qproperty(bool, calendar_enabled)]
+        #[qproperty(bool, gmail_granted)]
+        #[qproperty(bool, calendar_granted)]
+        #[qproperty(QString, declined_features)]
         type GoogleAuthModel = super::GoogleAuthModelRust;
 
+        /// Connect, requesting access for the features enabled in settings
         #[qinvokable]
         fn authenticate(self: Pin<&mut GoogleAuthModel>);
 
+        /// Turn "gmail" or "calendar" on or off. Enabling a feature whose scope
+        /// wasn't granted yet asks Google for just that scope.
+        #[qinvokable]
+        fn set_feature_enabled(self: Pin<&mut GoogleAuthModel>, feature: QString, enabled: bool);
+
+        /// Ask again for the features the user declined on the consent screen
+        #[qinvokable]
+        fn reconsent(self: Pin<&mut GoogleAuthModel>);
+
         #[qinvokable]
         fn check_auth(self: Pin<&mut GoogleAuthModel>);
 
@@ -54,7 +69,13 @@ pub mod qobject {
 
 /// Messages for async operations
 enum AuthMessage {
-    AuthenticateDone(Result<String, String>), // Result<access_token, error>
+    AuthenticateDone(Result<AuthOutcome, String>),
+}
+
+struct AuthOutcome {
+    email: String,
+    /// Requested features left unticked on the consent screen
+    declined: Vec<GoogleFeature>,
 }
 
 /// Operation state tracking
@@ -71,8 +92,14 @@ pub struct GoogleAuthModelRust {
     authenticated: bool,
     error_message: QString,
     user_email: QString,
+    gmail_enabled: bool,
+    calendar_enabled: bool,
+    gmail_granted: bool,
+    calendar_granted: bool,
+    declined_features: QString,
     op_state: OpState,
     rx: Option<mpsc::Receiver<AuthMessage>>,
+    declined: Vec<GoogleFeature>,
 }
 
 impl GoogleAuthModelRust {
@@ -98,6 +125,18 @@ impl GoogleAuthModelRust {
         }
     }
 
+    /// Features switched on in config.toml (both by default).
+    fn enabled_features() -> Vec<GoogleFeature> {
+        let google = myme_core::Config::load().ok().and_then(|c| c.google).unwrap_or_default();
+        GoogleFeature::ALL
+            .into_iter()
+            .filter(|f| match f {
+                GoogleFeature::Gmail => google.gmail,
+                GoogleFeature::Calendar => google.calendar,
+            })
+            .collect()
+    }
+
     /// Path to config.toml for display in error messages (platform-specific).
     fn config_display_path() -> String {
         dirs::config_dir()
@@ -108,9 +147,80 @@ impl GoogleAuthModelRust {
     }
 }
 
+fn parse_feature(name: &str) -> Option<GoogleFeature> {
+    match name {
+        "gmail" => Some(GoogleFeature::Gmail),
+        "calendar" => Some(GoogleFeature::Calendar),
+        _ => None,
+    }
+}
+
 impl qobject::GoogleAuthModel {
     /// Start Google OAuth authentication flow (non-blocking)
     pub fn authenticate(mut self: Pin<&mut Self>) {
+        let features = GoogleAuthModelRust::enabled_features();
+        if features.is_empty() {
+            self.as_mut()
+                .set_error_message(QString::from("Turn on Gmail or Calendar before connecting"));
+            return;
+        }
+        self.start_flow(features, false);
+    }
+
+    pub fn set_feature_enabled(mut self: Pin<&mut Self>, feature: QString, enabled: bool) {
+        let Some(feature) = parse_feature(&feature.to_string()) else {
+            return;
+        };
+        let mut config = match myme_core::Config::load() {
+            Ok(config) => config,
+            Err(e) => {
+                tracing::warn!("Failed to load config: {}", e);
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                return;
+            }
+        };
+        let google = config.google.get_or_insert_with(Default::default);
+        match feature {
+            GoogleFeature::Gmail => google.gmail = enabled,
+            GoogleFeature::Calendar => google.calendar = enabled,
+        }
+        if let Err(e) = config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+            self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            return;
+        }
+        self.as_mut().refresh_features();
+
+        // Incremental authorization: ask only for the newly enabled scope
+        let granted = match feature {
+            GoogleFeature::Gmail => *self.gmail_granted(),
+            GoogleFeature::Calendar => *self.calendar_granted(),
+        };
+        if enabled && *self.authenticated() && !granted {
+            self.start_flow(vec![feature], true);
+        }
+    }
+
+    pub fn reconsent(self: Pin<&mut Self>) {
+        let declined = self.as_ref().rust().declined.clone();
+        if declined.is_empty() {
+            return;
+        }
+        let incremental = *self.authenticated();
+        self.start_flow(declined, incremental);
+    }
+
+    /// Sync the feature properties with config and the stored token.
+    fn refresh_features(mut self: Pin<&mut Self>) {
+        let enabled = GoogleAuthModelRust::enabled_features();
+        let granted = SecureStorage::retrieve_token("google").map(|t| t.scopes).unwrap_or_default();
+        self.as_mut().set_gmail_enabled(enabled.contains(&GoogleFeature::Gmail));
+        self.as_mut().set_calendar_enabled(enabled.contains(&GoogleFeature::Calendar));
+        self.as_mut().set_gmail_granted(GoogleFeature::Gmail.is_granted(&granted));
+        self.as_mut().set_calendar_granted(GoogleFeature::Calendar.is_granted(&granted));
+    }
+
+    fn start_flow(mut self: Pin<&mut Self>, features: Vec<GoogleFeature>, incremental: bool) {
         // Prevent concurrent operations
         if !matches!(self.as_ref().rust().op_state, OpState::Idle) {
             tracing::warn!("authenticate: operation already in progress");
@@ -145,13 +255,16 @@ impl qobject::GoogleAuthModel {
             #[allow(clippy::unwrap_used)]
             let rt = tokio::runtime::Runtime::new().unwrap();
             let result = rt.block_on(async {
-                let provider = GoogleOAuth2Provider::new(client_id, client_secret);
+                let proxies = myme_core::Config::load_cached().proxy.proxies_for("google_auth");
+                let provider =
+                    GoogleOAuth2Provider::new(client_id, client_secret).with_proxies(proxies);
 
                 // Find available port
                 let port = find_available_port(8080, 8089).ok_or("No available port")?;
 
                 // Generate auth URL
-                let (auth_url, expected_state) = provider.authorization_url(port);
+                let (auth_url, expected_state) =
+                    provider.authorization_url_for(port, &features, incremental);
 
                 // Open browser
                 if let Err(e) = webbrowser::open(&auth_url) {
@@ -176,10 +289,15 @@ impl qobject::GoogleAuthModel {
                 // Calculate expiration
                 let expires_at = chrono::Utc::now().timestamp() + token_response.expires_in as i64;
 
+                // Google may skip the refresh token on an incremental grant; keep the old one
+                let refresh_token = token_response.refresh_token.or_else(|| {
+                    SecureStorage::retrieve_token("google").ok().and_then(|t| t.refresh_token)
+                });
+
                 // Store tokens securely
                 let token_set = TokenSet {
                     access_token: token_response.access_token.clone(),
-                    refresh_token: token_response.refresh_token,
+                    refresh_token,
                     expires_at,
                     scopes: token_response.scope.split(' ').map(|s| s.to_string()).collect(),
                 };
@@ -187,8 +305,12 @@ impl qobject::GoogleAuthModel {
                 SecureStorage::store_token("google", &token_set)
                     .map_err(|e| format!("Failed to store token: {}", e))?;
 
+                let declined = declined_features(&features, &token_response.scope);
+                if !declined.is_empty() {
+                    tracing::warn!("Google consent skipped {:?}", declined);
+                }
                 tracing::info!("Google authentication successful for {}", user_info.email);
-                Ok(user_info.email)
+                Ok(AuthOutcome { email: user_info.email, declined })
             });
 
             let _ = tx.send(AuthMessage::AuthenticateDone(result));
@@ -208,11 +330,21 @@ impl qobject::GoogleAuthModel {
                 self.as_mut().rust_mut().op_state = OpState::Idle;
 
                 match result {
-                    Ok(email) => {
+                    Ok(AuthOutcome { email, declined }) => {
                         tracing::info!("Google authentication completed");
                         self.as_mut().rust_mut().clear_error();
+                        let labels: Vec<&str> = declined.iter().map(|f| f.label()).collect();
+                        if !labels.is_empty() {
+                            self.as_mut().rust_mut().set_error(&format!(
+                                "Access to {} wasn't granted. Grant it, or turn the feature off.",
+                                labels.join(" and ")
+                            ));
+                        }
+                        self.as_mut().set_declined_features(QString::from(&labels.join(", ")));
+                        self.as_mut().rust_mut().declined = declined;
                         self.as_mut().set_authenticated(true);
                         self.as_mut().set_user_email(QString::from(&email));
+                        self.as_mut().refresh_features();
                         self.as_mut().auth_changed();
                         self.as_mut().auth_completed();
                     }
@@ -228,6 +360,7 @@ impl qobject::GoogleAuthModel {
 
     /// Check if currently authenticated
     pub fn check_auth(mut self: Pin<&mut Self>) {
+        self.as_mut().refresh_features();
         let is_authenticated = SecureStorage::has_token("google");
 
         if is_authenticated {
@@ -277,7 +410,10 @@ impl qobject::GoogleAuthModel {
                 tracing::info!("Signed out from Google successfully");
                 self.as_mut().set_authenticated(false);
                 self.as_mut().set_user_email(QString::from(""));
+                self.as_mut().set_declined_features(QString::default());
+                self.as_mut().rust_mut().declined.clear();
                 self.as_mut().rust_mut().clear_error();
+                self.as_mut().refresh_features();
                 self.as_mut().auth_changed();
             }
             Err(e) => {
@@ -0,0 +1,57 @@
+//! Cached remote images for QML.
+//!
+//! `source(url)` gives a local file for an avatar or thumbnail URL once it's
+//! downloaded. `revision` changes when downloads land, so bindings that read
+//! it re-evaluate (see `AppContext.imageSource`).
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+
+use crate::services::{image_generation, image_source};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, revision)]
+        type ImageCacheModel = super::ImageCacheModelRust;
+
+        /// Local `file://` URL for `url` once cached, else empty while it downloads
+        #[qinvokable]
+        fn source(self: &ImageCacheModel, url: &QString) -> QString;
+
+        /// Pick up finished downloads. Call this from a QML Timer.
+        #[qinvokable]
+        fn refresh(self: Pin<&mut ImageCacheModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct ImageCacheModelRust {
+    revision: i32,
+    generation: u64,
+}
+
+impl qobject::ImageCacheModel {
+    pub fn source(&self, url: &QString) -> QString {
+        QString::from(image_source(&url.to_string()).as_str())
+    }
+
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        let generation = image_generation();
+        if generation == self.rust().generation {
+            return;
+        }
+        self.as_mut().rust_mut().generation = generation;
+        let revision = self.rust().revision.wrapping_add(1);
+        self.as_mut().set_revision(revision);
+    }
+}
@@ -0,0 +1,194 @@
+//! Import model for QML: pick a Todoist, Google Keep or Trello export, preview
+//! what it would add (duplicates dropped), then create the notes and tasks.
+
+use core::pin::Pin;
+use std::path::PathBuf;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::{ImportReport, ImportSource};
+
+use crate::bridge;
+use crate::services::{request_import, ImportServiceMessage};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(QString, error_message)]
+        type ImportModel = super::ImportModelRust;
+
+        /// Supported formats as a JSON array: id, label
+        #[qinvokable]
+        fn get_sources(self: &ImportModel) -> QString;
+
+        /// Projects tasks can be imported into, as a JSON array: id, name
+        #[qinvokable]
+        fn get_projects(self: &ImportModel) -> QString;
+
+        /// Source id guessed from the file (or Keep folder); empty if unknown
+        #[qinvokable]
+        fn detect_source(self: &ImportModel, path: &QString) -> QString;
+
+        /// Dry run: count what importing `path` would create
+        #[qinvokable]
+        fn preview(
+            self: Pin<&mut ImportModel>,
+            path: &QString,
+            source: &QString,
+            project_id: &QString,
+        );
+
+        /// Create the notes and tasks; tasks go to `project_id`
+        #[qinvokable]
+        fn run_import(
+            self: Pin<&mut ImportModel>,
+            path: &QString,
+            source: &QString,
+            project_id: &QString,
+        );
+
+        /// Last preview or import as JSON: source, notes, tasks, duplicates,
+        /// warnings, dry_run. Empty object before the first one.
+        #[qinvokable]
+        fn get_report(self: &ImportModel) -> QString;
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut ImportModel>);
+
+        #[qsignal]
+        fn report_changed(self: Pin<&mut ImportModel>);
+
+        /// The import finished and created notes or tasks
+        #[qsignal]
+        fn imported(self: Pin<&mut ImportModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct ImportModelRust {
+    loading: bool,
+    error_message: QString,
+    report: Option<ImportReport>,
+}
+
+impl qobject::ImportModel {
+    pub fn get_sources(&self) -> QString {
+        let rows: Vec<serde_json::Value> = [
+            ImportSource::TodoistCsv,
+            ImportSource::TodoistJson,
+            ImportSource::GoogleKeep,
+            ImportSource::Trello,
+        ]
+        .iter()
+        .map(|s| serde_json::json!({ "id": s.as_str(), "label": s.label() }))
+        .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn get_projects(&self) -> QString {
+        let projects = bridge::get_project_store_or_init()
+            .map(|store| store.list_projects())
+            .transpose()
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to list projects: {}", e);
+                None
+            })
+            .unwrap_or_default();
+        let rows: Vec<serde_json::Value> =
+            projects.iter().map(|p| serde_json::json!({ "id": p.id, "name": p.name })).collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn detect_source(&self, path: &QString) -> QString {
+        let path = PathBuf::from(path.to_string().trim());
+        QString::from(ImportSource::detect(&path).map(|s| s.as_str()).unwrap_or(""))
+    }
+
+    pub fn preview(self: Pin<&mut Self>, path: &QString, source: &QString, project_id: &QString) {
+        self.start(path, source, project_id, true);
+    }
+
+    pub fn run_import(
+        self: Pin<&mut Self>,
+        path: &QString,
+        source: &QString,
+        project_id: &QString,
+    ) {
+        self.start(path, source, project_id, false);
+    }
+
+    pub fn get_report(&self) -> QString {
+        let json = match &self.rust().report {
+            Some(report) => serde_json::to_string(report).unwrap_or_else(|_| "{}".to_string()),
+            None => "{}".to_string(),
+        };
+        QString::from(&json)
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let Some(msg) = bridge::try_recv_import_message() else {
+            return;
+        };
+        self.as_mut().set_loading(false);
+        let (result, dry_run) = match msg {
+            ImportServiceMessage::PreviewDone(result) => (result, true),
+            ImportServiceMessage::ImportDone(result) => (result, false),
+        };
+        match result {
+            Ok(report) => {
+                let created = report.notes + report.tasks > 0;
+                self.as_mut().rust_mut().report = Some(report);
+                self.as_mut().report_changed();
+                if !dry_run && created {
+                    self.as_mut().imported();
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Import failed: {}", e);
+                let msg = myme_core::AppError::from(e).user_message().to_string();
+                self.as_mut().set_error_message(QString::from(&msg));
+            }
+        }
+    }
+
+    fn start(
+        mut self: Pin<&mut Self>,
+        path: &QString,
+        source: &QString,
+        project_id: &QString,
+        dry_run: bool,
+    ) {
+        let path = PathBuf::from(path.to_string().trim());
+        if !path.exists() {
+            self.as_mut()
+                .set_error_message(QString::from(&format!("{} not found", path.display())));
+            return;
+        }
+        let Some(source) = ImportSource::parse(&source.to_string()) else {
+            self.as_mut()
+                .set_error_message(QString::from("Choose what the file was exported from"));
+            return;
+        };
+        let Some(client) = bridge::get_note_client_or_init() else {
+            self.as_mut().set_error_message(QString::from("Notes are not available"));
+            return;
+        };
+        bridge::init_import_service_channel();
+        let Some(tx) = bridge::get_import_service_tx() else {
+            return;
+        };
+        let project_id = Some(project_id.to_string()).filter(|p| !p.is_empty());
+
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_loading(true);
+        request_import(&tx, client, path, source, project_id, dry_run);
+    }
+}
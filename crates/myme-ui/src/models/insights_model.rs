@@ -0,0 +1,233 @@
+//! GitHub insights model for QML: stars, traffic, contributors and community
+//! health for one repository at a time.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::{GitHubRepo, TrafficSummary};
+
+use crate::bridge;
+use crate::services::{
+    request_insights_fetch, request_insights_repos, InsightsServiceMessage, RepoInsights,
+};
+
+/// Contributors listed on the panel
+const TOP_CONTRIBUTORS: usize = 10;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(bool, authenticated)]
+        #[qproperty(QString, error_message)]
+        #[qproperty(QString, repo)]
+        type InsightsModel = super::InsightsModelRust;
+
+        #[qinvokable]
+        fn check_auth(self: Pin<&mut InsightsModel>);
+
+        /// Fetch the repositories to choose from; emits `repos_changed`
+        #[qinvokable]
+        fn load_repos(self: Pin<&mut InsightsModel>);
+
+        /// Show insights for `full_name`, from the cache when fresh
+        #[qinvokable]
+        fn load(self: Pin<&mut InsightsModel>, full_name: &QString);
+
+        /// Fetch the current repo's insights again, bypassing the cache
+        #[qinvokable]
+        fn refresh(self: Pin<&mut InsightsModel>);
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut InsightsModel>);
+
+        /// Non-fork repositories as a JSON array of `owner/repo` names
+        #[qinvokable]
+        fn get_repos(self: &InsightsModel) -> QString;
+
+        /// Insights for `repo` as a JSON object (`{}` before the first fetch)
+        #[qinvokable]
+        fn get_insights(self: &InsightsModel) -> QString;
+
+        #[qsignal]
+        fn repos_changed(self: Pin<&mut InsightsModel>);
+
+        #[qsignal]
+        fn insights_changed(self: Pin<&mut InsightsModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct InsightsModelRust {
+    loading: bool,
+    authenticated: bool,
+    error_message: QString,
+    /// `owner/repo` of the insights shown
+    repo: QString,
+    repos: Vec<GitHubRepo>,
+    insights: Option<RepoInsights>,
+    /// Requests sent but not yet answered
+    pending: u32,
+}
+
+fn traffic_json(summary: &TrafficSummary) -> serde_json::Value {
+    let points: Vec<serde_json::Value> = summary
+        .points
+        .iter()
+        .map(|p| {
+            serde_json::json!({
+                "date": p.timestamp.get(..10).unwrap_or(&p.timestamp),
+                "count": p.count,
+                "uniques": p.uniques,
+            })
+        })
+        .collect();
+    serde_json::json!({ "count": summary.count, "uniques": summary.uniques, "points": points })
+}
+
+fn insights_json(insights: &RepoInsights) -> serde_json::Value {
+    let repo = &insights.repo;
+    let contributors: Vec<serde_json::Value> = insights
+        .contributors
+        .iter()
+        .take(TOP_CONTRIBUTORS)
+        .map(|c| {
+            serde_json::json!({
+                "login": c.login,
+                "avatarUrl": c.avatar_url,
+                "htmlUrl": c.html_url,
+                "contributions": c.contributions,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "fullName": repo.full_name,
+        "htmlUrl": repo.html_url,
+        "stars": repo.stargazers_count,
+        "forks": repo.forks_count,
+        "openIssues": repo.open_issues_count,
+        "hasTraffic": insights.traffic.is_some(),
+        "views": insights.traffic.as_ref().map(|t| traffic_json(&t.views)),
+        "clones": insights.traffic.as_ref().map(|t| traffic_json(&t.clones)),
+        "contributors": contributors,
+        "contributorCount": insights.contributors.len(),
+        "health": insights.community.as_ref().map_or(-1, |c| c.health_percentage as i64),
+        "missingFiles": insights.community.as_ref().map(|c| c.missing_files()).unwrap_or_default(),
+        "fetchedAt": insights.fetched_at.to_rfc3339(),
+    })
+}
+
+impl qobject::InsightsModel {
+    pub fn check_auth(mut self: Pin<&mut Self>) {
+        let auth = bridge::get_github_client_and_runtime().is_some();
+        if self.as_ref().rust().authenticated != auth {
+            self.as_mut().set_authenticated(auth);
+        }
+    }
+
+    /// Client and channel for a request, flagging the model when either is missing
+    fn begin_request(
+        mut self: Pin<&mut Self>,
+    ) -> Option<(
+        std::sync::Arc<myme_services::GitHubClient>,
+        std::sync::mpsc::Sender<InsightsServiceMessage>,
+    )> {
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            self.as_mut().set_authenticated(false);
+            return None;
+        };
+        bridge::init_insights_service_channel();
+        let Some(tx) = bridge::get_insights_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Insights service channel not ready"));
+            return None;
+        };
+        self.as_mut().rust_mut().pending += 1;
+        self.as_mut().set_loading(true);
+        Some((client, tx))
+    }
+
+    pub fn load_repos(mut self: Pin<&mut Self>) {
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_insights_repos(&tx, client);
+        }
+    }
+
+    pub fn load(mut self: Pin<&mut Self>, full_name: &QString) {
+        let full_name = full_name.to_string().trim().to_string();
+        if full_name.is_empty() {
+            return;
+        }
+        self.as_mut().set_repo(QString::from(&full_name));
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_insights_fetch(&tx, client, full_name, false);
+        }
+    }
+
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        let full_name = self.rust().repo.to_string();
+        if full_name.is_empty() || self.rust().loading {
+            return;
+        }
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_insights_fetch(&tx, client, full_name, true);
+        }
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        while let Some(msg) = bridge::try_recv_insights_message() {
+            let pending = self.rust().pending.saturating_sub(1);
+            self.as_mut().rust_mut().pending = pending;
+            if pending == 0 {
+                self.as_mut().set_loading(false);
+            }
+
+            match msg {
+                InsightsServiceMessage::ReposDone(Ok(repos)) => {
+                    let mut repos: Vec<GitHubRepo> =
+                        repos.into_iter().filter(|r| !r.fork).collect();
+                    repos.sort_by(|a, b| b.stargazers_count.cmp(&a.stargazers_count));
+                    self.as_mut().rust_mut().repos = repos;
+                    self.as_mut().repos_changed();
+                }
+                InsightsServiceMessage::FetchDone(Ok(insights)) => {
+                    // Ignore answers for a repo the user has since moved away from
+                    if !insights.repo.full_name.eq_ignore_ascii_case(&self.rust().repo.to_string())
+                    {
+                        continue;
+                    }
+                    self.as_mut().set_error_message(QString::from(""));
+                    self.as_mut().rust_mut().insights = Some(insights);
+                    self.as_mut().insights_changed();
+                }
+                InsightsServiceMessage::ReposDone(Err(e))
+                | InsightsServiceMessage::FetchDone(Err(e)) => {
+                    let text = myme_core::AppError::from(e).user_message();
+                    self.as_mut().set_error_message(QString::from(text));
+                }
+            }
+        }
+    }
+
+    pub fn get_repos(&self) -> QString {
+        let names: Vec<&str> = self.rust().repos.iter().map(|r| r.full_name.as_str()).collect();
+        QString::from(serde_json::to_string(&names).unwrap_or_else(|_| "[]".into()))
+    }
+
+    pub fn get_insights(&self) -> QString {
+        let json = self
+            .rust()
+            .insights
+            .as_ref()
+            .map(insights_json)
+            .unwrap_or_else(|| serde_json::json!({}));
+        QString::from(json.to_string())
+    }
+}
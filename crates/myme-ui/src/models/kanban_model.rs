@@ -1,66 +1,329 @@
 // crates/myme-ui/src/models/kanban_model.rs
 
 use core::pin::Pin;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use cxx_qt::CxxQtType;
-use cxx_qt_lib::QString;
-use myme_services::{ProjectStore, Task, TaskStatus};
+use cxx_qt_lib::{QHash, QHashPair_i32_QByteArray, QModelIndex, QString, QVariant};
+use myme_core::WipEnforcement;
+use myme_services::{
+    checklist, CreateIssueRequest, EntityKind, EntityRef, IssueTemplate, ProjectColumn,
+    ProjectStore, Task, TaskStatus, TemplateKind,
+};
+
+use crate::bridge;
+use crate::models::list_model::{self, list_rows, ListRole};
+use crate::services::activity_service::{self, UndoHint};
+use crate::services::google_common::get_google_access_token;
+use crate::services::{
+    build_template, diff_tasks, parse_template_schedule, publish_ui_event,
+    request_kanban_checklist_item, request_kanban_create, request_kanban_templates,
+    request_schedule_task, request_sync_task_schedules, template_context_today, templates_json,
+    KanbanServiceMessage, TaskListChange, UiEvent, UiSeverity,
+};
 
 #[cxx_qt::bridge]
 pub mod qobject {
     unsafe extern "C++" {
+        include!(<QtCore/QAbstractListModel>);
+        type QAbstractListModel;
+
         include!("cxx-qt-lib/qstring.h");
+        include!("cxx-qt-lib/qvariant.h");
+        include!("cxx-qt-lib/qmodelindex.h");
+        include!("cxx-qt-lib/qhash.h");
+        include!("cxx-qt-lib/qvector.h");
         type QString = cxx_qt_lib::QString;
+        type QVariant = cxx_qt_lib::QVariant;
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        type QHash_i32_QByteArray = cxx_qt_lib::QHash<cxx_qt_lib::QHashPair_i32_QByteArray>;
+        type QVector_i32 = cxx_qt_lib::QVector<i32>;
     }
 
     extern "RustQt" {
         #[qobject]
         #[qml_element]
+        #[base = QAbstractListModel]
         #[qproperty(bool, loading)]
         #[qproperty(QString, error_message)]
         #[qproperty(QString, project_id)]
         #[qproperty(QString, repo_ids)]
+        #[qproperty(QString, filter)]
+        #[qproperty(bool, templates_loading)]
         type KanbanModel = super::KanbanModelRust;
 
         #[qinvokable]
         fn load_project(self: Pin<&mut KanbanModel>, project_id: QString);
 
+        /// Task at index for the edit dialog, as JSON: {"id","title","body","issueUrl"}
         #[qinvokable]
-        fn get_repo_id(self: &KanbanModel, task_index: i32) -> QString;
+        fn get_task(self: &KanbanModel, index: i32) -> QString;
 
+        /// Returns JSON array of the task's checklist items: [{"text","checked"}]
         #[qinvokable]
-        fn row_count(self: &KanbanModel) -> i32;
+        fn get_subtasks(self: &KanbanModel, index: i32) -> QString;
 
+        /// Tick or untick checklist item `item` in the task body, and in its
+        /// GitHub issue when the task has one
         #[qinvokable]
-        fn get_task_number(self: &KanbanModel, index: i32) -> i32;
+        fn set_subtask_checked(self: Pin<&mut KanbanModel>, index: i32, item: i32, checked: bool);
 
+        /// Tasks with a status that match the board filter
         #[qinvokable]
-        fn get_title(self: &KanbanModel, index: i32) -> QString;
+        fn count_by_status(self: &KanbanModel, status: QString) -> i32;
 
+        /// Returns JSON array of indices of tasks with a status that match the board filter
         #[qinvokable]
-        fn get_body(self: &KanbanModel, index: i32) -> QString;
+        fn tasks_for_status(self: &KanbanModel, status: QString) -> QString;
 
+        /// Returns JSON array of board columns in order:
+        /// [{"id","name","label","status","color","wipLimit","overWip"}]
+        /// (`wipLimit` is 0 when the column has none)
         #[qinvokable]
-        fn get_status(self: &KanbanModel, index: i32) -> QString;
+        fn get_columns(self: &KanbanModel) -> QString;
 
+        /// Every task in a column, filtered or not (WIP limits count them all)
         #[qinvokable]
-        fn count_by_status(self: &KanbanModel, status: QString) -> i32;
+        fn count_in_column(self: &KanbanModel, column_id: QString) -> i32;
 
+        /// Returns JSON array of task indices shown in a column (matching the board filter)
         #[qinvokable]
-        fn tasks_for_status(self: &KanbanModel, status: QString) -> QString;
+        fn tasks_for_column(self: &KanbanModel, column_id: QString) -> QString;
+
+        /// Position of the task at index among all cards of its column, or -1
+        #[qinvokable]
+        fn position_in_column(self: &KanbanModel, index: i32) -> i32;
+
+        /// Append a column; `label` is the GitHub label it maps to (may be empty)
+        #[qinvokable]
+        fn add_column(self: Pin<&mut KanbanModel>, name: QString, label: QString);
+
+        #[qinvokable]
+        fn rename_column(self: Pin<&mut KanbanModel>, column_id: QString, name: QString);
+
+        #[qinvokable]
+        fn set_column_label(self: Pin<&mut KanbanModel>, column_id: QString, label: QString);
+
+        /// Limit how many tasks a column holds at once; 0 or less removes the limit
+        #[qinvokable]
+        fn set_column_wip_limit(self: Pin<&mut KanbanModel>, column_id: QString, limit: i32);
+
+        /// Whether a column (column id, or a status key for the default board)
+        /// holds more tasks than its WIP limit
+        #[qinvokable]
+        fn is_over_wip(self: &KanbanModel, status: QString) -> bool;
+
+        /// Move a column left (negative offset) or right (positive offset)
+        #[qinvokable]
+        fn move_column(self: Pin<&mut KanbanModel>, column_id: QString, offset: i32);
+
+        /// Move a task to a column (column id, or a status key for the default board).
+        /// A column at its WIP limit warns or refuses (`projects.wip_enforcement`).
+        /// Returns whether the task moved.
+        #[qinvokable]
+        fn move_task(self: Pin<&mut KanbanModel>, index: i32, new_status: QString) -> bool;
 
+        /// Move a card to `new_position` among the cards of its column;
+        /// the new order is saved for the whole project
         #[qinvokable]
-        fn move_task(self: Pin<&mut KanbanModel>, index: i32, new_status: QString);
+        fn reorder_task(self: Pin<&mut KanbanModel>, index: i32, new_position: i32);
 
+        /// Create a task in a column (column id, or a status key)
         #[qinvokable]
         fn create_task(self: Pin<&mut KanbanModel>, title: QString, body: QString, status: QString);
 
         #[qinvokable]
         fn update_task(self: Pin<&mut KanbanModel>, index: i32, title: QString, body: QString);
 
+        /// Set the board filter (words matched against task titles and bodies);
+        /// persisted per project in the workspace session
+        #[qinvokable]
+        fn apply_filter(self: Pin<&mut KanbanModel>, filter: QString);
+
+        /// Returns JSON array of issue templates from the project's repos:
+        /// [{"name","about","title","labels","body"}]
+        #[qinvokable]
+        fn get_templates(self: &KanbanModel) -> QString;
+
+        /// Saved issue templates (see `save_issue_template`) as a JSON array
+        #[qinvokable]
+        fn get_saved_templates(self: &KanbanModel) -> QString;
+
+        /// Create (empty `id`) or update a saved issue template for this project.
+        /// `schedule` is "", "daily", "weekly:mon" or "monthly:1"; scheduled issues
+        /// are opened in `repo_id`. Returns the template id, or "" on error.
+        #[qinvokable]
+        fn save_issue_template(
+            self: Pin<&mut KanbanModel>,
+            id: QString,
+            name: QString,
+            title: QString,
+            body: QString,
+            repo_id: QString,
+            schedule: QString,
+        ) -> QString;
+
+        #[qinvokable]
+        fn delete_saved_template(self: Pin<&mut KanbanModel>, id: QString) -> bool;
+
+        /// Open a GitHub issue in `repo_id` (or the template's repo) from a saved
+        /// template and add it to the board. Without a repo only the card is added.
+        #[qinvokable]
+        fn create_issue_from_template(
+            self: Pin<&mut KanbanModel>,
+            template_id: QString,
+            repo_id: QString,
+        );
+
+        /// Block out time for a task on the primary Google calendar. `start` is
+        /// "YYYY-MM-DD HH:MM" local time. Completing the task ticks the event
+        /// off and vice versa. Returns false if the input is invalid (see
+        /// `error_message`); emits `task_scheduled` when the event exists.
+        #[qinvokable]
+        fn schedule_task(
+            self: Pin<&mut KanbanModel>,
+            task_id: QString,
+            start: QString,
+            duration_minutes: i32,
+        ) -> bool;
+
+        /// Time blocks for a task as a JSON array:
+        /// [{"eventId","start","end","label","done"}], earliest first
+        #[qinvokable]
+        fn get_schedules(self: &KanbanModel, task_id: QString) -> QString;
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut KanbanModel>);
+
+        #[cxx_override]
+        fn data(self: &KanbanModel, index: &QModelIndex, role: i32) -> QVariant;
+
+        #[cxx_override]
+        #[cxx_name = "roleNames"]
+        fn role_names(self: &KanbanModel) -> QHash_i32_QByteArray;
+
+        #[cxx_override]
+        #[cxx_name = "rowCount"]
+        fn row_count(self: &KanbanModel, parent: &QModelIndex) -> i32;
+
         #[qsignal]
-        fn tasks_changed(self: Pin<&mut KanbanModel>);
+        fn templates_changed(self: Pin<&mut KanbanModel>);
+
+        #[qsignal]
+        fn columns_changed(self: Pin<&mut KanbanModel>);
+
+        #[qsignal]
+        fn task_scheduled(self: Pin<&mut KanbanModel>, task_id: QString);
+    }
+
+    unsafe extern "RustQt" {
+        #[inherit]
+        #[cxx_name = "beginResetModel"]
+        fn begin_reset_model(self: Pin<&mut KanbanModel>);
+
+        #[inherit]
+        #[cxx_name = "endResetModel"]
+        fn end_reset_model(self: Pin<&mut KanbanModel>);
+
+        #[inherit]
+        #[cxx_name = "beginInsertRows"]
+        fn begin_insert_rows(
+            self: Pin<&mut KanbanModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        #[inherit]
+        #[cxx_name = "endInsertRows"]
+        fn end_insert_rows(self: Pin<&mut KanbanModel>);
+
+        #[inherit]
+        #[cxx_name = "beginRemoveRows"]
+        fn begin_remove_rows(
+            self: Pin<&mut KanbanModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        #[inherit]
+        #[cxx_name = "endRemoveRows"]
+        fn end_remove_rows(self: Pin<&mut KanbanModel>);
+
+        #[inherit]
+        #[cxx_name = "beginMoveRows"]
+        fn begin_move_rows(
+            self: Pin<&mut KanbanModel>,
+            source_parent: &QModelIndex,
+            source_first: i32,
+            source_last: i32,
+            destination_parent: &QModelIndex,
+            destination_child: i32,
+        ) -> bool;
+
+        #[inherit]
+        #[cxx_name = "endMoveRows"]
+        fn end_move_rows(self: Pin<&mut KanbanModel>);
+
+        #[inherit]
+        fn index(self: &KanbanModel, row: i32, column: i32, parent: &QModelIndex) -> QModelIndex;
+
+        #[inherit]
+        #[qsignal]
+        #[cxx_name = "dataChanged"]
+        fn data_changed(
+            self: Pin<&mut KanbanModel>,
+            top_left: &QModelIndex,
+            bottom_right: &QModelIndex,
+            roles: &QVector_i32,
+        );
+    }
+}
+
+/// Roles the board's task cards bind to
+#[derive(Clone, Copy)]
+enum TaskRole {
+    TaskId,
+    Title,
+    Body,
+    Status,
+    ColumnKey,
+    RepoId,
+    IssueNumber,
+    IssueUrl,
+    SubtaskProgress,
+    MatchesFilter,
+}
+
+impl ListRole for TaskRole {
+    const ALL: &'static [Self] = &[
+        TaskRole::TaskId,
+        TaskRole::Title,
+        TaskRole::Body,
+        TaskRole::Status,
+        TaskRole::ColumnKey,
+        TaskRole::RepoId,
+        TaskRole::IssueNumber,
+        TaskRole::IssueUrl,
+        TaskRole::SubtaskProgress,
+        TaskRole::MatchesFilter,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            TaskRole::TaskId => "taskId",
+            TaskRole::Title => "title",
+            TaskRole::Body => "body",
+            TaskRole::Status => "status",
+            TaskRole::ColumnKey => "columnKey",
+            TaskRole::RepoId => "repoId",
+            TaskRole::IssueNumber => "issueNumber",
+            TaskRole::IssueUrl => "issueUrl",
+            TaskRole::SubtaskProgress => "subtaskProgress",
+            TaskRole::MatchesFilter => "matchesFilter",
+        }
     }
 }
 
@@ -70,8 +333,17 @@ pub struct KanbanModelRust {
     error_message: QString,
     project_id: QString,
     repo_ids: QString,
+    filter: QString,
+    templates_loading: bool,
     tasks: Vec<Task>,
-    store: Option<Arc<parking_lot::Mutex<ProjectStore>>>,
+    columns: Vec<ProjectColumn>,
+    templates: Vec<IssueTemplate>,
+    store: Option<Arc<ProjectStore>>,
+    /// Repo of the issue being opened from a template, to link its card
+    pending_issue_repo: Option<String>,
+    /// Bodies of tasks from before checklist changes still being saved to
+    /// GitHub, restored if saving fails
+    pending_checklists: HashMap<String, Option<String>>,
 }
 
 impl KanbanModelRust {
@@ -117,6 +389,104 @@ impl KanbanModelRust {
         }
     }
 
+    /// Column id and status for a column id or (default board) status key
+    fn resolve_column(&self, key: &str) -> (Option<String>, TaskStatus) {
+        match self.columns.iter().find(|c| c.id == key) {
+            Some(column) => (Some(column.id.clone()), column.status),
+            None => (None, Self::status_from_string(key)),
+        }
+    }
+
+    /// GitHub issue (`owner/repo`, number) a task stands for: synced tasks
+    /// are named after their issue, others may be linked to one
+    fn linked_issue(&self, task: &Task) -> Option<(String, i32)> {
+        let parse = |id: &str| {
+            let (repo_id, number) = id.rsplit_once('#')?;
+            Some((repo_id.to_string(), number.parse().ok()?)).filter(|_| repo_id.contains('/'))
+        };
+        parse(&task.id).or_else(|| {
+            let links = self.store.as_ref()?.list_links(&EntityRef::task(&task.id)).ok()?;
+            links.iter().filter(|l| l.kind == EntityKind::Issue).find_map(|l| parse(&l.id))
+        })
+    }
+
+    fn issue_url(&self, task: &Task) -> Option<String> {
+        let (repo_id, number) = self.linked_issue(task)?;
+        Some(format!("https://github.com/{}/issues/{}", repo_id, number))
+    }
+
+    /// Checklist progress for a card, e.g. "3/7"; empty without a checklist
+    fn subtask_progress(&self, task: &Task) -> String {
+        let Some(store) = &self.store else {
+            return String::new();
+        };
+        match store.list_subtasks(&task.id) {
+            Ok(items) if !items.is_empty() => {
+                let (done, total) = checklist::progress(&items);
+                format!("{}/{}", done, total)
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// The column `task` would land in, if that puts it over its WIP limit
+    fn wip_exceeded_by(&self, task: &Task) -> Option<&ProjectColumn> {
+        let column = ProjectColumn::for_task(&self.columns, task)?;
+        let others = self
+            .tasks
+            .iter()
+            .filter(|t| t.id != task.id)
+            .filter(|t| {
+                ProjectColumn::for_task(&self.columns, t).is_some_and(|c| c.id == column.id)
+            })
+            .count();
+        column.is_over_wip(others + 1).then_some(column)
+    }
+
+    /// Whether `task` matches the board filter: every word of it appears in the
+    /// title or body, ignoring case. An empty filter matches every task.
+    fn matches_filter(&self, task: &Task) -> bool {
+        let filter = self.filter.to_string().to_lowercase();
+        let text =
+            format!("{}\n{}", task.title, task.body.as_deref().unwrap_or_default()).to_lowercase();
+        filter.split_whitespace().all(|word| text.contains(word))
+    }
+
+    fn task_indices_in_column(&self, column_id: &str) -> Vec<i32> {
+        self.tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| {
+                ProjectColumn::for_task(&self.columns, t).is_some_and(|c| c.id == column_id)
+            })
+            .map(|(i, _)| i as i32)
+            .collect()
+    }
+
+    /// Project-wide task id order after moving the card at `index` to
+    /// `new_position` within its column, or None if nothing changes.
+    /// Cards in other columns keep their places.
+    fn order_after_move(&self, index: i32, new_position: i32) -> Option<Vec<String>> {
+        let task = self.get_task(index)?;
+        let column_id = ProjectColumn::for_task(&self.columns, task)?.id.clone();
+        let column = self.task_indices_in_column(&column_id);
+        let from = column.iter().position(|&i| i == index)?;
+        let to = new_position.clamp(0, column.len() as i32 - 1) as usize;
+        if to == from {
+            return None;
+        }
+        let mut reordered = column.clone();
+        let moved = reordered.remove(from);
+        reordered.insert(to, moved);
+
+        let mut order: Vec<String> = self.tasks.iter().map(|t| t.id.clone()).collect();
+        for (slot, source) in column.iter().zip(&reordered) {
+            let id = self.get_task(*source)?.id.clone();
+            *order.get_mut(*slot as usize)? = id;
+        }
+        Some(order)
+    }
+
     fn set_error(&mut self, msg: &str) {
         self.error_message = QString::from(msg);
     }
@@ -138,6 +508,8 @@ impl qobject::KanbanModel {
             }
         };
 
+        // Reloading the open board (after a sync) animates the difference
+        let reload = self.rust().project_id == project_id;
         self.as_mut().set_loading(true);
         self.as_mut().rust_mut().clear_error();
         self.as_mut().set_project_id(project_id.clone());
@@ -145,9 +517,16 @@ impl qobject::KanbanModel {
 
         let project_id_str = project_id.to_string();
 
-        let store_guard = store.lock();
+        // Remember this project and restore its filter from the last session
+        if let Some(session) = crate::bridge::get_session_store() {
+            let mut session = session.lock();
+            session.set_last_project_id(Some(project_id_str.clone()));
+            let filter = session.kanban_filter(&project_id_str).unwrap_or("").to_string();
+            drop(session);
+            self.as_mut().set_filter(QString::from(&filter));
+        }
 
-        match store_guard.get_project(&project_id_str) {
+        match store.get_project(&project_id_str) {
             Ok(Some(_)) => {}
             Ok(None) => {
                 self.as_mut().set_error_message(QString::from("Project not found"));
@@ -161,64 +540,547 @@ impl qobject::KanbanModel {
             }
         }
 
-        let repo_ids = store_guard.list_repos_for_project(&project_id_str).unwrap_or_default();
+        let columns = store
+            .list_columns(&project_id_str)
+            .unwrap_or_else(|_| ProjectColumn::defaults(&project_id_str));
+        let columns_moved = self.rust().columns != columns;
+        self.as_mut().rust_mut().columns = columns;
+        self.as_mut().columns_changed();
+
+        let repo_ids = store.list_repos_for_project(&project_id_str).unwrap_or_default();
         let repo_ids_json = serde_json::to_string(&repo_ids).unwrap_or_else(|_| "[]".to_string());
         self.as_mut().set_repo_ids(QString::from(&repo_ids_json));
+        self.as_mut().load_templates(repo_ids);
 
-        match store_guard.list_tasks_for_project(&project_id_str) {
+        match store.list_tasks_for_project(&project_id_str) {
             Ok(tasks) => {
                 tracing::info!("Loaded {} tasks for project {}", tasks.len(), project_id_str);
-                drop(store_guard);
-                self.as_mut().rust_mut().tasks = tasks;
+                if reload {
+                    self.as_mut().apply_tasks(tasks);
+                    let count = self.rust().tasks.len();
+                    if columns_moved && count > 0 {
+                        self.as_mut().rows_changed(0, count - 1);
+                    }
+                } else {
+                    self.as_mut().reset_rows(|rust| rust.tasks = tasks);
+                }
                 self.as_mut().set_loading(false);
-                self.as_mut().tasks_changed();
             }
             Err(e) => {
                 tracing::error!("Failed to load tasks: {}", e);
-                drop(store_guard);
                 self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
                 self.as_mut().set_loading(false);
             }
         }
     }
 
-    pub fn get_repo_id(&self, _task_index: i32) -> QString {
-        QString::from("")
+    /// Bring the rows in line with `tasks`, announcing only the rows that
+    /// were added, removed, moved or edited
+    fn apply_tasks(mut self: Pin<&mut Self>, tasks: Vec<Task>) {
+        let changes = diff_tasks(&self.rust().tasks, &tasks);
+        for change in changes {
+            match change {
+                TaskListChange::Removed { first, count } => {
+                    self.as_mut().remove_rows(first, count, |rust| {
+                        rust.tasks.drain(first..first + count);
+                    });
+                }
+                TaskListChange::Moved { from, to } => {
+                    self.as_mut().move_row(from, to, |rust| {
+                        let moved = rust.tasks.remove(from);
+                        rust.tasks.insert(to, moved);
+                    });
+                }
+                TaskListChange::Inserted { first, tasks } => {
+                    self.as_mut().insert_rows(first, tasks.len(), |rust| {
+                        rust.tasks.splice(first..first, tasks);
+                    });
+                }
+                TaskListChange::Updated { first, tasks } => {
+                    let last = first + tasks.len() - 1;
+                    self.as_mut().rust_mut().tasks[first..=last].clone_from_slice(&tasks);
+                    self.as_mut().rows_changed(first, last);
+                }
+            }
+        }
+    }
+
+    /// Fetch issue templates for the project's repos in the background
+    fn load_templates(mut self: Pin<&mut Self>, repo_ids: Vec<String>) {
+        self.as_mut().rust_mut().templates.clear();
+        self.as_mut().templates_changed();
+        if repo_ids.is_empty() {
+            return;
+        }
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            return;
+        };
+        bridge::init_kanban_service_channel();
+        let Some(tx) = bridge::get_kanban_service_tx() else {
+            return;
+        };
+        self.as_mut().set_templates_loading(true);
+        request_kanban_templates(&tx, client, repo_ids);
+    }
+
+    pub fn get_templates(&self) -> QString {
+        let json =
+            serde_json::to_string(&self.rust().templates).unwrap_or_else(|_| "[]".to_string());
+        QString::from(json)
+    }
+
+    pub fn get_saved_templates(&self) -> QString {
+        let Some(store) = bridge::get_project_store_or_init() else {
+            return QString::from("[]");
+        };
+        let project_id = self.rust().project_id.to_string();
+        let templates: Vec<_> = store
+            .list_templates(Some(TemplateKind::Issue))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|t| t.project_id.as_deref().is_none_or(|p| p == project_id))
+            .collect();
+        QString::from(&templates_json(&templates))
+    }
+
+    pub fn save_issue_template(
+        mut self: Pin<&mut Self>,
+        id: QString,
+        name: QString,
+        title: QString,
+        body: QString,
+        repo_id: QString,
+        schedule: QString,
+    ) -> QString {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.rust().store.clone() else {
+            return QString::from("");
+        };
+        if name.to_string().trim().is_empty() || title.to_string().trim().is_empty() {
+            self.as_mut().set_error_message(QString::from("Template name and title are required"));
+            return QString::from("");
+        }
+        let schedule = match parse_template_schedule(&schedule.to_string()) {
+            Ok(schedule) => schedule,
+            Err(hint) => {
+                self.as_mut().set_error_message(QString::from(hint));
+                return QString::from("");
+            }
+        };
+
+        let id = id.to_string();
+        let existing = if id.is_empty() { None } else { store.get_template(&id).ok().flatten() };
+        let project_id = self.rust().project_id.to_string();
+        let template = build_template(
+            existing,
+            TemplateKind::Issue,
+            &name.to_string(),
+            &title.to_string(),
+            &body.to_string(),
+            Some(project_id).filter(|p| !p.is_empty()),
+            Some(repo_id.to_string()),
+            schedule,
+        );
+        if let Err(e) = store.upsert_template(&template) {
+            self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            return QString::from("");
+        }
+        QString::from(&template.id)
     }
 
-    pub fn row_count(&self) -> i32 {
+    pub fn delete_saved_template(mut self: Pin<&mut Self>, id: QString) -> bool {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.rust().store.clone() else {
+            return false;
+        };
+        match store.delete_template(&id.to_string()) {
+            Ok(()) => true,
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                false
+            }
+        }
+    }
+
+    pub fn create_issue_from_template(
+        mut self: Pin<&mut Self>,
+        template_id: QString,
+        repo_id: QString,
+    ) {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.rust().store.clone() else {
+            return;
+        };
+        let mut template = match store.get_template(&template_id.to_string()) {
+            Ok(Some(template)) => template,
+            Ok(None) => {
+                self.as_mut().set_error_message(QString::from("Template not found"));
+                return;
+            }
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                return;
+            }
+        };
+        // {{project}} is the open board unless the template names a project
+        let project_id = self.rust().project_id.to_string();
+        if template.project_id.is_none() && !project_id.is_empty() {
+            template.project_id = Some(project_id);
+        }
+        let (title, body) = template.render(&template_context_today(&store, &template));
+
+        let repo_id =
+            Some(repo_id.to_string()).filter(|r| !r.trim().is_empty()).or(template.repo_id.clone());
+        let Some((owner, repo)) = repo_id.as_deref().and_then(|r| r.split_once('/')) else {
+            self.create_task(QString::from(&title), QString::from(&body), QString::from("todo"));
+            return;
+        };
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            self.as_mut().set_error_message(QString::from("Sign in to GitHub to open issues"));
+            return;
+        };
+        bridge::init_kanban_service_channel();
+        let Some(tx) = bridge::get_kanban_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            return;
+        };
+
+        let request = CreateIssueRequest {
+            title,
+            body: Some(body).filter(|b| !b.trim().is_empty()),
+            labels: None,
+        };
+        let (owner, repo) = (owner.to_string(), repo.to_string());
+        self.as_mut().rust_mut().pending_issue_repo = repo_id;
+        self.as_mut().set_loading(true);
+        request_kanban_create(&tx, client, owner, repo, request);
+    }
+
+    /// Poll for async operation results. Call this from a QML Timer.
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let msg = match bridge::try_recv_kanban_message() {
+            Some(m) => m,
+            None => return,
+        };
+
+        match msg {
+            KanbanServiceMessage::TemplatesDone(result) => {
+                self.as_mut().set_templates_loading(false);
+                match result {
+                    Ok(templates) => {
+                        tracing::info!("Loaded {} issue templates", templates.len());
+                        self.as_mut().rust_mut().templates = templates;
+                        self.as_mut().templates_changed();
+                    }
+                    Err(e) => {
+                        // Templates are optional; the dialog falls back to a blank body
+                        tracing::warn!("Failed to load issue templates: {}", e);
+                    }
+                }
+            }
+            KanbanServiceMessage::CreateIssueDone(result) => {
+                self.as_mut().set_loading(false);
+                let repo_id = self.as_mut().rust_mut().pending_issue_repo.take();
+                match result {
+                    Ok(issue) => {
+                        tracing::info!("Opened issue #{} from template", issue.number);
+                        let count = self.rust().tasks.len();
+                        self.as_mut().create_task(
+                            QString::from(&issue.title),
+                            QString::from(issue.body.as_deref().unwrap_or_default()),
+                            QString::from("todo"),
+                        );
+                        let task_id = self.rust().tasks.get(count).map(|t| t.id.clone());
+                        if let (Some(task_id), Some(repo_id), Some(store)) =
+                            (task_id, repo_id, self.rust().store.clone())
+                        {
+                            let issue = EntityRef::issue(&repo_id, issue.number);
+                            if let Err(e) = store.link(&EntityRef::task(&task_id), &issue) {
+                                tracing::warn!("Failed to link task to issue: {:#}", e);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        let msg = myme_core::AppError::from(e).user_message().to_string();
+                        self.as_mut().set_error_message(QString::from(&msg));
+                    }
+                }
+            }
+            KanbanServiceMessage::TaskScheduled(result) => {
+                self.as_mut().set_loading(false);
+                match result {
+                    Ok(schedule) => {
+                        tracing::info!(
+                            "Scheduled task {} as {}",
+                            schedule.task_id,
+                            schedule.event_id
+                        );
+                        self.as_mut().task_scheduled(QString::from(&schedule.task_id));
+                    }
+                    Err(e) => {
+                        let msg = myme_core::AppError::from(e).user_message().to_string();
+                        self.as_mut().set_error_message(QString::from(&msg));
+                    }
+                }
+            }
+            KanbanServiceMessage::ChecklistSynced { task_id, result } => {
+                let previous = self.as_mut().rust_mut().pending_checklists.remove(&task_id);
+                let body = match result {
+                    // GitHub's copy, with any edits made there
+                    Ok(issue) => issue.body,
+                    Err(e) => {
+                        let msg = myme_core::AppError::from(e).user_message().to_string();
+                        self.as_mut().set_error_message(QString::from(&msg));
+                        match previous {
+                            Some(body) => body,
+                            None => return,
+                        }
+                    }
+                };
+                let existing = self.rust().tasks.iter().position(|t| t.id == task_id);
+                let (Some(i), Some(store)) = (existing, self.rust().store.clone()) else {
+                    return;
+                };
+                if self.rust().tasks[i].body == body {
+                    return;
+                }
+                let mut task = self.rust().tasks[i].clone();
+                task.body = body;
+                task.updated_at = chrono::Utc::now().to_rfc3339();
+                if let Err(e) = store.upsert_task(&task) {
+                    tracing::warn!("Failed to save checklist from GitHub: {}", e);
+                    return;
+                }
+                self.as_mut().rust_mut().tasks[i] = task;
+                self.as_mut().rows_changed(i, i);
+            }
+            KanbanServiceMessage::ProjectSynced(project_id) => {
+                let open = self.rust().project_id.to_string() == project_id;
+                if open && !*self.loading() {
+                    self.as_mut().load_project(QString::from(&project_id));
+                }
+            }
+            KanbanServiceMessage::TaskChanged(task) => {
+                let existing = self.as_ref().rust().tasks.iter().position(|t| t.id == task.id);
+                if let Some(i) = existing {
+                    self.as_mut().rust_mut().tasks[i] = task;
+                    self.as_mut().rows_changed(i, i);
+                }
+            }
+            other => {
+                tracing::debug!("KanbanModel: ignoring {:?}", other);
+            }
+        }
+    }
+
+    pub fn schedule_task(
+        mut self: Pin<&mut Self>,
+        task_id: QString,
+        start: QString,
+        duration_minutes: i32,
+    ) -> bool {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.rust().store.clone() else {
+            return false;
+        };
+        let task_id = task_id.to_string();
+        let Some(task) = self.rust().tasks.iter().find(|t| t.id == task_id).cloned() else {
+            return false;
+        };
+        let start =
+            match chrono::NaiveDateTime::parse_from_str(start.to_string().trim(), "%Y-%m-%d %H:%M")
+                .ok()
+                .and_then(|naive| naive.and_local_timezone(chrono::Local).earliest())
+            {
+                Some(start) => start.with_timezone(&chrono::Utc),
+                None => {
+                    self.as_mut()
+                        .set_error_message(QString::from("Start must be YYYY-MM-DD HH:MM"));
+                    return false;
+                }
+            };
+        if duration_minutes <= 0 {
+            self.as_mut().set_error_message(QString::from("Duration must be positive"));
+            return false;
+        }
+        let Some(access_token) = get_google_access_token() else {
+            self.as_mut().set_error_message(QString::from("Sign in to Google to schedule tasks"));
+            return false;
+        };
+        bridge::init_kanban_service_channel();
+        let Some(tx) = bridge::get_kanban_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            return false;
+        };
+
+        self.as_mut().set_loading(true);
+        self.as_mut().rust_mut().clear_error();
+        request_schedule_task(
+            &tx,
+            store,
+            access_token,
+            task,
+            start,
+            chrono::Duration::minutes(duration_minutes as i64),
+        );
+        true
+    }
+
+    pub fn get_schedules(&self, task_id: QString) -> QString {
+        let Some(store) = self.rust().store.clone() else {
+            return QString::from("[]");
+        };
+        let schedules: Vec<_> = store
+            .list_task_schedules(&task_id.to_string())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|s| {
+                let label = chrono::DateTime::parse_from_rfc3339(&s.start)
+                    .map(|start| {
+                        start.with_timezone(&chrono::Local).format("%a %b %-d %H:%M").to_string()
+                    })
+                    .unwrap_or_else(|_| s.start.clone());
+                serde_json::json!({
+                    "eventId": s.event_id,
+                    "start": s.start,
+                    "end": s.end,
+                    "label": label,
+                    "done": s.completed_at.is_some(),
+                })
+            })
+            .collect();
+        QString::from(serde_json::to_string(&schedules).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    pub fn apply_filter(mut self: Pin<&mut Self>, filter: QString) {
+        let project_id = self.as_ref().rust().project_id.to_string();
+        if !project_id.is_empty() {
+            if let Some(session) = crate::bridge::get_session_store() {
+                session.lock().set_kanban_filter(&project_id, &filter.to_string());
+            }
+        }
+        self.as_mut().set_filter(filter);
+
+        // Cards show or hide through the matchesFilter role
+        let count = self.rust().tasks.len();
+        if count > 0 {
+            self.as_mut().rows_changed(0, count - 1);
+        }
+    }
+
+    pub fn row_count(&self, _parent: &QModelIndex) -> i32 {
         self.rust().tasks.len() as i32
     }
 
-    pub fn get_task_number(&self, index: i32) -> i32 {
-        (index + 1) as i32
+    pub fn role_names(&self) -> QHash<QHashPair_i32_QByteArray> {
+        list_model::role_names::<TaskRole>()
     }
 
-    pub fn get_title(&self, index: i32) -> QString {
-        self.rust()
-            .get_task(index)
-            .map(|t| QString::from(&t.title))
-            .unwrap_or_else(|| QString::from(""))
+    pub fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        let rust = self.rust();
+        let (Some(task), Some(role)) = (rust.get_task(index.row()), TaskRole::from_role(role))
+        else {
+            return QVariant::default();
+        };
+        let text = |s: &str| QVariant::from(&QString::from(s));
+        match role {
+            TaskRole::TaskId => text(&task.id),
+            TaskRole::Title => text(&task.title),
+            TaskRole::Body => text(task.body.as_deref().unwrap_or_default()),
+            TaskRole::Status => text(KanbanModelRust::status_to_string(task.status)),
+            TaskRole::ColumnKey => text(
+                ProjectColumn::for_task(&rust.columns, task).map(|c| c.id.as_str()).unwrap_or(""),
+            ),
+            TaskRole::RepoId => {
+                text(&rust.linked_issue(task).map(|(repo_id, _)| repo_id).unwrap_or_default())
+            }
+            TaskRole::IssueNumber => {
+                QVariant::from(&rust.linked_issue(task).map(|(_, n)| n).unwrap_or(0))
+            }
+            TaskRole::IssueUrl => text(&rust.issue_url(task).unwrap_or_default()),
+            TaskRole::SubtaskProgress => text(&rust.subtask_progress(task)),
+            TaskRole::MatchesFilter => QVariant::from(&rust.matches_filter(task)),
+        }
     }
 
-    pub fn get_body(&self, index: i32) -> QString {
-        self.rust()
-            .get_task(index)
-            .and_then(|t| t.body.as_ref())
-            .map(|b| QString::from(b))
-            .unwrap_or_else(|| QString::from(""))
+    pub fn get_task(&self, index: i32) -> QString {
+        let rust = self.rust();
+        let Some(task) = rust.get_task(index) else {
+            return QString::from("{}");
+        };
+        let json = serde_json::json!({
+            "id": task.id,
+            "title": task.title,
+            "body": task.body.clone().unwrap_or_default(),
+            "issueUrl": rust.issue_url(task).unwrap_or_default(),
+        });
+        QString::from(&json.to_string())
     }
 
-    pub fn get_status(&self, index: i32) -> QString {
-        self.rust()
-            .get_task(index)
-            .map(|t| QString::from(KanbanModelRust::status_to_string(t.status)))
-            .unwrap_or_else(|| QString::from(""))
+    pub fn get_subtasks(&self, index: i32) -> QString {
+        let rust = self.rust();
+        let items = match (rust.get_task(index), &rust.store) {
+            (Some(task), Some(store)) => store.list_subtasks(&task.id).unwrap_or_else(|e| {
+                tracing::warn!("Failed to load subtasks: {}", e);
+                Vec::new()
+            }),
+            _ => Vec::new(),
+        };
+        QString::from(&serde_json::to_string(&items).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    pub fn set_subtask_checked(mut self: Pin<&mut Self>, index: i32, item: i32, checked: bool) {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.rust().store.clone() else {
+            return;
+        };
+        let Some(mut task) = self.rust().get_task(index).cloned() else {
+            return;
+        };
+        let Ok(item) = usize::try_from(item) else {
+            return;
+        };
+        let previous = task.body.clone();
+        let Some(body) = previous.as_deref().and_then(|b| checklist::set_checked(b, item, checked))
+        else {
+            return;
+        };
+        let Some(text) = checklist::parse(&body).get(item).map(|i| i.text.clone()) else {
+            return;
+        };
+        task.body = Some(body);
+        task.updated_at = chrono::Utc::now().to_rfc3339();
+        if let Err(e) = store.upsert_task(&task) {
+            self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            return;
+        }
+
+        let issue = self.rust().linked_issue(&task);
+        let task_id = task.id.clone();
+        if let Some(t) = self.as_mut().rust_mut().tasks.get_mut(index as usize) {
+            *t = task;
+        }
+        self.as_mut().rows_changed(index as usize, index as usize);
+
+        let Some((repo_id, number)) = issue else {
+            return;
+        };
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            return;
+        };
+        bridge::init_kanban_service_channel();
+        let Some(tx) = bridge::get_kanban_service_tx() else {
+            return;
+        };
+        self.as_mut().rust_mut().pending_checklists.entry(task_id.clone()).or_insert(previous);
+        request_kanban_checklist_item(&tx, client, task_id, repo_id, number, item, text, checked);
     }
 
     pub fn count_by_status(&self, status: QString) -> i32 {
         let target_status = KanbanModelRust::status_from_string(&status.to_string());
-        self.rust().tasks.iter().filter(|t| t.status == target_status).count() as i32
+        let rust = self.rust();
+        rust.tasks.iter().filter(|t| t.status == target_status && rust.matches_filter(t)).count()
+            as i32
     }
 
     pub fn tasks_for_status(&self, status: QString) -> QString {
@@ -228,45 +1090,283 @@ impl qobject::KanbanModel {
             .tasks
             .iter()
             .enumerate()
-            .filter(|(_, t)| t.status == target_status)
+            .filter(|(_, t)| t.status == target_status && self.rust().matches_filter(t))
             .map(|(i, _)| i as i32)
             .collect();
         let json = serde_json::to_string(&indices).unwrap_or_else(|_| "[]".to_string());
         QString::from(json)
     }
 
-    pub fn move_task(mut self: Pin<&mut Self>, index: i32, new_status: QString) {
+    pub fn get_columns(&self) -> QString {
+        let columns: Vec<serde_json::Value> = self
+            .rust()
+            .columns
+            .iter()
+            .map(|c| {
+                serde_json::json!({
+                    "id": c.id,
+                    "name": c.name,
+                    "label": c.label.clone().unwrap_or_default(),
+                    "status": c.status.key(),
+                    "color": format!("#{}", c.status.label_color()),
+                    "wipLimit": c.wip_limit.unwrap_or(0),
+                    "overWip": c.is_over_wip(self.rust().task_indices_in_column(&c.id).len()),
+                })
+            })
+            .collect();
+        let json = serde_json::to_string(&columns).unwrap_or_else(|_| "[]".to_string());
+        QString::from(json)
+    }
+
+    pub fn count_in_column(&self, column_id: QString) -> i32 {
+        self.rust().task_indices_in_column(&column_id.to_string()).len() as i32
+    }
+
+    pub fn tasks_for_column(&self, column_id: QString) -> QString {
+        let rust = self.rust();
+        let mut indices = rust.task_indices_in_column(&column_id.to_string());
+        indices.retain(|&i| rust.get_task(i).is_some_and(|t| rust.matches_filter(t)));
+        let json = serde_json::to_string(&indices).unwrap_or_else(|_| "[]".to_string());
+        QString::from(json)
+    }
+
+    pub fn position_in_column(&self, index: i32) -> i32 {
+        let rust = self.rust();
+        let Some(column) =
+            rust.get_task(index).and_then(|t| ProjectColumn::for_task(&rust.columns, t))
+        else {
+            return -1;
+        };
+        rust.task_indices_in_column(&column.id)
+            .iter()
+            .position(|&i| i == index)
+            .map_or(-1, |p| p as i32)
+    }
+
+    /// Re-read the board's columns after an edit
+    fn reload_columns(mut self: Pin<&mut Self>, store: &ProjectStore) {
+        let project_id = self.as_ref().rust().project_id.to_string();
+        match store.list_columns(&project_id) {
+            Ok(columns) => {
+                self.as_mut().rust_mut().columns = columns;
+                self.as_mut().columns_changed();
+                // Cards may now belong to a different column
+                let count = self.as_ref().rust().tasks.len();
+                if count > 0 {
+                    self.as_mut().rows_changed(0, count - 1);
+                }
+            }
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            }
+        }
+    }
+
+    pub fn add_column(mut self: Pin<&mut Self>, name: QString, label: QString) {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.as_ref().rust().store.clone() else {
+            return;
+        };
+        let project_id = self.as_ref().rust().project_id.to_string();
+        let label = label.to_string();
+        let label = Some(label.as_str()).filter(|l| !l.trim().is_empty());
+
+        // New columns count as in-progress work until mapped otherwise
+        match store.add_column(&project_id, &name.to_string(), label, TaskStatus::InProgress) {
+            Ok(column) => {
+                tracing::info!("Added column {} to project {}", column.id, project_id);
+                self.as_mut().reload_columns(&store);
+            }
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            }
+        }
+    }
+
+    pub fn rename_column(mut self: Pin<&mut Self>, column_id: QString, name: QString) {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.as_ref().rust().store.clone() else {
+            return;
+        };
+        let project_id = self.as_ref().rust().project_id.to_string();
+        match store.rename_column(&project_id, &column_id.to_string(), &name.to_string()) {
+            Ok(()) => self.as_mut().reload_columns(&store),
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            }
+        }
+    }
+
+    pub fn set_column_label(mut self: Pin<&mut Self>, column_id: QString, label: QString) {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.as_ref().rust().store.clone() else {
+            return;
+        };
+        let project_id = self.as_ref().rust().project_id.to_string();
+        let label = label.to_string();
+        match store.set_column_label(&project_id, &column_id.to_string(), Some(label.as_str())) {
+            Ok(()) => self.as_mut().reload_columns(&store),
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            }
+        }
+    }
+
+    pub fn set_column_wip_limit(mut self: Pin<&mut Self>, column_id: QString, limit: i32) {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.as_ref().rust().store.clone() else {
+            return;
+        };
+        let project_id = self.as_ref().rust().project_id.to_string();
+        let limit = u32::try_from(limit).ok().filter(|l| *l > 0);
+        match store.set_column_wip_limit(&project_id, &column_id.to_string(), limit) {
+            Ok(()) => self.as_mut().reload_columns(&store),
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            }
+        }
+    }
+
+    pub fn is_over_wip(&self, status: QString) -> bool {
+        let rust = self.rust();
+        let (column_id, status) = rust.resolve_column(&status.to_string());
+        // A status key stands for the first column with that status
+        let column = match column_id {
+            Some(id) => rust.columns.iter().find(|c| c.id == id),
+            None => rust.columns.iter().find(|c| c.status == status),
+        };
+        column.is_some_and(|c| c.is_over_wip(rust.task_indices_in_column(&c.id).len()))
+    }
+
+    pub fn move_column(mut self: Pin<&mut Self>, column_id: QString, offset: i32) {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(store) = self.as_ref().rust().store.clone() else {
+            return;
+        };
+        let column_id = column_id.to_string();
+        let mut ids: Vec<String> =
+            self.as_ref().rust().columns.iter().map(|c| c.id.clone()).collect();
+        let Some(from) = ids.iter().position(|id| *id == column_id) else {
+            return;
+        };
+        let to = (from as i64 + offset as i64).clamp(0, ids.len() as i64 - 1) as usize;
+        if to == from {
+            return;
+        }
+        let id = ids.remove(from);
+        ids.insert(to, id);
+
+        let project_id = self.as_ref().rust().project_id.to_string();
+        match store.reorder_columns(&project_id, &ids) {
+            Ok(()) => self.as_mut().reload_columns(&store),
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            }
+        }
+    }
+
+    pub fn move_task(mut self: Pin<&mut Self>, index: i32, new_status: QString) -> bool {
         self.as_mut().rust_mut().ensure_initialized();
 
         let mut task = match self.as_ref().rust().get_task(index) {
             Some(t) => t.clone(),
-            None => return,
+            None => return false,
         };
 
-        let new_status_enum = KanbanModelRust::status_from_string(&new_status.to_string());
-        if task.status == new_status_enum {
-            return;
+        let (column_id, new_status_enum) =
+            self.as_ref().rust().resolve_column(&new_status.to_string());
+        if task.status == new_status_enum && task.column_id == column_id {
+            return false;
         }
 
+        let was_done = task.status == TaskStatus::Done;
+        let undo = UndoHint::KanbanMove {
+            task_id: task.id.clone(),
+            column_id: task.column_id.clone(),
+            status: task.status.key().to_string(),
+        };
         task.status = new_status_enum;
+        task.column_id = column_id;
         task.updated_at = chrono::Utc::now().to_rfc3339();
 
         let store = match &self.as_ref().rust().store {
             Some(s) => s.clone(),
-            None => return,
+            None => return false,
         };
 
-        let store_guard = store.lock();
-        if let Err(e) = store_guard.upsert_task(&task) {
+        let over_wip = self
+            .as_ref()
+            .rust()
+            .wip_exceeded_by(&task)
+            .map(|c| (c.name.clone(), c.wip_limit.unwrap_or(0)));
+        if let Some((name, limit)) = over_wip {
+            let message = format!("{} is at its WIP limit of {}", name, limit);
+            match myme_core::Config::load_cached().projects.wip_enforcement {
+                WipEnforcement::Block => {
+                    self.as_mut().set_error_message(QString::from(&message));
+                    // Put the dropped card back
+                    self.as_mut().rows_changed(index as usize, index as usize);
+                    return false;
+                }
+                WipEnforcement::Warn => {
+                    publish_ui_event(
+                        UiEvent::new("kanban", UiSeverity::Warning, message)
+                            .with_detail("Finish something before starting more"),
+                    );
+                }
+            }
+        }
+
+        if let Err(e) = store.upsert_task(&task) {
             self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
-            return;
+            return false;
+        }
+
+        let to = ProjectColumn::for_task(&self.as_ref().rust().columns, &task)
+            .map(|c| c.name.clone())
+            .unwrap_or_else(|| task.status.display_name().to_string());
+        activity_service::record(
+            "kanban",
+            "moved",
+            &task.id,
+            &format!("Moved \"{}\" to {}", activity_service::short_title(&task.title), to),
+            Some(undo),
+        );
+        // Tick scheduled events off (or back on) when the task enters or leaves Done
+        if was_done != (task.status == TaskStatus::Done) {
+            if let Some(access_token) = get_google_access_token() {
+                request_sync_task_schedules(store, access_token, task.clone());
+            }
         }
 
         if let Some(t) = self.as_mut().rust_mut().tasks.get_mut(index as usize) {
             *t = task;
         }
 
-        self.as_mut().tasks_changed();
+        self.as_mut().rows_changed(index as usize, index as usize);
+        true
+    }
+
+    pub fn reorder_task(mut self: Pin<&mut Self>, index: i32, new_position: i32) {
+        self.as_mut().rust_mut().ensure_initialized();
+        let Some(order) = self.as_ref().rust().order_after_move(index, new_position) else {
+            return;
+        };
+        let Some(store) = self.as_ref().rust().store.clone() else {
+            return;
+        };
+        let project_id = self.as_ref().rust().project_id.to_string();
+        if let Err(e) = store.set_task_order(&project_id, &order) {
+            self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            return;
+        }
+
+        let mut tasks = self.rust().tasks.clone();
+        tasks.sort_by_key(|t| order.iter().position(|id| *id == t.id).unwrap_or(usize::MAX));
+        for (i, t) in tasks.iter_mut().enumerate() {
+            t.order_index = i as i32;
+        }
+        self.as_mut().apply_tasks(tasks);
     }
 
     pub fn create_task(mut self: Pin<&mut Self>, title: QString, body: QString, status: QString) {
@@ -279,7 +1379,7 @@ impl qobject::KanbanModel {
         }
 
         let project_id_str = self.as_ref().rust().project_id.to_string();
-        let status_enum = KanbanModelRust::status_from_string(&status.to_string());
+        let (column_id, status_enum) = self.as_ref().rust().resolve_column(&status.to_string());
         let now = chrono::Utc::now().to_rfc3339();
 
         let task = Task {
@@ -295,8 +1395,17 @@ impl qobject::KanbanModel {
                 }
             },
             status: status_enum,
+            column_id,
             created_at: now.clone(),
             updated_at: now,
+            order_index: self
+                .as_ref()
+                .rust()
+                .tasks
+                .iter()
+                .map(|t| t.order_index + 1)
+                .max()
+                .unwrap_or(0),
         };
 
         let store = match &self.as_ref().rust().store {
@@ -307,14 +1416,13 @@ impl qobject::KanbanModel {
             }
         };
 
-        let store_guard = store.lock();
-        if let Err(e) = store_guard.upsert_task(&task) {
+        if let Err(e) = store.upsert_task(&task) {
             self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
             return;
         }
 
-        self.as_mut().rust_mut().tasks.push(task);
-        self.as_mut().tasks_changed();
+        let count = self.as_ref().rust().tasks.len();
+        self.as_mut().insert_rows(count, 1, |rust| rust.tasks.push(task));
         tracing::info!("Created task: {}", title_str);
     }
 
@@ -348,8 +1456,7 @@ impl qobject::KanbanModel {
             None => return,
         };
 
-        let store_guard = store.lock();
-        if let Err(e) = store_guard.upsert_task(&task) {
+        if let Err(e) = store.upsert_task(&task) {
             self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
             return;
         }
@@ -358,6 +1465,8 @@ impl qobject::KanbanModel {
             *t = task;
         }
 
-        self.as_mut().tasks_changed();
+        self.as_mut().rows_changed(index as usize, index as usize);
     }
 }
+
+list_rows!(qobject::KanbanModel);
@@ -0,0 +1,214 @@
+use core::pin::Pin;
+use std::sync::Arc;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::{QString, QStringList};
+use myme_services::{LabelUsage, NoteClient};
+
+use crate::bridge;
+use crate::services::{
+    request_label_edit, request_label_fetch, suggest_labels, LabelEdit, LabelError,
+    LabelServiceMessage,
+};
+
+/// Autocomplete suggestions offered at once
+const SUGGESTION_LIMIT: usize = 8;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        include!("cxx-qt-lib/qstringlist.h");
+        type QString = cxx_qt_lib::QString;
+        type QStringList = cxx_qt_lib::QStringList;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(QString, error_message)]
+        type LabelModel = super::LabelModelRust;
+
+        #[qinvokable]
+        fn fetch_labels(self: Pin<&mut LabelModel>);
+
+        #[qinvokable]
+        fn create_label(self: Pin<&mut LabelModel>, name: &QString);
+
+        /// Rename a label on every note. Fails if `to` is already a label; use merge_labels.
+        #[qinvokable]
+        fn rename_label(self: Pin<&mut LabelModel>, from: &QString, to: &QString);
+
+        /// Give notes labelled `from` the label `into` instead, then drop `from`
+        #[qinvokable]
+        fn merge_labels(self: Pin<&mut LabelModel>, from: &QString, into: &QString);
+
+        /// Remove a label from every note
+        #[qinvokable]
+        fn delete_label(self: Pin<&mut LabelModel>, name: &QString);
+
+        /// Label names for autocomplete, best match first
+        #[qinvokable]
+        fn suggest(self: &LabelModel, prefix: &QString) -> QStringList;
+
+        /// Poll for async operation results. Call this from a QML Timer.
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut LabelModel>);
+
+        #[qinvokable]
+        fn row_count(self: &LabelModel) -> i32;
+
+        #[qinvokable]
+        fn get_name(self: &LabelModel, index: i32) -> QString;
+
+        /// Notes shown under the label (not archived or trashed)
+        #[qinvokable]
+        fn get_count(self: &LabelModel, index: i32) -> i32;
+
+        #[qsignal]
+        fn labels_changed(self: Pin<&mut LabelModel>);
+
+        /// A rename, merge or delete rewrote `count` notes; note lists should reload
+        #[qsignal]
+        fn notes_relabelled(self: Pin<&mut LabelModel>, count: i32);
+    }
+}
+
+#[derive(Default)]
+pub struct LabelModelRust {
+    loading: bool,
+    error_message: QString,
+    labels: Vec<LabelUsage>,
+    client: Option<Arc<NoteClient>>,
+    /// An edit is in flight; further edits wait for it
+    editing: bool,
+}
+
+impl LabelModelRust {
+    /// Auto-initialize from global services if not already initialized
+    fn ensure_initialized(&mut self) {
+        if self.client.is_some() {
+            return;
+        }
+        match bridge::get_note_client_and_runtime() {
+            Some((client, _runtime)) => self.client = Some(client),
+            None => tracing::error!("Cannot initialize LabelModel - note client not ready"),
+        }
+    }
+
+    fn get_label(&self, index: i32) -> Option<&LabelUsage> {
+        usize::try_from(index).ok().and_then(|i| self.labels.get(i))
+    }
+}
+
+impl qobject::LabelModel {
+    fn channel(
+        mut self: Pin<&mut Self>,
+    ) -> Option<(std::sync::mpsc::Sender<LabelServiceMessage>, Arc<NoteClient>)> {
+        self.as_mut().rust_mut().ensure_initialized();
+        let client = self.as_ref().rust().client.clone()?;
+        bridge::init_label_service_channel();
+        let tx = bridge::get_label_service_tx()?;
+        Some((tx, client))
+    }
+
+    fn show_error(mut self: Pin<&mut Self>, e: LabelError) {
+        tracing::warn!("Label operation failed: {}", e);
+        // Rejected edits say what was wrong; anything else gets the generic message
+        let msg = match &e {
+            LabelError::Invalid(reason) => reason.clone(),
+            _ => myme_core::AppError::from(e).user_message().to_string(),
+        };
+        self.as_mut().set_error_message(QString::from(&msg));
+    }
+
+    pub fn fetch_labels(mut self: Pin<&mut Self>) {
+        let Some((tx, client)) = self.as_mut().channel() else {
+            self.as_mut().set_error_message(QString::from("Not initialized"));
+            return;
+        };
+        self.as_mut().set_loading(true);
+        request_label_fetch(&tx, client);
+    }
+
+    fn edit(mut self: Pin<&mut Self>, edit: LabelEdit) {
+        if self.as_ref().rust().editing {
+            tracing::warn!("Label edit already in progress");
+            return;
+        }
+        let Some((tx, client)) = self.as_mut().channel() else {
+            return;
+        };
+        self.as_mut().rust_mut().editing = true;
+        self.as_mut().set_error_message(QString::from(""));
+        request_label_edit(&tx, client, edit);
+    }
+
+    pub fn create_label(self: Pin<&mut Self>, name: &QString) {
+        self.edit(LabelEdit::Create(name.to_string()));
+    }
+
+    pub fn rename_label(self: Pin<&mut Self>, from: &QString, to: &QString) {
+        self.edit(LabelEdit::Rename { from: from.to_string(), to: to.to_string() });
+    }
+
+    pub fn merge_labels(self: Pin<&mut Self>, from: &QString, into: &QString) {
+        self.edit(LabelEdit::Merge { from: from.to_string(), into: into.to_string() });
+    }
+
+    pub fn delete_label(self: Pin<&mut Self>, name: &QString) {
+        self.edit(LabelEdit::Delete(name.to_string()));
+    }
+
+    pub fn suggest(&self, prefix: &QString) -> QStringList {
+        let mut list = QStringList::default();
+        for name in suggest_labels(&self.rust().labels, &prefix.to_string(), SUGGESTION_LIMIT) {
+            list.append(QString::from(&name));
+        }
+        list
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let Some(msg) = bridge::try_recv_label_message() else {
+            return;
+        };
+
+        match msg {
+            LabelServiceMessage::FetchDone(result) => {
+                self.as_mut().set_loading(false);
+                match result {
+                    Ok(labels) => {
+                        self.as_mut().rust_mut().labels = labels;
+                        self.as_mut().labels_changed();
+                    }
+                    Err(e) => self.as_mut().show_error(e),
+                }
+            }
+            LabelServiceMessage::EditDone(result) => {
+                self.as_mut().rust_mut().editing = false;
+                match result {
+                    Ok(changed) => {
+                        if changed > 0 {
+                            self.as_mut().notes_relabelled(changed as i32);
+                        }
+                        self.as_mut().fetch_labels();
+                    }
+                    Err(e) => self.as_mut().show_error(e),
+                }
+            }
+        }
+    }
+
+    pub fn row_count(&self) -> i32 {
+        self.rust().labels.len() as i32
+    }
+
+    pub fn get_name(&self, index: i32) -> QString {
+        self.rust().get_label(index).map(|l| QString::from(&l.name)).unwrap_or_default()
+    }
+
+    pub fn get_count(&self, index: i32) -> i32 {
+        self.rust().get_label(index).map(|l| l.count as i32).unwrap_or(0)
+    }
+}
@@ -0,0 +1,133 @@
+//! Shared plumbing for models that subclass `QAbstractListModel`.
+//!
+//! QML views keep their delegates across updates only when every change to
+//! the backing `Vec` is announced with the matching begin/end pair. The
+//! helpers generated by [`list_rows!`] wrap the mutation in those calls, so a
+//! model can't announce half a change.
+
+use cxx_qt_lib::{QByteArray, QHash, QHashPair_i32_QByteArray};
+
+/// `Qt::UserRole`; custom roles are numbered from here
+pub const USER_ROLE: i32 = 0x0100;
+
+/// Data roles a list model exposes to QML delegates
+pub trait ListRole: Copy + 'static {
+    /// Every role, in the order they are numbered
+    const ALL: &'static [Self];
+
+    /// Name delegates read the role by (e.g. `required property string content`)
+    fn name(self) -> &'static str;
+
+    fn from_role(role: i32) -> Option<Self> {
+        let offset = usize::try_from(role.checked_sub(USER_ROLE)?).ok()?;
+        Self::ALL.get(offset).copied()
+    }
+}
+
+/// Role numbers and names for `QAbstractItemModel::roleNames`
+pub fn role_names<R: ListRole>() -> QHash<QHashPair_i32_QByteArray> {
+    let mut names = QHash::default();
+    for (offset, role) in R::ALL.iter().enumerate() {
+        names.insert(USER_ROLE + offset as i32, QByteArray::from(role.name()));
+    }
+    names
+}
+
+/// Row helpers for a `QAbstractListModel` subclass. The bridge must inherit
+/// `beginResetModel`/`endResetModel`, `beginInsertRows`/`endInsertRows`,
+/// `beginRemoveRows`/`endRemoveRows`, `beginMoveRows`/`endMoveRows`, `index`
+/// and the `dataChanged` signal under their snake_case names.
+macro_rules! list_rows {
+    ($model:ty) => {
+        #[allow(dead_code)]
+        impl $model {
+            /// Replace every row (a new fetch or filter)
+            fn reset_rows(
+                mut self: core::pin::Pin<&mut Self>,
+                update: impl FnOnce(&mut <Self as cxx_qt::CxxQtType>::Rust),
+            ) {
+                self.as_mut().begin_reset_model();
+                update(self.as_mut().rust_mut().get_mut());
+                self.as_mut().end_reset_model();
+            }
+
+            /// Insert `count` rows at `first`; `update` must add exactly those
+            fn insert_rows(
+                mut self: core::pin::Pin<&mut Self>,
+                first: usize,
+                count: usize,
+                update: impl FnOnce(&mut <Self as cxx_qt::CxxQtType>::Rust),
+            ) {
+                if count == 0 {
+                    update(self.as_mut().rust_mut().get_mut());
+                    return;
+                }
+                let root = cxx_qt_lib::QModelIndex::default();
+                let (first, last) = (first as i32, (first + count - 1) as i32);
+                self.as_mut().begin_insert_rows(&root, first, last);
+                update(self.as_mut().rust_mut().get_mut());
+                self.as_mut().end_insert_rows();
+            }
+
+            /// Remove `count` rows from `first`; `update` must drop exactly those
+            fn remove_rows(
+                mut self: core::pin::Pin<&mut Self>,
+                first: usize,
+                count: usize,
+                update: impl FnOnce(&mut <Self as cxx_qt::CxxQtType>::Rust),
+            ) {
+                if count == 0 {
+                    update(self.as_mut().rust_mut().get_mut());
+                    return;
+                }
+                let root = cxx_qt_lib::QModelIndex::default();
+                let (first, last) = (first as i32, (first + count - 1) as i32);
+                self.as_mut().begin_remove_rows(&root, first, last);
+                update(self.as_mut().rust_mut().get_mut());
+                self.as_mut().end_remove_rows();
+            }
+
+            /// Move the row at `from` so it ends up at `to`
+            fn move_row(
+                mut self: core::pin::Pin<&mut Self>,
+                from: usize,
+                to: usize,
+                update: impl FnOnce(&mut <Self as cxx_qt::CxxQtType>::Rust),
+            ) {
+                if from == to {
+                    update(self.as_mut().rust_mut().get_mut());
+                    return;
+                }
+                let root = cxx_qt_lib::QModelIndex::default();
+                // Qt wants the row the item goes in front of, counted before the move
+                let dest = if to > from { to + 1 } else { to };
+                if !self.as_mut().begin_move_rows(
+                    &root,
+                    from as i32,
+                    from as i32,
+                    &root,
+                    dest as i32,
+                ) {
+                    self.reset_rows(update);
+                    return;
+                }
+                update(self.as_mut().rust_mut().get_mut());
+                self.as_mut().end_move_rows();
+            }
+
+            /// Tell views rows `first..=last` changed in place (all roles)
+            fn rows_changed(mut self: core::pin::Pin<&mut Self>, first: usize, last: usize) {
+                let root = cxx_qt_lib::QModelIndex::default();
+                let top_left = self.index(first as i32, 0, &root);
+                let bottom_right = self.index(last as i32, 0, &root);
+                self.as_mut().data_changed(
+                    &top_left,
+                    &bottom_right,
+                    &cxx_qt_lib::QVector::default(),
+                );
+            }
+        }
+    };
+}
+
+pub(crate) use list_rows;
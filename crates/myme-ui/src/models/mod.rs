@@ -1,16 +1,48 @@
+pub mod activity_model;
+pub mod analytics_model;
 pub mod auth_model;
+pub mod bookmark_model;
+pub mod briefing_model;
+pub mod calendar_list_model;
 pub mod calendar_model;
+pub mod capture_model;
+pub mod clipboard_model;
+pub mod contacts_model;
+pub mod dashboard_model;
+pub mod discover_model;
+pub mod connectivity_model;
 pub mod encoding_model;
+pub mod error_log_model;
+pub mod feed_model;
+pub mod focus_model;
+pub mod gist_model;
+pub mod global_search_model;
 pub mod gmail_model;
 pub mod google_auth_model;
+pub mod image_cache_model;
+pub mod insights_model;
 pub mod hash_model;
+pub mod import_model;
 pub mod json_model;
 pub mod jwt_model;
 pub mod kanban_model;
+pub mod label_model;
+pub mod list_model;
 pub mod note_model;
+pub mod notifications_model;
+pub mod onboarding_model;
 pub mod project_model;
+pub mod release_model;
+pub mod remote_control_model;
 pub mod repo_model;
+pub mod runner_model;
+pub mod session_model;
+pub mod settings_model;
+pub mod startup_model;
+pub mod theme_model;
 pub mod time_model;
+pub mod toast_model;
+pub mod undo_model;
 pub mod uuid_model;
 pub mod weather_model;
 pub mod workflow_model;
@@ -1,36 +1,77 @@
 use core::pin::Pin;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use cxx_qt::CxxQtType;
-use cxx_qt_lib::{QString, QStringList};
-use myme_services::{NoteClient, Todo as Note, TodoUpdateRequest};
+use cxx_qt_lib::{QHash, QHashPair_i32_QByteArray, QModelIndex, QString, QStringList, QVariant};
+use myme_calendar::CalendarCache;
+use myme_core::dashboard::{NoteSnapshot, MAX_NOTES};
+use myme_core::datefmt;
+use myme_services::note_export;
+use myme_services::{
+    EntityKind, EntityRef, ExportFormat, NoteBackend, NoteClient, TemplateKind, Todo as Note,
+    TodoUpdateRequest,
+};
 
 use crate::bridge;
+use crate::models::list_model::{self, list_rows, ListRole};
+use crate::services::google_common::get_google_cache_path;
 use crate::services::{
-    request_note_create, request_note_delete, request_note_fetch_with_filter, request_note_toggle,
-    request_note_update, NoteServiceFilter as ServiceFilter, NoteServiceMessage,
+    add_note_palette_color, build_template, merge_note_changes, move_note, note_palette,
+    parse_template_schedule, remove_note_palette_color, request_note_create, request_note_delete,
+    request_note_export, request_note_export_label, request_note_fetch_changes,
+    request_note_fetch_page, request_note_fetch_with_filter, request_note_reorder,
+    request_note_restore, request_note_sync, request_note_toggle, request_note_trash,
+    request_note_unlock, request_note_update, template_context_today, templates_json,
+    watch_note_sync, NoteServiceFilter as ServiceFilter, NoteServiceMessage,
 };
 
+/// Notes loaded per page in the "all" view
+const PAGE_SIZE: usize = 100;
+
 #[cxx_qt::bridge]
 pub mod qobject {
     unsafe extern "C++" {
+        include!(<QtCore/QAbstractListModel>);
+        type QAbstractListModel;
+
         include!("cxx-qt-lib/qstring.h");
-        include!("cxx-qt-lib/qstringlist.h");
+        include!("cxx-qt-lib/qvariant.h");
+        include!("cxx-qt-lib/qmodelindex.h");
+        include!("cxx-qt-lib/qhash.h");
+        include!("cxx-qt-lib/qvector.h");
         type QString = cxx_qt_lib::QString;
-        type QStringList = cxx_qt_lib::QStringList;
+        type QVariant = cxx_qt_lib::QVariant;
+        type QModelIndex = cxx_qt_lib::QModelIndex;
+        type QHash_i32_QByteArray = cxx_qt_lib::QHash<cxx_qt_lib::QHashPair_i32_QByteArray>;
+        type QVector_i32 = cxx_qt_lib::QVector<i32>;
     }
 
     extern "RustQt" {
         #[qobject]
         #[qml_element]
+        #[base = QAbstractListModel]
         #[qproperty(bool, loading)]
         #[qproperty(bool, connected)]
         #[qproperty(QString, error_message)]
+        #[qproperty(QString, sync_status)]
+        #[qproperty(bool, has_more)]
+        #[qproperty(bool, locked)]
+        #[qproperty(i32, done_count)]
         type NoteModel = super::NoteModelRust;
 
         #[qinvokable]
         fn fetch_notes(self: Pin<&mut NoteModel>);
 
+        /// Load the next page (infinite scroll)
+        #[qinvokable]
+        fn fetch_more(self: Pin<&mut NoteModel>);
+
+        /// Merge notes changed since the last fetch instead of reloading everything
+        #[qinvokable]
+        fn refresh_changes(self: Pin<&mut NoteModel>);
+
         #[qinvokable]
         fn add_note(self: Pin<&mut NoteModel>, content: &QString);
 
@@ -40,9 +81,13 @@ pub mod qobject {
         #[qinvokable]
         fn toggle_done(self: Pin<&mut NoteModel>, index: i32);
 
+        /// Move a note to the trash; in the trash view, delete it permanently
         #[qinvokable]
         fn delete_note(self: Pin<&mut NoteModel>, index: i32);
 
+        #[qinvokable]
+        fn restore_note(self: Pin<&mut NoteModel>, index: i32);
+
         #[qinvokable]
         fn update_content(self: Pin<&mut NoteModel>, index: i32, content: &QString);
 
@@ -61,57 +106,233 @@ pub mod qobject {
         #[qinvokable]
         fn set_reminder(self: Pin<&mut NoteModel>, index: i32, iso: &QString);
 
+        /// Drag a note from index `from` to index `to`. Pinned and unpinned
+        /// notes are ordered separately; only the "all" and label views can be arranged.
+        #[qinvokable]
+        fn move_note(self: Pin<&mut NoteModel>, from: i32, to: i32);
+
+        /// Named note colors as a JSON array of {name, color}
+        #[qinvokable]
+        fn get_palette(self: &NoteModel) -> QString;
+
+        /// Add a color to the palette (or recolor the one with the same name).
+        /// Returns false on error.
+        #[qinvokable]
+        fn add_palette_color(self: Pin<&mut NoteModel>, name: &QString, color: &QString) -> bool;
+
+        #[qinvokable]
+        fn remove_palette_color(self: Pin<&mut NoteModel>, name: &QString) -> bool;
+
         #[qinvokable]
         fn set_filter(self: Pin<&mut NoteModel>, filter: &QString);
 
+        /// Current filter string (restored from the last session on startup)
+        #[qinvokable]
+        fn get_filter(self: &NoteModel) -> QString;
+
         #[qinvokable]
         fn add_label(self: Pin<&mut NoteModel>, index: i32, label: &QString);
 
         #[qinvokable]
         fn remove_label(self: Pin<&mut NoteModel>, index: i32, label: &QString);
 
-        /// Poll for async operation results. Call this from a QML Timer.
+        /// Export the note at index to `path` ("markdown" or "html").
+        /// `path` may be a file or an existing directory. Emits `export_finished` when written.
         #[qinvokable]
-        fn poll_channel(self: Pin<&mut NoteModel>);
+        fn export_note(self: Pin<&mut NoteModel>, index: i32, format: &QString, path: &QString);
 
+        /// Export every note with `label` into directory `dir`. Emits `export_finished`
+        /// when written.
+        #[qinvokable]
+        fn export_label(
+            self: Pin<&mut NoteModel>,
+            label: &QString,
+            format: &QString,
+            dir: &QString,
+        );
+
+        /// Attach the note at index to a kanban task. Returns false on error.
         #[qinvokable]
-        fn row_count(self: &NoteModel) -> i32;
+        fn link_note_to_task(self: Pin<&mut NoteModel>, index: i32, task_id: &QString) -> bool;
 
+        /// Attach the note at index to a calendar event (e.g. meeting notes)
         #[qinvokable]
-        fn get_content(self: &NoteModel, index: i32) -> QString;
+        fn link_note_to_event(self: Pin<&mut NoteModel>, index: i32, event_id: &QString) -> bool;
 
+        /// Detach the note at index from an item ("task", "event" or "issue")
         #[qinvokable]
-        fn get_done(self: &NoteModel, index: i32) -> bool;
+        fn unlink_note(self: Pin<&mut NoteModel>, index: i32, kind: &QString, id: &QString)
+            -> bool;
 
+        /// Items linked to a note, task, event or issue, as a JSON array of
+        /// {kind, id, title}. Works both ways: for a task this lists its notes.
         #[qinvokable]
-        fn get_id(self: &NoteModel, index: i32) -> QString;
+        fn get_linked_items(self: Pin<&mut NoteModel>, kind: &QString, id: &QString) -> QString;
 
+        /// Saved note templates as a JSON array
         #[qinvokable]
-        fn get_created_at(self: &NoteModel, index: i32) -> QString;
+        fn get_note_templates(self: &NoteModel) -> QString;
 
+        /// Create (empty `id`) or update a note template. `schedule` is "",
+        /// "daily", "weekly:mon" or "monthly:1". Returns the template id, or "" on error.
         #[qinvokable]
-        fn get_color(self: &NoteModel, index: i32) -> QString;
+        fn save_note_template(
+            self: Pin<&mut NoteModel>,
+            id: &QString,
+            name: &QString,
+            title: &QString,
+            body: &QString,
+            schedule: &QString,
+        ) -> QString;
 
         #[qinvokable]
-        fn get_pinned(self: &NoteModel, index: i32) -> bool;
+        fn delete_note_template(self: Pin<&mut NoteModel>, id: &QString) -> bool;
 
+        /// Add a note from a template, with {{date}} and {{project}} filled in
         #[qinvokable]
-        fn get_archived(self: &NoteModel, index: i32) -> bool;
+        fn create_note_from_template(self: Pin<&mut NoteModel>, template_id: &QString);
 
+        /// Sync notes with other devices through the configured sync folder
         #[qinvokable]
-        fn get_labels(self: &NoteModel, index: i32) -> QStringList;
+        fn sync_notes(self: Pin<&mut NoteModel>);
 
+        /// Unlock encrypted notes (or set the passphrase when encryption is first enabled)
         #[qinvokable]
-        fn get_is_checklist(self: &NoteModel, index: i32) -> bool;
+        fn unlock(self: Pin<&mut NoteModel>, passphrase: &QString);
 
+        /// Poll for async operation results. Call this from a QML Timer.
         #[qinvokable]
-        fn get_reminder(self: &NoteModel, index: i32) -> QString;
+        fn poll_channel(self: Pin<&mut NoteModel>);
 
-        #[qsignal]
-        fn notes_changed(self: Pin<&mut NoteModel>);
+        #[cxx_override]
+        fn data(self: &NoteModel, index: &QModelIndex, role: i32) -> QVariant;
+
+        #[cxx_override]
+        #[cxx_name = "roleNames"]
+        fn role_names(self: &NoteModel) -> QHash_i32_QByteArray;
+
+        #[cxx_override]
+        #[cxx_name = "rowCount"]
+        fn row_count(self: &NoteModel, parent: &QModelIndex) -> i32;
 
         #[qsignal]
         fn error_occurred(self: Pin<&mut NoteModel>);
+
+        /// An export completed: `count` files written, `path` is the first of them
+        #[qsignal]
+        fn export_finished(self: Pin<&mut NoteModel>, count: i32, path: QString);
+    }
+
+    unsafe extern "RustQt" {
+        #[inherit]
+        #[cxx_name = "beginResetModel"]
+        fn begin_reset_model(self: Pin<&mut NoteModel>);
+
+        #[inherit]
+        #[cxx_name = "endResetModel"]
+        fn end_reset_model(self: Pin<&mut NoteModel>);
+
+        #[inherit]
+        #[cxx_name = "beginInsertRows"]
+        fn begin_insert_rows(
+            self: Pin<&mut NoteModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        #[inherit]
+        #[cxx_name = "endInsertRows"]
+        fn end_insert_rows(self: Pin<&mut NoteModel>);
+
+        #[inherit]
+        #[cxx_name = "beginRemoveRows"]
+        fn begin_remove_rows(
+            self: Pin<&mut NoteModel>,
+            parent: &QModelIndex,
+            first: i32,
+            last: i32,
+        );
+
+        #[inherit]
+        #[cxx_name = "endRemoveRows"]
+        fn end_remove_rows(self: Pin<&mut NoteModel>);
+
+        #[inherit]
+        #[cxx_name = "beginMoveRows"]
+        fn begin_move_rows(
+            self: Pin<&mut NoteModel>,
+            source_parent: &QModelIndex,
+            source_first: i32,
+            source_last: i32,
+            destination_parent: &QModelIndex,
+            destination_child: i32,
+        ) -> bool;
+
+        #[inherit]
+        #[cxx_name = "endMoveRows"]
+        fn end_move_rows(self: Pin<&mut NoteModel>);
+
+        #[inherit]
+        fn index(self: &NoteModel, row: i32, column: i32, parent: &QModelIndex) -> QModelIndex;
+
+        #[inherit]
+        #[qsignal]
+        #[cxx_name = "dataChanged"]
+        fn data_changed(
+            self: Pin<&mut NoteModel>,
+            top_left: &QModelIndex,
+            bottom_right: &QModelIndex,
+            roles: &QVector_i32,
+        );
+    }
+}
+
+/// Roles NoteCard delegates bind to
+#[derive(Clone, Copy)]
+enum NoteRole {
+    NoteId,
+    Content,
+    Done,
+    CreatedAt,
+    NoteColor,
+    Pinned,
+    Archived,
+    Labels,
+    IsChecklist,
+    Reminder,
+    DeletedAt,
+}
+
+impl ListRole for NoteRole {
+    const ALL: &'static [Self] = &[
+        NoteRole::NoteId,
+        NoteRole::Content,
+        NoteRole::Done,
+        NoteRole::CreatedAt,
+        NoteRole::NoteColor,
+        NoteRole::Pinned,
+        NoteRole::Archived,
+        NoteRole::Labels,
+        NoteRole::IsChecklist,
+        NoteRole::Reminder,
+        NoteRole::DeletedAt,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            NoteRole::NoteId => "noteId",
+            NoteRole::Content => "content",
+            NoteRole::Done => "done",
+            NoteRole::CreatedAt => "createdAt",
+            NoteRole::NoteColor => "noteColor",
+            NoteRole::Pinned => "pinned",
+            NoteRole::Archived => "archived",
+            NoteRole::Labels => "labels",
+            NoteRole::IsChecklist => "isChecklist",
+            NoteRole::Reminder => "reminder",
+            NoteRole::DeletedAt => "deletedAt",
+        }
     }
 }
 
@@ -124,6 +345,10 @@ enum OpState {
     Creating,
     Updating(usize),
     Deleting(usize),
+    Restoring(usize),
+    Syncing,
+    Unlocking,
+    Reordering,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -131,6 +356,7 @@ enum NoteFilter {
     All,
     Archived,
     Reminders,
+    Trash,
     Label(String),
 }
 
@@ -140,15 +366,49 @@ impl Default for NoteFilter {
     }
 }
 
+impl NoteFilter {
+    fn parse(f: &str) -> Self {
+        if let Some(label) = f.strip_prefix("label:") {
+            return NoteFilter::Label(label.to_string());
+        }
+        match f {
+            "archived" => NoteFilter::Archived,
+            "reminders" => NoteFilter::Reminders,
+            "trash" => NoteFilter::Trash,
+            _ => NoteFilter::All,
+        }
+    }
+
+    fn as_string(&self) -> String {
+        match self {
+            NoteFilter::All => "all".to_string(),
+            NoteFilter::Archived => "archived".to_string(),
+            NoteFilter::Reminders => "reminders".to_string(),
+            NoteFilter::Trash => "trash".to_string(),
+            NoteFilter::Label(label) => format!("label:{}", label),
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct NoteModelRust {
     loading: bool,
     connected: bool,
     error_message: QString,
+    /// Empty until the first sync, then "disabled", "syncing", "synced", "conflicts" or "error"
+    sync_status: QString,
+    /// More pages are available in the "all" view
+    has_more: bool,
+    /// Notes are encrypted (or waiting for a passphrase) and not unlocked yet
+    locked: bool,
+    /// Loaded notes marked done, for the footer summary
+    done_count: i32,
     notes: Vec<Note>,
     client: Option<Arc<NoteClient>>,
     op_state: OpState,
     filter: NoteFilter,
+    /// When the loaded list was last brought up to date (for `refresh_changes`)
+    changes_since: Option<DateTime<Utc>>,
 }
 
 impl NoteModelRust {
@@ -165,6 +425,11 @@ impl NoteModelRust {
         match crate::bridge::get_note_client_and_runtime() {
             Some((client, _runtime)) => {
                 self.initialize(client);
+                if let Some(session) = crate::bridge::get_session_store() {
+                    if let Some(f) = session.lock().note_filter() {
+                        self.filter = NoteFilter::parse(f);
+                    }
+                }
                 tracing::info!("NoteModel auto-initialized from global services");
             }
             None => {
@@ -181,6 +446,34 @@ impl NoteModelRust {
         self.notes.get(index as usize)
     }
 
+    /// First line of a note, from the loaded list or the local store
+    fn note_title(&self, id: &str) -> Option<String> {
+        let id: i64 = id.parse().ok()?;
+        let content = match self.notes.iter().find(|n| n.id == id) {
+            Some(note) => note.content.clone(),
+            None => self.client.as_ref()?.sqlite_store().lock().get(id).ok()??.content,
+        };
+        content.lines().map(str::trim).find(|l| !l.is_empty()).map(str::to_string)
+    }
+
+    /// Keep the first notes of the "all" view for the dashboard snapshot
+    fn record_top_notes(&self) {
+        if self.filter != NoteFilter::All {
+            return;
+        }
+        let Some(store) = bridge::get_dashboard_store() else {
+            return;
+        };
+        let notes = self
+            .notes
+            .iter()
+            .filter(|n| !n.done && !n.archived && n.deleted_at.is_none())
+            .take(MAX_NOTES)
+            .map(|n| NoteSnapshot { id: n.id, title: note_export::note_title(n), pinned: n.pinned })
+            .collect();
+        store.lock().set_notes(notes);
+    }
+
     fn set_error(&mut self, msg: &str) {
         self.error_message = QString::from(msg);
     }
@@ -222,17 +515,88 @@ impl qobject::NoteModel {
             }
         };
 
-        self.as_mut().set_loading(true);
         self.as_mut().rust_mut().clear_error();
+        // Encrypted notes can't be read until the passphrase is entered
+        let locked = client.is_locked()
+            || (myme_core::Config::load_cached().notes.encrypt && !client.is_encrypted());
+        self.as_mut().set_locked(locked);
+        if locked {
+            return;
+        }
+        // Snapshots are plaintext, so encrypted notes are never synced
+        let sync_dir = myme_core::Config::load_cached().notes.sync_dir();
+        watch_note_sync(&tx, client.clone(), sync_dir.filter(|_| !client.is_encrypted()));
+        self.as_mut().start_fetch(&tx, client);
+    }
+
+    /// Reload the current filter: the first page for "all", everything otherwise.
+    fn start_fetch(
+        mut self: Pin<&mut Self>,
+        tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+        client: Arc<NoteClient>,
+    ) {
+        self.as_mut().set_loading(true);
         self.as_mut().rust_mut().op_state = OpState::Fetching;
+        self.as_mut().rust_mut().changes_since = Some(Utc::now());
 
         let service_filter = match &self.as_ref().rust().filter {
-            NoteFilter::All => ServiceFilter::All,
+            NoteFilter::All => {
+                request_note_fetch_page(tx, client, 0, PAGE_SIZE);
+                return;
+            }
             NoteFilter::Archived => ServiceFilter::Archived,
             NoteFilter::Reminders => ServiceFilter::Reminders,
+            NoteFilter::Trash => ServiceFilter::Trash,
             NoteFilter::Label(label) => ServiceFilter::Label(label.clone()),
         };
-        request_note_fetch_with_filter(&tx, client, service_filter);
+        self.as_mut().set_has_more(false);
+        request_note_fetch_with_filter(tx, client, service_filter);
+    }
+
+    /// Load the next page of the "all" view, if there is one
+    pub fn fetch_more(mut self: Pin<&mut Self>) {
+        let rust = self.as_ref().rust();
+        if !rust.has_more || rust.filter != NoteFilter::All || rust.op_state != OpState::Idle {
+            return;
+        }
+        let Some(client) = rust.client.clone() else {
+            return;
+        };
+        let offset = rust.notes.len();
+        let Some(tx) = bridge::get_note_service_tx() else {
+            return;
+        };
+
+        self.as_mut().set_loading(true);
+        self.as_mut().rust_mut().op_state = OpState::Fetching;
+        request_note_fetch_page(&tx, client, offset, PAGE_SIZE);
+    }
+
+    /// Bring the loaded "all" list up to date with notes changed since the
+    /// last fetch. Other views (and a model that hasn't loaded yet) reload.
+    pub fn refresh_changes(mut self: Pin<&mut Self>) {
+        let rust = self.as_ref().rust();
+        let since = match (rust.changes_since, &rust.filter) {
+            (Some(since), NoteFilter::All) => since,
+            _ => {
+                self.fetch_notes();
+                return;
+            }
+        };
+        if rust.op_state != OpState::Idle {
+            tracing::warn!("refresh_changes: operation already in progress");
+            return;
+        }
+        let Some(client) = rust.client.clone() else {
+            return;
+        };
+        let Some(tx) = bridge::get_note_service_tx() else {
+            return;
+        };
+
+        self.as_mut().rust_mut().op_state = OpState::Fetching;
+        self.as_mut().rust_mut().changes_since = Some(Utc::now());
+        request_note_fetch_changes(&tx, client, since);
     }
 
     /// Add a new note asynchronously (non-blocking)
@@ -340,7 +704,8 @@ impl qobject::NoteModel {
         request_note_toggle(&tx, client, index_usize, note_id, current_done);
     }
 
-    /// Delete a note asynchronously (non-blocking)
+    /// Trash a note asynchronously (non-blocking); notes already in the
+    /// trash are deleted permanently
     pub fn delete_note(mut self: Pin<&mut Self>, index: i32) {
         // Prevent concurrent operations
         if !matches!(self.as_ref().rust().op_state, OpState::Idle) {
@@ -369,10 +734,37 @@ impl qobject::NoteModel {
         };
 
         let index_usize = index as usize;
+        let permanent = self.as_ref().rust().filter == NoteFilter::Trash;
         self.as_mut().rust_mut().op_state = OpState::Deleting(index_usize);
 
         // Spawn async operation (non-blocking)
-        request_note_delete(&tx, client, index_usize, note_id);
+        if permanent {
+            request_note_delete(&tx, client, index_usize, note_id);
+        } else {
+            request_note_trash(&tx, client, index_usize, note_id);
+        }
+    }
+
+    /// Restore a trashed note asynchronously (non-blocking)
+    pub fn restore_note(mut self: Pin<&mut Self>, index: i32) {
+        if !matches!(self.as_ref().rust().op_state, OpState::Idle) {
+            tracing::warn!("restore_note: operation already in progress");
+            return;
+        }
+        let Some(note_id) = self.rust().get_note(index).map(|n| n.id) else {
+            return;
+        };
+        let Some(client) = self.rust().client.clone() else {
+            return;
+        };
+        bridge::init_note_service_channel();
+        let Some(tx) = bridge::get_note_service_tx() else {
+            return;
+        };
+
+        let index_usize = index as usize;
+        self.as_mut().rust_mut().op_state = OpState::Restoring(index_usize);
+        request_note_restore(&tx, client, index_usize, note_id);
     }
 
     /// Helper: send update request for note at index
@@ -487,17 +879,11 @@ impl qobject::NoteModel {
     }
 
     pub fn set_filter(mut self: Pin<&mut Self>, filter: &QString) {
-        let f = filter.to_string();
-        let new_filter = if f.starts_with("label:") {
-            NoteFilter::Label(f.strip_prefix("label:").unwrap_or("").to_string())
-        } else {
-            match f.as_str() {
-                "archived" => NoteFilter::Archived,
-                "reminders" => NoteFilter::Reminders,
-                _ => NoteFilter::All,
-            }
-        };
+        let new_filter = NoteFilter::parse(&filter.to_string());
         self.as_mut().rust_mut().filter = new_filter.clone();
+        if let Some(session) = bridge::get_session_store() {
+            session.lock().set_note_filter(Some(new_filter.as_string()));
+        }
 
         let client = match &self.as_ref().rust().client {
             Some(c) => c.clone(),
@@ -508,15 +894,360 @@ impl qobject::NoteModel {
             Some(t) => t,
             None => return,
         };
+        self.as_mut().start_fetch(&tx, client);
+    }
+
+    pub fn get_filter(&self) -> QString {
+        QString::from(&self.rust().filter.as_string())
+    }
+
+    /// Export a single note to Markdown or HTML
+    pub fn export_note(mut self: Pin<&mut Self>, index: i32, format: &QString, path: &QString) {
+        let Some(format) = ExportFormat::parse(&format.to_string()) else {
+            self.as_mut().set_error_message(QString::from("Unsupported export format"));
+            return;
+        };
+        let Some(note) = self.rust().get_note(index).cloned() else {
+            return;
+        };
+        let Some(tx) = bridge::get_note_service_tx() else {
+            return;
+        };
+
+        request_note_export(&tx, note, format, PathBuf::from(path.to_string()));
+    }
+
+    /// Export all notes carrying a label into a directory
+    pub fn export_label(
+        mut self: Pin<&mut Self>,
+        label: &QString,
+        format: &QString,
+        dir: &QString,
+    ) {
+        self.as_mut().rust_mut().ensure_initialized();
+
+        let Some(format) = ExportFormat::parse(&format.to_string()) else {
+            self.as_mut().set_error_message(QString::from("Unsupported export format"));
+            return;
+        };
+        let Some(client) = self.rust().client.clone() else {
+            return;
+        };
+        let Some(tx) = bridge::get_note_service_tx() else {
+            return;
+        };
+
+        request_note_export_label(
+            &tx,
+            client,
+            label.to_string(),
+            format,
+            PathBuf::from(dir.to_string()),
+        );
+    }
+
+    pub fn link_note_to_task(self: Pin<&mut Self>, index: i32, task_id: &QString) -> bool {
+        self.link_note(index, EntityRef::task(&task_id.to_string()))
+    }
+
+    pub fn link_note_to_event(self: Pin<&mut Self>, index: i32, event_id: &QString) -> bool {
+        self.link_note(index, EntityRef::event(&event_id.to_string()))
+    }
+
+    fn link_note(mut self: Pin<&mut Self>, index: i32, target: EntityRef) -> bool {
+        let Some(note) = self.rust().get_note(index).map(|n| EntityRef::note(n.id)) else {
+            return false;
+        };
+        if target.id.trim().is_empty() {
+            return false;
+        }
+        let Some(store) = bridge::get_project_store_or_init() else {
+            self.as_mut().set_error_message(QString::from("Project store not available"));
+            return false;
+        };
+        match store.link(&note, &target) {
+            Ok(_) => true,
+            Err(e) => {
+                tracing::warn!("Failed to link note: {:#}", e);
+                self.as_mut().set_error_message(QString::from("Failed to link note"));
+                self.as_mut().error_occurred();
+                false
+            }
+        }
+    }
+
+    pub fn unlink_note(mut self: Pin<&mut Self>, index: i32, kind: &QString, id: &QString) -> bool {
+        let Some(note) = self.rust().get_note(index).map(|n| EntityRef::note(n.id)) else {
+            return false;
+        };
+        let Some(kind) = EntityKind::parse(&kind.to_string()) else {
+            return false;
+        };
+        let Some(store) = bridge::get_project_store_or_init() else {
+            return false;
+        };
+        match store.unlink(&note, &EntityRef::new(kind, id.to_string())) {
+            Ok(removed) => removed,
+            Err(e) => {
+                tracing::warn!("Failed to unlink note: {:#}", e);
+                self.as_mut().set_error_message(QString::from("Failed to unlink note"));
+                false
+            }
+        }
+    }
+
+    pub fn get_linked_items(mut self: Pin<&mut Self>, kind: &QString, id: &QString) -> QString {
+        self.as_mut().rust_mut().ensure_initialized();
+
+        let Some(kind) = EntityKind::parse(&kind.to_string()) else {
+            return QString::from("[]");
+        };
+        let Some(store) = bridge::get_project_store_or_init() else {
+            return QString::from("[]");
+        };
+        let linked = match store.list_links(&EntityRef::new(kind, id.to_string())) {
+            Ok(linked) => linked,
+            Err(e) => {
+                tracing::warn!("Failed to list links: {:#}", e);
+                return QString::from("[]");
+            }
+        };
+
+        let calendar = linked
+            .iter()
+            .any(|item| item.kind == EntityKind::Event)
+            .then(|| CalendarCache::new(get_google_cache_path("calendar_cache.db")).ok())
+            .flatten();
+        let rows: Vec<serde_json::Value> = linked
+            .into_iter()
+            .map(|item| {
+                let title = match item.kind {
+                    EntityKind::Note => self.rust().note_title(&item.id),
+                    EntityKind::Task => store.get_task(&item.id).ok().flatten().map(|t| t.title),
+                    EntityKind::Event => calendar
+                        .as_ref()
+                        .and_then(|c| c.get_event("primary", &item.id).ok().flatten())
+                        .map(|e| e.summary),
+                    EntityKind::Issue => None,
+                };
+                serde_json::json!({
+                    "kind": item.kind.as_str(),
+                    "id": item.id,
+                    "title": title.unwrap_or_else(|| item.id.clone()),
+                })
+            })
+            .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn get_note_templates(&self) -> QString {
+        let templates = bridge::get_project_store_or_init()
+            .and_then(|store| store.list_templates(Some(TemplateKind::Note)).ok())
+            .unwrap_or_default();
+        QString::from(&templates_json(&templates))
+    }
+
+    pub fn save_note_template(
+        mut self: Pin<&mut Self>,
+        id: &QString,
+        name: &QString,
+        title: &QString,
+        body: &QString,
+        schedule: &QString,
+    ) -> QString {
+        let Some(store) = bridge::get_project_store_or_init() else {
+            self.as_mut().set_error_message(QString::from("Project store not available"));
+            return QString::from("");
+        };
+        if name.to_string().trim().is_empty() {
+            self.as_mut().set_error_message(QString::from("Template name is required"));
+            return QString::from("");
+        }
+        let schedule = match parse_template_schedule(&schedule.to_string()) {
+            Ok(schedule) => schedule,
+            Err(hint) => {
+                self.as_mut().set_error_message(QString::from(hint));
+                return QString::from("");
+            }
+        };
+
+        let id = id.to_string();
+        let existing = if id.is_empty() { None } else { store.get_template(&id).ok().flatten() };
+        let project_id = existing.as_ref().and_then(|t| t.project_id.clone());
+        let template = build_template(
+            existing,
+            TemplateKind::Note,
+            &name.to_string(),
+            &title.to_string(),
+            &body.to_string(),
+            project_id,
+            None,
+            schedule,
+        );
+        if let Err(e) = store.upsert_template(&template) {
+            let msg = myme_core::AppError::from(e).user_message();
+            self.as_mut().rust_mut().set_error(msg);
+            return QString::from("");
+        }
+        QString::from(&template.id)
+    }
+
+    pub fn delete_note_template(mut self: Pin<&mut Self>, id: &QString) -> bool {
+        let Some(store) = bridge::get_project_store_or_init() else {
+            return false;
+        };
+        match store.delete_template(&id.to_string()) {
+            Ok(()) => true,
+            Err(e) => {
+                let msg = myme_core::AppError::from(e).user_message();
+                self.as_mut().rust_mut().set_error(msg);
+                false
+            }
+        }
+    }
+
+    pub fn create_note_from_template(mut self: Pin<&mut Self>, template_id: &QString) {
+        let Some(store) = bridge::get_project_store_or_init() else {
+            return;
+        };
+        let template = match store.get_template(&template_id.to_string()) {
+            Ok(Some(template)) => template,
+            Ok(None) => {
+                self.as_mut().set_error_message(QString::from("Template not found"));
+                return;
+            }
+            Err(e) => {
+                let msg = myme_core::AppError::from(e).user_message();
+                self.as_mut().rust_mut().set_error(msg);
+                return;
+            }
+        };
+        let content = template.render_note(&template_context_today(&store, &template));
+        self.add_note(&QString::from(&content));
+    }
+
+    /// Sync notes with other devices through the configured sync folder
+    pub fn sync_notes(mut self: Pin<&mut Self>) {
+        self.as_mut().rust_mut().ensure_initialized();
+
+        if !matches!(self.as_ref().rust().op_state, OpState::Idle) {
+            tracing::warn!("sync_notes: operation already in progress");
+            return;
+        }
+
+        let Some(sync_dir) = myme_core::Config::load_cached().notes.sync_dir() else {
+            self.as_mut().set_sync_status(QString::from("disabled"));
+            return;
+        };
+        let Some(client) = self.rust().client.clone() else {
+            return;
+        };
+        if client.is_encrypted() {
+            self.as_mut().set_sync_status(QString::from("disabled"));
+            self.as_mut().rust_mut().set_error("Sync is off while notes are encrypted");
+            self.as_mut().error_occurred();
+            return;
+        }
+
+        bridge::init_note_service_channel();
+        let Some(tx) = bridge::get_note_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            self.as_mut().error_occurred();
+            return;
+        };
+
+        self.as_mut().rust_mut().op_state = OpState::Syncing;
+        self.as_mut().set_sync_status(QString::from("syncing"));
+        request_note_sync(&tx, client, sync_dir);
+    }
+
+    /// Unlock encrypted notes with `passphrase`, then load them
+    pub fn unlock(mut self: Pin<&mut Self>, passphrase: &QString) {
+        self.as_mut().rust_mut().ensure_initialized();
+
+        let passphrase = passphrase.to_string();
+        if passphrase.is_empty() || !matches!(self.as_ref().rust().op_state, OpState::Idle) {
+            return;
+        }
+        let Some(client) = self.rust().client.clone() else {
+            return;
+        };
+        bridge::init_note_service_channel();
+        let Some(tx) = bridge::get_note_service_tx() else {
+            return;
+        };
+
         self.as_mut().set_loading(true);
-        self.as_mut().rust_mut().op_state = OpState::Fetching;
-        let service_filter = match &new_filter {
-            NoteFilter::All => ServiceFilter::All,
-            NoteFilter::Archived => ServiceFilter::Archived,
-            NoteFilter::Reminders => ServiceFilter::Reminders,
-            NoteFilter::Label(label) => ServiceFilter::Label(label.clone()),
+        self.as_mut().rust_mut().clear_error();
+        self.as_mut().rust_mut().op_state = OpState::Unlocking;
+        request_note_unlock(&tx, client, passphrase);
+    }
+
+    /// Move a note within its group and save the new order
+    pub fn move_note(mut self: Pin<&mut Self>, from: i32, to: i32) {
+        if from == to || from < 0 || to < 0 {
+            return;
+        }
+        if !matches!(self.as_ref().rust().op_state, OpState::Idle) {
+            tracing::warn!("move_note: operation already in progress");
+            return;
+        }
+        if !matches!(self.as_ref().rust().filter, NoteFilter::All | NoteFilter::Label(_)) {
+            return;
+        }
+
+        let client = match &self.as_ref().rust().client {
+            Some(c) => c.clone(),
+            None => return,
+        };
+        bridge::init_note_service_channel();
+        let tx = match bridge::get_note_service_tx() {
+            Some(t) => t,
+            None => return,
         };
-        request_note_fetch_with_filter(&tx, client, service_filter);
+
+        let (from, to) = (from as usize, to as usize);
+        let notes = &self.as_ref().rust().notes;
+        match (notes.get(from), notes.get(to)) {
+            (Some(a), Some(b)) if a.pinned == b.pinned => {}
+            _ => return,
+        }
+        let mut ids = None;
+        self.as_mut().move_row(from, to, |rust| ids = move_note(&mut rust.notes, from, to));
+        let Some(ids) = ids else {
+            return;
+        };
+        self.as_mut().rust_mut().op_state = OpState::Reordering;
+        request_note_reorder(&tx, client, ids);
+    }
+
+    pub fn get_palette(&self) -> QString {
+        let json = serde_json::to_string(&note_palette()).unwrap_or_else(|_| "[]".to_string());
+        QString::from(&json)
+    }
+
+    pub fn add_palette_color(mut self: Pin<&mut Self>, name: &QString, color: &QString) -> bool {
+        match add_note_palette_color(&name.to_string(), &color.to_string()) {
+            Ok(()) => true,
+            Err(e) => {
+                let msg = myme_core::AppError::from(e).user_message();
+                self.as_mut().rust_mut().set_error(msg);
+                self.as_mut().error_occurred();
+                false
+            }
+        }
+    }
+
+    pub fn remove_palette_color(mut self: Pin<&mut Self>, name: &QString) -> bool {
+        match remove_note_palette_color(&name.to_string()) {
+            Ok(()) => true,
+            Err(e) => {
+                let msg = myme_core::AppError::from(e).user_message();
+                self.as_mut().rust_mut().set_error(msg);
+                self.as_mut().error_occurred();
+                false
+            }
+        }
     }
 
     /// Poll for async operation results. Call this from a QML Timer (e.g., every 100ms).
@@ -534,9 +1265,9 @@ impl qobject::NoteModel {
                     Ok(notes) => {
                         tracing::info!("Successfully fetched {} notes", notes.len());
                         self.as_mut().rust_mut().clear_error();
-                        self.as_mut().rust_mut().notes = notes;
+                        self.as_mut().reset_rows(|rust| rust.notes = notes);
                         self.as_mut().set_connected(true);
-                        self.as_mut().notes_changed();
+                        self.rust().record_top_notes();
                     }
                     Err(e) => {
                         tracing::error!("Failed to fetch notes: {}", e);
@@ -547,6 +1278,57 @@ impl qobject::NoteModel {
                     }
                 }
             }
+            NoteServiceMessage::PageDone { offset, result } => {
+                self.as_mut().set_loading(false);
+                self.as_mut().rust_mut().op_state = OpState::Idle;
+                match result {
+                    Ok(page) => {
+                        tracing::info!("Fetched {} notes at offset {}", page.len(), offset);
+                        self.as_mut().set_has_more(page.len() == PAGE_SIZE);
+                        self.as_mut().rust_mut().clear_error();
+                        if offset == 0 {
+                            self.as_mut().reset_rows(|rust| rust.notes = page);
+                        } else {
+                            // Notes that moved up since the previous page are already loaded
+                            let loaded = &self.as_ref().rust().notes;
+                            let new: Vec<Note> = page
+                                .into_iter()
+                                .filter(|n| !loaded.iter().any(|l| l.id == n.id))
+                                .collect();
+                            let first = loaded.len();
+                            self.as_mut()
+                                .insert_rows(first, new.len(), |rust| rust.notes.extend(new));
+                        }
+                        self.as_mut().set_connected(true);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to fetch notes: {}", e);
+                        let msg = myme_core::AppError::from(e).user_message();
+                        self.as_mut().rust_mut().set_error(msg);
+                        self.as_mut().set_connected(false);
+                        self.as_mut().error_occurred();
+                    }
+                }
+            }
+            NoteServiceMessage::ChangesDone(result) => {
+                self.as_mut().rust_mut().op_state = OpState::Idle;
+                match result {
+                    Ok(changed) => {
+                        if !changed.is_empty() {
+                            tracing::info!("Merging {} changed notes", changed.len());
+                            // Changes can move notes anywhere in the list
+                            self.as_mut()
+                                .reset_rows(|rust| merge_note_changes(&mut rust.notes, changed));
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to fetch note changes: {}", e);
+                        let msg = myme_core::AppError::from(e).user_message();
+                        self.as_mut().rust_mut().set_error(msg);
+                        self.as_mut().error_occurred();
+                    }
+                }
+            }
             NoteServiceMessage::CreateDone(result) => {
                 self.as_mut().set_loading(false);
                 self.as_mut().rust_mut().op_state = OpState::Idle;
@@ -554,8 +1336,9 @@ impl qobject::NoteModel {
                     Ok(note) => {
                         tracing::info!("Created note: {}", note.id);
                         self.as_mut().rust_mut().clear_error();
-                        self.as_mut().rust_mut().notes.push(note);
-                        self.as_mut().notes_changed();
+                        // New notes go on top, below the pinned ones
+                        let at = self.rust().notes.iter().take_while(|n| n.pinned).count();
+                        self.as_mut().insert_rows(at, 1, |rust| rust.notes.insert(at, note));
                     }
                     Err(e) => {
                         tracing::error!("Failed to create note: {}", e);
@@ -580,11 +1363,13 @@ impl qobject::NoteModel {
                                 || (matches!(&filter, NoteFilter::Reminders)
                                     && updated_note.reminder.is_none());
                             if should_remove {
-                                self.as_mut().rust_mut().notes.remove(index);
+                                self.as_mut().remove_rows(index, 1, |rust| {
+                                    rust.notes.remove(index);
+                                });
                             } else {
                                 self.as_mut().rust_mut().notes[index] = updated_note;
+                                self.as_mut().rows_changed(index, index);
                             }
-                            self.as_mut().notes_changed();
                         }
                     }
                     Err(e) => {
@@ -601,9 +1386,20 @@ impl qobject::NoteModel {
                     Ok(_) => {
                         tracing::info!("Deleted note at index {}", index);
                         self.as_mut().rust_mut().clear_error();
+                        // Deleting from the trash is permanent, so drop the note's links
+                        if self.rust().filter == NoteFilter::Trash {
+                            let note = self.rust().notes.get(index).map(|n| EntityRef::note(n.id));
+                            let store = bridge::get_project_store_or_init();
+                            if let (Some(note), Some(store)) = (note, store) {
+                                if let Err(e) = store.remove_links(&note) {
+                                    tracing::warn!("Failed to remove note links: {:#}", e);
+                                }
+                            }
+                        }
                         if index < self.as_ref().rust().notes.len() {
-                            self.as_mut().rust_mut().notes.remove(index);
-                            self.as_mut().notes_changed();
+                            self.as_mut().remove_rows(index, 1, |rust| {
+                                rust.notes.remove(index);
+                            });
                         }
                     }
                     Err(e) => {
@@ -614,76 +1410,145 @@ impl qobject::NoteModel {
                     }
                 }
             }
+            NoteServiceMessage::RestoreDone { index, result } => {
+                self.as_mut().rust_mut().op_state = OpState::Idle;
+                match result {
+                    Ok(note) => {
+                        tracing::info!("Restored note {} from trash", note.id);
+                        self.as_mut().rust_mut().clear_error();
+                        // Restoring always happens from the trash view, where it no longer belongs
+                        if index < self.as_ref().rust().notes.len() {
+                            self.as_mut().remove_rows(index, 1, |rust| {
+                                rust.notes.remove(index);
+                            });
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to restore note: {}", e);
+                        let msg = myme_core::AppError::from(e).user_message();
+                        self.as_mut().rust_mut().set_error(msg);
+                        self.as_mut().error_occurred();
+                    }
+                }
+            }
+            NoteServiceMessage::UnlockDone(result) => {
+                self.as_mut().set_loading(false);
+                self.as_mut().rust_mut().op_state = OpState::Idle;
+                match result {
+                    Ok(()) => {
+                        self.as_mut().set_locked(false);
+                        self.as_mut().fetch_notes();
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to unlock notes: {}", e);
+                        self.as_mut()
+                            .rust_mut()
+                            .set_error("Could not unlock notes. Check the passphrase.");
+                        self.as_mut().error_occurred();
+                    }
+                }
+            }
+            NoteServiceMessage::ReorderDone(result) => {
+                self.as_mut().rust_mut().op_state = OpState::Idle;
+                if let Err(e) = result {
+                    tracing::error!("Failed to save note order: {}", e);
+                    let msg = myme_core::AppError::from(e).user_message();
+                    self.as_mut().rust_mut().set_error(msg);
+                    self.as_mut().error_occurred();
+                    // Put back the order the store actually has
+                    self.as_mut().fetch_notes();
+                }
+            }
+            NoteServiceMessage::ExportDone(result) => match result {
+                Ok(written) => {
+                    let first = written
+                        .first()
+                        .map(|p| QString::from(p.to_string_lossy().as_ref()))
+                        .unwrap_or_default();
+                    self.as_mut().export_finished(written.len() as i32, first);
+                }
+                Err(e) => {
+                    tracing::warn!("Note export failed: {}", e);
+                    let msg = myme_core::AppError::from(e).user_message();
+                    self.as_mut().rust_mut().set_error(msg);
+                    self.as_mut().error_occurred();
+                }
+            },
+            NoteServiceMessage::SyncDone(result) => {
+                self.as_mut().rust_mut().op_state = OpState::Idle;
+                match result {
+                    Ok(report) => {
+                        let status = if report.conflicts > 0 { "conflicts" } else { "synced" };
+                        self.as_mut().set_sync_status(QString::from(status));
+                        // Deletions aren't reported as changes, so they need a reload
+                        if report.deleted > 0 {
+                            self.as_mut().fetch_notes();
+                        } else if report.applied + report.conflicts > 0 {
+                            self.as_mut().refresh_changes();
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Notes sync failed: {}", e);
+                        self.as_mut().set_sync_status(QString::from("error"));
+                        let msg = myme_core::AppError::from(e).user_message();
+                        self.as_mut().rust_mut().set_error(msg);
+                        self.as_mut().error_occurred();
+                    }
+                }
+            }
+            NoteServiceMessage::LiveSyncDone(report) => {
+                let status = if report.conflicts > 0 { "conflicts" } else { "synced" };
+                self.as_mut().set_sync_status(QString::from(status));
+                if report.deleted > 0 {
+                    self.as_mut().fetch_notes();
+                } else {
+                    self.as_mut().refresh_changes();
+                }
+            }
         }
+        self.update_done_count();
     }
 
-    pub fn row_count(&self) -> i32 {
+    pub fn row_count(&self, _parent: &QModelIndex) -> i32 {
         self.rust().notes.len() as i32
     }
 
-    pub fn get_content(&self, index: i32) -> QString {
-        self.rust()
-            .get_note(index)
-            .map(|note| QString::from(&note.content))
-            .unwrap_or_else(|| QString::from(""))
-    }
-
-    pub fn get_done(&self, index: i32) -> bool {
-        self.rust().get_note(index).map(|note| note.done).unwrap_or(false)
-    }
-
-    pub fn get_id(&self, index: i32) -> QString {
-        self.rust()
-            .get_note(index)
-            .map(|note| QString::from(note.id.to_string()))
-            .unwrap_or_else(|| QString::from(""))
+    pub fn role_names(&self) -> QHash<QHashPair_i32_QByteArray> {
+        list_model::role_names::<NoteRole>()
     }
 
-    pub fn get_created_at(&self, index: i32) -> QString {
-        self.rust()
-            .get_note(index)
-            .map(|note| QString::from(note.created_at.format("%Y-%m-%d %H:%M").to_string()))
-            .unwrap_or_else(|| QString::from(""))
-    }
-
-    pub fn get_color(&self, index: i32) -> QString {
-        self.rust()
-            .get_note(index)
-            .and_then(|note| note.color.as_ref())
-            .map(|s| QString::from(s.as_str()))
-            .unwrap_or_else(|| QString::from(""))
-    }
-
-    pub fn get_pinned(&self, index: i32) -> bool {
-        self.rust().get_note(index).map(|note| note.pinned).unwrap_or(false)
-    }
-
-    pub fn get_archived(&self, index: i32) -> bool {
-        self.rust().get_note(index).map(|note| note.archived).unwrap_or(false)
-    }
-
-    pub fn get_labels(&self, index: i32) -> QStringList {
-        self.rust()
-            .get_note(index)
-            .map(|note| {
+    pub fn data(&self, index: &QModelIndex, role: i32) -> QVariant {
+        let (Some(note), Some(role)) =
+            (self.rust().get_note(index.row()), NoteRole::from_role(role))
+        else {
+            return QVariant::default();
+        };
+        let text = |s: String| QVariant::from(&QString::from(&s));
+        match role {
+            NoteRole::NoteId => text(note.id.to_string()),
+            NoteRole::Content => text(note.content.clone()),
+            NoteRole::Done => QVariant::from(&note.done),
+            NoteRole::CreatedAt => text(datefmt::relative(note.created_at)),
+            NoteRole::NoteColor => text(note.color.clone().unwrap_or_default()),
+            NoteRole::Pinned => QVariant::from(&note.pinned),
+            NoteRole::Archived => QVariant::from(&note.archived),
+            NoteRole::Labels => {
                 let mut list = QStringList::default();
                 for label in &note.labels {
                     list.append(QString::from(label.as_str()));
                 }
-                list
-            })
-            .unwrap_or_else(QStringList::default)
-    }
-
-    pub fn get_is_checklist(&self, index: i32) -> bool {
-        self.rust().get_note(index).map(|note| note.is_checklist).unwrap_or(false)
+                QVariant::from(&list)
+            }
+            NoteRole::IsChecklist => QVariant::from(&note.is_checklist),
+            NoteRole::Reminder => text(note.reminder.map(datefmt::moment).unwrap_or_default()),
+            NoteRole::DeletedAt => text(note.deleted_at.map(datefmt::relative).unwrap_or_default()),
+        }
     }
 
-    pub fn get_reminder(&self, index: i32) -> QString {
-        self.rust()
-            .get_note(index)
-            .and_then(|note| note.reminder.as_ref())
-            .map(|dt| QString::from(dt.format("%Y-%m-%d %H:%M").to_string()))
-            .unwrap_or_else(|| QString::from(""))
+    fn update_done_count(mut self: Pin<&mut Self>) {
+        let done = self.rust().notes.iter().filter(|n| n.done).count() as i32;
+        self.as_mut().set_done_count(done);
     }
 }
+
+list_rows!(qobject::NoteModel);
@@ -0,0 +1,292 @@
+// crates/myme-ui/src/models/notifications_model.rs
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::GitHubNotification;
+
+use crate::bridge;
+use crate::services::{
+    request_notification_fetch, request_notification_mark_read, request_notification_unsubscribe,
+    NotificationServiceMessage,
+};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(bool, authenticated)]
+        #[qproperty(QString, error_message)]
+        #[qproperty(i32, unread_count)]
+        #[qproperty(bool, show_all)]
+        type NotificationsModel = super::NotificationsModelRust;
+
+        #[qinvokable]
+        fn check_auth(self: Pin<&mut NotificationsModel>);
+
+        /// Fetch the inbox; call from a polling Timer as well as on demand
+        #[qinvokable]
+        fn fetch_notifications(self: Pin<&mut NotificationsModel>);
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut NotificationsModel>);
+
+        #[qinvokable]
+        fn mark_read(self: Pin<&mut NotificationsModel>, index: i32);
+
+        #[qinvokable]
+        fn unsubscribe(self: Pin<&mut NotificationsModel>, index: i32);
+
+        #[qinvokable]
+        fn row_count(self: &NotificationsModel) -> i32;
+
+        #[qinvokable]
+        fn get_title(self: &NotificationsModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_repo(self: &NotificationsModel, index: i32) -> QString;
+
+        /// Human-readable reason, e.g. "Review requested"
+        #[qinvokable]
+        fn get_reason(self: &NotificationsModel, index: i32) -> QString;
+
+        /// Subject type: Issue, PullRequest, CheckSuite, Release, ...
+        #[qinvokable]
+        fn get_kind(self: &NotificationsModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_updated_at(self: &NotificationsModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_unread(self: &NotificationsModel, index: i32) -> bool;
+
+        #[qinvokable]
+        fn get_html_url(self: &NotificationsModel, index: i32) -> QString;
+
+        #[qsignal]
+        fn notifications_changed(self: Pin<&mut NotificationsModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct NotificationsModelRust {
+    loading: bool,
+    authenticated: bool,
+    error_message: QString,
+    unread_count: i32,
+    /// Include read threads (GitHub's `all=true`)
+    show_all: bool,
+    notifications: Vec<GitHubNotification>,
+}
+
+/// Label shown for GitHub's notification `reason`
+fn reason_label(reason: &str) -> &str {
+    match reason {
+        "review_requested" => "Review requested",
+        "mention" | "team_mention" => "Mentioned",
+        "ci_activity" => "CI activity",
+        "assign" => "Assigned",
+        "author" => "Your thread",
+        "comment" => "Commented",
+        "state_change" => "State changed",
+        "security_alert" => "Security alert",
+        "subscribed" | "manual" => "Watching",
+        other => other,
+    }
+}
+
+impl NotificationsModelRust {
+    fn get(&self, index: i32) -> Option<&GitHubNotification> {
+        if index < 0 {
+            return None;
+        }
+        self.notifications.get(index as usize)
+    }
+
+    fn thread_id(&self, index: i32) -> Option<String> {
+        self.get(index).map(|n| n.id.clone())
+    }
+
+    fn count_unread(&self) -> i32 {
+        self.notifications.iter().filter(|n| n.unread).count() as i32
+    }
+
+    fn set_error(&mut self, msg: &str) {
+        self.error_message = QString::from(msg);
+    }
+
+    fn clear_error(&mut self) {
+        self.error_message = QString::from("");
+    }
+}
+
+impl qobject::NotificationsModel {
+    pub fn check_auth(mut self: Pin<&mut Self>) {
+        let auth = bridge::get_github_client_and_runtime().is_some();
+        if self.as_ref().rust().authenticated != auth {
+            self.as_mut().set_authenticated(auth);
+        }
+    }
+
+    pub fn fetch_notifications(mut self: Pin<&mut Self>) {
+        if self.as_ref().rust().loading {
+            return;
+        }
+        let (client, _runtime) = match bridge::get_github_client_and_runtime() {
+            Some(pair) => pair,
+            None => {
+                self.as_mut().set_authenticated(false);
+                return;
+            }
+        };
+
+        bridge::init_notification_service_channel();
+        let tx = match bridge::get_notification_service_tx() {
+            Some(t) => t,
+            None => {
+                self.as_mut()
+                    .set_error_message(QString::from("Notification service channel not ready"));
+                return;
+            }
+        };
+
+        self.as_mut().set_loading(true);
+        let all = self.as_ref().rust().show_all;
+        request_notification_fetch(&tx, client, all);
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let msg = match bridge::try_recv_notification_message() {
+            Some(m) => m,
+            None => return,
+        };
+
+        match msg {
+            NotificationServiceMessage::FetchDone(result) => {
+                self.as_mut().set_loading(false);
+                match result {
+                    Ok(notifications) => {
+                        self.as_mut().rust_mut().clear_error();
+                        self.as_mut().rust_mut().notifications = notifications;
+                        let unread = self.as_ref().rust().count_unread();
+                        self.as_mut().set_unread_count(unread);
+                        self.as_mut().notifications_changed();
+                    }
+                    Err(e) => {
+                        self.as_mut()
+                            .rust_mut()
+                            .set_error(myme_core::AppError::from(e).user_message());
+                    }
+                }
+            }
+            NotificationServiceMessage::MarkReadDone { id, result }
+            | NotificationServiceMessage::UnsubscribeDone { id, result } => {
+                if let Err(e) = result {
+                    tracing::warn!("Notification thread {} update failed", id);
+                    self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                    // Resync so the optimistic change is undone
+                    self.as_mut().fetch_notifications();
+                }
+            }
+        }
+    }
+
+    pub fn mark_read(mut self: Pin<&mut Self>, index: i32) {
+        let Some(id) = self.as_ref().rust().thread_id(index) else {
+            return;
+        };
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            return;
+        };
+        bridge::init_notification_service_channel();
+        let Some(tx) = bridge::get_notification_service_tx() else {
+            return;
+        };
+
+        // Optimistic: dim it now, the server catches up
+        if let Some(n) = self.as_mut().rust_mut().notifications.get_mut(index as usize) {
+            n.unread = false;
+        }
+        let unread = self.as_ref().rust().count_unread();
+        self.as_mut().set_unread_count(unread);
+        self.as_mut().notifications_changed();
+        request_notification_mark_read(&tx, client, id);
+    }
+
+    pub fn unsubscribe(mut self: Pin<&mut Self>, index: i32) {
+        let Some(id) = self.as_ref().rust().thread_id(index) else {
+            return;
+        };
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            return;
+        };
+        bridge::init_notification_service_channel();
+        let Some(tx) = bridge::get_notification_service_tx() else {
+            return;
+        };
+
+        self.as_mut().rust_mut().notifications.remove(index as usize);
+        let unread = self.as_ref().rust().count_unread();
+        self.as_mut().set_unread_count(unread);
+        self.as_mut().notifications_changed();
+        request_notification_unsubscribe(&tx, client, id);
+    }
+
+    pub fn row_count(&self) -> i32 {
+        self.rust().notifications.len() as i32
+    }
+
+    pub fn get_title(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .map(|n| QString::from(&n.subject.title))
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_repo(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .map(|n| QString::from(&n.repository.full_name))
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_reason(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .map(|n| QString::from(reason_label(&n.reason)))
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_kind(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .map(|n| QString::from(&n.subject.kind))
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_updated_at(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .map(|n| QString::from(&n.updated_at))
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_unread(&self, index: i32) -> bool {
+        self.rust().get(index).map(|n| n.unread).unwrap_or(false)
+    }
+
+    pub fn get_html_url(&self, index: i32) -> QString {
+        self.rust()
+            .get(index)
+            .map(|n| QString::from(&n.html_url()))
+            .unwrap_or_else(|| QString::from(""))
+    }
+}
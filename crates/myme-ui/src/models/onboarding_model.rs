@@ -0,0 +1,224 @@
+//! First-run guide for QML: which setup step is next, overall progress, and
+//! invokables to complete, skip or dismiss steps. Progress is kept in
+//! `onboarding.json` via `myme_core::OnboardingStore`.
+
+use core::pin::Pin;
+use std::path::Path;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_auth::SecureStorage;
+use myme_core::onboarding::detect_steps;
+use myme_core::{OnboardingStep, OnboardingStore};
+
+use crate::bridge;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, active)]
+        #[qproperty(QString, current_step)]
+        #[qproperty(i32, completed_count)]
+        #[qproperty(i32, total_steps)]
+        #[qproperty(QString, error_message)]
+        type OnboardingModel = super::OnboardingModelRust;
+
+        /// Load progress and mark steps already satisfied (config file, repos
+        /// directory, notes sync folder, GitHub and Google tokens)
+        #[qinvokable]
+        fn refresh(self: Pin<&mut OnboardingModel>);
+
+        /// Steps as a JSON array: id, label, done, skipped, optional, current
+        #[qinvokable]
+        fn get_steps(self: &OnboardingModel) -> QString;
+
+        #[qinvokable]
+        fn complete_step(self: Pin<&mut OnboardingModel>, step: &QString);
+
+        /// Skip an optional step (Google, notes backend)
+        #[qinvokable]
+        fn skip_step(self: Pin<&mut OnboardingModel>, step: &QString);
+
+        /// Save `path` as `repos.local_search_path` and complete the step
+        #[qinvokable]
+        fn choose_repos_path(self: Pin<&mut OnboardingModel>, path: &QString);
+
+        /// Keep notes locally (empty `sync_dir`) or sync them through a shared
+        /// folder, saved as `notes.sync_dir`, and complete the step
+        #[qinvokable]
+        fn choose_notes_backend(self: Pin<&mut OnboardingModel>, sync_dir: &QString);
+
+        /// Hide the guide with steps left
+        #[qinvokable]
+        fn dismiss(self: Pin<&mut OnboardingModel>);
+
+        /// Start the guide over
+        #[qinvokable]
+        fn reset(self: Pin<&mut OnboardingModel>);
+
+        #[qsignal]
+        fn steps_changed(self: Pin<&mut OnboardingModel>);
+    }
+}
+
+pub struct OnboardingModelRust {
+    active: bool,
+    current_step: QString,
+    completed_count: i32,
+    total_steps: i32,
+    error_message: QString,
+    store: OnboardingStore,
+}
+
+impl Default for OnboardingModelRust {
+    fn default() -> Self {
+        let config_dir = myme_core::Config::load_cached().config_dir.clone();
+        Self {
+            active: false,
+            current_step: QString::from(""),
+            completed_count: 0,
+            total_steps: OnboardingStep::ALL.len() as i32,
+            error_message: QString::from(""),
+            store: OnboardingStore::new(&config_dir),
+        }
+    }
+}
+
+impl qobject::OnboardingModel {
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        if let Err(e) = self.as_mut().rust_mut().store.load() {
+            tracing::warn!("Failed to load onboarding progress: {}", e);
+        }
+        // Fresh config: the repos path or notes folder may have just been chosen
+        let config = myme_core::Config::load().unwrap_or_else(|e| {
+            tracing::warn!("Config load failed, using cached: {}", e);
+            myme_core::Config::load_cached().as_ref().clone()
+        });
+        let mut detected = detect_steps(&config);
+        if bridge::is_github_authenticated() {
+            detected.push(OnboardingStep::GithubConnected);
+        }
+        if SecureStorage::has_token("google") {
+            detected.push(OnboardingStep::GoogleConnected);
+        }
+        self.as_mut().rust_mut().store.sync(&detected);
+        self.as_mut().save_and_publish();
+    }
+
+    pub fn get_steps(&self) -> QString {
+        let state = self.rust().store.state();
+        let current = state.current_step();
+        let rows: Vec<serde_json::Value> = OnboardingStep::ALL
+            .iter()
+            .map(|step| {
+                serde_json::json!({
+                    "id": step.as_str(),
+                    "label": step.label(),
+                    "done": state.completed.contains(step),
+                    "skipped": state.skipped.contains(step),
+                    "optional": step.is_optional(),
+                    "current": current == Some(*step),
+                })
+            })
+            .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn complete_step(mut self: Pin<&mut Self>, step: &QString) {
+        let Some(step) = OnboardingStep::parse(&step.to_string()) else {
+            return;
+        };
+        self.as_mut().rust_mut().store.complete(step);
+        self.as_mut().save_and_publish();
+    }
+
+    pub fn skip_step(mut self: Pin<&mut Self>, step: &QString) {
+        let Some(step) = OnboardingStep::parse(&step.to_string()) else {
+            return;
+        };
+        if self.as_mut().rust_mut().store.skip(step) {
+            self.as_mut().save_and_publish();
+        }
+    }
+
+    pub fn choose_repos_path(mut self: Pin<&mut Self>, path: &QString) {
+        let path = path.to_string().trim().to_string();
+        if !Path::new(&path).is_dir() {
+            self.as_mut().set_error_message(QString::from(&format!("{} is not a folder", path)));
+            return;
+        }
+        if !self.as_mut().update_config(|config| config.repos.local_search_path = path.clone()) {
+            return;
+        }
+        self.as_mut().rust_mut().store.complete(OnboardingStep::ReposPathChosen);
+        self.as_mut().save_and_publish();
+    }
+
+    pub fn choose_notes_backend(mut self: Pin<&mut Self>, sync_dir: &QString) {
+        let sync_dir = sync_dir.to_string().trim().to_string();
+        if !sync_dir.is_empty() && !Path::new(&sync_dir).is_dir() {
+            self.as_mut()
+                .set_error_message(QString::from(&format!("{} is not a folder", sync_dir)));
+            return;
+        }
+        let value = Some(sync_dir).filter(|d| !d.is_empty());
+        if !self.as_mut().update_config(|config| config.notes.sync_dir = value) {
+            return;
+        }
+        self.as_mut().rust_mut().store.complete(OnboardingStep::NotesBackendSelected);
+        self.as_mut().save_and_publish();
+    }
+
+    pub fn dismiss(mut self: Pin<&mut Self>) {
+        self.as_mut().rust_mut().store.dismiss();
+        self.as_mut().save_and_publish();
+    }
+
+    pub fn reset(mut self: Pin<&mut Self>) {
+        self.as_mut().rust_mut().store.reset();
+        self.as_mut().save_and_publish();
+        self.refresh();
+    }
+
+    /// Apply `f` to the config file and save it; false (with the error shown)
+    /// when it couldn't be saved
+    fn update_config<F>(mut self: Pin<&mut Self>, f: F) -> bool
+    where
+        F: FnOnce(&mut myme_core::Config),
+    {
+        let result = myme_core::Config::load().and_then(|mut config| {
+            f(&mut config);
+            config.save()
+        });
+        if let Err(e) = result {
+            tracing::warn!("Failed to save config: {}", e);
+            let msg = myme_core::AppError::from(e).user_message().to_string();
+            self.as_mut().set_error_message(QString::from(&msg));
+            return false;
+        }
+        true
+    }
+
+    /// Persist progress and update the properties from it
+    fn save_and_publish(mut self: Pin<&mut Self>) {
+        if let Err(e) = self.as_mut().rust_mut().store.save() {
+            tracing::warn!("Failed to save onboarding progress: {}", e);
+        }
+        let state = self.rust().store.state().clone();
+        let (done, total) = state.progress();
+        let current = state.current_step().map(|s| s.as_str()).unwrap_or("");
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_active(state.is_active());
+        self.as_mut().set_current_step(QString::from(current));
+        self.as_mut().set_completed_count(done as i32);
+        self.as_mut().set_total_steps(total as i32);
+        self.as_mut().steps_changed();
+    }
+}
@@ -6,10 +6,14 @@ use std::sync::Arc;
 
 use cxx_qt::CxxQtType;
 use cxx_qt_lib::QString;
+use myme_core::i18n::tr;
 use myme_services::{GitHubClient, Project, ProjectStore, TaskStatus};
 
 use crate::bridge;
-use crate::services::{request_project_fetch_repo, ProjectServiceMessage};
+use crate::services::{
+    request_project_ensure_labels, request_project_fetch_repo, request_project_repo_choices,
+    ProjectServiceMessage,
+};
 
 #[cxx_qt::bridge]
 pub mod qobject {
@@ -72,9 +76,38 @@ pub mod qobject {
         #[qinvokable]
         fn remove_project(self: Pin<&mut ProjectModel>, index: i32);
 
+        /// Archive the project at index: it leaves the list and background
+        /// syncs but keeps its tasks
+        #[qinvokable]
+        fn archive_project(self: Pin<&mut ProjectModel>, index: i32);
+
+        /// Load archived projects for `archived_count`/`get_archived_name`;
+        /// emits `archived_changed`
+        #[qinvokable]
+        fn fetch_archived(self: Pin<&mut ProjectModel>);
+
+        #[qinvokable]
+        fn archived_count(self: &ProjectModel) -> i32;
+
+        #[qinvokable]
+        fn get_archived_name(self: &ProjectModel, index: i32) -> QString;
+
+        /// Put the archived project at index back on the list
+        #[qinvokable]
+        fn restore_project(self: Pin<&mut ProjectModel>, index: i32);
+
         #[qinvokable]
         fn sync_project(self: Pin<&mut ProjectModel>, index: i32);
 
+        /// Look up the repos to offer when linking one (own repos plus configured
+        /// orgs and teams); emits `repo_choices_changed`
+        #[qinvokable]
+        fn load_repo_choices(self: Pin<&mut ProjectModel>);
+
+        /// JSON array of `owner/repo` names from `load_repo_choices`
+        #[qinvokable]
+        fn get_repo_choices(self: &ProjectModel) -> QString;
+
         #[qinvokable]
         fn check_auth(self: Pin<&mut ProjectModel>);
 
@@ -87,6 +120,12 @@ pub mod qobject {
 
         #[qsignal]
         fn auth_changed(self: Pin<&mut ProjectModel>);
+
+        #[qsignal]
+        fn repo_choices_changed(self: Pin<&mut ProjectModel>);
+
+        #[qsignal]
+        fn archived_changed(self: Pin<&mut ProjectModel>);
     }
 }
 
@@ -142,10 +181,14 @@ pub struct ProjectModelRust {
     authenticated: bool,
     error_message: QString,
     projects: Vec<Project>,
+    archived: Vec<Project>,
     task_counts: HashMap<String, TaskCounts>,
     github_client: Option<Arc<GitHubClient>>,
-    project_store: Option<Arc<parking_lot::Mutex<ProjectStore>>>,
+    project_store: Option<Arc<ProjectStore>>,
     op_state: OpState,
+    repo_choices: Vec<String>,
+    /// A repo choices lookup is in flight
+    choices_pending: bool,
 }
 
 impl ProjectModelRust {
@@ -187,11 +230,9 @@ impl ProjectModelRust {
             None => return,
         };
 
-        let store_guard = store.lock();
-
         self.task_counts.clear();
         for project in &self.projects {
-            match store_guard.count_tasks_by_status(&project.id) {
+            match store.count_tasks_by_status(&project.id) {
                 Ok(counts) => {
                     self.task_counts
                         .insert(project.id.clone(), TaskCounts::from_status_counts(&counts));
@@ -230,12 +271,9 @@ impl qobject::ProjectModel {
         self.as_mut().rust_mut().clear_error();
 
         // Load projects from store (this is synchronous/local, so OK to do inline)
-        let store_guard = store.lock();
-
-        match store_guard.list_projects() {
+        match store.list_projects() {
             Ok(projects) => {
                 tracing::info!("Loaded {} projects from store", projects.len());
-                drop(store_guard); // Release lock before modifying self
                 self.as_mut().rust_mut().projects = projects;
                 self.as_mut().rust_mut().load_task_counts();
                 self.as_mut().set_loading(false);
@@ -243,7 +281,6 @@ impl qobject::ProjectModel {
             }
             Err(e) => {
                 tracing::error!("Failed to load projects: {}", e);
-                drop(store_guard);
                 self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
                 self.as_mut().set_loading(false);
             }
@@ -281,8 +318,7 @@ impl qobject::ProjectModel {
             Some(s) => s,
             None => return QString::from("[]"),
         };
-        let store_guard = store.lock();
-        match store_guard.list_repos_for_project(&project_id) {
+        match store.list_repos_for_project(&project_id) {
             Ok(repos) => {
                 let json = serde_json::to_string(&repos).unwrap_or_else(|_| "[]".to_string());
                 QString::from(&json)
@@ -341,11 +377,8 @@ impl qobject::ProjectModel {
             created_at: chrono::Utc::now().to_rfc3339(),
         };
 
-        let store_guard = store.lock();
-
-        match store_guard.upsert_project(&project) {
+        match store.upsert_project(&project) {
             Ok(_) => {
-                drop(store_guard);
                 self.as_mut().rust_mut().projects.push(project.clone());
                 self.as_mut()
                     .rust_mut()
@@ -355,7 +388,6 @@ impl qobject::ProjectModel {
                 tracing::info!("Created project: {}", project.name);
             }
             Err(e) => {
-                drop(store_guard);
                 self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
             }
         }
@@ -388,7 +420,7 @@ impl qobject::ProjectModel {
         let github_client = match &self.as_ref().rust().github_client {
             Some(c) => c.clone(),
             None => {
-                self.as_mut().set_error_message(QString::from("GitHub not authenticated"));
+                self.as_mut().set_error_message(QString::from(tr("github-not-authenticated")));
                 return;
             }
         };
@@ -439,7 +471,7 @@ impl qobject::ProjectModel {
         let github_client = match &self.as_ref().rust().github_client {
             Some(c) => c.clone(),
             None => {
-                self.as_mut().set_error_message(QString::from("GitHub not authenticated"));
+                self.as_mut().set_error_message(QString::from(tr("github-not-authenticated")));
                 return;
             }
         };
@@ -483,17 +515,13 @@ impl qobject::ProjectModel {
             None => return,
         };
 
-        let store_guard = store.lock();
-
-        match store_guard.remove_repo_from_project(&project_id, &repo_id_str) {
+        match store.remove_repo_from_project(&project_id, &repo_id_str) {
             Ok(_) => {
-                drop(store_guard);
                 self.as_mut().rust_mut().load_task_counts();
                 self.as_mut().projects_changed();
                 tracing::info!("Removed repo {} from project {}", repo_id_str, project_id);
             }
             Err(e) => {
-                drop(store_guard);
                 self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
             }
         }
@@ -511,19 +539,106 @@ impl qobject::ProjectModel {
             None => return,
         };
 
-        let store_guard = store.lock();
-
-        match store_guard.delete_project(&project_id) {
+        match store.delete_project(&project_id) {
             Ok(_) => {
                 tracing::info!("Removed project: {}", project_id);
-                drop(store_guard);
                 self.as_mut().rust_mut().projects.remove(index as usize);
                 self.as_mut().rust_mut().task_counts.remove(&project_id);
                 self.as_mut().projects_changed();
             }
             Err(e) => {
                 tracing::error!("Failed to delete project: {}", e);
-                drop(store_guard);
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            }
+        }
+    }
+
+    /// Archive a project at index
+    pub fn archive_project(mut self: Pin<&mut Self>, index: i32) {
+        let project_id = match self.as_ref().rust().get_project(index) {
+            Some(p) => p.id.clone(),
+            None => return,
+        };
+
+        let store = match &self.as_ref().rust().project_store {
+            Some(s) => s.clone(),
+            None => return,
+        };
+
+        match store.archive_project(&project_id) {
+            Ok(_) => {
+                tracing::info!("Archived project: {}", project_id);
+                let project = self.as_mut().rust_mut().projects.remove(index as usize);
+                self.as_mut().rust_mut().task_counts.remove(&project_id);
+                self.as_mut().rust_mut().archived.insert(0, project);
+                self.as_mut().projects_changed();
+                self.as_mut().archived_changed();
+            }
+            Err(e) => {
+                tracing::error!("Failed to archive project: {}", e);
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            }
+        }
+    }
+
+    /// Load archived projects from the local store
+    pub fn fetch_archived(mut self: Pin<&mut Self>) {
+        self.as_mut().rust_mut().ensure_initialized();
+
+        let store = match &self.as_ref().rust().project_store {
+            Some(s) => s.clone(),
+            None => return,
+        };
+
+        match store.list_archived() {
+            Ok(archived) => {
+                self.as_mut().rust_mut().archived = archived;
+                self.as_mut().archived_changed();
+            }
+            Err(e) => {
+                tracing::error!("Failed to load archived projects: {}", e);
+                self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+            }
+        }
+    }
+
+    pub fn archived_count(&self) -> i32 {
+        self.rust().archived.len() as i32
+    }
+
+    pub fn get_archived_name(&self, index: i32) -> QString {
+        usize::try_from(index)
+            .ok()
+            .and_then(|i| self.rust().archived.get(i))
+            .map(|p| QString::from(&p.name))
+            .unwrap_or_default()
+    }
+
+    /// Restore the archived project at index
+    pub fn restore_project(mut self: Pin<&mut Self>, index: i32) {
+        let Some(project_id) = usize::try_from(index)
+            .ok()
+            .and_then(|i| self.rust().archived.get(i))
+            .map(|p| p.id.clone())
+        else {
+            return;
+        };
+
+        let store = match &self.as_ref().rust().project_store {
+            Some(s) => s.clone(),
+            None => return,
+        };
+
+        match store.restore_project(&project_id) {
+            Ok(_) => {
+                tracing::info!("Restored project: {}", project_id);
+                self.as_mut().rust_mut().archived.remove(index as usize);
+                self.as_mut().archived_changed();
+                // Back in creation order, with fresh task counts
+                self.as_mut().fetch_projects();
+            }
+            Err(e) => {
+                tracing::error!("Failed to restore project: {}", e);
                 self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
             }
         }
@@ -535,6 +650,30 @@ impl qobject::ProjectModel {
         self.as_mut().projects_changed();
     }
 
+    pub fn load_repo_choices(mut self: Pin<&mut Self>) {
+        self.as_mut().rust_mut().ensure_initialized();
+        if self.rust().choices_pending {
+            return;
+        }
+        let Some(github_client) = self.rust().github_client.clone() else {
+            return;
+        };
+        bridge::init_project_service_channel();
+        let Some(tx) = bridge::get_project_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Service channel not ready"));
+            return;
+        };
+        self.as_mut().rust_mut().choices_pending = true;
+        self.as_mut().set_loading(true);
+        request_project_repo_choices(&tx, github_client);
+    }
+
+    pub fn get_repo_choices(&self) -> QString {
+        QString::from(
+            serde_json::to_string(&self.rust().repo_choices).unwrap_or_else(|_| "[]".into()),
+        )
+    }
+
     /// Poll for async operation results. Call this from a QML Timer (e.g., every 100ms).
     pub fn poll_channel(mut self: Pin<&mut Self>) {
         let msg = match bridge::try_recv_project_message() {
@@ -571,6 +710,30 @@ impl qobject::ProjectModel {
                     }
                 }
             }
+            ProjectServiceMessage::RepoChoicesDone(result) => {
+                self.as_mut().rust_mut().choices_pending = false;
+                if self.rust().op_state == OpState::Idle {
+                    self.as_mut().set_loading(false);
+                }
+                match result {
+                    Ok(choices) => {
+                        self.as_mut().rust_mut().repo_choices = choices;
+                        self.as_mut().repo_choices_changed();
+                    }
+                    // The dialog still takes a typed name
+                    Err(e) => tracing::warn!("Could not list repos to link: {}", e),
+                }
+            }
+            ProjectServiceMessage::EnsureLabelsDone { repo_id, result } => match result {
+                Ok(created) if !created.is_empty() => {
+                    tracing::info!("Created status labels {:?} on {}", created, repo_id);
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    // Not fatal: tasks still work, moves just won't have a label to apply
+                    tracing::warn!("Could not create status labels on {}: {}", repo_id, e);
+                }
+            },
         }
     }
 
@@ -585,24 +748,39 @@ impl qobject::ProjectModel {
             }
         };
 
-        let store_guard = store.lock();
-
-        match store_guard.add_repo_to_project(&project_id, &repo_id) {
+        match store.add_repo_to_project(&project_id, &repo_id) {
             Ok(_) => {
-                drop(store_guard);
                 self.as_mut().rust_mut().load_task_counts();
                 self.as_mut().set_loading(false);
                 self.as_mut().projects_changed();
                 tracing::info!("Added repo {} to project {}", repo_id, project_id);
+                self.as_mut().create_status_labels(&repo_id);
             }
             Err(e) => {
-                drop(store_guard);
                 self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
                 self.as_mut().set_loading(false);
             }
         }
     }
 
+    /// Create the kanban status labels on a newly linked repo, unless
+    /// `projects.auto_create_labels` is off
+    fn create_status_labels(self: Pin<&mut Self>, repo_id: &str) {
+        if !myme_core::Config::load_cached().projects.auto_create_labels {
+            return;
+        }
+        let Some((owner, repo)) = repo_id.split_once('/') else {
+            return;
+        };
+        let Some(client) = self.as_ref().rust().github_client.clone() else {
+            return;
+        };
+        let Some(tx) = bridge::get_project_service_tx() else {
+            return;
+        };
+        request_project_ensure_labels(&tx, client, owner.to_string(), repo.to_string());
+    }
+
     /// Check and update authentication status
     pub fn check_auth(mut self: Pin<&mut Self>) {
         let was_authenticated = self.as_ref().rust().authenticated;
@@ -0,0 +1,233 @@
+//! GitHub releases model for QML: list releases and tags for one repository,
+//! draft a changelog since the last tag, and publish a release.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_services::{CreateReleaseRequest, GitHubRelease, GitHubTag};
+
+use crate::bridge;
+use crate::services::{
+    request_release_create, request_release_draft, request_release_fetch, ReleaseServiceMessage,
+};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(bool, authenticated)]
+        #[qproperty(QString, error_message)]
+        #[qproperty(QString, repo)]
+        #[qproperty(QString, draft_body)]
+        #[qproperty(QString, suggested_tag)]
+        #[qproperty(QString, since_tag)]
+        type ReleaseModel = super::ReleaseModelRust;
+
+        #[qinvokable]
+        fn check_auth(self: Pin<&mut ReleaseModel>);
+
+        /// Show releases and tags for `full_name` (`owner/repo`)
+        #[qinvokable]
+        fn load(self: Pin<&mut ReleaseModel>, full_name: &QString);
+
+        /// Draft notes from pull requests merged and issues closed since the
+        /// last tag into `draft_body` and `suggested_tag`
+        #[qinvokable]
+        fn draft_changelog(self: Pin<&mut ReleaseModel>);
+
+        /// Publish a release; a new tag is cut from the default branch when
+        /// the release is published. Emits `release_created` with its URL.
+        #[qinvokable]
+        fn create_release(
+            self: Pin<&mut ReleaseModel>,
+            tag: &QString,
+            name: &QString,
+            body: &QString,
+            draft: bool,
+            prerelease: bool,
+        );
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut ReleaseModel>);
+
+        /// Releases as a JSON array, newest first
+        #[qinvokable]
+        fn get_releases(self: &ReleaseModel) -> QString;
+
+        /// Tag names as a JSON array, newest first
+        #[qinvokable]
+        fn get_tags(self: &ReleaseModel) -> QString;
+
+        #[qsignal]
+        fn releases_changed(self: Pin<&mut ReleaseModel>);
+
+        #[qsignal]
+        fn release_created(self: Pin<&mut ReleaseModel>, url: QString);
+    }
+}
+
+#[derive(Default)]
+pub struct ReleaseModelRust {
+    loading: bool,
+    authenticated: bool,
+    error_message: QString,
+    /// `owner/repo` the releases belong to
+    repo: QString,
+    /// Markdown notes from the last `draft_changelog`
+    draft_body: QString,
+    suggested_tag: QString,
+    /// Tag the drafted notes start after (empty for a first release)
+    since_tag: QString,
+    releases: Vec<GitHubRelease>,
+    tags: Vec<GitHubTag>,
+}
+
+fn release_json(release: &GitHubRelease) -> serde_json::Value {
+    serde_json::json!({
+        "tag": release.tag_name,
+        "name": release.name.as_deref().filter(|n| !n.is_empty()).unwrap_or(&release.tag_name),
+        "body": release.body.as_deref().unwrap_or(""),
+        "draft": release.draft,
+        "prerelease": release.prerelease,
+        "htmlUrl": release.html_url,
+        "publishedAt": release.published_at.as_deref().unwrap_or(&release.created_at),
+    })
+}
+
+impl qobject::ReleaseModel {
+    pub fn check_auth(mut self: Pin<&mut Self>) {
+        let auth = bridge::get_github_client_and_runtime().is_some();
+        if self.as_ref().rust().authenticated != auth {
+            self.as_mut().set_authenticated(auth);
+        }
+    }
+
+    /// Client and channel for a request, flagging the model when either is missing
+    fn begin_request(
+        mut self: Pin<&mut Self>,
+    ) -> Option<(
+        std::sync::Arc<myme_services::GitHubClient>,
+        std::sync::mpsc::Sender<ReleaseServiceMessage>,
+    )> {
+        if self.as_ref().rust().loading {
+            return None;
+        }
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            self.as_mut().set_authenticated(false);
+            return None;
+        };
+        bridge::init_release_service_channel();
+        let Some(tx) = bridge::get_release_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Release service channel not ready"));
+            return None;
+        };
+        self.as_mut().set_loading(true);
+        Some((client, tx))
+    }
+
+    pub fn load(mut self: Pin<&mut Self>, full_name: &QString) {
+        let full_name = full_name.to_string().trim().to_string();
+        if full_name.is_empty() {
+            return;
+        }
+        self.as_mut().set_repo(QString::from(&full_name));
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_release_fetch(&tx, client, &full_name);
+        }
+    }
+
+    pub fn draft_changelog(mut self: Pin<&mut Self>) {
+        let full_name = self.rust().repo.to_string();
+        if full_name.is_empty() {
+            return;
+        }
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_release_draft(&tx, client, &full_name);
+        }
+    }
+
+    pub fn create_release(
+        mut self: Pin<&mut Self>,
+        tag: &QString,
+        name: &QString,
+        body: &QString,
+        draft: bool,
+        prerelease: bool,
+    ) {
+        let full_name = self.rust().repo.to_string();
+        let tag_name = tag.to_string().trim().to_string();
+        if full_name.is_empty() || tag_name.is_empty() {
+            return;
+        }
+        let non_empty = |s: &QString| Some(s.to_string()).filter(|s| !s.trim().is_empty());
+        let req = CreateReleaseRequest {
+            tag_name,
+            target_commitish: None,
+            name: non_empty(name),
+            body: non_empty(body),
+            draft,
+            prerelease,
+        };
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_release_create(&tx, client, &full_name, req);
+        }
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let Some(msg) = bridge::try_recv_release_message() else {
+            return;
+        };
+
+        self.as_mut().set_loading(false);
+        match msg {
+            ReleaseServiceMessage::FetchDone(Ok((releases, tags))) => {
+                self.as_mut().set_error_message(QString::from(""));
+                self.as_mut().rust_mut().releases = releases;
+                self.as_mut().rust_mut().tags = tags;
+                self.as_mut().releases_changed();
+            }
+            ReleaseServiceMessage::DraftDone(Ok(draft)) => {
+                self.as_mut().set_error_message(QString::from(""));
+                // Empty when nothing was merged or closed since the last tag
+                self.as_mut().set_draft_body(QString::from(&draft.changelog.body));
+                self.as_mut().set_suggested_tag(QString::from(&draft.suggested_tag));
+                self.as_mut()
+                    .set_since_tag(QString::from(draft.since_tag.as_deref().unwrap_or("")));
+            }
+            ReleaseServiceMessage::CreateDone(Ok(release)) => {
+                self.as_mut().set_error_message(QString::from(""));
+                let url = QString::from(&release.html_url);
+                self.as_mut().rust_mut().releases.insert(0, release);
+                self.as_mut().set_draft_body(QString::from(""));
+                self.as_mut().set_suggested_tag(QString::from(""));
+                self.as_mut().set_since_tag(QString::from(""));
+                self.as_mut().releases_changed();
+                self.as_mut().release_created(url);
+            }
+            ReleaseServiceMessage::FetchDone(Err(e))
+            | ReleaseServiceMessage::DraftDone(Err(e))
+            | ReleaseServiceMessage::CreateDone(Err(e)) => {
+                let text = myme_core::AppError::from(e).user_message();
+                self.as_mut().set_error_message(QString::from(text));
+            }
+        }
+    }
+
+    pub fn get_releases(&self) -> QString {
+        let rows: Vec<serde_json::Value> = self.rust().releases.iter().map(release_json).collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn get_tags(&self) -> QString {
+        let names: Vec<&str> = self.rust().tags.iter().map(|t| t.name.as_str()).collect();
+        QString::from(serde_json::to_string(&names).unwrap_or_else(|_| "[]".into()))
+    }
+}
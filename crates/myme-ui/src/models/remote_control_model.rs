@@ -0,0 +1,37 @@
+use core::pin::Pin;
+
+use crate::bridge;
+use crate::services::IpcServiceMessage;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        type RemoteControlModel = super::RemoteControlModelRust;
+
+        /// Deliver commands received on the remote-control socket. Call this from a QML Timer.
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut RemoteControlModel>);
+
+        #[qsignal]
+        fn show_window_requested(self: Pin<&mut RemoteControlModel>);
+
+        #[qsignal]
+        fn sync_requested(self: Pin<&mut RemoteControlModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct RemoteControlModelRust;
+
+impl qobject::RemoteControlModel {
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        while let Some(msg) = bridge::try_recv_ipc_message() {
+            match msg {
+                IpcServiceMessage::ShowWindow => self.as_mut().show_window_requested(),
+                IpcServiceMessage::SyncNow => self.as_mut().sync_requested(),
+            }
+        }
+    }
+}
@@ -1,11 +1,30 @@
 use core::pin::Pin;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
 use cxx_qt::CxxQtType;
 use cxx_qt_lib::QString;
-use myme_integrations::{RepoEntry, RepoState};
+use myme_core::dashboard::{CommitSnapshot, MAX_COMMITS};
+use myme_core::datefmt;
+use myme_core::i18n::tr;
+use myme_integrations::{
+    CloneOptions, CommitInfo, GitOperations, RepoEntry, RepoHealth, RepoState, UPSTREAM_REMOTE,
+};
+use myme_services::{RepoMeta, RepoPreview, RepoScope, RepoSecurity};
 
 use crate::bridge;
-use crate::services::{request_clone, request_pull, request_refresh, RepoServiceMessage};
+use crate::services::{
+    activity_feed, cached_repo_preview, repo_scopes, request_activity, request_clone,
+    request_create_remote, request_pull, request_refresh, request_repo_health,
+    request_repo_preview, request_repo_scopes, request_security, request_sync_fork,
+    save_repo_scopes, watch_repos, RepoActivity, RepoServiceMessage,
+};
+
+/// Window for the per-repo and dashboard activity summaries
+const ACTIVITY_DAYS: u32 = 7;
+
+/// Stored alert counts older than this are re-checked after a refresh
+const SECURITY_RECHECK_HOURS: i64 = 12;
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum OpState {
@@ -13,6 +32,8 @@ enum OpState {
     BusyRefresh,
     BusyClone(usize),
     BusyPull(usize),
+    BusySync(usize),
+    BusyCreate,
 }
 
 #[cxx_qt::bridge]
@@ -30,6 +51,8 @@ pub mod qobject {
         #[qproperty(QString, error_message)]
         #[qproperty(bool, config_path_invalid)]
         #[qproperty(QString, effective_path)]
+        #[qproperty(i32, op_index)]
+        #[qproperty(i32, op_progress)]
         type RepoModel = super::RepoModelRust;
 
         #[qinvokable]
@@ -41,12 +64,150 @@ pub mod qobject {
         #[qinvokable]
         fn clone_repo(self: Pin<&mut RepoModel>, index: i32);
 
+        /// Clone with only the last `depth` commits (0 for all), only the
+        /// default branch when `single_branch` is set, and only the files under
+        /// `sparse_paths` (comma- or newline-separated; empty for everything)
+        #[qinvokable]
+        fn clone_repo_with_options(
+            self: Pin<&mut RepoModel>,
+            index: i32,
+            depth: i32,
+            single_branch: bool,
+            sparse_paths: &QString,
+        );
+
         #[qinvokable]
         fn pull_repo(self: Pin<&mut RepoModel>, index: i32);
 
+        /// Fast-forward a fork's default branch from its `upstream` remote
+        /// (added from the GitHub parent when missing), then push it to
+        /// `origin` when `push` is set. Emits `fork_synced` when done.
+        #[qinvokable]
+        fn sync_fork(self: Pin<&mut RepoModel>, index: i32, push: bool);
+
+        /// JSON array of the clone's remotes ({name, url, push_url})
+        #[qinvokable]
+        fn get_remotes(self: &RepoModel, index: i32) -> QString;
+
+        /// Point the clone's `upstream` remote at `url`, adding it if needed
+        #[qinvokable]
+        fn set_upstream(self: Pin<&mut RepoModel>, index: i32, url: &QString) -> bool;
+
+        /// Create a GitHub repo, clone it into the local search path and link it to
+        /// `project_id` (empty for none). `org`, `template`, `gitignore` and `license`
+        /// may be empty; `topics` is comma-separated.
+        #[qinvokable]
+        fn create_remote_repo(
+            self: Pin<&mut RepoModel>,
+            name: &QString,
+            description: &QString,
+            private: bool,
+            org: &QString,
+            template: &QString,
+            gitignore: &QString,
+            license: &QString,
+            topics: &QString,
+            project_id: &QString,
+        );
+
         #[qinvokable]
         fn cancel_operation(self: Pin<&mut RepoModel>);
 
+        /// Load commit activity for the last week. Uses the listed repos when a
+        /// refresh has run, otherwise discovers local repos (for the dashboard).
+        #[qinvokable]
+        fn fetch_activity(self: Pin<&mut RepoModel>);
+
+        /// JSON array of the repo's latest commits
+        /// ({id, author, message, time, files_changed, insertions, deletions})
+        #[qinvokable]
+        fn get_recent_commits(self: &RepoModel, index: i32) -> QString;
+
+        /// JSON object of the repo's commit totals this week
+        /// ({commits, authors, files_changed, insertions, deletions})
+        #[qinvokable]
+        fn get_commit_stats(self: &RepoModel, index: i32) -> QString;
+
+        /// JSON array of this week's commits across all repos, newest first,
+        /// each with a `repo` name
+        #[qinvokable]
+        fn get_activity_feed(self: &RepoModel, limit: i32) -> QString;
+
+        /// JSON array of the repo's uncommitted changes, one entry per file
+        /// ({path, old_path, status, binary, too_large, truncated, insertions,
+        /// deletions, hunks: [{old_start, old_lines, new_start, new_lines,
+        /// lines: [{kind, old_lineno, new_lineno, text}]}]})
+        #[qinvokable]
+        fn get_workdir_diff(self: &RepoModel, index: i32) -> QString;
+
+        /// JSON object for one file's uncommitted changes (same shape as an entry of
+        /// `get_workdir_diff`), or "null" when it is unchanged
+        #[qinvokable]
+        fn get_file_diff(self: &RepoModel, index: i32, file: &QString) -> QString;
+
+        /// JSON object of this week's totals across all repos
+        /// ({commits, insertions, deletions, active_repos})
+        #[qinvokable]
+        fn get_activity_summary(self: &RepoModel) -> QString;
+
+        /// Reload stored security alert counts (no network); emits `security_changed`
+        #[qinvokable]
+        fn load_security(self: Pin<&mut RepoModel>);
+
+        /// Check Dependabot and code scanning alerts for every listed GitHub repo
+        #[qinvokable]
+        fn fetch_security(self: Pin<&mut RepoModel>);
+
+        /// Open security alerts for the repo (0 when none or unchecked)
+        #[qinvokable]
+        fn get_alert_count(self: &RepoModel, index: i32) -> i32;
+
+        /// Most severe open alert: "critical", "high", "medium", "low" or ""
+        #[qinvokable]
+        fn get_alert_severity(self: &RepoModel, index: i32) -> QString;
+
+        /// Check the hygiene of every listed local clone; emits `health_changed`
+        #[qinvokable]
+        fn fetch_health(self: Pin<&mut RepoModel>);
+
+        /// Health score (0-100) of the repo's clone, or -1 when unchecked
+        #[qinvokable]
+        fn get_health_score(self: &RepoModel, index: i32) -> i32;
+
+        /// JSON object of the clone's health report ({score, has_license,
+        /// has_ci, stale_branches: [{name, last_commit, days}], unpushed:
+        /// [{name, ahead}], large_untracked: [{path, bytes}]}), or "null"
+        #[qinvokable]
+        fn get_health(self: &RepoModel, index: i32) -> QString;
+
+        /// JSON array of stored repos with open alerts, most severe first
+        /// ({repo, total, severity, critical, high, medium, low})
+        #[qinvokable]
+        fn get_vulnerable_repos(self: &RepoModel) -> QString;
+
+        /// README (markdown), topics and languages for the repo at index, as
+        /// JSON `{fullName, loading, error, readme, topics, languages}`. GitHub
+        /// repos are fetched in the background (cached for a while) and
+        /// `preview_changed` fires when they arrive; local-only repos read
+        /// README.md from the clone.
+        #[qinvokable]
+        fn get_readme(self: Pin<&mut RepoModel>, index: i32) -> QString;
+
+        /// Look up the organizations and teams the listing can include; emits
+        /// `scopes_changed`
+        #[qinvokable]
+        fn fetch_scopes(self: Pin<&mut RepoModel>);
+
+        /// JSON array of `{scope, org, team, selected}`: organizations and teams
+        /// found on GitHub plus any configured in `repos.orgs`
+        #[qinvokable]
+        fn get_scopes(self: &RepoModel) -> QString;
+
+        /// Include or leave out an org (`org`) or team (`org/team`) in the
+        /// listing, saving the choice to config and refreshing
+        #[qinvokable]
+        fn set_scope_selected(self: Pin<&mut RepoModel>, scope: &QString, selected: bool);
+
         #[qinvokable]
         fn poll_channel(self: Pin<&mut RepoModel>);
 
@@ -86,6 +247,44 @@ pub mod qobject {
         #[qinvokable]
         fn get_html_url(self: &RepoModel, index: i32) -> QString;
 
+        #[qinvokable]
+        fn get_is_fork(self: &RepoModel, index: i32) -> bool;
+
+        /// Name the local clone's remote still uses after a GitHub rename, or ""
+        #[qinvokable]
+        fn get_renamed_from(self: &RepoModel, index: i32) -> QString;
+
+        #[qinvokable]
+        fn get_favorite(self: &RepoModel, index: i32) -> bool;
+
+        #[qinvokable]
+        fn get_group(self: &RepoModel, index: i32) -> QString;
+
+        /// Returns JSON array of tags for the repo, e.g. ["rust","work"]
+        #[qinvokable]
+        fn get_tags(self: &RepoModel, index: i32) -> QString;
+
+        /// Returns JSON array of all distinct tags across repos
+        #[qinvokable]
+        fn get_all_tags(self: &RepoModel) -> QString;
+
+        #[qinvokable]
+        fn toggle_favorite(self: Pin<&mut RepoModel>, index: i32);
+
+        /// Assign repo to a group; empty string clears the group
+        #[qinvokable]
+        fn set_group(self: Pin<&mut RepoModel>, index: i32, group: &QString);
+
+        #[qinvokable]
+        fn add_tag(self: Pin<&mut RepoModel>, index: i32, tag: &QString);
+
+        #[qinvokable]
+        fn remove_tag(self: Pin<&mut RepoModel>, index: i32, tag: &QString);
+
+        /// Move repo at `from` to position `to` and persist the custom order
+        #[qinvokable]
+        fn move_repo(self: Pin<&mut RepoModel>, from: i32, to: i32);
+
         #[qsignal]
         fn repos_changed(self: Pin<&mut RepoModel>);
 
@@ -94,6 +293,29 @@ pub mod qobject {
 
         #[qsignal]
         fn error_occurred(self: Pin<&mut RepoModel>);
+
+        #[qsignal]
+        fn activity_changed(self: Pin<&mut RepoModel>);
+
+        #[qsignal]
+        fn security_changed(self: Pin<&mut RepoModel>);
+
+        #[qsignal]
+        fn health_changed(self: Pin<&mut RepoModel>);
+
+        /// `commits` is how far `branch` moved (0 when it was already current)
+        #[qsignal]
+        fn fork_synced(self: Pin<&mut RepoModel>, index: i32, branch: QString, commits: i32);
+
+        #[qsignal]
+        fn preview_changed(self: Pin<&mut RepoModel>, full_name: QString);
+
+        #[qsignal]
+        fn scopes_changed(self: Pin<&mut RepoModel>);
+
+        /// Emitted after `create_remote_repo` succeeds, with the new repo's full name
+        #[qsignal]
+        fn repo_created(self: Pin<&mut RepoModel>, full_name: QString);
     }
 }
 
@@ -104,8 +326,24 @@ pub struct RepoModelRust {
     error_message: QString,
     config_path_invalid: bool,
     effective_path: QString,
+    /// Entry the running clone/pull belongs to
+    op_index: i32,
+    /// Transfer progress of the running clone/pull (0-100), or -1 before any arrives
+    op_progress: i32,
     entries: Vec<RepoEntry>,
+    meta: HashMap<String, RepoMeta>,
     op_state: OpState,
+    activity: Vec<RepoActivity>,
+    /// Stored alert counts by repo id
+    security: HashMap<String, RepoSecurity>,
+    /// README previews being fetched
+    preview_pending: HashSet<String>,
+    /// Failed README previews, retried after the next refresh
+    preview_errors: HashMap<String, String>,
+    /// Organizations and teams found on GitHub
+    scope_choices: Vec<RepoScope>,
+    /// Hygiene reports by clone path
+    health: HashMap<PathBuf, RepoHealth>,
 }
 
 impl RepoModelRust {
@@ -123,6 +361,136 @@ impl RepoModelRust {
     fn clear_error_msg(&mut self) {
         self.error_message = QString::from("");
     }
+
+    fn get_activity(&self, index: i32) -> Option<&RepoActivity> {
+        let path = &self.get_entry(index)?.local.as_ref()?.path;
+        self.activity.iter().find(|a| &a.path == path)
+    }
+
+    /// Keep this week's newest commits for the dashboard snapshot
+    fn record_activity(&self) {
+        let Some(store) = bridge::get_dashboard_store() else {
+            return;
+        };
+        let since = chrono::Utc::now() - chrono::Duration::days(i64::from(ACTIVITY_DAYS));
+        let commits = activity_feed(&self.activity, since, MAX_COMMITS)
+            .into_iter()
+            .map(|(repo, commit)| CommitSnapshot {
+                repo: repo.to_string(),
+                message: commit.message.clone(),
+                author: commit.author.clone(),
+                time: commit.time,
+            })
+            .collect();
+        store.lock().set_commits(commits);
+    }
+
+    fn get_health(&self, index: i32) -> Option<&RepoHealth> {
+        self.health.get(&self.get_entry(index)?.local.as_ref()?.path)
+    }
+
+    fn get_meta(&self, index: i32) -> Option<&RepoMeta> {
+        self.get_entry(index).and_then(|e| self.meta.get(&e.id.0))
+    }
+
+    /// Reload tags/favorites/order from the project store.
+    fn load_meta(&mut self) {
+        let store = match bridge::get_project_store_or_init() {
+            Some(s) => s,
+            None => return,
+        };
+        let result = store.list_repo_meta();
+        match result {
+            Ok(metas) => {
+                self.meta = metas.into_iter().map(|m| (m.repo_id.clone(), m)).collect();
+            }
+            Err(e) => tracing::warn!("Failed to load repo metadata: {}", e),
+        }
+    }
+
+    /// Reload alert counts from the project store.
+    fn reload_security(&mut self) {
+        let Some(store) = bridge::get_project_store_or_init() else {
+            return;
+        };
+        match store.list_repo_security() {
+            Ok(rows) => {
+                self.security = rows.into_iter().map(|s| (s.repo_id.clone(), s)).collect();
+            }
+            Err(e) => tracing::warn!("Failed to load security alerts: {}", e),
+        }
+    }
+
+    fn get_security(&self, index: i32) -> Option<&RepoSecurity> {
+        self.get_entry(index).and_then(|e| self.security.get(&e.id.0))
+    }
+
+    /// GitHub repos that were never checked or whose counts are older than
+    /// `SECURITY_RECHECK_HOURS`; all of them when `force` is set.
+    fn security_due(&self, force: bool) -> Vec<String> {
+        let cutoff = chrono::Utc::now() - chrono::Duration::hours(SECURITY_RECHECK_HOURS);
+        self.entries
+            .iter()
+            .filter(|e| e.github.is_some())
+            .map(|e| e.id.0.clone())
+            .filter(|id| {
+                force
+                    || self.security.get(id).is_none_or(|s| {
+                        chrono::DateTime::parse_from_rfc3339(&s.checked_at)
+                            .map_or(true, |t| t < cutoff)
+                    })
+            })
+            .collect()
+    }
+
+    /// Sort entries: favorites first, then custom order, then group, then name.
+    fn sort_entries(&mut self) {
+        let meta = &self.meta;
+        self.entries.sort_by(|a, b| {
+            let ma = meta.get(&a.id.0);
+            let mb = meta.get(&b.id.0);
+            let fav = |m: Option<&RepoMeta>| !m.map(|m| m.favorite).unwrap_or(false);
+            let order = |m: Option<&RepoMeta>| m.map(|m| m.sort_order).unwrap_or(0);
+            let group = |m: Option<&RepoMeta>| m.and_then(|m| m.group_name.clone());
+            fav(ma)
+                .cmp(&fav(mb))
+                .then_with(|| order(ma).cmp(&order(mb)))
+                .then_with(|| group(ma).cmp(&group(mb)))
+                .then_with(|| a.full_name.to_lowercase().cmp(&b.full_name.to_lowercase()))
+        });
+    }
+
+    /// Apply a store mutation for the repo at `index`, then reload and re-sort.
+    fn update_meta<F>(&mut self, index: i32, f: F)
+    where
+        F: FnOnce(&myme_services::ProjectStore, &str) -> anyhow::Result<()>,
+    {
+        let repo_id = match self.get_entry(index) {
+            Some(e) => e.id.0.clone(),
+            None => return,
+        };
+        let store = match bridge::get_project_store_or_init() {
+            Some(s) => s,
+            None => {
+                self.set_error("Project store not initialized".into());
+                return;
+            }
+        };
+        let result = f(&store, &repo_id);
+        if let Err(e) = result {
+            self.set_error(myme_core::AppError::from(e).user_message().to_string());
+            return;
+        }
+        self.load_meta();
+        self.sort_entries();
+    }
+}
+
+/// Commit as JSON, with `when` as a relative time for display
+fn commit_value(commit: &CommitInfo) -> serde_json::Value {
+    let mut value = serde_json::to_value(commit).unwrap_or_default();
+    value["when"] = serde_json::Value::from(datefmt::relative(commit.time));
+    value
 }
 
 impl Default for OpState {
@@ -171,7 +539,38 @@ impl qobject::RepoModel {
         request_refresh(&tx);
     }
 
-    pub fn clone_repo(mut self: Pin<&mut Self>, index: i32) {
+    pub fn clone_repo(self: Pin<&mut Self>, index: i32) {
+        self.start_clone(index, CloneOptions::default());
+    }
+
+    pub fn clone_repo_with_options(
+        self: Pin<&mut Self>,
+        index: i32,
+        depth: i32,
+        single_branch: bool,
+        sparse_paths: &QString,
+    ) {
+        let branch = self
+            .rust()
+            .get_entry(index)
+            .and_then(|e| e.github.as_ref())
+            .map(|g| g.default_branch.clone())
+            .filter(|b| single_branch && !b.is_empty());
+        let options = CloneOptions {
+            depth: depth.max(0) as u32,
+            single_branch,
+            branch,
+            sparse_paths: sparse_paths
+                .to_string()
+                .split([',', '\n'])
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect(),
+        };
+        self.start_clone(index, options);
+    }
+
+    fn start_clone(mut self: Pin<&mut Self>, index: i32, options: CloneOptions) {
         if index < 0 {
             return;
         }
@@ -183,7 +582,7 @@ impl qobject::RepoModel {
             Some(e) => e.clone(),
             None => return,
         };
-        if ent.state != RepoState::GitHubOnly {
+        if ent.state.has_local() || !ent.state.has_github() {
             return;
         }
         let clone_url = ent.github.as_ref().and_then(|g| {
@@ -217,12 +616,14 @@ impl qobject::RepoModel {
         let cancel_token = bridge::new_repo_cancel_token();
 
         self.as_mut().rust_mut().op_state = OpState::BusyClone(i);
+        self.as_mut().set_op_index(index);
+        self.as_mut().set_op_progress(-1);
         if let Some(e) = self.as_mut().rust_mut().entries.get_mut(i) {
             e.busy = true;
         }
         self.as_mut().repos_changed();
 
-        request_clone(&tx, i, clone_url, target_path, Some(cancel_token));
+        request_clone(&tx, i, clone_url, target_path, options, Some(cancel_token));
     }
 
     pub fn pull_repo(mut self: Pin<&mut Self>, index: i32) {
@@ -252,6 +653,8 @@ impl qobject::RepoModel {
         let cancel_token = bridge::new_repo_cancel_token();
 
         self.as_mut().rust_mut().op_state = OpState::BusyPull(i);
+        self.as_mut().set_op_index(index);
+        self.as_mut().set_op_progress(-1);
         if let Some(e) = self.as_mut().rust_mut().entries.get_mut(i) {
             e.busy = true;
         }
@@ -260,114 +663,562 @@ impl qobject::RepoModel {
         request_pull(&tx, i, path, Some(cancel_token));
     }
 
+    pub fn sync_fork(mut self: Pin<&mut Self>, index: i32, push: bool) {
+        if index < 0 || !matches!(self.as_ref().rust().op_state, OpState::Idle) {
+            return;
+        }
+        let i = index as usize;
+        let Some(entry) = self.as_ref().rust().get_entry(index) else {
+            return;
+        };
+        let Some(path) = entry.local.as_ref().map(|l| l.path.clone()) else {
+            return;
+        };
+        let github = entry.github.as_ref().map(|g| g.full_name.clone()).and_then(|full_name| {
+            bridge::get_github_client_and_runtime().map(|(client, _)| (client, full_name))
+        });
+
+        bridge::init_repo_service_channel();
+        let Some(tx) = bridge::get_repo_service_tx() else {
+            return;
+        };
+        let cancel_token = bridge::new_repo_cancel_token();
+
+        self.as_mut().rust_mut().op_state = OpState::BusySync(i);
+        self.as_mut().set_op_index(index);
+        self.as_mut().set_op_progress(-1);
+        if let Some(e) = self.as_mut().rust_mut().entries.get_mut(i) {
+            e.busy = true;
+        }
+        self.as_mut().repos_changed();
+
+        request_sync_fork(&tx, i, path, push, github, Some(cancel_token));
+    }
+
+    pub fn get_remotes(&self, index: i32) -> QString {
+        let Some(local) = self.rust().get_entry(index).and_then(|e| e.local.as_ref()) else {
+            return QString::from("[]");
+        };
+        let json = GitOperations::list_remotes(&local.path)
+            .map_err(|e| {
+                tracing::warn!("Failed to list remotes of {}: {}", local.path.display(), e)
+            })
+            .ok()
+            .and_then(|remotes| serde_json::to_string(&remotes).ok())
+            .unwrap_or_else(|| "[]".to_string());
+        QString::from(&json)
+    }
+
+    pub fn set_upstream(mut self: Pin<&mut Self>, index: i32, url: &QString) -> bool {
+        let url = url.to_string().trim().to_string();
+        let Some(path) =
+            self.rust().get_entry(index).and_then(|e| e.local.as_ref()).map(|l| l.path.clone())
+        else {
+            return false;
+        };
+        if url.is_empty() {
+            return false;
+        }
+        match GitOperations::set_remote(&path, UPSTREAM_REMOTE, &url) {
+            Ok(()) => true,
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(
+                    myme_core::AppError::from(crate::services::RepoError::Git(e.to_string()))
+                        .user_message()
+                        .to_string(),
+                );
+                self.as_mut().error_occurred();
+                false
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_remote_repo(
+        mut self: Pin<&mut Self>,
+        name: &QString,
+        description: &QString,
+        private: bool,
+        org: &QString,
+        template: &QString,
+        gitignore: &QString,
+        license: &QString,
+        topics: &QString,
+        project_id: &QString,
+    ) {
+        if !matches!(self.as_ref().rust().op_state, OpState::Idle) {
+            return;
+        }
+        let name = name.to_string().trim().to_string();
+        if name.is_empty() {
+            self.as_mut().rust_mut().set_error("Repository name is required".into());
+            self.as_mut().error_occurred();
+            return;
+        }
+        let client = match bridge::get_github_client_and_runtime() {
+            Some((c, _)) => c,
+            None => {
+                self.as_mut().rust_mut().set_error(tr("github-not-authenticated").into());
+                self.as_mut().error_occurred();
+                return;
+            }
+        };
+
+        let non_empty = |s: &QString| {
+            let s = s.to_string().trim().to_string();
+            (!s.is_empty()).then_some(s)
+        };
+        let template = non_empty(template);
+        let gitignore_template = non_empty(gitignore);
+        let license_template = non_empty(license);
+        let request = myme_services::CreateRepoRequest {
+            name,
+            description: non_empty(description),
+            private,
+            // Seed a first commit so the clone has a branch to check out
+            auto_init: (template.is_none()).then_some(true),
+            gitignore_template,
+            license_template,
+            org: non_empty(org),
+            template,
+            topics: topics
+                .to_string()
+                .split(',')
+                .map(|t| t.trim().to_lowercase())
+                .filter(|t| !t.is_empty())
+                .collect(),
+        };
+
+        let (base_path, _) =
+            bridge::get_repos_local_search_path().unwrap_or((std::path::PathBuf::from("."), true));
+
+        bridge::init_repo_service_channel();
+        let tx = match bridge::get_repo_service_tx() {
+            Some(t) => t,
+            None => return,
+        };
+
+        self.as_mut().set_loading(true);
+        self.as_mut().rust_mut().clear_error_msg();
+        self.as_mut().rust_mut().op_state = OpState::BusyCreate;
+        request_create_remote(&tx, client, request, base_path, non_empty(project_id));
+    }
+
+    pub fn fetch_activity(self: Pin<&mut Self>) {
+        let entries = &self.rust().entries;
+        let repos = (!entries.is_empty()).then(|| {
+            entries
+                .iter()
+                .filter_map(|e| e.local.as_ref().map(|l| (e.full_name.clone(), l.path.clone())))
+                .collect()
+        });
+
+        bridge::init_repo_service_channel();
+        if let Some(tx) = bridge::get_repo_service_tx() {
+            request_activity(&tx, repos, ACTIVITY_DAYS);
+        }
+    }
+
+    pub fn fetch_health(self: Pin<&mut Self>) {
+        let paths: Vec<PathBuf> = self
+            .rust()
+            .entries
+            .iter()
+            .filter_map(|e| e.local.as_ref().map(|l| l.path.clone()))
+            .collect();
+        if paths.is_empty() {
+            return;
+        }
+        bridge::init_repo_service_channel();
+        if let Some(tx) = bridge::get_repo_service_tx() {
+            request_repo_health(&tx, paths);
+        }
+    }
+
+    /// Keep the listed clones' status live as they change on disk
+    fn watch_local(self: Pin<&mut Self>) {
+        let paths: Vec<PathBuf> = self
+            .rust()
+            .entries
+            .iter()
+            .filter_map(|e| e.local.as_ref().map(|l| l.path.clone()))
+            .collect();
+        if let Some(tx) = bridge::get_repo_service_tx() {
+            watch_repos(&tx, paths);
+        }
+    }
+
+    pub fn load_security(mut self: Pin<&mut Self>) {
+        self.as_mut().rust_mut().reload_security();
+        self.as_mut().security_changed();
+    }
+
+    pub fn fetch_security(mut self: Pin<&mut Self>) {
+        let due = self.as_ref().rust().security_due(true);
+        self.as_mut().request_security_for(due);
+    }
+
+    fn request_security_for(self: Pin<&mut Self>, repo_ids: Vec<String>) {
+        if repo_ids.is_empty() {
+            return;
+        }
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            return;
+        };
+        bridge::init_repo_service_channel();
+        if let Some(tx) = bridge::get_repo_service_tx() {
+            request_security(&tx, client, repo_ids);
+        }
+    }
+
+    pub fn fetch_scopes(mut self: Pin<&mut Self>) {
+        let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+            return;
+        };
+        bridge::init_repo_service_channel();
+        match bridge::get_repo_service_tx() {
+            Some(tx) => request_repo_scopes(&tx, client),
+            None => self.as_mut().rust_mut().set_error("Repo service not initialized".into()),
+        }
+    }
+
+    pub fn get_scopes(&self) -> QString {
+        let selected = repo_scopes();
+        let mut scopes = self.rust().scope_choices.clone();
+        for scope in &selected {
+            if !scopes.contains(scope) {
+                scopes.push(scope.clone());
+            }
+        }
+        let json: Vec<serde_json::Value> = scopes
+            .iter()
+            .map(|scope| {
+                serde_json::json!({
+                    "scope": scope.to_string(),
+                    "org": scope.org(),
+                    "team": match scope {
+                        RepoScope::Team { team, .. } => Some(team),
+                        RepoScope::Org(_) => None,
+                    },
+                    "selected": selected.contains(scope),
+                })
+            })
+            .collect();
+        QString::from(serde_json::to_string(&json).unwrap_or_else(|_| "[]".into()))
+    }
+
+    pub fn set_scope_selected(mut self: Pin<&mut Self>, scope: &QString, selected: bool) {
+        let Some(scope) = RepoScope::parse(&scope.to_string()) else {
+            return;
+        };
+        let mut scopes = repo_scopes();
+        if selected == scopes.contains(&scope) {
+            return;
+        }
+        if selected {
+            scopes.push(scope);
+        } else {
+            scopes.retain(|s| *s != scope);
+        }
+        if let Err(e) = save_repo_scopes(&scopes) {
+            self.as_mut()
+                .rust_mut()
+                .set_error(myme_core::AppError::from(e).user_message().to_string());
+            self.as_mut().error_occurred();
+            return;
+        }
+        self.as_mut().scopes_changed();
+        if matches!(self.rust().op_state, OpState::Idle) {
+            self.as_mut().fetch_repos();
+        }
+    }
+
+    pub fn get_readme(mut self: Pin<&mut Self>, index: i32) -> QString {
+        let Some(entry) = self.rust().get_entry(index) else {
+            return QString::from("{}");
+        };
+        let full_name = entry.full_name.clone();
+        let value = |loading: bool, error: Option<&str>, preview: &RepoPreview| {
+            let json = serde_json::json!({
+                "fullName": full_name,
+                "loading": loading,
+                "error": error,
+                "readme": preview.readme,
+                "topics": preview.topics,
+                "languages": preview.languages,
+            });
+            QString::from(json.to_string().as_str())
+        };
+
+        if entry.github.is_none() {
+            let readme = entry
+                .local
+                .as_ref()
+                .and_then(|local| std::fs::read_to_string(local.path.join("README.md")).ok());
+            return value(false, None, &RepoPreview { readme, ..Default::default() });
+        }
+        if let Some(preview) = cached_repo_preview(&full_name) {
+            return value(false, None, &preview);
+        }
+        if let Some(error) = self.rust().preview_errors.get(&full_name) {
+            return value(false, Some(error.as_str()), &RepoPreview::default());
+        }
+        if !self.rust().preview_pending.contains(&full_name) {
+            let Some((client, _runtime)) = bridge::get_github_client_and_runtime() else {
+                return value(false, Some("Sign in to GitHub to preview"), &RepoPreview::default());
+            };
+            bridge::init_repo_service_channel();
+            let Some(tx) = bridge::get_repo_service_tx() else {
+                return value(false, Some("Service channel not ready"), &RepoPreview::default());
+            };
+            self.as_mut().rust_mut().preview_pending.insert(full_name.clone());
+            request_repo_preview(&tx, client, full_name.clone());
+        }
+        value(true, None, &RepoPreview::default())
+    }
+
     pub fn cancel_operation(mut self: Pin<&mut Self>) {
         // Cancel any active operation
         bridge::cancel_repo_operation();
 
         // Reset state based on current operation
         match self.as_ref().rust().op_state {
-            OpState::BusyClone(idx) | OpState::BusyPull(idx) => {
+            OpState::BusyClone(idx) | OpState::BusyPull(idx) | OpState::BusySync(idx) => {
                 if let Some(e) = self.as_mut().rust_mut().entries.get_mut(idx) {
                     e.busy = false;
                 }
             }
-            OpState::BusyRefresh => {
+            OpState::BusyRefresh | OpState::BusyCreate => {
                 self.as_mut().set_loading(false);
             }
             OpState::Idle => {}
         }
 
         self.as_mut().rust_mut().op_state = OpState::Idle;
+        self.as_mut().set_op_progress(-1);
         self.as_mut().repos_changed();
         tracing::info!("Repo operation cancelled by user");
     }
 
     pub fn poll_channel(mut self: Pin<&mut Self>) {
-        let msg = match bridge::try_recv_repo_message() {
-            Some(m) => m,
-            None => return,
-        };
-
-        match msg {
-            RepoServiceMessage::RefreshDone(res) => {
-                self.as_mut().set_loading(false);
-                self.as_mut().rust_mut().op_state = OpState::Idle;
-                match res {
-                    Ok(entries) => {
-                        self.as_mut().rust_mut().clear_error_msg();
+        // Progress updates can arrive faster than the poll timer; drain them so the
+        // bar keeps up, stopping after the first non-progress message.
+        loop {
+            let msg = match bridge::try_recv_repo_message() {
+                Some(m) => m,
+                None => return,
+            };
+
+            match msg {
+                RepoServiceMessage::Progress { index, pct } => {
+                    let busy_here = matches!(
+                        self.as_ref().rust().op_state,
+                        OpState::BusyClone(i) | OpState::BusyPull(i) | OpState::BusySync(i)
+                            if i == index
+                    );
+                    if busy_here {
+                        self.as_mut().set_op_progress(pct as i32);
+                    }
+                    continue;
+                }
+                RepoServiceMessage::CachedDone(entries) => {
+                    // Only fills an empty pane; a finished refresh is always newer
+                    let empty = self.as_ref().rust().entries.is_empty();
+                    if empty && matches!(self.as_ref().rust().op_state, OpState::BusyRefresh) {
                         self.as_mut().rust_mut().entries = entries;
+                        self.as_mut().rust_mut().load_meta();
+                        self.as_mut().rust_mut().sort_entries();
                         self.as_mut().repos_changed();
                     }
-                    Err(e) => {
-                        self.as_mut()
-                            .rust_mut()
-                            .set_error(myme_core::AppError::from(e).user_message().to_string());
-                        self.as_mut().error_occurred();
+                }
+                RepoServiceMessage::RefreshDone(res) => {
+                    self.as_mut().set_loading(false);
+                    self.as_mut().rust_mut().op_state = OpState::Idle;
+                    match res {
+                        Ok(entries) => {
+                            self.as_mut().rust_mut().clear_error_msg();
+                            self.as_mut().rust_mut().preview_errors.clear();
+                            self.as_mut().rust_mut().entries = entries;
+                            self.as_mut().rust_mut().load_meta();
+                            self.as_mut().rust_mut().sort_entries();
+                            self.as_mut().repos_changed();
+                            self.as_mut().fetch_activity();
+                            self.as_mut().fetch_health();
+                            self.as_mut().watch_local();
+                            self.as_mut().load_security();
+                            let due = self.as_ref().rust().security_due(false);
+                            self.as_mut().request_security_for(due);
+                        }
+                        Err(e) => {
+                            self.as_mut()
+                                .rust_mut()
+                                .set_error(myme_core::AppError::from(e).user_message().to_string());
+                            self.as_mut().error_occurred();
+                        }
                     }
                 }
-            }
-            RepoServiceMessage::CloneDone { index, result } => {
-                // Clear cancellation token
-                bridge::clear_repo_cancel_token();
+                RepoServiceMessage::CloneDone { index, result } => {
+                    // Clear cancellation token
+                    bridge::clear_repo_cancel_token();
+                    self.as_mut().set_op_progress(-1);
 
-                if let Some(e) = self.as_mut().rust_mut().entries.get_mut(index) {
-                    e.busy = false;
+                    if let Some(e) = self.as_mut().rust_mut().entries.get_mut(index) {
+                        e.busy = false;
+                    }
+                    self.as_mut().rust_mut().op_state = OpState::Idle;
+                    self.as_mut().repos_changed();
+
+                    match &result {
+                        Ok(()) => {
+                            // Trigger refresh after successful clone
+                            if let Some(tx) = bridge::get_repo_service_tx() {
+                                self.as_mut().set_loading(true);
+                                self.as_mut().rust_mut().op_state = OpState::BusyRefresh;
+                                request_refresh(&tx);
+                            }
+                        }
+                        // The service has already shown the failure or cancellation as a toast
+                        Err(e) => tracing::info!("Clone did not complete: {}", e),
+                    }
                 }
-                self.as_mut().rust_mut().op_state = OpState::Idle;
-                self.as_mut().repos_changed();
-
-                match &result {
-                    Ok(()) => {
-                        // Trigger refresh after successful clone
-                        if let Some(tx) = bridge::get_repo_service_tx() {
-                            self.as_mut().set_loading(true);
-                            self.as_mut().rust_mut().op_state = OpState::BusyRefresh;
-                            request_refresh(&tx);
+                RepoServiceMessage::CreateDone(result) => {
+                    self.as_mut().set_loading(false);
+                    self.as_mut().rust_mut().op_state = OpState::Idle;
+                    match result {
+                        Ok(full_name) => {
+                            self.as_mut().repo_created(QString::from(&full_name));
+                            // Pick up the new clone (and its GitHub entry)
+                            if let Some(tx) = bridge::get_repo_service_tx() {
+                                self.as_mut().set_loading(true);
+                                self.as_mut().rust_mut().op_state = OpState::BusyRefresh;
+                                request_refresh(&tx);
+                            }
                         }
+                        // Shown as a toast by the service
+                        Err(e) => tracing::info!("Repo creation failed: {}", e),
                     }
-                    Err(crate::services::RepoError::Cancelled) => {
-                        // Silently handle cancellation - no error, no refresh
-                        tracing::info!("Clone operation was cancelled");
+                }
+                RepoServiceMessage::ActivityDone(result) => match result {
+                    Ok(activity) => {
+                        self.as_mut().rust_mut().activity = activity;
+                        self.rust().record_activity();
+                        self.as_mut().activity_changed();
                     }
-                    Err(e) => {
-                        self.as_mut().rust_mut().set_error(
-                            myme_core::AppError::from(e.clone()).user_message().to_string(),
-                        );
-                        self.as_mut().error_occurred();
+                    // Activity is decoration; a failure shouldn't replace the repo list error
+                    Err(e) => tracing::warn!("Failed to load repo activity: {}", e),
+                },
+                // Counts are stored by the service; pick them up from the store
+                RepoServiceMessage::SecurityDone(result) => match result {
+                    Ok(_) => self.as_mut().load_security(),
+                    Err(e) => tracing::warn!("Failed to check security alerts: {}", e),
+                },
+                RepoServiceMessage::PreviewDone { full_name, result } => {
+                    self.as_mut().rust_mut().preview_pending.remove(&full_name);
+                    if let Err(e) = result {
+                        tracing::warn!("Failed to load preview for {}: {}", full_name, e);
+                        let msg = myme_core::AppError::from(e).user_message().to_string();
+                        self.as_mut().rust_mut().preview_errors.insert(full_name.clone(), msg);
                     }
+                    self.as_mut().preview_changed(QString::from(&full_name));
                 }
-            }
-            RepoServiceMessage::PullDone { index, result } => {
-                // Clear cancellation token
-                bridge::clear_repo_cancel_token();
-
-                if let Some(e) = self.as_mut().rust_mut().entries.get_mut(index) {
-                    e.busy = false;
+                RepoServiceMessage::RepoUpdated(local) => {
+                    let entry = self
+                        .as_mut()
+                        .rust_mut()
+                        .entries
+                        .iter_mut()
+                        .find(|e| e.local.as_ref().is_some_and(|l| l.path == local.path));
+                    if let Some(entry) = entry.filter(|e| e.local.as_ref() != Some(&local)) {
+                        entry.local = Some(local);
+                        self.as_mut().repos_changed();
+                    }
                 }
-                self.as_mut().rust_mut().op_state = OpState::Idle;
-                self.as_mut().repos_changed();
-
-                match &result {
-                    Ok(()) => {
-                        // Trigger refresh after successful pull
+                RepoServiceMessage::RemoteChanged(full_names) => {
+                    let listed = self.rust().entries.iter().any(|e| {
+                        e.github.is_some() && full_names.iter().any(|n| n == &e.full_name)
+                    });
+                    let idle = matches!(self.rust().op_state, OpState::Idle);
+                    if listed && idle {
                         if let Some(tx) = bridge::get_repo_service_tx() {
                             self.as_mut().set_loading(true);
                             self.as_mut().rust_mut().op_state = OpState::BusyRefresh;
                             request_refresh(&tx);
                         }
                     }
-                    Err(crate::services::RepoError::Cancelled) => {
-                        // Silently handle cancellation - no error, no refresh
-                        tracing::info!("Pull operation was cancelled");
+                }
+                RepoServiceMessage::HealthDone(result) => match result {
+                    Ok(health) => {
+                        self.as_mut().rust_mut().health = health.into_iter().collect();
+                        self.as_mut().health_changed();
+                    }
+                    // Like activity, a badge; keep any repo list error in place
+                    Err(e) => tracing::warn!("Failed to check repo health: {}", e),
+                },
+                RepoServiceMessage::ScopesDone(result) => match result {
+                    Ok(choices) => {
+                        self.as_mut().rust_mut().scope_choices = choices;
+                        self.as_mut().scopes_changed();
                     }
                     Err(e) => {
-                        self.as_mut().rust_mut().set_error(
-                            myme_core::AppError::from(e.clone()).user_message().to_string(),
-                        );
+                        self.as_mut()
+                            .rust_mut()
+                            .set_error(myme_core::AppError::from(e).user_message().to_string());
                         self.as_mut().error_occurred();
                     }
+                },
+                RepoServiceMessage::SyncForkDone { index, result } => {
+                    bridge::clear_repo_cancel_token();
+                    self.as_mut().set_op_progress(-1);
+
+                    if let Some(e) = self.as_mut().rust_mut().entries.get_mut(index) {
+                        e.busy = false;
+                    }
+                    self.as_mut().rust_mut().op_state = OpState::Idle;
+                    self.as_mut().repos_changed();
+
+                    match result {
+                        Ok(sync) => {
+                            self.as_mut().fork_synced(
+                                index as i32,
+                                QString::from(&sync.branch),
+                                sync.fast_forwarded as i32,
+                            );
+                            if let Some(tx) = bridge::get_repo_service_tx() {
+                                self.as_mut().set_loading(true);
+                                self.as_mut().rust_mut().op_state = OpState::BusyRefresh;
+                                request_refresh(&tx);
+                            }
+                        }
+                        // Shown as a toast by the service
+                        Err(e) => tracing::info!("Fork sync did not complete: {}", e),
+                    }
+                }
+                RepoServiceMessage::PullDone { index, result } => {
+                    // Clear cancellation token
+                    bridge::clear_repo_cancel_token();
+                    self.as_mut().set_op_progress(-1);
+
+                    if let Some(e) = self.as_mut().rust_mut().entries.get_mut(index) {
+                        e.busy = false;
+                    }
+                    self.as_mut().rust_mut().op_state = OpState::Idle;
+                    self.as_mut().repos_changed();
+
+                    match &result {
+                        Ok(()) => {
+                            // Trigger refresh after successful pull
+                            if let Some(tx) = bridge::get_repo_service_tx() {
+                                self.as_mut().set_loading(true);
+                                self.as_mut().rust_mut().op_state = OpState::BusyRefresh;
+                                request_refresh(&tx);
+                            }
+                        }
+                        // Shown as a toast by the service
+                        Err(e) => tracing::info!("Pull did not complete: {}", e),
+                    }
                 }
             }
+            return;
         }
     }
 
@@ -445,4 +1296,221 @@ impl qobject::RepoModel {
             .map(QString::from)
             .unwrap_or_else(|| QString::from(""))
     }
+
+    pub fn get_is_fork(&self, index: i32) -> bool {
+        self.rust().get_entry(index).map(|e| e.state.is_fork()).unwrap_or(false)
+    }
+
+    pub fn get_renamed_from(&self, index: i32) -> QString {
+        self.rust()
+            .get_entry(index)
+            .and_then(|e| e.renamed_from.as_deref())
+            .map(QString::from)
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_recent_commits(&self, index: i32) -> QString {
+        let json = self
+            .rust()
+            .get_activity(index)
+            .map(|a| a.recent.iter().map(commit_value).collect::<Vec<_>>())
+            .and_then(|commits| serde_json::to_string(&commits).ok())
+            .unwrap_or_else(|| "[]".to_string());
+        QString::from(&json)
+    }
+
+    pub fn get_commit_stats(&self, index: i32) -> QString {
+        let stats = self.rust().get_activity(index).map(|a| a.stats.clone()).unwrap_or_default();
+        let json = serde_json::to_string(&stats).unwrap_or_else(|_| "{}".to_string());
+        QString::from(&json)
+    }
+
+    pub fn get_activity_feed(&self, limit: i32) -> QString {
+        let since = chrono::Utc::now() - chrono::Duration::days(i64::from(ACTIVITY_DAYS));
+        let feed: Vec<serde_json::Value> =
+            activity_feed(&self.rust().activity, since, limit.max(0) as usize)
+                .into_iter()
+                .map(|(repo, commit)| {
+                    let mut value = commit_value(commit);
+                    value["repo"] = serde_json::Value::from(repo);
+                    value
+                })
+                .collect();
+        let json = serde_json::to_string(&feed).unwrap_or_else(|_| "[]".to_string());
+        QString::from(&json)
+    }
+
+    pub fn get_workdir_diff(&self, index: i32) -> QString {
+        let Some(local) = self.rust().get_entry(index).and_then(|e| e.local.as_ref()) else {
+            return QString::from("[]");
+        };
+        let json = GitOperations::diff_workdir(&local.path)
+            .map_err(|e| tracing::warn!("Failed to diff {}: {}", local.path.display(), e))
+            .ok()
+            .and_then(|diffs| serde_json::to_string(&diffs).ok())
+            .unwrap_or_else(|| "[]".to_string());
+        QString::from(&json)
+    }
+
+    pub fn get_file_diff(&self, index: i32, file: &QString) -> QString {
+        let Some(local) = self.rust().get_entry(index).and_then(|e| e.local.as_ref()) else {
+            return QString::from("null");
+        };
+        let json = GitOperations::diff_file(&local.path, &file.to_string())
+            .map_err(|e| tracing::warn!("Failed to diff {}: {}", local.path.display(), e))
+            .ok()
+            .flatten()
+            .and_then(|diff| serde_json::to_string(&diff).ok())
+            .unwrap_or_else(|| "null".to_string());
+        QString::from(&json)
+    }
+
+    pub fn get_activity_summary(&self) -> QString {
+        let activity = &self.rust().activity;
+        let sum = |f: fn(&RepoActivity) -> usize| activity.iter().map(f).sum::<usize>();
+        let json = serde_json::json!({
+            "commits": sum(|a| a.stats.commits),
+            "insertions": sum(|a| a.stats.insertions),
+            "deletions": sum(|a| a.stats.deletions),
+            "active_repos": activity.iter().filter(|a| a.stats.commits > 0).count(),
+        });
+        QString::from(&json.to_string())
+    }
+
+    pub fn get_alert_count(&self, index: i32) -> i32 {
+        self.rust().get_security(index).map(|s| s.total() as i32).unwrap_or(0)
+    }
+
+    pub fn get_alert_severity(&self, index: i32) -> QString {
+        self.rust()
+            .get_security(index)
+            .and_then(|s| s.highest())
+            .map(QString::from)
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_health_score(&self, index: i32) -> i32 {
+        self.rust().get_health(index).map(|h| i32::from(h.score)).unwrap_or(-1)
+    }
+
+    pub fn get_health(&self, index: i32) -> QString {
+        let json = self
+            .rust()
+            .get_health(index)
+            .and_then(|h| serde_json::to_string(h).ok())
+            .unwrap_or_else(|| "null".to_string());
+        QString::from(&json)
+    }
+
+    pub fn get_vulnerable_repos(&self) -> QString {
+        let mut repos: Vec<&RepoSecurity> =
+            self.rust().security.values().filter(|s| s.total() > 0).collect();
+        repos.sort_by(|a, b| {
+            (b.critical, b.high, b.medium, b.low)
+                .cmp(&(a.critical, a.high, a.medium, a.low))
+                .then_with(|| a.repo_id.cmp(&b.repo_id))
+        });
+        let rows: Vec<serde_json::Value> = repos
+            .into_iter()
+            .map(|s| {
+                serde_json::json!({
+                    "repo": s.repo_id,
+                    "total": s.total(),
+                    "severity": s.highest().unwrap_or(""),
+                    "critical": s.critical,
+                    "high": s.high,
+                    "medium": s.medium,
+                    "low": s.low,
+                })
+            })
+            .collect();
+        QString::from(&serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn get_favorite(&self, index: i32) -> bool {
+        self.rust().get_meta(index).map(|m| m.favorite).unwrap_or(false)
+    }
+
+    pub fn get_group(&self, index: i32) -> QString {
+        self.rust()
+            .get_meta(index)
+            .and_then(|m| m.group_name.as_deref())
+            .map(QString::from)
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn get_tags(&self, index: i32) -> QString {
+        let tags = self.rust().get_meta(index).map(|m| m.tags.clone()).unwrap_or_default();
+        let json = serde_json::to_string(&tags).unwrap_or_else(|_| "[]".to_string());
+        QString::from(&json)
+    }
+
+    pub fn get_all_tags(&self) -> QString {
+        let mut tags: Vec<&String> = self.rust().meta.values().flat_map(|m| &m.tags).collect();
+        tags.sort();
+        tags.dedup();
+        let json = serde_json::to_string(&tags).unwrap_or_else(|_| "[]".to_string());
+        QString::from(&json)
+    }
+
+    pub fn toggle_favorite(mut self: Pin<&mut Self>, index: i32) {
+        let favorite = !self.get_favorite(index);
+        self.as_mut()
+            .rust_mut()
+            .update_meta(index, |store, id| store.set_repo_favorite(id, favorite));
+        self.as_mut().repos_changed();
+    }
+
+    pub fn set_group(mut self: Pin<&mut Self>, index: i32, group: &QString) {
+        let group = group.to_string().trim().to_string();
+        let group = if group.is_empty() { None } else { Some(group) };
+        self.as_mut()
+            .rust_mut()
+            .update_meta(index, |store, id| store.set_repo_group(id, group.as_deref()));
+        self.as_mut().repos_changed();
+    }
+
+    pub fn add_tag(mut self: Pin<&mut Self>, index: i32, tag: &QString) {
+        let tag = tag.to_string().trim().to_lowercase();
+        if tag.is_empty() {
+            return;
+        }
+        self.as_mut().rust_mut().update_meta(index, |store, id| store.add_repo_tag(id, &tag));
+        self.as_mut().repos_changed();
+    }
+
+    pub fn remove_tag(mut self: Pin<&mut Self>, index: i32, tag: &QString) {
+        let tag = tag.to_string();
+        self.as_mut().rust_mut().update_meta(index, |store, id| store.remove_repo_tag(id, &tag));
+        self.as_mut().repos_changed();
+    }
+
+    pub fn move_repo(mut self: Pin<&mut Self>, from: i32, to: i32) {
+        let len = self.as_ref().rust().entries.len() as i32;
+        if from < 0 || to < 0 || from >= len || to >= len || from == to {
+            return;
+        }
+        let store = match bridge::get_project_store_or_init() {
+            Some(s) => s,
+            None => return,
+        };
+
+        let mut rust = self.as_mut().rust_mut();
+        let entry = rust.entries.remove(from as usize);
+        rust.entries.insert(to as usize, entry);
+
+        // Persist the full visible order so the move survives the favorite-first sort
+        let result: anyhow::Result<()> = {
+            rust.entries
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, e)| store.set_repo_sort_order(&e.id.0, i as i32))
+        };
+        if let Err(e) = result {
+            rust.set_error(myme_core::AppError::from(e).user_message().to_string());
+        }
+        rust.load_meta();
+        rust.sort_entries();
+        self.as_mut().repos_changed();
+    }
 }
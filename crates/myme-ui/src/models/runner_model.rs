@@ -0,0 +1,217 @@
+//! Local checks model for QML: list the checks for one local clone, run one
+//! at a time with live output, and show past results from the project store.
+
+use core::pin::Pin;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_integrations::RepoTask;
+use myme_services::RepoTaskRun;
+use tokio_util::sync::CancellationToken;
+
+use crate::bridge;
+use crate::services::{request_runner_run, runner_tasks_for, RunnerServiceMessage};
+
+/// Output lines kept on screen while a check runs
+const OUTPUT_LINES: usize = 2000;
+
+/// Past runs listed for a repo
+const HISTORY_LIMIT: usize = 30;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, running)]
+        #[qproperty(QString, error_message)]
+        #[qproperty(QString, repo)]
+        #[qproperty(QString, output)]
+        #[qproperty(QString, current_task)]
+        type RunnerModel = super::RunnerModelRust;
+
+        /// Show checks for the clone at `path`; history is kept under `repo`
+        /// (`owner/repo`, or the directory name for local-only repos)
+        #[qinvokable]
+        fn load(self: Pin<&mut RunnerModel>, repo: &QString, path: &QString);
+
+        /// Checks as a JSON array: name, last status and when it last ran
+        #[qinvokable]
+        fn get_tasks(self: &RunnerModel) -> QString;
+
+        /// Past runs as a JSON array, newest first
+        #[qinvokable]
+        fn get_history(self: &RunnerModel) -> QString;
+
+        #[qinvokable]
+        fn run_task(self: Pin<&mut RunnerModel>, index: i32);
+
+        /// Stop the running check; it is recorded as cancelled
+        #[qinvokable]
+        fn cancel(self: Pin<&mut RunnerModel>);
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut RunnerModel>);
+
+        #[qsignal]
+        fn tasks_changed(self: Pin<&mut RunnerModel>);
+
+        /// A check ended; `status` is passed, failed, timed_out or cancelled
+        #[qsignal]
+        fn run_finished(self: Pin<&mut RunnerModel>, status: QString);
+    }
+}
+
+#[derive(Default)]
+pub struct RunnerModelRust {
+    running: bool,
+    error_message: QString,
+    repo: QString,
+    output: QString,
+    /// Name of the running or last run check
+    current_task: QString,
+    path: PathBuf,
+    tasks: Vec<RepoTask>,
+    history: Vec<RepoTaskRun>,
+    lines: VecDeque<String>,
+    cancel: Option<CancellationToken>,
+}
+
+impl RunnerModelRust {
+    fn reload_history(&mut self) {
+        let repo = self.repo.to_string();
+        let Some(store) = bridge::get_project_store_or_init() else {
+            return;
+        };
+        match store.list_repo_task_runs(&repo, HISTORY_LIMIT) {
+            Ok(runs) => self.history = runs,
+            Err(e) => tracing::warn!("Failed to load check history for {}: {}", repo, e),
+        }
+    }
+
+    fn push_line(&mut self, line: String) {
+        if self.lines.len() == OUTPUT_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+}
+
+fn run_json(run: &RepoTaskRun) -> serde_json::Value {
+    serde_json::json!({
+        "task": run.task,
+        "status": run.status.as_str(),
+        "exitCode": run.exit_code,
+        "startedAt": run.started_at,
+        "durationMs": run.duration_ms,
+        "outputTail": run.output_tail,
+    })
+}
+
+impl qobject::RunnerModel {
+    pub fn load(mut self: Pin<&mut Self>, repo: &QString, path: &QString) {
+        let path = PathBuf::from(path.to_string());
+        let repo_id = repo.to_string();
+        let configured = myme_core::Config::load_cached().repos.tasks.clone();
+        let tasks = runner_tasks_for(&configured, &repo_id, &path);
+
+        self.as_mut().set_repo(QString::from(&repo_id));
+        self.as_mut().rust_mut().path = path;
+        self.as_mut().rust_mut().tasks = tasks;
+        self.as_mut().rust_mut().reload_history();
+        self.as_mut().tasks_changed();
+    }
+
+    pub fn get_tasks(&self) -> QString {
+        let rows: Vec<serde_json::Value> = self
+            .rust()
+            .tasks
+            .iter()
+            .map(|task| {
+                let last = self.rust().history.iter().find(|run| run.task == task.name);
+                serde_json::json!({
+                    "name": task.name,
+                    "lastStatus": last.map(|run| run.status.as_str()).unwrap_or(""),
+                    "lastRunAt": last.map(|run| run.started_at.as_str()).unwrap_or(""),
+                })
+            })
+            .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn get_history(&self) -> QString {
+        let rows: Vec<serde_json::Value> = self.rust().history.iter().map(run_json).collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn run_task(mut self: Pin<&mut Self>, index: i32) {
+        if self.as_ref().rust().running || index < 0 {
+            return;
+        }
+        let Some(task) = self.rust().tasks.get(index as usize).cloned() else {
+            return;
+        };
+        bridge::init_runner_service_channel();
+        let Some(tx) = bridge::get_runner_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Runner service channel not ready"));
+            return;
+        };
+
+        let token = CancellationToken::new();
+        let repo_id = self.rust().repo.to_string();
+        let path = self.rust().path.clone();
+        self.as_mut().rust_mut().cancel = Some(token.clone());
+        self.as_mut().rust_mut().lines.clear();
+        self.as_mut().set_output(QString::from(""));
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_current_task(QString::from(&task.name));
+        self.as_mut().set_running(true);
+        request_runner_run(&tx, repo_id, path, task, token);
+    }
+
+    pub fn cancel(mut self: Pin<&mut Self>) {
+        if let Some(token) = self.as_mut().rust_mut().cancel.take() {
+            token.cancel();
+        }
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        // Drain everything queued so fast output doesn't lag behind the process
+        let mut appended = false;
+        while let Some(msg) = bridge::try_recv_runner_message() {
+            match msg {
+                RunnerServiceMessage::Output { line, .. } => {
+                    self.as_mut().rust_mut().push_line(line);
+                    appended = true;
+                }
+                RunnerServiceMessage::RunDone(result) => {
+                    self.as_mut().rust_mut().cancel = None;
+                    self.as_mut().set_running(false);
+                    match result {
+                        Ok(run) => {
+                            let status = QString::from(run.status.as_str());
+                            self.as_mut().rust_mut().reload_history();
+                            self.as_mut().tasks_changed();
+                            self.as_mut().run_finished(status);
+                        }
+                        Err(e) => {
+                            let text = myme_core::AppError::from(e).user_message();
+                            self.as_mut().set_error_message(QString::from(text));
+                        }
+                    }
+                }
+            }
+        }
+        if appended {
+            let text = self.rust().lines.iter().map(String::as_str).collect::<Vec<_>>().join("\n");
+            self.as_mut().set_output(QString::from(&text));
+        }
+    }
+}
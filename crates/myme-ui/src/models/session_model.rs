@@ -0,0 +1,85 @@
+//! QML access to the workspace session (last project, scroll positions).
+//! Filters are restored by NoteModel/KanbanModel themselves.
+
+use core::pin::Pin;
+
+use cxx_qt_lib::QString;
+
+use crate::bridge;
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        type SessionModel = super::SessionModelRust;
+
+        /// Project opened most recently, or empty string
+        #[qinvokable]
+        fn last_project_id(self: &SessionModel) -> QString;
+
+        /// The user left the project board; start on the home page next time
+        #[qinvokable]
+        fn forget_last_project(self: Pin<&mut SessionModel>);
+
+        #[qinvokable]
+        fn scroll_position(self: &SessionModel, view: &QString) -> f64;
+
+        #[qinvokable]
+        fn save_scroll_position(self: Pin<&mut SessionModel>, view: &QString, position: f64);
+
+        /// Flush the session to disk now (also done on shutdown)
+        #[qinvokable]
+        fn save(self: Pin<&mut SessionModel>);
+
+        /// Note the page the user switched to in the crash journal
+        #[qinvokable]
+        fn set_current_view(self: Pin<&mut SessionModel>, view: &QString);
+    }
+}
+
+#[derive(Default)]
+pub struct SessionModelRust {}
+
+impl qobject::SessionModel {
+    pub fn last_project_id(&self) -> QString {
+        bridge::get_session_store()
+            .and_then(|s| s.lock().last_project_id().map(QString::from))
+            .unwrap_or_else(|| QString::from(""))
+    }
+
+    pub fn forget_last_project(self: Pin<&mut Self>) {
+        if let Some(session) = bridge::get_session_store() {
+            session.lock().set_last_project_id(None);
+        }
+    }
+
+    pub fn scroll_position(&self, view: &QString) -> f64 {
+        bridge::get_session_store()
+            .map(|s| s.lock().scroll_position(&view.to_string()))
+            .unwrap_or(0.0)
+    }
+
+    pub fn save_scroll_position(self: Pin<&mut Self>, view: &QString, position: f64) {
+        if let Some(session) = bridge::get_session_store() {
+            session.lock().set_scroll_position(&view.to_string(), position);
+        }
+    }
+
+    pub fn save(self: Pin<&mut Self>) {
+        if let Some(session) = bridge::get_session_store() {
+            if let Err(e) = session.lock().save() {
+                tracing::warn!("Failed to save session: {}", e);
+            }
+        }
+    }
+
+    pub fn set_current_view(self: Pin<&mut Self>, view: &QString) {
+        myme_core::app::journal().set_open_view(&view.to_string());
+    }
+}
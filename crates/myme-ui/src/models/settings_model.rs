@@ -0,0 +1,343 @@
+// crates/myme-ui/src/models/settings_model.rs
+
+use core::pin::Pin;
+
+use std::sync::Arc;
+
+use chrono::{Local, TimeZone};
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_auth::{TokenReport, TokenStatus};
+use myme_core::config::ConfigValidationError;
+use myme_core::{Config, SettingsDraft};
+use tokio::sync::watch;
+
+use crate::bridge;
+use crate::services::{request_token_health, SettingsServiceMessage};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, checking)]
+        #[qproperty(bool, healthy)]
+        #[qproperty(QString, error_message)]
+        #[qproperty(bool, dirty)]
+        #[qproperty(bool, valid)]
+        type SettingsModel = super::SettingsModelRust;
+
+        /// Check the stored GitHub and Google tokens against their providers
+        #[qinvokable]
+        fn check_tokens(self: Pin<&mut SettingsModel>);
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut SettingsModel>);
+
+        /// JSON array, one entry per account:
+        /// [{"service","state","healthy","expires","summary","missing":[{"feature","scope"}]}]
+        #[qinvokable]
+        fn get_token_report(self: &SettingsModel) -> QString;
+
+        #[qsignal]
+        fn token_report_changed(self: Pin<&mut SettingsModel>);
+
+        /// Start editing from the current config, dropping unsaved edits
+        #[qinvokable]
+        fn load_settings(self: Pin<&mut SettingsModel>);
+
+        /// JSON array of every editable setting:
+        /// [{"key","label","type","options","optional","secret","value","overridden_by"}]
+        #[qinvokable]
+        fn get_settings(self: &SettingsModel) -> QString;
+
+        /// Validation of the draft as JSON: {"errors":[..],"warnings":[..]},
+        /// each {"field","message","source"}
+        #[qinvokable]
+        fn get_validation(self: &SettingsModel) -> QString;
+
+        /// Set a text or choice setting; empty unsets an optional one.
+        /// Returns false (and sets `error_message`) if the value is refused.
+        #[qinvokable]
+        fn set_text(self: Pin<&mut SettingsModel>, key: &QString, value: &QString) -> bool;
+
+        #[qinvokable]
+        fn set_flag(self: Pin<&mut SettingsModel>, key: &QString, value: bool) -> bool;
+
+        #[qinvokable]
+        fn set_number(self: Pin<&mut SettingsModel>, key: &QString, value: i64) -> bool;
+
+        /// Set a list setting from comma- or newline-separated text
+        #[qinvokable]
+        fn set_list(self: Pin<&mut SettingsModel>, key: &QString, value: &QString) -> bool;
+
+        /// Write the draft if it validates; everything following the config
+        /// sees the change
+        #[qinvokable]
+        fn save_settings(self: Pin<&mut SettingsModel>) -> bool;
+
+        #[qinvokable]
+        fn revert_settings(self: Pin<&mut SettingsModel>);
+
+        /// Pick up config changes made elsewhere (another window, the file);
+        /// ignored while there are unsaved edits
+        #[qinvokable]
+        fn poll_config(self: Pin<&mut SettingsModel>);
+
+        /// The draft, its validation or `dirty` changed
+        #[qsignal]
+        fn settings_changed(self: Pin<&mut SettingsModel>);
+
+        #[qsignal]
+        fn saved(self: Pin<&mut SettingsModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct SettingsModelRust {
+    checking: bool,
+    healthy: bool,
+    error_message: QString,
+    reports: Vec<TokenReport>,
+    draft: Option<SettingsDraft>,
+    config_rx: Option<watch::Receiver<Arc<Config>>>,
+}
+
+fn display_name(service: &str) -> &str {
+    match service {
+        "github" => "GitHub",
+        "google" => "Google",
+        other => other,
+    }
+}
+
+/// One line telling the user what's wrong with a token and what to do about it.
+fn summarize(report: &TokenReport) -> String {
+    let name = display_name(&report.service);
+    let status = match &report.status {
+        TokenStatus::Missing => return format!("Not connected — sign in to {name}"),
+        TokenStatus::Revoked => return format!("Access was revoked — reconnect {name}"),
+        TokenStatus::Expired { refreshable: true } => {
+            "Expired; it will be refreshed on next use".to_string()
+        }
+        TokenStatus::Expired { refreshable: false } => {
+            return format!("Expired — reconnect {name}");
+        }
+        TokenStatus::Unchecked(reason) => format!("Couldn't verify ({reason})"),
+        TokenStatus::Valid => "Connected".to_string(),
+    };
+    match report.missing_scopes.as_slice() {
+        [] => status,
+        [req] => format!("{} needs scope {} — reconnect {name}", req.feature, req.scope),
+        missing => {
+            let features: Vec<&str> = missing.iter().map(|r| r.feature).collect();
+            format!("{} need more scopes — reconnect {name}", features.join(", "))
+        }
+    }
+}
+
+fn issues_json(issues: &[ConfigValidationError]) -> Vec<serde_json::Value> {
+    issues
+        .iter()
+        .map(|i| serde_json::json!({ "field": i.field, "message": i.message, "source": i.source }))
+        .collect()
+}
+
+impl qobject::SettingsModel {
+    pub fn check_tokens(mut self: Pin<&mut Self>) {
+        if *self.checking() {
+            return;
+        }
+        bridge::init_settings_service_channel();
+        let Some(tx) = bridge::get_settings_service_tx() else {
+            self.as_mut().set_error_message(QString::from("Settings service not available"));
+            return;
+        };
+        self.as_mut().set_checking(true);
+        self.as_mut().set_error_message(QString::default());
+        request_token_health(&tx);
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        while let Some(msg) = bridge::try_recv_settings_message() {
+            match msg {
+                SettingsServiceMessage::TokenHealthDone(result) => {
+                    self.as_mut().set_checking(false);
+                    match result {
+                        Ok(reports) => {
+                            let healthy = reports.iter().all(TokenReport::is_healthy);
+                            self.as_mut().rust_mut().reports = reports;
+                            self.as_mut().set_healthy(healthy);
+                            self.as_mut().token_report_changed();
+                        }
+                        Err(e) => {
+                            let msg = myme_core::AppError::from(e).user_message();
+                            self.as_mut().set_error_message(QString::from(msg));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn get_token_report(&self) -> QString {
+        let reports: Vec<serde_json::Value> = self
+            .rust()
+            .reports
+            .iter()
+            .map(|r| {
+                let expires = r
+                    .expires_at
+                    .and_then(|t| Local.timestamp_opt(t, 0).single())
+                    .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_default();
+                let state = serde_json::to_value(&r.status)
+                    .ok()
+                    .and_then(|v| v.get("state").cloned())
+                    .unwrap_or_default();
+                serde_json::json!({
+                    "service": display_name(&r.service),
+                    "state": state,
+                    "healthy": r.is_healthy(),
+                    "expires": expires,
+                    "summary": summarize(r),
+                    "missing": r.missing_scopes,
+                })
+            })
+            .collect();
+        QString::from(&serde_json::to_string(&reports).unwrap_or_else(|_| "[]".into()))
+    }
+
+    pub fn load_settings(mut self: Pin<&mut Self>) {
+        let mut rx = Config::subscribe();
+        let config = rx.borrow_and_update().clone();
+        match SettingsDraft::new(&config) {
+            Ok(draft) => {
+                self.as_mut().rust_mut().draft = Some(draft);
+                self.as_mut().rust_mut().config_rx = Some(rx);
+                self.as_mut().set_error_message(QString::default());
+            }
+            Err(e) => {
+                tracing::warn!("Failed to read settings: {:#}", e);
+                self.as_mut().set_error_message(QString::from("Couldn't read the settings"));
+            }
+        }
+        self.as_mut().refresh_draft_state();
+    }
+
+    pub fn get_settings(&self) -> QString {
+        let rows = self.rust().draft.as_ref().map(SettingsDraft::rows).unwrap_or_default();
+        QString::from(&serde_json::to_string(&rows).unwrap_or_else(|_| "[]".into()))
+    }
+
+    pub fn get_validation(&self) -> QString {
+        let result = self.rust().draft.as_ref().map(SettingsDraft::validate).unwrap_or_default();
+        let json = serde_json::json!({
+            "errors": issues_json(&result.errors),
+            "warnings": issues_json(&result.warnings),
+        });
+        QString::from(&json.to_string())
+    }
+
+    pub fn set_text(self: Pin<&mut Self>, key: &QString, value: &QString) -> bool {
+        let value = value.to_string();
+        self.edit(key, |draft, key| draft.set_text(key, &value))
+    }
+
+    pub fn set_flag(self: Pin<&mut Self>, key: &QString, value: bool) -> bool {
+        self.edit(key, |draft, key| draft.set_bool(key, value))
+    }
+
+    pub fn set_number(self: Pin<&mut Self>, key: &QString, value: i64) -> bool {
+        self.edit(key, |draft, key| draft.set_int(key, value))
+    }
+
+    pub fn set_list(self: Pin<&mut Self>, key: &QString, value: &QString) -> bool {
+        let items: Vec<String> = value.to_string().split([',', '\n']).map(str::to_string).collect();
+        self.edit(key, |draft, key| draft.set_list(key, &items))
+    }
+
+    pub fn save_settings(mut self: Pin<&mut Self>) -> bool {
+        let result = match self.as_mut().rust_mut().draft.as_mut() {
+            Some(draft) => draft.save(),
+            None => return false,
+        };
+        match result {
+            Ok(_) => {
+                // Our own save shouldn't look like an outside change
+                if let Some(rx) = self.as_mut().rust_mut().config_rx.as_mut() {
+                    rx.mark_unchanged();
+                }
+                self.as_mut().set_error_message(QString::default());
+                self.as_mut().refresh_draft_state();
+                self.as_mut().saved();
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to save settings: {:#}", e);
+                self.as_mut().set_error_message(QString::from(&format!("{:#}", e)));
+                false
+            }
+        }
+    }
+
+    pub fn revert_settings(mut self: Pin<&mut Self>) {
+        if let Some(draft) = self.as_mut().rust_mut().draft.as_mut() {
+            draft.revert();
+        }
+        self.as_mut().set_error_message(QString::default());
+        self.as_mut().refresh_draft_state();
+    }
+
+    pub fn poll_config(mut self: Pin<&mut Self>) {
+        let changed = self
+            .rust()
+            .config_rx
+            .as_ref()
+            .map(|rx| rx.has_changed().unwrap_or(false))
+            .unwrap_or(false);
+        if changed && !*self.dirty() {
+            self.as_mut().load_settings();
+        }
+    }
+
+    /// Apply one edit to the draft, reporting a refused value
+    fn edit(
+        mut self: Pin<&mut Self>,
+        key: &QString,
+        apply: impl FnOnce(&mut SettingsDraft, &str) -> anyhow::Result<()>,
+    ) -> bool {
+        let key = key.to_string();
+        let result = match self.as_mut().rust_mut().draft.as_mut() {
+            Some(draft) => apply(draft, &key),
+            None => return false,
+        };
+        let ok = match result {
+            Ok(()) => {
+                self.as_mut().set_error_message(QString::default());
+                true
+            }
+            Err(e) => {
+                self.as_mut().set_error_message(QString::from(&format!("{:#}", e)));
+                false
+            }
+        };
+        self.as_mut().refresh_draft_state();
+        ok
+    }
+
+    fn refresh_draft_state(mut self: Pin<&mut Self>) {
+        let (dirty, valid) = match &self.rust().draft {
+            Some(draft) => (draft.is_dirty(), draft.validate().is_valid()),
+            None => (false, false),
+        };
+        self.as_mut().set_dirty(dirty);
+        self.as_mut().set_valid(valid);
+        self.as_mut().settings_changed();
+    }
+}
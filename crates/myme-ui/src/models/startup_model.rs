@@ -0,0 +1,182 @@
+// crates/myme-ui/src/models/startup_model.rs
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+
+use crate::bridge;
+use crate::startup::{self, Health};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, loading)]
+        #[qproperty(i32, failed_count)]
+        #[qproperty(i32, degraded_count)]
+        #[qproperty(QString, summary)]
+        #[qproperty(QString, recovery_summary)]
+        type StartupModel = super::StartupModelRust;
+
+        /// Pull the latest startup status. Call this from a QML Timer.
+        #[qinvokable]
+        fn refresh(self: Pin<&mut StartupModel>);
+
+        /// JSON array in start order:
+        /// [{"name","label","state","reason","attempts","progress","elapsedMs"}]
+        #[qinvokable]
+        fn get_services(self: &StartupModel) -> QString;
+
+        /// Phase end times as a JSON array: [{"phase","elapsed_ms"}]
+        #[qinvokable]
+        fn get_timings(self: &StartupModel) -> QString;
+
+        /// The window has drawn its first frame; deferred services start
+        #[qinvokable]
+        fn first_frame_shown(self: &StartupModel);
+
+        /// Start the services that failed again
+        #[qinvokable]
+        fn retry_failed(self: Pin<&mut StartupModel>);
+
+        /// Path of the previous run's crash report, or empty
+        #[qinvokable]
+        fn crash_report_path(self: &StartupModel) -> QString;
+
+        /// The user has seen the recovery info; don't show it again
+        #[qinvokable]
+        fn dismiss_recovery(self: Pin<&mut StartupModel>);
+
+        #[qsignal]
+        fn status_changed(self: Pin<&mut StartupModel>);
+
+        /// Every service has settled (ok, degraded or failed)
+        #[qsignal]
+        fn finished(self: Pin<&mut StartupModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct StartupModelRust {
+    loading: bool,
+    failed_count: i32,
+    degraded_count: i32,
+    summary: QString,
+    /// Set when the previous run crashed or didn't exit cleanly
+    recovery_summary: QString,
+    status_json: String,
+    recovery_checked: bool,
+}
+
+impl qobject::StartupModel {
+    pub fn refresh(mut self: Pin<&mut Self>) {
+        if !self.as_ref().rust().recovery_checked {
+            self.as_mut().rust_mut().recovery_checked = true;
+            if let Some(recovery) = bridge::get_recovery_info() {
+                self.as_mut().set_recovery_summary(QString::from(&recovery.summary()));
+            }
+        }
+
+        let services = startup::snapshot();
+        // Phase timings too, so the last one is seen after every step settled
+        let json = serde_json::json!({ "services": services, "timings": startup::phase_timings() })
+            .to_string();
+        if json == self.as_ref().rust().status_json {
+            return;
+        }
+
+        let loading = startup::is_running() || services.iter().any(|s| !s.health.is_settled());
+        let failed = services.iter().filter(|s| matches!(s.health, Health::Failed(_))).count();
+        let degraded = services.iter().filter(|s| matches!(s.health, Health::Degraded(_))).count();
+        let summary = if loading {
+            let waiting: Vec<String> = services
+                .iter()
+                .filter(|s| !s.health.is_settled())
+                .map(|s| match &s.progress {
+                    Some(progress) => format!("{}: {}", s.label, progress.to_lowercase()),
+                    None => s.label.to_string(),
+                })
+                .collect();
+            format!("Starting {}…", waiting.join(", "))
+        } else if failed > 0 {
+            let names: Vec<&str> = services
+                .iter()
+                .filter(|s| matches!(s.health, Health::Failed(_)))
+                .map(|s| s.label)
+                .collect();
+            format!("{} failed to start", names.join(", "))
+        } else {
+            String::new()
+        };
+
+        let was_loading = *self.loading();
+        self.as_mut().rust_mut().status_json = json;
+        self.as_mut().set_loading(loading);
+        self.as_mut().set_failed_count(failed as i32);
+        self.as_mut().set_degraded_count(degraded as i32);
+        self.as_mut().set_summary(QString::from(&summary));
+        self.as_mut().status_changed();
+        if was_loading && !loading {
+            self.as_mut().finished();
+        }
+    }
+
+    pub fn get_services(&self) -> QString {
+        let services: Vec<serde_json::Value> = startup::snapshot()
+            .into_iter()
+            .map(|s| {
+                let (state, reason) = match &s.health {
+                    Health::Pending => ("pending", ""),
+                    Health::Starting => ("starting", ""),
+                    Health::Ok => ("ok", ""),
+                    Health::Degraded(r) => ("degraded", r.as_str()),
+                    Health::Failed(r) => ("failed", r.as_str()),
+                };
+                serde_json::json!({
+                    "name": s.name,
+                    "label": s.label,
+                    "state": state,
+                    "reason": reason,
+                    "attempts": s.attempts,
+                    "progress": s.progress,
+                    "elapsedMs": s.elapsed_ms,
+                })
+            })
+            .collect();
+        QString::from(&serde_json::to_string(&services).unwrap_or_else(|_| "[]".into()))
+    }
+
+    pub fn get_timings(&self) -> QString {
+        let timings = startup::phase_timings();
+        QString::from(&serde_json::to_string(&timings).unwrap_or_else(|_| "[]".into()))
+    }
+
+    pub fn first_frame_shown(&self) {
+        startup::first_frame_shown();
+    }
+
+    pub fn crash_report_path(&self) -> QString {
+        bridge::get_recovery_info()
+            .and_then(|r| r.report_path)
+            .map(|p| QString::from(&p.display().to_string()))
+            .unwrap_or_default()
+    }
+
+    pub fn dismiss_recovery(mut self: Pin<&mut Self>) {
+        bridge::dismiss_recovery();
+        self.as_mut().set_recovery_summary(QString::default());
+    }
+
+    pub fn retry_failed(mut self: Pin<&mut Self>) {
+        if startup::retry_failed() {
+            self.as_mut().set_loading(true);
+        }
+    }
+}
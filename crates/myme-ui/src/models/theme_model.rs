@@ -0,0 +1,267 @@
+//! Theme model for QML: the configured mode, named theme, custom accent and
+//! dark schedule, resolved into `dark` and accent colors that Main.qml binds
+//! onto the Theme singleton. Changes are saved to `[ui]` in the config.
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use myme_core::config::UiConfig;
+use myme_core::theme::{self, ThemeMode};
+
+use crate::bridge;
+use crate::services::{request_portal_scheme, ThemeServiceMessage};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(QString, mode)]
+        #[qproperty(QString, theme)]
+        #[qproperty(QString, accent_color)]
+        #[qproperty(QString, dark_start)]
+        #[qproperty(QString, dark_end)]
+        #[qproperty(bool, dark)]
+        #[qproperty(QString, primary)]
+        #[qproperty(QString, primary_hover)]
+        #[qproperty(QString, primary_glow)]
+        #[qproperty(QString, error_message)]
+        type ThemeModel = super::ThemeModelRust;
+
+        /// Read `[ui]` from the config and ask the desktop for its preference
+        #[qinvokable]
+        fn load(self: Pin<&mut ThemeModel>);
+
+        /// "auto", "light", "dark" or "scheduled"
+        #[qinvokable]
+        fn save_mode(self: Pin<&mut ThemeModel>, mode: &QString);
+
+        /// Named theme id from `get_themes`
+        #[qinvokable]
+        fn save_theme(self: Pin<&mut ThemeModel>, id: &QString);
+
+        /// Custom accent `#rrggbb`; empty to use the theme's accent
+        #[qinvokable]
+        fn save_accent_color(self: Pin<&mut ThemeModel>, color: &QString);
+
+        /// Dark period for scheduled mode, `HH:MM` each
+        #[qinvokable]
+        fn save_schedule(self: Pin<&mut ThemeModel>, start: &QString, end: &QString);
+
+        /// Built-in themes as a JSON array: id, label, accent for the current brightness
+        #[qinvokable]
+        fn get_themes(self: &ThemeModel) -> QString;
+
+        /// Re-evaluate the schedule and desktop preference. `style_dark` is
+        /// Qt's color-scheme hint, used when the portal has no answer.
+        #[qinvokable]
+        fn tick(self: Pin<&mut ThemeModel>, style_dark: bool);
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut ThemeModel>);
+
+        /// `dark` or the accent colors changed
+        #[qsignal]
+        fn theme_changed(self: Pin<&mut ThemeModel>);
+    }
+}
+
+#[derive(Default)]
+pub struct ThemeModelRust {
+    mode: QString,
+    theme: QString,
+    accent_color: QString,
+    dark_start: QString,
+    dark_end: QString,
+    dark: bool,
+    primary: QString,
+    primary_hover: QString,
+    primary_glow: QString,
+    error_message: QString,
+    /// Portal color-scheme preference, when the desktop has one
+    portal_dark: Option<bool>,
+    /// Qt's color-scheme hint from the last tick
+    style_dark: bool,
+}
+
+impl ThemeModelRust {
+    fn theme_mode(&self) -> ThemeMode {
+        ThemeMode::parse(&self.mode.to_string()).unwrap_or_default()
+    }
+}
+
+/// Ask the settings portal for the desktop's preference; answered on the channel
+fn request_portal() {
+    bridge::init_theme_service_channel();
+    if let Some(tx) = bridge::get_theme_service_tx() {
+        request_portal_scheme(&tx);
+    }
+}
+
+impl qobject::ThemeModel {
+    pub fn load(mut self: Pin<&mut Self>) {
+        let config = myme_core::Config::load_cached();
+        let ui = &config.ui;
+        self.as_mut().set_mode(QString::from(ui.theme_mode().as_str()));
+        self.as_mut().set_theme(QString::from(&ui.theme));
+        self.as_mut().set_accent_color(QString::from(ui.accent_color.as_deref().unwrap_or("")));
+        self.as_mut().set_dark_start(QString::from(&ui.dark_start));
+        self.as_mut().set_dark_end(QString::from(&ui.dark_end));
+        self.as_mut().resolve();
+        request_portal();
+    }
+
+    pub fn save_mode(mut self: Pin<&mut Self>, mode: &QString) {
+        let Some(mode) = ThemeMode::parse(&mode.to_string()) else {
+            return;
+        };
+        if !self.as_mut().persist(|ui| {
+            ui.theme_mode = Some(mode);
+            ui.dark_mode = mode == ThemeMode::Dark;
+        }) {
+            return;
+        }
+        self.as_mut().set_mode(QString::from(mode.as_str()));
+        self.as_mut().resolve();
+        if mode == ThemeMode::Auto {
+            request_portal();
+        }
+    }
+
+    pub fn save_theme(mut self: Pin<&mut Self>, id: &QString) {
+        let id = id.to_string();
+        if theme::find_theme(&id).is_none() || !self.as_mut().persist(|ui| ui.theme = id.clone()) {
+            return;
+        }
+        self.as_mut().set_theme(QString::from(&id));
+        self.as_mut().resolve();
+    }
+
+    pub fn save_accent_color(mut self: Pin<&mut Self>, color: &QString) {
+        let color = color.to_string().trim().to_lowercase();
+        if !color.is_empty() && theme::parse_hex_color(&color).is_none() {
+            self.as_mut().set_error_message(QString::from("Accent color must be #rgb or #rrggbb"));
+            return;
+        }
+        let custom = Some(color.clone()).filter(|c| !c.is_empty());
+        if !self.as_mut().persist(|ui| ui.accent_color = custom) {
+            return;
+        }
+        self.as_mut().set_accent_color(QString::from(&color));
+        self.as_mut().resolve();
+    }
+
+    pub fn save_schedule(mut self: Pin<&mut Self>, start: &QString, end: &QString) {
+        let (start, end) =
+            (start.to_string().trim().to_string(), end.to_string().trim().to_string());
+        if theme::parse_time_of_day(&start).is_none() || theme::parse_time_of_day(&end).is_none() {
+            self.as_mut().set_error_message(QString::from("Times must be HH:MM (24-hour)"));
+            return;
+        }
+        if !self.as_mut().persist(|ui| {
+            ui.dark_start = start.clone();
+            ui.dark_end = end.clone();
+        }) {
+            return;
+        }
+        self.as_mut().set_dark_start(QString::from(&start));
+        self.as_mut().set_dark_end(QString::from(&end));
+        self.as_mut().resolve();
+    }
+
+    pub fn get_themes(&self) -> QString {
+        let dark = self.rust().dark;
+        let rows: Vec<serde_json::Value> = theme::THEMES
+            .iter()
+            .map(|t| {
+                serde_json::json!({
+                    "id": t.id,
+                    "label": t.label,
+                    "accent": if dark { t.accent_dark } else { t.accent_light },
+                })
+            })
+            .collect();
+        QString::from(serde_json::Value::Array(rows).to_string())
+    }
+
+    pub fn tick(mut self: Pin<&mut Self>, style_dark: bool) {
+        self.as_mut().rust_mut().style_dark = style_dark;
+        self.as_mut().resolve();
+        if self.rust().theme_mode() == ThemeMode::Auto {
+            request_portal();
+        }
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let Some(msg) = bridge::try_recv_theme_message() else {
+            return;
+        };
+        match msg {
+            ThemeServiceMessage::PortalScheme(scheme) => {
+                self.as_mut().rust_mut().portal_dark = scheme;
+                self.as_mut().resolve();
+            }
+        }
+    }
+
+    /// Apply `f` to `[ui]` in the config file and save it; false (with the
+    /// error shown) when it couldn't be saved
+    fn persist<F>(mut self: Pin<&mut Self>, f: F) -> bool
+    where
+        F: FnOnce(&mut UiConfig),
+    {
+        let result = myme_core::Config::load().and_then(|mut config| {
+            f(&mut config.ui);
+            config.save()
+        });
+        match result {
+            Ok(()) => {
+                self.as_mut().set_error_message(QString::from(""));
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to save theme settings: {}", e);
+                let msg = myme_core::AppError::from(e).user_message().to_string();
+                self.as_mut().set_error_message(QString::from(&msg));
+                false
+            }
+        }
+    }
+
+    /// Recompute `dark` and the accent colors, emitting `theme_changed` when
+    /// any of them moved
+    fn resolve(mut self: Pin<&mut Self>) {
+        let rust = self.rust();
+        let desktop_dark = rust.portal_dark.unwrap_or(rust.style_dark);
+        let now = chrono::Local::now().time();
+        let dark = theme::resolve_dark(
+            rust.theme_mode(),
+            desktop_dark,
+            now,
+            &rust.dark_start.to_string(),
+            &rust.dark_end.to_string(),
+        );
+        let custom = rust.accent_color.to_string();
+        let accent = theme::accent(
+            &rust.theme.to_string(),
+            Some(custom.as_str()).filter(|c| !c.is_empty()),
+            dark,
+        );
+
+        let changed = dark != rust.dark || accent.primary != rust.primary.to_string();
+        if !changed {
+            return;
+        }
+        self.as_mut().set_dark(dark);
+        self.as_mut().set_primary(QString::from(&accent.primary));
+        self.as_mut().set_primary_hover(QString::from(&accent.hover));
+        self.as_mut().set_primary_glow(QString::from(&accent.glow));
+        self.as_mut().theme_changed();
+    }
+}
@@ -3,6 +3,7 @@ use core::pin::Pin;
 use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
 use chrono_tz::Tz;
 use cxx_qt_lib::QString;
+use myme_core::i18n;
 
 #[cxx_qt::bridge]
 pub mod qobject {
@@ -340,26 +341,25 @@ impl qobject::TimeModel {
     fn format_relative_time(duration: chrono::Duration) -> String {
         let seconds = duration.num_seconds().abs();
         let is_past = duration.num_seconds() > 0;
-        let suffix = if is_past { "ago" } else { "from now" };
 
-        if seconds < 60 {
-            format!("{} seconds {}", seconds, suffix)
+        let (unit, count) = if seconds < 60 {
+            ("time-seconds", seconds)
         } else if seconds < 3600 {
-            let minutes = seconds / 60;
-            format!("{} minute{} {}", minutes, if minutes == 1 { "" } else { "s" }, suffix)
+            ("time-minutes", seconds / 60)
         } else if seconds < 86400 {
-            let hours = seconds / 3600;
-            format!("{} hour{} {}", hours, if hours == 1 { "" } else { "s" }, suffix)
+            ("time-hours", seconds / 3600)
         } else if seconds < 2592000 {
-            let days = seconds / 86400;
-            format!("{} day{} {}", days, if days == 1 { "" } else { "s" }, suffix)
+            ("time-days", seconds / 86400)
         } else if seconds < 31536000 {
-            let months = seconds / 2592000;
-            format!("{} month{} {}", months, if months == 1 { "" } else { "s" }, suffix)
+            ("time-months", seconds / 2592000)
         } else {
-            let years = seconds / 31536000;
-            format!("{} year{} {}", years, if years == 1 { "" } else { "s" }, suffix)
-        }
+            ("time-years", seconds / 31536000)
+        };
+        let span = i18n::tr_args(unit, &[("count", count.into())]);
+        i18n::tr_args(
+            if is_past { "time-ago" } else { "time-from-now" },
+            &[("duration", span.into())],
+        )
     }
 
     fn clear_outputs(mut self: Pin<&mut Self>) {
@@ -0,0 +1,103 @@
+// crates/myme-ui/src/models/toast_model.rs
+
+use core::pin::Pin;
+use std::time::Instant;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+use tokio::sync::broadcast::{self, error::TryRecvError};
+
+use crate::services::{ui_events, ToastQueue, UiEvent};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(QString, toasts)]
+        #[qproperty(i32, count)]
+        type ToastModel = super::ToastModelRust;
+
+        /// Pick up published events and drop expired toasts
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut ToastModel>);
+
+        #[qinvokable]
+        fn dismiss(self: Pin<&mut ToastModel>, id: i32);
+
+        /// Run the toast's action hint and dismiss it
+        #[qinvokable]
+        fn activate(self: Pin<&mut ToastModel>, id: i32);
+
+        /// `action` is `navigate:<Page>` or `open:<url>`
+        #[qsignal]
+        fn action_requested(self: Pin<&mut ToastModel>, action: QString);
+    }
+}
+
+#[derive(Default)]
+pub struct ToastModelRust {
+    /// JSON array of toasts on screen, oldest first (see `ToastQueue::to_json`)
+    toasts: QString,
+    count: i32,
+    queue: ToastQueue,
+    rx: Option<broadcast::Receiver<UiEvent>>,
+}
+
+impl qobject::ToastModel {
+    fn sync(mut self: Pin<&mut Self>) {
+        let json = self.as_ref().rust().queue.to_json();
+        let count = self.as_ref().rust().queue.toasts().len() as i32;
+        self.as_mut().set_toasts(QString::from(&json));
+        self.as_mut().set_count(count);
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        let now = Instant::now();
+        let mut changed = self.as_mut().rust_mut().queue.expire(now);
+        let mut rx =
+            self.as_mut().rust_mut().rx.take().unwrap_or_else(|| ui_events::global().subscribe());
+        loop {
+            match rx.try_recv() {
+                Ok(event) => {
+                    self.as_mut().rust_mut().queue.push(event, now);
+                    changed = true;
+                }
+                // Progress updates are superseded by later ones; skipping is fine
+                Err(TryRecvError::Lagged(n)) => {
+                    tracing::debug!("Toast model skipped {} events", n);
+                }
+                Err(TryRecvError::Empty | TryRecvError::Closed) => break,
+            }
+        }
+        self.as_mut().rust_mut().rx = Some(rx);
+        if changed {
+            self.sync();
+        }
+    }
+
+    pub fn dismiss(mut self: Pin<&mut Self>, id: i32) {
+        if self.as_mut().rust_mut().queue.dismiss(id as u64) {
+            self.sync();
+        }
+    }
+
+    pub fn activate(mut self: Pin<&mut Self>, id: i32) {
+        let action = self
+            .as_ref()
+            .rust()
+            .queue
+            .get(id as u64)
+            .and_then(|t| t.event.action.as_ref())
+            .map(|a| a.action.clone());
+        self.as_mut().dismiss(id);
+        if let Some(action) = action {
+            self.as_mut().action_requested(QString::from(&action));
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// crates/myme-ui/src/models/undo_model.rs
+
+use core::pin::Pin;
+
+use cxx_qt::CxxQtType;
+use cxx_qt_lib::QString;
+
+use crate::bridge;
+use crate::services::undo_service::{self, UndoError};
+use crate::services::{request_redo, request_undo, UndoServiceMessage};
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, busy)]
+        #[qproperty(bool, can_undo)]
+        #[qproperty(bool, can_redo)]
+        #[qproperty(QString, undo_summary)]
+        #[qproperty(QString, redo_summary)]
+        #[qproperty(QString, error_message)]
+        type UndoManager = super::UndoManagerRust;
+
+        /// Reverse the most recent change made in notes, Gmail or kanban
+        #[qinvokable]
+        fn undo(self: Pin<&mut UndoManager>);
+
+        /// Re-apply the most recently undone change
+        #[qinvokable]
+        fn redo(self: Pin<&mut UndoManager>);
+
+        #[qinvokable]
+        fn poll_channel(self: Pin<&mut UndoManager>);
+
+        /// A reversible change was made; show a transient "Undo" toast
+        #[qsignal]
+        fn undo_offered(self: Pin<&mut UndoManager>, summary: QString);
+
+        /// An undo or redo of a change in `service` ("notes", "gmail", "kanban") finished
+        #[qsignal]
+        fn undo_applied(
+            self: Pin<&mut UndoManager>,
+            service: QString,
+            summary: QString,
+            redo: bool,
+        );
+    }
+}
+
+#[derive(Default)]
+pub struct UndoManagerRust {
+    busy: bool,
+    can_undo: bool,
+    can_redo: bool,
+    undo_summary: QString,
+    redo_summary: QString,
+    error_message: QString,
+}
+
+impl qobject::UndoManager {
+    fn sync_state(mut self: Pin<&mut Self>) {
+        let undo = undo_service::undo_summary();
+        let redo = undo_service::redo_summary();
+        self.as_mut().set_can_undo(undo.is_some());
+        self.as_mut().set_can_redo(redo.is_some());
+        self.as_mut().set_undo_summary(QString::from(&undo.unwrap_or_default()));
+        self.as_mut().set_redo_summary(QString::from(&redo.unwrap_or_default()));
+    }
+
+    fn start(mut self: Pin<&mut Self>, redo: bool) {
+        if *self.busy() {
+            return;
+        }
+        bridge::init_undo_service_channel();
+        let Some(tx) = bridge::get_undo_service_tx() else {
+            return;
+        };
+        self.as_mut().set_busy(true);
+        self.as_mut().set_error_message(QString::default());
+        if redo {
+            request_redo(&tx);
+        } else {
+            request_undo(&tx);
+        }
+        self.sync_state();
+    }
+
+    pub fn undo(self: Pin<&mut Self>) {
+        self.start(false);
+    }
+
+    pub fn redo(self: Pin<&mut Self>) {
+        self.start(true);
+    }
+
+    pub fn poll_channel(mut self: Pin<&mut Self>) {
+        bridge::init_undo_service_channel();
+        while let Some(msg) = bridge::try_recv_undo_message() {
+            match msg {
+                UndoServiceMessage::Recorded { summary } => {
+                    self.as_mut().undo_offered(QString::from(&summary));
+                }
+                UndoServiceMessage::Applied { service, summary, redo, result } => {
+                    self.as_mut().set_busy(false);
+                    match result {
+                        Ok(()) => {
+                            self.as_mut().undo_applied(
+                                QString::from(&service),
+                                QString::from(&summary),
+                                redo,
+                            );
+                        }
+                        Err(UndoError::Empty) => {}
+                        Err(e) => {
+                            let msg = myme_core::AppError::from(e).user_message();
+                            self.as_mut().set_error_message(QString::from(msg));
+                        }
+                    }
+                }
+            }
+        }
+        self.sync_state();
+    }
+}
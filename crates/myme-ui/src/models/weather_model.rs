@@ -3,10 +3,12 @@ use std::sync::Arc;
 
 use cxx_qt::CxxQtType;
 use cxx_qt_lib::QString;
-use myme_weather::{TemperatureUnit, WeatherCache, WeatherData, WeatherProvider};
+use myme_core::dashboard::WeatherSnapshot;
+use myme_core::i18n::tr;
+use myme_weather::{RadarTile, TemperatureUnit, WeatherCache, WeatherData, WeatherProvider};
 
 use crate::bridge;
-use crate::services::{request_weather_fetch, WeatherServiceMessage};
+use crate::services::{request_weather_fetch, request_weather_radar, WeatherServiceMessage};
 
 #[cxx_qt::bridge]
 pub mod qobject {
@@ -36,6 +38,18 @@ pub mod qobject {
         #[qproperty(i32, precipitation_chance)]
         #[qproperty(QString, sunrise)]
         #[qproperty(QString, sunset)]
+        #[qproperty(i32, minutes_until_rain)]
+        // Compared with this time yesterday
+        #[qproperty(bool, has_comparison)]
+        #[qproperty(f64, temperature_delta)]
+        #[qproperty(QString, comparison_text)]
+        // Sun and moon for today
+        #[qproperty(QString, moon_phase)]
+        #[qproperty(i32, moon_illumination)]
+        #[qproperty(QString, day_length)]
+        #[qproperty(QString, golden_hour_morning)]
+        #[qproperty(QString, golden_hour_evening)]
+        #[qproperty(bool, radar_loading)]
         type WeatherModel = super::WeatherModelRust;
 
         #[qinvokable]
@@ -93,6 +107,24 @@ pub mod qobject {
         #[qinvokable]
         fn get_hourly_precip(self: &WeatherModel, day_index: i32, hour_index: i32) -> i32;
 
+        /// Precipitation sparkline data for the next `hours` as a JSON array of
+        /// {"time": RFC 3339, "intensity": mm/h, "probability": percent or null}
+        #[qinvokable]
+        fn get_precip_series(self: &WeatherModel, hours: i32) -> QString;
+
+        /// Fetch the latest radar frame around the current location; emits
+        /// `radar_changed` when it arrives
+        #[qinvokable]
+        fn refresh_radar(self: Pin<&mut WeatherModel>);
+
+        /// Latest radar frame as JSON: {"radarUrl","mapUrl","time","x","y"}, where
+        /// x/y place the location on the tile (0-1), or "{}" when there is none
+        #[qinvokable]
+        fn get_radar(self: &WeatherModel) -> QString;
+
+        #[qsignal]
+        fn radar_changed(self: Pin<&mut WeatherModel>);
+
         #[qsignal]
         fn weather_changed(self: Pin<&mut WeatherModel>);
 
@@ -129,8 +161,22 @@ pub struct WeatherModelRust {
     precipitation_chance: i32,
     sunrise: QString,
     sunset: QString,
+    // Minutes until precipitation in the next 2 hours (-1 if none expected)
+    minutes_until_rain: i32,
+    // Degrees warmer than this time yesterday (negative: colder)
+    has_comparison: bool,
+    temperature_delta: f64,
+    comparison_text: QString,
+    // Moon phase name, lit percent, "15h 27m", and golden hour as "05:36–06:31"
+    moon_phase: QString,
+    moon_illumination: i32,
+    day_length: QString,
+    golden_hour_morning: QString,
+    golden_hour_evening: QString,
+    radar_loading: bool,
     // Internal state
     weather_data: Option<WeatherData>,
+    radar: Option<RadarTile>,
     provider: Option<Arc<WeatherProvider>>,
     cache: Option<WeatherCache>,
     op_state: OpState,
@@ -175,7 +221,7 @@ impl qobject::WeatherModel {
         self.as_mut().set_feels_like(data.current.feels_like);
         self.as_mut().set_humidity(data.current.humidity as i32);
         self.as_mut().set_wind_speed(data.current.wind_speed);
-        self.as_mut().set_condition(QString::from(data.current.condition.description()));
+        self.as_mut().set_condition(QString::from(tr(data.current.condition.message_id())));
         self.as_mut().set_condition_icon(QString::from(data.current.condition.icon_name()));
 
         let location_name =
@@ -196,9 +242,83 @@ impl qobject::WeatherModel {
             self.as_mut().set_sunset(QString::from(today.sunset.format("%H:%M").to_string()));
         }
 
+        let minutes_until_rain =
+            data.minutes_until_precipitation(2).map(|m| m as i32).unwrap_or(-1);
+        self.as_mut().set_minutes_until_rain(minutes_until_rain);
+
+        // Caches written before astro data existed get it computed here
+        if let Some(astro) = data.astro.clone().or_else(|| data.compute_astro()) {
+            self.as_mut().set_moon_phase(QString::from(astro.moon_phase.description()));
+            self.as_mut().set_moon_illumination((astro.moon_illumination * 100.0).round() as i32);
+            let minutes = astro.day_length_minutes;
+            self.as_mut().set_day_length(QString::from(&format!(
+                "{}h {:02}m",
+                minutes / 60,
+                minutes % 60
+            )));
+            let span =
+                |from: Option<chrono::NaiveTime>, to: Option<chrono::NaiveTime>| match (from, to) {
+                    (Some(from), Some(to)) => {
+                        format!("{}–{}", from.format("%H:%M"), to.format("%H:%M"))
+                    }
+                    _ => String::new(),
+                };
+            let (sunrise, sunset) = data
+                .forecast
+                .first()
+                .map(|d| (Some(d.sunrise), Some(d.sunset)))
+                .unwrap_or_default();
+            self.as_mut().set_golden_hour_morning(QString::from(&span(
+                sunrise,
+                astro.golden_hour_morning_end,
+            )));
+            self.as_mut().set_golden_hour_evening(QString::from(&span(
+                astro.golden_hour_evening_start,
+                sunset,
+            )));
+        }
+
+        if let Some(store) = bridge::get_dashboard_store() {
+            let rust = self.as_ref().rust();
+            store.lock().set_weather(WeatherSnapshot {
+                temperature: rust.temperature,
+                feels_like: rust.feels_like,
+                humidity: rust.humidity,
+                wind_speed: rust.wind_speed,
+                condition: rust.condition.to_string(),
+                icon: rust.condition_icon.to_string(),
+                location: rust.location_name.to_string(),
+                high: rust.today_high,
+                low: rust.today_low,
+                precipitation_chance: rust.precipitation_chance,
+                sunrise: rust.sunrise.to_string(),
+                sunset: rust.sunset.to_string(),
+            });
+        }
+
         // Store weather data for forecast methods
         self.as_mut().rust_mut().store_weather_data(data);
         self.as_mut().set_has_data(true);
+        self.as_mut().update_comparison();
+    }
+
+    /// Compare the latest observation with this time yesterday
+    fn update_comparison(mut self: Pin<&mut Self>) {
+        let rust = self.as_ref().rust();
+        let unit = rust.provider.as_ref().map(|p| p.unit()).unwrap_or_default();
+        let delta = rust.cache.as_ref().and_then(|cache| cache.delta_vs_yesterday(unit));
+        match delta {
+            Some(delta) => {
+                self.as_mut().set_temperature_delta(delta.temperature);
+                self.as_mut().set_comparison_text(QString::from(&delta.summary()));
+                self.as_mut().set_has_comparison(true);
+            }
+            None => {
+                self.as_mut().set_has_comparison(false);
+                self.as_mut().set_temperature_delta(0.0);
+                self.as_mut().set_comparison_text(QString::default());
+            }
+        }
     }
 
     /// Refresh weather data asynchronously (non-blocking)
@@ -230,6 +350,15 @@ impl qobject::WeatherModel {
                 }
             });
 
+        myme_core::metrics::record_cache_lookup(
+            "weather",
+            match &cache_result {
+                Some((_, false, _)) => "hit",
+                Some((_, true, _)) => "stale",
+                None => "miss",
+            },
+        );
+
         if let Some((cached_data, is_stale, _)) = cache_result {
             tracing::info!("Using cached weather data");
             self.as_mut().update_from_data(&cached_data);
@@ -278,9 +407,11 @@ impl qobject::WeatherModel {
                     Ok(data) => {
                         tracing::info!("Weather data fetched successfully");
 
-                        // Update cache
+                        // Update cache and the history used for comparisons
+                        let unit = self.as_ref().rust().provider.as_ref().map(|p| p.unit());
                         if let Some(cache) = &mut self.as_mut().rust_mut().cache {
                             cache.update(data.clone());
+                            cache.record(&data.current, unit.unwrap_or_default());
                             if let Err(e) = cache.save() {
                                 tracing::warn!("Failed to save weather cache: {}", e);
                             }
@@ -305,9 +436,48 @@ impl qobject::WeatherModel {
                     }
                 }
             }
+            WeatherServiceMessage::RadarDone(tile) => {
+                self.as_mut().set_radar_loading(false);
+                self.as_mut().rust_mut().radar = tile;
+                self.as_mut().radar_changed();
+            }
         }
     }
 
+    pub fn refresh_radar(mut self: Pin<&mut Self>) {
+        if self.as_ref().rust().radar_loading {
+            return;
+        }
+        let Some(location) = self.as_ref().rust().weather_data.as_ref().map(|d| d.location.clone())
+        else {
+            return;
+        };
+        let Some(provider) = self.as_ref().rust().provider.clone() else {
+            return;
+        };
+        bridge::init_weather_service_channel();
+        let Some(tx) = bridge::get_weather_service_tx() else {
+            return;
+        };
+
+        self.as_mut().set_radar_loading(true);
+        request_weather_radar(&tx, provider, location);
+    }
+
+    pub fn get_radar(&self) -> QString {
+        let radar = match &self.rust().radar {
+            Some(tile) => serde_json::json!({
+                "radarUrl": tile.radar_url,
+                "mapUrl": tile.map_url,
+                "time": tile.time.with_timezone(&chrono::Local).format("%H:%M").to_string(),
+                "x": tile.x,
+                "y": tile.y,
+            }),
+            None => serde_json::json!({}),
+        };
+        QString::from(radar.to_string().as_str())
+    }
+
     pub fn set_temperature_unit(mut self: Pin<&mut Self>, unit: &QString) {
         let unit_enum = match unit.to_string().to_lowercase().as_str() {
             "celsius" => TemperatureUnit::Celsius,
@@ -320,7 +490,7 @@ impl qobject::WeatherModel {
 
         if has_provider {
             // Create new provider with updated unit
-            if let Ok(new_provider) = WeatherProvider::new(unit_enum) {
+            if let Ok(new_provider) = bridge::build_weather_provider(unit_enum) {
                 self.as_mut().rust_mut().provider = Some(Arc::new(new_provider));
                 // Refresh to get data in new unit
                 self.refresh();
@@ -373,7 +543,7 @@ impl qobject::WeatherModel {
             .weather_data
             .as_ref()
             .and_then(|d| d.forecast.get(index as usize))
-            .map(|f| QString::from(f.condition.description()))
+            .map(|f| QString::from(tr(f.condition.message_id())))
             .unwrap_or_default()
     }
 
@@ -461,4 +631,24 @@ impl qobject::WeatherModel {
             .map(|h| h.precipitation_chance as i32)
             .unwrap_or(0)
     }
+
+    pub fn get_precip_series(&self, hours: i32) -> QString {
+        let points: Vec<serde_json::Value> = self
+            .rust()
+            .weather_data
+            .as_ref()
+            .map(|d| d.precip_series(hours.max(0) as u32))
+            .unwrap_or_default()
+            .iter()
+            .map(|p| {
+                serde_json::json!({
+                    "time": p.time.to_rfc3339(),
+                    "intensity": p.intensity_mm_h,
+                    "probability": p.probability,
+                })
+            })
+            .collect();
+        let s = serde_json::to_string(&points).unwrap_or_else(|_| "[]".to_string());
+        QString::from(s.as_str())
+    }
 }
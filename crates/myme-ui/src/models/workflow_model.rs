@@ -1,14 +1,19 @@
 // crates/myme-ui/src/models/workflow_model.rs
 
 use core::pin::Pin;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 use cxx_qt::CxxQtType;
 use cxx_qt_lib::QString;
-use myme_services::ProjectStore;
+use myme_core::i18n::tr;
+use myme_services::{GitHubClient, ProjectStore, WorkflowInput};
 
 use crate::bridge;
-use crate::services::{request_fetch_workflows, RepoWorkflows, WorkflowServiceMessage};
+use crate::services::{
+    request_fetch_workflows, request_workflow_dispatch, request_workflow_inputs, RepoWorkflows,
+    WorkflowServiceMessage,
+};
 
 #[cxx_qt::bridge]
 pub mod qobject {
@@ -65,8 +70,47 @@ pub mod qobject {
             workflow_index: i32,
         ) -> QString;
 
+        /// Open security alerts for the repo (0 when none or unchecked)
+        #[qinvokable]
+        fn get_alert_count(self: &WorkflowModel, repo_index: i32) -> i32;
+
+        /// Most severe open alert: "critical", "high", "medium", "low" or ""
+        #[qinvokable]
+        fn get_alert_severity(self: &WorkflowModel, repo_index: i32) -> QString;
+
+        /// Fetch the workflow's dispatch inputs; emits inputs_changed
+        #[qinvokable]
+        fn load_inputs(self: Pin<&mut WorkflowModel>, repo_index: i32, workflow_index: i32);
+
+        /// JSON {loaded, dispatchable, inputs: [{name, description, required,
+        /// default, kind, options}]}
+        #[qinvokable]
+        fn get_inputs(self: &WorkflowModel, repo_index: i32, workflow_index: i32) -> QString;
+
+        /// Trigger a manual run on `git_ref` (empty for the default branch)
+        /// with `inputs_json` ({name: value}). Returns false when the inputs
+        /// don't check out; the reason is in error_message.
+        #[qinvokable]
+        fn run_workflow(
+            self: Pin<&mut WorkflowModel>,
+            repo_index: i32,
+            workflow_index: i32,
+            git_ref: &QString,
+            inputs_json: &QString,
+        ) -> bool;
+
         #[qsignal]
         fn workflows_changed(self: Pin<&mut WorkflowModel>);
+
+        #[qsignal]
+        fn inputs_changed(self: Pin<&mut WorkflowModel>);
+
+        #[qsignal]
+        fn workflow_dispatched(
+            self: Pin<&mut WorkflowModel>,
+            workflow_name: QString,
+            git_ref: QString,
+        );
     }
 }
 
@@ -76,7 +120,10 @@ pub struct WorkflowModelRust {
     authenticated: bool,
     error_message: QString,
     repo_workflows: Vec<RepoWorkflows>,
-    project_store: Option<Arc<parking_lot::Mutex<ProjectStore>>>,
+    project_store: Option<Arc<ProjectStore>>,
+    /// Dispatch inputs by (repo_id, workflow id); None when not dispatchable
+    inputs: HashMap<(String, i64), Option<Vec<WorkflowInput>>>,
+    pending: u32,
 }
 
 impl WorkflowModelRust {
@@ -117,6 +164,12 @@ impl WorkflowModelRust {
         }
         rw.workflows.get(workflow_index as usize)
     }
+
+    fn workflow_key(&self, repo_index: i32, workflow_index: i32) -> Option<(String, i64)> {
+        let rw = self.get_repo_workflows(repo_index)?;
+        let workflow = self.get_workflow(repo_index, workflow_index)?;
+        Some((rw.repo_id.clone(), workflow.id))
+    }
 }
 
 impl qobject::WorkflowModel {
@@ -131,7 +184,7 @@ impl qobject::WorkflowModel {
             }
         };
 
-        let repo_ids = match store.lock().list_all_linked_repo_ids() {
+        let repo_ids = match store.list_all_linked_repo_ids() {
             Ok(ids) => ids,
             Err(e) => {
                 self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
@@ -139,11 +192,22 @@ impl qobject::WorkflowModel {
             }
         };
 
+        let Some((client, tx)) = self.as_mut().begin_request() else {
+            return;
+        };
+        self.as_mut().rust_mut().clear_error();
+        request_fetch_workflows(&tx, client, repo_ids);
+    }
+
+    /// Client and channel for a request, counted until its reply is polled
+    fn begin_request(
+        mut self: Pin<&mut Self>,
+    ) -> Option<(Arc<GitHubClient>, std::sync::mpsc::Sender<WorkflowServiceMessage>)> {
         let (client, _runtime) = match bridge::get_github_client_and_runtime() {
             Some(pair) => pair,
             None => {
-                self.as_mut().set_error_message(QString::from("GitHub not authenticated"));
-                return;
+                self.as_mut().set_error_message(QString::from(tr("github-not-authenticated")));
+                return None;
             }
         };
 
@@ -153,39 +217,142 @@ impl qobject::WorkflowModel {
             None => {
                 self.as_mut()
                     .set_error_message(QString::from("Workflow service channel not ready"));
-                return;
+                return None;
             }
         };
 
+        self.as_mut().rust_mut().pending += 1;
         self.as_mut().set_loading(true);
+        Some((client, tx))
+    }
+
+    pub fn load_inputs(mut self: Pin<&mut Self>, repo_index: i32, workflow_index: i32) {
+        let (Some((repo_id, _)), Some(workflow)) = (
+            self.rust().workflow_key(repo_index, workflow_index),
+            self.rust().get_workflow(repo_index, workflow_index).cloned(),
+        ) else {
+            return;
+        };
+        if let Some((client, tx)) = self.as_mut().begin_request() {
+            request_workflow_inputs(&tx, client, repo_id, workflow);
+        }
+    }
+
+    pub fn get_inputs(&self, repo_index: i32, workflow_index: i32) -> QString {
+        let cached = self
+            .rust()
+            .workflow_key(repo_index, workflow_index)
+            .and_then(|key| self.rust().inputs.get(&key));
+        let json = match cached {
+            Some(Some(inputs)) => {
+                serde_json::json!({ "loaded": true, "dispatchable": true, "inputs": inputs })
+            }
+            Some(None) => {
+                serde_json::json!({ "loaded": true, "dispatchable": false, "inputs": [] })
+            }
+            None => serde_json::json!({ "loaded": false, "dispatchable": false, "inputs": [] }),
+        };
+        QString::from(&json.to_string())
+    }
+
+    pub fn run_workflow(
+        mut self: Pin<&mut Self>,
+        repo_index: i32,
+        workflow_index: i32,
+        git_ref: &QString,
+        inputs_json: &QString,
+    ) -> bool {
+        let (Some(key), Some(workflow)) = (
+            self.rust().workflow_key(repo_index, workflow_index),
+            self.rust().get_workflow(repo_index, workflow_index).cloned(),
+        ) else {
+            return false;
+        };
+        let Some(Some(inputs)) = self.rust().inputs.get(&key) else {
+            self.as_mut().set_error_message(QString::from("This workflow can't be run manually"));
+            return false;
+        };
+        let values: BTreeMap<String, String> =
+            serde_json::from_str(&inputs_json.to_string()).unwrap_or_default();
+        let values = match myme_services::dispatch_values(inputs, &values) {
+            Ok(values) => values,
+            Err(e) => {
+                self.as_mut().rust_mut().set_error(&e);
+                return false;
+            }
+        };
+        let Some((client, tx)) = self.as_mut().begin_request() else {
+            return false;
+        };
         self.as_mut().rust_mut().clear_error();
-        request_fetch_workflows(&tx, client, repo_ids);
+        request_workflow_dispatch(
+            &tx,
+            client,
+            key.0,
+            workflow,
+            git_ref.to_string().trim().to_string(),
+            values,
+        );
+        true
     }
 
     pub fn poll_channel(mut self: Pin<&mut Self>) {
-        let msg = match bridge::try_recv_workflow_message() {
-            Some(m) => m,
-            None => return,
-        };
+        while let Some(msg) = bridge::try_recv_workflow_message() {
+            // Remote changes arrive unasked; only replies settle a request
+            if !matches!(msg, WorkflowServiceMessage::RemoteChanged(_)) {
+                let pending = self.rust().pending.saturating_sub(1);
+                self.as_mut().rust_mut().pending = pending;
+                if pending == 0 {
+                    self.as_mut().set_loading(false);
+                }
+            }
+            self.as_mut().handle_message(msg);
+        }
+    }
 
+    fn handle_message(mut self: Pin<&mut Self>, msg: WorkflowServiceMessage) {
         match msg {
-            WorkflowServiceMessage::FetchWorkflowsDone(result) => {
-                self.as_mut().set_loading(false);
-                match result {
-                    Ok(mut data) => {
-                        for rw in &mut data {
-                            rw.workflows.sort_by(|a, b| {
-                                a.name.cmp(&b.name).then_with(|| a.path.cmp(&b.path))
-                            });
-                        }
-                        self.as_mut().rust_mut().repo_workflows = data;
-                        self.as_mut().workflows_changed();
-                    }
-                    Err(e) => {
-                        self.as_mut()
-                            .rust_mut()
-                            .set_error(myme_core::AppError::from(e).user_message());
+            WorkflowServiceMessage::FetchWorkflowsDone(result) => match result {
+                Ok(mut data) => {
+                    for rw in &mut data {
+                        rw.workflows
+                            .sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.path.cmp(&b.path)));
                     }
+                    self.as_mut().rust_mut().repo_workflows = data;
+                    self.as_mut().workflows_changed();
+                }
+                Err(e) => {
+                    self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                }
+            },
+            WorkflowServiceMessage::InputsDone { repo_id, workflow_id, result } => match result {
+                Ok(inputs) => {
+                    self.as_mut().rust_mut().inputs.insert((repo_id, workflow_id), inputs);
+                    self.as_mut().inputs_changed();
+                }
+                Err(e) => {
+                    self.as_mut().rust_mut().set_error(myme_core::AppError::from(e).user_message());
+                }
+            },
+            WorkflowServiceMessage::DispatchDone { repo_id, workflow_name, result } => match result
+            {
+                Ok(git_ref) => {
+                    tracing::info!("Dispatched {} in {} on {}", workflow_name, repo_id, git_ref);
+                    self.as_mut().workflow_dispatched(
+                        QString::from(&workflow_name),
+                        QString::from(&git_ref),
+                    );
+                }
+                // Shown as a toast by the service; the run dialog stays open to retry
+                Err(e) => {
+                    tracing::warn!("Failed to dispatch {} in {}: {}", workflow_name, repo_id, e)
+                }
+            },
+            WorkflowServiceMessage::RemoteChanged(repo_ids) => {
+                let shown =
+                    self.rust().repo_workflows.iter().any(|rw| repo_ids.contains(&rw.repo_id));
+                if shown && !*self.loading() {
+                    self.as_mut().fetch_workflows();
                 }
             }
         }
@@ -215,6 +382,23 @@ impl qobject::WorkflowModel {
         self.rust().get_repo_workflows(repo_index).map(|rw| rw.workflows.len() as i32).unwrap_or(0)
     }
 
+    pub fn get_alert_count(&self, repo_index: i32) -> i32 {
+        self.rust()
+            .get_repo_workflows(repo_index)
+            .and_then(|rw| rw.security.as_ref())
+            .map(|s| s.total() as i32)
+            .unwrap_or(0)
+    }
+
+    pub fn get_alert_severity(&self, repo_index: i32) -> QString {
+        self.rust()
+            .get_repo_workflows(repo_index)
+            .and_then(|rw| rw.security.as_ref())
+            .and_then(|s| s.highest())
+            .map(QString::from)
+            .unwrap_or_else(|| QString::from(""))
+    }
+
     pub fn get_workflow_name(&self, repo_index: i32, workflow_index: i32) -> QString {
         self.rust()
             .get_workflow(repo_index, workflow_index)
@@ -0,0 +1,133 @@
+//! Recording user-initiated changes in the activity log.
+//!
+//! Services call [`record`] after a change succeeds. Where the change can be
+//! reversed, an [`UndoHint`] is stored with the entry as JSON and the change
+//! is offered for undo.
+
+use myme_services::NewActivity;
+use serde::{Deserialize, Serialize};
+
+use super::undo_service;
+use crate::bridge;
+
+/// Longest summary title kept from a note or subject line.
+const TITLE_MAX_CHARS: usize = 60;
+
+/// How to reverse a recorded change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum UndoHint {
+    /// Move a note to the trash (reverses a create or restore)
+    NoteTrash { id: i64 },
+    /// Take a note out of the trash (reverses a trash)
+    NoteRestore { id: i64 },
+    /// Set a note's archived flag (reverses an archive or unarchive)
+    NoteSetArchived { id: i64, archived: bool },
+    /// Archive messages (reverses an unarchive)
+    GmailArchive { message_ids: Vec<String> },
+    /// Put messages back in the inbox (reverses an archive)
+    GmailUnarchive { message_ids: Vec<String> },
+    /// Move messages to the trash (reverses an untrash)
+    GmailTrash { message_ids: Vec<String> },
+    /// Take messages out of the trash (reverses a trash)
+    GmailUntrash { message_ids: Vec<String> },
+    /// Move a task back to its previous column and status
+    KanbanMove { task_id: String, column_id: Option<String>, status: String },
+}
+
+impl UndoHint {
+    /// Service the change belongs to, as recorded in the activity log.
+    pub fn service(&self) -> &'static str {
+        match self {
+            UndoHint::NoteTrash { .. }
+            | UndoHint::NoteRestore { .. }
+            | UndoHint::NoteSetArchived { .. } => "notes",
+            UndoHint::GmailArchive { .. }
+            | UndoHint::GmailUnarchive { .. }
+            | UndoHint::GmailTrash { .. }
+            | UndoHint::GmailUntrash { .. } => "gmail",
+            UndoHint::KanbanMove { .. } => "kanban",
+        }
+    }
+
+    /// Id of the changed item, as recorded in the activity log.
+    pub fn target(&self) -> String {
+        match self {
+            UndoHint::NoteTrash { id }
+            | UndoHint::NoteRestore { id }
+            | UndoHint::NoteSetArchived { id, .. } => id.to_string(),
+            UndoHint::GmailArchive { message_ids }
+            | UndoHint::GmailUnarchive { message_ids }
+            | UndoHint::GmailTrash { message_ids }
+            | UndoHint::GmailUntrash { message_ids } => message_ids.join(","),
+            UndoHint::KanbanMove { task_id, .. } => task_id.clone(),
+        }
+    }
+
+    /// Stored form.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Parse the stored form; `None` for hints this build doesn't understand.
+    pub fn from_json(stored: &str) -> Option<Self> {
+        serde_json::from_str(stored).ok()
+    }
+}
+
+/// First line of `text`, shortened for a one-line summary.
+pub fn short_title(text: &str) -> String {
+    let line = text.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("");
+    if line.chars().count() <= TITLE_MAX_CHARS {
+        return line.to_string();
+    }
+    let cut: String = line.chars().take(TITLE_MAX_CHARS - 1).collect();
+    format!("{}…", cut.trim_end())
+}
+
+/// Append a change to the activity log, and offer it for undo when `undo` is
+/// given. Failures are logged and otherwise ignored; a missing entry never
+/// blocks the change itself.
+pub fn record(service: &str, action: &str, target: &str, summary: &str, undo: Option<UndoHint>) {
+    if let Some(hint) = &undo {
+        undo_service::push(summary, hint.clone());
+    }
+    let Some(log) = bridge::get_activity_log_or_init() else {
+        return;
+    };
+    let entry = NewActivity {
+        service: service.to_string(),
+        action: action.to_string(),
+        target: target.to_string(),
+        summary: summary.to_string(),
+        undo_hint: undo.map(|h| h.to_json()),
+    };
+    if let Err(e) = log.record(&entry) {
+        tracing::warn!("Failed to record {} {} in activity log: {}", service, action, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn undo_hint_round_trips_tagged_json() {
+        let hint =
+            UndoHint::KanbanMove { task_id: "t1".into(), column_id: None, status: "todo".into() };
+        let json = hint.to_json();
+        assert!(json.contains(r#""op":"kanban_move""#));
+        assert_eq!(UndoHint::from_json(&json), Some(hint));
+        assert_eq!(UndoHint::from_json(r#"{"op":"unknown"}"#), None);
+    }
+
+    #[test]
+    fn short_title_uses_first_line() {
+        assert_eq!(short_title("\n  Buy milk \nand eggs"), "Buy milk");
+        let long = "x".repeat(100);
+        let title = short_title(&long);
+        assert_eq!(title.chars().count(), TITLE_MAX_CHARS);
+        assert!(title.ends_with('…'));
+    }
+}
@@ -0,0 +1,93 @@
+//! Bookmark backend: fetching page metadata for saved links.
+//! Bookmarks are saved straight to the local store so saving works offline;
+//! metadata fetches run off the UI thread and report back via mpsc.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use myme_core::connectivity;
+use myme_services::{BookmarkStore, MetadataFetcher};
+
+use crate::bridge;
+
+/// Error type for bookmark operations
+#[derive(Debug, Clone)]
+pub enum BookmarkError {
+    Network(String),
+    InvalidUrl(String),
+    Store(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for BookmarkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BookmarkError::Network(s) => write!(f, "Bookmark error: {}", s),
+            BookmarkError::InvalidUrl(s) => write!(f, "Not a web address: {}", s),
+            BookmarkError::Store(s) => write!(f, "Bookmark store error: {}", s),
+            BookmarkError::NotInitialized => write!(f, "Bookmark service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for BookmarkError {}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum BookmarkServiceMessage {
+    /// Page metadata fetched and stored; carries the bookmark id
+    MetadataDone(Result<i64, BookmarkError>),
+}
+
+fn fetcher() -> MetadataFetcher {
+    MetadataFetcher::new_with_proxies(
+        myme_core::Config::load_cached().proxy.proxies_for("bookmarks"),
+    )
+}
+
+/// Fetch the page behind bookmark `id` and store its title, excerpt and image.
+pub async fn fetch_metadata(
+    store: &BookmarkStore,
+    id: i64,
+    url: &str,
+) -> Result<(), BookmarkError> {
+    let meta = fetcher().fetch(url).await.map_err(|e| BookmarkError::Network(e.to_string()))?;
+    store.set_metadata(id, &meta, Utc::now()).map_err(|e| BookmarkError::Store(e.to_string()))
+}
+
+/// Request a metadata fetch for bookmark `id`. Sends `MetadataDone`.
+pub fn request_fetch_metadata(
+    tx: &std::sync::mpsc::Sender<BookmarkServiceMessage>,
+    store: Arc<BookmarkStore>,
+    id: i64,
+    url: String,
+) {
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(BookmarkServiceMessage::MetadataDone(Err(BookmarkError::NotInitialized)));
+        return;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(BookmarkServiceMessage::MetadataDone(Err(BookmarkError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let result = fetch_metadata(&store, id, &url).await.map(|()| id);
+        let _ = tx.send(BookmarkServiceMessage::MetadataDone(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn bookmark_error_display() {
+        assert!(format!("{}", BookmarkError::InvalidUrl("ftp://x".into())).contains("ftp://x"));
+        assert!(format!("{}", BookmarkError::NotInitialized).contains("not initialized"));
+    }
+}
@@ -0,0 +1,353 @@
+//! Daily suggestions for the dashboard: `[briefing]` rules checked against
+//! today's forecast, the evening's calendar and open outdoor tasks, e.g.
+//! "Bike commute OK until 16:00".
+
+use chrono::{Local, NaiveDate, NaiveTime, Timelike, Utc};
+use myme_calendar::{CalendarCache, DisplayZone, EventStatus};
+use myme_core::{BriefingConfig, SuggestionCondition, SuggestionRule};
+use myme_services::{ProjectColumn, ProjectStore, TaskStatus};
+use myme_weather::WeatherCache;
+
+use super::calendar_service::display_zone;
+use super::google_common::get_google_cache_path;
+use crate::bridge;
+
+/// What the rules can see of today
+#[derive(Debug, Clone, Default)]
+pub struct DayContext {
+    /// Local time of day
+    pub now: NaiveTime,
+    /// Chance of rain (percent) per forecast hour left today, ascending; empty
+    /// when there is no current forecast, so no weather condition holds
+    pub hourly: Vec<(NaiveTime, u8)>,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    /// Whether timed events overlap the evening window
+    pub evening_busy: bool,
+    /// Titles of open outdoor tasks
+    pub outdoor_tasks: Vec<String>,
+}
+
+/// Today as the rules see it, with the evening window and rain threshold
+/// applied
+struct Day<'a> {
+    ctx: &'a DayContext,
+    threshold: u8,
+    evening: Option<(NaiveTime, NaiveTime)>,
+}
+
+impl Day<'_> {
+    /// Hours from the current one on
+    fn ahead(&self) -> impl Iterator<Item = &(NaiveTime, u8)> {
+        let hour = self.ctx.now.hour();
+        self.ctx.hourly.iter().filter(move |(time, _)| time.hour() >= hour)
+    }
+
+    fn is_rainy(&self, chance: u8) -> bool {
+        chance >= self.threshold
+    }
+
+    fn raining_now(&self) -> Option<bool> {
+        self.ahead().next().map(|&(_, chance)| self.is_rainy(chance))
+    }
+
+    /// First rainy hour after the current one
+    fn rain_from(&self) -> Option<NaiveTime> {
+        self.ahead().skip(1).find(|&&(_, chance)| self.is_rainy(chance)).map(|&(time, _)| time)
+    }
+
+    /// Whether the evening is still ahead or under way
+    fn evening_left(&self) -> Option<(NaiveTime, NaiveTime)> {
+        self.evening.filter(|&(_, end)| self.ctx.now < end)
+    }
+
+    fn holds(&self, condition: SuggestionCondition) -> bool {
+        use SuggestionCondition::*;
+        let has_weather = !self.ctx.hourly.is_empty();
+        match condition {
+            DryNow => {
+                let mut next_two = self.ahead().take(2).peekable();
+                next_two.peek().is_some() && next_two.all(|&(_, chance)| !self.is_rainy(chance))
+            }
+            RainingNow => self.raining_now() == Some(true),
+            RainExpected => self.rain_from().is_some(),
+            DryToday => {
+                self.raining_now().is_some()
+                    && self.ahead().all(|&(_, chance)| !self.is_rainy(chance))
+            }
+            DryEvening => {
+                has_weather
+                    && self.evening_left().is_some_and(|(start, end)| {
+                        self.ctx
+                            .hourly
+                            .iter()
+                            .filter(|(time, _)| *time >= start && *time < end)
+                            .all(|&(_, chance)| !self.is_rainy(chance))
+                    })
+            }
+            FreeEvening => self.evening_left().is_some() && !self.ctx.evening_busy,
+            OutdoorTasks => !self.ctx.outdoor_tasks.is_empty(),
+        }
+    }
+
+    /// The rule's message with its placeholders filled, if all have a value
+    fn render(&self, rule: &SuggestionRule) -> Option<String> {
+        let temperature = |t: Option<f64>| t.map(|t| format!("{:.0}°", t));
+        let values = [
+            ("{rain_from}", self.rain_from().map(|t| t.format("%H:%M").to_string())),
+            ("{task}", self.ctx.outdoor_tasks.first().cloned()),
+            ("{task_count}", Some(self.ctx.outdoor_tasks.len().to_string())),
+            ("{high}", temperature(self.ctx.high)),
+            ("{low}", temperature(self.ctx.low)),
+        ];
+        let mut message = rule.message.clone();
+        for (placeholder, value) in values {
+            if message.contains(placeholder) {
+                message = message.replace(placeholder, &value?);
+            }
+        }
+        Some(message)
+    }
+}
+
+/// Messages of the rules that apply to `ctx`, in rule order
+pub fn evaluate(config: &BriefingConfig, ctx: &DayContext) -> Vec<String> {
+    let evening = myme_core::theme::parse_time_of_day(&config.evening_start)
+        .zip(myme_core::theme::parse_time_of_day(&config.evening_end));
+    let day = Day { ctx, threshold: config.rain_threshold, evening };
+    config
+        .rules
+        .iter()
+        .filter(|rule| rule.when.iter().all(|&condition| day.holds(condition)))
+        .filter_map(|rule| day.render(rule))
+        .collect()
+}
+
+/// Whether a task is tagged with one of `labels`: its column carries the
+/// label, or its title or description has it as a `#tag`.
+fn is_outdoor(
+    labels: &[String],
+    column: Option<&ProjectColumn>,
+    title: &str,
+    body: Option<&str>,
+) -> bool {
+    labels.iter().any(|label| {
+        let tag = format!("#{}", label.to_lowercase());
+        column.and_then(|c| c.label.as_deref()).is_some_and(|l| l.eq_ignore_ascii_case(label))
+            || [Some(title), body].into_iter().flatten().any(|text| {
+                text.to_lowercase()
+                    .split_whitespace()
+                    .any(|word| word.trim_end_matches(|c: char| c.is_ascii_punctuation()) == tag)
+            })
+    })
+}
+
+/// Whether timed events overlap today's evening window
+fn evening_busy(zone: &DisplayZone, today: NaiveDate, start: NaiveTime, end: NaiveTime) -> bool {
+    let cache = match CalendarCache::new(get_google_cache_path("calendar_cache.db")) {
+        Ok(cache) => cache,
+        Err(e) => {
+            tracing::warn!("Failed to open calendar cache for suggestions: {}", e);
+            return false;
+        }
+    };
+    let from = zone.to_utc(today.and_time(start));
+    let to = zone.to_utc(today.and_time(end));
+    match cache.list_visible_events(from, to, zone) {
+        Ok(events) => events.iter().any(|e| {
+            !e.all_day
+                && e.status != EventStatus::Cancelled
+                && !e.declined_by_self()
+                && e.end.instant_in(zone) > from
+        }),
+        Err(e) => {
+            tracing::warn!("Failed to read events for suggestions: {}", e);
+            false
+        }
+    }
+}
+
+/// Titles of open tasks tagged with one of `labels`, across active projects
+fn outdoor_tasks(store: &ProjectStore, labels: &[String]) -> anyhow::Result<Vec<String>> {
+    let mut titles = Vec::new();
+    for project in store.list_projects()? {
+        let columns = store.list_columns(&project.id)?;
+        for task in store.list_tasks_for_project(&project.id)? {
+            if task.status == TaskStatus::Done {
+                continue;
+            }
+            let column = ProjectColumn::for_task(&columns, &task);
+            if is_outdoor(labels, column, &task.title, task.body.as_deref()) {
+                titles.push(task.title);
+            }
+        }
+    }
+    Ok(titles)
+}
+
+/// Today's context from the weather cache, calendar cache and project store
+pub fn gather(config: &myme_core::Config) -> DayContext {
+    let briefing = &config.briefing;
+    // Forecast hours are local to where the forecast was fetched, so the
+    // weather conditions use the system clock; the evening's events are read
+    // in the calendar's display zone.
+    let local = Local::now().naive_local();
+    let mut ctx = DayContext { now: local.time(), ..DayContext::default() };
+
+    let mut cache = WeatherCache::new(&config.config_dir);
+    match cache.load() {
+        Ok(()) if !cache.is_stale() => {
+            let today = cache.get().and_then(|data| {
+                data.forecast.iter().find(|day| day.date == local.date()).cloned()
+            });
+            if let Some(day) = today {
+                ctx.hourly = day.hourly.iter().map(|h| (h.time, h.precipitation_chance)).collect();
+                ctx.high = Some(day.high);
+                ctx.low = Some(day.low);
+            }
+        }
+        Ok(()) => {}
+        Err(e) => tracing::debug!("No weather for suggestions: {}", e),
+    }
+
+    let start = myme_core::theme::parse_time_of_day(&briefing.evening_start);
+    let end = myme_core::theme::parse_time_of_day(&briefing.evening_end);
+    if let Some((start, end)) = start.zip(end) {
+        let zone = display_zone();
+        let today = zone.today(Utc::now());
+        ctx.evening_busy = evening_busy(&zone, today, start, end);
+    }
+
+    if let Some(store) = bridge::get_project_store_or_init() {
+        match outdoor_tasks(&store, &briefing.outdoor_labels) {
+            Ok(titles) => ctx.outdoor_tasks = titles,
+            Err(e) => tracing::warn!("Failed to read tasks for suggestions: {}", e),
+        }
+    }
+    ctx
+}
+
+/// Today's suggestions, or none when `[briefing] enabled` is off
+pub fn suggestions() -> Vec<String> {
+    let config = myme_core::Config::load_cached();
+    if !config.briefing.enabled {
+        return Vec::new();
+    }
+    evaluate(&config.briefing, &gather(&config))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn at(time: &str) -> NaiveTime {
+        NaiveTime::parse_from_str(time, "%H:%M").unwrap()
+    }
+
+    /// Hourly chances from 08:00 on
+    fn day(now: &str, chances: &[u8]) -> DayContext {
+        DayContext {
+            now: at(now),
+            hourly: chances
+                .iter()
+                .enumerate()
+                .map(|(i, &chance)| (NaiveTime::from_hms_opt(8 + i as u32, 0, 0).unwrap(), chance))
+                .collect(),
+            high: Some(21.4),
+            low: Some(9.0),
+            ..DayContext::default()
+        }
+    }
+
+    #[test]
+    fn default_rules_follow_the_forecast() {
+        let config = BriefingConfig::default();
+        // Dry until 16:00
+        let ctx = day("08:20", &[0, 10, 10, 20, 20, 30, 40, 40, 80, 90, 60, 20, 10, 0]);
+        assert_eq!(evaluate(&config, &ctx), ["Bike commute OK until 16:00"]);
+
+        let ctx = day("08:20", &[0; 14]);
+        assert_eq!(
+            evaluate(&config, &ctx),
+            ["No rain expected for the rest of the day (high 21°)"]
+        );
+
+        // Past hours don't count
+        let ctx = day("10:05", &[90, 90, 10, 10, 10]);
+        assert_eq!(
+            evaluate(&config, &ctx),
+            ["No rain expected for the rest of the day (high 21°)"]
+        );
+
+        let ctx = day("09:30", &[0, 70, 70, 10]);
+        assert_eq!(evaluate(&config, &ctx), ["Rain likely now, take an umbrella"]);
+
+        // No forecast, no weather suggestions
+        assert!(
+            evaluate(&config, &DayContext { now: at("09:00"), ..DayContext::default() }).is_empty()
+        );
+    }
+
+    #[test]
+    fn evening_rule_needs_free_dry_evening_and_outdoor_task() {
+        let config = BriefingConfig {
+            rules: BriefingConfig::default().rules[3..].to_vec(),
+            ..BriefingConfig::default()
+        };
+        let mut ctx = day("12:00", &[0; 15]);
+        assert!(evaluate(&config, &ctx).is_empty());
+
+        ctx.outdoor_tasks = vec!["Clean the gutters".to_string(), "Mow".to_string()];
+        assert_eq!(evaluate(&config, &ctx), ["Free, dry evening: time for \"Clean the gutters\""]);
+
+        ctx.evening_busy = true;
+        assert!(evaluate(&config, &ctx).is_empty());
+        ctx.evening_busy = false;
+
+        // Rain at 19:00
+        ctx.hourly[11].1 = 60;
+        assert!(evaluate(&config, &ctx).is_empty());
+        ctx.hourly[11].1 = 0;
+
+        // Evening over
+        ctx.now = at("22:10");
+        assert!(evaluate(&config, &ctx).is_empty());
+    }
+
+    #[test]
+    fn custom_rules_and_placeholders() {
+        let config: BriefingConfig = toml::from_str(
+            r#"
+            rain_threshold = 30
+            [[rules]]
+            when = ["outdoor_tasks"]
+            message = "{task_count} outdoor tasks, low {low}"
+            [[rules]]
+            when = []
+            message = "Rain from {rain_from}"
+            "#,
+        )
+        .unwrap();
+        let mut ctx = day("08:00", &[0, 35]);
+        ctx.outdoor_tasks = vec!["Mow".to_string()];
+        assert_eq!(evaluate(&config, &ctx), ["1 outdoor tasks, low 9°", "Rain from 09:00"]);
+
+        // A placeholder without a value skips the rule
+        let ctx = day("08:00", &[0, 0]);
+        assert!(evaluate(&config, &ctx).is_empty());
+    }
+
+    #[test]
+    fn outdoor_tasks_by_tag_or_column_label() {
+        let labels = vec!["outdoor".to_string(), "garden".to_string()];
+        assert!(is_outdoor(&labels, None, "Fix fence #outdoor", None));
+        assert!(is_outdoor(&labels, None, "Weed", Some("Before June. #Garden.")));
+        assert!(!is_outdoor(&labels, None, "Read about #outdoorsy gear", None));
+        assert!(!is_outdoor(&labels, None, "outdoor", None));
+
+        let mut column = ProjectColumn::defaults("p").remove(0);
+        column.label = Some("Outdoor".to_string());
+        assert!(is_outdoor(&labels, Some(&column), "Paint shed", None));
+    }
+}
@@ -2,12 +2,27 @@
 //! All network work runs off the UI thread; results sent via mpsc.
 
 use std::path::PathBuf;
+use std::time::Instant;
 
-use chrono::{Duration, Utc};
-use myme_calendar::{Calendar, CalendarCache, CalendarClient, Event};
+use chrono::{DateTime, Duration, Utc};
+use myme_calendar::{
+    Calendar, CalendarCache, CalendarClient, DisplayZone, DueReminder, Event, Reminder,
+};
+use myme_core::connectivity;
+use myme_services::RetryConfig;
 
+use super::focus_service::notify_desktop;
+use super::google_common::get_google_cache_path;
+use super::schedule_service;
 use crate::bridge;
 
+/// How far back a full sync reaches; incremental syncs then track changes.
+const FULL_SYNC_PAST_DAYS: i64 = 30;
+
+/// Reminders missed by more than this (app closed, machine asleep) are dropped
+/// rather than shown late.
+const REMINDER_GRACE_MINUTES: i64 = 5;
+
 /// Error type for Calendar operations.
 #[derive(Debug, Clone)]
 pub enum CalendarError {
@@ -35,6 +50,32 @@ pub enum CalendarServiceMessage {
     FetchEventsDone(Result<Vec<Event>, CalendarError>),
     /// Result of fetching calendar list.
     FetchCalendarsDone(Result<Vec<Calendar>, CalendarError>),
+    /// Result of creating an event.
+    CreateEventDone(Result<Event, CalendarError>),
+    /// Result of changing an event's reminders.
+    UpdateRemindersDone(Result<Event, CalendarError>),
+}
+
+/// Calendar API client using the configured proxy, timeout, retries, HTTP debug
+/// capture and metrics.
+pub(crate) fn calendar_client(access_token: &str) -> CalendarClient {
+    let config = myme_core::Config::load_cached();
+    let network = config.network.for_service("calendar");
+    let proxies = config.proxy.proxies_for("calendar");
+    CalendarClient::new_with_timeout(access_token, proxies, network.timeout)
+        .with_retry_config(RetryConfig {
+            max_retries: network.max_retries,
+            initial_delay: network.backoff_base,
+            max_delay: network.max_backoff,
+        })
+        .with_observer(myme_core::metrics::http_observer("calendar"))
+}
+
+/// Zone calendar times are shown in: `[calendar] display_timezone`, else the
+/// system zone. Read fresh so a change in settings applies without a restart.
+pub fn display_zone() -> DisplayZone {
+    let name = myme_core::Config::load().ok().and_then(|c| c.calendar.display_timezone);
+    DisplayZone::from_config(name.as_deref())
 }
 
 /// Request to fetch events for the next 7 days.
@@ -52,23 +93,41 @@ pub fn request_fetch_events(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let _ = tx.send(CalendarServiceMessage::FetchEventsDone(Err(CalendarError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
 
     runtime.spawn(async move {
-        let client = CalendarClient::new(&access_token);
+        let started = Instant::now();
+        let client = calendar_client(&access_token);
         let time_min = Utc::now();
         let time_max = time_min + Duration::days(7);
 
-        let result = client
-            .list_events("primary", time_min, time_max, None)
-            .await
-            .map_err(|e| CalendarError::Network(e.to_string()))
-            .map(|response| {
-                response
-                    .items
-                    .into_iter()
-                    .map(|api_event| Event::from_api(api_event, "primary"))
-                    .collect::<Vec<Event>>()
-            });
+        match client.list_calendars().await {
+            Ok(calendars) => {
+                if let Ok(cache) = CalendarCache::new(&cache_path) {
+                    for calendar in &calendars {
+                        let _ = cache.store_calendar(calendar);
+                    }
+                }
+                // Cached rows carry the visibility and colors chosen locally
+                let calendars = CalendarCache::new(&cache_path)
+                    .and_then(|cache| cache.list_calendars())
+                    .unwrap_or(calendars);
+                let _ = tx.send(CalendarServiceMessage::FetchCalendarsDone(Ok(calendars)));
+            }
+            Err(e) => tracing::warn!("Failed to list calendars: {}", e),
+        }
+
+        let result = sync_week(&client, &cache_path, time_min, time_max).await;
+        // Time blocks for tasks pick up completion made on either side
+        let result = match result {
+            Ok(events) => Ok(schedule_service::sync_scheduled_events(&client, events).await),
+            Err(e) => Err(e),
+        };
 
         if let Ok(ref events) = result {
             if let Ok(cache) = CalendarCache::new(&cache_path) {
@@ -78,10 +137,123 @@ pub fn request_fetch_events(
             }
         }
 
+        myme_core::metrics::record_sync("calendar_week", started.elapsed(), result.is_ok());
         let _ = tx.send(CalendarServiceMessage::FetchEventsDone(result));
     });
 }
 
+/// Bring every visible calendar's cache up to date and return their events in
+/// `[time_min, time_max)`. Only changes since the last sync are transferred;
+/// the first sync of a calendar, or one after Google expires its token, lists
+/// everything from `FULL_SYNC_PAST_DAYS` ago. Hidden calendars aren't synced.
+async fn sync_week(
+    client: &CalendarClient,
+    cache_path: &std::path::Path,
+    time_min: DateTime<Utc>,
+    time_max: DateTime<Utc>,
+) -> Result<Vec<Event>, CalendarError> {
+    let zone = display_zone();
+    // Like the API's timeMin: events still running at `time_min` count
+    let in_window = |e: &Event| e.end.instant_in(&zone) > time_min;
+    let full_sync_from = time_min - Duration::days(FULL_SYNC_PAST_DAYS);
+
+    let Ok(cache) = CalendarCache::new(cache_path) else {
+        // No cache, so no tokens or calendar list: list the primary calendar
+        let sync = client
+            .sync_events("primary", None, full_sync_from)
+            .await
+            .map_err(|e| CalendarError::Network(e.to_string()))?;
+        return Ok(sync
+            .events
+            .into_iter()
+            .filter(|e| in_window(e) && e.start.instant_in(&zone) < time_max)
+            .collect());
+    };
+
+    let mut calendar_ids: Vec<String> = cache
+        .list_calendars()
+        .unwrap_or_default()
+        .iter()
+        .filter(|c| c.visible)
+        .map(|c| c.event_calendar_id().to_string())
+        .collect();
+    let hidden = cache.hidden_calendar_ids().unwrap_or_default();
+    if calendar_ids.is_empty() && hidden.is_empty() {
+        // Calendar list not fetched yet
+        calendar_ids.push("primary".to_string());
+    }
+
+    for calendar_id in &calendar_ids {
+        let token = cache.sync_token(calendar_id).ok().flatten();
+        let sync = match client.sync_events(calendar_id, token.as_deref(), full_sync_from).await {
+            Ok(sync) => sync,
+            // A shared calendar failing shouldn't hide the user's own events
+            Err(e) if calendar_id != "primary" => {
+                tracing::warn!("Failed to sync calendar {}: {}", calendar_id, e);
+                continue;
+            }
+            Err(e) => return Err(CalendarError::Network(e.to_string())),
+        };
+        tracing::debug!(
+            calendar = %calendar_id,
+            full = sync.full,
+            changed = sync.events.len(),
+            deleted = sync.cancelled.len(),
+            "Calendar sync"
+        );
+        cache.apply_sync(calendar_id, &sync).map_err(|e| CalendarError::Network(e.to_string()))?;
+    }
+
+    let mut events = cache
+        .list_visible_events(time_min - Duration::days(1), time_max, &zone)
+        .map_err(|e| CalendarError::Network(e.to_string()))?;
+    events.retain(in_window);
+    Ok(events)
+}
+
+/// Request to create an event on the primary calendar, inviting `attendees`.
+/// The created event is cached so it shows up in search and contacts.
+pub fn request_create_event(
+    tx: &std::sync::mpsc::Sender<CalendarServiceMessage>,
+    access_token: String,
+    cache_path: PathBuf,
+    summary: String,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    attendees: Vec<String>,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx
+                .send(CalendarServiceMessage::CreateEventDone(Err(CalendarError::NotInitialized)));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(CalendarServiceMessage::CreateEventDone(Err(CalendarError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let result = calendar_client(&access_token)
+            .create_event("primary", &summary, start, end, None, None, &attendees)
+            .await
+            .map_err(|e| CalendarError::Network(e.to_string()));
+
+        if let Ok(ref event) = result {
+            if let Ok(cache) = CalendarCache::new(&cache_path) {
+                let _ = cache.store_event(event);
+            }
+        }
+
+        let _ = tx.send(CalendarServiceMessage::CreateEventDone(result));
+    });
+}
+
 /// Request to fetch events for today only.
 pub fn request_fetch_today_events(
     tx: &std::sync::mpsc::Sender<CalendarServiceMessage>,
@@ -96,18 +268,18 @@ pub fn request_fetch_today_events(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let _ = tx.send(CalendarServiceMessage::FetchEventsDone(Err(CalendarError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
 
     runtime.spawn(async move {
-        let client = CalendarClient::new(&access_token);
-        let today = Utc::now().date_naive();
-        let time_min = match today.and_hms_opt(0, 0, 0) {
-            Some(t) => t.and_utc(),
-            None => return,
-        };
-        let time_max = match today.and_hms_opt(23, 59, 59) {
-            Some(t) => t.and_utc(),
-            None => return,
-        };
+        let started = Instant::now();
+        let client = calendar_client(&access_token);
+        let zone = display_zone();
+        let (time_min, time_max) = zone.day_range(zone.today(Utc::now()));
 
         let result = client
             .list_events("primary", time_min, time_max, None)
@@ -121,6 +293,149 @@ pub fn request_fetch_today_events(
                     .collect::<Vec<Event>>()
             });
 
+        myme_core::metrics::record_sync("calendar_today", started.elapsed(), result.is_ok());
         let _ = tx.send(CalendarServiceMessage::FetchEventsDone(result));
     });
 }
+
+/// Request to change an event's reminders: `None` follows the calendar's
+/// defaults, otherwise the list replaces them. The updated event is cached
+/// so the reminder scheduler picks up the change.
+pub fn request_update_reminders(
+    tx: &std::sync::mpsc::Sender<CalendarServiceMessage>,
+    access_token: String,
+    cache_path: PathBuf,
+    calendar_id: String,
+    event_id: String,
+    reminders: Option<Vec<Reminder>>,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(CalendarServiceMessage::UpdateRemindersDone(Err(
+                CalendarError::NotInitialized,
+            )));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(CalendarServiceMessage::UpdateRemindersDone(Err(CalendarError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let result = calendar_client(&access_token)
+            .update_event(
+                &calendar_id,
+                &event_id,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(reminders.as_deref()),
+            )
+            .await
+            .map_err(|e| CalendarError::Network(e.to_string()));
+
+        if let Ok(ref event) = result {
+            if let Ok(cache) = CalendarCache::new(&cache_path) {
+                let _ = cache.store_event(event);
+            }
+        }
+
+        let _ = tx.send(CalendarServiceMessage::UpdateRemindersDone(result));
+    });
+}
+
+/// Raise a desktop notification for every popup reminder that came due since
+/// the last check, from the cached events. Returns how many were shown.
+pub fn notify_due_reminders() -> usize {
+    let cache = match CalendarCache::new(get_google_cache_path("calendar_cache.db")) {
+        Ok(cache) => cache,
+        Err(e) => {
+            tracing::warn!("Failed to open calendar cache for reminders: {}", e);
+            return 0;
+        }
+    };
+    let now = Utc::now();
+    let earliest = now - Duration::minutes(REMINDER_GRACE_MINUTES);
+    // First run starts from now rather than replaying the grace window
+    let after = match cache.reminders_checked_at() {
+        Ok(Some(checked)) => checked.max(earliest),
+        Ok(None) => now,
+        Err(e) => {
+            tracing::warn!("Failed to read reminder state: {}", e);
+            return 0;
+        }
+    };
+
+    let due = match cache.due_reminders(after, now, &display_zone()) {
+        Ok(due) => due,
+        Err(e) => {
+            tracing::warn!("Failed to read due reminders: {}", e);
+            return 0;
+        }
+    };
+    if let Err(e) = cache.set_reminders_checked_at(now) {
+        tracing::warn!("Failed to record reminder check: {}", e);
+    }
+    for reminder in &due {
+        notify_desktop(&reminder.event.summary, &reminder_body(reminder));
+    }
+    if !due.is_empty() {
+        tracing::info!("Showed {} calendar reminder(s)", due.len());
+    }
+    due.len()
+}
+
+/// Notification text for a reminder: when the event starts, and where.
+fn reminder_body(reminder: &DueReminder) -> String {
+    let event = &reminder.event;
+    let when = if event.all_day {
+        "Today".to_string()
+    } else {
+        match reminder.minutes_before {
+            0 => "Starting now".to_string(),
+            m if m < 60 => format!("In {} min", m),
+            m if m % (24 * 60) == 0 => format!("In {} day(s)", m / (24 * 60)),
+            m if m % 60 == 0 => format!("In {} h", m / 60),
+            m => format!("In {} h {} min", m / 60, m % 60),
+        }
+    };
+    match event.location.as_deref().filter(|l| !l.is_empty()) {
+        Some(location) => format!("{} · {}", when, location),
+        None => when,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn reminder_body_describes_lead_time_and_place() {
+        let json = r#"{
+            "id": "standup",
+            "summary": "Standup",
+            "location": "Room 4",
+            "start": {"dateTime": "2024-02-01T10:00:00Z"}
+        }"#;
+        let event = Event::from_api(serde_json::from_str(json).unwrap(), "primary");
+        let due = |minutes_before| DueReminder {
+            event: event.clone(),
+            minutes_before,
+            fire_at: Utc::now(),
+        };
+
+        assert_eq!(reminder_body(&due(0)), "Starting now · Room 4");
+        assert_eq!(reminder_body(&due(10)), "In 10 min · Room 4");
+        assert_eq!(reminder_body(&due(90)), "In 1 h 30 min · Room 4");
+        assert_eq!(reminder_body(&due(120)), "In 2 h · Room 4");
+        assert_eq!(reminder_body(&due(2 * 24 * 60)), "In 2 day(s) · Room 4");
+    }
+}
@@ -0,0 +1,398 @@
+//! Quick-capture syntax.
+//!
+//! - `todo: Buy milk` (or plain text) creates a note
+//! - `issue owner/repo: Title` creates a GitHub issue; extra lines become the body
+//! - `event [today|tomorrow|<weekday>] 3pm [45m|1h30m]: Title` creates a calendar event;
+//!   `+name@example.com` words in the title invite attendees
+//! - `bookmark example.com/post #tag` (or a bare `https://` link) saves a bookmark
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use myme_services::bookmark::{normalize_tag, normalize_url};
+
+/// Default event length when no duration is given
+pub const DEFAULT_EVENT_MINUTES: i64 = 60;
+
+/// Where a captured string should go
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureTarget {
+    Note { content: String },
+    Issue { owner: String, repo: String, title: String, body: Option<String> },
+    Event { title: String, start: DateTime<Utc>, end: DateTime<Utc>, attendees: Vec<String> },
+    Bookmark { url: String, tags: Vec<String> },
+}
+
+impl CaptureTarget {
+    /// Short kind name for the UI ("note", "issue", "event", "bookmark")
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CaptureTarget::Note { .. } => "note",
+            CaptureTarget::Issue { .. } => "issue",
+            CaptureTarget::Event { .. } => "event",
+            CaptureTarget::Bookmark { .. } => "bookmark",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureParseError {
+    Empty,
+    MissingTitle,
+    InvalidRepo(String),
+    InvalidTime(String),
+    InvalidUrl(String),
+}
+
+impl std::fmt::Display for CaptureParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureParseError::Empty => write!(f, "Nothing to capture"),
+            CaptureParseError::MissingTitle => write!(f, "Missing text after ':'"),
+            CaptureParseError::InvalidRepo(s) => write!(f, "Expected owner/repo, got '{}'", s),
+            CaptureParseError::InvalidTime(s) => write!(f, "Could not understand time '{}'", s),
+            CaptureParseError::InvalidUrl(s) => write!(f, "Not a web address: '{}'", s),
+        }
+    }
+}
+
+impl std::error::Error for CaptureParseError {}
+
+/// Strip a case-insensitive keyword prefix followed by whitespace or ':'.
+fn strip_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    let head = text.get(..keyword.len())?;
+    if !head.eq_ignore_ascii_case(keyword) {
+        return None;
+    }
+    let rest = &text[keyword.len()..];
+    rest.starts_with([':', ' ', '\t']).then_some(rest)
+}
+
+/// Split "spec: title" at the first ':' that is not part of a clock time like "9:30".
+fn split_title(rest: &str) -> Option<(&str, &str)> {
+    let idx = rest.char_indices().find_map(|(i, c)| {
+        let next_is_digit = rest[i + 1..].starts_with(|n: char| n.is_ascii_digit());
+        (c == ':' && !next_is_digit).then_some(i)
+    })?;
+    Some((&rest[..idx], &rest[idx + 1..]))
+}
+
+/// Parse capture text relative to `now` (whose time zone is used for event times).
+pub fn parse_capture<Tz: TimeZone>(
+    text: &str,
+    now: &DateTime<Tz>,
+) -> Result<CaptureTarget, CaptureParseError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(CaptureParseError::Empty);
+    }
+
+    if let Some(rest) = strip_keyword(text, "todo") {
+        let content = rest.trim_start().strip_prefix(':').unwrap_or(rest).trim();
+        if content.is_empty() {
+            return Err(CaptureParseError::MissingTitle);
+        }
+        return Ok(CaptureTarget::Note { content: content.to_string() });
+    }
+
+    if let Some(rest) = strip_keyword(text, "issue") {
+        let (spec, title) = split_title(rest).ok_or(CaptureParseError::MissingTitle)?;
+        let spec = spec.trim();
+        let (owner, repo) = spec
+            .split_once('/')
+            .filter(|(o, r)| !o.is_empty() && !r.is_empty() && !r.contains('/'))
+            .ok_or_else(|| CaptureParseError::InvalidRepo(spec.to_string()))?;
+        let title = title.trim();
+        let (title, body) = match title.split_once('\n') {
+            Some((first, rest)) => (first.trim(), Some(rest.trim().to_string())),
+            None => (title, None),
+        };
+        if title.is_empty() {
+            return Err(CaptureParseError::MissingTitle);
+        }
+        return Ok(CaptureTarget::Issue {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            title: title.to_string(),
+            body: body.filter(|b| !b.is_empty()),
+        });
+    }
+
+    if let Some(rest) = strip_keyword(text, "event") {
+        let (spec, title) = split_title(rest).ok_or(CaptureParseError::MissingTitle)?;
+        let (title, attendees) = split_attendees(title);
+        if title.is_empty() {
+            return Err(CaptureParseError::MissingTitle);
+        }
+        let (start, end) = parse_when(spec.trim(), now)?;
+        return Ok(CaptureTarget::Event { title, start, end, attendees });
+    }
+
+    if let Some(rest) = strip_keyword(text, "bookmark") {
+        return parse_bookmark(rest.trim_start().strip_prefix(':').unwrap_or(rest));
+    }
+
+    let mut words = text.split_whitespace().filter(|w| !w.starts_with('#'));
+    if let (Some(word), None) = (words.next(), words.next()) {
+        if word.starts_with("http://") || word.starts_with("https://") {
+            return parse_bookmark(text);
+        }
+    }
+
+    Ok(CaptureTarget::Note { content: text.to_string() })
+}
+
+/// Parse "<url> [#tag ...]".
+fn parse_bookmark(rest: &str) -> Result<CaptureTarget, CaptureParseError> {
+    let (tags, words): (Vec<&str>, Vec<&str>) =
+        rest.split_whitespace().partition(|w| w.starts_with('#'));
+    let url = match words.as_slice() {
+        [] => return Err(CaptureParseError::MissingTitle),
+        [url] => {
+            normalize_url(url).ok_or_else(|| CaptureParseError::InvalidUrl(url.to_string()))?
+        }
+        _ => return Err(CaptureParseError::InvalidUrl(words.join(" "))),
+    };
+    let tags = tags.into_iter().filter_map(normalize_tag).collect();
+    Ok(CaptureTarget::Bookmark { url, tags })
+}
+
+/// Pull `+address@domain` words out of an event title as attendees.
+fn split_attendees(title: &str) -> (String, Vec<String>) {
+    let (attendees, words): (Vec<&str>, Vec<&str>) = title
+        .split_whitespace()
+        .partition(|w| w.strip_prefix('+').is_some_and(|a| a.contains('@')));
+    let attendees = attendees.iter().map(|a| a[1..].to_lowercase()).collect();
+    (words.join(" "), attendees)
+}
+
+/// Parse "[day] time [duration]" into a UTC start and end.
+fn parse_when<Tz: TimeZone>(
+    spec: &str,
+    now: &DateTime<Tz>,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), CaptureParseError> {
+    let invalid = || CaptureParseError::InvalidTime(spec.to_string());
+    let today = now.date_naive();
+
+    let mut date = None;
+    let mut time = None;
+    let mut duration = None;
+    for token in spec.split_whitespace() {
+        let lower = token.to_ascii_lowercase();
+        if date.is_none() && time.is_none() {
+            if let Some(d) = parse_day(&lower, today) {
+                date = Some(d);
+                continue;
+            }
+        }
+        if time.is_none() {
+            time = Some(parse_time(&lower).ok_or_else(invalid)?);
+        } else if duration.is_none() {
+            duration = Some(parse_duration(&lower).ok_or_else(invalid)?);
+        } else {
+            return Err(invalid());
+        }
+    }
+    let time = time.ok_or_else(invalid)?;
+
+    // Without a day, a time that already passed means tomorrow
+    let date =
+        date.unwrap_or_else(|| if time < now.time() { today + Duration::days(1) } else { today });
+    let start = now
+        .timezone()
+        .from_local_datetime(&date.and_time(time))
+        .earliest()
+        .ok_or_else(invalid)?
+        .with_timezone(&Utc);
+    let end = start + duration.unwrap_or(Duration::minutes(DEFAULT_EVENT_MINUTES));
+    Ok((start, end))
+}
+
+fn parse_day(token: &str, today: NaiveDate) -> Option<NaiveDate> {
+    match token {
+        "today" => return Some(today),
+        "tomorrow" | "tmrw" => return Some(today + Duration::days(1)),
+        _ => {}
+    }
+    let weekday: Weekday = token.parse().ok()?;
+    let ahead = (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+    // "monday" on a Monday means next week
+    let ahead = if ahead == 0 { 7 } else { ahead };
+    Some(today + Duration::days(ahead as i64))
+}
+
+/// "3pm", "3:30pm", "15:00", "noon"
+fn parse_time(token: &str) -> Option<NaiveTime> {
+    match token {
+        "noon" => return NaiveTime::from_hms_opt(12, 0, 0),
+        "midnight" => return NaiveTime::from_hms_opt(0, 0, 0),
+        _ => {}
+    }
+    let (digits, meridiem) = if let Some(d) = token.strip_suffix("am") {
+        (d, Some(false))
+    } else if let Some(d) = token.strip_suffix("pm") {
+        (d, Some(true))
+    } else {
+        (token, None)
+    };
+    let (h, m) = match digits.split_once(':') {
+        Some((h, m)) => (h.parse::<u32>().ok()?, m.parse::<u32>().ok()?),
+        // A bare number is only a time with am/pm ("3pm"), not "15"
+        None if meridiem.is_some() => (digits.parse::<u32>().ok()?, 0),
+        None => return None,
+    };
+    let hour = match meridiem {
+        Some(pm) => {
+            if !(1..=12).contains(&h) {
+                return None;
+            }
+            (h % 12) + if pm { 12 } else { 0 }
+        }
+        None => h,
+    };
+    NaiveTime::from_hms_opt(hour, m, 0)
+}
+
+/// "45m", "2h", "1h30m"
+fn parse_duration(token: &str) -> Option<Duration> {
+    let mut minutes = 0i64;
+    let mut rest = token;
+    if let Some((h, tail)) = rest.split_once('h') {
+        minutes += h.parse::<i64>().ok()? * 60;
+        rest = tail;
+    }
+    if let Some(m) = rest.strip_suffix('m').or(rest.strip_suffix("min")) {
+        minutes += m.parse::<i64>().ok()?;
+    } else if !rest.is_empty() {
+        return None;
+    }
+    (minutes > 0).then(|| Duration::minutes(minutes))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::FixedOffset;
+
+    /// Wednesday 2026-01-21 10:00 at UTC-5
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2026-01-21T10:00:00-05:00").unwrap()
+    }
+
+    #[test]
+    fn test_todo_and_plain_text_become_notes() {
+        assert_eq!(
+            parse_capture("TODO: Buy milk", &now()).unwrap(),
+            CaptureTarget::Note { content: "Buy milk".into() }
+        );
+        assert_eq!(
+            parse_capture("remember the keys", &now()).unwrap(),
+            CaptureTarget::Note { content: "remember the keys".into() }
+        );
+        // A word that merely starts with "todo" is plain text
+        assert_eq!(
+            parse_capture("todos are piling up", &now()).unwrap(),
+            CaptureTarget::Note { content: "todos are piling up".into() }
+        );
+        assert_eq!(parse_capture("todo:", &now()), Err(CaptureParseError::MissingTitle));
+        assert_eq!(parse_capture("   ", &now()), Err(CaptureParseError::Empty));
+    }
+
+    #[test]
+    fn test_issue() {
+        let target = parse_capture("issue jonesrussell/myme: Fix login\nSteps: open app", &now());
+        assert_eq!(
+            target.unwrap(),
+            CaptureTarget::Issue {
+                owner: "jonesrussell".into(),
+                repo: "myme".into(),
+                title: "Fix login".into(),
+                body: Some("Steps: open app".into()),
+            }
+        );
+        assert!(matches!(
+            parse_capture("issue myme: Fix", &now()),
+            Err(CaptureParseError::InvalidRepo(_))
+        ));
+        assert_eq!(parse_capture("issue a/b", &now()), Err(CaptureParseError::MissingTitle));
+    }
+
+    #[test]
+    fn test_event_times() {
+        let CaptureTarget::Event { title, start, end, attendees } =
+            parse_capture("event 3pm: Standup", &now()).unwrap()
+        else {
+            panic!("expected event");
+        };
+        assert_eq!(title, "Standup");
+        assert!(attendees.is_empty());
+        assert_eq!(start.to_rfc3339(), "2026-01-21T20:00:00+00:00");
+        assert_eq!(end - start, Duration::minutes(DEFAULT_EVENT_MINUTES));
+
+        // Past time rolls to tomorrow; explicit duration
+        let CaptureTarget::Event { start, end, .. } =
+            parse_capture("event 9:30am 45m: Review", &now()).unwrap()
+        else {
+            panic!("expected event");
+        };
+        assert_eq!(start.to_rfc3339(), "2026-01-22T14:30:00+00:00");
+        assert_eq!(end - start, Duration::minutes(45));
+
+        // Weekday and 24h clock
+        let CaptureTarget::Event { start, end, .. } =
+            parse_capture("event friday 15:00 1h30m: Demo", &now()).unwrap()
+        else {
+            panic!("expected event");
+        };
+        assert_eq!(start.to_rfc3339(), "2026-01-23T20:00:00+00:00");
+        assert_eq!(end - start, Duration::minutes(90));
+    }
+
+    #[test]
+    fn test_event_attendees() {
+        let CaptureTarget::Event { title, attendees, .. } = parse_capture(
+            "event 3pm: Sync +Ada@example.com about +1 review +bob@example.com",
+            &now(),
+        )
+        .unwrap() else {
+            panic!("expected event");
+        };
+        assert_eq!(title, "Sync about +1 review");
+        assert_eq!(attendees, vec!["ada@example.com", "bob@example.com"]);
+        assert_eq!(
+            parse_capture("event 3pm: +ada@example.com", &now()),
+            Err(CaptureParseError::MissingTitle)
+        );
+    }
+
+    #[test]
+    fn test_event_errors() {
+        assert!(matches!(
+            parse_capture("event soon: Lunch", &now()),
+            Err(CaptureParseError::InvalidTime(_))
+        ));
+        assert!(matches!(
+            parse_capture("event 13pm: Lunch", &now()),
+            Err(CaptureParseError::InvalidTime(_))
+        ));
+        assert_eq!(parse_capture("event noon:", &now()), Err(CaptureParseError::MissingTitle));
+    }
+
+    #[test]
+    fn test_bookmark() {
+        assert_eq!(
+            parse_capture("bookmark: example.com/post #Rust #later", &now()).unwrap(),
+            CaptureTarget::Bookmark {
+                url: "https://example.com/post".into(),
+                tags: vec!["rust".into(), "later".into()],
+            }
+        );
+        // A bare link is a bookmark, a link inside a sentence is a note
+        assert_eq!(parse_capture("https://example.com #read", &now()).unwrap().kind(), "bookmark");
+        assert_eq!(parse_capture("see https://example.com", &now()).unwrap().kind(), "note");
+        assert_eq!(parse_capture("bookmark #rust", &now()), Err(CaptureParseError::MissingTitle));
+        assert!(matches!(
+            parse_capture("bookmark example.com great read", &now()),
+            Err(CaptureParseError::InvalidUrl(_))
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+//! Quick-capture backend: routes parsed capture text to notes, GitHub issues,
+//! Google Calendar or bookmarks. All network work runs off the UI thread;
+//! results sent via mpsc.
+
+use std::sync::Arc;
+
+use myme_core::connectivity;
+use myme_services::{
+    BookmarkStore, CreateIssueRequest, GitHubClient, NoteClient, TodoCreateRequest,
+};
+
+use super::bookmark_service::fetch_metadata;
+use super::calendar_service::calendar_client;
+use super::capture_parser::CaptureTarget;
+use crate::bridge;
+
+/// Error type for capture operations
+#[derive(Debug, Clone)]
+pub enum CaptureError {
+    Network(String),
+    /// The target service is not configured or signed in (e.g. "GitHub")
+    Unavailable(&'static str),
+    NotInitialized,
+}
+
+impl std::fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CaptureError::Network(s) => write!(f, "Capture failed: {}", s),
+            CaptureError::Unavailable(service) => write!(f, "{} is not connected", service),
+            CaptureError::NotInitialized => write!(f, "Capture service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for CaptureError {}
+
+/// What was created for a capture
+#[derive(Debug, Clone)]
+pub struct CaptureOutcome {
+    /// "note", "issue", "event" or "bookmark"
+    pub kind: &'static str,
+    pub title: String,
+    /// Web link to the created item, when there is one
+    pub url: Option<String>,
+}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum CaptureServiceMessage {
+    CaptureDone(Result<CaptureOutcome, CaptureError>),
+}
+
+/// Clients a capture may need; only the one matching the target is used.
+#[derive(Default)]
+pub struct CaptureClients {
+    pub notes: Option<Arc<NoteClient>>,
+    pub github: Option<Arc<GitHubClient>>,
+    pub google_token: Option<String>,
+    pub bookmarks: Option<Arc<BookmarkStore>>,
+}
+
+async fn route(
+    target: CaptureTarget,
+    clients: CaptureClients,
+) -> Result<CaptureOutcome, CaptureError> {
+    let kind = target.kind();
+    match target {
+        CaptureTarget::Note { content } => {
+            let notes = clients.notes.ok_or(CaptureError::Unavailable("Notes"))?;
+            let request = TodoCreateRequest { content: content.clone(), is_checklist: false };
+            notes.create_todo(request).await.map_err(|e| CaptureError::Network(e.to_string()))?;
+            Ok(CaptureOutcome { kind, title: content, url: None })
+        }
+        CaptureTarget::Issue { owner, repo, title, body } => {
+            let github = clients.github.ok_or(CaptureError::Unavailable("GitHub"))?;
+            let request = CreateIssueRequest { title, body, labels: None };
+            let issue = github
+                .create_issue(&owner, &repo, request)
+                .await
+                .map_err(|e| CaptureError::Network(e.to_string()))?;
+            Ok(CaptureOutcome { kind, title: issue.title, url: Some(issue.html_url) })
+        }
+        CaptureTarget::Event { title, start, end, attendees } => {
+            let token = clients.google_token.ok_or(CaptureError::Unavailable("Google Calendar"))?;
+            let event = calendar_client(&token)
+                .create_event("primary", &title, start, end, None, None, &attendees)
+                .await
+                .map_err(|e| CaptureError::Network(e.to_string()))?;
+            Ok(CaptureOutcome { kind, title: event.summary, url: event.html_link })
+        }
+        CaptureTarget::Bookmark { url, tags } => {
+            let store = clients.bookmarks.ok_or(CaptureError::Unavailable("Bookmarks"))?;
+            let (bookmark, _) =
+                store.add(&url, &tags).map_err(|e| CaptureError::Network(e.to_string()))?;
+            // The bookmark is saved either way; the page title is a bonus
+            if bookmark.fetched_at.is_none() && connectivity::is_online() {
+                if let Err(e) = fetch_metadata(&store, bookmark.id, &bookmark.url).await {
+                    tracing::debug!("Bookmark metadata fetch failed: {}", e);
+                }
+            }
+            let title = store.get(bookmark.id).ok().flatten().map_or(bookmark.title, |b| b.title);
+            Ok(CaptureOutcome { kind, title, url: Some(bookmark.url) })
+        }
+    }
+}
+
+/// Request to create the item for a parsed capture.
+/// Sends `CaptureDone` on the channel when complete.
+pub fn request_capture(
+    tx: &std::sync::mpsc::Sender<CaptureServiceMessage>,
+    target: CaptureTarget,
+    clients: CaptureClients,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(CaptureServiceMessage::CaptureDone(Err(CaptureError::NotInitialized)));
+            return;
+        }
+    };
+
+    runtime.spawn(async move {
+        let result = route(target, clients).await;
+        let _ = tx.send(CaptureServiceMessage::CaptureDone(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn capture_error_display() {
+        assert!(format!("{}", CaptureError::Unavailable("GitHub")).contains("GitHub"));
+        assert!(format!("{}", CaptureError::NotInitialized).contains("not initialized"));
+    }
+}
@@ -0,0 +1,110 @@
+//! Contacts backend: aggregates people from the Gmail and Calendar caches and
+//! collaborators on project-linked GitHub repos. Work runs off the UI thread;
+//! results sent via mpsc.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use myme_calendar::CalendarCache;
+use myme_contacts::{Contact, ContactBook};
+use myme_core::connectivity;
+use myme_gmail::GmailCache;
+use myme_services::GitHubClient;
+
+use super::calendar_service::display_zone;
+use super::google_common::get_google_cache_path;
+use crate::bridge;
+
+/// Past meetings older than this are not scanned
+const CALENDAR_LOOKBACK_DAYS: i64 = 180;
+
+/// Upcoming meetings within this window add their attendees
+const CALENDAR_LOOKAHEAD_DAYS: i64 = 30;
+
+/// Most recent cached messages scanned for senders
+const GMAIL_SCAN_LIMIT: u32 = 1000;
+
+/// Linked repos queried for collaborators per refresh
+const MAX_COLLABORATOR_REPOS: usize = 20;
+
+/// Error type for contacts operations
+#[derive(Debug, Clone)]
+pub enum ContactsError {
+    NotInitialized,
+}
+
+impl std::fmt::Display for ContactsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContactsError::NotInitialized => write!(f, "Contacts service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for ContactsError {}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum ContactsServiceMessage {
+    RefreshDone(Result<Vec<Contact>, ContactsError>),
+}
+
+/// Contacts from the local Gmail and Calendar caches. Calendar goes first so
+/// the signed-in user (marked on their own events) is excluded from Gmail too.
+fn cached_contacts(now: DateTime<Utc>) -> ContactBook {
+    let mut book = ContactBook::new();
+
+    let events = CalendarCache::new(get_google_cache_path("calendar_cache.db")).and_then(|c| {
+        c.list_events(
+            "primary",
+            now - Duration::days(CALENDAR_LOOKBACK_DAYS),
+            now + Duration::days(CALENDAR_LOOKAHEAD_DAYS),
+            &display_zone(),
+        )
+    });
+    match events {
+        Ok(events) => book.add_calendar(&events, now),
+        Err(e) => tracing::warn!("Contacts: calendar cache unavailable: {}", e),
+    }
+
+    let messages = GmailCache::new(get_google_cache_path("gmail_cache.db"))
+        .and_then(|c| c.list_messages(None, GMAIL_SCAN_LIMIT));
+    match messages {
+        Ok(messages) => book.add_gmail(&messages),
+        Err(e) => tracing::warn!("Contacts: Gmail cache unavailable: {}", e),
+    }
+
+    book
+}
+
+/// Request a fresh contact list. GitHub collaborators of `repo_ids`
+/// ("owner/repo") are included when a client is given and we are online;
+/// repos where listing fails (e.g. no push access) are skipped.
+/// Sends `RefreshDone`.
+pub fn request_refresh(
+    tx: &std::sync::mpsc::Sender<ContactsServiceMessage>,
+    github: Option<Arc<GitHubClient>>,
+    repo_ids: Vec<String>,
+) {
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(ContactsServiceMessage::RefreshDone(Err(ContactsError::NotInitialized)));
+        return;
+    };
+
+    runtime.spawn(async move {
+        let mut book = cached_contacts(Utc::now());
+
+        if let Some(client) = github.filter(|_| connectivity::is_online()) {
+            let repos = repo_ids.iter().filter_map(|id| id.split_once('/'));
+            for (owner, repo) in repos.take(MAX_COLLABORATOR_REPOS) {
+                match client.list_collaborators(owner, repo).await {
+                    Ok(collaborators) => book.add_github(&collaborators),
+                    Err(e) => tracing::debug!("Contacts: skipping {}/{}: {}", owner, repo, e),
+                }
+            }
+        }
+
+        let _ = tx.send(ContactsServiceMessage::RefreshDone(Ok(book.into_contacts())));
+    });
+}
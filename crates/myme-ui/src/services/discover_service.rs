@@ -0,0 +1,214 @@
+//! Discovery backend: starred repositories and a feed of their new releases
+//! and activity. Building the feed costs a request per starred repo, so it is
+//! cached for a while. Network work runs off the UI thread.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use myme_core::connectivity;
+use myme_services::discover::{build_feed, release_candidates};
+use myme_services::{DiscoverItem, GitHubClient, GitHubRepo};
+
+use crate::bridge;
+
+/// How long a built feed is reused before asking GitHub again
+const DISCOVER_CACHE_TTL_MINUTES: i64 = 30;
+
+/// Error type for discovery operations
+#[derive(Debug, Clone)]
+pub enum DiscoverError {
+    Network(String),
+    /// Not an `owner/repo` name
+    InvalidRepo(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for DiscoverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiscoverError::Network(s) => write!(f, "Discover error: {}", s),
+            DiscoverError::InvalidRepo(s) => write!(f, "Not a repository name: {}", s),
+            DiscoverError::NotInitialized => write!(f, "Discover service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for DiscoverError {}
+
+/// Starred repositories and the feed built from them
+#[derive(Debug, Clone)]
+pub struct DiscoverFeed {
+    /// Most recently starred first
+    pub starred: Vec<GitHubRepo>,
+    pub items: Vec<DiscoverItem>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum DiscoverServiceMessage {
+    FeedDone(Result<DiscoverFeed, DiscoverError>),
+    /// A star was added or removed: `(full_name, starred)`
+    StarDone(Result<(String, bool), DiscoverError>),
+}
+
+static DISCOVER_CACHE: std::sync::OnceLock<std::sync::Mutex<Option<DiscoverFeed>>> =
+    std::sync::OnceLock::new();
+
+fn discover_cache() -> &'static std::sync::Mutex<Option<DiscoverFeed>> {
+    DISCOVER_CACHE.get_or_init(|| std::sync::Mutex::new(None))
+}
+
+fn is_fresh(fetched_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    now - fetched_at < Duration::minutes(DISCOVER_CACHE_TTL_MINUTES)
+}
+
+/// Cached feed if it is still within the TTL
+pub fn cached() -> Option<DiscoverFeed> {
+    let cache = discover_cache().lock().ok()?;
+    cache.as_ref().filter(|f| is_fresh(f.fetched_at, Utc::now())).cloned()
+}
+
+fn set_cached(feed: Option<&DiscoverFeed>) {
+    if let Ok(mut cache) = discover_cache().lock() {
+        *cache = feed.cloned();
+    }
+}
+
+/// Runtime for a request, or None after reporting why there isn't one
+fn runtime_or_offline<F>(
+    tx: &std::sync::mpsc::Sender<DiscoverServiceMessage>,
+    fail: F,
+) -> Option<tokio::runtime::Handle>
+where
+    F: FnOnce(DiscoverError) -> DiscoverServiceMessage,
+{
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(fail(DiscoverError::NotInitialized));
+        return None;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(fail(DiscoverError::Network(connectivity::OFFLINE_ERROR.into())));
+        return None;
+    }
+    Some(runtime)
+}
+
+/// Request the starred repositories and their feed, answering from the cache
+/// unless `force` is set. Repos whose releases can't be read are left out of
+/// the release part of the feed. Sends `FeedDone`.
+pub fn request_feed(
+    tx: &std::sync::mpsc::Sender<DiscoverServiceMessage>,
+    client: Arc<GitHubClient>,
+    force: bool,
+) {
+    if !force {
+        if let Some(feed) = cached() {
+            let _ = tx.send(DiscoverServiceMessage::FeedDone(Ok(feed)));
+            return;
+        }
+    }
+    let tx = tx.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| DiscoverServiceMessage::FeedDone(Err(e)))
+    else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let starred = match client.list_starred().await {
+            Ok(starred) => starred,
+            Err(e) => {
+                let _ = tx.send(DiscoverServiceMessage::FeedDone(Err(DiscoverError::Network(
+                    e.to_string(),
+                ))));
+                return;
+            }
+        };
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for repo in release_candidates(&starred) {
+            let client = client.clone();
+            let full_name = repo.full_name.clone();
+            tasks.spawn(async move {
+                let (owner, name) = full_name.split_once('/')?;
+                match client.list_releases(owner, name).await {
+                    Ok(releases) => Some((full_name, releases)),
+                    Err(e) => {
+                        tracing::debug!("No releases for {}: {}", full_name, e);
+                        None
+                    }
+                }
+            });
+        }
+        let mut releases = Vec::new();
+        while let Some(joined) = tasks.join_next().await {
+            releases.extend(joined.ok().flatten());
+        }
+
+        let now = Utc::now();
+        let feed =
+            DiscoverFeed { items: build_feed(&starred, &releases, now), starred, fetched_at: now };
+        set_cached(Some(&feed));
+        let _ = tx.send(DiscoverServiceMessage::FeedDone(Ok(feed)));
+    });
+}
+
+/// Star (or with `star` false, unstar) `full_name`. The cached feed is dropped
+/// so the next fetch reflects the change. Sends `StarDone`.
+pub fn request_star(
+    tx: &std::sync::mpsc::Sender<DiscoverServiceMessage>,
+    client: Arc<GitHubClient>,
+    full_name: String,
+    star: bool,
+) {
+    let Some((owner, repo)) = full_name
+        .trim()
+        .split_once('/')
+        .filter(|(owner, repo)| !owner.is_empty() && !repo.is_empty() && !repo.contains('/'))
+        .map(|(owner, repo)| (owner.to_string(), repo.to_string()))
+    else {
+        let _ =
+            tx.send(DiscoverServiceMessage::StarDone(Err(DiscoverError::InvalidRepo(full_name))));
+        return;
+    };
+    let tx = tx.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| DiscoverServiceMessage::StarDone(Err(e)))
+    else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = if star {
+            client.star_repo(&owner, &repo).await
+        } else {
+            client.unstar_repo(&owner, &repo).await
+        };
+        let result = match result {
+            Ok(()) => {
+                set_cached(None);
+                Ok((format!("{}/{}", owner, repo), star))
+            }
+            Err(e) => Err(DiscoverError::Network(e.to_string())),
+        };
+        let _ = tx.send(DiscoverServiceMessage::StarDone(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn feed_cache_ttl() {
+        let now = Utc::now();
+        assert!(is_fresh(now - Duration::minutes(5), now));
+        assert!(!is_fresh(now - Duration::minutes(DISCOVER_CACHE_TTL_MINUTES), now));
+
+        let feed = DiscoverFeed { starred: Vec::new(), items: Vec::new(), fetched_at: now };
+        set_cached(Some(&feed));
+        assert!(cached().is_some());
+        set_cached(None);
+        assert!(cached().is_none());
+    }
+}
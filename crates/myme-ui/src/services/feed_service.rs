@@ -0,0 +1,181 @@
+//! Feed backend: subscribing to and refreshing RSS/Atom feeds.
+//! Network work runs off the UI thread; results sent via mpsc. Reads and
+//! read-state changes go straight to the local cache.
+
+use chrono::{Duration, Utc};
+use myme_core::connectivity;
+use myme_feeds::{FeedCache, FeedClient, FetchOutcome};
+
+use super::google_common::get_google_cache_path;
+use crate::bridge;
+
+/// Error type for feed operations
+#[derive(Debug, Clone)]
+pub enum FeedError {
+    Network(String),
+    InvalidFeed(String),
+    Cache(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for FeedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FeedError::Network(s) => write!(f, "Feed error: {}", s),
+            FeedError::InvalidFeed(s) => write!(f, "Not a feed: {}", s),
+            FeedError::Cache(s) => write!(f, "Feed cache error: {}", s),
+            FeedError::NotInitialized => write!(f, "Feed service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for FeedError {}
+
+impl From<myme_feeds::FeedError> for FeedError {
+    fn from(e: myme_feeds::FeedError) -> Self {
+        match e {
+            myme_feeds::FeedError::InvalidUrl(_) | myme_feeds::FeedError::Parse(_) => {
+                FeedError::InvalidFeed(e.user_message())
+            }
+            myme_feeds::FeedError::CacheError(s) => FeedError::Cache(s),
+            _ => FeedError::Network(e.user_message()),
+        }
+    }
+}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum FeedServiceMessage {
+    /// All subscriptions refreshed; carries the number of new items
+    RefreshDone(Result<usize, FeedError>),
+    /// A new subscription was fetched and saved; carries its URL
+    SubscribeDone(Result<String, FeedError>),
+}
+
+/// Open the feed cache.
+pub fn open_cache() -> Result<FeedCache, FeedError> {
+    FeedCache::new(get_google_cache_path("feeds_cache.db"))
+        .map_err(|e| FeedError::Cache(e.to_string()))
+}
+
+fn client() -> FeedClient {
+    FeedClient::new_with_proxies(myme_core::Config::load_cached().proxy.proxies_for("feeds"))
+}
+
+/// A URL typed by the user, with `https://` added when no scheme is given.
+pub fn normalize_url(url: &str) -> String {
+    let url = url.trim();
+    if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{}", url)
+    }
+}
+
+/// Fetch every subscription, store new items and drop old read ones.
+/// A feed that fails keeps its cached items and records the error.
+/// Returns the number of new items.
+pub async fn refresh_all() -> Result<usize, FeedError> {
+    let mut cache = open_cache()?;
+    let feeds = cache.list_feeds().map_err(|e| FeedError::Cache(e.to_string()))?;
+    let client = client();
+
+    let mut new_items = 0;
+    for feed in feeds {
+        let validators = cache.validators(&feed.url).unwrap_or_default();
+        let outcome = client.fetch(&feed.url, &validators).await;
+        let now = Utc::now();
+        let stored = match outcome {
+            Ok(FetchOutcome::Updated { feed: parsed, validators }) => {
+                cache.store_fetch(&feed.url, &parsed, &validators, now).map(|n| new_items += n)
+            }
+            Ok(FetchOutcome::NotModified) => cache.mark_fetched(&feed.url, now),
+            Err(e) => {
+                tracing::debug!("Feed {} failed: {}", feed.url, e);
+                cache.record_error(&feed.url, &e.user_message(), now)
+            }
+        };
+        if let Err(e) = stored {
+            tracing::warn!("Failed to cache feed {}: {:#}", feed.url, e);
+        }
+    }
+
+    let keep_days = myme_core::Config::load_cached().feeds.keep_read_days;
+    if let Err(e) = cache.prune(Utc::now() - Duration::days(i64::from(keep_days))) {
+        tracing::warn!("Failed to prune read feed items: {:#}", e);
+    }
+    Ok(new_items)
+}
+
+/// Request a refresh of all subscriptions. Sends `RefreshDone`.
+pub fn request_refresh(tx: &std::sync::mpsc::Sender<FeedServiceMessage>) {
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(FeedServiceMessage::RefreshDone(Err(FeedError::NotInitialized)));
+        return;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(FeedServiceMessage::RefreshDone(Err(FeedError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let _ = tx.send(FeedServiceMessage::RefreshDone(refresh_all().await));
+    });
+}
+
+/// Request a subscription to `url`. The feed is fetched first so that only
+/// URLs that really serve a feed are saved. Sends `SubscribeDone`.
+pub fn request_subscribe(tx: &std::sync::mpsc::Sender<FeedServiceMessage>, url: String) {
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(FeedServiceMessage::SubscribeDone(Err(FeedError::NotInitialized)));
+        return;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(FeedServiceMessage::SubscribeDone(Err(FeedError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    let url = normalize_url(&url);
+    runtime.spawn(async move {
+        let result = async {
+            let outcome = client().fetch(&url, &Default::default()).await?;
+            let mut cache = open_cache()?;
+            let cached = cache.add_feed(&url).and_then(|_| match outcome {
+                FetchOutcome::Updated { feed, validators } => {
+                    cache.store_fetch(&url, &feed, &validators, Utc::now()).map(|_| ())
+                }
+                FetchOutcome::NotModified => Ok(()),
+            });
+            cached.map_err(|e| FeedError::Cache(e.to_string()))?;
+            Ok::<_, FeedError>(url)
+        }
+        .await;
+        let _ = tx.send(FeedServiceMessage::SubscribeDone(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn normalize_url_adds_https() {
+        assert_eq!(normalize_url(" example.com/feed "), "https://example.com/feed");
+        assert_eq!(normalize_url("http://example.com/rss"), "http://example.com/rss");
+    }
+
+    #[test]
+    fn feed_errors_map_by_kind() {
+        let err: FeedError = myme_feeds::FeedError::Parse("not xml".into()).into();
+        assert!(matches!(err, FeedError::InvalidFeed(_)));
+        let err: FeedError = myme_feeds::FeedError::Http(503).into();
+        assert!(matches!(err, FeedError::Network(_)));
+    }
+}
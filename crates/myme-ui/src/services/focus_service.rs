@@ -0,0 +1,317 @@
+//! Pomodoro focus timer.
+//! Pure state machine driven by the caller's clock: FocusModel ticks it from a QML Timer,
+//! stores finished sessions in ProjectStore and raises a desktop notification.
+
+use chrono::{DateTime, Utc};
+use myme_services::{FocusKind, FocusSession};
+
+/// Interval lengths for a pomodoro cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusDurations {
+    pub work_secs: i64,
+    pub short_break_secs: i64,
+    pub long_break_secs: i64,
+    /// Take a long break after this many completed work intervals
+    pub long_break_every: u32,
+}
+
+impl Default for FocusDurations {
+    fn default() -> Self {
+        Self {
+            work_secs: 25 * 60,
+            short_break_secs: 5 * 60,
+            long_break_secs: 15 * 60,
+            long_break_every: 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusState {
+    Idle,
+    Running,
+    Paused,
+}
+
+/// Pomodoro timer linked to an optional kanban task
+#[derive(Debug, Clone)]
+pub struct FocusService {
+    durations: FocusDurations,
+    state: FocusState,
+    kind: FocusKind,
+    task_id: Option<String>,
+    started_at: Option<DateTime<Utc>>,
+    /// Start of the current running stretch (None while paused or idle)
+    resumed_at: Option<DateTime<Utc>>,
+    /// Seconds run before the current stretch
+    accumulated_secs: i64,
+    completed_work: u32,
+}
+
+impl Default for FocusService {
+    fn default() -> Self {
+        Self::new(FocusDurations::default())
+    }
+}
+
+impl FocusService {
+    pub fn new(durations: FocusDurations) -> Self {
+        Self {
+            durations,
+            state: FocusState::Idle,
+            kind: FocusKind::Work,
+            task_id: None,
+            started_at: None,
+            resumed_at: None,
+            accumulated_secs: 0,
+            completed_work: 0,
+        }
+    }
+
+    pub fn state(&self) -> FocusState {
+        self.state
+    }
+
+    pub fn kind(&self) -> FocusKind {
+        self.kind
+    }
+
+    pub fn task_id(&self) -> Option<&str> {
+        self.task_id.as_deref()
+    }
+
+    /// Work intervals completed since the timer was created
+    pub fn completed_work(&self) -> u32 {
+        self.completed_work
+    }
+
+    /// Planned length of the current phase
+    pub fn planned_secs(&self) -> i64 {
+        match self.kind {
+            FocusKind::Work => self.durations.work_secs,
+            FocusKind::ShortBreak => self.durations.short_break_secs,
+            FocusKind::LongBreak => self.durations.long_break_secs,
+        }
+    }
+
+    /// Seconds run in the current phase, excluding pauses
+    pub fn elapsed_secs(&self, now: DateTime<Utc>) -> i64 {
+        let running = self.resumed_at.map(|t| (now - t).num_seconds().max(0)).unwrap_or(0);
+        self.accumulated_secs + running
+    }
+
+    pub fn remaining_secs(&self, now: DateTime<Utc>) -> i64 {
+        if self.state == FocusState::Idle {
+            return self.planned_secs();
+        }
+        (self.planned_secs() - self.elapsed_secs(now)).max(0)
+    }
+
+    /// Start a work interval for `task_id`. Returns false if a session is already active.
+    pub fn start(&mut self, task_id: Option<String>, now: DateTime<Utc>) -> bool {
+        if self.state != FocusState::Idle {
+            return false;
+        }
+        self.task_id = task_id;
+        self.begin(FocusKind::Work, now);
+        true
+    }
+
+    pub fn pause(&mut self, now: DateTime<Utc>) -> bool {
+        if self.state != FocusState::Running {
+            return false;
+        }
+        self.accumulated_secs = self.elapsed_secs(now);
+        self.resumed_at = None;
+        self.state = FocusState::Paused;
+        true
+    }
+
+    pub fn resume(&mut self, now: DateTime<Utc>) -> bool {
+        if self.state != FocusState::Paused {
+            return false;
+        }
+        self.resumed_at = Some(now);
+        self.state = FocusState::Running;
+        true
+    }
+
+    /// Abandon the current phase and return to idle. Returns the partial session, if any.
+    pub fn stop(&mut self, now: DateTime<Utc>) -> Option<FocusSession> {
+        if self.state == FocusState::Idle {
+            return None;
+        }
+        let session = self.session(now, false);
+        self.reset();
+        Some(session)
+    }
+
+    /// End the current phase early and move on to the next one.
+    pub fn skip(&mut self, now: DateTime<Utc>) -> Option<FocusSession> {
+        if self.state == FocusState::Idle {
+            return None;
+        }
+        let session = self.session(now, false);
+        self.advance(now, false);
+        Some(session)
+    }
+
+    /// Advance the clock. Returns the finished session when the current interval ends.
+    pub fn tick(&mut self, now: DateTime<Utc>) -> Option<FocusSession> {
+        if self.state != FocusState::Running || self.elapsed_secs(now) < self.planned_secs() {
+            return None;
+        }
+        let session = self.session(now, true);
+        self.advance(now, true);
+        Some(session)
+    }
+
+    fn begin(&mut self, kind: FocusKind, now: DateTime<Utc>) {
+        self.kind = kind;
+        self.state = FocusState::Running;
+        self.started_at = Some(now);
+        self.resumed_at = Some(now);
+        self.accumulated_secs = 0;
+    }
+
+    fn reset(&mut self) {
+        self.kind = FocusKind::Work;
+        self.state = FocusState::Idle;
+        self.started_at = None;
+        self.resumed_at = None;
+        self.accumulated_secs = 0;
+    }
+
+    /// Work is followed by a break that starts right away; a break returns to idle.
+    fn advance(&mut self, now: DateTime<Utc>, completed: bool) {
+        match self.kind {
+            FocusKind::Work => {
+                if completed {
+                    self.completed_work += 1;
+                }
+                let every = self.durations.long_break_every.max(1);
+                let long = completed && self.completed_work.is_multiple_of(every);
+                self.begin(if long { FocusKind::LongBreak } else { FocusKind::ShortBreak }, now);
+            }
+            FocusKind::ShortBreak | FocusKind::LongBreak => self.reset(),
+        }
+    }
+
+    fn session(&self, now: DateTime<Utc>, completed: bool) -> FocusSession {
+        let planned = self.planned_secs();
+        FocusSession {
+            id: uuid::Uuid::new_v4().to_string(),
+            task_id: self.task_id.clone(),
+            kind: self.kind,
+            started_at: self.started_at.unwrap_or(now).to_rfc3339(),
+            ended_at: now.to_rfc3339(),
+            planned_secs: planned,
+            focused_secs: self.elapsed_secs(now).min(planned),
+            completed,
+        }
+    }
+}
+
+/// Show a desktop notification (notify-send / osascript / PowerShell).
+pub fn notify_desktop(title: &str, body: &str) -> bool {
+    use std::process::Command;
+
+    let result = if cfg!(target_os = "windows") {
+        let script = format!(
+            "Add-Type -AssemblyName System.Windows.Forms; \
+             $n = New-Object System.Windows.Forms.NotifyIcon; \
+             $n.Icon = [System.Drawing.SystemIcons]::Information; $n.Visible = $true; \
+             $n.ShowBalloonTip(10000, '{}', '{}', 'Info'); Start-Sleep -Seconds 10; $n.Dispose()",
+            title.replace('\'', "''"),
+            body.replace('\'', "''")
+        );
+        Command::new("powershell").args(["-NoProfile", "-Command", &script]).spawn()
+    } else if cfg!(target_os = "macos") {
+        let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let script =
+            format!("display notification \"{}\" with title \"{}\"", escape(body), escape(title));
+        Command::new("osascript").args(["-e", &script]).spawn()
+    } else {
+        Command::new("notify-send").args(["--app-name=MyMe", title, body]).spawn()
+    };
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            tracing::warn!("Failed to show desktop notification: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::Duration;
+
+    fn short() -> FocusService {
+        FocusService::new(FocusDurations {
+            work_secs: 60,
+            short_break_secs: 10,
+            long_break_secs: 30,
+            long_break_every: 2,
+        })
+    }
+
+    #[test]
+    fn test_work_then_break_then_idle() {
+        let t0 = Utc::now();
+        let mut focus = short();
+        assert!(focus.start(Some("task-1".into()), t0));
+        assert!(!focus.start(None, t0));
+
+        assert!(focus.tick(t0 + Duration::seconds(59)).is_none());
+        let done = focus.tick(t0 + Duration::seconds(60)).unwrap();
+        assert!(done.completed);
+        assert_eq!(done.kind, FocusKind::Work);
+        assert_eq!(done.task_id.as_deref(), Some("task-1"));
+        assert_eq!(focus.kind(), FocusKind::ShortBreak);
+        assert_eq!(focus.state(), FocusState::Running);
+
+        let brk = focus.tick(t0 + Duration::seconds(70)).unwrap();
+        assert_eq!(brk.kind, FocusKind::ShortBreak);
+        assert_eq!(focus.state(), FocusState::Idle);
+        assert_eq!(focus.kind(), FocusKind::Work);
+    }
+
+    #[test]
+    fn test_pause_excludes_paused_time() {
+        let t0 = Utc::now();
+        let mut focus = short();
+        focus.start(None, t0);
+        assert!(focus.pause(t0 + Duration::seconds(20)));
+        assert_eq!(focus.remaining_secs(t0 + Duration::seconds(500)), 40);
+        assert!(focus.tick(t0 + Duration::seconds(500)).is_none());
+
+        assert!(focus.resume(t0 + Duration::seconds(500)));
+        assert!(focus.tick(t0 + Duration::seconds(539)).is_none());
+        let done = focus.tick(t0 + Duration::seconds(540)).unwrap();
+        assert_eq!(done.focused_secs, 60);
+    }
+
+    #[test]
+    fn test_long_break_and_stop() {
+        let t0 = Utc::now();
+        let mut focus = short();
+        focus.start(None, t0);
+        focus.tick(t0 + Duration::seconds(60));
+        focus.skip(t0 + Duration::seconds(61));
+        assert_eq!(focus.state(), FocusState::Idle);
+
+        focus.start(None, t0 + Duration::seconds(100));
+        focus.tick(t0 + Duration::seconds(160));
+        assert_eq!(focus.kind(), FocusKind::LongBreak);
+        assert_eq!(focus.completed_work(), 2);
+
+        let partial = focus.stop(t0 + Duration::seconds(165)).unwrap();
+        assert!(!partial.completed);
+        assert_eq!(partial.focused_secs, 5);
+        assert_eq!(focus.state(), FocusState::Idle);
+        assert!(focus.stop(t0 + Duration::seconds(170)).is_none());
+    }
+}
@@ -0,0 +1,159 @@
+//! GitHub gists backend: list gists and publish notes as secret gists.
+//! All network work runs off the UI thread; results sent via mpsc.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use myme_core::connectivity;
+use myme_services::{
+    CreateGistRequest, GistFileContent, GitHubClient, GitHubGist, UpdateGistRequest,
+};
+
+use crate::bridge;
+
+/// Error type for gist operations
+#[derive(Debug, Clone)]
+pub enum GistError {
+    Network(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for GistError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GistError::Network(s) => write!(f, "Gist error: {}", s),
+            GistError::NotInitialized => write!(f, "Gist service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for GistError {}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum GistServiceMessage {
+    FetchDone(Result<Vec<GitHubGist>, GistError>),
+    CreateDone(Result<GitHubGist, GistError>),
+    UpdateDone(Result<GitHubGist, GistError>),
+}
+
+/// File name for a note published as a gist: the first line, slugged, as Markdown.
+pub fn note_filename(content: &str) -> String {
+    let first_line = content.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("");
+    let mut slug = String::new();
+    for c in first_line.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.chars().count() >= 40 {
+            break;
+        }
+    }
+    let slug = slug.trim_end_matches('-');
+    if slug.is_empty() {
+        "note.md".to_string()
+    } else {
+        format!("{}.md", slug)
+    }
+}
+
+/// Runtime for a request, or None after reporting why there isn't one
+fn runtime_or_offline<F>(
+    tx: &std::sync::mpsc::Sender<GistServiceMessage>,
+    fail: F,
+) -> Option<tokio::runtime::Handle>
+where
+    F: FnOnce(GistError) -> GistServiceMessage,
+{
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(fail(GistError::NotInitialized));
+        return None;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(fail(GistError::Network(connectivity::OFFLINE_ERROR.into())));
+        return None;
+    }
+    Some(runtime)
+}
+
+/// Request the user's gists. Sends `FetchDone`.
+pub fn request_fetch(tx: &std::sync::mpsc::Sender<GistServiceMessage>, client: Arc<GitHubClient>) {
+    let tx = tx.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| GistServiceMessage::FetchDone(Err(e))) else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = client.list_gists().await.map_err(|e| GistError::Network(e.to_string()));
+        let _ = tx.send(GistServiceMessage::FetchDone(result));
+    });
+}
+
+/// Request publishing `content` as a secret gist. Sends `CreateDone`.
+pub fn request_create(
+    tx: &std::sync::mpsc::Sender<GistServiceMessage>,
+    client: Arc<GitHubClient>,
+    description: Option<String>,
+    content: String,
+) {
+    let tx = tx.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| GistServiceMessage::CreateDone(Err(e))) else {
+        return;
+    };
+
+    let req = CreateGistRequest {
+        description,
+        public: false,
+        files: BTreeMap::from([(note_filename(&content), GistFileContent { content })]),
+    };
+    runtime.spawn(async move {
+        let result = client.create_gist(req).await.map_err(|e| GistError::Network(e.to_string()));
+        let _ = tx.send(GistServiceMessage::CreateDone(result));
+    });
+}
+
+/// Request replacing one file of an existing gist. Sends `UpdateDone`.
+pub fn request_update(
+    tx: &std::sync::mpsc::Sender<GistServiceMessage>,
+    client: Arc<GitHubClient>,
+    gist_id: String,
+    filename: String,
+    content: String,
+) {
+    let tx = tx.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| GistServiceMessage::UpdateDone(Err(e))) else {
+        return;
+    };
+
+    let req = UpdateGistRequest {
+        description: None,
+        files: BTreeMap::from([(filename, Some(GistFileContent { content }))]),
+    };
+    runtime.spawn(async move {
+        let result =
+            client.update_gist(&gist_id, req).await.map_err(|e| GistError::Network(e.to_string()));
+        let _ = tx.send(GistServiceMessage::UpdateDone(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn gist_error_display() {
+        assert!(format!("{}", GistError::Network("timeout".into())).contains("Gist"));
+        assert!(format!("{}", GistError::NotInitialized).contains("not initialized"));
+    }
+
+    #[test]
+    fn note_filename_slugs_first_line() {
+        assert_eq!(note_filename("\n  Shopping List: Week 3!\nmilk"), "shopping-list-week-3.md");
+        assert_eq!(note_filename("   \n"), "note.md");
+        assert_eq!(note_filename("!!!"), "note.md");
+        assert!(note_filename(&"a".repeat(100)).len() <= 43);
+    }
+}
@@ -0,0 +1,307 @@
+//! Keeps project boards, workflow runs and the repo list in step with GitHub.
+//!
+//! Changes arrive two ways: webhook deliveries read from the relay channel in
+//! `github.webhook_relay_url`, within seconds, and a poll of every
+//! project-linked repo each `projects.sync_interval_minutes`. Both become a
+//! [`SyncBatch`] handled by [`apply`], so a relayed event and a poll do the
+//! same work. The poller stands down while the relay is connected, and takes
+//! over (with an immediate catch-up) whenever the relay drops.
+
+use std::collections::{BTreeSet, HashMap};
+use std::time::Duration;
+
+use myme_core::connectivity;
+use myme_services::{GitHubEvent, RelayClient, RelayEvent, Task};
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::{Instant, MissedTickBehavior};
+
+use super::kanban_service::KanbanServiceMessage;
+use super::repo_service;
+use super::ui_events::{self, Severity, UiEvent};
+use super::workflow_service::WorkflowServiceMessage;
+use crate::bridge;
+
+/// Deliveries arriving this close together are handled as one batch
+const DELIVERY_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// First wait before reconnecting to the relay; doubles up to `RELAY_RETRY_MAX`
+const RELAY_RETRY_MIN: Duration = Duration::from_secs(15);
+const RELAY_RETRY_MAX: Duration = Duration::from_secs(600);
+
+/// Repos (`owner/repo`) to re-sync, by what changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncBatch {
+    /// Issues to pull into the boards of projects linking the repo
+    pub issues: BTreeSet<String>,
+    /// Workflow runs to re-fetch
+    pub workflows: BTreeSet<String>,
+    /// Repos whose branches moved on GitHub
+    pub pushes: BTreeSet<String>,
+}
+
+impl SyncBatch {
+    /// Everything about `repos`, as a poll checks it
+    pub fn everything(repos: impl IntoIterator<Item = String>) -> Self {
+        let issues: BTreeSet<String> = repos.into_iter().collect();
+        Self { workflows: issues.clone(), pushes: issues.clone(), issues }
+    }
+
+    pub fn add(&mut self, event: &GitHubEvent) {
+        let repo = event.repo().to_string();
+        match event {
+            GitHubEvent::Push { .. } => self.pushes.insert(repo),
+            GitHubEvent::WorkflowRun { .. } => self.workflows.insert(repo),
+            GitHubEvent::Issues { .. } => self.issues.insert(repo),
+        };
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty() && self.workflows.is_empty() && self.pushes.is_empty()
+    }
+}
+
+/// Repos linked to any project, which the poller checks
+fn linked_repos() -> Vec<String> {
+    let Some(store) = bridge::get_project_store_or_init() else {
+        return Vec::new();
+    };
+    match store.list_all_linked_repo_ids() {
+        Ok(ids) => ids.into_iter().filter(|id| id.contains('/')).collect(),
+        Err(e) => {
+            tracing::warn!("Failed to list linked repos: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Pull changed issues into the boards and tell the open pages to refresh.
+/// Does nothing offline or signed out.
+pub async fn apply(batch: SyncBatch) {
+    if batch.is_empty() || !connectivity::is_online() || !bridge::is_github_authenticated() {
+        return;
+    }
+    tracing::debug!(
+        "GitHub sync: {} issue, {} workflow, {} push repos",
+        batch.issues.len(),
+        batch.workflows.len(),
+        batch.pushes.len()
+    );
+    if !batch.issues.is_empty() {
+        for project_id in sync_issues(batch.issues).await {
+            if let Some(tx) = bridge::get_kanban_service_tx() {
+                let _ = tx.send(KanbanServiceMessage::ProjectSynced(project_id));
+            }
+        }
+    }
+    if !batch.workflows.is_empty() {
+        if let Some(tx) = bridge::get_workflow_service_tx() {
+            let _ = tx
+                .send(WorkflowServiceMessage::RemoteChanged(batch.workflows.into_iter().collect()));
+        }
+    }
+    if !batch.pushes.is_empty() {
+        repo_service::remote_changed(batch.pushes.into_iter().collect());
+    }
+}
+
+/// Import the issues of `repos` as tasks in every project linking them, as
+/// `myme kanban sync` does. Returns the projects whose tasks changed.
+async fn sync_issues(repos: BTreeSet<String>) -> Vec<String> {
+    let (Some((client, _)), Some(store)) =
+        (bridge::get_github_client_and_runtime(), bridge::get_project_store_or_init())
+    else {
+        return Vec::new();
+    };
+    let linked: Vec<(String, Vec<String>)> = repos
+        .into_iter()
+        .filter_map(|repo| {
+            let projects = store.list_projects_for_repo(&repo).ok()?;
+            let ids: Vec<String> = projects.into_iter().map(|p| p.id).collect();
+            (!ids.is_empty()).then_some((repo, ids))
+        })
+        .collect();
+    if linked.is_empty() {
+        return Vec::new();
+    }
+    let repo_ids: Vec<String> = linked.iter().map(|(repo, _)| repo.clone()).collect();
+    let projects: HashMap<String, Vec<String>> = linked.into_iter().collect();
+
+    let mut changed = BTreeSet::new();
+    for (repo_id, result) in client.list_issues_for_repos(&repo_ids).await {
+        let issues = match result {
+            Ok(issues) => issues,
+            Err(e) => {
+                tracing::warn!("Failed to fetch issues for {}: {}", repo_id, e);
+                continue;
+            }
+        };
+        for project_id in projects.get(&repo_id).into_iter().flatten() {
+            let existing: HashMap<String, Task> = match store.list_tasks_for_project(project_id) {
+                Ok(tasks) => tasks.into_iter().map(|t| (t.id.clone(), t)).collect(),
+                Err(e) => {
+                    tracing::warn!("Failed to read tasks of {}: {}", project_id, e);
+                    continue;
+                }
+            };
+            for issue in &issues {
+                let previous = existing.get(&Task::issue_id(&repo_id, issue.number));
+                let task = Task::from_issue(project_id, &repo_id, issue, previous);
+                if previous == Some(&task) {
+                    continue;
+                }
+                match store.upsert_task(&task) {
+                    Ok(()) => {
+                        changed.insert(project_id.clone());
+                    }
+                    Err(e) => tracing::warn!("Failed to save task {}: {}", task.id, e),
+                }
+            }
+        }
+    }
+    changed.into_iter().collect()
+}
+
+/// Keep reading the relay channel, reconnecting with backoff. Each
+/// connection's end is reported as `Err` with the reason.
+async fn relay_loop(
+    client: RelayClient,
+    updates: mpsc::UnboundedSender<Result<RelayEvent, String>>,
+) {
+    let mut delay = RELAY_RETRY_MIN;
+    loop {
+        let mut connected = false;
+        let result = client
+            .listen(|event| {
+                connected |= event == RelayEvent::Connected;
+                let _ = updates.send(Ok(event));
+            })
+            .await;
+        let reason = match result {
+            Ok(()) => "relay closed the stream".to_string(),
+            Err(e) => e.to_string(),
+        };
+        if updates.send(Err(reason)).is_err() {
+            return;
+        }
+        delay = if connected { RELAY_RETRY_MIN } else { (delay * 2).min(RELAY_RETRY_MAX) };
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Run the sync until shutdown: relay deliveries when `github.webhook_relay_url`
+/// is set, polling every `projects.sync_interval_minutes` otherwise.
+pub async fn run(mut shutdown: broadcast::Receiver<()>) {
+    let config = myme_core::Config::load_cached();
+    let minutes = config.projects.sync_interval_minutes.max(1);
+    let relay_url = config.github.webhook_relay_url.trim().to_string();
+
+    let (updates_tx, mut updates) = mpsc::unbounded_channel();
+    let relay = if relay_url.is_empty() {
+        None
+    } else {
+        match RelayClient::new(&relay_url, config.proxy.proxies_for("github")) {
+            Ok(client) => Some(tokio::spawn(relay_loop(client, updates_tx))),
+            Err(e) => {
+                tracing::warn!("Webhook relay disabled: {:#}", e);
+                None
+            }
+        }
+    };
+
+    let mut poll = tokio::time::interval(Duration::from_secs(u64::from(minutes) * 60));
+    poll.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut relay_up = false;
+    let mut relay_lost = false;
+    let mut pending = SyncBatch::default();
+    let mut flush_at: Option<Instant> = None;
+    loop {
+        tokio::select! {
+            _ = shutdown.recv() => break,
+            _ = poll.tick(), if !relay_up => apply(SyncBatch::everything(linked_repos())).await,
+            _ = tokio::time::sleep_until(flush_at.unwrap_or_else(Instant::now)),
+                if flush_at.is_some() =>
+            {
+                flush_at = None;
+                apply(std::mem::take(&mut pending)).await;
+            }
+            Some(update) = updates.recv() => match update {
+                Ok(RelayEvent::Connected) => {
+                    relay_up = true;
+                    tracing::info!("Webhook relay connected; polling paused");
+                    if std::mem::take(&mut relay_lost) {
+                        ui_events::publish(
+                            UiEvent::new("github", Severity::Info, "Live GitHub updates restored")
+                                .keyed("github-relay"),
+                        );
+                    }
+                    // Catch up on whatever happened while nothing was listening
+                    apply(SyncBatch::everything(linked_repos())).await;
+                }
+                Ok(RelayEvent::Delivery(event)) => {
+                    tracing::debug!("Webhook delivery: {:?}", event);
+                    pending.add(&event);
+                    flush_at.get_or_insert_with(|| Instant::now() + DELIVERY_DEBOUNCE);
+                }
+                Err(reason) if relay_up => {
+                    relay_up = false;
+                    relay_lost = true;
+                    tracing::warn!("Webhook relay lost ({}); polling every {} min", reason, minutes);
+                    ui_events::publish(
+                        UiEvent::new("github", Severity::Warning, "Live GitHub updates interrupted")
+                            .keyed("github-relay")
+                            .with_detail(format!(
+                                "Checking every {} minutes until the relay reconnects",
+                                minutes
+                            )),
+                    );
+                    poll.reset_immediately();
+                }
+                Err(reason) => tracing::debug!("Webhook relay unavailable: {}", reason),
+            },
+        }
+    }
+    if let Some(relay) = relay {
+        relay.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn batch_groups_events_by_kind() {
+        let mut batch = SyncBatch::default();
+        assert!(batch.is_empty());
+        batch.add(&GitHubEvent::Push { repo: "me/app".into(), git_ref: "refs/heads/main".into() });
+        batch.add(&GitHubEvent::Issues {
+            repo: "me/app".into(),
+            action: "opened".into(),
+            number: 1,
+        });
+        batch.add(&GitHubEvent::Issues {
+            repo: "me/app".into(),
+            action: "closed".into(),
+            number: 2,
+        });
+        batch.add(&GitHubEvent::WorkflowRun {
+            repo: "me/lib".into(),
+            workflow: "CI".into(),
+            status: "completed".into(),
+            conclusion: Some("success".into()),
+        });
+        assert_eq!(batch.issues.iter().collect::<Vec<_>>(), ["me/app"]);
+        assert_eq!(batch.workflows.iter().collect::<Vec<_>>(), ["me/lib"]);
+        assert_eq!(batch.pushes.iter().collect::<Vec<_>>(), ["me/app"]);
+    }
+
+    #[test]
+    fn poll_batch_covers_every_kind() {
+        let batch = SyncBatch::everything(vec!["b/two".to_string(), "a/one".to_string()]);
+        assert_eq!(batch.issues, batch.workflows);
+        assert_eq!(batch.issues, batch.pushes);
+        assert_eq!(batch.issues.iter().next().map(String::as_str), Some("a/one"));
+        assert!(SyncBatch::everything(Vec::new()).is_empty());
+    }
+}
@@ -1,12 +1,28 @@
 //! Gmail backend: async operations using the shared runtime and channel pattern.
 //! All network work runs off the UI thread; results sent via mpsc.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
-use myme_gmail::{GmailCache, GmailClient, Message};
+use chrono::{DateTime, Utc};
+use myme_core::connectivity;
+use myme_gmail::{
+    apply_rules, Attachment, CacheUsage, GmailCache, GmailClient, MailtoDraft, Message,
+    PrefetchPolicy, Rule, RuleAction, RuleStore, SyncAction, SyncQueue, Thread, UnsubscribeTarget,
+};
+use myme_services::RetryConfig;
 
+use super::activity_service::{self, UndoHint};
+use super::focus_service::notify_desktop;
+use super::google_common::{get_google_access_token, get_google_cache_path};
 use crate::bridge;
 
+/// Queued actions are dropped after this many failed replays.
+const MAX_SYNC_ATTEMPTS: u32 = 5;
+
+/// Bodies missing from the prefetch window fetched per inbox refresh.
+const PREFETCH_BATCH: u32 = 20;
+
 /// Error type for Gmail operations.
 #[derive(Debug, Clone)]
 pub enum GmailError {
@@ -34,6 +50,312 @@ pub enum GmailServiceMessage {
     FetchDone(Result<Vec<Message>, GmailError>),
     /// Result of an action (mark read, archive, trash); carries message_id or error.
     ActionDone(Result<String, GmailError>),
+    /// Result of a batch action; carries the affected message IDs or error.
+    BatchActionDone(Result<Vec<String>, GmailError>),
+    /// Result of fetching a full conversation.
+    ThreadDone(Result<Thread, GmailError>),
+    /// Result of downloading an attachment; carries the attachment with its local path set.
+    AttachmentDone(Result<Attachment, GmailError>),
+    /// Result of snoozing a message; carries the message_id or error.
+    SnoozeDone(Result<String, GmailError>),
+    /// Snoozed messages whose time came; they are back in the inbox.
+    Resurfaced(Vec<Message>),
+    /// Result of unsubscribing; carries the sender address and what was done.
+    UnsubscribeDone(Result<(String, Unsubscribed), GmailError>),
+}
+
+/// How an unsubscribe request was carried out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unsubscribed {
+    /// The sender accepted a one-click unsubscribe
+    OneClick,
+    /// An unsubscribe email was saved to Drafts for the user to send
+    Drafted,
+}
+
+/// Batch operation applied to a multi-selection of messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GmailBatchOp {
+    MarkRead,
+    Archive,
+    Trash,
+}
+
+/// Gmail API client using the configured proxy, timeout, retries, HTTP debug
+/// capture and metrics.
+pub(crate) fn gmail_client(access_token: &str) -> GmailClient {
+    let config = myme_core::Config::load_cached();
+    let network = config.network.for_service("gmail");
+    GmailClient::new_with_timeout(access_token, config.proxy.proxies_for("gmail"), network.timeout)
+        .with_retry_config(RetryConfig {
+            max_retries: network.max_retries,
+            initial_delay: network.backoff_base,
+            max_delay: network.max_backoff,
+        })
+        .with_observer(myme_core::metrics::http_observer("gmail"))
+}
+
+/// Path of the offline action queue database.
+pub fn sync_queue_path() -> PathBuf {
+    get_google_cache_path("gmail_sync_queue.db")
+}
+
+/// Path of the local mail rules database.
+pub fn rules_path() -> PathBuf {
+    get_google_cache_path("gmail_rules.db")
+}
+
+/// Mail rules in the order they run.
+pub fn list_rules() -> Result<Vec<Rule>, GmailError> {
+    RuleStore::new(rules_path())
+        .and_then(|store| store.list())
+        .map_err(|e| GmailError::Network(e.to_string()))
+}
+
+/// Create (`id` 0) or update a mail rule; returns its ID.
+pub fn save_rule(rule: &Rule) -> Result<i64, GmailError> {
+    RuleStore::new(rules_path())
+        .and_then(|store| store.save(rule))
+        .map_err(|e| GmailError::Network(e.to_string()))
+}
+
+/// Delete a mail rule; returns true if it existed.
+pub fn delete_rule(id: i64) -> Result<bool, GmailError> {
+    RuleStore::new(rules_path())
+        .and_then(|store| store.delete(id))
+        .map_err(|e| GmailError::Network(e.to_string()))
+}
+
+/// Run mail rules on the messages in `new_ids` (not seen before), applying
+/// their changes to `messages` and queueing the same changes for Gmail.
+/// Returns how many actions were queued.
+async fn apply_mail_rules(
+    client: &GmailClient,
+    cache_path: &Path,
+    messages: &mut [Message],
+    new_ids: &[String],
+) -> usize {
+    if new_ids.is_empty() {
+        return 0;
+    }
+    let rules = match list_rules() {
+        Ok(rules) if !rules.is_empty() => rules,
+        Ok(_) => return 0,
+        Err(e) => {
+            tracing::warn!("Failed to load mail rules: {}", e);
+            return 0;
+        }
+    };
+
+    // Rules name labels the way the user sees them; Gmail wants label IDs
+    let wants_labels =
+        rules.iter().flat_map(|r| &r.actions).any(|a| matches!(a, RuleAction::Label(_)));
+    let fetched = if wants_labels { Some(client.list_labels().await) } else { None };
+    let labels = match (fetched, GmailCache::new(cache_path)) {
+        (Some(Ok(labels)), Ok(cache)) => {
+            for label in &labels {
+                let _ = cache.store_label(label);
+            }
+            labels
+        }
+        (Some(Ok(labels)), Err(_)) => labels,
+        (Some(Err(_)), Ok(cache)) => cache.list_labels().unwrap_or_default(),
+        _ => Vec::new(),
+    };
+    let label_id = |name: &str| {
+        labels
+            .iter()
+            .find(|l| l.id == name || l.name.eq_ignore_ascii_case(name))
+            .map(|l| l.id.clone())
+            .unwrap_or_else(|| name.to_string())
+    };
+
+    let queue = match SyncQueue::new(sync_queue_path()) {
+        Ok(queue) => queue,
+        Err(e) => {
+            tracing::warn!("Failed to open Gmail sync queue for rules: {}", e);
+            return 0;
+        }
+    };
+    let mut queued = 0;
+    for msg in messages.iter_mut().filter(|m| new_ids.contains(&m.id)) {
+        for action in apply_rules(&rules, msg, label_id) {
+            match queue.enqueue(action) {
+                Ok(_) => queued += 1,
+                Err(e) => tracing::warn!("Failed to queue rule action for {}: {}", msg.id, e),
+            }
+        }
+    }
+    if queued > 0 {
+        tracing::info!("Mail rules queued {} Gmail action(s)", queued);
+        journal_pending(&queue);
+    }
+    queued
+}
+
+/// Queue an action to replay once we are back online.
+fn enqueue_offline(action: SyncAction) -> Result<(), GmailError> {
+    let queue =
+        SyncQueue::new(sync_queue_path()).map_err(|e| GmailError::Network(e.to_string()))?;
+    queue.enqueue(action).map_err(|e| GmailError::Network(e.to_string()))?;
+    tracing::info!("Offline: queued Gmail action for later sync");
+    journal_pending(&queue);
+    Ok(())
+}
+
+/// Keep the crash journal's and metrics' count of queued Gmail actions current.
+fn journal_pending(queue: &SyncQueue) {
+    if let Ok(count) = queue.pending_count() {
+        myme_core::app::journal().set_pending("gmail", count);
+        myme_core::metrics::set_queue_depth("gmail", count as usize);
+    }
+}
+
+async fn apply_action(
+    client: &GmailClient,
+    action: &SyncAction,
+) -> Result<(), myme_gmail::GmailError> {
+    match action {
+        SyncAction::MarkRead { message_id } => client.mark_as_read(message_id).await,
+        SyncAction::MarkUnread { message_id } => client.mark_as_unread(message_id).await,
+        SyncAction::Star { message_id } => client.star_message(message_id).await,
+        SyncAction::Unstar { message_id } => client.unstar_message(message_id).await,
+        SyncAction::Archive { message_id } => client.archive_message(message_id).await,
+        SyncAction::Trash { message_id } => client.trash_message(message_id).await,
+        SyncAction::AddLabels { message_id, labels } => {
+            let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+            client.modify_labels(message_id, &labels, &[]).await
+        }
+        SyncAction::RemoveLabels { message_id, labels } => {
+            let labels: Vec<&str> = labels.iter().map(String::as_str).collect();
+            client.modify_labels(message_id, &[], &labels).await
+        }
+        SyncAction::BatchMarkRead { message_ids } => client.batch_mark_as_read(message_ids).await,
+        SyncAction::BatchArchive { message_ids } => client.batch_archive(message_ids).await,
+        SyncAction::BatchTrash { message_ids } => client.batch_trash(message_ids).await,
+    }
+}
+
+/// Record an archive or trash of `message_ids` in the activity log.
+fn record_activity(op: GmailBatchOp, message_ids: &[String]) {
+    let (action, verb, undo) = match op {
+        GmailBatchOp::MarkRead => return,
+        GmailBatchOp::Archive => {
+            ("archived", "Archived", UndoHint::GmailUnarchive { message_ids: message_ids.to_vec() })
+        }
+        GmailBatchOp::Trash => {
+            ("trashed", "Trashed", UndoHint::GmailUntrash { message_ids: message_ids.to_vec() })
+        }
+    };
+    let summary = match message_ids {
+        [id] => {
+            let cached = GmailCache::new(get_google_cache_path("gmail_cache.db"))
+                .ok()
+                .and_then(|cache| cache.get_message(id).ok().flatten());
+            myme_core::metrics::record_cache_lookup(
+                "gmail",
+                if cached.is_some() { "hit" } else { "miss" },
+            );
+            let subject =
+                cached.map(|m| activity_service::short_title(&m.subject)).unwrap_or_default();
+            format!("{} email \"{}\"", verb, subject)
+        }
+        ids => format!("{} {} emails", verb, ids.len()),
+    };
+    activity_service::record("gmail", action, &message_ids.join(","), &summary, Some(undo));
+}
+
+/// Replay actions queued while offline, oldest first.
+/// Stops at the first failure so actions keep their order; returns how many were sent.
+pub async fn flush_sync_queue(access_token: &str) -> Result<usize, GmailError> {
+    let queue =
+        SyncQueue::new(sync_queue_path()).map_err(|e| GmailError::Network(e.to_string()))?;
+    let pending = queue.list_pending().map_err(|e| GmailError::Network(e.to_string()))?;
+    if pending.is_empty() {
+        return Ok(0);
+    }
+
+    let started = Instant::now();
+    let client = gmail_client(access_token);
+    let mut sent = 0;
+    let mut ok = true;
+    for queued in pending {
+        if let Err(e) = apply_action(&client, &queued.action).await {
+            tracing::warn!("Failed to replay queued Gmail action {}: {}", queued.id, e);
+            let _ = queue.record_failure(queued.id, &e.to_string());
+            let _ = queue.remove_failed(MAX_SYNC_ATTEMPTS);
+            ok = false;
+            break;
+        }
+        let _ = queue.complete(queued.id);
+        sent += 1;
+    }
+    myme_core::metrics::record_sync("gmail_offline_queue", started.elapsed(), ok);
+    journal_pending(&queue);
+    tracing::info!("Replayed {} queued Gmail action(s)", sent);
+    Ok(sent)
+}
+
+/// The configured offline body window.
+fn prefetch_policy() -> PrefetchPolicy {
+    PrefetchPolicy::new(myme_core::Config::load_cached().gmail.prefetch_days)
+}
+
+/// Bytes of mail bodies and attachments held offline.
+pub fn cache_usage(cache_path: &Path) -> Option<CacheUsage> {
+    GmailCache::new(cache_path).and_then(|cache| cache.cache_size()).ok()
+}
+
+/// Cache usage for display, e.g. "12.3 MB of 500 MB" ("12.3 MB" without a quota).
+pub fn describe_cache_usage(usage: CacheUsage, quota: Option<u64>) -> String {
+    let mb = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+    match quota {
+        Some(max) => format!("{:.1} MB of {:.0} MB", mb(usage.total()), mb(max)),
+        None => format!("{:.1} MB", mb(usage.total())),
+    }
+}
+
+/// Bring the offline cache in line with `[gmail]` settings: fetch missing
+/// bodies inside the prefetch window while under quota, drop bodies outside
+/// it, then evict least recently used data down to the quota.
+async fn maintain_cache(client: &GmailClient, cache_path: &Path) {
+    let config = myme_core::Config::load_cached();
+    let policy = PrefetchPolicy::new(config.gmail.prefetch_days);
+    let quota = config.gmail.cache_quota_bytes();
+    let now = Utc::now();
+    let Ok(cache) = GmailCache::new(cache_path) else {
+        return;
+    };
+
+    let under_quota = quota.is_none_or(|max| cache.cache_size().is_ok_and(|u| u.total() < max));
+    if under_quota && connectivity::is_online() {
+        for id in cache.messages_missing_body(&policy, now, PREFETCH_BATCH).unwrap_or_default() {
+            match client.get_message(&id).await {
+                Ok(msg) => {
+                    let _ = cache.store_message_with_policy(&msg, &policy, now);
+                }
+                Err(e) => tracing::debug!("Failed to prefetch message {}: {}", id, e),
+            }
+        }
+    }
+
+    match cache.apply_prefetch_policy(&policy, now) {
+        Ok(0) => {}
+        Ok(n) => tracing::debug!("Dropped {} mail bodies outside the prefetch window", n),
+        Err(e) => tracing::warn!("Failed to apply mail prefetch policy: {}", e),
+    }
+    if let Some(max) = quota {
+        match cache.enforce_quota(max) {
+            Ok(ev) if ev.freed > 0 => tracing::info!(
+                "Mail cache over quota: evicted {} bodies and {} attachments ({} bytes)",
+                ev.bodies,
+                ev.attachments,
+                ev.freed
+            ),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to enforce mail cache quota: {}", e),
+        }
+    }
 }
 
 /// Request to fetch messages asynchronously.
@@ -50,9 +372,16 @@ pub fn request_fetch(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let _ = tx.send(GmailServiceMessage::FetchDone(Err(GmailError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
 
     runtime.spawn(async move {
-        let client = GmailClient::new(&access_token);
+        let started = Instant::now();
+        let client = gmail_client(&access_token);
 
         let result = async {
             let list_response = client
@@ -68,15 +397,37 @@ pub fn request_fetch(
                 }
             }
 
+            let new_ids: Vec<String> = match GmailCache::new(&cache_path) {
+                Ok(cache) => messages
+                    .iter()
+                    .filter(|m| matches!(cache.get_message(&m.id), Ok(None)))
+                    .map(|m| m.id.clone())
+                    .collect(),
+                Err(_) => Vec::new(),
+            };
+            let rule_actions =
+                apply_mail_rules(&client, &cache_path, &mut messages, &new_ids).await;
             if let Ok(cache) = GmailCache::new(&cache_path) {
+                let (policy, now) = (prefetch_policy(), Utc::now());
                 for msg in &messages {
-                    let _ = cache.store_message(msg);
+                    let _ = cache.store_message_with_policy(msg, &policy, now);
+                }
+                // An archive queued while offline hasn't reached the server yet
+                messages.retain(|m| !matches!(cache.snoozed_until(&m.id), Ok(Some(_))));
+            }
+            if rule_actions > 0 {
+                if let Err(e) = flush_sync_queue(&access_token).await {
+                    tracing::warn!("Failed to send mail rule actions: {}", e);
                 }
+                // Archived by a rule
+                messages.retain(|m| m.labels.iter().any(|l| l == "INBOX"));
             }
+            maintain_cache(&client, &cache_path).await;
 
             Ok(messages)
         }
         .await;
+        myme_core::metrics::record_sync("gmail_inbox", started.elapsed(), result.is_ok());
 
         let _ = tx.send(GmailServiceMessage::FetchDone(result));
     });
@@ -96,9 +447,14 @@ pub fn request_mark_as_read(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let result = enqueue_offline(SyncAction::MarkRead { message_id: message_id.clone() });
+        let _ = tx.send(GmailServiceMessage::ActionDone(result.map(|_| message_id)));
+        return;
+    }
 
     runtime.spawn(async move {
-        let client = GmailClient::new(&access_token);
+        let client = gmail_client(&access_token);
         let result = client
             .mark_as_read(&message_id)
             .await
@@ -122,14 +478,25 @@ pub fn request_archive(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let result = enqueue_offline(SyncAction::Archive { message_id: message_id.clone() });
+        if result.is_ok() {
+            record_activity(GmailBatchOp::Archive, std::slice::from_ref(&message_id));
+        }
+        let _ = tx.send(GmailServiceMessage::ActionDone(result.map(|_| message_id)));
+        return;
+    }
 
     runtime.spawn(async move {
-        let client = GmailClient::new(&access_token);
+        let client = gmail_client(&access_token);
         let result = client
             .archive_message(&message_id)
             .await
             .map(|_| message_id)
             .map_err(|e| GmailError::Network(e.to_string()));
+        if let Ok(id) = &result {
+            record_activity(GmailBatchOp::Archive, std::slice::from_ref(id));
+        }
         let _ = tx.send(GmailServiceMessage::ActionDone(result));
     });
 }
@@ -148,14 +515,335 @@ pub fn request_trash(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let result = enqueue_offline(SyncAction::Trash { message_id: message_id.clone() });
+        if result.is_ok() {
+            record_activity(GmailBatchOp::Trash, std::slice::from_ref(&message_id));
+        }
+        let _ = tx.send(GmailServiceMessage::ActionDone(result.map(|_| message_id)));
+        return;
+    }
 
     runtime.spawn(async move {
-        let client = GmailClient::new(&access_token);
+        let client = gmail_client(&access_token);
         let result = client
             .trash_message(&message_id)
             .await
             .map(|_| message_id)
             .map_err(|e| GmailError::Network(e.to_string()));
+        if let Ok(id) = &result {
+            record_activity(GmailBatchOp::Trash, std::slice::from_ref(id));
+        }
         let _ = tx.send(GmailServiceMessage::ActionDone(result));
     });
 }
+
+/// Request a batch operation on several messages (one batchModify round trip).
+pub fn request_batch(
+    tx: &std::sync::mpsc::Sender<GmailServiceMessage>,
+    access_token: String,
+    message_ids: Vec<String>,
+    op: GmailBatchOp,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(GmailServiceMessage::BatchActionDone(Err(GmailError::NotInitialized)));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let ids = message_ids.clone();
+        let action = match op {
+            GmailBatchOp::MarkRead => SyncAction::BatchMarkRead { message_ids: ids },
+            GmailBatchOp::Archive => SyncAction::BatchArchive { message_ids: ids },
+            GmailBatchOp::Trash => SyncAction::BatchTrash { message_ids: ids },
+        };
+        let result = enqueue_offline(action).map(|_| message_ids);
+        if let Ok(ids) = &result {
+            record_activity(op, ids);
+        }
+        let _ = tx.send(GmailServiceMessage::BatchActionDone(result));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let client = gmail_client(&access_token);
+        let result = match op {
+            GmailBatchOp::MarkRead => client.batch_mark_as_read(&message_ids).await,
+            GmailBatchOp::Archive => client.batch_archive(&message_ids).await,
+            GmailBatchOp::Trash => client.batch_trash(&message_ids).await,
+        };
+        let result = result.map(|_| message_ids).map_err(|e| GmailError::Network(e.to_string()));
+        if let Ok(ids) = &result {
+            record_activity(op, ids);
+        }
+        let _ = tx.send(GmailServiceMessage::BatchActionDone(result));
+    });
+}
+
+/// Request to fetch a full conversation (all replies) asynchronously.
+pub fn request_thread(
+    tx: &std::sync::mpsc::Sender<GmailServiceMessage>,
+    access_token: String,
+    thread_id: String,
+    cache_path: PathBuf,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(GmailServiceMessage::ThreadDone(Err(GmailError::NotInitialized)));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(GmailServiceMessage::ThreadDone(Err(GmailError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let client = gmail_client(&access_token);
+        let result =
+            client.get_thread(&thread_id).await.map_err(|e| GmailError::Network(e.to_string()));
+
+        if let Ok(thread) = &result {
+            if let Ok(cache) = GmailCache::new(&cache_path) {
+                let _ = cache.store_thread(thread);
+                // Opened conversations keep their bodies past the prefetch window
+                let now = Utc::now();
+                for msg in &thread.messages {
+                    let _ = cache.touch_message(&msg.id, now);
+                }
+            }
+        }
+
+        let _ = tx.send(GmailServiceMessage::ThreadDone(result));
+    });
+}
+
+/// Request to download an attachment into the attachment cache directory.
+pub fn request_attachment(
+    tx: &std::sync::mpsc::Sender<GmailServiceMessage>,
+    access_token: String,
+    attachment: Attachment,
+    download_dir: PathBuf,
+    cache_path: PathBuf,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(GmailServiceMessage::AttachmentDone(Err(GmailError::NotInitialized)));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(GmailServiceMessage::AttachmentDone(Err(GmailError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let client = gmail_client(&access_token);
+        let result = client
+            .get_attachment(
+                &attachment.message_id,
+                &attachment.attachment_id,
+                &attachment.filename,
+                &download_dir,
+            )
+            .await
+            .map_err(|e| GmailError::Network(e.to_string()))
+            .map(|path| Attachment {
+                local_path: Some(path.to_string_lossy().into_owned()),
+                ..attachment
+            });
+
+        if let Ok(att) = &result {
+            if let (Ok(cache), Some(path)) = (GmailCache::new(&cache_path), &att.local_path) {
+                let _ = cache.store_attachments(std::slice::from_ref(att));
+                let _ = cache.set_attachment_path(&att.message_id, &att.attachment_id, path);
+            }
+        }
+
+        let _ = tx.send(GmailServiceMessage::AttachmentDone(result));
+    });
+}
+
+/// Request to snooze a message until `until`: it leaves the inbox now (archived
+/// on the server, or queued while offline) and comes back when
+/// [`resurface_snoozed`] finds it due.
+pub fn request_snooze(
+    tx: &std::sync::mpsc::Sender<GmailServiceMessage>,
+    access_token: String,
+    message_id: String,
+    until: DateTime<Utc>,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(GmailServiceMessage::SnoozeDone(Err(GmailError::NotInitialized)));
+            return;
+        }
+    };
+
+    let snoozed = GmailCache::new(get_google_cache_path("gmail_cache.db"))
+        .and_then(|cache| cache.snooze(&message_id, until))
+        .map_err(|e| GmailError::Network(e.to_string()));
+    if let Err(e) = snoozed {
+        let _ = tx.send(GmailServiceMessage::SnoozeDone(Err(e)));
+        return;
+    }
+    if !connectivity::is_online() {
+        let result = enqueue_offline(SyncAction::Archive { message_id: message_id.clone() });
+        let _ = tx.send(GmailServiceMessage::SnoozeDone(result.map(|_| message_id)));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let client = gmail_client(&access_token);
+        let result = match client.archive_message(&message_id).await {
+            Ok(()) => Ok(message_id),
+            // Still snoozed locally; archive on the server once we can
+            Err(e) => {
+                tracing::warn!("Failed to archive snoozed message {}: {}", message_id, e);
+                enqueue_offline(SyncAction::Archive { message_id: message_id.clone() })
+                    .map(|_| message_id)
+            }
+        };
+        let _ = tx.send(GmailServiceMessage::SnoozeDone(result));
+    });
+}
+
+/// Return snoozed messages that are due to the inbox (on the server, or queued
+/// while offline) and raise a desktop notification. Returns the cached copies
+/// of the resurfaced messages.
+pub async fn resurface_snoozed() -> Vec<Message> {
+    let cache = match GmailCache::new(get_google_cache_path("gmail_cache.db")) {
+        Ok(cache) => cache,
+        Err(e) => {
+            tracing::warn!("Failed to open Gmail cache for snoozes: {}", e);
+            return Vec::new();
+        }
+    };
+    let due = match cache.take_due_snoozes(Utc::now()) {
+        Ok(due) if !due.is_empty() => due,
+        Ok(_) => return Vec::new(),
+        Err(e) => {
+            tracing::warn!("Failed to read snoozed messages: {}", e);
+            return Vec::new();
+        }
+    };
+
+    // Token refresh blocks on its own runtime, so keep it off the async workers
+    let token = if connectivity::is_online() {
+        tokio::task::spawn_blocking(get_google_access_token).await.ok().flatten()
+    } else {
+        None
+    };
+    let client = token.as_deref().map(gmail_client);
+    for snooze in &due {
+        let unarchived = match &client {
+            Some(client) => client.modify_labels(&snooze.message_id, &["INBOX"], &[]).await.is_ok(),
+            None => false,
+        };
+        if !unarchived {
+            let _ = enqueue_offline(SyncAction::AddLabels {
+                message_id: snooze.message_id.clone(),
+                labels: vec!["INBOX".to_string()],
+            });
+        }
+    }
+
+    let messages: Vec<Message> =
+        due.iter().filter_map(|s| cache.get_message(&s.message_id).ok().flatten()).collect();
+    match messages.as_slice() {
+        [msg] => notify_desktop("Snoozed email is back", &msg.subject),
+        _ => notify_desktop(
+            "Snoozed emails are back",
+            &format!("{} emails are back in your inbox", due.len()),
+        ),
+    };
+    tracing::info!("Resurfaced {} snoozed Gmail message(s)", due.len());
+    messages
+}
+
+/// Request to unsubscribe from `sender` using `target`: a one-click POST when
+/// the sender supports it, otherwise (or if that fails) the mailto saved as a
+/// draft. Link-only targets are opened by the caller instead.
+pub fn request_unsubscribe(
+    tx: &std::sync::mpsc::Sender<GmailServiceMessage>,
+    access_token: String,
+    sender: String,
+    target: UnsubscribeTarget,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(GmailServiceMessage::UnsubscribeDone(Err(GmailError::NotInitialized)));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(GmailServiceMessage::UnsubscribeDone(Err(GmailError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let client = gmail_client(&access_token);
+        let mut one_click_error = None;
+        if let Some(url) = target.one_click_url() {
+            match client.one_click_unsubscribe(url).await {
+                Ok(()) => {
+                    if let Ok(cache) = GmailCache::new(get_google_cache_path("gmail_cache.db")) {
+                        let _ = cache.mark_unsubscribed(&sender, Utc::now());
+                    }
+                    tracing::info!("Unsubscribed from {} with one click", sender);
+                    let _ = tx.send(GmailServiceMessage::UnsubscribeDone(Ok((
+                        sender,
+                        Unsubscribed::OneClick,
+                    ))));
+                    return;
+                }
+                Err(e) => {
+                    tracing::warn!("One-click unsubscribe from {} failed: {}", sender, e);
+                    one_click_error = Some(e.to_string());
+                }
+            }
+        }
+
+        let result = match target.mailto.as_deref().and_then(MailtoDraft::parse) {
+            Some(draft) => client
+                .create_draft(&draft.to, &draft.subject, &draft.body)
+                .await
+                .map(|_| (sender, Unsubscribed::Drafted))
+                .map_err(|e| GmailError::Network(e.to_string())),
+            None => Err(GmailError::Network(
+                one_click_error.unwrap_or_else(|| "No unsubscribe address for sender".into()),
+            )),
+        };
+        let _ = tx.send(GmailServiceMessage::UnsubscribeDone(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn cache_usage_reads_in_megabytes() {
+        let usage = CacheUsage { bodies: 10 * 1024 * 1024, attachments: 2_516_582 };
+        assert_eq!(describe_cache_usage(usage, Some(500 * 1024 * 1024)), "12.4 MB of 500 MB");
+        assert_eq!(describe_cache_usage(CacheUsage::default(), None), "0.0 MB");
+    }
+}
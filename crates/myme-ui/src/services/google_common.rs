@@ -26,7 +26,8 @@ pub fn get_google_access_token() -> Option<String> {
         let refresh_token = token_set.refresh_token.as_ref()?;
         let (client_id, client_secret) = get_google_config()?;
         let rt = tokio::runtime::Runtime::new().ok()?;
-        let provider = GoogleOAuth2Provider::new(client_id, client_secret);
+        let proxies = myme_core::Config::load_cached().proxy.proxies_for("google_auth");
+        let provider = GoogleOAuth2Provider::new(client_id, client_secret).with_proxies(proxies);
 
         let new_tokens = rt.block_on(provider.refresh_token(refresh_token)).ok()?;
         let expires_at = chrono::Utc::now().timestamp() + new_tokens.expires_in as i64;
@@ -0,0 +1,173 @@
+//! Image cache backend: remote avatars and thumbnails kept on disk so QML
+//! loads a local file instead of downloading on every view. Downloads run
+//! off the UI thread, one per URL however many views ask for it; models
+//! pick up finished downloads by watching [`generation`].
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant, SystemTime};
+
+use myme_core::connectivity;
+use myme_services::{CachedImage, ImageCache};
+use parking_lot::Mutex;
+
+use crate::bridge;
+
+/// Shown images are downloaded again after this; the old copy shows meanwhile
+const IMAGE_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Images not downloaded again in this long are deleted at startup
+const IMAGE_MAX_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// A URL whose download failed isn't tried again sooner than this
+const RETRY_AFTER: Duration = Duration::from_secs(10 * 60);
+
+/// Downloads under way and recent failures
+#[derive(Debug, Default)]
+struct Downloads {
+    pending: HashSet<String>,
+    failed: HashMap<String, Instant>,
+}
+
+impl Downloads {
+    /// Claim `url` for download; false when it's already downloading or
+    /// failed within [`RETRY_AFTER`].
+    fn begin(&mut self, url: &str, now: Instant) -> bool {
+        if self.failed.get(url).is_some_and(|at| now.duration_since(*at) < RETRY_AFTER) {
+            return false;
+        }
+        self.pending.insert(url.to_string())
+    }
+
+    fn finish(&mut self, url: &str, ok: bool, now: Instant) {
+        self.pending.remove(url);
+        if ok {
+            self.failed.remove(url);
+        } else {
+            self.failed.insert(url.to_string(), now);
+        }
+    }
+}
+
+struct Images {
+    cache: Arc<ImageCache>,
+    downloads: Mutex<Downloads>,
+    generation: AtomicU64,
+}
+
+static IMAGES: OnceLock<Images> = OnceLock::new();
+
+fn images() -> &'static Images {
+    IMAGES.get_or_init(|| {
+        let dir =
+            dirs::cache_dir().unwrap_or_else(|| PathBuf::from(".")).join("myme").join("images");
+        let proxies = myme_core::Config::load_cached().proxy.proxies_for("images");
+        let cache = Arc::new(ImageCache::new(dir, IMAGE_TTL, proxies));
+        if let Some(runtime) = bridge::get_runtime() {
+            let cache = cache.clone();
+            runtime.spawn_blocking(move || {
+                let removed = cache.prune(IMAGE_MAX_AGE, SystemTime::now());
+                if removed > 0 {
+                    tracing::info!("Removed {} unused cached images", removed);
+                }
+            });
+        }
+        Images { cache, downloads: Mutex::default(), generation: AtomicU64::new(0) }
+    })
+}
+
+/// Bumped whenever a download lands; sources from [`source`] may have changed.
+pub fn generation() -> u64 {
+    IMAGES.get().map_or(0, |images| images.generation.load(Ordering::Relaxed))
+}
+
+/// What QML should load for `url`: a `file://` URL once cached, empty while
+/// the first download runs. A stale copy is used while it's downloaded
+/// again. Other schemes (`file:`, `qrc:`) pass through.
+pub fn source(url: &str) -> String {
+    if !(url.starts_with("https://") || url.starts_with("http://")) {
+        return url.to_string();
+    }
+    let images = images();
+    match images.cache.lookup(url, SystemTime::now()) {
+        CachedImage::Fresh(path) => file_url(&path),
+        CachedImage::Stale(path) => {
+            request_download(images, url);
+            file_url(&path)
+        }
+        CachedImage::Missing => {
+            request_download(images, url);
+            String::new()
+        }
+    }
+}
+
+fn file_url(path: &Path) -> String {
+    url::Url::from_file_path(path).map(String::from).unwrap_or_default()
+}
+
+/// Download `url` in the background unless it's under way, failed recently,
+/// or we're offline.
+fn request_download(images: &'static Images, url: &str) {
+    if !connectivity::is_online() {
+        return;
+    }
+    let Some(runtime) = bridge::get_runtime() else {
+        return;
+    };
+    if !images.downloads.lock().begin(url, Instant::now()) {
+        return;
+    }
+
+    let url = url.to_string();
+    runtime.spawn(async move {
+        let result = images.cache.fetch(&url).await;
+        if let Err(e) = &result {
+            tracing::debug!("Image download failed for {}: {:#}", url, e);
+        }
+        images.downloads.lock().finish(&url, result.is_ok(), Instant::now());
+        if result.is_ok() {
+            images.generation.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    const URL: &str = "https://avatars.example.com/u/1";
+
+    #[test]
+    fn downloads_run_once_per_url() {
+        let mut downloads = Downloads::default();
+        let now = Instant::now();
+        assert!(downloads.begin(URL, now));
+        assert!(!downloads.begin(URL, now));
+        assert!(downloads.begin("https://avatars.example.com/u/2", now));
+
+        downloads.finish(URL, true, now);
+        assert!(downloads.begin(URL, now));
+    }
+
+    #[test]
+    fn failed_downloads_wait_before_retrying() {
+        let mut downloads = Downloads::default();
+        let now = Instant::now();
+        assert!(downloads.begin(URL, now));
+        downloads.finish(URL, false, now);
+
+        assert!(!downloads.begin(URL, now + Duration::from_secs(60)));
+        assert!(downloads.begin(URL, now + RETRY_AFTER));
+    }
+
+    #[test]
+    fn non_web_sources_pass_through() {
+        assert_eq!(source(""), "");
+        assert_eq!(source("qrc:/icons/app.png"), "qrc:/icons/app.png");
+        assert_eq!(source("file:///tmp/a.png"), "file:///tmp/a.png");
+    }
+}
@@ -0,0 +1,137 @@
+//! Import backend: read a Todoist, Google Keep or Trello export, preview what
+//! it would add, and create the notes and kanban tasks.
+//! File and database work runs off the UI thread; reports sent via mpsc.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use myme_services::importers::{self, ImportPlan};
+use myme_services::{ImportReport, ImportSource, NoteBackend, NoteClient};
+
+use super::activity_service;
+use crate::bridge;
+
+/// Error type for imports
+#[derive(Debug, Clone)]
+pub enum ImportError {
+    /// The file couldn't be read or isn't the chosen format
+    Parse(String),
+    /// Notes or tasks couldn't be read or saved
+    Store(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportError::Parse(s) => write!(f, "Import file error: {}", s),
+            ImportError::Store(s) => write!(f, "Import storage error: {}", s),
+            ImportError::NotInitialized => write!(f, "Import service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum ImportServiceMessage {
+    /// Dry run: what the import would create
+    PreviewDone(Result<ImportReport, ImportError>),
+    /// What the import created
+    ImportDone(Result<ImportReport, ImportError>),
+}
+
+/// Parse `path` as `source` and plan against existing notes and the tasks of
+/// `project_id`; duplicates are dropped from the plan
+fn plan(
+    client: &NoteClient,
+    path: &std::path::Path,
+    source: ImportSource,
+    project_id: Option<&str>,
+) -> Result<ImportPlan, ImportError> {
+    let batch =
+        importers::parse_path(path, source).map_err(|e| ImportError::Parse(format!("{:#}", e)))?;
+
+    let existing_notes = if batch.notes.is_empty() {
+        Vec::new()
+    } else {
+        let store = client.sqlite_store();
+        let store = store.lock();
+        let mut notes = store.list().map_err(|e| ImportError::Store(e.to_string()))?;
+        notes.extend(store.list_archived().map_err(|e| ImportError::Store(e.to_string()))?);
+        notes
+    };
+    let existing_tasks = match project_id {
+        Some(project_id) if !batch.tasks.is_empty() => bridge::get_project_store_or_init()
+            .ok_or(ImportError::NotInitialized)?
+            .list_tasks_for_project(project_id)
+            .map_err(|e| ImportError::Store(e.to_string()))?,
+        _ => Vec::new(),
+    };
+    Ok(importers::plan_import(batch, &existing_notes, &existing_tasks))
+}
+
+/// Preview (`dry_run`) or run an import of `path`. Tasks go to `project_id`.
+/// Sends `PreviewDone` or `ImportDone`.
+pub fn request_import(
+    tx: &std::sync::mpsc::Sender<ImportServiceMessage>,
+    client: Arc<NoteClient>,
+    path: PathBuf,
+    source: ImportSource,
+    project_id: Option<String>,
+    dry_run: bool,
+) {
+    let tx = tx.clone();
+    let done = move |result| {
+        if dry_run {
+            ImportServiceMessage::PreviewDone(result)
+        } else {
+            ImportServiceMessage::ImportDone(result)
+        }
+    };
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(done(Err(ImportError::NotInitialized)));
+        return;
+    };
+
+    runtime.spawn_blocking(move || {
+        let result = plan(&client, &path, source, project_id.as_deref()).and_then(|plan| {
+            if dry_run {
+                return Ok(plan.preview());
+            }
+            let store = bridge::get_project_store_or_init().ok_or(ImportError::NotInitialized)?;
+            let notes = client.sqlite_store();
+            let notes = notes.lock();
+            importers::apply_import(&plan, &*notes, &store, project_id.as_deref())
+                .map_err(|e| ImportError::Store(format!("{:#}", e)))
+        });
+        if let (Ok(report), false) = (&result, dry_run) {
+            activity_service::record(
+                "import",
+                "imported",
+                source.as_str(),
+                &format!(
+                    "Imported {} notes and {} tasks from {}",
+                    report.notes,
+                    report.tasks,
+                    source.label()
+                ),
+                None,
+            );
+        }
+        let _ = tx.send(done(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn import_error_display() {
+        assert!(format!("{}", ImportError::Parse("bad csv".into())).contains("bad csv"));
+        assert!(format!("{}", ImportError::NotInitialized).contains("not initialized"));
+    }
+}
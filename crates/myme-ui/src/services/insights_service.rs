@@ -0,0 +1,203 @@
+//! GitHub insights backend: stars, traffic, contributors and community health
+//! for one repository. Results are cached per repo for a few minutes since
+//! GitHub only recomputes traffic hourly. Network work runs off the UI thread.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use myme_core::connectivity;
+use myme_services::{CommunityProfile, GitHubClient, GitHubContributor, GitHubRepo, RepoTraffic};
+
+use super::repo_service;
+use crate::bridge;
+
+/// How long fetched insights are reused before asking GitHub again
+const INSIGHTS_CACHE_TTL_MINUTES: i64 = 15;
+
+/// Error type for insights operations
+#[derive(Debug, Clone)]
+pub enum InsightsError {
+    Network(String),
+    /// Not an `owner/repo` name
+    InvalidRepo(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for InsightsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InsightsError::Network(s) => write!(f, "Insights error: {}", s),
+            InsightsError::InvalidRepo(s) => write!(f, "Not a repository name: {}", s),
+            InsightsError::NotInitialized => write!(f, "Insights service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for InsightsError {}
+
+/// Everything the insights panel shows for one repository
+#[derive(Debug, Clone)]
+pub struct RepoInsights {
+    pub repo: GitHubRepo,
+    /// None when the token lacks push access (GitHub only shows traffic to maintainers)
+    pub traffic: Option<RepoTraffic>,
+    pub contributors: Vec<GitHubContributor>,
+    /// None for repos without a community profile (e.g. private repos)
+    pub community: Option<CommunityProfile>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum InsightsServiceMessage {
+    ReposDone(Result<Vec<GitHubRepo>, InsightsError>),
+    FetchDone(Result<RepoInsights, InsightsError>),
+}
+
+static INSIGHTS_CACHE: std::sync::OnceLock<std::sync::Mutex<HashMap<String, RepoInsights>>> =
+    std::sync::OnceLock::new();
+
+fn insights_cache() -> &'static std::sync::Mutex<HashMap<String, RepoInsights>> {
+    INSIGHTS_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()))
+}
+
+fn is_fresh(fetched_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    now - fetched_at < Duration::minutes(INSIGHTS_CACHE_TTL_MINUTES)
+}
+
+/// Cached insights for `full_name` that are still within the TTL
+pub fn cached(full_name: &str) -> Option<RepoInsights> {
+    let cache = insights_cache().lock().ok()?;
+    cache.get(full_name).filter(|i| is_fresh(i.fetched_at, Utc::now())).cloned()
+}
+
+fn set_cached(full_name: &str, insights: &RepoInsights) {
+    if let Ok(mut cache) = insights_cache().lock() {
+        cache.insert(full_name.to_string(), insights.clone());
+    }
+}
+
+/// Split `owner/repo`, rejecting anything else
+fn split_full_name(full_name: &str) -> Option<(String, String)> {
+    let (owner, repo) = full_name.trim().split_once('/')?;
+    if owner.is_empty() || repo.is_empty() || repo.contains('/') {
+        return None;
+    }
+    Some((owner.to_string(), repo.to_string()))
+}
+
+/// Runtime for a request, or None after reporting why there isn't one
+fn runtime_or_offline<F>(
+    tx: &std::sync::mpsc::Sender<InsightsServiceMessage>,
+    fail: F,
+) -> Option<tokio::runtime::Handle>
+where
+    F: FnOnce(InsightsError) -> InsightsServiceMessage,
+{
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(fail(InsightsError::NotInitialized));
+        return None;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(fail(InsightsError::Network(connectivity::OFFLINE_ERROR.into())));
+        return None;
+    }
+    Some(runtime)
+}
+
+/// Request the user's repositories to pick from, reusing the Repos page's
+/// listing when there is one. Sends `ReposDone`.
+pub fn request_repos(
+    tx: &std::sync::mpsc::Sender<InsightsServiceMessage>,
+    client: Arc<GitHubClient>,
+) {
+    if let Some(repos) = repo_service::last_github_repos() {
+        let _ = tx.send(InsightsServiceMessage::ReposDone(Ok(repos)));
+        return;
+    }
+    let tx = tx.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| InsightsServiceMessage::ReposDone(Err(e)))
+    else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = repo_service::fetch_github_repos(&client)
+            .await
+            .map_err(|e| InsightsError::Network(e.to_string()));
+        let _ = tx.send(InsightsServiceMessage::ReposDone(result));
+    });
+}
+
+/// Request insights for `full_name` (`owner/repo`), answering from the cache
+/// unless `force` is set. Sends `FetchDone`.
+pub fn request_fetch(
+    tx: &std::sync::mpsc::Sender<InsightsServiceMessage>,
+    client: Arc<GitHubClient>,
+    full_name: String,
+    force: bool,
+) {
+    let Some((owner, repo)) = split_full_name(&full_name) else {
+        let _ =
+            tx.send(InsightsServiceMessage::FetchDone(Err(InsightsError::InvalidRepo(full_name))));
+        return;
+    };
+    if !force {
+        if let Some(insights) = cached(&full_name) {
+            let _ = tx.send(InsightsServiceMessage::FetchDone(Ok(insights)));
+            return;
+        }
+    }
+    let tx = tx.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| InsightsServiceMessage::FetchDone(Err(e)))
+    else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let (details, traffic, contributors, community) = tokio::join!(
+            client.get_repo(&owner, &repo),
+            client.get_repo_traffic(&owner, &repo),
+            client.list_contributors(&owner, &repo),
+            client.get_community_profile(&owner, &repo),
+        );
+        let result = match (details, contributors, community) {
+            (Ok(details), Ok(contributors), Ok(community)) => {
+                let insights = RepoInsights {
+                    repo: details,
+                    traffic: traffic
+                        .inspect_err(|e| tracing::debug!("No traffic for {}: {}", full_name, e))
+                        .ok(),
+                    contributors,
+                    community,
+                    fetched_at: Utc::now(),
+                };
+                set_cached(&full_name, &insights);
+                Ok(insights)
+            }
+            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
+                Err(InsightsError::Network(e.to_string()))
+            }
+        };
+        let _ = tx.send(InsightsServiceMessage::FetchDone(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn full_names_and_cache_ttl() {
+        assert_eq!(split_full_name(" octo/repo "), Some(("octo".to_string(), "repo".to_string())));
+        assert_eq!(split_full_name("octo"), None);
+        assert_eq!(split_full_name("octo/"), None);
+        assert_eq!(split_full_name("a/b/c"), None);
+
+        let now = Utc::now();
+        assert!(is_fresh(now - Duration::minutes(5), now));
+        assert!(!is_fresh(now - Duration::minutes(INSIGHTS_CACHE_TTL_MINUTES), now));
+    }
+}
@@ -0,0 +1,89 @@
+//! Remote-control command handling for the local IPC socket (`myme_core::ipc`).
+//! Commands that need the window (show, refresh) are forwarded to QML via mpsc.
+
+use myme_calendar::{CalendarCache, DisplayZone, Event};
+use myme_core::ipc::{IpcCommand, IpcResponse};
+use myme_services::TodoCreateRequest;
+
+use crate::bridge;
+use crate::services::calendar_display_zone;
+use crate::services::google_common::get_google_cache_path;
+
+/// How far ahead `next_event` looks in the calendar cache
+const NEXT_EVENT_WINDOW_DAYS: i64 = 7;
+
+/// Messages sent from the IPC dispatcher to the UI thread
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IpcServiceMessage {
+    /// Show and raise the main window
+    ShowWindow,
+    /// Refresh what the UI shows (after offline queues were replayed)
+    SyncNow,
+}
+
+/// Handle one command and build the reply sent back over the socket.
+pub async fn handle_command(command: IpcCommand) -> IpcResponse {
+    match command {
+        IpcCommand::CreateNote { text } => create_note(text).await,
+        IpcCommand::ShowWindow => {
+            notify_ui(IpcServiceMessage::ShowWindow);
+            IpcResponse::ok()
+        }
+        IpcCommand::NextEvent => match tokio::task::spawn_blocking(next_cached_event).await {
+            Ok(Ok(Some(event))) => IpcResponse::with_data(event),
+            Ok(Ok(None)) => IpcResponse::with_data(serde_json::Value::Null),
+            Ok(Err(e)) => IpcResponse::error(e),
+            Err(e) => IpcResponse::error(e.to_string()),
+        },
+        IpcCommand::SyncNow => {
+            crate::app_services::flush_offline_queues().await;
+            notify_ui(IpcServiceMessage::SyncNow);
+            IpcResponse::ok()
+        }
+    }
+}
+
+async fn create_note(text: String) -> IpcResponse {
+    let content = text.trim().to_string();
+    if content.is_empty() {
+        return IpcResponse::error("Note text is empty");
+    }
+    let Some(client) = bridge::get_note_client_or_init() else {
+        return IpcResponse::error("Note service not initialized");
+    };
+    match client.create_todo(TodoCreateRequest { content, is_checklist: false }).await {
+        Ok(note) => IpcResponse::with_data(serde_json::json!({ "id": note.id })),
+        Err(e) => IpcResponse::error(e.to_string()),
+    }
+}
+
+/// Next event on the primary calendar, read from the local cache so widgets
+/// polling this don't hit the network.
+fn next_cached_event() -> Result<Option<serde_json::Value>, String> {
+    let cache = CalendarCache::new(get_google_cache_path("calendar_cache.db"))
+        .map_err(|e| e.to_string())?;
+    let now = chrono::Utc::now();
+    let zone = calendar_display_zone();
+    let events = cache
+        .list_events("primary", now, now + chrono::Duration::days(NEXT_EVENT_WINDOW_DAYS), &zone)
+        .map_err(|e| e.to_string())?;
+    Ok(events.first().map(|event| event_json(event, &zone)))
+}
+
+/// Floating and all-day times are placed in `zone`.
+fn event_json(event: &Event, zone: &DisplayZone) -> serde_json::Value {
+    serde_json::json!({
+        "summary": event.summary,
+        "start": event.start.instant_in(zone).to_rfc3339(),
+        "end": event.end.instant_in(zone).to_rfc3339(),
+        "all_day": event.all_day,
+        "location": event.location,
+        "url": event.conference_url.as_ref().or(event.html_link.as_ref()),
+    })
+}
+
+fn notify_ui(message: IpcServiceMessage) {
+    if let Some(tx) = bridge::get_ipc_service_tx() {
+        let _ = tx.send(message);
+    }
+}
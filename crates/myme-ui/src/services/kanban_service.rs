@@ -3,7 +3,11 @@
 
 use std::sync::Arc;
 
-use myme_services::{CreateIssueRequest, GitHubClient, UpdateIssueRequest};
+use myme_core::connectivity;
+use myme_services::{
+    checklist, CreateIssueRequest, GitHubClient, GitHubIssue, IssueTemplate, Task, TaskSchedule,
+    UpdateIssueRequest,
+};
 
 use crate::bridge;
 
@@ -45,8 +49,33 @@ pub enum KanbanServiceMessage {
     UpdateIssueDone { index: i32, result: Result<IssueResult, KanbanError> },
     /// Result of creating an issue
     CreateIssueDone(Result<IssueResult, KanbanError>),
-    /// Result of syncing one repo (fetching issues)
+    /// Result of syncing one repo of a project (fetching issues)
     SyncDone { repo_id: String, result: Result<Vec<IssueResult>, KanbanError> },
+    /// Issue templates gathered from the project's repos
+    TemplatesDone(Result<Vec<IssueTemplate>, KanbanError>),
+    /// A task was changed outside the board (undo/redo); already saved
+    TaskChanged(Task),
+    /// Result of time-blocking a task on the calendar
+    TaskScheduled(Result<TaskSchedule, KanbanError>),
+    /// Result of ticking a checklist item of a task's issue
+    ChecklistSynced { task_id: String, result: Result<IssueResult, KanbanError> },
+    /// Issues changed on GitHub were imported into this project's tasks
+    ProjectSynced(String),
+}
+
+impl From<GitHubIssue> for IssueResult {
+    fn from(issue: GitHubIssue) -> Self {
+        Self {
+            number: issue.number,
+            title: issue.title,
+            body: issue.body,
+            state: issue.state,
+            labels: issue.labels.into_iter().map(|l| l.name).collect(),
+            html_url: issue.html_url,
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+        }
+    }
 }
 
 /// Request to update an issue asynchronously.
@@ -70,6 +99,13 @@ pub fn request_update_issue(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let _ = tx.send(KanbanServiceMessage::UpdateIssueDone {
+            index,
+            result: Err(KanbanError::Network(connectivity::OFFLINE_ERROR.into())),
+        });
+        return;
+    }
 
     runtime.spawn(async move {
         let result = client
@@ -107,6 +143,12 @@ pub fn request_create_issue(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let _ = tx.send(KanbanServiceMessage::CreateIssueDone(Err(KanbanError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
 
     runtime.spawn(async move {
         let result = client
@@ -127,54 +169,328 @@ pub fn request_create_issue(
     });
 }
 
-/// Request to sync one repo (fetch all issues) asynchronously.
-pub fn request_sync(
+/// Tick or untick a task-list item in an issue's body. The item is looked up
+/// in the issue as it is now (by `index`, else by `text`) so edits made on
+/// GitHub since the last sync are kept. Sends `ChecklistSynced` with the
+/// updated issue.
+#[allow(clippy::too_many_arguments)]
+pub fn request_set_checklist_item(
     tx: &std::sync::mpsc::Sender<KanbanServiceMessage>,
     client: Arc<GitHubClient>,
+    task_id: String,
     repo_id: String,
-    owner: String,
-    repo: String,
+    issue_number: i32,
+    index: usize,
+    text: String,
+    checked: bool,
 ) {
     let tx = tx.clone();
+    let send = move |result| {
+        let _ = tx.send(KanbanServiceMessage::ChecklistSynced { task_id, result });
+    };
     let runtime = match bridge::get_runtime() {
         Some(r) => r,
-        None => {
+        None => return send(Err(KanbanError::NotInitialized)),
+    };
+    if !connectivity::is_online() {
+        return send(Err(KanbanError::Network(connectivity::OFFLINE_ERROR.into())));
+    }
+    let Some((owner, repo)) = repo_id.split_once('/') else {
+        return send(Err(KanbanError::Network(format!("Not a GitHub repo: {}", repo_id))));
+    };
+    let (owner, repo) = (owner.to_string(), repo.to_string());
+
+    runtime.spawn(async move {
+        let result: anyhow::Result<GitHubIssue> = async {
+            let issue = client.get_issue(&owner, &repo, issue_number).await?;
+            let body = issue.body.clone().unwrap_or_default();
+            let index = checklist::locate(&body, index, &text)
+                .ok_or_else(|| anyhow::anyhow!("\"{}\" is no longer in the issue", text))?;
+            match checklist::set_checked(&body, index, checked) {
+                Some(updated) if updated != body => {
+                    let req = UpdateIssueRequest {
+                        title: None,
+                        body: Some(updated),
+                        state: None,
+                        labels: None,
+                    };
+                    client.update_issue(&owner, &repo, issue_number, req).await
+                }
+                _ => Ok(issue),
+            }
+        }
+        .await;
+        send(result.map(IssueResult::from).map_err(|e| KanbanError::Network(e.to_string())));
+    });
+}
+
+/// Request to sync a project's repos (`owner/repo`, fetch all issues)
+/// asynchronously. Sends one `SyncDone` per repo; with GraphQL enabled on the
+/// client, several repos share a request.
+pub fn request_sync(
+    tx: &std::sync::mpsc::Sender<KanbanServiceMessage>,
+    client: Arc<GitHubClient>,
+    repo_ids: Vec<String>,
+) {
+    let tx = tx.clone();
+    let fail_all = |e: KanbanError| {
+        for repo_id in &repo_ids {
             let _ = tx.send(KanbanServiceMessage::SyncDone {
-                repo_id,
-                result: Err(KanbanError::NotInitialized),
+                repo_id: repo_id.clone(),
+                result: Err(e.clone()),
             });
+        }
+    };
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            fail_all(KanbanError::NotInitialized);
             return;
         }
     };
+    if !connectivity::is_online() {
+        fail_all(KanbanError::Network(connectivity::OFFLINE_ERROR.into()));
+        return;
+    }
 
     runtime.spawn(async move {
-        let result = client
-            .list_issues(&owner, &repo)
-            .await
-            .map(|issues| {
-                issues
-                    .into_iter()
-                    .map(|issue| IssueResult {
-                        number: issue.number,
-                        title: issue.title,
-                        body: issue.body,
-                        state: issue.state,
-                        labels: issue.labels.into_iter().map(|l| l.name).collect(),
-                        html_url: issue.html_url,
-                        created_at: issue.created_at,
-                        updated_at: issue.updated_at,
-                    })
-                    .collect()
-            })
-            .map_err(|e| KanbanError::Network(e.to_string()));
-        let _ = tx.send(KanbanServiceMessage::SyncDone { repo_id, result });
+        for (repo_id, result) in client.list_issues_for_repos(&repo_ids).await {
+            let result = result
+                .map(|issues| {
+                    issues
+                        .into_iter()
+                        .map(|issue| IssueResult {
+                            number: issue.number,
+                            title: issue.title,
+                            body: issue.body,
+                            state: issue.state,
+                            labels: issue.labels.into_iter().map(|l| l.name).collect(),
+                            html_url: issue.html_url,
+                            created_at: issue.created_at,
+                            updated_at: issue.updated_at,
+                        })
+                        .collect()
+                })
+                .map_err(|e| KanbanError::Network(e.to_string()));
+            let _ = tx.send(KanbanServiceMessage::SyncDone { repo_id, result });
+        }
     });
 }
 
+/// Request the issue templates of every repo (`owner/repo`) in a project.
+/// Templates with the same name are only kept once. Sends `TemplatesDone`.
+pub fn request_templates(
+    tx: &std::sync::mpsc::Sender<KanbanServiceMessage>,
+    client: Arc<GitHubClient>,
+    repo_ids: Vec<String>,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(KanbanServiceMessage::TemplatesDone(Err(KanbanError::NotInitialized)));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(KanbanServiceMessage::TemplatesDone(Err(KanbanError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let mut templates: Vec<IssueTemplate> = Vec::new();
+        for repo_id in repo_ids {
+            let Some((owner, repo)) = repo_id.split_once('/') else {
+                continue;
+            };
+            match client.get_issue_templates(owner, repo).await {
+                Ok(found) => {
+                    for t in found {
+                        if !templates.iter().any(|existing| existing.name == t.name) {
+                            templates.push(t);
+                        }
+                    }
+                }
+                Err(e) => {
+                    let _ = tx.send(KanbanServiceMessage::TemplatesDone(Err(
+                        KanbanError::Network(e.to_string()),
+                    )));
+                    return;
+                }
+            }
+        }
+        let _ = tx.send(KanbanServiceMessage::TemplatesDone(Ok(templates)));
+    });
+}
+
+/// One step of turning the board's task list into a freshly loaded one.
+/// Indices refer to the list as it stands when the step is applied.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskListChange {
+    /// Rows `first..first + count` went away
+    Removed { first: usize, count: usize },
+    /// The row at `from` now sits at `to`
+    Moved { from: usize, to: usize },
+    /// New rows starting at `first`
+    Inserted { first: usize, tasks: Vec<Task> },
+    /// Rows starting at `first` were edited in place
+    Updated { first: usize, tasks: Vec<Task> },
+}
+
+/// Steps that turn `old` into `new`, matching tasks by id: removals (last
+/// first), moves, insertions, then in-place updates. Neighbouring rows are
+/// batched, and only tasks outside the longest run still in order are moved,
+/// so a reload that changes one card touches one row.
+pub fn diff_tasks(old: &[Task], new: &[Task]) -> Vec<TaskListChange> {
+    use std::collections::{HashMap, HashSet};
+
+    let new_pos: HashMap<&str, usize> =
+        new.iter().enumerate().map(|(i, t)| (t.id.as_str(), i)).collect();
+    let mut changes = Vec::new();
+
+    // Removals, from the bottom up so earlier indices stay valid
+    let removed: Vec<usize> =
+        (0..old.len()).filter(|&i| !new_pos.contains_key(old[i].id.as_str())).collect();
+    for run in runs(&removed).into_iter().rev() {
+        changes.push(TaskListChange::Removed { first: run.start, count: run.len() });
+    }
+
+    // Moves: tasks outside the longest increasing run of new positions
+    let mut current: Vec<&str> =
+        old.iter().map(|t| t.id.as_str()).filter(|id| new_pos.contains_key(id)).collect();
+    let positions: Vec<usize> = current.iter().map(|id| new_pos[id]).collect();
+    let stable: HashSet<&str> =
+        longest_increasing(&positions).into_iter().map(|i| current[i]).collect();
+    let kept: Vec<&str> =
+        new.iter().map(|t| t.id.as_str()).filter(|id| current.contains(id)).collect();
+    for (i, id) in kept.iter().enumerate() {
+        if stable.contains(id) {
+            continue;
+        }
+        let Some(from) = current.iter().position(|c| c == id) else {
+            continue;
+        };
+        // Right after the task that precedes it in the new order
+        let to = match i.checked_sub(1).and_then(|p| current.iter().position(|c| *c == kept[p])) {
+            Some(prev) if from > prev => prev + 1,
+            Some(prev) => prev,
+            None => 0,
+        };
+        if from != to {
+            let moved = current.remove(from);
+            current.insert(to, moved);
+            changes.push(TaskListChange::Moved { from, to });
+        }
+    }
+
+    // Insertions, top down, at their final positions
+    let old_by_id: HashMap<&str, &Task> = old.iter().map(|t| (t.id.as_str(), t)).collect();
+    let added: Vec<usize> =
+        (0..new.len()).filter(|&i| !old_by_id.contains_key(new[i].id.as_str())).collect();
+    for run in runs(&added) {
+        changes.push(TaskListChange::Inserted { first: run.start, tasks: new[run].to_vec() });
+    }
+
+    // Edits to tasks that were already on the board
+    let updated: Vec<usize> = (0..new.len())
+        .filter(|&i| old_by_id.get(new[i].id.as_str()).is_some_and(|t| **t != new[i]))
+        .collect();
+    for run in runs(&updated) {
+        changes.push(TaskListChange::Updated { first: run.start, tasks: new[run].to_vec() });
+    }
+    changes
+}
+
+/// Sorted indices grouped into ranges of consecutive values
+fn runs(indices: &[usize]) -> Vec<std::ops::Range<usize>> {
+    let mut runs: Vec<std::ops::Range<usize>> = Vec::new();
+    for &i in indices {
+        match runs.last_mut() {
+            Some(run) if run.end == i => run.end += 1,
+            _ => runs.push(i..i + 1),
+        }
+    }
+    runs
+}
+
+/// Indices into `values` of one longest strictly increasing subsequence
+fn longest_increasing(values: &[usize]) -> Vec<usize> {
+    // tails[k]: index of the smallest tail of an increasing run of length k + 1
+    let mut tails: Vec<usize> = Vec::new();
+    let mut parent: Vec<Option<usize>> = vec![None; values.len()];
+    for (i, &v) in values.iter().enumerate() {
+        let k = tails.partition_point(|&t| values[t] < v);
+        parent[i] = k.checked_sub(1).map(|p| tails[p]);
+        if k == tails.len() {
+            tails.push(i);
+        } else {
+            tails[k] = i;
+        }
+    }
+    let mut sequence = Vec::with_capacity(tails.len());
+    let mut next = tails.last().copied();
+    while let Some(i) = next {
+        sequence.push(i);
+        next = parent[i];
+    }
+    sequence.reverse();
+    sequence
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
     use super::*;
+    use myme_services::TaskStatus;
+
+    fn task(id: &str) -> Task {
+        Task {
+            id: id.into(),
+            project_id: "p".into(),
+            title: id.to_uppercase(),
+            body: None,
+            status: TaskStatus::Todo,
+            column_id: None,
+            created_at: "2026-01-01T00:00:00Z".into(),
+            updated_at: "2026-01-01T00:00:00Z".into(),
+            order_index: 0,
+        }
+    }
+
+    fn tasks(ids: &str) -> Vec<Task> {
+        ids.split_whitespace().map(task).collect()
+    }
+
+    /// Replays the changes the way the board model does
+    fn apply(old: &[Task], changes: &[TaskListChange]) -> Vec<Task> {
+        let mut list = old.to_vec();
+        for change in changes {
+            match change {
+                TaskListChange::Removed { first, count } => {
+                    list.drain(*first..first + count);
+                }
+                TaskListChange::Moved { from, to } => {
+                    let moved = list.remove(*from);
+                    list.insert(*to, moved);
+                }
+                TaskListChange::Inserted { first, tasks } => {
+                    list.splice(*first..*first, tasks.iter().cloned());
+                }
+                TaskListChange::Updated { first, tasks } => {
+                    list[*first..first + tasks.len()].clone_from_slice(tasks);
+                }
+            }
+        }
+        list
+    }
+
+    fn assert_diff(old: &[Task], new: &[Task]) -> Vec<TaskListChange> {
+        let changes = diff_tasks(old, new);
+        assert_eq!(apply(old, &changes), new, "changes: {:?}", changes);
+        changes
+    }
 
     #[test]
     fn kanban_error_display() {
@@ -182,11 +498,60 @@ mod tests {
         assert!(format!("{}", KanbanError::NotInitialized).contains("not initialized"));
     }
 
+    #[test]
+    fn diff_tasks_unchanged_is_empty() {
+        assert!(assert_diff(&tasks("a b c"), &tasks("a b c")).is_empty());
+    }
+
+    #[test]
+    fn diff_tasks_batches_neighbouring_removals_and_insertions() {
+        let changes = assert_diff(&tasks("a b c d e"), &tasks("a x y d e z"));
+        assert_eq!(
+            changes,
+            vec![
+                TaskListChange::Removed { first: 1, count: 2 },
+                TaskListChange::Inserted { first: 1, tasks: tasks("x y") },
+                TaskListChange::Inserted { first: 5, tasks: tasks("z") },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_tasks_moves_only_the_displaced_task() {
+        let changes = assert_diff(&tasks("a b c d e"), &tasks("b c d e a"));
+        assert_eq!(changes, vec![TaskListChange::Moved { from: 0, to: 4 }]);
+
+        let changes = assert_diff(&tasks("a b c d e"), &tasks("a d b c e"));
+        assert_eq!(changes, vec![TaskListChange::Moved { from: 3, to: 1 }]);
+    }
+
+    #[test]
+    fn diff_tasks_reports_edits_in_place() {
+        let old = tasks("a b c");
+        let mut new = old.clone();
+        new[1].title = "Renamed".into();
+        new[2].status = TaskStatus::Done;
+        let changes = assert_diff(&old, &new);
+        assert_eq!(changes, vec![TaskListChange::Updated { first: 1, tasks: new[1..].to_vec() }]);
+    }
+
+    #[test]
+    fn diff_tasks_handles_mixed_changes() {
+        let mut new = tasks("f c a x e b");
+        new[3].body = Some("new".into());
+        new[4].title = "Edited".into();
+        assert_diff(&tasks("a b c d e f"), &new);
+        assert_diff(&tasks("a b c"), &[]);
+        assert_diff(&[], &tasks("a b c"));
+        assert_diff(&tasks("a b c d"), &tasks("d c b a"));
+    }
+
     #[test]
     fn kanban_service_message_variants() {
         let _sync_err: KanbanServiceMessage = KanbanServiceMessage::SyncDone {
             repo_id: "owner/repo".into(),
             result: Err(KanbanError::NotInitialized),
         };
+        let _synced: KanbanServiceMessage = KanbanServiceMessage::ProjectSynced("p1".into());
     }
 }
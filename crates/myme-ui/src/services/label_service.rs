@@ -0,0 +1,148 @@
+//! Note label backend: the label list with usage counts, and renaming,
+//! merging or deleting a label across every note.
+//! Store work runs off the UI thread; results sent via mpsc.
+
+use std::sync::Arc;
+
+use myme_services::{LabelUsage, NoteClient};
+
+use crate::bridge;
+
+/// Error type for label operations
+#[derive(Debug, Clone)]
+pub enum LabelError {
+    Store(String),
+    /// Rejected edit (empty name, renaming onto an existing label)
+    Invalid(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for LabelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LabelError::Store(s) => write!(f, "Label store error: {}", s),
+            LabelError::Invalid(s) => write!(f, "{}", s),
+            LabelError::NotInitialized => write!(f, "Label service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for LabelError {}
+
+impl From<anyhow::Error> for LabelError {
+    fn from(e: anyhow::Error) -> Self {
+        let msg = e.to_string();
+        match msg.strip_prefix("Validation error: ") {
+            Some(reason) => LabelError::Invalid(reason.to_string()),
+            None => LabelError::Store(msg),
+        }
+    }
+}
+
+/// A change to the label set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LabelEdit {
+    Create(String),
+    Rename { from: String, to: String },
+    Merge { from: String, into: String },
+    Delete(String),
+}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum LabelServiceMessage {
+    /// Every label with its usage count, by name
+    FetchDone(Result<Vec<LabelUsage>, LabelError>),
+    /// An edit finished; carries the number of notes it changed
+    EditDone(Result<usize, LabelError>),
+}
+
+/// Request the label list. Sends `FetchDone` on the channel when complete.
+pub fn request_fetch(tx: &std::sync::mpsc::Sender<LabelServiceMessage>, client: Arc<NoteClient>) {
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(LabelServiceMessage::FetchDone(Err(LabelError::NotInitialized)));
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = client.list_labels().await.map_err(LabelError::from);
+        let _ = tx.send(LabelServiceMessage::FetchDone(result));
+    });
+}
+
+/// Request a label edit; every note carrying the label is rewritten at once.
+/// Sends `EditDone` on the channel when complete.
+pub fn request_edit(
+    tx: &std::sync::mpsc::Sender<LabelServiceMessage>,
+    client: Arc<NoteClient>,
+    edit: LabelEdit,
+) {
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(LabelServiceMessage::EditDone(Err(LabelError::NotInitialized)));
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = match edit {
+            LabelEdit::Create(name) => client.create_label(name).await.map(|()| 0),
+            LabelEdit::Rename { from, to } => client.rename_label(from, to).await,
+            LabelEdit::Merge { from, into } => client.merge_labels(from, into).await,
+            LabelEdit::Delete(name) => client.delete_label(name).await,
+        };
+        let _ = tx.send(LabelServiceMessage::EditDone(result.map_err(LabelError::from)));
+    });
+}
+
+/// Up to `limit` labels for autocomplete: names starting with `prefix` first,
+/// then names containing it, most used first within each (case-insensitive).
+/// An empty prefix suggests the most used labels.
+pub fn suggest(labels: &[LabelUsage], prefix: &str, limit: usize) -> Vec<String> {
+    let prefix = prefix.trim().to_lowercase();
+    let mut matches: Vec<(bool, &LabelUsage)> = labels
+        .iter()
+        .filter_map(|l| {
+            let name = l.name.to_lowercase();
+            if name.starts_with(&prefix) {
+                Some((true, l))
+            } else if name.contains(&prefix) {
+                Some((false, l))
+            } else {
+                None
+            }
+        })
+        .collect();
+    matches.sort_by(|(a_prefix, a), (b_prefix, b)| {
+        b_prefix.cmp(a_prefix).then(b.count.cmp(&a.count)).then(a.name.cmp(&b.name))
+    });
+    matches.into_iter().take(limit).map(|(_, l)| l.name.clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn label(name: &str, count: usize) -> LabelUsage {
+        LabelUsage { name: name.to_string(), count }
+    }
+
+    #[test]
+    fn validation_errors_keep_their_reason() {
+        let err = LabelError::from(anyhow::anyhow!("Validation error: Label 'x' already exists"));
+        assert!(matches!(&err, LabelError::Invalid(s) if s == "Label 'x' already exists"));
+        let err = LabelError::from(anyhow::anyhow!("Storage error: disk full"));
+        assert!(matches!(err, LabelError::Store(_)));
+    }
+
+    #[test]
+    fn suggest_prefers_prefix_then_usage() {
+        let labels =
+            [label("work", 2), label("Workout", 5), label("homework", 9), label("ideas", 1)];
+
+        assert_eq!(suggest(&labels, "wo", 10), ["Workout", "work", "homework"]);
+        assert_eq!(suggest(&labels, "", 2), ["homework", "Workout"]);
+        assert!(suggest(&labels, "zzz", 10).is_empty());
+    }
+}
@@ -1,46 +1,183 @@
+pub mod activity_service;
 pub mod auth_service;
+pub mod bookmark_service;
+pub mod briefing_service;
 pub mod calendar_service;
+pub mod capture_parser;
+pub mod capture_service;
+pub mod clipboard_service;
+pub mod contacts_service;
+pub mod discover_service;
+pub mod feed_service;
+pub mod focus_service;
+pub mod gist_service;
+pub mod github_sync_service;
 pub mod gmail_service;
 pub mod google_common;
+pub mod image_service;
+pub mod import_service;
+pub mod insights_service;
+pub mod ipc_service;
 pub mod kanban_service;
+pub mod label_service;
 pub mod note_service;
+pub mod notification_service;
 pub mod project_service;
+pub mod release_service;
 pub mod repo_service;
+pub mod runner_service;
+pub mod schedule_service;
+pub mod search_service;
+pub mod settings_service;
+pub mod template_service;
+pub mod theme_service;
+pub mod travel_service;
+pub mod ui_events;
+pub mod undo_service;
 pub mod weather_service;
 pub mod workflow_service;
 
+pub use activity_service::{record as record_activity, short_title as activity_title, UndoHint};
 pub use auth_service::{request_authenticate as request_auth, AuthError, AuthServiceMessage};
+pub use bookmark_service::{
+    fetch_metadata as fetch_bookmark_metadata, request_fetch_metadata as request_bookmark_metadata,
+    BookmarkError, BookmarkServiceMessage,
+};
+pub use briefing_service::suggestions as briefing_suggestions;
 pub use calendar_service::{
+    display_zone as calendar_display_zone, notify_due_reminders as notify_due_calendar_reminders,
+    request_create_event as request_calendar_create_event,
     request_fetch_events as request_calendar_fetch_events,
-    request_fetch_today_events as request_calendar_fetch_today_events, CalendarError,
+    request_fetch_today_events as request_calendar_fetch_today_events,
+    request_update_reminders as request_calendar_update_reminders, CalendarError,
     CalendarServiceMessage,
 };
+pub use capture_parser::{parse_capture, CaptureParseError, CaptureTarget};
+pub use capture_service::{
+    request_capture, CaptureClients, CaptureError, CaptureOutcome, CaptureServiceMessage,
+};
+pub use clipboard_service::{
+    request_record as request_clipboard_record, ClipboardError, ClipboardServiceMessage,
+    RecordOutcome as ClipboardRecordOutcome,
+};
+pub use contacts_service::{
+    request_refresh as request_contacts_refresh, ContactsError, ContactsServiceMessage,
+};
+pub use discover_service::{
+    request_feed as request_discover_feed, request_star as request_discover_star, DiscoverError,
+    DiscoverFeed, DiscoverServiceMessage,
+};
+pub use feed_service::{
+    open_cache as open_feed_cache, refresh_all as refresh_all_feeds,
+    request_refresh as request_feed_refresh, request_subscribe as request_feed_subscribe,
+    FeedError, FeedServiceMessage,
+};
+pub use focus_service::{notify_desktop, FocusDurations, FocusService, FocusState};
+pub use gist_service::{
+    request_create as request_gist_create, request_fetch as request_gist_fetch,
+    request_update as request_gist_update, GistError, GistServiceMessage,
+};
+pub use github_sync_service::run as run_github_sync;
 pub use gmail_service::{
-    request_archive as request_gmail_archive, request_fetch as request_gmail_fetch,
-    request_mark_as_read as request_gmail_mark_as_read, request_trash as request_gmail_trash,
-    GmailError, GmailServiceMessage,
+    cache_usage as gmail_cache_usage, delete_rule as delete_gmail_rule,
+    describe_cache_usage as describe_gmail_cache_usage, flush_sync_queue as flush_gmail_sync_queue,
+    list_rules as list_gmail_rules, request_archive as request_gmail_archive,
+    request_attachment as request_gmail_attachment, request_batch as request_gmail_batch,
+    request_fetch as request_gmail_fetch, request_mark_as_read as request_gmail_mark_as_read,
+    request_snooze as request_gmail_snooze, request_thread as request_gmail_thread,
+    request_trash as request_gmail_trash, request_unsubscribe as request_gmail_unsubscribe,
+    resurface_snoozed as resurface_gmail_snoozed, save_rule as save_gmail_rule, GmailBatchOp,
+    GmailError, GmailServiceMessage, Unsubscribed,
 };
+pub use image_service::{generation as image_generation, source as image_source};
+pub use import_service::{request_import, ImportError, ImportServiceMessage};
+pub use insights_service::{
+    request_fetch as request_insights_fetch, request_repos as request_insights_repos,
+    InsightsError, InsightsServiceMessage, RepoInsights,
+};
+pub use ipc_service::{handle_command as handle_ipc_command, IpcServiceMessage};
 pub use kanban_service::{
-    request_create_issue as request_kanban_create, request_sync as request_kanban_sync,
+    diff_tasks, request_create_issue as request_kanban_create,
+    request_set_checklist_item as request_kanban_checklist_item,
+    request_sync as request_kanban_sync, request_templates as request_kanban_templates,
     request_update_issue as request_kanban_update, IssueResult as KanbanIssueResult, KanbanError,
-    KanbanServiceMessage,
+    KanbanServiceMessage, TaskListChange,
+};
+pub use label_service::{
+    request_edit as request_label_edit, request_fetch as request_label_fetch,
+    suggest as suggest_labels, LabelEdit, LabelError, LabelServiceMessage,
 };
 pub use note_service::{
+    add_palette_color as add_note_palette_color, merge_changes as merge_note_changes, move_note,
+    palette as note_palette, remove_palette_color as remove_note_palette_color,
     request_create as request_note_create, request_delete as request_note_delete,
-    request_fetch as request_note_fetch,
+    request_export_label as request_note_export_label, request_export_note as request_note_export,
+    request_fetch as request_note_fetch, request_fetch_changes as request_note_fetch_changes,
+    request_fetch_page as request_note_fetch_page,
     request_fetch_with_filter as request_note_fetch_with_filter,
-    request_toggle_done as request_note_toggle, request_update as request_note_update, NoteError,
+    request_reorder as request_note_reorder, request_restore as request_note_restore,
+    request_sync as request_note_sync, request_toggle_done as request_note_toggle,
+    request_trash as request_note_trash, request_unlock as request_note_unlock,
+    request_update as request_note_update, watch_sync as watch_note_sync, NoteError,
     NoteFilter as NoteServiceFilter, NoteServiceMessage,
+    PASSPHRASE_SECRET as NOTE_PASSPHRASE_SECRET,
+};
+pub use notification_service::{
+    request_fetch as request_notification_fetch,
+    request_mark_read as request_notification_mark_read,
+    request_unsubscribe as request_notification_unsubscribe, NotificationError,
+    NotificationServiceMessage,
 };
 pub use project_service::{
-    request_fetch_repo as request_project_fetch_repo, ProjectError, ProjectServiceMessage, RepoInfo,
+    request_ensure_labels as request_project_ensure_labels,
+    request_fetch_repo as request_project_fetch_repo,
+    request_repo_choices as request_project_repo_choices, ProjectError, ProjectServiceMessage,
+    RepoInfo,
+};
+pub use release_service::{
+    request_create as request_release_create, request_draft as request_release_draft,
+    request_fetch as request_release_fetch, ReleaseDraft, ReleaseError, ReleaseServiceMessage,
 };
 pub use repo_service::{
-    request_clone, request_pull, request_refresh, RepoError, RepoServiceMessage,
+    activity_feed, cached_preview as cached_repo_preview, configured_scopes as repo_scopes,
+    request_activity, request_clone, request_create_remote, request_health as request_repo_health,
+    request_preview as request_repo_preview, request_pull, request_refresh,
+    request_scopes as request_repo_scopes, request_security, request_sync_fork,
+    save_scopes as save_repo_scopes, watch_repos, RepoActivity, RepoError, RepoServiceMessage,
+};
+pub use runner_service::{
+    request_run as request_runner_run, tasks_for as runner_tasks_for, RunnerError,
+    RunnerServiceMessage,
+};
+pub use schedule_service::{
+    request_schedule_task, request_sync_task_schedules, set_task_done as set_scheduled_task_done,
+    task_for_event,
+};
+pub use search_service::{
+    merge_hits as merge_search_hits, request_search, SearchError, SearchHit, SearchServiceMessage,
+    SearchSource,
+};
+pub use settings_service::{request_token_health, SettingsError, SettingsServiceMessage};
+pub use template_service::{
+    build_template, context_today as template_context_today,
+    instantiate_due as instantiate_due_templates, parse_schedule as parse_template_schedule,
+    templates_json,
+};
+pub use theme_service::{request_portal_scheme, ThemeServiceMessage};
+pub use travel_service::notify_travel_warnings;
+pub use ui_events::{
+    publish as publish_ui_event, ActionHint, Severity as UiSeverity, ToastQueue, UiEvent,
+    UiEventBus,
+};
+pub use undo_service::{
+    request_redo, request_undo, UndoError, UndoItem, UndoServiceMessage, UndoStack,
 };
 pub use weather_service::{
-    request_fetch as request_weather_fetch, WeatherError, WeatherServiceMessage,
+    request_fetch as request_weather_fetch, request_radar as request_weather_radar, WeatherError,
+    WeatherServiceMessage,
 };
 pub use workflow_service::{
-    request_fetch_workflows, RepoWorkflows, WorkflowError, WorkflowServiceMessage,
+    request_dispatch as request_workflow_dispatch, request_fetch_workflows,
+    request_inputs as request_workflow_inputs, RepoWorkflows, WorkflowError,
+    WorkflowServiceMessage,
 };
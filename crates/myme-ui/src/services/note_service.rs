@@ -1,18 +1,33 @@
 //! Note backend: async CRUD operations for notes/todos.
 //! All network work runs off the UI thread; results sent via mpsc.
 
+use std::path::PathBuf;
 use std::sync::Arc;
 
-use myme_services::{NoteClient, Todo as Note, TodoCreateRequest, TodoUpdateRequest};
+use chrono::{DateTime, Utc};
 
+use myme_core::NoteColor;
+use myme_services::note_export::{self, ExportFormat};
+use myme_services::{
+    sync_notes, FolderTransport, NoteClient, SyncReport, SyncWatcher, Todo as Note,
+    TodoCreateRequest, TodoUpdateRequest,
+};
+
+use super::activity_service::{self, UndoHint};
 use crate::bridge;
 
+/// Keyring entry holding the notes encryption passphrase
+pub const PASSPHRASE_SECRET: &str = "notes-passphrase";
+
 /// Error type for note operations
 #[derive(Debug, Clone)]
 pub enum NoteError {
     Network(String),
     NotInitialized,
     InvalidIndex,
+    Config(String),
+    /// Writing an export failed
+    Export(String),
 }
 
 impl std::fmt::Display for NoteError {
@@ -21,6 +36,8 @@ impl std::fmt::Display for NoteError {
             NoteError::Network(s) => write!(f, "Network error: {}", s),
             NoteError::NotInitialized => write!(f, "Note service not initialized"),
             NoteError::InvalidIndex => write!(f, "Invalid note index"),
+            NoteError::Config(s) => write!(f, "Config error: {}", s),
+            NoteError::Export(s) => write!(f, "Export failed: {}", s),
         }
     }
 }
@@ -32,12 +49,28 @@ impl std::error::Error for NoteError {}
 pub enum NoteServiceMessage {
     /// Result of fetching all notes
     FetchDone(Result<Vec<Note>, NoteError>),
+    /// Result of fetching one page of non-archived notes starting at `offset`
+    PageDone { offset: usize, result: Result<Vec<Note>, NoteError> },
+    /// Notes created or modified since the last fetch
+    ChangesDone(Result<Vec<Note>, NoteError>),
     /// Result of creating a new note
     CreateDone(Result<Note, NoteError>),
     /// Result of updating a note (toggle done, edit content)
     UpdateDone { index: usize, result: Result<Note, NoteError> },
-    /// Result of deleting a note
+    /// Result of deleting a note (moving it to the trash, or purging it from there)
     DeleteDone { index: usize, result: Result<(), NoteError> },
+    /// Result of restoring a note from the trash
+    RestoreDone { index: usize, result: Result<Note, NoteError> },
+    /// Result of syncing notes with other devices
+    SyncDone(Result<SyncReport, NoteError>),
+    /// Another device's edits were merged in by the sync folder watcher
+    LiveSyncDone(SyncReport),
+    /// Result of unlocking (or first encrypting) the notes database
+    UnlockDone(Result<(), NoteError>),
+    /// Result of saving a manual reordering
+    ReorderDone(Result<(), NoteError>),
+    /// Files written by an export
+    ExportDone(Result<Vec<PathBuf>, NoteError>),
 }
 
 /// Filter mode for note listing.
@@ -47,6 +80,7 @@ pub enum NoteFilter {
     Archived,
     Pinned,
     Reminders,
+    Trash,
     Label(String),
 }
 
@@ -76,6 +110,7 @@ pub fn request_fetch_with_filter(
             NoteFilter::All | NoteFilter::Pinned => client.list_todos().await,
             NoteFilter::Archived => client.list_archived().await,
             NoteFilter::Reminders => client.list_with_reminders().await,
+            NoteFilter::Trash => client.list_trashed().await,
             NoteFilter::Label(ref label) => client.list_by_label(label).await,
         };
         let result = result.map_err(|e| NoteError::Network(e.to_string()));
@@ -83,6 +118,75 @@ pub fn request_fetch_with_filter(
     });
 }
 
+/// Request one page of non-archived notes.
+/// Sends `PageDone` on the channel when complete.
+pub fn request_fetch_page(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    client: Arc<NoteClient>,
+    offset: usize,
+    limit: usize,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(NoteServiceMessage::PageDone {
+                offset,
+                result: Err(NoteError::NotInitialized),
+            });
+            return;
+        }
+    };
+
+    runtime.spawn(async move {
+        let result =
+            client.list_page(offset, limit).await.map_err(|e| NoteError::Network(e.to_string()));
+        let _ = tx.send(NoteServiceMessage::PageDone { offset, result });
+    });
+}
+
+/// Request notes changed at or after `since`.
+/// Sends `ChangesDone` on the channel when complete.
+pub fn request_fetch_changes(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    client: Arc<NoteClient>,
+    since: DateTime<Utc>,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(NoteServiceMessage::ChangesDone(Err(NoteError::NotInitialized)));
+            return;
+        }
+    };
+
+    runtime.spawn(async move {
+        let result =
+            client.list_changed_since(since).await.map_err(|e| NoteError::Network(e.to_string()));
+        let _ = tx.send(NoteServiceMessage::ChangesDone(result));
+    });
+}
+
+/// Fold changed notes into a loaded (unarchived, `list_todos`-ordered) list:
+/// replace or add them, drop ones that are now archived or trashed, and restore the order.
+pub fn merge_changes(notes: &mut Vec<Note>, changed: Vec<Note>) {
+    for note in changed {
+        let existing = notes.iter().position(|n| n.id == note.id);
+        match (existing, note.archived || note.deleted_at.is_some()) {
+            (Some(i), true) => {
+                notes.remove(i);
+            }
+            (Some(i), false) => notes[i] = note,
+            (None, false) => notes.push(note),
+            (None, true) => {}
+        }
+    }
+    notes.sort_by(|a, b| {
+        b.pinned.cmp(&a.pinned).then(b.order_index.cmp(&a.order_index)).then(b.id.cmp(&a.id))
+    });
+}
+
 /// Request to create a new note asynchronously.
 /// Sends `CreateDone` on the channel when complete.
 pub fn request_create(
@@ -104,6 +208,15 @@ pub fn request_create(
         let request = TodoCreateRequest { content, is_checklist };
         let result =
             client.create_todo(request).await.map_err(|e| NoteError::Network(e.to_string()));
+        if let Ok(note) = &result {
+            activity_service::record(
+                "notes",
+                "created",
+                &note.id.to_string(),
+                &format!("Created note \"{}\"", activity_service::short_title(&note.content)),
+                Some(UndoHint::NoteTrash { id: note.id }),
+            );
+        }
         let _ = tx.send(NoteServiceMessage::CreateDone(result));
     });
 }
@@ -130,10 +243,25 @@ pub fn request_update(
     };
 
     runtime.spawn(async move {
+        let archived = request.archived;
         let result = client
             .update_todo(note_id, request)
             .await
             .map_err(|e| NoteError::Network(e.to_string()));
+        if let (Some(archived), Ok(note)) = (archived, &result) {
+            let action = if archived { "archived" } else { "unarchived" };
+            activity_service::record(
+                "notes",
+                action,
+                &note_id.to_string(),
+                &format!(
+                    "{} note \"{}\"",
+                    if archived { "Archived" } else { "Unarchived" },
+                    activity_service::short_title(&note.content)
+                ),
+                Some(UndoHint::NoteSetArchived { id: note_id, archived: !archived }),
+            );
+        }
         let _ = tx.send(NoteServiceMessage::UpdateDone { index, result });
     });
 }
@@ -152,7 +280,80 @@ pub fn request_toggle_done(
     request_update(tx, client, index, note_id, req);
 }
 
-/// Request to delete a note asynchronously.
+/// Request to move a note to the trash asynchronously.
+/// Sends `DeleteDone` on the channel when complete.
+pub fn request_trash(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    client: Arc<NoteClient>,
+    index: usize,
+    note_id: i64,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(NoteServiceMessage::DeleteDone {
+                index,
+                result: Err(NoteError::NotInitialized),
+            });
+            return;
+        }
+    };
+
+    runtime.spawn(async move {
+        let title = client.get_todo(note_id).await.map(|n| n.content).unwrap_or_default();
+        let result =
+            client.trash_todo(note_id).await.map_err(|e| NoteError::Network(e.to_string()));
+        if result.is_ok() {
+            activity_service::record(
+                "notes",
+                "trashed",
+                &note_id.to_string(),
+                &format!("Moved note \"{}\" to trash", activity_service::short_title(&title)),
+                Some(UndoHint::NoteRestore { id: note_id }),
+            );
+        }
+        let _ = tx.send(NoteServiceMessage::DeleteDone { index, result });
+    });
+}
+
+/// Request to restore a trashed note asynchronously.
+/// Sends `RestoreDone` on the channel when complete.
+pub fn request_restore(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    client: Arc<NoteClient>,
+    index: usize,
+    note_id: i64,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(NoteServiceMessage::RestoreDone {
+                index,
+                result: Err(NoteError::NotInitialized),
+            });
+            return;
+        }
+    };
+
+    runtime.spawn(async move {
+        let result =
+            client.restore_todo(note_id).await.map_err(|e| NoteError::Network(e.to_string()));
+        if let Ok(note) = &result {
+            activity_service::record(
+                "notes",
+                "restored",
+                &note_id.to_string(),
+                &format!("Restored note \"{}\"", activity_service::short_title(&note.content)),
+                Some(UndoHint::NoteTrash { id: note_id }),
+            );
+        }
+        let _ = tx.send(NoteServiceMessage::RestoreDone { index, result });
+    });
+}
+
+/// Request to permanently delete a note asynchronously.
 /// Sends `DeleteDone` on the channel when complete.
 pub fn request_delete(
     tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
@@ -178,10 +379,269 @@ pub fn request_delete(
             .await
             .map(|_| ())
             .map_err(|e| NoteError::Network(e.to_string()));
+        if result.is_ok() {
+            activity_service::record(
+                "notes",
+                "deleted",
+                &note_id.to_string(),
+                "Permanently deleted a note",
+                None,
+            );
+        }
         let _ = tx.send(NoteServiceMessage::DeleteDone { index, result });
     });
 }
 
+/// Request a notes sync through the shared folder `sync_dir`.
+/// Sends `SyncDone` on the channel when complete.
+pub fn request_sync(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    client: Arc<NoteClient>,
+    sync_dir: PathBuf,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(NoteServiceMessage::SyncDone(Err(NoteError::NotInitialized)));
+            return;
+        }
+    };
+
+    runtime.spawn_blocking(move || {
+        let transport = FolderTransport::new(sync_dir);
+        let store = client.sqlite_store();
+        let result =
+            sync_notes(&store.lock(), &transport).map_err(|e| NoteError::Network(e.to_string()));
+        let _ = tx.send(NoteServiceMessage::SyncDone(result));
+    });
+}
+
+/// How long the sync folder must be quiet before other devices' snapshots are merged
+const SYNC_WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// The running sync folder watcher and the folder it covers
+static SYNC_WATCH: std::sync::OnceLock<std::sync::Mutex<Option<(PathBuf, SyncWatcher)>>> =
+    std::sync::OnceLock::new();
+
+/// Watch `sync_dir` and merge other devices' snapshots as they arrive,
+/// sending `LiveSyncDone` when anything changed. Failed merges are retried
+/// with backoff. Replaces the previous watch; `None` stops watching.
+pub fn watch_sync(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    client: Arc<NoteClient>,
+    sync_dir: Option<PathBuf>,
+) {
+    let Ok(mut current) = SYNC_WATCH.get_or_init(|| std::sync::Mutex::new(None)).lock() else {
+        return;
+    };
+    if current.as_ref().map(|(dir, _)| dir) == sync_dir.as_ref() {
+        return;
+    }
+    // Drop the old watcher first so its thread stops before the new one starts
+    *current = None;
+    let Some(sync_dir) = sync_dir else {
+        return;
+    };
+    let device = match client.sqlite_store().lock().sync_device_id() {
+        Ok(device) => device,
+        Err(e) => {
+            tracing::warn!("Not watching the notes sync folder: {}", e);
+            return;
+        }
+    };
+
+    let tx = tx.clone();
+    let transport = FolderTransport::new(sync_dir.clone());
+    let on_change = move || {
+        if client.is_locked() {
+            return Ok(());
+        }
+        let report = sync_notes(&client.sqlite_store().lock(), &transport)?;
+        if report.applied + report.deleted + report.conflicts > 0 {
+            let _ = tx.send(NoteServiceMessage::LiveSyncDone(report));
+        }
+        Ok(())
+    };
+    match SyncWatcher::start(sync_dir.clone(), device, SYNC_WATCH_DEBOUNCE, on_change) {
+        Ok(watcher) => *current = Some((sync_dir, watcher)),
+        Err(e) => tracing::warn!("Could not watch the notes sync folder: {}", e),
+    }
+}
+
+/// Request to unlock the notes database with `passphrase`, encrypting it
+/// first if it is still plaintext. The passphrase is saved to the keyring on
+/// success so later starts unlock automatically.
+/// Sends `UnlockDone` on the channel when complete.
+pub fn request_unlock(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    client: Arc<NoteClient>,
+    passphrase: String,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(NoteServiceMessage::UnlockDone(Err(NoteError::NotInitialized)));
+            return;
+        }
+    };
+
+    runtime.spawn(async move {
+        let result = client
+            .unlock_or_encrypt(passphrase.clone())
+            .await
+            .map_err(|e| NoteError::Network(e.to_string()));
+        if result.is_ok() {
+            if let Err(e) = myme_auth::SecureStorage::store_secret(PASSPHRASE_SECRET, &passphrase) {
+                tracing::warn!("Failed to save notes passphrase: {:#}", e);
+            }
+        }
+        let _ = tx.send(NoteServiceMessage::UnlockDone(result));
+    });
+}
+
+/// Move the note at `from` to `to` within its pinned or unpinned group.
+/// Returns the ids of the notes that changed places, in their new order, or
+/// `None` when either index is out of range or the move would cross groups.
+pub fn move_note(notes: &mut [Note], from: usize, to: usize) -> Option<Vec<i64>> {
+    if from >= notes.len() || to >= notes.len() || notes[from].pinned != notes[to].pinned {
+        return None;
+    }
+    let range = from.min(to)..=from.max(to);
+    let slots: Vec<i64> = notes[range.clone()].iter().map(|n| n.order_index).collect();
+    if from < to {
+        notes[range.clone()].rotate_left(1);
+    } else {
+        notes[range.clone()].rotate_right(1);
+    }
+    // Keep the positions in step with the store so later merges sort the same way
+    for (note, slot) in notes[range.clone()].iter_mut().zip(slots) {
+        note.order_index = slot;
+    }
+    Some(notes[range].iter().map(|n| n.id).collect())
+}
+
+/// Request to save a manual ordering: `ids` take the positions they already
+/// hold among themselves, in the given order.
+/// Sends `ReorderDone` on the channel when complete.
+pub fn request_reorder(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    client: Arc<NoteClient>,
+    ids: Vec<i64>,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(NoteServiceMessage::ReorderDone(Err(NoteError::NotInitialized)));
+            return;
+        }
+    };
+
+    runtime.spawn(async move {
+        let result = client.reorder(ids).await.map_err(|e| NoteError::Network(e.to_string()));
+        let _ = tx.send(NoteServiceMessage::ReorderDone(result));
+    });
+}
+
+/// Request to export `note` to `path` (a file, or a directory to write into).
+/// Sends `ExportDone` with the written file when complete.
+pub fn request_export_note(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    note: Note,
+    format: ExportFormat,
+    path: PathBuf,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(NoteServiceMessage::ExportDone(Err(NoteError::NotInitialized)));
+            return;
+        }
+    };
+
+    runtime.spawn_blocking(move || {
+        let result = note_export::export_note(&note, format, &path)
+            .map(|written| vec![written])
+            .map_err(|e| NoteError::Export(format!("{:#}", e)));
+        let _ = tx.send(NoteServiceMessage::ExportDone(result));
+    });
+}
+
+/// Request to export every note carrying `label` into `dir`.
+/// Sends `ExportDone` with the written files when complete.
+pub fn request_export_label(
+    tx: &std::sync::mpsc::Sender<NoteServiceMessage>,
+    client: Arc<NoteClient>,
+    label: String,
+    format: ExportFormat,
+    dir: PathBuf,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(NoteServiceMessage::ExportDone(Err(NoteError::NotInitialized)));
+            return;
+        }
+    };
+
+    runtime.spawn(async move {
+        let notes = match client.list_by_label(&label).await {
+            Ok(notes) => notes,
+            Err(e) => {
+                let _ =
+                    tx.send(NoteServiceMessage::ExportDone(Err(NoteError::Network(e.to_string()))));
+                return;
+            }
+        };
+        let result = tokio::task::spawn_blocking(move || {
+            note_export::export_notes(&notes, format, &dir)
+                .map_err(|e| NoteError::Export(format!("{:#}", e)))
+        })
+        .await
+        .unwrap_or_else(|e| Err(NoteError::Export(e.to_string())));
+        let _ = tx.send(NoteServiceMessage::ExportDone(result));
+    });
+}
+
+/// Named note colors offered by the color picker (`[notes] palette`)
+pub fn palette() -> Vec<NoteColor> {
+    myme_core::Config::load_cached().notes.palette.clone()
+}
+
+/// Add `color` to the palette, or recolor the entry with the same name.
+pub fn add_palette_color(name: &str, color: &str) -> Result<(), NoteError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(NoteError::Config("Palette colors need a name".into()));
+    }
+    if myme_core::theme::parse_hex_color(color).is_none() {
+        return Err(NoteError::Config(format!("Not a hex color: {}", color)));
+    }
+    let mut palette = palette();
+    match palette.iter_mut().find(|c| c.name.eq_ignore_ascii_case(name)) {
+        Some(existing) => existing.color = color.to_string(),
+        None => palette.push(NoteColor::new(name, color)),
+    }
+    save_palette(&palette)
+}
+
+/// Drop the palette entry called `name`. Notes keep their color.
+pub fn remove_palette_color(name: &str) -> Result<(), NoteError> {
+    let mut palette = palette();
+    palette.retain(|c| !c.name.eq_ignore_ascii_case(name.trim()));
+    save_palette(&palette)
+}
+
+fn save_palette(palette: &[NoteColor]) -> Result<(), NoteError> {
+    let mut config = myme_core::Config::load().map_err(|e| NoteError::Config(e.to_string()))?;
+    config.notes.palette = palette.to_vec();
+    config.save().map_err(|e| NoteError::Config(e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -192,6 +652,7 @@ mod tests {
         assert!(format!("{}", NoteError::Network("timeout".into())).contains("Network"));
         assert!(format!("{}", NoteError::NotInitialized).contains("not initialized"));
         assert!(format!("{}", NoteError::InvalidIndex).contains("Invalid"));
+        assert!(format!("{}", NoteError::Config("bad".into())).contains("Config"));
     }
 
     #[test]
@@ -206,5 +667,84 @@ mod tests {
             NoteServiceMessage::UpdateDone { index: 0, result: Err(NoteError::InvalidIndex) };
         let _delete: NoteServiceMessage =
             NoteServiceMessage::DeleteDone { index: 1, result: Ok(()) };
+        let _page: NoteServiceMessage =
+            NoteServiceMessage::PageDone { offset: 50, result: Ok(vec![]) };
+        let _changes: NoteServiceMessage = NoteServiceMessage::ChangesDone(Ok(vec![]));
+        let _unlock: NoteServiceMessage = NoteServiceMessage::UnlockDone(Ok(()));
+        let _restore: NoteServiceMessage =
+            NoteServiceMessage::RestoreDone { index: 0, result: Err(NoteError::InvalidIndex) };
+        let _reorder: NoteServiceMessage = NoteServiceMessage::ReorderDone(Ok(()));
+    }
+
+    fn note(id: i64, order_index: i64, pinned: bool, archived: bool) -> Note {
+        let at = Utc::now();
+        Note {
+            id,
+            content: format!("note {}", id),
+            done: false,
+            created_at: at,
+            updated_at: at,
+            color: None,
+            pinned,
+            archived,
+            labels: vec![],
+            is_checklist: false,
+            reminder: None,
+            deleted_at: None,
+            order_index,
+        }
+    }
+
+    #[test]
+    fn merge_changes_updates_adds_and_drops_archived() {
+        let mut notes =
+            vec![note(1, 1, true, false), note(2, 3, false, false), note(3, 2, false, false)];
+
+        merge_changes(
+            &mut notes,
+            vec![
+                note(3, 2, false, false),
+                note(2, 3, false, true),
+                note(4, 5, false, false),
+                note(5, 6, false, true),
+            ],
+        );
+
+        let ids: Vec<i64> = notes.iter().map(|n| n.id).collect();
+        // Pinned first, then manual order (a new note on top)
+        assert_eq!(ids, [1, 4, 3]);
+    }
+
+    #[test]
+    fn merge_changes_drops_trashed() {
+        let mut notes = vec![note(1, 2, false, false), note(2, 1, false, false)];
+        let mut trashed = note(2, 1, false, false);
+        trashed.deleted_at = Some(Utc::now());
+
+        merge_changes(&mut notes, vec![trashed]);
+
+        let ids: Vec<i64> = notes.iter().map(|n| n.id).collect();
+        assert_eq!(ids, [1]);
+    }
+
+    #[test]
+    fn move_note_stays_within_its_group() {
+        let mut notes = vec![
+            note(1, 9, true, false),
+            note(2, 4, false, false),
+            note(3, 3, false, false),
+            note(4, 2, false, false),
+        ];
+
+        assert_eq!(move_note(&mut notes, 3, 1), Some(vec![4, 2, 3]));
+        let ids: Vec<i64> = notes.iter().map(|n| n.id).collect();
+        assert_eq!(ids, [1, 4, 2, 3]);
+        let slots: Vec<i64> = notes.iter().map(|n| n.order_index).collect();
+        assert_eq!(slots, [9, 4, 3, 2]);
+
+        assert_eq!(move_note(&mut notes, 1, 2), Some(vec![2, 4]));
+        // Pinned and unpinned notes don't mix, and indices must exist
+        assert_eq!(move_note(&mut notes, 0, 1), None);
+        assert_eq!(move_note(&mut notes, 1, 9), None);
     }
 }
@@ -0,0 +1,139 @@
+//! GitHub notifications backend: fetch the inbox, mark threads read, unsubscribe.
+//! All network work runs off the UI thread; results sent via mpsc.
+
+use std::sync::Arc;
+
+use myme_core::connectivity;
+use myme_services::{GitHubClient, GitHubNotification};
+
+use crate::bridge;
+
+/// Error type for notification operations
+#[derive(Debug, Clone)]
+pub enum NotificationError {
+    Network(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for NotificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationError::Network(s) => write!(f, "Notification error: {}", s),
+            NotificationError::NotInitialized => {
+                write!(f, "Notification service not initialized")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NotificationError {}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum NotificationServiceMessage {
+    FetchDone(Result<Vec<GitHubNotification>, NotificationError>),
+    MarkReadDone { id: String, result: Result<(), NotificationError> },
+    UnsubscribeDone { id: String, result: Result<(), NotificationError> },
+}
+
+/// Runtime for a request, or None after reporting why there isn't one
+fn runtime_or_offline<F>(
+    tx: &std::sync::mpsc::Sender<NotificationServiceMessage>,
+    fail: F,
+) -> Option<tokio::runtime::Handle>
+where
+    F: FnOnce(NotificationError) -> NotificationServiceMessage,
+{
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(fail(NotificationError::NotInitialized));
+        return None;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(fail(NotificationError::Network(connectivity::OFFLINE_ERROR.into())));
+        return None;
+    }
+    Some(runtime)
+}
+
+/// Request the notification inbox (unread only unless `all`). Sends `FetchDone`.
+pub fn request_fetch(
+    tx: &std::sync::mpsc::Sender<NotificationServiceMessage>,
+    client: Arc<GitHubClient>,
+    all: bool,
+) {
+    let tx = tx.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| NotificationServiceMessage::FetchDone(Err(e)))
+    else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = client
+            .list_notifications(all)
+            .await
+            .map_err(|e| NotificationError::Network(e.to_string()));
+        let _ = tx.send(NotificationServiceMessage::FetchDone(result));
+    });
+}
+
+/// Request marking a thread as read. Sends `MarkReadDone`.
+pub fn request_mark_read(
+    tx: &std::sync::mpsc::Sender<NotificationServiceMessage>,
+    client: Arc<GitHubClient>,
+    id: String,
+) {
+    let tx = tx.clone();
+    let fail_id = id.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| NotificationServiceMessage::MarkReadDone {
+        id: fail_id,
+        result: Err(e),
+    }) else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = client
+            .mark_notification_read(&id)
+            .await
+            .map_err(|e| NotificationError::Network(e.to_string()));
+        let _ = tx.send(NotificationServiceMessage::MarkReadDone { id, result });
+    });
+}
+
+/// Request unsubscribing from a thread. Sends `UnsubscribeDone`.
+pub fn request_unsubscribe(
+    tx: &std::sync::mpsc::Sender<NotificationServiceMessage>,
+    client: Arc<GitHubClient>,
+    id: String,
+) {
+    let tx = tx.clone();
+    let fail_id = id.clone();
+    let Some(runtime) = runtime_or_offline(&tx, |e| NotificationServiceMessage::UnsubscribeDone {
+        id: fail_id,
+        result: Err(e),
+    }) else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = client
+            .unsubscribe_thread(&id)
+            .await
+            .map_err(|e| NotificationError::Network(e.to_string()));
+        let _ = tx.send(NotificationServiceMessage::UnsubscribeDone { id, result });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn notification_error_display() {
+        assert!(
+            format!("{}", NotificationError::Network("timeout".into())).contains("Notification")
+        );
+        assert!(format!("{}", NotificationError::NotInitialized).contains("not initialized"));
+    }
+}
@@ -3,8 +3,10 @@
 
 use std::sync::Arc;
 
+use myme_core::connectivity;
 use myme_services::GitHubClient;
 
+use super::repo_service;
 use crate::bridge;
 
 /// Error type for project operations
@@ -37,6 +39,10 @@ pub struct RepoInfo {
 pub enum ProjectServiceMessage {
     /// Result of fetching repo info from GitHub (when adding repo to project)
     FetchRepoDone(Result<RepoInfo, ProjectError>),
+    /// Result of creating missing status labels on a newly linked repo
+    EnsureLabelsDone { repo_id: String, result: Result<Vec<String>, ProjectError> },
+    /// `owner/repo` names to offer when linking a repo, most recently updated first
+    RepoChoicesDone(Result<Vec<String>, ProjectError>),
 }
 
 /// Request to fetch repo info asynchronously.
@@ -56,6 +62,12 @@ pub fn request_fetch_repo(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let _ = tx.send(ProjectServiceMessage::FetchRepoDone(Err(ProjectError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
 
     runtime.spawn(async move {
         let result = client
@@ -67,6 +79,78 @@ pub fn request_fetch_repo(
     });
 }
 
+/// Request creation of the standard status labels on a repo.
+/// Sends `EnsureLabelsDone` with the labels that had to be created.
+pub fn request_ensure_labels(
+    tx: &std::sync::mpsc::Sender<ProjectServiceMessage>,
+    client: Arc<GitHubClient>,
+    owner: String,
+    repo: String,
+) {
+    let tx = tx.clone();
+    let repo_id = format!("{}/{}", owner, repo);
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(ProjectServiceMessage::EnsureLabelsDone {
+                repo_id,
+                result: Err(ProjectError::NotInitialized),
+            });
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(ProjectServiceMessage::EnsureLabelsDone {
+            repo_id,
+            result: Err(ProjectError::Network(connectivity::OFFLINE_ERROR.into())),
+        });
+        return;
+    }
+
+    runtime.spawn(async move {
+        let result = client
+            .ensure_status_labels(&owner, &repo)
+            .await
+            .map_err(|e| ProjectError::Network(e.to_string()));
+        let _ = tx.send(ProjectServiceMessage::EnsureLabelsDone { repo_id, result });
+    });
+}
+
+/// Request the repos to offer when linking one to a project: the user's own
+/// plus those of the orgs and teams in `repos.orgs`, reusing the Repos page's
+/// listing when there is one. Sends `RepoChoicesDone`.
+pub fn request_repo_choices(
+    tx: &std::sync::mpsc::Sender<ProjectServiceMessage>,
+    client: Arc<GitHubClient>,
+) {
+    let full_names = |repos: Vec<myme_services::GitHubRepo>| -> Vec<String> {
+        repos.into_iter().map(|r| r.full_name).collect()
+    };
+    if let Some(repos) = repo_service::last_github_repos() {
+        let _ = tx.send(ProjectServiceMessage::RepoChoicesDone(Ok(full_names(repos))));
+        return;
+    }
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(ProjectServiceMessage::RepoChoicesDone(Err(ProjectError::NotInitialized)));
+        return;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(ProjectServiceMessage::RepoChoicesDone(Err(ProjectError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let result = repo_service::fetch_github_repos(&client)
+            .await
+            .map(full_names)
+            .map_err(|e| ProjectError::Network(e.to_string()));
+        let _ = tx.send(ProjectServiceMessage::RepoChoicesDone(result));
+    });
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -0,0 +1,223 @@
+//! GitHub releases backend: list releases and tags, draft a changelog from
+//! work closed since the last tag, and publish releases.
+//! All network work runs off the UI thread; results sent via mpsc.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use myme_core::connectivity;
+use myme_services::{
+    draft_changelog, next_tag, ChangelogDraft, CreateReleaseRequest, GitHubClient, GitHubRelease,
+    GitHubTag,
+};
+
+use crate::bridge;
+
+/// Tag suggested for a repository's first release
+const FIRST_TAG: &str = "v0.1.0";
+
+/// Error type for release operations
+#[derive(Debug, Clone)]
+pub enum ReleaseError {
+    Network(String),
+    /// Not an `owner/repo` name
+    InvalidRepo(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for ReleaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReleaseError::Network(s) => write!(f, "Release error: {}", s),
+            ReleaseError::InvalidRepo(s) => write!(f, "Not a repository name: {}", s),
+            ReleaseError::NotInitialized => write!(f, "Release service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for ReleaseError {}
+
+/// A drafted changelog and the tag it would be released as
+#[derive(Debug, Clone)]
+pub struct ReleaseDraft {
+    /// Tag the changelog starts after; None when the repo has no tags
+    pub since_tag: Option<String>,
+    pub suggested_tag: String,
+    pub changelog: ChangelogDraft,
+}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum ReleaseServiceMessage {
+    FetchDone(Result<(Vec<GitHubRelease>, Vec<GitHubTag>), ReleaseError>),
+    DraftDone(Result<ReleaseDraft, ReleaseError>),
+    CreateDone(Result<GitHubRelease, ReleaseError>),
+}
+
+fn network(e: anyhow::Error) -> ReleaseError {
+    ReleaseError::Network(e.to_string())
+}
+
+/// Split `owner/repo`, rejecting anything else
+fn split_full_name(full_name: &str) -> Result<(String, String), ReleaseError> {
+    match full_name.trim().split_once('/') {
+        Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() && !repo.contains('/') => {
+            Ok((owner.to_string(), repo.to_string()))
+        }
+        _ => Err(ReleaseError::InvalidRepo(full_name.to_string())),
+    }
+}
+
+/// Runtime for a request, or None after reporting why there isn't one
+fn runtime_or_offline<F>(
+    tx: &std::sync::mpsc::Sender<ReleaseServiceMessage>,
+    fail: F,
+) -> Option<tokio::runtime::Handle>
+where
+    F: FnOnce(ReleaseError) -> ReleaseServiceMessage,
+{
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(fail(ReleaseError::NotInitialized));
+        return None;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(fail(ReleaseError::Network(connectivity::OFFLINE_ERROR.into())));
+        return None;
+    }
+    Some(runtime)
+}
+
+/// Request the repo's releases and tags. Sends `FetchDone`.
+pub fn request_fetch(
+    tx: &std::sync::mpsc::Sender<ReleaseServiceMessage>,
+    client: Arc<GitHubClient>,
+    full_name: &str,
+) {
+    let tx = tx.clone();
+    let (owner, repo) = match split_full_name(full_name) {
+        Ok(parts) => parts,
+        Err(e) => {
+            let _ = tx.send(ReleaseServiceMessage::FetchDone(Err(e)));
+            return;
+        }
+    };
+    let Some(runtime) = runtime_or_offline(&tx, |e| ReleaseServiceMessage::FetchDone(Err(e)))
+    else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let (releases, tags) =
+            tokio::join!(client.list_releases(&owner, &repo), client.list_tags(&owner, &repo));
+        let result = match (releases, tags) {
+            (Ok(releases), Ok(tags)) => Ok((releases, tags)),
+            (Err(e), _) | (_, Err(e)) => Err(network(e)),
+        };
+        let _ = tx.send(ReleaseServiceMessage::FetchDone(result));
+    });
+}
+
+/// When the latest tag was cut: its release's publish date, or else the
+/// date of the commit it points at.
+async fn tag_date(
+    client: &GitHubClient,
+    owner: &str,
+    repo: &str,
+    tag: &GitHubTag,
+) -> anyhow::Result<DateTime<Utc>> {
+    let releases = client.list_releases(owner, repo).await?;
+    let published =
+        releases.iter().find(|r| r.tag_name == tag.name).and_then(|r| r.published_at.clone());
+    let date = match published {
+        Some(date) => date,
+        None => client.get_commit_date(owner, repo, &tag.commit.sha).await?,
+    };
+    Ok(DateTime::parse_from_rfc3339(&date)?.with_timezone(&Utc))
+}
+
+/// Draft a changelog of pull requests merged and issues closed since the
+/// latest tag. Sends `DraftDone`.
+pub fn request_draft(
+    tx: &std::sync::mpsc::Sender<ReleaseServiceMessage>,
+    client: Arc<GitHubClient>,
+    full_name: &str,
+) {
+    let tx = tx.clone();
+    let (owner, repo) = match split_full_name(full_name) {
+        Ok(parts) => parts,
+        Err(e) => {
+            let _ = tx.send(ReleaseServiceMessage::DraftDone(Err(e)));
+            return;
+        }
+    };
+    let Some(runtime) = runtime_or_offline(&tx, |e| ReleaseServiceMessage::DraftDone(Err(e)))
+    else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = async {
+            let tags = client.list_tags(&owner, &repo).await?;
+            let last = tags.first();
+            let since = match last {
+                Some(tag) => Some(tag_date(&client, &owner, &repo, tag).await?),
+                None => None,
+            };
+            let since_param =
+                since.map(|t| t.to_rfc3339()).unwrap_or_else(|| "1970-01-01T00:00:00Z".into());
+            let issues = client.list_issues_since(&owner, &repo, &since_param).await?;
+            let changelog = draft_changelog(&issues, since);
+            let suggested_tag = last
+                .and_then(|tag| next_tag(&tag.name, changelog.bump))
+                .unwrap_or_else(|| FIRST_TAG.to_string());
+            Ok(ReleaseDraft {
+                since_tag: last.map(|tag| tag.name.clone()),
+                suggested_tag,
+                changelog,
+            })
+        }
+        .await
+        .map_err(network);
+        let _ = tx.send(ReleaseServiceMessage::DraftDone(result));
+    });
+}
+
+/// Request publishing a release. Sends `CreateDone`.
+pub fn request_create(
+    tx: &std::sync::mpsc::Sender<ReleaseServiceMessage>,
+    client: Arc<GitHubClient>,
+    full_name: &str,
+    req: CreateReleaseRequest,
+) {
+    let tx = tx.clone();
+    let (owner, repo) = match split_full_name(full_name) {
+        Ok(parts) => parts,
+        Err(e) => {
+            let _ = tx.send(ReleaseServiceMessage::CreateDone(Err(e)));
+            return;
+        }
+    };
+    let Some(runtime) = runtime_or_offline(&tx, |e| ReleaseServiceMessage::CreateDone(Err(e)))
+    else {
+        return;
+    };
+
+    runtime.spawn(async move {
+        let result = client.create_release(&owner, &repo, req).await.map_err(network);
+        let _ = tx.send(ReleaseServiceMessage::CreateDone(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn release_error_display_and_repo_names() {
+        assert!(format!("{}", ReleaseError::Network("timeout".into())).contains("Release"));
+        assert!(format!("{}", ReleaseError::NotInitialized).contains("not initialized"));
+        assert_eq!(split_full_name("octo/repo").unwrap(), ("octo".into(), "repo".into()));
+        assert!(matches!(split_full_name("octo"), Err(ReleaseError::InvalidRepo(_))));
+    }
+}
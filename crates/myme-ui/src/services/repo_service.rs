@@ -2,13 +2,23 @@
 //! All heavy work runs off the UI thread; results sent via mpsc.
 //! Supports cancellation for long-running operations.
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-use myme_integrations::{match_repos, GitOperations, RepoEntry};
+use chrono::{DateTime, Duration, Utc};
+use myme_core::connectivity;
+use myme_integrations::{
+    check_repo_health, match_repos, CloneOptions, CommitInfo, CommitStats, ForkSync, GitOperations,
+    HealthOptions, LocalRepo, RepoEntry, RepoHealth, RepoWatcher, TransferCancelled,
+    TransferProgress, UPSTREAM_REMOTE,
+};
+use myme_services::{GitHubClient, GitHubRepo, RepoPreview, RepoScope, RepoSecurity};
 use tokio_util::sync::CancellationToken;
 
+use super::activity_service;
+use super::ui_events::{self, UiEvent};
 use crate::bridge;
 
 #[derive(Debug, Clone)]
@@ -36,11 +46,62 @@ impl std::error::Error for RepoError {}
 
 #[derive(Debug)]
 pub enum RepoServiceMessage {
+    /// Local clones as last seen, from the repo state cache; `RefreshDone` follows
+    CachedDone(Vec<RepoEntry>),
     RefreshDone(Result<Vec<RepoEntry>, RepoError>),
-    CloneDone { index: usize, result: Result<(), RepoError> },
-    PullDone { index: usize, result: Result<(), RepoError> },
+    CloneDone {
+        index: usize,
+        result: Result<(), RepoError>,
+    },
+    PullDone {
+        index: usize,
+        result: Result<(), RepoError>,
+    },
+    /// Fork's default branch brought up to date with `upstream`
+    SyncForkDone {
+        index: usize,
+        result: Result<ForkSync, RepoError>,
+    },
+    /// Clone/pull/sync transfer progress for entry `index`, 0-100
+    Progress {
+        index: usize,
+        pct: u8,
+    },
+    /// Remote repo created, cloned and (optionally) linked; carries its full name
+    CreateDone(Result<String, RepoError>),
+    /// Commit activity for each local repo
+    ActivityDone(Result<Vec<RepoActivity>, RepoError>),
+    /// Open security alert counts for the checked repos (already stored)
+    SecurityDone(Result<Vec<RepoSecurity>, RepoError>),
+    /// README and metadata for `full_name` (already cached)
+    PreviewDone {
+        full_name: String,
+        result: Result<RepoPreview, RepoError>,
+    },
+    /// Organizations and teams the user can scope the listing to
+    ScopesDone(Result<Vec<RepoScope>, RepoError>),
+    /// Hygiene report for each checked local clone
+    HealthDone(Result<Vec<(PathBuf, RepoHealth)>, RepoError>),
+    /// A watched clone changed on disk; carries its re-read status
+    RepoUpdated(LocalRepo),
+    /// These GitHub repos (`owner/repo`) were pushed to
+    RemoteChanged(Vec<String>),
 }
 
+/// Recent commit activity for one local repo
+#[derive(Debug, Clone)]
+pub struct RepoActivity {
+    pub name: String,
+    pub path: PathBuf,
+    /// Totals over the requested window
+    pub stats: CommitStats,
+    /// Latest commits, newest first (not limited to the window)
+    pub recent: Vec<CommitInfo>,
+}
+
+/// Commits kept per repo for the card and the activity feed
+const RECENT_COMMITS: usize = 10;
+
 const GITHUB_CACHE_TTL_SECS: u64 = 60;
 static GH_CACHE_TIME: AtomicU64 = AtomicU64::new(0);
 static GH_CACHE: std::sync::OnceLock<std::sync::Mutex<Option<Vec<myme_services::GitHubRepo>>>> =
@@ -66,6 +127,45 @@ fn use_cached_github() -> Option<Vec<myme_services::GitHubRepo>> {
     github_cache().lock().ok()?.clone()
 }
 
+/// Last GitHub listing fetched by a refresh, however old (None before the first).
+pub(crate) fn last_github_repos() -> Option<Vec<myme_services::GitHubRepo>> {
+    github_cache().lock().ok()?.clone()
+}
+
+/// Drop the cached GitHub listing so the next refresh fetches it again.
+fn invalidate_github_cache() {
+    GH_CACHE_TIME.store(0, Ordering::Relaxed);
+}
+
+/// Tell the repo list that `repos` were pushed to on GitHub, dropping the
+/// cached listing so the refresh it triggers sees the change.
+pub fn remote_changed(repos: Vec<String>) {
+    invalidate_github_cache();
+    if let Some(tx) = bridge::get_repo_service_tx() {
+        let _ = tx.send(RepoServiceMessage::RemoteChanged(repos));
+    }
+}
+
+/// Org and team scopes from `repos.orgs`
+pub fn configured_scopes() -> Vec<RepoScope> {
+    RepoScope::parse_all(&myme_core::Config::load_cached().repos.orgs)
+}
+
+/// Save `scopes` to `repos.orgs` and drop the cached listing so the next
+/// refresh includes (or leaves out) their repos.
+pub fn save_scopes(scopes: &[RepoScope]) -> Result<(), RepoError> {
+    let mut config = myme_core::Config::load().map_err(|e| RepoError::Config(e.to_string()))?;
+    config.repos.orgs = scopes.iter().map(ToString::to_string).collect();
+    config.save().map_err(|e| RepoError::Config(e.to_string()))?;
+    invalidate_github_cache();
+    Ok(())
+}
+
+/// The user's repositories plus those of the configured orgs and teams
+pub(crate) async fn fetch_github_repos(client: &GitHubClient) -> anyhow::Result<Vec<GitHubRepo>> {
+    client.list_scoped_repos(&configured_scopes()).await
+}
+
 fn set_github_cache(repos: Vec<myme_services::GitHubRepo>) {
     let now = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -77,8 +177,98 @@ fn set_github_cache(repos: Vec<myme_services::GitHubRepo>) {
     }
 }
 
+/// How long a fetched README preview is reused before asking GitHub again
+const PREVIEW_CACHE_TTL_MINUTES: i64 = 30;
+
+/// Previews by full name, with when they were fetched
+type PreviewCache = HashMap<String, (DateTime<Utc>, RepoPreview)>;
+
+static PREVIEW_CACHE: std::sync::OnceLock<std::sync::Mutex<PreviewCache>> =
+    std::sync::OnceLock::new();
+
+fn preview_cache() -> &'static std::sync::Mutex<PreviewCache> {
+    PREVIEW_CACHE.get_or_init(|| std::sync::Mutex::new(HashMap::new()))
+}
+
+fn preview_is_fresh(fetched_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+    now - fetched_at < Duration::minutes(PREVIEW_CACHE_TTL_MINUTES)
+}
+
+/// Cached preview for `full_name` that is still within the TTL
+pub fn cached_preview(full_name: &str) -> Option<RepoPreview> {
+    let cache = preview_cache().lock().ok()?;
+    cache
+        .get(full_name)
+        .filter(|(fetched_at, _)| preview_is_fresh(*fetched_at, Utc::now()))
+        .map(|(_, preview)| preview.clone())
+}
+
+fn set_cached_preview(full_name: &str, preview: &RepoPreview) {
+    if let Ok(mut cache) = preview_cache().lock() {
+        cache.insert(full_name.to_string(), (Utc::now(), preview.clone()));
+    }
+}
+
+/// Progress callback for clone/pull: forwards percentage changes to the UI and
+/// the `key` progress toast, and stops the transfer once `cancel_token` fires.
+fn progress_reporter(
+    tx: std::sync::mpsc::Sender<RepoServiceMessage>,
+    index: usize,
+    key: String,
+    title: String,
+    cancel_token: Option<Arc<CancellationToken>>,
+) -> impl FnMut(TransferProgress) -> bool {
+    let mut last_pct = None;
+    move |progress| {
+        if cancel_token.as_ref().is_some_and(|t| t.is_cancelled()) {
+            return false;
+        }
+        let pct = progress.percent();
+        if last_pct != Some(pct) {
+            last_pct = Some(pct);
+            let _ = tx.send(RepoServiceMessage::Progress { index, pct });
+            ui_events::publish(UiEvent::progress("repos", title.clone(), Some(pct)).keyed(&key));
+        }
+        true
+    }
+}
+
+/// Toast key for an operation on the clone at `path`
+fn op_key(op: &str, path: &std::path::Path) -> String {
+    format!("repos:{}:{}", op, path.display())
+}
+
+fn repo_name(path: &std::path::Path) -> String {
+    path.file_name().unwrap_or_default().to_string_lossy().into_owned()
+}
+
+/// Replace the `key` progress toast with how the operation ended: `done` on
+/// success, `failed` with the error's user message otherwise.
+fn publish_outcome<T>(
+    key: &str,
+    result: &Result<T, RepoError>,
+    done: impl FnOnce(&T) -> String,
+    failed: String,
+) {
+    let event = match result {
+        Ok(value) => UiEvent::success("repos", done(value)),
+        Err(RepoError::Cancelled) => UiEvent::info("repos", "Cancelled"),
+        Err(e) => UiEvent::error("repos", failed, &myme_core::AppError::from(e.clone())),
+    };
+    ui_events::publish(event.keyed(key));
+}
+
+fn git_error(e: anyhow::Error) -> RepoError {
+    if e.is::<TransferCancelled>() {
+        RepoError::Cancelled
+    } else {
+        RepoError::Git(e.to_string())
+    }
+}
+
 /// Request a full refresh (discover local + fetch GitHub + match).
-/// Sends `RefreshDone` on the channel when done.
+/// Sends `CachedDone` first when the repo state cache has entries, then
+/// `RefreshDone` on the channel when done; the result is saved to the cache.
 pub fn request_refresh(tx: &std::sync::mpsc::Sender<RepoServiceMessage>) {
     let tx = tx.clone();
     let runtime = match bridge::get_runtime() {
@@ -98,6 +288,18 @@ pub fn request_refresh(tx: &std::sync::mpsc::Sender<RepoServiceMessage>) {
     let authenticated = bridge::is_github_authenticated();
 
     runtime.spawn(async move {
+        let cached = tokio::task::spawn_blocking(|| {
+            let store = bridge::get_repo_state_store_or_init()?;
+            store
+                .load(Utc::now())
+                .map_err(|e| tracing::warn!("Failed to read repo state cache: {}", e))
+                .ok()
+        })
+        .await;
+        if let Ok(Some(entries)) = cached.map(|c| c.filter(|e| !e.is_empty())) {
+            let _ = tx.send(RepoServiceMessage::CachedDone(entries));
+        }
+
         let local = tokio::task::spawn_blocking({
             let path = effective_path.clone();
             move || GitOperations::discover_repositories(&path, Some(5))
@@ -121,7 +323,7 @@ pub fn request_refresh(tx: &std::sync::mpsc::Sender<RepoServiceMessage>) {
             if let Some(cached) = use_cached_github() {
                 cached
             } else if let Some(client) = github_client {
-                match client.list_repos().await {
+                match fetch_github_repos(&client).await {
                     Ok(repos) => {
                         set_github_cache(repos.clone());
                         repos
@@ -141,20 +343,117 @@ pub fn request_refresh(tx: &std::sync::mpsc::Sender<RepoServiceMessage>) {
         };
 
         let entries = match_repos(&local, &remote);
+        record_github_ids(&entries).await;
+        save_repo_state(&entries).await;
         let _ = tx.send(RepoServiceMessage::RefreshDone(Ok(entries)));
     });
 }
 
+/// Remember what discovery found for the next launch, tombstoning clones it
+/// no longer sees.
+async fn save_repo_state(entries: &[RepoEntry]) {
+    let entries = entries.to_vec();
+    let _ = tokio::task::spawn_blocking(move || {
+        let Some(store) = bridge::get_repo_state_store_or_init() else {
+            return;
+        };
+        match store.save(&entries, Utc::now()) {
+            Ok(gone) if !gone.is_empty() => {
+                tracing::info!("{} repos no longer found; hidden from the cache", gone.len());
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to save repo state cache: {}", e),
+        }
+    })
+    .await;
+}
+
+/// How long a clone must be quiet before its status is re-read
+const WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(750);
+
+/// The running watcher and the clones it covers
+static REPO_WATCH: std::sync::OnceLock<std::sync::Mutex<Option<(Vec<PathBuf>, RepoWatcher)>>> =
+    std::sync::OnceLock::new();
+
+/// Watch the clones at `paths` and send `RepoUpdated` whenever one commits,
+/// switches branch or has its working tree edited. Replaces the previous
+/// watch; an empty list (or `repos.watch = false`) stops watching.
+pub fn watch_repos(tx: &std::sync::mpsc::Sender<RepoServiceMessage>, mut paths: Vec<PathBuf>) {
+    if !myme_core::Config::load_cached().repos.watch {
+        paths.clear();
+    }
+    paths.sort();
+    let Ok(mut current) = REPO_WATCH.get_or_init(|| std::sync::Mutex::new(None)).lock() else {
+        return;
+    };
+    if current.as_ref().is_some_and(|(watched, _)| *watched == paths) {
+        return;
+    }
+    // Drop the old watcher first so its thread stops before the new one starts
+    *current = None;
+    if paths.is_empty() {
+        return;
+    }
+
+    let tx = tx.clone();
+    let on_change = move |path: &std::path::Path, changes: myme_integrations::RepoChanges| {
+        tracing::debug!("{} changed on disk: {:?}", path.display(), changes);
+        let local = match GitOperations::get_repository_info(path) {
+            Ok(local) => local,
+            Err(e) => {
+                tracing::debug!("Could not re-read {}: {}", path.display(), e);
+                return;
+            }
+        };
+        if let Some(store) = bridge::get_repo_state_store_or_init() {
+            if let Err(e) = store.update_local(&local, Utc::now()) {
+                tracing::warn!("Failed to update repo state cache: {}", e);
+            }
+        }
+        let _ = tx.send(RepoServiceMessage::RepoUpdated(local));
+    };
+    match RepoWatcher::start(paths.clone(), WATCH_DEBOUNCE, on_change) {
+        Ok(watcher) => *current = Some((paths, watcher)),
+        Err(e) => tracing::warn!("Could not watch local repos: {}", e),
+    }
+}
+
+/// Store the GitHub repo id in each newly matched clone's git config, so the
+/// pairing survives a later rename or transfer of the GitHub repo.
+async fn record_github_ids(entries: &[RepoEntry]) {
+    let unrecorded: Vec<(PathBuf, i64)> = entries
+        .iter()
+        .filter_map(|e| match (&e.local, &e.github) {
+            (Some(l), Some(g)) if l.github_id != Some(g.id) => Some((l.path.clone(), g.id)),
+            _ => None,
+        })
+        .collect();
+    if unrecorded.is_empty() {
+        return;
+    }
+    let _ = tokio::task::spawn_blocking(move || {
+        for (path, id) in unrecorded {
+            if let Err(e) = GitOperations::set_github_id(&path, id) {
+                tracing::warn!("Could not record repo id in {}: {}", path.display(), e);
+            }
+        }
+    })
+    .await;
+}
+
 /// Request clone for a GitHub-only repo. Sends `CloneDone { index, result }`, then
-/// the pump should trigger a refresh.
+/// the pump should trigger a refresh. `options` can make it shallow, single-branch
+/// or sparse.
 ///
-/// If a `cancel_token` is provided, the operation will check for cancellation
-/// before starting the actual clone.
+/// Sends `Progress` while objects download. If a `cancel_token` is provided, it is
+/// checked before starting and between transfer chunks; cancelling aborts the clone
+/// and removes the partial checkout.
 pub fn request_clone(
     tx: &std::sync::mpsc::Sender<RepoServiceMessage>,
     index: usize,
     clone_url: String,
     target_path: PathBuf,
+    options: CloneOptions,
     cancel_token: Option<Arc<CancellationToken>>,
 ) {
     let tx = tx.clone();
@@ -181,9 +480,38 @@ pub fn request_clone(
             }
         }
 
-        let result = GitOperations::clone_repository(&clone_url, &target_path)
-            .map(|_| ())
-            .map_err(|e| RepoError::Git(e.to_string()));
+        let name = repo_name(&target_path);
+        let key = op_key("clone", &target_path);
+        let on_progress = progress_reporter(
+            tx.clone(),
+            index,
+            key.clone(),
+            format!("Cloning {}", name),
+            cancel_token,
+        );
+        let result = GitOperations::clone_repository_with_progress(
+            &clone_url,
+            &target_path,
+            &options,
+            on_progress,
+        )
+        .map(|_| ())
+        .map_err(git_error);
+        if result.is_ok() {
+            activity_service::record(
+                "repos",
+                "cloned",
+                &target_path.to_string_lossy(),
+                &format!("Cloned {}", name),
+                None,
+            );
+        }
+        publish_outcome(
+            &key,
+            &result,
+            |_| format!("Cloned {}", name),
+            format!("Couldn't clone {}", name),
+        );
         let _ = tx.send(RepoServiceMessage::CloneDone { index, result });
     });
 }
@@ -191,8 +519,8 @@ pub fn request_clone(
 /// Request pull for a local repo. Sends `PullDone { index, result }`, then
 /// the pump should trigger a refresh.
 ///
-/// If a `cancel_token` is provided, the operation will check for cancellation
-/// before starting the actual pull.
+/// Sends `Progress` during the fetch. If a `cancel_token` is provided, it is checked
+/// before starting and between transfer chunks; cancelling aborts before merging.
 pub fn request_pull(
     tx: &std::sync::mpsc::Sender<RepoServiceMessage>,
     index: usize,
@@ -223,11 +551,458 @@ pub fn request_pull(
             }
         }
 
-        let result = GitOperations::pull(&path).map_err(|e| RepoError::Git(e.to_string()));
+        let name = repo_name(&path);
+        let key = op_key("pull", &path);
+        let on_progress = progress_reporter(
+            tx.clone(),
+            index,
+            key.clone(),
+            format!("Pulling {}", name),
+            cancel_token,
+        );
+        let result = GitOperations::pull_with_progress(&path, on_progress).map_err(git_error);
+        publish_outcome(
+            &key,
+            &result,
+            |_| format!("Pulled {}", name),
+            format!("Couldn't pull {}", name),
+        );
         let _ = tx.send(RepoServiceMessage::PullDone { index, result });
     });
 }
 
+/// Sync the fork at `path` with its upstream (see `GitOperations::sync_fork`),
+/// pushing the result to `origin` when `push` is set. A fork without an
+/// `upstream` remote gets one from its GitHub parent first, so `github` (client
+/// and owner/repo) should be given for GitHub forks.
+/// Sends `Progress` during the fetch, then `SyncForkDone { index, result }`.
+pub fn request_sync_fork(
+    tx: &std::sync::mpsc::Sender<RepoServiceMessage>,
+    index: usize,
+    path: PathBuf,
+    push: bool,
+    github: Option<(Arc<GitHubClient>, String)>,
+    cancel_token: Option<Arc<CancellationToken>>,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(RepoServiceMessage::SyncForkDone {
+                index,
+                result: Err(RepoError::Config("Runtime not initialized".into())),
+            });
+            return;
+        }
+    };
+    let name = repo_name(&path);
+    let key = op_key("sync", &path);
+    if !connectivity::is_online() {
+        let result = Err(RepoError::GitHub(connectivity::OFFLINE_ERROR.into()));
+        publish_outcome(
+            &key,
+            &result,
+            |_: &ForkSync| String::new(),
+            format!("Couldn't sync {}", name),
+        );
+        let _ = tx.send(RepoServiceMessage::SyncForkDone { index, result });
+        return;
+    }
+
+    runtime.spawn(async move {
+        let result = async {
+            let has_upstream = GitOperations::list_remotes(&path)
+                .map_err(git_error)?
+                .iter()
+                .any(|r| r.name == UPSTREAM_REMOTE);
+            if !has_upstream {
+                if let Some((client, full_name)) = github {
+                    let url = parent_clone_url(&client, &full_name).await?;
+                    GitOperations::set_remote(&path, UPSTREAM_REMOTE, &url).map_err(git_error)?;
+                }
+            }
+            let on_progress = progress_reporter(
+                tx.clone(),
+                index,
+                key.clone(),
+                format!("Syncing {} with upstream", name),
+                cancel_token,
+            );
+            tokio::task::spawn_blocking(move || {
+                GitOperations::sync_fork_with_progress(&path, push, on_progress)
+            })
+            .await
+            .map_err(|e| RepoError::Io(e.to_string()))?
+            .map_err(git_error)
+        }
+        .await;
+        let done = |sync: &ForkSync| match sync.fast_forwarded {
+            0 => format!("{} is up to date with upstream", name),
+            n => format!("Synced {}: {} moved {} commit(s)", name, sync.branch, n),
+        };
+        publish_outcome(&key, &result, done, format!("Couldn't sync {}", name));
+        let _ = tx.send(RepoServiceMessage::SyncForkDone { index, result });
+    });
+}
+
+/// Clone URL of the repo `full_name` (owner/repo) was forked from
+async fn parent_clone_url(client: &GitHubClient, full_name: &str) -> Result<String, RepoError> {
+    let (owner, name) = full_name
+        .split_once('/')
+        .ok_or_else(|| RepoError::Config(format!("Invalid repo: {}", full_name)))?;
+    let repo = client.get_repo(owner, name).await.map_err(|e| RepoError::GitHub(e.to_string()))?;
+    let parent =
+        repo.parent.ok_or_else(|| RepoError::GitHub(format!("{} is not a fork", full_name)))?;
+    Ok(parent.clone_url.unwrap_or_else(|| format!("https://github.com/{}.git", parent.full_name)))
+}
+
+/// Create a GitHub repo, clone it under `base_path/<owner>/<name>`, and link it to
+/// `project_id` when given. Sends `CreateDone` with the new repo's full name.
+///
+/// A failed clone or link is reported as an error, but the GitHub repo is left
+/// in place so it shows up as GitHub-only on the next refresh.
+pub fn request_create_remote(
+    tx: &std::sync::mpsc::Sender<RepoServiceMessage>,
+    client: Arc<myme_services::GitHubClient>,
+    request: myme_services::CreateRepoRequest,
+    base_path: PathBuf,
+    project_id: Option<String>,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(RepoServiceMessage::CreateDone(Err(RepoError::Config(
+                "Runtime not initialized".into(),
+            ))));
+            return;
+        }
+    };
+
+    runtime.spawn(async move {
+        let key = format!("repos:create:{}", request.name);
+        ui_events::publish(
+            UiEvent::progress("repos", format!("Creating {}", request.name), None).keyed(&key),
+        );
+        let failed = format!("Couldn't create {}", request.name);
+        let result = create_clone_and_link(client, request, base_path, project_id).await;
+        publish_outcome(&key, &result, |full_name| format!("Created {}", full_name), failed);
+        let _ = tx.send(RepoServiceMessage::CreateDone(result));
+    });
+}
+
+async fn create_clone_and_link(
+    client: Arc<myme_services::GitHubClient>,
+    request: myme_services::CreateRepoRequest,
+    base_path: PathBuf,
+    project_id: Option<String>,
+) -> Result<String, RepoError> {
+    let repo = client.create_repo(request).await.map_err(|e| RepoError::GitHub(e.to_string()))?;
+    invalidate_github_cache();
+
+    let full_name = repo.full_name.clone();
+    let clone_url = repo
+        .clone_url
+        .clone()
+        .filter(|u| !u.is_empty())
+        .unwrap_or_else(|| format!("https://github.com/{}.git", full_name));
+    let target_path = base_path.join(full_name.replace('/', std::path::MAIN_SEPARATOR_STR));
+    tokio::task::spawn_blocking(move || {
+        GitOperations::clone_repository(&clone_url, &target_path).map(|_| ())
+    })
+    .await
+    .map_err(|e| RepoError::Io(e.to_string()))?
+    .map_err(git_error)?;
+
+    if let Some(project_id) = project_id.filter(|id| !id.is_empty()) {
+        let store = bridge::get_project_store_or_init()
+            .ok_or_else(|| RepoError::Config("Project store not initialized".into()))?;
+        store
+            .add_repo_to_project(&project_id, &full_name)
+            .map_err(|e| RepoError::Io(e.to_string()))?;
+    }
+
+    tracing::info!("Created and cloned {}", full_name);
+    activity_service::record(
+        "repos",
+        "created",
+        &full_name,
+        &format!("Created repository {}", full_name),
+        None,
+    );
+    Ok(full_name)
+}
+
+/// Gather commit activity over the last `days` for `repos` (name, path). When
+/// `repos` is `None`, local repos under the search path are discovered first.
+/// Sends `ActivityDone`; repos that can't be read are skipped.
+pub fn request_activity(
+    tx: &std::sync::mpsc::Sender<RepoServiceMessage>,
+    repos: Option<Vec<(String, PathBuf)>>,
+    days: u32,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(RepoServiceMessage::ActivityDone(Err(RepoError::Config(
+                "Runtime not initialized".into(),
+            ))));
+            return;
+        }
+    };
+    let search_path = bridge::get_repos_local_search_path().map(|(p, _)| p);
+
+    runtime.spawn_blocking(move || {
+        let repos = match repos {
+            Some(repos) => repos,
+            None => {
+                let base = search_path.unwrap_or_else(|| PathBuf::from("."));
+                match GitOperations::discover_repositories(&base, Some(5)) {
+                    Ok(found) => found
+                        .into_iter()
+                        .map(|l| {
+                            let name = l
+                                .remote_url
+                                .as_deref()
+                                .and_then(myme_integrations::normalize_github_url)
+                                .unwrap_or(l.name);
+                            (name, l.path)
+                        })
+                        .collect(),
+                    Err(e) => {
+                        let _ = tx.send(RepoServiceMessage::ActivityDone(Err(RepoError::Git(
+                            e.to_string(),
+                        ))));
+                        return;
+                    }
+                }
+            }
+        };
+
+        let since = chrono::Utc::now() - chrono::Duration::days(i64::from(days));
+        let activity = repos
+            .into_iter()
+            .filter_map(|(name, path)| {
+                let read = GitOperations::commit_stats(&path, since).and_then(|stats| {
+                    Ok((stats, GitOperations::recent_commits(&path, RECENT_COMMITS)?))
+                });
+                match read {
+                    Ok((stats, recent)) => Some(RepoActivity { name, path, stats, recent }),
+                    Err(e) => {
+                        tracing::debug!("Skipping activity for {}: {}", path.display(), e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        let _ = tx.send(RepoServiceMessage::ActivityDone(Ok(activity)));
+    });
+}
+
+/// Check the hygiene of the local clones at `paths` (stale branches, missing
+/// LICENSE/CI, unpushed commits, large untracked files). Sends `HealthDone`;
+/// clones that can't be read are skipped.
+pub fn request_health(tx: &std::sync::mpsc::Sender<RepoServiceMessage>, paths: Vec<PathBuf>) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(RepoServiceMessage::HealthDone(Err(RepoError::Config(
+                "Runtime not initialized".into(),
+            ))));
+            return;
+        }
+    };
+    let options = HealthOptions {
+        stale_days: myme_core::Config::load_cached().repos.stale_branch_days.max(1),
+        ..HealthOptions::default()
+    };
+
+    runtime.spawn_blocking(move || {
+        let now = Utc::now();
+        let health = paths
+            .into_iter()
+            .filter_map(|path| match check_repo_health(&path, &options, now) {
+                Ok(health) => Some((path, health)),
+                Err(e) => {
+                    tracing::debug!("Skipping health check for {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .collect();
+        let _ = tx.send(RepoServiceMessage::HealthDone(Ok(health)));
+    });
+}
+
+/// Count open Dependabot and code scanning alerts for `repo_id` (owner/repo)
+/// and store them, so the repo stays flagged until the next check.
+pub(crate) async fn check_security(
+    client: &GitHubClient,
+    repo_id: &str,
+) -> anyhow::Result<RepoSecurity> {
+    let Some((owner, repo)) = repo_id.split_once('/') else {
+        anyhow::bail!("Not a repository name: {}", repo_id);
+    };
+    let (dependabot, code_scanning) = tokio::join!(
+        client.list_dependabot_alerts(owner, repo),
+        client.list_code_scanning_alerts(owner, repo)
+    );
+    let security =
+        RepoSecurity::from_alerts(repo_id, dependabot?.as_deref(), code_scanning?.as_deref());
+    if let Some(store) = bridge::get_project_store_or_init() {
+        if let Err(e) = store.set_repo_security(&security) {
+            tracing::warn!("Could not store security alerts for {}: {}", repo_id, e);
+        }
+    }
+    Ok(security)
+}
+
+/// Check security alerts for `repo_ids` (owner/repo), one repo at a time.
+/// Sends `SecurityDone`; repos that fail to load are skipped.
+pub fn request_security(
+    tx: &std::sync::mpsc::Sender<RepoServiceMessage>,
+    client: Arc<GitHubClient>,
+    repo_ids: Vec<String>,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(RepoServiceMessage::SecurityDone(Err(RepoError::Config(
+                "Runtime not initialized".into(),
+            ))));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(RepoServiceMessage::SecurityDone(Err(RepoError::GitHub(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let mut checked = Vec::with_capacity(repo_ids.len());
+        for repo_id in repo_ids {
+            match check_security(&client, &repo_id).await {
+                Ok(security) => checked.push(security),
+                Err(e) => tracing::warn!("Skipping security alerts for {}: {}", repo_id, e),
+            }
+        }
+        let _ = tx.send(RepoServiceMessage::SecurityDone(Ok(checked)));
+    });
+}
+
+/// Request the README, topics and languages of `full_name` ("owner/repo").
+/// Sends `PreviewDone`; the result is cached for `cached_preview`.
+pub fn request_preview(
+    tx: &std::sync::mpsc::Sender<RepoServiceMessage>,
+    client: Arc<GitHubClient>,
+    full_name: String,
+) {
+    let tx = tx.clone();
+    let fail = |full_name: String, e: RepoError| RepoServiceMessage::PreviewDone {
+        full_name,
+        result: Err(e),
+    };
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(fail(full_name, RepoError::Config("Runtime not initialized".into())));
+        return;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(fail(full_name, RepoError::GitHub(connectivity::OFFLINE_ERROR.into())));
+        return;
+    }
+    let Some((owner, repo)) = full_name.split_once('/') else {
+        let e = RepoError::Config(format!("Not a GitHub repository: {}", full_name));
+        let _ = tx.send(fail(full_name, e));
+        return;
+    };
+    let (owner, repo) = (owner.to_string(), repo.to_string());
+
+    runtime.spawn(async move {
+        let result = client
+            .get_repo_preview(&owner, &repo)
+            .await
+            .map_err(|e| RepoError::GitHub(e.to_string()));
+        if let Ok(preview) = &result {
+            set_cached_preview(&full_name, preview);
+        }
+        let _ = tx.send(RepoServiceMessage::PreviewDone { full_name, result });
+    });
+}
+
+/// Request the organizations and teams the user belongs to, as scopes to pick
+/// from. Teams are skipped if they can't be listed. Sends `ScopesDone`.
+pub fn request_scopes(tx: &std::sync::mpsc::Sender<RepoServiceMessage>, client: Arc<GitHubClient>) {
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(RepoServiceMessage::ScopesDone(Err(RepoError::Config(
+            "Runtime not initialized".into(),
+        ))));
+        return;
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(RepoServiceMessage::ScopesDone(Err(RepoError::GitHub(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let orgs = match client.list_user_orgs().await {
+            Ok(orgs) => orgs,
+            Err(e) => {
+                let _ =
+                    tx.send(RepoServiceMessage::ScopesDone(Err(RepoError::GitHub(e.to_string()))));
+                return;
+            }
+        };
+        let teams = client.list_user_teams().await.unwrap_or_else(|e| {
+            tracing::warn!("Could not list teams: {}", e);
+            Vec::new()
+        });
+        let _ = tx.send(RepoServiceMessage::ScopesDone(Ok(scope_choices(&orgs, &teams))));
+    });
+}
+
+/// Each organization followed by its teams, sorted by name
+fn scope_choices(
+    orgs: &[myme_services::GitHubOrg],
+    teams: &[myme_services::GitHubTeam],
+) -> Vec<RepoScope> {
+    let mut choices: Vec<RepoScope> =
+        orgs.iter()
+            .map(|o| RepoScope::Org(o.login.clone()))
+            .chain(teams.iter().map(|t| RepoScope::Team {
+                org: t.organization.login.clone(),
+                team: t.slug.clone(),
+            }))
+            .collect();
+    // "org" sorts before "org/team"
+    choices.sort_by_key(|scope| (scope.org().to_lowercase(), scope.to_string()));
+    choices.dedup();
+    choices
+}
+
+/// Commits across `activity` made at or after `since`, newest first, each paired
+/// with its repo name.
+pub fn activity_feed(
+    activity: &[RepoActivity],
+    since: chrono::DateTime<chrono::Utc>,
+    limit: usize,
+) -> Vec<(&str, &CommitInfo)> {
+    let mut feed: Vec<(&str, &CommitInfo)> = activity
+        .iter()
+        .flat_map(|a| a.recent.iter().map(move |c| (a.name.as_str(), c)))
+        .filter(|(_, c)| c.time >= since)
+        .collect();
+    feed.sort_by(|a, b| b.1.time.cmp(&a.1.time));
+    feed.truncate(limit);
+    feed
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -245,11 +1020,128 @@ mod tests {
     #[test]
     fn repo_service_message_variants() {
         // Verify we can construct and match all message variants
+        let _cached: RepoServiceMessage = RepoServiceMessage::CachedDone(vec![]);
         let _refresh_ok: RepoServiceMessage = RepoServiceMessage::RefreshDone(Ok(vec![]));
         let _refresh_err: RepoServiceMessage =
             RepoServiceMessage::RefreshDone(Err(RepoError::Config("x".into())));
         let _clone: RepoServiceMessage = RepoServiceMessage::CloneDone { index: 0, result: Ok(()) };
         let _pull: RepoServiceMessage =
             RepoServiceMessage::PullDone { index: 1, result: Err(RepoError::Git("e".into())) };
+        let _progress: RepoServiceMessage = RepoServiceMessage::Progress { index: 2, pct: 50 };
+        let _create: RepoServiceMessage = RepoServiceMessage::CreateDone(Ok("me/app".into()));
+        let _activity: RepoServiceMessage = RepoServiceMessage::ActivityDone(Ok(vec![]));
+        let _preview: RepoServiceMessage = RepoServiceMessage::PreviewDone {
+            full_name: "me/app".into(),
+            result: Ok(RepoPreview::default()),
+        };
+        let _updated: RepoServiceMessage = RepoServiceMessage::RepoUpdated(LocalRepo {
+            path: PathBuf::from("/dev/app"),
+            name: "app".into(),
+            current_branch: Some("main".into()),
+            is_clean: true,
+            remote_url: None,
+            uncommitted_changes: 0,
+            last_commit: None,
+            last_commit_time: None,
+            github_id: None,
+        });
+        let _remote: RepoServiceMessage = RepoServiceMessage::RemoteChanged(vec!["me/app".into()]);
+    }
+
+    #[test]
+    fn scope_choices_group_teams_under_orgs() {
+        let org = |id: i64, login: &str| myme_services::GitHubOrg {
+            id,
+            login: login.to_string(),
+            description: None,
+        };
+        let team = |org_login: &str, slug: &str| myme_services::GitHubTeam {
+            id: 0,
+            slug: slug.to_string(),
+            name: slug.to_string(),
+            organization: org(0, org_login),
+        };
+        let choices = scope_choices(
+            &[org(1, "zeta"), org(2, "Acme")],
+            &[team("zeta", "web"), team("Acme", "infra"), team("Acme", "infra")],
+        );
+        let names: Vec<String> = choices.iter().map(ToString::to_string).collect();
+        assert_eq!(names, vec!["Acme", "Acme/infra", "zeta", "zeta/web"]);
+    }
+
+    #[test]
+    fn preview_cache_ttl() {
+        let now = Utc::now();
+        assert!(preview_is_fresh(now - Duration::minutes(5), now));
+        assert!(!preview_is_fresh(now - Duration::minutes(PREVIEW_CACHE_TTL_MINUTES), now));
+
+        let preview = RepoPreview { topics: vec!["rust".into()], ..Default::default() };
+        set_cached_preview("me/cached", &preview);
+        assert_eq!(cached_preview("me/cached").unwrap().topics, vec!["rust"]);
+        assert!(cached_preview("me/other").is_none());
+    }
+
+    #[test]
+    fn progress_reporter_dedupes_and_cancels() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let token = Arc::new(CancellationToken::new());
+        let mut toasts = ui_events::global().subscribe();
+        let mut report =
+            progress_reporter(tx, 3, "test:progress".into(), "Cloning".into(), Some(token.clone()));
+        let at = |received_objects| TransferProgress {
+            received_objects,
+            total_objects: 10,
+            ..Default::default()
+        };
+
+        assert!(report(at(5)));
+        assert!(report(at(5)));
+        assert!(report(at(10)));
+        token.cancel();
+        assert!(!report(at(10)));
+
+        let pcts: Vec<u8> = rx
+            .try_iter()
+            .map(|m| match m {
+                RepoServiceMessage::Progress { index: 3, pct } => pct,
+                other => panic!("unexpected message {:?}", other),
+            })
+            .collect();
+        assert_eq!(pcts, vec![50, 100]);
+
+        let toast_pcts: Vec<Option<u8>> = std::iter::from_fn(|| toasts.try_recv().ok())
+            .filter(|e| e.key == "test:progress")
+            .map(|e| e.percent)
+            .collect();
+        assert_eq!(toast_pcts, vec![Some(50), Some(100)]);
+    }
+
+    #[test]
+    fn activity_feed_merges_newest_first() {
+        let now = chrono::Utc::now();
+        let commit = |id: &str, hours: i64| CommitInfo {
+            id: id.into(),
+            author: "a".into(),
+            message: id.into(),
+            time: now - chrono::Duration::hours(hours),
+            files_changed: 1,
+            insertions: 1,
+            deletions: 0,
+        };
+        let repo = |name: &str, recent| RepoActivity {
+            name: name.into(),
+            path: PathBuf::from(name),
+            stats: CommitStats::default(),
+            recent,
+        };
+        let activity = vec![
+            repo("a", vec![commit("a1", 1), commit("a2", 30 * 24)]),
+            repo("b", vec![commit("b1", 2), commit("b2", 0)]),
+        ];
+
+        let feed = activity_feed(&activity, now - chrono::Duration::days(7), 10);
+        let ids: Vec<(&str, &str)> = feed.iter().map(|(n, c)| (*n, c.id.as_str())).collect();
+        assert_eq!(ids, vec![("b", "b2"), ("a", "a1"), ("b", "b1")]);
+        assert_eq!(activity_feed(&activity, now - chrono::Duration::days(7), 1).len(), 1);
     }
 }
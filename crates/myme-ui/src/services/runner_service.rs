@@ -0,0 +1,143 @@
+//! Local checks backend: run detected or configured commands in a local clone
+//! and record pass/fail history in the project store.
+//! Processes run off the UI thread; output lines and results sent via mpsc.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use myme_integrations::{RepoTask, TaskRunner};
+use myme_services::RepoTaskRun;
+use tokio_util::sync::CancellationToken;
+
+use crate::bridge;
+
+/// Error type for local check runs
+#[derive(Debug, Clone)]
+pub enum RunnerError {
+    /// The command couldn't be started (missing program, bad directory)
+    Spawn(String),
+    /// No local clone at the given path
+    NotLocal(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for RunnerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunnerError::Spawn(s) => write!(f, "Check error: {}", s),
+            RunnerError::NotLocal(s) => write!(f, "No local clone at {}", s),
+            RunnerError::NotInitialized => write!(f, "Runner service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for RunnerError {}
+
+/// Messages sent from a running check back to the UI thread
+#[derive(Debug)]
+pub enum RunnerServiceMessage {
+    /// One line of output, in the order it was read
+    Output { line: String, stderr: bool },
+    /// The check finished, timed out or was cancelled; already recorded
+    RunDone(Result<RepoTaskRun, RunnerError>),
+}
+
+/// Checks for a repo: detected from its manifests, then any configured under
+/// `repos.tasks` for its `owner/repo` or directory name. Duplicates dropped.
+pub fn tasks_for(
+    configured: &BTreeMap<String, Vec<String>>,
+    repo_id: &str,
+    path: &Path,
+) -> Vec<RepoTask> {
+    let mut tasks = TaskRunner::detect(path);
+    let dir_name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+    let extra = [repo_id, dir_name]
+        .into_iter()
+        .filter(|key| !key.is_empty())
+        .filter_map(|key| configured.get(key))
+        .flatten()
+        .filter_map(|command| RepoTask::parse(command));
+    for task in extra {
+        if !tasks.iter().any(|t| t.name == task.name) {
+            tasks.push(task);
+        }
+    }
+    tasks
+}
+
+/// Run `task` in the clone at `path`, streaming `Output` and ending with
+/// `RunDone`. Cancelling `cancel` stops the process; the run is still recorded.
+pub fn request_run(
+    tx: &std::sync::mpsc::Sender<RunnerServiceMessage>,
+    repo_id: String,
+    path: PathBuf,
+    task: RepoTask,
+    cancel: CancellationToken,
+) {
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        let _ = tx.send(RunnerServiceMessage::RunDone(Err(RunnerError::NotInitialized)));
+        return;
+    };
+    if !path.is_dir() {
+        let _ = tx.send(RunnerServiceMessage::RunDone(Err(RunnerError::NotLocal(
+            path.display().to_string(),
+        ))));
+        return;
+    }
+    let minutes = myme_core::Config::load_cached().repos.task_timeout_minutes.max(1);
+    let runner = TaskRunner::new(Duration::from_secs(u64::from(minutes) * 60));
+
+    runtime.spawn(async move {
+        let out = tx.clone();
+        let result = runner
+            .run(&repo_id, &path, &task, cancel, |line| {
+                let _ =
+                    out.send(RunnerServiceMessage::Output { line: line.text, stderr: line.stderr });
+            })
+            .await
+            .map_err(|e| RunnerError::Spawn(format!("{:#}", e)));
+        if let Ok(run) = &result {
+            match bridge::get_project_store_or_init() {
+                Some(store) => {
+                    if let Err(e) = store.record_repo_task_run(run) {
+                        tracing::warn!("Failed to record check run for {}: {}", repo_id, e);
+                    }
+                }
+                None => tracing::warn!("Project store unavailable; check run not recorded"),
+            }
+        }
+        let _ = tx.send(RunnerServiceMessage::RunDone(result));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn configured_tasks_follow_detected_ones() {
+        let dir = std::env::temp_dir().join(format!("myme-runner-{}", uuid::Uuid::new_v4()));
+        let repo = dir.join("app");
+        std::fs::create_dir_all(&repo).unwrap();
+        std::fs::write(repo.join("Cargo.toml"), "[package]\n").unwrap();
+
+        let mut configured = BTreeMap::new();
+        configured.insert("me/app".to_string(), vec!["make lint".to_string()]);
+        configured.insert("app".to_string(), vec!["cargo test".to_string(), " ".to_string()]);
+        configured.insert("other".to_string(), vec!["make all".to_string()]);
+
+        let names: Vec<String> =
+            tasks_for(&configured, "me/app", &repo).into_iter().map(|t| t.name).collect();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert_eq!(names, ["cargo test", "cargo clippy --all-targets", "make lint"]);
+    }
+
+    #[test]
+    fn runner_error_display() {
+        assert!(format!("{}", RunnerError::NotLocal("/x".into())).contains("/x"));
+        assert!(format!("{}", RunnerError::NotInitialized).contains("not initialized"));
+    }
+}
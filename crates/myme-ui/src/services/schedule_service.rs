@@ -0,0 +1,249 @@
+//! Time-blocking kanban tasks on the calendar.
+//! A scheduled task gets an event on the primary calendar; completing the task
+//! ticks the event off (a mark on its title) and ticking the event off
+//! completes the task. Network work runs on the shared runtime.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use myme_calendar::{CalendarCache, CalendarClient, Event};
+use myme_core::connectivity;
+use myme_services::{EntityRef, ProjectStore, ScheduleSync, Task, TaskSchedule, TaskStatus};
+
+use super::activity_service;
+use super::calendar_service::calendar_client;
+use super::google_common::get_google_cache_path;
+use super::kanban_service::{KanbanError, KanbanServiceMessage};
+use crate::bridge;
+
+/// Calendar that time blocks are created on
+const SCHEDULE_CALENDAR: &str = "primary";
+
+/// Request to block out `duration` from `start` for `task`: creates the event,
+/// records the schedule and links the event to the task.
+pub fn request_schedule_task(
+    tx: &std::sync::mpsc::Sender<KanbanServiceMessage>,
+    store: Arc<ProjectStore>,
+    access_token: String,
+    task: Task,
+    start: DateTime<Utc>,
+    duration: Duration,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(KanbanServiceMessage::TaskScheduled(Err(KanbanError::NotInitialized)));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(KanbanServiceMessage::TaskScheduled(Err(KanbanError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let issue_url = store
+            .list_links(&EntityRef::task(&task.id))
+            .unwrap_or_default()
+            .iter()
+            .find_map(EntityRef::issue_url);
+        let description = TaskSchedule::event_description(&task, issue_url.as_deref());
+        let summary = TaskSchedule::event_summary(&task.title, task.status == TaskStatus::Done);
+
+        let result = async {
+            let event = calendar_client(&access_token)
+                .create_event(
+                    SCHEDULE_CALENDAR,
+                    &summary,
+                    start,
+                    start + duration,
+                    Some(&description),
+                    None,
+                    &[],
+                )
+                .await
+                .map_err(|e| KanbanError::Network(e.to_string()))?;
+            if let Ok(cache) = CalendarCache::new(get_google_cache_path("calendar_cache.db")) {
+                let _ = cache.store_event(&event);
+            }
+
+            let now = Utc::now().to_rfc3339();
+            let schedule = TaskSchedule {
+                event_id: event.id,
+                calendar_id: SCHEDULE_CALENDAR.to_string(),
+                task_id: task.id.clone(),
+                start: start.to_rfc3339(),
+                end: (start + duration).to_rfc3339(),
+                created_at: now.clone(),
+                completed_at: (task.status == TaskStatus::Done).then_some(now),
+            };
+            store.add_task_schedule(&schedule).map_err(|e| KanbanError::Network(e.to_string()))?;
+            Ok(schedule)
+        }
+        .await;
+
+        if result.is_ok() {
+            activity_service::record(
+                "kanban",
+                "scheduled",
+                &task.id,
+                &format!(
+                    "Scheduled \"{}\" for {}",
+                    activity_service::short_title(&task.title),
+                    start.with_timezone(&chrono::Local).format("%a %b %-d %H:%M")
+                ),
+                None,
+            );
+        }
+        let _ = tx.send(KanbanServiceMessage::TaskScheduled(result));
+    });
+}
+
+/// Bring the events scheduled for `task` in line with its status after it
+/// changed on the board. Skipped offline; the next calendar fetch catches up.
+pub fn request_sync_task_schedules(store: Arc<ProjectStore>, access_token: String, task: Task) {
+    let schedules = match store.list_task_schedules(&task.id) {
+        Ok(schedules) if !schedules.is_empty() => schedules,
+        _ => return,
+    };
+    let Some(runtime) = bridge::get_runtime() else {
+        return;
+    };
+    if !connectivity::is_online() {
+        return;
+    }
+
+    runtime.spawn(async move {
+        let client = calendar_client(&access_token);
+        for schedule in schedules {
+            match client.get_event(&schedule.calendar_id, &schedule.event_id).await {
+                Ok(event) => {
+                    sync_schedule(&client, &store, &schedule, &event).await;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to read scheduled event {}: {}", schedule.event_id, e);
+                }
+            }
+        }
+    });
+}
+
+/// Reconcile fetched events that are time blocks for tasks: tick off events
+/// whose task is done and complete tasks whose event was ticked off. Returns
+/// the events with any title changes applied.
+pub async fn sync_scheduled_events(client: &CalendarClient, events: Vec<Event>) -> Vec<Event> {
+    let Some(store) = bridge::get_project_store_or_init() else {
+        return events;
+    };
+    let mut synced = Vec::with_capacity(events.len());
+    for event in events {
+        let schedule = match store.task_schedule_for_event(&event.id) {
+            Ok(Some(schedule)) => schedule,
+            _ => {
+                synced.push(event);
+                continue;
+            }
+        };
+        let updated = sync_schedule(client, &store, &schedule, &event).await;
+        synced.push(updated.unwrap_or(event));
+    }
+    synced
+}
+
+/// Apply [`TaskSchedule::sync_action`] for one event. Returns the event if its
+/// title was changed.
+async fn sync_schedule(
+    client: &CalendarClient,
+    store: &ProjectStore,
+    schedule: &TaskSchedule,
+    event: &Event,
+) -> Option<Event> {
+    let task = match store.get_task(&schedule.task_id) {
+        Ok(Some(task)) => task,
+        _ => return None,
+    };
+    let now = Utc::now().to_rfc3339();
+    let record = |done: bool| {
+        let completed_at = done.then_some(now.as_str());
+        if let Err(e) = store.set_task_schedule_completed(&schedule.event_id, completed_at) {
+            tracing::warn!("Failed to record schedule {}: {}", schedule.event_id, e);
+        }
+    };
+
+    match schedule.sync_action(task.status == TaskStatus::Done, &event.summary) {
+        ScheduleSync::InSync => None,
+        ScheduleSync::Record(done) => {
+            record(done);
+            None
+        }
+        ScheduleSync::MarkEvent | ScheduleSync::UnmarkEvent => {
+            let done = task.status == TaskStatus::Done;
+            let summary = TaskSchedule::event_summary(TaskSchedule::unmarked(&event.summary), done);
+            match client
+                .update_event(
+                    &schedule.calendar_id,
+                    &schedule.event_id,
+                    Some(&summary),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+            {
+                Ok(updated) => {
+                    record(done);
+                    if let Ok(cache) =
+                        CalendarCache::new(get_google_cache_path("calendar_cache.db"))
+                    {
+                        let _ = cache.store_event(&updated);
+                    }
+                    Some(updated)
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to update scheduled event {}: {}", schedule.event_id, e);
+                    None
+                }
+            }
+        }
+        action @ (ScheduleSync::CompleteTask | ScheduleSync::ReopenTask) => {
+            let done = action == ScheduleSync::CompleteTask;
+            set_task_done(store, task, done);
+            record(done);
+            None
+        }
+    }
+}
+
+/// Move a task into or out of Done from its calendar event, telling the board.
+pub fn set_task_done(store: &ProjectStore, mut task: Task, done: bool) {
+    task.status = if done { TaskStatus::Done } else { TaskStatus::Todo };
+    // Placed by status
+    task.column_id = None;
+    task.updated_at = Utc::now().to_rfc3339();
+    if let Err(e) = store.upsert_task(&task) {
+        tracing::warn!("Failed to update task {} from its event: {}", task.id, e);
+        return;
+    }
+    let (action, verb) = if done { ("completed", "Completed") } else { ("reopened", "Reopened") };
+    activity_service::record(
+        "kanban",
+        action,
+        &task.id,
+        &format!("{} \"{}\" from the calendar", verb, activity_service::short_title(&task.title)),
+        None,
+    );
+    if let Some(tx) = bridge::get_kanban_service_tx() {
+        let _ = tx.send(KanbanServiceMessage::TaskChanged(task));
+    }
+}
+
+/// Task scheduled by an event, if the event is a time block
+pub fn task_for_event(store: &ProjectStore, event_id: &str) -> Option<Task> {
+    let schedule = store.task_schedule_for_event(event_id).ok().flatten()?;
+    store.get_task(&schedule.task_id).ok().flatten()
+}
@@ -0,0 +1,444 @@
+//! Global search: fans a query out to notes, cached email, calendar events,
+//! repos and kanban tasks. Each source reports as soon as it finishes so the
+//! UI can show results incrementally; hits from all sources share one ranking.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use myme_calendar::CalendarCache;
+use myme_gmail::GmailCache;
+use myme_integrations::{match_repos, GitOperations};
+use myme_services::{NoteClient, ProjectStore};
+
+use super::calendar_service::display_zone;
+use super::google_common::get_google_cache_path;
+use super::repo_service;
+use crate::bridge;
+
+/// Most hits taken from each source.
+pub const RESULTS_PER_SOURCE: usize = 20;
+
+/// How far back and ahead calendar events are searched.
+const EVENT_WINDOW_DAYS: i64 = 365;
+
+/// Where a search hit came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchSource {
+    Note,
+    Email,
+    Event,
+    Repo,
+    Task,
+}
+
+impl SearchSource {
+    pub const ALL: [SearchSource; 5] = [
+        SearchSource::Note,
+        SearchSource::Email,
+        SearchSource::Event,
+        SearchSource::Repo,
+        SearchSource::Task,
+    ];
+
+    /// Source tag used by the UI to label and open a hit
+    pub fn key(self) -> &'static str {
+        match self {
+            SearchSource::Note => "note",
+            SearchSource::Email => "email",
+            SearchSource::Event => "event",
+            SearchSource::Repo => "repo",
+            SearchSource::Task => "task",
+        }
+    }
+}
+
+/// One search result.
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub source: SearchSource,
+    /// Id within the source: note id, message id, event id, repo full name, task id
+    pub id: String,
+    pub title: String,
+    pub snippet: String,
+    /// Where to open it: thread id, event link, repo path or URL, project id
+    pub context: String,
+    pub time: Option<DateTime<Utc>>,
+    pub score: f64,
+}
+
+/// Error type for search operations
+#[derive(Debug, Clone)]
+pub enum SearchError {
+    Source(String),
+    NotInitialized,
+}
+
+impl std::fmt::Display for SearchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchError::Source(s) => write!(f, "Search error: {}", s),
+            SearchError::NotInitialized => write!(f, "Search service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for SearchError {}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum SearchServiceMessage {
+    /// Hits from one source for search `generation`
+    Results { generation: u64, source: SearchSource, result: Result<Vec<SearchHit>, SearchError> },
+    /// Every source has reported for search `generation`
+    Done { generation: u64 },
+}
+
+/// Relevance of a hit: matches in the title count most (whole query, then
+/// prefix, then per word), body matches less, and recent items get a boost.
+pub fn score(
+    query: &str,
+    title: &str,
+    body: &str,
+    time: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> f64 {
+    let query = query.trim().to_lowercase();
+    let title = title.to_lowercase();
+    let body = body.to_lowercase();
+
+    let mut score = if title == query {
+        10.0
+    } else if title.starts_with(&query) {
+        6.0
+    } else if title.contains(&query) {
+        4.0
+    } else {
+        0.0
+    };
+    for word in query.split_whitespace() {
+        if title.contains(word) {
+            score += 2.0;
+            if title.split_whitespace().any(|t| t.starts_with(word)) {
+                score += 1.0;
+            }
+        } else if body.contains(word) {
+            score += 1.0;
+        }
+    }
+    if let Some(time) = time {
+        let days = (now - time).num_days().unsigned_abs() as f64;
+        score += 2.0 / (1.0 + days / 30.0);
+    }
+    score
+}
+
+/// Order hits best first (newest first on ties).
+fn rank(hits: &mut [SearchHit]) {
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| b.time.cmp(&a.time)));
+}
+
+/// Add `hits` to `results`, keeping them ranked.
+pub fn merge_hits(results: &mut Vec<SearchHit>, hits: Vec<SearchHit>) {
+    results.extend(hits);
+    rank(results);
+}
+
+/// Up to `max_chars` of `text` on one line, starting a little before the first
+/// query word so the match is visible.
+pub fn excerpt(text: &str, query: &str, max_chars: usize) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let lower = flat.to_lowercase();
+    let match_byte =
+        query.split_whitespace().filter_map(|w| lower.find(&w.to_lowercase())).min().unwrap_or(0);
+    // Lowercasing can change byte lengths; fall back to the start then
+    let match_char = flat.get(..match_byte).map(|s| s.chars().count()).unwrap_or(0);
+    let start = match_char.saturating_sub(max_chars / 4);
+
+    let mut out: String = flat.chars().skip(start).take(max_chars).collect();
+    if start > 0 {
+        out.insert_str(0, "...");
+    }
+    if flat.chars().count() > start + max_chars {
+        out.push_str("...");
+    }
+    out
+}
+
+/// First non-empty line of `text`.
+fn first_line(text: &str) -> &str {
+    text.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("")
+}
+
+fn source_error(e: impl std::fmt::Display) -> SearchError {
+    SearchError::Source(e.to_string())
+}
+
+/// Handles the sources need, gathered on the UI thread before fanning out.
+#[derive(Clone)]
+struct SearchContext {
+    notes: Option<Arc<NoteClient>>,
+    projects: Option<Arc<ProjectStore>>,
+    repos_path: Option<PathBuf>,
+}
+
+/// Request a search across every source. Sends one `Results` per source as it
+/// finishes, then `Done`; `generation` lets the caller drop stale replies.
+pub fn request_search(
+    tx: &std::sync::mpsc::Sender<SearchServiceMessage>,
+    query: String,
+    generation: u64,
+) {
+    let tx = tx.clone();
+    let Some(runtime) = bridge::get_runtime() else {
+        for source in SearchSource::ALL {
+            let _ = tx.send(SearchServiceMessage::Results {
+                generation,
+                source,
+                result: Err(SearchError::NotInitialized),
+            });
+        }
+        let _ = tx.send(SearchServiceMessage::Done { generation });
+        return;
+    };
+
+    let ctx = SearchContext {
+        notes: bridge::get_note_client_or_init(),
+        projects: bridge::get_project_store_or_init(),
+        repos_path: bridge::get_repos_local_search_path()
+            .and_then(|(path, invalid)| (!invalid).then_some(path)),
+    };
+    let query = Arc::new(query);
+
+    runtime.spawn(async move {
+        let now = Utc::now();
+        let tasks: Vec<_> = SearchSource::ALL
+            .into_iter()
+            .map(|source| {
+                let (tx, ctx, query) = (tx.clone(), ctx.clone(), query.clone());
+                tokio::spawn(async move {
+                    let result = search_source(source, &ctx, &query, now).await;
+                    if let Err(e) = &result {
+                        tracing::debug!("Search of {} failed: {}", source.key(), e);
+                    }
+                    let _ = tx.send(SearchServiceMessage::Results { generation, source, result });
+                })
+            })
+            .collect();
+        for task in tasks {
+            let _ = task.await;
+        }
+        let _ = tx.send(SearchServiceMessage::Done { generation });
+    });
+}
+
+async fn search_source(
+    source: SearchSource,
+    ctx: &SearchContext,
+    query: &str,
+    now: DateTime<Utc>,
+) -> Result<Vec<SearchHit>, SearchError> {
+    if source == SearchSource::Note {
+        let client = ctx.notes.clone().ok_or(SearchError::NotInitialized)?;
+        let notes = client.search(query, RESULTS_PER_SOURCE).await.map_err(source_error)?;
+        return Ok(notes
+            .into_iter()
+            .map(|n| {
+                let title = first_line(&n.content).to_string();
+                SearchHit {
+                    source,
+                    id: n.id.to_string(),
+                    score: score(query, &title, &n.content, Some(n.updated_at), now),
+                    snippet: excerpt(&n.content, query, 120),
+                    title,
+                    context: String::new(),
+                    time: Some(n.updated_at),
+                }
+            })
+            .collect());
+    }
+
+    let ctx = ctx.clone();
+    let query = query.to_string();
+    tokio::task::spawn_blocking(move || search_blocking(source, &ctx, &query, now))
+        .await
+        .map_err(source_error)?
+}
+
+/// Sources backed by local SQLite caches or the filesystem.
+fn search_blocking(
+    source: SearchSource,
+    ctx: &SearchContext,
+    query: &str,
+    now: DateTime<Utc>,
+) -> Result<Vec<SearchHit>, SearchError> {
+    match source {
+        SearchSource::Email => {
+            let cache =
+                GmailCache::new(get_google_cache_path("gmail_cache.db")).map_err(source_error)?;
+            let messages =
+                cache.search_messages(query, RESULTS_PER_SOURCE as u32).map_err(source_error)?;
+            Ok(messages
+                .into_iter()
+                .map(|m| SearchHit {
+                    source,
+                    score: score(query, &m.subject, &m.snippet, Some(m.date), now),
+                    snippet: format!("{} - {}", m.from, excerpt(&m.snippet, query, 100)),
+                    id: m.id,
+                    title: m.subject,
+                    context: m.thread_id,
+                    time: Some(m.date),
+                })
+                .collect())
+        }
+        SearchSource::Event => {
+            let cache = CalendarCache::new(get_google_cache_path("calendar_cache.db"))
+                .map_err(source_error)?;
+            let window = Duration::days(EVENT_WINDOW_DAYS);
+            let mut events =
+                cache.search_events(query, now - window, now + window).map_err(source_error)?;
+            let zone = display_zone();
+            // Nearest first, so the cap keeps the events most likely wanted
+            events.sort_by_key(|e| (e.start.instant_in(&zone) - now).num_seconds().unsigned_abs());
+            events.truncate(RESULTS_PER_SOURCE);
+            Ok(events
+                .into_iter()
+                .map(|e| {
+                    let start = e.start.instant_in(&zone);
+                    let body = [e.location.as_deref(), e.description.as_deref()]
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>()
+                        .join(" - ");
+                    SearchHit {
+                        source,
+                        score: score(query, &e.summary, &body, Some(start), now),
+                        snippet: excerpt(&body, query, 100),
+                        id: e.id,
+                        title: e.summary,
+                        context: e.html_link.unwrap_or_default(),
+                        time: Some(start),
+                    }
+                })
+                .collect())
+        }
+        SearchSource::Repo => {
+            let local = match &ctx.repos_path {
+                Some(path) => {
+                    GitOperations::discover_repositories(path, Some(5)).map_err(source_error)?
+                }
+                None => Vec::new(),
+            };
+            let remote = repo_service::last_github_repos().unwrap_or_default();
+            let words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+            let mut hits: Vec<SearchHit> = match_repos(&local, &remote)
+                .into_iter()
+                .filter_map(|entry| {
+                    let description = entry
+                        .github
+                        .as_ref()
+                        .and_then(|g| g.description.clone())
+                        .unwrap_or_default();
+                    let haystack = format!("{} {}", entry.full_name, description).to_lowercase();
+                    if !words.iter().all(|w| haystack.contains(w.as_str())) {
+                        return None;
+                    }
+                    let context = match (&entry.local, &entry.github) {
+                        (Some(l), _) => l.path.display().to_string(),
+                        (None, Some(g)) => g.html_url.clone(),
+                        (None, None) => String::new(),
+                    };
+                    Some(SearchHit {
+                        source,
+                        score: score(query, &entry.full_name, &description, None, now),
+                        snippet: excerpt(&description, query, 100),
+                        id: entry.full_name.clone(),
+                        title: entry.full_name,
+                        context,
+                        time: None,
+                    })
+                })
+                .collect();
+            rank(&mut hits);
+            hits.truncate(RESULTS_PER_SOURCE);
+            Ok(hits)
+        }
+        SearchSource::Task => {
+            let store = ctx.projects.clone().ok_or(SearchError::NotInitialized)?;
+            let tasks = store.search_tasks(query, RESULTS_PER_SOURCE).map_err(source_error)?;
+            Ok(tasks
+                .into_iter()
+                .map(|t| {
+                    let body = t.body.unwrap_or_default();
+                    let time = DateTime::parse_from_rfc3339(&t.updated_at)
+                        .ok()
+                        .map(|dt| dt.with_timezone(&Utc));
+                    SearchHit {
+                        source,
+                        score: score(query, &t.title, &body, time, now),
+                        snippet: excerpt(&body, query, 100),
+                        id: t.id,
+                        title: t.title,
+                        context: t.project_id,
+                        time,
+                    }
+                })
+                .collect())
+        }
+        // Searched through the async note client in `search_source`
+        SearchSource::Note => Ok(Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn hit(title: &str, score: f64) -> SearchHit {
+        SearchHit {
+            source: SearchSource::Note,
+            id: title.to_string(),
+            title: title.to_string(),
+            snippet: String::new(),
+            context: String::new(),
+            time: None,
+            score,
+        }
+    }
+
+    #[test]
+    fn score_prefers_title_matches_and_recency() {
+        let now = Utc::now();
+        let exact = score("release notes", "Release notes", "", None, now);
+        let prefix = score("release", "Release checklist", "", None, now);
+        let body = score("release", "Weekly sync", "talk about the release", None, now);
+        assert!(exact > prefix && prefix > body && body > 0.0);
+
+        let recent = score("sync", "Weekly sync", "", Some(now - Duration::days(1)), now);
+        let old = score("sync", "Weekly sync", "", Some(now - Duration::days(300)), now);
+        assert!(recent > old);
+    }
+
+    #[test]
+    fn merge_keeps_best_first() {
+        let mut results = vec![hit("b", 3.0)];
+        merge_hits(&mut results, vec![hit("a", 5.0), hit("c", 1.0)]);
+        let titles: Vec<&str> = results.iter().map(|h| h.title.as_str()).collect();
+        assert_eq!(titles, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn excerpt_starts_near_match() {
+        let text = format!("{} needle here", "word ".repeat(50));
+        let ex = excerpt(&text, "NEEDLE", 40);
+        assert!(ex.starts_with("...") && ex.contains("needle"));
+        assert_eq!(excerpt("short\n text", "text", 40), "short text");
+    }
+
+    #[test]
+    fn search_error_display() {
+        assert!(format!("{}", SearchError::Source("locked".into())).contains("locked"));
+        assert!(format!("{}", SearchError::NotInitialized).contains("not initialized"));
+    }
+}
@@ -0,0 +1,97 @@
+//! Settings backend: health checks for connected accounts.
+//! Provider probes run off the UI thread; results sent via mpsc.
+
+use myme_auth::{
+    scopes_for, GoogleFeature, ScopeRequirement, TokenInspector, TokenReport, GITHUB_REQUIREMENTS,
+    GOOGLE_REQUIREMENTS,
+};
+
+use crate::bridge;
+
+/// Error type for settings operations
+#[derive(Debug, Clone)]
+pub enum SettingsError {
+    NotInitialized,
+}
+
+impl std::fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsError::NotInitialized => write!(f, "Settings service not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum SettingsServiceMessage {
+    /// One report per account (GitHub, then Google when configured)
+    TokenHealthDone(Result<Vec<TokenReport>, SettingsError>),
+}
+
+/// Google scope requirements for the features the user switched on.
+fn google_requirements(gmail: bool, calendar: bool) -> Vec<ScopeRequirement> {
+    let enabled: Vec<GoogleFeature> = GoogleFeature::ALL
+        .into_iter()
+        .filter(|f| match f {
+            GoogleFeature::Gmail => gmail,
+            GoogleFeature::Calendar => calendar,
+        })
+        .collect();
+    let scopes = scopes_for(&enabled);
+    GOOGLE_REQUIREMENTS.iter().filter(|r| scopes.contains(&r.scope)).copied().collect()
+}
+
+/// Request a health check of the stored GitHub and Google tokens.
+/// Sends `TokenHealthDone` on the channel when complete.
+pub fn request_token_health(tx: &std::sync::mpsc::Sender<SettingsServiceMessage>) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx
+                .send(SettingsServiceMessage::TokenHealthDone(Err(SettingsError::NotInitialized)));
+            return;
+        }
+    };
+
+    let config = myme_core::Config::load_cached();
+    let github = TokenInspector::new().with_proxies(config.proxy.proxies_for("github"));
+    // Google features are only on once OAuth credentials are configured; read
+    // fresh config since the feature switches change at runtime
+    let google =
+        myme_core::Config::load().ok().and_then(|c| c.google).filter(|g| g.client_id.is_some());
+    let google = google.map(|g| {
+        let inspector = TokenInspector::new().with_proxies(config.proxy.proxies_for("google_auth"));
+        (inspector, google_requirements(g.gmail, g.calendar))
+    });
+
+    runtime.spawn(async move {
+        let mut reports = vec![github.inspect_stored("github", GITHUB_REQUIREMENTS).await];
+        if let Some((google, required)) = google {
+            reports.push(google.inspect_stored("google", &required).await);
+        }
+        let _ = tx.send(SettingsServiceMessage::TokenHealthDone(Ok(reports)));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn settings_error_display() {
+        assert!(format!("{}", SettingsError::NotInitialized).contains("not initialized"));
+    }
+
+    #[test]
+    fn google_requirements_follow_enabled_features() {
+        let features: Vec<&str> =
+            google_requirements(false, true).iter().map(|r| r.feature).collect();
+        assert_eq!(features, ["Calendar", "Account email"]);
+        assert_eq!(google_requirements(true, true).len(), GOOGLE_REQUIREMENTS.len());
+    }
+}
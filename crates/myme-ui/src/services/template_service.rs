@@ -0,0 +1,192 @@
+//! Note and issue templates: saving, rendering and the schedule that creates
+//! recurring notes and issues (e.g. a weekly review note every Monday).
+//! Templates live in ProjectStore; NoteModel and KanbanModel instantiate them
+//! on demand, `AppServices::init_template_scheduler` on their schedule.
+
+use std::sync::Arc;
+
+use chrono::{Local, NaiveDate};
+use myme_core::connectivity;
+use myme_services::{
+    CreateIssueRequest, GitHubClient, NoteClient, ProjectStore, Template, TemplateContext,
+    TemplateKind, TemplateSchedule, TodoCreateRequest,
+};
+
+/// Shown when a schedule string cannot be parsed
+pub const SCHEDULE_HINT: &str = "Unknown schedule; use daily, weekly:mon or monthly:1";
+
+/// Placeholder values for `template` on `date`, with the project name looked up.
+pub fn context_for(store: &ProjectStore, template: &Template, date: NaiveDate) -> TemplateContext {
+    let project = template
+        .project_id
+        .as_deref()
+        .and_then(|id| store.get_project(id).ok().flatten())
+        .map(|p| p.name);
+    TemplateContext::new(date).with_project(project)
+}
+
+/// Today's placeholder values, in the user's time zone.
+pub fn context_today(store: &ProjectStore, template: &Template) -> TemplateContext {
+    context_for(store, template, Local::now().date_naive())
+}
+
+/// Parse a schedule from the UI; empty means "only on demand".
+pub fn parse_schedule(schedule: &str) -> Result<Option<TemplateSchedule>, &'static str> {
+    if schedule.trim().is_empty() {
+        return Ok(None);
+    }
+    TemplateSchedule::parse(schedule).map(Some).ok_or(SCHEDULE_HINT)
+}
+
+/// New template with a fresh id, or `existing` with the given fields replaced.
+#[allow(clippy::too_many_arguments)]
+pub fn build_template(
+    existing: Option<Template>,
+    kind: TemplateKind,
+    name: &str,
+    title: &str,
+    body: &str,
+    project_id: Option<String>,
+    repo_id: Option<String>,
+    schedule: Option<TemplateSchedule>,
+) -> Template {
+    let base = existing.unwrap_or_else(|| Template {
+        id: uuid::Uuid::new_v4().to_string(),
+        name: String::new(),
+        kind,
+        title: String::new(),
+        body: String::new(),
+        project_id: None,
+        repo_id: None,
+        schedule: None,
+        last_run: None,
+        created_at: chrono::Utc::now().to_rfc3339(),
+    });
+    Template {
+        name: name.trim().to_string(),
+        kind,
+        title: title.to_string(),
+        body: body.to_string(),
+        project_id,
+        repo_id: repo_id.filter(|r| !r.trim().is_empty()),
+        schedule,
+        ..base
+    }
+}
+
+/// Templates as a JSON array for QML
+pub fn templates_json(templates: &[Template]) -> String {
+    serde_json::to_string(templates).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Create the notes and issues whose schedule is due on `today`. A template
+/// whose instance could not be created (offline, not signed in) is tried
+/// again on the next check. Returns how many were created.
+pub async fn instantiate_due(
+    store: Arc<ProjectStore>,
+    notes: Option<Arc<NoteClient>>,
+    github: Option<Arc<GitHubClient>>,
+    today: NaiveDate,
+) -> usize {
+    let due = match store.due_templates(today) {
+        Ok(due) => due,
+        Err(e) => {
+            tracing::warn!("Failed to read scheduled templates: {:#}", e);
+            return 0;
+        }
+    };
+
+    let mut created = 0;
+    for template in due {
+        let context = context_for(&store, &template, today);
+        let done = match template.kind {
+            TemplateKind::Note => {
+                let Some(client) = &notes else {
+                    continue;
+                };
+                let request = TodoCreateRequest {
+                    content: template.render_note(&context),
+                    is_checklist: false,
+                };
+                client
+                    .create_todo(request)
+                    .await
+                    .map_err(|e| tracing::warn!("Template {}: {:#}", template.name, e))
+                    .is_ok()
+            }
+            TemplateKind::Issue => {
+                let target = template.repo_id.as_deref().and_then(|r| r.split_once('/'));
+                let (Some(client), Some((owner, repo))) = (&github, target) else {
+                    tracing::debug!("Template {}: no GitHub client or repo", template.name);
+                    continue;
+                };
+                if !connectivity::is_online() {
+                    continue;
+                }
+                let (title, body) = template.render(&context);
+                let request = CreateIssueRequest {
+                    title,
+                    body: Some(body).filter(|b| !b.trim().is_empty()),
+                    labels: None,
+                };
+                client
+                    .create_issue(owner, repo, request)
+                    .await
+                    .map_err(|e| tracing::warn!("Template {}: {:#}", template.name, e))
+                    .is_ok()
+            }
+        };
+        if !done {
+            continue;
+        }
+        created += 1;
+        tracing::info!(
+            "Created scheduled {} from template {}",
+            template.kind.as_str(),
+            template.name
+        );
+        if let Err(e) = store.mark_template_run(&template.id, today) {
+            tracing::warn!("Failed to record template run: {:#}", e);
+        }
+    }
+    created
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn build_template_keeps_identity_on_edit() {
+        let created = build_template(
+            None,
+            TemplateKind::Note,
+            " Weekly review ",
+            "Review {{date}}",
+            "",
+            None,
+            Some(" ".to_string()),
+            parse_schedule("weekly:mon").unwrap(),
+        );
+        assert_eq!(created.name, "Weekly review");
+        assert_eq!(created.repo_id, None);
+
+        let edited = build_template(
+            Some(created.clone()),
+            TemplateKind::Note,
+            "Review",
+            "Review {{date}}",
+            "- Wins",
+            None,
+            None,
+            None,
+        );
+        assert_eq!(edited.id, created.id);
+        assert_eq!(edited.created_at, created.created_at);
+        assert_eq!(edited.schedule, None);
+
+        assert_eq!(parse_schedule(""), Ok(None));
+        assert_eq!(parse_schedule("fortnightly"), Err(SCHEDULE_HINT));
+    }
+}
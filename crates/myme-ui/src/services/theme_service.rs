@@ -0,0 +1,94 @@
+//! Theme backend: read the desktop's light/dark preference from the XDG
+//! settings portal (`org.freedesktop.appearance color-scheme`).
+//! D-Bus calls run off the UI thread; the result is sent via mpsc.
+
+/// Messages sent from async operations back to the UI thread
+#[derive(Debug)]
+pub enum ThemeServiceMessage {
+    /// Desktop prefers dark (Some(true)) or light (Some(false)); None when there
+    /// is no portal or no preference
+    PortalScheme(Option<bool>),
+}
+
+/// Map the portal's color-scheme value: 1 prefers dark, 2 prefers light,
+/// 0 has no preference
+pub fn scheme_preference(value: u32) -> Option<bool> {
+    match value {
+        1 => Some(true),
+        2 => Some(false),
+        _ => None,
+    }
+}
+
+/// Ask the settings portal for the desktop color scheme. Sends `PortalScheme`.
+pub fn request_portal_scheme(tx: &std::sync::mpsc::Sender<ThemeServiceMessage>) {
+    let tx = tx.clone();
+    let Some(runtime) = crate::bridge::get_runtime() else {
+        let _ = tx.send(ThemeServiceMessage::PortalScheme(None));
+        return;
+    };
+    runtime.spawn(async move {
+        let scheme = portal::color_scheme().await;
+        let _ = tx.send(ThemeServiceMessage::PortalScheme(scheme.and_then(scheme_preference)));
+    });
+}
+
+#[cfg(target_os = "linux")]
+mod portal {
+    use zbus::zvariant::{OwnedValue, Value};
+    use zbus::Connection;
+
+    const PORTAL_BUS: &str = "org.freedesktop.portal.Desktop";
+    const PORTAL_PATH: &str = "/org/freedesktop/portal/desktop";
+    const SETTINGS_IFACE: &str = "org.freedesktop.portal.Settings";
+    const KEY: (&str, &str) = ("org.freedesktop.appearance", "color-scheme");
+
+    /// Raw color-scheme value, or None without a session bus or portal
+    pub async fn color_scheme() -> Option<u32> {
+        let conn = Connection::session().await.ok()?;
+        // ReadOne (portal v2) returns the value; older portals only have Read,
+        // which wraps it in a second variant
+        for method in ["ReadOne", "Read"] {
+            let reply =
+                conn.call_method(Some(PORTAL_BUS), PORTAL_PATH, Some(SETTINGS_IFACE), method, &KEY);
+            match reply.await {
+                Ok(msg) => {
+                    let value: OwnedValue = msg.body().deserialize().ok()?;
+                    return unwrap_u32(&value);
+                }
+                Err(e) => tracing::debug!("Portal {} failed: {}", method, e),
+            }
+        }
+        None
+    }
+
+    fn unwrap_u32(value: &Value<'_>) -> Option<u32> {
+        match value {
+            Value::U32(v) => Some(*v),
+            Value::Value(inner) => unwrap_u32(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod portal {
+    /// No XDG portal; the platform style hints are used instead
+    pub async fn color_scheme() -> Option<u32> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn maps_portal_scheme() {
+        assert_eq!(scheme_preference(1), Some(true));
+        assert_eq!(scheme_preference(2), Some(false));
+        assert_eq!(scheme_preference(0), None);
+        assert_eq!(scheme_preference(7), None);
+    }
+}
@@ -0,0 +1,244 @@
+//! Leave-by warnings: for upcoming events with a physical location, estimate
+//! the trip from the current location and raise a desktop notification when
+//! it's time to go. Event locations are geocoded once and cached.
+
+use chrono::{DateTime, Duration, Utc};
+use myme_calendar::{CalendarCache, DisplayZone, Event, EventStatus, Geocode};
+use myme_core::connectivity;
+use myme_weather::travel::{distance_km, estimate_from_distance};
+use myme_weather::{Location, TravelSource};
+
+use super::calendar_service::display_zone;
+use super::focus_service::notify_desktop;
+use super::google_common::get_google_cache_path;
+
+/// Events starting within this many hours are considered
+const LOOKAHEAD_HOURS: i64 = 4;
+
+/// Warn this many minutes before the leave-by time
+const WARNING_LEAD_MINUTES: i64 = 5;
+
+/// Ask for a route only once the rough distance estimate puts departure
+/// within this many minutes, to keep routing requests few
+const ROUTE_WINDOW_MINUTES: i64 = 60;
+
+/// Closer than this (km) counts as already there
+const ALREADY_THERE_KM: f64 = 0.5;
+
+/// Nominatim allows one request per second
+const GEOCODE_SPACING: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Locations that are links or online meeting placeholders, not places.
+fn is_physical_location(location: &str) -> bool {
+    let lower = location.trim().to_lowercase();
+    if lower.is_empty() || lower.contains("://") || lower.starts_with("www.") {
+        return false;
+    }
+    const VIRTUAL: &[&str] = &[
+        "zoom",
+        "google meet",
+        "meet.google",
+        "microsoft teams",
+        "teams meeting",
+        "webex",
+        "jitsi",
+        "online",
+        "virtual",
+        "phone call",
+        "conference call",
+    ];
+    !VIRTUAL.iter().any(|word| lower.contains(word))
+}
+
+/// When to leave to arrive on time: the start, less travel and slack.
+fn leave_by(start: DateTime<Utc>, travel: Duration, buffer_minutes: u32) -> DateTime<Utc> {
+    start - travel - Duration::minutes(i64::from(buffer_minutes))
+}
+
+/// Whether a warning for `leave_by` is due at `now`, for an event at `start`.
+fn warning_due(now: DateTime<Utc>, leave_by: DateTime<Utc>, start: DateTime<Utc>) -> bool {
+    now >= leave_by - Duration::minutes(WARNING_LEAD_MINUTES) && now < start
+}
+
+/// The calendar cache. Opened per use: the connection can't be held across
+/// awaits on the shared runtime.
+fn open_cache() -> Option<CalendarCache> {
+    match CalendarCache::new(get_google_cache_path("calendar_cache.db")) {
+        Ok(cache) => Some(cache),
+        Err(e) => {
+            tracing::warn!("Failed to open calendar cache for travel warnings: {}", e);
+            None
+        }
+    }
+}
+
+/// Coordinates of an event location: `cached` if it was looked up before,
+/// else looked up now and cached.
+async fn locate(location: &str, cached: Option<Geocode>) -> Option<Location> {
+    let geocode = match cached {
+        Some(geocode) => geocode,
+        None => {
+            let proxies = myme_core::Config::load_cached().proxy.proxies_for("weather");
+            let looked_up = myme_weather::geocode_address(location, proxies).await;
+            tokio::time::sleep(GEOCODE_SPACING).await;
+            let geocode = match looked_up {
+                Ok(Some(place)) => {
+                    Geocode::Found { latitude: place.latitude, longitude: place.longitude }
+                }
+                Ok(None) => Geocode::NotFound,
+                Err(e) => {
+                    // Not cached: try again next time
+                    tracing::debug!("Failed to geocode event location: {}", e);
+                    return None;
+                }
+            };
+            if let Some(Err(e)) = open_cache().map(|c| c.store_geocoded(location, geocode)) {
+                tracing::warn!("Failed to cache geocoded location: {}", e);
+            }
+            geocode
+        }
+    };
+    match geocode {
+        Geocode::Found { latitude, longitude } => {
+            Some(Location { latitude, longitude, accuracy_meters: None, city_name: None })
+        }
+        Geocode::NotFound => None,
+    }
+}
+
+/// Upcoming events that may need a leave-by warning: timed, at a physical
+/// place, not cancelled, declined or opted out, and not yet warned about.
+/// Each comes with its location's cached geocode, if any.
+fn candidates(
+    cache: &CalendarCache,
+    now: DateTime<Utc>,
+    zone: &DisplayZone,
+) -> anyhow::Result<Vec<(Event, Option<Geocode>)>> {
+    let opt_outs = cache.travel_opt_outs()?;
+    let events = cache.list_visible_events(now, now + Duration::hours(LOOKAHEAD_HOURS), zone)?;
+    Ok(events
+        .into_iter()
+        .filter(|e| !e.all_day && e.status != EventStatus::Cancelled && !e.declined_by_self())
+        .filter(|e| e.location.as_deref().is_some_and(is_physical_location))
+        .filter(|e| !opt_outs.contains(&(e.calendar_id.clone(), e.id.clone())))
+        .filter(|e| !cache.travel_alert_sent(e).unwrap_or(true))
+        .map(|e| {
+            let cached = e.location.as_deref().and_then(|l| cache.geocoded(l).ok().flatten());
+            (e, cached)
+        })
+        .collect())
+}
+
+/// Raise leave-by warnings that are due (`[calendar] travel_alerts`).
+/// Returns how many were shown.
+pub async fn notify_travel_warnings() -> usize {
+    let config = myme_core::Config::load_cached();
+    if !config.calendar.travel_alerts || !connectivity::is_online() {
+        return 0;
+    }
+    let buffer_minutes = config.calendar.travel_buffer_minutes;
+    let zone = display_zone();
+    let now = Utc::now();
+    let Some(found) = open_cache().map(|cache| candidates(&cache, now, &zone)) else {
+        return 0;
+    };
+    let events = match found {
+        Ok(events) if !events.is_empty() => events,
+        Ok(_) => return 0,
+        Err(e) => {
+            tracing::warn!("Failed to read events for travel warnings: {}", e);
+            return 0;
+        }
+    };
+    let origin = match myme_weather::location::get_current_location().await {
+        Ok(origin) => origin,
+        Err(e) => {
+            tracing::debug!("No current location for travel warnings: {}", e);
+            return 0;
+        }
+    };
+
+    let mut shown = 0;
+    for (event, cached) in events {
+        let Some(location) = event.location.as_deref() else {
+            continue;
+        };
+        let Some(destination) = locate(location, cached).await else {
+            continue;
+        };
+        if distance_km(&origin, &destination) < ALREADY_THERE_KM {
+            continue;
+        }
+        let start = event.start.instant_in(&zone);
+        let rough = estimate_from_distance(&origin, &destination);
+        if now
+            < leave_by(start, rough.duration, buffer_minutes)
+                - Duration::minutes(ROUTE_WINDOW_MINUTES)
+        {
+            continue;
+        }
+
+        let proxies = config.proxy.proxies_for("weather");
+        let estimate = myme_weather::estimate_travel(&origin, &destination, proxies).await;
+        let leave_at = leave_by(start, estimate.duration, buffer_minutes);
+        if !warning_due(now, leave_at, start) {
+            continue;
+        }
+
+        let how = match estimate.source {
+            TravelSource::Route => "drive",
+            TravelSource::Distance => "trip",
+        };
+        notify_desktop(
+            &format!(
+                "Leave by {} for {}",
+                zone.local_datetime(leave_at).format("%H:%M"),
+                event.summary
+            ),
+            &format!(
+                "About {} min {} to {}",
+                estimate.duration.num_minutes().max(1),
+                how,
+                location
+            ),
+        );
+        if let Some(Err(e)) = open_cache().map(|c| c.record_travel_alert(&event)) {
+            tracing::warn!("Failed to record travel warning: {}", e);
+        }
+        shown += 1;
+    }
+    if shown > 0 {
+        tracing::info!("Showed {} leave-by warning(s)", shown);
+    }
+    shown
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn physical_locations_skip_links_and_online_meetings() {
+        assert!(is_physical_location("100 Queen St W, Toronto"));
+        assert!(is_physical_location("Conference Room B"));
+        assert!(!is_physical_location("https://zoom.us/j/123"));
+        assert!(!is_physical_location("Zoom"));
+        assert!(!is_physical_location("Microsoft Teams Meeting"));
+        assert!(!is_physical_location("Online"));
+        assert!(!is_physical_location("  "));
+    }
+
+    #[test]
+    fn warning_is_due_shortly_before_leave_by_until_the_start() {
+        let start =
+            DateTime::parse_from_rfc3339("2024-02-01T14:00:00Z").unwrap().with_timezone(&Utc);
+        let leave_at = leave_by(start, Duration::minutes(25), 5);
+        assert_eq!(leave_at, start - Duration::minutes(30));
+
+        assert!(!warning_due(leave_at - Duration::minutes(6), leave_at, start));
+        assert!(warning_due(leave_at - Duration::minutes(5), leave_at, start));
+        assert!(warning_due(leave_at + Duration::minutes(10), leave_at, start));
+        assert!(!warning_due(start, leave_at, start));
+    }
+}
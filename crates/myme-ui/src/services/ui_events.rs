@@ -0,0 +1,363 @@
+//! Process-wide bus for user-facing progress, success and error notices.
+//!
+//! Services publish a [`UiEvent`] as background work advances or finishes, and
+//! the `ToastModel` shows it whichever page is open, so a clone finishing or a
+//! dispatch failing is seen from anywhere. The bus is a tokio broadcast channel:
+//! publishing never blocks a service, and a subscriber that falls behind skips
+//! ahead rather than holding events back.
+//!
+//! Events sharing a `key` describe one operation: a progress toast is updated in
+//! place and then replaced by the success or error that ends it.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use myme_core::{AppError, AuthError, ConfigError, GitHubError};
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+/// Events buffered per subscriber before the oldest are skipped.
+const BUS_CAPACITY: usize = 256;
+
+/// Toasts shown at once; the oldest finished one makes room for a new one.
+pub const MAX_TOASTS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    /// An operation still running; stays up until its key is reused
+    Progress,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// How long a toast stays up, or `None` until replaced or dismissed.
+    pub fn lifetime(self) -> Option<Duration> {
+        match self {
+            Severity::Progress => None,
+            Severity::Info | Severity::Success => Some(Duration::from_secs(5)),
+            Severity::Warning => Some(Duration::from_secs(8)),
+            Severity::Error => Some(Duration::from_secs(12)),
+        }
+    }
+}
+
+/// Something the user can do about an event. `action` is routed by QML:
+/// `navigate:<Page>` opens a page and `open:<url>` opens a link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ActionHint {
+    pub label: String,
+    pub action: String,
+}
+
+impl ActionHint {
+    pub fn new(label: impl Into<String>, action: impl Into<String>) -> Self {
+        Self { label: label.into(), action: action.into() }
+    }
+
+    pub fn navigate(label: impl Into<String>, page: &str) -> Self {
+        Self::new(label, format!("navigate:{}", page))
+    }
+
+    pub fn open_url(label: impl Into<String>, url: &str) -> Self {
+        Self::new(label, format!("open:{}", url))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UiEvent {
+    /// Events with the same key replace each other's toast
+    pub key: String,
+    /// Publishing service, e.g. "repos", "workflows"
+    pub source: String,
+    pub severity: Severity,
+    pub title: String,
+    /// Longer explanation, or empty
+    pub detail: String,
+    /// 0-100 for progress events, when known
+    pub percent: Option<u8>,
+    pub action: Option<ActionHint>,
+}
+
+impl UiEvent {
+    /// A one-off event with a key of its own.
+    pub fn new(source: &str, severity: Severity, title: impl Into<String>) -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(1);
+        let n = NEXT.fetch_add(1, Ordering::Relaxed);
+        Self {
+            key: format!("{}#{}", source, n),
+            source: source.to_string(),
+            severity,
+            title: title.into(),
+            detail: String::new(),
+            percent: None,
+            action: None,
+        }
+    }
+
+    pub fn progress(source: &str, title: impl Into<String>, percent: Option<u8>) -> Self {
+        Self {
+            percent: percent.map(|p| p.min(100)),
+            ..Self::new(source, Severity::Progress, title)
+        }
+    }
+
+    pub fn success(source: &str, title: impl Into<String>) -> Self {
+        Self::new(source, Severity::Success, title)
+    }
+
+    pub fn info(source: &str, title: impl Into<String>) -> Self {
+        Self::new(source, Severity::Info, title)
+    }
+
+    /// A failure: the error's user message as detail, with a hint when the fix
+    /// is somewhere the user can go (signing in, settings).
+    pub fn error(source: &str, title: impl Into<String>, err: &AppError) -> Self {
+        Self {
+            detail: err.user_message().to_string(),
+            action: hint_for(err),
+            ..Self::new(source, Severity::Error, title)
+        }
+    }
+
+    pub fn keyed(mut self, key: impl Into<String>) -> Self {
+        self.key = key.into();
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = detail.into();
+        self
+    }
+
+    pub fn with_action(mut self, action: ActionHint) -> Self {
+        self.action = Some(action);
+        self
+    }
+}
+
+/// Where to send the user for errors they can fix themselves.
+pub fn hint_for(err: &AppError) -> Option<ActionHint> {
+    match err {
+        AppError::Auth(AuthError::OAuthCancelled) => None,
+        AppError::Auth(_)
+        | AppError::GitHub(GitHubError::Unauthorized | GitHubError::NotAuthenticated) => {
+            Some(ActionHint::navigate("Sign in", "SettingsPage"))
+        }
+        AppError::Config(ConfigError::NotFound(_) | ConfigError::MissingSetting(_)) => {
+            Some(ActionHint::navigate("Open settings", "SettingsPage"))
+        }
+        _ => None,
+    }
+}
+
+pub struct UiEventBus {
+    tx: broadcast::Sender<UiEvent>,
+}
+
+impl Default for UiEventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UiEventBus {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(BUS_CAPACITY);
+        Self { tx }
+    }
+
+    /// Send to every subscriber; with none listening the event is dropped.
+    pub fn publish(&self, event: UiEvent) {
+        tracing::debug!(source = %event.source, key = %event.key, "ui event: {}", event.title);
+        let _ = self.tx.send(event);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<UiEvent> {
+        self.tx.subscribe()
+    }
+}
+
+/// The process-wide bus.
+pub fn global() -> &'static UiEventBus {
+    static BUS: OnceLock<UiEventBus> = OnceLock::new();
+    BUS.get_or_init(UiEventBus::new)
+}
+
+/// Publish on the process-wide bus.
+pub fn publish(event: UiEvent) {
+    global().publish(event);
+}
+
+/// A toast on screen: its event, a stable id for QML, and when it goes away.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub id: u64,
+    pub event: UiEvent,
+    pub expires_at: Option<Instant>,
+}
+
+/// Toasts currently shown, oldest first.
+#[derive(Debug, Default)]
+pub struct ToastQueue {
+    toasts: Vec<Toast>,
+    next_id: u64,
+}
+
+impl ToastQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show `event`, replacing the toast with the same key in place. Returns its id.
+    pub fn push(&mut self, event: UiEvent, now: Instant) -> u64 {
+        let expires_at = event.severity.lifetime().map(|d| now + d);
+        if let Some(t) = self.toasts.iter_mut().find(|t| t.event.key == event.key) {
+            t.event = event;
+            t.expires_at = expires_at;
+            return t.id;
+        }
+        self.next_id += 1;
+        let id = self.next_id;
+        self.toasts.push(Toast { id, event, expires_at });
+        while self.toasts.len() > MAX_TOASTS {
+            // Running operations stay; drop the oldest finished notice
+            let oldest = self.toasts.iter().position(|t| t.expires_at.is_some()).unwrap_or(0);
+            self.toasts.remove(oldest);
+        }
+        id
+    }
+
+    pub fn dismiss(&mut self, id: u64) -> bool {
+        let before = self.toasts.len();
+        self.toasts.retain(|t| t.id != id);
+        self.toasts.len() != before
+    }
+
+    /// Drop expired toasts; returns whether any went.
+    pub fn expire(&mut self, now: Instant) -> bool {
+        let before = self.toasts.len();
+        self.toasts.retain(|t| t.expires_at.is_none_or(|at| at > now));
+        self.toasts.len() != before
+    }
+
+    pub fn get(&self, id: u64) -> Option<&Toast> {
+        self.toasts.iter().find(|t| t.id == id)
+    }
+
+    pub fn toasts(&self) -> &[Toast] {
+        &self.toasts
+    }
+
+    /// JSON array for QML: id, severity, source, title, detail, percent (-1 when
+    /// unknown), action_label and action ("" when none).
+    pub fn to_json(&self) -> String {
+        let items: Vec<serde_json::Value> = self
+            .toasts
+            .iter()
+            .map(|t| {
+                let (label, action) = t
+                    .event
+                    .action
+                    .as_ref()
+                    .map(|a| (a.label.as_str(), a.action.as_str()))
+                    .unwrap_or_default();
+                serde_json::json!({
+                    "id": t.id,
+                    "severity": t.event.severity,
+                    "source": t.event.source,
+                    "title": t.event.title,
+                    "detail": t.event.detail,
+                    "percent": t.event.percent.map_or(-1, i32::from),
+                    "action_label": label,
+                    "action": action,
+                })
+            })
+            .collect();
+        serde_json::Value::Array(items).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+    use super::*;
+
+    #[test]
+    fn keyed_events_replace_their_toast() {
+        let mut queue = ToastQueue::new();
+        let now = Instant::now();
+        let id =
+            queue.push(UiEvent::progress("repos", "Cloning myme", Some(10)).keyed("clone"), now);
+        queue.push(UiEvent::info("notes", "Saved"), now);
+        let same = queue.push(UiEvent::success("repos", "Cloned myme").keyed("clone"), now);
+
+        assert_eq!(id, same);
+        assert_eq!(queue.toasts().len(), 2);
+        let toast = queue.get(id).unwrap();
+        assert_eq!(toast.event.severity, Severity::Success);
+        assert!(toast.expires_at.is_some());
+    }
+
+    #[test]
+    fn progress_stays_until_replaced_and_others_expire() {
+        let mut queue = ToastQueue::new();
+        let now = Instant::now();
+        queue.push(UiEvent::progress("repos", "Pulling", None), now);
+        queue.push(UiEvent::success("repos", "Done"), now);
+        assert!(!queue.expire(now + Duration::from_secs(1)));
+        assert!(queue.expire(now + Duration::from_secs(60)));
+        assert_eq!(queue.toasts().len(), 1);
+        assert_eq!(queue.toasts()[0].event.severity, Severity::Progress);
+    }
+
+    #[test]
+    fn overflow_drops_oldest_finished_toast() {
+        let mut queue = ToastQueue::new();
+        let now = Instant::now();
+        let running = queue.push(UiEvent::progress("repos", "Cloning", Some(0)), now);
+        let first = queue.push(UiEvent::info("a", "1"), now);
+        for i in 2..=MAX_TOASTS {
+            queue.push(UiEvent::info("a", i.to_string()), now);
+        }
+        assert_eq!(queue.toasts().len(), MAX_TOASTS);
+        assert!(queue.get(running).is_some());
+        assert!(queue.get(first).is_none());
+        assert!(queue.dismiss(running));
+        assert!(!queue.dismiss(running));
+    }
+
+    #[test]
+    fn errors_carry_user_message_and_hint() {
+        let err = AppError::GitHub(GitHubError::NotAuthenticated);
+        let event = UiEvent::error("workflows", "Couldn't dispatch build", &err);
+        assert_eq!(event.detail, err.user_message());
+        assert_eq!(event.action, Some(ActionHint::navigate("Sign in", "SettingsPage")));
+        assert!(hint_for(&AppError::Service("x".into())).is_none());
+
+        let mut queue = ToastQueue::new();
+        queue.push(event, Instant::now());
+        let json: serde_json::Value = serde_json::from_str(&queue.to_json()).unwrap();
+        assert_eq!(json[0]["severity"], "error");
+        assert_eq!(json[0]["action"], "navigate:SettingsPage");
+        assert_eq!(json[0]["percent"], -1);
+    }
+
+    #[test]
+    fn bus_delivers_to_every_subscriber() {
+        let bus = UiEventBus::new();
+        // Nobody listening yet: dropped without error
+        bus.publish(UiEvent::info("test", "lost"));
+        let mut a = bus.subscribe();
+        let mut b = bus.subscribe();
+        bus.publish(UiEvent::info("test", "hello"));
+        assert_eq!(a.try_recv().unwrap().title, "hello");
+        assert_eq!(b.try_recv().unwrap().title, "hello");
+        assert!(a.try_recv().is_err());
+    }
+}
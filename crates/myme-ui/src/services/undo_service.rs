@@ -0,0 +1,360 @@
+//! Undo and redo of recorded changes.
+//!
+//! Changes recorded with an [`UndoHint`] are pushed onto a process-wide stack.
+//! Undoing one runs the compensating operation and posts the result on the
+//! owning service's channel, so the note, Gmail or kanban model updates as if
+//! the change had been made there. The inverse is kept for redo.
+
+use myme_core::connectivity;
+use myme_services::{TaskStatus, TodoUpdateRequest};
+use parking_lot::Mutex;
+
+use super::activity_service::{self, UndoHint};
+use super::gmail_service::{self, GmailServiceMessage};
+use super::google_common::get_google_access_token;
+use super::kanban_service::KanbanServiceMessage;
+use super::note_service::NoteServiceMessage;
+use crate::bridge;
+
+/// Most changes kept for undo; older ones fall off the bottom.
+const MAX_DEPTH: usize = 50;
+
+/// Error type for undo and redo.
+#[derive(Debug, Clone)]
+pub enum UndoError {
+    /// The compensating operation failed
+    Failed(String),
+    /// The compensating operation needs a connection
+    Offline,
+    /// Nothing to undo or redo
+    Empty,
+    NotInitialized,
+}
+
+impl std::fmt::Display for UndoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UndoError::Failed(s) => write!(f, "{}", s),
+            UndoError::Offline => write!(f, "Offline"),
+            UndoError::Empty => write!(f, "Nothing to undo"),
+            UndoError::NotInitialized => write!(f, "Undo not initialized"),
+        }
+    }
+}
+
+impl std::error::Error for UndoError {}
+
+/// Messages sent from async undo operations back to the UI thread.
+#[derive(Debug)]
+pub enum UndoServiceMessage {
+    /// A reversible change was recorded; offer to undo it
+    Recorded { summary: String },
+    /// An undo (or redo, when `redo` is set) of a change in `service` finished
+    Applied { service: String, summary: String, redo: bool, result: Result<(), UndoError> },
+}
+
+/// A reversible change and how to reverse it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndoItem {
+    pub summary: String,
+    pub hint: UndoHint,
+}
+
+/// Undo and redo stacks, most recent last.
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    undo: Vec<UndoItem>,
+    redo: Vec<UndoItem>,
+}
+
+impl UndoStack {
+    pub const fn new() -> Self {
+        Self { undo: Vec::new(), redo: Vec::new() }
+    }
+
+    /// A new change: it becomes the next undo and the redo history is dropped.
+    pub fn push(&mut self, item: UndoItem) {
+        self.redo.clear();
+        push_bounded(&mut self.undo, item);
+    }
+
+    /// Take the next item to undo (or redo, when `redo` is set).
+    pub fn pop(&mut self, redo: bool) -> Option<UndoItem> {
+        if redo {
+            self.redo.pop()
+        } else {
+            self.undo.pop()
+        }
+    }
+
+    /// An undo (or redo) succeeded; `inverse` reverses it again.
+    pub fn applied(&mut self, inverse: UndoItem, redo: bool) {
+        if redo {
+            push_bounded(&mut self.undo, inverse);
+        } else {
+            push_bounded(&mut self.redo, inverse);
+        }
+    }
+
+    /// An undo (or redo) failed; put the item back so it can be retried.
+    pub fn restore(&mut self, item: UndoItem, redo: bool) {
+        if redo {
+            push_bounded(&mut self.redo, item);
+        } else {
+            push_bounded(&mut self.undo, item);
+        }
+    }
+
+    /// Summary of the next undo (or redo), if any.
+    pub fn peek(&self, redo: bool) -> Option<&str> {
+        let stack = if redo { &self.redo } else { &self.undo };
+        stack.last().map(|item| item.summary.as_str())
+    }
+}
+
+fn push_bounded(stack: &mut Vec<UndoItem>, item: UndoItem) {
+    stack.push(item);
+    if stack.len() > MAX_DEPTH {
+        stack.remove(0);
+    }
+}
+
+static STACK: Mutex<UndoStack> = Mutex::new(UndoStack::new());
+
+/// Offer a change for undo. Called by `activity_service::record`.
+pub fn push(summary: &str, hint: UndoHint) {
+    STACK.lock().push(UndoItem { summary: summary.to_string(), hint });
+    if let Some(tx) = bridge::get_undo_service_tx() {
+        let _ = tx.send(UndoServiceMessage::Recorded { summary: summary.to_string() });
+    }
+}
+
+/// Summary of the next undo, if any.
+pub fn undo_summary() -> Option<String> {
+    STACK.lock().peek(false).map(str::to_string)
+}
+
+/// Summary of the next redo, if any.
+pub fn redo_summary() -> Option<String> {
+    STACK.lock().peek(true).map(str::to_string)
+}
+
+/// Undo the most recent change. Sends `Applied` on the channel when complete.
+pub fn request_undo(tx: &std::sync::mpsc::Sender<UndoServiceMessage>) {
+    request_apply(tx, false);
+}
+
+/// Redo the most recently undone change. Sends `Applied` on the channel when complete.
+pub fn request_redo(tx: &std::sync::mpsc::Sender<UndoServiceMessage>) {
+    request_apply(tx, true);
+}
+
+fn request_apply(tx: &std::sync::mpsc::Sender<UndoServiceMessage>, redo: bool) {
+    let tx = tx.clone();
+    let Some(item) = STACK.lock().pop(redo) else {
+        let _ = tx.send(UndoServiceMessage::Applied {
+            service: String::new(),
+            summary: String::new(),
+            redo,
+            result: Err(UndoError::Empty),
+        });
+        return;
+    };
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let service = item.hint.service().to_string();
+            let summary = item.summary.clone();
+            STACK.lock().restore(item, redo);
+            let _ = tx.send(UndoServiceMessage::Applied {
+                service,
+                summary,
+                redo,
+                result: Err(UndoError::NotInitialized),
+            });
+            return;
+        }
+    };
+    // The token helper may block on its own runtime to refresh, so fetch it here
+    let access_token = match item.hint.service() {
+        "gmail" => get_google_access_token(),
+        _ => None,
+    };
+
+    runtime.spawn(async move {
+        let result = match apply(&item.hint, access_token).await {
+            Ok(inverse) => {
+                let (action, verb) = if redo { ("redone", "Redid") } else { ("undone", "Undid") };
+                activity_service::record(
+                    item.hint.service(),
+                    action,
+                    &item.hint.target(),
+                    &format!("{}: {}", verb, item.summary),
+                    None,
+                );
+                STACK
+                    .lock()
+                    .applied(UndoItem { summary: item.summary.clone(), hint: inverse }, redo);
+                Ok(())
+            }
+            Err(e) => {
+                tracing::warn!("Failed to apply undo for {}: {}", item.summary, e);
+                STACK.lock().restore(item.clone(), redo);
+                Err(e)
+            }
+        };
+        let _ = tx.send(UndoServiceMessage::Applied {
+            service: item.hint.service().to_string(),
+            summary: item.summary,
+            redo,
+            result,
+        });
+    });
+}
+
+fn failed(e: impl std::fmt::Display) -> UndoError {
+    UndoError::Failed(e.to_string())
+}
+
+/// Run the operation described by `hint` and return the hint that reverses it.
+async fn apply(hint: &UndoHint, access_token: Option<String>) -> Result<UndoHint, UndoError> {
+    match hint {
+        UndoHint::NoteTrash { id } => {
+            let client = bridge::get_note_client_or_init().ok_or(UndoError::NotInitialized)?;
+            client.trash_todo(*id).await.map_err(failed)?;
+            let note = client.get_todo(*id).await.map_err(failed)?;
+            notify_notes(note);
+            Ok(UndoHint::NoteRestore { id: *id })
+        }
+        UndoHint::NoteRestore { id } => {
+            let client = bridge::get_note_client_or_init().ok_or(UndoError::NotInitialized)?;
+            notify_notes(client.restore_todo(*id).await.map_err(failed)?);
+            Ok(UndoHint::NoteTrash { id: *id })
+        }
+        UndoHint::NoteSetArchived { id, archived } => {
+            let client = bridge::get_note_client_or_init().ok_or(UndoError::NotInitialized)?;
+            let request = TodoUpdateRequest { archived: Some(*archived), ..Default::default() };
+            notify_notes(client.update_todo(*id, request).await.map_err(failed)?);
+            Ok(UndoHint::NoteSetArchived { id: *id, archived: !archived })
+        }
+        UndoHint::GmailArchive { message_ids }
+        | UndoHint::GmailUnarchive { message_ids }
+        | UndoHint::GmailTrash { message_ids }
+        | UndoHint::GmailUntrash { message_ids } => {
+            if !connectivity::is_online() {
+                return Err(UndoError::Offline);
+            }
+            let token =
+                access_token.ok_or_else(|| UndoError::Failed("Not signed in to Google".into()))?;
+            let client = gmail_service::gmail_client(&token);
+            let ids = message_ids.clone();
+            let inverse = match hint {
+                UndoHint::GmailArchive { .. } => {
+                    client.batch_archive(message_ids).await.map_err(failed)?;
+                    UndoHint::GmailUnarchive { message_ids: ids }
+                }
+                UndoHint::GmailUnarchive { .. } => {
+                    client.batch_unarchive(message_ids).await.map_err(failed)?;
+                    UndoHint::GmailArchive { message_ids: ids }
+                }
+                UndoHint::GmailTrash { .. } => {
+                    client.batch_trash(message_ids).await.map_err(failed)?;
+                    UndoHint::GmailUntrash { message_ids: ids }
+                }
+                _ => {
+                    client.batch_untrash(message_ids).await.map_err(failed)?;
+                    UndoHint::GmailTrash { message_ids: ids }
+                }
+            };
+            if let Some(tx) = bridge::get_gmail_service_tx() {
+                let _ = tx.send(GmailServiceMessage::BatchActionDone(Ok(message_ids.clone())));
+            }
+            Ok(inverse)
+        }
+        UndoHint::KanbanMove { task_id, column_id, status } => {
+            let store = bridge::get_project_store_or_init().ok_or(UndoError::NotInitialized)?;
+            let mut task = store
+                .get_task(task_id)
+                .map_err(failed)?
+                .ok_or_else(|| UndoError::Failed("The task no longer exists".into()))?;
+            let inverse = UndoHint::KanbanMove {
+                task_id: task_id.clone(),
+                column_id: task.column_id.clone(),
+                status: task.status.key().to_string(),
+            };
+            task.column_id = column_id.clone();
+            task.status = TaskStatus::from_key(status).unwrap_or(TaskStatus::Todo);
+            task.updated_at = chrono::Utc::now().to_rfc3339();
+            store.upsert_task(&task).map_err(failed)?;
+            if let Some(tx) = bridge::get_kanban_service_tx() {
+                let _ = tx.send(KanbanServiceMessage::TaskChanged(task));
+            }
+            Ok(inverse)
+        }
+    }
+}
+
+/// Hand a changed note to the notes model, which merges it like a sync change.
+fn notify_notes(note: myme_services::Todo) {
+    if let Some(tx) = bridge::get_note_service_tx() {
+        let _ = tx.send(NoteServiceMessage::ChangesDone(Ok(vec![note])));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn item(id: i64) -> UndoItem {
+        UndoItem { summary: format!("note {}", id), hint: UndoHint::NoteRestore { id } }
+    }
+
+    #[test]
+    fn undo_then_redo_moves_between_stacks() {
+        let mut stack = UndoStack::new();
+        stack.push(item(1));
+        stack.push(item(2));
+
+        let undone = stack.pop(false).unwrap();
+        assert_eq!(undone, item(2));
+        stack.applied(
+            UndoItem { summary: undone.summary, hint: UndoHint::NoteTrash { id: 2 } },
+            false,
+        );
+        assert_eq!(stack.peek(false), Some("note 1"));
+        assert_eq!(stack.peek(true), Some("note 2"));
+
+        let redone = stack.pop(true).unwrap();
+        assert_eq!(redone.hint, UndoHint::NoteTrash { id: 2 });
+        stack.applied(item(2), true);
+        assert_eq!(stack.peek(false), Some("note 2"));
+        assert_eq!(stack.peek(true), None);
+    }
+
+    #[test]
+    fn new_change_clears_redo_and_depth_is_bounded() {
+        let mut stack = UndoStack::new();
+        stack.push(item(1));
+        let undone = stack.pop(false).unwrap();
+        stack.applied(undone, false);
+        assert!(stack.peek(true).is_some());
+
+        for id in 0..(MAX_DEPTH as i64 + 5) {
+            stack.push(item(id));
+        }
+        assert!(stack.peek(true).is_none());
+        assert_eq!(stack.undo.len(), MAX_DEPTH);
+        assert_eq!(stack.undo[0], item(5));
+    }
+
+    #[test]
+    fn failed_apply_restores_item() {
+        let mut stack = UndoStack::new();
+        stack.push(item(1));
+        let taken = stack.pop(false).unwrap();
+        assert!(stack.pop(false).is_none());
+        stack.restore(taken, false);
+        assert_eq!(stack.peek(false), Some("note 1"));
+    }
+}
@@ -3,7 +3,9 @@
 
 use std::sync::Arc;
 
-use myme_weather::{WeatherData, WeatherProvider};
+use myme_core::connectivity;
+use myme_weather::radar::DEFAULT_ZOOM;
+use myme_weather::{Location, RadarTile, WeatherData, WeatherProvider};
 
 use crate::bridge;
 
@@ -32,6 +34,8 @@ impl std::error::Error for WeatherError {}
 pub enum WeatherServiceMessage {
     /// Result of fetching weather data
     FetchDone(Result<WeatherData, WeatherError>),
+    /// Latest radar frame around the location (None if unavailable)
+    RadarDone(Option<RadarTile>),
 }
 
 /// Request to fetch weather data asynchronously.
@@ -48,6 +52,12 @@ pub fn request_fetch(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let _ = tx.send(WeatherServiceMessage::FetchDone(Err(WeatherError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
 
     runtime.spawn(async move {
         // First get location
@@ -66,7 +76,9 @@ pub fn request_fetch(
 
         // Reverse geocode to get city name (coordinates only from system APIs)
         if location.city_name.is_none() {
-            if let Some(name) = myme_weather::reverse_geocode(&location).await {
+            let proxies = myme_core::Config::load_cached().proxy.proxies_for("weather");
+            if let Some(name) = myme_weather::reverse_geocode_with_proxies(&location, proxies).await
+            {
                 location.city_name = Some(name);
             }
         }
@@ -78,6 +90,38 @@ pub fn request_fetch(
     });
 }
 
+/// Request the latest precipitation radar tile around `location`.
+/// Sends `RadarDone` when complete; radar is optional, so failures are only logged.
+pub fn request_radar(
+    tx: &std::sync::mpsc::Sender<WeatherServiceMessage>,
+    provider: Arc<WeatherProvider>,
+    location: Location,
+) {
+    let tx = tx.clone();
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(WeatherServiceMessage::RadarDone(None));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ = tx.send(WeatherServiceMessage::RadarDone(None));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let tile = match provider.fetch_radar().await {
+            Ok(maps) => maps.latest_tile(&location, DEFAULT_ZOOM),
+            Err(e) => {
+                tracing::warn!("Radar fetch failed: {}", e);
+                None
+            }
+        };
+        let _ = tx.send(WeatherServiceMessage::RadarDone(tile));
+    });
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -94,5 +138,6 @@ mod tests {
     fn weather_service_message_variants() {
         let _fetch_err: WeatherServiceMessage =
             WeatherServiceMessage::FetchDone(Err(WeatherError::NotInitialized));
+        let _radar: WeatherServiceMessage = WeatherServiceMessage::RadarDone(None);
     }
 }
@@ -1,10 +1,15 @@
-//! Workflow backend: async fetch of GitHub Actions workflows for project-linked repos.
+//! Workflow backend: async fetch of GitHub Actions workflows and security alert
+//! counts for project-linked repos, and manual (`workflow_dispatch`) runs.
 //! All network work runs off the UI thread; results sent via mpsc.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use myme_services::{GitHubClient, GitHubWorkflow};
+use myme_core::connectivity;
+use myme_services::{GitHubClient, GitHubWorkflow, RepoSecurity, WorkflowInput};
 
+use super::repo_service;
+use super::ui_events::{self, ActionHint, UiEvent};
 use crate::bridge;
 
 /// Error type for workflow operations
@@ -30,6 +35,8 @@ impl std::error::Error for WorkflowError {}
 pub struct RepoWorkflows {
     pub repo_id: String,
     pub workflows: Vec<GitHubWorkflow>,
+    /// Open security alerts; None when they couldn't be checked
+    pub security: Option<RepoSecurity>,
 }
 
 /// Messages sent from async operations back to the UI thread
@@ -37,10 +44,27 @@ pub struct RepoWorkflows {
 pub enum WorkflowServiceMessage {
     /// Result of fetching workflows for all linked repos
     FetchWorkflowsDone(Result<Vec<RepoWorkflows>, WorkflowError>),
+    /// Dispatch inputs of one workflow; `None` when it can't be run by hand
+    InputsDone {
+        repo_id: String,
+        workflow_id: i64,
+        result: Result<Option<Vec<WorkflowInput>>, WorkflowError>,
+    },
+    /// Result of triggering a workflow run; Ok carries the ref it ran on
+    DispatchDone { repo_id: String, workflow_name: String, result: Result<String, WorkflowError> },
+    /// Workflow runs changed on GitHub for these repos (not a reply to a request)
+    RemoteChanged(Vec<String>),
 }
 
-/// Request to fetch workflows for the given repo_ids (owner/repo format).
-/// Sorts repo_ids before fetching. Sends `FetchWorkflowsDone` on the channel when complete.
+fn split_repo_id(repo_id: &str) -> Result<(&str, &str), WorkflowError> {
+    repo_id
+        .split_once('/')
+        .ok_or_else(|| WorkflowError::Network(format!("Invalid repo: {}", repo_id)))
+}
+
+/// Request to fetch workflows and security alerts for the given repo_ids
+/// (owner/repo format). Sorts repo_ids before fetching.
+/// Sends `FetchWorkflowsDone` on the channel when complete.
 pub fn request_fetch_workflows(
     tx: &std::sync::mpsc::Sender<WorkflowServiceMessage>,
     client: Arc<GitHubClient>,
@@ -56,6 +80,12 @@ pub fn request_fetch_workflows(
             return;
         }
     };
+    if !connectivity::is_online() {
+        let _ = tx.send(WorkflowServiceMessage::FetchWorkflowsDone(Err(WorkflowError::Network(
+            connectivity::OFFLINE_ERROR.into(),
+        ))));
+        return;
+    }
 
     repo_ids.sort();
 
@@ -66,13 +96,21 @@ pub fn request_fetch_workflows(
             let (owner, repo) = match parts.as_slice() {
                 [o, r] => (*o, *r),
                 _ => {
-                    results.push(RepoWorkflows { repo_id, workflows: vec![] });
+                    results.push(RepoWorkflows { repo_id, workflows: vec![], security: None });
                     continue;
                 }
             };
             match client.list_workflows(owner, repo).await {
                 Ok(workflows) => {
-                    results.push(RepoWorkflows { repo_id, workflows });
+                    // Alerts are a badge on the repo; don't fail the workflows over them
+                    let security = match repo_service::check_security(&client, &repo_id).await {
+                        Ok(security) => Some(security),
+                        Err(e) => {
+                            tracing::warn!("Skipping security alerts for {}: {}", repo_id, e);
+                            None
+                        }
+                    };
+                    results.push(RepoWorkflows { repo_id, workflows, security });
                 }
                 Err(e) => {
                     let _ = tx.send(WorkflowServiceMessage::FetchWorkflowsDone(Err(
@@ -86,6 +124,122 @@ pub fn request_fetch_workflows(
     });
 }
 
+/// Request the `workflow_dispatch` inputs of `workflow` in `repo_id`
+/// (owner/repo). Sends `InputsDone` on the channel when complete.
+pub fn request_inputs(
+    tx: &std::sync::mpsc::Sender<WorkflowServiceMessage>,
+    client: Arc<GitHubClient>,
+    repo_id: String,
+    workflow: GitHubWorkflow,
+) {
+    let tx = tx.clone();
+    let workflow_id = workflow.id;
+    let done = move |repo_id: String, result| WorkflowServiceMessage::InputsDone {
+        repo_id,
+        workflow_id,
+        result,
+    };
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(done(repo_id, Err(WorkflowError::NotInitialized)));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let _ =
+            tx.send(done(repo_id, Err(WorkflowError::Network(connectivity::OFFLINE_ERROR.into()))));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let result = match split_repo_id(&repo_id) {
+            Ok((owner, repo)) => client
+                .get_workflow_inputs(owner, repo, &workflow.path)
+                .await
+                .map_err(|e| WorkflowError::Network(e.to_string())),
+            Err(e) => Err(e),
+        };
+        let _ = tx.send(done(repo_id, result));
+    });
+}
+
+/// Request a manual run of workflow `workflow_id` in `repo_id` with checked
+/// `inputs`. An empty `git_ref` runs on the repo's default branch.
+/// Sends `DispatchDone` on the channel when complete.
+pub fn request_dispatch(
+    tx: &std::sync::mpsc::Sender<WorkflowServiceMessage>,
+    client: Arc<GitHubClient>,
+    repo_id: String,
+    workflow: GitHubWorkflow,
+    git_ref: String,
+    inputs: BTreeMap<String, String>,
+) {
+    let tx = tx.clone();
+    let workflow_name = workflow.name.clone();
+    let done = move |repo_id: String, result| WorkflowServiceMessage::DispatchDone {
+        repo_id,
+        workflow_name,
+        result,
+    };
+    let runtime = match bridge::get_runtime() {
+        Some(r) => r,
+        None => {
+            let _ = tx.send(done(repo_id, Err(WorkflowError::NotInitialized)));
+            return;
+        }
+    };
+    if !connectivity::is_online() {
+        let e = WorkflowError::Network(connectivity::OFFLINE_ERROR.into());
+        let failed = format!("Couldn't start {}", workflow.name);
+        ui_events::publish(UiEvent::error(
+            "workflows",
+            failed,
+            &myme_core::AppError::from(e.clone()),
+        ));
+        let _ = tx.send(done(repo_id, Err(e)));
+        return;
+    }
+
+    runtime.spawn(async move {
+        let result = async {
+            let (owner, repo) = split_repo_id(&repo_id)?;
+            let git_ref = if git_ref.is_empty() {
+                client
+                    .get_repo(owner, repo)
+                    .await
+                    .map_err(|e| WorkflowError::Network(e.to_string()))?
+                    .default_branch
+            } else {
+                git_ref
+            };
+            client
+                .dispatch_workflow(owner, repo, &workflow.id.to_string(), &git_ref, &inputs)
+                .await
+                .map_err(|e| WorkflowError::Network(e.to_string()))?;
+            Ok::<_, WorkflowError>(git_ref)
+        }
+        .await;
+        let event = match &result {
+            Ok(git_ref) => {
+                UiEvent::success("workflows", format!("{} started on {}", workflow.name, git_ref))
+                    .with_detail(repo_id.clone())
+                    .with_action(ActionHint::open_url(
+                        "View runs",
+                        &format!("https://github.com/{}/actions", repo_id),
+                    ))
+            }
+            Err(e) => UiEvent::error(
+                "workflows",
+                format!("Couldn't start {}", workflow.name),
+                &myme_core::AppError::from(e.clone()),
+            ),
+        };
+        ui_events::publish(event);
+        let _ = tx.send(done(repo_id, result));
+    });
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -96,4 +250,10 @@ mod tests {
         assert!(format!("{}", WorkflowError::Network("timeout".into())).contains("Workflow"));
         assert!(format!("{}", WorkflowError::NotInitialized).contains("not initialized"));
     }
+
+    #[test]
+    fn split_repo_id_requires_owner_and_name() {
+        assert_eq!(split_repo_id("octo/repo").unwrap(), ("octo", "repo"));
+        assert!(split_repo_id("repo").is_err());
+    }
 }
@@ -0,0 +1,592 @@
+//! Startup orchestration.
+//!
+//! Initializes the shared services in dependency order on a background task
+//! instead of blocking `main` before the window appears. Each step records its
+//! health (ok / degraded / failed, with a reason) so the UI can show what is
+//! still loading and what didn't come up. Transient failures, such as a
+//! database locked by another process, are retried with backoff.
+//!
+//! Steps the first screen needs run at once ([`Phase::Eager`]). The local
+//! databases and schedulers ([`Phase::Deferred`]) wait until the window has
+//! drawn its first frame, so opening stores and running schema migrations
+//! doesn't hold up first paint; a model that needs a store sooner opens it
+//! on first use. Migration progress shows in the step's status, and each
+//! step's duration and the phase times are recorded.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use myme_services::db;
+use parking_lot::RwLock;
+use serde::Serialize;
+
+use crate::app_services::AppServices;
+
+/// Attempts per step before a transient failure is reported as failed
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry; doubled for each one after
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Deferred steps start after the first frame, or after this if no frame is
+/// reported (e.g. the window starts hidden)
+const FIRST_FRAME_WAIT: Duration = Duration::from_secs(3);
+
+/// When a step runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Right away; the first screen depends on it
+    Eager,
+    /// After the first frame
+    Deferred,
+}
+
+/// Health of one service.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", content = "reason", rename_all = "snake_case")]
+pub enum Health {
+    /// Waiting for its turn (or for a retry)
+    Pending,
+    Starting,
+    Ok,
+    /// Running with reduced functionality (e.g. not signed in)
+    Degraded(String),
+    Failed(String),
+}
+
+impl Health {
+    pub fn is_settled(&self) -> bool {
+        !matches!(self, Health::Pending | Health::Starting)
+    }
+}
+
+/// Startup status of one service, as shown to the user.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceHealth {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub health: Health,
+    pub attempts: u32,
+    /// What a starting step is doing, e.g. "Updating database (3/12)"
+    pub progress: Option<String>,
+    /// How long the step took, once settled
+    pub elapsed_ms: Option<u64>,
+}
+
+/// Time from [`start`] to the end of a startup phase.
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseTiming {
+    pub phase: &'static str,
+    pub elapsed_ms: u64,
+}
+
+/// Result of running one step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Ok,
+    Degraded(String),
+    Failed { reason: String, transient: bool },
+}
+
+/// One service to initialize.
+#[derive(Clone, Copy)]
+pub struct Step {
+    pub name: &'static str,
+    pub label: &'static str,
+    /// Steps that must not have failed before this one runs
+    pub deps: &'static [&'static str],
+    pub phase: Phase,
+    pub run: fn() -> Outcome,
+}
+
+impl Step {
+    const fn eager(
+        name: &'static str,
+        label: &'static str,
+        deps: &'static [&'static str],
+        run: fn() -> Outcome,
+    ) -> Self {
+        Self { name, label, deps, phase: Phase::Eager, run }
+    }
+
+    const fn deferred(
+        name: &'static str,
+        label: &'static str,
+        deps: &'static [&'static str],
+        run: fn() -> Outcome,
+    ) -> Self {
+        Self { name, label, deps, phase: Phase::Deferred, run }
+    }
+}
+
+/// Services started at launch, in dependency order; within a phase, earlier
+/// steps run first.
+pub const STEPS: &[Step] = &[
+    Step::eager("error_log", "Error log", &[], start_error_log),
+    Step::eager("config_watch", "Settings watcher", &[], start_config_watcher),
+    Step::eager("metrics", "Metrics", &[], start_metrics),
+    Step::eager("connectivity", "Connectivity", &[], start_connectivity),
+    Step::eager("weather", "Weather", &[], start_weather),
+    Step::eager("github_auth", "GitHub sign-in", &[], start_github_auth),
+    Step::eager("github", "GitHub", &["github_auth", "connectivity"], start_github_client),
+    Step::deferred("projects", "Project store", &[], start_project_store),
+    Step::deferred("notes", "Notes", &[], start_notes),
+    Step::deferred("activity", "Activity log", &[], start_activity_log),
+    Step::deferred("bookmarks", "Bookmarks", &[], start_bookmarks),
+    Step::deferred("ipc", "Remote control", &["notes"], start_ipc_server),
+    Step::deferred("snooze", "Email snooze", &[], start_snooze_scheduler),
+    Step::deferred("reminders", "Calendar reminders", &[], start_reminder_scheduler),
+    Step::deferred("travel", "Leave-by alerts", &["connectivity"], start_travel_scheduler),
+    Step::deferred(
+        "templates",
+        "Scheduled templates",
+        &["notes", "projects"],
+        start_template_scheduler,
+    ),
+    Step::deferred("feeds", "Feed refresh", &["connectivity"], start_feed_refresh),
+    Step::deferred(
+        "github_sync",
+        "GitHub sync",
+        &["github", "projects", "connectivity"],
+        start_github_sync,
+    ),
+];
+
+static STATUS: RwLock<Vec<ServiceHealth>> = RwLock::new(Vec::new());
+static RUNNING: AtomicBool = AtomicBool::new(false);
+static FIRST_FRAME: AtomicBool = AtomicBool::new(false);
+static STARTED_AT: OnceLock<Instant> = OnceLock::new();
+static TIMINGS: RwLock<Vec<PhaseTiming>> = RwLock::new(Vec::new());
+
+/// Start initializing services on the runtime. Returns `false` if a run is
+/// already in progress.
+pub fn start() -> bool {
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        return false;
+    }
+    STARTED_AT.get_or_init(Instant::now);
+    reset(&STATUS, STEPS);
+    AppServices::init().runtime().spawn(async {
+        let (eager, deferred): (Vec<Step>, Vec<Step>) =
+            STEPS.iter().partition(|step| step.phase == Phase::Eager);
+        run(&eager, &STATUS, RETRY_DELAY).await;
+        mark_phase("eager_services");
+
+        let waited = Instant::now();
+        while !FIRST_FRAME.load(Ordering::SeqCst) && waited.elapsed() < FIRST_FRAME_WAIT {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        run(&deferred, &STATUS, RETRY_DELAY).await;
+        RUNNING.store(false, Ordering::SeqCst);
+        // After clearing RUNNING, so pollers see a change once it's done
+        mark_phase("all_services");
+
+        let timings: Vec<String> =
+            TIMINGS.read().iter().map(|t| format!("{} {} ms", t.phase, t.elapsed_ms)).collect();
+        tracing::info!("Startup timings: {}", timings.join(", "));
+    });
+    true
+}
+
+/// Start the startup clock; phase times count from the first call (or
+/// from [`start`] when this isn't called).
+pub fn mark_launch() {
+    STARTED_AT.get_or_init(Instant::now);
+}
+
+/// The window has drawn its first frame; deferred steps may start. Only
+/// the first call counts.
+pub fn first_frame_shown() {
+    if !FIRST_FRAME.swap(true, Ordering::SeqCst) {
+        mark_phase("first_frame");
+    }
+}
+
+/// Record that `phase` ended now
+pub fn mark_phase(phase: &'static str) {
+    let Some(started) = STARTED_AT.get() else {
+        return;
+    };
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+    tracing::debug!("Startup phase {} done after {} ms", phase, elapsed_ms);
+    TIMINGS.write().push(PhaseTiming { phase, elapsed_ms });
+}
+
+/// Phase end times since [`start`], in the order they happened.
+pub fn phase_timings() -> Vec<PhaseTiming> {
+    TIMINGS.read().clone()
+}
+
+/// Run the steps that failed again (e.g. after the user fixed the cause).
+pub fn retry_failed() -> bool {
+    if RUNNING.swap(true, Ordering::SeqCst) {
+        return false;
+    }
+    let failed: Vec<Step> = {
+        let status = STATUS.read();
+        STEPS
+            .iter()
+            .filter(|step| {
+                status.iter().any(|s| s.name == step.name && matches!(s.health, Health::Failed(_)))
+            })
+            .copied()
+            .collect()
+    };
+    AppServices::init().runtime().spawn(async move {
+        run(&failed, &STATUS, RETRY_DELAY).await;
+        RUNNING.store(false, Ordering::SeqCst);
+    });
+    true
+}
+
+/// Current status of every startup service.
+pub fn snapshot() -> Vec<ServiceHealth> {
+    STATUS.read().clone()
+}
+
+/// Whether a startup run is in progress.
+pub fn is_running() -> bool {
+    RUNNING.load(Ordering::SeqCst)
+}
+
+fn reset(status: &RwLock<Vec<ServiceHealth>>, steps: &[Step]) {
+    *status.write() = steps
+        .iter()
+        .map(|s| ServiceHealth {
+            name: s.name,
+            label: s.label,
+            health: Health::Pending,
+            attempts: 0,
+            progress: None,
+            elapsed_ms: None,
+        })
+        .collect();
+}
+
+fn set_health(status: &RwLock<Vec<ServiceHealth>>, name: &str, health: Health, attempts: u32) {
+    if let Some(entry) = status.write().iter_mut().find(|s| s.name == name) {
+        entry.health = health;
+        entry.attempts = attempts;
+    }
+}
+
+fn set_progress(status: &RwLock<Vec<ServiceHealth>>, name: &str, progress: Option<String>) {
+    if let Some(entry) = status.write().iter_mut().find(|s| s.name == name) {
+        entry.progress = progress;
+    }
+}
+
+/// Run `steps` in order, skipping any whose dependency failed.
+async fn run(steps: &[Step], status: &'static RwLock<Vec<ServiceHealth>>, retry_delay: Duration) {
+    for step in steps {
+        let failed_dep = step.deps.iter().find_map(|dep| {
+            status
+                .read()
+                .iter()
+                .find(|s| s.name == *dep && matches!(s.health, Health::Failed(_)))
+                .map(|s| s.label)
+        });
+        if let Some(dep) = failed_dep {
+            set_health(status, step.name, Health::Failed(format!("{} failed to start", dep)), 0);
+            continue;
+        }
+
+        let started = Instant::now();
+        let mut attempt = 0;
+        let health = loop {
+            attempt += 1;
+            set_health(status, step.name, Health::Starting, attempt);
+            let (name, run) = (step.name, step.run);
+            let outcome = tokio::task::spawn_blocking(move || {
+                // Opening a store may migrate its schema; show how far along it is
+                let report = move |p: db::MigrationProgress| {
+                    let text = format!("Updating database ({}/{})", p.step, p.steps);
+                    set_progress(status, name, Some(text));
+                };
+                db::with_migration_progress(report, run)
+            })
+            .await
+            .unwrap_or_else(|e| Outcome::Failed {
+                reason: format!("Startup task panicked: {}", e),
+                transient: false,
+            });
+            set_progress(status, step.name, None);
+            match outcome {
+                Outcome::Ok => break Health::Ok,
+                Outcome::Degraded(reason) => break Health::Degraded(reason),
+                Outcome::Failed { reason, transient } => {
+                    if !transient || attempt >= MAX_ATTEMPTS {
+                        break Health::Failed(reason);
+                    }
+                    tracing::warn!(
+                        "{} failed to start (attempt {}): {}; retrying",
+                        step.label,
+                        attempt,
+                        reason
+                    );
+                    set_health(status, step.name, Health::Pending, attempt);
+                    tokio::time::sleep(retry_delay * 2u32.pow(attempt - 1)).await;
+                }
+            }
+        };
+        match &health {
+            Health::Failed(reason) => tracing::error!("{} failed to start: {}", step.label, reason),
+            Health::Degraded(reason) => tracing::info!("{} degraded: {}", step.label, reason),
+            _ => tracing::debug!("{} started", step.label),
+        }
+        let elapsed_ms = started.elapsed().as_millis() as u64;
+        if let Some(entry) = status.write().iter_mut().find(|s| s.name == step.name) {
+            entry.elapsed_ms = Some(elapsed_ms);
+        }
+        set_health(status, step.name, health, attempt);
+    }
+}
+
+// =========== Steps ===========
+
+fn start_error_log() -> Outcome {
+    let config_dir = myme_core::Config::load_cached().config_dir.clone();
+    myme_core::error_log::global().init_persistence(&config_dir.join("logs").join("errors.jsonl"));
+    Outcome::Ok
+}
+
+fn start_metrics() -> Outcome {
+    use myme_core::metrics;
+
+    let Some(registry) = metrics::global() else {
+        return Outcome::Ok;
+    };
+    let config = myme_core::Config::load_cached();
+    let runtime = AppServices::init().runtime();
+    runtime.spawn(metrics::run_snapshots(
+        registry.clone(),
+        metrics::snapshot_path(&config.config_dir),
+        metrics::SNAPSHOT_INTERVAL,
+    ));
+
+    let Some(listen) = &config.metrics.listen else {
+        return Outcome::Ok;
+    };
+    let addr = match metrics::parse_listen(listen) {
+        Ok(addr) => addr,
+        Err(e) => return Outcome::Degraded(e),
+    };
+    let listener = match std::net::TcpListener::bind(addr) {
+        Ok(listener) => listener,
+        Err(e) => return Outcome::Degraded(format!("Couldn't listen on {}: {}", addr, e)),
+    };
+    if let Err(e) = listener.set_nonblocking(true) {
+        return Outcome::Degraded(format!("Couldn't listen on {}: {}", addr, e));
+    }
+    runtime.spawn(async move {
+        match tokio::net::TcpListener::from_std(listener) {
+            Ok(listener) => {
+                tracing::info!("Serving metrics at http://{}/metrics", addr);
+                metrics::serve(registry, listener).await
+            }
+            Err(e) => tracing::warn!("Metrics endpoint unavailable: {}", e),
+        }
+    });
+    Outcome::Ok
+}
+
+fn start_connectivity() -> Outcome {
+    AppServices::init().init_connectivity_monitor();
+    Outcome::Ok
+}
+
+fn start_notes() -> Outcome {
+    if AppServices::init().init_note_client() {
+        Outcome::Ok
+    } else {
+        // Most often the database is locked by another instance
+        Outcome::Failed { reason: "Couldn't open the notes database".into(), transient: true }
+    }
+}
+
+fn start_activity_log() -> Outcome {
+    if AppServices::init().init_activity_log() {
+        Outcome::Ok
+    } else {
+        Outcome::Failed { reason: "Couldn't open the activity log".into(), transient: true }
+    }
+}
+
+fn start_bookmarks() -> Outcome {
+    if AppServices::init().init_bookmark_store() {
+        Outcome::Ok
+    } else {
+        Outcome::Failed { reason: "Couldn't open the bookmark store".into(), transient: true }
+    }
+}
+
+fn start_weather() -> Outcome {
+    if AppServices::init().init_weather_services() {
+        Outcome::Ok
+    } else {
+        Outcome::Failed { reason: "Weather provider misconfigured".into(), transient: false }
+    }
+}
+
+fn start_project_store() -> Outcome {
+    if AppServices::init().init_project_store() {
+        Outcome::Ok
+    } else {
+        Outcome::Failed { reason: "Couldn't open the project database".into(), transient: true }
+    }
+}
+
+fn start_github_auth() -> Outcome {
+    if AppServices::init().init_github_auth() {
+        Outcome::Ok
+    } else {
+        Outcome::Degraded("GitHub OAuth app not configured".into())
+    }
+}
+
+fn start_github_client() -> Outcome {
+    if !myme_auth::SecureStorage::has_token("github") {
+        return Outcome::Degraded("Not signed in".into());
+    }
+    if AppServices::init().init_github_client() {
+        Outcome::Ok
+    } else {
+        Outcome::Degraded("Stored token expired or unreadable; sign in again".into())
+    }
+}
+
+fn start_ipc_server() -> Outcome {
+    if AppServices::init().init_ipc_server() {
+        Outcome::Ok
+    } else {
+        Outcome::Degraded("Remote control unavailable".into())
+    }
+}
+
+fn start_snooze_scheduler() -> Outcome {
+    AppServices::init().init_snooze_scheduler();
+    Outcome::Ok
+}
+
+fn start_reminder_scheduler() -> Outcome {
+    AppServices::init().init_reminder_scheduler();
+    Outcome::Ok
+}
+
+fn start_travel_scheduler() -> Outcome {
+    AppServices::init().init_travel_scheduler();
+    Outcome::Ok
+}
+
+fn start_template_scheduler() -> Outcome {
+    AppServices::init().init_template_scheduler();
+    Outcome::Ok
+}
+
+fn start_config_watcher() -> Outcome {
+    AppServices::init().init_config_watcher();
+    Outcome::Ok
+}
+
+fn start_feed_refresh() -> Outcome {
+    if AppServices::init().init_feed_refresh() {
+        Outcome::Ok
+    } else {
+        Outcome::Degraded("Feed refresh disabled".into())
+    }
+}
+
+fn start_github_sync() -> Outcome {
+    AppServices::init().init_github_sync();
+    Outcome::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    static FLAKY_CALLS: AtomicU32 = AtomicU32::new(0);
+
+    fn flaky() -> Outcome {
+        if FLAKY_CALLS.fetch_add(1, Ordering::SeqCst) == 0 {
+            Outcome::Failed { reason: "locked".into(), transient: true }
+        } else {
+            Outcome::Ok
+        }
+    }
+
+    fn broken() -> Outcome {
+        Outcome::Failed { reason: "bad config".into(), transient: false }
+    }
+
+    fn ok() -> Outcome {
+        Outcome::Ok
+    }
+
+    fn migrating() -> Outcome {
+        let conn = db::open_in_memory().unwrap();
+        db::migrate(&conn, 3, |_| Ok(())).unwrap();
+        Outcome::Ok
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_and_skips_dependents_of_failures() {
+        let steps = [
+            Step::eager("db", "Database", &[], flaky),
+            Step::eager("cfg", "Config", &[], broken),
+            Step::eager("sync", "Sync", &["cfg"], ok),
+            Step::eager("ui", "UI", &["db"], ok),
+        ];
+        let status = Box::leak(Box::new(RwLock::new(Vec::new())));
+        reset(status, &steps);
+        run(&steps, status, Duration::from_millis(1)).await;
+
+        let status = status.read();
+        assert_eq!(status[0].health, Health::Ok);
+        assert_eq!(status[0].attempts, 2);
+        assert_eq!(status[1].health, Health::Failed("bad config".into()));
+        assert_eq!(status[1].attempts, 1);
+        assert_eq!(status[2].health, Health::Failed("Config failed to start".into()));
+        assert_eq!(status[3].health, Health::Ok);
+        assert!(status.iter().all(|s| s.health.is_settled()));
+        assert!(status.iter().all(|s| s.progress.is_none()));
+        // Skipped steps never ran, so have no duration
+        assert_eq!(status[2].elapsed_ms, None);
+        assert!(status[3].elapsed_ms.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_migration_progress_clears_when_done() {
+        let steps = [Step::deferred("store", "Store", &[], migrating)];
+        let status = Box::leak(Box::new(RwLock::new(Vec::new())));
+        reset(status, &steps);
+        run(&steps, status, Duration::from_millis(1)).await;
+
+        let status = status.read();
+        assert_eq!(status[0].health, Health::Ok);
+        assert_eq!(status[0].progress, None);
+    }
+
+    #[test]
+    fn test_steps_only_depend_on_earlier_phases() {
+        for (i, step) in STEPS.iter().enumerate() {
+            for dep in step.deps {
+                let Some(d) = STEPS[..i].iter().find(|s| s.name == *dep) else {
+                    panic!("{} depends on {}, which isn't listed before it", step.name, dep);
+                };
+                assert!(
+                    d.phase == Phase::Eager || step.phase == Phase::Deferred,
+                    "eager {} depends on deferred {}",
+                    step.name,
+                    dep
+                );
+            }
+        }
+        // Deferred steps all come after the eager ones
+        let first_deferred = STEPS.iter().position(|s| s.phase == Phase::Deferred).unwrap();
+        assert!(STEPS[first_deferred..].iter().all(|s| s.phase == Phase::Deferred));
+    }
+}
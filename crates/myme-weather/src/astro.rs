@@ -0,0 +1,192 @@
+//! Moon phase, day length and golden hour, computed locally.
+//!
+//! Approximations good to a few minutes, which is plenty for planning a walk
+//! or a photo: the moon's age from a reference new moon and the mean synodic
+//! month, and the sun's hour angle from its declination for the day. Golden
+//! hour (sun below 6°) is measured from the sunrise and sunset the forecast
+//! already has, so no time zone is needed.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Mean length of a lunar cycle in days
+const SYNODIC_MONTH_DAYS: f64 = 29.530_588_853;
+
+/// Sun elevation at sunrise/sunset (refraction and the sun's radius)
+const SUNRISE_ELEVATION_DEG: f64 = -0.833;
+
+/// Golden hour lasts while the sun is below this elevation
+const GOLDEN_HOUR_ELEVATION_DEG: f64 = 6.0;
+
+/// Eight named phases of the moon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MoonPhase {
+    #[default]
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    /// Phase for a position in the cycle (0 = new, 0.5 = full)
+    pub fn from_fraction(fraction: f64) -> Self {
+        const PHASES: [MoonPhase; 8] = [
+            MoonPhase::NewMoon,
+            MoonPhase::WaxingCrescent,
+            MoonPhase::FirstQuarter,
+            MoonPhase::WaxingGibbous,
+            MoonPhase::FullMoon,
+            MoonPhase::WaningGibbous,
+            MoonPhase::LastQuarter,
+            MoonPhase::WaningCrescent,
+        ];
+        let index = (fraction.rem_euclid(1.0) * 8.0).round() as usize % 8;
+        PHASES[index]
+    }
+
+    /// Get a human-readable description
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::NewMoon => "New Moon",
+            Self::WaxingCrescent => "Waxing Crescent",
+            Self::FirstQuarter => "First Quarter",
+            Self::WaxingGibbous => "Waxing Gibbous",
+            Self::FullMoon => "Full Moon",
+            Self::WaningGibbous => "Waning Gibbous",
+            Self::LastQuarter => "Last Quarter",
+            Self::WaningCrescent => "Waning Crescent",
+        }
+    }
+}
+
+/// Sun and moon details for one day at one place
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AstroData {
+    pub moon_phase: MoonPhase,
+    /// Position in the lunar cycle, 0 (new) to 1
+    pub moon_fraction: f64,
+    /// Illuminated share of the disc, 0 to 1
+    pub moon_illumination: f64,
+    /// Minutes between sunrise and sunset
+    pub day_length_minutes: i64,
+    /// End of the morning golden hour (it starts at sunrise); None when the
+    /// sun never climbs above 6° or never sets
+    pub golden_hour_morning_end: Option<NaiveTime>,
+    /// Start of the evening golden hour (it ends at sunset)
+    pub golden_hour_evening_start: Option<NaiveTime>,
+}
+
+/// Position in the lunar cycle at `at`, 0 (new) to 1
+pub fn moon_fraction(at: DateTime<Utc>) -> f64 {
+    // New moon of 2000-01-06 18:14 UTC
+    let reference = Utc.with_ymd_and_hms(2000, 1, 6, 18, 14, 0).single();
+    let Some(reference) = reference else {
+        return 0.0;
+    };
+    let days = (at - reference).num_seconds() as f64 / 86_400.0;
+    (days / SYNODIC_MONTH_DAYS).rem_euclid(1.0)
+}
+
+/// Hours between solar noon and the sun reaching `elevation_deg` on `date` at
+/// `latitude`. None when it never gets that high; 12 when it never drops below.
+fn hour_angle_hours(date: NaiveDate, latitude: f64, elevation_deg: f64) -> Option<f64> {
+    let day_of_year = f64::from(date.ordinal());
+    let declination = (-23.44_f64).to_radians()
+        * (2.0 * std::f64::consts::PI / 365.0 * (day_of_year + 10.0)).cos();
+    let latitude = latitude.to_radians();
+    let cos_h = (elevation_deg.to_radians().sin() - latitude.sin() * declination.sin())
+        / (latitude.cos() * declination.cos());
+    if cos_h > 1.0 {
+        None
+    } else if cos_h < -1.0 {
+        Some(12.0)
+    } else {
+        Some(cos_h.acos().to_degrees() / 15.0)
+    }
+}
+
+/// Astronomical data for `date` at `latitude`, using the forecast's local
+/// `sunrise` and `sunset`; the moon is placed at `at`
+pub fn compute(
+    date: NaiveDate,
+    latitude: f64,
+    sunrise: NaiveTime,
+    sunset: NaiveTime,
+    at: DateTime<Utc>,
+) -> AstroData {
+    let fraction = moon_fraction(at);
+    let illumination = (1.0 - (2.0 * std::f64::consts::PI * fraction).cos()) / 2.0;
+
+    let sun_up = hour_angle_hours(date, latitude, SUNRISE_ELEVATION_DEG);
+    let day_length_minutes = if sunset > sunrise {
+        (sunset - sunrise).num_minutes()
+    } else {
+        // Polar day or night: the forecast's times don't bracket a day
+        sun_up.map(|h| (h * 120.0).round() as i64).unwrap_or(0)
+    };
+
+    // Time for the sun to climb from the horizon to 6° (same going down)
+    let golden = match (sun_up, hour_angle_hours(date, latitude, GOLDEN_HOUR_ELEVATION_DEG)) {
+        (Some(up), Some(high)) if up < 12.0 && sunset > sunrise => {
+            Some(Duration::minutes(((up - high) * 60.0).round() as i64))
+        }
+        _ => None,
+    };
+
+    AstroData {
+        moon_phase: MoonPhase::from_fraction(fraction),
+        moon_fraction: fraction,
+        moon_illumination: illumination,
+        day_length_minutes,
+        golden_hour_morning_end: golden.map(|d| sunrise + d),
+        golden_hour_evening_start: golden.map(|d| sunset - d),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn moon_phase_at_known_dates() {
+        // Full moon 2024-01-25 17:54 UTC, new moon 2024-02-09 22:59 UTC
+        let full = Utc.with_ymd_and_hms(2024, 1, 25, 18, 0, 0).unwrap();
+        let data = compute(full.date_naive(), 45.0, time(7, 30), time(17, 0), full);
+        assert_eq!(data.moon_phase, MoonPhase::FullMoon);
+        assert!(data.moon_illumination > 0.98);
+
+        let new = Utc.with_ymd_and_hms(2024, 2, 9, 23, 0, 0).unwrap();
+        assert_eq!(MoonPhase::from_fraction(moon_fraction(new)), MoonPhase::NewMoon);
+        assert_eq!(MoonPhase::from_fraction(0.97), MoonPhase::NewMoon);
+        assert_eq!(MoonPhase::from_fraction(0.25), MoonPhase::FirstQuarter);
+    }
+
+    #[test]
+    fn day_length_and_golden_hour() {
+        let at = Utc.with_ymd_and_hms(2026, 6, 21, 12, 0, 0).unwrap();
+        let date = at.date_naive();
+
+        // Toronto around the solstice
+        let data = compute(date, 43.65, time(5, 36), time(21, 3), at);
+        assert_eq!(data.day_length_minutes, 15 * 60 + 27);
+        let morning = data.golden_hour_morning_end.unwrap() - time(5, 36);
+        assert!((40..=70).contains(&morning.num_minutes()), "{:?}", morning);
+        assert_eq!(time(21, 3) - data.golden_hour_evening_start.unwrap(), morning);
+
+        // Midnight sun: no sunset, no golden hour
+        let data = compute(date, 78.2, time(0, 0), time(0, 0), at);
+        assert_eq!(data.day_length_minutes, 24 * 60);
+        assert!(data.golden_hour_morning_end.is_none());
+    }
+}
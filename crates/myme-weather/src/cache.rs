@@ -1,22 +1,35 @@
-use crate::types::{WeatherData, WeatherError};
-use chrono::{Duration, Utc};
+use crate::types::{
+    CurrentWeather, Observation, TemperatureUnit, WeatherData, WeatherDelta, WeatherError,
+};
+use chrono::{DateTime, Duration, Utc};
 use std::path::PathBuf;
 
 const STALE_THRESHOLD_MINUTES: i64 = 15;
 const EXPIRED_THRESHOLD_HOURS: i64 = 2;
 
-/// Persistent weather cache
+/// Days of observations kept for comparisons
+const HISTORY_DAYS: i64 = 7;
+/// Observations closer together than this are not recorded twice
+const HISTORY_MIN_SPACING_MINUTES: i64 = 10;
+/// How far from exactly 24 hours ago "this time yesterday" may be
+const YESTERDAY_TOLERANCE_MINUTES: i64 = 90;
+
+/// Persistent weather cache, plus a rolling history of observed conditions
 #[derive(Debug, Clone)]
 pub struct WeatherCache {
     cache_path: PathBuf,
+    history_path: PathBuf,
     data: Option<WeatherData>,
+    /// Oldest first
+    history: Vec<Observation>,
 }
 
 impl WeatherCache {
     /// Create a new cache instance
     pub fn new(config_dir: &std::path::Path) -> Self {
         let cache_path = config_dir.join("weather_cache.json");
-        Self { cache_path, data: None }
+        let history_path = config_dir.join("weather_history.json");
+        Self { cache_path, history_path, data: None, history: Vec::new() }
     }
 
     /// Load cache from disk
@@ -31,6 +44,14 @@ impl WeatherCache {
         self.data =
             serde_json::from_str(&contents).map_err(|e| WeatherError::Cache(e.to_string()))?;
 
+        // History is best effort: a bad file only loses the comparison
+        if let Ok(contents) = std::fs::read_to_string(&self.history_path) {
+            match serde_json::from_str(&contents) {
+                Ok(history) => self.history = history,
+                Err(e) => tracing::warn!("Ignoring weather history: {}", e),
+            }
+        }
+
         Ok(())
     }
 
@@ -47,6 +68,12 @@ impl WeatherCache {
             std::fs::write(&self.cache_path, contents)
                 .map_err(|e| WeatherError::Cache(e.to_string()))?;
         }
+        if !self.history.is_empty() {
+            let contents = serde_json::to_string(&self.history)
+                .map_err(|e| WeatherError::Cache(e.to_string()))?;
+            std::fs::write(&self.history_path, contents)
+                .map_err(|e| WeatherError::Cache(e.to_string()))?;
+        }
         Ok(())
     }
 
@@ -87,6 +114,45 @@ impl WeatherCache {
             .unwrap_or(true)
     }
 
+    /// Add the current conditions (fetched in `unit`) to the history, dropping
+    /// observations older than a week
+    pub fn record(&mut self, current: &CurrentWeather, unit: TemperatureUnit) {
+        let observation = Observation::from_current(current, unit);
+        if let Some(last) = self.history.last() {
+            if observation.at - last.at < Duration::minutes(HISTORY_MIN_SPACING_MINUTES) {
+                return;
+            }
+        }
+        let cutoff = observation.at - Duration::days(HISTORY_DAYS);
+        self.history.retain(|o| o.at >= cutoff);
+        self.history.push(observation);
+    }
+
+    /// Observations kept, oldest first
+    pub fn history(&self) -> &[Observation] {
+        &self.history
+    }
+
+    /// Latest observation compared with the one nearest to 24 hours before
+    /// it, in `unit`. None until there is an observation from about then.
+    pub fn delta_vs_yesterday(&self, unit: TemperatureUnit) -> Option<WeatherDelta> {
+        let now = self.history.last()?;
+        let target = now.at - Duration::days(1);
+        let distance = |at: DateTime<Utc>| (at - target).num_minutes().abs();
+        let yesterday = self
+            .history
+            .iter()
+            .filter(|o| distance(o.at) <= YESTERDAY_TOLERANCE_MINUTES)
+            .min_by_key(|o| distance(o.at))?;
+
+        let celsius = now.temperature_c - yesterday.temperature_c;
+        let temperature = match unit {
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0,
+            TemperatureUnit::Celsius | TemperatureUnit::Auto => celsius,
+        };
+        Some(WeatherDelta { temperature, now: now.clone(), yesterday: yesterday.clone() })
+    }
+
     /// Get age of cached data in minutes
     pub fn age_minutes(&self) -> Option<i64> {
         self.data.as_ref().map(|d| {
@@ -131,6 +197,8 @@ mod tests {
                 city_name: Some("Seattle".to_string()),
             },
             fetched_at,
+            precipitation: vec![],
+            astro: None,
         }
     }
 
@@ -215,4 +283,42 @@ mod tests {
         let age = cache.age_minutes().unwrap();
         assert!((10..=11).contains(&age));
     }
+
+    #[test]
+    fn test_history_delta_vs_yesterday() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut cache = WeatherCache::new(temp_dir.path());
+        let now = Utc::now();
+        let reading = |at: DateTime<Utc>, temperature: f64| CurrentWeather {
+            temperature,
+            feels_like: temperature,
+            humidity: 50,
+            wind_speed: 0.0,
+            condition: WeatherCondition::Clear,
+            updated_at: at,
+        };
+
+        // A week-old reading, then yesterday's (in Fahrenheit): no pair yet
+        cache.record(&reading(now - Duration::days(9), 0.0), TemperatureUnit::Celsius);
+        cache.record(&reading(now - Duration::hours(25), 50.0), TemperatureUnit::Fahrenheit);
+        assert!(cache.delta_vs_yesterday(TemperatureUnit::Celsius).is_none());
+
+        // Now, in Celsius; a second reading minutes later isn't recorded
+        cache.record(&reading(now - Duration::minutes(5), 15.0), TemperatureUnit::Celsius);
+        cache.record(&reading(now, 30.0), TemperatureUnit::Celsius);
+        assert_eq!(cache.history().len(), 2, "9-day-old reading pruned, duplicate skipped");
+
+        let delta = cache.delta_vs_yesterday(TemperatureUnit::Celsius).unwrap();
+        assert!((delta.temperature - 5.0).abs() < 1e-9);
+        assert_eq!(delta.summary(), "5° warmer than this time yesterday");
+        let delta = cache.delta_vs_yesterday(TemperatureUnit::Fahrenheit).unwrap();
+        assert!((delta.temperature - 9.0).abs() < 1e-9);
+
+        // Persisted with the cache
+        cache.update(make_test_weather_data(now));
+        cache.save().unwrap();
+        let mut reloaded = WeatherCache::new(temp_dir.path());
+        reloaded.load().unwrap();
+        assert_eq!(reloaded.history(), cache.history());
+    }
 }
@@ -1,12 +1,14 @@
-//! Reverse geocoding: convert coordinates to human-readable place names.
+//! Geocoding: convert coordinates to human-readable place names, and
+//! addresses to coordinates.
 //! Uses Nominatim (OpenStreetMap) - free, no API key required.
 
-use crate::types::Location;
+use crate::types::{Location, WeatherError};
 use reqwest::Client;
 use serde::Deserialize;
 use std::time::Duration;
 
 const NOMINATIM_URL: &str = "https://nominatim.openstreetmap.org/reverse";
+const NOMINATIM_SEARCH_URL: &str = "https://nominatim.openstreetmap.org/search";
 const REQUEST_TIMEOUT_SECS: u64 = 10;
 const USER_AGENT: &str = "MyMe/0.1.0 (https://github.com/myme)";
 
@@ -30,18 +32,40 @@ struct NominatimAddress {
     country: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct NominatimPlace {
+    lat: String,
+    lon: String,
+    display_name: Option<String>,
+}
+
+/// HTTP client for OpenStreetMap services, which ask for an identifying
+/// user agent.
+pub(crate) fn http_client(proxies: Vec<reqwest::Proxy>) -> reqwest::Result<Client> {
+    proxies
+        .into_iter()
+        .fold(Client::builder(), reqwest::ClientBuilder::proxy)
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .user_agent(USER_AGENT)
+        .build()
+}
+
 /// Reverse geocode coordinates to a human-readable place name (e.g. "Seattle, WA").
 /// Returns `None` on failure or timeout; the caller can fall back to coordinates.
 pub async fn reverse_geocode(location: &Location) -> Option<String> {
+    reverse_geocode_with_proxies(location, Vec::new()).await
+}
+
+/// Like [`reverse_geocode`], sending the request through `proxies`.
+pub async fn reverse_geocode_with_proxies(
+    location: &Location,
+    proxies: Vec<reqwest::Proxy>,
+) -> Option<String> {
     if location.city_name.is_some() {
         return location.city_name.clone();
     }
 
-    let client = match Client::builder()
-        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
-        .user_agent(USER_AGENT)
-        .build()
-    {
+    let client = match http_client(proxies) {
         Ok(c) => c,
         Err(e) => {
             tracing::warn!("Failed to create geocoding client: {}", e);
@@ -110,6 +134,43 @@ pub async fn reverse_geocode(location: &Location) -> Option<String> {
     Some(result)
 }
 
+/// Look up coordinates for an address or place name (e.g. an event's
+/// location). `Ok(None)` means nothing matched.
+pub async fn geocode_address(
+    query: &str,
+    proxies: Vec<reqwest::Proxy>,
+) -> Result<Option<Location>, WeatherError> {
+    geocode_address_at(NOMINATIM_SEARCH_URL, query, proxies).await
+}
+
+async fn geocode_address_at(
+    url: &str,
+    query: &str,
+    proxies: Vec<reqwest::Proxy>,
+) -> Result<Option<Location>, WeatherError> {
+    let places: Vec<NominatimPlace> = http_client(proxies)?
+        .get(url)
+        .query(&[("q", query), ("format", "json"), ("limit", "1")])
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let Some(place) = places.into_iter().next() else {
+        return Ok(None);
+    };
+    let coordinate = |value: &str| {
+        value.parse::<f64>().map_err(|_| WeatherError::Parse(format!("Bad coordinate: {}", value)))
+    };
+    Ok(Some(Location {
+        latitude: coordinate(&place.lat)?,
+        longitude: coordinate(&place.lon)?,
+        accuracy_meters: None,
+        city_name: place.display_name,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
@@ -141,4 +202,35 @@ mod tests {
         let name = reverse_geocode(&loc).await;
         assert_eq!(name.as_deref(), Some("Seattle"));
     }
+
+    #[tokio::test]
+    async fn test_geocode_address() {
+        use wiremock::matchers::{method, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(query_param("q", "100 Queen St W, Toronto"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {"lat": "43.6534", "lon": "-79.3841", "display_name": "Toronto City Hall"}
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(query_param("q", "Nowhere at all"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+
+        let place = geocode_address_at(&server.uri(), "100 Queen St W, Toronto", Vec::new())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!((place.latitude - 43.6534).abs() < 1e-9);
+        assert!((place.longitude + 79.3841).abs() < 1e-9);
+        assert_eq!(place.city_name.as_deref(), Some("Toronto City Hall"));
+
+        let missing = geocode_address_at(&server.uri(), "Nowhere at all", Vec::new()).await;
+        assert!(missing.unwrap().is_none());
+    }
 }
@@ -1,15 +1,23 @@
 //! Weather service for MyMe
 //!
-//! Provides weather data via Open-Meteo API with system location detection
-//! and persistent caching.
+//! Provides weather data via Open-Meteo (or OpenWeatherMap, with fallback
+//! between them) with system location detection and persistent caching.
+//! Moon phase, day length and golden hour are computed locally. Geocoding
+//! and travel estimates reuse the same OpenStreetMap services.
 
+pub mod astro;
 pub mod cache;
 pub mod geocode;
 pub mod location;
 pub mod provider;
+pub mod radar;
+pub mod travel;
 pub mod types;
 
+pub use astro::{AstroData, MoonPhase};
 pub use cache::WeatherCache;
-pub use geocode::reverse_geocode;
-pub use provider::WeatherProvider;
+pub use geocode::{geocode_address, reverse_geocode, reverse_geocode_with_proxies};
+pub use provider::{SourceKind, WeatherProvider, WeatherSource};
+pub use radar::{RadarFrame, RadarMaps, RadarTile};
+pub use travel::{estimate_travel, TravelEstimate, TravelSource};
 pub use types::*;
@@ -0,0 +1,372 @@
+//! Weather data sources.
+//!
+//! [`WeatherProvider`] owns the HTTP client and asks each configured
+//! [`WeatherSource`] in turn, so a failing primary source falls back to the
+//! next one. Sources normalize their responses into [`WeatherData`].
+
+mod open_meteo;
+mod openweathermap;
+
+pub use open_meteo::OpenMeteo;
+pub use openweathermap::OpenWeatherMap;
+
+use crate::radar::RadarMaps;
+use crate::types::*;
+use myme_core::http_log::HttpObserver;
+use myme_services::retry::{send_observed, RetryConfig};
+use reqwest::Client;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+const RAINVIEWER_MAPS_URL: &str = "https://api.rainviewer.com/public/weather-maps.json";
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+const USER_AGENT: &str = "MyMe/0.1.0";
+
+/// HTTP client shared by all sources
+#[derive(Clone)]
+pub struct HttpClient {
+    client: Client,
+    observer: Option<HttpObserver>,
+    /// Retries after a timeout, dropped connection or 5xx/429 response
+    retry_config: RetryConfig,
+}
+
+impl HttpClient {
+    fn new(proxies: Vec<reqwest::Proxy>, timeout: Duration) -> Result<Self, WeatherError> {
+        let client = proxies
+            .into_iter()
+            .fold(Client::builder(), reqwest::ClientBuilder::proxy)
+            .timeout(timeout)
+            .user_agent(USER_AGENT)
+            .build()?;
+
+        Ok(Self { client, observer: None, retry_config: RetryConfig::none() })
+    }
+
+    /// GET `url`, retrying transient failures and reporting each exchange to
+    /// the observer if one is set.
+    pub async fn get(&self, url: &str) -> reqwest::Result<reqwest::Response> {
+        let request = self.client.get(url).build()?;
+        send_observed(&self.client, request, &self.retry_config, self.observer.as_ref()).await
+    }
+}
+
+/// Future returned by [`WeatherSource::fetch`]
+pub type SourceFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<WeatherData, WeatherError>> + Send + 'a>>;
+
+/// A forecast API that produces normalized [`WeatherData`]: conditions mapped
+/// to [`WeatherCondition`], temperatures in `unit`, wind in mph and
+/// precipitation as a UTC mm/h series.
+pub trait WeatherSource: Send + Sync {
+    /// Name used in logs
+    fn name(&self) -> &'static str;
+
+    fn fetch<'a>(
+        &'a self,
+        http: &'a HttpClient,
+        location: &'a Location,
+        unit: TemperatureUnit,
+    ) -> SourceFuture<'a>;
+}
+
+/// Built-in sources, as selected in config
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceKind {
+    OpenMeteo,
+    OpenWeatherMap,
+}
+
+/// Weather data provider: the configured sources, tried in order
+#[derive(Clone)]
+pub struct WeatherProvider {
+    http: HttpClient,
+    unit: TemperatureUnit,
+    sources: Vec<Arc<dyn WeatherSource>>,
+}
+
+impl std::fmt::Debug for WeatherProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WeatherProvider")
+            .field("unit", &self.unit)
+            .field("sources", &self.source_names())
+            .finish_non_exhaustive()
+    }
+}
+
+impl WeatherProvider {
+    /// Create a new weather provider (Open-Meteo only)
+    pub fn new(unit: TemperatureUnit) -> Result<Self, WeatherError> {
+        Self::new_with_proxies(unit, Vec::new())
+    }
+
+    /// Create a weather provider that sends requests through `proxies`
+    pub fn new_with_proxies(
+        unit: TemperatureUnit,
+        proxies: Vec<reqwest::Proxy>,
+    ) -> Result<Self, WeatherError> {
+        Self::new_with_timeout(unit, proxies, Duration::from_secs(REQUEST_TIMEOUT_SECS))
+    }
+
+    /// Like [`Self::new_with_proxies`], abandoning requests after `timeout`
+    pub fn new_with_timeout(
+        unit: TemperatureUnit,
+        proxies: Vec<reqwest::Proxy>,
+        timeout: Duration,
+    ) -> Result<Self, WeatherError> {
+        Ok(Self {
+            http: HttpClient::new(proxies, timeout)?,
+            unit,
+            sources: vec![Arc::new(OpenMeteo::new())],
+        })
+    }
+
+    /// Retry requests that time out or get a 5xx/429 response up to
+    /// `max_retries` times, waiting `backoff_base` (doubling, capped at
+    /// `max_backoff`) in between. Off by default.
+    pub fn with_retries(
+        mut self,
+        max_retries: u32,
+        backoff_base: Duration,
+        max_backoff: Duration,
+    ) -> Self {
+        self.http.retry_config =
+            RetryConfig { max_retries, initial_delay: backoff_base, max_delay: max_backoff };
+        self
+    }
+
+    /// Report every request to `observer` (e.g. the HTTP debug capture).
+    pub fn with_observer(mut self, observer: Option<HttpObserver>) -> Self {
+        self.http.observer = observer;
+        self
+    }
+
+    /// Use `sources` in order; each later one is a fallback for the ones
+    /// before it. An empty list is ignored.
+    pub fn with_sources(mut self, sources: Vec<Arc<dyn WeatherSource>>) -> Self {
+        if !sources.is_empty() {
+            self.sources = sources;
+        }
+        self
+    }
+
+    /// Use `primary`, then (if `fallback`) the other built-in source.
+    /// OpenWeatherMap is left out when there is no API key.
+    pub fn with_source_kind(
+        self,
+        primary: SourceKind,
+        openweathermap_key: Option<&str>,
+        fallback: bool,
+    ) -> Self {
+        let owm = openweathermap_key
+            .map(|key| Arc::new(OpenWeatherMap::new(key)) as Arc<dyn WeatherSource>);
+        let open_meteo: Arc<dyn WeatherSource> = Arc::new(OpenMeteo::new());
+
+        let mut sources = Vec::new();
+        match primary {
+            SourceKind::OpenMeteo => {
+                sources.push(open_meteo);
+                if fallback {
+                    sources.extend(owm);
+                }
+            }
+            SourceKind::OpenWeatherMap => {
+                if let Some(owm) = owm {
+                    sources.push(owm);
+                    if fallback {
+                        sources.push(open_meteo);
+                    }
+                } else {
+                    tracing::warn!("OpenWeatherMap selected without an API key, using Open-Meteo");
+                    sources.push(open_meteo);
+                }
+            }
+        }
+        self.with_sources(sources)
+    }
+
+    /// Names of the configured sources, primary first
+    pub fn source_names(&self) -> Vec<&'static str> {
+        self.sources.iter().map(|s| s.name()).collect()
+    }
+
+    /// Temperature unit data is fetched in
+    pub fn unit(&self) -> TemperatureUnit {
+        self.unit
+    }
+
+    /// Set temperature unit preference
+    pub fn set_unit(&mut self, unit: TemperatureUnit) {
+        self.unit = unit;
+    }
+
+    /// Fetch weather data for a location, falling back through the sources
+    pub async fn fetch(&self, location: &Location) -> Result<WeatherData, WeatherError> {
+        let mut last_error = None;
+        for source in &self.sources {
+            match source.fetch(&self.http, location, self.unit).await {
+                Ok(mut data) => {
+                    data.astro = data.compute_astro();
+                    return Ok(data);
+                }
+                Err(e) => {
+                    tracing::warn!("{} weather fetch failed: {}", source.name(), e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error
+            .unwrap_or_else(|| WeatherError::Parse("No weather source configured".to_string())))
+    }
+
+    /// Fetch available precipitation radar frames (RainViewer)
+    pub async fn fetch_radar(&self) -> Result<RadarMaps, WeatherError> {
+        let response = self.http.get(RAINVIEWER_MAPS_URL).await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(WeatherError::Parse(format!("Radar API error {}", status)));
+        }
+
+        response.json().await.map_err(|e| WeatherError::Parse(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Source that fails or returns canned data, counting calls
+    struct FakeSource {
+        name: &'static str,
+        fail: bool,
+        calls: AtomicUsize,
+    }
+
+    impl FakeSource {
+        fn new(name: &'static str, fail: bool) -> Arc<Self> {
+            Arc::new(Self { name, fail, calls: AtomicUsize::new(0) })
+        }
+    }
+
+    impl WeatherSource for FakeSource {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn fetch<'a>(
+            &'a self,
+            _http: &'a HttpClient,
+            location: &'a Location,
+            _unit: TemperatureUnit,
+        ) -> SourceFuture<'a> {
+            Box::pin(async move {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                if self.fail {
+                    return Err(WeatherError::Parse(format!("{} down", self.name)));
+                }
+                let now = chrono::Utc::now();
+                Ok(WeatherData {
+                    current: CurrentWeather {
+                        temperature: 20.0,
+                        feels_like: 20.0,
+                        humidity: 50,
+                        wind_speed: 3.0,
+                        condition: WeatherCondition::Clear,
+                        updated_at: now,
+                    },
+                    forecast: Vec::new(),
+                    location: location.clone(),
+                    fetched_at: now,
+                    precipitation: Vec::new(),
+                    astro: None,
+                })
+            })
+        }
+    }
+
+    fn location() -> Location {
+        Location { latitude: 0.0, longitude: 0.0, accuracy_meters: None, city_name: None }
+    }
+
+    #[tokio::test]
+    async fn test_http_client_retries_server_errors() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = WeatherProvider::new(TemperatureUnit::Celsius).unwrap();
+        assert_eq!(provider.http.get(&server.uri()).await.unwrap().status(), 503);
+        let provider = provider.with_retries(2, Duration::from_millis(1), Duration::from_millis(1));
+        assert_eq!(provider.http.get(&server.uri()).await.unwrap().status(), 200);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_falls_back_when_primary_fails() {
+        let primary = FakeSource::new("primary", true);
+        let backup = FakeSource::new("backup", false);
+        let provider = WeatherProvider::new(TemperatureUnit::Celsius)
+            .unwrap()
+            .with_sources(vec![primary.clone(), backup.clone()]);
+
+        assert!(provider.fetch(&location()).await.is_ok());
+        assert_eq!(primary.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(backup.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_skips_fallback_when_primary_succeeds() {
+        let primary = FakeSource::new("primary", false);
+        let backup = FakeSource::new("backup", false);
+        let provider = WeatherProvider::new(TemperatureUnit::Celsius)
+            .unwrap()
+            .with_sources(vec![primary.clone(), backup.clone()]);
+
+        provider.fetch(&location()).await.unwrap();
+        assert_eq!(backup.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_returns_last_error_when_all_fail() {
+        let provider = WeatherProvider::new(TemperatureUnit::Celsius)
+            .unwrap()
+            .with_sources(vec![FakeSource::new("primary", true), FakeSource::new("backup", true)]);
+
+        let err = provider.fetch(&location()).await.unwrap_err();
+        assert!(err.to_string().contains("backup down"));
+    }
+
+    #[test]
+    fn test_with_source_kind() {
+        let provider = WeatherProvider::new(TemperatureUnit::Celsius).unwrap();
+        let names = |kind, key, fallback| {
+            provider.clone().with_source_kind(kind, key, fallback).source_names()
+        };
+
+        assert_eq!(names(SourceKind::OpenMeteo, None, true), ["Open-Meteo"]);
+        assert_eq!(names(SourceKind::OpenMeteo, Some("k"), true), ["Open-Meteo", "OpenWeatherMap"]);
+        assert_eq!(names(SourceKind::OpenMeteo, Some("k"), false), ["Open-Meteo"]);
+        assert_eq!(
+            names(SourceKind::OpenWeatherMap, Some("k"), true),
+            ["OpenWeatherMap", "Open-Meteo"]
+        );
+        assert_eq!(names(SourceKind::OpenWeatherMap, Some("k"), false), ["OpenWeatherMap"]);
+        assert_eq!(names(SourceKind::OpenWeatherMap, None, true), ["Open-Meteo"]);
+    }
+}
@@ -1,12 +1,12 @@
+//! Open-Meteo forecast API (no API key required)
+
+use super::{HttpClient, SourceFuture, WeatherSource};
 use crate::types::*;
-use chrono::{NaiveDate, NaiveTime, Utc};
-use reqwest::Client;
-use std::sync::Arc;
-use std::time::Duration;
+use chrono::{DateTime, Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
 const OPEN_METEO_BASE_URL: &str = "https://api.open-meteo.com/v1/forecast";
-const REQUEST_TIMEOUT_SECS: u64 = 30;
-const USER_AGENT: &str = "MyMe/0.1.0";
+/// 15-minute steps requested for nowcasting (6 hours)
+const MINUTELY_STEPS: u32 = 24;
 
 /// Open-Meteo API response structures
 mod api {
@@ -14,9 +14,12 @@ mod api {
 
     #[derive(Debug, Deserialize)]
     pub struct ForecastResponse {
+        #[serde(default)]
+        pub utc_offset_seconds: i32,
         pub current: CurrentData,
         pub daily: DailyData,
         pub hourly: HourlyData,
+        pub minutely_15: Option<MinutelyData>,
     }
 
     #[derive(Debug, Deserialize)]
@@ -45,50 +48,79 @@ mod api {
         pub temperature_2m: Vec<f64>,
         pub weather_code: Vec<i32>,
         pub precipitation_probability: Vec<i32>,
+        #[serde(default)]
+        pub precipitation: Vec<f64>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct MinutelyData {
+        pub time: Vec<String>,
+        /// Precipitation sum over each 15-minute step (mm)
+        #[serde(default)]
+        pub precipitation: Vec<f64>,
     }
 }
 
-/// Weather data provider using Open-Meteo API
+/// Weather source backed by Open-Meteo
 #[derive(Debug, Clone)]
-pub struct WeatherProvider {
-    client: Arc<Client>,
-    unit: TemperatureUnit,
+pub struct OpenMeteo {
+    base_url: String,
+}
+
+impl Default for OpenMeteo {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl WeatherProvider {
-    /// Create a new weather provider
-    pub fn new(unit: TemperatureUnit) -> Result<Self, WeatherError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
-            .user_agent(USER_AGENT)
-            .build()?;
+impl WeatherSource for OpenMeteo {
+    fn name(&self) -> &'static str {
+        "Open-Meteo"
+    }
 
-        Ok(Self { client: Arc::new(client), unit })
+    fn fetch<'a>(
+        &'a self,
+        http: &'a HttpClient,
+        location: &'a Location,
+        unit: TemperatureUnit,
+    ) -> SourceFuture<'a> {
+        Box::pin(self.fetch_forecast(http, location, unit))
     }
+}
 
-    /// Set temperature unit preference
-    pub fn set_unit(&mut self, unit: TemperatureUnit) {
-        self.unit = unit;
+impl OpenMeteo {
+    pub fn new() -> Self {
+        Self::with_base_url(OPEN_METEO_BASE_URL)
     }
 
-    /// Fetch weather data for a location
-    pub async fn fetch(&self, location: &Location) -> Result<WeatherData, WeatherError> {
-        let unit_param = match self.unit {
+    /// Point at a different forecast endpoint (used by tests)
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        Self { base_url: base_url.into() }
+    }
+
+    async fn fetch_forecast(
+        &self,
+        http: &HttpClient,
+        location: &Location,
+        unit: TemperatureUnit,
+    ) -> Result<WeatherData, WeatherError> {
+        let unit_param = match unit {
             TemperatureUnit::Celsius | TemperatureUnit::Auto => "celsius",
             TemperatureUnit::Fahrenheit => "fahrenheit",
         };
 
         let url = format!(
-            "{}?latitude={}&longitude={}&current=temperature_2m,apparent_temperature,relative_humidity_2m,wind_speed_10m,weather_code&daily=temperature_2m_max,temperature_2m_min,weather_code,precipitation_probability_max,sunrise,sunset&hourly=temperature_2m,weather_code,precipitation_probability&temperature_unit={}&wind_speed_unit=mph&forecast_days=7&timezone=auto",
-            OPEN_METEO_BASE_URL,
+            "{}?latitude={}&longitude={}&current=temperature_2m,apparent_temperature,relative_humidity_2m,wind_speed_10m,weather_code&daily=temperature_2m_max,temperature_2m_min,weather_code,precipitation_probability_max,sunrise,sunset&hourly=temperature_2m,weather_code,precipitation_probability,precipitation&minutely_15=precipitation&forecast_minutely_15={}&temperature_unit={}&wind_speed_unit=mph&forecast_days=7&timezone=auto",
+            self.base_url,
             location.latitude,
             location.longitude,
+            MINUTELY_STEPS,
             unit_param
         );
 
         tracing::debug!("Fetching weather from: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = http.get(&url).await?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -99,11 +131,10 @@ impl WeatherProvider {
         let api_response: api::ForecastResponse =
             response.json().await.map_err(|e| WeatherError::Parse(e.to_string()))?;
 
-        self.parse_response(api_response, location)
+        Self::parse_response(api_response, location)
     }
 
     fn parse_response(
-        &self,
         resp: api::ForecastResponse,
         location: &Location,
     ) -> Result<WeatherData, WeatherError> {
@@ -159,7 +190,53 @@ impl WeatherProvider {
             });
         }
 
-        Ok(WeatherData { current, forecast, location: location.clone(), fetched_at: now })
+        let precipitation = Self::build_precip_series(&resp);
+
+        Ok(WeatherData {
+            current,
+            forecast,
+            location: location.clone(),
+            fetched_at: now,
+            precipitation,
+            astro: None,
+        })
+    }
+
+    /// Merge 15-minute nowcast and hourly precipitation into one UTC series.
+    /// Minutely points win; hourly points fill in after the minutely range ends.
+    fn build_precip_series(resp: &api::ForecastResponse) -> Vec<PrecipPoint> {
+        let offset = ChronoDuration::seconds(resp.utc_offset_seconds as i64);
+        let to_utc = |t: &str| {
+            NaiveDateTime::parse_from_str(t, "%Y-%m-%dT%H:%M")
+                .ok()
+                .map(|local| (local - offset).and_utc())
+        };
+
+        let mut series: Vec<PrecipPoint> = Vec::new();
+        if let Some(minutely) = &resp.minutely_15 {
+            for (t, mm) in minutely.time.iter().zip(&minutely.precipitation) {
+                if let Some(time) = to_utc(t) {
+                    // mm per 15 minutes -> mm/h
+                    series.push(PrecipPoint { time, intensity_mm_h: mm * 4.0, probability: None });
+                }
+            }
+        }
+
+        let minutely_end: Option<DateTime<Utc>> =
+            series.last().map(|p| p.time + ChronoDuration::minutes(15));
+        for (j, t) in resp.hourly.time.iter().enumerate() {
+            let (Some(time), Some(mm)) = (to_utc(t), resp.hourly.precipitation.get(j)) else {
+                continue;
+            };
+            if minutely_end.is_some_and(|end| time < end) {
+                continue;
+            }
+            let probability =
+                resp.hourly.precipitation_probability.get(j).map(|p| (*p).clamp(0, 100) as u8);
+            series.push(PrecipPoint { time, intensity_mm_h: *mm, probability });
+        }
+
+        series
     }
 
     fn parse_time_from_datetime(datetime_str: &str) -> Result<NaiveTime, WeatherError> {
@@ -182,14 +259,42 @@ mod tests {
 
     #[test]
     fn test_parse_time_from_datetime() {
-        let result = WeatherProvider::parse_time_from_datetime("2026-01-20T06:32").unwrap();
+        let result = OpenMeteo::parse_time_from_datetime("2026-01-20T06:32").unwrap();
         assert_eq!(result.hour(), 6);
         assert_eq!(result.minute(), 32);
     }
 
+    #[test]
+    fn test_build_precip_series_merges_minutely_and_hourly() {
+        let json = r#"{
+            "utc_offset_seconds": -3600,
+            "current": {"temperature_2m": 10.0, "apparent_temperature": 9.0,
+                        "relative_humidity_2m": 80, "wind_speed_10m": 5.0, "weather_code": 61},
+            "daily": {"time": [], "temperature_2m_max": [], "temperature_2m_min": [],
+                      "weather_code": [], "precipitation_probability_max": [],
+                      "sunrise": [], "sunset": []},
+            "hourly": {"time": ["2026-01-20T10:00", "2026-01-20T11:00"],
+                       "temperature_2m": [10.0, 11.0], "weather_code": [61, 61],
+                       "precipitation_probability": [40, 70], "precipitation": [0.5, 1.2]},
+            "minutely_15": {"time": ["2026-01-20T10:00", "2026-01-20T10:15",
+                                     "2026-01-20T10:30", "2026-01-20T10:45"],
+                            "precipitation": [0.0, 0.1, 0.3, 0.2]}
+        }"#;
+        let resp: api::ForecastResponse = serde_json::from_str(json).unwrap();
+
+        let series = OpenMeteo::build_precip_series(&resp);
+
+        // 4 minutely points, then the 11:00 hourly point (10:00 is covered by minutely)
+        assert_eq!(series.len(), 5);
+        assert_eq!(series[0].time.to_rfc3339(), "2026-01-20T11:00:00+00:00");
+        assert!((series[1].intensity_mm_h - 0.4).abs() < 1e-9);
+        assert_eq!(series[4].probability, Some(70));
+        assert!((series[4].intensity_mm_h - 1.2).abs() < 1e-9);
+    }
+
     #[test]
     fn test_parse_time_invalid_format() {
-        let result = WeatherProvider::parse_time_from_datetime("invalid");
+        let result = OpenMeteo::parse_time_from_datetime("invalid");
         assert!(result.is_err());
     }
 
@@ -197,7 +302,7 @@ mod tests {
     #[tokio::test]
     #[ignore] // Run with: cargo test -p myme-weather -- --ignored
     async fn test_fetch_real_api() {
-        let provider = WeatherProvider::new(TemperatureUnit::Fahrenheit).unwrap();
+        let provider = crate::WeatherProvider::new(TemperatureUnit::Fahrenheit).unwrap();
         let location = Location {
             latitude: 47.6062,
             longitude: -122.3321,
@@ -0,0 +1,400 @@
+//! OpenWeatherMap current weather and 5-day/3-hour forecast (API key required)
+
+use super::{HttpClient, SourceFuture, WeatherSource};
+use crate::types::*;
+use chrono::{DateTime, Duration as ChronoDuration, NaiveDate, NaiveDateTime, Timelike, Utc};
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+
+const OPENWEATHERMAP_BASE_URL: &str = "https://api.openweathermap.org/data/2.5";
+const MPS_TO_MPH: f64 = 2.236_936;
+
+/// OpenWeatherMap API response structures
+mod api {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    pub struct CurrentResponse {
+        pub weather: Vec<Condition>,
+        pub main: Main,
+        pub wind: Wind,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct ForecastResponse {
+        pub list: Vec<ForecastEntry>,
+        pub city: City,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct ForecastEntry {
+        /// Unix timestamp (UTC) at the start of the 3-hour step
+        pub dt: i64,
+        pub main: Main,
+        pub weather: Vec<Condition>,
+        /// Probability of precipitation, 0.0-1.0
+        #[serde(default)]
+        pub pop: f64,
+        pub rain: Option<Volume>,
+        pub snow: Option<Volume>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Main {
+        pub temp: f64,
+        #[serde(default)]
+        pub feels_like: f64,
+        #[serde(default)]
+        pub temp_min: f64,
+        #[serde(default)]
+        pub temp_max: f64,
+        #[serde(default)]
+        pub humidity: i32,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Condition {
+        pub id: i32,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Wind {
+        pub speed: f64,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct Volume {
+        /// Precipitation over the 3-hour step (mm)
+        #[serde(rename = "3h", default)]
+        pub three_hours: f64,
+    }
+
+    #[derive(Debug, Deserialize)]
+    pub struct City {
+        /// Shift from UTC in seconds
+        #[serde(default)]
+        pub timezone: i64,
+        pub sunrise: i64,
+        pub sunset: i64,
+    }
+}
+
+/// Weather source backed by OpenWeatherMap
+#[derive(Clone)]
+pub struct OpenWeatherMap {
+    api_key: String,
+    base_url: String,
+}
+
+impl std::fmt::Debug for OpenWeatherMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenWeatherMap").field("base_url", &self.base_url).finish_non_exhaustive()
+    }
+}
+
+impl WeatherSource for OpenWeatherMap {
+    fn name(&self) -> &'static str {
+        "OpenWeatherMap"
+    }
+
+    fn fetch<'a>(
+        &'a self,
+        http: &'a HttpClient,
+        location: &'a Location,
+        unit: TemperatureUnit,
+    ) -> SourceFuture<'a> {
+        Box::pin(self.fetch_forecast(http, location, unit))
+    }
+}
+
+impl OpenWeatherMap {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self { api_key: api_key.into(), base_url: OPENWEATHERMAP_BASE_URL.to_string() }
+    }
+
+    /// Point at a different API root (used by tests)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    async fn fetch_forecast(
+        &self,
+        http: &HttpClient,
+        location: &Location,
+        unit: TemperatureUnit,
+    ) -> Result<WeatherData, WeatherError> {
+        let metric = !matches!(unit, TemperatureUnit::Fahrenheit);
+        let query = format!(
+            "lat={}&lon={}&units={}",
+            location.latitude,
+            location.longitude,
+            if metric { "metric" } else { "imperial" }
+        );
+
+        let current: api::CurrentResponse = self.get_json(http, "weather", &query).await?;
+        let forecast: api::ForecastResponse = self.get_json(http, "forecast", &query).await?;
+
+        Ok(Self::parse_response(current, forecast, location, metric, Utc::now()))
+    }
+
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        http: &HttpClient,
+        endpoint: &str,
+        query: &str,
+    ) -> Result<T, WeatherError> {
+        // Log without the key
+        tracing::debug!("Fetching weather from: {}/{}?{}", self.base_url, endpoint, query);
+        let url = format!("{}/{}?{}&appid={}", self.base_url, endpoint, query, self.api_key);
+
+        let response = http.get(&url).await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(WeatherError::Parse(format!("API error {}: {}", status, text)));
+        }
+
+        response.json().await.map_err(|e| WeatherError::Parse(e.to_string()))
+    }
+
+    /// Normalize into [`WeatherData`]. The 3-hour forecast steps are grouped
+    /// into local days; each day's condition is the step nearest local noon,
+    /// and sunrise/sunset come from the city (the free API has no daily values).
+    fn parse_response(
+        current: api::CurrentResponse,
+        forecast: api::ForecastResponse,
+        location: &Location,
+        metric: bool,
+        now: DateTime<Utc>,
+    ) -> WeatherData {
+        let condition_of = |weather: &[api::Condition]| {
+            weather
+                .first()
+                .map(|c| WeatherCondition::from_owm_code(c.id))
+                .unwrap_or(WeatherCondition::Clear)
+        };
+        let offset = ChronoDuration::seconds(forecast.city.timezone);
+        let local = |ts: i64| -> Option<NaiveDateTime> {
+            DateTime::from_timestamp(ts, 0).map(|utc| utc.naive_utc() + offset)
+        };
+
+        let current = CurrentWeather {
+            temperature: current.main.temp,
+            feels_like: current.main.feels_like,
+            humidity: current.main.humidity.clamp(0, 255) as u8,
+            // Open-Meteo is asked for mph, so match it here
+            wind_speed: if metric { current.wind.speed * MPS_TO_MPH } else { current.wind.speed },
+            condition: condition_of(&current.weather),
+            updated_at: now,
+        };
+
+        let sunrise = local(forecast.city.sunrise).map(|t| t.time()).unwrap_or_default();
+        let sunset = local(forecast.city.sunset).map(|t| t.time()).unwrap_or_default();
+
+        let mut days: BTreeMap<NaiveDate, Vec<(NaiveDateTime, &api::ForecastEntry)>> =
+            BTreeMap::new();
+        for entry in &forecast.list {
+            if let Some(at) = local(entry.dt) {
+                days.entry(at.date()).or_default().push((at, entry));
+            }
+        }
+
+        let forecast_days = days
+            .into_iter()
+            .map(|(date, entries)| {
+                let high =
+                    entries.iter().map(|(_, e)| e.main.temp_max).fold(f64::NEG_INFINITY, f64::max);
+                let low =
+                    entries.iter().map(|(_, e)| e.main.temp_min).fold(f64::INFINITY, f64::min);
+                let midday = entries
+                    .iter()
+                    .min_by_key(|(at, _)| (at.hour() as i32 - 12).abs())
+                    .map(|(_, e)| condition_of(&e.weather))
+                    .unwrap_or(WeatherCondition::Clear);
+                let pop = entries.iter().map(|(_, e)| e.pop).fold(0.0, f64::max);
+                let hourly = entries
+                    .iter()
+                    .map(|(at, e)| HourlyForecast {
+                        time: at.time(),
+                        temperature: e.main.temp,
+                        condition: condition_of(&e.weather),
+                        precipitation_chance: percent(e.pop),
+                    })
+                    .collect();
+                DayForecast {
+                    date,
+                    high,
+                    low,
+                    condition: midday,
+                    precipitation_chance: percent(pop),
+                    sunrise,
+                    sunset,
+                    hourly,
+                }
+            })
+            .collect();
+
+        let precipitation = forecast
+            .list
+            .iter()
+            .filter_map(|e| {
+                let time = DateTime::from_timestamp(e.dt, 0)?;
+                let mm = e.rain.as_ref().map_or(0.0, |v| v.three_hours)
+                    + e.snow.as_ref().map_or(0.0, |v| v.three_hours);
+                Some(PrecipPoint {
+                    time,
+                    intensity_mm_h: mm / 3.0,
+                    probability: Some(percent(e.pop)),
+                })
+            })
+            .collect();
+
+        WeatherData {
+            current,
+            forecast: forecast_days,
+            location: location.clone(),
+            fetched_at: now,
+            precipitation,
+            astro: None,
+        }
+    }
+}
+
+/// 0.0-1.0 probability as a whole percentage
+fn percent(pop: f64) -> u8 {
+    (pop * 100.0).round().clamp(0.0, 100.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use chrono::NaiveTime;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn location() -> Location {
+        Location {
+            latitude: 47.6,
+            longitude: -122.3,
+            accuracy_meters: None,
+            city_name: Some("Seattle".to_string()),
+        }
+    }
+
+    fn current_json() -> serde_json::Value {
+        serde_json::json!({
+            "weather": [{ "id": 500, "main": "Rain" }],
+            "main": { "temp": 8.0, "feels_like": 6.5, "humidity": 91 },
+            "wind": { "speed": 4.0 }
+        })
+    }
+
+    /// Three steps on Jan 20 (local, UTC-8) and one on Jan 21
+    fn forecast_json() -> serde_json::Value {
+        // 2026-01-20T17:00:00Z = 09:00 local
+        let base = 1_768_928_400;
+        serde_json::json!({
+            "list": [
+                { "dt": base, "main": { "temp": 7.0, "temp_min": 6.0, "temp_max": 7.5 },
+                  "weather": [{ "id": 804 }], "pop": 0.1 },
+                { "dt": base + 3 * 3600, "main": { "temp": 9.0, "temp_min": 8.5, "temp_max": 9.5 },
+                  "weather": [{ "id": 501 }], "pop": 0.8, "rain": { "3h": 4.5 } },
+                { "dt": base + 6 * 3600, "main": { "temp": 8.0, "temp_min": 7.0, "temp_max": 8.0 },
+                  "weather": [{ "id": 500 }], "pop": 0.55, "rain": { "3h": 1.2 }, "snow": { "3h": 0.3 } },
+                { "dt": base + 24 * 3600, "main": { "temp": 3.0, "temp_min": 2.0, "temp_max": 4.0 },
+                  "weather": [{ "id": 600 }], "pop": 0.3 }
+            ],
+            "city": { "timezone": -28800, "sunrise": 1_768_923_900, "sunset": 1_768_957_200 }
+        })
+    }
+
+    fn parse(metric: bool) -> WeatherData {
+        OpenWeatherMap::parse_response(
+            serde_json::from_value(current_json()).unwrap(),
+            serde_json::from_value(forecast_json()).unwrap(),
+            &location(),
+            metric,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn test_parse_groups_steps_into_local_days() {
+        let data = parse(true);
+
+        assert_eq!(data.current.condition, WeatherCondition::Rain);
+        assert_eq!(data.current.humidity, 91);
+        assert!((data.current.wind_speed - 8.947_744).abs() < 1e-3);
+
+        assert_eq!(data.forecast.len(), 2);
+        let today = &data.forecast[0];
+        assert_eq!(today.date, NaiveDate::from_ymd_opt(2026, 1, 20).unwrap());
+        assert_eq!(today.high, 9.5);
+        assert_eq!(today.low, 6.0);
+        // 12:00 local step wins over 09:00 and 15:00
+        assert_eq!(today.condition, WeatherCondition::Rain);
+        assert_eq!(today.precipitation_chance, 80);
+        assert_eq!(today.hourly.len(), 3);
+        assert_eq!(today.hourly[0].time, NaiveTime::from_hms_opt(9, 0, 0).unwrap());
+        assert_eq!(today.sunrise, NaiveTime::from_hms_opt(7, 45, 0).unwrap());
+        assert_eq!(today.sunset, NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+        assert_eq!(data.forecast[1].condition, WeatherCondition::Snow);
+    }
+
+    #[test]
+    fn test_parse_precipitation_series() {
+        let data = parse(true);
+
+        assert_eq!(data.precipitation.len(), 4);
+        assert_eq!(data.precipitation[0].time.to_rfc3339(), "2026-01-20T17:00:00+00:00");
+        assert_eq!(data.precipitation[0].intensity_mm_h, 0.0);
+        assert!((data.precipitation[1].intensity_mm_h - 1.5).abs() < 1e-9);
+        assert!((data.precipitation[2].intensity_mm_h - 0.5).abs() < 1e-9);
+        assert_eq!(data.precipitation[2].probability, Some(55));
+    }
+
+    #[test]
+    fn test_parse_imperial_keeps_wind_speed() {
+        assert_eq!(parse(false).current.wind_speed, 4.0);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_sends_key_and_units() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/weather"))
+            .and(query_param("appid", "secret"))
+            .and(query_param("units", "imperial"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(current_json()))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/forecast"))
+            .and(query_param("appid", "secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(forecast_json()))
+            .mount(&server)
+            .await;
+
+        let source = OpenWeatherMap::new("secret").with_base_url(server.uri());
+        let http = HttpClient::new(Vec::new(), std::time::Duration::from_secs(5)).unwrap();
+        let data = source.fetch(&http, &location(), TemperatureUnit::Fahrenheit).await.unwrap();
+        assert_eq!(data.forecast.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_rejected_key_is_an_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("Invalid API key"))
+            .mount(&server)
+            .await;
+
+        let source = OpenWeatherMap::new("bad").with_base_url(server.uri());
+        let http = HttpClient::new(Vec::new(), std::time::Duration::from_secs(5)).unwrap();
+        let err = source.fetch(&http, &location(), TemperatureUnit::Celsius).await.unwrap_err();
+        assert!(err.to_string().contains("401"));
+    }
+}
@@ -0,0 +1,166 @@
+//! Precipitation radar tiles (RainViewer)
+//!
+//! Only assembles tile URLs; the UI loads the PNG tiles itself and draws
+//! the radar over an OpenStreetMap tile of the same area.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::types::Location;
+
+/// Tile edge size in pixels
+pub const TILE_SIZE: u32 = 256;
+/// Zoom of the tile shown around the user's location (roughly 600 km across)
+pub const DEFAULT_ZOOM: u8 = 6;
+/// RainViewer color scheme 2 ("Universal Blue")
+const COLOR_SCHEME: u32 = 2;
+/// Base map tiles the radar is drawn over
+const BASE_MAP_URL: &str = "https://tile.openstreetmap.org";
+
+/// Latest radar frame around a location, ready to display
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadarTile {
+    /// Radar overlay (transparent PNG)
+    pub radar_url: String,
+    /// Map tile underneath the overlay
+    pub map_url: String,
+    /// When the frame was observed
+    pub time: DateTime<Utc>,
+    /// Where the location falls within the tile, 0.0-1.0 from the left edge
+    pub x: f64,
+    /// Where the location falls within the tile, 0.0-1.0 from the top edge
+    pub y: f64,
+}
+
+/// One radar snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RadarFrame {
+    /// Unix timestamp of the frame
+    pub time: i64,
+    /// Path prefix appended to the host
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct RadarFrames {
+    #[serde(default)]
+    past: Vec<RadarFrame>,
+    #[serde(default)]
+    nowcast: Vec<RadarFrame>,
+}
+
+/// Available radar frames (`weather-maps.json`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct RadarMaps {
+    pub host: String,
+    #[serde(default)]
+    radar: RadarFrames,
+}
+
+impl RadarMaps {
+    /// Past frames followed by nowcast frames, oldest first
+    pub fn frames(&self) -> Vec<&RadarFrame> {
+        self.radar.past.iter().chain(self.radar.nowcast.iter()).collect()
+    }
+
+    /// Most recent observed (non-forecast) frame
+    pub fn latest(&self) -> Option<&RadarFrame> {
+        self.radar.past.last()
+    }
+
+    /// URL of the tile containing `location` at `zoom` for a frame
+    pub fn tile_url(&self, frame: &RadarFrame, location: &Location, zoom: u8) -> String {
+        let (x, y) = tile_coords(location.latitude, location.longitude, zoom);
+        format!(
+            "{}{}/{}/{}/{}/{}/{}/1_1.png",
+            self.host, frame.path, TILE_SIZE, zoom, x, y, COLOR_SCHEME
+        )
+    }
+
+    /// Tile URL for the latest frame, if any
+    pub fn latest_tile_url(&self, location: &Location, zoom: u8) -> Option<String> {
+        self.latest().map(|frame| self.tile_url(frame, location, zoom))
+    }
+
+    /// Latest frame around `location` with its base map tile, if any
+    pub fn latest_tile(&self, location: &Location, zoom: u8) -> Option<RadarTile> {
+        let frame = self.latest()?;
+        let (x, y) = tile_coords(location.latitude, location.longitude, zoom);
+        let (fx, fy) = tile_position(location.latitude, location.longitude, zoom);
+        Some(RadarTile {
+            radar_url: self.tile_url(frame, location, zoom),
+            map_url: format!("{}/{}/{}/{}.png", BASE_MAP_URL, zoom, x, y),
+            time: DateTime::from_timestamp(frame.time, 0)?,
+            x: fx - x as f64,
+            y: fy - y as f64,
+        })
+    }
+}
+
+/// Web-Mercator position of a point in tile units (the integer part is the tile)
+fn tile_position(latitude: f64, longitude: f64, zoom: u8) -> (f64, f64) {
+    let n = 2f64.powi(zoom as i32);
+    let lat = latitude.clamp(-85.0511, 85.0511).to_radians();
+    let x = (longitude + 180.0) / 360.0 * n;
+    let y = (1.0 - lat.tan().asinh() / std::f64::consts::PI) / 2.0 * n;
+    // The far edge belongs to the last tile
+    (x.clamp(0.0, n - 1e-9), y.clamp(0.0, n - 1e-9))
+}
+
+/// Web-Mercator (slippy map) tile coordinates for a point
+pub fn tile_coords(latitude: f64, longitude: f64, zoom: u8) -> (u32, u32) {
+    let (x, y) = tile_position(latitude, longitude, zoom);
+    (x.floor() as u32, y.floor() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+
+    #[test]
+    fn test_tile_coords() {
+        assert_eq!(tile_coords(0.0, 0.0, 0), (0, 0));
+        assert_eq!(tile_coords(0.0, 0.0, 1), (1, 1));
+        // Seattle at zoom 6
+        assert_eq!(tile_coords(47.6062, -122.3321, 6), (10, 22));
+    }
+
+    #[test]
+    fn test_parse_maps_and_tile_url() {
+        let json = r#"{
+            "version": "2.0",
+            "host": "https://tilecache.rainviewer.com",
+            "radar": {
+                "past": [
+                    {"time": 1700000000, "path": "/v2/radar/1700000000"},
+                    {"time": 1700000600, "path": "/v2/radar/1700000600"}
+                ],
+                "nowcast": [{"time": 1700001200, "path": "/v2/radar/nowcast_1"}]
+            }
+        }"#;
+        let maps: RadarMaps = serde_json::from_str(json).unwrap();
+        assert_eq!(maps.frames().len(), 3);
+
+        let location =
+            Location { latitude: 0.0, longitude: 0.0, accuracy_meters: None, city_name: None };
+        assert_eq!(
+            maps.latest_tile_url(&location, 1).unwrap(),
+            "https://tilecache.rainviewer.com/v2/radar/1700000600/256/1/1/1/2/1_1.png"
+        );
+
+        let tile = maps.latest_tile(&location, 1).unwrap();
+        assert_eq!(tile.map_url, "https://tile.openstreetmap.org/1/1/1.png");
+        assert_eq!(tile.time.timestamp(), 1700000600);
+        // (0, 0) is the top-left corner of tile (1, 1) at zoom 1
+        assert!(tile.x.abs() < 1e-9 && tile.y.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_frames_no_tile() {
+        let maps: RadarMaps = serde_json::from_str(r#"{"host": "https://h"}"#).unwrap();
+        let location =
+            Location { latitude: 47.6, longitude: -122.3, accuracy_meters: None, city_name: None };
+        assert!(maps.latest_tile(&location, DEFAULT_ZOOM).is_none());
+    }
+}
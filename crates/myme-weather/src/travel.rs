@@ -0,0 +1,170 @@
+//! Travel time estimates between two places: a driving route from OSRM
+//! (OpenStreetMap routing, no API key required), else the straight-line
+//! distance at a typical door-to-door speed.
+
+use chrono::Duration;
+use serde::Deserialize;
+
+use crate::geocode::http_client;
+use crate::types::{Location, WeatherError};
+
+const OSRM_URL: &str = "https://router.project-osrm.org/route/v1/driving";
+
+/// Roads run about this much longer than the straight line
+const DETOUR_FACTOR: f64 = 1.4;
+
+/// Average speed assumed without a route, in km/h (city driving, parking)
+const FALLBACK_SPEED_KMH: f64 = 35.0;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Where an estimate came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TravelSource {
+    /// A driving route
+    Route,
+    /// Straight-line distance, when no route was available
+    Distance,
+}
+
+/// Estimated trip from one place to another.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TravelEstimate {
+    pub duration: Duration,
+    pub distance_km: f64,
+    pub source: TravelSource,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsrmResponse {
+    code: String,
+    #[serde(default)]
+    routes: Vec<OsrmRoute>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OsrmRoute {
+    /// Seconds
+    duration: f64,
+    /// Meters
+    distance: f64,
+}
+
+/// Great-circle distance between two places in kilometers.
+pub fn distance_km(from: &Location, to: &Location) -> f64 {
+    let (lat1, lat2) = (from.latitude.to_radians(), to.latitude.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (to.longitude - from.longitude).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Estimate from the straight-line distance alone.
+pub fn estimate_from_distance(from: &Location, to: &Location) -> TravelEstimate {
+    let distance_km = distance_km(from, to) * DETOUR_FACTOR;
+    let minutes = (distance_km / FALLBACK_SPEED_KMH * 60.0).ceil() as i64;
+    TravelEstimate {
+        duration: Duration::minutes(minutes),
+        distance_km,
+        source: TravelSource::Distance,
+    }
+}
+
+/// Driving time from `from` to `to`, falling back to
+/// [`estimate_from_distance`] when routing fails.
+pub async fn estimate_travel(
+    from: &Location,
+    to: &Location,
+    proxies: Vec<reqwest::Proxy>,
+) -> TravelEstimate {
+    match route_at(OSRM_URL, from, to, proxies).await {
+        Ok(Some(estimate)) => estimate,
+        Ok(None) => estimate_from_distance(from, to),
+        Err(e) => {
+            tracing::debug!("Routing failed, estimating from distance: {}", e);
+            estimate_from_distance(from, to)
+        }
+    }
+}
+
+/// Driving route from OSRM at `base_url`; `Ok(None)` when there is no route.
+async fn route_at(
+    base_url: &str,
+    from: &Location,
+    to: &Location,
+    proxies: Vec<reqwest::Proxy>,
+) -> Result<Option<TravelEstimate>, WeatherError> {
+    // OSRM takes longitude first
+    let url = format!(
+        "{}/{},{};{},{}?overview=false",
+        base_url, from.longitude, from.latitude, to.longitude, to.latitude
+    );
+    let response: OsrmResponse =
+        http_client(proxies)?.get(&url).send().await?.error_for_status()?.json().await?;
+    if response.code != "Ok" {
+        return Ok(None);
+    }
+    Ok(response.routes.first().map(|route| TravelEstimate {
+        duration: Duration::seconds(route.duration.ceil() as i64),
+        distance_km: route.distance / 1000.0,
+        source: TravelSource::Route,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn place(latitude: f64, longitude: f64) -> Location {
+        Location { latitude, longitude, accuracy_meters: None, city_name: None }
+    }
+
+    #[test]
+    fn test_distance_and_fallback_estimate() {
+        let toronto = place(43.6532, -79.3832);
+        let ottawa = place(45.4215, -75.6972);
+        let km = distance_km(&toronto, &ottawa);
+        assert!((km - 352.0).abs() < 5.0, "got {km}");
+        assert_eq!(distance_km(&toronto, &toronto), 0.0);
+
+        let estimate = estimate_from_distance(&toronto, &ottawa);
+        assert_eq!(estimate.source, TravelSource::Distance);
+        // ~493 km of road at 35 km/h
+        assert!((840..=850).contains(&estimate.duration.num_minutes()));
+    }
+
+    #[tokio::test]
+    async fn test_route_from_osrm() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/-79.3832,43.6532;-79.3957,43.6629"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "code": "Ok",
+                "routes": [{"duration": 611.4, "distance": 2870.2}]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/-79.3832,43.6532;-3.1883,55.9533"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "code": "NoRoute",
+                "routes": []
+            })))
+            .mount(&server)
+            .await;
+
+        let from = place(43.6532, -79.3832);
+        let estimate =
+            route_at(&server.uri(), &from, &place(43.6629, -79.3957), Vec::new()).await.unwrap();
+        let estimate = estimate.unwrap();
+        assert_eq!(estimate.source, TravelSource::Route);
+        assert_eq!(estimate.duration, Duration::seconds(612));
+        assert!((estimate.distance_km - 2.8702).abs() < 1e-9);
+
+        let overseas = route_at(&server.uri(), &from, &place(55.9533, -3.1883), Vec::new()).await;
+        assert!(overseas.unwrap().is_none());
+    }
+}
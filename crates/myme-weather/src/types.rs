@@ -1,6 +1,8 @@
 use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::astro::{self, AstroData};
+
 /// Temperature unit preference
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -48,6 +50,23 @@ impl WeatherCondition {
         }
     }
 
+    /// Convert an OpenWeatherMap condition id to WeatherCondition
+    /// See: https://openweathermap.org/weather-conditions
+    pub fn from_owm_code(id: i32) -> Self {
+        match id {
+            200..=299 => Self::Thunderstorm,
+            300..=399 => Self::Drizzle,
+            500 | 501 | 520 | 521 => Self::Rain,
+            502..=504 | 522 | 531 => Self::HeavyRain,
+            511 | 611..=616 => Self::Sleet, // Freezing rain, sleet, rain and snow
+            600..=602 | 620..=622 => Self::Snow,
+            700..=799 => Self::Fog, // Mist, haze, dust, fog
+            801 | 802 => Self::PartlyCloudy,
+            803 | 804 => Self::Cloudy,
+            _ => Self::Clear, // 800 and unknown ids
+        }
+    }
+
     /// Get a human-readable description
     pub fn description(&self) -> &'static str {
         match self {
@@ -64,6 +83,22 @@ impl WeatherCondition {
         }
     }
 
+    /// Id of the description in the message catalogs (`myme_core::i18n`)
+    pub fn message_id(&self) -> &'static str {
+        match self {
+            Self::Clear => "weather-clear",
+            Self::PartlyCloudy => "weather-partly-cloudy",
+            Self::Cloudy => "weather-cloudy",
+            Self::Fog => "weather-fog",
+            Self::Drizzle => "weather-drizzle",
+            Self::Rain => "weather-rain",
+            Self::HeavyRain => "weather-heavy-rain",
+            Self::Snow => "weather-snow",
+            Self::Sleet => "weather-sleet",
+            Self::Thunderstorm => "weather-thunderstorm",
+        }
+    }
+
     /// Get icon name (Phosphor icon unicode will be in QML)
     pub fn icon_name(&self) -> &'static str {
         match self {
@@ -123,6 +158,16 @@ pub struct DayForecast {
     pub hourly: Vec<HourlyForecast>,
 }
 
+/// Precipitation intensity at a point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrecipPoint {
+    pub time: DateTime<Utc>,
+    /// Precipitation rate in mm/h
+    pub intensity_mm_h: f64,
+    /// Probability in percent (hourly points only)
+    pub probability: Option<u8>,
+}
+
 /// Complete weather data bundle
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WeatherData {
@@ -130,6 +175,106 @@ pub struct WeatherData {
     pub forecast: Vec<DayForecast>,
     pub location: Location,
     pub fetched_at: DateTime<Utc>,
+    /// Nowcast (15-minute) then hourly precipitation, ascending by time
+    #[serde(default)]
+    pub precipitation: Vec<PrecipPoint>,
+    /// Moon phase, day length and golden hour for today (older caches lack it)
+    #[serde(default)]
+    pub astro: Option<AstroData>,
+}
+
+/// Current conditions as observed at one fetch, kept for day-over-day comparisons
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Observation {
+    pub at: DateTime<Utc>,
+    /// Always Celsius, whatever unit it was fetched in
+    pub temperature_c: f64,
+    pub humidity: u8,
+    pub condition: WeatherCondition,
+}
+
+impl Observation {
+    /// Reading from `current`, fetched in `unit`
+    pub fn from_current(current: &CurrentWeather, unit: TemperatureUnit) -> Self {
+        Self {
+            at: current.updated_at,
+            temperature_c: to_celsius(current.temperature, unit),
+            humidity: current.humidity,
+            condition: current.condition,
+        }
+    }
+}
+
+/// Now compared with the same time yesterday
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeatherDelta {
+    /// Degrees warmer (negative: colder), in the requested unit
+    pub temperature: f64,
+    pub now: Observation,
+    pub yesterday: Observation,
+}
+
+impl WeatherDelta {
+    /// "5° warmer than this time yesterday"; differences under a degree read
+    /// as "about the same"
+    pub fn summary(&self) -> String {
+        let degrees = self.temperature.round();
+        if degrees == 0.0 {
+            return "About the same as this time yesterday".to_string();
+        }
+        let direction = if degrees > 0.0 { "warmer" } else { "colder" };
+        format!("{}° {} than this time yesterday", degrees.abs(), direction)
+    }
+}
+
+/// Convert a temperature fetched in `unit` to Celsius (both sources fetch
+/// Celsius for `Auto`)
+pub fn to_celsius(temperature: f64, unit: TemperatureUnit) -> f64 {
+    match unit {
+        TemperatureUnit::Fahrenheit => (temperature - 32.0) * 5.0 / 9.0,
+        TemperatureUnit::Celsius | TemperatureUnit::Auto => temperature,
+    }
+}
+
+/// Rate (mm/h) above which precipitation counts as "rain" for nowcast hints
+pub const PRECIP_THRESHOLD_MM_H: f64 = 0.1;
+
+impl WeatherData {
+    /// Astronomical data for the first forecast day, from its sunrise/sunset
+    pub fn compute_astro(&self) -> Option<AstroData> {
+        let today = self.forecast.first()?;
+        Some(astro::compute(
+            today.date,
+            self.location.latitude,
+            today.sunrise,
+            today.sunset,
+            self.fetched_at,
+        ))
+    }
+
+    /// Precipitation points from the current 15-minute slot up to `hours` ahead
+    pub fn precip_series(&self, hours: u32) -> Vec<PrecipPoint> {
+        self.precip_series_at(Utc::now(), hours)
+    }
+
+    fn precip_series_at(&self, now: DateTime<Utc>, hours: u32) -> Vec<PrecipPoint> {
+        let start = now - chrono::Duration::minutes(15);
+        let end = now + chrono::Duration::hours(hours as i64);
+        self.precipitation.iter().filter(|p| p.time > start && p.time <= end).cloned().collect()
+    }
+
+    /// Minutes until precipitation starts within the next `hours`
+    /// (0 if it is already raining, None if none expected)
+    pub fn minutes_until_precipitation(&self, hours: u32) -> Option<i64> {
+        self.minutes_until_precipitation_at(Utc::now(), hours)
+    }
+
+    fn minutes_until_precipitation_at(&self, now: DateTime<Utc>, hours: u32) -> Option<i64> {
+        self.precip_series_at(now, hours)
+            .iter()
+            .find(|p| p.intensity_mm_h >= PRECIP_THRESHOLD_MM_H)
+            .map(|p| (p.time - now).num_minutes().max(0))
+    }
 }
 
 /// Location service errors
@@ -237,6 +382,84 @@ mod tests {
         assert_eq!(WeatherCondition::from_wmo_code(-1), WeatherCondition::Clear);
     }
 
+    #[test]
+    fn test_owm_code_mapping() {
+        assert_eq!(WeatherCondition::from_owm_code(211), WeatherCondition::Thunderstorm);
+        assert_eq!(WeatherCondition::from_owm_code(301), WeatherCondition::Drizzle);
+        assert_eq!(WeatherCondition::from_owm_code(500), WeatherCondition::Rain);
+        assert_eq!(WeatherCondition::from_owm_code(502), WeatherCondition::HeavyRain);
+        assert_eq!(WeatherCondition::from_owm_code(511), WeatherCondition::Sleet);
+        assert_eq!(WeatherCondition::from_owm_code(613), WeatherCondition::Sleet);
+        assert_eq!(WeatherCondition::from_owm_code(601), WeatherCondition::Snow);
+        assert_eq!(WeatherCondition::from_owm_code(741), WeatherCondition::Fog);
+        assert_eq!(WeatherCondition::from_owm_code(800), WeatherCondition::Clear);
+        assert_eq!(WeatherCondition::from_owm_code(802), WeatherCondition::PartlyCloudy);
+        assert_eq!(WeatherCondition::from_owm_code(804), WeatherCondition::Cloudy);
+        assert_eq!(WeatherCondition::from_owm_code(0), WeatherCondition::Clear);
+    }
+
+    #[test]
+    fn test_wmo_and_owm_codes_normalize_alike() {
+        // (WMO code, OWM id) pairs describing the same phenomenon
+        let pairs = [
+            (0, 800),  // clear sky
+            (2, 802),  // scattered clouds
+            (3, 804),  // overcast
+            (45, 741), // fog
+            (53, 301), // moderate drizzle
+            (61, 500), // light rain
+            (65, 502), // heavy rain
+            (67, 511), // freezing rain
+            (73, 601), // snow
+            (95, 211), // thunderstorm
+        ];
+        for (wmo, owm) in pairs {
+            assert_eq!(
+                WeatherCondition::from_wmo_code(wmo),
+                WeatherCondition::from_owm_code(owm),
+                "WMO {} vs OWM {}",
+                wmo,
+                owm
+            );
+        }
+    }
+
+    #[test]
+    fn test_precip_series_and_minutes_until_rain() {
+        let now = DateTime::parse_from_rfc3339("2026-01-20T10:05:00Z").unwrap().with_timezone(&Utc);
+        let at = |m: i64, mm: f64| PrecipPoint {
+            time: DateTime::parse_from_rfc3339("2026-01-20T10:00:00Z").unwrap().with_timezone(&Utc)
+                + chrono::Duration::minutes(m),
+            intensity_mm_h: mm,
+            probability: None,
+        };
+        let data = WeatherData {
+            current: CurrentWeather {
+                temperature: 10.0,
+                feels_like: 9.0,
+                humidity: 80,
+                wind_speed: 5.0,
+                condition: WeatherCondition::Cloudy,
+                updated_at: now,
+            },
+            forecast: vec![],
+            location: Location {
+                latitude: 0.0,
+                longitude: 0.0,
+                accuracy_meters: None,
+                city_name: None,
+            },
+            fetched_at: now,
+            precipitation: vec![at(-15, 2.0), at(0, 0.0), at(15, 0.0), at(30, 0.8), at(120, 1.0)],
+            astro: None,
+        };
+
+        let series = data.precip_series_at(now, 1);
+        assert_eq!(series.len(), 3);
+        assert_eq!(data.minutes_until_precipitation_at(now, 1), Some(25));
+        assert_eq!(data.minutes_until_precipitation_at(now + chrono::Duration::hours(3), 1), None);
+    }
+
     #[test]
     fn test_condition_description() {
         assert_eq!(WeatherCondition::Clear.description(), "Clear");
@@ -7,11 +7,8 @@
 // cxx-qt generated bridges
 extern "C" bool cxx_qt_init_crate_myme_ui();
 
-// Rust initialization functions
-extern "C" bool initialize_note_client();
-extern "C" bool initialize_weather_services();
-extern "C" bool initialize_github_auth();
-extern "C" bool initialize_github_client();
+// Rust initialization (services start in the background, in dependency order)
+extern "C" bool start_app_services();
 
 // Rust shutdown function (called on app exit for graceful cleanup)
 extern "C" void shutdown_app_services();
@@ -37,17 +34,9 @@ int main(int argc, char *argv[])
     // Initialize cxx-qt types (this also registers QML types)
     cxx_qt_init_crate_myme_ui();
 
-    // Initialize note client (SQLite)
-    initialize_note_client();
-
-    // Initialize weather services (WeatherProvider, cache)
-    initialize_weather_services();
-
-    // Initialize GitHub OAuth provider (enables authentication flow)
-    initialize_github_auth();
-
-    // Initialize GitHub client (requires prior OAuth authentication)
-    initialize_github_client();
+    // Error log, connectivity, notes, weather, GitHub, remote control...
+    // StartupModel shows what is still loading and what failed
+    start_app_services();
 
     // Connect shutdown handler to aboutToQuit signal
     // This ensures graceful cleanup of Rust services before the app exits